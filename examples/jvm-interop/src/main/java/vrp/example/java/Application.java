@@ -7,6 +7,7 @@ import com.sun.jna.Native;
 import java.io.IOException;
 import java.nio.file.Files;
 import java.nio.file.Paths;
+import java.util.concurrent.atomic.AtomicBoolean;
 
 /** Encapsulate Vehicle Routing Problem solver behavior.  */
 interface Solver extends Library {
@@ -19,6 +20,14 @@ interface Solver extends Library {
                          int matricesSize,
                          String config,
                          OnSuccess onSuccess, OnError onError);
+    /** Solves pragmatic problem reporting progress and checking cancellation. **/
+    void solve_pragmatic_with_progress(String problem, String[] matrices,
+                                       int matricesSize,
+                                       String config,
+                                       long progressInterval,
+                                       OnProgress onProgress,
+                                       IsCancelled isCancelled,
+                                       OnSuccess onSuccess, OnError onError);
 }
 
 interface OnSuccess extends Callback {
@@ -29,6 +38,14 @@ interface OnError extends Callback {
     void result(String error);
 }
 
+interface OnProgress extends Callback {
+    void progress(String json);
+}
+
+interface IsCancelled extends Callback {
+    int invoke();
+}
+
 class Application {
     public static void main(String[] args) throws IOException {
         if (args.length < 1) {
@@ -56,8 +73,21 @@ class Application {
                     }
                 });
 
-        solver.solve_pragmatic(problem, matrices, matrices.length, "{}",
-                new OnSuccess() {
+        // NOTE set it from another thread to stop the search and get the best known solution
+        AtomicBoolean cancellation = new AtomicBoolean(false);
+
+        solver.solve_pragmatic_with_progress(problem, matrices, matrices.length, "{}", 100,
+                new OnProgress() {
+                    @Override
+                    public void progress(String json) {
+                        System.out.println(json);
+                    }
+                }, new IsCancelled() {
+                    @Override
+                    public int invoke() {
+                        return cancellation.get() ? 1 : 0;
+                    }
+                }, new OnSuccess() {
                     @Override
                     public void result(String json) {
                         System.out.println(json);
@@ -5,6 +5,8 @@ import com.sun.jna.Library
 import com.sun.jna.Native
 import java.nio.file.Files
 import java.nio.file.Paths
+import java.util.concurrent.atomic.AtomicBoolean
+import kotlin.concurrent.thread
 
 /** Encapsulate Vehicle Routing Problem solver behavior.  */
 private interface Solver : Library {
@@ -12,12 +14,26 @@ private interface Solver : Library {
     fun get_routing_locations(problem: String, onSuccess: OnSuccess, onError: OnError)
     /** Converts problem to pragmatic format. **/
     fun convert_to_pragmatic(format: String, inputs: Array<String>, inputsLen: Int, onSuccess: OnSuccess, onError: OnError)
+    /** Validates pragmatic problem. **/
+    fun validate_pragmatic(problem: String,
+                           matrices: Array<String>,
+                           matricesLen: Int,
+                           onSuccess: OnSuccess, onError: OnError)
     /** Solves pragmatic problem. **/
     fun solve_pragmatic(problem: String,
                         matrices: Array<String>,
                         matricesLen: Int,
                         config: String,
                         onSuccess: OnSuccess, onError: OnError)
+    /** Solves pragmatic problem reporting progress and checking cancellation. **/
+    fun solve_pragmatic_with_progress(problem: String,
+                                      matrices: Array<String>,
+                                      matricesLen: Int,
+                                      config: String,
+                                      progressInterval: Long,
+                                      onProgress: OnProgress,
+                                      isCancelled: IsCancelled,
+                                      onSuccess: OnSuccess, onError: OnError)
 }
 
 private interface OnSuccess : Callback {
@@ -28,39 +44,99 @@ private interface OnError : Callback {
     fun result(error: String)
 }
 
+private interface OnProgress : Callback {
+    fun progress(json: String)
+}
+
+private interface IsCancelled : Callback {
+    fun invoke(): Int
+}
+
+/** A result of solver call: json string on success or error description. */
+sealed class SolverResult {
+    data class Success(val json: String) : SolverResult()
+    data class Failure(val error: String) : SolverResult()
+}
+
+/** Provides a typed API on top of native solver functions. */
+class PragmaticSolver {
+    private val library = Native.load("vrp_cli", Solver::class.java)
+
+    /** Returns list of unique locations which can be used to request a routing matrix. */
+    fun getRoutingLocations(problem: String): SolverResult = call { onSuccess, onError ->
+        library.get_routing_locations(problem, onSuccess, onError)
+    }
+
+    /** Validates problem and matrices, returns an empty json array if there are no errors. */
+    fun validate(problem: String, matrices: List<String>): SolverResult = call { onSuccess, onError ->
+        library.validate_pragmatic(problem, matrices.toTypedArray(), matrices.size, onSuccess, onError)
+    }
+
+    /**
+     * Solves problem and returns solution. Search progress is passed to [onProgress] as json line every
+     * [progressInterval] generations. When [cancellation] is set, the best known solution is returned.
+     */
+    fun solve(problem: String,
+              matrices: List<String>,
+              config: String = "{}",
+              progressInterval: Long = 100,
+              cancellation: AtomicBoolean = AtomicBoolean(false),
+              onProgress: (String) -> Unit = {}): SolverResult = call { onSuccess, onError ->
+        library.solve_pragmatic_with_progress(problem, matrices.toTypedArray(), matrices.size, config, progressInterval,
+                object : OnProgress {
+                    override fun progress(json: String) = onProgress(json)
+                },
+                object : IsCancelled {
+                    override fun invoke(): Int = if (cancellation.get()) 1 else 0
+                },
+                onSuccess, onError)
+    }
+
+    private fun call(action: (OnSuccess, OnError) -> Unit): SolverResult {
+        var result: SolverResult = SolverResult.Failure("no result")
+        action(object : OnSuccess {
+            override fun result(json: String) {
+                result = SolverResult.Success(json)
+            }
+        }, object : OnError {
+            override fun result(error: String) {
+                result = SolverResult.Failure(error)
+            }
+        })
+
+        return result
+    }
+}
+
 fun main(args: Array<String>) {
     if (args.count() < 1) {
         throw IllegalStateException("Specify problem and, optionally, routing matrices paths")
     }
 
     val problem = String(Files.readAllBytes(Paths.get(args[0])))
-    val matrices = args.drop(1).map { String(Files.readAllBytes(Paths.get(it))) }.toTypedArray()
-
-    val solver = Native.load("vrp_cli", Solver::class.java)
-
-    solver.get_routing_locations(problem,
-            onSuccess = object : OnSuccess {
-                override fun result(json: String) {
-                    println("locations: $json")
-                }
-            },
-            onError = object : OnError {
-                override fun result(error: String) {
-                    println(error)
-                }
-            }
-    )
-
-    solver.solve_pragmatic(problem, matrices, matrices.size, "{}",
-            onSuccess = object : OnSuccess {
-                override fun result(json: String) {
-                    println("solution: $json")
-                }
-            },
-            onError = object : OnError {
-                override fun result(error: String) {
-                    println(error)
-                }
-            }
-    )
+    val matrices = args.drop(1).map { String(Files.readAllBytes(Paths.get(it))) }
+
+    val solver = PragmaticSolver()
+
+    println("locations: ${solver.getRoutingLocations(problem)}")
+
+    when (val validation = solver.validate(problem, matrices)) {
+        is SolverResult.Failure -> {
+            println("invalid problem: ${validation.error}")
+            return
+        }
+        is SolverResult.Success -> println("problem is valid")
+    }
+
+    // stop the search after 10 seconds and get the best known solution
+    val cancellation = AtomicBoolean(false)
+    thread(isDaemon = true) {
+        Thread.sleep(10_000)
+        cancellation.set(true)
+    }
+
+    when (val result = solver.solve(problem, matrices, cancellation = cancellation) { println("progress: $it") }) {
+        is SolverResult.Success -> println("solution: ${result.json}")
+        is SolverResult.Failure -> println("error: ${result.error}")
+    }
 }
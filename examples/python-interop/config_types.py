@@ -54,6 +54,8 @@ Logging.__pydantic_model__.update_forward_refs()
 class Environment:
     logging: Logging = Logging(enabled=True)
     isExperimental: Optional[bool] = None
+    isDeterministic: Optional[bool] = None
+    memoryBudget: Optional[int] = None
 
 
 Config.__pydantic_model__.update_forward_refs()
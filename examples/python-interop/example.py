@@ -112,11 +112,28 @@ problem = prg.Problem(
 
 )
 
+problem_json = json.dumps(problem, default=pydantic_encoder)
+matrices_json = [json.dumps(matrix, default=pydantic_encoder)]
+
+
+# optionally, get notified about search progress: each event is a json string
+def on_progress(event):
+    event = json.loads(event)
+    print(f"generation: {event['generation']}, best cost: {event.get('bestCost')}")
+
+
 # run solver and deserialize result into solution model
-solution = prg.Solution(**json.loads(vrp_cli.solve_pragmatic(
-    problem=json.dumps(problem, default=pydantic_encoder),
-    matrices=[json.dumps(matrix, default=pydantic_encoder)],
+solution_json = vrp_cli.solve_pragmatic(
+    problem=problem_json,
+    matrices=matrices_json,
     config=json.dumps(config, default=pydantic_encoder),
-)))
+    progress=on_progress,
+    progress_interval=100,
+)
+solution = prg.Solution(**json.loads(solution_json))
 
 print(solution)
+
+# check solution feasibility: small time (seconds) and distance (meters) mismatches are reported as warnings
+report = json.loads(vrp_cli.check_pragmatic(problem_json, solution_json, matrices_json, time_tolerance=1))
+print(f"is feasible: {report['isFeasible']}, errors: {report['errors']}, warnings: {report['warnings']}")
@@ -167,6 +167,7 @@ Objective.__pydantic_model__.update_forward_refs()
 class Solution:
     statistic: Statistic
     tours: List[Tour]
+    schemaVersion: Optional[int] = None
 
 
 @dataclass
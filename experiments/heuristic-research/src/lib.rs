@@ -17,12 +17,21 @@ use wasm_bindgen::prelude::*;
 
 mod plots;
 pub use self::plots::{
-    Axes, draw_fitness_plots, draw_population_plots, draw_search_best_statistics_plots,
-    draw_search_duration_statistics_plots, draw_search_iteration_plots, draw_search_overall_statistics_plots,
+    Axes, draw_comparison_plots, draw_fitness_plots, draw_operator_effectiveness_plots, draw_population_plots,
+    draw_routes_plots, draw_search_best_statistics_plots, draw_search_duration_statistics_plots,
+    draw_search_iteration_plots, draw_search_overall_statistics_plots, draw_solution_dynamics_plots,
 };
 
 mod solver;
-pub use self::solver::{solve_function, solve_vrp};
+pub use self::solver::{
+    PopulationListener, PopulationState, compare_function, compare_vrp, set_population_listener,
+    set_solution_snapshot_interval, solve_function, solve_vrp,
+};
+
+#[cfg(all(feature = "live-stream", not(target_arch = "wasm32")))]
+mod stream;
+#[cfg(all(feature = "live-stream", not(target_arch = "wasm32")))]
+pub use self::stream::{PopulationSnapshot, PopulationStream, start_population_stream};
 
 /// Coordinate of the node.
 #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, Ord, PartialOrd)]
@@ -73,7 +82,7 @@ impl Visitor<'_> for CoordinateVisitor {
 pub type MatrixData = HashMap<Coordinate, Float>;
 
 /// Represents a single experiment observation data.
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub enum ObservationData {
     /// Observation for benchmarking 3D function experiment.
     Function(DataPoint3D),
@@ -85,6 +94,9 @@ pub enum ObservationData {
 lazy_static! {
     /// Keeps track of data used by the solver population.
     static ref EXPERIMENT_DATA: Mutex<ExperimentData> = Mutex::new(ExperimentData::default());
+
+    /// Keeps track of data collected by comparing heuristic configurations.
+    static ref COMPARISON_DATA: Mutex<ComparisonData> = Mutex::new(ComparisonData::default());
 }
 
 #[inline]
@@ -123,6 +135,65 @@ pub fn run_vrp_experiment(format_type: &str, problem: &str, population_type: &st
     solve_vrp(format_type, problem, population_type, selection_size, generations, logger)
 }
 
+/// Runs 3D functions experiment comparing two population types using multiple seeds.
+#[wasm_bindgen]
+#[allow(clippy::too_many_arguments)]
+pub fn run_function_comparison(
+    function_name: &str,
+    population_type_a: &str,
+    population_type_b: &str,
+    x: Float,
+    z: Float,
+    generations: usize,
+    seeds: usize,
+) {
+    set_panic_hook_once();
+    let selection_size = 8;
+    let logger = Arc::new(|message: &str| {
+        web_sys::console::log_1(&message.into());
+    });
+
+    let population_types = [population_type_a, population_type_b];
+    compare_function(function_name, population_types, selection_size, vec![x, z], generations, seeds, logger)
+}
+
+/// Runs VRP experiment comparing two population types using multiple seeds.
+#[wasm_bindgen]
+pub fn run_vrp_comparison(
+    format_type: &str,
+    problem: &str,
+    population_type_a: &str,
+    population_type_b: &str,
+    generations: usize,
+    seeds: usize,
+) {
+    set_panic_hook_once();
+    let problem = problem.to_string();
+    let selection_size = 8;
+    let logger = Arc::new(|message: &str| {
+        web_sys::console::log_1(&message.into());
+    });
+
+    let population_types = [population_type_a, population_type_b];
+    compare_vrp(format_type, problem, population_types, selection_size, generations, seeds, logger)
+}
+
+/// Gets a text summary of the last comparison.
+#[wasm_bindgen]
+pub fn get_comparison_summary() -> String {
+    let data = COMPARISON_DATA.lock().unwrap();
+
+    let names = data.configurations.iter().map(|configuration| configuration.name.as_str()).collect::<Vec<_>>();
+    match data.get_summary() {
+        Some(summary) => format!(
+            "{}: {summary}, significant at 0.05: {}",
+            names.join(" vs "),
+            if summary.is_significant(0.05) { "yes" } else { "no" }
+        ),
+        None => "no comparison data".to_string(),
+    }
+}
+
 /// Loads experiment data from json serialized representation.
 #[wasm_bindgen]
 pub fn load_state(data: &str) -> usize {
@@ -138,7 +209,37 @@ pub fn load_state(data: &str) -> usize {
 /// Clears experiment data.
 #[wasm_bindgen]
 pub fn clear() {
-    EXPERIMENT_DATA.lock().unwrap().clear()
+    EXPERIMENT_DATA.lock().unwrap().clear();
+    *COMPARISON_DATA.lock().unwrap() = ComparisonData::default();
+}
+
+/// Loads population state snapshot received from live stream, returns its generation.
+#[wasm_bindgen]
+pub fn load_snapshot(data: &str) -> usize {
+    set_panic_hook_once();
+
+    #[derive(Deserialize)]
+    struct Snapshot {
+        generation: usize,
+        state: PopulationState,
+    }
+
+    match serde_json::from_str::<Snapshot>(data) {
+        Ok(Snapshot { generation, state }) => {
+            let mut experiment_data = EXPERIMENT_DATA.lock().unwrap();
+            experiment_data.generation = experiment_data.generation.max(generation);
+            experiment_data.population_state.insert(generation, state);
+        }
+        Err(err) => web_sys::console::log_1(&format!("cannot deserialize snapshot: {err}").into()),
+    }
+
+    EXPERIMENT_DATA.lock().unwrap().generation
+}
+
+/// Sets generation interval at which the best known VRP solution is stored for replay, zero disables it.
+#[wasm_bindgen]
+pub fn set_replay_interval(interval: usize) {
+    set_solution_snapshot_interval(interval);
 }
 
 /// Gets current (last) generation.
@@ -155,3 +256,35 @@ pub fn save_state(state_file_path: &str) {
     serde_json::to_writer(BufWriter::new(Box::new(file)), experiment_data.deref())
         .expect("cannot save experiment data");
 }
+
+/// Exports observations of experiment data as csv file.
+pub fn export_observations_csv(csv_file_path: &str) {
+    let file = File::create(csv_file_path).expect("cannot create file");
+    let experiment_data = EXPERIMENT_DATA.lock().unwrap();
+
+    write_observations_csv(experiment_data.deref(), BufWriter::new(file)).expect("cannot export experiment data");
+}
+
+/// Exports observations of experiment data as parquet file.
+#[cfg(feature = "parquet-format")]
+pub fn export_observations_parquet(parquet_file_path: &str) {
+    let file = File::create(parquet_file_path).expect("cannot create file");
+    let experiment_data = EXPERIMENT_DATA.lock().unwrap();
+
+    write_observations_parquet(experiment_data.deref(), BufWriter::new(file)).expect("cannot export experiment data");
+}
+
+/// Gets observations of experiment data in csv format.
+#[wasm_bindgen]
+pub fn get_observations_csv() -> String {
+    let mut buffer = Vec::new();
+    let experiment_data = EXPERIMENT_DATA.lock().unwrap();
+
+    match write_observations_csv(experiment_data.deref(), &mut buffer) {
+        Ok(_) => String::from_utf8(buffer).unwrap_or_default(),
+        Err(err) => {
+            web_sys::console::log_1(&err.to_string().into());
+            String::default()
+        }
+    }
+}
@@ -12,6 +12,16 @@ fn main() {
     let population_type = "rosomaxa";
     let logger = Environment::default().logger;
 
+    #[cfg(feature = "live-stream")]
+    if let Ok(address) = std::env::var("POPULATION_STREAM_ADDRESS") {
+        let address = start_population_stream(address.as_str()).expect("cannot start population stream");
+        (logger)(&format!("population stream is available at ws://{address}"));
+    }
+
+    if let Some(interval) = std::env::var("SOLUTION_SNAPSHOT_INTERVAL").ok().and_then(|value| value.parse().ok()) {
+        set_solution_snapshot_interval(interval);
+    }
+
     let (axes, function_name) = if let Some(vrp_file_path) = vrp_file_path {
         let function_name = "vrp";
         let vrp_type = vrp_type.unwrap_or("tsplib".to_string());
@@ -38,6 +48,14 @@ fn main() {
     let area = BitMapBackend::new("fitness_plot.png", (800, 400)).into_drawing_area();
     draw_fitness_plots(area, function_name).unwrap();
 
+    if function_name == "vrp" {
+        let area = BitMapBackend::new("solution_dynamics_plot.png", (800, 400)).into_drawing_area();
+        draw_solution_dynamics_plots(area).unwrap();
+
+        let area = BitMapBackend::new("routes_plot.png", (800, 800)).into_drawing_area();
+        draw_routes_plots(area, generation).unwrap();
+    }
+
     let area = BitMapBackend::new("search_best_plot.png", (800, 400)).into_drawing_area();
     draw_search_best_statistics_plots(area, generation, "best").unwrap();
 
@@ -50,5 +68,11 @@ fn main() {
     let area = BitMapBackend::new("search_iteration_plot.png", (800, 400)).into_drawing_area();
     draw_search_iteration_plots(area, generation, "best").unwrap();
 
+    let area = BitMapBackend::new("operator_effectiveness_plot.png", (800, 400)).into_drawing_area();
+    draw_operator_effectiveness_plots(area, generation).unwrap();
+
     save_state("heuristic_state.json");
+    export_observations_csv("heuristic_observations.csv");
+    #[cfg(feature = "parquet-format")]
+    export_observations_parquet("heuristic_observations.parquet");
 }
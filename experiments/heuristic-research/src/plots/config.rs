@@ -111,8 +111,42 @@ pub struct SearchDrawConfig {
     pub durations: Vec<(String, usize)>,
 }
 
+/// Specifies drawing configuration for search operator effectiveness.
+#[derive(Default)]
+pub struct OperatorDrawConfig {
+    /// Operator names with amount of added individuals and amount of improvements of best known fitness.
+    pub operators: Vec<(String, usize, usize)>,
+}
+
+/// Specifies drawing configuration for VRP solution routes.
+#[derive(Default)]
+pub struct RoutesDrawConfig {
+    /// Chart caption.
+    pub caption: String,
+    /// Coordinates of all problem locations.
+    pub locations: Vec<(Float, Float)>,
+    /// Coordinates of locations visited by each route.
+    pub routes: Vec<Vec<(Float, Float)>>,
+}
+
 /// A series configuration.
 pub struct Series2D {
     /// A matrix data receiver function.
     pub matrix_fn: Box<dyn Fn() -> MatrixData>,
 }
+
+/// Specifies drawing configuration for comparison of heuristic configurations.
+pub struct ComparisonDrawConfig {
+    /// Configurations to be drawn side-by-side.
+    pub configurations: Vec<ConfigurationDrawConfig>,
+}
+
+/// Specifies drawing configuration for a single heuristic configuration.
+pub struct ConfigurationDrawConfig {
+    /// Chart caption.
+    pub caption: String,
+    /// Target objective values for each generation of each run.
+    pub runs: Vec<Vec<(usize, Float)>>,
+    /// Population state of the first run at selected generation.
+    pub population: PopulationDrawConfig,
+}
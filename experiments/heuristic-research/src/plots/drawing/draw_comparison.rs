@@ -0,0 +1,90 @@
+use super::*;
+use rosomaxa::prelude::Float;
+
+/// Draws fitness progression of heuristic configurations side-by-side with their population state.
+pub(crate) fn draw_on_area<B: DrawingBackend + 'static>(
+    area: &DrawingArea<B, Shift>,
+    config: &ComparisonDrawConfig,
+) -> DrawResult<()> {
+    area.fill(&WHITE)?;
+
+    let columns = config.configurations.len();
+    if columns == 0 {
+        return Ok(());
+    }
+
+    // use the same axes for all configurations to make them visually comparable
+    let points = config.configurations.iter().flat_map(|configuration| configuration.runs.iter().flatten());
+    let (max_generation, min_value, max_value) = points
+        .fold((1. as Float, Float::INFINITY, Float::NEG_INFINITY), |(generation, min, max), &(r#gen, value)| {
+            (generation.max(r#gen as Float), min.min(value), max.max(value))
+        });
+    let (min_value, max_value) = if min_value > max_value { (0., 1.) } else { (min_value, max_value) };
+    let margin = ((max_value - min_value) * 0.05).max(Float::EPSILON);
+
+    let (top, bottom) = area.split_vertically(50.percent_height());
+    let fitness_areas = top.split_evenly((1, columns));
+    let population_areas = bottom.split_evenly((1, columns));
+
+    config.configurations.iter().zip(fitness_areas.iter().zip(population_areas.iter())).try_for_each(
+        |(configuration, (fitness_area, population_area))| {
+            let mut chart = ChartBuilder::on(fitness_area)
+                .caption(configuration.caption.as_str(), ("sans-serif", 14))
+                .margin(5)
+                .x_label_area_size(20)
+                .y_label_area_size(50)
+                .build_cartesian_2d(0.0..max_generation, (min_value - margin)..(max_value + margin))?;
+
+            chart.configure_mesh().draw()?;
+
+            configuration.runs.iter().enumerate().try_for_each(|(idx, run)| {
+                let color = Palette99::pick(idx).mix(0.5);
+                chart.draw_series(LineSeries::new(
+                    run.iter().map(|&(generation, value)| (generation as Float, value)),
+                    &color,
+                ))?;
+
+                DrawResult::Ok(())
+            })?;
+
+            chart.draw_series(LineSeries::new(
+                get_median_series(configuration.runs.as_slice()),
+                BLACK.stroke_width(2),
+            ))?;
+
+            draw_population::draw_on_area(population_area, &configuration.population)
+        },
+    )?;
+
+    area.present()?;
+
+    Ok(())
+}
+
+/// Returns median value of all runs for each generation.
+fn get_median_series(runs: &[Vec<(usize, Float)>]) -> Vec<(Float, Float)> {
+    let generations = runs.iter().flatten().map(|(generation, _)| *generation).max().unwrap_or_default();
+
+    (0..=generations)
+        .filter_map(|generation| {
+            let mut values = runs
+                .iter()
+                .filter_map(|run| run.iter().find(|(r#gen, _)| *r#gen == generation).map(|(_, value)| *value))
+                .collect::<Vec<_>>();
+
+            if values.is_empty() {
+                return None;
+            }
+
+            values.sort_by(|a, b| a.total_cmp(b));
+            let middle = values.len() / 2;
+            let median = if values.len().is_multiple_of(2) {
+                (values[middle - 1] + values[middle]) / 2.
+            } else {
+                values[middle]
+            };
+
+            Some((generation as Float, median))
+        })
+        .collect()
+}
@@ -0,0 +1,49 @@
+use super::*;
+use rosomaxa::prelude::Float;
+
+/// Draws routes of VRP solution on top of problem locations.
+pub(crate) fn draw_on_area<B: DrawingBackend + 'static>(
+    area: &DrawingArea<B, Shift>,
+    config: &RoutesDrawConfig,
+) -> DrawResult<()> {
+    area.fill(&WHITE)?;
+
+    if config.locations.is_empty() {
+        return Ok(());
+    }
+
+    let ((min_x, max_x), (min_y, max_y)) = config.locations.iter().fold(
+        ((Float::INFINITY, Float::NEG_INFINITY), (Float::INFINITY, Float::NEG_INFINITY)),
+        |((min_x, max_x), (min_y, max_y)), &(x, y)| ((min_x.min(x), max_x.max(x)), (min_y.min(y), max_y.max(y))),
+    );
+    let margin_x = ((max_x - min_x) * 0.05).max(Float::EPSILON);
+    let margin_y = ((max_y - min_y) * 0.05).max(Float::EPSILON);
+
+    let mut chart = ChartBuilder::on(area)
+        .caption(config.caption.as_str(), ("sans-serif", 14))
+        .margin(5)
+        .x_label_area_size(20)
+        .y_label_area_size(40)
+        .build_cartesian_2d((min_x - margin_x)..(max_x + margin_x), (min_y - margin_y)..(max_y + margin_y))?;
+
+    chart.configure_mesh().disable_mesh().draw()?;
+
+    chart.draw_series(config.locations.iter().map(|&location| Circle::new(location, 2, BLACK.mix(0.5).filled())))?;
+
+    config.routes.iter().enumerate().try_for_each(|(idx, route)| {
+        let color = Palette99::pick(idx);
+        chart.draw_series(LineSeries::new(route.iter().copied(), color.stroke_width(2)))?;
+        chart.draw_series(route.iter().map(|&location| Circle::new(location, 3, color.filled())))?;
+
+        DrawResult::Ok(())
+    })?;
+
+    // NOTE route start is typically a depot
+    chart.draw_series(
+        config.routes.iter().filter_map(|route| route.first()).map(|&location| TriangleMarker::new(location, 6, BLACK)),
+    )?;
+
+    area.present()?;
+
+    Ok(())
+}
@@ -38,6 +38,55 @@ pub(crate) fn draw_search_overall_statistics<B: DrawingBackend + 'static>(
     draw_search_statistics(area, fitness_config.overall.as_slice())
 }
 
+/// Draws amount of added individuals and best known improvements produced by each search operator.
+pub(crate) fn draw_operator_effectiveness<B: DrawingBackend + 'static>(
+    area: &DrawingArea<B, Shift>,
+    operator_config: &OperatorDrawConfig,
+) -> DrawResult<()> {
+    area.fill(&WHITE)?;
+
+    let mut operators = operator_config.operators.clone();
+    operators.sort_by(|(_, a, _), (_, b, _)| b.cmp(a));
+    operators.truncate(TOP_SIZE);
+
+    if operators.is_empty() {
+        return Ok(());
+    }
+
+    let max_x = operators.iter().map(|(_, total, _)| *total).max().unwrap_or(1).max(1) as Float;
+    let max_y = operators.len() - 1;
+    let font_size = if max_y < TOP_SIZE { 16 } else { 6 };
+
+    let mut chart = ChartBuilder::on(area)
+        .set_label_area_size(LabelAreaPosition::Bottom, 40)
+        .build_cartesian_2d(0.0..max_x, (0..max_y).into_segmented())?;
+
+    chart.configure_mesh().draw()?;
+
+    let get_bar = |y: usize, x: Float, style: ShapeStyle| {
+        let mut bar = Rectangle::new([(0.0, SegmentValue::Exact(y)), (x, SegmentValue::Exact(y + 1))], style);
+        bar.set_margin(2, 2, 0, 0);
+        bar
+    };
+
+    chart.draw_series(
+        (0..).zip(operators.iter()).map(|(y, (_, total, _))| get_bar(y, *total as Float, BLUE_200.filled())),
+    )?;
+    chart.draw_series(
+        (0..).zip(operators.iter()).map(|(y, (_, _, improvements))| get_bar(y, *improvements as Float, RED.filled())),
+    )?;
+
+    chart.draw_series((0..).zip(operators.iter()).map(|(y, (name, total, improvements))| {
+        Text::new(
+            format!("{name}: {improvements}/{total}"),
+            (0.0, SegmentValue::Exact(y + 1)),
+            ("sans-serif", font_size).into_font().color(&BLACK),
+        )
+    }))?;
+
+    Ok(())
+}
+
 fn draw_search_statistics<B: DrawingBackend + 'static>(
     area: &DrawingArea<B, Shift>,
     statistics: &[(String, usize)],
@@ -1,7 +1,9 @@
 use crate::plots::*;
 
+mod draw_comparison;
 mod draw_fitness;
 mod draw_population;
+mod draw_routes;
 mod draw_search;
 mod draw_solution;
 
@@ -64,3 +66,24 @@ pub fn draw_search_overall_statistics<B: DrawingBackend + 'static>(
 ) -> DrawResult<()> {
     draw_search::draw_search_overall_statistics(&area, &heuristic_config)
 }
+
+pub fn draw_operator_effectiveness<B: DrawingBackend + 'static>(
+    area: DrawingArea<B, Shift>,
+    operator_config: OperatorDrawConfig,
+) -> DrawResult<()> {
+    draw_search::draw_operator_effectiveness(&area, &operator_config)
+}
+
+pub fn draw_comparison<B: DrawingBackend + 'static>(
+    area: DrawingArea<B, Shift>,
+    comparison_config: ComparisonDrawConfig,
+) -> DrawResult<()> {
+    draw_comparison::draw_on_area(&area, &comparison_config)
+}
+
+pub fn draw_routes<B: DrawingBackend + 'static>(
+    area: DrawingArea<B, Shift>,
+    routes_config: RoutesDrawConfig,
+) -> DrawResult<()> {
+    draw_routes::draw_on_area(&area, &routes_config)
+}
@@ -32,6 +32,17 @@ impl Chart {
         draw_fitness_plots(get_canvas_drawing_area(canvas), "vrp").map_err(|err| JsValue::from_str(&err.to_string()))
     }
 
+    /// Draws route count and cost dynamics of the best known solution for vrp problem.
+    pub fn solution_dynamics(canvas: HtmlCanvasElement) -> Result<(), JsValue> {
+        draw_solution_dynamics_plots(get_canvas_drawing_area(canvas)).map_err(|err| JsValue::from_str(&err.to_string()))
+    }
+
+    /// Draws routes of the best known VRP solution reconstructed from the latest snapshot at given generation.
+    pub fn routes(canvas: HtmlCanvasElement, generation: usize) -> Result<(), JsValue> {
+        draw_routes_plots(get_canvas_drawing_area(canvas), generation)
+            .map_err(|err| JsValue::from_str(&err.to_string()))
+    }
+
     /// Draws plot for rosenbrock function.
     pub fn rosenbrock(canvas: HtmlCanvasElement, generation: usize, pitch: Float, yaw: Float) -> Result<(), JsValue> {
         let axes = Axes { x: (-2.0..2.0, 0.15), y: (0.0..3610.), z: (-2.0..2.0, 0.15) };
@@ -81,6 +92,18 @@ impl Chart {
         Ok(())
     }
 
+    /// Draws side-by-side comparison of heuristic configurations.
+    pub fn comparison(canvas: HtmlCanvasElement, generation: usize) -> Result<(), JsValue> {
+        draw_comparison_plots(get_canvas_drawing_area(canvas), generation)
+            .map_err(|err| JsValue::from_str(&err.to_string()))
+    }
+
+    /// Draws plot for search operator effectiveness.
+    pub fn operator_effectiveness(canvas: HtmlCanvasElement, generation: usize) -> Result<(), JsValue> {
+        draw_operator_effectiveness_plots(get_canvas_drawing_area(canvas), generation)
+            .map_err(|err| JsValue::from_str(&err.to_string()))
+    }
+
     /// Draws plot for search estimations.
     pub fn search_iteration(canvas: HtmlCanvasElement, generation: usize, kind: &str) -> Result<(), JsValue> {
         draw_search_iteration_plots(get_canvas_drawing_area(canvas), generation, kind)
@@ -115,10 +138,11 @@ pub fn draw_fitness_plots<B: DrawingBackend + 'static>(
     let fitness_size = if fitness.is_empty() { return Ok(()) } else { fitness[0].1.len() };
 
     let (labels, target_idx) = if function_name == "vrp" {
-        let labels = if fitness_size == 2 {
-            vec!["min-unassigned".to_string(), "min-cost".to_string()]
-        } else {
-            vec!["min-unassigned".to_string(), "min-tours".to_string(), "min-cost".to_string()]
+        let labels = match fitness_size {
+            2 => vec!["min-unassigned".to_string(), "min-cost".to_string()],
+            3 => vec!["min-unassigned".to_string(), "min-tours".to_string(), "min-cost".to_string()],
+            // NOTE pragmatic problems can have arbitrary objectives
+            _ => (0..fitness_size).map(|idx| format!("objective-{idx}")).collect(),
         };
 
         (labels, fitness_size - 1)
@@ -129,6 +153,57 @@ pub fn draw_fitness_plots<B: DrawingBackend + 'static>(
     draw_fitness(area, FitnessDrawConfig { labels, fitness, target_idx }).map_err(From::from)
 }
 
+/// Draws route count, unassigned jobs and cost dynamics of the best known VRP solution on given area.
+pub fn draw_solution_dynamics_plots<B: DrawingBackend + 'static>(
+    area: DrawingArea<B, Shift>,
+) -> Result<(), GenericError> {
+    let fitness = EXPERIMENT_DATA
+        .lock()
+        .ok()
+        .map(|data| {
+            data.get_solution_dynamics()
+                .into_iter()
+                .map(|(generation, statistics)| {
+                    (generation, vec![statistics.unassigned as Float, statistics.routes as Float, statistics.cost])
+                })
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    let labels = vec!["unassigned".to_string(), "routes".to_string(), "cost".to_string()];
+
+    draw_fitness(area, FitnessDrawConfig { labels, fitness, target_idx: 2 }).map_err(From::from)
+}
+
+/// Draws routes of the best known VRP solution reconstructed from the latest snapshot taken at given generation
+/// or before it.
+pub fn draw_routes_plots<B: DrawingBackend + 'static>(
+    area: DrawingArea<B, Shift>,
+    generation: usize,
+) -> Result<(), GenericError> {
+    let config = EXPERIMENT_DATA
+        .lock()
+        .ok()
+        .map(|data| {
+            let (caption, routes) = match data.get_solution_snapshot(generation) {
+                Some((snapshot_gen, snapshot)) => (
+                    format!(
+                        "generation {snapshot_gen}: {} routes, {} unassigned",
+                        snapshot.routes.len(),
+                        snapshot.unassigned
+                    ),
+                    data.get_solution_routes(snapshot),
+                ),
+                None => ("no solution snapshot".to_string(), Vec::default()),
+            };
+
+            RoutesDrawConfig { caption, locations: data.locations.clone(), routes }
+        })
+        .unwrap_or_default();
+
+    draw_routes(area, config).map_err(From::from)
+}
+
 pub fn draw_search_iteration_plots<B: DrawingBackend + 'static>(
     area: DrawingArea<B, Shift>,
     generation: usize,
@@ -161,6 +236,75 @@ pub fn draw_search_overall_statistics_plots<B: DrawingBackend + 'static>(
     draw_search_overall_statistics(area, get_search_config(generation, kind)).map_err(From::from)
 }
 
+/// Draws effectiveness of search operators (added individuals and best known improvements) till given generation.
+pub fn draw_operator_effectiveness_plots<B: DrawingBackend + 'static>(
+    area: DrawingArea<B, Shift>,
+    generation: usize,
+) -> Result<(), GenericError> {
+    let operators = EXPERIMENT_DATA
+        .lock()
+        .ok()
+        .map(|data| {
+            data.get_operator_effectiveness(generation)
+                .into_iter()
+                .map(|operator| (operator.name, operator.total, operator.improvements))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    draw_operator_effectiveness(area, OperatorDrawConfig { operators }).map_err(From::from)
+}
+
+/// Draws comparison of heuristic configurations on given area: best fitness progression of each run (with
+/// median in black) and population state of the first run at given generation.
+pub fn draw_comparison_plots<B: DrawingBackend + 'static>(
+    area: DrawingArea<B, Shift>,
+    generation: usize,
+) -> Result<(), GenericError> {
+    let configurations = COMPARISON_DATA
+        .lock()
+        .ok()
+        .map(|data| {
+            data.configurations
+                .iter()
+                .map(|configuration| {
+                    let final_fitness = configuration.final_fitness();
+                    let caption = match final_fitness.as_slice() {
+                        [] => configuration.name.clone(),
+                        values => format!(
+                            "{}: best {:.3}, worst {:.3}",
+                            configuration.name,
+                            values.iter().copied().fold(Float::INFINITY, Float::min),
+                            values.iter().copied().fold(Float::NEG_INFINITY, Float::max)
+                        ),
+                    };
+
+                    ConfigurationDrawConfig {
+                        caption,
+                        runs: configuration
+                            .runs
+                            .iter()
+                            .map(|run| {
+                                run.fitness
+                                    .iter()
+                                    .filter_map(|(generation, fitness)| {
+                                        fitness.last().map(|value| (*generation, *value))
+                                    })
+                                    .collect()
+                            })
+                            .collect(),
+                        population: PopulationDrawConfig {
+                            series: get_experiment_population_series(&configuration.experiment, generation),
+                        },
+                    }
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    draw_comparison(area, ComparisonDrawConfig { configurations }).map_err(From::from)
+}
+
 /// Draws population plots on given area.
 pub fn draw_population_plots<B: DrawingBackend + 'static>(
     area: DrawingArea<B, Shift>,
@@ -200,19 +344,7 @@ fn get_canvas_drawing_area(canvas: HtmlCanvasElement) -> DrawingArea<CanvasBacke
 }
 
 fn get_best_known_fitness() -> Vec<(usize, Vec<Float>)> {
-    EXPERIMENT_DATA
-        .lock()
-        .ok()
-        .map(|data| {
-            (0..=data.generation)
-                .filter_map(|generation| match data.population_state.get(&generation) {
-                    Some(PopulationState::Rosomaxa { fitness_values, .. })
-                    | Some(PopulationState::Unknown { fitness_values }) => Some((generation, fitness_values.clone())),
-                    _ => None,
-                })
-                .collect()
-        })
-        .unwrap_or_default()
+    EXPERIMENT_DATA.lock().ok().map(|data| data.get_best_known_fitness()).unwrap_or_default()
 }
 
 fn get_solution_points(generation: usize) -> Vec<ColoredDataPoint3D> {
@@ -362,38 +494,43 @@ fn get_population_series(generation: usize) -> PopulationSeries {
     EXPERIMENT_DATA
         .lock()
         .ok()
-        .and_then(|data| match data.population_state.get(&generation) {
-            Some(PopulationState::Rosomaxa {
-                rows,
-                cols,
-                mse,
-                fitness_values,
-                fitness_matrices,
-                u_matrix,
-                t_matrix,
-                l_matrix,
-                m_matrix,
-            }) => {
-                let get_series = |matrix: &MatrixData| {
-                    let matrix = matrix.clone();
-                    Series2D { matrix_fn: Box::new(move || matrix.clone()) }
-                };
+        .map(|data| get_experiment_population_series(&data, generation))
+        .unwrap_or(PopulationSeries::Unknown)
+}
 
-                Some(PopulationSeries::Rosomaxa {
-                    rows: rows.clone(),
-                    cols: cols.clone(),
-                    mse: *mse,
-                    fitness_values: fitness_values.clone(),
-                    fitness_matrices: fitness_matrices.iter().map(get_series).collect(),
-                    u_matrix: get_series(u_matrix),
-                    t_matrix: get_series(t_matrix),
-                    l_matrix: get_series(l_matrix),
-                    m_matrix: get_series(m_matrix),
-                })
+fn get_experiment_population_series(data: &ExperimentData, generation: usize) -> PopulationSeries {
+    match data.population_state.get(&generation) {
+        Some(PopulationState::Rosomaxa {
+            rows,
+            cols,
+            mse,
+            fitness_values,
+            fitness_matrices,
+            u_matrix,
+            t_matrix,
+            l_matrix,
+            m_matrix,
+            ..
+        }) => {
+            let get_series = |matrix: &MatrixData| {
+                let matrix = matrix.clone();
+                Series2D { matrix_fn: Box::new(move || matrix.clone()) }
+            };
+
+            PopulationSeries::Rosomaxa {
+                rows: rows.clone(),
+                cols: cols.clone(),
+                mse: *mse,
+                fitness_values: fitness_values.clone(),
+                fitness_matrices: fitness_matrices.iter().map(get_series).collect(),
+                u_matrix: get_series(u_matrix),
+                t_matrix: get_series(t_matrix),
+                l_matrix: get_series(l_matrix),
+                m_matrix: get_series(m_matrix),
             }
-            _ => None,
-        })
-        .unwrap_or(PopulationSeries::Unknown)
+        }
+        _ => PopulationSeries::Unknown,
+    }
 }
 
 fn get_axis_sizes() -> (Float, Float, Float) {
@@ -0,0 +1,264 @@
+#[cfg(test)]
+#[path = "../../tests/unit/solver/comparison_test.rs"]
+mod comparison_test;
+
+use super::*;
+use crate::{COMPARISON_DATA, EXPERIMENT_DATA, ExperimentData};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Keeps results of heuristic configurations run on the same instance with multiple seeds.
+#[derive(Default, Serialize, Deserialize)]
+pub struct ComparisonData {
+    /// Runs of each configuration in order of their addition.
+    pub configurations: Vec<ConfigurationRuns>,
+}
+
+/// Keeps runs of a single heuristic configuration.
+#[derive(Serialize, Deserialize)]
+pub struct ConfigurationRuns {
+    /// A configuration name.
+    pub name: String,
+    /// Runs with different seeds.
+    pub runs: Vec<RunObservation>,
+    /// Experiment data of the first run, used to visualize population dynamics.
+    pub experiment: ExperimentData,
+}
+
+/// Keeps observations of a single run.
+#[derive(Serialize, Deserialize)]
+pub struct RunObservation {
+    /// A seed used by random generator.
+    pub seed: u64,
+    /// Best known fitness for each generation.
+    pub fitness: Vec<(usize, Vec<Float>)>,
+}
+
+/// A statistical significance summary of two samples based on two-sided Mann-Whitney U test
+/// with normal approximation, tie and continuity corrections.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SignificanceSummary {
+    /// Sample sizes.
+    pub sizes: (usize, usize),
+    /// Sample means.
+    pub means: (Float, Float),
+    /// Sample medians.
+    pub medians: (Float, Float),
+    /// U statistic of the first sample.
+    pub u_statistic: Float,
+    /// Standard score of U statistic.
+    pub z_score: Float,
+    /// Two-sided p-value.
+    pub p_value: Float,
+}
+
+impl RunObservation {
+    /// Returns the last objective value of the final best known fitness, e.g. cost for VRP.
+    pub fn final_fitness(&self) -> Option<Float> {
+        self.fitness.last().and_then(|(_, fitness)| fitness.last()).copied()
+    }
+}
+
+impl ConfigurationRuns {
+    /// Returns final fitness values of all runs.
+    pub fn final_fitness(&self) -> Vec<Float> {
+        self.runs.iter().filter_map(|run| run.final_fitness()).collect()
+    }
+}
+
+impl ComparisonData {
+    /// Adds run data of the configuration with given name.
+    pub fn add_run(&mut self, name: &str, seed: u64, experiment: ExperimentData) {
+        let run = RunObservation { seed, fitness: experiment.get_best_known_fitness() };
+
+        match self.configurations.iter_mut().find(|configuration| configuration.name == name) {
+            Some(configuration) => configuration.runs.push(run),
+            None => self.configurations.push(ConfigurationRuns { name: name.to_string(), runs: vec![run], experiment }),
+        }
+    }
+
+    /// Returns significance summary of final fitness for the first two configurations.
+    pub fn get_summary(&self) -> Option<SignificanceSummary> {
+        match self.configurations.as_slice() {
+            [first, second, ..] => SignificanceSummary::new(&first.final_fitness(), &second.final_fitness()),
+            _ => None,
+        }
+    }
+}
+
+impl SignificanceSummary {
+    /// Creates a new instance of `SignificanceSummary` if both samples are not empty.
+    pub fn new(first: &[Float], second: &[Float]) -> Option<Self> {
+        if first.is_empty() || second.is_empty() {
+            return None;
+        }
+
+        let (n1, n2) = (first.len() as Float, second.len() as Float);
+        let total = n1 + n2;
+
+        let mut values = first
+            .iter()
+            .map(|&value| (value, true))
+            .chain(second.iter().map(|&value| (value, false)))
+            .collect::<Vec<_>>();
+        values.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+
+        // assign average ranks to tied values
+        let (mut rank_sum, mut tie_sum, mut start) = (0., 0., 0);
+        while start < values.len() {
+            let end = (start..values.len()).find(|&idx| values[idx].0 != values[start].0).unwrap_or(values.len());
+            let rank = (start + end + 1) as Float / 2.;
+            let ties = (end - start) as Float;
+
+            tie_sum += ties.powi(3) - ties;
+            rank_sum += values[start..end].iter().filter(|(_, is_first)| *is_first).count() as Float * rank;
+            start = end;
+        }
+
+        let u_statistic = rank_sum - n1 * (n1 + 1.) / 2.;
+        let u_mean = n1 * n2 / 2.;
+        let variance = n1 * n2 / 12. * ((total + 1.) - tie_sum / (total * (total - 1.)));
+
+        let (z_score, p_value) = if variance > 0. {
+            let delta = u_statistic - u_mean;
+            let z_score = delta.signum() * ((delta.abs() - 0.5).max(0.) / variance.sqrt());
+            (z_score, (2. * (1. - get_normal_cdf(z_score.abs()))).clamp(0., 1.))
+        } else {
+            (0., 1.)
+        };
+
+        Some(Self {
+            sizes: (first.len(), second.len()),
+            means: (get_mean(first), get_mean(second)),
+            medians: (get_median(first), get_median(second)),
+            u_statistic,
+            z_score,
+            p_value,
+        })
+    }
+
+    /// Checks whether the difference is statistically significant on given significance level.
+    pub fn is_significant(&self, alpha: Float) -> bool {
+        self.p_value < alpha
+    }
+}
+
+impl Display for SignificanceSummary {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "n=({}, {}), mean=({:.3}, {:.3}), median=({:.3}, {:.3}), U={:.1}, z={:.3}, p={:.4}",
+            self.sizes.0,
+            self.sizes.1,
+            self.means.0,
+            self.means.1,
+            self.medians.0,
+            self.medians.1,
+            self.u_statistic,
+            self.z_score,
+            self.p_value
+        )
+    }
+}
+
+/// Runs two population types on the same VRP instance using given amount of seeds.
+pub fn compare_vrp(
+    format_type: &str,
+    problem: String,
+    population_types: [&str; 2],
+    selection_size: usize,
+    generations: usize,
+    seeds: usize,
+    logger: InfoLogger,
+) {
+    run_comparison(population_types, seeds, |population_type, seed| {
+        let problem = problem.clone();
+        solve_vrp_with_seed(
+            format_type,
+            problem,
+            population_type,
+            selection_size,
+            generations,
+            Some(seed),
+            logger.clone(),
+        )
+    })
+}
+
+/// Runs two population types on the same benchmark function using given amount of seeds.
+pub fn compare_function(
+    function_name: &str,
+    population_types: [&str; 2],
+    selection_size: usize,
+    init_solution: Vec<Float>,
+    generations: usize,
+    seeds: usize,
+    logger: InfoLogger,
+) {
+    run_comparison(population_types, seeds, |population_type, seed| {
+        let init_solution = init_solution.clone();
+        let logger = logger.clone();
+        solve_function_with_seed(
+            function_name,
+            population_type,
+            selection_size,
+            init_solution,
+            generations,
+            Some(seed),
+            logger,
+        )
+    })
+}
+
+fn run_comparison<F>(population_types: [&str; 2], seeds: usize, run_fn: F)
+where
+    F: Fn(&str, u64),
+{
+    let is_same = population_types[0] == population_types[1];
+    let names = population_types
+        .iter()
+        .enumerate()
+        .map(|(idx, name)| if is_same { format!("{name} ({})", idx + 1) } else { name.to_string() })
+        .collect::<Vec<_>>();
+
+    let comparison = (0..seeds as u64).fold(ComparisonData::default(), |comparison, seed| {
+        population_types.iter().zip(names.iter()).fold(comparison, |mut comparison, (population_type, name)| {
+            run_fn(population_type, seed);
+
+            let experiment = std::mem::take(&mut *EXPERIMENT_DATA.lock().unwrap());
+            comparison.add_run(name, seed, experiment);
+
+            comparison
+        })
+    });
+
+    // NOTE keep data of the first configuration to be visualized by other plots
+    if let Some(configuration) = comparison.configurations.first() {
+        *EXPERIMENT_DATA.lock().unwrap() = configuration.experiment.clone();
+    }
+
+    *COMPARISON_DATA.lock().unwrap() = comparison;
+}
+
+fn get_mean(values: &[Float]) -> Float {
+    values.iter().sum::<Float>() / values.len() as Float
+}
+
+fn get_median(values: &[Float]) -> Float {
+    let mut values = values.to_vec();
+    values.sort_by(|a, b| a.total_cmp(b));
+
+    let middle = values.len() / 2;
+    if values.len().is_multiple_of(2) { (values[middle - 1] + values[middle]) / 2. } else { values[middle] }
+}
+
+/// Approximates standard normal cumulative distribution function for non-negative values
+/// using Abramowitz and Stegun formula 7.1.26 for error function.
+fn get_normal_cdf(value: Float) -> Float {
+    let x = value / std::f64::consts::SQRT_2 as Float;
+    let t = 1. / (1. + 0.3275911 * x);
+    let poly = t * (0.254829592 + t * (-0.284496736 + t * (1.421413741 + t * (-1.453152027 + t * 1.061405429))));
+    let erf = 1. - poly * (-x * x).exp();
+
+    0.5 * (1. + erf)
+}
@@ -0,0 +1,229 @@
+//! Provides a way to export experiment observations as tabular data (CSV or Parquet), so runs can be
+//! analyzed with dataframe tools without custom parsing of json state.
+//!
+//! Each observation is represented by one row with the following columns:
+//! - `generation`: generation when observation was made
+//! - `event`: one of `add`, `select` or `generation`
+//! - `idx`: observation index within the event of the given generation
+//! - `x`, `fitness`, `z`: solution point for function experiment, empty for VRP
+//! - `dimension`, `edges`: shadow dimension and amount of used edges for VRP experiment, empty for function
+//! - `operator`, `improvement`: search operator which produced added individual and whether it improved
+//!   the best known fitness, empty if unknown
+
+#[cfg(test)]
+#[path = "../../tests/unit/solver/export_test.rs"]
+mod export_test;
+
+use crate::{ExperimentData, ObservationData};
+use rosomaxa::prelude::{Float, GenericResult};
+use std::io::Write;
+
+/// Represents a single observation flattened into a table row.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ObservationRecord {
+    /// Generation when observation was made.
+    pub generation: usize,
+    /// Event name: `add`, `select` or `generation`.
+    pub event: &'static str,
+    /// Observation index within event of the generation.
+    pub idx: usize,
+    /// Solution point x coordinate (function experiment only).
+    pub x: Option<Float>,
+    /// Solution fitness (function experiment only).
+    pub fitness: Option<Float>,
+    /// Solution point z coordinate (function experiment only).
+    pub z: Option<Float>,
+    /// Solution shadow dimension (VRP experiment only).
+    pub dimension: Option<usize>,
+    /// Amount of edges used by solution shadow (VRP experiment only).
+    pub edges: Option<usize>,
+    /// Search operator which produced the individual (add event only).
+    pub operator: Option<String>,
+    /// Whether the individual improved the best known fitness (add event only).
+    pub improvement: Option<bool>,
+}
+
+/// Column names of observation table.
+pub const OBSERVATION_COLUMNS: [&str; 10] =
+    ["generation", "event", "idx", "x", "fitness", "z", "dimension", "edges", "operator", "improvement"];
+
+impl ExperimentData {
+    /// Returns all observations flattened into records ordered by generation and event.
+    pub fn get_observation_records(&self) -> Vec<ObservationRecord> {
+        let events = [("add", &self.on_add), ("select", &self.on_select)];
+
+        let mut generations = self
+            .on_add
+            .keys()
+            .chain(self.on_select.keys())
+            .chain(self.on_generation.keys())
+            .copied()
+            .collect::<Vec<_>>();
+        generations.sort_unstable();
+        generations.dedup();
+
+        generations
+            .into_iter()
+            .flat_map(|generation| {
+                let observations = events
+                    .iter()
+                    .filter_map(move |(event, observations)| {
+                        observations.get(&generation).map(|observations| (*event, observations.as_slice()))
+                    })
+                    .chain(
+                        self.on_generation
+                            .get(&generation)
+                            .map(|(_, observations)| ("generation", observations.as_slice())),
+                    );
+
+                observations.flat_map(move |(event, observations)| {
+                    observations.iter().enumerate().map(move |(idx, observation)| {
+                        let attribution = match event {
+                            "add" => self
+                                .on_add_operator
+                                .get(&generation)
+                                .and_then(|attributions| attributions.get(idx))
+                                .and_then(|attribution| attribution.as_ref()),
+                            _ => None,
+                        };
+
+                        ObservationRecord {
+                            operator: attribution.map(|attribution| attribution.name.clone()),
+                            improvement: attribution.map(|attribution| attribution.is_improvement),
+                            ..create_record(generation, event, idx, observation)
+                        }
+                    })
+                })
+            })
+            .collect()
+    }
+}
+
+/// Writes experiment observations in CSV format.
+pub fn write_observations_csv<W: Write>(data: &ExperimentData, mut writer: W) -> GenericResult<()> {
+    let write_err = |err: std::io::Error| format!("cannot write csv: '{err}'");
+    let format_opt = |value: Option<String>| value.unwrap_or_default();
+
+    writeln!(writer, "{}", OBSERVATION_COLUMNS.join(",")).map_err(write_err)?;
+
+    data.get_observation_records().into_iter().try_for_each(|record| {
+        writeln!(
+            writer,
+            "{},{},{},{},{},{},{},{},{},{}",
+            record.generation,
+            record.event,
+            record.idx,
+            format_opt(record.x.map(|value| value.to_string())),
+            format_opt(record.fitness.map(|value| value.to_string())),
+            format_opt(record.z.map(|value| value.to_string())),
+            format_opt(record.dimension.map(|value| value.to_string())),
+            format_opt(record.edges.map(|value| value.to_string())),
+            format_opt(record.operator),
+            format_opt(record.improvement.map(|value| value.to_string())),
+        )
+        .map_err(write_err)
+    })?;
+
+    writer.flush().map_err(write_err)?;
+
+    Ok(())
+}
+
+/// Writes experiment observations in Parquet format.
+#[cfg(feature = "parquet-format")]
+pub fn write_observations_parquet<W: Write + Send>(data: &ExperimentData, writer: W) -> GenericResult<()> {
+    use arrow::array::{ArrayRef, BooleanBuilder, Float64Builder, StringBuilder, UInt64Builder};
+    use arrow::datatypes::{DataType, Field, Schema};
+    use arrow::record_batch::RecordBatch;
+    use parquet::arrow::ArrowWriter;
+    use std::sync::Arc;
+
+    let write_err = |err: &dyn std::fmt::Display| format!("cannot write parquet: '{err}'");
+
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("generation", DataType::UInt64, false),
+        Field::new("event", DataType::Utf8, false),
+        Field::new("idx", DataType::UInt64, false),
+        Field::new("x", DataType::Float64, true),
+        Field::new("fitness", DataType::Float64, true),
+        Field::new("z", DataType::Float64, true),
+        Field::new("dimension", DataType::UInt64, true),
+        Field::new("edges", DataType::UInt64, true),
+        Field::new("operator", DataType::Utf8, true),
+        Field::new("improvement", DataType::Boolean, true),
+    ]));
+
+    let mut generations = UInt64Builder::new();
+    let mut events = StringBuilder::new();
+    let mut indices = UInt64Builder::new();
+    let mut xs = Float64Builder::new();
+    let mut fitnesses = Float64Builder::new();
+    let mut zs = Float64Builder::new();
+    let mut dimensions = UInt64Builder::new();
+    let mut edges = UInt64Builder::new();
+    let mut operators = StringBuilder::new();
+    let mut improvements = BooleanBuilder::new();
+
+    data.get_observation_records().into_iter().for_each(|record| {
+        generations.append_value(record.generation as u64);
+        events.append_value(record.event);
+        indices.append_value(record.idx as u64);
+        xs.append_option(record.x);
+        fitnesses.append_option(record.fitness);
+        zs.append_option(record.z);
+        dimensions.append_option(record.dimension.map(|value| value as u64));
+        edges.append_option(record.edges.map(|value| value as u64));
+        operators.append_option(record.operator);
+        improvements.append_option(record.improvement);
+    });
+
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(generations.finish()),
+        Arc::new(events.finish()),
+        Arc::new(indices.finish()),
+        Arc::new(xs.finish()),
+        Arc::new(fitnesses.finish()),
+        Arc::new(zs.finish()),
+        Arc::new(dimensions.finish()),
+        Arc::new(edges.finish()),
+        Arc::new(operators.finish()),
+        Arc::new(improvements.finish()),
+    ];
+
+    let batch = RecordBatch::try_new(schema.clone(), columns).map_err(|err| write_err(&err))?;
+
+    let mut writer = ArrowWriter::try_new(writer, schema, None).map_err(|err| write_err(&err))?;
+    writer.write(&batch).map_err(|err| write_err(&err))?;
+    writer.close().map_err(|err| write_err(&err))?;
+
+    Ok(())
+}
+
+fn create_record(
+    generation: usize,
+    event: &'static str,
+    idx: usize,
+    observation: &ObservationData,
+) -> ObservationRecord {
+    let record = ObservationRecord {
+        generation,
+        event,
+        idx,
+        x: None,
+        fitness: None,
+        z: None,
+        dimension: None,
+        edges: None,
+        operator: None,
+        improvement: None,
+    };
+
+    match observation {
+        ObservationData::Function(point) => {
+            ObservationRecord { x: Some(point.0), fitness: Some(point.1), z: Some(point.2), ..record }
+        }
+        ObservationData::Vrp(shadow) => {
+            ObservationRecord { dimension: Some(shadow.dimension()), edges: Some(shadow.edges()), ..record }
+        }
+    }
+}
@@ -4,6 +4,12 @@ pub use self::proxies::*;
 mod state;
 pub use self::state::*;
 
+mod comparison;
+pub use self::comparison::*;
+
+mod export;
+pub use self::export::*;
+
 mod vector;
 pub use self::vector::*;
 
@@ -39,3 +45,8 @@ where
         _ => unreachable!(),
     }
 }
+
+/// Creates a random generator which uses given seed if it is specified.
+fn get_random(seed: Option<u64>) -> Arc<dyn Random> {
+    Arc::new(seed.map_or_else(DefaultRandom::default, DefaultRandom::new_with_seed))
+}
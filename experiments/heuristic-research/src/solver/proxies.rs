@@ -1,3 +1,7 @@
+#[cfg(test)]
+#[path = "../../tests/unit/solver/proxies_test.rs"]
+mod proxies_test;
+
 use crate::*;
 use rosomaxa::example::VectorSolution;
 use rosomaxa::population::{Alternative, RosomaxaContext, RosomaxaSolution};
@@ -7,11 +11,12 @@ use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::marker::PhantomData;
 use std::sync::MutexGuard;
+use std::sync::atomic::{self, AtomicUsize};
 use vrp_scientific::core::models::common::Shadow;
 use vrp_scientific::core::prelude::*;
 
 /// Keeps track of all experiment data for visualization purposes.
-#[derive(Default, Serialize, Deserialize)]
+#[derive(Clone, Default, Serialize, Deserialize)]
 pub struct ExperimentData {
     /// Current generation.
     pub generation: usize,
@@ -25,6 +30,41 @@ pub struct ExperimentData {
     pub population_state: HashMap<usize, PopulationState>,
     /// Keeps track of heuristic state at specific generation.
     pub heuristic_state: HyperHeuristicState,
+    /// Keeps track of the best known VRP solution statistics at specific generation.
+    #[serde(default)]
+    pub solution_statistics: HashMap<usize, SolutionStatistics>,
+    /// Keeps snapshots of the best known VRP solution taken at configurable generation interval.
+    #[serde(default)]
+    pub solution_snapshots: HashMap<usize, SolutionSnapshot>,
+    /// Coordinates of VRP problem locations ordered by location index, empty if they are unknown.
+    #[serde(default)]
+    pub locations: Vec<(Float, Float)>,
+    /// Search operator attribution of individuals added at specific generation, ordered as in `on_add`.
+    #[serde(default)]
+    pub on_add_operator: HashMap<usize, Vec<Option<OperatorAttribution>>>,
+    /// Attributions reported by search operators which are not yet matched with added individuals.
+    #[serde(skip)]
+    pub(crate) pending_operator: HashMap<Vec<u64>, Vec<OperatorAttribution>>,
+}
+
+/// Specifies which search operator has produced an individual.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OperatorAttribution {
+    /// A search operator name.
+    pub name: String,
+    /// True if the individual has improved the best known fitness.
+    pub is_improvement: bool,
+}
+
+/// Keeps aggregated effectiveness of a search operator.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct OperatorEffectiveness {
+    /// A search operator name.
+    pub name: String,
+    /// Amount of individuals produced by the operator and added to the population.
+    pub total: usize,
+    /// Amount of individuals which improved the best known fitness.
+    pub improvements: usize,
 }
 
 impl ExperimentData {
@@ -34,6 +74,92 @@ impl ExperimentData {
         self.on_add.clear();
         self.on_select.clear();
         self.on_generation.clear();
+        self.on_add_operator.clear();
+        self.pending_operator.clear();
+        self.solution_statistics.clear();
+        self.solution_snapshots.clear();
+        self.locations.clear();
+    }
+
+    /// Registers an individual produced by the search operator, so it can be matched on addition.
+    pub fn add_pending_operator(&mut self, fitness: &[Float], attribution: OperatorAttribution) {
+        self.pending_operator.entry(get_fitness_key(fitness)).or_default().push(attribution);
+    }
+
+    /// Returns attribution of an individual with given fitness, if it was reported by the search operator.
+    pub fn take_pending_operator(&mut self, fitness: &[Float]) -> Option<OperatorAttribution> {
+        let key = get_fitness_key(fitness);
+        let attributions = self.pending_operator.get_mut(&key)?;
+        let attribution = attributions.pop();
+
+        if attributions.is_empty() {
+            self.pending_operator.remove(&key);
+        }
+
+        attribution
+    }
+
+    /// Returns statistics of the best known VRP solution for each generation with known statistics.
+    pub fn get_solution_dynamics(&self) -> Vec<(usize, SolutionStatistics)> {
+        (0..=self.generation)
+            .filter_map(|generation| {
+                self.solution_statistics.get(&generation).map(|statistics| (generation, statistics.clone()))
+            })
+            .collect()
+    }
+
+    /// Returns the latest solution snapshot taken at given generation or before it with its generation.
+    pub fn get_solution_snapshot(&self, generation: usize) -> Option<(usize, &SolutionSnapshot)> {
+        self.solution_snapshots
+            .iter()
+            .filter(|(r#gen, _)| **r#gen <= generation)
+            .max_by_key(|(r#gen, _)| **r#gen)
+            .map(|(r#gen, snapshot)| (*r#gen, snapshot))
+    }
+
+    /// Returns coordinates of locations visited by each route of the solution snapshot.
+    pub fn get_solution_routes(&self, snapshot: &SolutionSnapshot) -> Vec<Vec<(Float, Float)>> {
+        snapshot
+            .routes
+            .iter()
+            .map(|route| route.iter().filter_map(|&location| self.locations.get(location).copied()).collect())
+            .collect()
+    }
+
+    /// Returns effectiveness of search operators up to given generation (inclusive) sorted by name.
+    pub fn get_operator_effectiveness(&self, generation: usize) -> Vec<OperatorEffectiveness> {
+        let mut effectiveness = self
+            .on_add_operator
+            .iter()
+            .filter(|(r#gen, _)| **r#gen <= generation)
+            .flat_map(|(_, attributions)| attributions.iter().flatten())
+            .fold(HashMap::<&str, OperatorEffectiveness>::new(), |mut acc, attribution| {
+                let entry = acc.entry(attribution.name.as_str()).or_insert_with(|| OperatorEffectiveness {
+                    name: attribution.name.clone(),
+                    ..OperatorEffectiveness::default()
+                });
+                entry.total += 1;
+                entry.improvements += attribution.is_improvement as usize;
+
+                acc
+            })
+            .into_values()
+            .collect::<Vec<_>>();
+
+        effectiveness.sort_by(|a, b| a.name.cmp(&b.name));
+
+        effectiveness
+    }
+
+    /// Returns best known fitness values for each generation with known population state.
+    pub fn get_best_known_fitness(&self) -> Vec<(usize, Vec<Float>)> {
+        (0..=self.generation)
+            .filter_map(|generation| match self.population_state.get(&generation) {
+                Some(PopulationState::Rosomaxa { fitness_values, .. })
+                | Some(PopulationState::Unknown { fitness_values }) => Some((generation, fitness_values.clone())),
+                _ => None,
+            })
+            .collect()
     }
 }
 
@@ -95,6 +221,32 @@ where
     }
 }
 
+/// A function which is called with population state when generation is completed.
+pub type PopulationListener = Arc<dyn Fn(usize, &PopulationState) + Send + Sync>;
+
+lazy_static! {
+    /// Keeps listener of population state updates.
+    static ref POPULATION_LISTENER: Mutex<Option<PopulationListener>> = Mutex::new(None);
+}
+
+/// Keeps generation interval of solution snapshots, zero disables them.
+static SOLUTION_SNAPSHOT_INTERVAL: AtomicUsize = AtomicUsize::new(10);
+
+/// Sets generation interval at which the best known VRP solution is stored for replay, zero disables it.
+pub fn set_solution_snapshot_interval(interval: usize) {
+    SOLUTION_SNAPSHOT_INTERVAL.store(interval, atomic::Ordering::Relaxed);
+}
+
+/// Sets listener which receives population state as soon as generation is completed.
+pub fn set_population_listener(listener: Option<PopulationListener>) {
+    *POPULATION_LISTENER.lock().unwrap() = listener;
+}
+
+/// Creates a key from fitness values which is used to match individuals produced by search operators.
+fn get_fitness_key(fitness: &[Float]) -> Vec<u64> {
+    fitness.iter().map(|value| value.to_bits()).collect()
+}
+
 /// A population type which provides a way to intercept some of the population data.
 pub struct ProxyPopulation<P, C, O, S>
 where
@@ -124,6 +276,15 @@ where
     fn acquire(&self) -> MutexGuard<'_, ExperimentData> {
         EXPERIMENT_DATA.lock().unwrap()
     }
+
+    fn observe_add(&self, individual: &S) {
+        let fitness = individual.fitness().collect::<Vec<_>>();
+        let mut data = self.acquire();
+
+        let attribution = data.take_pending_operator(fitness.as_slice());
+        data.on_add.entry(self.generation).or_default().push(individual.into());
+        data.on_add_operator.entry(self.generation).or_default().push(attribution);
+    }
 }
 
 impl<P, C, O, S> HeuristicPopulation for ProxyPopulation<P, C, O, S>
@@ -137,13 +298,13 @@ where
     type Individual = S;
 
     fn add_all(&mut self, individuals: Vec<Self::Individual>) -> bool {
-        self.acquire().on_add.entry(self.generation).or_default().extend(individuals.iter().map(|i| i.into()));
+        individuals.iter().for_each(|individual| self.observe_add(individual));
 
         self.inner.add_all(individuals)
     }
 
     fn add(&mut self, individual: Self::Individual) -> bool {
-        self.acquire().on_add.entry(self.generation).or_default().push((&individual).into());
+        self.observe_add(&individual);
 
         self.inner.add(individual)
     }
@@ -168,7 +329,23 @@ where
             });
         self.acquire().on_generation.insert(self.generation, (individuals_footprint, individuals_data));
 
-        self.acquire().population_state.insert(self.generation, get_population_state(&self.inner));
+        if let Some(statistics) = self.inner.ranked().next().and_then(get_solution_statistics) {
+            self.acquire().solution_statistics.insert(self.generation, statistics);
+        }
+
+        let interval = SOLUTION_SNAPSHOT_INTERVAL.load(atomic::Ordering::Relaxed);
+        if interval > 0
+            && self.generation.is_multiple_of(interval)
+            && let Some(snapshot) = self.inner.ranked().next().and_then(get_solution_snapshot)
+        {
+            self.acquire().solution_snapshots.insert(self.generation, snapshot);
+        }
+
+        let population_state = get_population_state(&self.inner);
+        if let Some(listener) = POPULATION_LISTENER.lock().unwrap().as_ref() {
+            (listener)(self.generation, &population_state);
+        }
+        self.acquire().population_state.insert(self.generation, population_state);
 
         self.inner.on_generation(statistics)
     }
@@ -202,6 +379,14 @@ where
     fn selection_phase(&self) -> SelectionPhase {
         self.inner.selection_phase()
     }
+
+    fn on_search(&self, operator_name: &str, individual: &Self::Individual, is_improvement: bool) {
+        let fitness = individual.fitness().collect::<Vec<_>>();
+        let attribution = OperatorAttribution { name: operator_name.to_string(), is_improvement };
+
+        self.acquire().add_pending_operator(fitness.as_slice(), attribution);
+        self.inner.on_search(operator_name, individual, is_improvement)
+    }
 }
 
 /// Creates info logger proxy to catch dynamic heuristic state.
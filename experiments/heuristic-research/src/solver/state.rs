@@ -7,11 +7,12 @@ use std::any::TypeId;
 use std::collections::HashMap;
 use std::fmt;
 use std::ops::Range;
+use vrp_scientific::core::construction::heuristics::InsertionContext;
 use vrp_scientific::core::models::common::{Footprint, Shadow};
 
 /// Represents population state specific for supported types.
 #[allow(clippy::large_enum_variant)]
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub enum PopulationState {
     /// Unknown (or unimplemented) population type.
     Unknown {
@@ -38,6 +39,9 @@ pub enum PopulationState {
         l_matrix: MatrixData,
         /// MSE node values data.
         m_matrix: MatrixData,
+        /// Node weights data.
+        #[serde(default)]
+        w_matrix: HashMap<Coordinate, Vec<Float>>,
     },
 }
 
@@ -75,12 +79,22 @@ fn create_rosomaxa_state(network_state: NetworkState, fitness_values: Vec<Float>
         t_matrix: Default::default(),
         l_matrix: Default::default(),
         m_matrix: Default::default(),
+        w_matrix: Default::default(),
     };
 
     network_state.nodes.iter().fold(rosomaxa, |mut rosomaxa, node| {
         let coordinate = Coordinate(node.coordinate.0, node.coordinate.1);
         match &mut rosomaxa {
-            PopulationState::Rosomaxa { fitness_matrices, mse, u_matrix, t_matrix, l_matrix, m_matrix, .. } => {
+            PopulationState::Rosomaxa {
+                fitness_matrices,
+                mse,
+                u_matrix,
+                t_matrix,
+                l_matrix,
+                m_matrix,
+                w_matrix,
+                ..
+            } => {
                 // NOTE get first fitness in assumption of sorted order
                 let fitness = match (node.dump.starts_with("[["), node.dump.find(']')) {
                     (true, Some(value)) => node.dump[2..value]
@@ -102,6 +116,7 @@ fn create_rosomaxa_state(network_state: NetworkState, fitness_values: Vec<Float>
                 u_matrix.insert(coordinate, node.unified_distance);
                 t_matrix.insert(coordinate, node.total_hits as Float);
                 l_matrix.insert(coordinate, node.last_hits as Float);
+                w_matrix.insert(coordinate, node.weights.clone());
                 *mse = network_state.mse;
             }
             _ => unreachable!(),
@@ -111,16 +126,89 @@ fn create_rosomaxa_state(network_state: NetworkState, fitness_values: Vec<Float>
     })
 }
 
+/// Keeps statistics of the best known VRP solution.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SolutionStatistics {
+    /// Amount of routes.
+    pub routes: usize,
+    /// Amount of unassigned jobs.
+    pub unassigned: usize,
+    /// Total cost.
+    pub cost: Float,
+}
+
+impl From<&InsertionContext> for SolutionStatistics {
+    fn from(insertion_ctx: &InsertionContext) -> Self {
+        Self {
+            routes: insertion_ctx.solution.routes.len(),
+            unassigned: insertion_ctx.solution.unassigned.len(),
+            cost: insertion_ctx.get_total_cost().unwrap_or_default(),
+        }
+    }
+}
+
+/// Returns statistics of the solution if it is a VRP solution.
+pub fn get_solution_statistics<S: 'static>(solution: &S) -> Option<SolutionStatistics> {
+    if TypeId::of::<S>() == TypeId::of::<InsertionContext>() {
+        // SAFETY: type id check above ensures that S-type is the right one
+        let insertion_ctx = unsafe { std::mem::transmute::<&S, &InsertionContext>(solution) };
+
+        Some(insertion_ctx.into())
+    } else {
+        None
+    }
+}
+
+/// Keeps a compact representation of the full VRP solution which is enough to reconstruct its routes.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SolutionSnapshot {
+    /// Location indices visited by each route, consecutive duplicates are collapsed.
+    pub routes: Vec<Vec<usize>>,
+    /// Amount of unassigned jobs.
+    pub unassigned: usize,
+}
+
+impl From<&InsertionContext> for SolutionSnapshot {
+    fn from(insertion_ctx: &InsertionContext) -> Self {
+        let routes = insertion_ctx
+            .solution
+            .routes
+            .iter()
+            .map(|route_ctx| {
+                let mut locations =
+                    route_ctx.route().tour.all_activities().map(|activity| activity.place.location).collect::<Vec<_>>();
+                locations.dedup();
+
+                locations
+            })
+            .collect();
+
+        Self { routes, unassigned: insertion_ctx.solution.unassigned.len() }
+    }
+}
+
+/// Returns snapshot of the solution if it is a VRP solution.
+pub fn get_solution_snapshot<S: 'static>(solution: &S) -> Option<SolutionSnapshot> {
+    if TypeId::of::<S>() == TypeId::of::<InsertionContext>() {
+        // SAFETY: type id check above ensures that S-type is the right one
+        let insertion_ctx = unsafe { std::mem::transmute::<&S, &InsertionContext>(solution) };
+
+        Some(insertion_ctx.into())
+    } else {
+        None
+    }
+}
+
 /// Search state result represented as (name idx, reward, (from state idx, to state idx), duration).
-#[derive(Default, Serialize, Deserialize)]
+#[derive(Clone, Default, Serialize, Deserialize)]
 pub struct SearchResult(pub usize, pub Float, pub (usize, usize), pub usize);
 
 /// Heuristic state result represented as (state idx, name idx, alpha, beta, mu, v, n).
-#[derive(Default, Serialize, Deserialize)]
+#[derive(Clone, Default, Serialize, Deserialize)]
 pub struct HeuristicResult(pub usize, pub usize, pub Float, pub Float, pub Float, pub Float, pub usize);
 
 /// Keeps track of dynamic selective hyper heuristic state.
-#[derive(Default, Serialize, Deserialize)]
+#[derive(Clone, Default, Serialize, Deserialize)]
 pub struct HyperHeuristicState {
     /// Unique heuristic names.
     pub names: HashMap<String, usize>,
@@ -168,9 +256,7 @@ impl HyperHeuristicState {
                     data
                 },
             );
-            search_states
-                .values_mut()
-                .for_each(|states| states.sort_by(|SearchResult(a, ..), SearchResult(b, ..)| a.cmp(b)));
+            search_states.values_mut().for_each(|states| states.sort_by_key(|SearchResult(a, ..)| *a));
 
             let mut heuristic_states =
                 data.lines().skip_while(|line| *line != "heuristic:").skip(2).take_while(|line| !line.is_empty()).fold(
@@ -198,9 +284,7 @@ impl HyperHeuristicState {
                         data
                     },
                 );
-            heuristic_states
-                .values_mut()
-                .for_each(|states| states.sort_by(|HeuristicResult(_, a, ..), HeuristicResult(_, b, ..)| a.cmp(b)));
+            heuristic_states.values_mut().for_each(|states| states.sort_by_key(|HeuristicResult(_, a, ..)| *a));
 
             Some(Self { names, states, search_states, heuristic_states })
         } else {
@@ -239,7 +323,7 @@ impl From<&Footprint> for FootprintState {
     }
 }
 
-#[derive(Default, Serialize, Deserialize)]
+#[derive(Clone, Default, Serialize, Deserialize)]
 pub struct ShadowState {
     // NOTE use original shadow as more space efficient representation.
     #[serde(skip)]
@@ -256,6 +340,10 @@ impl ShadowState {
     pub fn dimension(&self) -> usize {
         self.shadow.as_ref().map(|shadow| shadow.dimension()).unwrap_or_default()
     }
+
+    pub fn edges(&self) -> usize {
+        self.shadow.as_ref().map(|shadow| shadow.iter().filter(|(_, bit)| *bit).count()).unwrap_or_default()
+    }
 }
 
 // NOTE non-string keys requires some special handling
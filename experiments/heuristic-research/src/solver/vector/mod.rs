@@ -18,10 +18,23 @@ pub fn solve_function(
     init_solution: Vec<Float>,
     generations: usize,
     logger: InfoLogger,
+) {
+    solve_function_with_seed(function_name, population_type, selection_size, init_solution, generations, None, logger)
+}
+
+/// Runs the solver to minimize objective function with given name using an optional seed for random generator.
+pub(crate) fn solve_function_with_seed(
+    function_name: &str,
+    population_type: &str,
+    selection_size: usize,
+    init_solution: Vec<Float>,
+    generations: usize,
+    seed: Option<u64>,
+    logger: InfoLogger,
 ) {
     let logger = create_info_logger_proxy(logger);
     let fitness_fn = get_fitness_fn_by_name(function_name);
-    let random = Arc::new(DefaultRandom::default());
+    let random = get_random(seed);
 
     let noise_op = VectorHeuristicOperatorMode::JustNoise(Noise::new_with_ratio(1., (-0.1, 0.1), random));
     let delta_op = VectorHeuristicOperatorMode::JustDelta(-0.1..0.1);
@@ -40,6 +53,7 @@ pub fn solve_function(
             let logger = logger.clone();
             let population_type = population_type.to_string();
             move |objective, environment| {
+                let environment = Arc::new(Environment { random: get_random(seed), ..environment.as_ref().clone() });
                 let context = VectorRosomaxaContext;
                 let population =
                     get_population(context, &population_type, objective.clone(), environment.clone(), selection_size);
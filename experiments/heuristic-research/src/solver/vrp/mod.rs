@@ -6,7 +6,12 @@ mod population;
 pub use self::population::{get_population_desc, get_population_fitness_fn};
 
 use super::*;
+use crate::EXPERIMENT_DATA;
 use std::io::BufWriter;
+use vrp_pragmatic::format::problem::PragmaticProblem;
+use vrp_pragmatic::format::solution::{PragmaticOutputType, write_pragmatic};
+use vrp_pragmatic::format::{CoordIndexExtraProperty as PragmaticCoordIndex, Location as PragmaticLocation};
+use vrp_scientific::common::CoordIndexExtraProperty as ScientificCoordIndex;
 use vrp_scientific::core::models::common::Footprint;
 use vrp_scientific::core::prelude::*;
 use vrp_scientific::core::solver::RefinementContext;
@@ -22,6 +27,19 @@ pub fn solve_vrp(
     selection_size: usize,
     generations: usize,
     logger: InfoLogger,
+) {
+    solve_vrp_with_seed(format_type, problem, population_type, selection_size, generations, None, logger)
+}
+
+/// Solves VRP of the given format type using an optional seed for random generator.
+pub(crate) fn solve_vrp_with_seed(
+    format_type: &str,
+    problem: String,
+    population_type: &str,
+    selection_size: usize,
+    generations: usize,
+    seed: Option<u64>,
+    logger: InfoLogger,
 ) {
     let is_rounded = true;
     let is_experimental = true;
@@ -31,6 +49,7 @@ pub fn solve_vrp(
         "tsplib" => problem.read_tsplib(is_rounded),
         "solomon" => problem.read_solomon(is_rounded),
         "lilim" => problem.read_lilim(is_rounded),
+        "pragmatic" => problem.read_pragmatic().map_err(|errs| errs.to_string().into()),
         _ => panic!("unknown format: {format_type}"),
     }
     .unwrap();
@@ -39,12 +58,14 @@ pub fn solve_vrp(
 
     let environment = Arc::new(Environment {
         logger: logger.clone(),
+        random: get_random(seed),
         is_experimental,
         ..Environment::new_with_time_quota(Some(300))
     });
     let footprint = Footprint::new(problem.as_ref());
     let population =
         get_population(footprint, population_type, problem.goal.clone(), environment.clone(), selection_size);
+    EXPERIMENT_DATA.lock().unwrap().locations = get_problem_locations(problem.as_ref());
     let telemetry_mode = TelemetryMode::OnlyLogging { logger: logger.clone(), log_best: 100, log_population: 1000 };
 
     let config = VrpConfigBuilder::new(problem.clone())
@@ -57,13 +78,14 @@ pub fn solve_vrp(
         .build()
         .expect("cannot build config");
 
-    let solution = Solver::new(problem, config).solve().expect("cannot solve problem");
+    let solution = Solver::new(problem.clone(), config).solve().expect("cannot solve problem");
 
     let mut writer = BufWriter::new(Vec::new());
     match format_type {
         "tsplib" => solution.write_tsplib(&mut writer),
         "solomon" => solution.write_solomon(&mut writer),
         "lilim" => solution.write_lilim(&mut writer),
+        "pragmatic" => write_pragmatic(problem.as_ref(), &solution, PragmaticOutputType::default(), &mut writer),
         _ => unreachable!("unknown format: {}", format_type),
     }
     .expect("cannot write solution");
@@ -72,3 +94,23 @@ pub fn solve_vrp(
 
     (logger)(&result);
 }
+
+/// Returns coordinates of problem locations ordered by location index, empty if locations have no coordinates.
+pub(crate) fn get_problem_locations(problem: &Problem) -> Vec<(Float, Float)> {
+    if let Some(coord_index) = ScientificCoordIndex::get_coord_index(problem.extras.as_ref()) {
+        return coord_index.locations.clone();
+    }
+
+    PragmaticCoordIndex::get_coord_index(problem.extras.as_ref())
+        .and_then(|coord_index| {
+            coord_index
+                .unique()
+                .into_iter()
+                .map(|location| match location {
+                    PragmaticLocation::Coordinate { lat, lng } => Some((lng, lat)),
+                    _ => None,
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
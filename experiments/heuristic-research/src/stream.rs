@@ -0,0 +1,99 @@
+//! Provides a WebSocket endpoint which streams population state snapshots as generations complete, so
+//! visualization can animate population (e.g. GSOM lattice growth) without reloading full experiment dumps.
+//!
+//! Each message is a json object with `generation` and `state` (serialized `PopulationState`) properties.
+
+#[cfg(test)]
+#[path = "../tests/unit/stream_test.rs"]
+mod stream_test;
+
+use crate::solver::{PopulationListener, PopulationState, set_population_listener};
+use rosomaxa::prelude::GenericResult;
+use serde::Serialize;
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use tungstenite::{Message, WebSocket};
+
+/// A population state snapshot sent to stream clients.
+#[derive(Serialize)]
+pub struct PopulationSnapshot<'a> {
+    /// Generation when snapshot was taken.
+    pub generation: usize,
+    /// Population state.
+    pub state: &'a PopulationState,
+}
+
+/// A WebSocket server which broadcasts messages to all connected clients.
+pub struct PopulationStream {
+    address: SocketAddr,
+    clients: Arc<Mutex<Vec<WebSocket<TcpStream>>>>,
+}
+
+impl PopulationStream {
+    /// Binds server to the given address and starts accepting clients in background thread.
+    pub fn bind(address: &str) -> GenericResult<Self> {
+        let listener = TcpListener::bind(address).map_err(|err| format!("cannot bind to '{address}': '{err}'"))?;
+        let address = listener.local_addr().map_err(|err| format!("cannot get local address: '{err}'"))?;
+        let clients = Arc::new(Mutex::new(Vec::new()));
+
+        std::thread::spawn({
+            let clients = clients.clone();
+            move || {
+                listener.incoming().filter_map(Result::ok).for_each(|stream| {
+                    // NOTE ignore clients which failed to complete handshake
+                    if let Ok(socket) = tungstenite::accept(stream) {
+                        clients.lock().unwrap().push(socket);
+                    }
+                })
+            }
+        });
+
+        Ok(Self { address, clients })
+    }
+
+    /// Returns local address the server is bound to.
+    pub fn local_addr(&self) -> SocketAddr {
+        self.address
+    }
+
+    /// Returns amount of connected clients.
+    pub fn clients(&self) -> usize {
+        self.clients.lock().unwrap().len()
+    }
+
+    /// Sends text message to all connected clients, disconnected ones are dropped.
+    /// Returns amount of clients which received the message.
+    pub fn broadcast(&self, message: &str) -> usize {
+        let mut clients = self.clients.lock().unwrap();
+
+        clients.retain_mut(|socket| socket.send(Message::text(message)).is_ok());
+
+        clients.len()
+    }
+
+    /// Sends population state snapshot to all connected clients.
+    pub fn send_snapshot(&self, generation: usize, state: &PopulationState) -> usize {
+        match serde_json::to_string(&PopulationSnapshot { generation, state }) {
+            Ok(message) => self.broadcast(message.as_str()),
+            Err(_) => 0,
+        }
+    }
+
+    /// Converts stream into population listener.
+    pub fn into_listener(self) -> PopulationListener {
+        Arc::new(move |generation, state| {
+            self.send_snapshot(generation, state);
+        })
+    }
+}
+
+/// Starts population stream on given address and registers it as population listener.
+/// Returns the actual address the stream is bound to.
+pub fn start_population_stream(address: &str) -> GenericResult<SocketAddr> {
+    let stream = PopulationStream::bind(address)?;
+    let address = stream.local_addr();
+
+    set_population_listener(Some(stream.into_listener()));
+
+    Ok(address)
+}
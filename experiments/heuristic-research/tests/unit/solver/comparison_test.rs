@@ -0,0 +1,64 @@
+use super::*;
+
+fn create_experiment(fitness: &[Float]) -> ExperimentData {
+    ExperimentData {
+        generation: fitness.len() - 1,
+        population_state: fitness
+            .iter()
+            .enumerate()
+            .map(|(generation, &value)| (generation, PopulationState::Unknown { fitness_values: vec![0., value] }))
+            .collect(),
+        ..ExperimentData::default()
+    }
+}
+
+#[test]
+fn can_detect_significant_difference() {
+    let summary = SignificanceSummary::new(&[1., 2., 3., 4., 5.], &[6., 7., 8., 9., 10.]).unwrap();
+
+    assert_eq!(summary.sizes, (5, 5));
+    assert_eq!(summary.means, (3., 8.));
+    assert_eq!(summary.medians, (3., 8.));
+    assert_eq!(summary.u_statistic, 0.);
+    assert!(summary.z_score < -2.5 && summary.z_score > -2.6);
+    assert!((summary.p_value - 0.0122).abs() < 0.001);
+    assert!(summary.is_significant(0.05));
+}
+
+#[test]
+fn can_detect_no_significant_difference() {
+    let samples: [(&[Float], &[Float]); 3] =
+        [(&[1., 1., 1.], &[1., 1., 1.]), (&[1., 4., 5., 8.], &[2., 3., 6., 7.]), (&[1.], &[2.])];
+
+    samples.into_iter().for_each(|(first, second)| {
+        let summary = SignificanceSummary::new(first, second).unwrap();
+
+        assert!(!summary.is_significant(0.05));
+    });
+}
+
+#[test]
+fn can_handle_empty_samples() {
+    assert!(SignificanceSummary::new(&[], &[1.]).is_none());
+    assert!(SignificanceSummary::new(&[1.], &[]).is_none());
+}
+
+#[test]
+fn can_add_runs_to_comparison_data() {
+    let mut data = ComparisonData::default();
+
+    data.add_run("a", 0, create_experiment(&[10., 8., 5.]));
+    data.add_run("b", 0, create_experiment(&[10., 9., 9.]));
+    data.add_run("a", 1, create_experiment(&[10., 7., 4.]));
+    data.add_run("b", 1, create_experiment(&[10., 10., 8.]));
+
+    assert_eq!(data.configurations.len(), 2);
+    assert_eq!(data.configurations[0].name, "a");
+    assert_eq!(data.configurations[0].runs.iter().map(|run| run.seed).collect::<Vec<_>>(), vec![0, 1]);
+    assert_eq!(data.configurations[0].final_fitness(), vec![5., 4.]);
+    assert_eq!(data.configurations[1].final_fitness(), vec![9., 8.]);
+    assert_eq!(data.configurations[0].experiment.generation, 2);
+    let summary = data.get_summary().unwrap();
+    assert_eq!(summary.means, (4.5, 8.5));
+    assert_eq!(summary.u_statistic, 0.);
+}
@@ -0,0 +1,84 @@
+use super::*;
+use crate::DataPoint3D;
+use crate::solver::{FootprintState, OperatorAttribution};
+use std::collections::HashMap;
+
+fn create_experiment() -> ExperimentData {
+    let point = |x: Float, fitness: Float, z: Float| ObservationData::Function(DataPoint3D(x, fitness, z));
+
+    ExperimentData {
+        generation: 1,
+        on_add: HashMap::from([(0, vec![point(1., 2., 3.), point(4., 5., 6.)]), (1, vec![point(7., 8., 9.)])]),
+        on_select: HashMap::from([(1, vec![point(4., 5., 6.)])]),
+        on_generation: HashMap::from([(1, (FootprintState::default(), vec![point(1., 0.5, 1.)]))]),
+        on_add_operator: HashMap::from([(
+            1,
+            vec![Some(OperatorAttribution { name: "delta".to_string(), is_improvement: true })],
+        )]),
+        ..ExperimentData::default()
+    }
+}
+
+#[test]
+fn can_flatten_observations_into_records() {
+    let records = create_experiment().get_observation_records();
+
+    assert_eq!(
+        records.iter().map(|record| (record.generation, record.event, record.idx)).collect::<Vec<_>>(),
+        vec![(0, "add", 0), (0, "add", 1), (1, "add", 0), (1, "select", 0), (1, "generation", 0)]
+    );
+    assert_eq!(
+        records[1],
+        ObservationRecord {
+            generation: 0,
+            event: "add",
+            idx: 1,
+            x: Some(4.),
+            fitness: Some(5.),
+            z: Some(6.),
+            dimension: None,
+            edges: None,
+            operator: None,
+            improvement: None,
+        }
+    );
+}
+
+#[test]
+fn can_write_observations_csv() {
+    let mut buffer = Vec::new();
+
+    write_observations_csv(&create_experiment(), &mut buffer).unwrap();
+
+    let content = String::from_utf8(buffer).unwrap();
+    let lines = content.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 6);
+    assert_eq!(lines[0], "generation,event,idx,x,fitness,z,dimension,edges,operator,improvement");
+    assert_eq!(lines[1], "0,add,0,1,2,3,,,,");
+    assert_eq!(lines[3], "1,add,0,7,8,9,,,delta,true");
+    assert_eq!(lines[5], "1,generation,0,1,0.5,1,,,,");
+}
+
+#[test]
+fn can_write_empty_observations_csv() {
+    let mut buffer = Vec::new();
+
+    write_observations_csv(&ExperimentData::default(), &mut buffer).unwrap();
+
+    assert_eq!(
+        String::from_utf8(buffer).unwrap(),
+        "generation,event,idx,x,fitness,z,dimension,edges,operator,improvement\n"
+    );
+}
+
+#[cfg(feature = "parquet-format")]
+#[test]
+fn can_write_observations_parquet() {
+    let mut buffer = Vec::new();
+
+    write_observations_parquet(&create_experiment(), &mut buffer).unwrap();
+
+    assert!(buffer.len() > 8);
+    assert_eq!(&buffer[..4], b"PAR1");
+    assert_eq!(&buffer[buffer.len() - 4..], b"PAR1");
+}
@@ -0,0 +1,75 @@
+use super::*;
+
+fn attribution(name: &str, is_improvement: bool) -> OperatorAttribution {
+    OperatorAttribution { name: name.to_string(), is_improvement }
+}
+
+#[test]
+fn can_match_pending_operator_by_fitness() {
+    let mut data = ExperimentData::default();
+
+    data.add_pending_operator(&[1., 2.], attribution("noise", false));
+    data.add_pending_operator(&[3., 4.], attribution("delta", true));
+
+    assert_eq!(data.take_pending_operator(&[3., 4.]), Some(attribution("delta", true)));
+    assert_eq!(data.take_pending_operator(&[3., 4.]), None);
+    assert_eq!(data.take_pending_operator(&[1., 3.]), None);
+    assert_eq!(data.take_pending_operator(&[1., 2.]), Some(attribution("noise", false)));
+    assert!(data.pending_operator.is_empty());
+}
+
+#[test]
+fn can_get_operator_effectiveness() {
+    let data = ExperimentData {
+        on_add_operator: HashMap::from([
+            (0, vec![Some(attribution("noise", true)), None, Some(attribution("delta", false))]),
+            (1, vec![Some(attribution("noise", false)), Some(attribution("delta", true))]),
+            (2, vec![Some(attribution("noise", true))]),
+        ]),
+        ..ExperimentData::default()
+    };
+
+    let effectiveness = data.get_operator_effectiveness(1);
+
+    assert_eq!(
+        effectiveness,
+        vec![
+            OperatorEffectiveness { name: "delta".to_string(), total: 2, improvements: 1 },
+            OperatorEffectiveness { name: "noise".to_string(), total: 2, improvements: 1 },
+        ]
+    );
+}
+
+#[test]
+fn can_get_solution_dynamics() {
+    let statistics = |routes, cost| SolutionStatistics { routes, unassigned: 0, cost };
+    let data = ExperimentData {
+        generation: 2,
+        solution_statistics: HashMap::from([
+            (0, statistics(3, 100.)),
+            (2, statistics(2, 80.)),
+            (5, statistics(1, 70.)),
+        ]),
+        ..ExperimentData::default()
+    };
+
+    assert_eq!(data.get_solution_dynamics(), vec![(0, statistics(3, 100.)), (2, statistics(2, 80.))]);
+}
+
+#[test]
+fn can_get_solution_snapshot_for_replay() {
+    let snapshot = |routes: Vec<Vec<usize>>| SolutionSnapshot { routes, unassigned: 0 };
+    let data = ExperimentData {
+        generation: 25,
+        solution_snapshots: HashMap::from([(0, snapshot(vec![vec![0, 1, 0]])), (10, snapshot(vec![vec![0, 2, 1, 0]]))]),
+        locations: vec![(0., 0.), (1., 1.), (2., 0.)],
+        ..ExperimentData::default()
+    };
+
+    assert_eq!(data.get_solution_snapshot(5).map(|(generation, _)| generation), Some(0));
+    assert_eq!(data.get_solution_snapshot(10).map(|(generation, _)| generation), Some(10));
+    assert_eq!(data.get_solution_snapshot(25).map(|(generation, _)| generation), Some(10));
+
+    let (_, snapshot) = data.get_solution_snapshot(12).unwrap();
+    assert_eq!(data.get_solution_routes(snapshot), vec![vec![(0., 0.), (2., 0.), (1., 1.), (0., 0.)]]);
+}
@@ -1,8 +1,7 @@
 use super::*;
 
-#[test]
-fn can_solve_scientific_problem() {
-    let problem = r#"NAME : SMALL
+fn create_tsplib_problem() -> String {
+    r#"NAME : SMALL
 COMMENT : Test problem
 TYPE : CVRP
 DIMENSION : 5
@@ -25,8 +24,52 @@ DEPOT_SECTION
  -1
 EOF
 "#
-    .to_string();
+    .to_string()
+}
+
+#[test]
+fn can_solve_scientific_problem() {
+    let problem = create_tsplib_problem();
     let logger = Environment::default().logger;
 
     solve_vrp("tsplib", problem, "rosomaxa", 8, 200, logger);
 }
+
+#[test]
+fn can_solve_pragmatic_problem() {
+    let problem = r#"{
+  "plan": {
+    "jobs": [
+      { "id": "job1", "deliveries": [{ "places": [{ "location": { "lat": 52.52599, "lng": 13.45413 }, "duration": 300 }], "demand": [1] }] },
+      { "id": "job2", "pickups": [{ "places": [{ "location": { "lat": 52.5165, "lng": 13.3808 }, "duration": 240 }], "demand": [1] }] },
+      { "id": "job3", "deliveries": [{ "places": [{ "location": { "lat": 52.50, "lng": 13.40 }, "duration": 120 }], "demand": [1] }] }
+    ]
+  },
+  "fleet": {
+    "vehicles": [
+      {
+        "typeId": "vehicle",
+        "vehicleIds": ["vehicle_1", "vehicle_2"],
+        "profile": { "matrix": "car" },
+        "costs": { "fixed": 20.0, "distance": 0.002, "time": 0.003 },
+        "shifts": [{ "start": { "earliest": "2019-07-04T09:00:00Z", "location": { "lat": 52.4664, "lng": 13.4023 } } }],
+        "capacity": [2]
+      }
+    ],
+    "profiles": [{ "name": "car" }]
+  }
+}"#
+    .to_string();
+    let logger = Environment::default().logger;
+
+    solve_vrp("pragmatic", problem, "rosomaxa", 8, 20, logger);
+}
+
+#[test]
+fn can_get_problem_locations() {
+    let problem = create_tsplib_problem().read_tsplib(true).unwrap();
+
+    let locations = get_problem_locations(&problem);
+
+    assert_eq!(locations, vec![(82., 76.), (96., 44.), (50., 5.), (49., 8.), (13., 7.)]);
+}
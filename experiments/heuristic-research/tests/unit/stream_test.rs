@@ -0,0 +1,42 @@
+use super::*;
+use std::time::{Duration, Instant};
+
+fn wait_for_clients(stream: &PopulationStream, expected: usize) {
+    let start = Instant::now();
+    while stream.clients() < expected {
+        assert!(start.elapsed() < Duration::from_secs(5), "clients are not connected");
+        std::thread::sleep(Duration::from_millis(10));
+    }
+}
+
+#[test]
+fn can_broadcast_population_snapshot() {
+    let stream = PopulationStream::bind("127.0.0.1:0").unwrap();
+    let (mut client, _) = tungstenite::connect(format!("ws://{}", stream.local_addr())).unwrap();
+    wait_for_clients(&stream, 1);
+
+    let state = PopulationState::Unknown { fitness_values: vec![1., 2.] };
+    let received = stream.send_snapshot(3, &state);
+
+    assert_eq!(received, 1);
+    let message = client.read().unwrap().into_text().unwrap();
+    assert_eq!(message.as_str(), r#"{"generation":3,"state":{"Unknown":{"fitness_values":[1.0,2.0]}}}"#);
+}
+
+#[test]
+fn can_drop_disconnected_clients() {
+    let stream = PopulationStream::bind("127.0.0.1:0").unwrap();
+    let (mut client, _) = tungstenite::connect(format!("ws://{}", stream.local_addr())).unwrap();
+    wait_for_clients(&stream, 1);
+
+    client.close(None).unwrap();
+    drop(client);
+
+    let start = Instant::now();
+    while stream.broadcast("message") > 0 {
+        assert!(start.elapsed() < Duration::from_secs(5), "client is not dropped");
+        std::thread::sleep(Duration::from_millis(10));
+    }
+
+    assert_eq!(stream.clients(), 0);
+}
@@ -1,11 +1,11 @@
 init();
 
 async function init() {
-    const [{Chart, default: init, run_function_experiment, run_vrp_experiment, load_state, clear}, {main, setup}] = await Promise.all([
+    const [{Chart, default: init, run_function_experiment, run_vrp_experiment, run_function_comparison, run_vrp_comparison, get_comparison_summary, load_state, load_snapshot, set_replay_interval, clear}, {main, setup}] = await Promise.all([
         import("../pkg/heuristic_research.js"),
         import("./index.js"),
     ]);
     await init();
-    setup(Chart, run_function_experiment, run_vrp_experiment, load_state, clear);
+    setup(Chart, run_function_experiment, run_vrp_experiment, run_function_comparison, run_vrp_comparison, get_comparison_summary, load_state, load_snapshot, set_replay_interval, clear);
     main();
 }
@@ -6,12 +6,19 @@ const overallCanvas = document.getElementById("overallCanvas");
 const bestCanvas = document.getElementById("bestCanvas");
 const durationCanvas = document.getElementById("durationCanvas");
 const fitnessCanvas = document.getElementById("fitnessCanvas");
+const operatorCanvas = document.getElementById("operatorCanvas");
+const routesCanvas = document.getElementById("routesCanvas");
+const replayCanvas = document.getElementById("replayCanvas");
+const comparisonCanvas = document.getElementById("comparisonCanvas");
 
 const benchmarkType = document.getElementById("benchmarkType");
 const functionControls = document.getElementById("functionControls");
 const vrpControls = document.getElementById("vrpControls");
 const fileSelector = document.getElementById("fileSelector");
 const plotPopulation = document.getElementById("plotPopulation");
+const comparePopulation = document.getElementById("comparePopulation");
+const compareSeeds = document.getElementById("compareSeeds");
+const comparisonSummary = document.getElementById("comparisonSummary");
 const plotFunction = document.getElementById("plotFunction");
 const vrpFormat = document.getElementById("vrpFormat");
 const pitch = document.getElementById("pitch");
@@ -25,10 +32,15 @@ const generationControl = document.getElementById("generationControl");
 const currentGen = document.getElementById("currentGen");
 const maxGen = document.getElementById("maxGen");
 const maxGenerations = document.getElementById("maxGenerations");
+const replayInterval = document.getElementById("replayInterval");
 const autoInitPoint = document.getElementById("autoInitPoint");
 const manualPointControls = document.getElementById("manualPointControls");
 const initX = document.getElementById("initX");
 const initZ = document.getElementById("initZ");
+const streamAddress = document.getElementById("streamAddress");
+const stream = document.getElementById("stream");
+
+let streamSocket = null;
 
 /** Main entry point */
 export function main() {
@@ -39,11 +51,17 @@ export function main() {
 }
 
 /** This function is used in `vector.bootstrap.js` to setup imports. */
-export function setup(WasmChart, run_function_experiment, run_vrp_experiment, load_state, clear) {
+export function setup(WasmChart, run_function_experiment, run_vrp_experiment, run_function_comparison,
+                      run_vrp_comparison, get_comparison_summary, load_state, load_snapshot, set_replay_interval, clear) {
     Chart = WasmChart;
     Chart.run_function_experiment = run_function_experiment;
     Chart.run_vrp_experiment = run_vrp_experiment;
+    Chart.run_function_comparison = run_function_comparison;
+    Chart.run_vrp_comparison = run_vrp_comparison;
+    Chart.get_comparison_summary = get_comparison_summary;
     Chart.load_state = load_state;
+    Chart.load_snapshot = load_snapshot;
+    Chart.set_replay_interval = set_replay_interval;
     Chart.clear = clear;
 }
 
@@ -56,6 +74,7 @@ function setupListeners() {
     fileSelector.addEventListener("change", openFile);
     plotFunction.addEventListener("change", changePlot);
     plotPopulation.addEventListener("change", changePlot);
+    comparePopulation.addEventListener("change", changePlot);
     autoInitPoint.addEventListener("change", toggleInitPointMode);
 
     yaw.addEventListener("change", updatePlots);
@@ -76,13 +95,14 @@ function setupListeners() {
     });
 
     run.addEventListener("click", runExperiment);
+    stream.addEventListener("click", toggleStream);
     window.addEventListener("resize", () => {
         resizeAllCanvases();
         updatePlots();
     });
 
     // setup horizontal tab buttons
-    ['solution', 'search', 'overall', 'best', 'duration', 'fitness'].forEach(function(type) {
+    ['solution', 'search', 'overall', 'best', 'duration', 'fitness', 'operator', 'routes', 'replay', 'comparison'].forEach(function(type) {
         document.getElementById(type + 'TabButton').addEventListener("click", function(evt) {
             openTab(evt, 'canvasTab', type + 'Tab', '');
         });
@@ -135,7 +155,7 @@ function setupCanvas(canvas) {
 
 /** Resize all canvases */
 function resizeAllCanvases() {
-    [solutionCanvas, searchCanvas, overallCanvas, bestCanvas, durationCanvas, fitnessCanvas].forEach(canvas => {
+    [solutionCanvas, searchCanvas, overallCanvas, bestCanvas, durationCanvas, fitnessCanvas, operatorCanvas, routesCanvas, replayCanvas, comparisonCanvas].forEach(canvas => {
         setupCanvas(canvas);
     });
 }
@@ -251,6 +271,8 @@ function updateDynamicPlots(run) {
     let pitch_value = Number(pitch.value) / 100.0;
     let generation_value = Number(generations.value);
     let population_type = plotPopulation.selectedOptions[0].value;
+    let compare_type = comparePopulation.selectedOptions[0].value;
+    let seeds = Math.max(parseInt(compareSeeds.value) || 1, 1);
     let heuristic_kind = "best";
 
     // Get max generations from user input
@@ -317,7 +339,11 @@ function updateDynamicPlots(run) {
                 }
 
                 console.log(`init point is: (${x}, ${z})`)
-                Chart.run_function_experiment(function_name, population_type, x, z, max_gen);
+                if (compare_type === "none") {
+                    Chart.run_function_experiment(function_name, population_type, x, z, max_gen);
+                } else {
+                    Chart.run_function_comparison(function_name, population_type, compare_type, x, z, max_gen, seeds);
+                }
             }
 
             break;
@@ -334,16 +360,21 @@ function updateDynamicPlots(run) {
                     return;
                 }
                 
+                Chart.set_replay_interval(Math.max(parseInt(replayInterval.value) || 0, 0));
+
                 if (format_type === "state") {
                     max_gen = Chart.load_state(Chart.data);
-                } else {
+                } else if (compare_type === "none") {
                     Chart.run_vrp_experiment(format_type, Chart.data, population_type, max_gen);
+                } else {
+                    Chart.run_vrp_comparison(format_type, Chart.data, population_type, compare_type, max_gen, seeds);
                 }
             }
 
             // Only render if data has been loaded
             if (Chart.data) {
                 Chart.vrp(solutionCanvas, generation_value, pitch_value, yaw_value);
+                Chart.routes(replayCanvas, generation_value);
             }
             break;
         }
@@ -355,6 +386,12 @@ function updateDynamicPlots(run) {
         Chart.search_best_statistics(bestCanvas, generation_value, heuristic_kind);
         Chart.search_duration_statistics(durationCanvas, generation_value, heuristic_kind);
         Chart.search_overall_statistics(overallCanvas, generation_value, heuristic_kind);
+        Chart.operator_effectiveness(operatorCanvas, generation_value);
+    }
+
+    if (compare_type !== "none") {
+        Chart.comparison(comparisonCanvas, generation_value);
+        comparisonSummary.innerText = Chart.get_comparison_summary();
     }
 
     const end = performance.now();
@@ -381,6 +418,7 @@ function updateStaticPlots() {
             // Only render if data has been loaded
             if (Chart.data) {
                 Chart.fitness_vrp(fitnessCanvas);
+                Chart.solution_dynamics(routesCanvas);
             }
             break;
         }
@@ -403,6 +441,37 @@ function runExperiment() {
     }, 50);
 }
 
+/** Connects to (or disconnects from) population stream and animates population as generations complete. */
+function toggleStream() {
+    if (streamSocket) {
+        streamSocket.close();
+        return;
+    }
+
+    streamSocket = new WebSocket(streamAddress.value);
+    stream.innerText = "Stop";
+
+    streamSocket.onopen = () => {
+        Chart.clear();
+        status.innerText = `✓ Connected to ${streamAddress.value}`;
+    };
+    streamSocket.onmessage = (event) => {
+        const max_gen = Chart.load_snapshot(event.data);
+
+        generations.max = max_gen;
+        generations.value = max_gen;
+        maxGen.innerText = max_gen;
+        currentGen.innerText = max_gen;
+        generationControl.classList.remove("hide");
+
+        updatePlots();
+    };
+    streamSocket.onclose = () => {
+        streamSocket = null;
+        stream.innerText = "Live";
+    };
+}
+
 function updatePlots() {
     updateDynamicPlots(false);
     updateStaticPlots(false);
@@ -3,6 +3,7 @@
 mod network_test;
 
 use super::*;
+use crate::algorithms::math::scaled_euclidean_distance;
 use crate::utils::*;
 use rand::prelude::SliceRandom;
 use rayon::iter::Either;
@@ -63,6 +64,8 @@ pub struct MinMaxWeights {
     pub min: Vec<Float>,
     /// Max weights.
     pub max: Vec<Float>,
+    /// Scales used to normalize difference between weights: 1 / (max - min) or 0 when max equals min.
+    scales: Vec<Float>,
     /// Indicates if min-max values are reset.
     is_reset: bool,
 }
@@ -70,7 +73,12 @@ pub struct MinMaxWeights {
 impl MinMaxWeights {
     /// Creates a new instance of [MinMaxWeights].
     fn new(dimension: usize) -> Self {
-        Self { min: vec![Float::MAX; dimension], max: vec![Float::MIN; dimension], is_reset: true }
+        Self {
+            min: vec![Float::MAX; dimension],
+            max: vec![Float::MIN; dimension],
+            scales: vec![1.; dimension],
+            is_reset: true,
+        }
     }
 
     /// Updates min max weights.
@@ -79,9 +87,17 @@ impl MinMaxWeights {
 
         self.min.iter_mut().zip(weights.iter()).for_each(|(curr, v)| *curr = curr.min(*v));
         self.max.iter_mut().zip(weights.iter()).for_each(|(curr, v)| *curr = curr.max(*v));
+        self.scales.iter_mut().zip(self.min.iter().zip(self.max.iter())).for_each(|(scale, (min, max))| {
+            *scale = if max != min { 1. / (max - min) } else { 0. };
+        });
         self.is_reset = false;
     }
 
+    /// Returns scales which normalize difference between weights.
+    pub fn scales(&self) -> &[Float] {
+        &self.scales
+    }
+
     /// Iterates over min-max values.
     pub fn iter(&self) -> impl Iterator<Item = (Float, Float)> + '_ {
         if self.is_reset {
@@ -97,6 +113,7 @@ impl MinMaxWeights {
         self.is_reset = true;
         self.min.fill(Float::MAX);
         self.max.fill(Float::MIN);
+        self.scales.fill(1.);
     }
 }
 
@@ -224,6 +241,24 @@ where
         self.nodes.into_iter()
     }
 
+    /// Shrinks storages of all nodes to the specified size.
+    pub fn resize_storages(&mut self, size: usize) {
+        self.nodes.values_mut().for_each(|node| node.storage.resize(size));
+    }
+
+    /// Returns an approximate amount of memory (in bytes) used by the network nodes, excluding data
+    /// kept in their storages.
+    pub fn memory_usage(&self) -> usize {
+        self.nodes
+            .values()
+            .map(|node| {
+                size_of::<(Coordinate, Node<I, S>)>()
+                    + node.weights.capacity() * size_of::<Float>()
+                    + node.last_hits.capacity() * size_of::<usize>()
+            })
+            .sum()
+    }
+
     /// Returns a total amount of nodes.
     pub fn size(&self) -> usize {
         self.nodes.len()
@@ -493,7 +528,8 @@ where
 
         // create initial node coordinates and data assignments (by index)
         let grid_size = (initial_node_indices.len() as f64).sqrt().ceil() as i32;
-        let mut node_assignments: HashMap<Coordinate, Vec<usize>> = initial_node_indices
+        // NOTE use a hasher with fixed state as iteration order affects random generation below
+        let mut node_assignments: HashMap<Coordinate, Vec<usize>, BuildHasherDefault<FxHasher>> = initial_node_indices
             .iter()
             .enumerate()
             .map(|(grid_idx, &data_idx)| {
@@ -602,9 +638,8 @@ fn normalize<'a>(values: &'a [Float], min_max: &'a MinMaxWeights) -> impl Iterat
 }
 
 fn euclidian_distance(left: &[Float], right: &[Float], min_max: &MinMaxWeights) -> Float {
-    let left_iter = normalize(left, min_max);
-    let right_iter = normalize(right, min_max);
-
+    // NOTE difference of min-max normalized values is equal to scaled difference of original ones,
+    // so normalization is not applied explicitly to keep the loop vectorizable
     // TODO allow to pass custom distance function
-    left_iter.zip(right_iter).map(|(a, b)| (a - b).powi(2)).sum::<Float>().sqrt()
+    scaled_euclidean_distance(left, right, min_max.scales())
 }
@@ -8,3 +8,6 @@ pub use self::remedian::{Remedian, RemedianUsize};
 
 mod statistics;
 pub use self::statistics::*;
+
+mod vector;
+pub use self::vector::*;
@@ -1,3 +1,4 @@
+use super::sum;
 use crate::prelude::Float;
 
 /// Returns coefficient variation.
@@ -20,12 +21,7 @@ pub fn get_cv_safe(values: &[Float]) -> Float {
 
 /// Gets mean of values using given slice.
 pub fn get_mean_slice(values: &[Float]) -> Float {
-    if values.is_empty() {
-        0.
-    } else {
-        let sum: Float = values.iter().sum();
-        sum / values.len() as Float
-    }
+    if values.is_empty() { 0. } else { sum(values) / values.len() as Float }
 }
 
 /// Gets mean of values using given iterator.
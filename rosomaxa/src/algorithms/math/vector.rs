@@ -0,0 +1,89 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/algorithms/math/vector_test.rs"]
+mod vector_test;
+
+use crate::prelude::Float;
+
+/// Amount of values processed at once when `simd` feature is enabled. Independent accumulators
+/// break dependency chain between iterations, so the compiler can map them to SIMD registers.
+#[cfg(feature = "simd")]
+const LANES: usize = 8;
+
+/// Returns sum of values.
+pub fn sum(values: &[Float]) -> Float {
+    #[cfg(feature = "simd")]
+    {
+        let chunks = values.chunks_exact(LANES);
+        let remainder = chunks.remainder().iter().sum::<Float>();
+
+        reduce_lanes(chunks.fold([0.; LANES], |mut acc, chunk| {
+            acc.iter_mut().zip(chunk).for_each(|(acc, value)| *acc += value);
+            acc
+        })) + remainder
+    }
+
+    #[cfg(not(feature = "simd"))]
+    {
+        values.iter().sum()
+    }
+}
+
+/// Returns euclidean distance between two vectors of the same size.
+pub fn euclidean_distance(a: &[Float], b: &[Float]) -> Float {
+    debug_assert_eq!(a.len(), b.len());
+
+    #[cfg(feature = "simd")]
+    {
+        let (a_chunks, b_chunks) = (a.chunks_exact(LANES), b.chunks_exact(LANES));
+        let remainder =
+            a_chunks.remainder().iter().zip(b_chunks.remainder()).map(|(a, b)| (a - b) * (a - b)).sum::<Float>();
+
+        (reduce_lanes(a_chunks.zip(b_chunks).fold([0.; LANES], |mut acc, (a, b)| {
+            (0..LANES).for_each(|idx| {
+                let diff = a[idx] - b[idx];
+                acc[idx] += diff * diff;
+            });
+            acc
+        })) + remainder)
+            .sqrt()
+    }
+
+    #[cfg(not(feature = "simd"))]
+    {
+        a.iter().zip(b).map(|(a, b)| (a - b) * (a - b)).sum::<Float>().sqrt()
+    }
+}
+
+/// Returns euclidean distance between two vectors of the same size where difference in each dimension
+/// is multiplied by the corresponding scale: D = sqrt(Σ((a_i - b_i) * s_i)²).
+/// It is equal to the distance between min-max normalized vectors when `s_i = 1 / (max_i - min_i)`.
+pub fn scaled_euclidean_distance(a: &[Float], b: &[Float], scales: &[Float]) -> Float {
+    debug_assert!(a.len() == b.len() && a.len() == scales.len());
+
+    #[cfg(feature = "simd")]
+    {
+        let (a_chunks, b_chunks, s_chunks) = (a.chunks_exact(LANES), b.chunks_exact(LANES), scales.chunks_exact(LANES));
+        let remainder = (a_chunks.remainder().iter().zip(b_chunks.remainder()).zip(s_chunks.remainder()))
+            .map(|((a, b), s)| ((a - b) * s) * ((a - b) * s))
+            .sum::<Float>();
+
+        (reduce_lanes(a_chunks.zip(b_chunks).zip(s_chunks).fold([0.; LANES], |mut acc, ((a, b), s)| {
+            (0..LANES).for_each(|idx| {
+                let diff = (a[idx] - b[idx]) * s[idx];
+                acc[idx] += diff * diff;
+            });
+            acc
+        })) + remainder)
+            .sqrt()
+    }
+
+    #[cfg(not(feature = "simd"))]
+    {
+        a.iter().zip(b).zip(scales).map(|((a, b), s)| ((a - b) * s) * ((a - b) * s)).sum::<Float>().sqrt()
+    }
+}
+
+#[cfg(feature = "simd")]
+fn reduce_lanes(lanes: [Float; LANES]) -> Float {
+    lanes.iter().sum()
+}
@@ -194,7 +194,7 @@ where
         self
     }
 
-    /// Sets termination.
+    /// Sets an extra termination criteria which is checked along with ones configured by other methods.
     pub fn with_termination(mut self, termination: Box<dyn Termination<Context = C, Objective = O>>) -> Self {
         self.termination = Some(termination);
         self
@@ -306,6 +306,10 @@ where
         let logger = context.environment().logger.clone();
         let termination =
             Self::get_termination(&logger, self.max_generations, self.max_time, self.min_cv, self.target_proximity)?;
+        let termination: Box<dyn Termination<Context = C, Objective = O>> = match self.termination {
+            Some(extra) => Box::new(CompositeTermination::new(vec![extra, termination])),
+            None => termination,
+        };
 
         Ok(EvolutionConfig {
             initial: self.initial,
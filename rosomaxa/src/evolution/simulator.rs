@@ -1,6 +1,6 @@
 use crate::evolution::EvolutionResult;
 use crate::prelude::*;
-use crate::utils::Timer;
+use crate::utils::{Timer, with_deterministic_mode};
 
 /// An entity which simulates evolution process.
 pub struct EvolutionSimulator<C, O, S>
@@ -30,6 +30,14 @@ where
     /// Runs evolution for given `problem` using evolution `config`.
     /// Returns populations filled with solutions.
     pub fn run(self) -> EvolutionResult<S> {
+        if self.config.context.environment().parallelism.is_deterministic() {
+            with_deterministic_mode(|| self.run_evolution())
+        } else {
+            self.run_evolution()
+        }
+    }
+
+    fn run_evolution(self) -> EvolutionResult<S> {
         let mut config = self.config;
 
         let hooks = config.processing;
@@ -59,7 +67,9 @@ where
             let item_time = Timer::start();
 
             let is_overall_termination = config.termination.is_termination(&mut heuristic_ctx);
-            let is_initial_quota_reached = config.termination.estimate(&heuristic_ctx) > config.initial.quota;
+            // NOTE at least one solution is built even if pre-processing has consumed the initial quota
+            let is_initial_quota_reached =
+                idx > 0 && config.termination.estimate(&heuristic_ctx) > config.initial.quota;
 
             if is_initial_quota_reached || is_overall_termination {
                 (logger)(
@@ -45,12 +45,14 @@ where
         let mut heuristic_ctx = heuristic_ctx;
         let heuristic = &mut self.heuristic;
 
-        loop {
-            let is_terminated = termination.is_termination(&mut heuristic_ctx);
+        let termination_reason = loop {
+            let termination_reason = termination.check(&mut heuristic_ctx);
             let is_quota_reached = heuristic_ctx.environment().quota.as_ref().is_some_and(|q| q.is_reached());
 
-            if is_terminated || is_quota_reached {
-                break;
+            match (termination_reason, is_quota_reached) {
+                (Some(reason), _) => break reason,
+                (None, true) => break "quota".to_string(),
+                _ => {}
             }
 
             let generation_time = Timer::start();
@@ -70,12 +72,14 @@ where
             let termination_estimate = termination.estimate(&heuristic_ctx);
 
             heuristic_ctx.on_generation(offspring, termination_estimate, generation_time);
-        }
+        };
 
         // NOTE give a chance to report internal state of heuristic
         (heuristic_ctx.environment().logger)(&format!("{heuristic}"));
 
         let (population, telemetry_metrics) = heuristic_ctx.on_result()?;
+        let telemetry_metrics =
+            telemetry_metrics.map(|metrics| TelemetryMetrics { termination: Some(termination_reason), ..metrics });
 
         let solutions =
             population.ranked().map(|solution| solution.deep_copy()).take(self.desired_solutions_amount).collect();
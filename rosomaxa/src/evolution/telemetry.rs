@@ -6,9 +6,11 @@ mod telemetry_test;
 
 use crate::algorithms::math::relative_distance;
 use crate::prelude::*;
-use crate::utils::Timer;
+use crate::utils::{Timer, is_deterministic_mode};
 use crate::{DynHeuristicPopulation, RemedianUsize};
+use std::collections::HashMap;
 use std::marker::PhantomData;
+use std::sync::Mutex;
 
 /// Encapsulates different measurements regarding algorithm evaluation.
 pub struct TelemetryMetrics {
@@ -20,6 +22,39 @@ pub struct TelemetryMetrics {
     pub speed: Float,
     /// Evolution progress.
     pub evolution: Vec<TelemetryGeneration>,
+    /// A name of termination criteria which has stopped the search, if known.
+    pub termination: Option<String>,
+    /// Best known fitness samples taken on generations with improvement.
+    pub best_fitness: Vec<TelemetryFitness>,
+    /// Usage statistics of search operators sorted by name.
+    pub operators: Vec<TelemetryOperator>,
+    /// A peak of approximate memory usage (in bytes), if tracked.
+    pub memory_peak: Option<usize>,
+    /// Amount of times population was shrunk due to exceeded memory budget.
+    pub memory_shrinks: usize,
+    /// Named values reported by the heuristic context, e.g. domain specific estimations.
+    pub properties: Vec<(String, Float)>,
+}
+
+/// Represents a best known fitness sample.
+pub struct TelemetryFitness {
+    /// Generation sequence number.
+    pub generation: usize,
+    /// Time since evolution started.
+    pub timestamp: Float,
+    /// Objectives fitness values.
+    pub fitness: Vec<Float>,
+}
+
+/// Keeps usage statistics of a search operator.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TelemetryOperator {
+    /// A search operator name.
+    pub name: String,
+    /// Amount of solutions produced by the operator.
+    pub calls: usize,
+    /// Amount of solutions which improved the best known fitness.
+    pub improvements: usize,
 }
 
 /// Represents information about generation.
@@ -97,6 +132,7 @@ where
     improvement_tracker: ImprovementTracker,
     speed_tracker: SpeedTracker,
     next_generation: Option<usize>,
+    operators: Mutex<HashMap<String, (usize, usize)>>,
     _marker: (PhantomData<O>, PhantomData<S>),
 }
 
@@ -109,12 +145,24 @@ where
     pub fn new(mode: TelemetryMode) -> Self {
         Self {
             time: Timer::start(),
-            metrics: TelemetryMetrics { duration: 0, generations: 0, speed: 0.0, evolution: vec![] },
+            metrics: TelemetryMetrics {
+                duration: 0,
+                generations: 0,
+                speed: 0.0,
+                evolution: vec![],
+                termination: None,
+                best_fitness: vec![],
+                operators: vec![],
+                memory_peak: None,
+                memory_shrinks: 0,
+                properties: vec![],
+            },
             mode,
             statistics: Default::default(),
             improvement_tracker: ImprovementTracker::new(1000),
             speed_tracker: SpeedTracker::default(),
             next_generation: None,
+            operators: Mutex::default(),
             _marker: Default::default(),
         }
     }
@@ -155,7 +203,12 @@ where
         self.statistics = HeuristicStatistics {
             generation,
             time: self.time.clone(),
-            speed: self.speed_tracker.get_current_speed(),
+            // NOTE speed depends on hardware and load, so it is not reported in deterministic mode
+            speed: if is_deterministic_mode() {
+                HeuristicSpeed::Unknown
+            } else {
+                self.speed_tracker.get_current_speed()
+            },
             improvement_all_ratio: self.improvement_tracker.i_all_ratio,
             improvement_1000_ratio: self.improvement_tracker.i_1000_ratio,
             termination_estimate,
@@ -172,6 +225,14 @@ where
 
         match population.ranked().next() {
             Some(best_individual) => {
+                if track_population.is_some() && (is_improved || self.metrics.best_fitness.is_empty()) {
+                    self.metrics.best_fitness.push(TelemetryFitness {
+                        generation,
+                        timestamp: self.time.elapsed_secs_as_float(),
+                        fitness: best_individual.fitness().collect(),
+                    });
+                }
+
                 let should_log_best = generation.is_multiple_of(*log_best.unwrap_or(&usize::MAX));
                 let should_log_population = generation.is_multiple_of(*log_population.unwrap_or(&usize::MAX));
                 let should_track_population = generation.is_multiple_of(*track_population.unwrap_or(&usize::MAX));
@@ -272,6 +333,39 @@ where
 
         self.metrics.duration = elapsed;
         self.metrics.speed = speed;
+        self.metrics.operators = self.get_operators();
+    }
+
+    /// Reports a solution produced by the search operator with given name.
+    pub fn on_search(&self, operator_name: &str, is_improvement: bool) {
+        if matches!(self.mode, TelemetryMode::OnlyMetrics { .. } | TelemetryMode::All { .. }) {
+            let mut operators = self.operators.lock().unwrap();
+            let (calls, improvements) = operators.entry(operator_name.to_string()).or_default();
+
+            *calls += 1;
+            *improvements += is_improvement as usize;
+        }
+    }
+
+    /// Reports an approximate memory usage (in bytes) and whether population was shrunk due to it.
+    pub fn on_memory_usage(&mut self, memory_usage: usize, is_shrunk: bool) {
+        self.metrics.memory_peak = Some(self.metrics.memory_peak.map_or(memory_usage, |peak| peak.max(memory_usage)));
+        self.metrics.memory_shrinks += is_shrunk as usize;
+    }
+
+    /// Reports a named value: it is written into log and kept in metrics.
+    pub fn on_property(&mut self, name: &str, value: Float) {
+        self.log(format!("{name}: {value}").as_str());
+
+        match self.metrics.properties.iter_mut().find(|(key, _)| key == name) {
+            Some((_, existing)) => *existing = value,
+            None => self.metrics.properties.push((name.to_string(), value)),
+        }
+    }
+
+    /// Returns true if metrics are collected.
+    pub fn has_metrics(&self) -> bool {
+        matches!(self.mode, TelemetryMode::OnlyMetrics { .. } | TelemetryMode::All { .. })
     }
 
     /// Gets metrics.
@@ -296,6 +390,20 @@ where
         &self.statistics
     }
 
+    fn get_operators(&self) -> Vec<TelemetryOperator> {
+        let mut operators = self
+            .operators
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(name, &(calls, improvements))| TelemetryOperator { name: name.clone(), calls, improvements })
+            .collect::<Vec<_>>();
+
+        operators.sort_by(|a, b| a.name.cmp(&b.name));
+
+        operators
+    }
+
     fn get_individual_metrics(&self, population: &DynHeuristicPopulation<O, S>, solution: &S) -> TelemetryIndividual {
         let fitness = solution.fitness().collect::<Vec<_>>();
 
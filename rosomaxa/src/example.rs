@@ -101,6 +101,10 @@ impl HeuristicContext for VectorContext {
         self.inner_context.on_generation(offspring, termination_estimate, generation_time)
     }
 
+    fn on_search(&self, operator_name: &str, solution: &Self::Solution, is_improvement: bool) {
+        self.inner_context.on_search(operator_name, solution, is_improvement)
+    }
+
     fn on_result(self) -> HeuristicResult<Self::Objective, Self::Solution> {
         self.inner_context.on_result()
     }
@@ -162,6 +166,10 @@ impl HeuristicSolution for VectorSolution {
     fn deep_copy(&self) -> Self {
         self.clone()
     }
+
+    fn memory_usage(&self) -> usize {
+        std::mem::size_of::<Self>() + (self.data.capacity() + self.weights.capacity()) * std::mem::size_of::<Float>()
+    }
 }
 
 impl RosomaxaSolution for VectorSolution {
@@ -6,7 +6,7 @@ use super::*;
 use crate::Timer;
 use crate::algorithms::math::RemedianUsize;
 use crate::algorithms::rl::{SlotAction, SlotFeedback, SlotMachine};
-use crate::utils::{DefaultDistributionSampler, random_argmax};
+use crate::utils::{DefaultDistributionSampler, is_deterministic_mode, random_argmax};
 use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::fmt::Formatter;
@@ -15,8 +15,11 @@ use std::iter::once;
 use std::sync::Arc;
 
 /// A collection of heuristic search operators with their name and initial weight.
-pub type HeuristicSearchOperators<C, O, S> =
-    Vec<(Arc<dyn HeuristicSearchOperator<Context = C, Objective = O, Solution = S> + Send + Sync>, String, Float)>;
+pub type HeuristicSearchOperators<C, O, S> = Vec<WeightedSearchOperator<C, O, S>>;
+
+/// A heuristic search operator with its name and initial weight.
+type WeightedSearchOperator<C, O, S> =
+    (Arc<dyn HeuristicSearchOperator<Context = C, Objective = O, Solution = S> + Send + Sync>, String, Float);
 
 /// A collection of heuristic diversify operators.
 pub type HeuristicDiversifyOperators<C, O, S> =
@@ -84,6 +87,10 @@ where
     fn diversify_many(&self, heuristic_ctx: &Self::Context, solutions: Vec<&Self::Solution>) -> Vec<Self::Solution> {
         diversify_solutions(heuristic_ctx, solutions, self.diversify_operators.as_slice())
     }
+
+    fn reset(&mut self) {
+        self.agent.reset();
+    }
 }
 
 impl<C, O, S> DynamicSelective<C, O, S>
@@ -102,6 +109,54 @@ where
     }
 }
 
+/// Creates slot machines for each search state with priors derived from operators' initial weights.
+fn create_slot_machines<'a, C, O, S>(
+    search_operators: &[WeightedSearchOperator<C, O, S>],
+    random: Arc<dyn Random>,
+) -> HashMap<SearchState, SlotMachines<'a, C, O, S>>
+where
+    C: HeuristicContext<Objective = O, Solution = S> + 'a,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution + 'a,
+{
+    // Normalize weights so the average operator has prior_mean ≈ 1.0.
+    // This aligns with typical success rewards (~1-3 range).
+    let total_weight: Float = search_operators.iter().map(|(_, _, w)| *w).sum();
+    let count = search_operators.len() as Float;
+    let avg_weight = if count > 0.0 && total_weight > f64::EPSILON { total_weight / count } else { 1.0 };
+
+    // Factory function to create slot configurations for each state.
+    // Uses domain knowledge (initial weights) as priors - important because:
+    // 1. We have many operators (cold start problem)
+    // 2. Limited search time may not be enough to learn from scratch
+    // 3. Weights encode expert knowledge about operator effectiveness
+    let create_slots = || {
+        search_operators
+            .iter()
+            .map(|(operator, name, initial_weight)| {
+                // Smooth mapping of weight ratio to prior mean range [0.1, 3.0].
+                // Uses tanh for smooth compression without hard cutoffs.
+                // ratio=1 (average) → prior=1.0, higher ratios → up to 3.0, lower → down to 0.1
+                let ratio = initial_weight / avg_weight;
+                let t = (ratio - 1.0).tanh(); // smooth compression to [-1, 1]
+                // Asymmetric scaling: [−1,0] → [0.1,1.0], [0,1] → [1.0,3.0]
+                let prior_mean = if t >= 0.0 { 1.0 + t * 2.0 } else { 1.0 + t * 0.9 };
+                (
+                    SlotMachine::new(
+                        prior_mean,
+                        SearchAction { operator: operator.clone(), operator_name: name.to_string() },
+                        DefaultDistributionSampler::new(random.clone()),
+                    ),
+                    name.clone(),
+                )
+            })
+            .collect::<Vec<_>>()
+    };
+
+    // Initialize separate states with identical priors but independent learning.
+    once((SearchState::BestKnown, create_slots())).chain(once((SearchState::Diverse, create_slots()))).collect()
+}
+
 /// Type alias for slot machines used in Thompson sampling.
 pub type SlotMachines<'a, C, O, S> = Vec<(SlotMachine<SearchAction<'a, C, O, S>, DefaultDistributionSampler>, String)>;
 
@@ -184,13 +239,15 @@ where
         let (new_solution, duration) =
             Timer::measure_duration(|| self.operator.search(context.heuristic_ctx, context.solution));
 
-        let duration = duration.as_millis() as usize;
+        // NOTE use the same duration for all operators as actual one depends on hardware and load
+        let duration = if is_deterministic_mode() { 1 } else { duration.as_millis() as usize };
 
         // Compute reward using the simplified V2.1 formula.
         let reward =
             compute_reward(context.heuristic_ctx, context.solution, &new_solution, duration, context.approx_median);
 
         let is_new_best = compare_to_best(context.heuristic_ctx, &new_solution) == Ordering::Less;
+        context.heuristic_ctx.on_search(self.operator_name.as_str(), &new_solution, is_new_best);
         let to = if is_new_best { SearchState::BestKnown } else { SearchState::Diverse };
         let transition = (context.from, to);
 
@@ -215,6 +272,8 @@ where
 }
 
 struct SearchAgent<'a, C, O, S> {
+    /// Search operators with their initial weights used to (re)create slot machines.
+    search_operators: HeuristicSearchOperators<C, O, S>,
     /// Separate learning contexts for different search phases (BestKnown vs Diverse).
     slot_machines: HashMap<SearchState, SlotMachines<'a, C, O, S>>,
     /// Tracks operator durations for median calculation.
@@ -230,52 +289,21 @@ where
     S: HeuristicSolution + 'a,
 {
     pub fn new(search_operators: HeuristicSearchOperators<C, O, S>, environment: &Environment) -> Self {
-        // Normalize weights so the average operator has prior_mean ≈ 1.0.
-        // This aligns with typical success rewards (~1-3 range).
-        let total_weight: Float = search_operators.iter().map(|(_, _, w)| *w).sum();
-        let count = search_operators.len() as Float;
-        let avg_weight = if count > 0.0 && total_weight > f64::EPSILON { total_weight / count } else { 1.0 };
-
-        // Factory function to create slot configurations for each state.
-        // Uses domain knowledge (initial weights) as priors - important because:
-        // 1. We have many operators (cold start problem)
-        // 2. Limited search time may not be enough to learn from scratch
-        // 3. Weights encode expert knowledge about operator effectiveness
-        let create_slots = || {
-            search_operators
-                .iter()
-                .map(|(operator, name, initial_weight)| {
-                    // Smooth mapping of weight ratio to prior mean range [0.1, 3.0].
-                    // Uses tanh for smooth compression without hard cutoffs.
-                    // ratio=1 (average) → prior=1.0, higher ratios → up to 3.0, lower → down to 0.1
-                    let ratio = initial_weight / avg_weight;
-                    let t = (ratio - 1.0).tanh(); // smooth compression to [-1, 1]
-                    // Asymmetric scaling: [−1,0] → [0.1,1.0], [0,1] → [1.0,3.0]
-                    let prior_mean = if t >= 0.0 { 1.0 + t * 2.0 } else { 1.0 + t * 0.9 };
-                    (
-                        SlotMachine::new(
-                            prior_mean,
-                            SearchAction { operator: operator.clone(), operator_name: name.to_string() },
-                            DefaultDistributionSampler::new(environment.random.clone()),
-                        ),
-                        name.clone(),
-                    )
-                })
-                .collect::<Vec<_>>()
-        };
-
-        // Initialize separate states with identical priors but independent learning.
-        let slot_machines = once((SearchState::BestKnown, create_slots()))
-            .chain(once((SearchState::Diverse, create_slots())))
-            .collect();
+        let slot_machines = create_slot_machines(search_operators.as_slice(), environment.random.clone());
 
         Self {
+            search_operators,
             slot_machines,
             tracker: HeuristicTracker::new(environment.is_experimental),
             random: environment.random.clone(),
         }
     }
 
+    /// Resets learned state of slot machines back to priors defined by initial weights.
+    pub fn reset(&mut self) {
+        self.slot_machines = create_slot_machines(self.search_operators.as_slice(), self.random.clone());
+    }
+
     /// Picks the relevant search operator using pure Thompson Sampling and runs the search.
     pub fn search(&self, heuristic_ctx: &C, solution: &S) -> SearchFeedback<S> {
         // Determine search context - critical for operator selection.
@@ -6,6 +6,9 @@ pub use self::dynamic_selective::*;
 mod static_selective;
 pub use self::static_selective::*;
 
+mod warm_restart;
+pub use self::warm_restart::*;
+
 use crate::prelude::*;
 use crate::utils::parallel_into_collect;
 use std::fmt::Display;
@@ -62,6 +65,9 @@ pub trait HyperHeuristic: Display {
     /// As the `diversify` method requires a mutable reference, implementations of `diversify_many` is
     /// supposed to run its logic in parallel for each solution.
     fn diversify_many(&self, heuristic_ctx: &Self::Context, solutions: Vec<&Self::Solution>) -> Vec<Self::Solution>;
+
+    /// Resets internal state learned during the search, if any. Default implementation does nothing.
+    fn reset(&mut self) {}
 }
 
 /// Gets probability to run diversify search.
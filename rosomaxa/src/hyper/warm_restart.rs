@@ -0,0 +1,186 @@
+#[cfg(test)]
+#[path = "../../tests/unit/hyper/warm_restart_test.rs"]
+mod warm_restart_test;
+
+use super::*;
+use std::cmp::Ordering;
+use std::fmt::Formatter;
+
+/// A hyper heuristic decorator which restarts the search when the best known solution is not improved
+/// during given amount of generations (stagnation window). On restart, the best known solution is heavily
+/// perturbed by the restart operator and the learned state of the inner heuristic is reset.
+///
+/// The perturbed solution is usually worse than the population members, so it would be discarded by
+/// an elitist population right away. To give it a chance, the inner heuristic follows a separate restart
+/// trajectory during `trajectory_size` generations: the search continues from the best solution found
+/// within the trajectory, independently from the population selection.
+pub struct WarmRestart<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    inner: Box<dyn HyperHeuristic<Context = C, Objective = O, Solution = S>>,
+    restart_operator: Arc<dyn HeuristicSearchOperator<Context = C, Objective = O, Solution = S> + Send + Sync>,
+    stagnation_window: usize,
+    trajectory_size: usize,
+    best_known: Option<(S, usize)>,
+    trajectory: Option<(S, usize)>,
+    restarts: usize,
+}
+
+impl<C, O, S> WarmRestart<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    /// Creates a new instance of `WarmRestart`.
+    pub fn new(
+        inner: Box<dyn HyperHeuristic<Context = C, Objective = O, Solution = S>>,
+        restart_operator: Arc<dyn HeuristicSearchOperator<Context = C, Objective = O, Solution = S> + Send + Sync>,
+        stagnation_window: usize,
+        trajectory_size: usize,
+    ) -> Self {
+        assert!(stagnation_window > 0);
+        assert!(trajectory_size > 0);
+
+        Self {
+            inner,
+            restart_operator,
+            stagnation_window,
+            trajectory_size,
+            best_known: None,
+            trajectory: None,
+            restarts: 0,
+        }
+    }
+
+    /// Returns amount of restarts happened so far.
+    pub fn restarts(&self) -> usize {
+        self.restarts
+    }
+
+    /// Tracks the best known solution and returns a perturbed one if the search has stagnated.
+    fn try_restart(&mut self, heuristic_ctx: &C) -> Option<S> {
+        let generation = heuristic_ctx.statistics().generation;
+        let best = heuristic_ctx.ranked().next()?;
+
+        let is_improved = self
+            .best_known
+            .as_ref()
+            .is_none_or(|(known, _)| heuristic_ctx.objective().total_order(best, known) == Ordering::Less);
+
+        if is_improved {
+            self.best_known = Some((best.deep_copy(), generation));
+            return None;
+        }
+
+        let (known, last_generation) = self.best_known.as_mut()?;
+        if generation.saturating_sub(*last_generation) < self.stagnation_window {
+            return None;
+        }
+
+        *last_generation = generation;
+        self.restarts += 1;
+        self.inner.reset();
+
+        (heuristic_ctx.environment().logger)(&format!("warm restart at generation {generation}"));
+
+        Some(self.restart_operator.search(heuristic_ctx, known))
+    }
+
+    /// Returns a solution to continue the search from when restart trajectory is active or starts.
+    fn get_trajectory_solution(&mut self, heuristic_ctx: &C) -> Option<S> {
+        if let Some((current, _)) = self.trajectory.as_ref() {
+            return Some(current.deep_copy());
+        }
+
+        let perturbed = self.try_restart(heuristic_ctx)?;
+        self.trajectory = Some((perturbed.deep_copy(), self.trajectory_size));
+
+        Some(perturbed)
+    }
+
+    /// Moves restart trajectory to the best offspring if it is an improvement and finishes it when its
+    /// size is exceeded.
+    fn advance_trajectory(&mut self, heuristic_ctx: &C, offspring: &[S]) {
+        let Some((current, remaining)) = self.trajectory.as_mut() else { return };
+        let objective = heuristic_ctx.objective();
+
+        let best = offspring.iter().min_by(|a, b| objective.total_order(a, b));
+        if let Some(best) = best.filter(|best| objective.total_order(best, current) == Ordering::Less) {
+            *current = best.deep_copy();
+        }
+
+        *remaining = remaining.saturating_sub(1);
+        if *remaining > 0 {
+            return;
+        }
+
+        self.trajectory = None;
+
+        // NOTE stagnation window is counted from the end of restart trajectory
+        if let Some((_, last_generation)) = self.best_known.as_mut() {
+            *last_generation = heuristic_ctx.statistics().generation;
+        }
+    }
+}
+
+impl<C, O, S> HyperHeuristic for WarmRestart<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    type Context = C;
+    type Objective = O;
+    type Solution = S;
+
+    fn search(&mut self, heuristic_ctx: &Self::Context, solution: &Self::Solution) -> Vec<Self::Solution> {
+        match self.get_trajectory_solution(heuristic_ctx) {
+            Some(current) => {
+                let offspring = self.inner.search(heuristic_ctx, &current);
+                self.advance_trajectory(heuristic_ctx, offspring.as_slice());
+                offspring.into_iter().chain(std::iter::once(current)).collect()
+            }
+            None => self.inner.search(heuristic_ctx, solution),
+        }
+    }
+
+    fn search_many(&mut self, heuristic_ctx: &Self::Context, solutions: Vec<&Self::Solution>) -> Vec<Self::Solution> {
+        match self.get_trajectory_solution(heuristic_ctx) {
+            Some(current) => {
+                let offspring = self.inner.search_many(heuristic_ctx, solutions.iter().map(|_| &current).collect());
+                self.advance_trajectory(heuristic_ctx, offspring.as_slice());
+                offspring.into_iter().chain(std::iter::once(current)).collect()
+            }
+            None => self.inner.search_many(heuristic_ctx, solutions),
+        }
+    }
+
+    fn diversify(&self, heuristic_ctx: &Self::Context, solution: &Self::Solution) -> Vec<Self::Solution> {
+        self.inner.diversify(heuristic_ctx, solution)
+    }
+
+    fn diversify_many(&self, heuristic_ctx: &Self::Context, solutions: Vec<&Self::Solution>) -> Vec<Self::Solution> {
+        self.inner.diversify_many(heuristic_ctx, solutions)
+    }
+
+    fn reset(&mut self) {
+        self.best_known = None;
+        self.trajectory = None;
+        self.inner.reset();
+    }
+}
+
+impl<C, O, S> Display for WarmRestart<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
@@ -71,6 +71,12 @@ pub trait HeuristicSolution: Send + Sync {
     fn fitness(&self) -> impl Iterator<Item = Float>;
     /// Creates a deep copy of the solution.
     fn deep_copy(&self) -> Self;
+
+    /// Returns an approximate amount of memory (in bytes) used by the solution.
+    /// Default implementation returns only the size of the type.
+    fn memory_usage(&self) -> usize {
+        std::mem::size_of_val(self)
+    }
 }
 
 /// Specifies a dynamically dispatched type for heuristic population.
@@ -109,6 +115,10 @@ pub trait HeuristicContext: Send + Sync {
     /// Updates population with a new offspring.
     fn on_generation(&mut self, offspring: Vec<Self::Solution>, termination_estimate: Float, generation_time: Timer);
 
+    /// Informs about a solution produced by the search operator with given name before it is added
+    /// to the population. Default implementation does nothing.
+    fn on_search(&self, _operator_name: &str, _solution: &Self::Solution, _is_improvement: bool) {}
+
     /// Returns final population and telemetry metrics
     fn on_result(self) -> HeuristicResult<Self::Objective, Self::Solution>;
 }
@@ -158,6 +168,8 @@ where
     population: Box<DynHeuristicPopulation<O, S>>,
     telemetry: Telemetry<O, S>,
     environment: Arc<Environment>,
+    memory_baseline: usize,
+    is_memory_exceeded: bool,
 }
 
 impl<O, S> TelemetryHeuristicContext<O, S>
@@ -173,7 +185,14 @@ where
         environment: Arc<Environment>,
     ) -> Self {
         let telemetry = Telemetry::new(telemetry_mode);
-        Self { objective, population, telemetry, environment }
+        Self { objective, population, telemetry, environment, memory_baseline: 0, is_memory_exceeded: false }
+    }
+
+    /// Sets an approximate amount of memory (in bytes) used by structures outside of the population,
+    /// e.g. by problem definition. It is taken into account when memory budget is checked.
+    pub fn with_memory_baseline(mut self, memory_baseline: usize) -> Self {
+        self.memory_baseline = memory_baseline;
+        self
     }
 
     /// Consumes context and returns all individuals.
@@ -185,6 +204,11 @@ where
     pub fn add_solution(&mut self, solution: S) {
         self.population.add(solution);
     }
+
+    /// Reports a named value which is kept in telemetry metrics.
+    pub fn on_property(&mut self, name: &str, value: Float) {
+        self.telemetry.on_property(name, value);
+    }
 }
 
 impl<O, S> HeuristicContext for TelemetryHeuristicContext<O, S>
@@ -228,6 +252,12 @@ where
         let is_improved = self.population.add_all(offspring);
         self.telemetry.on_generation(self.population.as_ref(), termination_estimate, generation_time, is_improved);
         self.population.on_generation(self.telemetry.get_statistics());
+        self.check_memory_usage();
+    }
+
+    fn on_search(&self, operator_name: &str, solution: &Self::Solution, is_improvement: bool) {
+        self.telemetry.on_search(operator_name, is_improvement);
+        self.population.on_search(operator_name, solution, is_improvement)
     }
 
     fn on_result(self) -> Result<(Box<DynHeuristicPopulation<O, S>>, Option<TelemetryMetrics>), GenericError> {
@@ -239,6 +269,46 @@ where
     }
 }
 
+impl<O, S> TelemetryHeuristicContext<O, S>
+where
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    /// Estimates memory usage periodically and shrinks population if memory budget is exceeded.
+    fn check_memory_usage(&mut self) {
+        const CHECK_INTERVAL: usize = 10;
+
+        let memory_budget = self.environment.memory_budget;
+        let generation = self.telemetry.get_statistics().generation;
+
+        if !generation.is_multiple_of(CHECK_INTERVAL) || (memory_budget.is_none() && !self.telemetry.has_metrics()) {
+            return;
+        }
+
+        let memory_usage = self.memory_baseline + self.population.memory_usage();
+        let is_exceeded = memory_budget.is_some_and(|memory_budget| memory_usage > memory_budget);
+        let is_shrunk = is_exceeded && self.population.shrink();
+
+        // NOTE do not spam log when population cannot be shrunk anymore
+        if is_shrunk || (is_exceeded && !self.is_memory_exceeded) {
+            (self.environment.logger)(
+                format!(
+                    "memory usage estimate ({} MiB) exceeds the budget ({} MiB), population is {}",
+                    memory_usage / BYTES_IN_MIB,
+                    memory_budget.unwrap_or_default() / BYTES_IN_MIB,
+                    if is_shrunk { "shrunk" } else { "already minimal" }
+                )
+                .as_str(),
+            );
+        }
+
+        self.is_memory_exceeded = is_exceeded;
+        self.telemetry.on_memory_usage(memory_usage, is_shrunk);
+    }
+}
+
+const BYTES_IN_MIB: usize = 1024 * 1024;
+
 /// Defines instant refinement speed type.
 #[derive(Clone, Debug)]
 pub enum HeuristicSpeed {
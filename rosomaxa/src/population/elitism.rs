@@ -110,6 +110,16 @@ where
     fn selection_phase(&self) -> SelectionPhase {
         SelectionPhase::Exploitation
     }
+
+    fn shrink(&mut self) -> bool {
+        if self.max_population_size < 2 {
+            return false;
+        }
+
+        self.set_max_population_size(self.max_population_size / 2);
+
+        true
+    }
 }
 
 impl<O, S> Elitism<O, S>
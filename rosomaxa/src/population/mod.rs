@@ -65,4 +65,19 @@ pub trait HeuristicPopulation: Send + Sync {
 
     /// Returns a current selection phase.
     fn selection_phase(&self) -> SelectionPhase;
+
+    /// Informs population about an individual produced by the search operator with given name before
+    /// it is added. Default implementation does nothing.
+    fn on_search(&self, _operator_name: &str, _individual: &Self::Individual, _is_improvement: bool) {}
+
+    /// Returns an approximate amount of memory (in bytes) used by the population.
+    fn memory_usage(&self) -> usize {
+        self.iter().map(|individual| individual.memory_usage()).sum()
+    }
+
+    /// Reduces memory used by the population, e.g. by removing the least valuable individuals.
+    /// Returns true if population was shrunk. Default implementation does nothing.
+    fn shrink(&mut self) -> bool {
+        false
+    }
 }
@@ -208,6 +208,33 @@ where
             RosomaxaPhases::Exploitation { .. } => SelectionPhase::Exploitation,
         }
     }
+
+    fn memory_usage(&self) -> usize {
+        let individuals = self.iter().map(|individual| individual.memory_usage()).sum::<usize>();
+
+        individuals
+            + match &self.phase {
+                RosomaxaPhases::Initial { solutions } => solutions.iter().map(|s| s.memory_usage()).sum(),
+                RosomaxaPhases::Exploration { network, .. } => network.memory_usage(),
+                RosomaxaPhases::Exploitation { .. } => 0,
+            }
+    }
+
+    fn shrink(&mut self) -> bool {
+        match &mut self.phase {
+            RosomaxaPhases::Exploration { network, coordinates, .. } => {
+                // NOTE keep only the best individual in each node and reduce amount of nodes
+                network.resize_storages(1);
+                network.compact(&self.external_ctx);
+                network.smooth(&self.external_ctx, 1, |i| i.on_update(&self.external_ctx));
+
+                Self::fill_populations(network, coordinates, self.environment.random.as_ref());
+
+                true
+            }
+            _ => false,
+        }
+    }
 }
 
 type IndividualNetwork<C, O, S> = Network<C, S, IndividualStorage<C, O, S>, IndividualStorageFactory<C, O, S>>;
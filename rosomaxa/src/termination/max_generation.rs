@@ -44,4 +44,8 @@ where
     fn estimate(&self, heuristic_ctx: &Self::Context) -> Float {
         (heuristic_ctx.statistics().generation as Float / self.limit as Float).min(1.)
     }
+
+    fn name(&self) -> &str {
+        "max-generation"
+    }
 }
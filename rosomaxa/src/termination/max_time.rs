@@ -46,4 +46,8 @@ where
     fn estimate(&self, _: &Self::Context) -> Float {
         (self.start.elapsed_secs_as_float() / self.limit_in_secs).min(1.)
     }
+
+    fn name(&self) -> &str {
+        "max-time"
+    }
 }
@@ -92,7 +92,7 @@ where
 
                         result
                     });
-                    values.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+                    values.sort_unstable_by_key(|(a, _)| *a);
                 }
 
                 if *period > elapsed_time || values.len() < 2 {
@@ -161,4 +161,8 @@ where
     fn estimate(&self, _: &Self::Context) -> Float {
         0.
     }
+
+    fn name(&self) -> &str {
+        "min-variation"
+    }
 }
@@ -16,6 +16,16 @@ pub trait Termination: Send + Sync {
 
     /// Returns a relative estimation till termination. Value is in the `[0, 1]` range.
     fn estimate(&self, heuristic_ctx: &Self::Context) -> Float;
+
+    /// Returns a short name of termination criteria which is used to report why search was stopped.
+    fn name(&self) -> &str {
+        "custom"
+    }
+
+    /// Returns a name of termination criteria if termination condition is met.
+    fn check(&self, heuristic_ctx: &mut Self::Context) -> Option<String> {
+        self.is_termination(heuristic_ctx).then(|| self.name().to_string())
+    }
 }
 
 mod min_variation;
@@ -68,4 +78,12 @@ where
     fn estimate(&self, heuristic_ctx: &Self::Context) -> Float {
         self.terminations.iter().map(|t| t.estimate(heuristic_ctx)).max_by(|a, b| a.total_cmp(b)).unwrap_or_default()
     }
+
+    fn name(&self) -> &str {
+        "composite"
+    }
+
+    fn check(&self, heuristic_ctx: &mut Self::Context) -> Option<String> {
+        self.terminations.iter().find_map(|t| t.check(heuristic_ctx))
+    }
 }
@@ -54,4 +54,8 @@ where
     fn estimate(&self, _: &Self::Context) -> Float {
         0.
     }
+
+    fn name(&self) -> &str {
+        "target-proximity"
+    }
 }
@@ -30,6 +30,9 @@ pub struct Environment {
 
     /// A boolean flag which signalizes that experimental behavior is allowed.
     pub is_experimental: bool,
+
+    /// A soft limit (in bytes) of approximate memory usage. When exceeded, population is shrunk.
+    pub memory_budget: Option<usize>,
 }
 
 impl Environment {
@@ -49,7 +52,7 @@ impl Environment {
         logger: InfoLogger,
         is_experimental: bool,
     ) -> Self {
-        Self { random, quota, parallelism, logger, is_experimental }
+        Self { random, quota, parallelism, logger, is_experimental, memory_budget: None }
     }
 }
 
@@ -89,11 +92,12 @@ impl Quota for TimeQuota {
 pub struct Parallelism {
     available_cpus: usize,
     thread_pools: Option<Arc<Vec<ThreadPool>>>,
+    is_deterministic: bool,
 }
 
 impl Default for Parallelism {
     fn default() -> Self {
-        Self { available_cpus: get_cpus(), thread_pools: None }
+        Self { available_cpus: get_cpus(), thread_pools: None, is_deterministic: false }
     }
 }
 
@@ -101,7 +105,7 @@ impl Parallelism {
     /// Creates an instance of `Parallelism`.
     pub fn new(num_thread_pools: usize, threads_per_pool: usize) -> Self {
         let thread_pools = (0..num_thread_pools).map(|_| ThreadPool::new(threads_per_pool)).collect();
-        Self { available_cpus: get_cpus(), thread_pools: Some(Arc::new(thread_pools)) }
+        Self { available_cpus: get_cpus(), thread_pools: Some(Arc::new(thread_pools)), is_deterministic: false }
     }
 
     /// Creates an instance of `Parallelism` using available cpus as given.
@@ -109,6 +113,17 @@ impl Parallelism {
         Self { available_cpus, ..Self::default() }
     }
 
+    /// Sets deterministic mode which makes results of parallel operations independent from thread
+    /// scheduling. See [`with_deterministic_mode`](crate::utils::with_deterministic_mode) for details.
+    pub fn with_deterministic(self, is_deterministic: bool) -> Self {
+        Self { is_deterministic, ..self }
+    }
+
+    /// Returns true if deterministic mode is enabled.
+    pub fn is_deterministic(&self) -> bool {
+        self.is_deterministic
+    }
+
     /// Amount of total available CPUs.
     pub fn available_cpus(&self) -> usize {
         self.available_cpus
@@ -10,8 +10,40 @@ pub use self::actual::parallel_collect;
 pub use self::actual::parallel_foreach_mut;
 pub use self::actual::parallel_into_collect;
 
+use crate::utils::random::TaskSeed;
+use std::cell::Cell;
+
+thread_local! {
+    /// Keeps track whether deterministic mode is enabled on the current thread.
+    static IS_DETERMINISTIC: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Runs given function in deterministic mode: results of parallel operations do not depend on thread
+/// scheduling. Each parallel task uses its own repeatable random generator derived from the caller's one
+/// and reduce operations are applied in the order of the source collection.
+/// NOTE it makes runs reproducible only when repeatable random generator is used.
+pub fn with_deterministic_mode<R>(func: impl FnOnce() -> R) -> R {
+    let is_deterministic = IS_DETERMINISTIC.replace(true);
+    let result = func();
+    IS_DETERMINISTIC.set(is_deterministic);
+
+    result
+}
+
+/// Returns true if deterministic mode is enabled on the current thread.
+pub fn is_deterministic_mode() -> bool {
+    IS_DETERMINISTIC.get()
+}
+
+/// Runs a parallel task in deterministic mode using given random seed.
+#[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+fn run_task<R>(seed: TaskSeed, func: impl FnOnce() -> R) -> R {
+    with_deterministic_mode(|| seed.run(func))
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 mod actual {
+    use super::{TaskSeed, is_deterministic_mode, run_task};
     use rayon::prelude::*;
     use rayon::{ThreadPool as RayonThreadPool, ThreadPoolBuilder};
 
@@ -34,7 +66,12 @@ mod actual {
             OP: FnOnce() -> R + Send,
             R: Send,
         {
-            self.inner.install(op)
+            if is_deterministic_mode() {
+                let seed = TaskSeed::new();
+                self.inner.install(|| run_task(seed, op))
+            } else {
+                self.inner.install(op)
+            }
         }
     }
 
@@ -55,7 +92,11 @@ mod actual {
         FM: Fn(T) -> R + Sync + Send,
         R: Send,
     {
-        source.into_par_iter().map(map_op).collect()
+        if is_deterministic_mode() {
+            deterministic_map(source.into_par_iter().collect(), map_op)
+        } else {
+            source.into_par_iter().map(map_op).collect()
+        }
     }
 
     /// Maps collection and collects results into vector in parallel.
@@ -65,7 +106,11 @@ mod actual {
         F: Fn(T) -> R + Sync + Send,
         R: Send,
     {
-        source.into_par_iter().map(map_op).collect()
+        if is_deterministic_mode() {
+            deterministic_map(source, map_op)
+        } else {
+            source.into_par_iter().map(map_op).collect()
+        }
     }
 
     /// Performs map reduce operations in parallel.
@@ -78,7 +123,11 @@ mod actual {
         FD: Fn() -> R + Sync + Send,
         R: Send,
     {
-        source.par_iter().map(map_op).reduce(default_op, reduce_op)
+        if is_deterministic_mode() {
+            deterministic_map(source.par_iter().collect(), map_op).into_iter().fold(default_op(), reduce_op)
+        } else {
+            source.par_iter().map(map_op).reduce(default_op, reduce_op)
+        }
     }
 
     /// Performs fold and then reduce operations in parallel.
@@ -91,7 +140,13 @@ mod actual {
         FR: Fn(R, R) -> R + Sync + Send,
         R: Send,
     {
-        source.into_par_iter().fold(identity.clone(), fold).reduce(identity, reduce)
+        if is_deterministic_mode() {
+            deterministic_map(source.into_par_iter().collect(), |item| fold(identity(), item))
+                .into_iter()
+                .fold(identity(), reduce)
+        } else {
+            source.into_par_iter().fold(identity.clone(), fold).reduce(identity, reduce)
+        }
     }
 
     /// Performs mutable foreach in parallel.
@@ -100,7 +155,23 @@ mod actual {
         T: Send + Sync,
         F: Fn(&mut T) + Send + Sync,
     {
-        source.par_iter_mut().for_each(action)
+        if is_deterministic_mode() {
+            let seed = TaskSeed::new();
+            source.par_iter_mut().enumerate().for_each(|(idx, item)| run_task(seed.for_task(idx), || action(item)))
+        } else {
+            source.par_iter_mut().for_each(action)
+        }
+    }
+
+    /// Maps items in parallel keeping their order and running each map operation with its own random seed.
+    fn deterministic_map<T, F, R>(items: Vec<T>, map_op: F) -> Vec<R>
+    where
+        T: Send,
+        F: Fn(T) -> R + Sync + Send,
+        R: Send,
+    {
+        let seed = TaskSeed::new();
+        items.into_par_iter().enumerate().map(|(idx, item)| run_task(seed.for_task(idx), || map_op(item))).collect()
     }
 }
 
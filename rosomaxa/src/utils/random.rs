@@ -2,7 +2,7 @@
 #[path = "../../tests/unit/utils/random_test.rs"]
 mod random_test;
 
-use crate::utils::Float;
+use crate::utils::{Float, is_deterministic_mode};
 use rand::Error;
 use rand::prelude::*;
 use rand_distr::{Gamma, Normal};
@@ -40,6 +40,12 @@ pub trait Random: Send + Sync {
 
     /// Returns RNG.
     fn get_rng(&self) -> RandomGen;
+
+    /// Returns true if random values are generated in repeatable (predictable) way. Such generators
+    /// keep their state per thread, so the caller should avoid consuming them from parallel code.
+    fn is_repeatable(&self) -> bool {
+        false
+    }
 }
 
 /// Provides way to sample from different distributions.
@@ -80,13 +86,18 @@ impl DistributionSampler for DefaultDistributionSampler {
 /// A default random implementation.
 #[derive(Default)]
 pub struct DefaultRandom {
-    use_repeatable: bool,
+    seed: Option<u64>,
 }
 
 impl DefaultRandom {
     /// Creates an instance of `DefaultRandom` with repeatable (predictable) random generation.
     pub fn new_repeatable() -> Self {
-        Self { use_repeatable: true }
+        Self { seed: Some(0) }
+    }
+
+    /// Creates an instance of `DefaultRandom` with repeatable random generation seeded with given value.
+    pub fn new_with_seed(seed: u64) -> Self {
+        Self { seed: Some(seed) }
     }
 }
 
@@ -129,7 +140,11 @@ impl Random for DefaultRandom {
     }
 
     fn get_rng(&self) -> RandomGen {
-        RandomGen { use_repeatable: self.use_repeatable }
+        RandomGen { seed: self.seed }
+    }
+
+    fn is_repeatable(&self) -> bool {
+        self.seed.is_some()
     }
 }
 
@@ -137,60 +152,103 @@ thread_local! {
     /// Random generator seeded from thread_rng to make runs non-repeatable.
     static RANDOMIZED_RNG: RefCell<SmallRng> = RefCell::new(SmallRng::from_rng(thread_rng()).expect("cannot get RNG from thread rng"));
 
-    /// Random generator seeded with 0 SmallRng to make runs repeatable.
-    static REPEATABLE_RNG: RefCell<SmallRng> = RefCell::new(SmallRng::seed_from_u64(0));
+    /// Random generator seeded with fixed value (0 by default) to make runs repeatable.
+    static REPEATABLE_RNG: RefCell<(u64, SmallRng)> = RefCell::new((0, SmallRng::seed_from_u64(0)));
 }
 
 /// Provides underlying random generator API.
 #[derive(Clone, Debug)]
 pub struct RandomGen {
-    use_repeatable: bool,
+    seed: Option<u64>,
 }
 
 impl RandomGen {
     /// Creates an instance of `RandomGen` using random generator with fixed seed.
     pub fn new_repeatable() -> Self {
-        Self { use_repeatable: true }
+        Self { seed: Some(0) }
+    }
+
+    /// Creates an instance of `RandomGen` using random generator with given seed.
+    pub fn new_seeded(seed: u64) -> Self {
+        Self { seed: Some(seed) }
     }
 
     /// Creates an instance of `RandomGen` using random generator with randomized seed.
     pub fn new_randomized() -> Self {
-        Self { use_repeatable: false }
+        Self { seed: None }
     }
-}
 
-impl RngCore for RandomGen {
-    fn next_u32(&mut self) -> u32 {
+    fn with_rng<R>(&self, func: impl FnOnce(&mut SmallRng) -> R) -> R {
         // NOTE use 'likely!' macro for better branch prediction once it is stabilized?
-        if self.use_repeatable {
-            REPEATABLE_RNG.with(|t| t.borrow_mut().next_u32())
+        if let Some(seed) = self.seed {
+            REPEATABLE_RNG.with(|t| {
+                let (current_seed, rng) = &mut *t.borrow_mut();
+                // NOTE generator is re-created only when a different seed is requested
+                if *current_seed != seed {
+                    *current_seed = seed;
+                    *rng = SmallRng::seed_from_u64(seed);
+                }
+
+                func(rng)
+            })
+        } else if is_deterministic_mode() {
+            // NOTE randomized generator can be created outside of solver (e.g. by problem reader), so use
+            // the repeatable one instead to keep the run reproducible
+            REPEATABLE_RNG.with(|t| func(&mut t.borrow_mut().1))
         } else {
-            RANDOMIZED_RNG.with(|t| t.borrow_mut().next_u32())
+            RANDOMIZED_RNG.with(|t| func(&mut t.borrow_mut()))
         }
     }
+}
+
+/// Specifies a seed of repeatable random generator used by a parallel task in deterministic mode.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+pub(crate) struct TaskSeed {
+    key: u64,
+    value: u64,
+}
+
+impl TaskSeed {
+    /// Creates a new task seed using repeatable random generator of the current thread.
+    pub fn new() -> Self {
+        REPEATABLE_RNG.with(|t| {
+            let (key, rng) = &mut *t.borrow_mut();
+            Self { key: *key, value: rng.next_u64() }
+        })
+    }
+
+    /// Returns a seed for the task with given index.
+    pub fn for_task(self, idx: usize) -> Self {
+        Self { key: self.key, value: self.value ^ (idx as u64).wrapping_add(1).wrapping_mul(0x9E37_79B9_7F4A_7C15) }
+    }
+
+    /// Runs given function with repeatable random generator of the current thread temporarily
+    /// replaced by the one created from the seed.
+    pub fn run<R>(self, func: impl FnOnce() -> R) -> R {
+        let original = REPEATABLE_RNG.replace((self.key, SmallRng::seed_from_u64(self.value)));
+        let result = func();
+        REPEATABLE_RNG.set(original);
+
+        result
+    }
+}
+
+impl RngCore for RandomGen {
+    fn next_u32(&mut self) -> u32 {
+        self.with_rng(|rng| rng.next_u32())
+    }
 
     fn next_u64(&mut self) -> u64 {
-        if self.use_repeatable {
-            REPEATABLE_RNG.with(|t| t.borrow_mut().next_u64())
-        } else {
-            RANDOMIZED_RNG.with(|t| t.borrow_mut().next_u64())
-        }
+        self.with_rng(|rng| rng.next_u64())
     }
 
     fn fill_bytes(&mut self, dest: &mut [u8]) {
-        if self.use_repeatable {
-            REPEATABLE_RNG.with(|t| t.borrow_mut().fill_bytes(dest))
-        } else {
-            RANDOMIZED_RNG.with(|t| t.borrow_mut().fill_bytes(dest))
-        }
+        self.with_rng(|rng| rng.fill_bytes(dest))
     }
 
     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
-        if self.use_repeatable {
-            REPEATABLE_RNG.with(|t| t.borrow_mut().try_fill_bytes(dest))
-        } else {
-            RANDOMIZED_RNG.with(|t| t.borrow_mut().try_fill_bytes(dest))
-        }
+        self.with_rng(|rng| rng.try_fill_bytes(dest))
     }
 }
 
@@ -480,3 +480,23 @@ fn can_create_new_network_empty_regions() {
     let failure_fraction = failed_pairs as f64 / total_pairs as f64;
     assert!(failure_fraction < 0.1, "Too many node pairs are too close: {failure_fraction}");
 }
+
+#[test]
+fn can_use_min_max_weights_to_calculate_normalized_distance() {
+    let (left, right) = (vec![1., 10., 5.], vec![3., 20., 5.]);
+    let mut min_max = MinMaxWeights::new(3);
+    let distance_fn = |min_max: &MinMaxWeights| super::euclidian_distance(&left, &right, min_max);
+
+    assert!((distance_fn(&min_max) - euclidian_distance(&left, &right)).abs() < 1E-9);
+
+    min_max.update(&[0., 0., 5.]);
+    min_max.update(&[4., 40., 5.]);
+    let expected = euclidian_distance(
+        &normalize(&left, &min_max).collect::<Vec<_>>(),
+        &normalize(&right, &min_max).collect::<Vec<_>>(),
+    );
+    assert!((distance_fn(&min_max) - expected).abs() < 1E-9);
+
+    min_max.reset();
+    assert!((distance_fn(&min_max) - euclidian_distance(&left, &right)).abs() < 1E-9);
+}
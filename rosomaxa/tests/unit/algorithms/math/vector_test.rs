@@ -0,0 +1,41 @@
+use super::*;
+
+fn create_vector(size: usize, offset: Float) -> Vec<Float> {
+    (0..size).map(|idx| (idx as Float + offset) * 0.5).collect()
+}
+
+#[test]
+fn can_calculate_sum() {
+    (0..20).for_each(|size| {
+        let values = create_vector(size, 1.);
+
+        assert!((sum(values.as_slice()) - values.iter().sum::<Float>()).abs() < 1E-9);
+    });
+}
+
+#[test]
+fn can_calculate_euclidean_distance() {
+    assert_eq!(euclidean_distance(&[], &[]), 0.);
+    assert_eq!(euclidean_distance(&[1., 2., 3.], &[1., 2., 3.]), 0.);
+    assert!((euclidean_distance(&[0., 0.], &[3., 4.]) - 5.).abs() < 1E-9);
+
+    (0..20).for_each(|size| {
+        let (a, b) = (create_vector(size, 0.), create_vector(size, 2.));
+        let expected = (size as Float).sqrt();
+
+        assert!((euclidean_distance(a.as_slice(), b.as_slice()) - expected).abs() < 1E-9);
+    });
+}
+
+#[test]
+fn can_calculate_scaled_euclidean_distance() {
+    (0..20).for_each(|size| {
+        let (a, b) = (create_vector(size, 0.), create_vector(size, 2.));
+        let scales = (0..size).map(|idx| if idx % 3 == 0 { 0. } else { 2. }).collect::<Vec<_>>();
+        let expected = (size - size.div_ceil(3)) as Float;
+
+        let actual = scaled_euclidean_distance(a.as_slice(), b.as_slice(), scales.as_slice());
+
+        assert!((actual - expected.sqrt() * 2.).abs() < 1E-9);
+    });
+}
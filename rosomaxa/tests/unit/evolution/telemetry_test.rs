@@ -1,7 +1,8 @@
 use super::*;
 use crate::example::*;
 use crate::helpers::example::create_example_objective;
-use crate::{get_default_population, get_default_selection_size};
+use crate::population::Elitism;
+use crate::{TelemetryHeuristicContext, get_default_population, get_default_selection_size};
 use std::sync::Arc;
 
 fn compare_statistic(statistics: &HeuristicStatistics, expected: (usize, Float, Float)) {
@@ -38,3 +39,85 @@ fn can_update_statistic() {
     telemetry.on_generation(population, 0., Timer::start(), true);
     compare_statistic(telemetry.get_statistics(), (1000, 2. / 1001., 0.001));
 }
+
+#[test]
+fn can_collect_best_fitness_and_operator_usage() {
+    let environment = Arc::new(Environment::default());
+    let objective = create_example_objective();
+    let selection_size = get_default_selection_size(environment.as_ref());
+    let mut population = get_default_population(objective.clone(), VectorRosomaxaContext, environment, selection_size);
+    population.add(VectorSolution::new_with_objective(vec![1., 1.], objective.as_ref()));
+    let population = population.as_ref();
+
+    let mut telemetry = Telemetry::new(TelemetryMode::OnlyMetrics { track_population: 1000 });
+    telemetry.on_generation(population, 0., Timer::start(), true);
+    telemetry.on_generation(population, 0., Timer::start(), false);
+    telemetry.on_generation(population, 0., Timer::start(), true);
+    telemetry.on_search("b", false);
+    telemetry.on_search("a", true);
+    telemetry.on_search("b", true);
+    telemetry.on_result(population);
+
+    let metrics = telemetry.take_metrics().expect("no metrics");
+    assert_eq!(metrics.best_fitness.iter().map(|sample| sample.generation).collect::<Vec<_>>(), vec![0, 2]);
+    assert_eq!(
+        metrics.operators,
+        vec![
+            TelemetryOperator { name: "a".to_string(), calls: 1, improvements: 1 },
+            TelemetryOperator { name: "b".to_string(), calls: 2, improvements: 1 },
+        ]
+    );
+}
+
+#[test]
+fn can_skip_operator_usage_without_metrics() {
+    let environment = Arc::new(Environment::default());
+    let objective = create_example_objective();
+    let selection_size = get_default_selection_size(environment.as_ref());
+    let population = get_default_population(objective.clone(), VectorRosomaxaContext, environment, selection_size);
+
+    let mut telemetry = Telemetry::new(TelemetryMode::None);
+    telemetry.on_search("a", true);
+    telemetry.on_result(population.as_ref());
+
+    assert!(telemetry.get_operators().is_empty());
+}
+
+#[test]
+fn can_keep_reported_properties() {
+    let mut telemetry =
+        Telemetry::<VectorObjective, VectorSolution>::new(TelemetryMode::OnlyMetrics { track_population: 1000 });
+
+    telemetry.on_property("a", 1.);
+    telemetry.on_property("b", 2.);
+    telemetry.on_property("a", 3.);
+
+    let metrics = telemetry.take_metrics().expect("no metrics");
+    assert_eq!(metrics.properties, vec![("a".to_string(), 3.), ("b".to_string(), 2.)]);
+}
+
+#[test]
+fn can_shrink_population_when_memory_budget_exceeded() {
+    let environment =
+        Arc::new(Environment { memory_budget: Some(1), logger: Arc::new(|_| {}), ..Environment::default() });
+    let objective = create_example_objective();
+    let population = Box::new(Elitism::new(objective.clone(), environment.random.clone(), 4, 1));
+    let mut context = TelemetryHeuristicContext::new(
+        objective.clone(),
+        population,
+        TelemetryMode::OnlyMetrics { track_population: 1000 },
+        environment,
+    );
+    [[0., 0.], [0.5, 0.5], [-0.5, -0.5], [1., 1.]].into_iter().for_each(|data| {
+        context.on_initial(VectorSolution::new_with_objective(data.to_vec(), objective.as_ref()), Timer::start())
+    });
+    assert_eq!(context.ranked().count(), 4);
+
+    context.on_generation(vec![], 0., Timer::start());
+
+    assert_eq!(context.ranked().count(), 2);
+    let (_, metrics) = context.on_result().expect("cannot get result");
+    let metrics = metrics.expect("no metrics");
+    assert_eq!(metrics.memory_shrinks, 1);
+    assert!(metrics.memory_peak.is_some_and(|memory_peak| memory_peak > 0));
+}
@@ -0,0 +1,150 @@
+use super::*;
+use crate::example::{VectorContext, VectorObjective, VectorSolution};
+use crate::helpers::example::create_heuristic_context_with_solutions;
+use crate::utils::Timer;
+use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+
+struct FakeHyperHeuristic {
+    resets: Arc<AtomicUsize>,
+    is_improving: bool,
+}
+
+impl HyperHeuristic for FakeHyperHeuristic {
+    type Context = VectorContext;
+    type Objective = VectorObjective;
+    type Solution = VectorSolution;
+
+    fn search(&mut self, _: &Self::Context, solution: &Self::Solution) -> Vec<Self::Solution> {
+        vec![solution.deep_copy()]
+    }
+
+    fn search_many(&mut self, heuristic_ctx: &Self::Context, solutions: Vec<&Self::Solution>) -> Vec<Self::Solution> {
+        solutions
+            .into_iter()
+            .map(|solution| {
+                if self.is_improving {
+                    // NOTE moves solution halfway to the optimum of example objective
+                    let data = solution.data.iter().map(|value| (value + 1.) / 2.).collect();
+                    VectorSolution::new_with_objective(data, heuristic_ctx.objective())
+                } else {
+                    solution.deep_copy()
+                }
+            })
+            .collect()
+    }
+
+    fn diversify(&self, _: &Self::Context, _: &Self::Solution) -> Vec<Self::Solution> {
+        vec![]
+    }
+
+    fn diversify_many(&self, _: &Self::Context, _: Vec<&Self::Solution>) -> Vec<Self::Solution> {
+        vec![]
+    }
+
+    fn reset(&mut self) {
+        self.resets.fetch_add(1, AtomicOrdering::Relaxed);
+    }
+}
+
+impl Display for FakeHyperHeuristic {
+    fn fmt(&self, _: &mut Formatter<'_>) -> std::fmt::Result {
+        Ok(())
+    }
+}
+
+struct PerturbOperator;
+
+impl HeuristicSearchOperator for PerturbOperator {
+    type Context = VectorContext;
+    type Objective = VectorObjective;
+    type Solution = VectorSolution;
+
+    fn search(&self, heuristic_ctx: &Self::Context, solution: &Self::Solution) -> Self::Solution {
+        let data = solution.data.iter().map(|value| value - 10.).collect();
+        VectorSolution::new_with_objective(data, heuristic_ctx.objective())
+    }
+}
+
+fn create_warm_restart(
+    stagnation_window: usize,
+    trajectory_size: usize,
+    is_improving: bool,
+) -> (WarmRestart<VectorContext, VectorObjective, VectorSolution>, Arc<AtomicUsize>) {
+    let resets = Arc::new(AtomicUsize::new(0));
+    let heuristic = WarmRestart::new(
+        Box::new(FakeHyperHeuristic { resets: resets.clone(), is_improving }),
+        Arc::new(PerturbOperator),
+        stagnation_window,
+        trajectory_size,
+    );
+
+    (heuristic, resets)
+}
+
+parameterized_test! {can_restart_after_stagnation_window, (generations, trajectory_size, expected_restarts, expected_trajectory), {
+    can_restart_after_stagnation_window_impl(generations, trajectory_size, expected_restarts, expected_trajectory);
+}}
+
+can_restart_after_stagnation_window! {
+    case01_single_generation_trajectory: (5, 1, 1, vec![4]),
+    case02_long_trajectory: (7, 2, 1, vec![4, 5]),
+    case03_restart_after_trajectory: (9, 2, 2, vec![4, 5, 8]),
+}
+
+fn can_restart_after_stagnation_window_impl(
+    generations: usize,
+    trajectory_size: usize,
+    expected_restarts: usize,
+    expected_trajectory: Vec<usize>,
+) {
+    let mut heuristic_ctx = create_heuristic_context_with_solutions(vec![vec![0., 0.]]);
+    let (mut heuristic, resets) = create_warm_restart(3, trajectory_size, false);
+    let solution = heuristic_ctx.ranked().next().unwrap().deep_copy();
+
+    let trajectory = (0..generations)
+        .filter(|_| {
+            let offspring = heuristic.search_many(&heuristic_ctx, vec![&solution]);
+            heuristic_ctx.on_generation(vec![], 0., Timer::start());
+            // NOTE perturbed solution is returned together with the offspring
+            offspring.len() == 2 && offspring.iter().all(|solution| solution.data[0] < 0.)
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(heuristic.restarts(), expected_restarts);
+    assert_eq!(resets.load(AtomicOrdering::Relaxed), expected_restarts);
+    assert_eq!(trajectory, expected_trajectory);
+}
+
+#[test]
+fn can_skip_restart_when_search_improves() {
+    let mut heuristic_ctx = create_heuristic_context_with_solutions(vec![vec![0., 0.]]);
+    let (mut heuristic, resets) = create_warm_restart(2, 1, false);
+    let objective = heuristic_ctx.objective().clone();
+
+    [vec![0.2, 0.04], vec![0.5, 0.25], vec![0.8, 0.64], vec![1., 1.]].into_iter().for_each(|data| {
+        let solution = heuristic_ctx.ranked().next().unwrap().deep_copy();
+        heuristic.search_many(&heuristic_ctx, vec![&solution]);
+        heuristic_ctx.on_generation(vec![VectorSolution::new_with_objective(data, &objective)], 0., Timer::start());
+    });
+
+    assert_eq!(heuristic.restarts(), 0);
+    assert_eq!(resets.load(AtomicOrdering::Relaxed), 0);
+}
+
+#[test]
+fn can_continue_restart_trajectory_from_improved_solution() {
+    let mut heuristic_ctx = create_heuristic_context_with_solutions(vec![vec![1., 1.]]);
+    let (mut heuristic, _) = create_warm_restart(1, 3, true);
+    let solution = heuristic_ctx.ranked().next().unwrap().deep_copy();
+
+    let starts = (0..5)
+        .map(|_| {
+            let offspring = heuristic.search_many(&heuristic_ctx, vec![&solution]);
+            heuristic_ctx.on_generation(vec![], 0., Timer::start());
+            offspring.last().map(|solution| solution.data[0]).unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(heuristic.restarts(), 1);
+    assert_eq!(starts, vec![1., 1., -9., -4., -1.5]);
+}
@@ -151,3 +151,24 @@ fn can_detect_improvement_impl(new_individuals: Vec<Vec<Float>>, expected: bool)
         expected
     )
 }
+
+#[test]
+fn can_shrink_population() {
+    let (objective, mut population) = create_objective_population(4, 1);
+    population.add_all(
+        [[0., 0.], [0.5, 0.5], [-0.5, -0.5], [1., 1.]]
+            .into_iter()
+            .map(|data| VectorSolution::new_with_objective(data.to_vec(), objective.as_ref()))
+            .collect(),
+    );
+    assert_eq!(population.size(), 4);
+
+    assert!(population.shrink());
+    assert_eq!(get_all_fitness(&population), &[0., 1.]);
+
+    assert!(population.shrink());
+    assert_eq!(population.size(), 1);
+
+    assert!(!population.shrink());
+    assert_eq!(population.size(), 1);
+}
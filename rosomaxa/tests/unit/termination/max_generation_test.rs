@@ -23,3 +23,18 @@ fn can_detect_termination_impl(generation: usize, limit: usize, expected: bool)
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn can_report_termination_reason() {
+    let mut context = create_default_heuristic_context();
+    let termination = CompositeTermination::new(vec![
+        Box::new(MaxTime::<_, _, _>::new(300.)),
+        Box::new(MaxGeneration::<_, _, _>::new(2)),
+    ]);
+
+    assert_eq!(termination.check(&mut context), None);
+
+    (0..=2).for_each(|_| context.on_generation(vec![], 0.1, Timer::start()));
+
+    assert_eq!(termination.check(&mut context), Some("max-generation".to_string()));
+}
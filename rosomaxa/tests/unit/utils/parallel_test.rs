@@ -1,4 +1,5 @@
 use super::*;
+use crate::utils::{DefaultRandom, Random};
 use std::collections::HashMap;
 
 #[test]
@@ -29,3 +30,59 @@ fn can_use_map_reduce_for_slice() {
 
     assert_eq!(result, 6);
 }
+
+#[test]
+fn can_keep_source_order_in_deterministic_mode() {
+    let source = (0..100).collect::<Vec<_>>();
+
+    let (collected, reduced, folded) = with_deterministic_mode(|| {
+        assert!(is_deterministic_mode());
+        (
+            parallel_collect(&source, |item| *item),
+            map_reduce(
+                &source,
+                |item| vec![*item],
+                Vec::new,
+                |mut a, b| {
+                    a.extend(b);
+                    a
+                },
+            ),
+            fold_reduce(
+                source.clone(),
+                Vec::new,
+                |mut acc, item| {
+                    acc.push(item);
+                    acc
+                },
+                |mut a, b| {
+                    a.extend(b);
+                    a
+                },
+            ),
+        )
+    });
+
+    assert!(!is_deterministic_mode());
+    assert_eq!(collected, source);
+    assert_eq!(reduced, source);
+    assert_eq!(folded, source);
+}
+
+#[test]
+fn can_use_repeatable_random_per_task_in_deterministic_mode() {
+    let run = |seed: u64| {
+        let random = DefaultRandom::new_with_seed(seed);
+        // NOTE generator is re-seeded only when a different seed is requested
+        random.uniform_int(0, 1);
+        with_deterministic_mode(|| parallel_into_collect((0..32).collect(), |_| random.uniform_int(0, 1_000_000)))
+    };
+
+    let first = run(1);
+    let second = run(2);
+    let third = run(1);
+
+    assert_eq!(first, third);
+    assert_ne!(first, second);
+    assert!(first.iter().any(|value| *value != first[0]));
+}
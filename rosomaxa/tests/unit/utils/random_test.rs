@@ -20,3 +20,36 @@ fn can_return_weights() {
         assert!((actual_ratio - expected_ratio).abs() < 0.05);
     });
 }
+
+#[test]
+fn can_produce_same_values_with_same_seed() {
+    let sample = |seed: u64| {
+        let random = DefaultRandom::new_with_seed(seed);
+        (0..10).map(|_| random.uniform_int(0, 1000)).collect::<Vec<_>>()
+    };
+
+    let first = sample(42);
+    let other = sample(7);
+    let second = sample(42);
+
+    assert!(DefaultRandom::new_with_seed(42).is_repeatable());
+    assert_ne!(first, other);
+    assert_eq!(first, second);
+}
+
+#[test]
+fn can_use_repeatable_generator_for_randomized_one_in_deterministic_mode() {
+    let sample = |seed: u64| {
+        let repeatable = DefaultRandom::new_with_seed(seed);
+        let randomized = DefaultRandom::default();
+        repeatable.uniform_int(0, 1);
+        crate::utils::with_deterministic_mode(|| (0..10).map(|_| randomized.uniform_int(0, 1000)).collect::<Vec<_>>())
+    };
+
+    let first = sample(42);
+    let other = sample(7);
+    let second = sample(42);
+
+    assert_ne!(first, other);
+    assert_eq!(first, second);
+}
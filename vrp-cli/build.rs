@@ -0,0 +1,36 @@
+fn main() {
+    #[cfg(feature = "proto-format")]
+    compile_format_protos();
+
+    #[cfg(feature = "grpc")]
+    compile_service_protos();
+}
+
+/// Compiles pragmatic format messages using pure rust protobuf compiler, so `protoc` is not required.
+#[cfg(feature = "proto-format")]
+fn compile_format_protos() {
+    const PROTO_PATH: &str = "proto/pragmatic.proto";
+
+    println!("cargo:rerun-if-changed={PROTO_PATH}");
+
+    let file_descriptors = protox::compile([PROTO_PATH], ["proto"]).expect("cannot compile proto files");
+
+    prost_build::Config::new().compile_fds(file_descriptors).expect("cannot generate pragmatic format code");
+}
+
+/// Compiles gRPC service definitions using pure rust protobuf compiler, so `protoc` is not required.
+#[cfg(feature = "grpc")]
+fn compile_service_protos() {
+    const PROTO_PATH: &str = "proto/solver.proto";
+
+    println!("cargo:rerun-if-changed={PROTO_PATH}");
+
+    let file_descriptors = protox::compile([PROTO_PATH], ["proto"]).expect("cannot compile proto files");
+
+    tonic_build::configure()
+        .build_client(true)
+        .build_server(true)
+        .extern_path(".vrp.pragmatic.v1", "crate::extensions::proto::model")
+        .compile_fds(file_descriptors)
+        .expect("cannot generate grpc service code");
+}
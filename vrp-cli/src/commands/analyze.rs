@@ -4,9 +4,12 @@ mod analyze_test;
 
 use super::*;
 use std::sync::Arc;
-use vrp_cli::extensions::analyze::{get_dbscan_clusters, get_k_medoids_clusters};
+use vrp_cli::extensions::analyze::{
+    get_dbscan_clusters, get_k_medoids_clusters, get_problem_statistics, write_problem_statistics,
+};
 use vrp_core::prelude::*;
 use vrp_pragmatic::format::Location as ApiLocation;
+use vrp_pragmatic::format::problem::{deserialize_matrix, deserialize_problem};
 use vrp_pragmatic::format::solution::serialize_named_locations_as_geojson;
 
 const FORMAT_ARG_NAME: &str = "FORMAT";
@@ -16,6 +19,7 @@ const MIN_POINTS_ARG_NAME: &str = "min-points";
 const EPSILON_ARG_NAME: &str = "epsilon";
 const K_ARG_NAME: &str = "k";
 const OUT_RESULT_ARG_NAME: &str = "out-result";
+const OUT_FORMAT_ARG_NAME: &str = "out-format";
 
 pub fn get_analyze_app() -> Command {
     Command::new("analyze")
@@ -96,6 +100,41 @@ pub fn get_analyze_app() -> Command {
                         .required(true),
                 ),
         )
+        .subcommand(
+            Command::new("problem")
+                .about("Prints problem characteristics which help to choose termination budget")
+                .arg(
+                    Arg::new(FORMAT_ARG_NAME)
+                        .help("Specifies input type")
+                        .required(true)
+                        .value_parser(["pragmatic"])
+                        .index(1),
+                )
+                .arg(Arg::new(PROBLEM_ARG_NAME).help("Sets the problem file to use").required(true).index(2))
+                .arg(
+                    Arg::new(MATRIX_ARG_NAME)
+                        .help("Specifies path to file with routing matrix")
+                        .short('m')
+                        .long(MATRIX_ARG_NAME)
+                        .num_args(1..)
+                        .required(false),
+                )
+                .arg(
+                    Arg::new(OUT_FORMAT_ARG_NAME)
+                        .help("Specifies output format")
+                        .long(OUT_FORMAT_ARG_NAME)
+                        .value_parser(["text", "json"])
+                        .default_value("text")
+                        .required(false),
+                )
+                .arg(
+                    Arg::new(OUT_RESULT_ARG_NAME)
+                        .help("Specifies path to the file for result output")
+                        .short('o')
+                        .long(OUT_RESULT_ARG_NAME)
+                        .required(false),
+                ),
+        )
 }
 
 pub fn run_analyze(
@@ -118,6 +157,7 @@ pub fn run_analyze(
                 get_k_medoids_clusters(problem, k.unwrap_or(2))
             })
         }
+        Some(("problem", problem_matches)) => run_problem_statistics(problem_matches, out_writer_func),
         _ => Err("no argument with analyze subcommand was used. Use -h to print help information".into()),
     }
 }
@@ -151,3 +191,32 @@ where
 
     geo_writer.write_all(result.as_bytes()).map_err(|err| format!("cannot write result: '{err}'").into())
 }
+
+fn run_problem_statistics(
+    matches: &ArgMatches,
+    out_writer_func: fn(Option<File>) -> BufWriter<Box<dyn Write>>,
+) -> GenericResult<()> {
+    let problem_path = matches.get_one::<String>(PROBLEM_ARG_NAME).unwrap();
+    let problem = deserialize_problem(BufReader::new(open_file(problem_path, "problem")))
+        .map_err(|errs| format!("cannot read problem: '{errs}'"))?;
+    let matrices = matches
+        .get_many::<String>(MATRIX_ARG_NAME)
+        .map(|paths| {
+            paths
+                .map(|path| deserialize_matrix(BufReader::new(open_file(path, "routing matrix"))))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .transpose()
+        .map_err(|errs| format!("cannot read routing matrix: '{errs}'"))?;
+
+    let statistics = get_problem_statistics(&problem, matrices.as_deref())?;
+
+    let out_result = matches.get_one::<String>(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out result"));
+    let mut out_buffer = out_writer_func(out_result);
+
+    match matches.get_one::<String>(OUT_FORMAT_ARG_NAME).map(String::as_str) {
+        Some("json") => serde_json::to_writer_pretty(&mut out_buffer, &statistics)
+            .map_err(|err| format!("cannot write statistics: '{err}'").into()),
+        _ => write_problem_statistics(&mut out_buffer, &statistics),
+    }
+}
@@ -0,0 +1,162 @@
+#[cfg(test)]
+#[path = "../../tests/unit/commands/batch_test.rs"]
+mod batch_test;
+
+use super::*;
+use std::path::Path;
+use vrp_cli::extensions::solve::batch::{SharedRouting, solve_batch};
+use vrp_cli::extensions::solve::config::{Config, TerminationConfig, read_config};
+use vrp_core::prelude::GenericResult;
+use vrp_pragmatic::format::Location;
+use vrp_pragmatic::format::solution::{PragmaticOutputType, write_pragmatic};
+
+const PROBLEMS_ARG_NAME: &str = "PROBLEMS";
+const MATRIX_ARG_NAME: &str = "matrix";
+const LOCATIONS_ARG_NAME: &str = "locations";
+const CONFIG_ARG_NAME: &str = "config";
+const GENERATIONS_ARG_NAME: &str = "max-generations";
+const TIME_ARG_NAME: &str = "max-time";
+const THREADS_ARG_NAME: &str = "threads";
+const OUT_DIR_ARG_NAME: &str = "out-dir";
+
+pub fn get_batch_app() -> Command {
+    Command::new("batch")
+        .about("Solves multiple pragmatic problems which share the same routing matrix")
+        .arg(Arg::new(PROBLEMS_ARG_NAME).help("Sets problem files").required(true).num_args(1..).index(1))
+        .arg(
+            Arg::new(MATRIX_ARG_NAME)
+                .help("Specifies path to file with shared routing matrix, one per profile")
+                .short('m')
+                .long(MATRIX_ARG_NAME)
+                .num_args(1..)
+                .required(true),
+        )
+        .arg(
+            Arg::new(LOCATIONS_ARG_NAME)
+                .help("Specifies path to file with locations which define shared matrix indices")
+                .long(LOCATIONS_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::new(CONFIG_ARG_NAME)
+                .help("Specifies path to algorithm configuration file")
+                .short('c')
+                .long(CONFIG_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::new(GENERATIONS_ARG_NAME)
+                .help("Specifies maximum number of generations for each problem")
+                .short('n')
+                .long(GENERATIONS_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::new(TIME_ARG_NAME)
+                .help("Specifies max time of algorithm in seconds for each problem")
+                .short('t')
+                .long(TIME_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::new(THREADS_ARG_NAME)
+                .help("Specifies total amount of threads shared by all solves. Default is amount of cpus")
+                .long(THREADS_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::new(OUT_DIR_ARG_NAME)
+                .help("Specifies path to the directory where solutions are written")
+                .short('o')
+                .long(OUT_DIR_ARG_NAME)
+                .required(true),
+        )
+}
+
+pub fn run_batch(matches: &ArgMatches) -> GenericResult<()> {
+    let problem_paths = matches.get_many::<String>(PROBLEMS_ARG_NAME).unwrap().collect::<Vec<_>>();
+    let out_dir = Path::new(matches.get_one::<String>(OUT_DIR_ARG_NAME).unwrap());
+    if !out_dir.is_dir() {
+        return Err(format!("cannot find output directory: '{}'", out_dir.display()).into());
+    }
+
+    let problems = problem_paths
+        .iter()
+        .map(|path| {
+            deserialize_problem(BufReader::new(open_file(path, "problem")))
+                .map_err(|errs| format!("cannot read problem '{path}': '{errs}'").into())
+        })
+        .collect::<GenericResult<Vec<_>>>()?;
+
+    let matrices = matches
+        .get_many::<String>(MATRIX_ARG_NAME)
+        .unwrap()
+        .map(|path| {
+            deserialize_matrix(BufReader::new(open_file(path, "routing matrix")))
+                .map_err(|errs| format!("cannot read matrix '{path}': '{errs}'").into())
+        })
+        .collect::<GenericResult<Vec<_>>>()?;
+
+    let locations = matches
+        .get_one::<String>(LOCATIONS_ARG_NAME)
+        .map(|path| {
+            serde_json::from_reader::<_, Vec<Location>>(BufReader::new(open_file(path, "locations")))
+                .map_err(|err| GenericError::from(format!("cannot read locations: '{err}'")))
+        })
+        .transpose()?;
+
+    let config = get_config(matches)?;
+    let max_threads =
+        parse_int_value::<usize>(matches, THREADS_ARG_NAME, "threads")?.unwrap_or_else(num_cpus::get).max(1);
+
+    let results = solve_batch(problems, &SharedRouting { matrices, locations }, &config, max_threads);
+
+    let failures = problem_paths
+        .iter()
+        .zip(results)
+        .filter_map(|(path, result)| {
+            let out_path = out_dir.join(format!(
+                "{}.solution.json",
+                Path::new(path).file_stem().and_then(|stem| stem.to_str()).unwrap_or("problem")
+            ));
+
+            let result = result.and_then(|(problem, solution)| {
+                let mut out_buffer = BufWriter::new(create_file(out_path.to_str().unwrap_or_default(), "solution"));
+                write_pragmatic(problem.as_ref(), &solution, PragmaticOutputType::default(), &mut out_buffer)
+            });
+
+            result.err().map(|err| eprintln!("cannot solve problem '{path}': '{err}'"))
+        })
+        .count();
+
+    if failures > 0 {
+        Err(format!("{failures} of {} problems are not solved", problem_paths.len()).into())
+    } else {
+        Ok(())
+    }
+}
+
+fn get_config(matches: &ArgMatches) -> GenericResult<Config> {
+    let config = match matches.get_one::<String>(CONFIG_ARG_NAME) {
+        Some(path) => read_config(BufReader::new(open_file(path, "config")))
+            .map_err(|err| format!("cannot read config: '{err}'"))?,
+        None => Config::default(),
+    };
+
+    let max_time = parse_int_value::<usize>(matches, TIME_ARG_NAME, "max time")?;
+    let max_generations = parse_int_value::<usize>(matches, GENERATIONS_ARG_NAME, "max generations")?;
+
+    if max_time.is_none() && max_generations.is_none() {
+        return Ok(config);
+    }
+
+    let termination = config.termination.clone();
+    Ok(Config {
+        termination: Some(TerminationConfig {
+            max_time: max_time.or(termination.as_ref().and_then(|t| t.max_time)),
+            max_generations: max_generations.or(termination.as_ref().and_then(|t| t.max_generations)),
+            variation: termination.and_then(|t| t.variation),
+        }),
+        ..config
+    })
+}
@@ -0,0 +1,165 @@
+#[cfg(test)]
+#[path = "../../tests/unit/commands/benchmark_test.rs"]
+mod benchmark_test;
+
+use super::*;
+
+use clap::ArgAction;
+use std::collections::HashSet;
+use std::path::Path;
+use std::sync::Arc;
+use vrp_cli::extensions::benchmark::*;
+use vrp_cli::extensions::solve::config::{Config, ConfigFormat, read_config_with_format};
+use vrp_cli::extensions::solve::formats::get_formats;
+use vrp_core::prelude::*;
+
+const FORMAT_ARG_NAME: &str = "format";
+const DIR_ARG_NAME: &str = "DIR";
+const RUNS_ARG_NAME: &str = "runs";
+const GENERATIONS_ARG_NAME: &str = "max-generations";
+const TIME_ARG_NAME: &str = "max-time";
+const CONFIG_ARG_NAME: &str = "config";
+const COMPARE_CONFIG_ARG_NAME: &str = "compare-config";
+const OUT_RESULT_ARG_NAME: &str = "out-result";
+const OUT_CSV_ARG_NAME: &str = "out-csv";
+const LOG_ARG_NAME: &str = "log";
+const ROUNDED_ARG_NAME: &str = "round";
+
+pub fn get_benchmark_app() -> Command {
+    Command::new("benchmark")
+        .about("Solves every problem instance in a directory and aggregates statistics of multiple runs")
+        .arg(Arg::new(DIR_ARG_NAME).help("Sets the directory with problem instances").required(true).index(1))
+        .arg(
+            Arg::new(FORMAT_ARG_NAME)
+                .help("Specifies the problem type")
+                .long(FORMAT_ARG_NAME)
+                .required(true)
+                .value_parser(["solomon", "lilim", "pdptw", "tsplib", "cordeau", "pragmatic"]),
+        )
+        .arg(
+            Arg::new(RUNS_ARG_NAME)
+                .help("Specifies amount of runs per instance and configuration")
+                .long(RUNS_ARG_NAME)
+                .required(false)
+                .default_value("1"),
+        )
+        .arg(
+            Arg::new(GENERATIONS_ARG_NAME)
+                .help("Specifies maximum number of generations of each run")
+                .short('n')
+                .long(GENERATIONS_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::new(TIME_ARG_NAME)
+                .help("Specifies max time of each run in seconds")
+                .short('t')
+                .long(TIME_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::new(CONFIG_ARG_NAME)
+                .help("Specifies path to algorithm configuration file")
+                .short('c')
+                .long(CONFIG_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::new(COMPARE_CONFIG_ARG_NAME)
+                .help("Specifies path to another algorithm configuration file to compare with")
+                .long(COMPARE_CONFIG_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::new(OUT_RESULT_ARG_NAME)
+                .help("Specifies path to file for json report output")
+                .short('o')
+                .long(OUT_RESULT_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::new(OUT_CSV_ARG_NAME)
+                .help("Specifies path to file for csv report output")
+                .long(OUT_CSV_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::new(LOG_ARG_NAME)
+                .help("Specifies whether benchmark progress should be logged")
+                .long(LOG_ARG_NAME)
+                .required(false)
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new(ROUNDED_ARG_NAME)
+                .help("Specifies whether costs are rounded. Applicable only for scientific formats.")
+                .long(ROUNDED_ARG_NAME)
+                .required(false)
+                .action(ArgAction::SetTrue),
+        )
+}
+
+/// Runs benchmark command.
+pub fn run_benchmark(
+    matches: &ArgMatches,
+    out_writer_func: fn(Option<File>) -> BufWriter<Box<dyn Write>>,
+) -> GenericResult<()> {
+    let dir = matches.get_one::<String>(DIR_ARG_NAME).unwrap();
+    let format = matches.get_one::<String>(FORMAT_ARG_NAME).unwrap();
+    let runs = parse_int_value::<usize>(matches, RUNS_ARG_NAME, "runs")?.unwrap_or(1);
+    let max_generations = parse_int_value::<usize>(matches, GENERATIONS_ARG_NAME, "max generations")?;
+    let max_time = parse_int_value::<usize>(matches, TIME_ARG_NAME, "max time")?;
+    let is_rounded = matches.get_one::<bool>(ROUNDED_ARG_NAME).copied().unwrap_or(false);
+
+    let logger: InfoLogger = if matches.get_one::<bool>(LOG_ARG_NAME).copied().unwrap_or(false) {
+        Arc::new(|msg: &str| println!("{msg}"))
+    } else {
+        Arc::new(|_: &str| {})
+    };
+
+    let configs = get_benchmark_configs(matches)?;
+    let instances = get_benchmark_instances(Path::new(dir))?;
+
+    let formats = get_formats(is_rounded, Arc::new(DefaultRandom::default()), Default::default());
+    let (problem_reader, ..) = formats.get(format.as_str()).ok_or_else(|| format!("unknown format: '{format}'"))?;
+
+    let settings = BenchmarkSettings { format: format.clone(), runs, max_time, max_generations };
+    let report = vrp_cli::extensions::benchmark::run_benchmark(
+        instances.as_slice(),
+        problem_reader,
+        &configs,
+        &settings,
+        logger,
+    )?;
+
+    if let Some(path) = matches.get_one::<String>(OUT_CSV_ARG_NAME) {
+        write_benchmark_csv(&report, &mut create_write_buffer(Some(create_file(path, "out csv"))))?;
+    }
+
+    let out_result = matches.get_one::<String>(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out result"));
+    write_benchmark_json(&report, &mut out_writer_func(out_result))
+}
+
+fn get_benchmark_configs(matches: &ArgMatches) -> GenericResult<Vec<BenchmarkConfig>> {
+    let mut names = HashSet::new();
+
+    [CONFIG_ARG_NAME, COMPARE_CONFIG_ARG_NAME]
+        .iter()
+        .filter_map(|arg_name| matches.get_one::<String>(arg_name))
+        .map(|path| {
+            let format = ConfigFormat::from_path(Path::new(path));
+            let config = read_config_with_format(BufReader::new(open_file(path, "config")), format)
+                .map_err(|err| format!("cannot read config from '{path}': '{err}'"))?;
+            let name = Path::new(path).file_stem().map(|stem| stem.to_string_lossy().to_string()).unwrap_or_default();
+            let name = if names.insert(name.clone()) { name } else { format!("{name}-{}", names.len() + 1) };
+
+            Ok(BenchmarkConfig { name, config })
+        })
+        .collect::<GenericResult<Vec<_>>>()
+        .map(|mut configs| {
+            if configs.is_empty() {
+                configs.push(BenchmarkConfig { name: "default".to_string(), config: Config::default() });
+            }
+            configs
+        })
+}
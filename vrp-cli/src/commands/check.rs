@@ -3,16 +3,24 @@
 mod check_test;
 
 use super::*;
-use vrp_core::prelude::GenericError;
+use vrp_cli::extensions::check::{check_pragmatic_problem, write_checker_report, write_problem_report};
+use vrp_core::prelude::{Float, GenericError};
 
 const FORMAT_ARG_NAME: &str = "FORMAT";
 const PROBLEM_ARG_NAME: &str = "problem-file";
 const SOLUTION_ARG_NAME: &str = "solution-file";
 const MATRIX_ARG_NAME: &str = "matrix";
+const PROBLEM_FILE_ARG_NAME: &str = "PROBLEM";
+const OUT_REPORT_ARG_NAME: &str = "out-report";
+const TIME_TOLERANCE_ARG_NAME: &str = "time-tolerance";
+const DISTANCE_TOLERANCE_ARG_NAME: &str = "distance-tolerance";
 
 pub fn get_check_app() -> Command {
     Command::new("check")
         .about("Provides the way to check solution feasibility")
+        .args_conflicts_with_subcommands(true)
+        .subcommand_negates_reqs(true)
+        .subcommand(get_check_problem_app())
         .arg(Arg::new(FORMAT_ARG_NAME).help("Specifies input type").required(true).value_parser(["pragmatic"]).index(1))
         .arg(
             Arg::new(PROBLEM_ARG_NAME)
@@ -31,9 +39,95 @@ pub fn get_check_app() -> Command {
                 .required(false)
                 .num_args(1..),
         )
+        .arg(
+            Arg::new(TIME_TOLERANCE_ARG_NAME)
+                .help("Specifies time tolerance in seconds: smaller time mismatches are reported as warnings")
+                .long(TIME_TOLERANCE_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::new(DISTANCE_TOLERANCE_ARG_NAME)
+                .help("Specifies distance tolerance in meters: smaller distance mismatches are reported as warnings")
+                .long(DISTANCE_TOLERANCE_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::new(OUT_REPORT_ARG_NAME)
+                .help("Specifies path to file for check report output in json format")
+                .short('o')
+                .long(OUT_REPORT_ARG_NAME)
+                .required(false),
+        )
+}
+
+fn get_check_problem_app() -> Command {
+    Command::new("problem")
+        .about("Validates problem definition without solving it and reports found issues in json format")
+        .arg(Arg::new(FORMAT_ARG_NAME).help("Specifies input type").required(true).value_parser(["pragmatic"]).index(1))
+        .arg(Arg::new(PROBLEM_FILE_ARG_NAME).help("Sets problem file").required(true).index(2))
+        .arg(
+            Arg::new(MATRIX_ARG_NAME)
+                .help("Specifies path to file with routing matrix")
+                .short('m')
+                .long(MATRIX_ARG_NAME)
+                .required(false)
+                .num_args(1..),
+        )
+        .arg(
+            Arg::new(OUT_REPORT_ARG_NAME)
+                .help("Specifies path to file for report output")
+                .short('o')
+                .long(OUT_REPORT_ARG_NAME)
+                .required(false),
+        )
 }
 
-pub fn run_check(matches: &ArgMatches) -> Result<(), GenericError> {
+pub fn run_check(
+    matches: &ArgMatches,
+    out_writer_func: fn(Option<File>) -> BufWriter<Box<dyn Write>>,
+) -> Result<(), GenericError> {
+    if let Some(("problem", problem_matches)) = matches.subcommand() {
+        return run_check_problem(problem_matches, out_writer_func);
+    }
+
     let input_format = matches.get_one::<String>(FORMAT_ARG_NAME).unwrap();
-    check_solution(matches, input_format, PROBLEM_ARG_NAME, SOLUTION_ARG_NAME, MATRIX_ARG_NAME)
+    let options = CheckerOptions {
+        time_tolerance: get_tolerance(matches, TIME_TOLERANCE_ARG_NAME, "time tolerance")?,
+        distance_tolerance: get_tolerance(matches, DISTANCE_TOLERANCE_ARG_NAME, "distance tolerance")?,
+    };
+
+    let report = check_solution(matches, input_format, PROBLEM_ARG_NAME, SOLUTION_ARG_NAME, MATRIX_ARG_NAME, options)?;
+
+    if let Some(path) = matches.get_one::<String>(OUT_REPORT_ARG_NAME) {
+        write_checker_report(&report, &mut out_writer_func(Some(create_file(path, "out report"))))?;
+    }
+
+    get_check_result(&report)
+}
+
+fn get_tolerance(matches: &ArgMatches, arg_name: &str, arg_desc: &str) -> Result<Float, GenericError> {
+    match parse_float_value::<Float>(matches, arg_name, arg_desc)? {
+        Some(tolerance) if tolerance < 0. || !tolerance.is_finite() => {
+            Err(format!("{arg_desc} should be a non-negative number, got: '{tolerance}'").into())
+        }
+        tolerance => Ok(tolerance.unwrap_or_default()),
+    }
+}
+
+fn run_check_problem(
+    matches: &ArgMatches,
+    out_writer_func: fn(Option<File>) -> BufWriter<Box<dyn Write>>,
+) -> Result<(), GenericError> {
+    let problem_path = matches.get_one::<String>(PROBLEM_FILE_ARG_NAME).unwrap();
+    let problem_reader = BufReader::new(open_file(problem_path, "problem"));
+    let matrices_readers = matches
+        .get_many::<String>(MATRIX_ARG_NAME)
+        .map(|paths| paths.map(|path| BufReader::new(open_file(path, "routing matrix"))).collect());
+
+    let report = check_pragmatic_problem(problem_reader, matrices_readers);
+
+    let out_report = matches.get_one::<String>(OUT_REPORT_ARG_NAME).map(|path| create_file(path, "out report"));
+    write_problem_report(&report, &mut out_writer_func(out_report))?;
+
+    if report.is_valid { Ok(()) } else { Err("problem definition has errors".into()) }
 }
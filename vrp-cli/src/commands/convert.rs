@@ -0,0 +1,131 @@
+#[cfg(test)]
+#[path = "../../tests/unit/commands/convert_test.rs"]
+mod convert_test;
+
+use super::*;
+use std::str::FromStr;
+use vrp_cli::extensions::convert::{ScientificFormat, convert_from_scientific, convert_to_scientific};
+use vrp_core::prelude::GenericResult;
+use vrp_pragmatic::format::problem::{deserialize_matrix, deserialize_problem, serialize_problem, write_binary_matrix};
+use vrp_pragmatic::format::solution::{deserialize_solution, serialize_solution_as_gpx, serialize_solution_as_kml};
+
+const INPUT_ARG_NAME: &str = "INPUT";
+const FROM_FORMAT_ARG_NAME: &str = "from";
+const TO_FORMAT_ARG_NAME: &str = "to";
+const OUT_MATRIX_ARG_NAME: &str = "out-matrix";
+const OUT_RESULT_ARG_NAME: &str = "out-result";
+
+pub fn get_convert_app() -> Command {
+    Command::new("convert")
+        .about(
+            "Converts pragmatic solution into other formats, problem between pragmatic and scientific formats \
+             or pragmatic routing matrix into binary format",
+        )
+        .arg(Arg::new(INPUT_ARG_NAME).help("Sets solution, problem or routing matrix file").required(true).index(1))
+        .arg(
+            Arg::new(FROM_FORMAT_ARG_NAME)
+                .help(
+                    "Specifies source format: solution is expected for kml or gpx target, routing matrix for \
+                     binary-matrix target, problem otherwise",
+                )
+                .long(FROM_FORMAT_ARG_NAME)
+                .required(false)
+                .value_parser(["pragmatic", "solomon", "lilim"])
+                .default_value("pragmatic"),
+        )
+        .arg(
+            Arg::new(TO_FORMAT_ARG_NAME)
+                .help("Specifies target format")
+                .long(TO_FORMAT_ARG_NAME)
+                .required(true)
+                .value_parser(["kml", "gpx", "solomon", "lilim", "pragmatic", "binary-matrix"]),
+        )
+        .arg(
+            Arg::new(OUT_MATRIX_ARG_NAME)
+                .help("Specifies path to the file for routing matrix output when converting to pragmatic problem")
+                .long(OUT_MATRIX_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::new(OUT_RESULT_ARG_NAME)
+                .help("Specifies path to the file for result output")
+                .short('o')
+                .long(OUT_RESULT_ARG_NAME)
+                .required(false),
+        )
+}
+
+pub fn run_convert(
+    matches: &ArgMatches,
+    out_writer_func: fn(Option<File>) -> BufWriter<Box<dyn Write>>,
+) -> GenericResult<()> {
+    let path = matches.get_one::<String>(INPUT_ARG_NAME).unwrap();
+    let from_format = matches.get_one::<String>(FROM_FORMAT_ARG_NAME).map(String::as_str).unwrap_or("pragmatic");
+    let to_format = matches.get_one::<String>(TO_FORMAT_ARG_NAME).map(String::as_str).unwrap_or_default();
+
+    let out_result = matches.get_one::<String>(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out result"));
+
+    match (from_format, to_format) {
+        ("pragmatic", "kml" | "gpx") => {
+            let solution = deserialize_solution(BufReader::new(open_file(path, "solution")))
+                .map_err(|err| GenericError::from(format!("cannot read solution: '{err}'")))?;
+
+            let mut out_buffer = out_writer_func(out_result);
+
+            if to_format == "kml" {
+                serialize_solution_as_kml(&solution, &mut out_buffer)
+            } else {
+                serialize_solution_as_gpx(&solution, &mut out_buffer)
+            }
+            .map_err(|err| GenericError::from(format!("cannot convert solution: '{err}'")))
+        }
+        ("pragmatic", "solomon" | "lilim") => {
+            let problem = deserialize_problem(BufReader::new(open_file(path, "problem")))
+                .map_err(|errs| format!("cannot read problem: '{errs}'"))?;
+
+            let mut out_buffer = out_writer_func(out_result);
+            let dropped = convert_to_scientific(&problem, ScientificFormat::from_str(to_format)?, &mut out_buffer)
+                .map_err(|err| format!("cannot convert problem: '{err}'"))?;
+
+            report_dropped(dropped.as_slice());
+
+            Ok(())
+        }
+        ("pragmatic", "binary-matrix") => {
+            let matrix = deserialize_matrix(BufReader::new(open_file(path, "routing matrix")))
+                .map_err(|errs| format!("cannot read routing matrix: '{errs}'"))?;
+
+            let mut out_buffer = out_writer_func(out_result);
+            write_binary_matrix(&matrix, &mut out_buffer)
+                .map_err(|err| format!("cannot convert matrix: '{err}'").into())
+        }
+        ("solomon" | "lilim", "pragmatic") => {
+            let conversion = convert_from_scientific(
+                BufReader::new(open_file(path, "problem")),
+                ScientificFormat::from_str(from_format)?,
+            )
+            .map_err(|err| format!("cannot convert problem: '{err}'"))?;
+
+            if let Some(matrix_path) = matches.get_one::<String>(OUT_MATRIX_ARG_NAME) {
+                let mut matrix_writer = BufWriter::new(create_file(matrix_path, "out matrix"));
+                serde_json::to_writer_pretty(&mut matrix_writer, &conversion.matrix)
+                    .map_err(|err| format!("cannot write matrix: '{err}'"))?;
+            } else {
+                eprintln!("routing matrix is not written: use '--{OUT_MATRIX_ARG_NAME}' to specify its path");
+            }
+
+            report_dropped(conversion.dropped.as_slice());
+
+            let mut out_buffer = out_writer_func(out_result);
+            serialize_problem(&conversion.problem, &mut out_buffer)
+                .map_err(|err| format!("cannot write problem: '{err}'").into())
+        }
+        _ => Err(format!("conversion from '{from_format}' to '{to_format}' is not supported").into()),
+    }
+}
+
+fn report_dropped(dropped: &[String]) {
+    if !dropped.is_empty() {
+        eprintln!("dropped or approximated features: {}", dropped.join(", "));
+    }
+}
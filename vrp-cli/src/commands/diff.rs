@@ -0,0 +1,47 @@
+#[cfg(test)]
+#[path = "../../tests/unit/commands/diff_test.rs"]
+mod diff_test;
+
+use super::*;
+use vrp_core::prelude::GenericResult;
+use vrp_pragmatic::format::deserialize_solution_compat;
+use vrp_pragmatic::format::solution::{get_solution_diff, serialize_solution_diff};
+
+const OLD_SOLUTION_ARG_NAME: &str = "OLD_SOLUTION";
+const NEW_SOLUTION_ARG_NAME: &str = "NEW_SOLUTION";
+const OUT_RESULT_ARG_NAME: &str = "out-result";
+
+pub fn get_diff_app() -> Command {
+    Command::new("diff")
+        .about("Compares two pragmatic solutions of the same problem")
+        .arg(Arg::new(OLD_SOLUTION_ARG_NAME).help("Sets old solution file").required(true).index(1))
+        .arg(Arg::new(NEW_SOLUTION_ARG_NAME).help("Sets new solution file").required(true).index(2))
+        .arg(
+            Arg::new(OUT_RESULT_ARG_NAME)
+                .help("Specifies path to the file for result output")
+                .short('o')
+                .long(OUT_RESULT_ARG_NAME)
+                .required(false),
+        )
+}
+
+pub fn run_diff(
+    matches: &ArgMatches,
+    out_writer_func: fn(Option<File>) -> BufWriter<Box<dyn Write>>,
+) -> GenericResult<()> {
+    let read_solution = |arg_name: &str, description: &str| {
+        let path = matches.get_one::<String>(arg_name).unwrap();
+        deserialize_solution_compat(BufReader::new(open_file(path, description)))
+            .map_err(|err| GenericError::from(format!("cannot read {description}: '{err}'")))
+    };
+
+    let old_solution = read_solution(OLD_SOLUTION_ARG_NAME, "old solution")?;
+    let new_solution = read_solution(NEW_SOLUTION_ARG_NAME, "new solution")?;
+
+    let diff = get_solution_diff(&old_solution, &new_solution);
+
+    let out_result = matches.get_one::<String>(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out result"));
+    let mut out_buffer = out_writer_func(out_result);
+
+    serialize_solution_diff(&diff, &mut out_buffer)
+}
@@ -4,7 +4,9 @@ mod generate_test;
 
 use super::*;
 use std::io::BufReader;
-use vrp_cli::extensions::generate::generate_problem;
+use vrp_cli::extensions::generate::{
+    DemandDistribution, LocationDistribution, PlanOptions, generate_problem, generate_problem_from_template,
+};
 use vrp_core::prelude::{Float, GenericError};
 use vrp_pragmatic::format::CoordIndex;
 use vrp_pragmatic::format::problem::{Problem, serialize_problem};
@@ -17,6 +19,13 @@ pub const JOBS_SIZE_ARG_NAME: &str = "jobs-size";
 pub const VEHICLES_SIZE_ARG_NAME: &str = "vehicles-size";
 pub const LOCATIONS_ARG_NAME: &str = "locations";
 pub const AREA_SIZE_ARG_NAME: &str = "area-size";
+pub const DISTRIBUTION_ARG_NAME: &str = "distribution";
+pub const CLUSTERS_ARG_NAME: &str = "clusters";
+pub const CLUSTER_RADIUS_ARG_NAME: &str = "cluster-radius";
+pub const TIME_WINDOW_TIGHTNESS_ARG_NAME: &str = "time-window-tightness";
+pub const DEMAND_DISTRIBUTION_ARG_NAME: &str = "demand-distribution";
+pub const SNAP_TO_ARG_NAME: &str = "snap-to";
+pub const TEMPLATE_ARG_NAME: &str = "template";
 
 pub fn get_generate_app() -> Command {
     Command::new("generate")
@@ -27,9 +36,18 @@ pub fn get_generate_app() -> Command {
                 .help("Sets input files which contains a VRP definition prototype")
                 .short('p')
                 .long(PROTOTYPES_ARG_NAME)
-                .required(true)
+                .required_unless_present(TEMPLATE_ARG_NAME)
                 .num_args(1..),
         )
+        .arg(
+            Arg::new(TEMPLATE_ARG_NAME)
+                .help("Specifies scenario template used instead of prototypes")
+                .short('t')
+                .long(TEMPLATE_ARG_NAME)
+                .required(false)
+                .value_parser(["food-delivery", "field-service", "line-haul"])
+                .conflicts_with_all([PROTOTYPES_ARG_NAME, DEMAND_DISTRIBUTION_ARG_NAME]),
+        )
         .arg(
             Arg::new(OUT_RESULT_ARG_NAME)
                 .help("Specifies path to the file for result output")
@@ -65,6 +83,48 @@ pub fn get_generate_app() -> Command {
                 .long(AREA_SIZE_ARG_NAME)
                 .required(false),
         )
+        .arg(
+            Arg::new(DISTRIBUTION_ARG_NAME)
+                .help("Specifies spatial distribution of job locations within bounding box")
+                .short('d')
+                .long(DISTRIBUTION_ARG_NAME)
+                .required(false)
+                .value_parser(["uniform", "clustered", "radial"])
+                .default_value("uniform")
+                .conflicts_with(LOCATIONS_ARG_NAME),
+        )
+        .arg(
+            Arg::new(CLUSTERS_ARG_NAME)
+                .help("Amount of clusters used by clustered distribution. Default is 5")
+                .long(CLUSTERS_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::new(CLUSTER_RADIUS_ARG_NAME)
+                .help("Standard deviation of cluster in meters used by clustered distribution")
+                .long(CLUSTER_RADIUS_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::new(TIME_WINDOW_TIGHTNESS_ARG_NAME)
+                .help("Ratio in (0, 1] to shrink prototype time windows: smaller values produce tighter time windows")
+                .long(TIME_WINDOW_TIGHTNESS_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::new(DEMAND_DISTRIBUTION_ARG_NAME)
+                .help("Specifies how job demands are sampled using prototype demands")
+                .long(DEMAND_DISTRIBUTION_ARG_NAME)
+                .required(false)
+                .value_parser(["prototype", "uniform", "normal"])
+                .default_value("prototype"),
+        )
+        .arg(
+            Arg::new(SNAP_TO_ARG_NAME)
+                .help("Snaps job locations to street network using OSRM server specified as 'osrm:http://host:5000'")
+                .long(SNAP_TO_ARG_NAME)
+                .required(false),
+        )
 }
 
 pub fn run_generate(matches: &ArgMatches) -> Result<(), GenericError> {
@@ -96,14 +156,76 @@ fn generate_problem_from_args(matches: &ArgMatches) -> Result<(Problem, String),
     let jobs_size = parse_int_value::<usize>(matches, JOBS_SIZE_ARG_NAME, "jobs size")?.unwrap();
     let vehicles_size = parse_int_value::<usize>(matches, VEHICLES_SIZE_ARG_NAME, "vehicles size")?.unwrap();
     let area_size = parse_float_value::<Float>(matches, AREA_SIZE_ARG_NAME, "area size")?;
+    let plan_options = get_plan_options(matches)?;
+
+    match matches.get_one::<String>(TEMPLATE_ARG_NAME) {
+        Some(template) => {
+            generate_problem_from_template(template, locations_file, jobs_size, vehicles_size, area_size, &plan_options)
+        }
+        None => generate_problem(
+            input_format,
+            input_files,
+            locations_file,
+            jobs_size,
+            vehicles_size,
+            area_size,
+            &plan_options,
+        ),
+    }
+    .and_then(|problem| match matches.get_one::<String>(SNAP_TO_ARG_NAME) {
+        Some(snap_to) => snap_job_locations(problem, snap_to),
+        None => Ok(problem),
+    })
+    .and_then(|problem| {
+        let coord_index = CoordIndex::new(&problem);
+        ValidationContext::new(&problem, None, &coord_index)
+            .validate()
+            .map_err(|errs| format!("generated problem has some validation errors:\n{errs}",).into())
+            .map(|_| (problem, input_format.to_owned()))
+    })
+}
 
-    generate_problem(input_format, input_files, locations_file, jobs_size, vehicles_size, area_size).and_then(
-        |problem| {
-            let coord_index = CoordIndex::new(&problem);
-            ValidationContext::new(&problem, None, &coord_index)
-                .validate()
-                .map_err(|errs| format!("generated problem has some validation errors:\n{errs}",).into())
-                .map(|_| (problem, input_format.to_owned()))
+fn get_plan_options(matches: &ArgMatches) -> Result<PlanOptions, GenericError> {
+    let location_distribution = match matches.get_one::<String>(DISTRIBUTION_ARG_NAME).map(String::as_str) {
+        Some("clustered") => LocationDistribution::Clustered {
+            clusters: parse_int_value::<usize>(matches, CLUSTERS_ARG_NAME, "clusters")?.unwrap_or(5),
+            radius: parse_float_value::<Float>(matches, CLUSTER_RADIUS_ARG_NAME, "cluster radius")?,
         },
-    )
+        Some("radial") => LocationDistribution::Radial,
+        _ => LocationDistribution::Uniform,
+    };
+
+    let demand_distribution = match matches.get_one::<String>(DEMAND_DISTRIBUTION_ARG_NAME).map(String::as_str) {
+        Some("uniform") => DemandDistribution::Uniform,
+        Some("normal") => DemandDistribution::Normal,
+        _ => DemandDistribution::Prototype,
+    };
+
+    Ok(PlanOptions {
+        location_distribution,
+        time_window_tightness: parse_float_value::<Float>(
+            matches,
+            TIME_WINDOW_TIGHTNESS_ARG_NAME,
+            "time window tightness",
+        )?,
+        demand_distribution,
+    })
+}
+
+#[cfg(feature = "osrm-routing")]
+fn snap_job_locations(problem: Problem, snap_to: &str) -> Result<Problem, GenericError> {
+    use vrp_cli::extensions::routing::OsrmConfig;
+    const OSRM_PREFIX: &str = "osrm:";
+
+    let url = snap_to
+        .strip_prefix(OSRM_PREFIX)
+        .ok_or_else(|| GenericError::from(format!("expecting OSRM server url as 'osrm:url', got: '{snap_to}'")))?;
+
+    vrp_cli::extensions::routing::snap_job_locations(problem, &OsrmConfig::new(url))
+        .map_err(|err| format!("cannot snap locations: '{err}'").into())
+}
+
+#[cfg(not(feature = "osrm-routing"))]
+fn snap_job_locations(_: Problem, _: &str) -> Result<Problem, GenericError> {
+    Err("OSRM routing support is not enabled: build with 'osrm-routing' feature".into())
 }
@@ -1,18 +1,27 @@
 use clap::{Arg, ArgMatches, Command};
 
 pub mod analyze;
+pub mod batch;
+pub mod benchmark;
 pub mod check;
+pub mod convert;
+pub mod diff;
 pub mod generate;
 pub mod import;
+#[cfg(feature = "plot")]
+pub mod plot;
+pub mod scrub;
+pub mod serve;
 pub mod solve;
 
 use std::fs::File;
 use std::io::{BufReader, BufWriter, Read, Write, stdout};
 use std::process;
 use std::str::FromStr;
-use vrp_cli::extensions::check::check_pragmatic_solution;
+use vrp_cli::extensions::check::check_pragmatic_solution_with_report;
 use vrp_core::models::Problem;
 use vrp_core::prelude::GenericError;
+use vrp_pragmatic::checker::{CheckerOptions, CheckerReport};
 use vrp_pragmatic::format::MultiFormatError;
 use vrp_pragmatic::format::problem::{PragmaticProblem, deserialize_matrix, deserialize_problem};
 
@@ -73,7 +82,8 @@ fn check_solution(
     problem_arg_name: &str,
     solution_arg_name: &str,
     matrix_arg_name: &str,
-) -> Result<(), GenericError> {
+    options: CheckerOptions,
+) -> Result<CheckerReport, GenericError> {
     let problem_files = matches
         .get_many::<String>(problem_arg_name)
         .map(|paths| paths.map(|path| BufReader::new(open_file(path, "problem"))).collect::<Vec<_>>());
@@ -85,14 +95,26 @@ fn check_solution(
 
     match (input_format, problem_files, solution_file) {
         ("pragmatic", Some(mut problem_files), Some(solution_file)) if problem_files.len() == 1 => {
-            check_pragmatic_solution(problem_files.swap_remove(0), solution_file, matrix_files)
+            Ok(check_pragmatic_solution_with_report(problem_files.swap_remove(0), solution_file, matrix_files, options))
         }
         ("pragmatic", _, _) => {
-            Err(vec!["pragmatic format expects one problem, one solution file, and optionally matrices".into()])
+            Err("pragmatic format expects one problem, one solution file, and optionally matrices".into())
         }
-        _ => Err(vec![format!("unknown format: '{input_format}'").into()]),
+        _ => Err(format!("unknown format: '{input_format}'").into()),
+    }
+}
+
+/// Prints warnings found by the checker and returns an error if solution is not feasible.
+fn get_check_result(report: &CheckerReport) -> Result<(), GenericError> {
+    if !report.warnings.is_empty() {
+        println!("checker found {} warnings:\n{}", report.warnings.len(), report.warnings.join("\n"));
+    }
+
+    if report.is_feasible {
+        Ok(())
+    } else {
+        Err(format!("checker found {} errors:\n{}", report.errors.len(), report.errors.join("\n")).into())
     }
-    .map_err(|errs| format!("checker found {} errors:\n{}", errs.len(), GenericError::join_many(&errs, "\n")).into())
 }
 
 pub(crate) fn get_core_problem<F: Read>(
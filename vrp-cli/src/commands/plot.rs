@@ -0,0 +1,85 @@
+#[cfg(test)]
+#[path = "../../tests/unit/commands/plot_test.rs"]
+mod plot_test;
+
+use super::*;
+use std::path::Path;
+use vrp_cli::extensions::plot::{PlotFormat, PlotOptions, plot_solution};
+use vrp_core::prelude::{GenericError, GenericResult};
+use vrp_pragmatic::format::problem::deserialize_problem;
+use vrp_pragmatic::format::solution::deserialize_solution;
+
+const SOLUTION_ARG_NAME: &str = "SOLUTION";
+const PROBLEM_ARG_NAME: &str = "problem";
+const OUT_ARG_NAME: &str = "out";
+const FORMAT_ARG_NAME: &str = "format";
+const WIDTH_ARG_NAME: &str = "width";
+const HEIGHT_ARG_NAME: &str = "height";
+
+pub fn get_plot_app() -> Command {
+    Command::new("plot")
+        .about("Renders pragmatic solution tours as svg or png image")
+        .arg(Arg::new(SOLUTION_ARG_NAME).help("Sets solution file").required(true).index(1))
+        .arg(
+            Arg::new(PROBLEM_ARG_NAME)
+                .help("Sets problem file which is used to highlight unassigned jobs")
+                .short('p')
+                .long(PROBLEM_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::new(OUT_ARG_NAME)
+                .help("Specifies path to the image file")
+                .short('o')
+                .long(OUT_ARG_NAME)
+                .required(true),
+        )
+        .arg(
+            Arg::new(FORMAT_ARG_NAME)
+                .help("Specifies image format. Default is detected from file extension")
+                .short('f')
+                .long(FORMAT_ARG_NAME)
+                .required(false)
+                .value_parser(["svg", "png"]),
+        )
+        .arg(
+            Arg::new(WIDTH_ARG_NAME)
+                .help("Image width in pixels. Default is 1024")
+                .long(WIDTH_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::new(HEIGHT_ARG_NAME)
+                .help("Image height in pixels. Default is 1024")
+                .long(HEIGHT_ARG_NAME)
+                .required(false),
+        )
+}
+
+pub fn run_plot(matches: &ArgMatches) -> GenericResult<()> {
+    let solution_path = matches.get_one::<String>(SOLUTION_ARG_NAME).unwrap();
+    let solution = deserialize_solution(BufReader::new(open_file(solution_path, "solution")))
+        .map_err(|err| format!("cannot read solution: '{err}'"))?;
+
+    let problem = matches
+        .get_one::<String>(PROBLEM_ARG_NAME)
+        .map(|path| deserialize_problem(BufReader::new(open_file(path, "problem"))))
+        .transpose()
+        .map_err(|errs| format!("cannot read problem: '{errs}'"))?;
+
+    let out_path = Path::new(matches.get_one::<String>(OUT_ARG_NAME).unwrap());
+    let format = match matches.get_one::<String>(FORMAT_ARG_NAME).map(String::as_str) {
+        Some("svg") => PlotFormat::Svg,
+        Some("png") => PlotFormat::Png,
+        _ => PlotFormat::from_path(out_path)?,
+    };
+
+    let defaults = PlotOptions::default();
+    let width = parse_int_value::<u32>(matches, WIDTH_ARG_NAME, "width")?.unwrap_or(defaults.size.0);
+    let height = parse_int_value::<u32>(matches, HEIGHT_ARG_NAME, "height")?.unwrap_or(defaults.size.1);
+    if width == 0 || height == 0 {
+        return Err(GenericError::from("image size must be positive"));
+    }
+
+    plot_solution(&solution, problem.as_ref(), out_path, format, &PlotOptions { size: (width, height) })
+}
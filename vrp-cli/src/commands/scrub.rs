@@ -0,0 +1,86 @@
+#[cfg(test)]
+#[path = "../../tests/unit/commands/scrub_test.rs"]
+mod scrub_test;
+
+use super::*;
+use vrp_cli::extensions::scrub::{IdScrubMode, ScrubOptions, scrub_problem};
+use vrp_core::prelude::{Float, GenericResult};
+use vrp_pragmatic::format::problem::{deserialize_problem, serialize_problem};
+
+const PROBLEM_ARG_NAME: &str = "PROBLEM";
+const IDS_ARG_NAME: &str = "ids";
+const SALT_ARG_NAME: &str = "salt";
+const RADIUS_ARG_NAME: &str = "radius";
+const TIME_SCALE_ARG_NAME: &str = "time-scale";
+const OUT_RESULT_ARG_NAME: &str = "out-result";
+
+pub fn get_scrub_app() -> Command {
+    Command::new("scrub")
+        .about("Anonymizes pragmatic problem to share it as a reproduction case")
+        .arg(Arg::new(PROBLEM_ARG_NAME).help("Sets problem file").required(true).index(1))
+        .arg(
+            Arg::new(IDS_ARG_NAME)
+                .help("Specifies how ids, skills and other strings are replaced: sequential ones or salted hashes")
+                .long(IDS_ARG_NAME)
+                .required(false)
+                .value_parser(["sequential", "hash"])
+                .default_value("sequential"),
+        )
+        .arg(
+            Arg::new(SALT_ARG_NAME)
+                .help("Specifies salt used to hash ids")
+                .long(SALT_ARG_NAME)
+                .required(false)
+                .requires_if("hash", IDS_ARG_NAME),
+        )
+        .arg(
+            Arg::new(RADIUS_ARG_NAME)
+                .help("Max distance in meters each unique location is moved by. Default is 100")
+                .short('r')
+                .long(RADIUS_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::new(TIME_SCALE_ARG_NAME)
+                .help("A factor applied to all times and durations. Default is 1")
+                .long(TIME_SCALE_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::new(OUT_RESULT_ARG_NAME)
+                .help("Specifies path to the file for result output")
+                .short('o')
+                .long(OUT_RESULT_ARG_NAME)
+                .required(false),
+        )
+}
+
+pub fn run_scrub(
+    matches: &ArgMatches,
+    out_writer_func: fn(Option<File>) -> BufWriter<Box<dyn Write>>,
+) -> GenericResult<()> {
+    let problem_path = matches.get_one::<String>(PROBLEM_ARG_NAME).unwrap();
+    let problem = deserialize_problem(BufReader::new(open_file(problem_path, "problem")))
+        .map_err(|errs| format!("cannot read problem: '{errs}'"))?;
+
+    let defaults = ScrubOptions::default();
+    let ids = match matches.get_one::<String>(IDS_ARG_NAME).map(String::as_str) {
+        Some("hash") => {
+            IdScrubMode::Hash { salt: matches.get_one::<String>(SALT_ARG_NAME).cloned().unwrap_or_default() }
+        }
+        _ => IdScrubMode::Sequential,
+    };
+    let options = ScrubOptions {
+        ids,
+        radius: parse_float_value::<Float>(matches, RADIUS_ARG_NAME, "radius")?.unwrap_or(defaults.radius),
+        time_scale: parse_float_value::<Float>(matches, TIME_SCALE_ARG_NAME, "time scale")?
+            .unwrap_or(defaults.time_scale),
+    };
+
+    let problem = scrub_problem(problem, &options).map_err(|err| format!("cannot scrub problem: '{err}'"))?;
+
+    let out_result = matches.get_one::<String>(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out result"));
+    let mut out_buffer = out_writer_func(out_result);
+
+    serialize_problem(&problem, &mut out_buffer).map_err(|err| format!("cannot write problem: '{err}'").into())
+}
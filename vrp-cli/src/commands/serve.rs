@@ -0,0 +1,105 @@
+#[cfg(test)]
+#[path = "../../tests/unit/commands/serve_test.rs"]
+mod serve_test;
+
+use super::*;
+
+use std::sync::Arc;
+use vrp_cli::extensions::serve::{ServeConfig, run_server};
+use vrp_core::prelude::*;
+
+const HOST_ARG_NAME: &str = "host";
+const PORT_ARG_NAME: &str = "port";
+const CONCURRENCY_ARG_NAME: &str = "concurrency";
+const GENERATIONS_ARG_NAME: &str = "max-generations";
+const TIME_ARG_NAME: &str = "max-time";
+const JOB_TTL_ARG_NAME: &str = "job-ttl";
+#[cfg(feature = "grpc")]
+const GRPC_ARG_NAME: &str = "grpc";
+
+pub fn get_serve_app() -> Command {
+    let app = Command::new("serve")
+        .about("Runs solver as HTTP service which solves problems in pragmatic format")
+        .arg(
+            Arg::new(HOST_ARG_NAME)
+                .help("Specifies host to listen on")
+                .long(HOST_ARG_NAME)
+                .required(false)
+                .default_value("127.0.0.1"),
+        )
+        .arg(
+            Arg::new(PORT_ARG_NAME)
+                .help("Specifies port to listen on")
+                .short('p')
+                .long(PORT_ARG_NAME)
+                .required(false)
+                .default_value("8080"),
+        )
+        .arg(
+            Arg::new(CONCURRENCY_ARG_NAME)
+                .help("Specifies max amount of problems solved simultaneously, other requests are queued")
+                .long(CONCURRENCY_ARG_NAME)
+                .required(false)
+                .default_value("1"),
+        )
+        .arg(
+            Arg::new(GENERATIONS_ARG_NAME)
+                .help("Specifies default maximum number of generations, used when request has no termination config")
+                .short('n')
+                .long(GENERATIONS_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::new(TIME_ARG_NAME)
+                .help("Specifies default max time in seconds, used when request has no termination config")
+                .short('t')
+                .long(TIME_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::new(JOB_TTL_ARG_NAME)
+                .help("Specifies time in seconds to keep completed or failed jobs before they are removed")
+                .long(JOB_TTL_ARG_NAME)
+                .required(false)
+                .default_value("3600"),
+        );
+
+    #[cfg(feature = "grpc")]
+    let app = app.arg(
+        Arg::new(GRPC_ARG_NAME)
+            .help("Specifies whether gRPC service should be run instead of HTTP one")
+            .long(GRPC_ARG_NAME)
+            .required(false)
+            .action(clap::ArgAction::SetTrue),
+    );
+
+    app
+}
+
+/// Runs serve command.
+pub fn run_serve(matches: &ArgMatches) -> GenericResult<()> {
+    let config = get_serve_config(matches)?;
+    let logger: InfoLogger = Arc::new(|msg: &str| println!("{msg}"));
+
+    #[cfg(feature = "grpc")]
+    if matches.get_one::<bool>(GRPC_ARG_NAME).copied().unwrap_or(false) {
+        return vrp_cli::extensions::serve::grpc::run_grpc_server(&config, logger);
+    }
+
+    run_server(&config, logger)
+}
+
+fn get_serve_config(matches: &ArgMatches) -> GenericResult<ServeConfig> {
+    let host = matches.get_one::<String>(HOST_ARG_NAME).cloned().unwrap_or_else(|| "127.0.0.1".to_string());
+    let port = parse_int_value::<u16>(matches, PORT_ARG_NAME, "port")?.unwrap_or(8080);
+    let concurrency = parse_int_value::<usize>(matches, CONCURRENCY_ARG_NAME, "concurrency")?.unwrap_or(1);
+    let max_generations = parse_int_value::<usize>(matches, GENERATIONS_ARG_NAME, "max generations")?;
+    let max_time = parse_int_value::<usize>(matches, TIME_ARG_NAME, "max time")?;
+    let job_ttl = parse_int_value::<usize>(matches, JOB_TTL_ARG_NAME, "job ttl")?.unwrap_or(3600);
+
+    if concurrency == 0 {
+        return Err("concurrency must be bigger than 0".into());
+    }
+
+    Ok(ServeConfig { host, port, concurrency, max_time, max_generations, job_ttl })
+}
@@ -7,38 +7,75 @@ use super::*;
 use clap::ArgAction;
 use std::fs::File;
 use std::io::{BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use vrp_cli::core::solver::TargetHeuristic;
-use vrp_cli::extensions::solve::config::create_builder_from_config_file;
+use vrp_cli::extensions::solve::calendar::write_solution_as_ics;
+use vrp_cli::extensions::solve::config::{
+    Config, ConfigFormat, EnvironmentConfig, EvolutionConfig, HyperType, LoggingConfig, LowerBoundConfig,
+    ParallelismConfig, PopulationType, ProgressConfig, TelemetryConfig, TerminationConfig, VariationConfig,
+    create_arc_penalties, create_builder_from_config, create_ruin_recreate_from_search_config, read_config_with_format,
+    read_search_config, write_config,
+};
 use vrp_cli::extensions::solve::formats::*;
-use vrp_core::construction::heuristics::InsertionContext;
+use vrp_cli::extensions::solve::progress::*;
+use vrp_cli::extensions::solve::summary::{SolveSummary, write_solve_summary};
+use vrp_core::construction::heuristics::*;
+use vrp_core::construction::probing::repair_solution_from_unknown;
 use vrp_core::models::common::Footprint;
+use vrp_core::models::problem::{FileJobIndexCache, JobIndexCache};
 use vrp_core::prelude::*;
 use vrp_core::rosomaxa::{evolution::*, get_default_population, get_default_selection_size};
+use vrp_core::solver::processing::CostLowerBound;
 use vrp_core::solver::*;
 use vrp_core::utils::*;
+use vrp_pragmatic::format::problem::ProblemReadOptions;
+use vrp_pragmatic::format::solution::{
+    LegGeometryProvider, PragmaticOutputType, PragmaticWriterOptions, deserialize_solution,
+    write_pragmatic_with_options,
+};
 
 const FORMAT_ARG_NAME: &str = "FORMAT";
 const PROBLEM_ARG_NAME: &str = "PROBLEM";
 const MATRIX_ARG_NAME: &str = "matrix";
+const MATRIX_CACHE_ARG_NAME: &str = "matrix-cache";
+const JOB_INDEX_CACHE_ARG_NAME: &str = "job-index-cache";
+const GEOMETRY_ARG_NAME: &str = "geometry";
+const MERGE_STOPS_ARG_NAME: &str = "merge-stops";
 const GENERATIONS_ARG_NAME: &str = "max-generations";
 const TIME_ARG_NAME: &str = "max-time";
 const MIN_CV_ARG_NAME: &str = "min-cv";
 const GEO_JSON_ARG_NAME: &str = "geo-json";
+const OUT_CSV_ARG_NAME: &str = "out-csv";
+const OUT_ICS_ARG_NAME: &str = "out-ics";
+const OUT_SUMMARY_ARG_NAME: &str = "out-summary";
 
 const INIT_SOLUTION_ARG_NAME: &str = "init-solution";
 const INIT_SIZE_ARG_NAME: &str = "init-size";
+const INIT_MODE_ARG_NAME: &str = "init-mode";
 const OUT_RESULT_ARG_NAME: &str = "out-result";
+const OUT_FORMAT_ARG_NAME: &str = "out-format";
 const GET_LOCATIONS_ARG_NAME: &str = "get-locations";
 const CONFIG_ARG_NAME: &str = "config";
+const SEARCH_CONFIG_ARG_NAME: &str = "search-config";
+const DUMP_CONFIG_ARG_NAME: &str = "dump-config";
 const LOG_ARG_NAME: &str = "log";
+const LOG_FORMAT_ARG_NAME: &str = "log-format";
 const CHECK_ARG_NAME: &str = "check";
 const SEARCH_MODE_ARG_NAME: &str = "search-mode";
 const PARALLELISM_ARG_NAME: &str = "parallelism";
 const HEURISTIC_ARG_NAME: &str = "heuristic";
 const EXPERIMENTAL_ARG_NAME: &str = "experimental";
+const MEMORY_BUDGET_ARG_NAME: &str = "memory-budget";
+const DETERMINISTIC_ARG_NAME: &str = "deterministic";
 const ROUNDED_ARG_NAME: &str = "round";
+const NO_EXACT_ARG_NAME: &str = "no-exact";
+const LOWER_BOUND_ARG_NAME: &str = "lower-bound";
+
+const OSRM_MATRIX_PREFIX: &str = "osrm:";
+/// Specifies how often (in generations) search progress is written in json log format.
+const PROGRESS_INTERVAL: usize = 100;
 
 pub fn get_solve_app() -> Command {
     Command::new("solve")
@@ -47,7 +84,7 @@ pub fn get_solve_app() -> Command {
             Arg::new(FORMAT_ARG_NAME)
                 .help("Specifies the problem type")
                 .required(true)
-                .value_parser(["solomon", "lilim", "tsplib", "pragmatic"])
+                .value_parser(["solomon", "lilim", "pdptw", "tsplib", "cordeau", "pragmatic"])
                 .index(1),
         )
         .arg(Arg::new(PROBLEM_ARG_NAME).help("Sets the problem file to use").required(true).index(2))
@@ -87,14 +124,52 @@ pub fn get_solve_app() -> Command {
                 .long(INIT_SIZE_ARG_NAME)
                 .required(false)
         )
+        .arg(
+            Arg::new(INIT_MODE_ARG_NAME)
+                .help(
+                    "Specifies how initial solution is preserved: keep (only missing jobs are inserted), \
+                     repair (infeasible activities are removed, missing jobs are inserted) or reoptimize \
+                     (repaired solution is used to start search)",
+                )
+                .long(INIT_MODE_ARG_NAME)
+                .required(false)
+                .value_parser(["keep", "repair", "reoptimize"])
+                .default_value("reoptimize"),
+        )
         .arg(
             Arg::new(MATRIX_ARG_NAME)
-                .help("Specifies path to file with routing matrix")
+                .help("Specifies path to file with routing matrix or OSRM server url as 'osrm:http://host:5000'")
                 .short('m')
                 .long(MATRIX_ARG_NAME)
                 .action(ArgAction::Append)
                 .required(false)
         )
+        .arg(
+            Arg::new(MATRIX_CACHE_ARG_NAME)
+                .help("Specifies path to directory to cache routing matrices fetched from OSRM server")
+                .long(MATRIX_CACHE_ARG_NAME)
+                .required(false)
+        )
+        .arg(
+            Arg::new(JOB_INDEX_CACHE_ARG_NAME)
+                .help("Specifies path to directory to cache job neighbourhood index, so repeated solves of the same problem skip its creation.\n\
+                Applicable only for pragmatic format.")
+                .long(JOB_INDEX_CACHE_ARG_NAME)
+                .required(false)
+        )
+        .arg(
+            Arg::new(GEOMETRY_ARG_NAME)
+                .help("Includes leg geometries as encoded polylines into solution using OSRM server from matrix option")
+                .long(GEOMETRY_ARG_NAME)
+                .required(false)
+                .action(ArgAction::SetTrue)
+        )
+        .arg(
+            Arg::new(MERGE_STOPS_ARG_NAME)
+                .help("Merges consecutive stops within given radius in meters into one stop. Supported only for pragmatic format")
+                .long(MERGE_STOPS_ARG_NAME)
+                .required(false)
+        )
         .arg(
             Arg::new(OUT_RESULT_ARG_NAME)
                 .help("Specifies path to file for result output")
@@ -102,6 +177,14 @@ pub fn get_solve_app() -> Command {
                 .long(OUT_RESULT_ARG_NAME)
                 .required(false)
         )
+        .arg(
+            Arg::new(OUT_FORMAT_ARG_NAME)
+                .help("Specifies format of result output. Geojson is supported only for pragmatic format")
+                .long(OUT_FORMAT_ARG_NAME)
+                .required(false)
+                .value_parser(["default", "geojson"])
+                .default_value("default"),
+        )
         .arg(
             Arg::new(GET_LOCATIONS_ARG_NAME)
                 .help("Returns list of unique locations")
@@ -116,13 +199,45 @@ pub fn get_solve_app() -> Command {
                 .long(GEO_JSON_ARG_NAME)
                 .required(false)
         )
+        .arg(
+            Arg::new(OUT_CSV_ARG_NAME)
+                .help("Specifies path to solution output as csv table with one row per stop. Supported only for pragmatic format")
+                .long(OUT_CSV_ARG_NAME)
+                .required(false)
+        )
+        .arg(
+            Arg::new(OUT_ICS_ARG_NAME)
+                .help("Specifies path to directory for iCalendar files with one file per tour. Supported only for pragmatic format")
+                .long(OUT_ICS_ARG_NAME)
+                .required(false)
+        )
+        .arg(
+            Arg::new(OUT_SUMMARY_ARG_NAME)
+                .help("Specifies path to json file with run summary: cost, gap to best known solution, termination reason, best fitness trajectory, search operator usage, wall/CPU time and peak memory. Enables telemetry metrics which are also included into pragmatic solution")
+                .long(OUT_SUMMARY_ARG_NAME)
+                .required(false)
+        )
         .arg(
             Arg::new(CONFIG_ARG_NAME)
-                .help("Specifies path to algorithm configuration file")
+                .help("Specifies path to algorithm configuration file in json or toml (detected by .toml extension) format")
                 .short('c')
                 .long(CONFIG_ARG_NAME)
                 .required(false)
         )
+        .arg(
+            Arg::new(SEARCH_CONFIG_ARG_NAME)
+                .help("Specifies path to ruin and recreate methods configuration file used by static heuristic")
+                .long(SEARCH_CONFIG_ARG_NAME)
+                .required(false)
+                .conflicts_with(CONFIG_ARG_NAME)
+        )
+        .arg(
+            Arg::new(DUMP_CONFIG_ARG_NAME)
+                .help("Specifies path to file where effective algorithm configuration is written in json or toml (detected by .toml extension) format")
+                .long(DUMP_CONFIG_ARG_NAME)
+                .required(false)
+                .conflicts_with(SEARCH_CONFIG_ARG_NAME)
+        )
         .arg(
             Arg::new(LOG_ARG_NAME)
                 .help("Specifies whether default logging is enabled")
@@ -130,6 +245,14 @@ pub fn get_solve_app() -> Command {
                 .required(false)
                 .action(ArgAction::SetTrue)
         )
+        .arg(
+            Arg::new(LOG_FORMAT_ARG_NAME)
+                .help("Specifies log format: json enables logging and writes messages and search progress as json lines to stderr")
+                .long(LOG_FORMAT_ARG_NAME)
+                .required(false)
+                .value_parser(["text", "json"])
+                .default_value("text")
+        )
         .arg(
             Arg::new(CHECK_ARG_NAME)
                 .help("Specifies whether final solution should be checked for feasibility")
@@ -169,6 +292,20 @@ pub fn get_solve_app() -> Command {
                 .required(false)
                 .action(ArgAction::SetTrue)
         )
+        .arg(
+            Arg::new(MEMORY_BUDGET_ARG_NAME)
+                .help("Specifies a soft memory budget in megabytes: population is shrunk when approximate memory usage exceeds it")
+                .long(MEMORY_BUDGET_ARG_NAME)
+                .required(false)
+        )
+        .arg(
+            Arg::new(DETERMINISTIC_ARG_NAME)
+                .help("Specifies whether the search is deterministic: two runs with the same seed produce identical solutions.\n\
+                Random seed is set to zero if not specified otherwise. Use generations based termination to get the same result.")
+                .long(DETERMINISTIC_ARG_NAME)
+                .required(false)
+                .action(ArgAction::SetTrue)
+        )
         .arg(
             Arg::new(ROUNDED_ARG_NAME)
                 .help("Specifies whether costs are rounded. Applicable only for scientific formats.")
@@ -176,6 +313,21 @@ pub fn get_solve_app() -> Command {
                 .required(false)
                 .action(ArgAction::SetTrue)
         )
+        .arg(
+            Arg::new(NO_EXACT_ARG_NAME)
+                .help("Disables exact search which is used for tiny problems instead of metaheuristic")
+                .long(NO_EXACT_ARG_NAME)
+                .required(false)
+                .action(ArgAction::SetTrue)
+        )
+        .arg(
+            Arg::new(LOWER_BOUND_ARG_NAME)
+                .help("Specifies whether cost lower bound is estimated before the search to report optimality gap.\n\
+                Estimation time counts against time limit and can be noticeable for large problems.")
+                .long(LOWER_BOUND_ARG_NAME)
+                .required(false)
+                .action(ArgAction::SetTrue)
+        )
 }
 
 /// Runs solver commands.
@@ -184,9 +336,15 @@ pub fn run_solve(
     out_writer_func: fn(Option<File>) -> BufWriter<Box<dyn Write>>,
 ) -> Result<(), GenericError> {
     let environment = get_environment(matches)?;
+    let json_writer = get_json_writer(matches);
 
     let is_rounded = matches.get_one::<bool>(ROUNDED_ARG_NAME).copied().unwrap_or(false);
-    let formats = get_formats(is_rounded, environment.random.clone());
+    let config = matches.get_one::<String>(CONFIG_ARG_NAME).map(|path| read_config_file(path)).transpose()?;
+    let read_options = ProblemReadOptions {
+        job_index_cache: get_job_index_cache(matches),
+        arc_penalties: config.as_ref().and_then(create_arc_penalties),
+    };
+    let formats = get_formats(is_rounded, environment.random.clone(), read_options.clone());
 
     let problem_path = matches
         .get_one::<String>(PROBLEM_ARG_NAME)
@@ -198,13 +356,53 @@ pub fn run_solve(
     let problem_file = open_file(problem_path, "problem");
 
     let init_solution = matches.get_one::<String>(INIT_SOLUTION_ARG_NAME).map(|path| open_file(path, "init solution"));
-    let config = matches.get_one::<String>(CONFIG_ARG_NAME).map(|path| open_file(path, "config"));
+    let init_mode = matches.get_one::<String>(INIT_MODE_ARG_NAME).map(String::as_str).unwrap_or("reoptimize");
     let matrix_files = get_matrix_files(matches);
+    let osrm_url = get_osrm_url(matches);
     let out_result = matches.get_one::<String>(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out solution"));
     let out_geojson = matches.get_one::<String>(GEO_JSON_ARG_NAME).map(|path| create_file(path, "out geojson"));
+    let out_csv = matches.get_one::<String>(OUT_CSV_ARG_NAME);
+    let out_ics_dir = matches.get_one::<String>(OUT_ICS_ARG_NAME).map(PathBuf::from);
+    let out_summary = matches.get_one::<String>(OUT_SUMMARY_ARG_NAME);
+
+    if let Some(path) = matches.get_one::<String>(DUMP_CONFIG_ARG_NAME) {
+        let config = config.clone().map_or_else(|| get_config_from_cli(matches), Ok)?;
+        let mut writer = create_write_buffer(Some(create_file(path, "dump config")));
+        write_config(&mut writer, &config, ConfigFormat::from_path(Path::new(path)))?;
+    }
 
     let is_get_locations_set = matches.get_one::<bool>(GET_LOCATIONS_ARG_NAME).copied().unwrap_or(false);
     let is_check_requested = matches.get_one::<bool>(CHECK_ARG_NAME).copied().unwrap_or(false);
+    let is_geometry_requested = matches.get_one::<bool>(GEOMETRY_ARG_NAME).copied().unwrap_or(false);
+    let is_exact_disabled = matches.get_one::<bool>(NO_EXACT_ARG_NAME).copied().unwrap_or(false);
+    let is_geojson_output = matches.get_one::<String>(OUT_FORMAT_ARG_NAME).is_some_and(|format| format == "geojson");
+    let stop_merge_radius = parse_float_value::<Float>(matches, MERGE_STOPS_ARG_NAME, "merge stops radius")?;
+
+    if is_geojson_output && problem_format != "pragmatic" {
+        return Err(format!("geojson output is not supported for '{problem_format}' format").into());
+    }
+
+    if out_csv.is_some() && problem_format != "pragmatic" {
+        return Err(format!("csv output is not supported for '{problem_format}' format").into());
+    }
+
+    if out_ics_dir.is_some() && problem_format != "pragmatic" {
+        return Err(format!("iCalendar output is not supported for '{problem_format}' format").into());
+    }
+
+    if let Some(radius) = stop_merge_radius {
+        if problem_format != "pragmatic" {
+            return Err(format!("stop merging is not supported for '{problem_format}' format").into());
+        }
+
+        if radius < 0. || !radius.is_finite() {
+            return Err(format!("stop merging radius should be a non-negative number, got: '{radius}'").into());
+        }
+
+        if is_check_requested {
+            return Err("solution check is not supported with merged stops".into());
+        }
+    }
 
     match formats.get(problem_format.as_str()) {
         Some((
@@ -215,12 +413,31 @@ pub fn run_solve(
         )) => {
             let out_buffer = out_writer_func(out_result);
             let geo_buffer = out_geojson.map(|geojson| create_write_buffer(Some(geojson)));
+            let csv_buffer = out_csv.map(|path| create_write_buffer(Some(create_file(path, "out csv"))));
 
             if is_get_locations_set {
                 locations_writer(problem_file, out_buffer).map_err(|err| format!("cannot get locations '{err}'").into())
             } else {
-                match problem_reader(problem_file, matrix_files) {
-                    Ok(problem) => {
+                let problem = match osrm_url {
+                    Some(_) if is_check_requested => {
+                        Err("solution check is not supported with matrices fetched from OSRM server".into())
+                    }
+                    Some(url) => read_problem_with_osrm(
+                        problem_format,
+                        problem_file,
+                        url,
+                        is_geometry_requested,
+                        matches,
+                        read_options,
+                    ),
+                    None if is_geometry_requested => {
+                        Err("leg geometries require routing matrix from OSRM server specified as 'osrm:url'".into())
+                    }
+                    None => problem_reader(problem_file, matrix_files).map(|problem| (problem, None)),
+                };
+
+                match problem {
+                    Ok((problem, geometry)) => {
                         let problem = Arc::new(problem);
 
                         let init_solutions = read_init_solutions_if_necessary(
@@ -228,16 +445,91 @@ pub fn run_solve(
                             environment.clone(),
                             init_solution,
                             init_reader,
+                            init_mode,
                         )?;
 
-                        let solver = match config {
-                            Some(config) => from_config_parameters(problem.clone(), init_solutions, config)?,
-                            _ => from_cli_parameters(problem.clone(), environment, init_solutions, matches)?,
+                        let logger = environment.logger.clone();
+                        let timer = Timer::start();
+                        let is_search_skipped = matches!(init_mode, "keep" | "repair");
+                        // NOTE tiny problems are solved exactly without running metaheuristic, if exact search
+                        // is not completed within its limits, metaheuristic is used instead
+                        let is_exact_applicable =
+                            !is_exact_disabled && init_solutions.is_empty() && ExactSolver::is_applicable(&problem);
+                        let exact_solution = if is_exact_applicable {
+                            ExactSolver::new(problem.clone(), environment.clone()).solve()
+                        } else {
+                            None
+                        };
+                        let solution = match (exact_solution, init_solutions.into_iter().next()) {
+                            (Some(exact_solution), _) => exact_solution,
+                            (None, Some(init_solution)) if is_search_skipped => complete_init_solution(init_solution),
+                            (None, init_solution) => {
+                                let init_solutions = init_solution.into_iter().collect();
+                                let solver = match config.as_ref() {
+                                    Some(config) => from_config_parameters(
+                                        problem.clone(),
+                                        init_solutions,
+                                        config,
+                                        json_writer.clone(),
+                                    )?,
+                                    _ => from_cli_parameters(
+                                        problem.clone(),
+                                        environment,
+                                        init_solutions,
+                                        matches,
+                                        json_writer.clone(),
+                                        out_summary.is_some(),
+                                    )?,
+                                };
+
+                                solver.solve().map_err(|err| format!("cannot find any solution: '{err}'"))?
+                            }
                         };
 
-                        let solution = solver.solve().map_err(|err| format!("cannot find any solution: '{err}'"))?;
+                        let summary =
+                            SolveSummary::new(problem_path, problem_format, &solution, timer.elapsed_secs_as_float())
+                                .with_resource_usage();
+                        if let Some(message) = summary.get_gap_message() {
+                            (logger)(message.as_str());
+                        }
+                        if let Some(message) = summary.get_lower_bound_message() {
+                            (logger)(message.as_str());
+                        }
+                        if let Some(json_writer) = json_writer.as_ref() {
+                            write_progress_event(&ProgressEvent::Result(Box::new(summary.clone())), json_writer);
+                        }
+                        if let Some(path) = out_summary {
+                            write_solve_summary(
+                                &summary,
+                                &mut create_write_buffer(Some(create_file(path, "out summary"))),
+                            )?;
+                        }
+
+                        if geometry.is_some()
+                            || stop_merge_radius.is_some()
+                            || is_geojson_output
+                            || csv_buffer.is_some()
+                            || out_ics_dir.is_some()
+                        {
+                            let output_type = if is_geojson_output {
+                                PragmaticOutputType::OnlyGeoJson
+                            } else {
+                                PragmaticOutputType::OnlyPragmatic
+                            };
 
-                        solution_writer(&problem, solution, out_buffer, geo_buffer)?;
+                            let options = PragmaticWriterOptions { geometry: geometry.as_deref(), stop_merge_radius };
+
+                            write_pragmatic_solution(
+                                &problem,
+                                &solution,
+                                options,
+                                output_type,
+                                out_buffer,
+                                ExtraOutputs { geojson: geo_buffer, csv: csv_buffer, ics_dir: out_ics_dir },
+                            )?;
+                        } else {
+                            solution_writer(&problem, solution, out_buffer, geo_buffer)?;
+                        }
 
                         if is_check_requested {
                             check_pragmatic_solution_with_args(matches)?;
@@ -261,16 +553,23 @@ fn read_init_solutions_if_necessary(
     environment: Arc<Environment>,
     init_solution_file: Option<File>,
     InitSolutionReader(init_reader): &InitSolutionReader,
+    init_mode: &str,
 ) -> GenericResult<Vec<InsertionContext>> {
     Ok(match init_solution_file {
         Some(file) => {
             let init_solution = Timer::measure_duration_with_callback(
                 || {
-                    init_reader(file, problem.clone())
+                    init_reader(file, problem.clone(), environment.logger.clone())
                         .map_err(|err| GenericError::from(format!("cannot read initial solution '{err}'")))
                         .map(|solution| {
                             InsertionContext::new_from_solution(problem.clone(), (solution, None), environment.clone())
                         })
+                        .map(|insertion_ctx| match init_mode {
+                            "keep" => insertion_ctx,
+                            _ => repair_solution_from_unknown(&insertion_ctx, &|| {
+                                InsertionContext::new(problem.clone(), environment.clone())
+                            }),
+                        })
                 },
                 |duration| {
                     (environment.logger)(
@@ -284,12 +583,30 @@ fn read_init_solutions_if_necessary(
     })
 }
 
+/// Inserts jobs missing in initial solution without changing order of already assigned ones.
+fn complete_init_solution(insertion_ctx: InsertionContext) -> Solution {
+    InsertionHeuristic::default()
+        .process(
+            insertion_ctx,
+            &AllJobSelector::default(),
+            &AllRouteSelector::default(),
+            &LegSelection::Exhaustive,
+            &BestResultSelector::default(),
+        )
+        .into()
+}
+
 fn from_config_parameters(
     problem: Arc<Problem>,
     init_solutions: Vec<InsertionContext>,
-    config: File,
+    config: &Config,
+    json_writer: Option<JsonLineWriter>,
 ) -> GenericResult<Solver> {
-    create_builder_from_config_file(problem.clone(), init_solutions, BufReader::new(config))
+    create_builder_from_config(problem.clone(), init_solutions, config)
+        .map(|builder| match json_writer {
+            Some(writer) => builder.with_termination(Box::new(JsonProgressTelemetry::new(PROGRESS_INTERVAL, writer))),
+            None => builder,
+        })
         .and_then(|builder| builder.build())
         .map(|config| Solver::new(problem.clone(), config))
         .map_err(|err| format!("cannot read config: '{err}'").into())
@@ -300,20 +617,27 @@ fn from_cli_parameters(
     environment: Arc<Environment>,
     init_solutions: Vec<InsertionContext>,
     matches: &ArgMatches,
+    json_writer: Option<JsonLineWriter>,
+    is_summary_requested: bool,
 ) -> GenericResult<Solver> {
     let max_time = parse_int_value::<usize>(matches, TIME_ARG_NAME, "max time")?;
 
     let max_generations = parse_int_value::<usize>(matches, GENERATIONS_ARG_NAME, "max generations")?;
-    let telemetry_mode = if matches.get_one::<bool>(LOG_ARG_NAME).copied().unwrap_or(false) {
+    // NOTE json progress is written by a separate termination, so text telemetry is disabled
+    let telemetry_mode = if json_writer.is_some() {
+        TelemetryMode::None
+    } else if matches.get_one::<bool>(LOG_ARG_NAME).copied().unwrap_or(false) {
         get_default_telemetry_mode(environment.logger.clone())
     } else {
         TelemetryMode::None
     };
+    let telemetry_mode = if is_summary_requested { with_summary_metrics(telemetry_mode) } else { telemetry_mode };
     let min_cv = get_min_cv(matches)?;
     let init_size = get_init_size(matches)?;
     let mode = matches.get_one::<String>(SEARCH_MODE_ARG_NAME);
+    let is_lower_bound_requested = matches.get_one::<bool>(LOWER_BOUND_ARG_NAME).copied().unwrap_or(false);
 
-    let config = VrpConfigBuilder::new(problem.clone())
+    let builder = VrpConfigBuilder::new(problem.clone())
         .set_environment(environment.clone())
         .set_telemetry_mode(telemetry_mode.clone())
         .set_heuristic(get_heuristic(matches, problem.clone(), environment.clone())?)
@@ -327,12 +651,105 @@ fn from_cli_parameters(
             get_population(mode, &problem, environment.clone()),
             telemetry_mode,
             environment,
-        ))
-        .build()?;
+        ));
+
+    let builder = if is_lower_bound_requested {
+        let mut processing = create_default_processing();
+        processing.context.insert(0, Box::<CostLowerBound>::default());
+        builder.with_processing(processing)
+    } else {
+        builder
+    };
+
+    let config = match json_writer {
+        Some(writer) => builder.with_termination(Box::new(JsonProgressTelemetry::new(PROGRESS_INTERVAL, writer))),
+        None => builder,
+    }
+    .build()?;
 
     Ok(Solver::new(problem, config))
 }
 
+/// Enables telemetry metrics required by run summary keeping logging settings.
+fn with_summary_metrics(telemetry_mode: TelemetryMode) -> TelemetryMode {
+    // NOTE population is tracked only on the first and the last generations
+    let track_population = usize::MAX;
+
+    match telemetry_mode {
+        TelemetryMode::None => TelemetryMode::OnlyMetrics { track_population },
+        TelemetryMode::OnlyLogging { logger, log_best, log_population } => {
+            TelemetryMode::All { logger, log_best, log_population, track_population }
+        }
+        telemetry_mode => telemetry_mode,
+    }
+}
+
+fn read_config_file(path: &str) -> GenericResult<Config> {
+    read_config_with_format(BufReader::new(open_file(path, "config")), ConfigFormat::from_path(Path::new(path)))
+        .map_err(|err| format!("cannot read config: '{err}'").into())
+}
+
+/// Creates config which represents solver parameters specified via command line arguments.
+fn get_config_from_cli(matches: &ArgMatches) -> GenericResult<Config> {
+    let is_logging = matches.get_one::<bool>(LOG_ARG_NAME).copied().unwrap_or(false);
+    let is_json_log = get_json_writer(matches).is_some();
+
+    let evolution = match matches.get_one::<String>(SEARCH_MODE_ARG_NAME).map(String::as_str) {
+        Some("deep") => Some(EvolutionConfig {
+            initial: None,
+            population: Some(PopulationType::Elitism { max_size: Some(4), selection_size: None }),
+            restart: None,
+        }),
+        _ => None,
+    };
+
+    let hyper = match matches.get_one::<String>(HEURISTIC_ARG_NAME).map(String::as_str) {
+        Some("dynamic") => Some(HyperType::DynamicSelective),
+        Some("static") => Some(HyperType::StaticSelective { operators: None }),
+        _ => None,
+    };
+
+    let termination = TerminationConfig {
+        max_time: parse_int_value::<usize>(matches, TIME_ARG_NAME, "max time")?,
+        max_generations: parse_int_value::<usize>(matches, GENERATIONS_ARG_NAME, "max generations")?,
+        variation: get_min_cv(matches)?.map(|(interval_type, value, cv, is_global)| VariationConfig {
+            interval_type,
+            value,
+            cv,
+            is_global,
+        }),
+    };
+
+    let environment = EnvironmentConfig {
+        parallelism: get_parallelism(matches)?
+            .map(|(num_thread_pools, threads_per_pool)| ParallelismConfig { num_thread_pools, threads_per_pool }),
+        logging: Some(LoggingConfig { enabled: is_logging || is_json_log, prefix: None }),
+        is_experimental: matches.get_one::<bool>(EXPERIMENTAL_ARG_NAME).copied(),
+        random_seed: None,
+        memory_budget: parse_int_value::<usize>(matches, MEMORY_BUDGET_ARG_NAME, "memory budget")?,
+        is_deterministic: matches.get_one::<bool>(DETERMINISTIC_ARG_NAME).copied(),
+    };
+
+    let telemetry = (is_logging && !is_json_log).then_some(TelemetryConfig {
+        progress: Some(ProgressConfig { enabled: true, log_best: Some(100), log_population: Some(1000) }),
+        metrics: None,
+    });
+
+    Ok(Config {
+        evolution,
+        hyper,
+        termination: Some(termination),
+        environment: Some(environment),
+        telemetry,
+        lower_bound: matches
+            .get_one::<bool>(LOWER_BOUND_ARG_NAME)
+            .copied()
+            .filter(|is_enabled| *is_enabled)
+            .map(|enabled| LowerBoundConfig { enabled }),
+        ..Config::default()
+    })
+}
+
 fn get_min_cv(matches: &ArgMatches) -> GenericResult<Option<(String, usize, Float, bool)>> {
     let err_result = Err("cannot parse min_cv parameter".into());
     matches
@@ -367,41 +784,177 @@ fn get_init_size(matches: &ArgMatches) -> GenericResult<Option<usize>> {
         .unwrap_or(Ok(None))
 }
 
+fn get_json_writer(matches: &ArgMatches) -> Option<JsonLineWriter> {
+    matches
+        .get_one::<String>(LOG_FORMAT_ARG_NAME)
+        .filter(|format| *format == "json")
+        .map(|_| Arc::new(|line: &str| eprintln!("{line}")) as JsonLineWriter)
+}
+
 fn get_environment(matches: &ArgMatches) -> GenericResult<Arc<Environment>> {
     let max_time = parse_int_value::<usize>(matches, TIME_ARG_NAME, "max time")?;
     let quota = Some(create_interruption_quota(max_time));
     let is_experimental = matches.get_one::<bool>(EXPERIMENTAL_ARG_NAME).copied().unwrap_or(false);
+    let json_logger = get_json_writer(matches).map(create_json_logger);
+    let memory_budget =
+        parse_int_value::<usize>(matches, MEMORY_BUDGET_ARG_NAME, "memory budget")?.map(|budget| budget * 1024 * 1024);
+
+    let environment = match get_parallelism(matches)? {
+        Some((num_thread_pools, threads_per_pool)) => {
+            let parallelism = Parallelism::new(num_thread_pools, threads_per_pool);
+            let logger: InfoLogger = if let Some(json_logger) = json_logger {
+                json_logger
+            } else if matches.get_one::<bool>(LOG_ARG_NAME).copied().unwrap_or(false) {
+                Arc::new(|msg: &str| println!("{msg}"))
+            } else {
+                Arc::new(|_: &str| {})
+            };
+            Environment::new(Arc::new(DefaultRandom::default()), quota, parallelism, logger, is_experimental)
+        }
+        None => {
+            let environment = Environment { quota, is_experimental, ..Environment::default() };
+            match json_logger {
+                Some(logger) => Environment { logger, ..environment },
+                None => environment,
+            }
+        }
+    };
+
+    let environment = Environment { memory_budget, ..environment };
 
+    Ok(Arc::new(if matches.get_one::<bool>(DETERMINISTIC_ARG_NAME).copied().unwrap_or(false) {
+        Environment {
+            random: Arc::new(DefaultRandom::new_repeatable()),
+            parallelism: environment.parallelism.clone().with_deterministic(true),
+            ..environment
+        }
+    } else {
+        environment
+    }))
+}
+
+fn get_parallelism(matches: &ArgMatches) -> GenericResult<Option<(usize, usize)>> {
     matches
         .get_one::<String>(PARALLELISM_ARG_NAME)
         .map(|arg| {
             if let [num_thread_pools, threads_per_pool] =
                 arg.split(',').filter_map(|line| line.parse::<usize>().ok()).collect::<Vec<_>>().as_slice()
             {
-                let parallelism = Parallelism::new(*num_thread_pools, *threads_per_pool);
-                let logger: InfoLogger = if matches.get_one::<bool>(LOG_ARG_NAME).copied().unwrap_or(false) {
-                    Arc::new(|msg: &str| println!("{msg}"))
-                } else {
-                    Arc::new(|_: &str| {})
-                };
-                Ok(Arc::new(Environment::new(
-                    Arc::new(DefaultRandom::default()),
-                    quota.clone(),
-                    parallelism,
-                    logger,
-                    is_experimental,
-                )))
+                Ok(Some((*num_thread_pools, *threads_per_pool)))
             } else {
                 Err("cannot parse parallelism parameter".into())
             }
         })
-        .unwrap_or_else(|| Ok(Arc::new(Environment { quota, is_experimental, ..Environment::default() })))
+        .unwrap_or(Ok(None))
 }
 
 fn get_matrix_files(matches: &ArgMatches) -> Option<Vec<File>> {
+    matches.get_many::<String>(MATRIX_ARG_NAME).and_then(|paths| {
+        let files = paths
+            .filter(|path| !path.starts_with(OSRM_MATRIX_PREFIX))
+            .map(|path| open_file(path, "routing matrix"))
+            .collect::<Vec<_>>();
+
+        if files.is_empty() { None } else { Some(files) }
+    })
+}
+
+fn get_osrm_url(matches: &ArgMatches) -> Option<&str> {
     matches
         .get_many::<String>(MATRIX_ARG_NAME)
-        .map(|paths| paths.map(|path| open_file(path, "routing matrix")).collect())
+        .and_then(|mut paths| paths.find_map(|path| path.strip_prefix(OSRM_MATRIX_PREFIX)))
+}
+
+fn get_job_index_cache(matches: &ArgMatches) -> Option<Arc<dyn JobIndexCache>> {
+    matches
+        .get_one::<String>(JOB_INDEX_CACHE_ARG_NAME)
+        .map(|path| Arc::new(FileJobIndexCache::new(path)) as Arc<dyn JobIndexCache>)
+}
+
+type GeometryProvider = Box<dyn LegGeometryProvider>;
+
+#[cfg(feature = "osrm-routing")]
+fn read_problem_with_osrm(
+    problem_format: &str,
+    problem_file: File,
+    url: &str,
+    is_geometry_requested: bool,
+    matches: &ArgMatches,
+    read_options: ProblemReadOptions,
+) -> GenericResult<(Problem, Option<GeometryProvider>)> {
+    use std::path::PathBuf;
+    use vrp_cli::extensions::routing::{OsrmConfig, OsrmGeometryProvider, fetch_osrm_matrices};
+    use vrp_pragmatic::format::problem::{PragmaticProblem, deserialize_problem};
+
+    if problem_format != "pragmatic" {
+        return Err(format!("OSRM routing matrix is not supported for '{problem_format}' format").into());
+    }
+
+    let problem = deserialize_problem(BufReader::new(problem_file))?;
+    let config = OsrmConfig {
+        cache_dir: matches.get_one::<String>(MATRIX_CACHE_ARG_NAME).map(PathBuf::from),
+        ..OsrmConfig::new(url)
+    };
+
+    let matrices =
+        fetch_osrm_matrices(&problem, &config).map_err(|err| format!("cannot fetch matrices from OSRM: '{err}'"))?;
+
+    let geometry = if is_geometry_requested {
+        Some(Box::new(OsrmGeometryProvider::new(&problem, &config)?) as GeometryProvider)
+    } else {
+        None
+    };
+
+    (problem, matrices, read_options).read_pragmatic().map(|problem| (problem, geometry)).map_err(From::from)
+}
+
+#[cfg(not(feature = "osrm-routing"))]
+fn read_problem_with_osrm(
+    _: &str,
+    _: File,
+    _: &str,
+    _: bool,
+    _: &ArgMatches,
+    _: ProblemReadOptions,
+) -> GenericResult<(Problem, Option<GeometryProvider>)> {
+    Err("OSRM routing support is not enabled: build with 'osrm-routing' feature".into())
+}
+
+/// Keeps additional outputs of pragmatic solution.
+struct ExtraOutputs {
+    geojson: Option<BufWriter<Box<dyn Write>>>,
+    csv: Option<BufWriter<Box<dyn Write>>>,
+    ics_dir: Option<PathBuf>,
+}
+
+fn write_pragmatic_solution(
+    problem: &Problem,
+    solution: &Solution,
+    options: PragmaticWriterOptions,
+    output_type: PragmaticOutputType,
+    mut out_buffer: BufWriter<Box<dyn Write>>,
+    extra_outputs: ExtraOutputs,
+) -> GenericResult<()> {
+    if let Some(mut geo_buffer) = extra_outputs.geojson {
+        write_pragmatic_with_options(problem, solution, PragmaticOutputType::OnlyGeoJson, options, &mut geo_buffer)?;
+    }
+
+    if let Some(mut csv_buffer) = extra_outputs.csv {
+        write_pragmatic_with_options(problem, solution, PragmaticOutputType::OnlyCsv, options, &mut csv_buffer)?;
+    }
+
+    if let Some(ics_dir) = extra_outputs.ics_dir {
+        let mut buffer = BufWriter::new(Vec::new());
+        write_pragmatic_with_options(problem, solution, PragmaticOutputType::OnlyPragmatic, options, &mut buffer)?;
+
+        let buffer = buffer.into_inner().map_err(|err| format!("cannot write solution: '{err}'"))?;
+        let api_solution = deserialize_solution(BufReader::new(buffer.as_slice()))
+            .map_err(|err| format!("cannot read solution: '{err}'"))?;
+
+        write_solution_as_ics(&api_solution, ics_dir.as_path())?;
+    }
+
+    write_pragmatic_with_options(problem, solution, output_type, options, &mut out_buffer)
 }
 
 fn get_population(mode: Option<&String>, problem: &Problem, environment: Arc<Environment>) -> TargetPopulation {
@@ -422,6 +975,10 @@ fn get_heuristic(
     problem: Arc<Problem>,
     environment: Arc<Environment>,
 ) -> GenericResult<TargetHeuristic> {
+    if let Some(path) = matches.get_one::<String>(SEARCH_CONFIG_ARG_NAME) {
+        return get_heuristic_from_search_config(matches, path, problem, environment);
+    }
+
     match matches.get_one::<String>(HEURISTIC_ARG_NAME).map(String::as_str) {
         Some("dynamic") => Ok(Box::new(get_dynamic_heuristic(problem, environment))),
         Some("static") => Ok(Box::new(get_static_heuristic(problem, environment))),
@@ -430,8 +987,29 @@ fn get_heuristic(
     }
 }
 
+fn get_heuristic_from_search_config(
+    matches: &ArgMatches,
+    path: &str,
+    problem: Arc<Problem>,
+    environment: Arc<Environment>,
+) -> GenericResult<TargetHeuristic> {
+    match matches.get_one::<String>(HEURISTIC_ARG_NAME).map(String::as_str) {
+        Some("default") | Some("static") | None => {
+            let config = read_search_config(BufReader::new(open_file(path, "search config")))?;
+            let ruin_recreate = create_ruin_recreate_from_search_config(problem.clone(), environment.clone(), &config)
+                .map_err(|err| format!("cannot create search from config: '{err}'"))?;
+
+            Ok(Box::new(get_static_heuristic_with_ruin_recreate(problem, environment, ruin_recreate)))
+        }
+        Some(name) => Err(format!("search config is not supported by '{name}' heuristic").into()),
+    }
+}
+
 fn check_pragmatic_solution_with_args(matches: &ArgMatches) -> GenericResult<()> {
-    check_solution(matches, "pragmatic", PROBLEM_ARG_NAME, OUT_RESULT_ARG_NAME, MATRIX_ARG_NAME)
+    let options = CheckerOptions::default();
+    let report = check_solution(matches, "pragmatic", PROBLEM_ARG_NAME, OUT_RESULT_ARG_NAME, MATRIX_ARG_NAME, options)?;
+
+    get_check_result(&report)
 }
 
 /// Creates interruption quota.
@@ -2,3 +2,6 @@
 
 mod clusters;
 pub use self::clusters::*;
+
+mod statistics;
+pub use self::statistics::*;
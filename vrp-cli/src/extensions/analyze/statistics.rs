@@ -0,0 +1,533 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/analyze/statistics_test.rs"]
+mod statistics_test;
+
+use serde::Serialize;
+use std::collections::HashSet;
+use std::io::{BufWriter, Write};
+use time::OffsetDateTime;
+use time::format_description::well_known::Rfc3339;
+use vrp_core::prelude::{Float, GenericResult};
+use vrp_pragmatic::format::Location;
+use vrp_pragmatic::format::problem::{Matrix, Problem, VehicleShift};
+
+/// Amount of bins in time window tightness histogram.
+const HISTOGRAM_BINS: usize = 10;
+
+/// Contains problem instance characteristics.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProblemStatistics {
+    /// Job statistics.
+    pub jobs: JobStatistics,
+    /// Fleet statistics.
+    pub fleet: FleetStatistics,
+    /// Time window statistics.
+    pub time_windows: TimeWindowStatistics,
+    /// Demand statistics.
+    pub demand: DemandStatistics,
+    /// Geographic statistics, available only when locations are specified by coordinates.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub geography: Option<GeographyStatistics>,
+    /// Routing matrix statistics.
+    pub matrices: Vec<MatrixStatistics>,
+    /// Hints about expected problem difficulty.
+    pub hints: Vec<String>,
+}
+
+/// Contains amount of jobs of different types.
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobStatistics {
+    /// Total amount of jobs.
+    pub total: usize,
+    /// Total amount of job tasks.
+    pub tasks: usize,
+    /// Amount of jobs with a single delivery.
+    pub deliveries: usize,
+    /// Amount of jobs with a single pickup.
+    pub pickups: usize,
+    /// Amount of jobs with one pickup and one delivery.
+    pub pickup_deliveries: usize,
+    /// Amount of jobs with a single service.
+    pub services: usize,
+    /// Amount of jobs with a single replacement.
+    pub replacements: usize,
+    /// Amount of other jobs with multiple tasks.
+    pub multi: usize,
+    /// Amount of jobs which require skills.
+    pub with_skills: usize,
+}
+
+/// Contains fleet characteristics.
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FleetStatistics {
+    /// Amount of vehicle types.
+    pub vehicle_types: usize,
+    /// Amount of vehicles.
+    pub vehicles: usize,
+    /// Amount of vehicle shifts.
+    pub shifts: usize,
+    /// Names of routing profiles.
+    pub profiles: Vec<String>,
+    /// Amount of vehicles with breaks.
+    pub with_breaks: usize,
+    /// Amount of vehicles with reloads.
+    pub with_reloads: usize,
+}
+
+/// Contains time window characteristics.
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeWindowStatistics {
+    /// Planning horizon in seconds: from the earliest shift start till the latest shift end.
+    pub horizon: Float,
+    /// Amount of job places with time windows.
+    pub constrained: usize,
+    /// Amount of job places without time windows.
+    pub unconstrained: usize,
+    /// Mean tightness: a ratio of time windows duration to planning horizon.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mean_tightness: Option<Float>,
+    /// Amount of job places per tightness range: [0, 0.1), [0.1, 0.2), ..., [0.9, 1].
+    pub histogram: Vec<usize>,
+}
+
+/// Contains demand characteristics.
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DemandStatistics {
+    /// Total demand of all jobs per dimension.
+    pub total_demand: Vec<i64>,
+    /// Total capacity of all vehicle shifts per dimension.
+    pub fleet_capacity: Vec<i64>,
+    /// A ratio of total demand to fleet capacity per dimension, empty when there is no capacity.
+    pub ratio: Vec<Option<Float>>,
+}
+
+/// Contains geographic characteristics.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GeographyStatistics {
+    /// Amount of unique locations.
+    pub unique_locations: usize,
+    /// A bounding box specified by min and max (lat, lng) pairs.
+    pub bounding_box: ((f64, f64), (f64, f64)),
+    /// A diagonal of bounding box in meters.
+    pub diagonal: Float,
+    /// A mean distance to locations centroid in meters.
+    pub mean_distance_to_center: Float,
+}
+
+/// Contains routing matrix characteristics.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MatrixStatistics {
+    /// Matrix profile.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile: Option<String>,
+    /// Amount of locations.
+    pub size: usize,
+    /// Mean relative difference between durations of A to B and B to A.
+    pub duration_asymmetry: Float,
+    /// Mean relative difference between distances of A to B and B to A.
+    pub distance_asymmetry: Float,
+    /// Max relative difference between distances of A to B and B to A.
+    pub max_distance_asymmetry: Float,
+}
+
+/// Gets statistics of pragmatic problem with optional routing matrices.
+pub fn get_problem_statistics(problem: &Problem, matrices: Option<&[Matrix]>) -> GenericResult<ProblemStatistics> {
+    let jobs = get_job_statistics(problem);
+    let fleet = get_fleet_statistics(problem);
+    let time_windows = get_time_window_statistics(problem)?;
+    let demand = get_demand_statistics(problem);
+    let geography = get_geography_statistics(problem);
+    let matrices = matrices.unwrap_or_default().iter().map(get_matrix_statistics).collect::<GenericResult<Vec<_>>>()?;
+
+    let mut statistics = ProblemStatistics { jobs, fleet, time_windows, demand, geography, matrices, hints: vec![] };
+    statistics.hints = get_hints(&statistics);
+
+    Ok(statistics)
+}
+
+/// Writes problem statistics in human-readable text format.
+pub fn write_problem_statistics<W: Write>(
+    writer: &mut BufWriter<W>,
+    statistics: &ProblemStatistics,
+) -> GenericResult<()> {
+    let format_list = |values: &[String]| values.join(", ");
+    let jobs = &statistics.jobs;
+    let fleet = &statistics.fleet;
+    let time_windows = &statistics.time_windows;
+    let demand = &statistics.demand;
+
+    let mut lines = vec![
+        format!("jobs: {} (tasks: {}, with skills: {})", jobs.total, jobs.tasks, jobs.with_skills),
+        format!(
+            "job types: deliveries: {}, pickups: {}, pickup-deliveries: {}, services: {}, replacements: {}, multi: {}",
+            jobs.deliveries, jobs.pickups, jobs.pickup_deliveries, jobs.services, jobs.replacements, jobs.multi
+        ),
+        format!(
+            "fleet: {} vehicles of {} types (shifts: {}, with breaks: {}, with reloads: {}, profiles: {})",
+            fleet.vehicles,
+            fleet.vehicle_types,
+            fleet.shifts,
+            fleet.with_breaks,
+            fleet.with_reloads,
+            format_list(&fleet.profiles)
+        ),
+        format!(
+            "time windows: horizon {:.1}h, constrained places: {}, unconstrained places: {}, mean tightness: {}",
+            time_windows.horizon / 3600.,
+            time_windows.constrained,
+            time_windows.unconstrained,
+            time_windows.mean_tightness.map_or("n/a".to_string(), |tightness| format!("{tightness:.3}"))
+        ),
+        "time window tightness histogram:".to_string(),
+    ];
+
+    let max_count = time_windows.histogram.iter().copied().max().unwrap_or(0).max(1);
+    lines.extend(time_windows.histogram.iter().enumerate().map(|(idx, &count)| {
+        let bar = "#".repeat((count * 40).div_ceil(max_count));
+        let bracket = if idx + 1 == HISTOGRAM_BINS { "]" } else { ")" };
+        let line = format!("  [{:.1}, {:.1}{bracket} {count:>6} {bar}", idx as Float / 10., (idx + 1) as Float / 10.);
+        line.trim_end().to_string()
+    }));
+
+    lines.push(format!(
+        "demand: total {:?}, fleet capacity {:?}, ratio [{}]",
+        demand.total_demand,
+        demand.fleet_capacity,
+        format_list(
+            &demand
+                .ratio
+                .iter()
+                .map(|ratio| ratio.map_or("n/a".to_string(), |ratio| format!("{ratio:.3}")))
+                .collect::<Vec<_>>()
+        )
+    ));
+
+    if let Some(geography) = &statistics.geography {
+        let ((min_lat, min_lng), (max_lat, max_lng)) = geography.bounding_box;
+        lines.push(format!(
+            "geography: {} unique locations, bounding box [{min_lat:.5}, {min_lng:.5}, {max_lat:.5}, {max_lng:.5}]",
+            geography.unique_locations
+        ));
+        lines.push(format!(
+            "geography: diagonal {:.1}km, mean distance to center {:.1}km",
+            geography.diagonal / 1000.,
+            geography.mean_distance_to_center / 1000.
+        ));
+    }
+
+    lines.extend(statistics.matrices.iter().map(|matrix| {
+        format!(
+            "matrix '{}': {} locations, duration asymmetry {:.3}, distance asymmetry {:.3} (max {:.3})",
+            matrix.profile.as_deref().unwrap_or("default"),
+            matrix.size,
+            matrix.duration_asymmetry,
+            matrix.distance_asymmetry,
+            matrix.max_distance_asymmetry
+        )
+    }));
+
+    lines.push("hints:".to_string());
+    lines.extend(statistics.hints.iter().map(|hint| format!("  - {hint}")));
+
+    lines
+        .iter()
+        .try_for_each(|line| writeln!(writer, "{line}"))
+        .map_err(|err| format!("cannot write statistics: '{err}'").into())
+}
+
+fn get_job_statistics(problem: &Problem) -> JobStatistics {
+    problem.plan.jobs.iter().fold(JobStatistics::default(), |mut statistics, job| {
+        let count = |tasks: &Option<Vec<_>>| tasks.as_ref().map_or(0, |tasks| tasks.len());
+        let counts = (count(&job.pickups), count(&job.deliveries), count(&job.services), count(&job.replacements));
+
+        match counts {
+            (0, 1, 0, 0) => statistics.deliveries += 1,
+            (1, 0, 0, 0) => statistics.pickups += 1,
+            (1, 1, 0, 0) => statistics.pickup_deliveries += 1,
+            (0, 0, 1, 0) => statistics.services += 1,
+            (0, 0, 0, 1) => statistics.replacements += 1,
+            _ => statistics.multi += 1,
+        }
+
+        statistics.total += 1;
+        statistics.tasks += counts.0 + counts.1 + counts.2 + counts.3;
+        statistics.with_skills += job.skills.is_some() as usize;
+
+        statistics
+    })
+}
+
+fn get_fleet_statistics(problem: &Problem) -> FleetStatistics {
+    problem.fleet.vehicles.iter().fold(
+        FleetStatistics {
+            profiles: problem.fleet.profiles.iter().map(|profile| profile.name.clone()).collect(),
+            ..FleetStatistics::default()
+        },
+        |mut statistics, vehicle| {
+            let vehicles = vehicle.vehicle_ids.len();
+            let has_shift_with =
+                |predicate: fn(&VehicleShift) -> bool| vehicle.shifts.iter().any(predicate) as usize * vehicles;
+
+            statistics.vehicle_types += 1;
+            statistics.vehicles += vehicles;
+            statistics.shifts += vehicle.shifts.len() * vehicles;
+            statistics.with_breaks += has_shift_with(|shift| shift.breaks.as_ref().is_some_and(|b| !b.is_empty()));
+            statistics.with_reloads += has_shift_with(|shift| shift.reloads.as_ref().is_some_and(|r| !r.is_empty()));
+
+            statistics
+        },
+    )
+}
+
+fn get_time_window_statistics(problem: &Problem) -> GenericResult<TimeWindowStatistics> {
+    let shift_times = problem
+        .fleet
+        .vehicles
+        .iter()
+        .flat_map(|vehicle| vehicle.shifts.iter())
+        .map(|shift| {
+            let start = parse_time(&shift.start.earliest)?;
+            let end = shift.end.as_ref().map(|end| parse_time(&end.latest)).transpose()?;
+            Ok((start, end))
+        })
+        .collect::<GenericResult<Vec<_>>>()?;
+
+    let job_times = problem
+        .plan
+        .jobs
+        .iter()
+        .flat_map(|job| job.all_tasks_iter())
+        .flat_map(|task| task.places.iter())
+        .map(|place| {
+            place
+                .times
+                .iter()
+                .flatten()
+                .filter(|time_window| time_window.len() == 2)
+                .map(|time_window| Ok((parse_time(&time_window[0])?, parse_time(&time_window[1])?)))
+                .collect::<GenericResult<Vec<_>>>()
+        })
+        .collect::<GenericResult<Vec<_>>>()?;
+
+    // NOTE when shift has no end, the latest time window end is used as the end of planning horizon
+    let start = shift_times.iter().map(|(start, _)| *start).min().unwrap_or(0);
+    let end = shift_times
+        .iter()
+        .filter_map(|(_, end)| *end)
+        .chain(job_times.iter().flatten().map(|(_, end)| *end))
+        .max()
+        .unwrap_or(start);
+    let horizon = (end - start).max(0) as Float;
+
+    let tightness = job_times
+        .iter()
+        .filter(|times| !times.is_empty())
+        .map(|times| {
+            let duration = times.iter().map(|(start, end)| (end - start).max(0)).sum::<i64>() as Float;
+            if horizon > 0. { (duration / horizon).min(1.) } else { 1. }
+        })
+        .collect::<Vec<_>>();
+
+    let histogram = tightness.iter().fold(vec![0; HISTOGRAM_BINS], |mut histogram, &tightness| {
+        histogram[((tightness * HISTOGRAM_BINS as Float) as usize).min(HISTOGRAM_BINS - 1)] += 1;
+        histogram
+    });
+
+    Ok(TimeWindowStatistics {
+        horizon,
+        constrained: tightness.len(),
+        unconstrained: job_times.len() - tightness.len(),
+        mean_tightness: (!tightness.is_empty()).then(|| tightness.iter().sum::<Float>() / tightness.len() as Float),
+        histogram,
+    })
+}
+
+fn get_demand_statistics(problem: &Problem) -> DemandStatistics {
+    let add = |mut acc: Vec<i64>, values: &[i32], multiplier: usize| {
+        acc.resize(acc.len().max(values.len()), 0);
+        acc.iter_mut().zip(values.iter()).for_each(|(acc, &value)| *acc += value as i64 * multiplier as i64);
+        acc
+    };
+
+    let total_demand = problem.plan.jobs.iter().fold(vec![], |acc, job| {
+        // NOTE the same goods are picked up and delivered within pickup and delivery job, so pickups are ignored
+        let is_pickup_delivery = job.pickups.is_some() && job.deliveries.is_some();
+        let pickups = if is_pickup_delivery { None } else { job.pickups.as_ref() };
+
+        pickups
+            .into_iter()
+            .chain(job.deliveries.iter())
+            .chain(job.services.iter())
+            .chain(job.replacements.iter())
+            .flatten()
+            .filter_map(|task| task.demand.as_ref())
+            .fold(acc, |acc, demand| add(acc, demand, 1))
+    });
+
+    let fleet_capacity = problem
+        .fleet
+        .vehicles
+        .iter()
+        .fold(vec![], |acc, vehicle| add(acc, &vehicle.capacity, vehicle.vehicle_ids.len() * vehicle.shifts.len()));
+
+    let ratio = total_demand
+        .iter()
+        .enumerate()
+        .map(|(idx, &demand)| {
+            let capacity = fleet_capacity.get(idx).copied().unwrap_or(0);
+            (capacity > 0).then(|| demand as Float / capacity as Float)
+        })
+        .collect();
+
+    DemandStatistics { total_demand, fleet_capacity, ratio }
+}
+
+fn get_geography_statistics(problem: &Problem) -> Option<GeographyStatistics> {
+    let coordinates =
+        problem
+            .plan
+            .jobs
+            .iter()
+            .flat_map(|job| job.all_tasks_iter())
+            .flat_map(|task| task.places.iter().map(|place| &place.location))
+            .chain(problem.fleet.vehicles.iter().flat_map(|vehicle| vehicle.shifts.iter()).flat_map(|shift| {
+                std::iter::once(&shift.start.location).chain(shift.end.iter().map(|end| &end.location))
+            }))
+            .filter_map(|location| match location {
+                Location::Coordinate { lat, lng } => Some((*lat, *lng)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+    if coordinates.is_empty() {
+        return None;
+    }
+
+    let unique_locations =
+        coordinates.iter().map(|(lat, lng)| (lat.to_bits(), lng.to_bits())).collect::<HashSet<_>>().len();
+
+    let (min, max) = coordinates.iter().fold(
+        ((f64::MAX, f64::MAX), (f64::MIN, f64::MIN)),
+        |((min_lat, min_lng), (max_lat, max_lng)), &(lat, lng)| {
+            ((min_lat.min(lat), min_lng.min(lng)), (max_lat.max(lat), max_lng.max(lng)))
+        },
+    );
+
+    let size = coordinates.len() as f64;
+    let center = (
+        coordinates.iter().map(|(lat, _)| lat).sum::<f64>() / size,
+        coordinates.iter().map(|(_, lng)| lng).sum::<f64>() / size,
+    );
+    let mean_distance_to_center =
+        coordinates.iter().map(|&coordinate| get_haversine_distance(coordinate, center)).sum::<f64>() / size;
+
+    Some(GeographyStatistics {
+        unique_locations,
+        bounding_box: (min, max),
+        diagonal: get_haversine_distance(min, max) as Float,
+        mean_distance_to_center: mean_distance_to_center as Float,
+    })
+}
+
+fn get_matrix_statistics(matrix: &Matrix) -> GenericResult<MatrixStatistics> {
+    let size = (matrix.distances.len() as f64).sqrt().round() as usize;
+    if size * size != matrix.distances.len() || matrix.distances.len() != matrix.travel_times.len() {
+        let profile = matrix.profile.as_deref().unwrap_or("default");
+        return Err(format!("routing matrix of '{profile}' profile is not square").into());
+    }
+
+    // NOTE relative difference is calculated only for pairs of reachable locations
+    let get_asymmetry = |values: &[i64]| {
+        (0..size)
+            .flat_map(|from| (from + 1..size).map(move |to| (from, to)))
+            .map(|(from, to)| (values[from * size + to], values[to * size + from]))
+            .filter(|&(forward, backward)| forward > 0 && backward > 0)
+            .map(|(forward, backward)| (forward - backward).abs() as Float / forward.max(backward) as Float)
+            .collect::<Vec<_>>()
+    };
+    let get_mean = |values: &[Float]| {
+        if values.is_empty() { 0. } else { values.iter().sum::<Float>() / values.len() as Float }
+    };
+
+    let durations = get_asymmetry(&matrix.travel_times);
+    let distances = get_asymmetry(&matrix.distances);
+
+    Ok(MatrixStatistics {
+        profile: matrix.profile.clone(),
+        size,
+        duration_asymmetry: get_mean(&durations),
+        distance_asymmetry: get_mean(&distances),
+        max_distance_asymmetry: distances.iter().copied().fold(0., Float::max),
+    })
+}
+
+fn get_hints(statistics: &ProblemStatistics) -> Vec<String> {
+    let jobs = statistics.jobs.total;
+    let mut hints = vec![];
+
+    let (max_time, size) = match jobs {
+        0..100 => (30, "small"),
+        100..500 => (120, "medium"),
+        500..2000 => (600, "large"),
+        _ => (1800, "very large"),
+    };
+    hints.push(format!("{size} problem: consider termination budget around --max-time {max_time}"));
+
+    if statistics.fleet.with_reloads > 0 {
+        hints.push("fleet has reloads: total demand can exceed capacity of a single tour".to_string());
+    } else if statistics.demand.ratio.iter().any(|ratio| ratio.is_none_or(|ratio| ratio > 1.)) {
+        hints.push("total demand exceeds fleet capacity: some jobs are expected to be unassigned".to_string());
+    } else if statistics.demand.ratio.iter().flatten().any(|&ratio| ratio > 0.9) {
+        hints.push("total demand is close to fleet capacity: finding feasible solution might be hard".to_string());
+    }
+
+    match statistics.time_windows.mean_tightness {
+        Some(tightness) if tightness < 0.2 => {
+            hints.push("tight time windows: expect more tours and harder feasibility".to_string())
+        }
+        None => hints.push("no time windows: problem is mostly driven by capacity and distance".to_string()),
+        _ => {}
+    }
+
+    if statistics.jobs.pickup_deliveries + statistics.jobs.multi > 0 {
+        hints.push("pickup and delivery or multi jobs increase search complexity".to_string());
+    }
+
+    if statistics.jobs.with_skills > 0 {
+        hints.push("skills restrict assignment of jobs to vehicles".to_string());
+    }
+
+    if statistics.matrices.iter().any(|matrix| matrix.distance_asymmetry > 0.1) {
+        hints.push("routing matrix is noticeably asymmetric: tour direction matters".to_string());
+    }
+
+    if statistics.fleet.vehicles > 0 && jobs / statistics.fleet.vehicles > 50 {
+        hints.push("many jobs per vehicle: long tours are slower to optimize".to_string());
+    }
+
+    hints
+}
+
+fn parse_time(time: &str) -> GenericResult<i64> {
+    OffsetDateTime::parse(time, &Rfc3339)
+        .map(|time| time.unix_timestamp())
+        .map_err(|err| format!("cannot parse time '{time}': {err}").into())
+}
+
+/// Returns haversine distance in meters between two (lat, lng) pairs.
+fn get_haversine_distance((lat1, lng1): (f64, f64), (lat2, lng2): (f64, f64)) -> f64 {
+    const EARTH_RADIUS: f64 = 6_371_000.;
+
+    let d_lat = (lat2 - lat1).to_radians();
+    let d_lng = (lng2 - lng1).to_radians();
+    let a = (d_lat / 2.).sin().powi(2) + lat1.to_radians().cos() * lat2.to_radians().cos() * (d_lng / 2.).sin().powi(2);
+
+    2. * EARTH_RADIUS * a.sqrt().asin()
+}
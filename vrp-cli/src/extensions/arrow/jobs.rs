@@ -0,0 +1,122 @@
+use super::*;
+use arrow::array::{Array, ArrayRef, AsArray};
+use arrow::compute::cast;
+use arrow::datatypes::{DataType, Float64Type, Int32Type, Int64Type};
+use std::collections::HashMap;
+use vrp_pragmatic::format::Location;
+use vrp_pragmatic::format::problem::{JobPlace, JobTask};
+
+/// Reads jobs from record batches with jobs table. Rows with the same id are grouped into one job
+/// keeping the order of their first appearance.
+pub fn read_jobs_from_batches(batches: &[RecordBatch]) -> GenericResult<Vec<Job>> {
+    let mut indices = HashMap::<String, usize>::new();
+    let mut jobs: Vec<(String, Vec<(i32, JobTask)>)> = Vec::new();
+
+    batches.iter().try_for_each(|batch| {
+        read_tasks(batch)?.into_iter().for_each(|(id, demand, task)| {
+            let idx = *indices.entry(id.clone()).or_insert_with(|| {
+                jobs.push((id, Vec::new()));
+                jobs.len() - 1
+            });
+            jobs[idx].1.push((demand, task));
+        });
+
+        Ok::<_, GenericError>(())
+    })?;
+
+    Ok(jobs.into_iter().map(|(id, tasks)| create_job(id, tasks)).collect())
+}
+
+fn read_tasks(batch: &RecordBatch) -> GenericResult<Vec<(String, i32, JobTask)>> {
+    let ids = get_column(batch, "id", DataType::Utf8)?.ok_or_else(|| GenericError::from("missing column: 'id'"))?;
+    let lats = get_column(batch, "lat", DataType::Float64)?;
+    let lngs = get_column(batch, "lng", DataType::Float64)?;
+    let indices = get_column(batch, "location_index", DataType::Int64)?;
+    let demands = get_column(batch, "demand", DataType::Int32)?;
+    let durations = get_column(batch, "duration", DataType::Float64)?;
+    let tw_starts = get_column(batch, "tw_start", DataType::Utf8)?;
+    let tw_ends = get_column(batch, "tw_end", DataType::Utf8)?;
+    let tags = get_column(batch, "tag", DataType::Utf8)?;
+
+    let get_f64 = |array: &Option<ArrayRef>, row: usize| {
+        array.as_ref().filter(|array| array.is_valid(row)).map(|array| array.as_primitive::<Float64Type>().value(row))
+    };
+    let get_string = |array: &Option<ArrayRef>, row: usize| {
+        array.as_ref().filter(|array| array.is_valid(row)).map(|array| array.as_string::<i32>().value(row).to_string())
+    };
+
+    (0..batch.num_rows())
+        .map(|row| {
+            let id = ids
+                .is_valid(row)
+                .then(|| ids.as_string::<i32>().value(row).to_string())
+                .ok_or_else(|| format!("row {row}: job id is not set"))?;
+
+            let location = match (get_f64(&lats, row), get_f64(&lngs, row)) {
+                (Some(lat), Some(lng)) => Location::Coordinate { lat, lng },
+                _ => indices
+                    .as_ref()
+                    .filter(|array| array.is_valid(row))
+                    .map(|array| array.as_primitive::<Int64Type>().value(row))
+                    .and_then(|index| usize::try_from(index).ok())
+                    .map(|index| Location::Reference { index })
+                    .ok_or_else(|| format!("row {row}: job '{id}' has no valid location"))?,
+            };
+
+            let demand = demands
+                .as_ref()
+                .filter(|array| array.is_valid(row))
+                .map_or(0, |array| array.as_primitive::<Int32Type>().value(row));
+
+            let times = match (get_string(&tw_starts, row), get_string(&tw_ends, row)) {
+                (Some(start), Some(end)) => Some(vec![vec![start, end]]),
+                (None, None) => None,
+                _ => return Err(format!("row {row}: job '{id}' has incomplete time window").into()),
+            };
+
+            let task = JobTask {
+                places: vec![JobPlace {
+                    location,
+                    duration: get_f64(&durations, row).unwrap_or_default(),
+                    times,
+                    tag: get_string(&tags, row),
+                    cost: None,
+                }],
+                demand: (demand != 0).then(|| vec![demand.abs()]),
+                order: None,
+            };
+
+            Ok((id, demand, task))
+        })
+        .collect()
+}
+
+fn get_column(batch: &RecordBatch, name: &str, data_type: DataType) -> GenericResult<Option<ArrayRef>> {
+    batch
+        .column_by_name(name)
+        .map(|column| cast(column, &data_type).map_err(|err| format!("cannot read column '{name}': '{err}'").into()))
+        .transpose()
+}
+
+fn create_job(id: String, tasks: Vec<(i32, JobTask)>) -> Job {
+    let get_tasks = |filter: fn(i32) -> bool| {
+        let tasks =
+            tasks.iter().filter(|(demand, _)| filter(*demand)).map(|(_, task)| task.clone()).collect::<Vec<_>>();
+        if tasks.is_empty() { None } else { Some(tasks) }
+    };
+
+    Job {
+        pickups: get_tasks(|demand| demand > 0),
+        deliveries: get_tasks(|demand| demand < 0),
+        replacements: None,
+        services: get_tasks(|demand| demand == 0),
+        id,
+        skills: None,
+        value: None,
+        group: None,
+        compatibility: None,
+        priority: None,
+        category: None,
+        crew: None,
+    }
+}
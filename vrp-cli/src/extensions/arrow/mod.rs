@@ -0,0 +1,58 @@
+//! Provides Arrow and Parquet interop for jobs and solution tables, so problems and solutions in pragmatic
+//! format can be exchanged with dataframe pipelines without json conversion.
+//!
+//! Jobs table has one row per job task with the following columns (only `id` is required):
+//! - `id`: job id, rows with the same id are grouped into one job
+//! - `lat`, `lng`: task location as geocoordinate
+//! - `location_index`: task location as index in routing matrix, used when coordinate is not set
+//! - `demand`: positive value means pickup, negative is delivery, zero or null is service
+//! - `duration`: task duration in seconds
+//! - `tw_start`, `tw_end`: task time window in RFC3339 format
+//! - `tag`: task tag
+//!
+//! Solution table has one row per stop with the same columns as csv output, see `StopRecord`.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/arrow/arrow_test.rs"]
+mod arrow_test;
+
+mod jobs;
+pub use self::jobs::read_jobs_from_batches;
+
+mod solution;
+pub use self::solution::{get_stops_schema, to_stops_batch};
+
+use arrow::record_batch::RecordBatch;
+use parquet::arrow::ArrowWriter;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use parquet::file::reader::ChunkReader;
+use std::io::Write;
+use vrp_core::prelude::{GenericError, GenericResult};
+use vrp_pragmatic::format::problem::Job;
+use vrp_pragmatic::format::solution::Solution;
+
+/// Reads jobs from parquet file with jobs table.
+pub fn read_parquet_jobs<R: ChunkReader + 'static>(reader: R) -> GenericResult<Vec<Job>> {
+    let batches = ParquetRecordBatchReaderBuilder::try_new(reader)
+        .and_then(|builder| builder.build())
+        .map_err(|err| format!("cannot read parquet: '{err}'"))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|err| format!("cannot read parquet: '{err}'"))?;
+
+    read_jobs_from_batches(batches.as_slice())
+}
+
+/// Writes solution stops table as parquet file.
+pub fn write_parquet_stops<W: Write + Send>(solution: &Solution, writer: W) -> GenericResult<()> {
+    write_parquet(&to_stops_batch(solution)?, writer)
+}
+
+fn write_parquet<W: Write + Send>(batch: &RecordBatch, writer: W) -> GenericResult<()> {
+    let write_err = |err| GenericError::from(format!("cannot write parquet: '{err}'"));
+
+    let mut writer = ArrowWriter::try_new(writer, batch.schema(), None).map_err(write_err)?;
+    writer.write(batch).map_err(write_err)?;
+    writer.close().map_err(write_err)?;
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use super::*;
+use arrow::array::{ArrayRef, Float64Builder, Int32Builder, Int64Builder, ListBuilder, StringBuilder, UInt64Builder};
+use arrow::datatypes::{DataType, Field, Schema, SchemaRef};
+use std::sync::Arc;
+use vrp_pragmatic::format::Location;
+use vrp_pragmatic::format::solution::get_stop_records;
+
+/// Returns schema of solution stops table.
+pub fn get_stops_schema() -> SchemaRef {
+    let list_of = |data_type: DataType| DataType::List(Arc::new(Field::new("item", data_type, true)));
+
+    Arc::new(Schema::new(vec![
+        Field::new("tour_id", DataType::Utf8, false),
+        Field::new("seq", DataType::UInt64, false),
+        Field::new("job_id", list_of(DataType::Utf8), false),
+        Field::new("lat", DataType::Float64, true),
+        Field::new("lng", DataType::Float64, true),
+        Field::new("location_index", DataType::UInt64, true),
+        Field::new("arrival", DataType::Utf8, false),
+        Field::new("departure", DataType::Utf8, false),
+        Field::new("wait", DataType::Int64, false),
+        Field::new("load_in", list_of(DataType::Int32), false),
+        Field::new("load_out", list_of(DataType::Int32), false),
+        Field::new("distance", DataType::Int64, false),
+    ]))
+}
+
+/// Converts solution into record batch with one row per stop.
+pub fn to_stops_batch(solution: &Solution) -> GenericResult<RecordBatch> {
+    let records = get_stop_records(solution).map_err(|err| format!("cannot get solution stops: '{err}'"))?;
+
+    let mut tour_ids = StringBuilder::new();
+    let mut seqs = UInt64Builder::new();
+    let mut job_ids = ListBuilder::new(StringBuilder::new());
+    let mut lats = Float64Builder::new();
+    let mut lngs = Float64Builder::new();
+    let mut indices = UInt64Builder::new();
+    let mut arrivals = StringBuilder::new();
+    let mut departures = StringBuilder::new();
+    let mut waits = Int64Builder::new();
+    let mut loads_in = ListBuilder::new(Int32Builder::new());
+    let mut loads_out = ListBuilder::new(Int32Builder::new());
+    let mut distances = Int64Builder::new();
+
+    records.into_iter().for_each(|record| {
+        tour_ids.append_value(record.tour_id);
+        seqs.append_value(record.seq as u64);
+        job_ids.append_value(record.job_ids.into_iter().map(Some));
+
+        let (lat, lng, index) = match record.location {
+            Some(Location::Coordinate { lat, lng }) => (Some(lat), Some(lng), None),
+            Some(Location::Reference { index }) => (None, None, Some(index as u64)),
+            _ => (None, None, None),
+        };
+        lats.append_option(lat);
+        lngs.append_option(lng);
+        indices.append_option(index);
+
+        arrivals.append_value(record.arrival);
+        departures.append_value(record.departure);
+        waits.append_value(record.wait);
+        loads_in.append_value(record.load_in.into_iter().map(Some));
+        loads_out.append_value(record.load_out.into_iter().map(Some));
+        distances.append_value(record.distance);
+    });
+
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(tour_ids.finish()),
+        Arc::new(seqs.finish()),
+        Arc::new(job_ids.finish()),
+        Arc::new(lats.finish()),
+        Arc::new(lngs.finish()),
+        Arc::new(indices.finish()),
+        Arc::new(arrivals.finish()),
+        Arc::new(departures.finish()),
+        Arc::new(waits.finish()),
+        Arc::new(loads_in.finish()),
+        Arc::new(loads_out.finish()),
+        Arc::new(distances.finish()),
+    ];
+
+    RecordBatch::try_new(get_stops_schema(), columns)
+        .map_err(|err| format!("cannot create solution stops table: '{err}'").into())
+}
@@ -0,0 +1,265 @@
+//! A helper module which contains functionality to run solver on a set of problem instances and aggregate
+//! statistics of multiple runs.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/benchmark/benchmark_test.rs"]
+mod benchmark_test;
+
+use crate::extensions::solve::config::{Config, TerminationConfig, create_builder_from_config};
+use crate::extensions::solve::formats::ProblemReader;
+use crate::extensions::solve::summary::SolveSummary;
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use vrp_core::prelude::*;
+use vrp_core::rosomaxa::algorithms::math::{get_mean_slice, get_stdev};
+use vrp_core::utils::Timer;
+
+/// Specifies a named solver configuration used in benchmark.
+pub struct BenchmarkConfig {
+    /// A name of configuration used in the report.
+    pub name: String,
+    /// Solver configuration.
+    pub config: Config,
+}
+
+/// Specifies benchmark run settings.
+pub struct BenchmarkSettings {
+    /// Problem format.
+    pub format: String,
+    /// Amount of runs per instance and configuration.
+    pub runs: usize,
+    /// Max time in seconds of each run, overrides configuration's termination.
+    pub max_time: Option<usize>,
+    /// Max generations of each run, overrides configuration's termination.
+    pub max_generations: Option<usize>,
+}
+
+/// Represents aggregated statistic of runs for one instance and solver configuration.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BenchmarkRecord {
+    /// Instance name.
+    pub instance: String,
+    /// Configuration name.
+    pub config: String,
+    /// Amount of runs.
+    pub runs: usize,
+    /// The best (minimal) cost.
+    pub min_cost: Float,
+    /// Mean cost.
+    pub mean_cost: Float,
+    /// The worst (maximal) cost.
+    pub max_cost: Float,
+    /// Standard deviation of cost.
+    pub stdev_cost: Float,
+    /// Minimal amount of tours.
+    pub min_tours: usize,
+    /// Maximal amount of unassigned jobs.
+    pub max_unassigned: usize,
+    /// Mean run duration in seconds.
+    pub mean_duration: Float,
+    /// Cost of the best known solution, if known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub best_known_cost: Option<Float>,
+    /// Gap of the best cost to the best known solution in percents.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_gap: Option<Float>,
+    /// Gap of the mean cost to the best known solution in percents.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mean_gap: Option<Float>,
+}
+
+/// Represents side by side comparison of two configurations on one instance.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BenchmarkComparison {
+    /// Instance name.
+    pub instance: String,
+    /// Baseline configuration name.
+    pub baseline: String,
+    /// Candidate configuration name.
+    pub candidate: String,
+    /// Relative difference of the best costs in percents: negative value means that candidate is better.
+    pub min_cost_diff: Float,
+    /// Relative difference of the mean costs in percents: negative value means that candidate is better.
+    pub mean_cost_diff: Float,
+}
+
+/// Represents benchmark results.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BenchmarkReport {
+    /// Aggregated statistics per instance and configuration.
+    pub records: Vec<BenchmarkRecord>,
+    /// Side by side comparison of the first two configurations, if more than one configuration is used.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub comparisons: Vec<BenchmarkComparison>,
+}
+
+/// Returns sorted list of instance files in the directory.
+pub fn get_benchmark_instances(dir: &Path) -> GenericResult<Vec<PathBuf>> {
+    let entries =
+        std::fs::read_dir(dir).map_err(|err| format!("cannot read directory '{}': '{err}'", dir.display()))?;
+
+    let mut paths = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.is_file())
+        .collect::<Vec<_>>();
+    paths.sort();
+
+    Ok(paths)
+}
+
+/// Runs benchmark: solves every instance with every configuration given amount of times. Instances which cannot be
+/// read by problem reader are skipped.
+pub fn run_benchmark(
+    instances: &[PathBuf],
+    ProblemReader(problem_reader): &ProblemReader,
+    configs: &[BenchmarkConfig],
+    settings: &BenchmarkSettings,
+    logger: InfoLogger,
+) -> GenericResult<BenchmarkReport> {
+    if settings.runs == 0 {
+        return Err("amount of runs should be bigger than 0".into());
+    }
+
+    let mut report = BenchmarkReport::default();
+
+    for path in instances {
+        let path_str = path.to_string_lossy().to_string();
+        let problem =
+            File::open(path).map_err(GenericError::from).and_then(|file| problem_reader(file, None)).map(Arc::new);
+
+        let problem = match problem {
+            Ok(problem) => problem,
+            Err(err) => {
+                (logger)(format!("skipping '{path_str}': cannot read problem: '{err}'").as_str());
+                continue;
+            }
+        };
+
+        let records = configs
+            .iter()
+            .map(|benchmark_config| {
+                let summaries = (0..settings.runs)
+                    .map(|run| {
+                        (logger)(
+                            format!("solving '{path_str}' with '{}', run {}", benchmark_config.name, run + 1).as_str(),
+                        );
+                        solve_once(problem.clone(), &path_str, benchmark_config, settings)
+                    })
+                    .collect::<GenericResult<Vec<_>>>()?;
+
+                Ok(create_record(benchmark_config.name.as_str(), summaries.as_slice()))
+            })
+            .collect::<GenericResult<Vec<_>>>()?;
+
+        if let [baseline, candidate, ..] = records.as_slice() {
+            report.comparisons.push(create_comparison(baseline, candidate));
+        }
+
+        report.records.extend(records);
+    }
+
+    Ok(report)
+}
+
+/// Writes benchmark report as json.
+pub fn write_benchmark_json<W: Write>(report: &BenchmarkReport, writer: &mut BufWriter<W>) -> GenericResult<()> {
+    serde_json::to_writer_pretty(writer, report).map_err(|err| format!("cannot write benchmark report: '{err}'").into())
+}
+
+/// Writes benchmark records as csv table with one row per instance and configuration.
+pub fn write_benchmark_csv<W: Write>(report: &BenchmarkReport, writer: &mut BufWriter<W>) -> GenericResult<()> {
+    let format_optional = |value: Option<Float>| value.map(|value| format!("{value:.4}")).unwrap_or_default();
+
+    writeln!(
+        writer,
+        "instance,config,runs,min_cost,mean_cost,max_cost,stdev_cost,min_tours,max_unassigned,mean_duration,\
+         best_known_cost,min_gap,mean_gap"
+    )?;
+
+    report.records.iter().try_for_each(|record| {
+        writeln!(
+            writer,
+            "{},{},{},{:.4},{:.4},{:.4},{:.4},{},{},{:.4},{},{},{}",
+            record.instance,
+            record.config,
+            record.runs,
+            record.min_cost,
+            record.mean_cost,
+            record.max_cost,
+            record.stdev_cost,
+            record.min_tours,
+            record.max_unassigned,
+            record.mean_duration,
+            format_optional(record.best_known_cost),
+            format_optional(record.min_gap),
+            format_optional(record.mean_gap),
+        )
+    })?;
+
+    Ok(())
+}
+
+fn solve_once(
+    problem: Arc<Problem>,
+    path: &str,
+    benchmark_config: &BenchmarkConfig,
+    settings: &BenchmarkSettings,
+) -> GenericResult<SolveSummary> {
+    let mut config = benchmark_config.config.clone();
+    if settings.max_time.is_some() || settings.max_generations.is_some() {
+        let termination = config.termination.take();
+        config.termination = Some(TerminationConfig {
+            max_time: settings.max_time.or_else(|| termination.as_ref().and_then(|t| t.max_time)),
+            max_generations: settings.max_generations.or_else(|| termination.as_ref().and_then(|t| t.max_generations)),
+            variation: termination.and_then(|t| t.variation),
+        });
+    }
+
+    let solver_config = create_builder_from_config(problem.clone(), Vec::default(), &config)?.build()?;
+
+    let timer = Timer::start();
+    let solution = Solver::new(problem, solver_config).solve()?;
+
+    Ok(SolveSummary::new(path, settings.format.as_str(), &solution, timer.elapsed_secs_as_float()))
+}
+
+fn create_record(config: &str, summaries: &[SolveSummary]) -> BenchmarkRecord {
+    let costs = summaries.iter().map(|summary| summary.cost).collect::<Vec<_>>();
+    let durations = summaries.iter().map(|summary| summary.duration).collect::<Vec<_>>();
+    let gaps =
+        summaries.iter().filter_map(|summary| summary.best_known.as_ref().map(|bks| bks.gap)).collect::<Vec<_>>();
+
+    BenchmarkRecord {
+        instance: summaries.first().map(|summary| summary.instance.clone()).unwrap_or_default(),
+        config: config.to_string(),
+        runs: summaries.len(),
+        min_cost: costs.iter().cloned().fold(Float::MAX, Float::min),
+        mean_cost: get_mean_slice(costs.as_slice()),
+        max_cost: costs.iter().cloned().fold(Float::MIN, Float::max),
+        stdev_cost: get_stdev(costs.as_slice()),
+        min_tours: summaries.iter().map(|summary| summary.tours).min().unwrap_or_default(),
+        max_unassigned: summaries.iter().map(|summary| summary.unassigned).max().unwrap_or_default(),
+        mean_duration: get_mean_slice(durations.as_slice()),
+        best_known_cost: summaries.first().and_then(|summary| summary.best_known.as_ref().map(|bks| bks.cost)),
+        min_gap: (!gaps.is_empty()).then(|| gaps.iter().cloned().fold(Float::MAX, Float::min)),
+        mean_gap: (!gaps.is_empty()).then(|| get_mean_slice(gaps.as_slice())),
+    }
+}
+
+fn create_comparison(baseline: &BenchmarkRecord, candidate: &BenchmarkRecord) -> BenchmarkComparison {
+    let get_diff = |base: Float, other: Float| if base == 0. { 0. } else { (other - base) / base * 100. };
+
+    BenchmarkComparison {
+        instance: baseline.instance.clone(),
+        baseline: baseline.config.clone(),
+        candidate: candidate.config.clone(),
+        min_cost_diff: get_diff(baseline.min_cost, candidate.min_cost),
+        mean_cost_diff: get_diff(baseline.mean_cost, candidate.mean_cost),
+    }
+}
@@ -1,15 +1,27 @@
-//! A helper module which contains functionality to run feasibility checks on solution.
+//! A helper module which contains functionality to run feasibility checks on solution and validate problem definition.
 
 #[cfg(test)]
 #[path = "../../../tests/unit/extensions/check/check_test.rs"]
 mod check_test;
 
-use std::io::{BufReader, Read};
+use serde::Serialize;
+use std::io::{BufReader, BufWriter, Read, Write};
 use std::sync::Arc;
-use vrp_core::prelude::GenericError;
-use vrp_pragmatic::checker::CheckerContext;
-use vrp_pragmatic::format::problem::{PragmaticProblem, deserialize_matrix, deserialize_problem};
-use vrp_pragmatic::format::solution::deserialize_solution;
+use vrp_core::prelude::{GenericError, GenericResult};
+use vrp_pragmatic::checker::{CheckerContext, CheckerOptions, CheckerReport};
+use vrp_pragmatic::format::problem::{PragmaticProblem, deserialize_matrix};
+use vrp_pragmatic::format::{deserialize_problem_compat, deserialize_solution_compat};
+use vrp_pragmatic::validation::{Diagnostic, Severity, get_problem_diagnostics};
+
+/// A report of pragmatic problem validation.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProblemReport {
+    /// True if problem has no errors and can be solved.
+    pub is_valid: bool,
+    /// A list of found issues.
+    pub diagnostics: Vec<Diagnostic>,
+}
 
 /// Checks pragmatic solution feasibility.
 pub fn check_pragmatic_solution<F: Read>(
@@ -17,11 +29,40 @@ pub fn check_pragmatic_solution<F: Read>(
     solution_reader: BufReader<F>,
     matrices_readers: Option<Vec<BufReader<F>>>,
 ) -> Result<(), Vec<GenericError>> {
-    let problem =
-        deserialize_problem(problem_reader).map_err(|errs| vec![format!("cannot read problem: '{errs}'").into()])?;
+    create_checker_context(problem_reader, solution_reader, matrices_readers).and_then(|ctx| ctx.check())
+}
+
+/// Checks pragmatic solution feasibility using given tolerances and returns a report with found errors and warnings.
+pub fn check_pragmatic_solution_with_report<F: Read>(
+    problem_reader: BufReader<F>,
+    solution_reader: BufReader<F>,
+    matrices_readers: Option<Vec<BufReader<F>>>,
+    options: CheckerOptions,
+) -> CheckerReport {
+    create_checker_context(problem_reader, solution_reader, matrices_readers)
+        .map(|ctx| ctx.with_options(options).check_with_report())
+        .unwrap_or_else(|errs| CheckerReport {
+            is_feasible: false,
+            errors: errs.into_iter().map(|err| err.to_string()).collect(),
+            warnings: vec![],
+        })
+}
+
+/// Writes solution check report in json format.
+pub fn write_checker_report<W: Write>(report: &CheckerReport, writer: &mut BufWriter<W>) -> GenericResult<()> {
+    serde_json::to_writer_pretty(writer, report).map_err(|err| format!("cannot write report: '{err}'").into())
+}
+
+fn create_checker_context<F: Read>(
+    problem_reader: BufReader<F>,
+    solution_reader: BufReader<F>,
+    matrices_readers: Option<Vec<BufReader<F>>>,
+) -> Result<CheckerContext, Vec<GenericError>> {
+    let problem = deserialize_problem_compat(problem_reader)
+        .map_err(|errs| vec![format!("cannot read problem: '{errs}'").into()])?;
 
-    let solution =
-        deserialize_solution(solution_reader).map_err(|err| vec![format!("cannot read solution: '{err}'").into()])?;
+    let solution = deserialize_solution_compat(solution_reader)
+        .map_err(|err| vec![format!("cannot read solution: '{err}'").into()])?;
 
     let matrices = match matrices_readers {
         Some(matrices_readers) => Some(
@@ -42,5 +83,39 @@ pub fn check_pragmatic_solution<F: Read>(
             .map_err(|errs| vec![format!("cannot read pragmatic problem: '{errs}'").into()])?,
     );
 
-    CheckerContext::new(core_problem, problem, matrices, solution).and_then(|ctx| ctx.check())
+    CheckerContext::new(core_problem, problem, matrices, solution)
+}
+
+/// Runs validation rules on pragmatic problem without solving it.
+pub fn check_pragmatic_problem<F: Read>(
+    problem_reader: BufReader<F>,
+    matrices_readers: Option<Vec<BufReader<F>>>,
+) -> ProblemReport {
+    let diagnostics = deserialize_problem_compat(problem_reader)
+        .and_then(|problem| {
+            let matrices = matrices_readers
+                .map(|readers| readers.into_iter().map(deserialize_matrix).collect::<Result<Vec<_>, _>>())
+                .transpose()?;
+
+            let diagnostics = get_problem_diagnostics(&problem, matrices.as_ref());
+            if diagnostics.iter().any(|diagnostic| diagnostic.severity == Severity::Error) {
+                return Ok(diagnostics);
+            }
+
+            // NOTE some issues can be detected only when problem model is created
+            Ok(match (problem, matrices).read_pragmatic() {
+                Ok(_) => diagnostics,
+                Err(errs) => errs.into_iter().map(Diagnostic::from).chain(diagnostics).collect(),
+            })
+        })
+        .unwrap_or_else(|errs| errs.into_iter().map(Diagnostic::from).collect());
+
+    let is_valid = diagnostics.iter().all(|diagnostic| diagnostic.severity != Severity::Error);
+
+    ProblemReport { is_valid, diagnostics }
+}
+
+/// Writes problem validation report in json format.
+pub fn write_problem_report<W: Write>(report: &ProblemReport, writer: &mut BufWriter<W>) -> GenericResult<()> {
+    serde_json::to_writer_pretty(writer, report).map_err(|err| format!("cannot write report: '{err}'").into())
 }
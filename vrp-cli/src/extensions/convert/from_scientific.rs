@@ -0,0 +1,239 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/convert/from_scientific_test.rs"]
+mod from_scientific_test;
+
+use super::*;
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read};
+use time::OffsetDateTime;
+use time::format_description::well_known::Rfc3339;
+use vrp_core::prelude::{Float, GenericResult};
+use vrp_pragmatic::format::Location;
+use vrp_pragmatic::format::problem::*;
+
+/// A timestamp which corresponds to zero time of scientific problem: 2000-01-01T00:00:00Z.
+const TIME_ORIGIN: i64 = 946_684_800;
+
+/// A name of routing profile used by converted problem.
+const PROFILE_NAME: &str = "car";
+
+/// Keeps result of conversion from scientific format.
+pub struct PragmaticConversion {
+    /// A pragmatic problem which uses location references.
+    pub problem: Problem,
+    /// A routing matrix with euclidean distances between referenced locations.
+    pub matrix: Matrix,
+    /// Features which are dropped or approximated.
+    pub dropped: Vec<String>,
+}
+
+/// Converts scientific problem into pragmatic problem with routing matrix. Scientific time units are mapped
+/// to seconds since 2000-01-01T00:00:00Z and euclidean distances are rounded to integers.
+pub fn convert_from_scientific<R: Read>(
+    reader: BufReader<R>,
+    format: ScientificFormat,
+) -> GenericResult<PragmaticConversion> {
+    let mut dropped = DroppedFeatures::default();
+    let (vehicle_line, customers) = read_lines(reader, format)?;
+
+    let (depot, customers) = customers.split_first().ok_or_else(|| GenericError::from("cannot find depot line"))?;
+
+    let mut locations = vec![depot.location];
+    let mut location_index = HashMap::from([(depot.location, 0)]);
+    let mut get_location = |location: (i64, i64)| {
+        let index = *location_index.entry(location).or_insert_with(|| {
+            locations.push(location);
+            locations.len() - 1
+        });
+        Location::Reference { index }
+    };
+
+    let jobs = match format {
+        ScientificFormat::Solomon => customers
+            .iter()
+            .map(|customer| {
+                Ok(Job {
+                    deliveries: Some(vec![create_task(customer, get_location(customer.location))?]),
+                    ..create_job(customer.id.to_string())
+                })
+            })
+            .collect::<GenericResult<Vec<_>>>()?,
+        ScientificFormat::LiLim => {
+            let customers = customers.iter().map(|customer| (customer.id, customer)).collect::<HashMap<_, _>>();
+            let mut pickups = customers.values().filter(|customer| customer.demand > 0).collect::<Vec<_>>();
+            pickups.sort_by_key(|customer| customer.id);
+
+            pickups
+                .into_iter()
+                .map(|pickup| {
+                    let delivery =
+                        customers.get(&pickup.relation.1).filter(|delivery| delivery.demand < 0).ok_or_else(|| {
+                            format!("cannot find delivery {} for pickup {}", pickup.relation.1, pickup.id)
+                        })?;
+
+                    Ok(Job {
+                        pickups: Some(vec![create_task(pickup, get_location(pickup.location))?]),
+                        deliveries: Some(vec![create_task(delivery, get_location(delivery.location))?]),
+                        ..create_job(pickup.id.to_string())
+                    })
+                })
+                .collect::<GenericResult<Vec<_>>>()?
+        }
+    };
+
+    let (vehicles, capacity) = (vehicle_line[0], vehicle_line[1]);
+    dropped.add_if(vehicle_line.get(2).is_some_and(|&speed| speed != 1), "vehicle speed");
+
+    let depot_location = get_location(depot.location);
+    let shift = VehicleShift {
+        start: ShiftStart { earliest: format_time(depot.tw.0)?, latest: None, location: depot_location.clone() },
+        end: Some(ShiftEnd { earliest: None, latest: format_time(depot.tw.1)?, location: depot_location }),
+        breaks: None,
+        reloads: None,
+        recharges: None,
+        capacity_change: None,
+    };
+
+    let matrix = create_matrix(locations.as_slice(), &mut dropped);
+
+    let problem = Problem {
+        plan: Plan { jobs, relations: None, clustering: None, incompatibilities: None },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                type_id: "vehicle".to_string(),
+                vehicle_ids: (1..=vehicles).map(|idx| format!("vehicle_{idx}")).collect(),
+                profile: VehicleProfile { matrix: PROFILE_NAME.to_string(), scale: None },
+                costs: VehicleCosts { fixed: None, distance: 1., time: 0., tiers: None },
+                shifts: vec![shift],
+                capacity: vec![capacity as i32],
+                skills: None,
+                limits: None,
+            }],
+            profiles: vec![MatrixProfile { name: PROFILE_NAME.to_string(), speed: None, restrictions: None }],
+            resources: None,
+            depots: None,
+        },
+        objectives: Some(vec![
+            Objective::MinimizeUnassigned { breaks: None },
+            Objective::MinimizeTours,
+            Objective::MinimizeDistance,
+        ]),
+        timezone: None,
+    };
+
+    Ok(PragmaticConversion { problem, matrix, dropped: dropped.into_messages() })
+}
+
+/// Reads vehicle and customer lines skipping empty and header lines.
+fn read_lines<R: Read>(reader: BufReader<R>, format: ScientificFormat) -> GenericResult<(Vec<i64>, Vec<CustomerLine>)> {
+    let mut lines = reader.lines().filter_map(|line| match line {
+        Ok(line) if line.trim().is_empty() || line.chars().any(|ch| ch.is_alphabetic()) => None,
+        Ok(line) => Some(
+            line.split_whitespace()
+                .map(|value| value.parse::<i64>().map_err(|err| format!("cannot parse '{line}': {err}").into()))
+                .collect::<GenericResult<Vec<_>>>(),
+        ),
+        Err(err) => Some(Err(err.to_string().into())),
+    });
+
+    let vehicle_line = lines.next().ok_or_else(|| GenericError::from("cannot find vehicle line"))??;
+    if !(2..=3).contains(&vehicle_line.len()) || vehicle_line.iter().take(2).any(|&value| value < 0) {
+        return Err("cannot parse vehicle number or/and capacity".into());
+    }
+
+    let customers = lines
+        .map(|values| {
+            let values = values?;
+            match (format, values.as_slice()) {
+                (ScientificFormat::Solomon, &[id, x, y, demand, start, end, service]) => {
+                    create_customer(id, (x, y), demand, (start, end), service, (0, 0))
+                }
+                (ScientificFormat::LiLim, &[id, x, y, demand, start, end, service, pickup, delivery]) => {
+                    create_customer(id, (x, y), demand, (start, end), service, (pickup, delivery))
+                }
+                _ => Err(format!("cannot read customer line: {values:?}").into()),
+            }
+        })
+        .collect::<GenericResult<Vec<_>>>()?;
+
+    Ok((vehicle_line, customers))
+}
+
+fn create_customer(
+    id: i64,
+    location: (i64, i64),
+    demand: i64,
+    tw: (i64, i64),
+    service: i64,
+    (pickup, delivery): (i64, i64),
+) -> GenericResult<CustomerLine> {
+    let to_usize = |value: i64| usize::try_from(value).map_err(|_| format!("unexpected negative value: {value}"));
+
+    Ok(CustomerLine {
+        id: to_usize(id)?,
+        location,
+        demand: i32::try_from(demand).map_err(|err| err.to_string())?,
+        tw,
+        service,
+        relation: (to_usize(pickup)?, to_usize(delivery)?),
+    })
+}
+
+fn create_matrix(locations: &[(i64, i64)], dropped: &mut DroppedFeatures) -> Matrix {
+    let distances = locations
+        .iter()
+        .flat_map(|&(x1, y1)| {
+            locations.iter().map(move |&(x2, y2)| (((x1 - x2) as f64).powi(2) + ((y1 - y2) as f64).powi(2)).sqrt())
+        })
+        .collect::<Vec<_>>();
+
+    dropped.add_if(distances.iter().any(|distance| distance.fract() != 0.), "fractional euclidean distances");
+
+    let distances = distances.into_iter().map(|distance| distance.round() as i64).collect::<Vec<_>>();
+
+    Matrix {
+        profile: Some(PROFILE_NAME.to_string()),
+        timestamp: None,
+        travel_times: distances.clone(),
+        distances,
+        error_codes: None,
+    }
+}
+
+fn create_job(id: String) -> Job {
+    Job {
+        id,
+        pickups: None,
+        deliveries: None,
+        replacements: None,
+        services: None,
+        skills: None,
+        value: None,
+        group: None,
+        compatibility: None,
+        priority: None,
+        category: None,
+        crew: None,
+    }
+}
+
+fn create_task(customer: &CustomerLine, location: Location) -> GenericResult<JobTask> {
+    Ok(JobTask {
+        places: vec![JobPlace {
+            location,
+            duration: customer.service as Float,
+            times: Some(vec![vec![format_time(customer.tw.0)?, format_time(customer.tw.1)?]]),
+            tag: None,
+            cost: None,
+        }],
+        demand: Some(vec![customer.demand.abs()]),
+        order: None,
+    })
+}
+
+fn format_time(time: i64) -> GenericResult<String> {
+    OffsetDateTime::from_unix_timestamp(TIME_ORIGIN + time)
+        .map_err(|err| err.to_string())
+        .and_then(|time| time.format(&Rfc3339).map_err(|err| err.to_string()))
+        .map_err(|err| format!("cannot format time: {err}").into())
+}
@@ -0,0 +1,66 @@
+//! A helper module which contains functionality to convert problems between pragmatic and scientific formats.
+
+mod from_scientific;
+pub use self::from_scientific::*;
+
+mod to_scientific;
+pub use self::to_scientific::*;
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use vrp_core::prelude::GenericError;
+
+/// Specifies a scientific format of the problem.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ScientificFormat {
+    /// Solomon format: capacitated vehicle routing problem with time windows.
+    Solomon,
+    /// Li&Lim format: pickup and delivery problem with time windows.
+    LiLim,
+}
+
+impl FromStr for ScientificFormat {
+    type Err = GenericError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "solomon" => Ok(Self::Solomon),
+            "lilim" | "pdptw" => Ok(Self::LiLim),
+            _ => Err(format!("unknown scientific format: '{value}'").into()),
+        }
+    }
+}
+
+/// A customer line of scientific problem. Depot has zero id.
+#[derive(Clone, Debug, PartialEq)]
+struct CustomerLine {
+    id: usize,
+    location: (i64, i64),
+    demand: i32,
+    tw: (i64, i64),
+    service: i64,
+    /// Pickup and delivery customer ids of the counterpart in Li&Lim format, zero otherwise.
+    relation: (usize, usize),
+}
+
+/// Collects features which cannot be represented in the target format.
+#[derive(Default)]
+struct DroppedFeatures {
+    features: BTreeMap<String, usize>,
+}
+
+impl DroppedFeatures {
+    fn add(&mut self, feature: &str) {
+        *self.features.entry(feature.to_string()).or_default() += 1;
+    }
+
+    fn add_if(&mut self, condition: bool, feature: &str) {
+        if condition {
+            self.add(feature);
+        }
+    }
+
+    fn into_messages(self) -> Vec<String> {
+        self.features.into_iter().map(|(feature, count)| format!("{feature} (x{count})")).collect()
+    }
+}
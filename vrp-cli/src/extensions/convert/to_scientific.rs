@@ -0,0 +1,342 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/convert/to_scientific_test.rs"]
+mod to_scientific_test;
+
+use super::*;
+use std::io::{BufWriter, Write};
+use time::OffsetDateTime;
+use time::format_description::well_known::Rfc3339;
+use vrp_core::prelude::{Float, GenericResult};
+use vrp_pragmatic::format::Location;
+use vrp_pragmatic::format::problem::*;
+
+/// A default approximation speed in meters per second, the same as pragmatic format uses.
+const DEFAULT_SPEED: f64 = 10.;
+
+/// Earth radius in meters.
+const EARTH_RADIUS: f64 = 6_371_000.;
+
+/// Converts pragmatic problem into scientific format and writes it. Coordinates are projected on a plane and scaled
+/// by the profile speed, so that one unit of distance equals to one second of travel. Times are rebased to the
+/// earliest shift start. Returns a list of features which are dropped or approximated.
+pub fn convert_to_scientific<W: Write>(
+    problem: &Problem,
+    format: ScientificFormat,
+    writer: &mut BufWriter<W>,
+) -> GenericResult<Vec<String>> {
+    let mut dropped = DroppedFeatures::default();
+
+    let projection = Projection::new(problem)?;
+    let time_origin = get_time_origin(problem)?;
+    let to_time = |time: &str| parse_time(time).map(|time| time - time_origin);
+
+    let (vehicles, capacity, depot) = get_fleet(problem, &projection, &to_time, &mut dropped)?;
+    add_plan_features(problem, &mut dropped);
+
+    let mut customers = vec![depot];
+    problem.plan.jobs.iter().try_for_each(|job| {
+        let tasks = match format {
+            ScientificFormat::Solomon => get_solomon_tasks(job, &mut dropped),
+            ScientificFormat::LiLim => get_lilim_tasks(job, &mut dropped),
+        };
+
+        if tasks.is_empty() {
+            return Ok(());
+        }
+
+        add_job_features(job, &mut dropped);
+
+        let first_id = customers.len();
+        tasks.into_iter().enumerate().try_for_each(|(idx, (task, demand))| {
+            let customer =
+                create_customer(first_id + idx, task, demand, &customers[0], &projection, &to_time, &mut dropped)?;
+            customers.push(customer);
+
+            Ok::<_, GenericError>(())
+        })?;
+
+        if format == ScientificFormat::LiLim {
+            customers[first_id].relation = (0, first_id + 1);
+            customers[first_id + 1].relation = (first_id, 0);
+        }
+
+        Ok::<_, GenericError>(())
+    })?;
+
+    match format {
+        ScientificFormat::Solomon => write_solomon(writer, vehicles, capacity, customers.as_slice()),
+        ScientificFormat::LiLim => write_lilim(writer, vehicles, capacity, customers.as_slice()),
+    }
+    .map_err(|err| format!("cannot write problem: '{err}'"))?;
+
+    Ok(dropped.into_messages())
+}
+
+/// Projects geocoordinates on a plane using equirectangular projection.
+struct Projection {
+    min: (f64, f64),
+    lng_scale: f64,
+    speed: f64,
+}
+
+impl Projection {
+    fn new(problem: &Problem) -> GenericResult<Self> {
+        #![allow(clippy::unnecessary_cast)]
+        let (min_lat, min_lng, max_lat) = get_locations(problem)
+            .map(|location| match location {
+                Location::Coordinate { lat, lng } => Ok((*lat, *lng)),
+                _ => Err(GenericError::from("only locations specified by coordinates can be converted")),
+            })
+            .try_fold((f64::MAX, f64::MAX, f64::MIN), |(min_lat, min_lng, max_lat), point| {
+                point.map(|(lat, lng)| (min_lat.min(lat), min_lng.min(lng), max_lat.max(lat)))
+            })?;
+
+        if min_lat > max_lat {
+            return Err("cannot find any location".into());
+        }
+
+        let speed =
+            problem.fleet.profiles.first().and_then(|profile| profile.speed).map_or(DEFAULT_SPEED, |s| s as f64);
+        let lng_scale = ((min_lat + max_lat) / 2.).to_radians().cos();
+
+        Ok(Self { min: (min_lat, min_lng), lng_scale, speed })
+    }
+
+    fn project(&self, location: &Location) -> GenericResult<(i64, i64)> {
+        match location {
+            Location::Coordinate { lat, lng } => {
+                let x = (lng - self.min.1).to_radians() * EARTH_RADIUS * self.lng_scale / self.speed;
+                let y = (lat - self.min.0).to_radians() * EARTH_RADIUS / self.speed;
+
+                Ok((x.round() as i64, y.round() as i64))
+            }
+            _ => Err("only locations specified by coordinates can be converted".into()),
+        }
+    }
+}
+
+fn get_locations(problem: &Problem) -> impl Iterator<Item = &Location> {
+    let job_locations = problem
+        .plan
+        .jobs
+        .iter()
+        .flat_map(|job| job.all_tasks_iter())
+        .flat_map(|task| task.places.iter())
+        .map(|place| &place.location);
+
+    let shift_locations = problem
+        .fleet
+        .vehicles
+        .iter()
+        .flat_map(|vehicle| vehicle.shifts.iter())
+        .flat_map(|shift| std::iter::once(&shift.start.location).chain(shift.end.iter().map(|end| &end.location)));
+
+    job_locations.chain(shift_locations)
+}
+
+fn get_time_origin(problem: &Problem) -> GenericResult<i64> {
+    problem
+        .fleet
+        .vehicles
+        .iter()
+        .flat_map(|vehicle| vehicle.shifts.iter())
+        .map(|shift| parse_time(&shift.start.earliest))
+        .try_fold(None, |origin: Option<i64>, time| time.map(|time| Some(origin.map_or(time, |o| o.min(time)))))?
+        .ok_or_else(|| "cannot find any vehicle shift".into())
+}
+
+/// Returns total amount of vehicles, capacity and depot defined by the first vehicle type.
+fn get_fleet(
+    problem: &Problem,
+    projection: &Projection,
+    to_time: &dyn Fn(&str) -> GenericResult<i64>,
+    dropped: &mut DroppedFeatures,
+) -> GenericResult<(usize, i32, CustomerLine)> {
+    let first = problem.fleet.vehicles.first().ok_or_else(|| GenericError::from("cannot find any vehicle type"))?;
+    let first_shift = first.shifts.first().ok_or_else(|| GenericError::from("cannot find any vehicle shift"))?;
+    let capacity = first.capacity.first().copied().unwrap_or_default();
+
+    let start = to_time(&first_shift.start.earliest)?;
+    let end = match first_shift.end.as_ref() {
+        Some(end) => to_time(&end.latest)?,
+        None => get_horizon_end(problem, to_time)?.max(start),
+    };
+    let depot_location = &first_shift.start.location;
+
+    problem.fleet.vehicles.iter().for_each(|vehicle| {
+        dropped.add_if(vehicle.capacity.len() > 1, "multi-dimensional vehicle capacity");
+        dropped.add_if(vehicle.capacity.first().copied().unwrap_or_default() != capacity, "vehicle capacity");
+        dropped.add_if(vehicle.shifts.len() > 1, "multiple vehicle shifts");
+        dropped.add_if(vehicle.skills.is_some(), "vehicle skills");
+        dropped.add_if(vehicle.limits.is_some(), "vehicle limits");
+        dropped.add_if(vehicle.costs.tiers.is_some(), "vehicle cost tiers");
+        dropped.add_if(vehicle.profile.scale.is_some(), "vehicle profile scale");
+
+        vehicle.shifts.iter().for_each(|shift| {
+            let is_same_depot = format!("{:?}", shift.start.location) == format!("{depot_location:?}")
+                && shift.end.as_ref().is_none_or(|end| format!("{:?}", end.location) == format!("{depot_location:?}"));
+
+            dropped.add_if(!is_same_depot, "vehicle shift depot");
+            dropped.add_if(shift.start.earliest != first_shift.start.earliest, "vehicle shift start time");
+            dropped.add_if(shift.start.latest.is_some(), "vehicle shift latest start time");
+            dropped.add_if(shift.end.is_none(), "open vehicle shift end");
+            dropped.add_if(
+                shift.end.as_ref().zip(first_shift.end.as_ref()).is_some_and(|(a, b)| a.latest != b.latest),
+                "vehicle shift end time",
+            );
+            dropped.add_if(shift.breaks.is_some(), "vehicle breaks");
+            dropped.add_if(shift.reloads.is_some(), "vehicle reloads");
+            dropped.add_if(shift.recharges.is_some(), "vehicle recharges");
+        });
+    });
+
+    let vehicles = problem.fleet.vehicles.iter().map(|vehicle| vehicle.vehicle_ids.len()).sum();
+    let depot = CustomerLine {
+        id: 0,
+        location: projection.project(depot_location)?,
+        demand: 0,
+        tw: (start, end),
+        service: 0,
+        relation: (0, 0),
+    };
+
+    Ok((vehicles, capacity, depot))
+}
+
+fn get_horizon_end(problem: &Problem, to_time: &dyn Fn(&str) -> GenericResult<i64>) -> GenericResult<i64> {
+    problem
+        .plan
+        .jobs
+        .iter()
+        .flat_map(|job| job.all_tasks_iter())
+        .flat_map(|task| task.places.iter())
+        .flat_map(|place| place.times.iter().flatten())
+        .filter_map(|tw| tw.last())
+        .try_fold(0, |end, time| to_time(time).map(|time| end.max(time)))
+}
+
+fn add_plan_features(problem: &Problem, dropped: &mut DroppedFeatures) {
+    dropped.add_if(problem.plan.relations.is_some(), "plan relations");
+    dropped.add_if(problem.plan.clustering.is_some(), "plan clustering");
+    dropped.add_if(problem.plan.incompatibilities.is_some(), "plan incompatibilities");
+    dropped.add_if(problem.fleet.resources.is_some(), "fleet resources");
+    dropped.add_if(problem.fleet.depots.is_some(), "fleet depots");
+    dropped.add_if(problem.fleet.profiles.len() > 1, "multiple routing profiles");
+    dropped.add_if(problem.objectives.is_some(), "objectives");
+}
+
+fn add_job_features(job: &Job, dropped: &mut DroppedFeatures) {
+    dropped.add_if(job.skills.is_some(), "job skills");
+    dropped.add_if(job.value.is_some(), "job value");
+    dropped.add_if(job.group.is_some(), "job group");
+    dropped.add_if(job.compatibility.is_some(), "job compatibility");
+    dropped.add_if(job.priority.is_some(), "job priority");
+    dropped.add_if(job.category.is_some(), "job category");
+    dropped.add_if(job.crew.is_some_and(|crew| crew > 1), "crew jobs");
+}
+
+/// Returns tasks of the job with signed demand (positive for pickup) if the job can be represented in solomon format.
+fn get_solomon_tasks<'a>(job: &'a Job, dropped: &mut DroppedFeatures) -> Vec<(&'a JobTask, i32)> {
+    let tasks = job.all_tasks_iter().collect::<Vec<_>>();
+
+    match (tasks.as_slice(), job.pickups.is_some()) {
+        ([task], is_pickup) => {
+            dropped.add_if(is_pickup, "pickup job converted to delivery");
+            vec![(*task, get_demand(task, dropped))]
+        }
+        _ => {
+            dropped.add("job with multiple tasks");
+            vec![]
+        }
+    }
+}
+
+/// Returns pickup and delivery tasks of the job if the job can be represented in Li&Lim format.
+fn get_lilim_tasks<'a>(job: &'a Job, dropped: &mut DroppedFeatures) -> Vec<(&'a JobTask, i32)> {
+    match (job.pickups.as_deref(), job.deliveries.as_deref(), job.replacements.as_ref(), job.services.as_ref()) {
+        (Some([pickup]), Some([delivery]), None, None) => {
+            let demand = get_demand(pickup, dropped);
+            let is_mismatch = get_demand(delivery, dropped) != demand;
+            dropped.add_if(is_mismatch, "pickup and delivery demand mismatch");
+
+            vec![(pickup, demand), (delivery, -demand)]
+        }
+        _ => {
+            dropped.add("job which is not a single pickup and delivery");
+            vec![]
+        }
+    }
+}
+
+fn get_demand(task: &JobTask, dropped: &mut DroppedFeatures) -> i32 {
+    let demand = task.demand.as_deref().unwrap_or_default();
+    dropped.add_if(demand.len() > 1, "multi-dimensional job demand");
+
+    demand.first().copied().unwrap_or_default()
+}
+
+fn create_customer(
+    id: usize,
+    task: &JobTask,
+    demand: i32,
+    depot: &CustomerLine,
+    projection: &Projection,
+    to_time: &dyn Fn(&str) -> GenericResult<i64>,
+    dropped: &mut DroppedFeatures,
+) -> GenericResult<CustomerLine> {
+    let place = task.places.first().ok_or_else(|| GenericError::from("cannot find job place"))?;
+
+    dropped.add_if(task.places.len() > 1, "alternative job places");
+    dropped.add_if(place.times.as_ref().is_some_and(|times| times.len() > 1), "multiple job time windows");
+    dropped.add_if(place.tag.is_some(), "job place tag");
+    dropped.add_if(place.cost.is_some(), "job place cost");
+    dropped.add_if(task.order.is_some(), "job task order");
+
+    let tw = match place.times.as_ref().and_then(|times| times.first()) {
+        Some(tw) if tw.len() == 2 => (to_time(&tw[0])?, to_time(&tw[1])?),
+        _ => depot.tw,
+    };
+
+    #[allow(clippy::unnecessary_cast)]
+    let service = (place.duration as Float).round() as i64;
+
+    Ok(CustomerLine { id, location: projection.project(&place.location)?, demand, tw, service, relation: (0, 0) })
+}
+
+fn write_solomon<W: Write>(
+    writer: &mut BufWriter<W>,
+    vehicles: usize,
+    capacity: i32,
+    customers: &[CustomerLine],
+) -> std::io::Result<()> {
+    writer.write_all(b"converted\n\nVEHICLE\nNUMBER     CAPACITY\n")?;
+    writeln!(writer, "{vehicles:>5}{capacity:>12}")?;
+    writer
+        .write_all(b"\nCUSTOMER\nCUST NO.  XCOORD.   YCOORD.    DEMAND   READY TIME  DUE DATE   SERVICE   TIME\n\n")?;
+
+    customers.iter().try_for_each(|customer| {
+        let CustomerLine { id, location: (x, y), demand, tw: (start, end), service, .. } = customer;
+        writeln!(writer, "{id:>5}{x:>11}{y:>11}{demand:>11}{start:>11}{end:>11}{service:>11}")
+    })
+}
+
+fn write_lilim<W: Write>(
+    writer: &mut BufWriter<W>,
+    vehicles: usize,
+    capacity: i32,
+    customers: &[CustomerLine],
+) -> std::io::Result<()> {
+    writeln!(writer, "{vehicles}\t{capacity}\t1")?;
+
+    customers.iter().try_for_each(|customer| {
+        let CustomerLine { id, location: (x, y), demand, tw: (start, end), service, relation: (pickup, delivery) } =
+            customer;
+        writeln!(writer, "{id}\t{x}\t{y}\t{demand}\t{start}\t{end}\t{service}\t{pickup}\t{delivery}")
+    })
+}
+
+fn parse_time(time: &str) -> GenericResult<i64> {
+    OffsetDateTime::parse(time, &Rfc3339)
+        .map(|time| time.unix_timestamp())
+        .map_err(|err| format!("cannot parse time '{time}': {err}").into())
+}
@@ -0,0 +1,204 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/generate/distribution_test.rs"]
+mod distribution_test;
+
+use std::f64::consts::PI;
+use time::OffsetDateTime;
+use time::format_description::well_known::Rfc3339;
+use vrp_core::prelude::{Float, GenericResult};
+use vrp_core::utils::{DefaultDistributionSampler, DefaultRandom, Random};
+use vrp_pragmatic::format::Location;
+
+/// Approximate amount of meters in one degree of latitude.
+const METERS_PER_DEGREE: f64 = 111_320.;
+
+/// Specifies how job locations are distributed within the bounding box.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum LocationDistribution {
+    /// Locations are uniformly distributed.
+    #[default]
+    Uniform,
+    /// Locations are sampled from a gaussian mixture: cluster centers are uniformly distributed.
+    Clustered {
+        /// Amount of clusters.
+        clusters: usize,
+        /// Standard deviation of a cluster in meters. Default is a tenth of the bounding box size.
+        radius: Option<Float>,
+    },
+    /// Locations are distributed around the bounding box center with density decreasing with distance.
+    Radial,
+}
+
+/// Specifies how job demands are generated.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum DemandDistribution {
+    /// Demands are taken from prototype jobs as is.
+    #[default]
+    Prototype,
+    /// Each demand dimension is uniformly distributed within the range of prototype values.
+    Uniform,
+    /// Each demand dimension is normally distributed with mean and standard deviation of prototype values.
+    Normal,
+}
+
+/// Specifies how jobs of the plan are generated.
+#[derive(Clone, Debug, Default)]
+pub struct PlanOptions {
+    /// A spatial distribution of job locations. Ignored when locations are specified explicitly.
+    pub location_distribution: LocationDistribution,
+    /// A ratio in (0, 1] to shrink prototype time windows: smaller values produce tighter time windows.
+    pub time_window_tightness: Option<Float>,
+    /// A distribution of job demands.
+    pub demand_distribution: DemandDistribution,
+}
+
+/// A bounding box specified by min and max (lat, lng) pairs.
+pub(crate) type BoundingBox = ((f64, f64), (f64, f64));
+
+pub(crate) type LocationFn = Box<dyn Fn(&DefaultRandom) -> Location>;
+
+pub(crate) type DemandFn = Box<dyn Fn(&DefaultRandom) -> Option<Vec<i32>>>;
+
+/// Creates a function which samples locations within the bounding box using given distribution.
+pub(crate) fn create_location_fn(
+    bounding_box: BoundingBox,
+    distribution: &LocationDistribution,
+    rnd: &DefaultRandom,
+) -> GenericResult<LocationFn> {
+    #![allow(clippy::unnecessary_cast)]
+
+    let ((min_lat, min_lng), (max_lat, max_lng)) = bounding_box;
+    let (half_lat, half_lng) = ((max_lat - min_lat) / 2., (max_lng - min_lng) / 2.);
+    let (center_lat, center_lng) = (min_lat + half_lat, min_lng + half_lng);
+
+    let sample_uniform = move |rnd: &DefaultRandom| {
+        (rnd.uniform_real(min_lat as Float, max_lat as Float), rnd.uniform_real(min_lng as Float, max_lng as Float))
+    };
+
+    Ok(match distribution {
+        LocationDistribution::Uniform => Box::new(move |rnd| {
+            let (lat, lng) = sample_uniform(rnd);
+            Location::Coordinate { lat, lng }
+        }),
+        LocationDistribution::Clustered { clusters, radius } => {
+            if *clusters == 0 {
+                return Err("amount of clusters must be positive".into());
+            }
+
+            let (std_lat, std_lng) = match radius {
+                Some(radius) if *radius > 0. => {
+                    let std_lat = *radius as f64 / METERS_PER_DEGREE;
+                    (std_lat, std_lat / center_lat.to_radians().cos().max(1E-6))
+                }
+                Some(_) => return Err("cluster radius must be positive".into()),
+                None => (half_lat / 10., half_lng / 10.),
+            };
+
+            let centers = (0..*clusters).map(|_| sample_uniform(rnd)).collect::<Vec<_>>();
+
+            Box::new(move |rnd| {
+                let (lat, lng) = centers[rnd.uniform_int(0, centers.len() as i32 - 1) as usize];
+                let lat = DefaultDistributionSampler::sample_normal(lat, std_lat as Float, rnd);
+                let lng = DefaultDistributionSampler::sample_normal(lng, std_lng as Float, rnd);
+
+                Location::Coordinate { lat, lng }
+            })
+        }
+        LocationDistribution::Radial => Box::new(move |rnd| {
+            // NOTE squared distance ratio makes density higher close to the center
+            let distance = rnd.uniform_real(0., 1.).powi(2) as f64;
+            let angle = rnd.uniform_real(0., 2. * PI as Float) as f64;
+
+            let lat = center_lat + distance * angle.sin() * half_lat;
+            let lng = center_lng + distance * angle.cos() * half_lng;
+
+            Location::Coordinate { lat: lat as Float, lng: lng as Float }
+        }),
+    })
+}
+
+/// Creates a function which samples demands using prototype demands and given distribution.
+pub(crate) fn create_demand_fn(demands: Vec<Vec<i32>>, distribution: DemandDistribution) -> DemandFn {
+    let dimensions = demands.iter().map(|demand| demand.len()).max().unwrap_or(0);
+    let get_values = |dimension: usize| {
+        demands.iter().map(move |demand| demand.get(dimension).copied().unwrap_or(0) as Float).collect::<Vec<_>>()
+    };
+
+    match distribution {
+        _ if demands.is_empty() => Box::new(|_| None),
+        DemandDistribution::Prototype => Box::new(move |rnd| {
+            let idx = rnd.uniform_int(0, demands.len() as i32 - 1) as usize;
+            demands.get(idx).cloned()
+        }),
+        DemandDistribution::Uniform => {
+            let ranges = (0..dimensions)
+                .map(|dimension| {
+                    let values = get_values(dimension);
+                    let min = values.iter().copied().fold(Float::MAX, Float::min);
+                    let max = values.iter().copied().fold(Float::MIN, Float::max);
+                    (min as i32, max as i32)
+                })
+                .collect::<Vec<_>>();
+
+            Box::new(move |rnd| Some(ranges.iter().map(|&(min, max)| rnd.uniform_int(min, max)).collect()))
+        }
+        DemandDistribution::Normal => {
+            let parameters = (0..dimensions)
+                .map(|dimension| {
+                    let values = get_values(dimension);
+                    let mean = values.iter().sum::<Float>() / values.len() as Float;
+                    let variance =
+                        values.iter().map(|value| (value - mean).powi(2)).sum::<Float>() / values.len() as Float;
+                    (mean, variance.sqrt())
+                })
+                .collect::<Vec<_>>();
+
+            Box::new(move |rnd| {
+                Some(
+                    parameters
+                        .iter()
+                        .map(|&(mean, std_dev)| {
+                            // NOTE demand cannot be negative and zero demand is kept only for zero mean
+                            let min = if mean > 0. { 1 } else { 0 };
+                            (DefaultDistributionSampler::sample_normal(mean, std_dev, rnd).round() as i32).max(min)
+                        })
+                        .collect(),
+                )
+            })
+        }
+    }
+}
+
+/// Shrinks time windows using tightness ratio: a new time window has duration proportional to the ratio
+/// and is randomly placed within the original one.
+pub(crate) fn tighten_time_windows(
+    times: Vec<Vec<String>>,
+    tightness: Float,
+    rnd: &DefaultRandom,
+) -> GenericResult<Vec<Vec<String>>> {
+    let parse_time = |time: &str| {
+        OffsetDateTime::parse(time, &Rfc3339)
+            .map(|time| time.unix_timestamp())
+            .map_err(|err| format!("cannot parse time '{time}': {err}"))
+    };
+    let format_time = |timestamp: i64| {
+        OffsetDateTime::from_unix_timestamp(timestamp)
+            .map_err(|err| err.to_string())
+            .and_then(|time| time.format(&Rfc3339).map_err(|err| err.to_string()))
+    };
+
+    times
+        .into_iter()
+        .map(|time_window| match time_window.as_slice() {
+            [start, end] => {
+                let (start, end) = (parse_time(start)?, parse_time(end)?);
+                let duration = (end - start).max(0);
+                let new_duration = (duration as Float * tightness).round() as i64;
+                let offset = rnd.uniform_int(0, (duration - new_duration).clamp(0, i32::MAX as i64) as i32) as i64;
+
+                Ok(vec![format_time(start + offset)?, format_time(start + offset + new_duration)?])
+            }
+            _ => Err("time window should have start and end".into()),
+        })
+        .collect()
+}
@@ -37,7 +37,7 @@ pub(crate) fn generate_fleet(problem_proto: &Problem, vehicle_types_size: usize)
         })
         .collect();
 
-    Fleet { vehicles, profiles, resources: None }
+    Fleet { vehicles, profiles, resources: None, depots: None }
 }
 
 fn get_from_vehicle<F, T>(problem_proto: &Problem, func: F) -> Vec<T>
@@ -59,7 +59,7 @@ fn get_vehicle_capacities(problem_proto: &Problem) -> Vec<Vec<i32>> {
     get_from_vehicle(problem_proto, |vehicle| vehicle.capacity.clone())
 }
 
-fn get_vehicle_skills(problem_proto: &Problem) -> Vec<Option<Vec<String>>> {
+fn get_vehicle_skills(problem_proto: &Problem) -> Vec<Option<Vec<VehicleSkill>>> {
     get_from_vehicle(problem_proto, |vehicle| vehicle.skills.clone())
 }
 
@@ -1,5 +1,8 @@
 //! Generate command helpers.
 
+mod distribution;
+pub use self::distribution::*;
+
 mod plan;
 use self::plan::generate_plan;
 
@@ -9,6 +12,9 @@ use self::fleet::generate_fleet;
 mod prototype;
 use self::prototype::generate_from_prototype;
 
+mod template;
+pub use self::template::*;
+
 use std::io::{BufReader, Read};
 use vrp_core::prelude::{Float, GenericError};
 use vrp_core::utils::{DefaultRandom, Random};
@@ -22,11 +28,9 @@ pub fn generate_problem<R: Read>(
     job_size: usize,
     vehicles_size: usize,
     area_size: Option<Float>,
+    plan_options: &PlanOptions,
 ) -> Result<Problem, GenericError> {
-    let locations = match locations_reader {
-        Some(locations_reader) => Some(deserialize_locations(locations_reader).map_err(|errs| errs.to_string())?),
-        _ => None,
-    };
+    let locations = read_locations(locations_reader)?;
 
     let problem_proto = match (input_format, prototype_readers) {
         (_, Some(readers)) if readers.len() != 1 => {
@@ -38,7 +42,31 @@ pub fn generate_problem<R: Read>(
         _ => Err(format!("unknown format: '{input_format}'")),
     }?;
 
-    generate_from_prototype(&problem_proto, locations, job_size, vehicles_size, area_size)
+    generate_from_prototype(&problem_proto, locations, job_size, vehicles_size, area_size, plan_options)
+}
+
+/// Generates a pragmatic problem using scenario template.
+pub fn generate_problem_from_template<R: Read>(
+    template: &str,
+    locations_reader: Option<BufReader<R>>,
+    job_size: usize,
+    vehicle_types_size: usize,
+    area_size: Option<Float>,
+    plan_options: &PlanOptions,
+) -> Result<Problem, GenericError> {
+    let template = template.parse::<ScenarioTemplate>()?;
+    let locations = read_locations(locations_reader)?;
+
+    generate_from_template(template, locations, job_size, vehicle_types_size, area_size, plan_options)
+}
+
+fn read_locations<R: Read>(
+    locations_reader: Option<BufReader<R>>,
+) -> Result<Option<Vec<vrp_pragmatic::format::Location>>, GenericError> {
+    Ok(match locations_reader {
+        Some(locations_reader) => Some(deserialize_locations(locations_reader).map_err(|errs| errs.to_string())?),
+        _ => None,
+    })
 }
 
 fn get_random_item<'a, T>(items: &'a [T], rnd: &DefaultRandom) -> Option<&'a T> {
@@ -2,27 +2,36 @@
 #[path = "../../../tests/unit/extensions/generate/plan_test.rs"]
 mod plan_test;
 
+use super::distribution::*;
 use super::get_random_item;
 use vrp_core::prelude::{Float, GenericError};
-use vrp_core::utils::{DefaultRandom, Random};
+use vrp_core::utils::DefaultRandom;
 use vrp_pragmatic::format::Location;
 use vrp_pragmatic::format::problem::{Job, JobPlace, JobTask, Plan, Problem};
 
 /// Generates a new plan for given problem with amount of jobs specified by`jobs_size` and
 /// bounding box of size `area_size` (half size in meters). When not specified, jobs bounding
-/// box is used.
+/// box is used. Locations, time windows and demands are sampled according to `options`.
 pub(crate) fn generate_plan(
     problem_proto: &Problem,
     locations: Option<Vec<Location>>,
     jobs_size: usize,
     area_size: Option<Float>,
+    options: &PlanOptions,
 ) -> Result<Plan, GenericError> {
     let rnd = DefaultRandom::default();
 
-    let get_location_fn = get_location_fn(problem_proto, locations, area_size)?;
+    let get_location_fn = get_location_fn(problem_proto, locations, area_size, options, &rnd)?;
 
-    let time_windows = get_plan_time_windows(&problem_proto.plan);
-    let demands = get_plan_demands(&problem_proto.plan);
+    let time_windows = match options.time_window_tightness {
+        Some(tightness) if tightness > 0. && tightness <= 1. => get_plan_time_windows(&problem_proto.plan)
+            .into_iter()
+            .map(|times| tighten_time_windows(times, tightness, &rnd))
+            .collect::<Result<Vec<_>, _>>()?,
+        Some(tightness) => return Err(format!("time window tightness must be in (0, 1], got: {tightness}").into()),
+        None => get_plan_time_windows(&problem_proto.plan),
+    };
+    let get_demand_fn = create_demand_fn(get_plan_demands(&problem_proto.plan), options.demand_distribution);
     let durations = get_plan_durations(&problem_proto.plan);
 
     let generate_tasks = |tasks: &Option<Vec<JobTask>>, keep_original_demand: bool| {
@@ -38,13 +47,10 @@ pub(crate) fn generate_plan(
                             duration: get_random_item(durations.as_slice(), &rnd).cloned().unwrap(),
                             times: get_random_item(time_windows.as_slice(), &rnd).cloned(),
                             tag: place.tag.clone(),
+                            cost: place.cost,
                         })
                         .collect(),
-                    demand: if keep_original_demand {
-                        task.demand.clone()
-                    } else {
-                        get_random_item(demands.as_slice(), &rnd).cloned()
-                    },
+                    demand: if keep_original_demand { task.demand.clone() } else { get_demand_fn(&rnd) },
                     order: task.order,
                 })
                 .collect::<Vec<_>>()
@@ -69,19 +75,27 @@ pub(crate) fn generate_plan(
                 value: job_proto.value,
                 group: job_proto.group.clone(),
                 compatibility: job_proto.compatibility.clone(),
+                priority: job_proto.priority,
+                category: job_proto.category.clone(),
+                crew: job_proto.crew,
             }
         })
         .collect();
 
-    Ok(Plan { jobs, relations: None, clustering: None })
+    Ok(Plan {
+        jobs,
+        relations: None,
+        clustering: None,
+        incompatibilities: problem_proto.plan.incompatibilities.clone(),
+    })
 }
 
-type LocationFn = Box<dyn Fn(&DefaultRandom) -> Location>;
-
 fn get_location_fn(
     problem_proto: &Problem,
     locations: Option<Vec<Location>>,
     area_size: Option<Float>,
+    options: &PlanOptions,
+    rnd: &DefaultRandom,
 ) -> Result<LocationFn, GenericError> {
     if let Some(locations) = locations {
         Ok(Box::new(move |rnd| get_random_item(locations.as_slice(), rnd).cloned().expect("cannot get any location")))
@@ -95,17 +109,12 @@ fn get_location_fn(
         } else {
             get_bounding_box_from_plan(&problem_proto.plan)
         };
-        Ok(Box::new(move |rnd| {
-            // TODO allow to configure distribution
-            let lat = rnd.uniform_real((bounding_box.0).0 as Float, (bounding_box.1).0 as Float) as f64;
-            let lng = rnd.uniform_real((bounding_box.0).1 as Float, (bounding_box.1).1 as Float) as f64;
 
-            Location::Coordinate { lat, lng }
-        }))
+        create_location_fn(bounding_box, &options.location_distribution, rnd)
     }
 }
 
-fn get_bounding_box_from_plan(plan: &Plan) -> ((f64, f64), (f64, f64)) {
+fn get_bounding_box_from_plan(plan: &Plan) -> BoundingBox {
     let mut lat_min = f64::MAX;
     let mut lat_max = f64::MIN;
     let mut lng_min = f64::MAX;
@@ -122,7 +131,16 @@ fn get_bounding_box_from_plan(plan: &Plan) -> ((f64, f64), (f64, f64)) {
     ((lat_min, lng_min), (lat_max, lng_max))
 }
 
-fn get_bounding_box_from_size(plan: &Plan, area_size: Float) -> ((f64, f64), (f64, f64)) {
+fn get_bounding_box_from_size(plan: &Plan, area_size: Float) -> BoundingBox {
+    let ((min_lat, min_lng), (max_lat, max_lng)) = get_bounding_box_from_plan(plan);
+    let center_lat = min_lat + (max_lat - min_lat) / 2.;
+    let center_lng = min_lng + (max_lng - min_lng) / 2.;
+
+    get_bounding_box_around((center_lat, center_lng), area_size)
+}
+
+/// Returns a bounding box around the center with half side size specified by `area_size` (in meters).
+pub(crate) fn get_bounding_box_around((center_lat, center_lng): (f64, f64), area_size: Float) -> BoundingBox {
     #![allow(clippy::unnecessary_cast)]
 
     const WGS84_A: f64 = 6_378_137.0;
@@ -130,10 +148,6 @@ fn get_bounding_box_from_size(plan: &Plan, area_size: Float) -> ((f64, f64), (f6
     let deg_to_rad = |deg| std::f64::consts::PI * deg / 180.;
     let rad_to_deg = |rad| 180. * rad / std::f64::consts::PI;
 
-    let ((min_lat, min_lng), (max_lat, max_lng)) = get_bounding_box_from_plan(plan);
-    let center_lat = min_lat + (max_lat - min_lat) / 2.;
-    let center_lng = min_lng + (max_lng - min_lng) / 2.;
-
     let lat = deg_to_rad(center_lat);
     let lng = deg_to_rad(center_lng);
 
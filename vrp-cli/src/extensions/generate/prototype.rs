@@ -18,14 +18,16 @@ pub(crate) fn generate_from_prototype(
     jobs_size: usize,
     vehicle_types_size: usize,
     area_size: Option<Float>,
+    plan_options: &PlanOptions,
 ) -> Result<Problem, GenericError> {
     if problem.plan.jobs.len() < 3 {
         return Err("at least three jobs should be defined".into());
     }
 
     Ok(Problem {
-        plan: generate_plan(problem, locations, jobs_size, area_size)?,
+        plan: generate_plan(problem, locations, jobs_size, area_size, plan_options)?,
         fleet: generate_fleet(problem, vehicle_types_size),
         objectives: problem.objectives.clone(),
+        timezone: problem.timezone.clone(),
     })
 }
@@ -0,0 +1,364 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/generate/template_test.rs"]
+mod template_test;
+
+use super::plan::get_bounding_box_around;
+use super::*;
+use std::str::FromStr;
+use vrp_core::prelude::GenericResult;
+use vrp_pragmatic::format::Location;
+
+/// A center of the generated problem area when locations are not specified.
+const DEFAULT_CENTER: (f64, f64) = (52.52, 13.405);
+
+/// A date used by all generated times.
+const DEFAULT_DATE: &str = "2024-07-01";
+
+const FIELD_SERVICE_SKILLS: [&str; 3] = ["plumbing", "electrical", "hvac"];
+
+/// Specifies a scenario template which defines shifts, breaks, skills and demand patterns of the problem.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ScenarioTemplate {
+    /// Pickups at restaurants with delivery to customers during lunch and dinner peaks, done by bike couriers.
+    FoodDelivery,
+    /// Service visits which require technician skills, done during a working day with a lunch break.
+    FieldService,
+    /// Heavy depot deliveries with some backhaul pickups, done by trucks with weight and pallet capacity.
+    LineHaul,
+}
+
+impl FromStr for ScenarioTemplate {
+    type Err = GenericError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "food-delivery" => Ok(Self::FoodDelivery),
+            "field-service" => Ok(Self::FieldService),
+            "line-haul" => Ok(Self::LineHaul),
+            _ => Err(format!("unknown scenario template: '{value}'").into()),
+        }
+    }
+}
+
+impl ScenarioTemplate {
+    /// Returns a default half size of the area in meters.
+    fn default_area_size(&self) -> Float {
+        match self {
+            Self::FoodDelivery => 3_000.,
+            Self::FieldService => 15_000.,
+            Self::LineHaul => 100_000.,
+        }
+    }
+
+    fn get_profile(&self) -> MatrixProfile {
+        let (name, speed) = match self {
+            Self::FoodDelivery => ("bike", 5.),
+            Self::FieldService => ("car", 10.),
+            Self::LineHaul => ("truck", 15.),
+        };
+
+        MatrixProfile { name: name.to_string(), speed: Some(speed), restrictions: None }
+    }
+}
+
+/// Generates a pragmatic problem from the scenario template. Job locations are sampled from the given
+/// list (the first one is used as a depot) or within the area around default center.
+/// Amount of vehicles per type is scaled by jobs size.
+pub fn generate_from_template(
+    template: ScenarioTemplate,
+    locations: Option<Vec<Location>>,
+    jobs_size: usize,
+    vehicle_types_size: usize,
+    area_size: Option<Float>,
+    plan_options: &PlanOptions,
+) -> GenericResult<Problem> {
+    if jobs_size == 0 || vehicle_types_size == 0 {
+        return Err("jobs size and vehicle types size must be positive".into());
+    }
+
+    if let Some(tightness) = plan_options.time_window_tightness.filter(|&t| !(t > 0. && t <= 1.)) {
+        return Err(format!("time window tightness must be in (0, 1], got: {tightness}").into());
+    }
+
+    let rnd = DefaultRandom::default();
+    let (depot, location_fn): (Location, LocationFn) = match locations {
+        Some(locations) => {
+            let depot = locations.first().cloned().ok_or("locations list is empty")?;
+            (depot, Box::new(move |rnd| get_random_item(locations.as_slice(), rnd).cloned().expect("no locations")))
+        }
+        None => {
+            let area_size = area_size.unwrap_or_else(|| template.default_area_size());
+            if area_size <= 0. {
+                return Err("area size must be positive".into());
+            }
+
+            let bounding_box = get_bounding_box_around(DEFAULT_CENTER, area_size);
+            let location_fn = create_location_fn(bounding_box, &plan_options.location_distribution, &rnd)?;
+            let (lat, lng) = DEFAULT_CENTER;
+
+            (Location::Coordinate { lat, lng }, location_fn)
+        }
+    };
+
+    let ctx = TemplateContext { depot, location_fn, tightness: plan_options.time_window_tightness, rnd };
+    let profile = template.get_profile();
+
+    let (jobs, vehicles) = match template {
+        ScenarioTemplate::FoodDelivery => (
+            create_food_delivery_jobs(&ctx, jobs_size)?,
+            create_food_delivery_vehicles(&ctx, &profile, jobs_size, vehicle_types_size),
+        ),
+        ScenarioTemplate::FieldService => (
+            create_field_service_jobs(&ctx, jobs_size)?,
+            create_field_service_vehicles(&ctx, &profile, jobs_size, vehicle_types_size),
+        ),
+        ScenarioTemplate::LineHaul => (
+            create_line_haul_jobs(&ctx, jobs_size)?,
+            create_line_haul_vehicles(&ctx, &profile, jobs_size, vehicle_types_size),
+        ),
+    };
+
+    Ok(Problem {
+        plan: Plan { jobs, relations: None, clustering: None, incompatibilities: None },
+        fleet: Fleet { vehicles, profiles: vec![profile], resources: None, depots: None },
+        objectives: None,
+        timezone: None,
+    })
+}
+
+struct TemplateContext {
+    depot: Location,
+    location_fn: LocationFn,
+    tightness: Option<Float>,
+    rnd: DefaultRandom,
+}
+
+impl TemplateContext {
+    fn location(&self) -> Location {
+        (self.location_fn)(&self.rnd)
+    }
+
+    /// Returns a random time (in minutes since midnight) within the range using five minutes step.
+    fn time(&self, (start, end): (i32, i32)) -> i32 {
+        self.rnd.uniform_int(start / 5, end / 5) * 5
+    }
+
+    /// Returns job time windows, optionally tightened.
+    fn times(&self, start: i32, end: i32) -> GenericResult<Option<Vec<Vec<String>>>> {
+        let times = vec![vec![format_time(start), format_time(end)]];
+
+        match self.tightness {
+            Some(tightness) => tighten_time_windows(times, tightness, &self.rnd).map(Some),
+            None => Ok(Some(times)),
+        }
+    }
+}
+
+fn create_food_delivery_jobs(ctx: &TemplateContext, jobs_size: usize) -> GenericResult<Vec<Job>> {
+    const LUNCH_PEAK: (i32, i32) = (11 * 60, 13 * 60 + 30);
+    const DINNER_PEAK: (i32, i32) = (17 * 60 + 30, 20 * 60 + 30);
+
+    let restaurants = (0..(jobs_size / 10).clamp(3, 50)).map(|_| ctx.location()).collect::<Vec<_>>();
+
+    (1..=jobs_size)
+        .map(|idx| {
+            let ready = ctx.time(if ctx.rnd.is_head_not_tails() { LUNCH_PEAK } else { DINNER_PEAK });
+            let demand = Some(vec![if ctx.rnd.is_hit(0.2) { 2 } else { 1 }]);
+            let restaurant = get_random_item(restaurants.as_slice(), &ctx.rnd).cloned().expect("no restaurants");
+
+            Ok(Job {
+                pickups: Some(vec![create_task(restaurant, 180., ctx.times(ready, ready + 15)?, demand.clone())]),
+                deliveries: Some(vec![create_task(ctx.location(), 120., ctx.times(ready + 10, ready + 55)?, demand)]),
+                ..create_job(idx)
+            })
+        })
+        .collect()
+}
+
+fn create_food_delivery_vehicles(
+    ctx: &TemplateContext,
+    profile: &MatrixProfile,
+    jobs_size: usize,
+    vehicle_types_size: usize,
+) -> Vec<VehicleType> {
+    (1..=vehicle_types_size)
+        .map(|type_idx| {
+            let shift = match type_idx % 3 {
+                1 => create_shift(ctx, (10 * 60 + 30, 15 * 60), None),
+                2 => create_shift(ctx, (16 * 60 + 30, 21 * 60 + 30), None),
+                _ => create_shift(ctx, (10 * 60 + 30, 21 * 60 + 30), Some((14 * 60 + 30, 16 * 60 + 30, 1800.))),
+            };
+
+            VehicleType {
+                shifts: vec![shift],
+                capacity: vec![4],
+                ..create_vehicle_type(type_idx, profile, (jobs_size, vehicle_types_size, 8), (5., 0.0001, 0.004))
+            }
+        })
+        .collect()
+}
+
+fn create_field_service_jobs(ctx: &TemplateContext, jobs_size: usize) -> GenericResult<Vec<Job>> {
+    (1..=jobs_size)
+        .map(|idx| {
+            let skill = get_random_item(&FIELD_SERVICE_SKILLS, &ctx.rnd).expect("no skills").to_string();
+            let duration = *get_random_item(&[1800., 2700., 3600., 5400.], &ctx.rnd).expect("no durations");
+            let (start, end) = match ctx.rnd.uniform_int(0, 2) {
+                0 => (8 * 60, 12 * 60),
+                1 => (12 * 60, 17 * 60),
+                _ => (8 * 60, 17 * 60),
+            };
+
+            Ok(Job {
+                services: Some(vec![create_task(ctx.location(), duration, ctx.times(start, end)?, None)]),
+                skills: Some(JobSkills { all_of: Some(vec![skill]), one_of: None, none_of: None, levels: None }),
+                ..create_job(idx)
+            })
+        })
+        .collect()
+}
+
+fn create_field_service_vehicles(
+    ctx: &TemplateContext,
+    profile: &MatrixProfile,
+    jobs_size: usize,
+    vehicle_types_size: usize,
+) -> Vec<VehicleType> {
+    (1..=vehicle_types_size)
+        .map(|type_idx| {
+            // NOTE each type has two skills which are rotated to cover all of them with two types or more
+            let skills = if vehicle_types_size == 1 {
+                FIELD_SERVICE_SKILLS.iter().map(|&skill| skill.into()).collect()
+            } else {
+                (0..2).map(|offset| FIELD_SERVICE_SKILLS[(type_idx - 1 + offset) % 3].into()).collect()
+            };
+
+            VehicleType {
+                shifts: vec![create_shift(ctx, (7 * 60 + 30, 18 * 60), Some((12 * 60, 13 * 60 + 30, 1800.)))],
+                capacity: vec![10],
+                skills: Some(skills),
+                ..create_vehicle_type(type_idx, profile, (jobs_size, vehicle_types_size, 5), (20., 0.0005, 0.006))
+            }
+        })
+        .collect()
+}
+
+fn create_line_haul_jobs(ctx: &TemplateContext, jobs_size: usize) -> GenericResult<Vec<Job>> {
+    (1..=jobs_size)
+        .map(|idx| {
+            let pallets = ctx.rnd.uniform_int(1, 10);
+            let demand = Some(vec![pallets * ctx.rnd.uniform_int(300, 800), pallets]);
+            let duration = (900 + pallets * 180) as Float;
+            let (start, end) = if ctx.rnd.is_hit(0.4) {
+                (6 * 60, 20 * 60)
+            } else {
+                let start = ctx.time((6 * 60, 17 * 60));
+                (start, start + 180)
+            };
+            let tasks = Some(vec![create_task(ctx.location(), duration, ctx.times(start, end)?, demand)]);
+
+            // NOTE some of the jobs are backhaul pickups which are brought back to the depot
+            Ok(if ctx.rnd.is_hit(0.2) {
+                Job { pickups: tasks, ..create_job(idx) }
+            } else {
+                Job { deliveries: tasks, ..create_job(idx) }
+            })
+        })
+        .collect()
+}
+
+fn create_line_haul_vehicles(
+    ctx: &TemplateContext,
+    profile: &MatrixProfile,
+    jobs_size: usize,
+    vehicle_types_size: usize,
+) -> Vec<VehicleType> {
+    (1..=vehicle_types_size)
+        .map(|type_idx| {
+            let (capacity, costs) = if type_idx % 2 == 1 {
+                (vec![24_000, 33], (100., 0.001, 0.008))
+            } else {
+                (vec![12_000, 18], (70., 0.0007, 0.008))
+            };
+
+            VehicleType {
+                shifts: vec![create_shift(ctx, (5 * 60, 22 * 60), Some((10 * 60, 14 * 60, 2700.)))],
+                capacity,
+                ..create_vehicle_type(type_idx, profile, (jobs_size, vehicle_types_size, 6), costs)
+            }
+        })
+        .collect()
+}
+
+fn format_time(minutes: i32) -> String {
+    format!("{DEFAULT_DATE}T{:02}:{:02}:00Z", minutes / 60, minutes % 60)
+}
+
+fn create_job(idx: usize) -> Job {
+    Job {
+        id: format!("job{idx}"),
+        pickups: None,
+        deliveries: None,
+        replacements: None,
+        services: None,
+        skills: None,
+        value: None,
+        group: None,
+        compatibility: None,
+        priority: None,
+        category: None,
+        crew: None,
+    }
+}
+
+fn create_task(
+    location: Location,
+    duration: Float,
+    times: Option<Vec<Vec<String>>>,
+    demand: Option<Vec<i32>>,
+) -> JobTask {
+    JobTask { places: vec![JobPlace { location, duration, times, tag: None, cost: None }], demand, order: None }
+}
+
+/// Creates a shift which starts and ends at the depot with an optional break specified by time window and duration.
+fn create_shift(
+    ctx: &TemplateContext,
+    (start, end): (i32, i32),
+    vehicle_break: Option<(i32, i32, Float)>,
+) -> VehicleShift {
+    VehicleShift {
+        start: ShiftStart { earliest: format_time(start), latest: None, location: ctx.depot.clone() },
+        end: Some(ShiftEnd { earliest: None, latest: format_time(end), location: ctx.depot.clone() }),
+        breaks: vehicle_break.map(|(start, end, duration)| {
+            vec![VehicleBreak::Optional {
+                time: VehicleOptionalBreakTime::TimeWindow(vec![format_time(start), format_time(end)]),
+                places: vec![VehicleOptionalBreakPlace { duration, location: None, tag: None }],
+                policy: None,
+            }]
+        }),
+        reloads: None,
+        recharges: None,
+        capacity_change: None,
+    }
+}
+
+/// Creates a vehicle type with amount of vehicles enough to serve its share of jobs.
+fn create_vehicle_type(
+    type_idx: usize,
+    profile: &MatrixProfile,
+    (jobs_size, vehicle_types_size, jobs_per_vehicle): (usize, usize, usize),
+    (fixed, distance, time): (Float, Float, Float),
+) -> VehicleType {
+    let vehicles = jobs_size.div_ceil(vehicle_types_size * jobs_per_vehicle).max(1);
+
+    VehicleType {
+        type_id: format!("type{type_idx}"),
+        vehicle_ids: (1..=vehicles).map(|vehicle_idx| format!("type{type_idx}_{vehicle_idx}")).collect(),
+        profile: VehicleProfile { matrix: profile.name.clone(), scale: None },
+        costs: VehicleCosts { fixed: Some(fixed), distance, time, tiers: None },
+        shifts: vec![],
+        capacity: vec![],
+        skills: None,
+        limits: None,
+    }
+}
@@ -71,6 +71,7 @@ mod actual {
                 duration: job.duration as Float,
                 times: parse_tw(job.tw_start.clone(), job.tw_end.clone()).map(|tw| vec![tw]),
                 tag: None,
+                cost: None,
             }],
             demand: if job.demand != 0 { Some(vec![job.demand.abs()]) } else { None },
             order: None,
@@ -98,6 +99,9 @@ mod actual {
                 value: None,
                 group: None,
                 compatibility: None,
+                priority: None,
+                category: None,
+                crew: None,
             })
             .collect();
 
@@ -114,7 +118,7 @@ mod actual {
                     type_id: vehicle.id.clone(),
                     vehicle_ids: (1..=vehicle.amount).map(|seq| format!("{}_{}", vehicle.profile, seq)).collect(),
                     profile: VehicleProfile { matrix: vehicle.profile, scale: None },
-                    costs: VehicleCosts { fixed: Some(25.), distance: 0.0002, time: 0.005 },
+                    costs: VehicleCosts { fixed: Some(25.), distance: 0.0002, time: 0.005, tiers: None },
                     shifts: vec![VehicleShift {
                         start: ShiftStart {
                             earliest: vehicle.tw_start,
@@ -125,6 +129,7 @@ mod actual {
                         breaks: None,
                         reloads: None,
                         recharges: None,
+                        capacity_change: None,
                     }],
                     capacity: vec![vehicle.capacity],
                     skills: None,
@@ -155,13 +160,18 @@ mod actual {
         let matrix_profile_names = vehicles.iter().map(|v| v.profile.matrix.clone()).collect::<HashSet<_>>();
 
         Ok(Problem {
-            plan: Plan { jobs, relations: None, clustering: None },
+            plan: Plan { jobs, relations: None, clustering: None, incompatibilities: None },
             fleet: Fleet {
                 vehicles,
-                profiles: matrix_profile_names.into_iter().map(|name| MatrixProfile { name, speed: None }).collect(),
+                profiles: matrix_profile_names
+                    .into_iter()
+                    .map(|name| MatrixProfile { name, speed: None, restrictions: None })
+                    .collect(),
                 resources: None,
+                depots: None,
             },
             objectives: None,
+            timezone: None,
         })
     }
 }
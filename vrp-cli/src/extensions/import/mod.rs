@@ -3,6 +3,9 @@
 mod csv;
 pub use self::csv::*;
 
+mod xlsx;
+pub use self::xlsx::*;
+
 use std::io::{BufReader, Read};
 use vrp_core::prelude::GenericError;
 use vrp_pragmatic::format::problem::Problem;
@@ -19,6 +22,10 @@ pub fn import_problem<R: Read>(
             read_csv_problem(jobs, vehicles).map_err(|err| format!("cannot read csv: {err}").into())
         }
         ("csv", _) => Err("csv format expects two files with jobs and vehicles as an input".into()),
+        ("xlsx", Some(mut readers)) if readers.len() == 1 => {
+            read_xlsx_problem(readers.swap_remove(0)).map_err(|err| format!("cannot read xlsx: {err}").into())
+        }
+        ("xlsx", _) => Err("xlsx format expects one workbook file with jobs and vehicles sheets as an input".into()),
         _ => Err(format!("unknown format: '{input_format}'").into()),
     }
 }
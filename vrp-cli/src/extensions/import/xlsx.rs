@@ -0,0 +1,315 @@
+//! Import from a simple spreadsheet format logic.
+#[cfg(all(test, feature = "xlsx-format"))]
+#[path = "../../../tests/unit/extensions/import/xlsx_test.rs"]
+mod xlsx_test;
+
+pub use self::actual::read_xlsx_problem;
+
+#[cfg(feature = "xlsx-format")]
+mod actual {
+    extern crate calamine;
+
+    use calamine::{Data, Range, Reader, Xlsx, open_workbook_from_rs};
+    use std::collections::{HashMap, HashSet};
+    use std::error::Error;
+    use std::io::{BufReader, Cursor, Read};
+    use vrp_core::prelude::Float;
+    use vrp_pragmatic::format::problem::*;
+    use vrp_pragmatic::format::{FormatError, Location};
+
+    const VEHICLES_SHEET_NAME: &str = "vehicles";
+    const JOBS_SHEET_NAME: &str = "jobs";
+    const DEFAULT_PROFILE_NAME: &str = "car";
+
+    /// Amount of days between excel epoch (1899-12-30) and unix epoch.
+    const EXCEL_UNIX_EPOCH_DAYS: Float = 25569.;
+    const SECONDS_IN_DAY: i64 = 86400;
+
+    /// Keeps sheet rows with values accessible by column name.
+    struct Sheet {
+        columns: HashMap<String, usize>,
+        rows: Vec<Vec<Data>>,
+    }
+
+    impl Sheet {
+        fn new(range: Range<Data>) -> Self {
+            let mut rows = range.rows().filter(|row| row.iter().any(|cell| *cell != Data::Empty));
+
+            let columns = rows
+                .next()
+                .map(|header| {
+                    header
+                        .iter()
+                        .enumerate()
+                        .filter_map(|(idx, cell)| get_string(cell).map(|name| (name.to_uppercase(), idx)))
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            Self { columns, rows: rows.map(|row| row.to_vec()).collect() }
+        }
+
+        fn get<'a>(&self, row: &'a [Data], names: &[&str]) -> Option<&'a Data> {
+            names
+                .iter()
+                .filter_map(|name| self.columns.get(*name))
+                .filter_map(|idx| row.get(*idx))
+                .find(|cell| **cell != Data::Empty)
+        }
+
+        fn get_string(&self, row: &[Data], names: &[&str]) -> Option<String> {
+            self.get(row, names).and_then(get_string)
+        }
+
+        fn get_float(&self, row_idx: usize, row: &[Data], names: &[&str]) -> Result<Option<Float>, Box<dyn Error>> {
+            self.get(row, names)
+                .map(|cell| get_float(cell).ok_or_else(|| create_cell_error(row_idx, names, cell)))
+                .transpose()
+        }
+
+        fn get_time(&self, row_idx: usize, row: &[Data], names: &[&str]) -> Result<Option<String>, Box<dyn Error>> {
+            self.get(row, names)
+                .map(|cell| get_time(cell).ok_or_else(|| create_cell_error(row_idx, names, cell)))
+                .transpose()
+        }
+
+        fn get_required_string(&self, row_idx: usize, row: &[Data], names: &[&str]) -> Result<String, Box<dyn Error>> {
+            self.get_string(row, names).ok_or_else(|| create_missing_error(row_idx, names))
+        }
+
+        fn get_required_float(&self, row_idx: usize, row: &[Data], names: &[&str]) -> Result<Float, Box<dyn Error>> {
+            self.get_float(row_idx, row, names)?.ok_or_else(|| create_missing_error(row_idx, names))
+        }
+
+        fn get_required_time(&self, row_idx: usize, row: &[Data], names: &[&str]) -> Result<String, Box<dyn Error>> {
+            self.get_time(row_idx, row, names)?.ok_or_else(|| create_missing_error(row_idx, names))
+        }
+
+        fn get_location(&self, row_idx: usize, row: &[Data]) -> Result<Location, Box<dyn Error>> {
+            Ok(Location::Coordinate {
+                lat: self.get_required_float(row_idx, row, &["LAT"])?,
+                lng: self.get_required_float(row_idx, row, &["LNG", "LON"])?,
+            })
+        }
+
+        fn get_time_window(&self, row_idx: usize, row: &[Data]) -> Result<Option<Vec<String>>, Box<dyn Error>> {
+            match (self.get_time(row_idx, row, &["TW_START"])?, self.get_time(row_idx, row, &["TW_END"])?) {
+                (Some(start), Some(end)) => Ok(Some(vec![start, end])),
+                (None, None) => Ok(None),
+                _ => Err(format!("row {}: time window requires both 'TW_START' and 'TW_END'", row_idx + 2).into()),
+            }
+        }
+    }
+
+    fn get_string(cell: &Data) -> Option<String> {
+        match cell {
+            Data::String(value) | Data::DateTimeIso(value) => {
+                Some(value.trim().to_string()).filter(|value| !value.is_empty())
+            }
+            Data::Int(value) => Some(value.to_string()),
+            Data::Float(value) => Some(value.to_string()),
+            Data::Bool(value) => Some(value.to_string()),
+            _ => None,
+        }
+    }
+
+    fn get_float(cell: &Data) -> Option<Float> {
+        match cell {
+            Data::Int(value) => Some(*value as Float),
+            Data::Float(value) => Some(*value),
+            Data::String(value) => value.trim().parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// Gets time as RFC3339 string: text cells are used as is, date cells are interpreted as UTC time.
+    fn get_time(cell: &Data) -> Option<String> {
+        match cell {
+            Data::String(value) => Some(value.trim().to_string()),
+            Data::DateTimeIso(value) if value.ends_with('Z') || value.len() > 19 => Some(value.clone()),
+            Data::DateTimeIso(value) => Some(format!("{value}Z")),
+            Data::DateTime(value) => Some(format_excel_time(value.as_f64())),
+            Data::Float(value) => Some(format_excel_time(*value)),
+            Data::Int(value) => Some(format_excel_time(*value as Float)),
+            _ => None,
+        }
+    }
+
+    /// Converts excel serial date (days since 1899-12-30) into RFC3339 UTC string.
+    fn format_excel_time(serial: Float) -> String {
+        let timestamp = ((serial - EXCEL_UNIX_EPOCH_DAYS) * SECONDS_IN_DAY as Float).round() as i64;
+        let (days, seconds) = (timestamp.div_euclid(SECONDS_IN_DAY), timestamp.rem_euclid(SECONDS_IN_DAY));
+
+        // NOTE civil from days algorithm, see http://howardhinnant.github.io/date_algorithms.html
+        let z = days + 719468;
+        let era = z.div_euclid(146097);
+        let doe = z.rem_euclid(146097);
+        let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = doy - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = yoe + era * 400 + i64::from(month <= 2);
+
+        format!("{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z", seconds / 3600, (seconds % 3600) / 60, seconds % 60)
+    }
+
+    fn create_missing_error(row_idx: usize, names: &[&str]) -> Box<dyn Error> {
+        format!("row {}: missing value in '{}' column", row_idx + 2, names.join("/")).into()
+    }
+
+    fn create_cell_error(row_idx: usize, names: &[&str], cell: &Data) -> Box<dyn Error> {
+        format!("row {}: unexpected value '{cell}' in '{}' column", row_idx + 2, names.join("/")).into()
+    }
+
+    fn read_jobs(sheet: &Sheet) -> Result<Vec<Job>, Box<dyn Error>> {
+        sheet
+            .rows
+            .iter()
+            .enumerate()
+            .map(|(row_idx, row)| {
+                let demand = sheet.get_float(row_idx, row, &["DEMAND"])?.unwrap_or(0.) as i32;
+                let task = JobTask {
+                    places: vec![JobPlace {
+                        location: sheet.get_location(row_idx, row)?,
+                        duration: sheet.get_float(row_idx, row, &["SERVICE_TIME", "DURATION"])?.unwrap_or(0.),
+                        times: sheet.get_time_window(row_idx, row)?.map(|tw| vec![tw]),
+                        tag: sheet.get_string(row, &["ADDRESS"]),
+                        cost: None,
+                    }],
+                    demand: if demand != 0 { Some(vec![demand.abs()]) } else { None },
+                    order: None,
+                };
+
+                Ok(Job {
+                    id: sheet.get_required_string(row_idx, row, &["ID"])?,
+                    pickups: if demand < 0 { Some(vec![task.clone()]) } else { None },
+                    deliveries: if demand > 0 { Some(vec![task.clone()]) } else { None },
+                    replacements: None,
+                    services: if demand == 0 { Some(vec![task]) } else { None },
+                    skills: None,
+                    value: None,
+                    group: None,
+                    compatibility: None,
+                    priority: None,
+                    category: None,
+                    crew: None,
+                })
+            })
+            .collect()
+    }
+
+    fn read_vehicles(sheet: &Sheet) -> Result<Vec<VehicleType>, Box<dyn Error>> {
+        sheet
+            .rows
+            .iter()
+            .enumerate()
+            .map(|(row_idx, row)| {
+                let type_id = sheet.get_required_string(row_idx, row, &["ID"])?;
+                let depot_location = sheet.get_location(row_idx, row)?;
+                let amount = sheet.get_float(row_idx, row, &["AMOUNT"])?.unwrap_or(1.) as usize;
+                let profile = sheet.get_string(row, &["PROFILE"]).unwrap_or_else(|| DEFAULT_PROFILE_NAME.to_string());
+
+                Ok(VehicleType {
+                    vehicle_ids: (1..=amount).map(|seq| format!("{type_id}_{seq}")).collect(),
+                    type_id,
+                    profile: VehicleProfile { matrix: profile, scale: None },
+                    costs: VehicleCosts { fixed: Some(25.), distance: 0.0002, time: 0.005, tiers: None },
+                    shifts: vec![VehicleShift {
+                        start: ShiftStart {
+                            earliest: sheet.get_required_time(row_idx, row, &["TW_START"])?,
+                            latest: None,
+                            location: depot_location.clone(),
+                        },
+                        end: Some(ShiftEnd {
+                            earliest: None,
+                            latest: sheet.get_required_time(row_idx, row, &["TW_END"])?,
+                            location: depot_location,
+                        }),
+                        breaks: None,
+                        reloads: None,
+                        recharges: None,
+                        capacity_change: None,
+                    }],
+                    capacity: vec![sheet.get_required_float(row_idx, row, &["CAPACITY"])? as i32],
+                    skills: None,
+                    limits: None,
+                })
+            })
+            .collect()
+    }
+
+    fn read_sheets<R: Read>(mut reader: BufReader<R>) -> Result<(Sheet, Sheet), Box<dyn Error>> {
+        let mut buffer = Vec::new();
+        reader.read_to_end(&mut buffer)?;
+
+        let mut workbook: Xlsx<_> = open_workbook_from_rs(Cursor::new(buffer))?;
+        let sheet_names = workbook.sheet_names();
+
+        let vehicles_name = sheet_names
+            .iter()
+            .find(|name| name.eq_ignore_ascii_case(VEHICLES_SHEET_NAME))
+            .cloned()
+            .ok_or_else(|| format!("cannot find '{VEHICLES_SHEET_NAME}' sheet"))?;
+        let jobs_name = sheet_names
+            .iter()
+            .find(|name| name.eq_ignore_ascii_case(JOBS_SHEET_NAME))
+            .or_else(|| sheet_names.iter().find(|name| **name != vehicles_name))
+            .cloned()
+            .ok_or_else(|| format!("cannot find '{JOBS_SHEET_NAME}' sheet"))?;
+
+        let jobs = Sheet::new(workbook.worksheet_range(jobs_name.as_str())?);
+        let vehicles = Sheet::new(workbook.worksheet_range(vehicles_name.as_str())?);
+
+        Ok((jobs, vehicles))
+    }
+
+    fn create_format_error(entity: &str, error: Box<dyn Error>) -> FormatError {
+        FormatError::new_with_details(
+            "E0000".to_string(),
+            format!("cannot read {entity}"),
+            format!("check {entity} definition"),
+            format!("{error}",),
+        )
+    }
+
+    /// Reads problem from xlsx workbook with jobs and vehicles sheets.
+    pub fn read_xlsx_problem<R: Read>(reader: BufReader<R>) -> Result<Problem, FormatError> {
+        let (jobs, vehicles) = read_sheets(reader).map_err(|err| create_format_error("workbook", err))?;
+
+        let jobs = read_jobs(&jobs).map_err(|err| create_format_error("jobs", err))?;
+        let vehicles = read_vehicles(&vehicles).map_err(|err| create_format_error("vehicles", err))?;
+        let matrix_profile_names = vehicles.iter().map(|v| v.profile.matrix.clone()).collect::<HashSet<_>>();
+
+        Ok(Problem {
+            plan: Plan { jobs, relations: None, clustering: None, incompatibilities: None },
+            fleet: Fleet {
+                vehicles,
+                profiles: matrix_profile_names
+                    .into_iter()
+                    .map(|name| MatrixProfile { name, speed: None, restrictions: None })
+                    .collect(),
+                resources: None,
+                depots: None,
+            },
+            objectives: None,
+            timezone: None,
+        })
+    }
+}
+
+#[cfg(not(feature = "xlsx-format"))]
+mod actual {
+    use std::io::{BufReader, Read};
+    use vrp_pragmatic::format::FormatError;
+    use vrp_pragmatic::format::problem::Problem;
+
+    /// A stub method for reading problem from xlsx format.
+    pub fn read_xlsx_problem<R: Read>(_reader: BufReader<R>) -> Result<Problem, FormatError> {
+        Err(FormatError::new(
+            "E0000".to_string(),
+            "xlsx format support is not enabled".to_string(),
+            "build with 'xlsx-format' feature".to_string(),
+        ))
+    }
+}
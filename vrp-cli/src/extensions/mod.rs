@@ -2,10 +2,26 @@
 
 #[cfg(not(target_arch = "wasm32"))]
 pub mod analyze;
+#[cfg(all(feature = "arrow-format", not(target_arch = "wasm32")))]
+pub mod arrow;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod benchmark;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod check;
 #[cfg(not(target_arch = "wasm32"))]
+pub mod convert;
+#[cfg(not(target_arch = "wasm32"))]
 pub mod generate;
 
 pub mod import;
+#[cfg(all(feature = "plot", not(target_arch = "wasm32")))]
+pub mod plot;
+#[cfg(all(feature = "proto-format", not(target_arch = "wasm32")))]
+pub mod proto;
+#[cfg(all(feature = "osrm-routing", not(target_arch = "wasm32")))]
+pub mod routing;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod scrub;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod serve;
 pub mod solve;
@@ -0,0 +1,165 @@
+//! A helper module which contains functionality to render pragmatic solution as an image.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/plot/plot_test.rs"]
+mod plot_test;
+
+use plotters::coord::Shift;
+use plotters::prelude::*;
+use std::collections::HashSet;
+use std::path::Path;
+use vrp_core::prelude::{GenericError, GenericResult};
+use vrp_pragmatic::format::Location;
+use vrp_pragmatic::format::problem::Problem;
+use vrp_pragmatic::format::solution::Solution;
+
+/// Specifies image format.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PlotFormat {
+    /// Scalable vector graphics.
+    Svg,
+    /// Raster image in png format.
+    Png,
+}
+
+impl PlotFormat {
+    /// Detects image format from file extension.
+    pub fn from_path(path: &Path) -> GenericResult<Self> {
+        match path.extension().and_then(|extension| extension.to_str()).map(|ext| ext.to_lowercase()).as_deref() {
+            Some("svg") => Ok(Self::Svg),
+            Some("png") => Ok(Self::Png),
+            _ => Err(format!("cannot detect image format from '{}': use svg or png extension", path.display()).into()),
+        }
+    }
+}
+
+/// Specifies plot options.
+#[derive(Clone, Debug)]
+pub struct PlotOptions {
+    /// Image size in pixels.
+    pub size: (u32, u32),
+}
+
+impl Default for PlotOptions {
+    fn default() -> Self {
+        Self { size: (1024, 1024) }
+    }
+}
+
+/// Renders solution tours in distinct colors with depot markers. When the problem is specified, unassigned jobs
+/// are highlighted with red crosses. Only locations specified by coordinates can be rendered.
+pub fn plot_solution(
+    solution: &Solution,
+    problem: Option<&Problem>,
+    path: &Path,
+    format: PlotFormat,
+    options: &PlotOptions,
+) -> GenericResult<()> {
+    let plot_data = get_plot_data(solution, problem)?;
+
+    match format {
+        PlotFormat::Svg => draw_plot(&SVGBackend::new(path, options.size).into_drawing_area(), &plot_data),
+        PlotFormat::Png => draw_plot(&BitMapBackend::new(path, options.size).into_drawing_area(), &plot_data),
+    }
+}
+
+/// Keeps plot data as (lng, lat) pairs.
+struct PlotData {
+    tours: Vec<Vec<(f64, f64)>>,
+    depots: Vec<(f64, f64)>,
+    unassigned: Vec<(f64, f64)>,
+}
+
+fn get_plot_data(solution: &Solution, problem: Option<&Problem>) -> GenericResult<PlotData> {
+    let get_point = |location: &Location| match location {
+        Location::Coordinate { lat, lng } => Ok((*lng, *lat)),
+        _ => Err(GenericError::from("only locations specified by coordinates can be plotted")),
+    };
+
+    let tours = solution
+        .tours
+        .iter()
+        .map(|tour| tour.stops.iter().filter_map(|stop| stop.location()).map(get_point).collect())
+        .collect::<GenericResult<Vec<Vec<_>>>>()?;
+
+    let depots = tours
+        .iter()
+        .flat_map(|tour| tour.first().into_iter().chain(tour.last()))
+        .fold((HashSet::new(), Vec::new()), |(mut seen, mut depots), &(lng, lat)| {
+            if seen.insert((lng.to_bits(), lat.to_bits())) {
+                depots.push((lng, lat));
+            }
+            (seen, depots)
+        })
+        .1;
+
+    let unassigned_ids = solution.unassigned.iter().flatten().map(|job| job.job_id.as_str()).collect::<HashSet<_>>();
+    let unassigned = problem
+        .iter()
+        .flat_map(|problem| problem.plan.jobs.iter())
+        .filter(|job| unassigned_ids.contains(job.id.as_str()))
+        .flat_map(|job| job.all_tasks_iter())
+        .flat_map(|task| task.places.iter())
+        .map(|place| get_point(&place.location))
+        .collect::<GenericResult<Vec<_>>>()?;
+
+    Ok(PlotData { tours, depots, unassigned })
+}
+
+fn draw_plot<B: DrawingBackend>(area: &DrawingArea<B, Shift>, plot_data: &PlotData) -> GenericResult<()> {
+    let to_error = |err: DrawingAreaErrorKind<B::ErrorType>| GenericError::from(format!("cannot plot solution: {err}"));
+
+    let ((min_x, min_y), (max_x, max_y)) = get_bounds(plot_data, area.dim_in_pixel());
+
+    area.fill(&WHITE).map_err(to_error)?;
+
+    let mut chart =
+        ChartBuilder::on(area).margin(20).build_cartesian_2d(min_x..max_x, min_y..max_y).map_err(to_error)?;
+
+    plot_data.tours.iter().enumerate().try_for_each(|(idx, tour)| {
+        let color = Palette99::pick(idx).to_rgba();
+
+        chart.draw_series(LineSeries::new(tour.iter().copied(), color.stroke_width(2))).map_err(to_error)?;
+        chart.draw_series(tour.iter().map(|&point| Circle::new(point, 3, color.filled()))).map_err(to_error)?;
+
+        Ok::<_, GenericError>(())
+    })?;
+
+    chart
+        .draw_series(
+            plot_data
+                .depots
+                .iter()
+                .map(|&point| EmptyElement::at(point) + Rectangle::new([(-6, -6), (6, 6)], BLACK.filled())),
+        )
+        .map_err(to_error)?;
+
+    chart
+        .draw_series(plot_data.unassigned.iter().map(|&point| Cross::new(point, 6, RED.stroke_width(3))))
+        .map_err(to_error)?;
+
+    area.present().map_err(to_error)
+}
+
+/// Returns bounds of all points with some padding keeping aspect ratio close to real one.
+fn get_bounds(plot_data: &PlotData, (width, height): (u32, u32)) -> ((f64, f64), (f64, f64)) {
+    let points = plot_data.tours.iter().flatten().chain(plot_data.unassigned.iter()).chain(plot_data.depots.iter());
+
+    let ((min_x, min_y), (max_x, max_y)) = points
+        .fold(((f64::MAX, f64::MAX), (f64::MIN, f64::MIN)), |((min_x, min_y), (max_x, max_y)), &(x, y)| {
+            ((min_x.min(x), min_y.min(y)), (max_x.max(x), max_y.max(y)))
+        });
+
+    if min_x > max_x {
+        return ((0., 0.), (1., 1.));
+    }
+
+    // NOTE one degree of longitude is shorter than one degree of latitude away from equator
+    let lng_scale = ((min_y + max_y) / 2.).to_radians().cos().max(1E-6);
+    let aspect = width.max(1) as f64 / height.max(1) as f64;
+    let half_y = ((max_x - min_x) * lng_scale / 2. / aspect).max((max_y - min_y) / 2.).max(1E-4) * 1.1;
+    let half_x = half_y * aspect / lng_scale;
+    let (center_x, center_y) = ((min_x + max_x) / 2., (min_y + max_y) / 2.);
+
+    ((center_x - half_x, center_y - half_y), (center_x + half_x, center_y + half_y))
+}
@@ -0,0 +1,122 @@
+//! Provides protobuf serialization of problem, routing matrix and solution in pragmatic format which reduces
+//! payload size and parse time for very large problems.
+//!
+//! See `proto/pragmatic.proto` for message definitions.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/proto/proto_test.rs"]
+mod proto_test;
+
+mod problem;
+pub use self::problem::{from_proto_matrix, from_proto_problem, to_proto_matrix, to_proto_problem};
+
+mod solution;
+pub use self::solution::{from_proto_solution, to_proto_solution};
+
+use prost::Message;
+use std::io::{BufReader, BufWriter, Read, Write};
+use vrp_core::prelude::{GenericError, GenericResult};
+use vrp_pragmatic::format::problem::{Matrix, Problem};
+use vrp_pragmatic::format::solution::Solution;
+use vrp_pragmatic::format::{CustomLocationType, Location};
+
+/// Contains code generated from message definitions.
+#[allow(missing_docs, clippy::all)]
+pub mod model {
+    include!(concat!(env!("OUT_DIR"), "/vrp.pragmatic.v1.rs"));
+}
+
+/// Reads problem in pragmatic format from protobuf message.
+pub fn read_proto_problem<R: Read>(reader: BufReader<R>) -> GenericResult<Problem> {
+    decode::<model::Problem, _>(reader).and_then(from_proto_problem)
+}
+
+/// Writes problem in pragmatic format as protobuf message.
+pub fn write_proto_problem<W: Write>(problem: &Problem, writer: &mut BufWriter<W>) -> GenericResult<()> {
+    encode(&to_proto_problem(problem)?, writer)
+}
+
+/// Reads routing matrix in pragmatic format from protobuf message.
+pub fn read_proto_matrix<R: Read>(reader: BufReader<R>) -> GenericResult<Matrix> {
+    decode::<model::Matrix, _>(reader).map(from_proto_matrix)
+}
+
+/// Writes routing matrix in pragmatic format as protobuf message.
+pub fn write_proto_matrix<W: Write>(matrix: &Matrix, writer: &mut BufWriter<W>) -> GenericResult<()> {
+    encode(&to_proto_matrix(matrix), writer)
+}
+
+/// Reads solution in pragmatic format from protobuf message.
+pub fn read_proto_solution<R: Read>(reader: BufReader<R>) -> GenericResult<Solution> {
+    decode::<model::Solution, _>(reader).and_then(from_proto_solution)
+}
+
+/// Writes solution in pragmatic format as protobuf message.
+pub fn write_proto_solution<W: Write>(solution: &Solution, writer: &mut BufWriter<W>) -> GenericResult<()> {
+    encode(&to_proto_solution(solution)?, writer)
+}
+
+fn decode<M: Message + Default, R: Read>(mut reader: BufReader<R>) -> GenericResult<M> {
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes).map_err(|err| format!("cannot read protobuf message: '{err}'"))?;
+
+    M::decode(bytes.as_slice()).map_err(|err| format!("cannot decode protobuf message: '{err}'").into())
+}
+
+fn encode<M: Message, W: Write>(message: &M, writer: &mut BufWriter<W>) -> GenericResult<()> {
+    writer
+        .write_all(message.encode_to_vec().as_slice())
+        .map_err(|err| format!("cannot write protobuf message: '{err}'").into())
+}
+
+fn to_proto_location(location: &Location) -> model::Location {
+    use self::model::location::Kind;
+
+    let kind = match location {
+        Location::Coordinate { lat, lng } => Kind::Coordinate(model::Coordinate { lat: *lat, lng: *lng }),
+        Location::Reference { index } => Kind::Index(*index as u64),
+        Location::Custom { r#type: CustomLocationType::Unknown } => Kind::Custom("unknown".to_string()),
+    };
+
+    model::Location { kind: Some(kind) }
+}
+
+fn from_proto_location(location: Option<model::Location>) -> GenericResult<Location> {
+    use self::model::location::Kind;
+
+    match location.and_then(|location| location.kind) {
+        Some(Kind::Coordinate(model::Coordinate { lat, lng })) => Ok(Location::Coordinate { lat, lng }),
+        Some(Kind::Index(index)) => Ok(Location::Reference { index: index as usize }),
+        Some(Kind::Custom(custom)) if custom == "unknown" => {
+            Ok(Location::Custom { r#type: CustomLocationType::Unknown })
+        }
+        Some(Kind::Custom(custom)) => Err(format!("unknown custom location type: '{custom}'").into()),
+        None => Err("missing location".into()),
+    }
+}
+
+fn to_proto_list<T, R>(values: &Option<Vec<T>>, map_fn: impl Fn(&T) -> R) -> Vec<R> {
+    values.iter().flatten().map(map_fn).collect()
+}
+
+fn from_proto_list<T, R>(values: Vec<T>, map_fn: impl Fn(T) -> GenericResult<R>) -> GenericResult<Option<Vec<R>>> {
+    let values = values.into_iter().map(map_fn).collect::<GenericResult<Vec<_>>>()?;
+
+    Ok(if values.is_empty() { None } else { Some(values) })
+}
+
+fn required<T>(value: Option<T>, name: &str) -> GenericResult<T> {
+    value.ok_or_else(|| GenericError::from(format!("missing {name}")))
+}
+
+fn to_option<T>(values: Vec<T>) -> Option<Vec<T>> {
+    if values.is_empty() { None } else { Some(values) }
+}
+
+fn to_json<T: serde::Serialize>(value: &Option<T>) -> GenericResult<Option<String>> {
+    value.as_ref().map(|value| serde_json::to_string(value).map_err(|err| err.to_string().into())).transpose()
+}
+
+fn from_json<T: serde::de::DeserializeOwned>(value: Option<String>) -> GenericResult<Option<T>> {
+    value.map(|value| serde_json::from_str(value.as_str()).map_err(|err| err.to_string().into())).transpose()
+}
@@ -0,0 +1,535 @@
+use super::*;
+use vrp_pragmatic::format::problem::*;
+
+/// Converts problem in pragmatic format to protobuf message.
+pub fn to_proto_problem(problem: &Problem) -> GenericResult<model::Problem> {
+    Ok(model::Problem {
+        plan: Some(to_proto_plan(&problem.plan)?),
+        fleet: Some(to_proto_fleet(&problem.fleet)),
+        objectives_json: to_json(&problem.objectives)?,
+        timezone: problem.timezone.clone(),
+    })
+}
+
+/// Converts protobuf message to problem in pragmatic format.
+pub fn from_proto_problem(problem: model::Problem) -> GenericResult<Problem> {
+    Ok(Problem {
+        plan: from_proto_plan(required(problem.plan, "plan")?)?,
+        fleet: from_proto_fleet(required(problem.fleet, "fleet")?)?,
+        objectives: from_json(problem.objectives_json)?,
+        timezone: problem.timezone,
+    })
+}
+
+/// Converts routing matrix in pragmatic format to protobuf message.
+pub fn to_proto_matrix(matrix: &Matrix) -> model::Matrix {
+    model::Matrix {
+        profile: matrix.profile.clone(),
+        timestamp: matrix.timestamp.clone(),
+        travel_times: matrix.travel_times.clone(),
+        distances: matrix.distances.clone(),
+        error_codes: matrix.error_codes.clone().unwrap_or_default(),
+    }
+}
+
+/// Converts protobuf message to routing matrix in pragmatic format.
+pub fn from_proto_matrix(matrix: model::Matrix) -> Matrix {
+    Matrix {
+        profile: matrix.profile,
+        timestamp: matrix.timestamp,
+        travel_times: matrix.travel_times,
+        distances: matrix.distances,
+        error_codes: if matrix.error_codes.is_empty() { None } else { Some(matrix.error_codes) },
+    }
+}
+
+// region Plan
+
+fn to_proto_plan(plan: &Plan) -> GenericResult<model::Plan> {
+    Ok(model::Plan {
+        jobs: plan.jobs.iter().map(to_proto_job).collect(),
+        relations: to_proto_list(&plan.relations, to_proto_relation),
+        clustering_json: to_json(&plan.clustering)?,
+        incompatibilities: to_proto_list(&plan.incompatibilities, |incompatibility| model::JobIncompatibility {
+            categories: incompatibility.categories.clone(),
+        }),
+    })
+}
+
+fn from_proto_plan(plan: model::Plan) -> GenericResult<Plan> {
+    Ok(Plan {
+        jobs: plan.jobs.into_iter().map(from_proto_job).collect::<GenericResult<_>>()?,
+        relations: from_proto_list(plan.relations, from_proto_relation)?,
+        clustering: from_json(plan.clustering_json)?,
+        incompatibilities: from_proto_list(plan.incompatibilities, |incompatibility| {
+            Ok(JobIncompatibility { categories: incompatibility.categories })
+        })?,
+    })
+}
+
+fn to_proto_job(job: &Job) -> model::Job {
+    model::Job {
+        id: job.id.clone(),
+        pickups: to_proto_list(&job.pickups, to_proto_task),
+        deliveries: to_proto_list(&job.deliveries, to_proto_task),
+        replacements: to_proto_list(&job.replacements, to_proto_task),
+        services: to_proto_list(&job.services, to_proto_task),
+        skills: job.skills.as_ref().map(|skills| model::JobSkills {
+            all_of: skills.all_of.clone().unwrap_or_default(),
+            one_of: skills.one_of.clone().unwrap_or_default(),
+            none_of: skills.none_of.clone().unwrap_or_default(),
+            levels: to_proto_list(&skills.levels, |level| model::JobSkillLevel {
+                name: level.name.clone(),
+                min_level: level.min_level.map(|level| level as u64),
+                preferred_level: level.preferred_level.map(|level| level as u64),
+            }),
+        }),
+        value: job.value,
+        group: job.group.clone(),
+        compatibility: job.compatibility.clone(),
+        priority: job.priority.map(|priority| priority as u64),
+        category: job.category.clone(),
+        crew: job.crew.map(|crew| crew as u64),
+    }
+}
+
+fn from_proto_job(job: model::Job) -> GenericResult<Job> {
+    Ok(Job {
+        id: job.id,
+        pickups: from_proto_list(job.pickups, from_proto_task)?,
+        deliveries: from_proto_list(job.deliveries, from_proto_task)?,
+        replacements: from_proto_list(job.replacements, from_proto_task)?,
+        services: from_proto_list(job.services, from_proto_task)?,
+        skills: job.skills.map(|skills| JobSkills {
+            all_of: to_option(skills.all_of),
+            one_of: to_option(skills.one_of),
+            none_of: to_option(skills.none_of),
+            levels: to_option(
+                skills
+                    .levels
+                    .into_iter()
+                    .map(|level| JobSkillLevel {
+                        name: level.name,
+                        min_level: level.min_level.map(|level| level as usize),
+                        preferred_level: level.preferred_level.map(|level| level as usize),
+                    })
+                    .collect(),
+            ),
+        }),
+        value: job.value,
+        group: job.group,
+        compatibility: job.compatibility,
+        priority: job.priority.map(|priority| priority as usize),
+        category: job.category,
+        crew: job.crew.map(|crew| crew as usize),
+    })
+}
+
+fn to_proto_task(task: &JobTask) -> model::JobTask {
+    model::JobTask {
+        places: task.places.iter().map(to_proto_place).collect(),
+        demand: task.demand.clone().unwrap_or_default(),
+        order: task.order,
+    }
+}
+
+fn from_proto_task(task: model::JobTask) -> GenericResult<JobTask> {
+    Ok(JobTask {
+        places: task.places.into_iter().map(from_proto_place).collect::<GenericResult<_>>()?,
+        demand: to_option(task.demand),
+        order: task.order,
+    })
+}
+
+fn to_proto_place(place: &JobPlace) -> model::JobPlace {
+    model::JobPlace {
+        location: Some(to_proto_location(&place.location)),
+        duration: place.duration,
+        times: to_proto_times(&place.times),
+        tag: place.tag.clone(),
+        cost: place.cost,
+    }
+}
+
+fn from_proto_place(place: model::JobPlace) -> GenericResult<JobPlace> {
+    Ok(JobPlace {
+        location: from_proto_location(place.location)?,
+        duration: place.duration,
+        times: from_proto_times(place.times),
+        tag: place.tag,
+        cost: place.cost,
+    })
+}
+
+fn to_proto_relation(relation: &Relation) -> model::Relation {
+    let relation_type = match relation.type_field {
+        RelationType::Any => model::RelationType::Any,
+        RelationType::Sequence => model::RelationType::Sequence,
+        RelationType::Strict => model::RelationType::Strict,
+        RelationType::NotSameTour => model::RelationType::NotSameTour,
+        RelationType::DifferentVehicle => model::RelationType::DifferentVehicle,
+    };
+
+    model::Relation {
+        r#type: relation_type as i32,
+        jobs: relation.jobs.clone(),
+        vehicle_id: relation.vehicle_id.clone(),
+        shift_index: relation.shift_index.map(|index| index as u64),
+    }
+}
+
+fn from_proto_relation(relation: model::Relation) -> GenericResult<Relation> {
+    let type_field = match model::RelationType::try_from(relation.r#type) {
+        Ok(model::RelationType::Any) => RelationType::Any,
+        Ok(model::RelationType::Sequence) => RelationType::Sequence,
+        Ok(model::RelationType::Strict) => RelationType::Strict,
+        Ok(model::RelationType::NotSameTour) => RelationType::NotSameTour,
+        Ok(model::RelationType::DifferentVehicle) => RelationType::DifferentVehicle,
+        Err(_) => return Err(format!("unknown relation type: {}", relation.r#type).into()),
+    };
+
+    Ok(Relation {
+        type_field,
+        jobs: relation.jobs,
+        vehicle_id: relation.vehicle_id,
+        shift_index: relation.shift_index.map(|index| index as usize),
+    })
+}
+
+// endregion
+
+// region Fleet
+
+fn to_proto_fleet(fleet: &Fleet) -> model::Fleet {
+    model::Fleet {
+        vehicles: fleet.vehicles.iter().map(to_proto_vehicle).collect(),
+        profiles: fleet
+            .profiles
+            .iter()
+            .map(|profile| model::MatrixProfile {
+                name: profile.name.clone(),
+                speed: profile.speed,
+                restrictions: to_proto_list(&profile.restrictions, |restriction| model::AccessRestriction {
+                    locations: restriction.locations.iter().map(to_proto_location).collect(),
+                    time: Some(model::TimeWindow { times: restriction.time.clone() }),
+                }),
+            })
+            .collect(),
+        resources: to_proto_list(&fleet.resources, |resource| match resource {
+            VehicleResource::Reload { id, capacity } => {
+                model::VehicleResource { id: id.clone(), capacity: capacity.clone() }
+            }
+        }),
+        depots: to_proto_list(&fleet.depots, |depot| model::Depot {
+            location: Some(to_proto_location(&depot.location)),
+            times: to_proto_times(&Some(depot.times.clone())),
+        }),
+    }
+}
+
+fn from_proto_fleet(fleet: model::Fleet) -> GenericResult<Fleet> {
+    Ok(Fleet {
+        vehicles: fleet.vehicles.into_iter().map(from_proto_vehicle).collect::<GenericResult<_>>()?,
+        profiles: fleet
+            .profiles
+            .into_iter()
+            .map(|profile| {
+                Ok(MatrixProfile {
+                    name: profile.name,
+                    speed: profile.speed,
+                    restrictions: from_proto_list(profile.restrictions, |restriction| {
+                        Ok(AccessRestriction {
+                            locations: restriction
+                                .locations
+                                .into_iter()
+                                .map(|location| from_proto_location(Some(location)))
+                                .collect::<GenericResult<_>>()?,
+                            time: required(restriction.time, "access restriction time")?.times,
+                        })
+                    })?,
+                })
+            })
+            .collect::<GenericResult<_>>()?,
+        resources: from_proto_list(fleet.resources, |resource| {
+            Ok(VehicleResource::Reload { id: resource.id, capacity: resource.capacity })
+        })?,
+        depots: from_proto_list(fleet.depots, |depot| {
+            Ok(Depot {
+                location: from_proto_location(depot.location)?,
+                times: from_proto_times(depot.times).unwrap_or_default(),
+            })
+        })?,
+    })
+}
+
+fn to_proto_vehicle(vehicle: &VehicleType) -> model::VehicleType {
+    model::VehicleType {
+        type_id: vehicle.type_id.clone(),
+        vehicle_ids: vehicle.vehicle_ids.clone(),
+        profile: Some(to_proto_profile(&vehicle.profile)),
+        costs: Some(model::VehicleCosts {
+            fixed: vehicle.costs.fixed,
+            distance: vehicle.costs.distance,
+            time: vehicle.costs.time,
+            tiers: to_proto_list(&vehicle.costs.tiers, |tier| model::VehicleCostTier {
+                fixed: tier.fixed,
+                max_duration: tier.max_duration,
+                max_distance: tier.max_distance,
+            }),
+        }),
+        shifts: vehicle.shifts.iter().map(to_proto_shift).collect(),
+        capacity: vehicle.capacity.clone(),
+        skills: to_proto_list(&vehicle.skills, |skill| model::VehicleSkill {
+            name: skill.name().to_string(),
+            level: skill.level().map(|level| level as u64),
+        }),
+        limits: vehicle.limits.as_ref().map(|limits| model::VehicleLimits {
+            max_distance: limits.max_distance,
+            max_duration: limits.max_duration,
+            tour_size: limits.tour_size.map(|size| size as u64),
+            max_stops: limits.max_stops.map(|stops| stops as u64),
+            max_total_distance: limits.max_total_distance,
+            max_total_driving_time: limits.max_total_driving_time,
+        }),
+    }
+}
+
+fn from_proto_vehicle(vehicle: model::VehicleType) -> GenericResult<VehicleType> {
+    let costs = required(vehicle.costs, "vehicle costs")?;
+
+    Ok(VehicleType {
+        type_id: vehicle.type_id,
+        vehicle_ids: vehicle.vehicle_ids,
+        profile: from_proto_profile(required(vehicle.profile, "vehicle profile")?),
+        costs: VehicleCosts {
+            fixed: costs.fixed,
+            distance: costs.distance,
+            time: costs.time,
+            tiers: from_proto_list(costs.tiers, |tier| {
+                Ok(VehicleCostTier {
+                    fixed: tier.fixed,
+                    max_duration: tier.max_duration,
+                    max_distance: tier.max_distance,
+                })
+            })?,
+        },
+        shifts: vehicle.shifts.into_iter().map(from_proto_shift).collect::<GenericResult<_>>()?,
+        capacity: vehicle.capacity,
+        skills: from_proto_list(vehicle.skills, |skill| {
+            Ok(match skill.level {
+                Some(level) => VehicleSkill::Level { name: skill.name, level: level as usize },
+                None => VehicleSkill::Name(skill.name),
+            })
+        })?,
+        limits: vehicle.limits.map(|limits| VehicleLimits {
+            max_distance: limits.max_distance,
+            max_duration: limits.max_duration,
+            tour_size: limits.tour_size.map(|size| size as usize),
+            max_stops: limits.max_stops.map(|stops| stops as usize),
+            max_total_distance: limits.max_total_distance,
+            max_total_driving_time: limits.max_total_driving_time,
+        }),
+    })
+}
+
+fn to_proto_profile(profile: &VehicleProfile) -> model::VehicleProfile {
+    model::VehicleProfile { matrix: profile.matrix.clone(), scale: profile.scale }
+}
+
+fn from_proto_profile(profile: model::VehicleProfile) -> VehicleProfile {
+    VehicleProfile { matrix: profile.matrix, scale: profile.scale }
+}
+
+fn to_proto_shift(shift: &VehicleShift) -> model::VehicleShift {
+    model::VehicleShift {
+        start: Some(model::ShiftStart {
+            earliest: shift.start.earliest.clone(),
+            latest: shift.start.latest.clone(),
+            location: Some(to_proto_location(&shift.start.location)),
+        }),
+        end: shift.end.as_ref().map(|end| model::ShiftEnd {
+            earliest: end.earliest.clone(),
+            latest: end.latest.clone(),
+            location: Some(to_proto_location(&end.location)),
+        }),
+        breaks: to_proto_list(&shift.breaks, to_proto_break),
+        reloads: to_proto_list(&shift.reloads, |reload| model::VehicleReload {
+            location: Some(to_proto_location(&reload.location)),
+            duration: reload.duration,
+            times: to_proto_times(&reload.times),
+            tag: reload.tag.clone(),
+            resource_id: reload.resource_id.clone(),
+        }),
+        recharges: shift.recharges.as_ref().map(|recharges| model::VehicleRecharges {
+            max_distance: recharges.max_distance,
+            stations: recharges.stations.iter().map(to_proto_place).collect(),
+        }),
+        capacity_change: shift.capacity_change.as_ref().map(|change| model::VehicleCapacityChange {
+            location: Some(to_proto_location(&change.location)),
+            duration: change.duration,
+            times: to_proto_times(&change.times),
+            capacity: change.capacity.clone(),
+            profile: change.profile.as_ref().map(to_proto_profile),
+            tag: change.tag.clone(),
+        }),
+    }
+}
+
+fn from_proto_shift(shift: model::VehicleShift) -> GenericResult<VehicleShift> {
+    let start = required(shift.start, "shift start")?;
+
+    Ok(VehicleShift {
+        start: ShiftStart {
+            earliest: start.earliest,
+            latest: start.latest,
+            location: from_proto_location(start.location)?,
+        },
+        end: shift
+            .end
+            .map(|end| {
+                Ok::<_, GenericError>(ShiftEnd {
+                    earliest: end.earliest,
+                    latest: end.latest,
+                    location: from_proto_location(end.location)?,
+                })
+            })
+            .transpose()?,
+        breaks: from_proto_list(shift.breaks, from_proto_break)?,
+        reloads: from_proto_list(shift.reloads, |reload| {
+            Ok(VehicleReload {
+                location: from_proto_location(reload.location)?,
+                duration: reload.duration,
+                times: from_proto_times(reload.times),
+                tag: reload.tag,
+                resource_id: reload.resource_id,
+            })
+        })?,
+        recharges: shift
+            .recharges
+            .map(|recharges| {
+                Ok::<_, GenericError>(VehicleRecharges {
+                    max_distance: recharges.max_distance,
+                    stations: recharges.stations.into_iter().map(from_proto_place).collect::<GenericResult<_>>()?,
+                })
+            })
+            .transpose()?,
+        capacity_change: shift
+            .capacity_change
+            .map(|change| {
+                Ok::<_, GenericError>(VehicleCapacityChange {
+                    location: from_proto_location(change.location)?,
+                    duration: change.duration,
+                    times: from_proto_times(change.times),
+                    capacity: change.capacity,
+                    profile: change.profile.map(from_proto_profile),
+                    tag: change.tag,
+                })
+            })
+            .transpose()?,
+    })
+}
+
+fn to_proto_break(vehicle_break: &VehicleBreak) -> model::VehicleBreak {
+    use self::model::vehicle_break::Kind;
+    use self::model::{optional_break, required_break};
+
+    let kind = match vehicle_break {
+        VehicleBreak::Optional { time, places, policy } => Kind::Optional(model::OptionalBreak {
+            time: Some(match time {
+                VehicleOptionalBreakTime::TimeWindow(times) => {
+                    optional_break::Time::TimeWindow(model::TimeWindow { times: times.clone() })
+                }
+                VehicleOptionalBreakTime::TimeOffset(offsets) => {
+                    optional_break::Time::TimeOffset(model::OffsetRange { offsets: offsets.clone() })
+                }
+            }),
+            places: places
+                .iter()
+                .map(|place| model::OptionalBreakPlace {
+                    duration: place.duration,
+                    location: place.location.as_ref().map(to_proto_location),
+                    tag: place.tag.clone(),
+                })
+                .collect(),
+            policy: policy.as_ref().map(|policy| {
+                match policy {
+                    VehicleOptionalBreakPolicy::SkipIfNoIntersection => "skip-if-no-intersection",
+                    VehicleOptionalBreakPolicy::SkipIfArrivalBeforeEnd => "skip-if-arrival-before-end",
+                }
+                .to_string()
+            }),
+        }),
+        VehicleBreak::Required { time, duration } => Kind::Required(model::RequiredBreak {
+            time: Some(match time {
+                VehicleRequiredBreakTime::ExactTime { earliest, latest } => {
+                    required_break::Time::ExactTime(model::TimeWindow { times: vec![earliest.clone(), latest.clone()] })
+                }
+                VehicleRequiredBreakTime::OffsetTime { earliest, latest } => {
+                    required_break::Time::OffsetTime(model::OffsetRange { offsets: vec![*earliest, *latest] })
+                }
+            }),
+            duration: *duration,
+        }),
+    };
+
+    model::VehicleBreak { kind: Some(kind) }
+}
+
+fn from_proto_break(vehicle_break: model::VehicleBreak) -> GenericResult<VehicleBreak> {
+    use self::model::vehicle_break::Kind;
+    use self::model::{optional_break, required_break};
+
+    match required(vehicle_break.kind, "vehicle break")? {
+        Kind::Optional(optional) => Ok(VehicleBreak::Optional {
+            time: match required(optional.time, "break time")? {
+                optional_break::Time::TimeWindow(window) => VehicleOptionalBreakTime::TimeWindow(window.times),
+                optional_break::Time::TimeOffset(range) => VehicleOptionalBreakTime::TimeOffset(range.offsets),
+            },
+            places: optional
+                .places
+                .into_iter()
+                .map(|place| {
+                    Ok(VehicleOptionalBreakPlace {
+                        duration: place.duration,
+                        location: place.location.map(|location| from_proto_location(Some(location))).transpose()?,
+                        tag: place.tag,
+                    })
+                })
+                .collect::<GenericResult<_>>()?,
+            policy: optional
+                .policy
+                .map(|policy| match policy.as_str() {
+                    "skip-if-no-intersection" => Ok(VehicleOptionalBreakPolicy::SkipIfNoIntersection),
+                    "skip-if-arrival-before-end" => Ok(VehicleOptionalBreakPolicy::SkipIfArrivalBeforeEnd),
+                    _ => Err(GenericError::from(format!("unknown break policy: '{policy}'"))),
+                })
+                .transpose()?,
+        }),
+        Kind::Required(required_break) => Ok(VehicleBreak::Required {
+            time: match required(required_break.time, "break time")? {
+                required_break::Time::ExactTime(model::TimeWindow { times }) if times.len() == 2 => {
+                    let mut times = times.into_iter();
+                    VehicleRequiredBreakTime::ExactTime {
+                        earliest: times.next().unwrap_or_default(),
+                        latest: times.next().unwrap_or_default(),
+                    }
+                }
+                required_break::Time::OffsetTime(model::OffsetRange { offsets }) if offsets.len() == 2 => {
+                    VehicleRequiredBreakTime::OffsetTime { earliest: offsets[0], latest: offsets[1] }
+                }
+                _ => return Err("required break time should have exactly two values".into()),
+            },
+            duration: required_break.duration,
+        }),
+    }
+}
+
+// endregion
+
+fn to_proto_times(times: &Option<Vec<Vec<String>>>) -> Vec<model::TimeWindow> {
+    to_proto_list(times, |times| model::TimeWindow { times: times.clone() })
+}
+
+fn from_proto_times(times: Vec<model::TimeWindow>) -> Option<Vec<Vec<String>>> {
+    to_option(times.into_iter().map(|window| window.times).collect())
+}
@@ -0,0 +1,246 @@
+use super::*;
+use vrp_pragmatic::format::solution::*;
+
+/// Converts solution in pragmatic format to protobuf message.
+pub fn to_proto_solution(solution: &Solution) -> GenericResult<model::Solution> {
+    Ok(model::Solution {
+        statistic: Some(to_proto_statistic(&solution.statistic)),
+        tours: solution.tours.iter().map(to_proto_tour).collect(),
+        unassigned: to_proto_list(&solution.unassigned, |job| model::UnassignedJob {
+            job_id: job.job_id.clone(),
+            reasons: job
+                .reasons
+                .iter()
+                .map(|reason| model::UnassignedJobReason {
+                    code: reason.code.clone(),
+                    description: reason.description.clone(),
+                    details: to_proto_list(&reason.details, |detail| model::UnassignedJobDetail {
+                        vehicle_id: detail.vehicle_id.clone(),
+                        shift_index: detail.shift_index as u64,
+                        time: detail.time.as_ref().map(to_proto_interval),
+                    }),
+                })
+                .collect(),
+        }),
+        violations: to_proto_list(&solution.violations, |violation| match violation {
+            Violation::Break { vehicle_id, shift_index } => {
+                model::Violation { vehicle_id: vehicle_id.clone(), shift_index: *shift_index as u64 }
+            }
+        }),
+        extras_json: to_json(&solution.extras)?,
+    })
+}
+
+/// Converts protobuf message to solution in pragmatic format.
+pub fn from_proto_solution(solution: model::Solution) -> GenericResult<Solution> {
+    Ok(Solution {
+        statistic: from_proto_statistic(required(solution.statistic, "solution statistic")?),
+        tours: solution.tours.into_iter().map(from_proto_tour).collect::<GenericResult<_>>()?,
+        unassigned: from_proto_list(solution.unassigned, |job| {
+            Ok(UnassignedJob {
+                job_id: job.job_id,
+                reasons: job
+                    .reasons
+                    .into_iter()
+                    .map(|reason| UnassignedJobReason {
+                        code: reason.code,
+                        description: reason.description,
+                        details: to_option(
+                            reason
+                                .details
+                                .into_iter()
+                                .map(|detail| UnassignedJobDetail {
+                                    vehicle_id: detail.vehicle_id,
+                                    shift_index: detail.shift_index as usize,
+                                    time: detail.time.map(from_proto_interval),
+                                })
+                                .collect(),
+                        ),
+                    })
+                    .collect(),
+            })
+        })?,
+        violations: from_proto_list(solution.violations, |violation| {
+            Ok(Violation::Break { vehicle_id: violation.vehicle_id, shift_index: violation.shift_index as usize })
+        })?,
+        extras: from_json(solution.extras_json)?,
+    })
+}
+
+fn to_proto_statistic(statistic: &Statistic) -> model::Statistic {
+    let times = &statistic.times;
+    let costs = &statistic.costs;
+
+    model::Statistic {
+        cost: statistic.cost,
+        distance: statistic.distance,
+        duration: statistic.duration,
+        times: Some(model::Timing {
+            driving: times.driving,
+            serving: times.serving,
+            waiting: times.waiting,
+            r#break: times.break_time,
+            commuting: times.commuting,
+            parking: times.parking,
+        }),
+        costs: Some(model::CostBreakdown {
+            fixed: costs.fixed,
+            distance: costs.distance,
+            driving: costs.driving,
+            serving: costs.serving,
+            waiting: costs.waiting,
+            r#break: costs.break_cost,
+        }),
+    }
+}
+
+fn from_proto_statistic(statistic: model::Statistic) -> Statistic {
+    let times = statistic.times.unwrap_or_default();
+    let costs = statistic.costs.unwrap_or_default();
+
+    Statistic {
+        cost: statistic.cost,
+        distance: statistic.distance,
+        duration: statistic.duration,
+        times: Timing {
+            driving: times.driving,
+            serving: times.serving,
+            waiting: times.waiting,
+            break_time: times.r#break,
+            commuting: times.commuting,
+            parking: times.parking,
+        },
+        costs: CostBreakdown {
+            fixed: costs.fixed,
+            distance: costs.distance,
+            driving: costs.driving,
+            serving: costs.serving,
+            waiting: costs.waiting,
+            break_cost: costs.r#break,
+        },
+    }
+}
+
+fn to_proto_tour(tour: &Tour) -> model::Tour {
+    model::Tour {
+        vehicle_id: tour.vehicle_id.clone(),
+        type_id: tour.type_id.clone(),
+        shift_index: tour.shift_index as u64,
+        stops: tour.stops.iter().map(to_proto_stop).collect(),
+        statistic: Some(to_proto_statistic(&tour.statistic)),
+    }
+}
+
+fn from_proto_tour(tour: model::Tour) -> GenericResult<Tour> {
+    Ok(Tour {
+        vehicle_id: tour.vehicle_id,
+        type_id: tour.type_id,
+        shift_index: tour.shift_index as usize,
+        stops: tour.stops.into_iter().map(from_proto_stop).collect::<GenericResult<_>>()?,
+        statistic: from_proto_statistic(required(tour.statistic, "tour statistic")?),
+    })
+}
+
+fn to_proto_stop(stop: &Stop) -> model::Stop {
+    match stop {
+        Stop::Point(point) => model::Stop {
+            location: Some(to_proto_location(&point.location)),
+            time: Some(to_proto_schedule(&point.time)),
+            distance: point.distance,
+            load: point.load.clone(),
+            parking: point.parking.as_ref().map(to_proto_interval),
+            activities: point.activities.iter().map(to_proto_activity).collect(),
+            geometry: point.geometry.clone(),
+        },
+        Stop::Transit(transit) => model::Stop {
+            location: None,
+            time: Some(to_proto_schedule(&transit.time)),
+            distance: 0,
+            load: transit.load.clone(),
+            parking: None,
+            activities: transit.activities.iter().map(to_proto_activity).collect(),
+            geometry: None,
+        },
+    }
+}
+
+fn from_proto_stop(stop: model::Stop) -> GenericResult<Stop> {
+    let time = from_proto_schedule(required(stop.time, "stop time")?);
+    let activities = stop.activities.into_iter().map(from_proto_activity).collect::<GenericResult<_>>()?;
+
+    Ok(match stop.location {
+        Some(location) => Stop::Point(PointStop {
+            location: from_proto_location(Some(location))?,
+            time,
+            distance: stop.distance,
+            load: stop.load,
+            parking: stop.parking.map(from_proto_interval),
+            activities,
+            geometry: stop.geometry,
+        }),
+        None => Stop::Transit(TransitStop { time, load: stop.load, activities }),
+    })
+}
+
+fn to_proto_activity(activity: &Activity) -> model::Activity {
+    let to_proto_commute_info = |info: &CommuteInfo| model::CommuteInfo {
+        location: Some(to_proto_location(&info.location)),
+        distance: info.distance,
+        time: Some(to_proto_interval(&info.time)),
+    };
+
+    model::Activity {
+        job_id: activity.job_id.clone(),
+        r#type: activity.activity_type.clone(),
+        location: activity.location.as_ref().map(to_proto_location),
+        time: activity.time.as_ref().map(to_proto_interval),
+        job_tag: activity.job_tag.clone(),
+        commute: activity.commute.as_ref().map(|commute| model::Commute {
+            forward: commute.forward.as_ref().map(to_proto_commute_info),
+            backward: commute.backward.as_ref().map(to_proto_commute_info),
+        }),
+    }
+}
+
+fn from_proto_activity(activity: model::Activity) -> GenericResult<Activity> {
+    let from_proto_commute_info = |info: model::CommuteInfo| {
+        Ok::<_, GenericError>(CommuteInfo {
+            location: from_proto_location(info.location)?,
+            distance: info.distance,
+            time: from_proto_interval(required(info.time, "commute time")?),
+        })
+    };
+
+    Ok(Activity {
+        job_id: activity.job_id,
+        activity_type: activity.r#type,
+        location: activity.location.map(|location| from_proto_location(Some(location))).transpose()?,
+        time: activity.time.map(from_proto_interval),
+        job_tag: activity.job_tag,
+        commute: activity
+            .commute
+            .map(|commute| {
+                Ok::<_, GenericError>(Commute {
+                    forward: commute.forward.map(from_proto_commute_info).transpose()?,
+                    backward: commute.backward.map(from_proto_commute_info).transpose()?,
+                })
+            })
+            .transpose()?,
+    })
+}
+
+fn to_proto_schedule(schedule: &Schedule) -> model::Schedule {
+    model::Schedule { arrival: schedule.arrival.clone(), departure: schedule.departure.clone() }
+}
+
+fn from_proto_schedule(schedule: model::Schedule) -> Schedule {
+    Schedule { arrival: schedule.arrival, departure: schedule.departure }
+}
+
+fn to_proto_interval(interval: &Interval) -> model::Interval {
+    model::Interval { start: interval.start.clone(), end: interval.end.clone() }
+}
+
+fn from_proto_interval(interval: model::Interval) -> Interval {
+    Interval { start: interval.start, end: interval.end }
+}
@@ -0,0 +1,393 @@
+//! Provides the way to build routing matrices using [OSRM](https://project-osrm.org/) `/table` service,
+//! leg geometries using `/route` service and snap locations to street network using `/nearest` service.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/routing/osrm_test.rs"]
+mod osrm_test;
+
+use serde::Deserialize;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use vrp_core::models::common::Profile;
+use vrp_core::prelude::{Float, GenericError, GenericResult};
+use vrp_pragmatic::format::Location;
+use vrp_pragmatic::format::problem::{Matrix, Problem, deserialize_matrix};
+use vrp_pragmatic::format::solution::{LegGeometryProvider, decode_polyline};
+use vrp_pragmatic::get_unique_locations;
+
+/// A default max amount of sources or destinations in one request: OSRM limits amount of
+/// coordinates in a table request (`--max-table-size`) which is 100 by default.
+const DEFAULT_CHUNK_SIZE: usize = 50;
+
+/// A default timeout to establish connection with OSRM server.
+const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A default timeout of one request to OSRM server, including reading the whole response.
+const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Specifies settings to fetch routing matrices from OSRM server.
+pub struct OsrmConfig {
+    /// A base url of OSRM server, e.g. `http://localhost:5000`. Both http and https are supported.
+    pub url: String,
+    /// Max amount of locations used as sources or destinations in one request.
+    pub chunk_size: usize,
+    /// A directory to cache fetched matrices. No caching is used when not set.
+    pub cache_dir: Option<PathBuf>,
+    /// A timeout to establish connection with OSRM server.
+    pub connect_timeout: Duration,
+    /// A timeout of one request to OSRM server.
+    pub request_timeout: Duration,
+}
+
+impl OsrmConfig {
+    /// Creates a new instance of `OsrmConfig` with default chunk size and timeouts, without caching.
+    pub fn new(url: &str) -> Self {
+        Self {
+            url: url.to_string(),
+            chunk_size: DEFAULT_CHUNK_SIZE,
+            cache_dir: None,
+            connect_timeout: DEFAULT_CONNECT_TIMEOUT,
+            request_timeout: DEFAULT_REQUEST_TIMEOUT,
+        }
+    }
+}
+
+/// Fetches routing matrices for all profiles of the problem from OSRM server. Profile names are
+/// used as OSRM profiles in request urls. Unreachable location pairs are marked via error codes.
+pub fn fetch_osrm_matrices(problem: &Problem, config: &OsrmConfig) -> GenericResult<Vec<Matrix>> {
+    if config.chunk_size == 0 {
+        return Err("chunk size should be greater than zero".into());
+    }
+
+    let endpoint = HttpEndpoint::new(config)?;
+
+    let coordinates = get_unique_locations(problem)
+        .into_iter()
+        .filter(|location| !matches!(location, Location::Custom { .. }))
+        .map(|location| match location {
+            Location::Coordinate { lat, lng } => Ok((lat, lng)),
+            _ => Err(GenericError::from("osrm matrices can be fetched only for locations with coordinates")),
+        })
+        .collect::<GenericResult<Vec<_>>>()?;
+
+    problem
+        .fleet
+        .profiles
+        .iter()
+        .map(|profile| {
+            let cache_path = config
+                .cache_dir
+                .as_ref()
+                .map(|dir| dir.join(get_cache_file_name(config.url.as_str(), profile.name.as_str(), &coordinates)));
+
+            if let Some(matrix) = cache_path.as_deref().and_then(read_cached_matrix) {
+                return Ok(matrix);
+            }
+
+            let matrix = fetch_matrix(&endpoint, profile.name.as_str(), &coordinates, config.chunk_size)?;
+
+            if let Some(cache_path) = cache_path {
+                write_cached_matrix(cache_path.as_path(), &matrix)
+                    .map_err(|err| format!("cannot write matrix cache '{}': {err}", cache_path.display()))?;
+            }
+
+            Ok(matrix)
+        })
+        .collect()
+}
+
+/// Provides leg geometries of solution tours using OSRM route service. Paths which cannot be
+/// fetched are omitted, so the corresponding legs have no geometry.
+pub struct OsrmGeometryProvider {
+    endpoint: HttpEndpoint,
+    profiles: Vec<String>,
+}
+
+impl OsrmGeometryProvider {
+    /// Creates a new instance of `OsrmGeometryProvider` for profiles of the given problem.
+    pub fn new(problem: &Problem, config: &OsrmConfig) -> GenericResult<Self> {
+        let endpoint = HttpEndpoint::new(config)?;
+        let profiles = problem.fleet.profiles.iter().map(|profile| profile.name.clone()).collect();
+
+        Ok(Self { endpoint, profiles })
+    }
+}
+
+impl LegGeometryProvider for OsrmGeometryProvider {
+    fn get_path(&self, profile: &Profile, from: &Location, to: &Location) -> Option<Vec<(Float, Float)>> {
+        let profile = self.profiles.get(profile.index)?;
+
+        let (Location::Coordinate { lat: from_lat, lng: from_lng }, Location::Coordinate { lat: to_lat, lng: to_lng }) =
+            (from, to)
+        else {
+            return None;
+        };
+
+        let path = format!(
+            "{}/route/v1/{profile}/{from_lng},{from_lat};{to_lng},{to_lat}?overview=full&geometries=polyline",
+            self.endpoint.base_url
+        );
+
+        let response: RouteResponse = serde_json::from_str(self.endpoint.get(path.as_str()).ok()?.as_str()).ok()?;
+
+        if response.code != "Ok" {
+            return None;
+        }
+
+        response.routes?.into_iter().next().and_then(|route| decode_polyline(route.geometry.as_str()).ok())
+    }
+}
+
+/// Snaps coordinates of all job places to the nearest street network location using OSRM nearest service.
+/// The first profile of the fleet is used as OSRM profile.
+pub fn snap_job_locations(mut problem: Problem, config: &OsrmConfig) -> GenericResult<Problem> {
+    let endpoint = HttpEndpoint::new(config)?;
+    let profile = problem
+        .fleet
+        .profiles
+        .first()
+        .map(|profile| profile.name.clone())
+        .ok_or_else(|| GenericError::from("no profiles to snap locations"))?;
+
+    let places = problem.plan.jobs.iter_mut().flat_map(|job| {
+        job.pickups
+            .iter_mut()
+            .chain(job.deliveries.iter_mut())
+            .chain(job.replacements.iter_mut())
+            .chain(job.services.iter_mut())
+            .flat_map(|tasks| tasks.iter_mut())
+            .flat_map(|task| task.places.iter_mut())
+    });
+
+    for place in places {
+        if let Location::Coordinate { lat, lng } = place.location {
+            let path = format!("{}/nearest/v1/{profile}/{lng},{lat}?number=1", endpoint.base_url);
+            let response: NearestResponse = serde_json::from_str(endpoint.get(path.as_str())?.as_str())
+                .map_err(|err| format!("cannot parse osrm response: {err}"))?;
+
+            if response.code != "Ok" {
+                return Err(
+                    format!("osrm returned '{}': {}", response.code, response.message.unwrap_or_default()).into()
+                );
+            }
+
+            let (lng, lat) = response
+                .waypoints
+                .and_then(|waypoints| waypoints.into_iter().next())
+                .map(|waypoint| (waypoint.location[0], waypoint.location[1]))
+                .ok_or_else(|| GenericError::from("osrm response has no waypoints"))?;
+
+            place.location = Location::Coordinate { lat, lng };
+        }
+    }
+
+    Ok(problem)
+}
+
+/// A response of OSRM nearest service.
+#[derive(Deserialize)]
+struct NearestResponse {
+    code: String,
+    message: Option<String>,
+    waypoints: Option<Vec<WaypointInfo>>,
+}
+
+/// A waypoint returned by OSRM nearest service.
+#[derive(Deserialize)]
+struct WaypointInfo {
+    /// A snapped location in longitude, latitude order.
+    location: [Float; 2],
+}
+
+/// A response of OSRM route service.
+#[derive(Deserialize)]
+struct RouteResponse {
+    code: String,
+    routes: Option<Vec<RouteInfo>>,
+}
+
+/// A route returned by OSRM route service.
+#[derive(Deserialize)]
+struct RouteInfo {
+    geometry: String,
+}
+
+/// A response of OSRM table service.
+#[derive(Deserialize)]
+struct TableResponse {
+    code: String,
+    message: Option<String>,
+    durations: Option<Vec<Vec<Option<Float>>>>,
+    distances: Option<Vec<Vec<Option<Float>>>>,
+}
+
+fn fetch_matrix(
+    endpoint: &HttpEndpoint,
+    profile: &str,
+    coordinates: &[(Float, Float)],
+    chunk_size: usize,
+) -> GenericResult<Matrix> {
+    let size = coordinates.len();
+
+    let mut travel_times = vec![0; size * size];
+    let mut distances = vec![0; size * size];
+    let mut error_codes = vec![0; size * size];
+
+    let chunks = (0..size).step_by(chunk_size).map(|start| start..(start + chunk_size).min(size)).collect::<Vec<_>>();
+
+    for sources in chunks.iter() {
+        for destinations in chunks.iter() {
+            let path = get_table_path(endpoint, profile, coordinates, sources.clone(), destinations.clone());
+            let response: TableResponse = serde_json::from_str(endpoint.get(path.as_str())?.as_str())
+                .map_err(|err| format!("cannot parse osrm response: {err}"))?;
+
+            if response.code != "Ok" {
+                return Err(
+                    format!("osrm returned '{}': {}", response.code, response.message.unwrap_or_default()).into()
+                );
+            }
+
+            let (Some(table_durations), Some(table_distances)) = (response.durations, response.distances) else {
+                return Err("osrm response has no durations or distances".into());
+            };
+
+            for (row_idx, from) in sources.clone().enumerate() {
+                for (column_idx, to) in destinations.clone().enumerate() {
+                    let get_value = |table: &Vec<Vec<Option<Float>>>| {
+                        table.get(row_idx).and_then(|row| row.get(column_idx)).copied().flatten()
+                    };
+                    let idx = from * size + to;
+
+                    match (get_value(&table_durations), get_value(&table_distances)) {
+                        (Some(duration), Some(distance)) => {
+                            travel_times[idx] = duration.round() as i64;
+                            distances[idx] = distance.round() as i64;
+                        }
+                        _ => error_codes[idx] = 1,
+                    }
+                }
+            }
+        }
+    }
+
+    let error_codes = if error_codes.iter().any(|&code| code > 0) { Some(error_codes) } else { None };
+
+    Ok(Matrix { profile: Some(profile.to_string()), timestamp: None, travel_times, distances, error_codes })
+}
+
+fn get_table_path(
+    endpoint: &HttpEndpoint,
+    profile: &str,
+    coordinates: &[(Float, Float)],
+    sources: std::ops::Range<usize>,
+    destinations: std::ops::Range<usize>,
+) -> String {
+    let source_count = sources.len();
+    let destination_count = destinations.len();
+
+    // NOTE osrm expects coordinates in longitude,latitude order
+    let locations = sources
+        .chain(destinations)
+        .map(|idx| coordinates[idx])
+        .map(|(lat, lng)| format!("{lng},{lat}"))
+        .collect::<Vec<_>>()
+        .join(";");
+
+    let join_indices = |range: std::ops::Range<usize>| range.map(|idx| idx.to_string()).collect::<Vec<_>>().join(";");
+
+    format!(
+        "{}/table/v1/{profile}/{locations}?sources={}&destinations={}&annotations=duration,distance",
+        endpoint.base_url,
+        join_indices(0..source_count),
+        join_indices(source_count..(source_count + destination_count)),
+    )
+}
+
+fn get_cache_file_name(url: &str, profile: &str, coordinates: &[(Float, Float)]) -> String {
+    // NOTE use FNV-1a hash as it is stable between runs and compiler versions
+    let hash = get_cache_key_bytes(url, profile, coordinates)
+        .fold(0xcbf29ce484222325_u64, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x100000001b3));
+
+    format!("osrm-{profile}-{hash:016x}.json")
+}
+
+fn get_cache_key_bytes<'a>(
+    url: &'a str,
+    profile: &'a str,
+    coordinates: &'a [(Float, Float)],
+) -> impl Iterator<Item = u8> + 'a {
+    url.bytes()
+        .chain(profile.bytes())
+        .chain(coordinates.iter().flat_map(|(lat, lng)| lat.to_le_bytes().into_iter().chain(lng.to_le_bytes())))
+}
+
+fn read_cached_matrix(path: &Path) -> Option<Matrix> {
+    File::open(path).ok().and_then(|file| deserialize_matrix(BufReader::new(file)).ok())
+}
+
+fn write_cached_matrix(path: &Path, matrix: &Matrix) -> GenericResult<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+
+    let mut writer = BufWriter::new(File::create(path)?);
+    serde_json::to_writer(&mut writer, matrix).map_err(|err| err.to_string())?;
+    writer.flush()?;
+
+    Ok(())
+}
+
+/// A http client which sends GET requests to OSRM server using configured timeouts.
+struct HttpEndpoint {
+    base_url: String,
+    agent: ureq::Agent,
+}
+
+impl HttpEndpoint {
+    fn new(config: &OsrmConfig) -> GenericResult<Self> {
+        let url = config.url.as_str();
+        let authority = url
+            .strip_prefix("http://")
+            .or_else(|| url.strip_prefix("https://"))
+            .ok_or_else(|| GenericError::from(format!("only http or https urls are supported: '{url}'")))?;
+
+        if authority.split(['/', ':']).next().is_none_or(|host| host.is_empty()) {
+            return Err(format!("no host in url: '{url}'").into());
+        }
+
+        let agent = ureq::Agent::config_builder()
+            .timeout_connect(Some(config.connect_timeout))
+            .timeout_global(Some(config.request_timeout))
+            .http_status_as_error(false)
+            .build()
+            .into();
+
+        Ok(Self { base_url: url.trim_end_matches('/').to_string(), agent })
+    }
+
+    fn get(&self, url: &str) -> GenericResult<String> {
+        let mut response = self
+            .agent
+            .get(url)
+            .header("Accept", "application/json")
+            .call()
+            .map_err(|err| format!("cannot send request to {}: {err}", self.base_url))?;
+
+        let status = response.status().as_u16();
+        let body = response
+            .body_mut()
+            .with_config()
+            .limit(u64::MAX)
+            .read_to_string()
+            .map_err(|err| format!("cannot read response from {}: {err}", self.base_url))?;
+
+        // NOTE osrm returns error details in json body, so keep it for non-success codes which have it
+        match status {
+            200 => Ok(body),
+            _ if body.trim_start().starts_with('{') => Ok(body),
+            _ => Err(format!("unexpected http status {status}: '{body}'").into()),
+        }
+    }
+}
@@ -0,0 +1,544 @@
+//! A helper module which contains functionality to anonymize pragmatic problem, so it can be shared
+//! as a reproduction case.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/scrub/scrub_test.rs"]
+mod scrub_test;
+
+use std::collections::HashMap;
+use time::OffsetDateTime;
+use time::format_description::well_known::Rfc3339;
+use vrp_core::prelude::{Float, GenericError, GenericResult};
+use vrp_core::utils::{DefaultRandom, Random};
+use vrp_pragmatic::format::Location;
+use vrp_pragmatic::format::problem::*;
+
+/// A time which is used as the earliest vehicle shift start in scrubbed problem.
+const SCRUBBED_EPOCH: &str = "2000-01-01T00:00:00Z";
+
+/// Approximate amount of meters in one degree of latitude.
+const METERS_PER_DEGREE: f64 = 111_320.;
+
+/// Specifies how ids and other sensitive strings are replaced.
+#[derive(Clone, Debug)]
+pub enum IdScrubMode {
+    /// Ids are replaced with sequential ones (e.g. `job1`), tags are removed.
+    Sequential,
+    /// Ids and tags are replaced with salted hashes: the same original value and salt give the same hash.
+    Hash {
+        /// A salt used to hash values.
+        salt: String,
+    },
+}
+
+/// Specifies how problem is scrubbed.
+#[derive(Clone, Debug)]
+pub struct ScrubOptions {
+    /// A mode for ids, tags, skills and other user defined strings.
+    pub ids: IdScrubMode,
+    /// Max distance (in meters) each unique location is moved by. Pairwise distances are changed
+    /// at most by double radius.
+    pub radius: Float,
+    /// A factor applied to all times and durations. Travel durations are scaled via vehicle profile scale.
+    pub time_scale: Float,
+}
+
+impl Default for ScrubOptions {
+    fn default() -> Self {
+        Self { ids: IdScrubMode::Sequential, radius: 100., time_scale: 1. }
+    }
+}
+
+/// Removes or hashes sensitive data of the problem: job, vehicle and resource ids, tags, skills,
+/// groups, compatibilities and categories. Coordinates are jittered within radius (the same location is
+/// always moved to the same place, so routing matrices stay valid) and times are moved to a neutral date
+/// and rescaled.
+pub fn scrub_problem(problem: Problem, options: &ScrubOptions) -> GenericResult<Problem> {
+    if options.radius < 0. {
+        return Err("radius cannot be negative".into());
+    }
+
+    if options.time_scale <= 0. {
+        return Err("time scale must be positive".into());
+    }
+
+    let origin = problem
+        .fleet
+        .vehicles
+        .iter()
+        .flat_map(|vehicle| vehicle.shifts.iter())
+        .map(|shift| parse_time(&shift.start.earliest))
+        .try_fold(None, |acc: Option<i64>, time| time.map(|time| Some(acc.map_or(time, |acc| acc.min(time)))))?
+        .ok_or_else(|| GenericError::from("problem has no vehicle shifts"))?;
+
+    let mut scrubber = Scrubber {
+        options: options.clone(),
+        rnd: DefaultRandom::default(),
+        origin,
+        epoch: parse_time(SCRUBBED_EPOCH)?,
+        names: Default::default(),
+        counters: Default::default(),
+        locations: Default::default(),
+    };
+
+    // NOTE jobs and vehicles are scrubbed first to have names for references in relations
+    let jobs = problem.plan.jobs.into_iter().map(|job| scrubber.job(job)).collect::<GenericResult<Vec<_>>>()?;
+    let vehicles = problem
+        .fleet
+        .vehicles
+        .into_iter()
+        .map(|vehicle| scrubber.vehicle(vehicle))
+        .collect::<GenericResult<Vec<_>>>()?;
+
+    let relations = problem.plan.relations.map(|relations| {
+        relations
+            .into_iter()
+            .map(|relation| Relation {
+                jobs: relation.jobs.iter().map(|job_id| scrubber.reference("job", job_id)).collect(),
+                vehicle_id: if relation.vehicle_id.is_empty() {
+                    relation.vehicle_id.clone()
+                } else {
+                    scrubber.reference("vehicle", &relation.vehicle_id)
+                },
+                ..relation
+            })
+            .collect()
+    });
+
+    let clustering = problem.plan.clustering.map(|clustering| scrubber.clustering(clustering));
+
+    let incompatibilities = problem.plan.incompatibilities.map(|incompatibilities| {
+        incompatibilities
+            .into_iter()
+            .map(|incompatibility| JobIncompatibility {
+                categories: scrubber.names("category", incompatibility.categories),
+            })
+            .collect()
+    });
+
+    let profiles = problem
+        .fleet
+        .profiles
+        .into_iter()
+        .map(|profile| {
+            Ok(MatrixProfile {
+                restrictions: profile
+                    .restrictions
+                    .map(|restrictions| {
+                        restrictions
+                            .into_iter()
+                            .map(|restriction| {
+                                Ok(AccessRestriction {
+                                    locations: restriction
+                                        .locations
+                                        .into_iter()
+                                        .map(|location| scrubber.location(location))
+                                        .collect(),
+                                    time: scrubber.time_window(restriction.time)?,
+                                })
+                            })
+                            .collect::<GenericResult<Vec<_>>>()
+                    })
+                    .transpose()?,
+                ..profile
+            })
+        })
+        .collect::<GenericResult<Vec<_>>>()?;
+
+    let resources = problem.fleet.resources.map(|resources| {
+        resources
+            .into_iter()
+            .map(|resource| match resource {
+                VehicleResource::Reload { id, capacity } => {
+                    VehicleResource::Reload { id: scrubber.name("resource", &id), capacity }
+                }
+            })
+            .collect()
+    });
+
+    let depots = problem
+        .fleet
+        .depots
+        .map(|depots| {
+            depots
+                .into_iter()
+                .map(|depot| {
+                    Ok(Depot {
+                        location: scrubber.location(depot.location),
+                        times: depot
+                            .times
+                            .into_iter()
+                            .map(|time| scrubber.time_window(time))
+                            .collect::<GenericResult<_>>()?,
+                    })
+                })
+                .collect::<GenericResult<Vec<_>>>()
+        })
+        .transpose()?;
+
+    Ok(Problem {
+        plan: Plan { jobs, relations, clustering, incompatibilities },
+        fleet: Fleet { vehicles, profiles, resources, depots },
+        objectives: problem.objectives,
+        timezone: problem.timezone,
+    })
+}
+
+struct Scrubber {
+    options: ScrubOptions,
+    rnd: DefaultRandom,
+    origin: i64,
+    epoch: i64,
+    names: HashMap<(&'static str, String), String>,
+    counters: HashMap<&'static str, usize>,
+    locations: HashMap<(u64, u64), Location>,
+}
+
+impl Scrubber {
+    fn job(&mut self, job: Job) -> GenericResult<Job> {
+        let mut tasks = |tasks: Option<Vec<JobTask>>| -> GenericResult<Option<Vec<JobTask>>> {
+            tasks
+                .map(|tasks| {
+                    tasks
+                        .into_iter()
+                        .map(|task| {
+                            Ok(JobTask {
+                                places: task
+                                    .places
+                                    .into_iter()
+                                    .map(|place| self.job_place(place))
+                                    .collect::<GenericResult<_>>()?,
+                                ..task
+                            })
+                        })
+                        .collect()
+                })
+                .transpose()
+        };
+
+        Ok(Job {
+            pickups: tasks(job.pickups)?,
+            deliveries: tasks(job.deliveries)?,
+            replacements: tasks(job.replacements)?,
+            services: tasks(job.services)?,
+            id: self.name("job", &job.id),
+            skills: job.skills.map(|skills| JobSkills {
+                all_of: skills.all_of.map(|skills| self.names("skill", skills)),
+                one_of: skills.one_of.map(|skills| self.names("skill", skills)),
+                none_of: skills.none_of.map(|skills| self.names("skill", skills)),
+                levels: skills.levels.map(|levels| {
+                    levels
+                        .into_iter()
+                        .map(|level| JobSkillLevel { name: self.name("skill", &level.name), ..level })
+                        .collect()
+                }),
+            }),
+            group: job.group.map(|group| self.name("group", &group)),
+            compatibility: job.compatibility.map(|compatibility| self.name("compatibility", &compatibility)),
+            category: job.category.map(|category| self.name("category", &category)),
+            crew: job.crew,
+            ..job
+        })
+    }
+
+    fn job_place(&mut self, place: JobPlace) -> GenericResult<JobPlace> {
+        Ok(JobPlace {
+            location: self.location(place.location),
+            duration: self.duration(place.duration),
+            times: self.time_windows(place.times)?,
+            tag: self.tag(place.tag),
+            cost: place.cost,
+        })
+    }
+
+    fn vehicle(&mut self, vehicle: VehicleType) -> GenericResult<VehicleType> {
+        let scale = self.options.time_scale;
+
+        Ok(VehicleType {
+            type_id: self.name("type", &vehicle.type_id),
+            vehicle_ids: vehicle.vehicle_ids.iter().map(|vehicle_id| self.name("vehicle", vehicle_id)).collect(),
+            profile: self.profile(vehicle.profile),
+            costs: VehicleCosts {
+                time: vehicle.costs.time / scale,
+                tiers: vehicle.costs.tiers.map(|tiers| {
+                    tiers
+                        .into_iter()
+                        .map(|tier| VehicleCostTier {
+                            max_duration: tier.max_duration.map(|duration| duration * scale),
+                            ..tier
+                        })
+                        .collect()
+                }),
+                ..vehicle.costs
+            },
+            shifts: vehicle.shifts.into_iter().map(|shift| self.shift(shift)).collect::<GenericResult<_>>()?,
+            skills: vehicle.skills.map(|skills| {
+                skills
+                    .into_iter()
+                    .map(|skill| match skill {
+                        VehicleSkill::Name(name) => VehicleSkill::Name(self.name("skill", &name)),
+                        VehicleSkill::Level { name, level } => {
+                            VehicleSkill::Level { name: self.name("skill", &name), level }
+                        }
+                    })
+                    .collect()
+            }),
+            limits: vehicle.limits.map(|limits| VehicleLimits {
+                max_duration: limits.max_duration.map(|duration| duration * scale),
+                max_total_driving_time: limits.max_total_driving_time.map(|duration| duration * scale),
+                ..limits
+            }),
+            ..vehicle
+        })
+    }
+
+    fn shift(&mut self, shift: VehicleShift) -> GenericResult<VehicleShift> {
+        Ok(VehicleShift {
+            start: ShiftStart {
+                earliest: self.time(&shift.start.earliest)?,
+                latest: shift.start.latest.map(|time| self.time(&time)).transpose()?,
+                location: self.location(shift.start.location),
+            },
+            end: shift
+                .end
+                .map(|end| -> GenericResult<_> {
+                    Ok(ShiftEnd {
+                        earliest: end.earliest.map(|time| self.time(&time)).transpose()?,
+                        latest: self.time(&end.latest)?,
+                        location: self.location(end.location),
+                    })
+                })
+                .transpose()?,
+            breaks: shift
+                .breaks
+                .map(|breaks| breaks.into_iter().map(|vehicle_break| self.vehicle_break(vehicle_break)).collect())
+                .transpose()?,
+            reloads: shift
+                .reloads
+                .map(|reloads| {
+                    reloads
+                        .into_iter()
+                        .map(|reload| -> GenericResult<_> {
+                            Ok(VehicleReload {
+                                location: self.location(reload.location),
+                                duration: self.duration(reload.duration),
+                                times: self.time_windows(reload.times)?,
+                                tag: self.tag(reload.tag),
+                                resource_id: reload.resource_id.map(|id| self.name("resource", &id)),
+                            })
+                        })
+                        .collect()
+                })
+                .transpose()?,
+            recharges: shift
+                .recharges
+                .map(|recharges| -> GenericResult<_> {
+                    Ok(VehicleRecharges {
+                        stations: recharges
+                            .stations
+                            .into_iter()
+                            .map(|station| self.job_place(station))
+                            .collect::<GenericResult<_>>()?,
+                        ..recharges
+                    })
+                })
+                .transpose()?,
+            capacity_change: shift
+                .capacity_change
+                .map(|change| -> GenericResult<_> {
+                    Ok(VehicleCapacityChange {
+                        location: self.location(change.location),
+                        duration: self.duration(change.duration),
+                        times: self.time_windows(change.times)?,
+                        profile: change.profile.map(|profile| self.profile(profile)),
+                        tag: self.tag(change.tag),
+                        ..change
+                    })
+                })
+                .transpose()?,
+        })
+    }
+
+    fn vehicle_break(&mut self, vehicle_break: VehicleBreak) -> GenericResult<VehicleBreak> {
+        let scale = self.options.time_scale;
+
+        Ok(match vehicle_break {
+            VehicleBreak::Optional { time, places, policy } => VehicleBreak::Optional {
+                time: match time {
+                    VehicleOptionalBreakTime::TimeWindow(time) => {
+                        VehicleOptionalBreakTime::TimeWindow(self.time_window(time)?)
+                    }
+                    VehicleOptionalBreakTime::TimeOffset(offset) => {
+                        VehicleOptionalBreakTime::TimeOffset(offset.into_iter().map(|time| time * scale).collect())
+                    }
+                },
+                places: places
+                    .into_iter()
+                    .map(|place| VehicleOptionalBreakPlace {
+                        duration: self.duration(place.duration),
+                        location: place.location.map(|location| self.location(location)),
+                        tag: self.tag(place.tag),
+                    })
+                    .collect(),
+                policy,
+            },
+            VehicleBreak::Required { time, duration } => VehicleBreak::Required {
+                time: match time {
+                    VehicleRequiredBreakTime::ExactTime { earliest, latest } => VehicleRequiredBreakTime::ExactTime {
+                        earliest: self.time(&earliest)?,
+                        latest: self.time(&latest)?,
+                    },
+                    VehicleRequiredBreakTime::OffsetTime { earliest, latest } => {
+                        VehicleRequiredBreakTime::OffsetTime { earliest: earliest * scale, latest: latest * scale }
+                    }
+                },
+                duration: self.duration(duration),
+            },
+        })
+    }
+
+    fn clustering(&mut self, clustering: Clustering) -> Clustering {
+        let scale = self.options.time_scale;
+
+        match clustering {
+            Clustering::Vicinity { profile, threshold, visiting, serving, filtering } => Clustering::Vicinity {
+                profile: self.profile(profile),
+                threshold: VicinityThresholdPolicy {
+                    duration: threshold.duration * scale,
+                    min_shared_time: threshold.min_shared_time.map(|time| time * scale),
+                    smallest_time_window: threshold.smallest_time_window.map(|time| time * scale),
+                    ..threshold
+                },
+                visiting,
+                serving: match serving {
+                    VicinityServingPolicy::Original { parking } => {
+                        VicinityServingPolicy::Original { parking: parking * scale }
+                    }
+                    VicinityServingPolicy::Multiplier { value, parking } => {
+                        VicinityServingPolicy::Multiplier { value, parking: parking * scale }
+                    }
+                    VicinityServingPolicy::Fixed { value, parking } => {
+                        VicinityServingPolicy::Fixed { value: value * scale, parking: parking * scale }
+                    }
+                },
+                filtering: filtering.map(|filtering| VicinityFilteringPolicy {
+                    exclude_job_ids: filtering
+                        .exclude_job_ids
+                        .iter()
+                        .map(|job_id| self.reference("job", job_id))
+                        .collect(),
+                }),
+            },
+        }
+    }
+
+    fn profile(&self, profile: VehicleProfile) -> VehicleProfile {
+        let scale = self.options.time_scale;
+
+        VehicleProfile {
+            scale: if scale == 1. { profile.scale } else { Some(profile.scale.unwrap_or(1.) * scale) },
+            ..profile
+        }
+    }
+
+    /// Returns a new name for the original value, the same value of the same kind always gets the same name.
+    fn name(&mut self, kind: &'static str, value: &str) -> String {
+        if let Some(name) = self.names.get(&(kind, value.to_string())) {
+            return name.clone();
+        }
+
+        let name = match &self.options.ids {
+            IdScrubMode::Sequential => {
+                let counter = self.counters.entry(kind).or_insert(0);
+                *counter += 1;
+                format!("{kind}{counter}")
+            }
+            IdScrubMode::Hash { salt } => format!("{kind}_{:016x}", get_hash(salt, value)),
+        };
+
+        self.names.insert((kind, value.to_string()), name.clone());
+
+        name
+    }
+
+    fn names(&mut self, kind: &'static str, values: Vec<String>) -> Vec<String> {
+        values.iter().map(|value| self.name(kind, value)).collect()
+    }
+
+    /// Returns a name of already scrubbed value or keeps it as is (e.g. reserved job ids in relations).
+    fn reference(&self, kind: &'static str, value: &str) -> String {
+        self.names.get(&(kind, value.to_string())).cloned().unwrap_or_else(|| value.to_string())
+    }
+
+    fn tag(&mut self, tag: Option<String>) -> Option<String> {
+        match self.options.ids {
+            IdScrubMode::Sequential => None,
+            IdScrubMode::Hash { .. } => tag.map(|tag| self.name("tag", &tag)),
+        }
+    }
+
+    fn location(&mut self, location: Location) -> Location {
+        #![allow(clippy::unnecessary_cast)]
+
+        match location {
+            Location::Coordinate { lat, lng } => {
+                let radius = self.options.radius as f64;
+                let rnd = &self.rnd;
+
+                self.locations
+                    .entry((lat.to_bits(), lng.to_bits()))
+                    .or_insert_with(|| {
+                        // NOTE square root gives uniform distribution within the circle
+                        let distance = radius * (rnd.uniform_real(0., 1.) as f64).sqrt();
+                        let angle = rnd.uniform_real(0., 2. * std::f64::consts::PI as Float) as f64;
+
+                        let lat_delta = distance * angle.sin() / METERS_PER_DEGREE;
+                        let lng_delta = distance * angle.cos() / (METERS_PER_DEGREE * lat.to_radians().cos().max(1E-6));
+
+                        Location::Coordinate { lat: lat + lat_delta, lng: lng + lng_delta }
+                    })
+                    .clone()
+            }
+            location => location,
+        }
+    }
+
+    fn duration(&self, duration: Float) -> Float {
+        duration * self.options.time_scale
+    }
+
+    fn time(&self, time: &str) -> GenericResult<String> {
+        let offset = (parse_time(time)? - self.origin) as Float * self.options.time_scale;
+
+        format_time(self.epoch + offset.round() as i64)
+    }
+
+    fn time_window(&self, time_window: Vec<String>) -> GenericResult<Vec<String>> {
+        time_window.iter().map(|time| self.time(time)).collect()
+    }
+
+    fn time_windows(&self, times: Option<Vec<Vec<String>>>) -> GenericResult<Option<Vec<Vec<String>>>> {
+        times.map(|times| times.into_iter().map(|time_window| self.time_window(time_window)).collect()).transpose()
+    }
+}
+
+fn parse_time(time: &str) -> GenericResult<i64> {
+    OffsetDateTime::parse(time, &Rfc3339)
+        .map(|time| time.unix_timestamp())
+        .map_err(|err| format!("cannot parse time '{time}': {err}").into())
+}
+
+fn format_time(timestamp: i64) -> GenericResult<String> {
+    OffsetDateTime::from_unix_timestamp(timestamp)
+        .map_err(|err| err.to_string())
+        .and_then(|time| time.format(&Rfc3339).map_err(|err| err.to_string()))
+        .map_err(GenericError::from)
+}
+
+/// Returns FNV-1a hash of salted value which, unlike default hasher, is stable across runs.
+fn get_hash(salt: &str, value: &str) -> u64 {
+    salt.bytes()
+        .chain(std::iter::once(0))
+        .chain(value.bytes())
+        .fold(0xcbf2_9ce4_8422_2325, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3))
+}
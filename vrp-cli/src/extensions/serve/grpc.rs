@@ -0,0 +1,213 @@
+//! A gRPC service which solves problems in pragmatic format and streams improved solutions.
+//!
+//! See `proto/solver.proto` for service definitions.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/serve/grpc_test.rs"]
+mod grpc_test;
+
+use super::*;
+use crate::extensions::proto::{from_proto_matrix, from_proto_problem, model, to_proto_solution};
+use std::net::{SocketAddr, ToSocketAddrs};
+use tokio::sync::{Semaphore, mpsc};
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::{Request, Response, Status};
+use vrp_pragmatic::format::solution::Solution as ApiSolution;
+
+/// Contains code generated from service definitions.
+#[allow(missing_docs, clippy::all)]
+pub mod proto {
+    tonic::include_proto!("vrp.v1");
+}
+
+use self::proto::solver_server::{Solver as SolverService, SolverServer};
+use self::proto::{CancelRequest, CancelResponse, SolveRequest as ProtoSolveRequest, SolveResponse};
+
+/// Implements gRPC solver service.
+pub struct GrpcSolver {
+    requests: Arc<Mutex<HashMap<String, Arc<AtomicBool>>>>,
+    counter: AtomicUsize,
+    semaphore: Arc<Semaphore>,
+    max_time: Option<usize>,
+    max_generations: Option<usize>,
+}
+
+impl GrpcSolver {
+    /// Creates a new instance of `GrpcSolver`.
+    pub fn new(config: &ServeConfig) -> Self {
+        Self {
+            requests: Default::default(),
+            counter: AtomicUsize::new(0),
+            semaphore: Arc::new(Semaphore::new(config.concurrency.max(1))),
+            max_time: config.max_time,
+            max_generations: config.max_generations,
+        }
+    }
+
+    fn register(&self, id: String) -> GenericResult<(String, Arc<AtomicBool>)> {
+        let mut requests = self.requests.lock().unwrap();
+
+        let id = if id.is_empty() { format!("{}", self.counter.fetch_add(1, Ordering::Relaxed) + 1) } else { id };
+        if requests.contains_key(&id) {
+            return Err(format!("request with id '{id}' is already being solved").into());
+        }
+
+        let is_cancelled = Arc::new(AtomicBool::new(false));
+        requests.insert(id.clone(), is_cancelled.clone());
+
+        Ok((id, is_cancelled))
+    }
+}
+
+#[tonic::async_trait]
+impl SolverService for GrpcSolver {
+    type SolveStream = ReceiverStream<Result<SolveResponse, Status>>;
+
+    async fn solve(&self, request: Request<ProtoSolveRequest>) -> Result<Response<Self::SolveStream>, Status> {
+        let mut request = request.into_inner();
+        let progress_interval = request.progress_interval as usize;
+        let binary_output = request.binary_output;
+
+        let (problem, config) = parse_request(&mut request)
+            .and_then(|request| prepare_request(request, self.max_time, self.max_generations))
+            .map_err(|err| Status::invalid_argument(err.to_string()))?;
+
+        let (id, is_cancelled) = self.register(request.id).map_err(|err| Status::already_exists(err.to_string()))?;
+        let (sender, receiver) = mpsc::channel(16);
+
+        let requests = self.requests.clone();
+        let semaphore = self.semaphore.clone();
+
+        tokio::spawn(async move {
+            let _permit = semaphore.acquire_owned().await;
+
+            let progress = (progress_interval > 0).then(|| {
+                let (sender, id, is_cancelled) = (sender.clone(), id.clone(), is_cancelled.clone());
+                let observer: ProgressObserver = Box::new(move |generation, cost, solution| {
+                    let response = create_response(id.clone(), generation, cost, false, solution, binary_output)
+                        .map_err(|err| Status::internal(err.to_string()));
+                    // NOTE client has gone away, there is no need to continue
+                    if sender.blocking_send(response).is_err() {
+                        is_cancelled.store(true, Ordering::Relaxed);
+                    }
+                });
+
+                (progress_interval, observer)
+            });
+
+            let result = tokio::task::spawn_blocking(move || solve_problem(problem, &config, is_cancelled, progress))
+                .await
+                .map_err(|err| err.to_string().into())
+                .and_then(|result| result);
+
+            requests.lock().unwrap().remove(&id);
+
+            let response = result
+                .and_then(|output| {
+                    create_response(id, output.generations, output.cost, true, output.solution, binary_output)
+                })
+                .map_err(|err: GenericError| Status::internal(err.to_string()));
+
+            let _ = sender.send(response).await;
+        });
+
+        Ok(Response::new(ReceiverStream::new(receiver)))
+    }
+
+    async fn cancel(&self, request: Request<CancelRequest>) -> Result<Response<CancelResponse>, Status> {
+        let id = request.into_inner().id;
+
+        let is_cancelled = self
+            .requests
+            .lock()
+            .unwrap()
+            .get(&id)
+            .map(|is_cancelled| is_cancelled.store(true, Ordering::Relaxed))
+            .is_some();
+
+        Ok(Response::new(CancelResponse { is_cancelled }))
+    }
+}
+
+/// Runs gRPC service which blocks the current thread.
+pub fn run_grpc_server(config: &ServeConfig, logger: InfoLogger) -> GenericResult<()> {
+    let address = (config.host.as_str(), config.port)
+        .to_socket_addrs()
+        .ok()
+        .and_then(|mut addresses| addresses.next())
+        .ok_or_else(|| format!("cannot resolve address {}:{}", config.host, config.port))?;
+
+    let runtime = tokio::runtime::Runtime::new().map_err(|err| format!("cannot create runtime: '{err}'"))?;
+
+    runtime.block_on(async {
+        (logger)(format!("listening on grpc://{address}").as_str());
+
+        serve_grpc(address, GrpcSolver::new(config)).await
+    })
+}
+
+async fn serve_grpc(address: SocketAddr, solver: GrpcSolver) -> GenericResult<()> {
+    tonic::transport::Server::builder()
+        .add_service(SolverServer::new(solver))
+        .serve(address)
+        .await
+        .map_err(|err| format!("grpc server error: '{err}'").into())
+}
+
+fn parse_request(request: &mut ProtoSolveRequest) -> GenericResult<SolveRequest> {
+    let problem = match request.problem_message.take() {
+        Some(problem) => from_proto_problem(problem).map_err(|err| format!("cannot read problem message: '{err}'"))?,
+        None => serde_json::from_str::<ApiProblem>(request.problem.as_str())
+            .map_err(|err| format!("cannot read problem: '{err}'"))?,
+    };
+
+    let matrices = if !request.matrix_messages.is_empty() {
+        Some(std::mem::take(&mut request.matrix_messages).into_iter().map(from_proto_matrix).collect())
+    } else if request.matrices.is_empty() {
+        None
+    } else {
+        Some(
+            request
+                .matrices
+                .iter()
+                .map(|matrix| serde_json::from_str::<Matrix>(matrix.as_str()))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|err| format!("cannot read matrix: '{err}'"))?,
+        )
+    };
+
+    let config = if request.config.is_empty() {
+        None
+    } else {
+        Some(
+            serde_json::from_str::<Config>(request.config.as_str())
+                .map_err(|err| format!("cannot read config: '{err}'"))?,
+        )
+    };
+
+    Ok(SolveRequest { problem, matrices, config })
+}
+
+fn create_response(
+    id: String,
+    generation: usize,
+    cost: Float,
+    is_final: bool,
+    solution: String,
+    binary_output: bool,
+) -> GenericResult<SolveResponse> {
+    let (solution, solution_message) = if binary_output {
+        (String::default(), Some(to_solution_message(solution.as_str())?))
+    } else {
+        (solution, None)
+    };
+
+    Ok(SolveResponse { id, generation: generation as u64, cost, is_final, solution, solution_message })
+}
+
+fn to_solution_message(solution: &str) -> GenericResult<model::Solution> {
+    let solution =
+        serde_json::from_str::<ApiSolution>(solution).map_err(|err| format!("cannot read solution: '{err}'"))?;
+
+    to_proto_solution(&solution)
+}
@@ -0,0 +1,561 @@
+//! A helper module which contains functionality to run solver as a long-running HTTP service.
+//!
+//! The service exposes the following endpoints:
+//! - `POST /solve`: submits a solve request and returns a job id
+//! - `GET /status/{id}`: returns a job status
+//! - `GET /solution/{id}`: returns a solution in pragmatic format once the job is completed
+//! - `DELETE /jobs/{id}`: cancels a queued or running job and removes it
+//!
+//! Additionally, gRPC service with streaming of intermediate solutions is available behind `grpc` feature.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/serve/serve_test.rs"]
+mod serve_test;
+
+#[cfg(feature = "grpc")]
+pub mod grpc;
+
+use crate::extensions::solve::config::{Config, TerminationConfig, create_builder_from_config_with_quota};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, BufWriter, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc::{Receiver, Sender, channel};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+use vrp_core::models::GoalContext;
+use vrp_core::prelude::*;
+use vrp_core::rosomaxa::prelude::*;
+use vrp_core::rosomaxa::termination::Termination;
+use vrp_core::rosomaxa::utils::Quota;
+use vrp_core::solver::RefinementContext;
+use vrp_pragmatic::format::problem::{Matrix, PragmaticProblem, Problem as ApiProblem};
+use vrp_pragmatic::format::solution::write_pragmatic;
+
+/// Specifies service settings.
+pub struct ServeConfig {
+    /// A host to listen on.
+    pub host: String,
+    /// A port to listen on.
+    pub port: u16,
+    /// Max amount of problems solved simultaneously, other requests wait in a queue.
+    pub concurrency: usize,
+    /// Default max time of solving in seconds, used when request has no termination settings.
+    pub max_time: Option<usize>,
+    /// Default max generations of solving, used when request has no termination settings.
+    pub max_generations: Option<usize>,
+    /// Time in seconds to keep completed or failed jobs, after that they are removed.
+    pub job_ttl: usize,
+}
+
+/// Max size of request body in bytes.
+const MAX_BODY_SIZE: usize = 256 * 1024 * 1024;
+/// Max amount of connections handled simultaneously, others are rejected.
+const MAX_CONNECTIONS: usize = 64;
+/// Max time to wait for reading from or writing to connection.
+const CONNECTION_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// A request to solve the problem in pragmatic format.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SolveRequest {
+    /// A problem definition.
+    pub problem: ApiProblem,
+    /// Routing matrices, if not specified, then they are approximated from location coordinates.
+    pub matrices: Option<Vec<Matrix>>,
+    /// Solver configuration.
+    pub config: Option<Config>,
+}
+
+/// Specifies a status of solve job.
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum JobStatus {
+    /// Job waits for a free solver.
+    Queued,
+    /// Job is being solved.
+    Running,
+    /// Job is solved and the solution is available.
+    Completed,
+    /// Job has failed.
+    Failed,
+}
+
+/// Represents a state of solve job returned by the service.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobState {
+    /// Job id.
+    pub id: String,
+    /// Job status.
+    pub status: JobStatus,
+    /// An error message if job has failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// A total cost of the solution if job is completed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cost: Option<Float>,
+    /// Amount of refinement generations if job is completed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub generations: Option<usize>,
+}
+
+/// Runs HTTP service which blocks the current thread.
+pub fn run_server(config: &ServeConfig, logger: InfoLogger) -> GenericResult<()> {
+    let listener = TcpListener::bind((config.host.as_str(), config.port))
+        .map_err(|err| format!("cannot listen on {}:{}: '{err}'", config.host, config.port))?;
+
+    (logger)(format!("listening on http://{}:{}", config.host, config.port).as_str());
+
+    let manager = JobManager::new(
+        config.concurrency,
+        config.max_time,
+        config.max_generations,
+        Duration::from_secs(config.job_ttl as u64),
+    );
+
+    serve(listener, manager, logger)
+}
+
+fn serve(listener: TcpListener, manager: Arc<JobManager>, logger: InfoLogger) -> GenericResult<()> {
+    let connections = Arc::new(AtomicUsize::new(0));
+
+    for stream in listener.incoming() {
+        match stream {
+            Ok(stream) => {
+                let result = stream
+                    .set_read_timeout(Some(CONNECTION_TIMEOUT))
+                    .and_then(|_| stream.set_write_timeout(Some(CONNECTION_TIMEOUT)));
+                if let Err(err) = result {
+                    (logger)(format!("cannot configure connection: '{err}'").as_str());
+                    continue;
+                }
+
+                // NOTE the guard is moved into connection thread and releases its slot once the thread is done
+                let Some(guard) = ConnectionGuard::acquire(connections.clone()) else {
+                    let response = HttpResponse::error(503, "too many connections");
+                    if let Err(err) = write_response(stream, response) {
+                        (logger)(format!("cannot reject connection: '{err}'").as_str());
+                    }
+                    continue;
+                };
+
+                let manager = manager.clone();
+                let logger = logger.clone();
+                thread::spawn(move || {
+                    let _guard = guard;
+                    if let Err(err) = handle_connection(stream, manager.as_ref()) {
+                        (logger)(format!("cannot handle request: '{err}'").as_str());
+                    }
+                });
+            }
+            Err(err) => (logger)(format!("cannot accept connection: '{err}'").as_str()),
+        }
+    }
+
+    Ok(())
+}
+
+/// Keeps a slot of active connection until dropped.
+struct ConnectionGuard(Arc<AtomicUsize>);
+
+impl ConnectionGuard {
+    fn acquire(connections: Arc<AtomicUsize>) -> Option<Self> {
+        connections
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |active| {
+                (active < MAX_CONNECTIONS).then_some(active + 1)
+            })
+            .ok()
+            .map(|_| Self(connections))
+    }
+}
+
+impl Drop for ConnectionGuard {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+struct HttpResponse {
+    status: u16,
+    body: String,
+}
+
+impl HttpResponse {
+    fn json<T: Serialize>(status: u16, value: &T) -> Self {
+        match serde_json::to_string(value) {
+            Ok(body) => Self { status, body },
+            Err(err) => Self::error(500, format!("cannot serialize response: '{err}'").as_str()),
+        }
+    }
+
+    fn error(status: u16, message: &str) -> Self {
+        Self { status, body: serde_json::json!({ "error": message }).to_string() }
+    }
+}
+
+struct JobEntry {
+    status: JobStatus,
+    error: Option<String>,
+    output: Option<SolveOutput>,
+    is_cancelled: Arc<AtomicBool>,
+    finished_at: Option<Instant>,
+}
+
+impl JobEntry {
+    fn get_state(&self, id: &str) -> JobState {
+        JobState {
+            id: id.to_string(),
+            status: self.status.clone(),
+            error: self.error.clone(),
+            cost: self.output.as_ref().map(|output| output.cost),
+            generations: self.output.as_ref().map(|output| output.generations),
+        }
+    }
+}
+
+struct JobManager {
+    jobs: Mutex<HashMap<String, JobEntry>>,
+    sender: Mutex<Sender<(String, SolveRequest)>>,
+    counter: AtomicUsize,
+    max_time: Option<usize>,
+    max_generations: Option<usize>,
+    job_ttl: Duration,
+}
+
+impl JobManager {
+    fn new(
+        concurrency: usize,
+        max_time: Option<usize>,
+        max_generations: Option<usize>,
+        job_ttl: Duration,
+    ) -> Arc<Self> {
+        let (sender, receiver) = channel();
+        let receiver = Arc::new(Mutex::new(receiver));
+
+        let manager = Arc::new(Self {
+            jobs: Mutex::new(HashMap::default()),
+            sender: Mutex::new(sender),
+            counter: AtomicUsize::new(0),
+            max_time,
+            max_generations,
+            job_ttl,
+        });
+
+        (0..concurrency.max(1)).for_each(|_| {
+            let manager = Arc::downgrade(&manager);
+            let receiver = receiver.clone();
+            thread::spawn(move || run_worker(manager, receiver));
+        });
+
+        manager
+    }
+
+    fn submit(&self, request: SolveRequest) -> GenericResult<JobState> {
+        let id = (self.counter.fetch_add(1, Ordering::Relaxed) + 1).to_string();
+
+        let entry = JobEntry {
+            status: JobStatus::Queued,
+            error: None,
+            output: None,
+            is_cancelled: Arc::new(AtomicBool::new(false)),
+            finished_at: None,
+        };
+        let state = entry.get_state(id.as_str());
+
+        let mut jobs = self.jobs.lock().unwrap();
+        self.evict_expired(&mut jobs);
+        jobs.insert(id.clone(), entry);
+        drop(jobs);
+
+        self.sender.lock().unwrap().send((id.clone(), request)).map_err(|err| format!("cannot submit job: '{err}'"))?;
+
+        Ok(state)
+    }
+
+    fn get_state(&self, id: &str) -> Option<JobState> {
+        self.jobs.lock().unwrap().get(id).map(|entry| entry.get_state(id))
+    }
+
+    fn get_solution(&self, id: &str) -> Option<Result<String, JobState>> {
+        self.jobs
+            .lock()
+            .unwrap()
+            .get(id)
+            .map(|entry| entry.output.as_ref().map(|output| output.solution.clone()).ok_or_else(|| entry.get_state(id)))
+    }
+
+    fn remove(&self, id: &str) -> Option<JobState> {
+        self.jobs.lock().unwrap().remove(id).map(|entry| {
+            entry.is_cancelled.store(true, Ordering::Relaxed);
+            entry.get_state(id)
+        })
+    }
+
+    /// Marks job as running and returns its cancellation flag, if job is not removed yet.
+    fn start(&self, id: &str) -> Option<Arc<AtomicBool>> {
+        self.jobs.lock().unwrap().get_mut(id).map(|entry| {
+            entry.status = JobStatus::Running;
+            entry.is_cancelled.clone()
+        })
+    }
+
+    fn finish(&self, id: &str, result: GenericResult<SolveOutput>) {
+        if let Some(entry) = self.jobs.lock().unwrap().get_mut(id) {
+            entry.finished_at = Some(Instant::now());
+            match result {
+                Ok(output) => {
+                    entry.status = JobStatus::Completed;
+                    entry.output = Some(output);
+                }
+                Err(err) => {
+                    entry.status = JobStatus::Failed;
+                    entry.error = Some(err.to_string());
+                }
+            }
+        }
+    }
+
+    /// Removes finished jobs which are kept longer than job ttl.
+    fn evict_expired(&self, jobs: &mut HashMap<String, JobEntry>) {
+        jobs.retain(|_, entry| entry.finished_at.is_none_or(|finished_at| finished_at.elapsed() < self.job_ttl));
+    }
+}
+
+fn run_worker(manager: std::sync::Weak<JobManager>, receiver: Arc<Mutex<Receiver<(String, SolveRequest)>>>) {
+    loop {
+        // NOTE lock is released before the job is solved, so other workers can take next jobs
+        let message = receiver.lock().unwrap().recv();
+        let Ok((id, request)) = message else { break };
+        let Some(manager) = manager.upgrade() else { break };
+
+        if let Some(is_cancelled) = manager.start(id.as_str()) {
+            let result = prepare_request(request, manager.max_time, manager.max_generations)
+                .and_then(|(problem, config)| solve_problem(problem, &config, is_cancelled, None));
+            manager.finish(id.as_str(), result);
+        }
+    }
+}
+
+/// Observes improved solutions found during search: accepts generation, cost and solution in pragmatic format.
+type ProgressObserver = Box<dyn Fn(usize, Float, String) + Send + Sync>;
+
+/// Keeps the final solution in pragmatic format with its cost and amount of generations.
+struct SolveOutput {
+    generations: usize,
+    cost: Float,
+    solution: String,
+}
+
+/// Reads a problem from the request and resolves algorithm configuration using default termination limits.
+fn prepare_request(
+    request: SolveRequest,
+    max_time: Option<usize>,
+    max_generations: Option<usize>,
+) -> GenericResult<(Arc<Problem>, Config)> {
+    let SolveRequest { problem, matrices, config } = request;
+
+    let problem = Arc::new(match matrices {
+        Some(matrices) => (problem, matrices).read_pragmatic(),
+        None => problem.read_pragmatic(),
+    }?);
+
+    let mut config = config.unwrap_or_default();
+    if config.termination.is_none() {
+        config.termination = Some(TerminationConfig { max_time, max_generations, variation: None });
+    }
+
+    Ok((problem, config))
+}
+
+fn solve_problem(
+    problem: Arc<Problem>,
+    config: &Config,
+    is_cancelled: Arc<AtomicBool>,
+    progress: Option<(usize, ProgressObserver)>,
+) -> GenericResult<SolveOutput> {
+    struct CancellationQuota(Arc<AtomicBool>);
+
+    impl Quota for CancellationQuota {
+        fn is_reached(&self) -> bool {
+            self.0.load(Ordering::Relaxed)
+        }
+    }
+
+    let generations = Arc::new(AtomicUsize::new(0));
+    let progress = ProgressTermination {
+        problem: problem.clone(),
+        generations: generations.clone(),
+        last_cost: Mutex::new(None),
+        progress,
+    };
+
+    let quota = Arc::new(CancellationQuota(is_cancelled));
+    let solver_config = create_builder_from_config_with_quota(problem.clone(), Vec::default(), config, quota)?
+        .with_termination(Box::new(progress))
+        .build()?;
+    let solution = Solver::new(problem.clone(), solver_config).solve()?;
+
+    Ok(SolveOutput {
+        generations: generations.load(Ordering::Relaxed),
+        cost: solution.cost,
+        solution: write_solution(&problem, &solution)?,
+    })
+}
+
+fn write_solution(problem: &Problem, solution: &Solution) -> GenericResult<String> {
+    let mut writer = BufWriter::new(Vec::new());
+    write_pragmatic(problem, solution, Default::default(), &mut writer)?;
+    let bytes = writer.into_inner().map_err(|err| format!("cannot write solution: '{err}'"))?;
+
+    String::from_utf8(bytes).map_err(|err| format!("cannot write solution: '{err}'").into())
+}
+
+/// A termination which never stops search, but tracks generations and reports improved best known solutions.
+struct ProgressTermination {
+    problem: Arc<Problem>,
+    generations: Arc<AtomicUsize>,
+    last_cost: Mutex<Option<Float>>,
+    progress: Option<(usize, ProgressObserver)>,
+}
+
+impl Termination for ProgressTermination {
+    type Context = RefinementContext;
+    type Objective = GoalContext;
+
+    fn is_termination(&self, heuristic_ctx: &mut Self::Context) -> bool {
+        let generation = heuristic_ctx.statistics().generation;
+        self.generations.store(generation, Ordering::Relaxed);
+
+        let Some((interval, observer)) = self.progress.as_ref() else { return false };
+        if *interval == 0 || generation % interval != 0 {
+            return false;
+        }
+
+        let Some(solution) = heuristic_ctx.ranked().next().map(|best| Solution::from(best.deep_copy())) else {
+            return false;
+        };
+
+        let mut last_cost = self.last_cost.lock().unwrap();
+        if last_cost.is_none_or(|last_cost| solution.cost < last_cost) {
+            *last_cost = Some(solution.cost);
+            if let Ok(solution_str) = write_solution(self.problem.as_ref(), &solution) {
+                (observer)(generation, solution.cost, solution_str);
+            }
+        }
+
+        false
+    }
+
+    fn estimate(&self, _: &Self::Context) -> Float {
+        0.
+    }
+}
+
+fn handle_request(manager: &JobManager, method: &str, path: &str, body: &[u8]) -> HttpResponse {
+    let segments = path.trim_matches('/').split('/').collect::<Vec<_>>();
+
+    match (method, segments.as_slice()) {
+        ("POST", ["solve"]) => match serde_json::from_slice::<SolveRequest>(body) {
+            Ok(request) => match manager.submit(request) {
+                Ok(state) => HttpResponse::json(202, &state),
+                Err(err) => HttpResponse::error(500, err.to_string().as_str()),
+            },
+            Err(err) => HttpResponse::error(400, format!("cannot deserialize solve request: '{err}'").as_str()),
+        },
+        ("GET", ["status", id]) => match manager.get_state(id) {
+            Some(state) => HttpResponse::json(200, &state),
+            None => HttpResponse::error(404, format!("unknown job id: '{id}'").as_str()),
+        },
+        ("GET", ["solution", id]) => match manager.get_solution(id) {
+            Some(Ok(solution)) => HttpResponse { status: 200, body: solution },
+            Some(Err(state)) => HttpResponse::json(409, &state),
+            None => HttpResponse::error(404, format!("unknown job id: '{id}'").as_str()),
+        },
+        ("DELETE", ["jobs", id]) => match manager.remove(id) {
+            Some(state) => HttpResponse::json(200, &state),
+            None => HttpResponse::error(404, format!("unknown job id: '{id}'").as_str()),
+        },
+        _ => HttpResponse::error(404, format!("unknown endpoint: '{method} {path}'").as_str()),
+    }
+}
+
+fn handle_connection(stream: TcpStream, manager: &JobManager) -> GenericResult<()> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+
+    let response = match request_line.split_whitespace().collect::<Vec<_>>().as_slice() {
+        [method, target, _] => {
+            let content_length = read_content_length(&mut reader)?;
+            if content_length > MAX_BODY_SIZE {
+                return write_response(
+                    stream,
+                    HttpResponse::error(413, format!("request body is bigger than {MAX_BODY_SIZE} bytes").as_str()),
+                );
+            }
+
+            // NOTE do not allocate the whole body upfront as content length is not trusted
+            let mut body = Vec::new();
+            reader.by_ref().take(content_length as u64).read_to_end(&mut body)?;
+            if body.len() != content_length {
+                return Err(format!("unexpected end of request body: {} of {content_length} bytes", body.len()).into());
+            }
+
+            let path = target.split_once('?').map_or(*target, |(path, _)| path);
+
+            handle_request(manager, method, path, body.as_slice())
+        }
+        _ => HttpResponse::error(400, "malformed request line"),
+    };
+
+    write_response(stream, response)
+}
+
+fn read_content_length<R: BufRead>(reader: &mut R) -> GenericResult<usize> {
+    let mut content_length = 0;
+
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 || line.trim().is_empty() {
+            break;
+        }
+
+        if let Some((name, value)) = line.split_once(':')
+            && name.trim().eq_ignore_ascii_case("content-length")
+        {
+            content_length =
+                value.trim().parse().map_err(|err| format!("cannot parse content length '{}': {err}", value.trim()))?;
+        }
+    }
+
+    Ok(content_length)
+}
+
+fn write_response(stream: TcpStream, response: HttpResponse) -> GenericResult<()> {
+    let reason = match response.status {
+        200 => "OK",
+        202 => "Accepted",
+        400 => "Bad Request",
+        404 => "Not Found",
+        409 => "Conflict",
+        413 => "Payload Too Large",
+        503 => "Service Unavailable",
+        _ => "Internal Server Error",
+    };
+
+    let mut writer = BufWriter::new(stream);
+    write!(
+        writer,
+        "HTTP/1.1 {} {reason}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        response.body.len(),
+        response.body
+    )?;
+    writer.flush()?;
+
+    Ok(())
+}
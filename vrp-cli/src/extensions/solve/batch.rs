@@ -0,0 +1,159 @@
+//! Provides functionality to solve multiple problems which share the same routing matrix.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/solve/batch_test.rs"]
+mod batch_test;
+
+use super::config::{Config, EnvironmentConfig, create_builder_from_config};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use vrp_core::models::{Problem as CoreProblem, Solution};
+use vrp_core::prelude::{GenericError, GenericResult, Solver};
+use vrp_core::utils::ThreadPool;
+use vrp_pragmatic::format::Location;
+use vrp_pragmatic::format::problem::{Matrix, PragmaticProblem, Problem};
+use vrp_pragmatic::get_unique_locations;
+
+/// Specifies routing data shared by all problems of the batch.
+pub struct SharedRouting {
+    /// Routing matrices, one per profile.
+    pub matrices: Vec<Matrix>,
+    /// Locations which define matrix indices. When specified, each problem gets a sub matrix built for
+    /// locations it uses, otherwise matrices are used as they are.
+    pub locations: Option<Vec<Location>>,
+}
+
+/// A result of the batch problem solving.
+pub type BatchResult = GenericResult<(Arc<CoreProblem>, Solution)>;
+
+/// Solves multiple pragmatic problems concurrently within a global thread budget. Problems are solved by
+/// `min(problems, max_threads)` workers, each of them uses a dedicated thread pool with an equal share of the
+/// budget. Parallelism settings of the config are ignored. Results are returned in the order of problems.
+pub fn solve_batch(
+    problems: Vec<Problem>,
+    routing: &SharedRouting,
+    config: &Config,
+    max_threads: usize,
+) -> Vec<BatchResult> {
+    let config = Config {
+        environment: config
+            .environment
+            .clone()
+            .map(|environment| EnvironmentConfig { parallelism: None, ..environment }),
+        ..config.clone()
+    };
+
+    let workers = problems.len().min(max_threads.max(1));
+    let threads_per_worker = (max_threads / workers.max(1)).max(1);
+
+    let location_index = routing.locations.as_ref().map(|locations| {
+        locations.iter().enumerate().map(|(idx, location)| (get_location_key(location), idx)).collect::<HashMap<_, _>>()
+    });
+
+    let next = AtomicUsize::new(0);
+    let problems = problems.into_iter().map(|problem| Mutex::new(Some(problem))).collect::<Vec<_>>();
+
+    let mut results = std::thread::scope(|scope| {
+        let handles = (0..workers)
+            .map(|_| {
+                scope.spawn(|| {
+                    let thread_pool = ThreadPool::new(threads_per_worker);
+                    let mut results = Vec::new();
+
+                    loop {
+                        let idx = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(problem) = problems.get(idx).and_then(|problem| problem.lock().ok()?.take()) else {
+                            break;
+                        };
+
+                        let result = thread_pool.execute(|| {
+                            get_matrices(&problem, routing, location_index.as_ref())
+                                .and_then(|matrices| solve_problem(problem, matrices, &config))
+                        });
+
+                        results.push((idx, result));
+                    }
+
+                    results
+                })
+            })
+            .collect::<Vec<_>>();
+
+        handles.into_iter().flat_map(|handle| handle.join().expect("cannot join batch worker")).collect::<Vec<_>>()
+    });
+
+    results.sort_by_key(|(idx, _)| *idx);
+
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+fn solve_problem(problem: Problem, matrices: Vec<Matrix>, config: &Config) -> BatchResult {
+    let problem = Arc::new(
+        (problem, matrices)
+            .read_pragmatic()
+            .map_err(|errs| GenericError::from(format!("cannot read problem: {errs}")))?,
+    );
+
+    let solution = create_builder_from_config(problem.clone(), Default::default(), config)
+        .and_then(|builder| builder.build())
+        .map(|config| Solver::new(problem.clone(), config))
+        .and_then(|solver| solver.solve().map_err(From::from))?;
+
+    Ok((problem, solution))
+}
+
+/// Returns matrices for the problem: sub matrices for its locations if shared locations are specified.
+fn get_matrices(
+    problem: &Problem,
+    routing: &SharedRouting,
+    location_index: Option<&HashMap<(u64, u64), usize>>,
+) -> GenericResult<Vec<Matrix>> {
+    let Some(location_index) = location_index else {
+        return Ok(routing.matrices.clone());
+    };
+
+    let indices = get_unique_locations(problem)
+        .iter()
+        .map(|location| match location {
+            Location::Coordinate { .. } => location_index
+                .get(&get_location_key(location))
+                .copied()
+                .ok_or_else(|| GenericError::from(format!("cannot find location in shared locations: {location:?}"))),
+            _ => Err("only locations specified by coordinates can be used with shared locations".into()),
+        })
+        .collect::<GenericResult<Vec<_>>>()?;
+
+    routing.matrices.iter().map(|matrix| get_sub_matrix(matrix, indices.as_slice())).collect()
+}
+
+fn get_sub_matrix(matrix: &Matrix, indices: &[usize]) -> GenericResult<Matrix> {
+    let size = (matrix.travel_times.len() as f64).sqrt().round() as usize;
+    if size * size != matrix.travel_times.len() || matrix.distances.len() != matrix.travel_times.len() {
+        return Err("shared routing matrix is not a square one".into());
+    }
+
+    if let Some(idx) = indices.iter().find(|&&idx| idx >= size) {
+        return Err(format!("location index {idx} is out of shared routing matrix size {size}").into());
+    }
+
+    let slice = |values: &[i64]| {
+        indices.iter().flat_map(|&from| indices.iter().map(move |&to| values[from * size + to])).collect::<Vec<_>>()
+    };
+
+    Ok(Matrix {
+        profile: matrix.profile.clone(),
+        timestamp: matrix.timestamp.clone(),
+        travel_times: slice(matrix.travel_times.as_slice()),
+        distances: slice(matrix.distances.as_slice()),
+        error_codes: matrix.error_codes.as_deref().map(slice),
+    })
+}
+
+fn get_location_key(location: &Location) -> (u64, u64) {
+    match location {
+        Location::Coordinate { lat, lng } => (lat.to_bits(), lng.to_bits()),
+        Location::Reference { index } => (u64::MAX, *index as u64),
+        Location::Custom { .. } => (u64::MAX, u64::MAX),
+    }
+}
@@ -0,0 +1,47 @@
+//! Provides a way to load routing matrices in binary format using memory mapping, so large matrices
+//! are neither parsed nor copied.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/solve/binary_matrix_test.rs"]
+mod binary_matrix_test;
+
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::sync::Arc;
+use vrp_core::prelude::{GenericError, GenericResult};
+use vrp_pragmatic::format::problem::{BINARY_MATRIX_MAGIC, BinaryMatrix, is_binary_matrix};
+
+/// Reads routing matrices in binary format from given files. Returns `None` when files contain
+/// matrices in another format, mixing binary and non-binary matrices is not allowed.
+pub fn read_binary_matrices(files: &mut [File]) -> GenericResult<Option<Vec<BinaryMatrix>>> {
+    let binary_flags = files.iter_mut().map(is_binary_matrix_file).collect::<GenericResult<Vec<_>>>()?;
+
+    match (binary_flags.iter().all(|&is_binary| is_binary), binary_flags.iter().any(|&is_binary| is_binary)) {
+        (true, true) => files.iter().map(map_binary_matrix).collect::<GenericResult<Vec<_>>>().map(Some),
+        (false, true) => Err("mixing binary and json routing matrices is not supported".into()),
+        _ => Ok(None),
+    }
+}
+
+/// Loads binary matrix from file using memory mapping.
+pub fn map_binary_matrix(file: &File) -> GenericResult<BinaryMatrix> {
+    // SAFETY: mapped file is expected to be not modified while solving, otherwise behavior is undefined.
+    #[allow(unsafe_code)]
+    let mmap = unsafe { memmap2::Mmap::map(file) }
+        .map_err(|err| GenericError::from(format!("cannot map binary matrix file: '{err}'")))?;
+
+    BinaryMatrix::from_bytes(Arc::new(mmap))
+}
+
+fn is_binary_matrix_file(file: &mut File) -> GenericResult<bool> {
+    let mut magic = [0_u8; BINARY_MATRIX_MAGIC.len()];
+
+    let length = file
+        .by_ref()
+        .take(magic.len() as u64)
+        .read(&mut magic)
+        .and_then(|length| file.seek(SeekFrom::Start(0)).map(|_| length))
+        .map_err(|err| GenericError::from(format!("cannot read routing matrix file: '{err}'")))?;
+
+    Ok(is_binary_matrix(&magic[..length]))
+}
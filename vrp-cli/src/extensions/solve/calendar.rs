@@ -0,0 +1,134 @@
+//! Provides the way to export solution tours as iCalendar files.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/solve/calendar_test.rs"]
+mod calendar_test;
+
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use vrp_core::prelude::GenericResult;
+use vrp_pragmatic::format::Location;
+use vrp_pragmatic::format::solution::{Solution, Stop, Tour};
+
+/// A max length of content line in octets as defined by RFC 5545.
+const MAX_LINE_LENGTH: usize = 75;
+
+/// Writes each tour of the solution as a separate iCalendar file named after vehicle id and shift index
+/// into the given directory.
+pub fn write_solution_as_ics(solution: &Solution, out_dir: &Path) -> GenericResult<()> {
+    std::fs::create_dir_all(out_dir)
+        .map_err(|err| format!("cannot create calendar directory '{}': '{err}'", out_dir.display()))?;
+
+    solution.tours.iter().try_for_each(|tour| {
+        let path = out_dir.join(get_tour_file_name(tour));
+        let file = File::create(&path).map_err(|err| format!("cannot create file '{}': '{err}'", path.display()))?;
+
+        write_tour_as_ics(tour, &mut BufWriter::new(file))
+    })
+}
+
+/// Writes tour as iCalendar with one event per stop. Events have stop location and activities description.
+pub fn write_tour_as_ics<W: Write>(tour: &Tour, writer: &mut BufWriter<W>) -> GenericResult<()> {
+    let tour_id = format!("{}-{}", tour.vehicle_id, tour.shift_index);
+    let dtstamp = tour.stops.first().map(|stop| format_ics_time(stop.schedule().arrival.as_str())).unwrap_or_default();
+
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//vrp-cli//tour schedule//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+        format!("X-WR-CALNAME:{}", escape_text(tour.vehicle_id.as_str())),
+    ];
+
+    tour.stops.iter().enumerate().for_each(|(seq, stop)| {
+        let schedule = stop.schedule();
+        let job_ids = stop.activities().iter().map(|activity| activity.job_id.as_str()).collect::<Vec<_>>().join(", ");
+
+        lines.push("BEGIN:VEVENT".to_string());
+        lines.push(format!("UID:{}-{seq}@vrp-cli", escape_text(tour_id.as_str())));
+        lines.push(format!("DTSTAMP:{dtstamp}"));
+        lines.push(format!("DTSTART:{}", format_ics_time(schedule.arrival.as_str())));
+        lines.push(format!("DTEND:{}", format_ics_time(schedule.departure.as_str())));
+        lines.push(format!("SUMMARY:{}", escape_text(format!("#{seq} {job_ids}").as_str())));
+
+        if let Some(Location::Coordinate { lat, lng }) = stop.location() {
+            lines.push(format!("LOCATION:{}", escape_text(format!("{lat},{lng}").as_str())));
+            lines.push(format!("GEO:{lat};{lng}"));
+        }
+
+        lines.push(format!("DESCRIPTION:{}", escape_text(get_stop_description(stop).as_str())));
+        lines.push("END:VEVENT".to_string());
+    });
+
+    lines.push("END:VCALENDAR".to_string());
+
+    lines.iter().try_for_each(|line| write!(writer, "{}\r\n", fold_line(line)))?;
+    writer.flush()?;
+
+    Ok(())
+}
+
+fn get_tour_file_name(tour: &Tour) -> String {
+    let vehicle_id = tour
+        .vehicle_id
+        .chars()
+        .map(|ch| if ch.is_ascii_alphanumeric() || ch == '-' || ch == '_' { ch } else { '_' })
+        .collect::<String>();
+
+    format!("{vehicle_id}_{}.ics", tour.shift_index)
+}
+
+fn get_stop_description(stop: &Stop) -> String {
+    let activities = stop
+        .activities()
+        .iter()
+        .map(|activity| match activity.time.as_ref() {
+            Some(time) => format!("{} ({}): {} - {}", activity.job_id, activity.activity_type, time.start, time.end),
+            None => format!("{} ({})", activity.job_id, activity.activity_type),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    let load = stop.load().iter().map(|value| value.to_string()).collect::<Vec<_>>().join(",");
+
+    format!("{activities}\nload: {load}")
+}
+
+/// Converts RFC3339 time in UTC produced by pragmatic writer (e.g. `2019-07-04T09:00:00Z`) into iCalendar
+/// UTC time format (e.g. `20190704T090000Z`).
+fn format_ics_time(time: &str) -> String {
+    time.chars().filter(|ch| *ch != '-' && *ch != ':').collect()
+}
+
+fn escape_text(value: &str) -> String {
+    value.chars().fold(String::with_capacity(value.len()), |mut acc, ch| {
+        match ch {
+            '\\' => acc.push_str("\\\\"),
+            ';' => acc.push_str("\\;"),
+            ',' => acc.push_str("\\,"),
+            '\n' => acc.push_str("\\n"),
+            '\r' => {}
+            _ => acc.push(ch),
+        }
+        acc
+    })
+}
+
+/// Folds content line longer than allowed limit: continuation lines start with a single space.
+fn fold_line(line: &str) -> String {
+    let mut result = String::with_capacity(line.len());
+    let mut length = 0;
+
+    line.chars().for_each(|ch| {
+        // NOTE continuation line has leading space which is counted in its length
+        if length + ch.len_utf8() > MAX_LINE_LENGTH {
+            result.push_str("\r\n ");
+            length = 1;
+        }
+
+        result.push(ch);
+        length += ch.len_utf8();
+    });
+
+    result
+}
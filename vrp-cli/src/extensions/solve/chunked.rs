@@ -0,0 +1,123 @@
+//! Provides a way to run the search in chunks of generations.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/solve/chunked_test.rs"]
+mod chunked_test;
+
+use super::config::{Config, TerminationConfig, create_builder_from_config};
+use super::progress::JsonProgressTelemetry;
+use std::sync::Arc;
+use vrp_core::prelude::*;
+use vrp_core::rosomaxa::utils::Timer;
+
+/// Default max generations used when termination is not configured.
+const DEFAULT_MAX_GENERATIONS: usize = 3000;
+/// Default max time (in seconds) used when termination is not configured.
+const DEFAULT_MAX_TIME: usize = 300;
+
+/// Runs the search in chunks of generations, so the caller can yield control between them, e.g. to keep
+/// a web worker responsive. Each chunk is a separate solver run which starts from the best known solution
+/// of the previous one, so population diversity is not kept between chunks.
+pub struct ChunkedSolver {
+    problem: Arc<Problem>,
+    config: Config,
+    environment: Arc<Environment>,
+    max_generations: Option<usize>,
+    max_time: Option<Float>,
+    generation: usize,
+    timer: Timer,
+    solution: Option<Solution>,
+    progress: Option<JsonProgressTelemetry>,
+}
+
+impl ChunkedSolver {
+    /// Creates a new instance of `ChunkedSolver`. Termination by variation coefficient is not supported
+    /// as search statistics are not kept between chunks.
+    pub fn new(problem: Arc<Problem>, config: Config) -> GenericResult<Self> {
+        let (max_generations, max_time) = match config.termination.as_ref() {
+            Some(TerminationConfig { variation: Some(_), .. }) => {
+                return Err("variation termination is not supported by chunked solver".into());
+            }
+            Some(TerminationConfig { max_generations: None, max_time: None, .. }) | None => {
+                (Some(DEFAULT_MAX_GENERATIONS), Some(DEFAULT_MAX_TIME))
+            }
+            Some(TerminationConfig { max_generations, max_time, .. }) => (*max_generations, *max_time),
+        };
+
+        Ok(Self {
+            problem,
+            config,
+            environment: Arc::new(Environment::default()),
+            max_generations,
+            max_time: max_time.map(|max_time| max_time as Float),
+            generation: 0,
+            timer: Timer::start(),
+            solution: None,
+            progress: None,
+        })
+    }
+
+    /// Sets progress telemetry which is used to report search progress of every chunk.
+    pub fn with_progress(mut self, progress: JsonProgressTelemetry) -> Self {
+        self.progress = Some(progress);
+        self
+    }
+
+    /// Runs the search for given amount of generations. Returns true if the search is finished.
+    pub fn step(&mut self, generations: usize) -> GenericResult<bool> {
+        if self.is_finished() {
+            return Ok(true);
+        }
+
+        let generations = generations.max(1).min(self.max_generations.map_or(usize::MAX, |max| max - self.generation));
+        let config = Config {
+            termination: Some(TerminationConfig {
+                max_time: None,
+                max_generations: Some(generations),
+                variation: None,
+            }),
+            ..self.config.clone()
+        };
+
+        let mut builder = create_builder_from_config(self.problem.clone(), Vec::default(), &config)?;
+
+        if let Some(solution) = self.solution.take() {
+            let insertion_ctx =
+                InsertionContext::new_from_solution(self.problem.clone(), (solution, None), self.environment.clone());
+            builder = builder.with_init_solutions(vec![insertion_ctx], Some(1));
+        }
+
+        if let Some(progress) = self.progress.as_ref() {
+            let progress = progress.continue_from(self.generation, self.timer.elapsed_secs_as_float());
+            builder = builder.with_termination(Box::new(progress));
+        }
+
+        let solution = builder.build().map(|config| Solver::new(self.problem.clone(), config))?.solve()?;
+
+        self.solution = Some(solution);
+        self.generation += generations;
+
+        Ok(self.is_finished())
+    }
+
+    /// Returns true if the search is finished.
+    pub fn is_finished(&self) -> bool {
+        self.max_generations.is_some_and(|max| self.generation >= max)
+            || self.max_time.is_some_and(|max| self.timer.elapsed_secs_as_float() >= max)
+    }
+
+    /// Returns amount of generations done.
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// Returns the best known solution.
+    pub fn solution(&self) -> Option<&Solution> {
+        self.solution.as_ref()
+    }
+
+    /// Returns the problem being solved.
+    pub fn problem(&self) -> &Problem {
+        self.problem.as_ref()
+    }
+}
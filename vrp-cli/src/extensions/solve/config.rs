@@ -8,8 +8,9 @@ mod config_test;
 
 extern crate serde_json;
 
-use serde::Deserialize;
-use std::io::{BufReader, Read};
+use serde::{Deserialize, Serialize};
+use std::io::{BufReader, Read, Write};
+use std::path::Path;
 use std::sync::Arc;
 use vrp_core::construction::heuristics::InsertionContext;
 use vrp_core::models::GoalContext;
@@ -20,11 +21,17 @@ use vrp_core::rosomaxa::get_default_selection_size;
 use vrp_core::rosomaxa::prelude::*;
 use vrp_core::rosomaxa::utils::*;
 use vrp_core::solver::RecreateInitialOperator;
+use vrp_core::solver::processing::{
+    CostLowerBound, DEFAULT_ROUTE_POOL_SIZE, DEFAULT_SET_PARTITIONING_ITERATIONS, FleetMinimization,
+    MAX_EXACT_POLISHING_SIZE, RoutePolishing, SetPartitioning,
+};
 use vrp_core::solver::search::*;
 use vrp_core::solver::*;
 
 /// An algorithm configuration.
-#[derive(Clone, Default, Deserialize, Debug)]
+#[derive(Clone, Default, Deserialize, Serialize, Debug)]
+#[serde(deny_unknown_fields)]
+#[serde(rename_all = "camelCase")]
 pub struct Config {
     /// Specifies evolution configuration.
     pub evolution: Option<EvolutionConfig>,
@@ -38,22 +45,46 @@ pub struct Config {
     pub telemetry: Option<TelemetryConfig>,
     /// Specifies output configuration.
     pub output: Option<OutputConfig>,
+    /// Specifies solution polishing configuration.
+    pub polishing: Option<PolishingConfig>,
+    /// Specifies fleet minimization pre-phase configuration.
+    pub fleet_minimization: Option<FleetMinimizationConfig>,
+    /// Specifies route pool and set partitioning configuration.
+    pub set_partitioning: Option<SetPartitioningConfig>,
+    /// Specifies cost lower bound estimation configuration.
+    pub lower_bound: Option<LowerBoundConfig>,
 }
 
 /// An evolution configuration.
-#[derive(Clone, Deserialize, Debug)]
+#[derive(Clone, Deserialize, Serialize, Debug)]
+#[serde(deny_unknown_fields)]
 #[serde(rename_all = "camelCase")]
 pub struct EvolutionConfig {
     pub initial: Option<InitialConfig>,
     pub population: Option<PopulationType>,
+    pub restart: Option<RestartConfig>,
+}
+
+/// A warm restart configuration: when the best known solution is not improved during given amount of
+/// generations, the search continues from its heavily perturbed copy with reset operator learning.
+#[derive(Clone, Deserialize, Serialize, Debug)]
+#[serde(deny_unknown_fields)]
+#[serde(rename_all = "camelCase")]
+pub struct RestartConfig {
+    /// Amount of generations without improvement which triggers restart.
+    pub stagnation_window: usize,
+    /// Amount of generations the perturbed solution is refined independently from the population.
+    /// Default is a quarter of stagnation window.
+    pub trajectory_size: Option<usize>,
 }
 
-#[derive(Clone, Deserialize, Debug)]
+#[derive(Clone, Deserialize, Serialize, Debug)]
+#[serde(deny_unknown_fields)]
 #[serde(tag = "type")]
 #[serde(rename_all = "camelCase")]
 pub enum PopulationType {
     /// A greedy population keeps track only of one best-known individual.
-    #[serde(rename(deserialize = "greedy"))]
+    #[serde(rename = "greedy")]
     #[serde(rename_all = "camelCase")]
     Greedy {
         /// Selection size. Default is number of cpus.
@@ -62,7 +93,7 @@ pub enum PopulationType {
 
     /// A basic population which sorts individuals based on their
     /// dominance order.
-    #[serde(rename(deserialize = "elitism"))]
+    #[serde(rename = "elitism")]
     #[serde(rename_all = "camelCase")]
     Elitism {
         /// Max population size. Default is 4.
@@ -72,7 +103,7 @@ pub enum PopulationType {
     },
 
     /// A population algorithm based on SOM.
-    #[serde(rename(deserialize = "rosomaxa"))]
+    #[serde(rename = "rosomaxa")]
     #[serde(rename_all = "camelCase")]
     Rosomaxa {
         /// Selection size. Default is number of cpus.
@@ -93,14 +124,16 @@ pub enum PopulationType {
 }
 
 /// An initial solution configuration.
-#[derive(Clone, Deserialize, Debug)]
+#[derive(Clone, Deserialize, Serialize, Debug)]
+#[serde(deny_unknown_fields)]
 pub struct InitialConfig {
     pub method: RecreateMethod,
     pub alternatives: InitialAlternativesConfig,
 }
 
 /// An initial solution alternatives configuration.
-#[derive(Clone, Deserialize, Debug)]
+#[derive(Clone, Deserialize, Serialize, Debug)]
+#[serde(deny_unknown_fields)]
 #[serde(rename_all = "camelCase")]
 pub struct InitialAlternativesConfig {
     pub methods: Vec<RecreateMethod>,
@@ -109,11 +142,12 @@ pub struct InitialAlternativesConfig {
 }
 
 /// A selection operator configuration.
-#[derive(Clone, Deserialize, Debug)]
+#[derive(Clone, Deserialize, Serialize, Debug)]
+#[serde(deny_unknown_fields)]
 #[serde(tag = "type")]
 #[serde(rename_all = "camelCase")]
 pub enum SelectionType {
-    #[serde(rename(deserialize = "naive"))]
+    #[serde(rename = "naive")]
     Naive {
         /// A size of offspring.
         offspring_size: Option<usize>,
@@ -121,11 +155,12 @@ pub enum SelectionType {
 }
 
 /// A hyper heuristic configuration.
-#[derive(Clone, Deserialize, Debug)]
+#[derive(Clone, Deserialize, Serialize, Debug)]
+#[serde(deny_unknown_fields)]
 #[serde(tag = "type")]
 pub enum HyperType {
     /// A hyper heuristic which selects one operator from the list based on its predefined probability.
-    #[serde(rename(deserialize = "static-selective"))]
+    #[serde(rename = "static-selective")]
     StaticSelective {
         /// A collection of inner operators (metaheuristics).
         operators: Option<Vec<SearchOperatorType>>,
@@ -133,16 +168,17 @@ pub enum HyperType {
 
     /// A hyper heuristic which selects operator from the predefined list using reinforcement
     /// learning technics.
-    #[serde(rename(deserialize = "dynamic-selective"))]
+    #[serde(rename = "dynamic-selective")]
     DynamicSelective,
 }
 
 /// A operator configuration.
-#[derive(Clone, Deserialize, Debug)]
+#[derive(Clone, Deserialize, Serialize, Debug)]
+#[serde(deny_unknown_fields)]
 #[serde(tag = "type")]
 pub enum SearchOperatorType {
     /// A metaheuristic which splits problem into smaller and solves them independently.
-    #[serde(rename(deserialize = "decomposition"))]
+    #[serde(rename = "decomposition")]
     #[serde(rename_all = "camelCase")]
     Decomposition {
         /// Max routes to be selected in decomposed solution.
@@ -154,7 +190,7 @@ pub enum SearchOperatorType {
     },
 
     /// A local search heuristic.
-    #[serde(rename(deserialize = "local-search"))]
+    #[serde(rename = "local-search")]
     LocalSearch {
         /// Probability of operator.
         probability: OperatorProbabilityType,
@@ -164,8 +200,21 @@ pub enum SearchOperatorType {
         operators: Vec<LocalOperatorType>,
     },
 
+    /// A guided local search heuristic: penalizes arcs of local optima found by local search operators.
+    /// Requires a problem read with arc penalties, see [`create_arc_penalties`], otherwise, it is used
+    /// as a plain local search.
+    #[serde(rename = "guided-local-search")]
+    GuidedLocalSearch {
+        /// Probability of operator.
+        probability: OperatorProbabilityType,
+        /// Amount of times one of operators is applied.
+        times: MinMaxConfig,
+        /// Local search operator.
+        operators: Vec<LocalOperatorType>,
+    },
+
     /// A ruin and recreate metaheuristic settings.
-    #[serde(rename(deserialize = "ruin-recreate"))]
+    #[serde(rename = "ruin-recreate")]
     RuinRecreate {
         /// Probability.
         probability: OperatorProbabilityType,
@@ -177,7 +226,8 @@ pub enum SearchOperatorType {
 }
 
 /// A operator probability type
-#[derive(Clone, Deserialize, Debug)]
+#[derive(Clone, Deserialize, Serialize, Debug)]
+#[serde(deny_unknown_fields)]
 #[serde(untagged)]
 pub enum OperatorProbabilityType {
     /// A scalar probability based type.
@@ -196,7 +246,8 @@ pub enum OperatorProbabilityType {
 }
 
 /// A context condition for `MutationProbabilityType`.
-#[derive(Clone, Deserialize, Debug)]
+#[derive(Clone, Deserialize, Serialize, Debug)]
+#[serde(deny_unknown_fields)]
 pub struct ContextThreshold {
     /// Min amount of jobs in individual.
     pub jobs: usize,
@@ -205,25 +256,26 @@ pub struct ContextThreshold {
 }
 
 /// A selection phase filter for `MutationProbabilityType`.
-#[derive(Clone, Deserialize, Debug)]
+#[derive(Clone, Deserialize, Serialize, Debug)]
+#[serde(deny_unknown_fields)]
 #[serde(tag = "type")]
 pub enum ContextPhase {
     /// Initial selection phase.
-    #[serde(rename(deserialize = "initial"))]
+    #[serde(rename = "initial")]
     Initial {
         /// A chance defined by probability.
         chance: Float,
     },
 
     /// Exploration search phase.
-    #[serde(rename(deserialize = "exploration"))]
+    #[serde(rename = "exploration")]
     Exploration {
         /// A chance defined by probability.
         chance: Float,
     },
 
     /// Exploitation search phase.
-    #[serde(rename(deserialize = "exploitation"))]
+    #[serde(rename = "exploitation")]
     Exploitation {
         /// A chance defined by probability.
         chance: Float,
@@ -231,7 +283,8 @@ pub enum ContextPhase {
 }
 
 /// A ruin method configuration.
-#[derive(Clone, Deserialize, Debug)]
+#[derive(Clone, Deserialize, Serialize, Debug)]
+#[serde(deny_unknown_fields)]
 pub struct RuinGroupConfig {
     /// Ruin methods.
     methods: Vec<RuinMethod>,
@@ -240,99 +293,137 @@ pub struct RuinGroupConfig {
 }
 
 /// Specifies ruin methods with their probability weight and specific parameters.
-#[derive(Clone, Deserialize, Debug)]
+#[derive(Clone, Deserialize, Serialize, Debug)]
+#[serde(deny_unknown_fields)]
 #[serde(tag = "type")]
 pub enum RuinMethod {
     /// Adjusted string removal method.
-    #[serde(rename(deserialize = "adjusted-string"))]
+    #[serde(rename = "adjusted-string")]
     AdjustedString { probability: Float, lmax: usize, cavg: usize, alpha: Float },
     /// Neighbour jobs method
-    #[serde(rename(deserialize = "neighbour"))]
+    #[serde(rename = "neighbour")]
     Neighbour { probability: Float, min: usize, max: usize },
     /// Random job removal method.
-    #[serde(rename(deserialize = "random-job"))]
+    #[serde(rename = "random-job")]
     RandomJob { probability: Float, min: usize, max: usize },
     /// Random route removal method.
-    #[serde(rename(deserialize = "random-route"))]
+    #[serde(rename = "random-route")]
     RandomRoute { probability: Float, min: usize, max: usize },
     /// Close route removal method.
-    #[serde(rename(deserialize = "close-route"))]
+    #[serde(rename = "close-route")]
     CloseRoute { probability: Float },
-    #[serde(rename(deserialize = "worst-route"))]
+    #[serde(rename = "worst-route")]
     WorstRoute { probability: Float },
     /// Worst job removal method.
-    #[serde(rename(deserialize = "worst-job"))]
+    #[serde(rename = "worst-job")]
     WorstJob { probability: Float, min: usize, max: usize, skip: usize },
     /// Clustered jobs removal method.
-    #[serde(rename(deserialize = "cluster"))]
+    #[serde(rename = "cluster")]
     #[serde(rename_all = "camelCase")]
     Cluster { probability: Float, min: usize, max: usize },
 }
 
 /// Specifies recreate methods with their probability weight and specific parameters.
-#[derive(Clone, Deserialize, Debug)]
+#[derive(Clone, Deserialize, Serialize, Debug)]
+#[serde(deny_unknown_fields)]
 #[serde(tag = "type")]
 pub enum RecreateMethod {
     /// Cheapest insertion method.
-    #[serde(rename(deserialize = "cheapest"))]
+    #[serde(rename = "cheapest")]
     Cheapest { weight: usize },
     /// SkipBest insertion method.
-    #[serde(rename(deserialize = "skip-best"))]
+    #[serde(rename = "skip-best")]
     SkipBest { weight: usize, start: usize, end: usize },
     /// Insertion with blinks method.
-    #[serde(rename(deserialize = "blinks"))]
+    #[serde(rename = "blinks")]
     Blinks { weight: usize },
     /// Insertion with gaps method.
-    #[serde(rename(deserialize = "gaps"))]
+    #[serde(rename = "gaps")]
     Gaps { weight: usize, min: usize, max: usize },
     /// Nearest neighbour method.
-    #[serde(rename(deserialize = "nearest"))]
+    #[serde(rename = "nearest")]
     Nearest { weight: usize },
     /// Insertion with skip random method.
-    #[serde(rename(deserialize = "skip-random"))]
+    #[serde(rename = "skip-random")]
     SkipRandom { weight: usize },
     /// Insertion with slice method.
-    #[serde(rename(deserialize = "slice"))]
+    #[serde(rename = "slice")]
     Slice { weight: usize },
     /// Farthest insertion method.
-    #[serde(rename(deserialize = "farthest"))]
+    #[serde(rename = "farthest")]
     Farthest { weight: usize },
     /// Insertion with perturbation method.
-    #[serde(rename(deserialize = "perturbation"))]
+    #[serde(rename = "perturbation")]
     Perturbation { weight: usize, probability: Float, min: Float, max: Float },
     /// Insertion with regret method.
-    #[serde(rename(deserialize = "regret"))]
+    #[serde(rename = "regret")]
     Regret { weight: usize, start: usize, end: usize },
 }
 
+/// A search configuration which customizes ruin and recreate methods of the default static heuristic.
+/// Methods are referenced by name: default ones can be reweighted, disabled using zero weight or
+/// replaced, new ones are added.
+#[derive(Clone, Default, Deserialize, Serialize, Debug)]
+pub struct SearchConfig {
+    /// Ruin methods to be changed or added.
+    pub ruins: Option<Vec<NamedRuinConfig>>,
+    /// Recreate methods to be changed or added.
+    pub recreates: Option<Vec<NamedRecreateConfig>>,
+}
+
+/// A named ruin method configuration.
+#[derive(Clone, Deserialize, Serialize, Debug)]
+pub struct NamedRuinConfig {
+    /// A name of the method.
+    pub name: String,
+    /// A weight of the method. Zero weight disables it.
+    pub weight: usize,
+    /// Ruin methods which replace the named one. If omitted, only weight is changed.
+    pub methods: Option<Vec<RuinMethod>>,
+}
+
+/// A named recreate method configuration.
+#[derive(Clone, Deserialize, Serialize, Debug)]
+pub struct NamedRecreateConfig {
+    /// A name of the method.
+    pub name: String,
+    /// A weight of the method. Zero weight disables it. If omitted, weight of `method` is used.
+    pub weight: Option<usize>,
+    /// Recreate method which replaces the named one. If omitted, only weight is changed.
+    pub method: Option<RecreateMethod>,
+}
+
 /// A local search configuration.
-#[derive(Clone, Deserialize, Debug)]
+#[derive(Clone, Deserialize, Serialize, Debug)]
+#[serde(deny_unknown_fields)]
 #[serde(tag = "type")]
 pub enum LocalOperatorType {
-    #[serde(rename(deserialize = "swap-star"))]
+    #[serde(rename = "swap-star")]
     SwapStar { weight: usize },
 
-    #[serde(rename(deserialize = "inter-route-best"))]
+    #[serde(rename = "inter-route-best")]
     InterRouteBest { weight: usize, noise: NoiseConfig },
 
-    #[serde(rename(deserialize = "inter-route-random"))]
+    #[serde(rename = "inter-route-random")]
     InterRouteRandom { weight: usize, noise: NoiseConfig },
 
-    #[serde(rename(deserialize = "intra-route-random"))]
+    #[serde(rename = "intra-route-random")]
     IntraRouteRandom { weight: usize, noise: NoiseConfig },
 
-    #[serde(rename(deserialize = "sequence"))]
+    #[serde(rename = "sequence")]
     Sequence { weight: usize },
 }
 
-#[derive(Clone, Deserialize, Debug)]
+#[derive(Clone, Deserialize, Serialize, Debug)]
+#[serde(deny_unknown_fields)]
 pub struct NoiseConfig {
     probability: Float,
     min: Float,
     max: Float,
 }
 
-#[derive(Clone, Deserialize, Debug)]
+#[derive(Clone, Deserialize, Serialize, Debug)]
+#[serde(deny_unknown_fields)]
 #[serde(rename_all = "camelCase")]
 pub struct TerminationConfig {
     pub max_time: Option<usize>,
@@ -340,44 +431,49 @@ pub struct TerminationConfig {
     pub variation: Option<VariationConfig>,
 }
 
-#[derive(Clone, Deserialize, Debug)]
+#[derive(Clone, Deserialize, Serialize, Debug)]
+#[serde(deny_unknown_fields)]
 #[serde(rename_all = "camelCase")]
 pub struct VariationConfig {
-    interval_type: String,
-    value: usize,
-    cv: Float,
-    is_global: bool,
+    pub interval_type: String,
+    pub value: usize,
+    pub cv: Float,
+    pub is_global: bool,
 }
 
 /// A telemetry config.
-#[derive(Clone, Deserialize, Debug)]
+#[derive(Clone, Deserialize, Serialize, Debug)]
+#[serde(deny_unknown_fields)]
 pub struct TelemetryConfig {
-    progress: Option<ProgressConfig>,
-    metrics: Option<MetricsConfig>,
+    pub progress: Option<ProgressConfig>,
+    pub metrics: Option<MetricsConfig>,
 }
 
-#[derive(Clone, Deserialize, Debug)]
+#[derive(Clone, Deserialize, Serialize, Debug)]
+#[serde(deny_unknown_fields)]
 #[serde(rename_all = "camelCase")]
 pub struct ProgressConfig {
     /// Specifies whether logging is enabled. Default is false.
-    enabled: bool,
+    pub enabled: bool,
     /// Specifies how often best individual is logged. Default is 100 (generations).
-    log_best: Option<usize>,
+    pub log_best: Option<usize>,
     /// Specifies how often population is logged. Default is 1000 (generations).
-    log_population: Option<usize>,
+    pub log_population: Option<usize>,
 }
 
-#[derive(Clone, Deserialize, Debug)]
+#[derive(Clone, Deserialize, Serialize, Debug)]
+#[serde(deny_unknown_fields)]
 #[serde(rename_all = "camelCase")]
 pub struct MetricsConfig {
     /// Specifies whether metrics collection is enabled. Default is false.
-    enabled: bool,
+    pub enabled: bool,
     /// Specifies how often population is tracked. Default is 1000 (generations).
-    track_population: Option<usize>,
+    pub track_population: Option<usize>,
 }
 
 /// An environment specific configuration.
-#[derive(Clone, Deserialize, Debug)]
+#[derive(Clone, Deserialize, Serialize, Debug)]
+#[serde(deny_unknown_fields)]
 #[serde(rename_all = "camelCase")]
 pub struct EnvironmentConfig {
     /// Specifies a data parallelism configuration.
@@ -388,10 +484,23 @@ pub struct EnvironmentConfig {
 
     /// Specifies experimental behavior flag.
     pub is_experimental: Option<bool>,
+
+    /// Specifies a seed of random generator which makes runs repeatable. Default is randomized.
+    pub random_seed: Option<u64>,
+
+    /// Specifies a soft memory budget in megabytes. When approximate memory usage exceeds it,
+    /// population is shrunk. Default is no budget.
+    pub memory_budget: Option<usize>,
+
+    /// Specifies whether results of parallel operations should not depend on thread scheduling, so two
+    /// runs with the same seed produce identical solutions. Random seed is set to zero if not specified.
+    /// Default is false.
+    pub is_deterministic: Option<bool>,
 }
 
 /// Data parallelism configuration.
-#[derive(Clone, Deserialize, Debug)]
+#[derive(Clone, Deserialize, Serialize, Debug)]
+#[serde(deny_unknown_fields)]
 #[serde(rename_all = "camelCase")]
 pub struct ParallelismConfig {
     /// Number of thread pools.
@@ -401,35 +510,87 @@ pub struct ParallelismConfig {
 }
 
 /// Global logging configuration.
-#[derive(Clone, Deserialize, Debug)]
+#[derive(Clone, Deserialize, Serialize, Debug)]
+#[serde(deny_unknown_fields)]
 #[serde(rename_all = "camelCase")]
 pub struct LoggingConfig {
     /// Specifies whether logging is enabled. Default is false.
-    enabled: bool,
+    pub enabled: bool,
     /// Prefix of logging messages.
-    prefix: Option<String>,
+    pub prefix: Option<String>,
 }
 
-#[derive(Clone, Deserialize, Debug, Eq, PartialEq)]
+#[derive(Clone, Deserialize, Serialize, Debug, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
 pub struct MinMaxConfig {
     pub min: usize,
     pub max: usize,
 }
 
-#[derive(Clone, Deserialize, Debug, Eq, PartialEq)]
+#[derive(Clone, Deserialize, Serialize, Debug, Eq, PartialEq)]
 pub struct NameWeight {
     pub name: String,
     pub weight: usize,
 }
 
 /// Specifies output configuration.
-#[derive(Clone, Deserialize, Debug, Eq, PartialEq)]
+#[derive(Clone, Deserialize, Serialize, Debug, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
 #[serde(rename_all = "camelCase")]
 pub struct OutputConfig {
     /// True if the solution, serialized as geojson features, should be included in solution.extras.
     pub include_geojson: Option<bool>,
 }
 
+/// A solution polishing configuration: when enabled, activities of each route in the final solution
+/// are re-ordered using exact (for small routes) or Lin-Kernighan style TSP optimization.
+#[derive(Clone, Deserialize, Serialize, Debug, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+#[serde(rename_all = "camelCase")]
+pub struct PolishingConfig {
+    /// True if polishing is enabled.
+    pub enabled: bool,
+    /// Maximum amount of route activities which are re-ordered exactly. Default is 12, should not be
+    /// greater than 15.
+    pub max_exact_size: Option<usize>,
+}
+
+/// A fleet minimization configuration: when enabled, a dedicated search eliminates routes of an initial
+/// solution before the main search starts.
+#[derive(Clone, Deserialize, Serialize, Debug, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+#[serde(rename_all = "camelCase")]
+pub struct FleetMinimizationConfig {
+    /// True if fleet minimization is enabled.
+    pub enabled: bool,
+    /// Maximum amount of route elimination iterations. Default is 200.
+    pub max_iterations: Option<usize>,
+}
+
+/// A set partitioning configuration: when enabled, routes discovered during the search are kept in
+/// a pool and recombined into a better solution when the search is finished.
+#[derive(Clone, Deserialize, Serialize, Debug, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+#[serde(rename_all = "camelCase")]
+pub struct SetPartitioningConfig {
+    /// True if set partitioning is enabled.
+    pub enabled: bool,
+    /// Maximum amount of routes kept in the pool. Default is 1000.
+    pub max_pool_size: Option<usize>,
+    /// Maximum amount of subgradient iterations. Default is 100.
+    pub max_iterations: Option<usize>,
+}
+
+/// A cost lower bound configuration: when enabled, a lower bound of the total cost is estimated
+/// before the search starts and reported with optimality gap. Estimation time counts against time limit.
+#[derive(Clone, Deserialize, Serialize, Debug, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+#[serde(rename_all = "camelCase")]
+pub struct LowerBoundConfig {
+    /// True if lower bound estimation is enabled.
+    pub enabled: bool,
+}
+
 fn configure_from_evolution(
     mut builder: ProblemConfigBuilder,
     problem: Arc<Problem>,
@@ -530,9 +691,10 @@ fn configure_from_hyper(
     problem: Arc<Problem>,
     environment: Arc<Environment>,
     hyper_config: &Option<HyperType>,
+    restart_config: Option<&RestartConfig>,
 ) -> Result<ProblemConfigBuilder, GenericError> {
-    if let Some(config) = hyper_config {
-        match config {
+    let heuristic: Option<TargetHeuristic> = if let Some(config) = hyper_config {
+        Some(match config {
             HyperType::StaticSelective { operators } => {
                 let static_selective = if let Some(operators) = operators {
                     let heuristic_group = operators
@@ -541,21 +703,99 @@ fn configure_from_hyper(
                         .collect::<Result<Vec<_>, _>>()?;
                     get_static_heuristic_from_heuristic_group(problem.clone(), environment.clone(), heuristic_group)
                 } else {
-                    get_static_heuristic(problem, environment)
+                    get_static_heuristic(problem.clone(), environment.clone())
                 };
 
-                builder = builder.with_heuristic(Box::new(static_selective));
-            }
-            HyperType::DynamicSelective => {
-                let dynamic_selective = get_dynamic_heuristic(problem, environment);
-                builder = builder.with_heuristic(Box::new(dynamic_selective));
+                Box::new(static_selective)
             }
+            HyperType::DynamicSelective => Box::new(get_dynamic_heuristic(problem.clone(), environment.clone())),
+        })
+    } else {
+        None
+    };
+
+    let heuristic = match restart_config {
+        Some(restart) if restart.stagnation_window == 0 => {
+            return Err("stagnation window of restart config should be greater than zero".into());
+        }
+        Some(restart) if restart.trajectory_size == Some(0) => {
+            return Err("trajectory size of restart config should be greater than zero".into());
         }
+        Some(restart) => {
+            let inner = heuristic.unwrap_or_else(|| get_default_heuristic(problem.clone(), environment.clone()));
+            let trajectory_size = restart.trajectory_size.unwrap_or((restart.stagnation_window / 4).max(1));
+            Some(Box::new(get_warm_restart_heuristic(
+                problem,
+                environment,
+                inner,
+                restart.stagnation_window,
+                trajectory_size,
+            )) as _)
+        }
+        None => heuristic,
+    };
+
+    if let Some(heuristic) = heuristic {
+        builder = builder.with_heuristic(heuristic);
     }
 
     Ok(builder)
 }
 
+fn configure_from_processing(
+    mut builder: ProblemConfigBuilder,
+    polishing_config: &Option<PolishingConfig>,
+    fleet_minimization_config: &Option<FleetMinimizationConfig>,
+    set_partitioning_config: &Option<SetPartitioningConfig>,
+    lower_bound_config: &Option<LowerBoundConfig>,
+) -> ProblemConfigBuilder {
+    let polishing_config = polishing_config.as_ref().filter(|config| config.enabled);
+    let fleet_minimization_config = fleet_minimization_config.as_ref().filter(|config| config.enabled);
+    let set_partitioning_config = set_partitioning_config.as_ref().filter(|config| config.enabled);
+    let is_lower_bound_enabled = lower_bound_config.as_ref().is_some_and(|config| config.enabled);
+
+    if polishing_config.is_none()
+        && fleet_minimization_config.is_none()
+        && set_partitioning_config.is_none()
+        && !is_lower_bound_enabled
+    {
+        return builder;
+    }
+
+    let mut processing = create_default_processing();
+
+    if is_lower_bound_enabled {
+        // NOTE lower bound is estimated for the original problem, so it goes before other context processing
+        processing.context.insert(0, Box::<CostLowerBound>::default());
+    }
+
+    if let Some(config) = polishing_config {
+        let polishing = config.max_exact_size.map(RoutePolishing::new).unwrap_or_default();
+        // NOTE polishing should run before schedule related post processing
+        processing.solution.insert(0, Box::new(polishing));
+    }
+
+    if let Some(config) = fleet_minimization_config {
+        let fleet_minimization = config.max_iterations.map(FleetMinimization::new).unwrap_or_default();
+        processing.context.push(Box::new(fleet_minimization));
+    }
+
+    if let Some(config) = set_partitioning_config {
+        let set_partitioning = SetPartitioning::new(
+            config.max_pool_size.unwrap_or(DEFAULT_ROUTE_POOL_SIZE),
+            config.max_iterations.unwrap_or(DEFAULT_SET_PARTITIONING_ITERATIONS),
+        );
+        // NOTE the same route pool is used to collect routes and recombine them, recombination should
+        // run before polishing
+        processing.context.push(Box::new(set_partitioning.clone()));
+        processing.solution.insert(0, Box::new(set_partitioning));
+    }
+
+    builder = builder.with_processing(processing);
+
+    builder
+}
+
 fn configure_from_termination(
     mut builder: ProblemConfigBuilder,
     termination_config: &Option<TerminationConfig>,
@@ -584,7 +824,7 @@ fn create_recreate_method(method: &RecreateMethod, environment: Arc<Environment>
         RecreateMethod::Gaps { weight, min, max } => (Arc::new(RecreateWithGaps::new(*min, *max, random)), *weight),
         RecreateMethod::Nearest { weight } => (Arc::new(RecreateWithNearestNeighbor::new(random)), *weight),
         RecreateMethod::Regret { weight, start, end } => {
-            (Arc::new(RecreateWithRegret::new(*start, *end, random)), *weight)
+            (Arc::new(RecreateWithRegret::new(*start, random).with_max_k(*end)), *weight)
         }
         RecreateMethod::Perturbation { weight, probability, min, max } => {
             let noise = Noise::new_with_addition(*probability, (*min, *max), random.clone());
@@ -615,6 +855,20 @@ fn create_operator(
             let operator = create_local_search(times, inners, environment.random.clone());
             (Arc::new(LocalSearch::new(operator)), create_operator_probability(probability, environment.random.clone()))
         }
+        SearchOperatorType::GuidedLocalSearch { probability, times, operators: inners } => {
+            let operator: TargetSearchOperator =
+                Arc::new(LocalSearch::new(create_local_search(times, inners, environment.random.clone())));
+            let operator: TargetSearchOperator = match problem.extras.get_arc_penalties() {
+                Some(penalties) => Arc::new(GuidedLocalSearch::new(operator, penalties)),
+                None => {
+                    (environment.logger)(
+                        "problem is read without arc penalties, guided local search is used as local search",
+                    );
+                    operator
+                }
+            };
+            (operator, create_operator_probability(probability, environment.random.clone()))
+        }
         SearchOperatorType::Decomposition { routes, repeat, probability } => {
             if *repeat < 1 {
                 return Err(format!("repeat must be greater than 1. Specified: {repeat}").into());
@@ -757,11 +1011,19 @@ fn get_telemetry_mode(environment: Arc<Environment>, telemetry_config: &Option<T
 fn configure_from_environment(
     environment_config: &Option<EnvironmentConfig>,
     max_time: Option<usize>,
+    quota: Option<Arc<dyn Quota>>,
 ) -> Arc<Environment> {
     let mut environment = Environment::new_with_time_quota(max_time);
 
+    if let Some(quota) = quota {
+        environment.quota = Some(Arc::new(CompositeQuota { inner: environment.quota.take(), extra: quota }));
+    }
+
+    if let Some(random_seed) = environment_config.as_ref().and_then(|c| c.random_seed) {
+        environment.random = Arc::new(DefaultRandom::new_with_seed(random_seed));
+    }
+
     if let Some(parallelism) = environment_config.as_ref().and_then(|c| c.parallelism.as_ref()) {
-        // TODO validate parameters
         environment.parallelism = Parallelism::new(parallelism.num_thread_pools, parallelism.threads_per_pool);
     }
 
@@ -777,12 +1039,257 @@ fn configure_from_environment(
         environment.is_experimental = is_experimental;
     }
 
+    if environment_config.as_ref().and_then(|c| c.is_deterministic).unwrap_or(false) {
+        if !environment.random.is_repeatable() {
+            environment.random = Arc::new(DefaultRandom::new_repeatable());
+        }
+        environment.parallelism = environment.parallelism.clone().with_deterministic(true);
+    }
+
+    if let Some(memory_budget) = environment_config.as_ref().and_then(|c| c.memory_budget) {
+        environment.memory_budget = Some(memory_budget * 1024 * 1024);
+    }
+
     Arc::new(environment)
 }
 
+/// Specifies a format of config file.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ConfigFormat {
+    /// A json format.
+    Json,
+    /// A toml format.
+    Toml,
+}
+
+impl ConfigFormat {
+    /// Detects config format from file extension: toml is used for `.toml` files, json otherwise.
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some(extension) if extension.eq_ignore_ascii_case("toml") => ConfigFormat::Toml,
+            _ => ConfigFormat::Json,
+        }
+    }
+}
+
 /// Reads config from reader.
 pub fn read_config<R: Read>(reader: BufReader<R>) -> GenericResult<Config> {
-    serde_json::from_reader(reader).map_err(|err| format!("cannot deserialize config: '{err}'").into())
+    read_config_with_format(reader, ConfigFormat::Json)
+}
+
+/// Reads config in given format from reader.
+pub fn read_config_with_format<R: Read>(mut reader: BufReader<R>, format: ConfigFormat) -> GenericResult<Config> {
+    let config: Config = match format {
+        ConfigFormat::Json => serde_json::from_reader(reader).map_err(|err| err.to_string()),
+        ConfigFormat::Toml => {
+            let mut content = String::new();
+            reader.read_to_string(&mut content).map_err(|err| err.to_string())?;
+            toml::from_str(content.as_str()).map_err(|err| err.message().to_string())
+        }
+    }
+    .map_err(|err| format!("cannot deserialize config: '{err}'"))?;
+
+    validate_config(&config)?;
+
+    Ok(config)
+}
+
+/// Writes config in given format to writer. Unspecified (optional) parameters are omitted.
+pub fn write_config<W: Write>(writer: &mut W, config: &Config, format: ConfigFormat) -> GenericResult<()> {
+    let mut value = serde_json::to_value(config).map_err(|err| format!("cannot serialize config: '{err}'"))?;
+    remove_null_values(&mut value);
+
+    let content = match format {
+        ConfigFormat::Json => serde_json::to_string_pretty(&value).map_err(|err| err.to_string()),
+        ConfigFormat::Toml => toml::to_string_pretty(&value).map_err(|err| err.to_string()),
+    }
+    .map_err(|err| format!("cannot serialize config: '{err}'"))?;
+
+    writer.write_all(content.as_bytes()).map_err(|err| format!("cannot write config: '{err}'").into())
+}
+
+/// Validates parameter values of the config and returns all found issues at once.
+pub fn validate_config(config: &Config) -> GenericResult<()> {
+    let mut errors = Vec::default();
+    let mut check = |is_valid: bool, message: &dyn Fn() -> String| {
+        if !is_valid {
+            errors.push(message());
+        }
+    };
+    let is_ratio = |value: Float| (0. ..=1.).contains(&value);
+    let is_positive = |value: Option<usize>| value.is_none_or(|value| value > 0);
+
+    if let Some(evolution) = &config.evolution {
+        if let Some(initial) = &evolution.initial {
+            let alternatives = &initial.alternatives;
+            check(is_ratio(alternatives.quota), &|| {
+                format!("evolution.initial.alternatives.quota should be in [0, 1], got: {}", alternatives.quota)
+            });
+        }
+
+        match &evolution.population {
+            Some(PopulationType::Greedy { selection_size }) => {
+                check(is_positive(*selection_size), &|| "population selectionSize should be greater than zero".into());
+            }
+            Some(PopulationType::Elitism { max_size, selection_size }) => {
+                check(is_positive(*max_size), &|| "population maxSize should be greater than zero".into());
+                check(is_positive(*selection_size), &|| "population selectionSize should be greater than zero".into());
+            }
+            Some(PopulationType::Rosomaxa {
+                selection_size,
+                max_elite_size,
+                max_node_size,
+                spread_factor,
+                distribution_factor,
+                rebalance_memory,
+                exploration_ratio,
+            }) => {
+                check(is_positive(*selection_size), &|| "population selectionSize should be greater than zero".into());
+                check(is_positive(*max_elite_size), &|| "population maxEliteSize should be greater than zero".into());
+                check(is_positive(*max_node_size), &|| "population maxNodeSize should be greater than zero".into());
+                check(is_positive(*rebalance_memory), &|| {
+                    "population rebalanceMemory should be greater than zero".into()
+                });
+                [
+                    ("spreadFactor", spread_factor),
+                    ("distributionFactor", distribution_factor),
+                    ("explorationRatio", exploration_ratio),
+                ]
+                .into_iter()
+                .filter_map(|(name, value)| value.map(|value| (name, value)))
+                .for_each(|(name, value)| {
+                    check(is_ratio(value), &|| format!("population {name} should be in [0, 1], got: {value}"))
+                });
+            }
+            None => {}
+        }
+
+        if let Some(restart) = &evolution.restart {
+            check(restart.stagnation_window > 0, &|| {
+                "evolution.restart.stagnationWindow should be greater than zero".into()
+            });
+            check(restart.trajectory_size.is_none_or(|size| size > 0), &|| {
+                "evolution.restart.trajectorySize should be greater than zero".into()
+            });
+        }
+    }
+
+    if let Some(HyperType::StaticSelective { operators: Some(operators) }) = &config.hyper {
+        operators.iter().for_each(|operator| {
+            let probability = match operator {
+                SearchOperatorType::Decomposition { probability, .. }
+                | SearchOperatorType::LocalSearch { probability, .. }
+                | SearchOperatorType::GuidedLocalSearch { probability, .. }
+                | SearchOperatorType::RuinRecreate { probability, .. } => probability,
+            };
+
+            match probability {
+                OperatorProbabilityType::Scalar { scalar } => {
+                    check(is_ratio(*scalar), &|| format!("operator probability should be in [0, 1], got: {scalar}"))
+                }
+                OperatorProbabilityType::Context { phases, .. } => phases.iter().for_each(|phase| {
+                    let (ContextPhase::Initial { chance }
+                    | ContextPhase::Exploration { chance }
+                    | ContextPhase::Exploitation { chance }) = phase;
+                    check(is_ratio(*chance), &|| format!("operator phase chance should be in [0, 1], got: {chance}"))
+                }),
+            }
+        });
+    }
+
+    if let Some(polishing) = &config.polishing {
+        check(polishing.max_exact_size.is_none_or(|size| size <= MAX_EXACT_POLISHING_SIZE), &|| {
+            format!("polishing.maxExactSize should not be greater than {MAX_EXACT_POLISHING_SIZE}")
+        });
+    }
+
+    if let Some(termination) = &config.termination {
+        check(is_positive(termination.max_time), &|| "termination.maxTime should be greater than zero".into());
+        check(is_positive(termination.max_generations), &|| {
+            "termination.maxGenerations should be greater than zero".into()
+        });
+
+        if let Some(variation) = &termination.variation {
+            check(matches!(variation.interval_type.as_str(), "sample" | "period"), &|| {
+                format!(
+                    "termination.variation.intervalType should be either 'sample' or 'period', got: '{}'",
+                    variation.interval_type
+                )
+            });
+            check(variation.value > 0, &|| "termination.variation.value should be greater than zero".into());
+            check(variation.cv >= 0., &|| "termination.variation.cv should not be negative".into());
+        }
+    }
+
+    if let Some(parallelism) = config.environment.as_ref().and_then(|environment| environment.parallelism.as_ref()) {
+        check(parallelism.num_thread_pools > 0 && parallelism.threads_per_pool > 0, &|| {
+            "environment.parallelism should have non-zero numThreadPools and threadsPerPool".into()
+        });
+    }
+
+    if let Some(telemetry) = &config.telemetry {
+        if let Some(progress) = &telemetry.progress {
+            check(is_positive(progress.log_best) && is_positive(progress.log_population), &|| {
+                "telemetry.progress intervals should be greater than zero".into()
+            });
+        }
+
+        if let Some(metrics) = &telemetry.metrics {
+            check(is_positive(metrics.track_population), &|| {
+                "telemetry.metrics.trackPopulation should be greater than zero".into()
+            });
+        }
+    }
+
+    if errors.is_empty() { Ok(()) } else { Err(format!("invalid config: {}", errors.join("; ")).into()) }
+}
+
+fn remove_null_values(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            map.retain(|_, value| !value.is_null());
+            map.values_mut().for_each(remove_null_values);
+        }
+        serde_json::Value::Array(values) => values.iter_mut().for_each(remove_null_values),
+        _ => {}
+    }
+}
+
+/// Reads search config from reader.
+pub fn read_search_config<R: Read>(reader: BufReader<R>) -> GenericResult<SearchConfig> {
+    serde_json::from_reader(reader).map_err(|err| format!("cannot deserialize search config: '{err}'").into())
+}
+
+/// Creates a ruin and recreate operator from search config applied on top of default methods.
+pub fn create_ruin_recreate_from_search_config(
+    problem: Arc<Problem>,
+    environment: Arc<Environment>,
+    config: &SearchConfig,
+) -> GenericResult<TargetSearchOperator> {
+    let builder = RuinRecreateBuilder::new(problem.clone(), environment.clone());
+
+    let builder = config.ruins.iter().flatten().try_fold(builder, |builder, ruin| match &ruin.methods {
+        Some(methods) => {
+            let ruin_methods = methods.iter().map(|method| create_ruin_method(&problem, method)).collect();
+            Ok(builder.with_ruin(ruin.name.as_str(), Arc::new(CompositeRuin::new(ruin_methods)), ruin.weight))
+        }
+        None => builder.set_ruin_weight(ruin.name.as_str(), ruin.weight),
+    })?;
+
+    let builder = config.recreates.iter().flatten().try_fold(builder, |builder, recreate| {
+        match (&recreate.method, recreate.weight) {
+            (Some(method), weight) => {
+                let (method, method_weight) = create_recreate_method(method, environment.clone());
+                Ok(builder.with_recreate(recreate.name.as_str(), method, weight.unwrap_or(method_weight)))
+            }
+            (None, Some(weight)) => builder.set_recreate_weight(recreate.name.as_str(), weight),
+            (None, None) => {
+                Err(format!("either weight or method should be set for '{}' recreate", recreate.name).into())
+            }
+        }
+    })?;
+
+    builder.build()
 }
 
 /// Creates a solver `Builder` from config file.
@@ -797,14 +1304,49 @@ where
     read_config(reader).and_then(|config| create_builder_from_config(problem, solutions, &config))
 }
 
+/// Creates arc penalties if guided local search operator is used within the config. Penalties have to be
+/// passed to the problem reader, so that transport costs are biased by them.
+pub fn create_arc_penalties(config: &Config) -> Option<Arc<ArcPenalties>> {
+    let has_guided_local_search = match &config.hyper {
+        Some(HyperType::StaticSelective { operators: Some(operators) }) => {
+            operators.iter().any(|operator| matches!(operator, SearchOperatorType::GuidedLocalSearch { .. }))
+        }
+        _ => false,
+    };
+
+    has_guided_local_search.then(|| Arc::new(ArcPenalties::default()))
+}
+
 /// Creates a solver `Builder` from config.
 pub fn create_builder_from_config(
     problem: Arc<Problem>,
     solutions: Vec<InsertionContext>,
     config: &Config,
 ) -> GenericResult<ProblemConfigBuilder> {
-    let environment =
-        configure_from_environment(&config.environment, config.termination.as_ref().and_then(|t| t.max_time));
+    create_builder(problem, solutions, config, None)
+}
+
+/// Creates a solver `Builder` from config with an extra quota which is checked along with the time quota of
+/// termination settings. It can be used to interrupt solving from outside.
+pub fn create_builder_from_config_with_quota(
+    problem: Arc<Problem>,
+    solutions: Vec<InsertionContext>,
+    config: &Config,
+    quota: Arc<dyn Quota>,
+) -> GenericResult<ProblemConfigBuilder> {
+    create_builder(problem, solutions, config, Some(quota))
+}
+
+fn create_builder(
+    problem: Arc<Problem>,
+    solutions: Vec<InsertionContext>,
+    config: &Config,
+    quota: Option<Arc<dyn Quota>>,
+) -> GenericResult<ProblemConfigBuilder> {
+    validate_config(config)?;
+
+    let max_time = config.termination.as_ref().and_then(|t| t.max_time);
+    let environment = configure_from_environment(&config.environment, max_time, quota);
     let telemetry_mode = get_telemetry_mode(environment.clone(), &config.telemetry);
     let mut builder = VrpConfigBuilder::new(problem.clone())
         .set_environment(environment.clone())
@@ -814,8 +1356,27 @@ pub fn create_builder_from_config(
 
     builder =
         configure_from_evolution(builder, problem.clone(), environment.clone(), telemetry_mode, &config.evolution)?;
-    builder = configure_from_hyper(builder, problem, environment, &config.hyper)?;
+    let restart_config = config.evolution.as_ref().and_then(|evolution| evolution.restart.as_ref());
+    builder = configure_from_hyper(builder, problem, environment, &config.hyper, restart_config)?;
     builder = configure_from_termination(builder, &config.termination);
+    builder = configure_from_processing(
+        builder,
+        &config.polishing,
+        &config.fleet_minimization,
+        &config.set_partitioning,
+        &config.lower_bound,
+    );
 
     Ok(builder)
 }
+
+struct CompositeQuota {
+    inner: Option<Arc<dyn Quota>>,
+    extra: Arc<dyn Quota>,
+}
+
+impl Quota for CompositeQuota {
+    fn is_reached(&self) -> bool {
+        self.inner.as_ref().is_some_and(|inner| inner.is_reached()) || self.extra.is_reached()
+    }
+}
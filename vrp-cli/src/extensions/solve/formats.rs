@@ -1,12 +1,13 @@
 //! Contains format readers and writers.
 
-use crate::get_locations_serialized;
+use crate::get_locations_serialized_from_reader;
 use std::collections::HashMap;
 use std::fs::File;
 use std::io::{BufReader, BufWriter, Write};
 use std::sync::Arc;
 use vrp_core::models::{Problem, Solution};
-use vrp_core::prelude::{GenericError, Random};
+use vrp_core::prelude::{GenericError, InfoLogger, Random};
+use vrp_pragmatic::format::problem::ProblemReadOptions;
 use vrp_pragmatic::format::solution::{PragmaticOutputType, write_pragmatic};
 use vrp_scientific::tsplib::{TsplibProblem, TsplibSolution};
 
@@ -14,8 +15,9 @@ use vrp_scientific::tsplib::{TsplibProblem, TsplibSolution};
 #[allow(clippy::type_complexity)]
 pub struct ProblemReader(pub Box<dyn Fn(File, Option<Vec<File>>) -> Result<Problem, GenericError>>);
 
-/// A reader for initial solution.
-pub struct InitSolutionReader(pub Box<dyn Fn(File, Arc<Problem>) -> Result<Solution, GenericError>>);
+/// A reader for initial solution. Warnings about parts of the solution which are skipped are reported via logger.
+#[allow(clippy::type_complexity)]
+pub struct InitSolutionReader(pub Box<dyn Fn(File, Arc<Problem>, InfoLogger) -> Result<Solution, GenericError>>);
 
 #[allow(clippy::type_complexity)]
 /// A writer for solution.
@@ -37,12 +39,12 @@ pub struct LocationWriter(pub Box<dyn Fn(File, BufWriter<Box<dyn Write>>) -> Res
 #[allow(clippy::type_complexity)]
 type FormatMap<'a> = HashMap<&'a str, (ProblemReader, InitSolutionReader, SolutionWriter, LocationWriter)>;
 
-/// Gets available format readers/writers.
-pub fn get_formats<'a>(is_rounded: bool, random: Arc<dyn Random>) -> FormatMap<'a> {
+/// Gets available format readers/writers. Read options are used by pragmatic format reader.
+pub fn get_formats<'a>(is_rounded: bool, random: Arc<dyn Random>, options: ProblemReadOptions) -> FormatMap<'a> {
     let mut formats = FormatMap::default();
 
     add_scientific(&mut formats, is_rounded, random.clone());
-    add_pragmatic(&mut formats, random);
+    add_pragmatic(&mut formats, random, options);
 
     formats
 }
@@ -50,6 +52,7 @@ pub fn get_formats<'a>(is_rounded: bool, random: Arc<dyn Random>) -> FormatMap<'
 fn add_scientific(formats: &mut FormatMap, is_rounded: bool, random: Arc<dyn Random>) {
     if cfg!(feature = "scientific-format") {
         use vrp_scientific::common::read_init_solution;
+        use vrp_scientific::cordeau::{CordeauProblem, CordeauSolution};
         use vrp_scientific::lilim::{LilimProblem, LilimSolution};
         use vrp_scientific::solomon::{SolomonProblem, SolomonSolution};
 
@@ -62,21 +65,42 @@ fn add_scientific(formats: &mut FormatMap, is_rounded: bool, random: Arc<dyn Ran
                 })),
                 InitSolutionReader(Box::new({
                     let random = random.clone();
-                    move |file, problem| read_init_solution(BufReader::new(file), problem, random.clone())
+                    move |file, problem, _| read_init_solution(BufReader::new(file), problem, random.clone())
                 })),
                 SolutionWriter(Box::new(|_, solution, mut writer, _| solution.write_solomon(&mut writer))),
                 LocationWriter(Box::new(|_, _| unimplemented!())),
             ),
         );
+        // NOTE pdptw is an alias of lilim: reader detects both native and solomon like layouts
+        ["lilim", "pdptw"].into_iter().for_each(|name| {
+            let random = random.clone();
+            formats.insert(
+                name,
+                (
+                    ProblemReader(Box::new(move |problem: File, matrices: Option<Vec<File>>| {
+                        assert!(matrices.is_none());
+                        BufReader::new(problem).read_lilim(is_rounded)
+                    })),
+                    InitSolutionReader(Box::new(move |file, problem, _| {
+                        read_init_solution(BufReader::new(file), problem, random.clone())
+                    })),
+                    SolutionWriter(Box::new(|_, solution, mut writer, _| solution.write_lilim(&mut writer))),
+                    LocationWriter(Box::new(|_, _| unimplemented!())),
+                ),
+            );
+        });
         formats.insert(
-            "lilim",
+            "cordeau",
             (
                 ProblemReader(Box::new(move |problem: File, matrices: Option<Vec<File>>| {
                     assert!(matrices.is_none());
-                    BufReader::new(problem).read_lilim(is_rounded)
+                    BufReader::new(problem).read_cordeau(is_rounded)
                 })),
-                InitSolutionReader(Box::new(|_file, _problem| unimplemented!())),
-                SolutionWriter(Box::new(|_, solution, mut writer, _| solution.write_lilim(&mut writer))),
+                InitSolutionReader(Box::new({
+                    let random = random.clone();
+                    move |file, problem, _| read_init_solution(BufReader::new(file), problem, random.clone())
+                })),
+                SolutionWriter(Box::new(|_, solution, mut writer, _| solution.write_cordeau(&mut writer))),
                 LocationWriter(Box::new(|_, _| unimplemented!())),
             ),
         );
@@ -87,7 +111,7 @@ fn add_scientific(formats: &mut FormatMap, is_rounded: bool, random: Arc<dyn Ran
                     assert!(matrices.is_none());
                     BufReader::new(problem).read_tsplib(is_rounded)
                 })),
-                InitSolutionReader(Box::new(move |file, problem| {
+                InitSolutionReader(Box::new(move |file, problem, _| {
                     read_init_solution(BufReader::new(file), problem, random.clone())
                 })),
                 SolutionWriter(Box::new(|_, solution, mut writer, _| solution.write_tsplib(&mut writer))),
@@ -97,25 +121,22 @@ fn add_scientific(formats: &mut FormatMap, is_rounded: bool, random: Arc<dyn Ran
     }
 }
 
-fn add_pragmatic(formats: &mut FormatMap, random: Arc<dyn Random>) {
-    use vrp_pragmatic::format::problem::{PragmaticProblem, deserialize_problem};
-    use vrp_pragmatic::format::solution::read_init_solution as read_init_pragmatic;
+fn add_pragmatic(formats: &mut FormatMap, random: Arc<dyn Random>, options: ProblemReadOptions) {
+    use vrp_pragmatic::format::problem::PragmaticProblem;
+    use vrp_pragmatic::format::solution::read_partial_init_solution;
 
     formats.insert(
         "pragmatic",
         (
-            ProblemReader(Box::new(|problem: File, matrices: Option<Vec<File>>| {
-                match matrices {
-                    Some(matrices) => {
-                        let matrices = matrices.into_iter().map(BufReader::new).collect();
-                        (BufReader::new(problem), matrices).read_pragmatic()
-                    }
-                    _ => BufReader::new(problem).read_pragmatic(),
-                }
-                .map_err(From::from)
+            ProblemReader(Box::new(move |problem: File, matrices: Option<Vec<File>>| match matrices {
+                Some(matrices) => read_pragmatic_with_matrices(problem, matrices, options.clone()),
+                None => (BufReader::new(problem), options.clone()).read_pragmatic().map_err(From::from),
             })),
-            InitSolutionReader(Box::new(move |file, problem| {
-                read_init_pragmatic(BufReader::new(file), problem, random.clone())
+            InitSolutionReader(Box::new(move |file, problem, logger: InfoLogger| {
+                read_partial_init_solution(BufReader::new(file), problem, random.clone()).map(|(solution, warnings)| {
+                    warnings.iter().for_each(|warning| (logger)(format!("init solution warning: {warning}").as_str()));
+                    solution
+                })
             })),
             SolutionWriter(Box::new(|problem, solution, mut default_writer, geojson_writer| {
                 geojson_writer
@@ -126,11 +147,31 @@ fn add_pragmatic(formats: &mut FormatMap, random: Arc<dyn Random>) {
             })),
             LocationWriter(Box::new(|problem, writer| {
                 let mut writer = writer;
-                deserialize_problem(BufReader::new(problem))
-                    .map_err(From::from)
-                    .and_then(|problem| get_locations_serialized(&problem))
+                get_locations_serialized_from_reader(BufReader::new(problem))
                     .and_then(|locations| writer.write_all(locations.as_bytes()).map_err(From::from))
             })),
         ),
     );
 }
+
+fn read_pragmatic_with_matrices(
+    problem: File,
+    matrices: Vec<File>,
+    options: ProblemReadOptions,
+) -> Result<Problem, GenericError> {
+    use vrp_pragmatic::format::problem::PragmaticProblem;
+
+    #[cfg(not(target_arch = "wasm32"))]
+    let matrices = {
+        let mut matrices = matrices;
+        if let Some(binary_matrices) = crate::extensions::solve::binary_matrix::read_binary_matrices(&mut matrices)? {
+            let problem = vrp_pragmatic::format::deserialize_problem_compat(BufReader::new(problem))?;
+            return (problem, binary_matrices, options).read_pragmatic().map_err(From::from);
+        }
+
+        matrices
+    };
+
+    let matrices = matrices.into_iter().map(BufReader::new).collect();
+    (BufReader::new(problem), matrices, options).read_pragmatic().map_err(From::from)
+}
@@ -1,4 +1,12 @@
 //! Solve command helpers
 
+#[cfg(not(target_arch = "wasm32"))]
+pub mod batch;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod binary_matrix;
+pub mod calendar;
+pub mod chunked;
 pub mod config;
 pub mod formats;
+pub mod progress;
+pub mod summary;
@@ -0,0 +1,155 @@
+//! Provides a machine-readable progress output of the solver run in JSON lines format.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/solve/progress_test.rs"]
+mod progress_test;
+
+use super::summary::SolveSummary;
+use serde::Serialize;
+use std::sync::{Arc, Mutex};
+use vrp_core::models::GoalContext;
+use vrp_core::prelude::*;
+use vrp_core::rosomaxa::prelude::*;
+use vrp_core::rosomaxa::termination::Termination;
+use vrp_core::solver::RefinementContext;
+
+/// A function which writes a single json line.
+pub type JsonLineWriter = Arc<dyn Fn(&str) + Send + Sync>;
+
+/// A progress event which is written as a single json line.
+#[derive(Clone, Debug, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum ProgressEvent {
+    /// A free form log message.
+    Message {
+        /// Message text.
+        message: String,
+    },
+    /// A search progress.
+    #[serde(rename_all = "camelCase")]
+    Progress {
+        /// Generation number.
+        generation: usize,
+        /// Elapsed time in seconds.
+        elapsed: Float,
+        /// Speed in generations per second.
+        speed: Float,
+        /// Total cost of the best known solution.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        best_cost: Option<Float>,
+        /// Amount of tours in the best known solution.
+        tours: usize,
+        /// Amount of unassigned jobs in the best known solution.
+        unassigned: usize,
+        /// True if the best known solution has been improved since the last progress event.
+        is_improved: bool,
+        /// Ratio of generations with improvement over the whole search.
+        improvement_all_ratio: Float,
+        /// Ratio of generations with improvement over the last 1000 generations.
+        improvement_1000_ratio: Float,
+        /// Estimated search progress in range [0, 1] based on termination criteria.
+        termination_estimate: Float,
+    },
+    /// A final result of the solver run.
+    Result(Box<SolveSummary>),
+}
+
+/// Writes progress event as a single json line.
+pub fn write_progress_event(event: &ProgressEvent, writer: &JsonLineWriter) {
+    if let Ok(line) = serde_json::to_string(event) {
+        (writer)(line.as_str())
+    }
+}
+
+/// Creates a logger which writes messages as json lines.
+pub fn create_json_logger(writer: JsonLineWriter) -> InfoLogger {
+    Arc::new(move |message: &str| {
+        // NOTE empty messages are used only as separators in text logs
+        if !message.trim().is_empty() {
+            write_progress_event(&ProgressEvent::Message { message: message.to_string() }, &writer)
+        }
+    })
+}
+
+/// Writes search progress as json lines. It is implemented as termination criteria which never
+/// stops the search, so it can be added to any solver configuration.
+pub struct JsonProgressTelemetry {
+    interval: usize,
+    writer: JsonLineWriter,
+    /// Keeps generation and elapsed time of the search before the current solver run.
+    offset: (usize, Float),
+    /// Keeps last reported generation and best cost.
+    last_reported: Arc<Mutex<(Option<usize>, Option<Float>)>>,
+}
+
+impl JsonProgressTelemetry {
+    /// Creates a new instance of `JsonProgressTelemetry` which writes progress every `interval` generations.
+    pub fn new(interval: usize, writer: JsonLineWriter) -> Self {
+        Self { interval: interval.max(1), writer, offset: (0, 0.), last_reported: Arc::new(Mutex::new((None, None))) }
+    }
+
+    /// Creates a new instance which continues reporting of the same search from given generation and elapsed
+    /// time. It is used when the search is split into several solver runs.
+    pub fn continue_from(&self, generation: usize, elapsed: Float) -> Self {
+        Self {
+            interval: self.interval,
+            writer: self.writer.clone(),
+            offset: (generation, elapsed),
+            last_reported: self.last_reported.clone(),
+        }
+    }
+}
+
+impl Termination for JsonProgressTelemetry {
+    type Context = RefinementContext;
+    type Objective = GoalContext;
+
+    fn is_termination(&self, heuristic_ctx: &mut Self::Context) -> bool {
+        let statistics = heuristic_ctx.statistics();
+        let generation = statistics.generation + self.offset.0;
+        if !generation.is_multiple_of(self.interval) {
+            return false;
+        }
+
+        // NOTE termination is checked several times per generation while initial solutions are built
+        let Some(best) = heuristic_ctx.ranked().next() else { return false };
+        let best_cost = best.get_total_cost();
+
+        let is_improved = {
+            let mut last_reported = self.last_reported.lock().unwrap();
+            let (last_generation, last_cost) = *last_reported;
+            if last_generation == Some(generation) {
+                return false;
+            }
+
+            let is_improved = best_cost.is_some_and(|cost| last_cost.is_none_or(|last_cost| cost < last_cost));
+            *last_reported = (Some(generation), if is_improved { best_cost } else { last_cost });
+
+            is_improved
+        };
+
+        let elapsed = statistics.time.elapsed_secs_as_float() + self.offset.1;
+        let speed = if elapsed > 0. { generation as Float / elapsed } else { 0. };
+
+        let event = ProgressEvent::Progress {
+            generation,
+            elapsed,
+            speed,
+            best_cost,
+            tours: best.solution.routes.len(),
+            unassigned: best.solution.unassigned.len(),
+            is_improved,
+            improvement_all_ratio: statistics.improvement_all_ratio,
+            improvement_1000_ratio: statistics.improvement_1000_ratio,
+            termination_estimate: statistics.termination_estimate,
+        };
+
+        write_progress_event(&event, &self.writer);
+
+        false
+    }
+
+    fn estimate(&self, _: &Self::Context) -> Float {
+        0.
+    }
+}
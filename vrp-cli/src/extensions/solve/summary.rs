@@ -0,0 +1,261 @@
+//! Provides a machine-readable summary of the solver run.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/solve/summary_test.rs"]
+mod summary_test;
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use vrp_core::models::Solution;
+use vrp_core::prelude::GenericResult;
+use vrp_core::solver::processing::COST_LOWER_BOUND_PROPERTY;
+use vrp_core::utils::Float;
+
+/// Represents a summary of the solver run.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SolveSummary {
+    /// Instance name which is a problem file name without extension.
+    pub instance: String,
+    /// Problem format.
+    pub format: String,
+    /// Total cost of the solution.
+    pub cost: Float,
+    /// Amount of tours.
+    pub tours: usize,
+    /// Amount of unassigned jobs.
+    pub unassigned: usize,
+    /// Solver run duration in seconds.
+    pub duration: Float,
+    /// Amount of generations if telemetry is enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub generations: Option<usize>,
+    /// Comparison with the best known solution if the instance is known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub best_known: Option<BestKnownSummary>,
+    /// Comparison with the cost lower bound if it is estimated by the solver and all jobs are assigned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lower_bound: Option<LowerBoundSummary>,
+    /// A name of termination criteria which has stopped the search if telemetry is enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub termination: Option<String>,
+    /// Best known fitness samples taken on improvements if telemetry is enabled.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub trajectory: Vec<FitnessSample>,
+    /// Usage statistics of search operators if telemetry is enabled.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub operators: Vec<OperatorSummary>,
+    /// CPU time (user and system) of the process in seconds if it can be measured.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cpu_time: Option<Float>,
+    /// Peak resident memory of the process in bytes if it can be measured.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory_peak: Option<u64>,
+    /// Peak of approximate memory usage estimate of solver structures in bytes if telemetry is enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory_estimate: Option<usize>,
+    /// Amount of times population was shrunk due to exceeded memory budget.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory_shrinks: Option<usize>,
+    /// Named values reported by the solver, e.g. fleet size lower bound, if telemetry is enabled.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub properties: BTreeMap<String, Float>,
+}
+
+/// Represents a best known fitness sample.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FitnessSample {
+    /// Generation when fitness was improved.
+    pub generation: usize,
+    /// Time since search started in seconds.
+    pub timestamp: Float,
+    /// Objective fitness values.
+    pub fitness: Vec<Float>,
+}
+
+/// Represents usage statistics of a search operator.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OperatorSummary {
+    /// Operator name.
+    pub name: String,
+    /// Amount of solutions produced by the operator.
+    pub calls: usize,
+    /// Amount of solutions which improved the best known fitness.
+    pub improvements: usize,
+}
+
+/// Represents a comparison with the best known solution.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BestKnownSummary {
+    /// Total cost of the best known solution.
+    pub cost: Float,
+    /// Amount of tours in the best known solution if it is a part of the objective.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tours: Option<usize>,
+    /// Relative cost gap in percents: positive value means that the solution is worse than the best known.
+    pub gap: Float,
+}
+
+/// Represents a comparison with the cost lower bound.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LowerBoundSummary {
+    /// Estimated lower bound of the total cost.
+    pub cost: Float,
+    /// Relative optimality gap in percents: the solution is at most that much worse than optimal one.
+    pub gap: Float,
+}
+
+impl SolveSummary {
+    /// Creates a summary of the solution. Best known solution is taken from the embedded registry of
+    /// scientific benchmark instances.
+    pub fn new(problem_path: &str, format: &str, solution: &Solution, duration: Float) -> Self {
+        let instance = get_instance_name(problem_path);
+        let best_known = get_best_known_summary(format, instance.as_str(), solution.cost);
+        let telemetry = solution.telemetry.as_ref();
+        let properties =
+            telemetry.iter().flat_map(|telemetry| telemetry.properties.iter().cloned()).collect::<BTreeMap<_, _>>();
+        let lower_bound = get_lower_bound_summary(&properties, solution);
+
+        Self {
+            instance,
+            format: format.to_string(),
+            cost: solution.cost,
+            tours: solution.routes.len(),
+            unassigned: solution.unassigned.len(),
+            duration,
+            generations: telemetry.map(|telemetry| telemetry.generations),
+            best_known,
+            lower_bound,
+            termination: telemetry.and_then(|telemetry| telemetry.termination.clone()),
+            trajectory: telemetry
+                .iter()
+                .flat_map(|telemetry| telemetry.best_fitness.iter())
+                .map(|sample| FitnessSample {
+                    generation: sample.generation,
+                    timestamp: sample.timestamp,
+                    fitness: sample.fitness.clone(),
+                })
+                .collect(),
+            operators: telemetry
+                .iter()
+                .flat_map(|telemetry| telemetry.operators.iter())
+                .map(|operator| OperatorSummary {
+                    name: operator.name.clone(),
+                    calls: operator.calls,
+                    improvements: operator.improvements,
+                })
+                .collect(),
+            cpu_time: None,
+            memory_peak: None,
+            memory_estimate: telemetry.and_then(|telemetry| telemetry.memory_peak),
+            memory_shrinks: telemetry.map(|telemetry| telemetry.memory_shrinks).filter(|&shrinks| shrinks > 0),
+            properties,
+        }
+    }
+
+    /// Adds CPU time and peak memory usage of the current process to the summary.
+    pub fn with_resource_usage(self) -> Self {
+        let (cpu_time, memory_peak) = get_resource_usage();
+
+        Self { cpu_time, memory_peak, ..self }
+    }
+
+    /// Returns a human-readable message about gap to the best known solution, if it is known.
+    pub fn get_gap_message(&self) -> Option<String> {
+        self.best_known.as_ref().map(|best_known| {
+            let tours = best_known.tours.map(|tours| format!(", tours: {} vs {tours}", self.tours)).unwrap_or_default();
+
+            format!(
+                "best known solution of '{}': cost: {:.2} vs {:.2}{tours}, gap: {:.2}%",
+                self.instance, self.cost, best_known.cost, best_known.gap
+            )
+        })
+    }
+
+    /// Returns a human-readable message about optimality gap, if cost lower bound is known.
+    pub fn get_lower_bound_message(&self) -> Option<String> {
+        self.lower_bound.as_ref().map(|lower_bound| {
+            format!(
+                "cost lower bound: {:.2} vs {:.2}, optimality gap: {:.2}%",
+                lower_bound.cost, self.cost, lower_bound.gap
+            )
+        })
+    }
+}
+
+/// Writes summary as json.
+pub fn write_solve_summary<W: Write>(summary: &SolveSummary, writer: &mut BufWriter<W>) -> GenericResult<()> {
+    serde_json::to_writer_pretty(writer, summary).map_err(|err| format!("cannot write summary: '{err}'").into())
+}
+
+/// Returns CPU time in seconds and peak resident memory in bytes of the current process.
+#[cfg(unix)]
+#[allow(unsafe_code)]
+fn get_resource_usage() -> (Option<Float>, Option<u64>) {
+    // SAFETY: getrusage only writes into provided structure
+    let usage = unsafe {
+        let mut usage = std::mem::zeroed::<libc::rusage>();
+        if libc::getrusage(libc::RUSAGE_SELF, &mut usage) != 0 {
+            return (None, None);
+        }
+        usage
+    };
+
+    let to_secs = |time: libc::timeval| time.tv_sec as Float + time.tv_usec as Float / 1_000_000.;
+    let cpu_time = to_secs(usage.ru_utime) + to_secs(usage.ru_stime);
+
+    // NOTE max resident set size is reported in bytes on macOS and in kilobytes elsewhere
+    let memory_peak = if cfg!(target_os = "macos") { usage.ru_maxrss as u64 } else { usage.ru_maxrss as u64 * 1024 };
+
+    (Some(cpu_time), Some(memory_peak))
+}
+
+#[cfg(not(unix))]
+fn get_resource_usage() -> (Option<Float>, Option<u64>) {
+    (None, None)
+}
+
+fn get_lower_bound_summary(properties: &BTreeMap<String, Float>, solution: &Solution) -> Option<LowerBoundSummary> {
+    // NOTE the bound is estimated for serving all jobs, so it is not comparable with partial solutions
+    if !solution.unassigned.is_empty() {
+        return None;
+    }
+
+    properties.get(COST_LOWER_BOUND_PROPERTY).map(|&cost| LowerBoundSummary {
+        cost,
+        gap: if solution.cost > 0. { ((solution.cost - cost) / solution.cost * 100.).max(0.) } else { 0. },
+    })
+}
+
+fn get_instance_name(problem_path: &str) -> String {
+    Path::new(problem_path)
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_else(|| problem_path.to_string())
+}
+
+#[cfg(feature = "scientific-format")]
+fn get_best_known_summary(format: &str, instance: &str, cost: Float) -> Option<BestKnownSummary> {
+    use vrp_scientific::common::get_best_known_solution;
+
+    if format == "pragmatic" {
+        return None;
+    }
+
+    get_best_known_solution(instance).map(|best_known| BestKnownSummary {
+        cost: best_known.cost,
+        tours: best_known.tours,
+        gap: best_known.cost_gap(cost),
+    })
+}
+
+#[cfg(not(feature = "scientific-format"))]
+fn get_best_known_summary(_: &str, _: &str, _: Float) -> Option<BestKnownSummary> {
+    None
+}
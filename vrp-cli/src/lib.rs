@@ -35,16 +35,19 @@ pub use vrp_scientific as scientific;
 pub mod extensions;
 
 use crate::extensions::import::import_problem;
-use crate::extensions::solve::config::{Config, create_builder_from_config};
-use std::io::{BufReader, BufWriter};
+use crate::extensions::solve::config::{Config, create_builder_from_config, create_builder_from_config_with_quota};
+use crate::extensions::solve::progress::JsonProgressTelemetry;
+use std::io::{BufReader, BufWriter, Read};
 use std::sync::Arc;
 use vrp_core::models::Problem as CoreProblem;
+use vrp_core::models::Solution;
 use vrp_core::prelude::{GenericError, Solver};
+use vrp_core::rosomaxa::utils::Quota;
 use vrp_pragmatic::format::FormatError;
-use vrp_pragmatic::format::problem::{PragmaticProblem, Problem, serialize_problem};
+use vrp_pragmatic::format::problem::{Matrix, PragmaticProblem, Problem, serialize_problem};
 use vrp_pragmatic::format::solution::{PragmaticOutputType, write_pragmatic};
-use vrp_pragmatic::get_unique_locations;
 use vrp_pragmatic::validation::ValidationContext;
+use vrp_pragmatic::{get_unique_locations, get_unique_locations_from_reader};
 
 #[cfg(not(target_arch = "wasm32"))]
 #[allow(unsafe_code)]
@@ -52,7 +55,7 @@ mod c_interop {
     use super::*;
     use crate::extensions::solve::config::read_config;
     use std::ffi::{CStr, CString};
-    use std::os::raw::c_char;
+    use std::os::raw::{c_char, c_int};
     use std::panic;
     use std::panic::UnwindSafe;
     use std::slice;
@@ -61,6 +64,7 @@ mod c_interop {
     use vrp_pragmatic::format::{CoordIndex, MultiFormatError};
 
     type Callback = extern "C" fn(*const c_char);
+    type CancelCallback = extern "C" fn() -> c_int;
 
     fn to_string(pointer: *const c_char) -> String {
         let slice = unsafe { CStr::from_ptr(pointer).to_bytes() };
@@ -101,8 +105,7 @@ mod c_interop {
         catch_panic(failure, || {
             let problem = to_string(problem);
             let problem = BufReader::new(problem.as_bytes());
-            let result =
-                deserialize_problem(problem).map_err(From::from).and_then(|problem| get_locations_serialized(&problem));
+            let result = get_locations_serialized_from_reader(problem);
 
             call_back(result, success, failure);
         });
@@ -190,28 +193,81 @@ mod c_interop {
         failure: Callback,
     ) {
         catch_panic(failure, || {
-            let problem = to_string(problem);
-            let matrices = unsafe { slice::from_raw_parts(matrices, matrices_len).to_vec() };
-            let matrices = matrices.iter().map(|m| to_string(*m)).collect::<Vec<_>>();
+            let result = read_problem_and_config(problem, matrices, matrices_len, config)
+                .and_then(|(problem, config)| get_solution_serialized(Arc::new(problem), config));
+
+            call_back(result, success, failure);
+        });
+    }
+
+    /// Solves Vehicle Routing Problem passed in `pragmatic` format. Search progress is passed to `progress` callback
+    /// as a json line every `progress_interval` generations. The search is stopped as soon as `is_cancelled` callback
+    /// returns non-zero value and the best known solution is passed to `success` callback.
+    #[unsafe(no_mangle)]
+    #[allow(clippy::too_many_arguments)]
+    extern "C" fn solve_pragmatic_with_progress(
+        problem: *const c_char,
+        matrices: *const *const c_char,
+        matrices_len: usize,
+        config: *const c_char,
+        progress_interval: usize,
+        progress: Callback,
+        is_cancelled: CancelCallback,
+        success: Callback,
+        failure: Callback,
+    ) {
+        catch_panic(failure, || {
+            let progress = JsonProgressTelemetry::new(
+                progress_interval,
+                Arc::new(move |line: &str| {
+                    let line = CString::new(line.as_bytes()).unwrap();
+                    progress(line.as_ptr());
+                }),
+            );
+            let quota: Arc<dyn Quota> = Arc::new(CallbackQuota(is_cancelled));
 
             let result =
-                if matrices.is_empty() { problem.read_pragmatic() } else { (problem, matrices).read_pragmatic() }
-                    .map_err(From::from)
-                    .and_then(|problem| {
-                        read_config(BufReader::new(to_string(config).as_bytes()))
-                            .map_err(|err| GenericError::from(serialize_as_config_error(err.to_string().as_str())))
-                            .map(|config| (problem, config))
-                    })
-                    .and_then(|(problem, config)| get_solution_serialized(Arc::new(problem), config));
+                read_problem_and_config(problem, matrices, matrices_len, config).and_then(|(problem, config)| {
+                    get_solution_serialized_with_quota(Arc::new(problem), config, Some(progress), Some(quota))
+                });
 
             call_back(result, success, failure);
         });
     }
 
+    fn read_problem_and_config(
+        problem: *const c_char,
+        matrices: *const *const c_char,
+        matrices_len: usize,
+        config: *const c_char,
+    ) -> Result<(CoreProblem, Config), GenericError> {
+        let problem = to_string(problem);
+        let matrices = unsafe { slice::from_raw_parts(matrices, matrices_len).to_vec() };
+        let matrices = matrices.iter().map(|m| to_string(*m)).collect::<Vec<_>>();
+
+        if matrices.is_empty() { problem.read_pragmatic() } else { (problem, matrices).read_pragmatic() }
+            .map_err(From::from)
+            .and_then(|problem| {
+                read_config(BufReader::new(to_string(config).as_bytes()))
+                    .map_err(|err| GenericError::from(serialize_as_config_error(err.to_string().as_str())))
+                    .map(|config| (problem, config))
+            })
+    }
+
+    /// Asks the caller whether the search should be stopped.
+    struct CallbackQuota(CancelCallback);
+
+    impl Quota for CallbackQuota {
+        fn is_reached(&self) -> bool {
+            (self.0)() != 0
+        }
+    }
+
     #[cfg(test)]
     mod tests {
         use super::*;
         use crate::helpers::generate::SIMPLE_PROBLEM;
+        use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 
         #[test]
         fn can_use_to_string() {
@@ -320,7 +376,7 @@ mod c_interop {
 
             let problem = CString::new(SIMPLE_PROBLEM).unwrap();
             let matrices = CString::new("[]").unwrap();
-            let config = CString::new("{\"termination\": {\"max-generations\": 1}}").unwrap();
+            let config = CString::new("{\"termination\": {\"maxGenerations\": 1}}").unwrap();
 
             solve_pragmatic(
                 problem.as_ptr() as *const c_char,
@@ -331,6 +387,81 @@ mod c_interop {
                 failure,
             );
         }
+
+        #[test]
+        fn can_solve_problem_with_progress() {
+            static PROGRESS: AtomicUsize = AtomicUsize::new(0);
+            static SOLVED: AtomicBool = AtomicBool::new(false);
+
+            extern "C" fn progress(line: *const c_char) {
+                assert!(to_string(line).contains(r#""type":"progress""#));
+                PROGRESS.fetch_add(1, Ordering::Relaxed);
+            }
+            extern "C" fn is_cancelled() -> c_int {
+                0
+            }
+            extern "C" fn success(solution: *const c_char) {
+                assert!(to_string(solution).contains("tours"));
+                SOLVED.store(true, Ordering::Relaxed);
+            }
+            extern "C" fn failure(err: *const c_char) {
+                unreachable!("{}", to_string(err))
+            }
+
+            let problem = CString::new(SIMPLE_PROBLEM).unwrap();
+            let matrices = CString::new("[]").unwrap();
+            let config = CString::new("{\"termination\": {\"maxGenerations\": 10}}").unwrap();
+
+            solve_pragmatic_with_progress(
+                problem.as_ptr() as *const c_char,
+                matrices.as_ptr() as *const *const c_char,
+                0,
+                config.as_ptr() as *const c_char,
+                2,
+                progress,
+                is_cancelled,
+                success,
+                failure,
+            );
+
+            assert!(SOLVED.load(Ordering::Relaxed));
+            assert_eq!(PROGRESS.load(Ordering::Relaxed), 6);
+        }
+
+        #[test]
+        fn can_cancel_solving() {
+            static SOLVED: AtomicBool = AtomicBool::new(false);
+
+            extern "C" fn progress(_: *const c_char) {}
+            extern "C" fn is_cancelled() -> c_int {
+                1
+            }
+            extern "C" fn success(solution: *const c_char) {
+                assert!(to_string(solution).contains("tours"));
+                SOLVED.store(true, Ordering::Relaxed);
+            }
+            extern "C" fn failure(err: *const c_char) {
+                unreachable!("{}", to_string(err))
+            }
+
+            let problem = CString::new(SIMPLE_PROBLEM).unwrap();
+            let matrices = CString::new("[]").unwrap();
+            let config = CString::new("{\"termination\": {\"maxGenerations\": 100000}}").unwrap();
+
+            solve_pragmatic_with_progress(
+                problem.as_ptr() as *const c_char,
+                matrices.as_ptr() as *const *const c_char,
+                0,
+                config.as_ptr() as *const c_char,
+                100,
+                progress,
+                is_cancelled,
+                success,
+                failure,
+            );
+
+            assert!(SOLVED.load(Ordering::Relaxed));
+        }
     }
 }
 
@@ -338,10 +469,13 @@ mod c_interop {
 #[cfg(not(target_arch = "wasm32"))]
 mod py_interop {
     use super::*;
+    use crate::extensions::check::{check_pragmatic_problem, check_pragmatic_solution_with_report};
     use crate::extensions::solve::config::read_config;
     use pyo3::exceptions::PyOSError;
     use pyo3::prelude::*;
     use std::io::BufReader;
+    use vrp_core::prelude::Float;
+    use vrp_pragmatic::checker::CheckerOptions;
     use vrp_pragmatic::format::CoordIndex;
     use vrp_pragmatic::format::problem::{deserialize_matrix, deserialize_problem};
 
@@ -367,15 +501,22 @@ mod py_interop {
     /// Returns a list of unique locations which can be used to request a routing matrix.
     #[pyfunction]
     fn get_routing_locations(problem: String) -> PyResult<String> {
-        deserialize_problem(BufReader::new(problem.as_bytes()))
-            .map_err(From::from)
-            .and_then(|problem| get_locations_serialized(&problem))
+        get_locations_serialized_from_reader(BufReader::new(problem.as_bytes()))
             .map_err(|err| PyOSError::new_err(err.to_string()))
     }
 
-    /// Validates and solves Vehicle Routing Problem.
+    /// Validates and solves Vehicle Routing Problem. When `progress` callback is specified, it is called with search
+    /// progress event serialized as json string every `progress_interval` generations.
     #[pyfunction]
-    fn solve_pragmatic(problem: String, matrices: Vec<String>, config: String) -> PyResult<String> {
+    #[pyo3(signature = (problem, matrices, config, progress=None, progress_interval=100))]
+    fn solve_pragmatic(
+        py: Python<'_>,
+        problem: String,
+        matrices: Vec<String>,
+        config: String,
+        progress: Option<Py<PyAny>>,
+        progress_interval: usize,
+    ) -> PyResult<String> {
         // validate first
         deserialize_problem(BufReader::new(problem.as_bytes()))
             .and_then(|problem| {
@@ -401,15 +542,74 @@ mod py_interop {
                     .map_err(|err| GenericError::from(serialize_as_config_error(err.to_string().as_str())))
                     .map(|config| (problem, config))
             })
-            .and_then(|(problem, config)| get_solution_serialized(Arc::new(problem), config))
+            .and_then(|(problem, config)| {
+                let progress = progress.map(|callback| {
+                    JsonProgressTelemetry::new(
+                        progress_interval,
+                        Arc::new(move |line: &str| {
+                            Python::with_gil(|py| {
+                                if let Err(err) = callback.call1(py, (line,)) {
+                                    err.print(py);
+                                }
+                            })
+                        }),
+                    )
+                });
+
+                // NOTE release GIL, so progress callback can acquire it from the solver's thread
+                py.allow_threads(|| get_solution_serialized_with_progress(Arc::new(problem), config, progress))
+            })
             .map_err(|err| PyOSError::new_err(err.to_string()))
     }
 
+    /// Validates Vehicle Routing Problem without solving it and returns a report with found issues in json format.
+    #[pyfunction]
+    fn validate_pragmatic(problem: String, matrices: Vec<String>) -> PyResult<String> {
+        let matrices = if matrices.is_empty() {
+            None
+        } else {
+            Some(matrices.iter().map(|matrix| BufReader::new(matrix.as_bytes())).collect())
+        };
+
+        let report = check_pragmatic_problem(BufReader::new(problem.as_bytes()), matrices);
+
+        serde_json::to_string_pretty(&report).map_err(|err| PyOSError::new_err(err.to_string()))
+    }
+
+    /// Checks feasibility of the solution and returns a report with found errors and warnings in json format.
+    /// Time (in seconds) and distance (in meters) mismatches within given tolerances are reported as warnings.
+    #[pyfunction]
+    #[pyo3(signature = (problem, solution, matrices, time_tolerance=0., distance_tolerance=0.))]
+    fn check_pragmatic(
+        problem: String,
+        solution: String,
+        matrices: Vec<String>,
+        time_tolerance: Float,
+        distance_tolerance: Float,
+    ) -> PyResult<String> {
+        let matrices = if matrices.is_empty() {
+            None
+        } else {
+            Some(matrices.iter().map(|matrix| BufReader::new(matrix.as_bytes())).collect())
+        };
+
+        let report = check_pragmatic_solution_with_report(
+            BufReader::new(problem.as_bytes()),
+            BufReader::new(solution.as_bytes()),
+            matrices,
+            CheckerOptions { time_tolerance, distance_tolerance },
+        );
+
+        serde_json::to_string_pretty(&report).map_err(|err| PyOSError::new_err(err.to_string()))
+    }
+
     #[pymodule]
     fn vrp_cli(m: &Bound<'_, PyModule>) -> PyResult<()> {
         m.add_function(wrap_pyfunction!(convert_to_pragmatic, m)?)?;
         m.add_function(wrap_pyfunction!(get_routing_locations, m)?)?;
         m.add_function(wrap_pyfunction!(solve_pragmatic, m)?)?;
+        m.add_function(wrap_pyfunction!(validate_pragmatic, m)?)?;
+        m.add_function(wrap_pyfunction!(check_pragmatic, m)?)?;
         Ok(())
     }
 }
@@ -420,6 +620,8 @@ mod wasm {
     extern crate wasm_bindgen;
 
     use super::*;
+    use crate::extensions::solve::chunked::ChunkedSolver;
+    use js_sys::{Array, Float64Array, Function, Reflect};
     use vrp_pragmatic::format::CoordIndex;
     use vrp_pragmatic::format::problem::Matrix;
     use wasm_bindgen::prelude::*;
@@ -441,8 +643,7 @@ mod wasm {
     pub fn validate_pragmatic(problem: JsValue, matrices: JsValue) -> Result<JsValue, JsValue> {
         let problem: Problem =
             serde_wasm_bindgen::from_value(problem).map_err(|err| JsValue::from_str(err.to_string().as_str()))?;
-        let matrices: Vec<Matrix> =
-            serde_wasm_bindgen::from_value(matrices).map_err(|err| JsValue::from_str(err.to_string().as_str()))?;
+        let matrices = read_matrices(matrices)?;
         let coord_index = CoordIndex::new(&problem);
 
         let matrices = if matrices.is_empty() { None } else { Some(&matrices) };
@@ -474,28 +675,146 @@ mod wasm {
         }
     }
 
-    /// Solves Vehicle Routing Problem passed in `pragmatic` format.
+    /// Solves Vehicle Routing Problem passed in `pragmatic` format. When `progress` callback is specified,
+    /// it is called with search progress serialized as a json line on every generation.
     #[wasm_bindgen]
-    pub fn solve_pragmatic(problem: JsValue, matrices: JsValue, config: JsValue) -> Result<JsValue, JsValue> {
+    pub fn solve_pragmatic(
+        problem: JsValue,
+        matrices: JsValue,
+        config: JsValue,
+        progress: Option<Function>,
+    ) -> Result<JsValue, JsValue> {
+        let problem = read_problem(problem, matrices)?;
+        let config = read_config(config)?;
+
+        get_solution_serialized_with_progress(problem, config, progress.map(create_progress))
+            .map(|problem| JsValue::from_str(problem.as_str()))
+            .map_err(|err| JsValue::from_str(&err.to_string()))
+    }
+
+    /// Solves Vehicle Routing Problem passed in `pragmatic` format in chunks of generations, so the search
+    /// does not block the caller (e.g. a web worker) for its whole duration.
+    #[wasm_bindgen]
+    pub struct PragmaticSolver {
+        solver: ChunkedSolver,
+        config: Config,
+    }
+
+    #[wasm_bindgen]
+    impl PragmaticSolver {
+        /// Creates a new solver. Arguments are the same as for `solve_pragmatic` function.
+        #[wasm_bindgen(constructor)]
+        pub fn new(
+            problem: JsValue,
+            matrices: JsValue,
+            config: JsValue,
+            progress: Option<Function>,
+        ) -> Result<PragmaticSolver, JsValue> {
+            let problem = read_problem(problem, matrices)?;
+            let config = read_config(config)?;
+
+            let solver = ChunkedSolver::new(problem, config.clone())
+                .map_err(|err| JsValue::from_str(serialize_as_config_error(&err.to_string()).as_str()))?;
+            let solver = match progress {
+                Some(progress) => solver.with_progress(create_progress(progress)),
+                None => solver,
+            };
+
+            Ok(Self { solver, config })
+        }
+
+        /// Runs the search for given amount of generations. Returns true if the search is finished.
+        pub fn step(&mut self, generations: usize) -> Result<bool, JsValue> {
+            self.solver.step(generations).map_err(|err| JsValue::from_str(&err.to_string()))
+        }
+
+        /// Returns true if the search is finished.
+        pub fn is_finished(&self) -> bool {
+            self.solver.is_finished()
+        }
+
+        /// Returns amount of generations done.
+        pub fn generation(&self) -> usize {
+            self.solver.generation()
+        }
+
+        /// Returns the best known solution in `pragmatic` format.
+        pub fn get_solution(&self) -> Result<JsValue, JsValue> {
+            let solution = self.solver.solution().ok_or_else(|| JsValue::from_str("no solution: call step first"))?;
+
+            get_solution_as_json(self.solver.problem(), solution, &self.config)
+                .map(|solution| JsValue::from_str(solution.as_str()))
+                .map_err(|err| JsValue::from_str(&err.to_string()))
+        }
+    }
+
+    fn read_problem(problem: JsValue, matrices: JsValue) -> Result<Arc<CoreProblem>, JsValue> {
         let problem: Problem =
             serde_wasm_bindgen::from_value(problem).map_err(|err| JsValue::from_str(err.to_string().as_str()))?;
+        let matrices = read_matrices(matrices)?;
 
-        let matrices: Vec<Matrix> =
-            serde_wasm_bindgen::from_value(matrices).map_err(|err| JsValue::from_str(err.to_string().as_str()))?;
-
-        let problem = Arc::new(
+        Ok(Arc::new(
             if matrices.is_empty() { problem.read_pragmatic() } else { (problem, matrices).read_pragmatic() }
                 .map_err(|errs| JsValue::from_str(errs.to_json().as_str()))?,
-        );
+        ))
+    }
 
-        let config: Config = serde_wasm_bindgen::from_value(config)
+    fn read_config(config: JsValue) -> Result<Config, JsValue> {
+        serde_wasm_bindgen::from_value(config)
             .map_err(|err| serialize_as_config_error(&err.to_string()))
-            .map_err(|err| JsValue::from_str(err.as_str()))?;
+            .map_err(|err| JsValue::from_str(err.as_str()))
+    }
 
-        get_solution_serialized(problem, config)
-            .map(|problem| JsValue::from_str(problem.as_str()))
-            .map_err(|err| JsValue::from_str(&err.to_string()))
+    /// Reads routing matrices. Travel times (`travelTimes` or `durations`) and `distances` of a matrix can be
+    /// passed as `Float64Array` which avoids the cost of serialization of large plain arrays.
+    fn read_matrices(matrices: JsValue) -> Result<Vec<Matrix>, JsValue> {
+        if !Array::is_array(&matrices) {
+            return serde_wasm_bindgen::from_value(matrices).map_err(|err| JsValue::from_str(err.to_string().as_str()));
+        }
+
+        Array::from(&matrices)
+            .iter()
+            .map(|matrix| {
+                let get_field = |name: &str| Reflect::get(&matrix, &JsValue::from_str(name)).ok();
+                let get_typed = |name: &str| get_field(name).and_then(|value| value.dyn_into::<Float64Array>().ok());
+
+                match (get_typed("travelTimes").or_else(|| get_typed("durations")), get_typed("distances")) {
+                    (Some(durations), Some(distances)) => {
+                        let profile = get_field("profile").and_then(|value| value.as_string());
+                        let timestamp = get_field("timestamp").and_then(|value| value.as_string());
+
+                        create_matrix_from_arrays(profile, &durations.to_vec(), &distances.to_vec())
+                            .map(|matrix| Matrix { timestamp, ..matrix })
+                            .map_err(|err| JsValue::from_str(err.to_string().as_str()))
+                    }
+                    _ => serde_wasm_bindgen::from_value(matrix)
+                        .map_err(|err| JsValue::from_str(err.to_string().as_str())),
+                }
+            })
+            .collect()
     }
+
+    fn create_progress(callback: Function) -> JsonProgressTelemetry {
+        let callback = JsCallback(callback);
+
+        JsonProgressTelemetry::new(1, Arc::new(move |line: &str| callback.call(line)))
+    }
+
+    /// Wraps javascript function to be used as a progress writer.
+    struct JsCallback(Function);
+
+    impl JsCallback {
+        fn call(&self, line: &str) {
+            // NOTE errors thrown by the callback should not break the search
+            let _ = self.0.call1(&JsValue::NULL, &JsValue::from_str(line));
+        }
+    }
+
+    // NOTE wasm32 target is built without atomics, so the callback is always called from the same thread
+    #[allow(unsafe_code)]
+    unsafe impl Send for JsCallback {}
+    #[allow(unsafe_code)]
+    unsafe impl Sync for JsCallback {}
 }
 
 /// Gets locations serialized in json.
@@ -506,29 +825,72 @@ pub fn get_locations_serialized(problem: &Problem) -> Result<String, GenericErro
     serde_json::to_string_pretty(&locations).map_err(|err| err.to_string().into())
 }
 
+/// Gets locations serialized in json. Problem is read from `reader` without keeping its jobs in memory.
+pub fn get_locations_serialized_from_reader<R: Read>(reader: BufReader<R>) -> Result<String, GenericError> {
+    let locations = get_unique_locations_from_reader(reader)?;
+    serde_json::to_string_pretty(&locations).map_err(|err| err.to_string().into())
+}
+
 /// Gets solution serialized in json.
 pub fn get_solution_serialized(problem: Arc<CoreProblem>, config: Config) -> Result<String, GenericError> {
-    let solution = create_builder_from_config(problem.clone(), Default::default(), &config)
-        .and_then(|builder| builder.build())
-        .map(|config| Solver::new(problem.clone(), config))
-        .and_then(|solver| solver.solve())
-        .map_err(|err| {
-            FormatError::new(
-                "E0003".to_string(),
-                "cannot find any solution".to_string(),
-                format!("please submit a bug and share original problem and routing matrix. Error: '{err}'"),
-            )
-            .to_json()
-        })?;
-
-    let output_type = if config.output.and_then(|output_cfg| output_cfg.include_geojson).unwrap_or(false) {
+    get_solution_serialized_with_progress(problem, config, None)
+}
+
+/// Gets solution serialized in json. When progress telemetry is specified, search progress is reported as json lines
+/// while the solver is running.
+pub fn get_solution_serialized_with_progress(
+    problem: Arc<CoreProblem>,
+    config: Config,
+    progress: Option<JsonProgressTelemetry>,
+) -> Result<String, GenericError> {
+    get_solution_serialized_with_quota(problem, config, progress, None)
+}
+
+/// Gets solution serialized in json. When quota is specified, the search is stopped as soon as the quota is reached
+/// and the best known solution is returned. It can be used to cancel solving from outside.
+pub fn get_solution_serialized_with_quota(
+    problem: Arc<CoreProblem>,
+    config: Config,
+    progress: Option<JsonProgressTelemetry>,
+    quota: Option<Arc<dyn Quota>>,
+) -> Result<String, GenericError> {
+    let solution = match quota {
+        Some(quota) => create_builder_from_config_with_quota(problem.clone(), Default::default(), &config, quota),
+        None => create_builder_from_config(problem.clone(), Default::default(), &config),
+    }
+    .map(|builder| match progress {
+        Some(progress) => builder.with_termination(Box::new(progress)),
+        None => builder,
+    })
+    .and_then(|builder| builder.build())
+    .map(|config| Solver::new(problem.clone(), config))
+    .and_then(|solver| solver.solve().map_err(From::from))
+    .map_err(|err| {
+        FormatError::new(
+            "E0003".to_string(),
+            "cannot find any solution".to_string(),
+            format!("please submit a bug and share original problem and routing matrix. Error: '{err}'"),
+        )
+        .to_json()
+    })?;
+
+    get_solution_as_json(problem.as_ref(), &solution, &config)
+}
+
+/// Gets solution of the problem serialized in json using output settings of the config.
+pub fn get_solution_as_json(
+    problem: &CoreProblem,
+    solution: &Solution,
+    config: &Config,
+) -> Result<String, GenericError> {
+    let output_type = if config.output.as_ref().and_then(|output_cfg| output_cfg.include_geojson).unwrap_or(false) {
         PragmaticOutputType::Combined
     } else {
         Default::default()
     };
 
     let mut writer = BufWriter::new(Vec::new());
-    write_pragmatic(problem.as_ref(), &solution, output_type, &mut writer)?;
+    write_pragmatic(problem, solution, output_type, &mut writer)?;
 
     let bytes = writer.into_inner().map_err(|err| format!("{err}"))?;
     let result = String::from_utf8(bytes).map_err(|err| format!("{err}"))?;
@@ -536,6 +898,46 @@ pub fn get_solution_serialized(problem: Arc<CoreProblem>, config: Config) -> Res
     Ok(result)
 }
 
+/// Creates a routing matrix from flat arrays of travel durations and distances, e.g. passed as typed arrays
+/// from javascript. Values are rounded to the nearest integer.
+pub fn create_matrix_from_arrays(
+    profile: Option<String>,
+    durations: &[f64],
+    distances: &[f64],
+) -> Result<Matrix, GenericError> {
+    if durations.len() != distances.len() {
+        return Err(
+            format!("durations and distances have different size: {} vs {}", durations.len(), distances.len()).into()
+        );
+    }
+
+    let size = (durations.len() as f64).sqrt().round() as usize;
+    if size * size != durations.len() {
+        return Err(format!("matrix size is not a square: {}", durations.len()).into());
+    }
+
+    let convert = |values: &[f64], name: &str| {
+        values
+            .iter()
+            .map(|&value| {
+                if value.is_finite() && value >= 0. {
+                    Ok(value.round() as i64)
+                } else {
+                    Err(GenericError::from(format!("{name} has invalid value: {value}")))
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()
+    };
+
+    Ok(Matrix {
+        profile,
+        timestamp: None,
+        travel_times: convert(durations, "durations")?,
+        distances: convert(distances, "distances")?,
+        error_codes: None,
+    })
+}
+
 fn serialize_as_config_error(err: &str) -> String {
     FormatError::new(
         "E0004".to_string(),
@@ -17,9 +17,17 @@ mod cli {
     use super::commands::import::{get_import_app, run_import};
     use super::commands::solve::{get_solve_app, run_solve};
     use crate::commands::analyze::{get_analyze_app, run_analyze};
+    use crate::commands::batch::{get_batch_app, run_batch};
+    use crate::commands::benchmark::{get_benchmark_app, run_benchmark};
     use crate::commands::check::{get_check_app, run_check};
+    use crate::commands::convert::{get_convert_app, run_convert};
     use crate::commands::create_write_buffer;
+    use crate::commands::diff::{get_diff_app, run_diff};
     use crate::commands::generate::{get_generate_app, run_generate};
+    #[cfg(feature = "plot")]
+    use crate::commands::plot::{get_plot_app, run_plot};
+    use crate::commands::scrub::{get_scrub_app, run_scrub};
+    use crate::commands::serve::{get_serve_app, run_serve};
     use clap::{ArgMatches, Command};
     use std::process;
 
@@ -28,24 +36,43 @@ mod cli {
     }
 
     pub fn get_app() -> Command {
-        Command::new("Vehicle Routing Problem Solver")
+        let app = Command::new("Vehicle Routing Problem Solver")
             .version("1.25.0")
             .author("Ilya Builuk <ilya.builuk@gmail.com>")
             .about("A command line interface to Vehicle Routing Problem solver")
             .subcommand(get_analyze_app())
             .subcommand(get_solve_app())
+            .subcommand(get_batch_app())
             .subcommand(get_import_app())
             .subcommand(get_check_app())
+            .subcommand(get_diff_app())
+            .subcommand(get_convert_app())
             .subcommand(get_generate_app())
+            .subcommand(get_scrub_app())
+            .subcommand(get_benchmark_app())
+            .subcommand(get_serve_app());
+
+        #[cfg(feature = "plot")]
+        let app = app.subcommand(get_plot_app());
+
+        app
     }
 
     pub fn run_subcommand(arg_matches: ArgMatches) {
         if let Err(err) = match arg_matches.subcommand() {
             Some(("analyze", analyze_matches)) => run_analyze(analyze_matches, create_write_buffer),
             Some(("solve", solve_matches)) => run_solve(solve_matches, create_write_buffer),
+            Some(("batch", batch_matches)) => run_batch(batch_matches),
             Some(("import", import_matches)) => run_import(import_matches),
-            Some(("check", check_matches)) => run_check(check_matches),
+            Some(("check", check_matches)) => run_check(check_matches, create_write_buffer),
+            Some(("diff", diff_matches)) => run_diff(diff_matches, create_write_buffer),
+            Some(("convert", convert_matches)) => run_convert(convert_matches, create_write_buffer),
             Some(("generate", generate_matches)) => run_generate(generate_matches),
+            #[cfg(feature = "plot")]
+            Some(("plot", plot_matches)) => run_plot(plot_matches),
+            Some(("scrub", scrub_matches)) => run_scrub(scrub_matches, create_write_buffer),
+            Some(("benchmark", benchmark_matches)) => run_benchmark(benchmark_matches, create_write_buffer),
+            Some(("serve", serve_matches)) => run_serve(serve_matches),
             _ => {
                 eprintln!("no subcommand was used. Use -h to print help information.");
                 process::exit(1);
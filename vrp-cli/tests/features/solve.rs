@@ -1,20 +1,21 @@
-use crate::extensions::solve::config::{create_builder_from_config, read_config};
+use crate::extensions::solve::config::{create_arc_penalties, create_builder_from_config, read_config};
 use std::fs::File;
 use std::io::BufReader;
 use std::sync::Arc;
 use vrp_core::prelude::Solver;
-use vrp_pragmatic::format::problem::PragmaticProblem;
+use vrp_pragmatic::format::problem::{PragmaticProblem, ProblemReadOptions};
 
 #[test]
 fn can_solve_problem_using_full_config() {
+    let reader = BufReader::new(File::open("../examples/data/config/config.full.json").unwrap());
+    // TODO override termination to avoid test timeout on CI
+    let mut config = read_config(reader).unwrap();
+    let options = ProblemReadOptions { arc_penalties: create_arc_penalties(&config), ..Default::default() };
     let problem = Arc::new(
-        BufReader::new(File::open("../examples/data/pragmatic/simple.basic.problem.json").unwrap())
+        (BufReader::new(File::open("../examples/data/pragmatic/simple.basic.problem.json").unwrap()), options)
             .read_pragmatic()
             .unwrap(),
     );
-    let reader = BufReader::new(File::open("../examples/data/config/config.full.json").unwrap());
-    // TODO override termination to avoid test timeout on CI
-    let mut config = read_config(reader).unwrap();
     if let Some(initial) = config.evolution.as_mut().and_then(|evolution| evolution.initial.as_mut()) {
         initial.alternatives.max_size = 1;
     }
@@ -12,6 +12,9 @@ pub fn create_empty_job() -> Job {
         value: None,
         group: None,
         compatibility: None,
+        priority: None,
+        category: None,
+        crew: None,
     }
 }
 
@@ -20,11 +23,17 @@ pub fn create_empty_job_task() -> JobTask {
 }
 
 pub fn create_empty_job_place() -> JobPlace {
-    JobPlace { location: Location::Coordinate { lat: 0.0, lng: 0.0 }, duration: 0.0, times: None, tag: None }
+    JobPlace {
+        location: Location::Coordinate { lat: 0.0, lng: 0.0 },
+        duration: 0.0,
+        times: None,
+        tag: None,
+        cost: None,
+    }
 }
 
 pub fn create_empty_plan() -> Plan {
-    Plan { jobs: vec![], relations: None, clustering: None }
+    Plan { jobs: vec![], relations: None, clustering: None, incompatibilities: None }
 }
 
 pub fn create_test_vehicle_type() -> VehicleType {
@@ -32,7 +41,7 @@ pub fn create_test_vehicle_type() -> VehicleType {
         type_id: "vehicle".to_string(),
         vehicle_ids: vec!["vehicle_1".to_string()],
         profile: VehicleProfile { matrix: "car".to_string(), scale: None },
-        costs: VehicleCosts { fixed: None, distance: 1., time: 0. },
+        costs: VehicleCosts { fixed: None, distance: 1., time: 0., tiers: None },
         shifts: vec![VehicleShift {
             start: ShiftStart {
                 earliest: "2020-05-01T09:00:00.00Z".to_string(),
@@ -43,6 +52,7 @@ pub fn create_test_vehicle_type() -> VehicleType {
             breaks: None,
             reloads: None,
             recharges: None,
+            capacity_change: None,
         }],
         capacity: vec![10],
         skills: None,
@@ -51,7 +61,7 @@ pub fn create_test_vehicle_type() -> VehicleType {
 }
 
 pub fn create_test_vehicle_profile() -> MatrixProfile {
-    MatrixProfile { name: "car".to_string(), speed: None }
+    MatrixProfile { name: "car".to_string(), speed: None, restrictions: None }
 }
 
 pub fn create_test_time_window() -> Vec<String> {
@@ -45,3 +45,26 @@ fn can_run_analyze_kmedoids() {
 
     run_subcommand(matches);
 }
+
+#[test]
+fn can_run_analyze_problem() {
+    let tmpfile = tempfile::NamedTempFile::new().unwrap();
+    let args = vec![
+        "vrp-cli",
+        "analyze",
+        "problem",
+        "pragmatic",
+        PRAGMATIC_PROBLEM_PATH,
+        "--out-format",
+        "json",
+        "--out-result",
+        tmpfile.path().to_str().unwrap(),
+    ];
+    let matches = get_app().try_get_matches_from(args).unwrap();
+
+    run_subcommand(matches);
+
+    let statistics: serde_json::Value = serde_json::from_reader(BufReader::new(tmpfile.as_file())).unwrap();
+    assert_eq!(statistics["jobs"]["total"], 3);
+    assert!(statistics["hints"].as_array().is_some_and(|hints| !hints.is_empty()));
+}
@@ -0,0 +1,40 @@
+use super::*;
+
+#[test]
+fn can_run_batch_solve() {
+    let out_dir = tempfile::tempdir().unwrap();
+    let args = vec![
+        "batch",
+        "../examples/data/pragmatic/simple.basic.problem.json",
+        "../examples/data/pragmatic/simple.index.problem.json",
+        "--matrix",
+        "../examples/data/pragmatic/simple.basic.matrix.json",
+        "--max-generations",
+        "10",
+        "--threads",
+        "2",
+        "--out-dir",
+        out_dir.path().to_str().unwrap(),
+    ];
+    let matches = get_batch_app().try_get_matches_from(args).unwrap();
+
+    run_batch(&matches).unwrap();
+
+    assert!(out_dir.path().join("simple.basic.problem.solution.json").exists());
+    assert!(out_dir.path().join("simple.index.problem.solution.json").exists());
+}
+
+#[test]
+fn can_detect_missing_output_directory() {
+    let args = vec![
+        "batch",
+        "../examples/data/pragmatic/simple.basic.problem.json",
+        "--matrix",
+        "../examples/data/pragmatic/simple.basic.matrix.json",
+        "--out-dir",
+        "non-existing-directory",
+    ];
+    let matches = get_batch_app().try_get_matches_from(args).unwrap();
+
+    assert!(run_batch(&matches).is_err());
+}
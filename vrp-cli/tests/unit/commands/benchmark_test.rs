@@ -0,0 +1,51 @@
+use super::*;
+use crate::cli::{get_app, run_subcommand};
+use vrp_cli::extensions::benchmark::BenchmarkReport;
+
+const SOLOMON_PROBLEM_PATH: &str = "../examples/data/scientific/solomon/C101.25.txt";
+const CONFIG_PATH: &str = "../examples/data/config/config.full.json";
+
+#[test]
+fn can_run_benchmark_with_two_configs() {
+    let instance_dir = tempfile::tempdir().unwrap();
+    std::fs::copy(SOLOMON_PROBLEM_PATH, instance_dir.path().join("C101.25.txt")).unwrap();
+    let out_result = tempfile::NamedTempFile::new().unwrap();
+    let out_csv = tempfile::NamedTempFile::new().unwrap();
+    let args = vec![
+        "vrp-cli",
+        "benchmark",
+        instance_dir.path().to_str().unwrap(),
+        "--format",
+        "solomon",
+        "--runs",
+        "2",
+        "--max-generations",
+        "1",
+        "--config",
+        CONFIG_PATH,
+        "--compare-config",
+        CONFIG_PATH,
+        "--out-result",
+        out_result.path().to_str().unwrap(),
+        "--out-csv",
+        out_csv.path().to_str().unwrap(),
+    ];
+    let matches = get_app().try_get_matches_from(args).unwrap();
+
+    run_subcommand(matches);
+
+    let report: BenchmarkReport =
+        serde_json::from_reader(BufReader::new(File::open(out_result.path()).unwrap())).unwrap();
+    let configs = report.records.iter().map(|record| record.config.as_str()).collect::<Vec<_>>();
+    assert_eq!(configs, vec!["config.full", "config.full-2"]);
+    assert_eq!(report.comparisons.len(), 1);
+    let csv = std::fs::read_to_string(out_csv.path()).unwrap();
+    assert_eq!(csv.lines().count(), 3);
+}
+
+#[test]
+fn can_detect_missing_format_argument_in_benchmark() {
+    let args = vec!["benchmark", "some_dir"];
+
+    assert!(get_benchmark_app().try_get_matches_from(args).is_err());
+}
@@ -1,3 +1,4 @@
+use super::*;
 use crate::cli::{get_app, run_subcommand};
 
 const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
@@ -21,3 +22,98 @@ fn can_run_check_solution() {
 
     run_subcommand(matches);
 }
+
+#[test]
+fn can_run_check_solution_with_report() {
+    let tmpfile = tempfile::NamedTempFile::new().unwrap();
+    let args = vec![
+        "vrp-cli",
+        "check",
+        "pragmatic",
+        "--problem-file",
+        PRAGMATIC_PROBLEM_PATH,
+        "--matrix",
+        PRAGMATIC_MATRIX_PATH,
+        "--solution-file",
+        PRAGMATIC_SOLUTION_PATH,
+        "--time-tolerance",
+        "5",
+        "--distance-tolerance",
+        "10",
+        "--out-report",
+        tmpfile.path().to_str().unwrap(),
+    ];
+    let matches = get_app().try_get_matches_from(args).unwrap();
+
+    run_subcommand(matches);
+
+    let report: serde_json::Value = serde_json::from_reader(tmpfile.reopen().unwrap()).unwrap();
+    assert_eq!(report["isFeasible"], serde_json::Value::Bool(true));
+    assert_eq!(report["errors"].as_array().map(|errors| errors.len()), Some(0));
+    assert!(report["warnings"].is_array());
+}
+
+#[test]
+fn can_reject_negative_tolerance() {
+    let args = vec![
+        "vrp-cli",
+        "check",
+        "pragmatic",
+        "--problem-file",
+        PRAGMATIC_PROBLEM_PATH,
+        "--solution-file",
+        PRAGMATIC_SOLUTION_PATH,
+        "--time-tolerance=-1",
+    ];
+    let matches = get_app().try_get_matches_from(args).unwrap();
+    let (_, matches) = matches.subcommand().unwrap();
+
+    let result = run_check(matches, create_write_buffer);
+
+    assert!(result.is_err_and(|err| err.to_string().contains("time tolerance should be a non-negative number")));
+}
+
+#[test]
+fn can_run_check_problem_with_report() {
+    let tmpfile = tempfile::NamedTempFile::new().unwrap();
+    let args = vec![
+        "vrp-cli",
+        "check",
+        "problem",
+        "pragmatic",
+        PRAGMATIC_PROBLEM_PATH,
+        "--matrix",
+        PRAGMATIC_MATRIX_PATH,
+        "--out-report",
+        tmpfile.path().to_str().unwrap(),
+    ];
+    let matches = get_app().try_get_matches_from(args).unwrap();
+
+    run_subcommand(matches);
+
+    let report: serde_json::Value = serde_json::from_reader(tmpfile.reopen().unwrap()).unwrap();
+    assert_eq!(report["isValid"], serde_json::Value::Bool(true));
+    assert_eq!(report["diagnostics"].as_array().map(|diagnostics| diagnostics.len()), Some(0));
+}
+
+#[test]
+fn can_return_error_when_checked_problem_is_invalid() {
+    let tmpfile = tempfile::NamedTempFile::new().unwrap();
+    let args = vec![
+        "vrp-cli",
+        "check",
+        "problem",
+        "pragmatic",
+        PRAGMATIC_MATRIX_PATH,
+        "--out-report",
+        tmpfile.path().to_str().unwrap(),
+    ];
+    let matches = get_app().try_get_matches_from(args).unwrap();
+    let (_, matches) = matches.subcommand().unwrap();
+
+    let result = run_check(matches, create_write_buffer);
+
+    assert!(result.is_err());
+    let report: serde_json::Value = serde_json::from_reader(tmpfile.reopen().unwrap()).unwrap();
+    assert_eq!(report["diagnostics"][0]["code"], serde_json::Value::String("E0000".to_string()));
+}
@@ -0,0 +1,122 @@
+use super::*;
+use crate::cli::{get_app, run_subcommand};
+
+const PRAGMATIC_SOLUTION_PATH: &str = "../examples/data/pragmatic/simple.basic.solution.json";
+
+fn can_run_convert_impl(format: &str) {
+    let tmpfile = tempfile::NamedTempFile::new().unwrap();
+    let args = vec![
+        "vrp-cli",
+        "convert",
+        PRAGMATIC_SOLUTION_PATH,
+        "--to",
+        format,
+        "--out-result",
+        tmpfile.path().to_str().unwrap(),
+    ];
+    let matches = get_app().try_get_matches_from(args).unwrap();
+
+    run_subcommand(matches);
+
+    let content = std::fs::read_to_string(tmpfile.path()).unwrap();
+    assert!(content.contains(format!("<{format} ").as_str()));
+}
+
+#[test]
+fn can_run_convert_to_kml() {
+    can_run_convert_impl("kml");
+}
+
+#[test]
+fn can_run_convert_to_gpx() {
+    can_run_convert_impl("gpx");
+}
+
+#[test]
+fn can_detect_unknown_format_in_convert() {
+    let args = vec!["convert", PRAGMATIC_SOLUTION_PATH, "--to", "geojson"];
+
+    assert!(get_convert_app().try_get_matches_from(args).is_err());
+}
+
+#[test]
+fn can_detect_missing_format_in_convert() {
+    let args = vec!["convert", PRAGMATIC_SOLUTION_PATH];
+
+    assert!(get_convert_app().try_get_matches_from(args).is_err());
+}
+
+#[test]
+fn can_run_convert_problem_to_solomon() {
+    let tmpfile = tempfile::NamedTempFile::new().unwrap();
+    let args = vec![
+        "vrp-cli",
+        "convert",
+        "../examples/data/pragmatic/simple.basic.problem.json",
+        "--to",
+        "solomon",
+        "--out-result",
+        tmpfile.path().to_str().unwrap(),
+    ];
+    let matches = get_app().try_get_matches_from(args).unwrap();
+
+    run_subcommand(matches);
+
+    let content = std::fs::read_to_string(tmpfile.path()).unwrap();
+    assert!(content.contains("VEHICLE"));
+}
+
+#[test]
+fn can_run_convert_problem_from_lilim() {
+    let out_problem = tempfile::NamedTempFile::new().unwrap();
+    let out_matrix = tempfile::NamedTempFile::new().unwrap();
+    let args = vec![
+        "convert",
+        "../examples/data/scientific/lilim/LC101.txt",
+        "--from",
+        "lilim",
+        "--to",
+        "pragmatic",
+        "--out-matrix",
+        out_matrix.path().to_str().unwrap(),
+        "--out-result",
+        out_problem.path().to_str().unwrap(),
+    ];
+    let matches = get_convert_app().try_get_matches_from(args).unwrap();
+
+    run_convert(&matches, create_write_buffer).unwrap();
+
+    let problem = deserialize_problem(BufReader::new(File::open(out_problem.path()).unwrap())).unwrap();
+    assert!(problem.plan.jobs.iter().all(|job| job.pickups.is_some() && job.deliveries.is_some()));
+    assert!(std::fs::read_to_string(out_matrix.path()).unwrap().contains("travelTimes"));
+}
+
+#[test]
+fn can_reject_unsupported_conversion() {
+    let args = vec!["convert", PRAGMATIC_SOLUTION_PATH, "--from", "solomon", "--to", "kml"];
+    let matches = get_convert_app().try_get_matches_from(args).unwrap();
+
+    let result = run_convert(&matches, create_write_buffer);
+
+    assert!(result.err().is_some_and(|err| err.to_string().contains("is not supported")));
+}
+
+#[test]
+fn can_run_convert_matrix_to_binary() {
+    let tmpfile = tempfile::NamedTempFile::new().unwrap();
+    let args = vec![
+        "vrp-cli",
+        "convert",
+        "../examples/data/pragmatic/simple.basic.matrix.json",
+        "--to",
+        "binary-matrix",
+        "--out-result",
+        tmpfile.path().to_str().unwrap(),
+    ];
+    let matches = get_app().try_get_matches_from(args).unwrap();
+
+    run_subcommand(matches);
+
+    let content = std::fs::read(tmpfile.path()).unwrap();
+    assert!(content.starts_with(b"VRPM"));
+}
@@ -0,0 +1,27 @@
+use super::*;
+use crate::cli::{get_app, run_subcommand};
+
+const PRAGMATIC_SOLUTION_PATH: &str = "../examples/data/pragmatic/simple.basic.solution.json";
+
+#[test]
+fn can_run_diff() {
+    let tmpfile = tempfile::NamedTempFile::new().unwrap();
+    let args = vec![
+        "vrp-cli",
+        "diff",
+        PRAGMATIC_SOLUTION_PATH,
+        PRAGMATIC_SOLUTION_PATH,
+        "--out-result",
+        tmpfile.path().to_str().unwrap(),
+    ];
+    let matches = get_app().try_get_matches_from(args).unwrap();
+
+    run_subcommand(matches);
+}
+
+#[test]
+fn can_detect_missing_solution_argument_in_diff() {
+    let args = vec!["diff", PRAGMATIC_SOLUTION_PATH];
+
+    assert!(get_diff_app().try_get_matches_from(args).is_err());
+}
@@ -28,3 +28,106 @@ fn can_generate_problem_from_args() {
     assert_eq!(problem.jobs.size(), 100);
     assert_eq!(problem.fleet.vehicles.len(), 10);
 }
+
+#[test]
+fn can_generate_problem_with_distribution_args() {
+    let tmpfile = tempfile::NamedTempFile::new().unwrap();
+    let args = vec![
+        "vrp-cli",
+        "generate",
+        "pragmatic",
+        "--prototypes",
+        PRAGMATIC_PROBLEM_PATH,
+        "--jobs-size",
+        "50",
+        "--vehicles-size",
+        "2",
+        "--area-size",
+        "5000",
+        "--distribution",
+        "clustered",
+        "--clusters",
+        "3",
+        "--cluster-radius",
+        "300",
+        "--time-window-tightness",
+        "0.5",
+        "--demand-distribution",
+        "normal",
+        "--out-result",
+        tmpfile.path().to_str().unwrap(),
+    ];
+    let matches = get_app().try_get_matches_from(args).unwrap();
+
+    run_subcommand(matches);
+
+    let problem = BufReader::new(tmpfile.as_file()).read_pragmatic().unwrap();
+    assert_eq!(problem.jobs.size(), 50);
+}
+
+#[test]
+fn can_reject_invalid_time_window_tightness() {
+    let args = vec![
+        "vrp-cli",
+        "generate",
+        "pragmatic",
+        "--prototypes",
+        PRAGMATIC_PROBLEM_PATH,
+        "--jobs-size",
+        "10",
+        "--vehicles-size",
+        "2",
+        "--time-window-tightness",
+        "1.5",
+    ];
+    let matches = get_app().try_get_matches_from(args).unwrap();
+    let (_, matches) = matches.subcommand().unwrap();
+
+    assert!(generate_problem_from_args(matches).is_err());
+}
+
+#[test]
+fn can_generate_problem_from_template_args() {
+    for template in ["food-delivery", "field-service", "line-haul"] {
+        let tmpfile = tempfile::NamedTempFile::new().unwrap();
+        let args = vec![
+            "vrp-cli",
+            "generate",
+            "pragmatic",
+            "--template",
+            template,
+            "--jobs-size",
+            "30",
+            "--vehicles-size",
+            "2",
+            "--out-result",
+            tmpfile.path().to_str().unwrap(),
+        ];
+        let matches = get_app().try_get_matches_from(args).unwrap();
+
+        run_subcommand(matches);
+
+        let problem = vrp_pragmatic::format::problem::deserialize_problem(BufReader::new(tmpfile.as_file())).unwrap();
+        assert_eq!(problem.plan.jobs.len(), 30);
+        assert_eq!(problem.fleet.vehicles.len(), 2);
+    }
+}
+
+#[test]
+fn can_reject_template_with_prototypes() {
+    let args = vec![
+        "vrp-cli",
+        "generate",
+        "pragmatic",
+        "--template",
+        "line-haul",
+        "--prototypes",
+        PRAGMATIC_PROBLEM_PATH,
+        "--jobs-size",
+        "30",
+        "--vehicles-size",
+        "2",
+    ];
+
+    assert!(get_app().try_get_matches_from(args).is_err());
+}
@@ -4,6 +4,8 @@ use vrp_cli::pragmatic::format::problem::PragmaticProblem;
 
 const CSV_JOBS_PATH: &str = "../examples/data/csv/jobs.csv";
 const VEHICLES_JOBS_PATH: &str = "../examples/data/csv/vehicles.csv";
+#[cfg(feature = "xlsx-format")]
+const XLSX_PROBLEM_PATH: &str = "../examples/data/xlsx/deliveries.xlsx";
 
 #[test]
 fn can_import_csv_problem_from_args() {
@@ -26,3 +28,25 @@ fn can_import_csv_problem_from_args() {
     assert_eq!(problem.jobs.size(), 3);
     assert_eq!(problem.fleet.vehicles.len(), 30);
 }
+
+#[test]
+#[cfg(feature = "xlsx-format")]
+fn can_import_xlsx_problem_from_args() {
+    let tmpfile = tempfile::NamedTempFile::new().unwrap();
+    let args = vec![
+        "vrp-cli",
+        "import",
+        "xlsx",
+        "--input-files",
+        XLSX_PROBLEM_PATH,
+        "--out-result",
+        tmpfile.path().to_str().unwrap(),
+    ];
+    let matches = get_app().try_get_matches_from(args).unwrap();
+
+    run_subcommand(matches);
+
+    let problem = BufReader::new(tmpfile.as_file()).read_pragmatic().unwrap();
+    assert_eq!(problem.jobs.size(), 4);
+    assert_eq!(problem.fleet.vehicles.len(), 2);
+}
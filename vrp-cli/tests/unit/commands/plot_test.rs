@@ -0,0 +1,36 @@
+use super::*;
+use crate::cli::{get_app, run_subcommand};
+
+const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
+const PRAGMATIC_SOLUTION_PATH: &str = "../examples/data/pragmatic/simple.basic.solution.json";
+
+#[test]
+fn can_run_plot() {
+    let tmpfile = tempfile::Builder::new().suffix(".svg").tempfile().unwrap();
+    let args = vec![
+        "vrp-cli",
+        "plot",
+        PRAGMATIC_SOLUTION_PATH,
+        "--problem",
+        PRAGMATIC_PROBLEM_PATH,
+        "--width",
+        "640",
+        "--height",
+        "480",
+        "--out",
+        tmpfile.path().to_str().unwrap(),
+    ];
+    let matches = get_app().try_get_matches_from(args).unwrap();
+
+    run_subcommand(matches);
+
+    let content = std::fs::read_to_string(tmpfile.path()).unwrap();
+    assert!(content.starts_with("<svg"));
+}
+
+#[test]
+fn can_detect_missing_out_argument_in_plot() {
+    let args = vec!["plot", PRAGMATIC_SOLUTION_PATH];
+
+    assert!(get_plot_app().try_get_matches_from(args).is_err());
+}
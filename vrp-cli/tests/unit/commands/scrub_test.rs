@@ -0,0 +1,37 @@
+use super::*;
+use crate::cli::{get_app, run_subcommand};
+
+const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
+
+#[test]
+fn can_run_scrub() {
+    let tmpfile = tempfile::NamedTempFile::new().unwrap();
+    let args = vec![
+        "vrp-cli",
+        "scrub",
+        PRAGMATIC_PROBLEM_PATH,
+        "--ids",
+        "hash",
+        "--salt",
+        "secret",
+        "--radius",
+        "50",
+        "--time-scale",
+        "0.5",
+        "--out-result",
+        tmpfile.path().to_str().unwrap(),
+    ];
+    let matches = get_app().try_get_matches_from(args).unwrap();
+
+    run_subcommand(matches);
+
+    let problem = deserialize_problem(BufReader::new(tmpfile.as_file())).unwrap();
+    assert!(problem.plan.jobs.iter().all(|job| job.id.starts_with("job_")));
+}
+
+#[test]
+fn can_detect_missing_problem_argument_in_scrub() {
+    let args = vec!["scrub", "--radius", "50"];
+
+    assert!(get_scrub_app().try_get_matches_from(args).is_err());
+}
@@ -0,0 +1,24 @@
+use super::*;
+
+#[test]
+fn can_get_serve_config() {
+    let args = vec!["serve", "--port", "9090", "--concurrency", "4", "--max-time", "60"];
+    let matches = get_serve_app().try_get_matches_from(args).unwrap();
+
+    let config = get_serve_config(&matches).unwrap();
+
+    assert_eq!(config.host, "127.0.0.1");
+    assert_eq!(config.port, 9090);
+    assert_eq!(config.concurrency, 4);
+    assert_eq!(config.max_time, Some(60));
+    assert_eq!(config.max_generations, None);
+    assert_eq!(config.job_ttl, 3600);
+}
+
+#[test]
+fn can_detect_invalid_serve_arguments() {
+    let get_config = |args: Vec<&str>| get_serve_config(&get_serve_app().try_get_matches_from(args).unwrap());
+
+    assert!(get_config(vec!["serve", "--concurrency", "0"]).is_err());
+    assert!(get_config(vec!["serve", "--port", "100000"]).is_err());
+}
@@ -1,10 +1,13 @@
 use super::*;
 use crate::cli::{get_app, run_subcommand};
+use vrp_pragmatic::format::problem::{deserialize_matrix, write_binary_matrix};
 
 const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
 const PRAGMATIC_MATRIX_PATH: &str = "../examples/data/pragmatic/simple.basic.matrix.json";
 const SOLOMON_PROBLEM_PATH: &str = "../examples/data/scientific/solomon/C101.25.txt";
 const LILIM_PROBLEM_PATH: &str = "../examples/data/scientific/lilim/LC101.txt";
+const CORDEAU_PROBLEM_PATH: &str = "../examples/data/scientific/cordeau/mdvrp.txt";
+const SEARCH_CONFIG_PATH: &str = "../examples/data/config/config.search.json";
 
 struct DummyWrite {}
 
@@ -42,6 +45,21 @@ fn can_solve_pragmatic_problem_with_matrix() {
     run_subcommand(get_app().try_get_matches_from(args).unwrap());
 }
 
+#[test]
+fn can_solve_pragmatic_problem_with_job_index_cache() {
+    let cache_dir = tempfile::tempdir().unwrap();
+    let cache_path = cache_dir.path().to_str().unwrap();
+    let args =
+        ["solve", "pragmatic", PRAGMATIC_PROBLEM_PATH, "--job-index-cache", cache_path, "--max-generations", "1"];
+    let get_cache_files = || std::fs::read_dir(cache_dir.path()).unwrap().count();
+
+    run_solve_without_writer(&get_solve_app().try_get_matches_from(args).unwrap());
+    assert_eq!(get_cache_files(), 1);
+
+    run_solve_without_writer(&get_solve_app().try_get_matches_from(args).unwrap());
+    assert_eq!(get_cache_files(), 1);
+}
+
 #[test]
 fn can_solve_pragmatic_problem_with_multiple_matrices() {
     const PRAGMATIC_BASICS_PATH: &str = "../examples/data/pragmatic/basics/";
@@ -63,6 +81,27 @@ fn can_solve_pragmatic_problem_with_multiple_matrices() {
     run_subcommand(get_app().try_get_matches_from(args).unwrap());
 }
 
+#[test]
+fn can_solve_pragmatic_problem_with_binary_matrix() {
+    let matrix_file = tempfile::NamedTempFile::new().unwrap();
+    let matrix = deserialize_matrix(BufReader::new(open_file(PRAGMATIC_MATRIX_PATH, "matrix"))).unwrap();
+    write_binary_matrix(&matrix, &mut BufWriter::new(matrix_file.reopen().unwrap())).unwrap();
+    let matrix_path = matrix_file.path().to_str().unwrap();
+
+    let args = vec![
+        "vrp-cli",
+        "solve",
+        "pragmatic",
+        PRAGMATIC_PROBLEM_PATH,
+        "--matrix",
+        matrix_path,
+        "--max-generations",
+        "1",
+    ];
+
+    run_subcommand(get_app().try_get_matches_from(args).unwrap());
+}
+
 #[test]
 fn can_solve_lilim_problem_with_multiple_limits() {
     let args = vec!["vrp-cli", "solve", "lilim", LILIM_PROBLEM_PATH, "--max-time", "300", "--max-generations", "1"];
@@ -70,14 +109,116 @@ fn can_solve_lilim_problem_with_multiple_limits() {
     run_subcommand(get_app().try_get_matches_from(args).unwrap());
 }
 
+#[test]
+fn can_solve_with_lower_bound_setting() {
+    let out_dir = tempfile::tempdir().unwrap();
+    let summary_path = out_dir.path().join("summary.json");
+    let matches = get_solomon_matches(&[
+        "--max-generations",
+        "1",
+        "--lower-bound",
+        "--out-summary",
+        summary_path.to_str().unwrap(),
+    ]);
+
+    run_solve_without_writer(&matches);
+
+    let summary: SolveSummary = serde_json::from_reader(File::open(summary_path).unwrap()).unwrap();
+    assert!(summary.lower_bound.is_some_and(|lower_bound| lower_bound.cost > 0.));
+}
+
+#[test]
+fn can_solve_pdptw_problem_with_init_solution() {
+    let out_dir = tempfile::tempdir().unwrap();
+    let out_path = out_dir.path().join("solution.txt");
+    let out_path = out_path.to_str().unwrap();
+    let args = ["solve", "pdptw", LILIM_PROBLEM_PATH, "-o", out_path, "--max-generations", "1"];
+    run_solve(&get_solve_app().try_get_matches_from(args).unwrap(), create_write_buffer).unwrap();
+
+    let args = ["solve", "pdptw", LILIM_PROBLEM_PATH, "--init-solution", out_path, "--max-generations", "1"];
+    let result =
+        run_solve(&get_solve_app().try_get_matches_from(args).unwrap(), |_| BufWriter::new(Box::new(DummyWrite {})));
+
+    assert!(result.is_ok());
+    assert!(std::fs::read_to_string(out_path).unwrap().starts_with("Route 1: "));
+}
+
+fn create_partial_pragmatic_solution(path: &std::path::Path) {
+    let mut solution: serde_json::Value =
+        serde_json::from_reader(File::open("../examples/data/pragmatic/simple.basic.solution.json").unwrap()).unwrap();
+    let mut unknown_tour = solution["tours"][0].clone();
+    unknown_tour["vehicleId"] = serde_json::Value::from("unknown_vehicle");
+    solution["tours"][0]["stops"][1]["activities"][0]["jobId"] = serde_json::Value::from("stale_job");
+    solution["tours"].as_array_mut().unwrap().push(unknown_tour);
+
+    serde_json::to_writer(File::create(path).unwrap(), &solution).unwrap();
+}
+
+fn solve_pragmatic_problem_with_partial_init_solution(init_mode: &str) {
+    let out_dir = tempfile::tempdir().unwrap();
+    let init_path = out_dir.path().join("init.json");
+    let out_path = out_dir.path().join("solution.json");
+    create_partial_pragmatic_solution(&init_path);
+    let args = [
+        "solve",
+        "pragmatic",
+        PRAGMATIC_PROBLEM_PATH,
+        "--init-solution",
+        init_path.to_str().unwrap(),
+        "--init-mode",
+        init_mode,
+        "--max-generations",
+        "1",
+        "-o",
+        out_path.to_str().unwrap(),
+    ];
+
+    run_solve(&get_solve_app().try_get_matches_from(args).unwrap(), create_write_buffer).unwrap();
+
+    let solution = deserialize_solution(BufReader::new(File::open(out_path).unwrap())).unwrap();
+    assert!(solution.unassigned.is_none_or(|unassigned| unassigned.is_empty()));
+    let job_ids = solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities().iter())
+        .map(|activity| activity.job_id.as_str())
+        .filter(|job_id| job_id.starts_with("job"))
+        .collect::<Vec<_>>();
+    assert_eq!(job_ids.len(), 4);
+    if init_mode != "reoptimize" {
+        let init_order = job_ids.iter().filter(|job_id| **job_id != "job1").cloned().collect::<Vec<_>>();
+        assert_eq!(init_order, vec!["job2", "job3", "job3"]);
+    }
+}
+
+#[test]
+fn can_solve_pragmatic_problem_with_partial_init_solution() {
+    ["keep", "repair", "reoptimize"].into_iter().for_each(solve_pragmatic_problem_with_partial_init_solution);
+}
+
 #[test]
 fn can_solve_solomon_problem_with_generation_limit() {
     run_solve_without_writer(&get_solomon_matches(&["--max-generations", "1"]));
 }
 
+#[test]
+fn can_solve_cordeau_problem() {
+    let out_dir = tempfile::tempdir().unwrap();
+    let out_path = out_dir.path().join("solution.res");
+    let out_path = out_path.to_str().unwrap();
+    let args = ["solve", "cordeau", CORDEAU_PROBLEM_PATH, "-o", out_path, "--max-generations", "1"];
+
+    run_solve(&get_solve_app().try_get_matches_from(args).unwrap(), create_write_buffer).unwrap();
+
+    let result = std::fs::read_to_string(out_path).unwrap();
+    assert!(result.lines().next().unwrap().parse::<f64>().is_ok());
+    assert!(result.lines().skip(1).all(|line| line.split_whitespace().nth(4) == Some("0")));
+}
+
 #[test]
 fn can_require_problem_path() {
-    for format in &["pragmatic", "solomon", "lilim", "tsplib"] {
+    for format in &["pragmatic", "solomon", "lilim", "pdptw", "tsplib", "cordeau"] {
         get_solve_app().try_get_matches_from(vec!["solve", format]).unwrap_err();
     }
 }
@@ -114,6 +255,17 @@ fn can_specify_log_setting() {
     get_solve_app().try_get_matches_from(args).unwrap();
 }
 
+#[test]
+fn can_solve_solomon_problem_with_json_log_format() {
+    run_solve_without_writer(&get_solomon_matches(&["--max-generations", "1", "--log-format", "json"]));
+}
+
+#[test]
+fn can_reject_unknown_log_format() {
+    let args = vec!["solve", "solomon", SOLOMON_PROBLEM_PATH, "--log-format", "xml"];
+    get_solve_app().try_get_matches_from(args).unwrap_err();
+}
+
 #[test]
 fn can_specify_locations_setting() {
     let args = vec!["solve", "solomon", SOLOMON_PROBLEM_PATH, "--get-locations"];
@@ -130,6 +282,64 @@ fn can_specify_heuristic_setting() {
     }
 }
 
+#[test]
+fn can_solve_with_search_config() {
+    let matches = get_solomon_matches(&["--max-generations", "2", "--search-config", SEARCH_CONFIG_PATH]);
+
+    run_solve_without_writer(&matches);
+}
+
+#[test]
+fn can_specify_search_config_setting() {
+    for (params, result) in [
+        (vec!["--search-config", SEARCH_CONFIG_PATH], Some(())),
+        (vec!["--search-config", SEARCH_CONFIG_PATH, "--config", SEARCH_CONFIG_PATH], None),
+    ] {
+        let args = [&["solve", "pragmatic", PRAGMATIC_PROBLEM_PATH], params.as_slice()].concat();
+        assert_eq!(get_solve_app().try_get_matches_from(args).ok().map(|_| ()), result);
+    }
+}
+
+#[test]
+fn can_reject_search_config_with_dynamic_heuristic() {
+    let matches = get_solomon_matches(&["--search-config", SEARCH_CONFIG_PATH, "--heuristic", "dynamic"]);
+
+    let result = run_solve(&matches, |_| BufWriter::new(Box::new(DummyWrite {})));
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn can_dump_effective_config_and_solve_with_it() {
+    let out_dir = tempfile::tempdir().unwrap();
+    let dump_path = out_dir.path().join("solver.toml");
+    let dump_path = dump_path.to_str().unwrap();
+
+    let matches = get_solomon_matches(&["--max-generations", "2", "--search-mode", "deep", "--dump-config", dump_path]);
+    run_solve_without_writer(&matches);
+
+    let config = read_config_file(dump_path).unwrap();
+    assert_eq!(config.termination.as_ref().and_then(|termination| termination.max_generations), Some(2));
+    assert!(matches!(
+        config.evolution.as_ref().and_then(|evolution| evolution.population.as_ref()),
+        Some(PopulationType::Elitism { max_size: Some(4), .. })
+    ));
+
+    run_solve_without_writer(&get_solomon_matches(&["--config", dump_path]));
+}
+
+#[test]
+fn can_reject_invalid_config() {
+    let out_dir = tempfile::tempdir().unwrap();
+    let config_path = out_dir.path().join("solver.toml");
+    std::fs::write(&config_path, "[termination]\nmaxGenerations = 0\n").unwrap();
+
+    let matches = get_solomon_matches(&["--config", config_path.to_str().unwrap()]);
+    let result = run_solve(&matches, |_| BufWriter::new(Box::new(DummyWrite {})));
+
+    assert!(result.unwrap_err().to_string().contains("maxGenerations"));
+}
+
 #[test]
 fn can_specify_parallelism() {
     for (params, result) in [
@@ -178,3 +388,187 @@ fn can_specify_cv() {
         assert_eq!(min_cv, result);
     }
 }
+
+#[test]
+fn can_specify_osrm_matrix() {
+    let args = ["solve", "pragmatic", PRAGMATIC_PROBLEM_PATH, "--matrix", "osrm:http://localhost:5000"];
+    let matches = get_solve_app().try_get_matches_from(args).unwrap();
+
+    assert_eq!(get_osrm_url(&matches), Some("http://localhost:5000"));
+    assert!(get_matrix_files(&matches).is_none());
+}
+
+#[test]
+fn can_reject_osrm_matrix_for_non_pragmatic_format() {
+    let matches = get_solomon_matches(&["--matrix", "osrm:http://localhost:5000"]);
+
+    let result = run_solve(&matches, |_| BufWriter::new(Box::new(DummyWrite {})));
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn can_reject_geometry_without_osrm_matrix() {
+    let args = ["solve", "pragmatic", PRAGMATIC_PROBLEM_PATH, "--geometry", "--max-generations", "1"];
+    let matches = get_solve_app().try_get_matches_from(args).unwrap();
+
+    let result = run_solve(&matches, |_| BufWriter::new(Box::new(DummyWrite {})));
+
+    assert!(result.is_err_and(|err| err.to_string().contains("leg geometries require")));
+}
+
+#[test]
+fn can_write_solution_as_geojson() {
+    let out_dir = tempfile::tempdir().unwrap();
+    let out_path = out_dir.path().join("solution.geojson");
+    let out_path = out_path.to_str().unwrap();
+    let args = ["solve", "pragmatic", PRAGMATIC_PROBLEM_PATH, "-o", out_path, "--out-format", "geojson"];
+    let matches = get_solve_app().try_get_matches_from([&args[..], &["--max-generations", "1"]].concat()).unwrap();
+
+    run_solve(&matches, create_write_buffer).unwrap();
+
+    let geo_json: serde_json::Value = serde_json::from_reader(File::open(out_path).unwrap()).unwrap();
+    assert_eq!(geo_json["type"], "FeatureCollection");
+    assert!(geo_json["features"].as_array().is_some_and(|features| !features.is_empty()));
+}
+
+#[test]
+fn can_reject_geojson_output_for_non_pragmatic_format() {
+    let matches = get_solomon_matches(&["--out-format", "geojson"]);
+
+    let result = run_solve(&matches, |_| BufWriter::new(Box::new(DummyWrite {})));
+
+    assert!(result.is_err_and(|err| err.to_string().contains("geojson output is not supported")));
+}
+
+#[test]
+fn can_write_solution_as_csv() {
+    let out_dir = tempfile::tempdir().unwrap();
+    let out_path = out_dir.path().join("plan.csv");
+    let out_path = out_path.to_str().unwrap();
+    let args = ["solve", "pragmatic", PRAGMATIC_PROBLEM_PATH, "--out-csv", out_path, "--max-generations", "1"];
+    let matches = get_solve_app().try_get_matches_from(args).unwrap();
+
+    run_solve(&matches, |_| BufWriter::new(Box::new(DummyWrite {}))).unwrap();
+
+    let content = std::fs::read_to_string(out_path).unwrap();
+    let mut rows = content.lines();
+    assert_eq!(rows.next(), Some("tour_id,seq,job_id,location,arrival,departure,wait,load_in,load_out,distance"));
+    assert!(rows.count() > 0);
+}
+
+#[test]
+fn can_reject_csv_output_for_non_pragmatic_format() {
+    let matches = get_solomon_matches(&["--out-csv", "plan.csv"]);
+
+    let result = run_solve(&matches, |_| BufWriter::new(Box::new(DummyWrite {})));
+
+    assert!(result.is_err_and(|err| err.to_string().contains("csv output is not supported")));
+}
+
+#[test]
+fn can_write_solution_as_ics() {
+    let out_dir = tempfile::tempdir().unwrap();
+    let out_path = out_dir.path().to_str().unwrap();
+    let args = ["solve", "pragmatic", PRAGMATIC_PROBLEM_PATH, "--out-ics", out_path, "--max-generations", "1"];
+    let matches = get_solve_app().try_get_matches_from(args).unwrap();
+
+    run_solve(&matches, |_| BufWriter::new(Box::new(DummyWrite {}))).unwrap();
+
+    let files = std::fs::read_dir(out_dir.path()).unwrap().map(|entry| entry.unwrap().path()).collect::<Vec<_>>();
+    assert!(!files.is_empty());
+    assert!(files.iter().all(|path| path.extension().is_some_and(|ext| ext == "ics")));
+}
+
+#[test]
+fn can_reject_ics_output_for_non_pragmatic_format() {
+    let matches = get_solomon_matches(&["--out-ics", "calendar"]);
+
+    let result = run_solve(&matches, |_| BufWriter::new(Box::new(DummyWrite {})));
+
+    assert!(result.is_err_and(|err| err.to_string().contains("iCalendar output is not supported")));
+}
+
+#[test]
+fn can_write_solution_with_merged_stops() {
+    let out_dir = tempfile::tempdir().unwrap();
+    let out_path = out_dir.path().join("solution.json");
+    let out_path = out_path.to_str().unwrap();
+    let args = ["solve", "pragmatic", PRAGMATIC_PROBLEM_PATH, "-o", out_path, "--merge-stops", "100000"];
+    let matches = get_solve_app().try_get_matches_from([&args[..], &["--max-generations", "1"]].concat()).unwrap();
+
+    run_solve(&matches, create_write_buffer).unwrap();
+
+    let solution = deserialize_solution(BufReader::new(File::open(out_path).unwrap())).unwrap();
+    assert!(!solution.tours.is_empty());
+    assert!(solution.tours.iter().all(|tour| tour.stops.len() == 1));
+}
+
+#[test]
+fn can_reject_merge_stops_for_non_pragmatic_format() {
+    let matches = get_solomon_matches(&["--merge-stops", "10"]);
+
+    let result = run_solve(&matches, |_| BufWriter::new(Box::new(DummyWrite {})));
+
+    assert!(result.is_err_and(|err| err.to_string().contains("stop merging is not supported")));
+}
+
+#[test]
+fn can_reject_merge_stops_with_invalid_radius() {
+    for radius in ["--merge-stops=-1", "--merge-stops=abc"] {
+        let matches =
+            get_solve_app().try_get_matches_from(["solve", "pragmatic", PRAGMATIC_PROBLEM_PATH, radius]).unwrap();
+
+        let result = run_solve(&matches, |_| BufWriter::new(Box::new(DummyWrite {})));
+
+        assert!(result.is_err(), "radius: '{radius}'");
+    }
+}
+
+#[test]
+fn can_reject_merge_stops_with_check() {
+    let args = ["solve", "pragmatic", PRAGMATIC_PROBLEM_PATH, "--merge-stops", "10", "--check"];
+    let matches = get_solve_app().try_get_matches_from(args).unwrap();
+
+    let result = run_solve(&matches, |_| BufWriter::new(Box::new(DummyWrite {})));
+
+    assert!(result.is_err_and(|err| err.to_string().contains("solution check is not supported with merged stops")));
+}
+
+#[test]
+fn can_write_summary_with_best_known_gap() {
+    let out_dir = tempfile::tempdir().unwrap();
+    let out_path = out_dir.path().join("summary.json");
+    let out_path = out_path.to_str().unwrap();
+    let problem_path = "../examples/data/scientific/tsplib/A-n32-k5.vrp";
+    let args = ["solve", "tsplib", problem_path, "--out-summary", out_path, "--max-generations", "1"];
+    let matches = get_solve_app().try_get_matches_from(args).unwrap();
+
+    run_solve(&matches, |_| BufWriter::new(Box::new(DummyWrite {}))).unwrap();
+
+    let summary: SolveSummary = serde_json::from_str(&std::fs::read_to_string(out_path).unwrap()).unwrap();
+    assert_eq!(summary.instance, "A-n32-k5");
+    assert_eq!(summary.format, "tsplib");
+    let best_known = summary.best_known.expect("no best known solution");
+    assert_eq!(best_known.cost, 784.);
+    assert!((best_known.gap - (summary.cost - 784.) / 784. * 100.).abs() < 1E-6);
+    assert_eq!(summary.generations, Some(1));
+    assert_eq!(summary.termination.as_deref(), Some("max-generation"));
+    assert!(!summary.trajectory.is_empty());
+}
+
+#[test]
+fn can_solve_tiny_problem_exactly_without_search() {
+    let out_dir = tempfile::tempdir().unwrap();
+    let out_path = out_dir.path().join("summary.json");
+    let out_path = out_path.to_str().unwrap();
+    let problem_path = "../examples/data/pragmatic/basics/priorities.value.problem.json";
+    let args = ["solve", "pragmatic", problem_path, "--out-summary", out_path, "--max-generations", "100"];
+    let matches = get_solve_app().try_get_matches_from(args).unwrap();
+
+    run_solve(&matches, |_| BufWriter::new(Box::new(DummyWrite {}))).unwrap();
+
+    let summary: SolveSummary = serde_json::from_str(&std::fs::read_to_string(out_path).unwrap()).unwrap();
+    assert_eq!(summary.tours, 1);
+    assert_eq!(summary.generations, None);
+}
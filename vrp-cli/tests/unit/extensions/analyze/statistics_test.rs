@@ -0,0 +1,97 @@
+use super::*;
+use std::fs::File;
+use std::io::BufReader;
+use vrp_pragmatic::format::problem::{deserialize_matrix, deserialize_problem};
+
+fn read_problem(name: &str) -> Problem {
+    let path = format!("../examples/data/pragmatic/{name}");
+    deserialize_problem(BufReader::new(File::open(path).unwrap())).unwrap()
+}
+
+fn create_matrix(travel_times: Vec<i64>, distances: Vec<i64>) -> Matrix {
+    Matrix { profile: Some("car".to_string()), timestamp: None, travel_times, distances, error_codes: None }
+}
+
+#[test]
+fn can_get_job_and_fleet_statistics() {
+    let problem = read_problem("simple.basic.problem.json");
+
+    let statistics = get_problem_statistics(&problem, None).unwrap();
+
+    assert_eq!(statistics.jobs.total, 3);
+    assert_eq!(statistics.jobs.tasks, 4);
+    assert_eq!((statistics.jobs.deliveries, statistics.jobs.pickups, statistics.jobs.pickup_deliveries), (1, 1, 1));
+    assert_eq!(statistics.fleet.vehicles, 1);
+    assert_eq!(statistics.fleet.profiles, vec!["normal_car".to_string()]);
+    assert!(statistics.matrices.is_empty());
+}
+
+#[test]
+fn can_get_time_window_statistics() {
+    let problem = read_problem("simple.basic.problem.json");
+
+    let statistics = get_problem_statistics(&problem, None).unwrap().time_windows;
+
+    assert_eq!(statistics.constrained + statistics.unconstrained, 4);
+    assert_eq!(statistics.histogram.len(), HISTOGRAM_BINS);
+    assert_eq!(statistics.histogram.iter().sum::<usize>(), statistics.constrained);
+    assert!(statistics.mean_tightness.is_some_and(|tightness| tightness > 0. && tightness <= 1.));
+}
+
+#[test]
+fn can_get_demand_statistics_and_hints() {
+    let problem = read_problem("simple.basic.problem.json");
+
+    let statistics = get_problem_statistics(&problem, None).unwrap();
+
+    // NOTE pickup and delivery job demand is counted once
+    assert_eq!(statistics.demand.total_demand, vec![3]);
+    assert_eq!(statistics.demand.fleet_capacity, vec![10]);
+    assert_eq!(statistics.demand.ratio, vec![Some(0.3)]);
+    assert!(statistics.hints.iter().any(|hint| hint.contains("--max-time 30")));
+}
+
+#[test]
+fn can_get_geography_statistics() {
+    let problem = read_problem("simple.basic.problem.json");
+
+    let geography = get_problem_statistics(&problem, None).unwrap().geography.unwrap();
+
+    assert_eq!(geography.unique_locations, 4);
+    assert!(geography.diagonal > 5000. && geography.diagonal < 5500.);
+    assert!(geography.mean_distance_to_center < geography.diagonal);
+}
+
+parameterized_test! {can_get_matrix_asymmetry, (travel_times, distances, expected), {
+    let statistics = get_matrix_statistics(&create_matrix(travel_times, distances)).unwrap();
+
+    assert_eq!(statistics.size, 2);
+    assert_eq!((statistics.duration_asymmetry, statistics.distance_asymmetry), expected);
+}}
+
+can_get_matrix_asymmetry! {
+    case01_symmetric: (vec![0, 10, 10, 0], vec![0, 100, 100, 0], (0., 0.)),
+    case02_asymmetric: (vec![0, 10, 5, 0], vec![0, 100, 75, 0], (0.5, 0.25)),
+}
+
+#[test]
+fn can_reject_non_square_matrix() {
+    assert!(get_matrix_statistics(&create_matrix(vec![0, 1, 2], vec![0, 1, 2])).is_err());
+}
+
+#[test]
+fn can_use_matrix_and_write_statistics_as_text() {
+    let problem = read_problem("simple.basic.problem.json");
+    let matrix_path = "../examples/data/pragmatic/simple.basic.matrix.json";
+    let matrix = deserialize_matrix(BufReader::new(File::open(matrix_path).unwrap())).unwrap();
+    let statistics = get_problem_statistics(&problem, Some(&[matrix])).unwrap();
+    let mut writer = BufWriter::new(Vec::new());
+
+    write_problem_statistics(&mut writer, &statistics).unwrap();
+
+    let text = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+    assert_eq!(statistics.matrices.len(), 1);
+    assert!(text.contains("jobs: 3"));
+    assert!(text.contains("matrix 'normal_car': 4 locations"));
+    assert!(text.contains("hints:"));
+}
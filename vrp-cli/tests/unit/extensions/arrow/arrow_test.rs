@@ -0,0 +1,141 @@
+use super::*;
+use arrow::array::{Array, AsArray, Float64Array, Int64Array, StringArray};
+use arrow::datatypes::{DataType, Field, Float64Type, Int64Type, Schema, UInt64Type};
+use std::fs::File;
+use std::io::BufReader;
+use std::sync::Arc;
+use vrp_pragmatic::format::Location;
+use vrp_pragmatic::format::solution::{deserialize_solution, get_stop_records};
+
+const PRAGMATIC_SOLUTION_PATH: &str = "../examples/data/pragmatic/simple.basic.solution.json";
+
+fn create_jobs_batch() -> RecordBatch {
+    let schema = Schema::new(vec![
+        Field::new("id", DataType::Utf8, false),
+        Field::new("lat", DataType::Float64, true),
+        Field::new("lng", DataType::Float64, true),
+        Field::new("location_index", DataType::Int64, true),
+        Field::new("demand", DataType::Int64, true),
+        Field::new("duration", DataType::Float64, true),
+        Field::new("tw_start", DataType::Utf8, true),
+        Field::new("tw_end", DataType::Utf8, true),
+    ]);
+
+    RecordBatch::try_new(
+        Arc::new(schema),
+        vec![
+            Arc::new(StringArray::from(vec!["job1", "job2", "job1", "job3"])),
+            Arc::new(Float64Array::from(vec![Some(52.5), None, Some(52.6), Some(52.7)])),
+            Arc::new(Float64Array::from(vec![Some(13.3), None, Some(13.4), Some(13.5)])),
+            Arc::new(Int64Array::from(vec![None, Some(3), None, None])),
+            Arc::new(Int64Array::from(vec![Some(1), Some(-2), Some(-1), None])),
+            Arc::new(Float64Array::from(vec![Some(60.), Some(120.), None, Some(30.)])),
+            Arc::new(StringArray::from(vec![Some("2020-07-04T09:00:00Z"), None, None, None])),
+            Arc::new(StringArray::from(vec![Some("2020-07-04T18:00:00Z"), None, None, None])),
+        ],
+    )
+    .unwrap()
+}
+
+fn read_solution() -> Solution {
+    deserialize_solution(BufReader::new(File::open(PRAGMATIC_SOLUTION_PATH).unwrap())).unwrap()
+}
+
+#[test]
+fn can_read_jobs_from_batches() {
+    let jobs = read_jobs_from_batches(&[create_jobs_batch()]).unwrap();
+
+    assert_eq!(jobs.iter().map(|job| job.id.as_str()).collect::<Vec<_>>(), vec!["job1", "job2", "job3"]);
+
+    let pickup = &jobs[0].pickups.as_ref().unwrap()[0];
+    assert_eq!(pickup.demand, Some(vec![1]));
+    assert_eq!(pickup.places[0].location, Location::Coordinate { lat: 52.5, lng: 13.3 });
+    assert_eq!(pickup.places[0].duration, 60.);
+    assert_eq!(
+        pickup.places[0].times,
+        Some(vec![vec!["2020-07-04T09:00:00Z".to_string(), "2020-07-04T18:00:00Z".to_string()]])
+    );
+    let delivery = &jobs[0].deliveries.as_ref().unwrap()[0];
+    assert_eq!(delivery.demand, Some(vec![1]));
+    assert_eq!(delivery.places[0].duration, 0.);
+
+    let delivery = &jobs[1].deliveries.as_ref().unwrap()[0];
+    assert_eq!(delivery.demand, Some(vec![2]));
+    assert_eq!(delivery.places[0].location, Location::Reference { index: 3 });
+
+    let service = &jobs[2].services.as_ref().unwrap()[0];
+    assert_eq!(service.demand, None);
+    assert!(jobs[2].pickups.is_none() && jobs[2].deliveries.is_none());
+}
+
+#[test]
+fn can_detect_invalid_jobs_table() {
+    let batch = create_jobs_batch();
+    let without_id = batch.project(&[1, 2]).unwrap();
+    let without_location = batch.project(&[0, 4]).unwrap();
+    let without_tw_end = batch.project(&[0, 1, 2, 3, 6]).unwrap();
+
+    let get_error = |batch: RecordBatch| read_jobs_from_batches(&[batch]).err().map(|err| err.to_string());
+
+    assert_eq!(get_error(without_id), Some("missing column: 'id'".to_string()));
+    assert_eq!(get_error(without_location), Some("row 0: job 'job1' has no valid location".to_string()));
+    assert_eq!(get_error(without_tw_end), Some("row 0: job 'job1' has incomplete time window".to_string()));
+}
+
+#[test]
+fn can_write_and_read_parquet_jobs() {
+    let batch = create_jobs_batch();
+    let file = tempfile::NamedTempFile::new().unwrap();
+    write_parquet(&batch, file.reopen().unwrap()).unwrap();
+
+    let jobs = read_parquet_jobs(file.reopen().unwrap()).unwrap();
+
+    assert_eq!(
+        serde_json::to_value(&jobs).unwrap(),
+        serde_json::to_value(read_jobs_from_batches(&[batch]).unwrap()).unwrap()
+    );
+}
+
+#[test]
+fn can_convert_solution_into_stops_batch() {
+    let solution = read_solution();
+    let records = get_stop_records(&solution).unwrap();
+
+    let batch = to_stops_batch(&solution).unwrap();
+
+    assert_eq!(batch.schema(), get_stops_schema());
+    assert_eq!(batch.num_rows(), records.len());
+    records.iter().enumerate().for_each(|(row, record)| {
+        assert_eq!(batch.column(0).as_string::<i32>().value(row), record.tour_id);
+        assert_eq!(batch.column(1).as_primitive::<UInt64Type>().value(row), record.seq as u64);
+        let job_ids = batch.column(2).as_list::<i32>().value(row);
+        assert_eq!(job_ids.as_string::<i32>().iter().flatten().collect::<Vec<_>>(), record.job_ids);
+        match record.location {
+            Some(Location::Coordinate { lat, lng }) => {
+                assert_eq!(batch.column(3).as_primitive::<Float64Type>().value(row), lat);
+                assert_eq!(batch.column(4).as_primitive::<Float64Type>().value(row), lng);
+                assert!(batch.column(5).is_null(row));
+            }
+            _ => unreachable!("unexpected location in test data"),
+        }
+        assert_eq!(batch.column(8).as_primitive::<Int64Type>().value(row), record.wait);
+        assert_eq!(batch.column(11).as_primitive::<Int64Type>().value(row), record.distance);
+    });
+}
+
+#[test]
+fn can_write_parquet_stops() {
+    let solution = read_solution();
+    let file = tempfile::NamedTempFile::new().unwrap();
+
+    write_parquet_stops(&solution, file.reopen().unwrap()).unwrap();
+
+    let batches = ParquetRecordBatchReaderBuilder::try_new(file.reopen().unwrap())
+        .unwrap()
+        .build()
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(batches.len(), 1);
+    assert_eq!(batches[0], to_stops_batch(&solution).unwrap());
+}
@@ -0,0 +1,118 @@
+use super::*;
+use crate::extensions::solve::formats::get_formats;
+use crate::extensions::solve::summary::BestKnownSummary;
+use std::io::BufWriter;
+use vrp_core::utils::DefaultRandom;
+
+fn create_summary(cost: Float, tours: usize, best_known: Option<Float>) -> SolveSummary {
+    SolveSummary {
+        instance: "C101".to_string(),
+        format: "solomon".to_string(),
+        cost,
+        tours,
+        unassigned: 0,
+        duration: 1.,
+        generations: None,
+        best_known: best_known.map(|bks| BestKnownSummary { cost: bks, tours: None, gap: (cost - bks) / bks * 100. }),
+        lower_bound: None,
+        termination: None,
+        trajectory: vec![],
+        operators: vec![],
+        cpu_time: None,
+        memory_peak: None,
+        memory_estimate: None,
+        memory_shrinks: None,
+        properties: Default::default(),
+    }
+}
+
+fn create_record_with_costs(config: &str, costs: &[Float]) -> BenchmarkRecord {
+    let summaries = costs.iter().map(|cost| create_summary(*cost, 10, Some(100.))).collect::<Vec<_>>();
+
+    create_record(config, summaries.as_slice())
+}
+
+#[test]
+fn can_create_record_from_summaries() {
+    let summaries = vec![create_summary(110., 11, Some(100.)), create_summary(120., 10, Some(100.))];
+
+    let record = create_record("default", summaries.as_slice());
+
+    assert_eq!(record.instance, "C101");
+    assert_eq!(record.config, "default");
+    assert_eq!(record.runs, 2);
+    assert_eq!(record.min_cost, 110.);
+    assert_eq!(record.mean_cost, 115.);
+    assert_eq!(record.max_cost, 120.);
+    assert!((record.stdev_cost - 5.).abs() < 1E-6);
+    assert_eq!(record.min_tours, 10);
+    assert_eq!(record.best_known_cost, Some(100.));
+    assert!((record.min_gap.unwrap() - 10.).abs() < 1E-6);
+    assert!((record.mean_gap.unwrap() - 15.).abs() < 1E-6);
+}
+
+#[test]
+fn can_create_record_without_best_known() {
+    let record = create_record("default", &[create_summary(110., 11, None)]);
+
+    assert_eq!(record.best_known_cost, None);
+    assert_eq!(record.min_gap, None);
+    assert_eq!(record.mean_gap, None);
+}
+
+#[test]
+fn can_create_comparison() {
+    let baseline = create_record_with_costs("baseline", &[100., 120.]);
+    let candidate = create_record_with_costs("candidate", &[90., 110.]);
+
+    let comparison = create_comparison(&baseline, &candidate);
+
+    assert_eq!(comparison.baseline, "baseline");
+    assert_eq!(comparison.candidate, "candidate");
+    assert!((comparison.min_cost_diff + 10.).abs() < 1E-6);
+    assert!((comparison.mean_cost_diff + 100. / 11.).abs() < 1E-6);
+}
+
+#[test]
+fn can_write_benchmark_csv() {
+    let report = BenchmarkReport {
+        records: vec![create_record(
+            "default",
+            &[create_summary(110., 11, Some(100.)), create_summary(110., 11, None)],
+        )],
+        comparisons: vec![],
+    };
+    let mut writer = BufWriter::new(Vec::new());
+
+    write_benchmark_csv(&report, &mut writer).unwrap();
+
+    let csv = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+    let lines = csv.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].starts_with("instance,config,runs,min_cost"));
+    assert_eq!(lines[1], "C101,default,2,110.0000,110.0000,110.0000,0.0000,11,0,1.0000,100.0000,10.0000,10.0000");
+}
+
+#[test]
+fn can_run_benchmark_and_skip_unreadable_instances() {
+    let instances = vec![
+        PathBuf::from("../examples/data/scientific/solomon/C101.25.txt"),
+        PathBuf::from("../examples/data/scientific/solomon/not_existing.txt"),
+    ];
+    let formats = get_formats(false, Arc::new(DefaultRandom::default()), Default::default());
+    let (problem_reader, ..) = formats.get("solomon").unwrap();
+    let configs = vec![
+        BenchmarkConfig { name: "first".to_string(), config: Config::default() },
+        BenchmarkConfig { name: "second".to_string(), config: Config::default() },
+    ];
+    let settings =
+        BenchmarkSettings { format: "solomon".to_string(), runs: 2, max_time: None, max_generations: Some(1) };
+
+    let report = run_benchmark(instances.as_slice(), problem_reader, &configs, &settings, Arc::new(|_| {})).unwrap();
+
+    assert_eq!(report.records.len(), 2);
+    assert!(report.records.iter().all(|record| record.instance == "C101.25" && record.runs == 2));
+    assert_eq!(report.comparisons.len(), 1);
+    assert_eq!(report.comparisons[0].baseline, "first");
+    assert_eq!(report.comparisons[0].candidate, "second");
+}
@@ -38,3 +38,80 @@ pub fn can_detect_invalid_matrix_file() {
         vec!["cannot read matrix: 'E0001, cause: 'cannot deserialize matrix', action: 'check input json: 'missing field `travelTimes` at line 159 column 1''.'".into()]
     );
 }
+
+#[test]
+pub fn can_check_valid_problem() {
+    let report = check_pragmatic_problem(reader(PRAGMATIC_PROBLEM_PATH), Some(vec![reader(PRAGMATIC_MATRIX_PATH)]));
+
+    assert!(report.is_valid);
+    assert!(report.diagnostics.is_empty());
+}
+
+#[test]
+pub fn can_report_diagnostics_with_paths() {
+    let problem = r#"{
+      "plan": { "jobs": [
+        { "id": "job1", "deliveries": [{ "places": [{ "location": { "lat": 1.0, "lng": 0.0 }, "duration": 10 }], "demand": [1] }] },
+        { "id": "job1", "deliveries": [{ "places": [{ "location": { "lat": 2.0, "lng": 0.0 }, "duration": 10 }], "demand": [1] }] }
+      ]},
+      "fleet": {
+        "vehicles": [{
+          "typeId": "vehicle", "vehicleIds": ["v1"], "profile": { "matrix": "car" },
+          "costs": { "fixed": 10.0, "distance": 1.0, "time": 1.0 },
+          "shifts": [{ "start": { "earliest": "2020-07-04T09:00:00Z", "location": { "lat": 0.0, "lng": 0.0 } } }],
+          "capacity": [10]
+        }],
+        "profiles": [{ "name": "car" }]
+      }
+    }"#;
+
+    let report = check_pragmatic_problem(BufReader::new(problem.as_bytes()), None);
+
+    assert!(!report.is_valid);
+    let paths = report
+        .diagnostics
+        .iter()
+        .map(|diagnostic| (diagnostic.code.as_str(), diagnostic.path.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(paths, vec![("E1100", "/plan/jobs/0"), ("E1100", "/plan/jobs/1")]);
+}
+
+#[test]
+pub fn can_report_deserialization_error() {
+    let report = check_pragmatic_problem(reader(PRAGMATIC_MATRIX_PATH), None);
+
+    assert!(!report.is_valid);
+    assert_eq!(report.diagnostics.len(), 1);
+    assert_eq!(report.diagnostics[0].code, "E0000");
+    assert_eq!(report.diagnostics[0].path, "");
+}
+
+#[test]
+pub fn can_check_solution_with_report() {
+    let report = check_pragmatic_solution_with_report(
+        reader(PRAGMATIC_PROBLEM_PATH),
+        reader(PRAGMATIC_SOLUTION_PATH),
+        Some(vec![reader(PRAGMATIC_MATRIX_PATH)]),
+        CheckerOptions::default(),
+    );
+
+    assert!(report.is_feasible);
+    assert!(report.errors.is_empty());
+}
+
+#[test]
+pub fn can_report_solution_read_errors() {
+    let report = check_pragmatic_solution_with_report(
+        reader(PRAGMATIC_PROBLEM_PATH),
+        reader(PRAGMATIC_MATRIX_PATH),
+        None,
+        CheckerOptions { time_tolerance: 10., distance_tolerance: 10. },
+    );
+
+    assert!(!report.is_feasible);
+    assert_eq!(
+        report.errors,
+        vec!["cannot read solution: 'missing field `statistic` at line 39 column 1'".to_string()]
+    );
+    assert!(report.warnings.is_empty());
+}
@@ -0,0 +1,57 @@
+use super::*;
+use std::fs::File;
+use vrp_pragmatic::format::problem::PragmaticProblem;
+
+fn convert_file(path: &str, format: ScientificFormat) -> PragmaticConversion {
+    convert_from_scientific(BufReader::new(File::open(path).unwrap()), format).unwrap()
+}
+
+#[test]
+fn can_convert_solomon_problem() {
+    let conversion = convert_file("../examples/data/scientific/solomon/C101.25.txt", ScientificFormat::Solomon);
+
+    let problem = &conversion.problem;
+    assert_eq!(problem.plan.jobs.len(), 25);
+    assert!(problem.plan.jobs.iter().all(|job| job.deliveries.is_some() && job.pickups.is_none()));
+    assert_eq!(problem.fleet.vehicles[0].vehicle_ids.len(), 25);
+    assert_eq!(problem.fleet.vehicles[0].capacity, vec![200]);
+    let shift = &problem.fleet.vehicles[0].shifts[0];
+    assert_eq!(shift.start.earliest, "2000-01-01T00:00:00Z");
+    assert_eq!(shift.end.as_ref().unwrap().latest, "2000-01-01T00:20:36Z");
+    let locations = (conversion.matrix.distances.len() as f64).sqrt() as usize;
+    assert_eq!(locations * locations, conversion.matrix.distances.len());
+    assert_eq!(locations, 26);
+    assert_eq!(conversion.dropped, vec!["fractional euclidean distances (x1)".to_string()]);
+    assert!((conversion.problem, vec![conversion.matrix]).read_pragmatic().is_ok());
+}
+
+#[test]
+fn can_convert_lilim_problem() {
+    let conversion = convert_file("../examples/data/scientific/lilim/LC101.txt", ScientificFormat::LiLim);
+
+    let problem = &conversion.problem;
+    assert!(!problem.plan.jobs.is_empty());
+    assert!(problem.plan.jobs.iter().all(|job| {
+        let demand = |tasks: &Option<Vec<JobTask>>| tasks.as_ref().map(|tasks| tasks[0].demand.clone());
+        job.pickups.as_ref().is_some_and(|tasks| tasks.len() == 1) && demand(&job.pickups) == demand(&job.deliveries)
+    }));
+    assert!((conversion.problem, vec![conversion.matrix]).read_pragmatic().is_ok());
+}
+
+#[test]
+fn can_detect_invalid_customer_line() {
+    let content = "1 100\n0 0 0 0 0 100 0\n1 10 10 5 0 100\n";
+
+    let result = convert_from_scientific(BufReader::new(content.as_bytes()), ScientificFormat::Solomon);
+
+    assert!(result.err().is_some_and(|err| err.to_string().contains("cannot read customer line")));
+}
+
+#[test]
+fn can_detect_missing_delivery_in_lilim() {
+    let content = "1\t100\t1\n0\t0\t0\t0\t0\t100\t0\t0\t0\n1\t10\t10\t5\t0\t100\t0\t0\t2\n";
+
+    let result = convert_from_scientific(BufReader::new(content.as_bytes()), ScientificFormat::LiLim);
+
+    assert!(result.err().is_some_and(|err| err.to_string().contains("cannot find delivery 2 for pickup 1")));
+}
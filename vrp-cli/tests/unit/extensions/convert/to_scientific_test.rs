@@ -0,0 +1,86 @@
+use super::*;
+use crate::extensions::convert::convert_from_scientific;
+use std::fs::File;
+use std::io::BufReader;
+
+fn read_problem(name: &str) -> Problem {
+    let path = format!("../examples/data/pragmatic/{name}");
+    deserialize_problem(BufReader::new(File::open(path).unwrap())).unwrap()
+}
+
+fn convert(problem: &Problem, format: ScientificFormat) -> (String, Vec<String>) {
+    let mut writer = BufWriter::new(Vec::new());
+    let dropped = convert_to_scientific(problem, format, &mut writer).unwrap();
+
+    (String::from_utf8(writer.into_inner().unwrap()).unwrap(), dropped)
+}
+
+#[test]
+fn can_convert_to_solomon_and_back() {
+    let problem = read_problem("simple.basic.problem.json");
+
+    let (content, dropped) = convert(&problem, ScientificFormat::Solomon);
+    let conversion = convert_from_scientific(BufReader::new(content.as_bytes()), ScientificFormat::Solomon).unwrap();
+
+    assert_eq!(
+        dropped,
+        vec![
+            "job with multiple tasks (x1)".to_string(),
+            "multiple job time windows (x1)".to_string(),
+            "pickup job converted to delivery (x1)".to_string()
+        ]
+    );
+    assert!(content.starts_with("converted\n\nVEHICLE\nNUMBER     CAPACITY\n    1          10\n"));
+    let jobs = &conversion.problem.plan.jobs;
+    assert_eq!(jobs.len(), 2);
+    assert_eq!(jobs[0].deliveries.as_ref().unwrap()[0].demand, Some(vec![1]));
+    assert_eq!(conversion.problem.fleet.vehicles[0].capacity, vec![10]);
+    let shift = &conversion.problem.fleet.vehicles[0].shifts[0];
+    assert_eq!(shift.start.earliest, "2000-01-01T00:00:00Z");
+    assert_eq!(shift.end.as_ref().unwrap().latest, "2000-01-01T09:00:00Z");
+}
+
+#[test]
+fn can_convert_to_lilim_and_back() {
+    let problem = read_problem("simple.basic.problem.json");
+
+    let (content, dropped) = convert(&problem, ScientificFormat::LiLim);
+    let conversion = convert_from_scientific(BufReader::new(content.as_bytes()), ScientificFormat::LiLim).unwrap();
+
+    assert_eq!(
+        dropped,
+        vec!["job place tag (x2)".to_string(), "job which is not a single pickup and delivery (x2)".to_string()]
+    );
+    assert_eq!(content.lines().count(), 4);
+    let jobs = &conversion.problem.plan.jobs;
+    assert_eq!(jobs.len(), 1);
+    assert_eq!(jobs[0].pickups.as_ref().unwrap()[0].demand, Some(vec![1]));
+    assert_eq!(jobs[0].deliveries.as_ref().unwrap()[0].demand, Some(vec![1]));
+}
+
+#[test]
+fn can_report_dropped_features() {
+    let mut problem = read_problem("simple.basic.problem.json");
+    problem.plan.jobs.iter_mut().for_each(|job| {
+        job.skills =
+            Some(JobSkills { all_of: Some(vec!["skill".to_string()]), one_of: None, none_of: None, levels: None })
+    });
+    problem.fleet.vehicles[0].shifts[0].breaks = Some(vec![]);
+    problem.fleet.vehicles[0].capacity = vec![10, 5];
+
+    let (_, dropped) = convert(&problem, ScientificFormat::Solomon);
+
+    assert!(dropped.contains(&"job skills (x2)".to_string()));
+    assert!(dropped.contains(&"vehicle breaks (x1)".to_string()));
+    assert!(dropped.contains(&"multi-dimensional vehicle capacity (x1)".to_string()));
+}
+
+#[test]
+fn can_reject_reference_locations() {
+    let mut problem = read_problem("simple.basic.problem.json");
+    problem.fleet.vehicles[0].shifts[0].start.location = Location::Reference { index: 0 };
+
+    let result = convert_to_scientific(&problem, ScientificFormat::Solomon, &mut BufWriter::new(Vec::new()));
+
+    assert!(result.err().is_some_and(|err| err.to_string().contains("only locations specified by coordinates")));
+}
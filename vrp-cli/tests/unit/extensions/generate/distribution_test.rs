@@ -0,0 +1,110 @@
+use super::*;
+
+const BOUNDING_BOX: BoundingBox = ((52.4, 13.2), (52.6, 13.6));
+
+fn sample_locations(distribution: LocationDistribution, size: usize) -> Vec<(f64, f64)> {
+    let rnd = DefaultRandom::default();
+    let location_fn = create_location_fn(BOUNDING_BOX, &distribution, &rnd).unwrap();
+
+    (0..size)
+        .map(|_| match location_fn(&rnd) {
+            Location::Coordinate { lat, lng } => (lat, lng),
+            _ => unreachable!(),
+        })
+        .collect()
+}
+
+fn get_mean_center_distance(locations: &[(f64, f64)]) -> f64 {
+    let ((min_lat, min_lng), (max_lat, max_lng)) = BOUNDING_BOX;
+    let (half_lat, half_lng) = ((max_lat - min_lat) / 2., (max_lng - min_lng) / 2.);
+    let (center_lat, center_lng) = (min_lat + half_lat, min_lng + half_lng);
+
+    locations
+        .iter()
+        .map(|(lat, lng)| (((lat - center_lat) / half_lat).powi(2) + ((lng - center_lng) / half_lng).powi(2)).sqrt())
+        .sum::<f64>()
+        / locations.len() as f64
+}
+
+#[test]
+fn can_sample_uniform_locations_within_bounding_box() {
+    let ((min_lat, min_lng), (max_lat, max_lng)) = BOUNDING_BOX;
+
+    let locations = sample_locations(LocationDistribution::Uniform, 1000);
+
+    assert!(locations.iter().all(|&(lat, lng)| lat >= min_lat && lat <= max_lat && lng >= min_lng && lng <= max_lng));
+}
+
+#[test]
+fn can_sample_radial_locations_closer_to_center() {
+    let uniform = get_mean_center_distance(&sample_locations(LocationDistribution::Uniform, 2000));
+    let radial = get_mean_center_distance(&sample_locations(LocationDistribution::Radial, 2000));
+
+    assert!(radial < uniform, "radial: {radial}, uniform: {uniform}");
+    assert!(radial < 0.5);
+}
+
+#[test]
+fn can_sample_clustered_locations() {
+    let locations = sample_locations(LocationDistribution::Clustered { clusters: 2, radius: Some(100.) }, 1000);
+
+    // NOTE with small radius, every location is close to one of two cluster centers
+    let first = locations[0];
+    let far_locations = locations
+        .iter()
+        .filter(|(lat, lng)| (lat - first.0).abs() > 0.01 || (lng - first.1).abs() > 0.01)
+        .collect::<Vec<_>>();
+    let second = far_locations.first().copied().copied().unwrap_or(first);
+
+    assert!(locations.iter().all(|(lat, lng)| {
+        [first, second].iter().any(|center| (lat - center.0).abs() < 0.01 && (lng - center.1).abs() < 0.02)
+    }));
+}
+
+#[test]
+fn can_reject_invalid_cluster_settings() {
+    let rnd = DefaultRandom::default();
+
+    for distribution in [
+        LocationDistribution::Clustered { clusters: 0, radius: None },
+        LocationDistribution::Clustered { clusters: 2, radius: Some(-1.) },
+    ] {
+        assert!(create_location_fn(BOUNDING_BOX, &distribution, &rnd).is_err());
+    }
+}
+
+#[test]
+fn can_sample_demands_using_distribution() {
+    let rnd = DefaultRandom::default();
+    let demands = vec![vec![1, 10], vec![3, 20], vec![5, 30]];
+
+    for (distribution, is_valid) in [
+        (DemandDistribution::Prototype, (|demand: &[i32]| [1, 3, 5].contains(&demand[0])) as fn(&[i32]) -> bool),
+        (DemandDistribution::Uniform, |demand: &[i32]| (1..=5).contains(&demand[0]) && (10..=30).contains(&demand[1])),
+        (DemandDistribution::Normal, |demand: &[i32]| demand[0] >= 1 && demand[1] >= 1),
+    ] {
+        let demand_fn = create_demand_fn(demands.clone(), distribution);
+
+        (0..100).for_each(|_| {
+            let demand = demand_fn(&rnd).expect("no demand");
+            assert_eq!(demand.len(), 2);
+            assert!(is_valid(demand.as_slice()), "{distribution:?}: {demand:?}");
+        });
+    }
+
+    assert_eq!(create_demand_fn(vec![], DemandDistribution::Normal)(&rnd), None);
+}
+
+#[test]
+fn can_tighten_time_windows() {
+    let rnd = DefaultRandom::default();
+    let times = vec![vec!["2020-07-04T08:00:00Z".to_string(), "2020-07-04T18:00:00Z".to_string()]];
+
+    let tightened = tighten_time_windows(times, 0.1, &rnd).unwrap();
+
+    let parse = |time: &str| OffsetDateTime::parse(time, &Rfc3339).unwrap().unix_timestamp();
+    let (start, end) = (parse(tightened[0][0].as_str()), parse(tightened[0][1].as_str()));
+    assert_eq!(end - start, 3600);
+    assert!(start >= parse("2020-07-04T08:00:00Z"));
+    assert!(end <= parse("2020-07-04T18:00:00Z"));
+}
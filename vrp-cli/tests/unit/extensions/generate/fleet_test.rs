@@ -8,10 +8,12 @@ fn can_generate_fleet_of_specific_size() {
         plan: create_empty_plan(),
         fleet: Fleet {
             vehicles: vec![create_test_vehicle_type()],
-            profiles: vec![MatrixProfile { name: "normal_car".to_string(), speed: None }],
+            profiles: vec![MatrixProfile { name: "normal_car".to_string(), speed: None, restrictions: None }],
             resources: None,
+            depots: None,
         },
         objectives: None,
+        timezone: None,
     };
 
     let generated = generate_fleet(&prototype, 2);
@@ -18,12 +18,14 @@ fn can_generate_jobs_with_time_windows() {
             vehicles: vec![create_test_vehicle_type()],
             profiles: vec![create_test_vehicle_profile()],
             resources: None,
+            depots: None,
         },
         objectives: None,
+        timezone: None,
     };
 
-    let result =
-        generate_from_prototype(&problem, None, 10, 2, None).unwrap_or_else(|err| panic!("cannot generate: '{err}'"));
+    let result = generate_from_prototype(&problem, None, 10, 2, None, &PlanOptions::default())
+        .unwrap_or_else(|err| panic!("cannot generate: '{err}'"));
 
     assert_eq!(result.plan.jobs.len(), 10);
     assert_eq!(
@@ -0,0 +1,75 @@
+use super::*;
+use vrp_pragmatic::format::CoordIndex;
+use vrp_pragmatic::validation::ValidationContext;
+
+fn generate(template: ScenarioTemplate, jobs_size: usize, vehicle_types_size: usize) -> Problem {
+    generate_from_template(template, None, jobs_size, vehicle_types_size, None, &PlanOptions::default()).unwrap()
+}
+
+parameterized_test! {can_generate_valid_problem_from_template, template, {
+    can_generate_valid_problem_from_template_impl(template);
+}}
+
+can_generate_valid_problem_from_template! {
+    case01_food_delivery: ScenarioTemplate::FoodDelivery,
+    case02_field_service: ScenarioTemplate::FieldService,
+    case03_line_haul: ScenarioTemplate::LineHaul,
+}
+
+fn can_generate_valid_problem_from_template_impl(template: ScenarioTemplate) {
+    let problem = generate(template, 50, 3);
+
+    assert_eq!(problem.plan.jobs.len(), 50);
+    assert_eq!(problem.fleet.vehicles.len(), 3);
+    assert!(problem.fleet.vehicles.iter().all(|vehicle| !vehicle.vehicle_ids.is_empty()));
+    let coord_index = CoordIndex::new(&problem);
+    assert!(ValidationContext::new(&problem, None, &coord_index).validate().is_ok());
+}
+
+parameterized_test! {can_parse_scenario_template, (value, expected), {
+    assert_eq!(value.parse::<ScenarioTemplate>().ok(), expected);
+}}
+
+can_parse_scenario_template! {
+    case01: ("food-delivery", Some(ScenarioTemplate::FoodDelivery)),
+    case02: ("field-service", Some(ScenarioTemplate::FieldService)),
+    case03: ("line-haul", Some(ScenarioTemplate::LineHaul)),
+    case04: ("unknown", None),
+}
+
+#[test]
+fn can_generate_food_delivery_with_pickup_and_delivery_in_peaks() {
+    let problem = generate(ScenarioTemplate::FoodDelivery, 20, 3);
+
+    assert!(problem.plan.jobs.iter().all(|job| job.pickups.is_some() && job.deliveries.is_some()));
+    assert!(problem.fleet.vehicles.iter().filter_map(|vehicle| vehicle.shifts[0].breaks.as_ref()).count() == 1);
+}
+
+#[test]
+fn can_cover_all_field_service_skills_by_fleet() {
+    let problem = generate(ScenarioTemplate::FieldService, 30, 2);
+
+    let skills = problem.fleet.vehicles.iter().flat_map(|vehicle| vehicle.skills.iter().flatten()).collect::<Vec<_>>();
+    assert!(FIELD_SERVICE_SKILLS.iter().all(|skill| skills.iter().any(|s| s.name() == *skill)));
+    assert!(problem.plan.jobs.iter().all(|job| job.skills.is_some()));
+}
+
+#[test]
+fn can_scale_vehicles_with_jobs_size() {
+    let small = generate(ScenarioTemplate::LineHaul, 10, 1);
+    let large = generate(ScenarioTemplate::LineHaul, 100, 1);
+
+    assert!(large.fleet.vehicles[0].vehicle_ids.len() > small.fleet.vehicles[0].vehicle_ids.len());
+    assert_eq!(large.fleet.vehicles[0].capacity.len(), 2);
+}
+
+#[test]
+fn can_reject_invalid_template_parameters() {
+    let options = PlanOptions { time_window_tightness: Some(2.), ..PlanOptions::default() };
+
+    assert!(generate_from_template(ScenarioTemplate::LineHaul, None, 0, 1, None, &PlanOptions::default()).is_err());
+    assert!(
+        generate_from_template(ScenarioTemplate::LineHaul, None, 10, 1, Some(-1.), &PlanOptions::default()).is_err()
+    );
+    assert!(generate_from_template(ScenarioTemplate::LineHaul, None, 10, 1, None, &options).is_err());
+}
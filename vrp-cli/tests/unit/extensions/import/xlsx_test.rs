@@ -0,0 +1,59 @@
+use super::*;
+use crate::extensions::import::import_problem;
+use std::fs::File;
+use std::io::BufReader;
+use vrp_pragmatic::format::Location;
+
+const XLSX_PROBLEM_PATH: &str = "../examples/data/xlsx/deliveries.xlsx";
+
+#[test]
+fn can_read_xlsx_problem() {
+    let problem = read_xlsx_problem(BufReader::new(File::open(XLSX_PROBLEM_PATH).unwrap())).expect("cannot read xlsx");
+
+    assert_eq!(problem.plan.jobs.len(), 4);
+    assert_eq!(problem.fleet.vehicles.len(), 1);
+    assert_eq!(problem.fleet.vehicles[0].vehicle_ids, vec!["van_1".to_string(), "van_2".to_string()]);
+    assert_eq!(problem.fleet.vehicles[0].capacity, vec![10]);
+
+    let job1 = problem.plan.jobs.iter().find(|job| job.id == "job1").unwrap();
+    let place = &job1.deliveries.as_ref().unwrap()[0].places[0];
+    assert_eq!(place.tag.as_deref(), Some("Alexanderplatz 1, Berlin"));
+    assert_eq!(place.duration, 300.);
+    assert!(matches!(place.location, Location::Coordinate { lat, lng } if lat == 52.52599 && lng == 13.45413));
+
+    let job2 = problem.plan.jobs.iter().find(|job| job.id == "job2").unwrap();
+    let times = job2.deliveries.as_ref().unwrap()[0].places[0].times.clone();
+    assert_eq!(times, Some(vec![vec!["2020-07-04T09:00:00Z".to_string(), "2020-07-04T16:00:00Z".to_string()]]));
+
+    let job3 = problem.plan.jobs.iter().find(|job| job.id == "job3").unwrap();
+    assert!(job3.deliveries.is_none());
+    assert_eq!(job3.pickups.as_ref().unwrap()[0].demand, Some(vec![1]));
+}
+
+#[test]
+fn can_propagate_xlsx_format_error() {
+    let result = import_problem("xlsx", Some(vec![BufReader::new("not a workbook".as_bytes())]))
+        .expect_err("Should return error!")
+        .to_string();
+
+    assert!(result.starts_with("cannot read xlsx: E0000, cause: 'cannot read workbook'"));
+}
+
+parameterized_test! {can_handle_invalid_xlsx_input_amount, input_size, {
+        can_handle_invalid_xlsx_input_amount_impl(input_size);
+}}
+
+can_handle_invalid_xlsx_input_amount! {
+        case01: None,
+        case02: Some(0),
+        case03: Some(2),
+}
+
+fn can_handle_invalid_xlsx_input_amount_impl(input_size: Option<usize>) {
+    let result =
+        import_problem("xlsx", input_size.map(|size| (0..size).map(|_| BufReader::new("".as_bytes())).collect()))
+            .expect_err("Should return error!")
+            .to_string();
+
+    assert_eq!(result, "xlsx format expects one workbook file with jobs and vehicles sheets as an input");
+}
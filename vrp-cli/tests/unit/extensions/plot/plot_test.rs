@@ -0,0 +1,61 @@
+use super::*;
+use std::fs::File;
+use std::io::BufReader;
+use vrp_pragmatic::format::problem::deserialize_problem;
+use vrp_pragmatic::format::solution::{UnassignedJob, deserialize_solution};
+
+fn read_problem_and_solution(name: &str) -> (Problem, Solution) {
+    let open =
+        |suffix: &str| BufReader::new(File::open(format!("../examples/data/pragmatic/{name}.{suffix}")).unwrap());
+
+    (deserialize_problem(open("problem.json")).unwrap(), deserialize_solution(open("solution.json")).unwrap())
+}
+
+parameterized_test! {can_detect_plot_format, (path, expected), {
+    assert_eq!(PlotFormat::from_path(Path::new(path)).ok(), expected);
+}}
+
+can_detect_plot_format! {
+    case01: ("plan.svg", Some(PlotFormat::Svg)),
+    case02: ("plan.PNG", Some(PlotFormat::Png)),
+    case03: ("plan.jpg", None),
+    case04: ("plan", None),
+}
+
+#[test]
+fn can_get_plot_data_with_unassigned_jobs() {
+    let (problem, mut solution) = read_problem_and_solution("simple.basic");
+    solution.unassigned = Some(vec![UnassignedJob { job_id: "job3".to_string(), reasons: vec![] }]);
+
+    let plot_data = get_plot_data(&solution, Some(&problem)).unwrap();
+
+    assert_eq!(plot_data.tours.len(), solution.tours.len());
+    assert_eq!(plot_data.depots.len(), 1);
+    assert_eq!(plot_data.unassigned.len(), 2);
+}
+
+#[test]
+fn can_keep_aspect_ratio_in_bounds() {
+    let plot_data = PlotData { tours: vec![vec![(13., 52.), (13.2, 52.1)]], depots: vec![], unassigned: vec![] };
+
+    let ((min_x, min_y), (max_x, max_y)) = get_bounds(&plot_data, (200, 100));
+
+    assert!(min_x < 13. && max_x > 13.2 && min_y < 52. && max_y > 52.1);
+    let width = (max_x - min_x) * 52.05_f64.to_radians().cos();
+    assert!((width / (max_y - min_y) - 2.).abs() < 1E-6);
+}
+
+parameterized_test! {can_plot_solution, format, {
+    let (problem, solution) = read_problem_and_solution("simple.basic");
+    let extension = if format == PlotFormat::Svg { "svg" } else { "png" };
+    let file = tempfile::Builder::new().suffix(&format!(".{extension}")).tempfile().unwrap();
+
+    plot_solution(&solution, Some(&problem), file.path(), format, &PlotOptions::default()).unwrap();
+
+    assert!(file.as_file().metadata().unwrap().len() > 0);
+}}
+
+can_plot_solution! {
+    case01_svg: PlotFormat::Svg,
+    case02_png: PlotFormat::Png,
+}
@@ -0,0 +1,113 @@
+use super::*;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+
+const PRAGMATIC_DATA_PATH: &str = "../examples/data/pragmatic";
+
+fn get_data_files(path: &Path, suffix: &str) -> Vec<PathBuf> {
+    let mut files = std::fs::read_dir(path)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .flat_map(|path| {
+            if path.is_dir() {
+                get_data_files(path.as_path(), suffix)
+            } else if path.to_string_lossy().ends_with(suffix) {
+                vec![path]
+            } else {
+                vec![]
+            }
+        })
+        .collect::<Vec<_>>();
+
+    files.sort();
+
+    files
+}
+
+fn round_trip<T, W, R>(value: &T, write_fn: W, read_fn: R) -> T
+where
+    W: Fn(&T, &mut BufWriter<Vec<u8>>) -> GenericResult<()>,
+    R: Fn(BufReader<std::io::Cursor<Vec<u8>>>) -> GenericResult<T>,
+{
+    let mut writer = BufWriter::new(Vec::new());
+    write_fn(value, &mut writer).unwrap();
+    let bytes = writer.into_inner().unwrap();
+
+    read_fn(BufReader::new(std::io::Cursor::new(bytes))).unwrap()
+}
+
+fn to_json_value<T: serde::Serialize>(value: &T) -> serde_json::Value {
+    serde_json::to_value(value).unwrap()
+}
+
+#[test]
+fn can_round_trip_problems() {
+    let files = get_data_files(Path::new(PRAGMATIC_DATA_PATH), "problem.json");
+    assert!(!files.is_empty());
+
+    files.into_iter().for_each(|path| {
+        let problem: Problem = serde_json::from_reader(File::open(&path).unwrap()).unwrap();
+
+        let result = round_trip(&problem, write_proto_problem, read_proto_problem);
+
+        assert_eq!(to_json_value(&result), to_json_value(&problem), "round trip failed for {path:?}");
+    });
+}
+
+#[test]
+fn can_round_trip_solutions() {
+    let files = get_data_files(Path::new(PRAGMATIC_DATA_PATH), "solution.json");
+    assert!(!files.is_empty());
+
+    files.into_iter().for_each(|path| {
+        let mut solution: Solution = serde_json::from_reader(File::open(&path).unwrap()).unwrap();
+        // NOTE empty lists are not distinguished from omitted ones in protobuf
+        solution.unassigned = solution.unassigned.filter(|unassigned| !unassigned.is_empty());
+        solution.violations = solution.violations.filter(|violations| !violations.is_empty());
+
+        let result = round_trip(&solution, write_proto_solution, read_proto_solution);
+
+        assert_eq!(to_json_value(&result), to_json_value(&solution), "round trip failed for {path:?}");
+    });
+}
+
+#[test]
+fn can_round_trip_matrix() {
+    let path = format!("{PRAGMATIC_DATA_PATH}/simple.basic.matrix.json");
+    let mut matrix: Matrix = serde_json::from_reader(File::open(path).unwrap()).unwrap();
+    matrix.error_codes = Some(vec![0; matrix.travel_times.len()]);
+
+    let result = round_trip(&matrix, write_proto_matrix, read_proto_matrix);
+
+    assert_eq!(to_json_value(&result), to_json_value(&matrix));
+}
+
+#[test]
+fn can_encode_problem_more_compact_than_json() {
+    let path = format!("{PRAGMATIC_DATA_PATH}/simple.basic.problem.json");
+    let problem: Problem = serde_json::from_reader(File::open(path).unwrap()).unwrap();
+
+    let mut writer = BufWriter::new(Vec::new());
+    write_proto_problem(&problem, &mut writer).unwrap();
+
+    assert!(writer.into_inner().unwrap().len() < serde_json::to_vec(&problem).unwrap().len());
+}
+
+#[test]
+fn can_detect_missing_location() {
+    let path = format!("{PRAGMATIC_DATA_PATH}/simple.basic.problem.json");
+    let problem: Problem = serde_json::from_reader(File::open(path).unwrap()).unwrap();
+    let mut message = to_proto_problem(&problem).unwrap();
+    message.plan.as_mut().unwrap().jobs[0].deliveries[0].places[0].location = None;
+
+    let result = from_proto_problem(message);
+
+    assert_eq!(result.err().map(|err| err.to_string()), Some("missing location".to_string()));
+}
+
+#[test]
+fn can_detect_invalid_message() {
+    let result = read_proto_problem(BufReader::new([0xFF_u8, 0xFF, 0xFF].as_slice()));
+
+    assert!(result.err().unwrap().to_string().starts_with("cannot decode protobuf message"));
+}
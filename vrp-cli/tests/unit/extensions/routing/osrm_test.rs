@@ -0,0 +1,259 @@
+use super::*;
+use crate::helpers::generate::*;
+use std::io::{BufRead, BufReader as IoBufReader};
+use std::net::TcpListener;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use vrp_pragmatic::format::problem::{Fleet, Job, Plan};
+use vrp_pragmatic::format::solution::encode_polyline;
+
+fn create_test_problem() -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: (1..=4).map(|idx| Job { id: format!("job{idx}"), ..create_test_job(0., idx as Float) }).collect(),
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![create_test_vehicle_type()],
+            profiles: vec![create_test_vehicle_profile()],
+            resources: None,
+            depots: None,
+        },
+        objectives: None,
+        timezone: None,
+    }
+}
+
+/// Returns travel data between two locations as a mock server does: location with longitude 4
+/// cannot reach any other location.
+fn get_mock_value(from: Float, to: Float) -> Option<(Float, Float)> {
+    if from == 4. && to != 4. { None } else { Some(((from - to).abs() * 10., (from - to).abs() * 100.)) }
+}
+
+fn create_mock_response(path: &str) -> String {
+    let (locations, query) = path.split_once('?').unwrap();
+    let is_route = locations.contains("/route/v1/");
+    let is_nearest = locations.contains("/nearest/v1/");
+    let locations = locations
+        .rsplit('/')
+        .next()
+        .unwrap()
+        .split(';')
+        .map(|location| location.split(',').next().unwrap().parse::<Float>().unwrap())
+        .collect::<Vec<_>>();
+
+    if is_nearest {
+        // NOTE nearest street is located at latitude 1
+        return serde_json::json!({ "code": "Ok", "waypoints": [{ "location": [locations[0], 1.] }] }).to_string();
+    }
+
+    if is_route {
+        // NOTE route goes via point with latitude 1 in the middle of from and to locations
+        let (from, to) = (locations[0], locations[1]);
+        let geometry = encode_polyline(&[(0., from), (1., (from + to) / 2.), (0., to)]);
+
+        return serde_json::json!({ "code": "Ok", "routes": [{ "geometry": geometry }] }).to_string();
+    }
+    let get_indices = |name: &str| {
+        query
+            .split('&')
+            .find_map(|param| param.strip_prefix(name))
+            .unwrap()
+            .split(';')
+            .map(|idx| idx.parse::<usize>().unwrap())
+            .collect::<Vec<_>>()
+    };
+    let (sources, destinations) = (get_indices("sources="), get_indices("destinations="));
+
+    let get_table = |get_value: fn((Float, Float)) -> Float| {
+        sources
+            .iter()
+            .map(|&from| {
+                destinations
+                    .iter()
+                    .map(|&to| get_mock_value(locations[from], locations[to]).map(get_value))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>()
+    };
+
+    serde_json::json!({
+        "code": "Ok",
+        "durations": get_table(|value| value.0),
+        "distances": get_table(|value| value.1),
+    })
+    .to_string()
+}
+
+/// Starts a mock server which responds with chunked body and returns its url and request counter.
+fn start_mock_server() -> (String, Arc<AtomicUsize>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let requests = Arc::new(AtomicUsize::new(0));
+
+    std::thread::spawn({
+        let requests = requests.clone();
+        move || {
+            for mut stream in listener.incoming().flatten() {
+                let mut reader = IoBufReader::new(&mut stream);
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                // NOTE consume request headers before responding
+                let mut header = String::new();
+                while reader.read_line(&mut header).unwrap() > 0 && header.trim_end() != "" {
+                    header.clear();
+                }
+                requests.fetch_add(1, Ordering::Relaxed);
+
+                let path = request_line.split_whitespace().nth(1).unwrap();
+                let body = create_mock_response(path);
+                let (first, second) = body.split_at(body.len() / 2);
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nTransfer-Encoding: chunked\r\n\r\n\
+                     {:x}\r\n{first}\r\n{:x}\r\n{second}\r\n0\r\n\r\n",
+                    first.len(),
+                    second.len()
+                );
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        }
+    });
+
+    (url, requests)
+}
+
+fn assert_matrix(problem: &Problem, matrix: &Matrix) {
+    let lngs = get_unique_locations(problem)
+        .into_iter()
+        .map(|location| match location {
+            Location::Coordinate { lng, .. } => lng,
+            _ => unreachable!(),
+        })
+        .collect::<Vec<_>>();
+    let size = lngs.len();
+
+    assert_eq!(matrix.profile.as_deref(), Some("car"));
+    assert_eq!(matrix.travel_times.len(), size * size);
+
+    for (from_idx, &from) in lngs.iter().enumerate() {
+        for (to_idx, &to) in lngs.iter().enumerate() {
+            let idx = from_idx * size + to_idx;
+            let (duration, distance, error) = match get_mock_value(from, to) {
+                Some((duration, distance)) => (duration as i64, distance as i64, 0),
+                None => (0, 0, 1),
+            };
+
+            assert_eq!(
+                (matrix.travel_times[idx], matrix.distances[idx], matrix.error_codes.as_ref().unwrap()[idx]),
+                (duration, distance, error)
+            );
+        }
+    }
+}
+
+parameterized_test! {can_parse_url, (url, expected), {
+    can_parse_url_impl(url, expected);
+}}
+
+can_parse_url! {
+    case01_host_port: ("http://localhost:5000", Ok("http://localhost:5000")),
+    case02_prefix: ("http://127.0.0.1:5000/osrm/", Ok("http://127.0.0.1:5000/osrm")),
+    case03_https: ("https://localhost", Ok("https://localhost")),
+    case04_no_scheme: ("localhost:5000", Err(())),
+    case05_no_host: ("http://:5000", Err(())),
+}
+
+fn can_parse_url_impl(url: &str, expected: Result<&str, ()>) {
+    let result = HttpEndpoint::new(&OsrmConfig::new(url)).map(|endpoint| endpoint.base_url).map_err(|_| ());
+
+    assert_eq!(result, expected.map(|url| url.to_string()));
+}
+
+#[test]
+fn can_fetch_matrices_using_chunks() {
+    let problem = create_test_problem();
+    let (url, requests) = start_mock_server();
+    let config = OsrmConfig { chunk_size: 2, ..OsrmConfig::new(url.as_str()) };
+
+    let matrices = fetch_osrm_matrices(&problem, &config).unwrap();
+
+    assert_eq!(matrices.len(), 1);
+    assert_matrix(&problem, &matrices[0]);
+    // 5 locations are split into 3 chunks of sources and destinations
+    assert_eq!(requests.load(Ordering::Relaxed), 9);
+}
+
+#[test]
+fn can_use_cached_matrices() {
+    let problem = create_test_problem();
+    let (url, requests) = start_mock_server();
+    let cache_dir = tempfile::tempdir().unwrap();
+    let config = OsrmConfig { cache_dir: Some(cache_dir.path().to_path_buf()), ..OsrmConfig::new(url.as_str()) };
+
+    let first = fetch_osrm_matrices(&problem, &config).unwrap();
+    let second = fetch_osrm_matrices(&problem, &config).unwrap();
+
+    assert_eq!(requests.load(Ordering::Relaxed), 1);
+    assert_matrix(&problem, &first[0]);
+    assert_matrix(&problem, &second[0]);
+}
+
+#[test]
+fn can_stop_waiting_for_response_after_timeout() {
+    let problem = create_test_problem();
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let config = OsrmConfig { request_timeout: Duration::from_millis(100), ..OsrmConfig::new(url.as_str()) };
+    // NOTE connection is accepted, but no response is ever sent
+    let handle = std::thread::spawn(move || listener.accept().map(|(stream, _)| stream));
+
+    let result = fetch_osrm_matrices(&problem, &config);
+
+    assert!(result.is_err());
+    drop(handle.join());
+}
+
+#[test]
+fn can_reject_problem_with_location_indices() {
+    let mut problem = create_test_problem();
+    problem.plan.jobs[0].pickups.as_mut().unwrap()[0].places[0].location = Location::Reference { index: 0 };
+
+    let result = fetch_osrm_matrices(&problem, &OsrmConfig::new("http://localhost:5000"));
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn can_get_leg_geometry() {
+    let problem = create_test_problem();
+    let (url, requests) = start_mock_server();
+    let provider = OsrmGeometryProvider::new(&problem, &OsrmConfig::new(url.as_str())).unwrap();
+    let (from, to) = (Location::Coordinate { lat: 0., lng: 1. }, Location::Coordinate { lat: 0., lng: 3. });
+
+    let path = provider.get_path(&Profile::new(0, None), &from, &to);
+    let unknown_profile = provider.get_path(&Profile::new(1, None), &from, &to);
+    let reference = provider.get_path(&Profile::new(0, None), &from, &Location::Reference { index: 0 });
+
+    assert_eq!(path, Some(vec![(0., 1.), (1., 2.), (0., 3.)]));
+    assert_eq!(unknown_profile, None);
+    assert_eq!(reference, None);
+    assert_eq!(requests.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn can_snap_job_locations() {
+    let (url, requests) = start_mock_server();
+
+    let problem = snap_job_locations(create_test_problem(), &OsrmConfig::new(url.as_str())).unwrap();
+
+    let locations = problem
+        .plan
+        .jobs
+        .iter()
+        .flat_map(|job| job.pickups.iter().flatten())
+        .flat_map(|task| task.places.iter())
+        .map(|place| place.location.clone())
+        .collect::<Vec<_>>();
+    assert_eq!(locations, (1..=4).map(|idx| Location::Coordinate { lat: 1., lng: idx as Float }).collect::<Vec<_>>());
+    assert_eq!(requests.load(Ordering::Relaxed), 4);
+}
@@ -0,0 +1,136 @@
+use super::*;
+use std::fs::File;
+use std::io::BufReader;
+use vrp_pragmatic::format::CoordIndex;
+use vrp_pragmatic::validation::ValidationContext;
+
+fn read_problem(name: &str) -> Problem {
+    let path = format!("../examples/data/pragmatic/{name}");
+    deserialize_problem(BufReader::new(File::open(path).unwrap())).unwrap()
+}
+
+fn get_coordinates(problem: &Problem) -> Vec<(f64, f64)> {
+    problem
+        .plan
+        .jobs
+        .iter()
+        .flat_map(|job| job.deliveries.iter().chain(job.pickups.iter()).chain(job.services.iter()).flatten())
+        .flat_map(|task| task.places.iter())
+        .map(|place| match place.location {
+            Location::Coordinate { lat, lng } => (lat, lng),
+            _ => unreachable!(),
+        })
+        .collect()
+}
+
+fn hash_options(salt: &str) -> ScrubOptions {
+    ScrubOptions { ids: IdScrubMode::Hash { salt: salt.to_string() }, ..ScrubOptions::default() }
+}
+
+#[test]
+fn can_scrub_ids_and_keep_problem_valid() {
+    let problem = read_problem("basics/break.basic.problem.json");
+
+    let scrubbed = scrub_problem(problem.clone(), &ScrubOptions::default()).unwrap();
+
+    assert_eq!(scrubbed.plan.jobs.len(), problem.plan.jobs.len());
+    assert!(scrubbed.plan.jobs.iter().enumerate().all(|(idx, job)| job.id == format!("job{}", idx + 1)));
+    assert_eq!(scrubbed.fleet.vehicles[0].type_id, "type1");
+    assert_eq!(scrubbed.fleet.vehicles[0].vehicle_ids[0], "vehicle1");
+    let coord_index = CoordIndex::new(&scrubbed);
+    assert!(ValidationContext::new(&scrubbed, None, &coord_index).validate().is_ok());
+}
+
+#[test]
+fn can_jitter_locations_within_radius_consistently() {
+    let problem = read_problem("simple.basic.problem.json");
+    let options = ScrubOptions { radius: 200., ..ScrubOptions::default() };
+
+    let scrubbed = scrub_problem(problem.clone(), &options).unwrap();
+
+    let original = get_coordinates(&problem);
+    let jittered = get_coordinates(&scrubbed);
+    assert!(original.iter().zip(jittered.iter()).all(|(&(lat, lng), &(new_lat, new_lng))| {
+        let lat_delta = (new_lat - lat) * METERS_PER_DEGREE;
+        let lng_delta = (new_lng - lng) * METERS_PER_DEGREE * lat.to_radians().cos();
+        (lat_delta.powi(2) + lng_delta.powi(2)).sqrt() <= 200. + 1E-6
+    }));
+    assert!(original.iter().zip(jittered.iter()).any(|(a, b)| a != b));
+    let shift = &scrubbed.fleet.vehicles[0].shifts[0];
+    assert_eq!(format!("{:?}", shift.start.location), format!("{:?}", shift.end.as_ref().unwrap().location));
+}
+
+#[test]
+fn can_rebase_and_rescale_times() {
+    let problem = read_problem("simple.basic.problem.json");
+    let options = ScrubOptions { time_scale: 2., ..ScrubOptions::default() };
+
+    let scrubbed = scrub_problem(problem.clone(), &options).unwrap();
+
+    let vehicle = &scrubbed.fleet.vehicles[0];
+    assert_eq!(vehicle.shifts[0].start.earliest, SCRUBBED_EPOCH);
+    let shift_duration = |vehicle: &VehicleType| {
+        parse_time(&vehicle.shifts[0].end.as_ref().unwrap().latest).unwrap()
+            - parse_time(&vehicle.shifts[0].start.earliest).unwrap()
+    };
+    assert_eq!(shift_duration(vehicle), 2 * shift_duration(&problem.fleet.vehicles[0]));
+    assert_eq!(vehicle.profile.scale, Some(2.));
+    assert_eq!(vehicle.costs.time, problem.fleet.vehicles[0].costs.time / 2.);
+    let get_duration = |problem: &Problem| problem.plan.jobs[0].deliveries.as_ref().unwrap()[0].places[0].duration;
+    assert_eq!(get_duration(&scrubbed), 2. * get_duration(&problem));
+}
+
+#[test]
+fn can_hash_ids_and_update_relations() {
+    let problem = read_problem("basics/relation-strict.basic.problem.json");
+
+    let scrubbed = scrub_problem(problem.clone(), &hash_options("salt")).unwrap();
+    let scrubbed_again = scrub_problem(problem.clone(), &hash_options("salt")).unwrap();
+    let scrubbed_other = scrub_problem(problem, &hash_options("other")).unwrap();
+
+    let relation = &scrubbed.plan.relations.as_ref().unwrap()[0];
+    let job_ids = scrubbed.plan.jobs.iter().map(|job| job.id.clone()).collect::<Vec<_>>();
+    assert_eq!(relation.jobs, vec!["departure".to_string(), job_ids[3].clone(), job_ids[0].clone()]);
+    assert_eq!(relation.vehicle_id, scrubbed.fleet.vehicles[0].vehicle_ids[0]);
+    assert!(job_ids.iter().all(|id| id.starts_with("job_")));
+    assert_eq!(job_ids, scrubbed_again.plan.jobs.iter().map(|job| job.id.clone()).collect::<Vec<_>>());
+    assert_ne!(job_ids[0], scrubbed_other.plan.jobs[0].id);
+}
+
+#[test]
+fn can_scrub_skills_consistently() {
+    let problem = read_problem("basics/skills.basic.problem.json");
+
+    let scrubbed = scrub_problem(problem, &ScrubOptions::default()).unwrap();
+
+    let job_skills = scrubbed
+        .plan
+        .jobs
+        .iter()
+        .filter_map(|job| job.skills.as_ref())
+        .flat_map(|skills| skills.all_of.iter().chain(skills.one_of.iter()).chain(skills.none_of.iter()).flatten())
+        .map(|skill| skill.as_str())
+        .collect::<Vec<_>>();
+    let vehicle_skills = scrubbed
+        .fleet
+        .vehicles
+        .iter()
+        .flat_map(|vehicle| vehicle.skills.iter().flatten())
+        .map(|skill| skill.name())
+        .collect::<Vec<_>>();
+    assert!(!job_skills.is_empty());
+    assert!(job_skills.iter().chain(vehicle_skills.iter()).all(|skill| skill.starts_with("skill")));
+    assert!(vehicle_skills.iter().any(|skill| job_skills.contains(skill)));
+}
+
+parameterized_test! {can_reject_invalid_options, (radius, time_scale), {
+    let problem = read_problem("simple.basic.problem.json");
+    let options = ScrubOptions { radius, time_scale, ..ScrubOptions::default() };
+
+    assert!(scrub_problem(problem, &options).is_err());
+}}
+
+can_reject_invalid_options! {
+    case01_negative_radius: (-1., 1.),
+    case02_zero_scale: (100., 0.),
+}
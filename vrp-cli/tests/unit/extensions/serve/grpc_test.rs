@@ -0,0 +1,115 @@
+use super::*;
+use crate::extensions::proto::{from_proto_solution, to_proto_problem};
+use crate::extensions::serve::grpc::proto::solver_client::SolverClient;
+use tokio_stream::StreamExt;
+use tokio_stream::wrappers::TcpListenerStream;
+use vrp_pragmatic::format::problem::deserialize_problem;
+use vrp_pragmatic::format::solution::deserialize_solution;
+
+const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
+
+fn create_proto_request(id: &str, termination: &str, progress_interval: u32) -> ProtoSolveRequest {
+    ProtoSolveRequest {
+        id: id.to_string(),
+        problem: std::fs::read_to_string(PRAGMATIC_PROBLEM_PATH).unwrap(),
+        matrices: vec![],
+        config: format!(r#"{{ "termination": {termination} }}"#),
+        progress_interval,
+        ..ProtoSolveRequest::default()
+    }
+}
+
+async fn start_server() -> SolverClient<tonic::transport::Channel> {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    let config = ServeConfig {
+        host: "127.0.0.1".to_string(),
+        port: 0,
+        concurrency: 2,
+        max_time: None,
+        max_generations: None,
+        job_ttl: 3600,
+    };
+
+    tokio::spawn(
+        tonic::transport::Server::builder()
+            .add_service(SolverServer::new(GrpcSolver::new(&config)))
+            .serve_with_incoming(TcpListenerStream::new(listener)),
+    );
+
+    SolverClient::connect(format!("http://{address}")).await.unwrap()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn can_stream_intermediate_and_final_solutions() {
+    let mut client = start_server().await;
+
+    let mut stream =
+        client.solve(create_proto_request("", r#"{ "maxGenerations": 20 }"#, 1)).await.unwrap().into_inner();
+
+    let mut responses = Vec::new();
+    while let Some(response) = stream.next().await {
+        responses.push(response.unwrap());
+    }
+
+    let last = responses.last().unwrap();
+    assert!(last.is_final);
+    assert!(responses.len() > 1);
+    assert!(responses.iter().rev().skip(1).all(|response| !response.is_final && response.id == last.id));
+    let solution = deserialize_solution(BufReader::new(last.solution.as_bytes())).unwrap();
+    assert!(solution.unassigned.is_none());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn can_cancel_running_request() {
+    let mut client = start_server().await;
+
+    let mut stream = client.solve(create_proto_request("long", r#"{ "maxTime": 300 }"#, 0)).await.unwrap().into_inner();
+
+    let mut is_cancelled = false;
+    for _ in 0..100 {
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        is_cancelled = client.cancel(CancelRequest { id: "long".to_string() }).await.unwrap().into_inner().is_cancelled;
+        if is_cancelled {
+            break;
+        }
+    }
+    assert!(is_cancelled);
+
+    let response = stream.next().await.unwrap().unwrap();
+    assert!(response.is_final);
+    assert_eq!(response.id, "long");
+
+    let is_cancelled = client.cancel(CancelRequest { id: "long".to_string() }).await.unwrap().into_inner().is_cancelled;
+    assert!(!is_cancelled);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn can_reject_invalid_request() {
+    let mut client = start_server().await;
+    let mut request = create_proto_request("", r#"{ "maxGenerations": 1 }"#, 0);
+    request.problem = "{".to_string();
+
+    let status = client.solve(request).await.unwrap_err();
+
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn can_solve_request_with_binary_messages() {
+    let mut client = start_server().await;
+    let mut request = create_proto_request("", r#"{ "maxGenerations": 10 }"#, 0);
+    let problem = deserialize_problem(BufReader::new(request.problem.as_bytes())).unwrap();
+    request.problem = String::default();
+    request.problem_message = Some(to_proto_problem(&problem).unwrap());
+    request.binary_output = true;
+
+    let mut stream = client.solve(request).await.unwrap().into_inner();
+
+    let response = stream.next().await.unwrap().unwrap();
+    assert!(response.is_final);
+    assert!(response.solution.is_empty());
+    let solution = from_proto_solution(response.solution_message.unwrap()).unwrap();
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+}
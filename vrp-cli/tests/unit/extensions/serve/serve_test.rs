@@ -0,0 +1,148 @@
+use super::*;
+use std::time::{Duration, Instant};
+use vrp_pragmatic::format::solution::deserialize_solution;
+
+const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
+
+fn create_solve_request_body(termination: &str) -> String {
+    let problem = std::fs::read_to_string(PRAGMATIC_PROBLEM_PATH).unwrap();
+
+    format!(r#"{{ "problem": {problem}, "config": {{ "termination": {termination} }} }}"#)
+}
+
+fn send_request(address: &str, method: &str, path: &str, body: &str) -> (u16, String) {
+    let mut stream = TcpStream::connect(address).unwrap();
+    write!(stream, "{method} {path} HTTP/1.1\r\nHost: {address}\r\nContent-Length: {}\r\n\r\n{body}", body.len())
+        .unwrap();
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+
+    let (head, body) = response.split_once("\r\n\r\n").unwrap();
+    let status = head.split_whitespace().nth(1).unwrap().parse().unwrap();
+
+    (status, body.to_string())
+}
+
+#[test]
+fn can_handle_unknown_endpoints_and_jobs() {
+    let manager = JobManager::new(1, None, Some(1), Duration::from_secs(3600));
+
+    assert_eq!(handle_request(&manager, "GET", "/unknown", &[]).status, 404);
+    assert_eq!(handle_request(&manager, "GET", "/status/42", &[]).status, 404);
+    assert_eq!(handle_request(&manager, "GET", "/solution/42", &[]).status, 404);
+    assert_eq!(handle_request(&manager, "DELETE", "/jobs/42", &[]).status, 404);
+}
+
+#[test]
+fn can_reject_malformed_solve_request() {
+    let manager = JobManager::new(1, None, Some(1), Duration::from_secs(3600));
+
+    let response = handle_request(&manager, "POST", "/solve", b"{ \"plan\": 42 }");
+
+    assert_eq!(response.status, 400);
+    assert!(response.body.contains("cannot deserialize solve request"));
+}
+
+#[test]
+fn can_cancel_and_remove_job() {
+    let manager = JobManager::new(1, None, None, Duration::from_secs(3600));
+    let body = create_solve_request_body(r#"{ "maxTime": 300 }"#);
+
+    let response = handle_request(&manager, "POST", "/solve", body.as_bytes());
+    assert_eq!(response.status, 202);
+    let state: JobState = serde_json::from_str(response.body.as_str()).unwrap();
+
+    let response = handle_request(&manager, "DELETE", format!("/jobs/{}", state.id).as_str(), &[]);
+    assert_eq!(response.status, 200);
+    assert_eq!(handle_request(&manager, "GET", format!("/status/{}", state.id).as_str(), &[]).status, 404);
+}
+
+#[test]
+fn can_evict_expired_finished_jobs() {
+    let manager = JobManager::new(1, None, None, Duration::from_secs(60));
+    let create_entry = |status, finished_at| JobEntry {
+        status,
+        error: None,
+        output: None,
+        is_cancelled: Arc::new(AtomicBool::new(false)),
+        finished_at,
+    };
+    let expired = Instant::now().checked_sub(Duration::from_secs(120)).unwrap();
+
+    let mut jobs = manager.jobs.lock().unwrap();
+    jobs.insert("expired".to_string(), create_entry(JobStatus::Completed, Some(expired)));
+    jobs.insert("recent".to_string(), create_entry(JobStatus::Failed, Some(Instant::now())));
+    jobs.insert("running".to_string(), create_entry(JobStatus::Running, None));
+    manager.evict_expired(&mut jobs);
+    drop(jobs);
+
+    assert!(manager.get_state("expired").is_none());
+    assert!(manager.get_state("recent").is_some());
+    assert!(manager.get_state("running").is_some());
+}
+
+#[test]
+fn can_reject_too_big_request_body() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap().to_string();
+    let manager = JobManager::new(1, None, None, Duration::from_secs(3600));
+    thread::spawn(move || serve(listener, manager, Arc::new(|_| {})));
+
+    let mut stream = TcpStream::connect(address.as_str()).unwrap();
+    write!(stream, "POST /solve HTTP/1.1\r\nHost: {address}\r\nContent-Length: {}\r\n\r\n", MAX_BODY_SIZE + 1).unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+
+    assert!(response.starts_with("HTTP/1.1 413"));
+}
+
+#[test]
+fn can_limit_amount_of_connections() {
+    let connections = Arc::new(AtomicUsize::new(0));
+
+    let guards = (0..MAX_CONNECTIONS).map(|_| ConnectionGuard::acquire(connections.clone())).collect::<Vec<_>>();
+    assert!(guards.iter().all(|guard| guard.is_some()));
+    assert!(ConnectionGuard::acquire(connections.clone()).is_none());
+
+    drop(guards);
+    assert_eq!(connections.load(Ordering::Relaxed), 0);
+    assert!(ConnectionGuard::acquire(connections.clone()).is_some());
+}
+
+#[test]
+fn can_solve_problem_via_http() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap().to_string();
+    let manager = JobManager::new(2, None, None, Duration::from_secs(3600));
+    thread::spawn(move || serve(listener, manager, Arc::new(|_| {})));
+
+    let (status, body) = send_request(
+        address.as_str(),
+        "POST",
+        "/solve",
+        create_solve_request_body(r#"{ "maxGenerations": 1 }"#).as_str(),
+    );
+    assert_eq!(status, 202);
+    let state: JobState = serde_json::from_str(body.as_str()).unwrap();
+    assert_eq!(state.status, JobStatus::Queued);
+
+    let start = Instant::now();
+    let state = loop {
+        let (_, body) = send_request(address.as_str(), "GET", format!("/status/{}", state.id).as_str(), "");
+        let state: JobState = serde_json::from_str(body.as_str()).unwrap();
+        if matches!(state.status, JobStatus::Completed | JobStatus::Failed) || start.elapsed().as_secs() > 60 {
+            break state;
+        }
+        thread::sleep(Duration::from_millis(50));
+    };
+    assert_eq!(state.status, JobStatus::Completed);
+
+    let (status, body) = send_request(address.as_str(), "GET", format!("/solution/{}", state.id).as_str(), "");
+    assert_eq!(status, 200);
+    let solution = deserialize_solution(BufReader::new(body.as_bytes())).unwrap();
+    assert_eq!(solution.tours.len(), 1);
+
+    let (status, _) = send_request(address.as_str(), "DELETE", format!("/jobs/{}", state.id).as_str(), "");
+    assert_eq!(status, 200);
+}
@@ -0,0 +1,80 @@
+use super::*;
+use std::fs::File;
+use std::io::BufReader;
+use vrp_pragmatic::format::problem::{deserialize_matrix, deserialize_problem};
+
+fn read_problem() -> Problem {
+    deserialize_problem(BufReader::new(File::open("../examples/data/pragmatic/simple.basic.problem.json").unwrap()))
+        .unwrap()
+}
+
+fn create_shared_routing(locations: Option<Vec<Location>>) -> SharedRouting {
+    let matrix = File::open("../examples/data/pragmatic/simple.basic.matrix.json").unwrap();
+
+    SharedRouting { matrices: vec![deserialize_matrix(BufReader::new(matrix)).unwrap()], locations }
+}
+
+fn read_locations() -> Vec<Location> {
+    let locations = File::open("../examples/data/pragmatic/simple.basic.locations.json").unwrap();
+    serde_json::from_reader(BufReader::new(locations)).unwrap()
+}
+
+fn create_config() -> Config {
+    serde_json::from_str(r#"{ "termination": { "maxGenerations": 10 } }"#).unwrap()
+}
+
+#[test]
+fn can_solve_problems_with_shared_locations() {
+    let full = read_problem();
+    let mut part = read_problem();
+    part.plan.jobs.truncate(1);
+
+    let results = solve_batch(vec![full, part], &create_shared_routing(Some(read_locations())), &create_config(), 2);
+
+    let jobs = results
+        .into_iter()
+        .map(|result| {
+            let (problem, solution) = result.unwrap();
+            assert!(solution.unassigned.is_empty());
+            problem.jobs.size()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(jobs, vec![3, 1]);
+}
+
+#[test]
+fn can_solve_problems_with_matrix_as_is() {
+    let problems = vec![read_problem(), read_problem(), read_problem()];
+
+    let results = solve_batch(problems, &create_shared_routing(None), &create_config(), 1);
+
+    assert_eq!(results.len(), 3);
+    assert!(results.iter().all(|result| result.is_ok()));
+}
+
+#[test]
+fn can_report_location_missing_in_shared_locations() {
+    let mut locations = read_locations();
+    locations.remove(0);
+
+    let results = solve_batch(vec![read_problem()], &create_shared_routing(Some(locations)), &create_config(), 1);
+
+    assert!(results[0].as_ref().err().is_some_and(|err| err.to_string().contains("cannot find location")));
+}
+
+#[test]
+fn can_get_sub_matrix() {
+    let matrix = Matrix {
+        profile: Some("car".to_string()),
+        timestamp: None,
+        travel_times: (0..9).collect(),
+        distances: (10..19).collect(),
+        error_codes: None,
+    };
+
+    let sub_matrix = get_sub_matrix(&matrix, &[2, 0]).unwrap();
+
+    assert_eq!(sub_matrix.travel_times, vec![8, 6, 2, 0]);
+    assert_eq!(sub_matrix.distances, vec![18, 16, 12, 10]);
+    assert!(get_sub_matrix(&matrix, &[3]).is_err());
+}
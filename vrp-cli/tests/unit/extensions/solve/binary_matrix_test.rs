@@ -0,0 +1,49 @@
+use super::*;
+use std::io::BufWriter;
+use vrp_pragmatic::format::problem::{Matrix, write_binary_matrix};
+
+const PRAGMATIC_MATRIX_PATH: &str = "../examples/data/pragmatic/simple.basic.matrix.json";
+
+fn create_binary_matrix_file() -> (tempfile::NamedTempFile, Matrix) {
+    let file = tempfile::NamedTempFile::new().unwrap();
+    let matrix: Matrix = serde_json::from_reader(File::open(PRAGMATIC_MATRIX_PATH).unwrap()).unwrap();
+    write_binary_matrix(&matrix, &mut BufWriter::new(file.reopen().unwrap())).unwrap();
+
+    (file, matrix)
+}
+
+#[test]
+fn can_map_binary_matrices() {
+    let (file, matrix) = create_binary_matrix_file();
+
+    let matrices = read_binary_matrices(&mut [file.reopen().unwrap()]).unwrap().unwrap();
+
+    assert_eq!(matrices.len(), 1);
+    assert_eq!(matrices[0].profile, matrix.profile);
+    assert_eq!(matrices[0].size, 4);
+    assert_eq!(matrices[0].distances().values(), matrix.distances.iter().map(|&v| v as f32).collect::<Vec<_>>());
+}
+
+#[test]
+fn can_skip_json_matrices() {
+    let mut json_file = File::open(PRAGMATIC_MATRIX_PATH).unwrap();
+
+    let result = read_binary_matrices(std::slice::from_mut(&mut json_file)).unwrap();
+
+    assert!(result.is_none());
+    let mut content = String::new();
+    json_file.read_to_string(&mut content).unwrap();
+    assert!(content.trim_start().starts_with('{'));
+}
+
+#[test]
+fn can_detect_mixed_matrices() {
+    let (file, _) = create_binary_matrix_file();
+
+    let result = read_binary_matrices(&mut [file.reopen().unwrap(), File::open(PRAGMATIC_MATRIX_PATH).unwrap()]);
+
+    assert_eq!(
+        result.err().map(|err| err.to_string()),
+        Some("mixing binary and json routing matrices is not supported".to_string())
+    );
+}
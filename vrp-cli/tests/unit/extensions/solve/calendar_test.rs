@@ -0,0 +1,61 @@
+use super::*;
+use std::fs::File;
+use std::io::BufReader;
+use vrp_pragmatic::format::solution::deserialize_solution;
+
+const PRAGMATIC_SOLUTION_PATH: &str = "../examples/data/pragmatic/simple.basic.solution.json";
+
+fn get_solution() -> Solution {
+    deserialize_solution(BufReader::new(File::open(PRAGMATIC_SOLUTION_PATH).unwrap())).unwrap()
+}
+
+#[test]
+fn can_write_tour_as_ics() {
+    let solution = get_solution();
+    let tour = solution.tours.first().unwrap();
+    let mut writer = BufWriter::new(Vec::new());
+
+    write_tour_as_ics(tour, &mut writer).unwrap();
+
+    let content = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+    assert!(content.starts_with("BEGIN:VCALENDAR\r\n"));
+    assert!(content.ends_with("END:VCALENDAR\r\n"));
+    assert_eq!(content.matches("BEGIN:VEVENT\r\n").count(), tour.stops.len());
+    assert_eq!(content.matches("\r\nGEO:").count(), tour.stops.iter().filter(|stop| stop.as_point().is_some()).count());
+    assert!(content.contains(&format!("DTSTART:{}\r\n", format_ics_time(tour.stops[0].schedule().arrival.as_str()))));
+    assert!(content.split("\r\n").all(|line| line.len() <= MAX_LINE_LENGTH));
+}
+
+#[test]
+fn can_write_solution_as_ics_files() {
+    let solution = get_solution();
+    let out_dir = tempfile::tempdir().unwrap();
+
+    write_solution_as_ics(&solution, out_dir.path()).unwrap();
+
+    assert_eq!(std::fs::read_dir(out_dir.path()).unwrap().count(), solution.tours.len());
+    solution.tours.iter().for_each(|tour| assert!(out_dir.path().join(get_tour_file_name(tour)).exists()));
+}
+
+#[test]
+fn can_format_ics_time() {
+    assert_eq!(format_ics_time("2019-07-04T09:00:00Z"), "20190704T090000Z");
+}
+
+#[test]
+fn can_escape_text() {
+    assert_eq!(escape_text("a,b;c\\d\ne"), r"a\,b\;c\\d\ne");
+}
+
+#[test]
+fn can_fold_long_line() {
+    let line = format!("DESCRIPTION:{}", "x".repeat(100));
+
+    let folded = fold_line(line.as_str());
+
+    let lines = folded.split("\r\n").collect::<Vec<_>>();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0].len(), MAX_LINE_LENGTH);
+    assert!(lines[1].starts_with(' '));
+    assert_eq!(lines.concat().replacen(' ', "", 1), line);
+}
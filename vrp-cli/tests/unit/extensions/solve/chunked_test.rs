@@ -0,0 +1,60 @@
+use super::*;
+use crate::extensions::solve::config::VariationConfig;
+use crate::extensions::solve::progress::JsonLineWriter;
+use std::io::BufReader;
+use std::sync::Mutex;
+use vrp_pragmatic::format::problem::PragmaticProblem;
+
+const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
+
+fn create_problem() -> Arc<Problem> {
+    Arc::new(BufReader::new(std::fs::File::open(PRAGMATIC_PROBLEM_PATH).unwrap()).read_pragmatic().unwrap())
+}
+
+fn create_config(max_generations: Option<usize>, variation: Option<VariationConfig>) -> Config {
+    Config { termination: Some(TerminationConfig { max_time: None, max_generations, variation }), ..Config::default() }
+}
+
+#[test]
+fn can_solve_problem_in_chunks() {
+    let mut solver = ChunkedSolver::new(create_problem(), create_config(Some(10), None)).unwrap();
+    assert!(solver.solution().is_none());
+
+    let steps = (0..10).map(|_| solver.step(4).unwrap()).take_while(|is_finished| !is_finished).count();
+
+    assert_eq!(steps, 2);
+    assert!(solver.is_finished());
+    assert_eq!(solver.generation(), 10);
+    let solution = solver.solution().unwrap();
+    assert!(solution.unassigned.is_empty());
+    assert!(!solution.routes.is_empty());
+}
+
+#[test]
+fn can_report_progress_of_all_chunks() {
+    let lines = Arc::new(Mutex::new(Vec::<serde_json::Value>::new()));
+    let writer: JsonLineWriter = {
+        let lines = lines.clone();
+        Arc::new(move |line: &str| lines.lock().unwrap().push(serde_json::from_str(line).unwrap()))
+    };
+    let mut solver = ChunkedSolver::new(create_problem(), create_config(Some(6), None))
+        .unwrap()
+        .with_progress(JsonProgressTelemetry::new(1, writer));
+
+    while !solver.step(3).unwrap() {}
+
+    let generations = lines.lock().unwrap().iter().map(|line| line["generation"].as_u64().unwrap()).collect::<Vec<_>>();
+    assert_eq!(generations, (0..=6).collect::<Vec<_>>());
+}
+
+#[test]
+fn can_reject_variation_termination() {
+    let variation = VariationConfig { interval_type: "sample".to_string(), value: 100, cv: 0.1, is_global: true };
+
+    let result = ChunkedSolver::new(create_problem(), create_config(None, Some(variation)));
+
+    assert_eq!(
+        result.err().map(|err| err.to_string()),
+        Some("variation termination is not supported by chunked solver".to_string())
+    );
+}
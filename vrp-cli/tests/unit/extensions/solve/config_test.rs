@@ -49,12 +49,16 @@ fn can_read_full_config() {
         }
         _ => unreachable!(),
     }
+    assert_eq!(
+        evolution_config.restart.map(|restart| (restart.stagnation_window, restart.trajectory_size)),
+        Some((2000, Some(200)))
+    );
 
     let hyper_config = config.hyper.expect("cannot get hyper");
     match hyper_config {
         HyperType::StaticSelective { operators } => {
             let operators = operators.expect("cannot get operators");
-            assert_eq!(operators.len(), 4);
+            assert_eq!(operators.len(), 5);
             match operators.first().unwrap() {
                 SearchOperatorType::Decomposition { routes, repeat, probability } => {
                     assert_eq!(*repeat, 4);
@@ -90,6 +94,15 @@ fn can_read_full_config() {
                 _ => unreachable!(),
             }
 
+            match operators.get(3).unwrap() {
+                SearchOperatorType::GuidedLocalSearch { probability, times, operators: inners } => {
+                    assert_eq!(as_scalar_probability(probability), 0.01);
+                    assert_eq!(*times, MinMaxConfig { min: 1, max: 1 });
+                    assert_eq!(inners.len(), 1);
+                }
+                _ => unreachable!(),
+            }
+
             match operators.last().unwrap() {
                 SearchOperatorType::LocalSearch { probability, times, operators: inners } => {
                     assert_eq!(as_scalar_probability(probability), 0.01);
@@ -119,6 +132,14 @@ fn can_read_full_config() {
 
     let output_cfg = config.output.expect("cannot read output config");
     assert_eq!(output_cfg.include_geojson, Some(true));
+
+    assert_eq!(config.polishing, Some(PolishingConfig { enabled: true, max_exact_size: Some(12) }));
+    assert_eq!(config.fleet_minimization, Some(FleetMinimizationConfig { enabled: true, max_iterations: Some(200) }));
+    assert_eq!(
+        config.set_partitioning,
+        Some(SetPartitioningConfig { enabled: true, max_pool_size: Some(1000), max_iterations: Some(100) })
+    );
+    assert_eq!(config.lower_bound, Some(LowerBoundConfig { enabled: true }));
 }
 
 #[test]
@@ -129,6 +150,10 @@ fn can_create_default_config() {
     assert!(config.hyper.is_none());
     assert!(config.termination.is_none());
     assert!(config.telemetry.is_none());
+    assert!(config.polishing.is_none());
+    assert!(config.fleet_minimization.is_none());
+    assert!(config.set_partitioning.is_none());
+    assert!(config.lower_bound.is_none());
 }
 
 #[test]
@@ -143,12 +168,16 @@ fn can_configure_telemetry_metrics() {
             metrics: Some(MetricsConfig { enabled: true, track_population: Some(10) }),
         }),
         output: None,
+        polishing: None,
+        fleet_minimization: None,
+        set_partitioning: None,
+        lower_bound: None,
     };
 
     let solution = create_builder_from_config(create_example_problem(), Vec::default(), &config)
         .and_then(|config_builder| config_builder.build())
         .map(|evolution_config| Solver::new(create_example_problem(), evolution_config))
-        .and_then(|solver| solver.solve())
+        .and_then(|solver| solver.solve().map_err(From::from))
         .unwrap();
 
     let metrics = solution.telemetry.expect("no metrics");
@@ -162,3 +191,212 @@ fn as_scalar_probability(probability: &OperatorProbabilityType) -> Float {
         _ => unreachable!(),
     }
 }
+
+#[test]
+fn can_read_search_config() {
+    let file = File::open("../examples/data/config/config.search.json").expect("cannot read config from file");
+
+    let config = read_search_config(BufReader::new(file)).unwrap();
+
+    let ruins = config.ruins.expect("no ruins");
+    assert_eq!(ruins.len(), 2);
+    assert_eq!(ruins[0].name, "worst-job");
+    assert_eq!(ruins[0].weight, 0);
+    assert!(ruins[0].methods.is_none());
+    assert_eq!(ruins[1].methods.as_ref().map(|methods| methods.len()), Some(1));
+
+    let recreates = config.recreates.expect("no recreates");
+    assert_eq!(recreates.len(), 2);
+    match recreates[0].method {
+        Some(RecreateMethod::Regret { weight: 30, start: 2, end: 4 }) => {}
+        _ => unreachable!(),
+    }
+    assert_eq!(recreates[1].weight, Some(0));
+}
+
+#[test]
+fn can_create_ruin_recreate_from_search_config() {
+    let problem = create_example_problem();
+    let environment = Arc::new(Environment::default());
+    let file = File::open("../examples/data/config/config.search.json").expect("cannot read config from file");
+    let config = read_search_config(BufReader::new(file)).unwrap();
+
+    let result = create_ruin_recreate_from_search_config(problem, environment, &config);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn can_return_error_for_unknown_method_in_search_config() {
+    let problem = create_example_problem();
+    let environment = Arc::new(Environment::default());
+    let config = serde_json::from_str::<SearchConfig>(r#"{"ruins": [{"name": "unknown", "weight": 1}]}"#).unwrap();
+
+    let result = create_ruin_recreate_from_search_config(problem, environment, &config);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn can_configure_warm_restart() {
+    let create_config = |stagnation_window: usize| {
+        serde_json::from_str::<Config>(&format!(
+            r#"{{"evolution": {{"restart": {{"stagnationWindow": {stagnation_window}}}}}}}"#
+        ))
+        .unwrap()
+    };
+
+    let create_config_with_trajectory = |trajectory_size: usize| {
+        serde_json::from_str::<Config>(&format!(
+            r#"{{"evolution": {{"restart": {{"stagnationWindow": 100, "trajectorySize": {trajectory_size}}}}}}}"#
+        ))
+        .unwrap()
+    };
+
+    assert!(create_builder_from_config(create_example_problem(), Vec::default(), &create_config(100)).is_ok());
+    assert!(create_builder_from_config(create_example_problem(), Vec::default(), &create_config(0)).is_err());
+    assert!(
+        create_builder_from_config(create_example_problem(), Vec::default(), &create_config_with_trajectory(10))
+            .is_ok()
+    );
+    assert!(
+        create_builder_from_config(create_example_problem(), Vec::default(), &create_config_with_trajectory(0))
+            .is_err()
+    );
+}
+
+#[test]
+fn can_write_and_read_config_in_toml_format() {
+    let file = File::open("../examples/data/config/config.full.json").expect("cannot read config from file");
+    let config = read_config(BufReader::new(file)).unwrap();
+
+    let mut buffer = Vec::new();
+    write_config(&mut buffer, &config, ConfigFormat::Toml).unwrap();
+    let restored = read_config_with_format(BufReader::new(buffer.as_slice()), ConfigFormat::Toml).unwrap();
+
+    assert_eq!(serde_json::to_value(&restored).unwrap(), serde_json::to_value(&config).unwrap());
+}
+
+#[test]
+fn can_read_config_in_toml_format() {
+    let content = r#"
+        [termination]
+        maxTime = 10
+
+        [environment]
+        randomSeed = 42
+        isExperimental = false
+
+        [evolution.population]
+        type = "rosomaxa"
+        explorationRatio = 0.5
+    "#;
+
+    let config = read_config_with_format(BufReader::new(content.as_bytes()), ConfigFormat::Toml).unwrap();
+
+    assert_eq!(config.termination.and_then(|termination| termination.max_time), Some(10));
+    assert_eq!(config.environment.and_then(|environment| environment.random_seed), Some(42));
+    match config.evolution.and_then(|evolution| evolution.population) {
+        Some(PopulationType::Rosomaxa { exploration_ratio, .. }) => assert_eq!(exploration_ratio, Some(0.5)),
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn can_detect_config_format() {
+    assert_eq!(ConfigFormat::from_path(Path::new("solver.toml")), ConfigFormat::Toml);
+    assert_eq!(ConfigFormat::from_path(Path::new("solver.TOML")), ConfigFormat::Toml);
+    assert_eq!(ConfigFormat::from_path(Path::new("solver.json")), ConfigFormat::Json);
+    assert_eq!(ConfigFormat::from_path(Path::new("solver")), ConfigFormat::Json);
+}
+
+#[test]
+fn can_reject_unknown_config_fields() {
+    for content in [r#"{"unknown": 1}"#, r#"{"termination": {"maxTimeout": 10}}"#] {
+        let result = read_config(BufReader::new(content.as_bytes()));
+
+        assert!(result.unwrap_err().to_string().contains("unknown field"));
+    }
+}
+
+#[test]
+fn can_validate_config_values() {
+    let content = r#"{
+        "evolution": { "population": { "type": "rosomaxa", "explorationRatio": 1.5, "selectionSize": 0 } },
+        "termination": { "maxTime": 0, "variation": { "intervalType": "unknown", "value": 10, "cv": 1, "isGlobal": true } },
+        "environment": { "parallelism": { "numThreadPools": 0, "threadsPerPool": 1 } },
+        "polishing": { "enabled": true, "maxExactSize": 64 }
+    }"#;
+
+    let error = read_config(BufReader::new(content.as_bytes())).unwrap_err().to_string();
+
+    ["explorationRatio", "selectionSize", "maxTime", "intervalType", "parallelism", "maxExactSize"]
+        .iter()
+        .for_each(|name| assert!(error.contains(name), "'{name}' is not in '{error}'"));
+}
+
+#[test]
+fn can_configure_random_seed() {
+    let config = serde_json::from_str::<Config>(r#"{"environment": {"randomSeed": 42}}"#).unwrap();
+
+    let environment = configure_from_environment(&config.environment, None, None);
+
+    assert!(environment.random.is_repeatable());
+    assert!(!configure_from_environment(&None, None, None).random.is_repeatable());
+}
+
+#[test]
+fn can_configure_memory_budget() {
+    let config = serde_json::from_str::<Config>(r#"{"environment": {"memoryBudget": 2048}}"#).unwrap();
+
+    let environment = configure_from_environment(&config.environment, None, None);
+
+    assert_eq!(environment.memory_budget, Some(2048 * 1024 * 1024));
+    assert_eq!(configure_from_environment(&None, None, None).memory_budget, None);
+}
+
+#[test]
+fn can_configure_deterministic_mode() {
+    let config = serde_json::from_str::<Config>(r#"{"environment": {"isDeterministic": true}}"#).unwrap();
+
+    let environment = configure_from_environment(&config.environment, None, None);
+
+    assert!(environment.parallelism.is_deterministic());
+    assert!(environment.random.is_repeatable());
+    assert!(!configure_from_environment(&None, None, None).parallelism.is_deterministic());
+}
+
+#[test]
+fn can_configure_external_quota() {
+    struct FixedQuota(bool);
+
+    impl Quota for FixedQuota {
+        fn is_reached(&self) -> bool {
+            self.0
+        }
+    }
+
+    let is_reached = |max_time: Option<usize>, quota: bool| {
+        configure_from_environment(&None, max_time, Some(Arc::new(FixedQuota(quota))))
+            .quota
+            .as_ref()
+            .is_some_and(|quota| quota.is_reached())
+    };
+
+    assert!(is_reached(None, true));
+    assert!(is_reached(Some(3600), true));
+    assert!(!is_reached(None, false));
+    assert!(!is_reached(Some(3600), false));
+    assert!(configure_from_environment(&None, None, None).quota.is_none());
+}
+
+#[test]
+fn can_create_arc_penalties_only_for_guided_local_search() {
+    let file = File::open("../examples/data/config/config.full.json").expect("cannot read config from file");
+    let config = read_config(BufReader::new(file)).unwrap();
+    let problem = create_example_problem();
+
+    assert!(create_arc_penalties(&config).is_some());
+    assert!(create_arc_penalties(&Config::default()).is_none());
+    assert!(create_builder_from_config(problem, Vec::default(), &config).is_ok());
+}
@@ -0,0 +1,85 @@
+use super::*;
+use crate::extensions::solve::config::{Config, TerminationConfig, create_builder_from_config};
+use std::io::BufReader;
+use vrp_pragmatic::format::problem::PragmaticProblem;
+
+const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
+
+fn create_collecting_writer() -> (JsonLineWriter, Arc<Mutex<Vec<serde_json::Value>>>) {
+    let lines = Arc::new(Mutex::new(Vec::new()));
+    let writer: JsonLineWriter = {
+        let lines = lines.clone();
+        Arc::new(move |line: &str| lines.lock().unwrap().push(serde_json::from_str(line).unwrap()))
+    };
+
+    (writer, lines)
+}
+
+#[test]
+fn can_write_search_progress_as_json_lines() {
+    let problem =
+        Arc::new(BufReader::new(std::fs::File::open(PRAGMATIC_PROBLEM_PATH).unwrap()).read_pragmatic().unwrap());
+    let config = Config {
+        termination: Some(TerminationConfig { max_time: None, max_generations: Some(10), variation: None }),
+        ..Config::default()
+    };
+    let (writer, lines) = create_collecting_writer();
+
+    let solver_config = create_builder_from_config(problem.clone(), Vec::default(), &config)
+        .unwrap()
+        .with_termination(Box::new(JsonProgressTelemetry::new(2, writer)))
+        .build()
+        .unwrap();
+    Solver::new(problem, solver_config).solve().unwrap();
+
+    let lines = lines.lock().unwrap();
+    assert!(!lines.is_empty());
+    assert!(lines.iter().all(|line| line["type"] == "progress"));
+    let generations = lines.iter().map(|line| line["generation"].as_u64().unwrap()).collect::<Vec<_>>();
+    assert_eq!(generations, vec![0, 2, 4, 6, 8, 10]);
+    assert!(lines.iter().all(|line| line["bestCost"].as_f64().is_some()));
+    assert_eq!(lines[0]["isImproved"], serde_json::Value::Bool(true));
+}
+
+#[test]
+fn can_write_log_messages_as_json_lines() {
+    let (writer, lines) = create_collecting_writer();
+    let logger = create_json_logger(writer);
+
+    (logger)("some message");
+
+    let lines = lines.lock().unwrap();
+    assert_eq!(lines.len(), 1);
+    assert_eq!(lines[0], serde_json::json!({ "type": "message", "message": "some message" }));
+}
+
+#[test]
+fn can_write_result_as_json_line() {
+    let summary = SolveSummary {
+        instance: "name".to_string(),
+        format: "pragmatic".to_string(),
+        cost: 10.,
+        tours: 1,
+        unassigned: 0,
+        duration: 1.,
+        generations: None,
+        best_known: None,
+        lower_bound: None,
+        termination: None,
+        trajectory: vec![],
+        operators: vec![],
+        cpu_time: None,
+        memory_peak: None,
+        memory_estimate: None,
+        memory_shrinks: None,
+        properties: Default::default(),
+    };
+    let (writer, lines) = create_collecting_writer();
+
+    write_progress_event(&ProgressEvent::Result(Box::new(summary)), &writer);
+
+    let lines = lines.lock().unwrap();
+    assert_eq!(lines[0]["type"], "result");
+    assert_eq!(lines[0]["instance"], "name");
+    assert_eq!(lines[0]["cost"].as_f64(), Some(10.));
+}
@@ -0,0 +1,154 @@
+use super::*;
+use std::fs::File;
+use std::io::BufReader;
+use vrp_core::construction::heuristics::UnassignmentInfo;
+use vrp_core::models::solution::Registry;
+use vrp_core::prelude::Environment;
+use vrp_core::rosomaxa::evolution::{TelemetryFitness, TelemetryMetrics, TelemetryOperator};
+use vrp_scientific::solomon::SolomonProblem;
+
+fn create_empty_solution(cost: Float) -> Solution {
+    let file = File::open("../examples/data/scientific/solomon/C101.25.txt").unwrap();
+    let problem = BufReader::new(file).read_solomon(false).unwrap();
+
+    Solution {
+        cost,
+        registry: Registry::new(&problem.fleet, Environment::default().random),
+        routes: vec![],
+        unassigned: vec![],
+        telemetry: None,
+    }
+}
+
+parameterized_test! {can_get_instance_name, (path, expected), {
+    assert_eq!(get_instance_name(path), expected);
+}}
+
+can_get_instance_name! {
+    case01_with_extension: ("../data/C101.txt", "C101"),
+    case02_without_extension: ("data/X-n101-k25", "X-n101-k25"),
+    case03_with_dots: ("C101.100.txt", "C101.100"),
+}
+
+#[test]
+fn can_create_summary_with_best_known_solution() {
+    let summary = SolveSummary::new("data/C101.txt", "solomon", &create_empty_solution(870.387), 1.5);
+
+    let best_known = summary.best_known.as_ref().expect("no best known solution");
+    assert_eq!(best_known.tours, Some(10));
+    assert!((best_known.gap - 5.).abs() < 1E-3);
+    assert_eq!(
+        summary.get_gap_message(),
+        Some("best known solution of 'C101': cost: 870.39 vs 828.94, tours: 0 vs 10, gap: 5.00%".to_string())
+    );
+}
+
+parameterized_test! {can_skip_best_known_solution, (path, format), {
+    let summary = SolveSummary::new(path, format, &create_empty_solution(100.), 1.);
+
+    assert!(summary.best_known.is_none());
+    assert!(summary.get_gap_message().is_none());
+}}
+
+can_skip_best_known_solution! {
+    case01_unknown_instance: ("data/unknown.txt", "solomon"),
+    case02_pragmatic_format: ("data/C101.json", "pragmatic"),
+}
+
+#[test]
+fn can_write_summary() {
+    let summary = SolveSummary::new("data/unknown.txt", "solomon", &create_empty_solution(100.), 1.);
+    let mut writer = BufWriter::new(Vec::new());
+
+    write_solve_summary(&summary, &mut writer).unwrap();
+
+    let content = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+    assert!(content.contains("\"instance\": \"unknown\""));
+    assert!(!content.contains("bestKnown"));
+    assert!(!content.contains("generations"));
+}
+
+#[test]
+fn can_create_summary_with_telemetry_metrics() {
+    let solution = Solution {
+        telemetry: Some(TelemetryMetrics {
+            duration: 1,
+            generations: 10,
+            speed: 10.,
+            evolution: vec![],
+            termination: Some("max-generation".to_string()),
+            best_fitness: vec![TelemetryFitness { generation: 2, timestamp: 0.5, fitness: vec![0., 100.] }],
+            operators: vec![TelemetryOperator { name: "local".to_string(), calls: 5, improvements: 1 }],
+            memory_peak: Some(1024),
+            memory_shrinks: 2,
+            properties: vec![("fleet_lower_bound".to_string(), 3.)],
+        }),
+        ..create_empty_solution(100.)
+    };
+
+    let summary = SolveSummary::new("data/unknown.txt", "solomon", &solution, 1.);
+
+    assert_eq!(summary.generations, Some(10));
+    assert_eq!(summary.termination.as_deref(), Some("max-generation"));
+    assert_eq!(summary.trajectory.len(), 1);
+    assert_eq!(summary.trajectory[0].fitness, vec![0., 100.]);
+    assert_eq!(summary.operators.len(), 1);
+    assert_eq!((summary.operators[0].calls, summary.operators[0].improvements), (5, 1));
+    assert_eq!(summary.memory_estimate, Some(1024));
+    assert_eq!(summary.memory_shrinks, Some(2));
+    assert_eq!(summary.properties.get("fleet_lower_bound"), Some(&3.));
+}
+
+parameterized_test! {can_create_summary_with_lower_bound, (lower_bound, has_unassigned, expected), {
+    can_create_summary_with_lower_bound_impl(lower_bound, has_unassigned, expected);
+}}
+
+can_create_summary_with_lower_bound! {
+    case01_with_bound: (Some(80.), false, Some((80., 20.))),
+    case02_without_bound: (None, false, None),
+    case03_with_unassigned: (Some(80.), true, None),
+}
+
+fn can_create_summary_with_lower_bound_impl(
+    lower_bound: Option<Float>,
+    has_unassigned: bool,
+    expected: Option<(Float, Float)>,
+) {
+    let mut solution = create_empty_solution(100.);
+    solution.telemetry = Some(TelemetryMetrics {
+        duration: 1,
+        generations: 10,
+        speed: 10.,
+        evolution: vec![],
+        termination: None,
+        best_fitness: vec![],
+        operators: vec![],
+        memory_peak: None,
+        memory_shrinks: 0,
+        properties: lower_bound.map(|cost| (COST_LOWER_BOUND_PROPERTY.to_string(), cost)).into_iter().collect(),
+    });
+    if has_unassigned {
+        let file = File::open("../examples/data/scientific/solomon/C101.25.txt").unwrap();
+        let problem = BufReader::new(file).read_solomon(false).unwrap();
+        let job = problem.jobs.all().first().cloned().unwrap();
+        solution.unassigned.push((job, UnassignmentInfo::Unknown));
+    }
+
+    let summary = SolveSummary::new("data/unknown.txt", "solomon", &solution, 1.);
+
+    let result = summary.lower_bound.as_ref().map(|lower_bound| (lower_bound.cost, lower_bound.gap));
+    assert_eq!(result, expected);
+    assert_eq!(summary.get_lower_bound_message().is_some(), expected.is_some());
+}
+
+#[cfg(unix)]
+#[test]
+fn can_add_resource_usage() {
+    let summary = SolveSummary::new("data/unknown.txt", "solomon", &create_empty_solution(100.), 1.);
+    assert!(summary.cpu_time.is_none());
+
+    let summary = summary.with_resource_usage();
+
+    assert!(summary.cpu_time.is_some_and(|cpu_time| cpu_time > 0.));
+    assert!(summary.memory_peak.is_some_and(|memory_peak| memory_peak > 0));
+}
@@ -1,14 +1,57 @@
 use super::*;
+use crate::extensions::solve::config::TerminationConfig;
 use crate::helpers::generate::{create_empty_plan, create_test_job, create_test_vehicle_type};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use vrp_pragmatic::format::MultiFormatError;
 use vrp_pragmatic::format::problem::{Fleet, MatrixProfile, Plan};
 
+struct CancellationQuota {
+    is_cancelled: Arc<AtomicBool>,
+    checks: AtomicUsize,
+}
+
+impl Quota for CancellationQuota {
+    fn is_reached(&self) -> bool {
+        self.checks.fetch_add(1, Ordering::Relaxed);
+        self.is_cancelled.load(Ordering::Relaxed)
+    }
+}
+
+fn create_core_problem() -> Arc<CoreProblem> {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_test_job(1., 0.)], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![create_test_vehicle_type()],
+            profiles: vec![MatrixProfile { name: "car".to_string(), speed: None, restrictions: None }],
+            resources: None,
+            depots: None,
+        },
+        objectives: None,
+        timezone: None,
+    };
+
+    Arc::new(problem.read_pragmatic().unwrap())
+}
+
+fn create_config_with_max_generations(max_generations: usize) -> Config {
+    Config {
+        termination: Some(TerminationConfig {
+            max_time: None,
+            max_generations: Some(max_generations),
+            variation: None,
+        }),
+        ..Config::default()
+    }
+}
+
 #[test]
 fn can_get_locations_serialized() {
     let problem = Problem {
         plan: Plan { jobs: vec![create_test_job(1., 1.), create_test_job(1., 0.)], ..create_empty_plan() },
-        fleet: Fleet { vehicles: vec![create_test_vehicle_type()], profiles: vec![], resources: None },
+        fleet: Fleet { vehicles: vec![create_test_vehicle_type()], profiles: vec![], resources: None, depots: None },
         objectives: None,
+        timezone: None,
     };
 
     let locations = get_locations_serialized(&problem).unwrap().replace([' ', '\n'], "");
@@ -22,10 +65,12 @@ fn can_get_solution_serialized() {
         plan: Plan { jobs: vec![create_test_job(1., 0.)], ..create_empty_plan() },
         fleet: Fleet {
             vehicles: vec![create_test_vehicle_type()],
-            profiles: vec![MatrixProfile { name: "car".to_string(), speed: None }],
+            profiles: vec![MatrixProfile { name: "car".to_string(), speed: None, restrictions: None }],
             resources: None,
+            depots: None,
         },
         objectives: None,
+        timezone: None,
     };
     let problem = Arc::new(problem.read_pragmatic().unwrap());
 
@@ -38,6 +83,85 @@ fn can_get_solution_serialized() {
     assert!(solution.contains("stops"));
 }
 
+#[test]
+fn can_get_solution_serialized_with_progress() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_test_job(1., 0.)], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![create_test_vehicle_type()],
+            profiles: vec![MatrixProfile { name: "car".to_string(), speed: None, restrictions: None }],
+            resources: None,
+            depots: None,
+        },
+        objectives: None,
+        timezone: None,
+    };
+    let problem = Arc::new(problem.read_pragmatic().unwrap());
+    let config = Config {
+        termination: Some(TerminationConfig { max_time: None, max_generations: Some(10), variation: None }),
+        ..Config::default()
+    };
+    let lines = Arc::new(std::sync::Mutex::new(Vec::<String>::new()));
+    let progress = JsonProgressTelemetry::new(1, {
+        let lines = lines.clone();
+        Arc::new(move |line: &str| lines.lock().unwrap().push(line.to_string()))
+    });
+
+    let solution = get_solution_serialized_with_progress(problem, config, Some(progress)).unwrap();
+
+    assert!(solution.contains("tours"));
+    let lines = lines.lock().unwrap();
+    assert!(!lines.is_empty());
+    assert!(lines.iter().all(|line| line.contains(r#""type":"progress""#)));
+}
+
+#[test]
+fn can_return_best_known_solution_when_cancelled_before_search() {
+    let quota =
+        Arc::new(CancellationQuota { is_cancelled: Arc::new(AtomicBool::new(true)), checks: Default::default() });
+
+    let solution = get_solution_serialized_with_quota(
+        create_core_problem(),
+        create_config_with_max_generations(1_000_000),
+        None,
+        Some(quota.clone() as Arc<dyn Quota>),
+    )
+    .unwrap();
+
+    assert!(solution.contains("tours"));
+    assert!(quota.checks.load(Ordering::Relaxed) > 0);
+}
+
+#[test]
+fn can_stop_reporting_progress_when_cancelled() {
+    let is_cancelled = Arc::new(AtomicBool::new(false));
+    let quota = Arc::new(CancellationQuota { is_cancelled: is_cancelled.clone(), checks: Default::default() });
+    let lines = Arc::new(Mutex::new(Vec::<String>::new()));
+    let progress = JsonProgressTelemetry::new(1, {
+        let lines = lines.clone();
+        Arc::new(move |line: &str| {
+            let mut lines = lines.lock().unwrap();
+            lines.push(line.to_string());
+            if lines.len() == 3 {
+                is_cancelled.store(true, Ordering::Relaxed);
+            }
+        })
+    });
+
+    let solution = get_solution_serialized_with_quota(
+        create_core_problem(),
+        create_config_with_max_generations(1_000_000),
+        Some(progress),
+        Some(quota),
+    )
+    .unwrap();
+
+    assert!(solution.contains("tours"));
+    let lines = lines.lock().unwrap();
+    assert_eq!(lines.len(), 3);
+    assert!(lines.iter().all(|line| line.contains(r#""type":"progress""#)));
+}
+
 #[test]
 fn can_get_errors_serialized() {
     let errors = vec![
@@ -63,3 +187,31 @@ fn can_get_config_error() {
     assert!(result.contains("E0004"));
     assert!(result.contains("cannot read config"));
 }
+
+parameterized_test! {can_create_matrix_from_arrays, (durations, distances, expected), {
+    can_create_matrix_from_arrays_impl(durations, distances, expected);
+}}
+
+can_create_matrix_from_arrays! {
+    case01_valid: (vec![0., 1.4, 2.6, 0.], vec![0., 10., 20., 0.], Ok((vec![0, 1, 3, 0], vec![0, 10, 20, 0]))),
+    case02_empty: (vec![], vec![], Ok((vec![], vec![]))),
+    case03_different_size: (vec![0., 1., 1., 0.], vec![0.], Err("durations and distances have different size: 4 vs 1")),
+    case04_not_square: (vec![0., 1.], vec![0., 1.], Err("matrix size is not a square: 2")),
+    case05_negative: (vec![0., -1., 1., 0.], vec![0., 1., 1., 0.], Err("durations has invalid value: -1")),
+    case06_not_finite: (vec![0., 1., 1., 0.], vec![0., f64::NAN, 1., 0.], Err("distances has invalid value: NaN")),
+}
+
+fn can_create_matrix_from_arrays_impl(
+    durations: Vec<f64>,
+    distances: Vec<f64>,
+    expected: Result<(Vec<i64>, Vec<i64>), &str>,
+) {
+    let result = create_matrix_from_arrays(Some("car".to_string()), durations.as_slice(), distances.as_slice())
+        .map(|matrix| {
+            assert_eq!(matrix.profile, Some("car".to_string()));
+            (matrix.travel_times, matrix.distances)
+        })
+        .map_err(|err| err.to_string());
+
+    assert_eq!(result, expected.map_err(|err| err.to_string()));
+}
@@ -111,6 +111,7 @@ fn define_problem(goal: GoalContext, transport: Arc<dyn TransportCost>) -> Gener
         .with_goal(goal)
         .with_transport_cost(transport)
         .build()
+        .map_err(From::from)
 }
 
 /// Defines optimization goal as CVRP variant with a priority objective function on top.
@@ -56,6 +56,7 @@ fn define_problem(goal: GoalContext, transport: Arc<dyn TransportCost>) -> Gener
         .with_goal(goal)
         .with_transport_cost(transport)
         .build()
+        .map_err(From::from)
 }
 
 /// Defines CVRP variant as a goal of optimization.
@@ -2,3 +2,6 @@
 
 mod point;
 pub use self::point::Point;
+
+mod polygon;
+pub use self::polygon::{get_convex_hull, get_convex_intersection_area, get_polygon_area};
@@ -0,0 +1,105 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/algorithms/geometry/polygon_test.rs"]
+mod polygon_test;
+
+use super::Point;
+use rosomaxa::prelude::Float;
+
+/// Builds a convex hull of given points using monotone chain algorithm.
+/// Returns hull's vertices in counter-clockwise order without collinear points.
+pub fn get_convex_hull(points: &[Point]) -> Vec<Point> {
+    let mut points = points.to_vec();
+    points.sort_by(|a, b| a.x.total_cmp(&b.x).then_with(|| a.y.total_cmp(&b.y)));
+    points.dedup();
+
+    if points.len() < 3 {
+        return points;
+    }
+
+    let mut hull: Vec<Point> = Vec::with_capacity(points.len() * 2);
+
+    // lower hull
+    for point in points.iter() {
+        while hull.len() >= 2 && Point::cross_product(&hull[hull.len() - 2], &hull[hull.len() - 1], point) <= 0. {
+            hull.pop();
+        }
+        hull.push(point.clone());
+    }
+
+    // upper hull
+    let lower_len = hull.len() + 1;
+    for point in points.iter().rev().skip(1) {
+        while hull.len() >= lower_len && Point::cross_product(&hull[hull.len() - 2], &hull[hull.len() - 1], point) <= 0.
+        {
+            hull.pop();
+        }
+        hull.push(point.clone());
+    }
+
+    // NOTE the last point is the same as the first one
+    hull.pop();
+
+    hull
+}
+
+/// Calculates area of a simple polygon defined by its vertices.
+pub fn get_polygon_area(polygon: &[Point]) -> Float {
+    if polygon.len() < 3 {
+        return 0.;
+    }
+
+    let double_area = polygon
+        .iter()
+        .zip(polygon.iter().cycle().skip(1))
+        .map(|(current, next)| current.x * next.y - next.x * current.y)
+        .sum::<Float>();
+
+    double_area.abs() / 2.
+}
+
+/// Calculates area of intersection of two convex polygons which vertices are given in
+/// counter-clockwise order (e.g. as returned by [get_convex_hull]).
+pub fn get_convex_intersection_area(subject: &[Point], clip: &[Point]) -> Float {
+    if subject.len() < 3 || clip.len() < 3 {
+        return 0.;
+    }
+
+    // NOTE Sutherland-Hodgman algorithm: clip subject polygon by each edge of clip polygon
+    let intersection = clip.iter().zip(clip.iter().cycle().skip(1)).try_fold(subject.to_vec(), |input, (a, b)| {
+        if input.is_empty() {
+            return None;
+        }
+
+        let is_inside = |point: &Point| Point::cross_product(a, b, point) >= 0.;
+
+        let output = input.iter().zip(input.iter().cycle().skip(1)).fold(
+            Vec::with_capacity(input.len() + 1),
+            |mut output, (current, next)| {
+                match (is_inside(current), is_inside(next)) {
+                    (true, true) => output.push(next.clone()),
+                    (true, false) => output.push(get_line_intersection(current, next, a, b)),
+                    (false, true) => {
+                        output.push(get_line_intersection(current, next, a, b));
+                        output.push(next.clone());
+                    }
+                    (false, false) => {}
+                }
+
+                output
+            },
+        );
+
+        Some(output)
+    });
+
+    intersection.map_or(0., |polygon| get_polygon_area(polygon.as_slice()))
+}
+
+/// Gets intersection point of segment p1-p2 with line drawn by points a and b.
+fn get_line_intersection(p1: &Point, p2: &Point, a: &Point, b: &Point) -> Point {
+    let d1 = Point::cross_product(a, b, p1);
+    let d2 = Point::cross_product(a, b, p2);
+    let ratio = if d1 == d2 { 0. } else { d1 / (d1 - d2) };
+
+    Point::new(p1.x + (p2.x - p1.x) * ratio, p1.y + (p2.y - p1.y) * ratio)
+}
@@ -0,0 +1,112 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/algorithms/structures/kdtree_test.rs"]
+mod kdtree_test;
+
+use crate::algorithms::geometry::Point;
+use rosomaxa::prelude::Float;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::ops::Range;
+
+/// A static two dimensional k-d tree which supports k-nearest neighbours queries.
+/// Each point is associated with its index in the original collection.
+pub struct KdTree {
+    nodes: Vec<(Point, usize)>,
+}
+
+impl KdTree {
+    /// Creates a new instance of `KdTree` from given points.
+    pub fn new(points: Vec<Point>) -> Self {
+        let mut nodes = points.into_iter().enumerate().map(|(idx, point)| (point, idx)).collect::<Vec<_>>();
+        build_tree(nodes.as_mut_slice(), 0);
+
+        Self { nodes }
+    }
+
+    /// Returns indices of at most `k` nearest points to the given one ordered by distance.
+    pub fn nearest(&self, point: &Point, k: usize) -> Vec<usize> {
+        if k == 0 {
+            return vec![];
+        }
+
+        let mut heap = BinaryHeap::with_capacity(k + 1);
+        self.search(0..self.nodes.len(), 0, point, k, &mut heap);
+
+        heap.into_sorted_vec().into_iter().map(|neighbour| neighbour.idx).collect()
+    }
+
+    /// Returns amount of points in the tree.
+    pub fn size(&self) -> usize {
+        self.nodes.len()
+    }
+
+    fn search(&self, range: Range<usize>, depth: usize, point: &Point, k: usize, heap: &mut BinaryHeap<Neighbour>) {
+        if range.is_empty() {
+            return;
+        }
+
+        let mid = range.start + range.len() / 2;
+        let (node, idx) = &self.nodes[mid];
+
+        heap.push(Neighbour { distance: node.distance_to_point(point), idx: *idx });
+        if heap.len() > k {
+            heap.pop();
+        }
+
+        let delta = if depth.is_multiple_of(2) { point.x - node.x } else { point.y - node.y };
+        let (near, far) =
+            if delta < 0. { (range.start..mid, mid + 1..range.end) } else { (mid + 1..range.end, range.start..mid) };
+
+        self.search(near, depth + 1, point, k, heap);
+
+        // NOTE visit another side only if it can contain a closer point
+        if heap.len() < k || heap.peek().is_some_and(|farthest| delta.abs() <= farthest.distance) {
+            self.search(far, depth + 1, point, k, heap);
+        }
+    }
+}
+
+/// Reorders nodes in place, so the median of each (sub)slice splits it by the axis of given depth.
+fn build_tree(nodes: &mut [(Point, usize)], depth: usize) {
+    if nodes.len() <= 1 {
+        return;
+    }
+
+    let mid = nodes.len() / 2;
+    nodes.select_nth_unstable_by(
+        mid,
+        |(a, _), (b, _)| {
+            if depth.is_multiple_of(2) { a.x.total_cmp(&b.x) } else { a.y.total_cmp(&b.y) }
+        },
+    );
+
+    let (left, right) = nodes.split_at_mut(mid);
+    build_tree(left, depth + 1);
+    build_tree(&mut right[1..], depth + 1);
+}
+
+/// A candidate neighbour ordered by distance and then by index to keep results deterministic.
+struct Neighbour {
+    distance: Float,
+    idx: usize,
+}
+
+impl PartialEq for Neighbour {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Neighbour {}
+
+impl PartialOrd for Neighbour {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Neighbour {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.distance.total_cmp(&other.distance).then(self.idx.cmp(&other.idx))
+    }
+}
@@ -2,3 +2,6 @@
 
 mod bitvec;
 pub use self::bitvec::BitVec;
+
+mod kdtree;
+pub use self::kdtree::KdTree;
@@ -2,6 +2,7 @@
 
 mod neighbour_clusters;
 pub use self::neighbour_clusters::create_job_clusters;
+pub(crate) use self::neighbour_clusters::estimate_epsilon;
 
 use crate::algorithms::clustering::dbscan::create_clusters;
 use crate::algorithms::geometry::Point;
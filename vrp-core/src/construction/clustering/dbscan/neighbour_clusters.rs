@@ -38,7 +38,7 @@ where
 }
 
 /// Estimates DBSCAN epsilon parameter.
-fn estimate_epsilon<'a, FN, IR>(jobs: &[Job], fleet: &Fleet, min_points: usize, neighbour_fn: &FN) -> Float
+pub(crate) fn estimate_epsilon<'a, FN, IR>(jobs: &[Job], fleet: &Fleet, min_points: usize, neighbour_fn: &FN) -> Float
 where
     FN: Fn(&Profile, &Job) -> IR + 'a,
     IR: Iterator<Item = (&'a Job, Cost)> + 'a,
@@ -0,0 +1,48 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/construction/clustering/grid/cell_clusters_test.rs"]
+mod cell_clusters_test;
+
+use crate::models::problem::{Job, get_job_centroid, get_job_locations};
+use rosomaxa::prelude::{Float, GenericResult};
+use std::collections::{BTreeMap, HashSet};
+
+/// Creates clusters of jobs which centroids fall into the same cell of a uniform grid with given
+/// cell size. Cells with less than `min_points` jobs are not considered as clusters.
+pub fn create_job_clusters(
+    jobs: &[Job],
+    coordinates: &[(Float, Float)],
+    cell_size: Float,
+    min_points: usize,
+) -> GenericResult<Vec<HashSet<Job>>> {
+    if !cell_size.is_finite() || cell_size <= 0. {
+        return Err("cell size of grid job clustering should be positive".into());
+    }
+
+    if min_points == 0 {
+        return Err("min points of grid job clustering should be greater than zero".into());
+    }
+
+    if jobs.iter().flat_map(|job| get_job_locations(job).flatten()).any(|location| location >= coordinates.len()) {
+        return Err("grid job clustering requires coordinates for all job locations".into());
+    }
+
+    let cells = jobs.iter().fold(BTreeMap::<_, Vec<_>>::new(), |mut acc, job| {
+        if let Some(cell) = get_grid_cell(job, coordinates, cell_size) {
+            acc.entry(cell).or_default().push(job);
+        }
+
+        acc
+    });
+
+    Ok(cells
+        .into_values()
+        .filter(|cell_jobs| cell_jobs.len() >= min_points)
+        .map(|cell_jobs| cell_jobs.into_iter().cloned().collect())
+        .collect())
+}
+
+/// Returns a grid cell which contains job's centroid.
+pub(crate) fn get_grid_cell(job: &Job, coordinates: &[(Float, Float)], cell_size: Float) -> Option<(i64, i64)> {
+    get_job_centroid(job, coordinates)
+        .map(|centroid| ((centroid.x / cell_size).floor() as i64, (centroid.y / cell_size).floor() as i64))
+}
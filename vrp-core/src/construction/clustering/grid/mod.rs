@@ -0,0 +1,5 @@
+//! This module provides functionality which clusters jobs using a uniform grid built on job coordinates.
+
+mod cell_clusters;
+pub use self::cell_clusters::create_job_clusters;
+pub(crate) use self::cell_clusters::get_grid_cell;
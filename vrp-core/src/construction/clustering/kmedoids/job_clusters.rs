@@ -0,0 +1,45 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/construction/clustering/kmedoids/job_clusters_test.rs"]
+mod job_clusters_test;
+
+use crate::algorithms::clustering::kmedoids::create_kmedoids;
+use crate::models::common::{Cost, Profile};
+use crate::models::problem::{Fleet, Job, get_job_locations};
+use rosomaxa::prelude::{GenericError, GenericResult};
+use std::collections::HashSet;
+
+/// Creates `k` clusters of jobs using K-Medoids algorithm. Clusters with a single job are omitted.
+pub fn create_job_clusters<FN>(
+    jobs: &[Job],
+    fleet: &Fleet,
+    k: usize,
+    distance_fn: FN,
+) -> GenericResult<Vec<HashSet<Job>>>
+where
+    FN: Fn(&Profile, &Job, &Job) -> Cost + Send + Sync,
+{
+    if k == 0 {
+        return Err("k of k-medoids job clustering should be greater than zero".into());
+    }
+
+    // NOTE use always first profile as it is done for DBSCAN clusters.
+    let profile = fleet.profiles.first().ok_or_else(|| GenericError::from("cannot find any profile"))?;
+    // exclude jobs without locations from clustering
+    let jobs = jobs.iter().filter(|job| get_job_locations(job).flatten().next().is_some()).collect::<Vec<_>>();
+    let points = (0..jobs.len()).collect::<Vec<_>>();
+
+    let mut clusters =
+        create_kmedoids(&points, k.min(jobs.len()), |from, to| distance_fn(profile, jobs[*from], jobs[*to]))
+            .into_values()
+            .filter(|cluster| cluster.len() > 1)
+            .map(|mut cluster| {
+                cluster.sort_unstable();
+                cluster
+            })
+            .collect::<Vec<_>>();
+
+    // NOTE keep clusters order stable
+    clusters.sort_unstable();
+
+    Ok(clusters.into_iter().map(|cluster| cluster.into_iter().map(|idx| jobs[idx].clone()).collect()).collect())
+}
@@ -1,4 +1,7 @@
 //! Contains implementation of k-medoids clustering algorithm.
 
+mod job_clusters;
+pub use self::job_clusters::create_job_clusters;
+
 mod multi_tier_clusters;
 pub use self::multi_tier_clusters::create_multi_tier_clusters;
@@ -1,5 +1,6 @@
 //! Contains implementation of job clustering algorithms.
 
 pub mod dbscan;
+pub mod grid;
 pub mod kmedoids;
 pub mod vicinity;
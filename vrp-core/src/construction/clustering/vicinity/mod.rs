@@ -17,6 +17,9 @@ use std::sync::Arc;
 
 mod estimations;
 use self::estimations::*;
+
+mod ordering;
+pub use self::ordering::get_optimized_order;
 use crate::models::solution::Commute;
 use crate::prelude::ViolationCode;
 
@@ -42,6 +45,8 @@ pub struct ClusterConfig {
     pub filtering: FilterPolicy,
     /// Specifies building policy.
     pub building: BuilderPolicy,
+    /// Specifies how clusters are handled by the solver.
+    pub solving: SolvingPolicy,
 }
 
 /// Defines a various thresholds to control cluster size.
@@ -121,6 +126,16 @@ pub struct BuilderPolicy {
     pub ordering_local_fn: OrderingLocalFn,
 }
 
+/// Specifies how clusters are handled by the solver.
+#[derive(Clone, Default)]
+pub struct SolvingPolicy {
+    /// If set, visiting order of clustered jobs is optimized when the cluster is unpacked into the tour,
+    /// otherwise, the order decided at clustering time is kept.
+    pub flexible_order: bool,
+    /// If set, unassigned clusters are dissolved during search and their jobs are inserted individually.
+    pub dissolve_unassigned: bool,
+}
+
 /// Keeps track of information specific for job in the cluster.
 #[derive(Clone)]
 pub struct ClusterInfo {
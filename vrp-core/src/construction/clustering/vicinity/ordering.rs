@@ -0,0 +1,120 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/construction/clustering/vicinity/ordering_test.rs"]
+mod ordering_test;
+
+use super::*;
+use crate::models::problem::TransportCost;
+use crate::models::solution::CommuteInfo;
+
+/// Tries to find a visiting order of clustered jobs which has a shorter commute duration than the one
+/// decided at clustering time. The first job (cluster center) is always visited first.
+/// Returns `None` if visiting order cannot be improved.
+pub fn get_optimized_order(
+    cluster: &[ClusterInfo],
+    config: &ClusterConfig,
+    transport: &dyn TransportCost,
+) -> Option<Vec<ClusterInfo>> {
+    // NOTE with return policy, commute duration does not depend on visiting order
+    if matches!(config.visiting, VisitPolicy::Return) || cluster.len() < 3 {
+        return None;
+    }
+
+    let locations = cluster.iter().map(get_location).collect::<Option<Vec<_>>>()?;
+    let duration = |from: usize, to: usize| transport.duration_approx(&config.profile, locations[from], locations[to]);
+    let get_order_duration = |order: &[usize]| {
+        let forward = order.windows(2).map(|pair| duration(pair[0], pair[1])).sum::<Duration>();
+        let backward = match config.visiting {
+            VisitPolicy::ClosedContinuation => order.last().map_or(0., |&last| duration(last, 0)),
+            _ => 0.,
+        };
+
+        forward + backward
+    };
+
+    let original = (0..cluster.len()).collect::<Vec<_>>();
+    let mut order = get_nearest_neighbour_order(cluster.len(), &duration);
+    improve_with_two_opt(&mut order, &get_order_duration);
+
+    if get_order_duration(&order) >= get_order_duration(&original) {
+        return None;
+    }
+
+    let last = order.last().copied();
+    let commute = |from: usize, to: usize| CommuteInfo {
+        location: locations[from],
+        distance: transport.distance_approx(&config.profile, locations[from], locations[to]),
+        duration: duration(from, to),
+    };
+    let stay = |idx: usize| CommuteInfo { location: locations[idx], distance: 0., duration: 0. };
+
+    Some(
+        std::iter::once(cluster[0].clone())
+            .chain(order.windows(2).map(|pair| {
+                let (prev, idx) = (pair[0], pair[1]);
+                let backward = match config.visiting {
+                    VisitPolicy::ClosedContinuation if Some(idx) == last => {
+                        CommuteInfo { location: locations[0], ..commute(idx, 0) }
+                    }
+                    _ => stay(idx),
+                };
+
+                ClusterInfo { commute: Commute { forward: commute(prev, idx), backward }, ..cluster[idx].clone() }
+            }))
+            .collect(),
+    )
+}
+
+fn get_location(info: &ClusterInfo) -> Option<Location> {
+    info.job.as_single().and_then(|single| single.places.get(info.place_idx)).and_then(|place| place.location)
+}
+
+/// Builds visiting order starting from the cluster center and moving to the nearest unvisited job.
+fn get_nearest_neighbour_order<F>(size: usize, duration: &F) -> Vec<usize>
+where
+    F: Fn(usize, usize) -> Duration,
+{
+    let mut order = vec![0];
+    let mut unvisited = (1..size).collect::<Vec<_>>();
+
+    while let Some(&current) = order.last() {
+        let Some((position, _)) = unvisited
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| duration(current, **a).total_cmp(&duration(current, **b)))
+        else {
+            break;
+        };
+
+        order.push(unvisited.remove(position));
+    }
+
+    order
+}
+
+/// Improves visiting order by reversing its segments while total duration decreases.
+/// The cluster center is kept at the first position.
+fn improve_with_two_opt<F>(order: &mut [usize], get_order_duration: &F)
+where
+    F: Fn(&[usize]) -> Duration,
+{
+    let mut best_duration = get_order_duration(order);
+    let mut is_improved = true;
+
+    while is_improved {
+        is_improved = false;
+
+        for start in 1..order.len() {
+            for end in (start + 1)..order.len() {
+                order[start..=end].reverse();
+                let duration = get_order_duration(order);
+
+                if duration < best_duration {
+                    best_duration = duration;
+                    is_improved = true;
+                } else {
+                    order[start..=end].reverse();
+                }
+            }
+        }
+    }
+}
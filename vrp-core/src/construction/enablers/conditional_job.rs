@@ -67,23 +67,26 @@ pub fn process_conditional_jobs(
     context_transition: &dyn JobContextTransition,
 ) {
     // analyzed required/ignored
-    let ignored: HashSet<Job> = solution_ctx
+    // NOTE keep moved jobs in the order of their source collections, so it does not depend on hashing
+    let ignored: Vec<Job> = solution_ctx
         .required
         .iter()
         .filter(|job| context_transition.remove_from_required(solution_ctx, route_index, job))
         .cloned()
         .collect();
-    solution_ctx.required.retain(|job| !ignored.contains(job));
-    solution_ctx.unassigned.retain(|job, _| !ignored.contains(job));
+    let ignored_index: HashSet<&Job> = ignored.iter().collect();
+    solution_ctx.required.retain(|job| !ignored_index.contains(job));
+    solution_ctx.unassigned.retain(|job, _| !ignored_index.contains(job));
 
     // identify required inside ignored
-    let required: HashSet<Job> = solution_ctx
+    let required: Vec<Job> = solution_ctx
         .ignored
         .iter()
         .filter(|job| context_transition.promote_to_required(solution_ctx, route_index, job))
         .cloned()
         .collect();
-    solution_ctx.ignored.retain(|job| !required.contains(job));
+    let required_index: HashSet<&Job> = required.iter().collect();
+    solution_ctx.ignored.retain(|job| !required_index.contains(job));
 
     solution_ctx.required.extend(required);
     solution_ctx.ignored.extend(ignored);
@@ -4,8 +4,9 @@ mod departure_time_test;
 
 use crate::construction::enablers::*;
 use crate::construction::heuristics::RouteContext;
-use crate::models::common::Timestamp;
+use crate::models::common::{Duration, Timestamp};
 use crate::models::problem::{ActivityCost, TransportCost, TravelTime};
+use crate::models::solution::Route;
 use rosomaxa::prelude::Float;
 
 /// Tries to move forward route's departure time.
@@ -27,6 +28,24 @@ pub fn recede_departure_time(route_ctx: &mut RouteContext, activity: &dyn Activi
     }
 }
 
+/// Returns a departure time shift which can be applied to the route's start in order to reduce
+/// waiting time in the whole tour without violating any time constraints.
+pub fn get_departure_time_shift(route: &Route) -> Duration {
+    let Some(start) = route.tour.start() else { return Duration::default() };
+
+    let latest_allowed_departure = route.actor.detail.start.as_ref().and_then(|s| s.time.latest).unwrap_or(Float::MAX);
+
+    let (total_waiting_time, max_shift) =
+        route.tour.all_activities().rev().fold((0., Float::MAX), |(total_waiting_time, max_shift), activity| {
+            let waiting_time = (activity.place.time.start - activity.schedule.arrival).max(0.);
+            let remaining_time = (activity.place.time.end - activity.schedule.arrival - waiting_time).max(0.);
+
+            (total_waiting_time + waiting_time, waiting_time + remaining_time.min(max_shift))
+        });
+
+    total_waiting_time.min(max_shift).min(latest_allowed_departure - start.schedule.departure).max(0.)
+}
+
 fn try_advance_departure_time(
     route_ctx: &RouteContext,
     transport: &dyn TransportCost,
@@ -41,16 +60,7 @@ fn try_advance_departure_time(
     let last_departure_time = start.schedule.departure;
 
     let new_departure_time = if optimize_whole_tour {
-        let (total_waiting_time, max_shift) =
-            route.tour.all_activities().rev().fold((0., Float::MAX), |(total_waiting_time, max_shift), activity| {
-                let waiting_time = (activity.place.time.start - activity.schedule.arrival).max(0.);
-                let remaining_time = (activity.place.time.end - activity.schedule.arrival - waiting_time).max(0.);
-
-                (total_waiting_time + waiting_time, waiting_time + remaining_time.min(max_shift))
-            });
-        let departure_shift = total_waiting_time.min(max_shift);
-
-        (start.schedule.departure + departure_shift).min(latest_allowed_departure)
+        start.schedule.departure + get_departure_time_shift(route)
     } else {
         let start_to_first = transport.duration(
             route,
@@ -63,6 +73,12 @@ fn try_advance_departure_time(
         last_departure_time.max(first.place.time.start - start_to_first).min(latest_allowed_departure)
     };
 
+    // NOTE do not shift arrivals into restricted access time
+    let new_departure_time = route_ctx
+        .state()
+        .get_access_shift_limit_at(1)
+        .map_or(new_departure_time, |&limit| new_departure_time.min(last_departure_time + limit));
+
     if new_departure_time > last_departure_time { Some(new_departure_time) } else { None }
 }
 
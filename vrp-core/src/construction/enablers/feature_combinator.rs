@@ -2,7 +2,7 @@
 
 use crate::construction::heuristics::*;
 use crate::models::common::Cost;
-use crate::models::problem::Job;
+use crate::models::problem::{Actor, Job};
 use crate::models::*;
 use rosomaxa::prelude::*;
 use std::ops::ControlFlow;
@@ -144,6 +144,10 @@ impl FeatureConstraint for CombinedFeatureConstraint {
     fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
         merge_with_constraints(&self.constraints, source, candidate)
     }
+
+    fn check_compatibility(&self, actor: &Actor, job: &Job) -> Option<ViolationCode> {
+        self.constraints.iter().find_map(|constraint| constraint.check_compatibility(actor, job))
+    }
 }
 
 struct SumFeatureObjective {
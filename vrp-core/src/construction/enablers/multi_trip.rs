@@ -3,7 +3,7 @@
 
 use crate::construction::enablers::*;
 use crate::construction::heuristics::*;
-use crate::models::problem::Job;
+use crate::models::problem::{Actor, Job};
 use crate::models::solution::Route;
 use crate::models::*;
 use rosomaxa::prelude::*;
@@ -100,6 +100,14 @@ impl FeatureConstraint for MultiTripConstraint {
 
         self.multi_trip.get_constraint().merge(source, candidate)
     }
+
+    fn check_compatibility(&self, actor: &Actor, job: &Job) -> Option<ViolationCode> {
+        if self.multi_trip.get_route_intervals().is_marker_job(job) {
+            return None;
+        }
+
+        self.multi_trip.get_constraint().check_compatibility(actor, job)
+    }
 }
 
 impl MultiTripConstraint {
@@ -151,6 +151,18 @@ impl TransportCost for DynamicTransportCost {
     fn size(&self) -> usize {
         self.inner.size()
     }
+
+    fn sparse_neighbours(&self, profile: &Profile, from: Location) -> Option<Vec<Location>> {
+        self.inner.sparse_neighbours(profile, from)
+    }
+
+    fn matrix_key(&self, profile: &Profile) -> Option<usize> {
+        self.inner.matrix_key(profile)
+    }
+
+    fn fingerprint(&self, profile: &Profile) -> Option<u64> {
+        self.inner.fingerprint(profile)
+    }
 }
 
 /// Optimizes reserved time schedules by rescheduling it to earlier time (e.g. to avoid transit stops,
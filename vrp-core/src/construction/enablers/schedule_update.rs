@@ -7,8 +7,10 @@ use rosomaxa::utils::UnwrapValue;
 
 custom_activity_state!(pub(crate) LatestArrival typeof Timestamp);
 custom_activity_state!(pub(crate) WaitingTime typeof Timestamp);
+custom_activity_state!(pub(crate) AccessShiftLimit typeof Duration);
 custom_tour_state!(pub TotalDistance typeof Distance);
 custom_tour_state!(pub TotalDuration typeof Duration);
+custom_tour_state!(pub TotalWaitingTime typeof Duration);
 custom_tour_state!(pub(crate) LimitDuration typeof Duration);
 
 /// Updates route schedule data.
@@ -114,13 +116,16 @@ fn update_statistics(route_ctx: &mut RouteContext, transport: &dyn TransportCost
     let end = route.tour.end().unwrap();
     let total_dur = end.schedule.departure - start.schedule.departure;
 
-    let init = (start.place.location, start.schedule.departure, Distance::default());
-    let (_, _, total_dist) = route.tour.all_activities().skip(1).fold(init, |(loc, dep, total_dist), a| {
-        let total_dist = total_dist + transport.distance(route, loc, a.place.location, TravelTime::Departure(dep));
+    let init = (start.place.location, start.schedule.departure, Distance::default(), Duration::default());
+    let (_, _, total_dist, total_waiting) =
+        route.tour.all_activities().skip(1).fold(init, |(loc, dep, total_dist, total_waiting), a| {
+            let total_dist = total_dist + transport.distance(route, loc, a.place.location, TravelTime::Departure(dep));
+            let total_waiting = total_waiting + (a.place.time.start - a.schedule.arrival).max(0.);
 
-        (a.place.location, a.schedule.departure, total_dist)
-    });
+            (a.place.location, a.schedule.departure, total_dist, total_waiting)
+        });
 
     state.set_total_distance(total_dist);
     state.set_total_duration(total_dur);
+    state.set_total_waiting_time(total_waiting);
 }
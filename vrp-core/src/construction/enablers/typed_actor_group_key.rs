@@ -1,5 +1,5 @@
 use crate::models::problem::Actor;
-use std::collections::{HashMap, HashSet};
+use std::collections::HashMap;
 use std::sync::Arc;
 
 /// An actor group key implementation which creates groups using "type" dimension.
@@ -10,9 +10,12 @@ pub fn create_typed_actor_groups<F>(
 where
     F: Fn(&Actor) -> String,
 {
-    let unique_type_keys: HashSet<_> = actors.iter().map(|a| (actor_type_fn(a.as_ref()), a.detail.clone())).collect();
-
-    let type_key_map: HashMap<_, _> = unique_type_keys.into_iter().zip(0_usize..).collect();
+    // NOTE assign group indices in the order of actors, so they do not depend on hashing
+    let type_key_map = actors.iter().fold(HashMap::<_, usize>::new(), |mut acc, a| {
+        let next_idx = acc.len();
+        acc.entry((actor_type_fn(a.as_ref()), a.detail.clone())).or_insert(next_idx);
+        acc
+    });
 
     let groups: HashMap<_, _> = actors
         .iter()
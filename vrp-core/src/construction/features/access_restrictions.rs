@@ -0,0 +1,254 @@
+//! Provides the way to restrict access to some locations for specific vehicle profiles during given time.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/access_restrictions_test.rs"]
+mod access_restrictions_test;
+
+use super::*;
+use crate::construction::enablers::AccessShiftLimitActivityState;
+use crate::models::problem::{ActivityCost, TransportCost, TravelTime};
+use crate::models::solution::Tour;
+use rosomaxa::utils::UnwrapValue;
+use std::collections::{HashMap, HashSet};
+
+/// Specifies a time dependent access restriction: vehicles with given routing profile are not
+/// allowed to arrive at given locations within given time window (e.g. a city center truck ban).
+/// Staying at the same location (e.g. serving several jobs there) is not considered as an arrival.
+#[derive(Clone, Debug)]
+pub struct AccessRestriction {
+    /// A routing profile index.
+    pub profile: usize,
+    /// Restricted locations.
+    pub locations: Vec<Location>,
+    /// A time window when arrival is forbidden. Arrival exactly at its start or end is allowed.
+    pub time: TimeWindow,
+}
+
+/// Creates a feature which rejects insertions leading to arrival at restricted locations within
+/// banned time windows defined for vehicle's profile. Jobs which violate restrictions after other
+/// modifications of the route (e.g. after removing some other job) are moved to unassigned.
+pub fn create_access_restriction_feature(
+    name: &str,
+    code: ViolationCode,
+    restrictions: &[AccessRestriction],
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+) -> GenericResult<Feature> {
+    let index = Arc::new(AccessRestrictionIndex::new(restrictions)?);
+
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(AccessRestrictionConstraint { code, index: index.clone(), transport, activity })
+        .with_state(AccessRestrictionState { code, index })
+        .build()
+}
+
+struct AccessRestrictionIndex {
+    windows: HashMap<(usize, Location), Vec<TimeWindow>>,
+    profiles: HashSet<usize>,
+}
+
+impl AccessRestrictionIndex {
+    fn new(restrictions: &[AccessRestriction]) -> GenericResult<Self> {
+        if restrictions.is_empty() {
+            return Err("no access restrictions defined".into());
+        }
+
+        if restrictions.iter().any(|restriction| restriction.time.start >= restriction.time.end) {
+            return Err("access restriction time window should have start before end".into());
+        }
+
+        let windows = restrictions.iter().fold(HashMap::<_, Vec<_>>::new(), |mut acc, restriction| {
+            restriction.locations.iter().for_each(|&location| {
+                acc.entry((restriction.profile, location)).or_default().push(restriction.time.clone());
+            });
+
+            acc
+        });
+        let profiles = windows.keys().map(|(profile, _)| *profile).collect();
+
+        Ok(Self { windows, profiles })
+    }
+
+    fn has_profile(&self, profile: usize) -> bool {
+        self.profiles.contains(&profile)
+    }
+
+    fn is_restricted(&self, profile: usize, location: Location, arrival: Timestamp) -> bool {
+        self.windows
+            .get(&(profile, location))
+            .is_some_and(|windows| windows.iter().any(|tw| arrival > tw.start && arrival < tw.end))
+    }
+
+    /// Returns how much arrival at given location can be delayed without falling into restriction.
+    fn get_allowed_delay(&self, profile: usize, location: Location, arrival: Timestamp) -> Duration {
+        self.windows.get(&(profile, location)).map_or(Float::MAX, |windows| {
+            windows
+                .iter()
+                .filter(|tw| arrival <= tw.start)
+                .map(|tw| tw.start - arrival)
+                .fold(Float::MAX, |acc, delay| acc.min(delay))
+        })
+    }
+}
+
+struct AccessRestrictionConstraint {
+    code: ViolationCode,
+    index: Arc<AccessRestrictionIndex>,
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+}
+
+impl FeatureConstraint for AccessRestrictionConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => None,
+            MoveContext::Activity { route_ctx, activity_ctx, .. } => self.evaluate_activity(route_ctx, activity_ctx),
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+}
+
+impl AccessRestrictionConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ConstraintViolation> {
+        let route = route_ctx.route();
+        let profile = route.actor.vehicle.profile.index;
+
+        if !self.index.has_profile(profile) {
+            return None;
+        }
+
+        let prev = activity_ctx.prev;
+        let target = activity_ctx.target;
+
+        let departure = prev.schedule.departure;
+        let arr_time_at_target = departure
+            + self.transport.duration(
+                route,
+                prev.place.location,
+                target.place.location,
+                TravelTime::Departure(departure),
+            );
+
+        if prev.place.location != target.place.location
+            && self.index.is_restricted(profile, target.place.location, arr_time_at_target)
+        {
+            return ConstraintViolation::skip(self.code);
+        }
+
+        let next = activity_ctx.next?;
+
+        let dep_time_at_target = self.activity.estimate_departure(route, target, arr_time_at_target).unwrap_value();
+        let arr_time_at_next = dep_time_at_target
+            + self.transport.duration(
+                route,
+                target.place.location,
+                next.place.location,
+                TravelTime::Departure(dep_time_at_target),
+            );
+
+        let delay = arr_time_at_next - next.schedule.arrival;
+        let allowed_delay =
+            route_ctx.state().get_access_shift_limit_at(activity_ctx.index + 1).copied().unwrap_or(Float::MAX);
+
+        if delay > allowed_delay { ConstraintViolation::skip(self.code) } else { None }
+    }
+}
+
+struct AccessRestrictionState {
+    code: ViolationCode,
+    index: Arc<AccessRestrictionIndex>,
+}
+
+impl FeatureState for AccessRestrictionState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let profile = route_ctx.route().actor.vehicle.profile.index;
+
+        if !self.index.has_profile(profile) {
+            return;
+        }
+
+        let tour = &route_ctx.route().tour;
+
+        // NOTE delay of arrival is absorbed by waiting time, so limit is propagated backwards
+        let mut limits = (0..tour.total())
+            .rev()
+            .filter_map(|idx| tour.get(idx).map(|activity| (idx, activity)))
+            .scan(Float::MAX, |next_limit, (idx, activity)| {
+                let waiting = (activity.place.time.start - activity.schedule.arrival).max(0.);
+                let own_limit = if is_arrival(tour, idx) {
+                    self.index.get_allowed_delay(profile, activity.place.location, activity.schedule.arrival)
+                } else {
+                    Float::MAX
+                };
+
+                *next_limit = own_limit.min(waiting + *next_limit);
+
+                Some(*next_limit)
+            })
+            .collect::<Vec<_>>();
+        limits.reverse();
+
+        route_ctx.state_mut().set_access_shift_limit_states(limits);
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        let jobs_to_remove = solution_ctx
+            .routes
+            .iter()
+            .filter(|route_ctx| self.index.has_profile(route_ctx.route().actor.vehicle.profile.index))
+            .flat_map(|route_ctx| {
+                let profile = route_ctx.route().actor.vehicle.profile.index;
+                let tour = &route_ctx.route().tour;
+
+                (1..tour.total())
+                    .filter(|&idx| is_arrival(tour, idx))
+                    .filter_map(|idx| tour.get(idx))
+                    .filter(move |activity| {
+                        self.index.is_restricted(profile, activity.place.location, activity.schedule.arrival)
+                    })
+                    .filter_map(|activity| activity.retrieve_job())
+            })
+            .filter(|job| !solution_ctx.locked.contains(job))
+            .collect::<HashSet<_>>();
+
+        jobs_to_remove.iter().for_each(|job| {
+            solution_ctx.routes.iter_mut().filter(|route_ctx| route_ctx.route().tour.contains(job)).for_each(
+                |route_ctx| {
+                    assert!(route_ctx.route_mut().tour.remove(job), "cannot remove job from the tour");
+                },
+            )
+        });
+
+        solution_ctx
+            .unassigned
+            .extend(jobs_to_remove.into_iter().map(|job| (job, UnassignmentInfo::Simple(self.code))));
+
+        solution_ctx
+            .routes
+            .iter_mut()
+            .filter(|route_ctx| route_ctx.is_stale())
+            .for_each(|route_ctx| self.accept_route_state(route_ctx));
+    }
+}
+
+/// Checks whether activity at given index is reached from another location: staying at the same
+/// location is not considered as an arrival.
+fn is_arrival(tour: &Tour, idx: usize) -> bool {
+    idx > 0
+        && tour
+            .get(idx - 1)
+            .zip(tour.get(idx))
+            .is_some_and(|(prev, current)| prev.place.location != current.place.location)
+}
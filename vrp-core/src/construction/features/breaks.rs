@@ -212,7 +212,7 @@ struct OptionalBreakState<JT: JobContextTransition + Send + Sync> {
     break_fns: BreakFns,
 }
 
-impl<JT: JobContextTransition + Send + Sync> FeatureState for OptionalBreakState<JT> {
+impl<JT: JobContextTransition + Send + Sync + 'static> FeatureState for OptionalBreakState<JT> {
     fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
         process_conditional_jobs(solution_ctx, Some(route_index), &self.context_transition);
     }
@@ -115,6 +115,27 @@ where
             _ => Err(self.violation_code),
         }
     }
+
+    fn check_compatibility(&self, actor: &Actor, job: &Job) -> Option<ViolationCode> {
+        let capacity: Option<&T> = actor.vehicle.dimens.get_vehicle_capacity();
+
+        // NOTE each demand part should fit into empty vehicle
+        let can_fit = |single: &Single| {
+            let Some(demand) = single.dimens.get_job_demand::<T>() else { return true };
+            let Some(capacity) = capacity else { return false };
+
+            [&demand.pickup.0, &demand.pickup.1, &demand.delivery.0, &demand.delivery.1]
+                .into_iter()
+                .all(|part| capacity.can_fit(part))
+        };
+
+        let can_handle = match job {
+            Job::Single(single) => can_fit(single),
+            Job::Multi(multi) => multi.jobs.iter().all(|single| can_fit(single)),
+        };
+
+        if can_handle { None } else { Some(self.violation_code) }
+    }
 }
 
 struct CapacitatedMultiTrip<T>
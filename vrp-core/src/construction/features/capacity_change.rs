@@ -0,0 +1,507 @@
+//! A feature to model a planned vehicle capacity change in the middle of the tour, e.g. a trailer drop.
+//! Once a special capacity change activity is served, the vehicle continues its tour with the reduced
+//! capacity and, optionally, with another routing profile.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/capacity_change_test.rs"]
+mod capacity_change_test;
+
+use super::capacity::{CurrentCapacityActivityState, MaxFutureCapacityActivityState};
+use super::*;
+use crate::construction::enablers::*;
+use crate::models::solution::{Activity, Route};
+use std::collections::HashMap;
+use std::iter::once;
+use std::marker::PhantomData;
+use std::ops::ControlFlow;
+
+custom_dimension!(pub ChangedCapacity typeof T: LoadOps);
+
+custom_dimension!(pub ChangedProfile typeof Profile);
+
+custom_tour_state!(CapacityChangeIndex typeof usize);
+
+custom_activity_state!(MaxLoadSinceChange typeof T: LoadOps);
+
+/// Provides a way to build a feature which keeps a vehicle capacity change activity in the tour
+/// when the vehicle is used. The capacity after change is specified on the change job using
+/// [ChangedCapacityDimension], the routing profile after change using [ChangedProfileDimension].
+/// A capacity is expected to be reduced and multi trip (reloads) is not supported.
+pub struct CapacityChangeFeatureBuilder<T: LoadOps> {
+    name: String,
+    violation_code: Option<ViolationCode>,
+    transport: Option<Arc<dyn TransportCost>>,
+    activity: Option<Arc<dyn ActivityCost>>,
+    is_change_single_fn: Option<ChangeSingleFn>,
+    belongs_to_route_fn: Option<BelongsToRouteFn>,
+    phantom: PhantomData<T>,
+}
+
+impl<T: LoadOps> CapacityChangeFeatureBuilder<T> {
+    /// Creates a new instance of `CapacityChangeFeatureBuilder`.
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            violation_code: None,
+            transport: None,
+            activity: None,
+            is_change_single_fn: None,
+            belongs_to_route_fn: None,
+            phantom: Default::default(),
+        }
+    }
+
+    /// Sets constraint violation code which is used to report back the reason of job's unassignment.
+    pub fn set_violation_code(mut self, violation_code: ViolationCode) -> Self {
+        self.violation_code = Some(violation_code);
+        self
+    }
+
+    /// Sets transport costs to update schedules when routing profile is changed.
+    pub fn set_transport(mut self, transport: Arc<dyn TransportCost>) -> Self {
+        self.transport = Some(transport);
+        self
+    }
+
+    /// Sets activity costs to update schedules when routing profile is changed.
+    pub fn set_activity(mut self, activity: Arc<dyn ActivityCost>) -> Self {
+        self.activity = Some(activity);
+        self
+    }
+
+    /// Sets a function which specifies whether a given single job is a capacity change job.
+    pub fn set_is_change_single<F>(mut self, func: F) -> Self
+    where
+        F: Fn(&Single) -> bool + Send + Sync + 'static,
+    {
+        self.is_change_single_fn = Some(Arc::new(func));
+        self
+    }
+
+    /// Sets a function which specifies whether a given route can serve a given capacity change job.
+    /// This function should return false, if the job is not a capacity change job.
+    pub fn set_belongs_to_route<F>(mut self, func: F) -> Self
+    where
+        F: Fn(&Route, &Job) -> bool + Send + Sync + 'static,
+    {
+        self.belongs_to_route_fn = Some(Arc::new(func));
+        self
+    }
+
+    /// Builds a capacity change feature.
+    pub fn build(mut self) -> GenericResult<Feature> {
+        let is_change_single_fn =
+            self.is_change_single_fn.take().ok_or_else(|| GenericError::from("is_change_single must be set"))?;
+        let belongs_to_route_fn =
+            self.belongs_to_route_fn.take().ok_or_else(|| GenericError::from("belongs_to_route must be set"))?;
+        let transport = self.transport.take().ok_or_else(|| GenericError::from("transport must be set"))?;
+        let activity = self.activity.take().ok_or_else(|| GenericError::from("activity must be set"))?;
+
+        let code = self.violation_code.unwrap_or_default();
+        let change_fns = ChangeFns { is_change_single_fn, belongs_to_route_fn };
+
+        let context_transition = ConcreteJobContextTransition {
+            remove_required: {
+                let change_fns = change_fns.clone();
+                move |solution_ctx, _, job| {
+                    change_fns.is_change_job(job) && !change_fns.has_route(solution_ctx.routes.as_slice(), job)
+                }
+            },
+            promote_required: {
+                let change_fns = change_fns.clone();
+                move |solution_ctx, _, job| {
+                    change_fns.is_change_job(job) && change_fns.has_route(solution_ctx.routes.as_slice(), job)
+                }
+            },
+            remove_locked: |_, _, _| false,
+            promote_locked: |_, _, _| false,
+        };
+
+        FeatureBuilder::default()
+            .with_name(self.name.as_str())
+            .with_constraint(CapacityChangeConstraint::<T> {
+                change_fns: change_fns.clone(),
+                transport: transport.clone(),
+                activity: activity.clone(),
+                code,
+                phantom: Default::default(),
+            })
+            .with_state(CapacityChangeState::<T, _> {
+                change_fns,
+                context_transition,
+                transport,
+                activity,
+                phantom: Default::default(),
+            })
+            .build()
+    }
+}
+
+/// Provides a way to calculate transport costs for actors which switch their routing profile when
+/// capacity change activity is served: travel which starts at or after the change departure time is
+/// estimated using the changed profile.
+pub struct ProfileChangeTransportCost {
+    inner: Arc<dyn TransportCost>,
+    changed_routes: HashMap<Arc<Actor>, Route>,
+}
+
+impl ProfileChangeTransportCost {
+    /// Creates a new instance of `ProfileChangeTransportCost` using actors with their changed profiles.
+    pub fn new(inner: Arc<dyn TransportCost>, changes: Vec<(Arc<Actor>, Profile)>) -> Self {
+        let changed_routes = changes
+            .into_iter()
+            .map(|(actor, profile)| {
+                let vehicle = Vehicle { profile, ..actor.vehicle.as_ref().clone() };
+                let changed_actor =
+                    Actor { vehicle: Arc::new(vehicle), driver: actor.driver.clone(), detail: actor.detail.clone() };
+
+                (actor, Route { actor: Arc::new(changed_actor), tour: Default::default() })
+            })
+            .collect();
+
+        Self { inner, changed_routes }
+    }
+
+    /// Returns route with changed profile if travel definitely happens after the change activity,
+    /// original route if it definitely happens before it, and both of them otherwise.
+    fn get_routes<'a>(&'a self, route: &'a Route, travel_time: TravelTime) -> ChangedRoutes<'a> {
+        let Some(changed_route) = self.changed_routes.get(&route.actor) else { return ChangedRoutes::One(route) };
+
+        let Some(change) = route.tour.all_activities().find(|activity| get_changed_profile(activity).is_some()) else {
+            return ChangedRoutes::One(route);
+        };
+
+        match travel_time {
+            TravelTime::Departure(departure) if departure >= change.schedule.departure => {
+                ChangedRoutes::One(changed_route)
+            }
+            TravelTime::Arrival(arrival) if arrival > change.schedule.departure => {
+                // NOTE latest arrival can be later than the change departure even for the travel which
+                //      happens before the change, so the worst estimate is used to stay on the safe side
+                ChangedRoutes::Both(route, changed_route)
+            }
+            _ => ChangedRoutes::One(route),
+        }
+    }
+}
+
+enum ChangedRoutes<'a> {
+    One(&'a Route),
+    Both(&'a Route, &'a Route),
+}
+
+impl ChangedRoutes<'_> {
+    fn estimate(&self, estimate_fn: impl Fn(&Route) -> Float) -> Float {
+        match self {
+            ChangedRoutes::One(route) => estimate_fn(route),
+            ChangedRoutes::Both(original, changed) => estimate_fn(original).max(estimate_fn(changed)),
+        }
+    }
+}
+
+impl TransportCost for ProfileChangeTransportCost {
+    fn duration_approx(&self, profile: &Profile, from: Location, to: Location) -> Duration {
+        self.inner.duration_approx(profile, from, to)
+    }
+
+    fn distance_approx(&self, profile: &Profile, from: Location, to: Location) -> Distance {
+        self.inner.distance_approx(profile, from, to)
+    }
+
+    fn duration(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Duration {
+        self.get_routes(route, travel_time).estimate(|route| self.inner.duration(route, from, to, travel_time))
+    }
+
+    fn distance(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Distance {
+        self.get_routes(route, travel_time).estimate(|route| self.inner.distance(route, from, to, travel_time))
+    }
+
+    fn size(&self) -> usize {
+        self.inner.size()
+    }
+
+    fn sparse_neighbours(&self, profile: &Profile, from: Location) -> Option<Vec<Location>> {
+        self.inner.sparse_neighbours(profile, from)
+    }
+
+    fn matrix_key(&self, profile: &Profile) -> Option<usize> {
+        self.inner.matrix_key(profile)
+    }
+
+    fn fingerprint(&self, profile: &Profile) -> Option<u64> {
+        self.inner.fingerprint(profile)
+    }
+}
+
+type ChangeSingleFn = Arc<dyn Fn(&Single) -> bool + Send + Sync>;
+type BelongsToRouteFn = Arc<dyn Fn(&Route, &Job) -> bool + Send + Sync>;
+
+#[derive(Clone)]
+struct ChangeFns {
+    is_change_single_fn: ChangeSingleFn,
+    belongs_to_route_fn: BelongsToRouteFn,
+}
+
+impl ChangeFns {
+    fn is_change_job(&self, job: &Job) -> bool {
+        job.as_single().is_some_and(|single| (self.is_change_single_fn)(single))
+    }
+
+    fn is_change_activity(&self, activity: &Activity) -> bool {
+        activity.job.as_ref().is_some_and(|single| (self.is_change_single_fn)(single))
+    }
+
+    /// Checks whether there is a route which serves other jobs and can serve given change job.
+    fn has_route(&self, routes: &[RouteContext], job: &Job) -> bool {
+        routes.iter().any(|route_ctx| {
+            (self.belongs_to_route_fn)(route_ctx.route(), job)
+                && route_ctx.route().tour.jobs().any(|job| !self.is_change_job(job))
+        })
+    }
+}
+
+struct CapacityChangeConstraint<T: LoadOps> {
+    change_fns: ChangeFns,
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+    code: ViolationCode,
+    phantom: PhantomData<T>,
+}
+
+impl<T: LoadOps> CapacityChangeConstraint<T> {
+    fn evaluate_route(&self, route_ctx: &RouteContext, job: &Job) -> Option<ConstraintViolation> {
+        if !self.change_fns.is_change_job(job) {
+            return None;
+        }
+
+        let is_assignable = (self.change_fns.belongs_to_route_fn)(route_ctx.route(), job)
+            && route_ctx.state().get_capacity_change_index().is_none();
+
+        if is_assignable { None } else { ConstraintViolation::fail(self.code) }
+    }
+
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ConstraintViolation> {
+        let target = activity_ctx.target;
+        let change_idx = route_ctx.state().get_capacity_change_index().copied();
+
+        let change = if self.change_fns.is_change_activity(target) {
+            target
+        } else {
+            route_ctx.route().tour.get(change_idx?)?
+        };
+
+        let has_capacity_violation = if std::ptr::eq(change, target) {
+            self.has_change_capacity_violation(route_ctx, activity_ctx, change)
+        } else {
+            self.has_demand_capacity_violation(route_ctx, activity_ctx, change, change_idx.unwrap_or_default())
+        };
+
+        if let Some(stopped) = has_capacity_violation {
+            return Some(ConstraintViolation { code: self.code, stopped });
+        }
+
+        if get_changed_profile(change).is_some_and(|profile| self.has_time_violation(route_ctx, activity_ctx, profile))
+        {
+            return ConstraintViolation::skip(self.code);
+        }
+
+        None
+    }
+
+    /// Checks whether the load carried after the change activity inserted at the given position fits
+    /// into the changed capacity.
+    fn has_change_capacity_violation(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+        change: &Activity,
+    ) -> Option<bool> {
+        let capacity = get_changed_capacity::<T>(change)?;
+        let future = route_ctx.state().get_max_future_capacity_at::<T>(activity_ctx.index).copied().unwrap_or_default();
+
+        if capacity.can_fit(&future) { None } else { Some(false) }
+    }
+
+    /// Checks whether the demand of the inserted activity fits into the changed capacity.
+    fn has_demand_capacity_violation(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+        change: &Activity,
+        change_idx: usize,
+    ) -> Option<bool> {
+        let capacity = get_changed_capacity::<T>(change)?;
+        let demand = activity_ctx.target.job.as_ref().and_then(|single| single.dimens.get_job_demand::<T>())?;
+
+        let state = route_ctx.state();
+        let pivot_idx = activity_ctx.index;
+        let is_after_change = pivot_idx >= change_idx;
+
+        // NOTE static delivery is carried from the tour start, so it affects the load after change only
+        //      when the activity is served after the change
+        if is_after_change && demand.delivery.0.is_not_empty() {
+            let past = state.get_max_load_since_change_at::<T>(pivot_idx).copied().unwrap_or_default();
+            if !capacity.can_fit(&(past + demand.delivery.0)) {
+                return Some(true);
+            }
+        }
+
+        let future_idx = if is_after_change { pivot_idx } else { change_idx };
+        let future = state.get_max_future_capacity_at::<T>(future_idx).copied().unwrap_or_default();
+
+        if demand.pickup.0.is_not_empty() && !capacity.can_fit(&(future + demand.pickup.0)) {
+            return Some(false);
+        }
+
+        let load_change = demand.change();
+        if load_change.is_not_empty() && !capacity.can_fit(&(future + load_change)) {
+            return Some(false);
+        }
+
+        None
+    }
+
+    /// Checks whether the rest of the tour can be served on time when travelling with the changed
+    /// profile after the change activity. Time independent durations are used for estimation.
+    fn has_time_violation(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext, profile: &Profile) -> bool {
+        let route = route_ctx.route();
+        let default_profile = &route.actor.vehicle.profile;
+        let change_idx = route_ctx.state().get_capacity_change_index().copied();
+
+        let init = (
+            activity_ctx.prev.place.location,
+            activity_ctx.prev.schedule.departure,
+            change_idx.is_some_and(|change_idx| change_idx <= activity_ctx.index),
+        );
+
+        let next_activities = route.tour.all_activities().skip(activity_ctx.index + 1);
+
+        once(activity_ctx.target)
+            .chain(next_activities)
+            .try_fold(init, |(location, departure, is_changed), activity| {
+                let profile = if is_changed { profile } else { default_profile };
+                let arrival = departure + self.transport.duration_approx(profile, location, activity.place.location);
+
+                if arrival > activity.place.time.end {
+                    return ControlFlow::Break(());
+                }
+
+                let departure = match self.activity.estimate_departure(route, activity, arrival) {
+                    ControlFlow::Continue(departure) => departure,
+                    ControlFlow::Break(_) => return ControlFlow::Break(()),
+                };
+
+                let is_changed = is_changed || self.change_fns.is_change_activity(activity);
+
+                ControlFlow::Continue((activity.place.location, departure, is_changed))
+            })
+            .is_break()
+    }
+}
+
+impl<T: LoadOps> FeatureConstraint for CapacityChangeConstraint<T> {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { route_ctx, job, .. } => self.evaluate_route(route_ctx, job),
+            MoveContext::Activity { route_ctx, activity_ctx, .. } => self.evaluate_activity(route_ctx, activity_ctx),
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        let any_is_change = once(&source).chain(once(&candidate)).any(|job| self.change_fns.is_change_job(job));
+
+        if any_is_change { Err(self.code) } else { Ok(source) }
+    }
+}
+
+struct CapacityChangeState<T: LoadOps, JT: JobContextTransition + Send + Sync> {
+    change_fns: ChangeFns,
+    context_transition: JT,
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+    phantom: PhantomData<T>,
+}
+
+impl<T: LoadOps, JT: JobContextTransition + Send + Sync + 'static> FeatureState for CapacityChangeState<T, JT> {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+        process_conditional_jobs(solution_ctx, Some(route_index), &self.context_transition);
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let Some(change_idx) =
+            route_ctx.route().tour.all_activities().position(|activity| self.change_fns.is_change_activity(activity))
+        else {
+            route_ctx.state_mut().remove_capacity_change_index();
+            return;
+        };
+
+        // NOTE schedules are updated once again to make sure that routing profile is switched exactly
+        //      at the change activity: its previous departure time might be not valid anymore
+        if route_ctx.route().tour.get(change_idx).and_then(get_changed_profile).is_some() {
+            route_ctx.route_mut().tour.get_mut(change_idx).unwrap().schedule.departure = Timestamp::MAX;
+            update_route_schedule(route_ctx, self.activity.as_ref(), self.transport.as_ref());
+        }
+
+        let total = route_ctx.route().tour.total();
+        let (_, max_loads) = (change_idx..total).fold(
+            (T::default(), vec![T::default(); total]),
+            |(max, mut max_loads), activity_idx| {
+                let current = route_ctx.state().get_current_capacity_at::<T>(activity_idx).copied().unwrap_or_default();
+                let max = max.max_load(current);
+                max_loads[activity_idx] = max;
+
+                (max, max_loads)
+            },
+        );
+
+        route_ctx.state_mut().set_capacity_change_index(change_idx);
+        route_ctx.state_mut().set_max_load_since_change_states(max_loads);
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        self.remove_trivial_changes(solution_ctx);
+
+        solution_ctx.routes.iter_mut().filter(|route_ctx| route_ctx.is_stale()).for_each(|route_ctx| {
+            self.accept_route_state(route_ctx);
+        });
+
+        process_conditional_jobs(solution_ctx, None, &self.context_transition);
+    }
+}
+
+impl<T: LoadOps, JT: JobContextTransition + Send + Sync> CapacityChangeState<T, JT> {
+    /// Removes change activities from the tours which have no other jobs.
+    fn remove_trivial_changes(&self, solution_ctx: &mut SolutionContext) {
+        let changes = solution_ctx
+            .routes
+            .iter_mut()
+            .filter_map(|route_ctx| {
+                let tour = &route_ctx.route().tour;
+                let change = tour.jobs().find(|job| self.change_fns.is_change_job(job)).cloned()?;
+
+                if tour.job_count() != 1 || solution_ctx.locked.contains(&change) {
+                    return None;
+                }
+
+                route_ctx.route_mut().tour.remove(&change);
+
+                Some(change)
+            })
+            .collect::<Vec<_>>();
+
+        solution_ctx.ignored.extend(changes);
+    }
+}
+
+fn get_changed_profile(activity: &Activity) -> Option<&Profile> {
+    activity.job.as_ref().and_then(|single| single.dimens.get_changed_profile())
+}
+
+fn get_changed_capacity<T: LoadOps>(activity: &Activity) -> Option<&T> {
+    activity.job.as_ref().and_then(|single| single.dimens.get_changed_capacity())
+}
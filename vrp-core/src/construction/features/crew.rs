@@ -0,0 +1,239 @@
+//! A feature to model jobs which require multiple vehicles to be present simultaneously.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/crew_test.rs"]
+mod crew_test;
+
+use super::*;
+use crate::construction::enablers::*;
+use crate::models::problem::{ActivityCost, TransportCost, TravelTime};
+use std::collections::{HashMap, HashSet};
+
+custom_dimension!(pub JobCrew typeof CrewMember);
+custom_tour_state!(CrewIds typeof HashSet<String>);
+custom_solution_state!(CrewTimes typeof HashMap<String, Timestamp>);
+
+/// Specifies a membership of the job in a crew: a set of jobs which have to be served by different
+/// vehicles at the same time. A job which requires multiple vehicles is modeled as multiple crew members.
+#[derive(Clone, Debug)]
+pub struct CrewMember {
+    /// A crew id shared by all members of the crew.
+    pub id: String,
+    /// Total amount of crew members.
+    pub size: usize,
+}
+
+/// Creates a crew feature as a hard constraint. Members of the same crew are assigned to different
+/// routes and their service starts at the same time. A crew which is not fully assigned when insertion
+/// ends is removed from the solution.
+pub fn create_crew_feature(
+    name: &str,
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+    code: ViolationCode,
+) -> Result<Feature, GenericError> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(CrewConstraint { transport: transport.clone(), code })
+        .with_state(CrewState { transport, activity, code })
+        .build()
+}
+
+struct CrewConstraint {
+    transport: Arc<dyn TransportCost>,
+    code: ViolationCode,
+}
+
+impl FeatureConstraint for CrewConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { route_ctx, job, .. } => job.dimens().get_job_crew().and_then(|crew| {
+                let has_member = route_ctx.state().get_crew_ids().is_some_and(|ids| ids.contains(&crew.id));
+
+                if has_member { ConstraintViolation::fail(self.code) } else { None }
+            }),
+            MoveContext::Activity { solution_ctx, route_ctx, activity_ctx } => {
+                let crew = activity_ctx.target.job.as_ref().and_then(|single| single.dimens.get_job_crew())?;
+                let start = solution_ctx.state.get_crew_times().and_then(|times| times.get(&crew.id)).copied()?;
+
+                self.evaluate_activity(route_ctx, activity_ctx, start)
+            }
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        match (source.dimens().get_job_crew(), candidate.dimens().get_job_crew()) {
+            (None, None) => Ok(source),
+            _ => Err(self.code),
+        }
+    }
+}
+
+impl CrewConstraint {
+    /// Checks that the crew member can start service exactly at the time of other crew members.
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+        start: Timestamp,
+    ) -> Option<ConstraintViolation> {
+        let route = route_ctx.route();
+        let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+
+        if !target.place.time.contains(start) {
+            return ConstraintViolation::skip(self.code);
+        }
+
+        let departure = prev.schedule.departure;
+        let arrival = departure
+            + self.transport.duration(
+                route,
+                prev.place.location,
+                target.place.location,
+                TravelTime::Departure(departure),
+            );
+
+        if arrival > start {
+            return ConstraintViolation::skip(self.code);
+        }
+
+        let next = activity_ctx.next?;
+
+        let departure = start + target.place.duration;
+        let arrival = departure
+            + self.transport.duration(
+                route,
+                target.place.location,
+                next.place.location,
+                TravelTime::Departure(departure),
+            );
+        let latest_arrival =
+            route_ctx.state().get_latest_arrival_at(activity_ctx.index + 1).copied().unwrap_or(next.place.time.end);
+
+        if arrival > latest_arrival { ConstraintViolation::skip(self.code) } else { None }
+    }
+}
+
+struct CrewState {
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+    code: ViolationCode,
+}
+
+impl FeatureState for CrewState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, job: &Job) {
+        let Some(crew) = job.dimens().get_job_crew() else { return };
+
+        let start = solution_ctx.state.get_crew_times().and_then(|times| times.get(&crew.id)).copied();
+        let route_ctx = solution_ctx.routes.get_mut(route_index).unwrap();
+
+        // NOTE schedule has to be actual to get the service start of the first crew member
+        update_route_schedule(route_ctx, self.activity.as_ref(), self.transport.as_ref());
+
+        let start = route_ctx.route().tour.index(job).and_then(|idx| {
+            let activity = route_ctx.route_mut().tour.get_mut(idx)?;
+            let start = start.unwrap_or_else(|| activity.schedule.arrival.max(activity.place.time.start));
+            // NOTE pin the service start, so other insertions cannot break the synchronization
+            activity.place.time = TimeWindow::new(start, start);
+
+            Some(start)
+        });
+
+        update_route_schedule(route_ctx, self.activity.as_ref(), self.transport.as_ref());
+
+        let mut crew_ids = route_ctx.state().get_crew_ids().cloned().unwrap_or_default();
+        crew_ids.insert(crew.id.clone());
+        route_ctx.state_mut().set_crew_ids(crew_ids);
+
+        if let Some(start) = start {
+            let mut times = solution_ctx.state.get_crew_times().cloned().unwrap_or_default();
+            times.insert(crew.id.clone(), start);
+            update_crew_times(solution_ctx, times);
+        }
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let crew_ids = get_crew_members(route_ctx).map(|(crew, _)| crew.id.clone()).collect();
+        route_ctx.state_mut().set_crew_ids(crew_ids);
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        // NOTE remove partially assigned crews when there is nothing left to insert
+        if solution_ctx.required.is_empty() {
+            self.remove_partial_crews(solution_ctx);
+        }
+
+        solution_ctx.routes.iter_mut().filter(|route_ctx| route_ctx.is_stale()).for_each(|route_ctx| {
+            self.accept_route_state(route_ctx);
+        });
+
+        let times = solution_ctx
+            .routes
+            .iter()
+            .flat_map(|route_ctx| get_crew_members(route_ctx).map(|(crew, start)| (crew.id.clone(), start)))
+            .collect();
+        update_crew_times(solution_ctx, times);
+    }
+}
+
+impl CrewState {
+    fn remove_partial_crews(&self, solution_ctx: &mut SolutionContext) {
+        let assigned = solution_ctx.routes.iter().flat_map(get_crew_members).fold(
+            HashMap::<String, usize>::default(),
+            |mut acc, (crew, _)| {
+                *acc.entry(crew.id.clone()).or_default() += 1;
+                acc
+            },
+        );
+
+        let partial = solution_ctx
+            .routes
+            .iter()
+            .flat_map(|route_ctx| route_ctx.route().tour.jobs())
+            .filter(|job| {
+                job.dimens().get_job_crew().is_some_and(|crew| assigned.get(&crew.id).is_some_and(|&c| c < crew.size))
+            })
+            .filter(|job| !solution_ctx.locked.contains(job))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        partial.iter().for_each(|job| {
+            solution_ctx.routes.iter_mut().filter(|route_ctx| route_ctx.route().tour.contains(job)).for_each(
+                |route_ctx| {
+                    route_ctx.route_mut().tour.remove(job);
+                },
+            );
+        });
+
+        let crew_ids = partial
+            .iter()
+            .filter_map(|job| job.dimens().get_job_crew().map(|crew| crew.id.clone()))
+            .collect::<HashSet<_>>();
+
+        solution_ctx.unassigned.extend(partial.into_iter().map(|job| (job, UnassignmentInfo::Simple(self.code))));
+
+        // NOTE crew members which were not assigned get the same reason as the rest of the crew
+        solution_ctx
+            .unassigned
+            .iter_mut()
+            .filter(|(job, _)| job.dimens().get_job_crew().is_some_and(|crew| crew_ids.contains(&crew.id)))
+            .for_each(|(_, info)| *info = UnassignmentInfo::Simple(self.code));
+    }
+}
+
+/// Sets crew start times if they are changed.
+fn update_crew_times(solution_ctx: &mut SolutionContext, times: HashMap<String, Timestamp>) {
+    if solution_ctx.state.get_crew_times().is_some_and(|current| *current == times) {
+        return;
+    }
+
+    solution_ctx.state.set_crew_times(times);
+}
+
+/// Returns crew members of the route with their service start time.
+fn get_crew_members(route_ctx: &RouteContext) -> impl Iterator<Item = (&CrewMember, Timestamp)> + '_ {
+    route_ctx.route().tour.all_activities().filter_map(|activity| {
+        let crew = activity.job.as_ref().and_then(|single| single.dimens.get_job_crew())?;
+        Some((crew, activity.schedule.arrival.max(activity.place.time.start)))
+    })
+}
@@ -0,0 +1,172 @@
+//! Provides a way to define a custom hard constraint using closures.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/custom_constraint_test.rs"]
+mod custom_constraint_test;
+
+use super::*;
+
+type RouteRuleFn = Arc<dyn Fn(&RouteContext, &Job) -> bool + Send + Sync>;
+type ActivityRuleFn = Arc<dyn Fn(&RouteContext, &ActivityContext<'_>) -> bool + Send + Sync>;
+type MergeRuleFn = Arc<dyn Fn(&Job, &Job) -> bool + Send + Sync>;
+type RouteStateFn = Arc<dyn Fn(&mut RouteContext) + Send + Sync>;
+
+/// Provides a way to build a hard constraint feature from simple closures without implementing
+/// [FeatureConstraint] and [FeatureState] traits.
+///
+/// Rules return `true` when the move is allowed:
+/// * route rules are checked once per job and route: a violation means that the job cannot be assigned
+///   to the route at all
+/// * activity rules are checked for each insertion position: a violation means that the job cannot be
+///   inserted at the given position, but other positions are still considered
+///
+/// Route states registered via `add_route_state` are recalculated automatically when a route is changed
+/// and can be read within rules from `route_ctx.state()` using the same key type.
+pub struct CustomConstraintBuilder {
+    name: String,
+    code: ViolationCode,
+    route_rules: Vec<RouteRuleFn>,
+    activity_rules: Vec<ActivityRuleFn>,
+    merge_rule: Option<MergeRuleFn>,
+    route_states: Vec<RouteStateFn>,
+}
+
+impl CustomConstraintBuilder {
+    /// Creates a new instance of `CustomConstraintBuilder`.
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            code: ViolationCode::default(),
+            route_rules: vec![],
+            activity_rules: vec![],
+            merge_rule: None,
+            route_states: vec![],
+        }
+    }
+
+    /// Sets constraint violation code which is used to report back the reason of job's unassignment.
+    /// If not set, the default violation code is used.
+    pub fn set_violation_code(mut self, code: ViolationCode) -> Self {
+        self.code = code;
+        self
+    }
+
+    /// Adds a rule which checks whether the job can be assigned to the route.
+    pub fn add_route_rule<F>(mut self, rule: F) -> Self
+    where
+        F: Fn(&RouteContext, &Job) -> bool + Send + Sync + 'static,
+    {
+        self.route_rules.push(Arc::new(rule));
+        self
+    }
+
+    /// Adds a rule which checks whether the target activity can be inserted between prev and next activities.
+    pub fn add_activity_rule<F>(mut self, rule: F) -> Self
+    where
+        F: Fn(&RouteContext, &ActivityContext<'_>) -> bool + Send + Sync + 'static,
+    {
+        self.activity_rules.push(Arc::new(rule));
+        self
+    }
+
+    /// Sets a rule which checks whether the candidate job can be merged into the source job (e.g. when jobs
+    /// are clustered). If not set, merging is not allowed as custom rules cannot be checked for merged jobs.
+    pub fn set_merge_rule<F>(mut self, rule: F) -> Self
+    where
+        F: Fn(&Job, &Job) -> bool + Send + Sync + 'static,
+    {
+        self.merge_rule = Some(Arc::new(rule));
+        self
+    }
+
+    /// Adds a route state calculated by the given function and stored within a route state using key `K`.
+    pub fn add_route_state<K, V, F>(mut self, state_fn: F) -> Self
+    where
+        K: 'static,
+        V: Send + Sync + 'static,
+        F: Fn(&RouteContext) -> V + Send + Sync + 'static,
+    {
+        self.route_states.push(Arc::new(move |route_ctx| {
+            let value = state_fn(route_ctx);
+            route_ctx.state_mut().set_tour_state::<K, _>(value);
+        }));
+        self
+    }
+
+    /// Builds a custom hard constraint feature.
+    pub fn build(self) -> GenericResult<Feature> {
+        if self.route_rules.is_empty() && self.activity_rules.is_empty() {
+            return Err(format!("custom constraint '{}' has no rules", self.name).into());
+        }
+
+        let builder = FeatureBuilder::default().with_name(self.name.as_str()).with_constraint(CustomConstraint {
+            code: self.code,
+            route_rules: self.route_rules,
+            activity_rules: self.activity_rules,
+            merge_rule: self.merge_rule,
+        });
+
+        if self.route_states.is_empty() {
+            builder.build()
+        } else {
+            builder.with_state(CustomState { route_states: self.route_states }).build()
+        }
+    }
+}
+
+struct CustomConstraint {
+    code: ViolationCode,
+    route_rules: Vec<RouteRuleFn>,
+    activity_rules: Vec<ActivityRuleFn>,
+    merge_rule: Option<MergeRuleFn>,
+}
+
+impl FeatureConstraint for CustomConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { route_ctx, job, .. } => {
+                if self.route_rules.iter().all(|rule| rule(route_ctx, job)) {
+                    None
+                } else {
+                    ConstraintViolation::fail(self.code)
+                }
+            }
+            MoveContext::Activity { route_ctx, activity_ctx, .. } => {
+                if self.activity_rules.iter().all(|rule| rule(route_ctx, activity_ctx)) {
+                    None
+                } else {
+                    ConstraintViolation::skip(self.code)
+                }
+            }
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        match self.merge_rule.as_ref() {
+            Some(rule) if rule(&source, &candidate) => Ok(source),
+            _ => Err(self.code),
+        }
+    }
+}
+
+struct CustomState {
+    route_states: Vec<RouteStateFn>,
+}
+
+impl FeatureState for CustomState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        self.route_states.iter().for_each(|state_fn| state_fn(route_ctx));
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx
+            .routes
+            .iter_mut()
+            .filter(|route_ctx| route_ctx.is_stale())
+            .for_each(|route_ctx| self.accept_route_state(route_ctx));
+    }
+}
@@ -0,0 +1,110 @@
+//! An incompatibility feature provides the way to avoid assigning jobs of incompatible categories
+//! to the same tour.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/incompatibility_test.rs"]
+mod incompatibility_test;
+
+use super::*;
+use std::collections::{HashMap, HashSet};
+
+custom_dimension!(pub JobCategory typeof String);
+custom_tour_state!(TourCategories typeof HashSet<String>);
+
+/// Creates an incompatibility feature as a hard constraint. Each item of `incompatibilities` is a
+/// list of categories which are mutually incompatible: jobs of two different categories from the
+/// same list cannot be assigned to the same tour.
+pub fn create_incompatibility_feature(
+    name: &str,
+    code: ViolationCode,
+    incompatibilities: &[Vec<String>],
+) -> Result<Feature, GenericError> {
+    let matrix = incompatibilities.iter().fold(IncompatibilityMatrix::default(), |mut acc, categories| {
+        categories.iter().for_each(|left| {
+            categories.iter().filter(|&right| right != left).for_each(|right| {
+                acc.entry(left.clone()).or_default().insert(right.clone());
+            })
+        });
+
+        acc
+    });
+
+    if matrix.is_empty() {
+        return Err(GenericError::from("incompatibility feature requires at least two different categories"));
+    }
+
+    let matrix = Arc::new(matrix);
+
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(IncompatibilityConstraint { code, matrix })
+        .with_state(IncompatibilityState {})
+        .build()
+}
+
+type IncompatibilityMatrix = HashMap<String, HashSet<String>>;
+
+struct IncompatibilityConstraint {
+    code: ViolationCode,
+    matrix: Arc<IncompatibilityMatrix>,
+}
+
+impl IncompatibilityConstraint {
+    fn is_incompatible(&self, left: &String, right: &String) -> bool {
+        self.matrix.get(left).is_some_and(|categories| categories.contains(right))
+    }
+}
+
+impl FeatureConstraint for IncompatibilityConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { route_ctx, job, .. } => job.dimens().get_job_category().and_then(|job_category| {
+                let has_conflict = route_ctx.state().get_tour_categories().is_some_and(|categories| {
+                    categories.iter().any(|category| self.is_incompatible(job_category, category))
+                });
+
+                if has_conflict { ConstraintViolation::fail(self.code) } else { None }
+            }),
+            MoveContext::Activity { .. } => None,
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        match (source.dimens().get_job_category(), candidate.dimens().get_job_category()) {
+            (Some(source_category), Some(candidate_category))
+                if self.is_incompatible(source_category, candidate_category) =>
+            {
+                Err(self.code)
+            }
+            _ => Ok(source),
+        }
+    }
+}
+
+struct IncompatibilityState {}
+
+impl FeatureState for IncompatibilityState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, job: &Job) {
+        if job.dimens().get_job_category().is_some() {
+            self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap())
+        }
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let categories = route_ctx
+            .route()
+            .tour
+            .jobs()
+            .filter_map(|job| job.dimens().get_job_category())
+            .cloned()
+            .collect::<HashSet<_>>();
+
+        if categories.is_empty() {
+            route_ctx.state_mut().remove_tour_categories();
+        } else {
+            route_ctx.state_mut().set_tour_categories(categories);
+        }
+    }
+
+    fn accept_solution_state(&self, _: &mut SolutionContext) {}
+}
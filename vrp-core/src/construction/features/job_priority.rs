@@ -0,0 +1,59 @@
+//! Provides the way to assign jobs strictly according to their priority tiers.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/job_priority_test.rs"]
+mod job_priority_test;
+
+use super::*;
+use std::collections::BTreeSet;
+
+custom_dimension!(pub JobPriority typeof usize);
+
+/// Creates features which enforce strict lexicographic assignment of jobs by their priority tiers:
+/// a solution with fewer unassigned jobs of a higher tier is always better, no matter how many jobs
+/// of lower tiers are unassigned. The lower priority value means the higher priority, jobs without
+/// priority belong to the lowest tier.
+///
+/// Each tier is represented by its own feature, ordered from the highest tier to the lowest one, so
+/// every feature's objective is expected to be added as a separate layer of the goal.
+pub fn create_job_priority_features(name: &str, jobs: &[Job]) -> GenericResult<Vec<Feature>> {
+    let priorities = jobs.iter().filter_map(|job| job.dimens().get_job_priority().copied()).collect::<BTreeSet<_>>();
+    let has_default_tier = jobs.iter().any(|job| job.dimens().get_job_priority().is_none());
+
+    priorities
+        .into_iter()
+        .map(Some)
+        .chain(has_default_tier.then_some(None))
+        .map(|priority| {
+            let tier_name = priority.map_or_else(|| format!("{name}_default"), |priority| format!("{name}_{priority}"));
+
+            FeatureBuilder::default()
+                .with_name(tier_name.as_str())
+                .with_objective(JobPriorityObjective { priority })
+                .build()
+        })
+        .collect()
+}
+
+struct JobPriorityObjective {
+    priority: Option<usize>,
+}
+
+impl JobPriorityObjective {
+    fn is_tier_job(&self, job: &Job) -> bool {
+        job.dimens().get_job_priority().copied() == self.priority
+    }
+}
+
+impl FeatureObjective for JobPriorityObjective {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        solution.solution.unassigned.keys().filter(|job| self.is_tier_job(job)).count() as Cost
+    }
+
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { job, .. } if self.is_tier_job(job) => -1.,
+            MoveContext::Route { .. } | MoveContext::Activity { .. } => Cost::default(),
+        }
+    }
+}
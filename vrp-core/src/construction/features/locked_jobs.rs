@@ -61,13 +61,7 @@ struct LockingConstraint {
 
 impl LockingConstraint {
     fn evaluate_route(&self, route_ctx: &RouteContext, job: &Job) -> Option<ConstraintViolation> {
-        if let Some(condition) = self.conditions.get(job)
-            && !(condition)(&route_ctx.route().actor)
-        {
-            return ConstraintViolation::fail(self.code);
-        }
-
-        None
+        self.check_compatibility(route_ctx.route().actor.as_ref(), job).and_then(ConstraintViolation::fail)
     }
 
     fn evaluate_activity(
@@ -104,6 +98,10 @@ impl FeatureConstraint for LockingConstraint {
     fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
         if self.conditions.contains_key(&candidate) { Err(self.code) } else { Ok(source) }
     }
+
+    fn check_compatibility(&self, actor: &Actor, job: &Job) -> Option<ViolationCode> {
+        self.conditions.get(job).filter(|condition| !(condition)(actor)).map(|_| self.code)
+    }
 }
 
 struct JobIndex {
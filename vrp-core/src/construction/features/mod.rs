@@ -7,16 +7,30 @@ use crate::models::*;
 use rosomaxa::prelude::*;
 use std::sync::Arc;
 
+mod access_restrictions;
+pub use self::access_restrictions::{AccessRestriction, create_access_restriction_feature};
+
 mod breaks;
 pub use self::breaks::*;
 
 mod capacity;
-pub(crate) use self::capacity::MaxVehicleLoadTourState;
 pub use self::capacity::{CapacityFeatureBuilder, JobDemandDimension, VehicleCapacityDimension};
+pub(crate) use self::capacity::{CurrentCapacityActivityState, MaxVehicleLoadTourState};
+
+mod capacity_change;
+pub use self::capacity_change::{
+    CapacityChangeFeatureBuilder, ChangedCapacityDimension, ChangedProfileDimension, ProfileChangeTransportCost,
+};
 
 mod compatibility;
 pub use self::compatibility::{JobCompatibilityDimension, create_compatibility_feature};
 
+mod crew;
+pub use self::crew::{CrewMember, JobCrewDimension, create_crew_feature};
+
+mod custom_constraint;
+pub use self::custom_constraint::CustomConstraintBuilder;
+
 mod fast_service;
 pub use self::fast_service::FastServiceFeatureBuilder;
 
@@ -29,6 +43,12 @@ pub use self::groups::{JobGroupDimension, create_group_feature};
 mod hierarchical_areas;
 pub use self::hierarchical_areas::*;
 
+mod incompatibility;
+pub use self::incompatibility::{JobCategoryDimension, create_incompatibility_feature};
+
+mod job_priority;
+pub use self::job_priority::{JobPriorityDimension, create_job_priority_features};
+
 mod known_edge;
 pub use self::known_edge::create_known_edge_feature;
 
@@ -47,8 +67,14 @@ pub use self::recharge::RechargeFeatureBuilder;
 mod reloads;
 pub use self::reloads::{ReloadFeatureFactory, ReloadIntervalsTourState, SharedResource, SharedResourceId};
 
+mod separation;
+pub use self::separation::{JobSeparation, JobSeparationsDimension, SeparationScope, create_separation_feature};
+
 mod skills;
-pub use self::skills::{JobSkills, JobSkillsDimension, VehicleSkillsDimension, create_skills_feature};
+pub use self::skills::{
+    DEFAULT_SKILL_LEVEL, JobSkillLevel, JobSkills, JobSkillsDimension, VehicleSkillLevelsDimension,
+    VehicleSkillsDimension, create_skill_preference_feature, create_skills_feature,
+};
 
 mod total_value;
 pub use self::total_value::*;
@@ -62,9 +88,15 @@ pub use self::tour_limits::*;
 mod tour_order;
 pub use self::tour_order::*;
 
+mod tour_overlap;
+pub use self::tour_overlap::create_tour_overlap_feature;
+
 mod transport;
 pub use self::transport::*;
 
+mod waiting_time;
+pub use self::waiting_time::create_minimize_waiting_time_feature;
+
 mod work_balance;
 pub use self::work_balance::{
     create_activity_balanced_feature, create_distance_balanced_feature, create_duration_balanced_feature,
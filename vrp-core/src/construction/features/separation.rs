@@ -0,0 +1,137 @@
+//! A separation feature provides the way to forbid assigning jobs of the same separation group
+//! to the same tour or to the same vehicle.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/separation_test.rs"]
+mod separation_test;
+
+use super::*;
+use std::collections::HashSet;
+
+custom_dimension!(pub JobSeparations typeof Vec<JobSeparation>);
+custom_tour_state!(TourSeparations typeof HashSet<usize>);
+
+/// Specifies where jobs of the same separation group cannot be assigned together.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SeparationScope {
+    /// Jobs cannot be assigned to the same tour.
+    Tour,
+    /// Jobs cannot be assigned to the same vehicle, including its different shifts.
+    Vehicle,
+}
+
+/// Specifies a separation group which job belongs to.
+#[derive(Clone, Debug)]
+pub struct JobSeparation {
+    /// A separation group id.
+    pub group: usize,
+    /// A separation scope.
+    pub scope: SeparationScope,
+}
+
+/// Creates a separation feature as a hard constraint. Jobs of the same separation group cannot be
+/// assigned to the same tour or vehicle depending on the group's scope. Vehicles are identified by
+/// `VehicleIdDimension`, so different shifts of the same vehicle share the same scope.
+pub fn create_separation_feature(name: &str, code: ViolationCode) -> Result<Feature, GenericError> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(SeparationConstraint { code })
+        .with_state(SeparationState {})
+        .build()
+}
+
+struct SeparationConstraint {
+    code: ViolationCode,
+}
+
+impl SeparationConstraint {
+    fn has_conflict(
+        &self,
+        solution_ctx: &SolutionContext,
+        route_ctx: &RouteContext,
+        separation: &JobSeparation,
+    ) -> bool {
+        let has_group = |route_ctx: &RouteContext| {
+            route_ctx.state().get_tour_separations().is_some_and(|groups| groups.contains(&separation.group))
+        };
+
+        match separation.scope {
+            SeparationScope::Tour => has_group(route_ctx),
+            SeparationScope::Vehicle => {
+                let actor = &route_ctx.route().actor;
+
+                has_group(route_ctx)
+                    || solution_ctx
+                        .routes
+                        .iter()
+                        .filter(|other| is_same_vehicle(actor, &other.route().actor))
+                        .any(has_group)
+            }
+        }
+    }
+}
+
+impl FeatureConstraint for SeparationConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { solution_ctx, route_ctx, job } => {
+                job.dimens().get_job_separations().and_then(|separations| {
+                    let has_conflict =
+                        separations.iter().any(|separation| self.has_conflict(solution_ctx, route_ctx, separation));
+
+                    if has_conflict { ConstraintViolation::fail(self.code) } else { None }
+                })
+            }
+            MoveContext::Activity { .. } => None,
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        match (source.dimens().get_job_separations(), candidate.dimens().get_job_separations()) {
+            (Some(source_separations), Some(candidate_separations))
+                if source_separations
+                    .iter()
+                    .any(|left| candidate_separations.iter().any(|right| left.group == right.group)) =>
+            {
+                Err(self.code)
+            }
+            _ => Ok(source),
+        }
+    }
+}
+
+struct SeparationState {}
+
+impl FeatureState for SeparationState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, job: &Job) {
+        if job.dimens().get_job_separations().is_some() {
+            self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap())
+        }
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let groups = route_ctx
+            .route()
+            .tour
+            .jobs()
+            .filter_map(|job| job.dimens().get_job_separations())
+            .flat_map(|separations| separations.iter().map(|separation| separation.group))
+            .collect::<HashSet<_>>();
+
+        if groups.is_empty() {
+            route_ctx.state_mut().remove_tour_separations();
+        } else {
+            route_ctx.state_mut().set_tour_separations(groups);
+        }
+    }
+
+    fn accept_solution_state(&self, _: &mut SolutionContext) {}
+}
+
+/// Checks whether two actors belong to the same vehicle, e.g. represent its different shifts.
+pub(crate) fn is_same_vehicle(left: &Actor, right: &Actor) -> bool {
+    match (left.vehicle.dimens.get_vehicle_id(), right.vehicle.dimens.get_vehicle_id()) {
+        (Some(left), Some(right)) => left == right,
+        _ => Arc::ptr_eq(&left.vehicle, &right.vehicle),
+    }
+}
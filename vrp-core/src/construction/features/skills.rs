@@ -5,10 +5,14 @@
 mod skills_test;
 
 use super::*;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 custom_dimension!(pub JobSkills typeof JobSkills);
 custom_dimension!(pub VehicleSkills typeof HashSet<String>);
+custom_dimension!(pub VehicleSkillLevels typeof HashMap<String, usize>);
+
+/// A default level of the vehicle skill which has no level specified.
+pub const DEFAULT_SKILL_LEVEL: usize = 1;
 
 /// A job skills limitation for a vehicle.
 pub struct JobSkills {
@@ -18,6 +22,17 @@ pub struct JobSkills {
     pub one_of: Option<HashSet<String>>,
     /// Vehicle should have none of these skills defined.
     pub none_of: Option<HashSet<String>>,
+    /// Vehicle should have these skills with at least minimum level, higher levels can be preferred.
+    pub levels: Option<HashMap<String, JobSkillLevel>>,
+}
+
+/// A job requirement for a vehicle skill level.
+#[derive(Clone, Debug)]
+pub struct JobSkillLevel {
+    /// A minimum skill level. Zero means no hard requirement.
+    pub min: usize,
+    /// A preferred skill level. Vehicles with a lower level are penalized by the skill preference objective.
+    pub preferred: Option<usize>,
 }
 
 impl JobSkills {
@@ -26,7 +41,13 @@ impl JobSkills {
         let map: fn(Option<Vec<_>>) -> Option<HashSet<_>> =
             |skills| skills.and_then(|v| if v.is_empty() { None } else { Some(v.into_iter().collect()) });
 
-        Self { all_of: map(all_of), one_of: map(one_of), none_of: map(none_of) }
+        Self { all_of: map(all_of), one_of: map(one_of), none_of: map(none_of), levels: None }
+    }
+
+    /// Sets skill level requirements.
+    pub fn with_levels(mut self, levels: Vec<(String, JobSkillLevel)>) -> Self {
+        self.levels = if levels.is_empty() { None } else { Some(levels.into_iter().collect()) };
+        self
     }
 }
 
@@ -35,6 +56,12 @@ pub fn create_skills_feature(name: &str, code: ViolationCode) -> Result<Feature,
     FeatureBuilder::default().with_name(name).with_constraint(SkillsConstraint { code }).build()
 }
 
+/// Creates a skill preference feature as an objective. Each assigned job is penalized by the difference
+/// between preferred and actual skill levels of the vehicle which serves it, so higher levels are preferred.
+pub fn create_skill_preference_feature(name: &str) -> Result<Feature, GenericError> {
+    FeatureBuilder::default().with_name(name).with_objective(SkillPreferenceObjective {}).build()
+}
+
 struct SkillsConstraint {
     code: ViolationCode,
 }
@@ -43,17 +70,7 @@ impl FeatureConstraint for SkillsConstraint {
     fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
         match move_ctx {
             MoveContext::Route { route_ctx, job, .. } => {
-                if let Some(job_skills) = job.dimens().get_job_skills() {
-                    let vehicle_skills = route_ctx.route().actor.vehicle.dimens.get_vehicle_skills();
-                    let is_ok = check_all_of(job_skills, &vehicle_skills)
-                        && check_one_of(job_skills, &vehicle_skills)
-                        && check_none_of(job_skills, &vehicle_skills);
-                    if !is_ok {
-                        return ConstraintViolation::fail(self.code);
-                    }
-                }
-
-                None
+                self.check_compatibility(route_ctx.route().actor.as_ref(), job).and_then(ConstraintViolation::fail)
             }
             MoveContext::Activity { .. } => None,
         }
@@ -79,11 +96,92 @@ impl FeatureConstraint for SkillsConstraint {
                 check_skill_sets(source_skills.all_of.as_ref(), candidate_skills.all_of.as_ref())
                     && check_skill_sets(source_skills.one_of.as_ref(), candidate_skills.one_of.as_ref())
                     && check_skill_sets(source_skills.none_of.as_ref(), candidate_skills.none_of.as_ref())
+                    && check_skill_levels(source_skills.levels.as_ref(), candidate_skills.levels.as_ref())
             }
         };
 
         if has_comparable_skills { Ok(source) } else { Err(self.code) }
     }
+
+    fn check_compatibility(&self, actor: &Actor, job: &Job) -> Option<ViolationCode> {
+        let job_skills = job.dimens().get_job_skills()?;
+        let vehicle_skills = actor.vehicle.dimens.get_vehicle_skills();
+
+        let is_ok = check_all_of(job_skills, &vehicle_skills)
+            && check_one_of(job_skills, &vehicle_skills)
+            && check_none_of(job_skills, &vehicle_skills)
+            && check_levels(job_skills, actor);
+
+        if is_ok { None } else { Some(self.code) }
+    }
+}
+
+struct SkillPreferenceObjective {}
+
+impl FeatureObjective for SkillPreferenceObjective {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        solution
+            .solution
+            .routes
+            .iter()
+            .flat_map(|route_ctx| {
+                let actor = route_ctx.route().actor.as_ref();
+                route_ctx.route().tour.jobs().map(move |job| get_level_shortfall(job, actor))
+            })
+            .sum::<usize>() as Cost
+    }
+
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { route_ctx, job, .. } => {
+                get_level_shortfall(job, route_ctx.route().actor.as_ref()) as Cost
+            }
+            MoveContext::Activity { .. } => Cost::default(),
+        }
+    }
+}
+
+/// Returns a skill level of the vehicle or zero if the vehicle has no such skill.
+fn get_vehicle_skill_level(actor: &Actor, skill: &str) -> usize {
+    let dimens = &actor.vehicle.dimens;
+
+    dimens.get_vehicle_skill_levels().and_then(|levels| levels.get(skill)).copied().unwrap_or_else(|| {
+        if dimens.get_vehicle_skills().is_some_and(|skills| skills.contains(skill)) { DEFAULT_SKILL_LEVEL } else { 0 }
+    })
+}
+
+fn get_level_shortfall(job: &Job, actor: &Actor) -> usize {
+    job.dimens()
+        .get_job_skills()
+        .and_then(|skills| skills.levels.as_ref())
+        .map(|levels| {
+            levels
+                .iter()
+                .filter_map(|(skill, level)| level.preferred.map(|preferred| (skill, preferred)))
+                .map(|(skill, preferred)| preferred.saturating_sub(get_vehicle_skill_level(actor, skill)))
+                .sum()
+        })
+        .unwrap_or_default()
+}
+
+fn check_skill_levels(
+    source_levels: Option<&HashMap<String, JobSkillLevel>>,
+    candidate_levels: Option<&HashMap<String, JobSkillLevel>>,
+) -> bool {
+    match (source_levels, candidate_levels) {
+        (_, None) => true,
+        (None, Some(_)) => false,
+        (Some(source_levels), Some(candidate_levels)) => candidate_levels
+            .iter()
+            .all(|(skill, level)| source_levels.get(skill).is_some_and(|source| source.min >= level.min)),
+    }
+}
+
+fn check_levels(job_skills: &JobSkills, actor: &Actor) -> bool {
+    job_skills
+        .levels
+        .as_ref()
+        .is_none_or(|levels| levels.iter().all(|(skill, level)| get_vehicle_skill_level(actor, skill) >= level.min))
 }
 
 fn check_all_of(job_skills: &JobSkills, vehicle_skills: &Option<&HashSet<String>>) -> bool {
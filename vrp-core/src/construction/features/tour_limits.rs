@@ -6,15 +6,21 @@ mod tour_limits_test;
 
 use std::cmp::Ordering;
 
+use super::separation::is_same_vehicle;
 use super::*;
 use crate::construction::enablers::*;
 use crate::models::common::{Distance, Duration};
-use crate::models::problem::{Actor, TransportCost};
+use crate::models::problem::{Actor, Single, TransportCost, TravelTime};
+
+custom_tour_state!(StopCount typeof usize);
+custom_tour_state!(TotalDrivingTime typeof Duration);
 
 /// A function which returns activity size limit for a given actor.
 pub type ActivitySizeResolver = Arc<dyn Fn(&Actor) -> Option<usize> + Sync + Send>;
 /// A function to resolve travel limit.
 pub type TravelLimitFn<T> = Arc<dyn Fn(&Actor) -> Option<T> + Send + Sync>;
+/// A function which checks whether a job activity should be counted as a stop.
+pub type StopFilterFn = Arc<dyn Fn(&Single) -> bool + Send + Sync>;
 
 /// Creates a limit for activity amount in a tour.
 /// This is a hard constraint.
@@ -29,6 +35,37 @@ pub fn create_activity_limit_feature(
         .build()
 }
 
+/// Creates a limit for amount of stops in a tour. Only job activities accepted by `stop_filter_fn`
+/// are counted, so, for example, breaks or depot visits can be excluded.
+/// This is a hard constraint.
+pub fn create_stop_limit_hard_feature(
+    name: &str,
+    code: ViolationCode,
+    limit_fn: ActivitySizeResolver,
+    stop_filter_fn: StopFilterFn,
+) -> Result<Feature, GenericError> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(StopLimitConstraint { code, limit_fn, stop_filter_fn: stop_filter_fn.clone() })
+        .with_state(StopLimitState { stop_filter_fn })
+        .build()
+}
+
+/// Creates a limit for amount of stops in a tour. Only job activities accepted by `stop_filter_fn`
+/// are counted. Each stop above the limit is penalized.
+/// This is a soft constraint.
+pub fn create_stop_limit_soft_feature(
+    name: &str,
+    limit_fn: ActivitySizeResolver,
+    stop_filter_fn: StopFilterFn,
+) -> Result<Feature, GenericError> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(StopLimitObjective { limit_fn, stop_filter_fn: stop_filter_fn.clone() })
+        .with_state(StopLimitState { stop_filter_fn })
+        .build()
+}
+
 /// Creates a travel limits such as distance and/or duration.
 /// This is a hard constraint.
 pub fn create_travel_limit_feature(
@@ -53,6 +90,31 @@ pub fn create_travel_limit_feature(
         .build()
 }
 
+/// Creates cumulative travel limits such as total distance and/or total driving time of the vehicle
+/// across all its shifts (tours) in the plan. Driving time includes only traveling between activities,
+/// without waiting and service time. Tours are attributed to the same vehicle using `VehicleIdDimension`.
+/// This is a hard constraint.
+pub fn create_cumulative_travel_limit_feature(
+    name: &str,
+    transport: Arc<dyn TransportCost>,
+    distance_code: ViolationCode,
+    duration_code: ViolationCode,
+    total_distance_limit_fn: TravelLimitFn<Distance>,
+    total_driving_limit_fn: TravelLimitFn<Duration>,
+) -> Result<Feature, GenericError> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(CumulativeTravelLimitConstraint {
+            transport: transport.clone(),
+            total_distance_limit_fn,
+            total_driving_limit_fn,
+            distance_code,
+            duration_code,
+        })
+        .with_state(CumulativeTravelLimitState { transport })
+        .build()
+}
+
 struct ActivityLimitConstraint {
     code: ViolationCode,
     limit_fn: ActivitySizeResolver,
@@ -86,6 +148,99 @@ impl FeatureConstraint for ActivityLimitConstraint {
     }
 }
 
+struct StopLimitConstraint {
+    code: ViolationCode,
+    limit_fn: ActivitySizeResolver,
+    stop_filter_fn: StopFilterFn,
+}
+
+impl FeatureConstraint for StopLimitConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { route_ctx, job, .. } => {
+                (self.limit_fn)(route_ctx.route().actor.as_ref()).and_then(|limit| {
+                    let tour_stops = route_ctx.state().get_stop_count().copied().unwrap_or_default();
+                    let job_stops = count_job_stops(job, self.stop_filter_fn.as_ref());
+
+                    if job_stops > 0 && tour_stops + job_stops > limit {
+                        ConstraintViolation::fail(self.code)
+                    } else {
+                        ConstraintViolation::success()
+                    }
+                })
+            }
+            MoveContext::Activity { .. } => ConstraintViolation::success(),
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+}
+
+struct StopLimitObjective {
+    limit_fn: ActivitySizeResolver,
+    stop_filter_fn: StopFilterFn,
+}
+
+impl StopLimitObjective {
+    fn get_excess(&self, route_ctx: &RouteContext, extra_stops: usize) -> usize {
+        (self.limit_fn)(route_ctx.route().actor.as_ref()).map_or(0, |limit| {
+            let tour_stops = route_ctx.state().get_stop_count().copied().unwrap_or_default();
+
+            (tour_stops + extra_stops).saturating_sub(limit)
+        })
+    }
+}
+
+impl FeatureObjective for StopLimitObjective {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        solution.solution.routes.iter().map(|route_ctx| self.get_excess(route_ctx, 0)).sum::<usize>() as Cost
+    }
+
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { route_ctx, job, .. } => {
+                let job_stops = count_job_stops(job, self.stop_filter_fn.as_ref());
+
+                (self.get_excess(route_ctx, job_stops) - self.get_excess(route_ctx, 0)) as Cost
+            }
+            MoveContext::Activity { .. } => Cost::default(),
+        }
+    }
+}
+
+struct StopLimitState {
+    stop_filter_fn: StopFilterFn,
+}
+
+impl FeatureState for StopLimitState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let stops = route_ctx
+            .route()
+            .tour
+            .all_activities()
+            .filter_map(|activity| activity.job.as_ref())
+            .filter(|single| (self.stop_filter_fn)(single))
+            .count();
+
+        route_ctx.state_mut().set_stop_count(stops);
+    }
+
+    fn accept_solution_state(&self, _: &mut SolutionContext) {}
+}
+
+fn count_job_stops(job: &Job, stop_filter_fn: &(dyn Fn(&Single) -> bool + Send + Sync)) -> usize {
+    match job {
+        Job::Single(single) => usize::from(stop_filter_fn(single)),
+        Job::Multi(multi) => multi.jobs.iter().filter(|single| stop_filter_fn(single)).count(),
+    }
+}
+
 struct TravelLimitConstraint {
     transport: Arc<dyn TransportCost>,
     tour_distance_limit_fn: TravelLimitFn<Distance>,
@@ -240,3 +395,134 @@ impl FeatureState for TravelLimitState {
 
     fn accept_solution_state(&self, _: &mut SolutionContext) {}
 }
+
+struct CumulativeTravelLimitConstraint {
+    transport: Arc<dyn TransportCost>,
+    total_distance_limit_fn: TravelLimitFn<Distance>,
+    total_driving_limit_fn: TravelLimitFn<Duration>,
+    distance_code: ViolationCode,
+    duration_code: ViolationCode,
+}
+
+impl CumulativeTravelLimitConstraint {
+    /// Returns total distance and driving time of all tours of the route's vehicle.
+    fn get_vehicle_totals(&self, solution_ctx: &SolutionContext, route_ctx: &RouteContext) -> (Distance, Duration) {
+        let actor = &route_ctx.route().actor;
+
+        solution_ctx
+            .routes
+            .iter()
+            .filter(|other| !Arc::ptr_eq(&other.route().actor, actor) && is_same_vehicle(actor, &other.route().actor))
+            .chain(std::iter::once(route_ctx))
+            .map(|route_ctx| {
+                let state = route_ctx.state();
+                (
+                    state.get_total_distance().copied().unwrap_or_default(),
+                    state.get_total_driving_time().copied().unwrap_or_default(),
+                )
+            })
+            .fold((Distance::default(), Duration::default()), |acc, item| (acc.0 + item.0, acc.1 + item.1))
+    }
+
+    fn calculate_driving_delta(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Duration {
+        let route = route_ctx.route();
+        let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+        let prev_dep = prev.schedule.departure;
+
+        let prev_to_tar =
+            self.transport.duration(route, prev.place.location, target.place.location, TravelTime::Departure(prev_dep));
+
+        if let Some(next) = activity_ctx.next {
+            let tar_dep = prev_dep + prev_to_tar + target.place.duration;
+
+            let tar_to_next = self.transport.duration(
+                route,
+                target.place.location,
+                next.place.location,
+                TravelTime::Departure(tar_dep),
+            );
+            let prev_to_next = self.transport.duration(
+                route,
+                prev.place.location,
+                next.place.location,
+                TravelTime::Departure(prev_dep),
+            );
+
+            prev_to_tar + tar_to_next - prev_to_next
+        } else {
+            prev_to_tar
+        }
+    }
+}
+
+impl FeatureConstraint for CumulativeTravelLimitConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => None,
+            MoveContext::Activity { solution_ctx, route_ctx, activity_ctx } => {
+                let actor = route_ctx.route().actor.as_ref();
+                let distance_limit = (self.total_distance_limit_fn)(actor);
+                let driving_limit = (self.total_driving_limit_fn)(actor);
+
+                if distance_limit.is_none() && driving_limit.is_none() {
+                    return None;
+                }
+
+                let (total_distance, total_driving) = self.get_vehicle_totals(solution_ctx, route_ctx);
+
+                if let Some(distance_limit) = distance_limit {
+                    let (change_distance, _) = calculate_travel_delta(route_ctx, activity_ctx, self.transport.as_ref());
+                    if distance_limit < total_distance + change_distance {
+                        return ConstraintViolation::skip(self.distance_code);
+                    }
+                }
+
+                if let Some(driving_limit) = driving_limit {
+                    let change_driving = self.calculate_driving_delta(route_ctx, activity_ctx);
+                    if driving_limit < total_driving + change_driving {
+                        return ConstraintViolation::skip(self.duration_code);
+                    }
+                }
+
+                None
+            }
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+}
+
+struct CumulativeTravelLimitState {
+    transport: Arc<dyn TransportCost>,
+}
+
+impl FeatureState for CumulativeTravelLimitState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let (route, state) = route_ctx.as_mut();
+
+        let Some(start) = route.tour.start() else { return };
+        let init = (start.place.location, start.schedule.departure, Duration::default());
+        let (_, _, total_driving) =
+            route.tour.all_activities().skip(1).fold(init, |(loc, dep, total_driving), activity| {
+                let driving = self.transport.duration(route, loc, activity.place.location, TravelTime::Departure(dep));
+
+                (activity.place.location, activity.schedule.departure, total_driving + driving)
+            });
+
+        state.set_total_driving_time(total_driving);
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx
+            .routes
+            .iter_mut()
+            .filter(|route_ctx| route_ctx.is_stale())
+            .for_each(|route_ctx| self.accept_route_state(route_ctx));
+    }
+}
@@ -0,0 +1,149 @@
+//! Provides the way to keep territories served by different tours visually separated.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/tour_overlap_test.rs"]
+mod tour_overlap_test;
+
+use super::*;
+use crate::algorithms::geometry::*;
+
+custom_tour_state!(TourHull typeof Vec<Point>);
+custom_solution_state!(TourOverlap typeof Cost);
+
+/// Creates a feature which penalizes tours whose convex hulls, built from job locations, overlap with
+/// convex hulls of other tours. An overlap between two tours is measured as their hulls intersection
+/// area relative to the area of the smaller hull. Only the part of the overlap which exceeds `threshold`
+/// is penalized, so the threshold is expected to be in `[0, 1)` range.
+///
+/// `point_fn` maps location to its coordinate; locations without coordinates are ignored.
+pub fn create_tour_overlap_feature<F>(name: &str, threshold: Float, point_fn: F) -> GenericResult<Feature>
+where
+    F: Fn(Location) -> Option<Point> + Send + Sync + 'static,
+{
+    if !(0. ..1.).contains(&threshold) {
+        return Err(GenericError::from("tour overlap threshold should be in [0, 1) range"));
+    }
+
+    let point_fn: Arc<dyn Fn(Location) -> Option<Point> + Send + Sync> = Arc::new(point_fn);
+
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(TourOverlapObjective { threshold, point_fn: point_fn.clone() })
+        .with_state(TourOverlapState { threshold, point_fn })
+        .build()
+}
+
+struct TourOverlapObjective {
+    threshold: Float,
+    point_fn: Arc<dyn Fn(Location) -> Option<Point> + Send + Sync>,
+}
+
+impl FeatureObjective for TourOverlapObjective {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        let solution_ctx = &solution.solution;
+
+        solution_ctx
+            .state
+            .get_tour_overlap()
+            .copied()
+            .unwrap_or_else(|| get_total_overlap(solution_ctx.routes.as_slice(), self.threshold))
+    }
+
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        let MoveContext::Route { solution_ctx, route_ctx, job } = move_ctx else {
+            return Cost::default();
+        };
+
+        let hull = get_route_hull(route_ctx);
+
+        // NOTE all alternative locations of the job are considered here
+        let job_points = job.places().filter_map(|place| place.location).filter_map(|l| (self.point_fn)(l));
+        let points = hull.iter().cloned().chain(job_points).collect::<Vec<_>>();
+
+        if points.len() == hull.len() {
+            return Cost::default();
+        }
+
+        let new_hull = get_convex_hull(points.as_slice());
+        let (old_area, new_area) = (get_polygon_area(hull), get_polygon_area(new_hull.as_slice()));
+
+        if (new_area - old_area).abs() < f64::EPSILON {
+            return Cost::default();
+        }
+
+        solution_ctx
+            .routes
+            .iter()
+            .filter(|other| !Arc::ptr_eq(&other.route().actor, &route_ctx.route().actor))
+            .map(|other| {
+                let other_hull = get_route_hull(other);
+                let other_area = get_polygon_area(other_hull);
+
+                get_overlap_penalty((new_hull.as_slice(), new_area), (other_hull, other_area), self.threshold)
+                    - get_overlap_penalty((hull, old_area), (other_hull, other_area), self.threshold)
+            })
+            .sum()
+    }
+}
+
+struct TourOverlapState {
+    threshold: Float,
+    point_fn: Arc<dyn Fn(Location) -> Option<Point> + Send + Sync>,
+}
+
+impl FeatureState for TourOverlapState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let points = route_ctx
+            .route()
+            .tour
+            .all_activities()
+            .filter(|activity| activity.job.is_some())
+            .filter_map(|activity| (self.point_fn)(activity.place.location))
+            .collect::<Vec<_>>();
+
+        route_ctx.state_mut().set_tour_hull(get_convex_hull(points.as_slice()));
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        let total_overlap = get_total_overlap(solution_ctx.routes.as_slice(), self.threshold);
+        solution_ctx.state.set_tour_overlap(total_overlap);
+    }
+}
+
+fn get_route_hull(route_ctx: &RouteContext) -> &[Point] {
+    route_ctx.state().get_tour_hull().map(|hull| hull.as_slice()).unwrap_or(&[])
+}
+
+fn get_total_overlap(routes: &[RouteContext], threshold: Float) -> Cost {
+    let hulls = routes
+        .iter()
+        .map(get_route_hull)
+        .filter(|hull| hull.len() > 2)
+        .map(|hull| (hull, get_polygon_area(hull)))
+        .collect::<Vec<_>>();
+
+    hulls
+        .iter()
+        .enumerate()
+        .flat_map(|(idx, &left)| hulls.iter().skip(idx + 1).map(move |&right| (left, right)))
+        .map(|(left, right)| get_overlap_penalty(left, right, threshold))
+        .sum()
+}
+
+fn get_overlap_penalty(left: (&[Point], Float), right: (&[Point], Float), threshold: Float) -> Cost {
+    let (left_hull, left_area) = left;
+    let (right_hull, right_area) = right;
+    let min_area = left_area.min(right_area);
+
+    if min_area < f64::EPSILON {
+        return Cost::default();
+    }
+
+    let overlap = get_convex_intersection_area(left_hull, right_hull) / min_area;
+
+    (overlap - threshold).max(0.)
+}
@@ -13,6 +13,20 @@ use crate::models::problem::{ActivityCost, Single, TransportCost, TravelTime};
 use crate::models::solution::Activity;
 use rosomaxa::utils::UnwrapValue;
 
+custom_dimension!(pub JobPlaceCosts typeof Vec<Cost>);
+custom_dimension!(pub VehicleCostTiers typeof Vec<CostTier>);
+
+/// Specifies a fixed cost tier of the vehicle, e.g. a rental contract which is cheaper for short tours.
+#[derive(Clone, Debug)]
+pub struct CostTier {
+    /// A fixed cost of the vehicle usage which is applied when the tour stays within the tier limits.
+    pub fixed: Cost,
+    /// A max tour duration of the tier. No limit when omitted.
+    pub max_duration: Option<Duration>,
+    /// A max tour distance of the tier. No limit when omitted.
+    pub max_distance: Option<Distance>,
+}
+
 // TODO
 //  remove get_total_cost, get_route_costs, get_max_cost methods from contexts
 //  add validation rule which ensures usage of only one of these methods.
@@ -24,12 +38,20 @@ pub struct TransportFeatureBuilder {
     activity: Option<Arc<dyn ActivityCost>>,
     code: Option<ViolationCode>,
     is_constrained: bool,
+    has_place_costs: bool,
 }
 
 impl TransportFeatureBuilder {
     /// Creates a new instance of `TransportFeatureBuilder`.
     pub fn new(name: &str) -> Self {
-        Self { name: name.to_string(), transport: None, activity: None, code: None, is_constrained: true }
+        Self {
+            name: name.to_string(),
+            transport: None,
+            activity: None,
+            code: None,
+            is_constrained: true,
+            has_place_costs: false,
+        }
     }
 
     /// Sets constraint violation code which is used to report back the reason of job's unassignment.
@@ -46,6 +68,13 @@ impl TransportFeatureBuilder {
         self
     }
 
+    /// Specifies whether extra costs of job places (see [JobPlaceCosts]) should be considered by cost
+    /// minimization objective. Default is false.
+    pub fn set_place_costs(mut self, has_place_costs: bool) -> Self {
+        self.has_place_costs = has_place_costs;
+        self
+    }
+
     /// Sets transport costs to estimate distance.
     pub fn set_transport_cost(mut self, transport: Arc<dyn TransportCost>) -> Self {
         self.transport = Some(transport);
@@ -104,7 +133,11 @@ impl TransportFeatureBuilder {
 
         create_feature(
             self.name.as_str(),
-            CostObjective { transport: transport.clone(), activity: activity.clone() },
+            CostObjective {
+                transport: transport.clone(),
+                activity: activity.clone(),
+                has_place_costs: self.has_place_costs,
+            },
             transport,
             activity,
             self.code.unwrap_or_default(),
@@ -278,6 +311,24 @@ impl FeatureConstraint for TransportConstraint {
         // NOTE we don't change temporal parameters here, it is responsibility of the caller
         Ok(source)
     }
+
+    fn check_compatibility(&self, actor: &Actor, job: &Job) -> Option<ViolationCode> {
+        // NOTE time offsets depend on route's departure time, so they are considered as compatible
+        let check_single = |single: &Arc<Single>| {
+            single
+                .places
+                .iter()
+                .flat_map(|place| place.times.iter())
+                .any(|time| time.as_time_window().is_none_or(|time| time.intersects(&actor.detail.time)))
+        };
+
+        let has_time_intersection = match job {
+            Job::Single(single) => check_single(single),
+            Job::Multi(multi) => multi.jobs.iter().all(check_single),
+        };
+
+        if has_time_intersection { None } else { Some(self.time_window_code) }
+    }
 }
 
 struct DistanceObjective {
@@ -377,9 +428,40 @@ where
     prev_target_next - prev_next
 }
 
+/// Returns a fixed cost of the vehicle for the tour with given duration and distance: the cheapest cost tier
+/// (see [VehicleCostTiers]) whose limits are not exceeded or the default vehicle fixed cost.
+pub fn get_vehicle_fixed_cost(vehicle: &Vehicle, duration: Duration, distance: Distance) -> Cost {
+    vehicle
+        .dimens
+        .get_vehicle_cost_tiers()
+        .and_then(|tiers| {
+            tiers
+                .iter()
+                .filter(|tier| {
+                    tier.max_duration.is_none_or(|max_duration| duration <= max_duration)
+                        && tier.max_distance.is_none_or(|max_distance| distance <= max_distance)
+                })
+                .map(|tier| tier.fixed)
+                .min_by(|a, b| a.total_cmp(b))
+        })
+        .unwrap_or(vehicle.costs.fixed)
+}
+
+/// Returns an extra cost of serving the activity at its place. It is zero if the job has no place costs.
+pub fn get_place_cost(activity: &Activity) -> Cost {
+    activity
+        .job
+        .as_ref()
+        .and_then(|single| single.dimens.get_job_place_costs())
+        .and_then(|costs| costs.get(activity.place.idx))
+        .copied()
+        .unwrap_or_default()
+}
+
 struct CostObjective {
     activity: Arc<dyn ActivityCost>,
     transport: Arc<dyn TransportCost>,
+    has_place_costs: bool,
 }
 
 impl CostObjective {
@@ -387,10 +469,46 @@ impl CostObjective {
         if route_ctx.route().tour.has_jobs() {
             0.
         } else {
-            route_ctx.route().actor.driver.costs.fixed + route_ctx.route().actor.vehicle.costs.fixed
+            let vehicle = route_ctx.route().actor.vehicle.as_ref();
+            let vehicle_fixed = if vehicle.dimens.get_vehicle_cost_tiers().is_some() {
+                get_vehicle_fixed_cost(vehicle, Duration::default(), Distance::default())
+            } else {
+                vehicle.costs.fixed
+            };
+
+            route_ctx.route().actor.driver.costs.fixed + vehicle_fixed
         }
     }
 
+    /// Estimates a change of the vehicle fixed cost caused by cost tiers when activity is inserted.
+    fn estimate_cost_tiers(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Float {
+        let vehicle = route_ctx.route().actor.vehicle.as_ref();
+        if vehicle.dimens.get_vehicle_cost_tiers().is_none() {
+            return 0.;
+        }
+
+        let (duration, distance) = if route_ctx.route().tour.has_jobs() {
+            let state = route_ctx.state();
+            (
+                state.get_total_duration().copied().unwrap_or_default(),
+                state.get_total_distance().copied().unwrap_or_default(),
+            )
+        } else {
+            (Duration::default(), Distance::default())
+        };
+
+        let (transport, activity) = (self.transport.as_ref(), self.activity.as_ref());
+        let duration_delta = estimate_leg(transport, activity, route_ctx, activity_ctx, |from, to, time| {
+            transport.duration(route_ctx.route(), from, to, time)
+        }) + activity_ctx.target.place.duration;
+        let distance_delta = estimate_leg(transport, activity, route_ctx, activity_ctx, |from, to, time| {
+            transport.distance(route_ctx.route(), from, to, time)
+        });
+
+        get_vehicle_fixed_cost(vehicle, duration + duration_delta, distance + distance_delta)
+            - get_vehicle_fixed_cost(vehicle, duration, distance)
+    }
+
     fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Float {
         let prev = activity_ctx.prev;
         let target = activity_ctx.target;
@@ -452,13 +570,48 @@ impl CostObjective {
 
 impl FeatureObjective for CostObjective {
     fn fitness(&self, insertion_ctx: &InsertionContext) -> Cost {
-        insertion_ctx.get_total_cost().unwrap_or_default()
+        let total_cost = insertion_ctx.get_total_cost().unwrap_or_default();
+
+        // NOTE total cost includes default vehicle fixed cost, so only the difference is added
+        let tiers_cost = insertion_ctx
+            .solution
+            .routes
+            .iter()
+            .filter(|route_ctx| route_ctx.route().actor.vehicle.dimens.get_vehicle_cost_tiers().is_some())
+            .map(|route_ctx| {
+                let vehicle = route_ctx.route().actor.vehicle.as_ref();
+                let duration = route_ctx.state().get_total_duration().copied().unwrap_or_default();
+                let distance = route_ctx.state().get_total_distance().copied().unwrap_or_default();
+
+                get_vehicle_fixed_cost(vehicle, duration, distance) - vehicle.costs.fixed
+            })
+            .sum::<Cost>();
+
+        let place_cost = if self.has_place_costs {
+            insertion_ctx
+                .solution
+                .routes
+                .iter()
+                .flat_map(|route_ctx| route_ctx.route().tour.all_activities())
+                .map(get_place_cost)
+                .sum::<Cost>()
+        } else {
+            Cost::default()
+        };
+
+        total_cost + tiers_cost + place_cost
     }
 
     fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
         match move_ctx {
             MoveContext::Route { route_ctx, .. } => self.estimate_route(route_ctx),
-            MoveContext::Activity { route_ctx, activity_ctx, .. } => self.estimate_activity(route_ctx, activity_ctx),
+            MoveContext::Activity { route_ctx, activity_ctx, .. } => {
+                let place_cost = if self.has_place_costs { get_place_cost(activity_ctx.target) } else { 0. };
+
+                self.estimate_activity(route_ctx, activity_ctx)
+                    + self.estimate_cost_tiers(route_ctx, activity_ctx)
+                    + place_cost
+            }
         }
     }
 }
@@ -0,0 +1,57 @@
+//! Provides the way to minimize waiting time considering flexible route departure.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/waiting_time_test.rs"]
+mod waiting_time_test;
+
+use super::*;
+use crate::construction::enablers::{TotalWaitingTimeTourState, get_departure_time_shift};
+use crate::models::problem::{TransportCost, TravelTime};
+
+/// Creates a feature to minimize total waiting time of all tours. Departure time of each route is
+/// considered as flexible: waiting time which can be avoided by delaying route's start is not counted.
+/// Actual departure time is expected to be moved forward later, e.g. by [crate::solver::processing::AdvanceDeparture].
+pub fn create_minimize_waiting_time_feature(name: &str, transport: Arc<dyn TransportCost>) -> GenericResult<Feature> {
+    FeatureBuilder::default().with_name(name).with_objective(WaitingTimeObjective { transport }).build()
+}
+
+struct WaitingTimeObjective {
+    transport: Arc<dyn TransportCost>,
+}
+
+impl FeatureObjective for WaitingTimeObjective {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        solution.solution.routes.iter().map(get_flexible_waiting_time).sum()
+    }
+
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        let MoveContext::Activity { route_ctx, activity_ctx, .. } = move_ctx else {
+            return Cost::default();
+        };
+
+        // NOTE waiting at the first activity can be avoided by delaying departure
+        if activity_ctx.index == 0 {
+            return Cost::default();
+        }
+
+        let prev = activity_ctx.prev;
+        let target = activity_ctx.target;
+        let departure = prev.schedule.departure;
+
+        let arrival = departure
+            + self.transport.duration(
+                route_ctx.route(),
+                prev.place.location,
+                target.place.location,
+                TravelTime::Departure(departure),
+            );
+
+        (target.place.time.start - arrival).max(0.)
+    }
+}
+
+fn get_flexible_waiting_time(route_ctx: &RouteContext) -> Cost {
+    let total_waiting = route_ctx.state().get_total_waiting_time().copied().unwrap_or_default();
+
+    (total_waiting - get_departure_time_shift(route_ctx.route())).max(0.)
+}
@@ -0,0 +1,85 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/heuristics/compatibility_test.rs"]
+mod compatibility_test;
+
+use crate::models::problem::{Actor, Fleet, Job};
+use crate::models::{FeatureConstraint, ViolationCode};
+use rosomaxa::utils::parallel_collect;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Keeps precomputed job-actor compatibility: for each pair, it stores a code of the constraint
+/// which rejects the job regardless of route and solution state.
+/// Actors with the same compatibility share the storage, so its size depends on amount of distinct
+/// vehicle types rather than on fleet size.
+#[derive(Default)]
+pub struct JobCompatibilityIndex {
+    jobs: HashMap<Job, usize>,
+    actors: HashMap<Arc<Actor>, usize>,
+    codes: Vec<ViolationCode>,
+    types: Vec<Vec<u8>>,
+}
+
+impl JobCompatibilityIndex {
+    /// Creates a new instance of `JobCompatibilityIndex`.
+    pub fn new(constraints: &[Arc<dyn FeatureConstraint>], fleet: &Fleet, jobs: &[Job]) -> Self {
+        let columns = parallel_collect(&fleet.actors, |actor| {
+            jobs.iter()
+                .map(|job| constraints.iter().find_map(|constraint| constraint.check_compatibility(actor, job)))
+                .collect::<Vec<_>>()
+        });
+
+        let mut codes = Vec::<ViolationCode>::default();
+        let mut type_keys = HashMap::<Vec<u8>, usize>::default();
+
+        let actors = fleet
+            .actors
+            .iter()
+            .zip(columns)
+            .map(|(actor, column)| {
+                let column = column
+                    .into_iter()
+                    .map(|code| {
+                        let Some(code) = code else { return 0 };
+
+                        let code_idx = codes.iter().position(|other| *other == code).unwrap_or_else(|| {
+                            codes.push(code);
+                            codes.len() - 1
+                        });
+
+                        // NOTE too many distinct codes are not expected, treat such pairs as compatible
+                        u8::try_from(code_idx + 1).unwrap_or(0)
+                    })
+                    .collect::<Vec<_>>();
+
+                let type_count = type_keys.len();
+                let type_idx = *type_keys.entry(column).or_insert(type_count);
+
+                (actor.clone(), type_idx)
+            })
+            .collect();
+
+        let mut types = vec![Vec::default(); type_keys.len()];
+        type_keys.into_iter().for_each(|(column, type_idx)| types[type_idx] = column);
+
+        let jobs = jobs.iter().enumerate().map(|(idx, job)| (job.clone(), idx)).collect();
+
+        Self { jobs, actors, codes, types }
+    }
+
+    /// Returns a violation code if the job can never be assigned to the actor.
+    /// Returns [None] if they are compatible or the pair is not known to the index.
+    pub fn get_violation(&self, actor: &Actor, job: &Job) -> Option<ViolationCode> {
+        if self.codes.is_empty() {
+            return None;
+        }
+
+        let type_idx = *self.actors.get(actor)?;
+        let job_idx = *self.jobs.get(job)?;
+
+        match self.types.get(type_idx).and_then(|column| column.get(job_idx)).copied() {
+            Some(code_idx) if code_idx > 0 => self.codes.get(code_idx as usize - 1).copied(),
+            _ => None,
+        }
+    }
+}
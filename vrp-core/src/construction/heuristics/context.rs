@@ -5,15 +5,17 @@ mod context_test;
 use crate::construction::enablers::{TotalDistanceTourState, TotalDurationTourState};
 use crate::construction::heuristics::factories::*;
 use crate::models::GoalContext;
-use crate::models::common::Cost;
+use crate::models::common::{Cost, TimeWindow};
 use crate::models::problem::*;
 use crate::models::solution::*;
 use crate::models::{Problem, Solution};
 use crate::prelude::ViolationCode;
+use crate::utils::{AllocationPool, release_pooled, take_pooled};
 use rosomaxa::evolution::TelemetryMetrics;
 use rosomaxa::prelude::*;
 use rustc_hash::FxHasher;
 use std::any::{Any, TypeId};
+use std::cell::RefCell;
 use std::collections::{HashMap, HashSet};
 use std::fmt::{Debug, Formatter};
 use std::hash::BuildHasherDefault;
@@ -59,27 +61,10 @@ impl InsertionContext {
     ///
     /// Returns None if cost cannot be calculate as the context is in non-consistent state.
     pub fn get_total_cost(&self) -> Option<Cost> {
-        let get_cost = |costs: &Costs, distance: Float, duration: Float| {
-            costs.fixed
-                + costs.per_distance * distance
-                // NOTE this is incorrect when timing costs are different: fitness value will be
-                // different from actual cost. However we accept this so far as it is simpler for
-                // implementation and pragmatic format does not expose this feature
-                // .
-                // TODO calculate actual cost
-                + costs.per_driving_time.max(costs.per_service_time).max(costs.per_waiting_time) * duration
-        };
-
-        self.solution.routes.iter().try_fold(Cost::default(), |acc, route_ctx| {
-            let actor = &route_ctx.route.actor;
-            let distance = route_ctx.state.get_total_distance();
-            let duration = route_ctx.state.get_total_duration();
-
-            distance.zip(duration).map(|(&distance, &duration)| {
-                acc + get_cost(&actor.vehicle.costs, distance, duration)
-                    + get_cost(&actor.driver.costs, distance, duration)
-            })
-        })
+        self.solution
+            .routes
+            .iter()
+            .try_fold(Cost::default(), |acc, route_ctx| route_ctx.get_route_cost().map(|cost| acc + cost))
     }
 
     /// Restores valid context state.
@@ -101,6 +86,11 @@ impl HeuristicSolution for InsertionContext {
             environment: self.environment.clone(),
         }
     }
+
+    fn memory_usage(&self) -> usize {
+        // NOTE problem and environment are shared between solutions, so they are not accounted here
+        size_of::<Self>() + self.solution.memory_usage()
+    }
 }
 
 impl Debug for InsertionContext {
@@ -119,8 +109,19 @@ pub enum UnassignmentInfo {
     Unknown,
     /// Only single code is available.
     Simple(ViolationCode),
-    /// A collection of actor-code pairs is available.
-    Detailed(Vec<(Arc<Actor>, ViolationCode)>),
+    /// A collection of per route details is available.
+    Detailed(Vec<UnassignmentDetail>),
+}
+
+/// Keeps information about the most frequent reason why a job cannot be inserted into a specific route.
+#[derive(Clone, Debug)]
+pub struct UnassignmentDetail {
+    /// An actor of the route.
+    pub actor: Arc<Actor>,
+    /// A violation code.
+    pub code: ViolationCode,
+    /// A time span of route legs where job insertion has failed with the given code.
+    pub time: Option<TimeWindow>,
 }
 
 /// Contains information regarding discovered solution.
@@ -187,6 +188,17 @@ impl SolutionContext {
             state: self.state.clone(),
         }
     }
+
+    /// Returns an approximate amount of memory (in bytes) used by the solution context.
+    pub fn memory_usage(&self) -> usize {
+        let jobs = (self.required.capacity() + self.ignored.capacity() + self.locked.capacity()) * size_of::<Job>()
+            + self.unassigned.capacity() * size_of::<(Job, UnassignmentInfo)>();
+        let routes = self.routes.iter().map(|route_ctx| route_ctx.memory_usage()).sum::<usize>();
+        // NOTE route prototypes in registry are shared between solutions
+        let registry = self.registry.index.capacity() * size_of::<(Arc<Actor>, Arc<RouteContext>)>();
+
+        jobs + routes + registry
+    }
 }
 
 impl Debug for SolutionContext {
@@ -246,18 +258,25 @@ impl SolutionState {
 }
 
 /// Specifies insertion context for route.
+/// NOTE route and state are shared between copies of the context and cloned only on `mut` access.
 pub struct RouteContext {
-    route: Route,
-    state: RouteState,
+    route: Arc<Route>,
+    state: Arc<RouteState>,
     cache: RouteCache,
 }
 
 /// Provides a way to associate arbitrary data within route or/and activity.
 /// NOTE: do not put any state that is not refreshed after `accept_route_state` call: it will be
 /// wiped out at some point.
-#[derive(Clone)]
 pub struct RouteState {
-    index: HashMap<TypeId, Arc<dyn Any + Send + Sync>, BuildHasherDefault<FxHasher>>,
+    index: RouteStateIndex,
+}
+
+type RouteStateIndex = HashMap<TypeId, Arc<dyn Any + Send + Sync>, BuildHasherDefault<FxHasher>>;
+
+thread_local! {
+    /// Keeps allocations of dropped route states to reuse them in deep copies.
+    static ROUTE_STATE_POOL: RefCell<AllocationPool<RouteStateIndex>> = RefCell::new(AllocationPool::default());
 }
 
 impl RouteContext {
@@ -269,15 +288,28 @@ impl RouteContext {
 
     /// Creates a new instance of `RouteContext` with arguments provided.
     pub fn new_with_state(route: Route, state: RouteState) -> Self {
-        RouteContext { route, state, cache: RouteCache { is_stale: true } }
+        RouteContext { route: Arc::new(route), state: Arc::new(state), cache: RouteCache { is_stale: true } }
     }
 
-    /// Creates a deep copy of `RouteContext`.
+    /// Creates a deep copy of `RouteContext`. Route and state are shared with the original context
+    /// until one of them is accessed by `mut` methods, so copying of unchanged routes is cheap.
     pub fn deep_copy(&self) -> Self {
-        let new_route = Route { actor: self.route.actor.clone(), tour: self.route.tour.deep_copy() };
-        let new_state = self.state.clone();
+        RouteContext {
+            route: self.route.clone(),
+            state: self.state.clone(),
+            cache: RouteCache { is_stale: self.cache.is_stale },
+        }
+    }
 
-        RouteContext { route: new_route, state: new_state, cache: RouteCache { is_stale: self.cache.is_stale } }
+    /// Returns an approximate amount of memory (in bytes) used by the route context.
+    /// NOTE state values are opaque, so each of them is assumed to keep a value per activity.
+    /// Memory of route and state shared between copies is split evenly among them.
+    pub fn memory_usage(&self) -> usize {
+        let activities = self.route.tour.total();
+        let tour = activities * size_of::<Activity>() + self.route.tour.job_count() * size_of::<Job>();
+        let state = self.state.index.capacity() * (size_of::<TypeId>() + (activities + 1) * size_of::<Float>());
+
+        size_of::<Self>() + tour / Arc::strong_count(&self.route) + state / Arc::strong_count(&self.state)
     }
 
     /// Returns a reference to route.
@@ -290,25 +322,49 @@ impl RouteContext {
         &self.state
     }
 
+    /// Gets total cost of the route.
+    ///
+    /// Returns None if cost cannot be calculate as the context is in non-consistent state.
+    pub fn get_route_cost(&self) -> Option<Cost> {
+        let get_cost = |costs: &Costs, distance: Float, duration: Float| {
+            costs.fixed
+                + costs.per_distance * distance
+                // NOTE this is incorrect when timing costs are different: fitness value will be
+                // different from actual cost. However we accept this so far as it is simpler for
+                // implementation and pragmatic format does not expose this feature
+                // .
+                // TODO calculate actual cost
+                + costs.per_driving_time.max(costs.per_service_time).max(costs.per_waiting_time) * duration
+        };
+
+        let actor = &self.route.actor;
+        let distance = self.state.get_total_distance();
+        let duration = self.state.get_total_duration();
+
+        distance.zip(duration).map(|(&distance, &duration)| {
+            get_cost(&actor.vehicle.costs, distance, duration) + get_cost(&actor.driver.costs, distance, duration)
+        })
+    }
+
     /// Unwraps given `RouteContext` as pair of mutable references.
     /// Marks context as stale.
     pub fn as_mut(&mut self) -> (&mut Route, &mut RouteState) {
         self.mark_stale(true);
-        (&mut self.route, &mut self.state)
+        (unshare_route(&mut self.route), Arc::make_mut(&mut self.state))
     }
 
     /// Returns mutable reference to used `Route`.
     /// Marks context as stale.
     pub fn route_mut(&mut self) -> &mut Route {
         self.mark_stale(true);
-        &mut self.route
+        unshare_route(&mut self.route)
     }
 
     /// Returns mutable reference to used `RouteState`.
     /// Marks context as stale.
     pub fn state_mut(&mut self) -> &mut RouteState {
         self.mark_stale(true);
-        &mut self.state
+        Arc::make_mut(&mut self.state)
     }
 
     /// Returns true if context is stale. Context is marked stale when it is accessed by `mut`
@@ -323,6 +379,15 @@ impl RouteContext {
     }
 }
 
+/// Returns a mutable reference to the route making its deep copy first if it is shared.
+fn unshare_route(route: &mut Arc<Route>) -> &mut Route {
+    if Arc::get_mut(route).is_none() {
+        *route = Arc::new(route.deep_copy());
+    }
+
+    Arc::get_mut(route).expect("route should not be shared")
+}
+
 impl PartialEq<RouteContext> for RouteContext {
     fn eq(&self, other: &RouteContext) -> bool {
         std::ptr::eq(self.route.actor.deref(), other.route.actor.deref())
@@ -346,6 +411,25 @@ impl Default for RouteState {
     }
 }
 
+impl Clone for RouteState {
+    fn clone(&self) -> Self {
+        let mut index = take_pooled(&ROUTE_STATE_POOL);
+        index.clone_from(&self.index);
+
+        RouteState { index }
+    }
+}
+
+impl Drop for RouteState {
+    fn drop(&mut self) {
+        if self.index.capacity() > 0 {
+            let mut index = std::mem::take(&mut self.index);
+            index.clear();
+            release_pooled(&ROUTE_STATE_POOL, index);
+        }
+    }
+}
+
 impl RouteState {
     /// Gets a value associated with the tour using `K` type as a key.
     pub fn get_tour_state<K: 'static, V: Send + Sync + 'static>(&self) -> Option<&V> {
@@ -55,11 +55,19 @@ pub fn eval_job_insertion_in_route(
 
     let goal = &insertion_ctx.problem.goal;
 
-    if let Some(violation) = goal.evaluate(&MoveContext::route(&insertion_ctx.solution, route_ctx, eval_ctx.job)) {
+    // NOTE skip route early if its actor cannot serve the job at all
+    let violation_code = goal
+        .check_compatibility(&insertion_ctx.problem, route_ctx.route().actor.as_ref(), eval_ctx.job)
+        .or_else(|| {
+            goal.evaluate(&MoveContext::route(&insertion_ctx.solution, route_ctx, eval_ctx.job))
+                .map(|violation| violation.code)
+        });
+
+    if let Some(code) = violation_code {
         return eval_ctx.result_selector.select_insertion(
             insertion_ctx,
             alternative,
-            InsertionResult::make_failure_with_code(violation.code, true, Some(eval_ctx.job.clone())),
+            InsertionResult::make_failure_with_code(code, true, Some(eval_ctx.job.clone())),
         );
     }
 
@@ -5,10 +5,11 @@ mod insertions_test;
 use crate::construction::heuristics::*;
 use crate::models::ViolationCode;
 use crate::models::common::Cost;
-use crate::models::problem::{Actor, Job, JobIdDimension};
+use crate::models::problem::{Actor, Job, JobIdDimension, sort_jobs_canonically};
 use crate::models::solution::Activity;
 use lazy_static::lazy_static;
 use rosomaxa::prelude::*;
+use rosomaxa::utils::is_deterministic_mode;
 use std::borrow::Borrow;
 use std::cmp::Ordering;
 use std::fmt::{Debug, Formatter};
@@ -363,6 +364,12 @@ impl TryFrom<InsertionResult> for InsertionSuccess {
 
 pub(crate) fn prepare_insertion_ctx(insertion_ctx: &mut InsertionContext) {
     insertion_ctx.solution.required.extend(insertion_ctx.solution.unassigned.keys().cloned());
+
+    if is_deterministic_mode() {
+        // NOTE required jobs are often collected from hash based collections which iteration order
+        // depends on job addresses, so use a canonical order instead
+        sort_jobs_canonically(&mut insertion_ctx.solution.required);
+    }
     insertion_ctx.problem.goal.accept_solution_state(&mut insertion_ctx.solution);
 }
 
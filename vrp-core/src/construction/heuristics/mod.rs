@@ -3,6 +3,9 @@
 //! # Design
 //!
 
+mod compatibility;
+pub use self::compatibility::*;
+
 mod context;
 pub use self::context::*;
 
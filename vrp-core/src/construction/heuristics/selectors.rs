@@ -81,6 +81,7 @@ pub trait InsertionEvaluator: Send + Sync {
 /// Evaluates job insertion in routes at given position.
 pub struct PositionInsertionEvaluator {
     insertion_position: InsertionPosition,
+    is_route_parallel: bool,
 }
 
 impl Default for PositionInsertionEvaluator {
@@ -92,7 +93,45 @@ impl Default for PositionInsertionEvaluator {
 impl PositionInsertionEvaluator {
     /// Creates a new instance of `PositionInsertionEvaluator`.
     pub fn new(insertion_position: InsertionPosition) -> Self {
-        Self { insertion_position }
+        Self { insertion_position, is_route_parallel: false }
+    }
+
+    /// Creates a new instance of `PositionInsertionEvaluator` which evaluates insertion of a single job
+    /// into different routes in parallel. Results are selected in the order of routes, so the outcome does
+    /// not depend on thread scheduling. When repeatable random is used, routes are evaluated sequentially
+    /// as its state is kept per thread.
+    pub fn new_route_parallel(insertion_position: InsertionPosition) -> Self {
+        Self { insertion_position, is_route_parallel: true }
+    }
+
+    /// Evaluates insertion of the job into given routes starting from the given accumulated result.
+    fn evaluate_job(
+        &self,
+        insertion_ctx: &InsertionContext,
+        eval_ctx: &EvaluationContext,
+        routes: &[&RouteContext],
+        acc: InsertionResult,
+    ) -> InsertionResult {
+        let is_parallel =
+            self.is_route_parallel && routes.len() > 1 && !insertion_ctx.environment.random.is_repeatable();
+
+        if is_parallel {
+            parallel_collect(routes, |route_ctx| {
+                eval_job_insertion_in_route(
+                    insertion_ctx,
+                    eval_ctx,
+                    route_ctx,
+                    self.insertion_position,
+                    InsertionResult::make_failure(),
+                )
+            })
+            .into_iter()
+            .fold(acc, |acc, result| eval_ctx.result_selector.select_insertion(insertion_ctx, acc, result))
+        } else {
+            routes.iter().fold(acc, |acc, route_ctx| {
+                eval_job_insertion_in_route(insertion_ctx, eval_ctx, route_ctx, self.insertion_position, acc)
+            })
+        }
     }
 
     /// Evaluates all jobs ad routes.
@@ -112,9 +151,7 @@ impl PositionInsertionEvaluator {
         if is_fold_jobs {
             parallel_collect(jobs, |job| {
                 let eval_ctx = EvaluationContext { goal, job, leg_selection, result_selector };
-                routes.iter().fold(InsertionResult::make_failure(), |acc, route_ctx| {
-                    eval_job_insertion_in_route(insertion_ctx, &eval_ctx, route_ctx, self.insertion_position, acc)
-                })
+                self.evaluate_job(insertion_ctx, &eval_ctx, routes, InsertionResult::make_failure())
             })
         } else {
             parallel_collect(routes, |route_ctx| {
@@ -138,6 +175,13 @@ impl InsertionEvaluator for PositionInsertionEvaluator {
     ) -> InsertionResult {
         let goal = &insertion_ctx.problem.goal;
 
+        if self.is_route_parallel {
+            return jobs.iter().fold(InsertionResult::make_failure(), |acc, job| {
+                let eval_ctx = EvaluationContext { goal, job, leg_selection, result_selector };
+                self.evaluate_job(insertion_ctx, &eval_ctx, routes, acc)
+            });
+        }
+
         fold_reduce(
             cartesian_product(routes, jobs),
             InsertionResult::make_failure,
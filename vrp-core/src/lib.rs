@@ -116,5 +116,6 @@ pub mod construction;
 pub mod models;
 pub mod solver;
 pub mod utils;
+pub mod validation;
 
 pub use rosomaxa;
@@ -5,6 +5,7 @@ use crate::models::solution::{Registry, Route};
 use crate::models::*;
 use rosomaxa::evolution::TelemetryMetrics;
 use rosomaxa::prelude::*;
+use rosomaxa::utils::Timer;
 use std::fmt::{Debug, Formatter};
 use std::sync::Arc;
 
@@ -43,6 +44,43 @@ impl Debug for Problem {
     }
 }
 
+impl Problem {
+    /// Creates a new problem which has given jobs in addition to the original ones. Fleet, locks,
+    /// goal and costs are shared with the original problem, so a solution of the original problem
+    /// stays valid for the new one. Please note that goal features created from the original job
+    /// list (e.g. job priority tiers) are not adjusted.
+    pub fn with_added_jobs(&self, jobs: Vec<Job>, logger: &InfoLogger) -> GenericResult<Problem> {
+        if jobs.is_empty() {
+            return Err("no jobs to add".into());
+        }
+
+        if jobs.iter().any(|job| self.jobs.all().contains(job)) {
+            return Err("some of the jobs are already present in the problem".into());
+        }
+
+        // NOTE update the job index incrementally instead of creating it from scratch
+        let jobs = Timer::measure_duration_with_callback(
+            || {
+                jobs.into_iter().try_fold(self.jobs.as_ref().clone(), |mut acc, job| {
+                    acc.insert(self.fleet.as_ref(), self.transport.as_ref(), job)?;
+                    Ok::<_, GenericError>(acc)
+                })
+            },
+            |duration| (logger)(format!("job index updated in {}ms", duration.as_millis()).as_str()),
+        )?;
+
+        Ok(Problem {
+            fleet: self.fleet.clone(),
+            jobs: Arc::new(jobs),
+            locks: self.locks.clone(),
+            goal: self.goal.clone(),
+            activity: self.activity.clone(),
+            transport: self.transport.clone(),
+            extras: self.extras.clone(),
+        })
+    }
+}
+
 /// Represents a VRP solution.
 pub struct Solution {
     /// A total solution cost.
@@ -133,7 +171,11 @@ pub struct ProblemBuilder {
     goal: Option<Arc<GoalContext>>,
     activity: Option<Arc<dyn ActivityCost>>,
     transport: Option<Arc<dyn TransportCost>>,
+    matrices: Option<(Vec<MatrixData>, MatrixPrecision)>,
     extras: Option<Arc<Extras>>,
+    job_index_mode: JobIndexMode,
+    job_clustering_mode: JobClusteringMode,
+    job_index_cache: Option<Arc<dyn JobIndexCache>>,
     logger: Option<InfoLogger>,
 }
 
@@ -190,6 +232,14 @@ impl ProblemBuilder {
         self
     }
 
+    /// Adds routing matrices which are used to create transport costs with given precision of internal
+    /// storage. [MatrixPrecision::Single] can be used to reduce memory consumption on large problems.
+    /// An alternative to `with_transport_cost` method which takes precedence if both are used.
+    pub fn with_transport_matrices(mut self, matrices: Vec<MatrixData>, precision: MatrixPrecision) -> Self {
+        self.matrices = Some((matrices, precision));
+        self
+    }
+
     /// Adds an activity service time estimation logic.
     /// An optional field: [SimpleActivityCost] will be used by default.
     pub fn with_activity_cost(mut self, activity: Arc<dyn ActivityCost>) -> Self {
@@ -205,6 +255,30 @@ impl ProblemBuilder {
         self
     }
 
+    /// Sets a mode of job neighbourhood index creation. [JobIndexMode::Approximate] can be used
+    /// to reduce startup time on problems with many jobs.
+    /// An optional field: [JobIndexMode::Exact] is used by default.
+    pub fn with_job_index_mode(mut self, job_index_mode: JobIndexMode) -> Self {
+        self.job_index_mode = job_index_mode;
+        self
+    }
+
+    /// Sets a mode of job clustering. Use it to tune DBSCAN parameters or to choose an alternative
+    /// algorithm when default parameters produce poor clusters, e.g. on sparse rural instances.
+    /// An optional field: [JobClusteringMode::Dbscan] with estimated epsilon is used by default.
+    pub fn with_job_clustering_mode(mut self, job_clustering_mode: JobClusteringMode) -> Self {
+        self.job_clustering_mode = job_clustering_mode;
+        self
+    }
+
+    /// Sets a cache of job neighbourhood index, so repeated builds of the same problem can skip
+    /// index creation.
+    /// An optional field: the index is always created when omitted.
+    pub fn with_job_index_cache(mut self, job_index_cache: Arc<dyn JobIndexCache>) -> Self {
+        self.job_index_cache = Some(job_index_cache);
+        self
+    }
+
     /// Adds a logger to the problem definition.
     pub fn with_logger(mut self, logger: InfoLogger) -> Self {
         self.logger = Some(logger);
@@ -213,24 +287,26 @@ impl ProblemBuilder {
 
     /// Builds a problem definition.
     /// Returns [Err] in case of an invalid configuration.
-    pub fn build(mut self) -> GenericResult<Problem> {
+    pub fn build(mut self) -> Result<Problem, ProblemBuildError> {
         if self.jobs.is_empty() {
-            return Err("empty list of jobs: specify at least one job".into());
+            return Err(ProblemBuildError::NoJobs);
         }
 
         if self.vehicles.is_empty() {
-            return Err("empty list of vehicles: specify at least one vehicle".into());
+            return Err(ProblemBuildError::NoVehicles);
         }
 
         // analyze user input
-        let transport = self.transport.take().ok_or_else(|| {
-            GenericError::from("no information about routing data: use 'with_transport_cost' method to specify it")
-        })?;
+        let transport = match (self.transport.take(), self.matrices.take()) {
+            (Some(transport), _) => transport,
+            (None, Some((matrices, precision))) => {
+                create_matrix_transport_cost_with_precision(matrices, NoFallback, precision)
+                    .map_err(ProblemBuildError::InvalidRoutingData)?
+            }
+            (None, None) => return Err(ProblemBuildError::NoRoutingData),
+        };
         let activity = self.activity.take().unwrap_or_else(|| Arc::new(SimpleActivityCost::default()));
-        let goal = self
-            .goal
-            .take()
-            .ok_or_else(|| GenericError::from("unknown goal of optimization: use 'with_goal' method to set it"))?;
+        let goal = self.goal.take().ok_or(ProblemBuildError::NoGoal)?;
         let extras = self.extras.take().unwrap_or_else(|| Arc::new(Extras::default()));
 
         // setup fleet
@@ -243,13 +319,44 @@ impl ProblemBuilder {
         let logger = self.logger.unwrap_or_else(|| Arc::new(|msg| println!("{msg}")));
 
         // setup jobs
-        let jobs = Arc::new(Jobs::new(fleet.as_ref(), self.jobs, transport.as_ref(), &logger)?);
+        let jobs = Arc::new(match self.job_index_cache.take() {
+            Some(cache) => Jobs::new_with_cache(
+                fleet.as_ref(),
+                self.jobs,
+                transport.as_ref(),
+                self.job_index_mode,
+                self.job_clustering_mode,
+                cache.as_ref(),
+                &logger,
+            )
+            .map_err(ProblemBuildError::InvalidJobs)?,
+            None => Jobs::new_with_modes(
+                fleet.as_ref(),
+                self.jobs,
+                transport.as_ref(),
+                self.job_index_mode,
+                self.job_clustering_mode,
+                &logger,
+            )
+            .map_err(ProblemBuildError::InvalidJobs)?,
+        });
 
         Ok(Problem { fleet, jobs, locks: vec![], goal, activity, transport, extras })
     }
 }
 
 impl Solution {
+    /// Creates a deep copy of the solution. Telemetry metrics are not copied.
+    pub fn deep_copy(&self) -> Self {
+        Self {
+            cost: self.cost,
+            registry: self.registry.deep_copy(),
+            routes: self.routes.iter().map(|route| route.deep_copy()).collect(),
+            unassigned: self.unassigned.clone(),
+            telemetry: None,
+        }
+    }
+
     /// Iterates through all tours and returns locations of each activity in the order they are visited.
     pub fn get_locations(&self) -> impl Iterator<Item = impl Iterator<Item = Location> + '_> + '_ {
         self.routes.iter().map(|route| route.tour.all_activities().map(|activity| activity.place.location))
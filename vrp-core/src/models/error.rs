@@ -0,0 +1,69 @@
+#[cfg(test)]
+#[path = "../../tests/unit/models/error_test.rs"]
+mod error_test;
+
+use rosomaxa::prelude::GenericError;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// Specifies an error returned when problem definition cannot be built.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ProblemBuildError {
+    /// No jobs are specified.
+    NoJobs,
+    /// No vehicles are specified.
+    NoVehicles,
+    /// Neither transport costs nor routing matrices are specified.
+    NoRoutingData,
+    /// Transport costs cannot be created from routing matrices.
+    InvalidRoutingData(GenericError),
+    /// No goal of optimization is specified.
+    NoGoal,
+    /// Jobs cannot be indexed, e.g. because of invalid routing data.
+    InvalidJobs(GenericError),
+}
+
+impl ProblemBuildError {
+    /// Returns an error code which is stable across releases.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::NoJobs => "E2000",
+            Self::NoVehicles => "E2001",
+            Self::NoRoutingData => "E2002",
+            Self::InvalidRoutingData(_) => "E2003",
+            Self::NoGoal => "E2004",
+            Self::InvalidJobs(_) => "E2005",
+        }
+    }
+}
+
+impl Display for ProblemBuildError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoJobs => write!(f, "empty list of jobs: specify at least one job"),
+            Self::NoVehicles => write!(f, "empty list of vehicles: specify at least one vehicle"),
+            Self::NoRoutingData => write!(
+                f,
+                "no information about routing data: use 'with_transport_cost' or 'with_transport_matrices' \
+                 method to specify it"
+            ),
+            Self::NoGoal => write!(f, "unknown goal of optimization: use 'with_goal' method to set it"),
+            Self::InvalidRoutingData(err) | Self::InvalidJobs(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl Error for ProblemBuildError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::InvalidRoutingData(err) | Self::InvalidJobs(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<ProblemBuildError> for GenericError {
+    fn from(value: ProblemBuildError) -> Self {
+        value.to_string().into()
+    }
+}
@@ -95,6 +95,7 @@ pub fn build_example_problem() -> GenericResult<Arc<Problem>> {
         .with_goal(goal)
         .build()
         .map(Arc::new)
+        .map_err(From::from)
 }
 
 /// Creates an example problem used in documentation tests.
@@ -3,18 +3,20 @@
 mod goal_test;
 
 use crate::construction::enablers::*;
-use crate::construction::features::create_known_edge_feature;
+use crate::construction::features::*;
 use crate::construction::heuristics::*;
+use crate::models::Problem;
 use crate::models::common::Cost;
-use crate::models::problem::Job;
+use crate::models::problem::{ActivityCost, Actor, Job, TransportCost};
 use rosomaxa::population::Alternative;
 use rosomaxa::prelude::*;
+use std::any::Any;
 use std::cmp::Ordering;
 use std::collections::HashSet;
 use std::fmt::{Debug, Display, Formatter};
 use std::iter::once;
 use std::ops::ControlFlow;
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 
 /// Defines Vehicle Routing Problem variant by global and local objectives:
 /// A **global objective** defines the way two VRP solutions are compared to select better one:
@@ -35,15 +37,22 @@ pub struct GoalContext {
     alternative_goals: Vec<Goal>,
     constraints: Vec<Arc<dyn FeatureConstraint>>,
     states: Vec<Arc<dyn FeatureState>>,
+    compatibility: Arc<OnceLock<JobCompatibilityIndex>>,
 }
 
 impl GoalContext {
     /// Creates a new instance of `GoalContext` with given feature constraints.
+    /// NOTE: job-actor compatibility index is not used by the new instance as given constraints
+    /// are typically a temporary modification of the original ones.
     pub fn with_constraints<Iter>(&self, constraints: Iter) -> Self
     where
         Iter: Iterator<Item = Arc<dyn FeatureConstraint>>,
     {
-        GoalContext { constraints: constraints.collect(), ..self.clone() }
+        GoalContext {
+            constraints: constraints.collect(),
+            compatibility: Arc::new(OnceLock::from(JobCompatibilityIndex::default())),
+            ..self.clone()
+        }
     }
 
     /// Returns an iterator over internal feature constraints.
@@ -110,7 +119,7 @@ impl GoalContextBuilder {
         let states = self.features.iter().filter_map(|feature| feature.state.clone()).collect();
         let constraints = self.features.iter().filter_map(|feature| feature.constraint.clone()).collect();
 
-        Ok(GoalContext { goal, alternative_goals, constraints, states })
+        Ok(GoalContext { goal, alternative_goals, constraints, states, compatibility: Default::default() })
     }
 
     fn get_heuristic_goal(features: &[Feature]) -> GenericResult<Goal> {
@@ -221,14 +230,7 @@ impl GoalBuilder {
     pub fn add_single(mut self, objective: Arc<dyn FeatureObjective>) -> Self {
         // NOTE: indices are controlled internally
         self.layers.push((
-            Arc::new(|objectives, a, b| {
-                let fitness_a = objectives[0].fitness(a);
-                let fitness_b = objectives[0].fitness(b);
-
-                // NOTE total_cmp distinguishes between positive zero and negative zero while
-                // logically they are the same in this context
-                if fitness_a == 0. && fitness_b == 0. { Ordering::Equal } else { fitness_a.total_cmp(&fitness_b) }
-            }),
+            Arc::new(|objectives, a, b| compare_fitness(objectives[0].fitness(a), objectives[0].fitness(b))),
             Arc::new(|objectives, move_ctx| objectives[0].estimate(move_ctx)),
             vec![objective],
         ));
@@ -251,6 +253,43 @@ impl GoalBuilder {
         self
     }
 
+    /// Add a layer which scalarizes multiple objective functions into a single value using given
+    /// weights: solutions are compared by weighted sum of their fitness values, so a worse value of
+    /// one objective can be traded for a better value of another one.
+    pub fn add_weighted(self, objectives: &[Arc<dyn FeatureObjective>], weights: &[Float]) -> GenericResult<Self> {
+        if objectives.is_empty() || objectives.len() != weights.len() {
+            return Err(format!(
+                "weighted layer requires same non-zero amount of weights as objective count: {} vs {}",
+                weights.len(),
+                objectives.len()
+            )
+            .into());
+        }
+
+        if weights.iter().any(|weight| !weight.is_finite() || *weight < 0.) {
+            return Err(GenericError::from("weights of weighted layer should be finite and non-negative"));
+        }
+
+        let weights: Arc<[Float]> = weights.into();
+
+        Ok(self.add_multi(
+            objectives,
+            {
+                let weights = weights.clone();
+                move |objectives, a, b| {
+                    let weighted_fitness = |solution: &InsertionContext| -> Float {
+                        objectives.iter().zip(weights.iter()).map(|(o, weight)| o.fitness(solution) * weight).sum()
+                    };
+
+                    compare_fitness(weighted_fitness(a), weighted_fitness(b))
+                }
+            },
+            move |objectives, move_ctx| {
+                objectives.iter().zip(weights.iter()).map(|(o, weight)| o.estimate(move_ctx) * weight).sum()
+            },
+        ))
+    }
+
     /// Builds a [Goal] of optimization using features provided.
     pub fn build(self) -> GenericResult<Goal> {
         if self.layers.is_empty() {
@@ -261,6 +300,174 @@ impl GoalBuilder {
     }
 }
 
+/// Provides a declarative way to define a goal of optimization for library users who embed the core
+/// crate directly, e.g.:
+///
+/// `DeclarativeGoalBuilder::minimize_unassigned().then_minimize_tours().then_minimize_cost(transport, activity)`
+///
+/// Objectives are added in lexicographical order: each `minimize_*`/`then_*` call starts a new layer,
+/// `with_soft` merges an objective into the last layer (their fitness values are summed), and `with_hard`
+/// adds a feature which has no objective (constraint and/or state only). Feature names and states are
+/// checked for conflicts when the goal context is built.
+#[derive(Default)]
+pub struct DeclarativeGoalBuilder {
+    layers: Vec<Vec<GenericResult<Feature>>>,
+    hard_features: Vec<GenericResult<Feature>>,
+}
+
+impl DeclarativeGoalBuilder {
+    /// A name of the feature used by `minimize_cost` and `then_minimize_cost`.
+    pub const MIN_COST_NAME: &'static str = "min_cost";
+    /// A name of the feature used by `minimize_unassigned` and `then_minimize_unassigned`.
+    pub const MIN_UNASSIGNED_NAME: &'static str = "min_unassigned";
+    /// A name of the feature used by `minimize_tours` and `then_minimize_tours`.
+    pub const MIN_TOURS_NAME: &'static str = "min_tours";
+
+    /// Starts a goal with minimization of total transport and activity costs.
+    /// Time windows are considered as hard constraint.
+    pub fn minimize_cost(transport: Arc<dyn TransportCost>, activity: Arc<dyn ActivityCost>) -> Self {
+        Self::default().then_minimize_cost(transport, activity)
+    }
+
+    /// Starts a goal with minimization of unassigned jobs.
+    pub fn minimize_unassigned() -> Self {
+        Self::default().then_minimize_unassigned()
+    }
+
+    /// Starts a goal with minimization of used tours.
+    pub fn minimize_tours() -> Self {
+        Self::default().then_minimize_tours()
+    }
+
+    /// Starts a goal with a custom feature which must have an objective.
+    pub fn minimize(feature: Feature) -> Self {
+        Self::default().then(feature)
+    }
+
+    /// Adds a layer with minimization of total transport and activity costs.
+    pub fn then_minimize_cost(self, transport: Arc<dyn TransportCost>, activity: Arc<dyn ActivityCost>) -> Self {
+        self.add_layer(
+            TransportFeatureBuilder::new(Self::MIN_COST_NAME)
+                .set_transport_cost(transport)
+                .set_activity_cost(activity)
+                .build_minimize_cost(),
+        )
+    }
+
+    /// Adds a layer with minimization of unassigned jobs.
+    pub fn then_minimize_unassigned(self) -> Self {
+        self.add_layer(MinimizeUnassignedBuilder::new(Self::MIN_UNASSIGNED_NAME).build())
+    }
+
+    /// Adds a layer with minimization of used tours.
+    pub fn then_minimize_tours(self) -> Self {
+        self.add_layer(create_minimize_tours_feature(Self::MIN_TOURS_NAME))
+    }
+
+    /// Adds a layer with a custom feature which must have an objective.
+    pub fn then(self, feature: Feature) -> Self {
+        self.add_layer(Ok(feature))
+    }
+
+    /// Adds a soft constraint to the last layer: its objective is combined with other objectives
+    /// of the layer by summing their fitness values and estimates.
+    pub fn with_soft(mut self, feature: Feature) -> Self {
+        match self.layers.last_mut() {
+            Some(layer) => layer.push(Ok(feature)),
+            None => self.layers.push(vec![Err(GenericError::from(format!(
+                "soft constraint '{}' should follow an objective",
+                feature.name
+            )))]),
+        }
+        self
+    }
+
+    /// Adds a hard constraint: a feature without objective which doesn't affect the goal.
+    pub fn with_hard(mut self, feature: Feature) -> Self {
+        self.hard_features.push(Ok(feature));
+        self
+    }
+
+    /// Builds a goal context validating features.
+    pub fn build(self) -> GenericResult<GoalContext> {
+        let layers = self
+            .layers
+            .into_iter()
+            .map(|layer| layer.into_iter().collect::<GenericResult<Vec<_>>>())
+            .collect::<GenericResult<Vec<_>>>()?;
+        let hard_features = self.hard_features.into_iter().collect::<GenericResult<Vec<_>>>()?;
+
+        if layers.is_empty() {
+            return Err(GenericError::from("no objectives specified in the goal"));
+        }
+
+        if let Some(feature) = layers.iter().flatten().find(|feature| feature.objective.is_none()) {
+            return Err(format!("feature '{}' has no objective", feature.name).into());
+        }
+
+        if let Some(feature) = hard_features.iter().find(|feature| feature.objective.is_some()) {
+            return Err(
+                format!("feature '{}' has objective, use it as objective or soft constraint", feature.name).into()
+            );
+        }
+
+        let features = layers.iter().flatten().chain(hard_features.iter()).cloned().collect::<Vec<_>>();
+        Self::validate_states(&features)?;
+
+        let goal = layers
+            .iter()
+            .fold(GoalBuilder::default(), |builder, layer| {
+                let objectives = layer.iter().filter_map(|feature| feature.objective.clone()).collect::<Vec<_>>();
+                match objectives.as_slice() {
+                    [objective] => builder.add_single(objective.clone()),
+                    _ => builder.add_multi(
+                        objectives.as_slice(),
+                        |objectives, a, b| {
+                            let fitness_a = objectives.iter().map(|objective| objective.fitness(a)).sum::<Float>();
+                            let fitness_b = objectives.iter().map(|objective| objective.fitness(b)).sum::<Float>();
+
+                            compare_fitness(fitness_a, fitness_b)
+                        },
+                        |objectives, move_ctx| objectives.iter().map(|objective| objective.estimate(move_ctx)).sum(),
+                    ),
+                }
+            })
+            .build()?;
+
+        GoalContextBuilder::with_features(&features)?.set_main_goal(goal).build()
+    }
+
+    fn add_layer(mut self, feature: GenericResult<Feature>) -> Self {
+        self.layers.push(vec![feature]);
+        self
+    }
+
+    fn validate_states(features: &[Feature]) -> GenericResult<()> {
+        let with_states = features
+            .iter()
+            .filter_map(|feature| feature.state.as_ref().map(|state| (feature.name.as_str(), state)))
+            .collect::<Vec<_>>();
+
+        // NOTE state keys are defined per state type, so two states of the same type write the same keys
+        let get_type_id = |state: &Arc<dyn FeatureState>| (state.as_ref() as &dyn Any).type_id();
+
+        with_states.iter().enumerate().try_for_each(|(idx, (name, state))| {
+            match with_states.iter().skip(idx + 1).find(|(_, other)| get_type_id(state) == get_type_id(other)) {
+                Some((other_name, _)) => {
+                    Err(format!("features '{name}' and '{other_name}' have the same state type").into())
+                }
+                None => Ok(()),
+            }
+        })
+    }
+}
+
+fn compare_fitness(fitness_a: Float, fitness_b: Float) -> Ordering {
+    // NOTE total_cmp distinguishes between positive zero and negative zero while
+    // logically they are the same in this context
+    if fitness_a == 0. && fitness_b == 0. { Ordering::Equal } else { fitness_a.total_cmp(&fitness_b) }
+}
+
 /// An individual feature which is used to build a specific VRP variant, e.g., capacity restriction,
 /// job values, etc. Each feature consists of three optional parts (but at least one should be defined):
 ///
@@ -400,7 +607,7 @@ impl FeatureBuilder {
 }
 
 /// Provides the way to modify solution state when the search is performed.
-pub trait FeatureState: Send + Sync {
+pub trait FeatureState: Any + Send + Sync {
     /// Notifies a state that given routes (indices) and jobs cannot be assigned due to constraint violations.
     /// This method can be used to modify solution context to help resolve some limitations imposed by
     /// constraints and, generally, can modify solution context.
@@ -443,6 +650,14 @@ pub trait FeatureConstraint: Send + Sync {
     fn merge(&self, _source: Job, _candidate: Job) -> Result<Job, ViolationCode> {
         Err(ViolationCode::default())
     }
+
+    /// Checks whether the job can never be assigned to the actor regardless of route and solution
+    /// state, e.g. due to missing skills. Returns a violation code if so. It is used to precompute
+    /// job-actor compatibility index which allows to skip evaluation of such routes early.
+    /// Default implementation returns [None].
+    fn check_compatibility(&self, _actor: &Actor, _job: &Job) -> Option<ViolationCode> {
+        None
+    }
 }
 
 /// Defines feature's objective function behavior.
@@ -517,6 +732,15 @@ impl GoalContext {
         merge_with_constraints(&self.constraints, source, candidate)
     }
 
+    /// Checks whether the job can never be assigned to the actor using job-actor compatibility index.
+    /// The index is built on the first call using the fleet and jobs of the given problem. Returns a
+    /// violation code of the constraint which rejects the pair.
+    pub fn check_compatibility(&self, problem: &Problem, actor: &Actor, job: &Job) -> Option<ViolationCode> {
+        self.compatibility
+            .get_or_init(|| JobCompatibilityIndex::new(&self.constraints, problem.fleet.as_ref(), problem.jobs.all()))
+            .get_violation(actor, job)
+    }
+
     /// Evaluates feasibility of the refinement move.
     pub fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
         evaluate_with_constraints(&self.constraints, move_ctx)
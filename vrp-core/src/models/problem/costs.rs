@@ -3,6 +3,7 @@
 mod costs_test;
 
 use crate::models::common::*;
+use crate::models::problem::index_cache::StableHasher;
 use crate::models::solution::{Activity, Route};
 use rosomaxa::prelude::{Float, GenericError, GenericResult};
 use rosomaxa::utils::CollectGroupBy;
@@ -104,6 +105,26 @@ pub trait TransportCost: Send + Sync {
 
     /// Returns size of known locations
     fn size(&self) -> usize;
+
+    /// Returns locations with known routing data from given location if routing data is sparse.
+    /// `None` means that routing data is available for all location pairs.
+    fn sparse_neighbours(&self, _profile: &Profile, _from: Location) -> Option<Vec<Location>> {
+        None
+    }
+
+    /// Returns a key of routing data used by given profile: profiles with the same key are expected
+    /// to have identical routing data, so the data derived from it can be shared between them.
+    /// `None` means that routing data of the profile is not known to be shared.
+    fn matrix_key(&self, _profile: &Profile) -> Option<usize> {
+        None
+    }
+
+    /// Returns a stable fingerprint of routing data used by given profile, so the data derived from it
+    /// can be reused between runs with the same routing data.
+    /// `None` means that routing data cannot be fingerprinted.
+    fn fingerprint(&self, _profile: &Profile) -> Option<u64> {
+        None
+    }
 }
 
 /// A simple implementation of transport costs around a single matrix.
@@ -147,6 +168,16 @@ impl TransportCost for SimpleTransportCost {
     fn size(&self) -> usize {
         self.size
     }
+
+    fn fingerprint(&self, _: &Profile) -> Option<u64> {
+        let mut hasher = StableHasher::default();
+        [&self.durations, &self.distances].into_iter().for_each(|values| {
+            hasher.write_usize(values.len());
+            values.iter().for_each(|&value| hasher.write_float(value));
+        });
+
+        Some(hasher.finish())
+    }
 }
 
 /// Contains matrix routing data for specific profile and, optionally, time.
@@ -168,6 +199,135 @@ impl MatrixData {
     }
 }
 
+/// Provides access to matrix values kept in external storage, e.g. in a memory mapped file, so they are
+/// used without copying.
+pub trait MatrixValuesSource: Send + Sync {
+    /// Returns matrix values in row-major order.
+    fn values(&self) -> &[f32];
+}
+
+impl MatrixValuesSource for Vec<f32> {
+    fn values(&self) -> &[f32] {
+        self.as_slice()
+    }
+}
+
+/// Contains matrix routing data for specific profile and, optionally, time which values are kept in
+/// external storage.
+pub struct SharedMatrixData {
+    /// A routing profile index.
+    pub index: usize,
+    /// A timestamp for which routing info is applicable.
+    pub timestamp: Option<Timestamp>,
+    /// Travel durations.
+    pub durations: Arc<dyn MatrixValuesSource>,
+    /// Travel distances.
+    pub distances: Arc<dyn MatrixValuesSource>,
+}
+
+impl SharedMatrixData {
+    /// Creates `SharedMatrixData` instance.
+    pub fn new(
+        index: usize,
+        timestamp: Option<Timestamp>,
+        durations: Arc<dyn MatrixValuesSource>,
+        distances: Arc<dyn MatrixValuesSource>,
+    ) -> Self {
+        Self { index, timestamp, durations, distances }
+    }
+}
+
+/// Specifies a precision used to store routing matrices internally.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum MatrixPrecision {
+    /// Matrix values are stored as they are.
+    #[default]
+    Double,
+    /// Matrix values are stored as `f32` which halves memory consumption for large matrices.
+    /// Values are converted back at use sites, so large values might lose precision.
+    Single,
+}
+
+/// Keeps matrix values using specific precision.
+enum MatrixValues {
+    Double(Vec<Float>),
+    Single(Vec<f32>),
+    Shared(Arc<dyn MatrixValuesSource>),
+}
+
+impl MatrixValues {
+    fn new(values: Vec<Float>, precision: MatrixPrecision) -> Self {
+        match precision {
+            MatrixPrecision::Double => Self::Double(values),
+            MatrixPrecision::Single => Self::Single(values.into_iter().map(|value| value as f32).collect()),
+        }
+    }
+
+    fn get(&self, idx: usize) -> Option<Float> {
+        match self {
+            Self::Double(values) => values.get(idx).copied(),
+            Self::Single(values) => values.get(idx).map(|&value| value as Float),
+            Self::Shared(source) => source.values().get(idx).map(|&value| value as Float),
+        }
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            Self::Double(values) => values.len(),
+            Self::Single(values) => values.len(),
+            Self::Shared(source) => source.values().len(),
+        }
+    }
+
+    fn hash(&self, hasher: &mut StableHasher) {
+        hasher.write_usize(self.len());
+        match self {
+            Self::Double(values) => values.iter().for_each(|&value| hasher.write_float(value)),
+            Self::Single(values) => values.iter().for_each(|&value| hasher.write_float(value as Float)),
+            Self::Shared(source) => source.values().iter().for_each(|&value| hasher.write_float(value as Float)),
+        }
+    }
+}
+
+impl PartialEq for MatrixValues {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Double(left), Self::Double(right)) => left == right,
+            (Self::Single(left), Self::Single(right)) => left == right,
+            (Self::Shared(left), Self::Shared(right)) => Arc::ptr_eq(left, right) || left.values() == right.values(),
+            _ => self.len() == other.len() && (0..self.len()).all(|idx| self.get(idx) == other.get(idx)),
+        }
+    }
+}
+
+/// Keeps matrix routing data of one matrix using specific precision.
+struct MatrixStorage {
+    index: usize,
+    timestamp: Option<Timestamp>,
+    durations: MatrixValues,
+    distances: MatrixValues,
+}
+
+impl MatrixStorage {
+    fn new(data: MatrixData, precision: MatrixPrecision) -> Self {
+        Self {
+            index: data.index,
+            timestamp: data.timestamp,
+            durations: MatrixValues::new(data.durations, precision),
+            distances: MatrixValues::new(data.distances, precision),
+        }
+    }
+
+    fn shared(data: SharedMatrixData) -> Self {
+        Self {
+            index: data.index,
+            timestamp: data.timestamp,
+            durations: MatrixValues::Shared(data.durations),
+            distances: MatrixValues::Shared(data.distances),
+        }
+    }
+}
+
 /// A fallback for transport costs if from->to entry is not defined.
 pub trait TransportFallback: Send + Sync {
     /// Returns fallback duration.
@@ -175,10 +335,15 @@ pub trait TransportFallback: Send + Sync {
 
     /// Returns fallback distance.
     fn distance(&self, profile: &Profile, from: Location, to: Location) -> Distance;
+
+    /// Checks whether fallback can be used for all locations in `0..size` range.
+    fn validate(&self, _size: usize) -> GenericResult<()> {
+        Ok(())
+    }
 }
 
-/// A trivial implementation of no fallback for transport cost.
-struct NoFallback;
+/// A trivial implementation of no fallback for transport cost: panics on unknown route.
+pub struct NoFallback;
 
 impl TransportFallback for NoFallback {
     fn duration(&self, profile: &Profile, from: Location, to: Location) -> Duration {
@@ -201,6 +366,35 @@ pub fn create_matrix_transport_cost(costs: Vec<MatrixData>) -> GenericResult<Arc
 pub fn create_matrix_transport_cost_with_fallback<T: TransportFallback + 'static>(
     costs: Vec<MatrixData>,
     fallback: T,
+) -> GenericResult<Arc<dyn TransportCost>> {
+    create_matrix_transport_cost_with_precision(costs, fallback, MatrixPrecision::default())
+}
+
+/// Creates time agnostic or time aware routing costs based on matrix data passed using
+/// a fallback function for unknown route. Matrix values are stored internally using given precision.
+pub fn create_matrix_transport_cost_with_precision<T: TransportFallback + 'static>(
+    costs: Vec<MatrixData>,
+    fallback: T,
+    precision: MatrixPrecision,
+) -> GenericResult<Arc<dyn TransportCost>> {
+    create_matrix_transport_cost_from_storage(
+        costs.into_iter().map(|data| MatrixStorage::new(data, precision)).collect(),
+        fallback,
+    )
+}
+
+/// Creates time agnostic or time aware routing costs based on matrix data kept in external storage using
+/// a fallback function for unknown route. Matrix values are not copied.
+pub fn create_shared_matrix_transport_cost<T: TransportFallback + 'static>(
+    costs: Vec<SharedMatrixData>,
+    fallback: T,
+) -> GenericResult<Arc<dyn TransportCost>> {
+    create_matrix_transport_cost_from_storage(costs.into_iter().map(MatrixStorage::shared).collect(), fallback)
+}
+
+fn create_matrix_transport_cost_from_storage<T: TransportFallback + 'static>(
+    costs: Vec<MatrixStorage>,
+    fallback: T,
 ) -> GenericResult<Arc<dyn TransportCost>> {
     if costs.is_empty() {
         return Err("no matrix data found".into());
@@ -220,6 +414,8 @@ pub fn create_matrix_transport_cost_with_fallback<T: TransportFallback + 'static
         return Err("duration lengths don't match".into());
     }
 
+    fallback.validate(size)?;
+
     Ok(if costs.iter().any(|costs| costs.timestamp.is_some()) {
         Arc::new(TimeAwareMatrixTransportCost::new(costs, size, fallback)?)
     } else {
@@ -227,19 +423,21 @@ pub fn create_matrix_transport_cost_with_fallback<T: TransportFallback + 'static
     })
 }
 
-/// A time agnostic matrix routing costs.
+/// A time agnostic matrix routing costs. Identical matrices of different profiles are stored once.
 struct TimeAgnosticMatrixTransportCost<T: TransportFallback> {
-    durations: Vec<Vec<Duration>>,
-    distances: Vec<Vec<Distance>>,
+    durations: Vec<MatrixValues>,
+    distances: Vec<MatrixValues>,
+    /// Maps profile index to the index of its matrix.
+    matrix_keys: Vec<usize>,
     size: usize,
     fallback: T,
 }
 
 impl<T: TransportFallback> TimeAgnosticMatrixTransportCost<T> {
     /// Creates an instance of `TimeAgnosticMatrixTransportCost`.
-    pub fn new(costs: Vec<MatrixData>, size: usize, fallback: T) -> Result<Self, GenericError> {
+    pub fn new(costs: Vec<MatrixStorage>, size: usize, fallback: T) -> Result<Self, GenericError> {
         let mut costs = costs;
-        costs.sort_by(|a, b| a.index.cmp(&b.index));
+        costs.sort_by_key(|a| a.index);
 
         if costs.iter().any(|costs| costs.timestamp.is_some()) {
             return Err("time aware routing".into());
@@ -249,34 +447,43 @@ impl<T: TransportFallback> TimeAgnosticMatrixTransportCost<T> {
             return Err("duplicate profiles can be passed only for time aware routing".into());
         }
 
-        let (durations, distances) = costs.into_iter().fold((vec![], vec![]), |mut acc, data| {
-            acc.0.push(data.durations);
-            acc.1.push(data.distances);
+        let (durations, distances, matrix_keys) =
+            costs.into_iter().fold((vec![], vec![], vec![]), |(mut durations, mut distances, mut keys), data| {
+                let key = durations
+                    .iter()
+                    .zip(distances.iter())
+                    .position(|(known_durations, known_distances)| {
+                        *known_durations == data.durations && *known_distances == data.distances
+                    })
+                    .unwrap_or_else(|| {
+                        durations.push(data.durations);
+                        distances.push(data.distances);
+                        durations.len() - 1
+                    });
+                keys.push(key);
 
-            acc
-        });
+                (durations, distances, keys)
+            });
 
-        Ok(Self { durations, distances, size, fallback })
+        Ok(Self { durations, distances, matrix_keys, size, fallback })
     }
 }
 
 impl<T: TransportFallback> TransportCost for TimeAgnosticMatrixTransportCost<T> {
     fn duration_approx(&self, profile: &Profile, from: Location, to: Location) -> Duration {
         self.durations
-            .get(profile.index)
+            .get(self.matrix_keys[profile.index])
             .unwrap()
             .get(from * self.size + to)
-            .copied()
             .unwrap_or_else(|| self.fallback.duration(profile, from, to))
             * profile.scale
     }
 
     fn distance_approx(&self, profile: &Profile, from: Location, to: Location) -> Distance {
         self.distances
-            .get(profile.index)
+            .get(self.matrix_keys[profile.index])
             .unwrap()
             .get(from * self.size + to)
-            .copied()
             .unwrap_or_else(|| self.fallback.distance(profile, from, to))
     }
 
@@ -291,18 +498,31 @@ impl<T: TransportFallback> TransportCost for TimeAgnosticMatrixTransportCost<T>
     fn size(&self) -> usize {
         self.size
     }
+
+    fn matrix_key(&self, profile: &Profile) -> Option<usize> {
+        self.matrix_keys.get(profile.index).copied()
+    }
+
+    fn fingerprint(&self, profile: &Profile) -> Option<u64> {
+        let key = *self.matrix_keys.get(profile.index)?;
+        let mut hasher = StableHasher::default();
+        self.durations[key].hash(&mut hasher);
+        self.distances[key].hash(&mut hasher);
+
+        Some(hasher.finish())
+    }
 }
 
-/// A time aware matrix costs.
+/// A time aware matrix costs: durations are linearly interpolated between matrices using actual departure time.
 struct TimeAwareMatrixTransportCost<T: TransportFallback> {
-    costs: HashMap<usize, (Vec<u64>, Vec<MatrixData>)>,
+    costs: HashMap<usize, (Vec<Timestamp>, Vec<MatrixStorage>)>,
     size: usize,
     fallback: T,
 }
 
 impl<T: TransportFallback> TimeAwareMatrixTransportCost<T> {
     /// Creates an instance of `TimeAwareMatrixTransportCost`.
-    fn new(costs: Vec<MatrixData>, size: usize, fallback: T) -> Result<Self, GenericError> {
+    fn new(costs: Vec<MatrixStorage>, size: usize, fallback: T) -> Result<Self, GenericError> {
         if costs.iter().any(|matrix| matrix.timestamp.is_none()) {
             return Err("time-aware routing requires all matrices to have timestamp".into());
         }
@@ -316,12 +536,16 @@ impl<T: TransportFallback> TimeAwareMatrixTransportCost<T> {
         let costs = costs
             .into_iter()
             .map(|(profile, mut matrices)| {
-                matrices.sort_by(|a, b| (a.timestamp.unwrap() as u64).cmp(&(b.timestamp.unwrap() as u64)));
-                let timestamps = matrices.iter().map(|matrix| matrix.timestamp.unwrap() as u64).collect();
+                matrices.sort_by(|a, b| a.timestamp.unwrap().total_cmp(&b.timestamp.unwrap()));
+                let timestamps: Vec<_> = matrices.iter().map(|matrix| matrix.timestamp.unwrap()).collect();
 
-                (profile, (timestamps, matrices))
+                if timestamps.windows(2).any(|pair| pair[0] == pair[1]) {
+                    return Err(GenericError::from("time-aware routing requires unique timestamps per profile"));
+                }
+
+                Ok((profile, (timestamps, matrices)))
             })
-            .collect();
+            .collect::<Result<_, _>>()?;
 
         Ok(Self { costs, size, fallback })
     }
@@ -333,41 +557,11 @@ impl<T: TransportFallback> TimeAwareMatrixTransportCost<T> {
         to: Location,
         travel_time: TravelTime,
     ) -> Duration {
-        let timestamp = match travel_time {
-            TravelTime::Arrival(arrival) => arrival,
-            TravelTime::Departure(departure) => departure,
+        let duration = match travel_time {
+            TravelTime::Departure(departure) => self.get_duration_at(profile, from, to, departure),
+            TravelTime::Arrival(arrival) => self.get_duration_by_arrival(profile, from, to, arrival),
         };
 
-        let (timestamps, matrices) = self.costs.get(&profile.index).unwrap();
-        let data_idx = from * self.size + to;
-
-        let duration = match timestamps.binary_search(&(timestamp as u64)) {
-            Ok(matrix_idx) => matrices.get(matrix_idx).unwrap().durations.get(data_idx).copied(),
-            Err(0) => matrices.first().unwrap().durations.get(data_idx).copied(),
-            Err(matrix_idx) if matrix_idx == matrices.len() => {
-                matrices.last().unwrap().durations.get(data_idx).copied()
-            }
-            Err(matrix_idx) => {
-                let left_matrix = matrices.get(matrix_idx - 1).unwrap();
-                let right_matrix = matrices.get(matrix_idx).unwrap();
-
-                matrices
-                    .get(matrix_idx - 1)
-                    .unwrap()
-                    .durations
-                    .get(data_idx)
-                    .zip(matrices.get(matrix_idx).unwrap().durations.get(data_idx))
-                    .map(|(&left_value, &right_value)| {
-                        // perform linear interpolation
-                        let ratio = (timestamp - left_matrix.timestamp.unwrap())
-                            / (right_matrix.timestamp.unwrap() - left_matrix.timestamp.unwrap());
-
-                        left_value + ratio * (right_value - left_value)
-                    })
-            }
-        }
-        .unwrap_or_else(|| self.fallback.duration(profile, from, to));
-
         duration * profile.scale
     }
 
@@ -386,20 +580,99 @@ impl<T: TransportFallback> TimeAwareMatrixTransportCost<T> {
         let (timestamps, matrices) = self.costs.get(&profile.index).unwrap();
         let data_idx = from * self.size + to;
 
-        match timestamps.binary_search(&(timestamp as u64)) {
-            Ok(matrix_idx) => matrices.get(matrix_idx).unwrap().distances.get(data_idx),
-            Err(0) => matrices.first().unwrap().distances.get(data_idx),
-            Err(matrix_idx) if matrix_idx == matrices.len() => matrices.last().unwrap().distances.get(data_idx),
-            Err(matrix_idx) => matrices.get(matrix_idx - 1).unwrap().distances.get(data_idx),
+        // NOTE distances are not interpolated: the latest matrix which has started is used
+        let matrix_idx = timestamps.partition_point(|&matrix_time| matrix_time <= timestamp).max(1) - 1;
+
+        matrices[matrix_idx].distances.get(data_idx).unwrap_or_else(|| self.fallback.distance(profile, from, to))
+    }
+
+    /// Returns unscaled duration for given departure time.
+    fn get_duration_at(&self, profile: &Profile, from: Location, to: Location, departure: Timestamp) -> Duration {
+        let (timestamps, matrices) = self.costs.get(&profile.index).unwrap();
+        let data_idx = from * self.size + to;
+
+        let duration = match timestamps.partition_point(|&matrix_time| matrix_time <= departure) {
+            0 => matrices.first().unwrap().durations.get(data_idx),
+            matrix_idx if matrix_idx == matrices.len() => matrices.last().unwrap().durations.get(data_idx),
+            matrix_idx => {
+                let (left_time, right_time) = (timestamps[matrix_idx - 1], timestamps[matrix_idx]);
+
+                matrices[matrix_idx - 1].durations.get(data_idx).zip(matrices[matrix_idx].durations.get(data_idx)).map(
+                    |(left_value, right_value)| {
+                        if left_value < 0. || right_value < 0. {
+                            // NOTE keep unreachable marker instead of interpolating it
+                            left_value.min(right_value)
+                        } else {
+                            // perform linear interpolation
+                            let ratio = (departure - left_time) / (right_time - left_time);
+                            left_value + ratio * (right_value - left_value)
+                        }
+                    },
+                )
+            }
+        };
+
+        duration.unwrap_or_else(|| self.fallback.duration(profile, from, to))
+    }
+
+    /// Returns unscaled duration for given arrival time: departure time is found by solving linear equation on
+    /// the interpolation segment which contains it.
+    fn get_duration_by_arrival(&self, profile: &Profile, from: Location, to: Location, arrival: Timestamp) -> Duration {
+        let (timestamps, matrices) = self.costs.get(&profile.index).unwrap();
+        let data_idx = from * self.size + to;
+
+        let Some(values) = matrices.iter().map(|matrix| matrix.durations.get(data_idx)).collect::<Option<Vec<_>>>()
+        else {
+            return self.fallback.duration(profile, from, to);
+        };
+
+        if values.iter().any(|&value| value < 0.) {
+            return -1.;
+        }
+
+        let (last_time, last_value) = (*timestamps.last().unwrap(), *values.last().unwrap());
+        if arrival - last_value >= last_time {
+            return last_value;
         }
-        .copied()
-        .unwrap_or_else(|| self.fallback.distance(profile, from, to))
+
+        (0..timestamps.len() - 1)
+            .rev()
+            .find_map(|idx| {
+                let (left_time, right_time) = (timestamps[idx], timestamps[idx + 1]);
+                let (left_value, right_value) = (values[idx], values[idx + 1]);
+                let slope = (right_value - left_value) / (right_time - left_time);
+
+                // NOTE solve departure + left_value + slope * (departure - left_time) = arrival
+                let departure = (arrival - left_value + slope * left_time) / (1. + slope);
+
+                (1. + slope > 0. && departure >= left_time && departure <= right_time)
+                    .then_some(left_value + slope * (departure - left_time))
+            })
+            .unwrap_or_else(|| *values.first().unwrap())
+    }
+
+    /// Returns time independent duration estimation as a mean value over all matrices.
+    fn get_mean_duration(&self, profile: &Profile, from: Location, to: Location) -> Duration {
+        let (_, matrices) = self.costs.get(&profile.index).unwrap();
+        let data_idx = from * self.size + to;
+
+        let values = matrices.iter().filter_map(|matrix| matrix.durations.get(data_idx)).collect::<Vec<_>>();
+
+        let duration = if values.is_empty() {
+            self.fallback.duration(profile, from, to)
+        } else if values.iter().any(|&value| value < 0.) {
+            -1.
+        } else {
+            values.iter().sum::<Float>() / values.len() as Float
+        };
+
+        duration * profile.scale
     }
 }
 
 impl<T: TransportFallback> TransportCost for TimeAwareMatrixTransportCost<T> {
     fn duration_approx(&self, profile: &Profile, from: Location, to: Location) -> Duration {
-        self.interpolate_duration(profile, from, to, TravelTime::Departure(0.))
+        self.get_mean_duration(profile, from, to)
     }
 
     fn distance_approx(&self, profile: &Profile, from: Location, to: Location) -> Distance {
@@ -417,4 +690,292 @@ impl<T: TransportFallback> TransportCost for TimeAwareMatrixTransportCost<T> {
     fn size(&self) -> usize {
         self.size
     }
+
+    fn fingerprint(&self, profile: &Profile) -> Option<u64> {
+        let (timestamps, matrices) = self.costs.get(&profile.index)?;
+        let mut hasher = StableHasher::default();
+        timestamps.iter().zip(matrices.iter()).for_each(|(&timestamp, matrix)| {
+            hasher.write_float(timestamp);
+            matrix.durations.hash(&mut hasher);
+            matrix.distances.hash(&mut hasher);
+        });
+
+        Some(hasher.finish())
+    }
+}
+
+/// Contains sparse routing data for specific profile: only known from->to entries are kept.
+pub struct SparseMatrixData {
+    /// A routing profile index.
+    pub index: usize,
+    /// Known routing entries as (from, to, duration, distance) tuples.
+    pub entries: Vec<(Location, Location, Duration, Distance)>,
+}
+
+impl SparseMatrixData {
+    /// Creates `SparseMatrixData` instance.
+    pub fn new(index: usize, entries: Vec<(Location, Location, Duration, Distance)>) -> Self {
+        Self { index, entries }
+    }
+}
+
+/// Creates routing costs which keep only `max_neighbours` nearest entries for each location and use
+/// a fallback for missing pairs. Such costs are intended for problems where dense matrix is infeasible.
+pub fn create_sparse_matrix_transport_cost<T: TransportFallback + 'static>(
+    costs: Vec<SparseMatrixData>,
+    size: usize,
+    max_neighbours: usize,
+    fallback: T,
+) -> GenericResult<Arc<dyn TransportCost>> {
+    Ok(Arc::new(SparseMatrixTransportCost::new(costs, size, max_neighbours, fallback)?))
+}
+
+/// A time agnostic routing costs which store only k-nearest neighbour entries per location.
+pub struct SparseMatrixTransportCost<T: TransportFallback> {
+    /// Known entries per profile and from location, sorted by destination location.
+    entries: Vec<Vec<Vec<(Location, Duration, Distance)>>>,
+    size: usize,
+    fallback: T,
+}
+
+impl<T: TransportFallback> SparseMatrixTransportCost<T> {
+    /// Creates an instance of `SparseMatrixTransportCost`.
+    pub fn new(costs: Vec<SparseMatrixData>, size: usize, max_neighbours: usize, fallback: T) -> GenericResult<Self> {
+        let mut costs = costs;
+        costs.sort_by_key(|a| a.index);
+
+        if costs.is_empty() {
+            return Err("no matrix data found".into());
+        }
+
+        if max_neighbours == 0 {
+            return Err("max neighbours should be greater than zero".into());
+        }
+
+        if (0..).zip(costs.iter().map(|c| &c.index)).any(|(a, &b)| a != b) {
+            return Err("duplicate or missing profiles in sparse matrix data".into());
+        }
+
+        if costs.iter().flat_map(|c| c.entries.iter()).any(|&(from, to, _, _)| from >= size || to >= size) {
+            return Err("sparse matrix entry location is out of range".into());
+        }
+
+        fallback.validate(size)?;
+
+        let entries = costs
+            .into_iter()
+            .map(|data| {
+                let mut rows = vec![Vec::new(); size];
+                data.entries.into_iter().for_each(|(from, to, duration, distance)| {
+                    rows[from].push((to, duration, distance));
+                });
+
+                rows.iter_mut().for_each(|row| {
+                    row.sort_by(|(a_to, _, a_distance), (b_to, _, b_distance)| {
+                        a_distance.total_cmp(b_distance).then(a_to.cmp(b_to))
+                    });
+                    row.dedup_by_key(|(to, _, _)| *to);
+                    row.truncate(max_neighbours);
+                    row.sort_by_key(|(to, _, _)| *to);
+                    row.shrink_to_fit();
+                });
+
+                rows
+            })
+            .collect();
+
+        Ok(Self { entries, size, fallback })
+    }
+
+    fn get_entry(&self, profile: &Profile, from: Location, to: Location) -> Option<&(Location, Duration, Distance)> {
+        let row = self.entries.get(profile.index)?.get(from)?;
+
+        row.binary_search_by_key(&to, |(to, _, _)| *to).ok().map(|idx| &row[idx])
+    }
+}
+
+impl<T: TransportFallback> TransportCost for SparseMatrixTransportCost<T> {
+    fn duration_approx(&self, profile: &Profile, from: Location, to: Location) -> Duration {
+        self.get_entry(profile, from, to)
+            .map(|&(_, duration, _)| duration)
+            .unwrap_or_else(|| self.fallback.duration(profile, from, to))
+            * profile.scale
+    }
+
+    fn distance_approx(&self, profile: &Profile, from: Location, to: Location) -> Distance {
+        self.get_entry(profile, from, to)
+            .map(|&(_, _, distance)| distance)
+            .unwrap_or_else(|| self.fallback.distance(profile, from, to))
+    }
+
+    fn duration(&self, route: &Route, from: Location, to: Location, _: TravelTime) -> Duration {
+        self.duration_approx(&route.actor.vehicle.profile, from, to)
+    }
+
+    fn distance(&self, route: &Route, from: Location, to: Location, _: TravelTime) -> Distance {
+        self.distance_approx(&route.actor.vehicle.profile, from, to)
+    }
+
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    fn sparse_neighbours(&self, profile: &Profile, from: Location) -> Option<Vec<Location>> {
+        self.entries.get(profile.index)?.get(from).map(|row| row.iter().map(|(to, _, _)| *to).collect())
+    }
+}
+
+/// Specifies a formula used to estimate distance between two coordinates.
+#[derive(Clone, Copy, Debug)]
+pub enum DistanceFormula {
+    /// Euclidean distance between (x, y) coordinates.
+    Euclidean,
+    /// Great-circle distance in meters between (latitude, longitude) coordinates given in degrees.
+    Haversine,
+}
+
+impl DistanceFormula {
+    /// Estimates distance between two coordinates using the formula.
+    pub fn distance(&self, from: (Float, Float), to: (Float, Float)) -> Distance {
+        match self {
+            DistanceFormula::Euclidean => ((from.0 - to.0).powi(2) + (from.1 - to.1).powi(2)).sqrt(),
+            DistanceFormula::Haversine => {
+                const EARTH_RADIUS: Float = 6_371_000.;
+
+                let (lat1, lat2) = (from.0.to_radians(), to.0.to_radians());
+                let d_lat = lat2 - lat1;
+                let d_lng = (to.1 - from.1).to_radians();
+
+                let a = (d_lat / 2.).sin().powi(2) + lat1.cos() * lat2.cos() * (d_lng / 2.).sin().powi(2);
+
+                2. * EARTH_RADIUS * a.sqrt().min(1.).asin()
+            }
+        }
+    }
+}
+
+/// A transport fallback which estimates routing data using location coordinates and an average speed.
+pub struct CoordinateFallback {
+    coordinates: Vec<(Float, Float)>,
+    formula: DistanceFormula,
+    speed: Float,
+}
+
+impl CoordinateFallback {
+    /// Creates a new instance of `CoordinateFallback`. Coordinates are indexed by location,
+    /// speed is used to estimate duration from distance.
+    pub fn new(coordinates: Vec<(Float, Float)>, formula: DistanceFormula, speed: Float) -> GenericResult<Self> {
+        if speed <= 0. || !speed.is_finite() {
+            return Err("speed should be positive".into());
+        }
+
+        Ok(Self { coordinates, formula, speed })
+    }
+
+    fn estimate(&self, profile: &Profile, from: Location, to: Location) -> Distance {
+        let get_coordinate = |location: Location| {
+            self.coordinates.get(location).copied().unwrap_or_else(|| {
+                panic!("cannot estimate routing for {from}->{to} for {profile:?}: unknown location {location}")
+            })
+        };
+
+        self.formula.distance(get_coordinate(from), get_coordinate(to))
+    }
+}
+
+impl TransportFallback for CoordinateFallback {
+    fn duration(&self, profile: &Profile, from: Location, to: Location) -> Duration {
+        self.estimate(profile, from, to) / self.speed
+    }
+
+    fn distance(&self, profile: &Profile, from: Location, to: Location) -> Distance {
+        self.estimate(profile, from, to)
+    }
+
+    fn validate(&self, size: usize) -> GenericResult<()> {
+        if self.coordinates.len() < size {
+            return Err(format!(
+                "fallback coordinates are specified for {} locations, but {size} are required",
+                self.coordinates.len()
+            )
+            .into());
+        }
+
+        Ok(())
+    }
+}
+
+/// Creates routing costs which calculate distances from location coordinates on demand and durations
+/// using per profile speed, so no routing matrices are needed. Speeds are indexed by profile index.
+/// A fallback is used for locations without coordinates.
+pub fn create_geo_transport_cost<T: TransportFallback + 'static>(
+    coordinates: Vec<(Float, Float)>,
+    formula: DistanceFormula,
+    speeds: Vec<Float>,
+    fallback: T,
+) -> GenericResult<Arc<dyn TransportCost>> {
+    Ok(Arc::new(GeoTransportCost::new(coordinates, formula, speeds, fallback)?))
+}
+
+/// A time agnostic routing costs which estimate routing data from location coordinates.
+pub struct GeoTransportCost<T: TransportFallback> {
+    coordinates: Vec<(Float, Float)>,
+    formula: DistanceFormula,
+    speeds: Vec<Float>,
+    fallback: T,
+}
+
+impl<T: TransportFallback> GeoTransportCost<T> {
+    /// Creates an instance of `GeoTransportCost`. Coordinates are indexed by location, speeds are
+    /// indexed by profile.
+    pub fn new(
+        coordinates: Vec<(Float, Float)>,
+        formula: DistanceFormula,
+        speeds: Vec<Float>,
+        fallback: T,
+    ) -> GenericResult<Self> {
+        if speeds.is_empty() {
+            return Err("no profile speed found".into());
+        }
+
+        if speeds.iter().any(|&speed| speed <= 0. || !speed.is_finite()) {
+            return Err("speed should be positive".into());
+        }
+
+        Ok(Self { coordinates, formula, speeds, fallback })
+    }
+
+    fn get_speed(&self, profile: &Profile) -> Float {
+        self.speeds.get(profile.index).copied().unwrap_or_else(|| panic!("cannot get speed for {profile:?}"))
+    }
+}
+
+impl<T: TransportFallback> TransportCost for GeoTransportCost<T> {
+    fn duration_approx(&self, profile: &Profile, from: Location, to: Location) -> Duration {
+        let duration = match (self.coordinates.get(from), self.coordinates.get(to)) {
+            (Some(&from), Some(&to)) => self.formula.distance(from, to) / self.get_speed(profile),
+            _ => self.fallback.duration(profile, from, to),
+        };
+
+        duration * profile.scale
+    }
+
+    fn distance_approx(&self, profile: &Profile, from: Location, to: Location) -> Distance {
+        match (self.coordinates.get(from), self.coordinates.get(to)) {
+            (Some(&from), Some(&to)) => self.formula.distance(from, to),
+            _ => self.fallback.distance(profile, from, to),
+        }
+    }
+
+    fn duration(&self, route: &Route, from: Location, to: Location, _: TravelTime) -> Duration {
+        self.duration_approx(&route.actor.vehicle.profile, from, to)
+    }
+
+    fn distance(&self, route: &Route, from: Location, to: Location, _: TravelTime) -> Distance {
+        self.distance_approx(&route.actor.vehicle.profile, from, to)
+    }
+
+    fn size(&self) -> usize {
+        self.coordinates.len()
+    }
 }
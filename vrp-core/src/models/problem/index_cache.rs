@@ -0,0 +1,260 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/models/problem/index_cache_test.rs"]
+mod index_cache_test;
+
+use crate::models::common::TimeSpan;
+use crate::models::problem::{Costs, Fleet, Job, JobClusteringMode, JobIdDimension, JobIndexMode, TransportCost};
+use rosomaxa::prelude::{Float, GenericError, GenericResult};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+/// Provides way to store and load serialized job neighbourhood index, so repeated solves of the same
+/// problem instance can skip index creation.
+pub trait JobIndexCache: Send + Sync {
+    /// Loads serialized index data for given fingerprint, if it is present.
+    fn load(&self, fingerprint: u64) -> Option<Vec<u8>>;
+
+    /// Stores serialized index data for given fingerprint.
+    fn store(&self, fingerprint: u64, data: &[u8]) -> GenericResult<()>;
+}
+
+/// A job index cache which keeps serialized index data as files in the given directory.
+pub struct FileJobIndexCache {
+    directory: PathBuf,
+}
+
+impl FileJobIndexCache {
+    /// Creates a new instance of `FileJobIndexCache`.
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self { directory: directory.into() }
+    }
+
+    fn get_path(&self, fingerprint: u64) -> PathBuf {
+        self.directory.join(format!("job_index_{fingerprint:016x}.bin"))
+    }
+}
+
+impl JobIndexCache for FileJobIndexCache {
+    fn load(&self, fingerprint: u64) -> Option<Vec<u8>> {
+        std::fs::read(self.get_path(fingerprint)).ok()
+    }
+
+    fn store(&self, fingerprint: u64, data: &[u8]) -> GenericResult<()> {
+        std::fs::create_dir_all(&self.directory)
+            .and_then(|_| std::fs::write(self.get_path(fingerprint), data))
+            .map_err(|err| format!("cannot store job index: {err}").into())
+    }
+}
+
+/// Creates a fingerprint of the data used to create job index: jobs with their ids and places, fleet
+/// profiles with costs, start locations and routing data fingerprints, index and clustering modes.
+/// Returns `None` if routing data of some profile cannot be fingerprinted.
+pub(crate) fn get_index_fingerprint(
+    fleet: &Fleet,
+    jobs: &[Job],
+    transport: &dyn TransportCost,
+    index_mode: &JobIndexMode,
+    clustering_mode: &JobClusteringMode,
+    avg_profile_costs: &HashMap<usize, Costs>,
+) -> Option<u64> {
+    let mut hasher = StableHasher::default();
+
+    hasher.write_usize(INDEX_FORMAT_VERSION as usize);
+    hasher.write_usize(jobs.len());
+    jobs.iter().for_each(|job| {
+        hasher.write_str(job.dimens().get_job_id().map_or("", |id| id.as_str()));
+        hasher.write_usize(match job {
+            Job::Single(_) => 1,
+            Job::Multi(multi) => multi.jobs.len() + 1,
+        });
+        hasher.write_usize(job.places().count());
+        job.places().for_each(|place| {
+            hasher.write_usize(place.location.map_or(0, |location| location + 1));
+            hasher.write_float(place.duration);
+            hasher.write_usize(place.times.len());
+            place.times.iter().for_each(|time| {
+                let (kind, start, end) = match time {
+                    TimeSpan::Window(window) => (0, window.start, window.end),
+                    TimeSpan::Offset(offset) => (1, offset.start, offset.end),
+                };
+                hasher.write_usize(kind);
+                hasher.write_float(start);
+                hasher.write_float(end);
+            });
+        });
+    });
+
+    hasher.write_usize(fleet.profiles.len());
+    fleet.profiles.iter().try_for_each(|profile| {
+        let costs = avg_profile_costs.get(&profile.index);
+        hasher.write_usize(profile.index);
+        hasher.write_float(profile.scale);
+        hasher.write_float(costs.map_or(0., |costs| costs.per_distance));
+        hasher.write_float(costs.map_or(0., |costs| costs.per_driving_time));
+        hasher.write_u64(transport.fingerprint(profile)?);
+
+        fleet
+            .vehicles
+            .iter()
+            .filter(|vehicle| vehicle.profile.index == profile.index)
+            .flat_map(|vehicle| vehicle.details.iter().filter_map(|detail| detail.start.as_ref()))
+            .for_each(|start| hasher.write_usize(start.location));
+
+        Some(())
+    })?;
+
+    match index_mode {
+        JobIndexMode::Exact => hasher.write_usize(0),
+        JobIndexMode::Approximate { coordinates, max_candidates } => {
+            hasher.write_usize(1);
+            hasher.write_usize(*max_candidates);
+            coordinates.iter().for_each(|&(x, y)| {
+                hasher.write_float(x);
+                hasher.write_float(y);
+            });
+        }
+    }
+
+    match clustering_mode {
+        JobClusteringMode::Dbscan { min_points, epsilon } => {
+            hasher.write_usize(0);
+            hasher.write_usize(*min_points);
+            hasher.write_float(epsilon.unwrap_or(-1.));
+        }
+        JobClusteringMode::KMedoids { k } => {
+            hasher.write_usize(1);
+            hasher.write_usize(*k);
+        }
+        JobClusteringMode::Grid { coordinates, cell_size, min_points } => {
+            hasher.write_usize(2);
+            hasher.write_float(*cell_size);
+            hasher.write_usize(*min_points);
+            coordinates.iter().for_each(|&(x, y)| {
+                hasher.write_float(x);
+                hasher.write_float(y);
+            });
+        }
+    }
+
+    Some(hasher.finish())
+}
+
+/// A version of serialized index format.
+pub(crate) const INDEX_FORMAT_VERSION: u8 = 1;
+
+/// A FNV-1a hasher which, unlike std's default one, is guaranteed to be stable between runs and
+/// compiler versions.
+pub(crate) struct StableHasher {
+    state: u64,
+}
+
+impl Default for StableHasher {
+    fn default() -> Self {
+        Self { state: 0xcbf2_9ce4_8422_2325 }
+    }
+}
+
+impl StableHasher {
+    pub fn write(&mut self, bytes: &[u8]) {
+        bytes.iter().for_each(|&byte| {
+            self.state ^= byte as u64;
+            self.state = self.state.wrapping_mul(0x0100_0000_01b3);
+        });
+    }
+
+    pub fn write_usize(&mut self, value: usize) {
+        self.write_u64(value as u64);
+    }
+
+    pub fn write_u64(&mut self, value: u64) {
+        self.write(&value.to_le_bytes());
+    }
+
+    pub fn write_float(&mut self, value: Float) {
+        self.write(&value.to_bits().to_le_bytes());
+    }
+
+    pub fn write_str(&mut self, value: &str) {
+        self.write_usize(value.len());
+        self.write(value.as_bytes());
+    }
+
+    pub fn finish(&self) -> u64 {
+        self.state
+    }
+}
+
+/// Writes index data in little endian binary format.
+#[derive(Default)]
+pub(crate) struct IndexWriter {
+    data: Vec<u8>,
+}
+
+impl IndexWriter {
+    pub fn write_u32(&mut self, value: usize) {
+        self.data.extend_from_slice(&(value as u32).to_le_bytes());
+    }
+
+    pub fn write_u64(&mut self, value: u64) {
+        self.data.extend_from_slice(&value.to_le_bytes());
+    }
+
+    pub fn write_f32(&mut self, value: f32) {
+        self.data.extend_from_slice(&value.to_le_bytes());
+    }
+
+    pub fn write_float(&mut self, value: Float) {
+        self.data.extend_from_slice(&value.to_bits().to_le_bytes());
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.data
+    }
+}
+
+/// Reads index data written by [`IndexWriter`].
+pub(crate) struct IndexReader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> IndexReader<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    pub fn read_u32(&mut self) -> GenericResult<usize> {
+        self.read_bytes::<4>().map(|bytes| u32::from_le_bytes(bytes) as usize)
+    }
+
+    pub fn read_u64(&mut self) -> GenericResult<u64> {
+        self.read_bytes::<8>().map(u64::from_le_bytes)
+    }
+
+    pub fn read_f32(&mut self) -> GenericResult<f32> {
+        self.read_bytes::<4>().map(f32::from_le_bytes)
+    }
+
+    pub fn read_float(&mut self) -> GenericResult<Float> {
+        self.read_bytes::<8>().map(|bytes| Float::from_bits(u64::from_le_bytes(bytes)))
+    }
+
+    /// Reads index which should be less than given size.
+    pub fn read_index(&mut self, size: usize) -> GenericResult<usize> {
+        self.read_u32().and_then(|idx| if idx < size { Ok(idx) } else { Err("index is out of range".into()) })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    fn read_bytes<const N: usize>(&mut self) -> GenericResult<[u8; N]> {
+        if self.data.len() < N {
+            return Err(GenericError::from("unexpected end of index data"));
+        }
+
+        let (bytes, rest) = self.data.split_at(N);
+        self.data = rest;
+
+        Ok(bytes.try_into().expect("slice has proper length"))
+    }
+}
@@ -2,8 +2,12 @@
 #[path = "../../../tests/unit/models/problem/jobs_test.rs"]
 mod jobs_test;
 
-use crate::construction::clustering::dbscan::create_job_clusters;
+use crate::algorithms::geometry::Point;
+use crate::algorithms::structures::KdTree;
+use crate::construction::clustering::dbscan::estimate_epsilon;
+use crate::construction::clustering::{dbscan, grid, kmedoids};
 use crate::models::common::*;
+use crate::models::problem::index_cache::*;
 use crate::models::problem::{Costs, Fleet, TransportCost};
 use crate::utils::{Either, short_type_name};
 use rosomaxa::prelude::{Float, GenericResult, InfoLogger};
@@ -69,6 +73,17 @@ impl Job {
     }
 }
 
+/// Compares jobs using their ids as a canonical key, so the order does not depend on job addresses in memory.
+pub fn compare_jobs_canonically(a: &Job, b: &Job) -> std::cmp::Ordering {
+    a.dimens().get_job_id().cmp(&b.dimens().get_job_id())
+}
+
+/// Sorts jobs in a canonical order (see [`compare_jobs_canonically`]).
+/// Jobs without ids keep their relative order.
+pub fn sort_jobs_canonically(jobs: &mut [Job]) {
+    jobs.sort_by(compare_jobs_canonically);
+}
+
 impl Debug for Job {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -217,7 +232,38 @@ struct JobLink;
 
 /// Floating type wit less precision, but lower impact on memory footprint.
 type LowPrecisionCost = f32;
-type JobIndex = HashMap<Job, (Vec<(Job, LowPrecisionCost)>, LowPrecisionCost)>;
+
+/// Sorted neighbours of each job. Can be shared by profiles which have identical job neighbourhood.
+type JobNeighbours = HashMap<Job, Vec<(Job, LowPrecisionCost)>>;
+
+/// Keeps job neighbourhood index for specific profile.
+#[derive(Clone)]
+struct JobIndex {
+    neighbours: Arc<JobNeighbours>,
+    ranks: HashMap<Job, LowPrecisionCost>,
+}
+
+/// A key to detect profiles with identical job neighbourhood: the same routing data, duration scale
+/// and average costs.
+#[derive(Hash, Eq, PartialEq)]
+struct NeighboursKey {
+    routing: Result<usize, usize>,
+    scale: u64,
+    per_distance: u64,
+    per_driving_time: u64,
+}
+
+impl NeighboursKey {
+    fn new(profile: &Profile, costs: &Costs, transport: &dyn TransportCost) -> Self {
+        Self {
+            // NOTE profile without known matrix key has its own neighbourhood
+            routing: transport.matrix_key(profile).ok_or(profile.index),
+            scale: profile.scale.to_bits(),
+            per_distance: costs.per_distance.to_bits(),
+            per_driving_time: costs.per_driving_time.to_bits(),
+        }
+    }
+}
 
 // TODO: we don't know actual departure and zero-cost when we create job index.
 const DEFAULT_COST: LowPrecisionCost = 0.;
@@ -230,11 +276,67 @@ const UNREACHABLE_COST: LowPrecisionCost = f32::MAX;
 /// but we keep it 2x times more.
 const MAX_NEIGHBOURS: usize = 256;
 
+/// Specifies how job neighbourhood index is created.
+#[derive(Clone, Default)]
+pub enum JobIndexMode {
+    /// Costs between all job pairs are evaluated, so the index is exact, but its creation
+    /// has quadratic complexity.
+    #[default]
+    Exact,
+    /// Neighbour candidates are found using k-d tree built on job centroids, so costs are evaluated
+    /// only between job and its nearest candidates. This trades exactness for startup time.
+    Approximate {
+        /// Coordinates of all locations: location is used as an index.
+        coordinates: Arc<Vec<(Float, Float)>>,
+        /// Maximum amount of the nearest candidates evaluated for each job.
+        max_candidates: usize,
+    },
+}
+
+/// Default minimum amount of job's neighbours to form a DBSCAN cluster.
+const CLUSTER_MIN_POINTS: usize = 3;
+
+/// Specifies how job clusters are created.
+#[derive(Clone)]
+pub enum JobClusteringMode {
+    /// Jobs are clustered using DBSCAN algorithm on their neighbourhood.
+    Dbscan {
+        /// Minimum amount of job's neighbours to form a cluster.
+        min_points: usize,
+        /// Maximum cost between neighbours in a cluster. Estimated from job neighbourhood when omitted.
+        epsilon: Option<Float>,
+    },
+    /// Jobs are split into `k` clusters using K-Medoids algorithm on costs between jobs.
+    KMedoids {
+        /// Amount of clusters.
+        k: usize,
+    },
+    /// Jobs are grouped by cells of a uniform grid built on their coordinates.
+    Grid {
+        /// Coordinates of all locations: location is used as an index.
+        coordinates: Arc<Vec<(Float, Float)>>,
+        /// Size of the grid cell.
+        cell_size: Float,
+        /// Minimum amount of jobs in a cell to form a cluster.
+        min_points: usize,
+    },
+}
+
+impl Default for JobClusteringMode {
+    fn default() -> Self {
+        Self::Dbscan { min_points: CLUSTER_MIN_POINTS, epsilon: None }
+    }
+}
+
 /// Stores all jobs taking into account their neighborhood.
+#[derive(Clone)]
 pub struct Jobs {
     jobs: Vec<Job>,
     index: HashMap<usize, JobIndex>,
     clusters: Vec<HashSet<Job>>,
+    cluster_epsilon: Float,
+    index_mode: JobIndexMode,
+    clustering_mode: JobClusteringMode,
 }
 
 impl Jobs {
@@ -245,11 +347,158 @@ impl Jobs {
         transport: &dyn TransportCost,
         logger: &InfoLogger,
     ) -> GenericResult<Jobs> {
-        let index = create_index(fleet, jobs.clone(), transport, logger);
-        let clusters =
-            create_job_clusters(&jobs, fleet, Some(3), None, |profile, job| neighbors(&index, profile, job))?;
+        Self::new_with_index_mode(fleet, jobs, transport, JobIndexMode::Exact, logger)
+    }
 
-        Ok(Jobs { jobs, index, clusters })
+    /// Creates a new instance of [`Jobs`] using given job index mode.
+    pub fn new_with_index_mode(
+        fleet: &Fleet,
+        jobs: Vec<Job>,
+        transport: &dyn TransportCost,
+        index_mode: JobIndexMode,
+        logger: &InfoLogger,
+    ) -> GenericResult<Jobs> {
+        Self::new_with_modes(fleet, jobs, transport, index_mode, JobClusteringMode::default(), logger)
+    }
+
+    /// Creates a new instance of [`Jobs`] using given job index and clustering modes.
+    pub fn new_with_modes(
+        fleet: &Fleet,
+        jobs: Vec<Job>,
+        transport: &dyn TransportCost,
+        index_mode: JobIndexMode,
+        clustering_mode: JobClusteringMode,
+        logger: &InfoLogger,
+    ) -> GenericResult<Jobs> {
+        let spatial_index = create_spatial_index(&jobs, &index_mode)?;
+        let index = create_index(fleet, jobs.clone(), transport, spatial_index.as_ref(), logger);
+        let (clusters, cluster_epsilon) = create_clusters(fleet, &jobs, transport, &index, &clustering_mode)?;
+
+        Ok(Jobs { jobs, index, clusters, cluster_epsilon, index_mode, clustering_mode })
+    }
+
+    /// Creates a new instance of [`Jobs`] reusing job index stored in the cache if it was created for
+    /// the same jobs, fleet, routing data, index and clustering modes. Otherwise, a new index is created
+    /// and stored. The cache is not used if routing data cannot be fingerprinted.
+    pub fn new_with_cache(
+        fleet: &Fleet,
+        jobs: Vec<Job>,
+        transport: &dyn TransportCost,
+        index_mode: JobIndexMode,
+        clustering_mode: JobClusteringMode,
+        cache: &dyn JobIndexCache,
+        logger: &InfoLogger,
+    ) -> GenericResult<Jobs> {
+        let avg_profile_costs = get_avg_profile_costs(fleet);
+        let Some(fingerprint) =
+            get_index_fingerprint(fleet, &jobs, transport, &index_mode, &clustering_mode, &avg_profile_costs)
+        else {
+            (logger)("job index cache is not used: routing data cannot be fingerprinted");
+            return Self::new_with_modes(fleet, jobs, transport, index_mode, clustering_mode, logger);
+        };
+
+        if let Some(data) = cache.load(fingerprint) {
+            match decode_index(&data, fingerprint, &jobs) {
+                Ok((index, clusters, cluster_epsilon)) => {
+                    (logger)("job index loaded from cache");
+                    return Ok(Jobs { jobs, index, clusters, cluster_epsilon, index_mode, clustering_mode });
+                }
+                Err(err) => (logger)(format!("cannot use cached job index: {err}").as_str()),
+            }
+        }
+
+        let result = Self::new_with_modes(fleet, jobs, transport, index_mode, clustering_mode, logger)?;
+
+        if let Err(err) = cache.store(fingerprint, &result.encode_index(fingerprint)) {
+            (logger)(err.to_string().as_str());
+        }
+
+        Ok(result)
+    }
+
+    /// Inserts a new job updating neighbourhood index and clusters incrementally: only costs between
+    /// the new job and existing ones are evaluated. Clusters are updated using the same clustering parameters,
+    /// so the result is an approximation of clusters built from scratch.
+    pub fn insert(&mut self, fleet: &Fleet, transport: &dyn TransportCost, job: Job) -> GenericResult<()> {
+        if self.jobs.contains(&job) {
+            return Err("job is already present".into());
+        }
+
+        let avg_profile_costs = get_avg_profile_costs(fleet);
+
+        update_neighbours(&mut self.index, fleet, |profile, neighbours| {
+            let avg_costs = avg_profile_costs.get(&profile.index).unwrap();
+            let costs = parallel_collect(&self.jobs, |other| {
+                let to_new = get_cost_between_jobs(profile, avg_costs, transport, other, &job);
+                let from_new = get_cost_between_jobs(profile, avg_costs, transport, &job, other);
+
+                (to_new, from_new)
+            });
+
+            let mut job_neighbours = self
+                .jobs
+                .iter()
+                .zip(costs.iter())
+                .map(|(other, &(_, from_new))| (other.clone(), from_new))
+                .collect::<Vec<_>>();
+            job_neighbours.sort_unstable_by(|(_, a), (_, b)| a.total_cmp(b));
+            job_neighbours.truncate(MAX_NEIGHBOURS);
+            job_neighbours.shrink_to_fit();
+
+            self.jobs.iter().zip(costs.iter()).for_each(|(other, &(to_new, _))| {
+                if let Some(other_neighbours) = neighbours.get_mut(other) {
+                    let position = other_neighbours.partition_point(|(_, cost)| *cost <= to_new);
+                    if position < MAX_NEIGHBOURS {
+                        other_neighbours.insert(position, (job.clone(), to_new));
+                        other_neighbours.truncate(MAX_NEIGHBOURS);
+                    }
+                }
+            });
+
+            neighbours.insert(job.clone(), job_neighbours);
+        });
+
+        fleet.profiles.iter().for_each(|profile| {
+            let avg_costs = avg_profile_costs.get(&profile.index).unwrap();
+            let starts = get_profile_starts(fleet, profile);
+            let rank = get_job_rank(profile, avg_costs, transport, &starts, &job);
+
+            if let Some(index) = self.index.get_mut(&profile.index) {
+                index.ranks.insert(job.clone(), rank);
+            }
+        });
+
+        self.jobs.push(job.clone());
+        self.insert_into_clusters(fleet, job);
+
+        Ok(())
+    }
+
+    /// Removes the job updating neighbourhood index and clusters incrementally. Please note that
+    /// neighbour lists are not refilled, so some jobs might have less neighbours than in index built
+    /// from scratch.
+    pub fn remove(&mut self, fleet: &Fleet, job: &Job) -> GenericResult<()> {
+        let Some(position) = self.jobs.iter().position(|other| other == job) else {
+            return Err("job is not found".into());
+        };
+
+        self.jobs.remove(position);
+
+        update_neighbours(&mut self.index, fleet, |_, neighbours| {
+            neighbours.remove(job);
+            neighbours.values_mut().for_each(|other_neighbours| other_neighbours.retain(|(other, _)| other != job));
+        });
+        self.index.values_mut().for_each(|index| {
+            index.ranks.remove(job);
+        });
+
+        self.clusters.iter_mut().for_each(|cluster| {
+            cluster.remove(job);
+        });
+        let min_cluster_size = self.get_min_cluster_size();
+        self.clusters.retain(|cluster| cluster.len() >= min_cluster_size);
+
+        Ok(())
     }
 
     /// Returns all jobs in the original order as a slice.
@@ -276,13 +525,218 @@ impl Jobs {
     /// Returns job rank as relative cost from any vehicle's start position.
     /// Returns `None` if a job is not found in index.
     pub fn rank(&self, profile: &Profile, job: &Job) -> Option<Cost> {
-        self.index.get(&profile.index).and_then(|index| index.get(job)).map(|(_, cost)| *cost as Cost)
+        self.index.get(&profile.index).and_then(|index| index.ranks.get(job)).map(|cost| *cost as Cost)
     }
 
     /// Returns number of jobs.
     pub fn size(&self) -> usize {
         self.jobs.len()
     }
+
+    /// Returns an approximate amount of memory (in bytes) used by jobs and their neighbourhood index.
+    pub fn memory_usage(&self) -> usize {
+        let jobs = self.jobs.capacity() * size_of::<Job>();
+        let clusters = self.clusters.iter().map(|cluster| cluster.capacity() * size_of::<Job>()).sum::<usize>();
+
+        // NOTE neighbours can be shared by multiple profiles, so count them only once
+        let mut counted = HashSet::new();
+        let index = self
+            .index
+            .values()
+            .map(|job_index| {
+                let ranks = job_index.ranks.capacity() * size_of::<(Job, LowPrecisionCost)>();
+                let neighbours = if counted.insert(Arc::as_ptr(&job_index.neighbours)) {
+                    job_index
+                        .neighbours
+                        .values()
+                        .map(|neighbours| {
+                            size_of::<(Job, Vec<(Job, LowPrecisionCost)>)>()
+                                + neighbours.capacity() * size_of::<(Job, LowPrecisionCost)>()
+                        })
+                        .sum::<usize>()
+                } else {
+                    0
+                };
+
+                size_of::<(usize, JobIndex)>() + ranks + neighbours
+            })
+            .sum::<usize>();
+
+        jobs + clusters + index
+    }
+
+    /// Returns mode used to create job neighbourhood index.
+    pub fn index_mode(&self) -> &JobIndexMode {
+        &self.index_mode
+    }
+
+    /// Returns a mode used to create job clusters.
+    pub fn clustering_mode(&self) -> &JobClusteringMode {
+        &self.clustering_mode
+    }
+
+    /// Serializes job index and clusters. Jobs are referenced by their position.
+    fn encode_index(&self, fingerprint: u64) -> Vec<u8> {
+        let positions = self.jobs.iter().enumerate().map(|(idx, job)| (job, idx)).collect::<HashMap<_, _>>();
+        let mut writer = IndexWriter::default();
+
+        writer.write_u32(INDEX_FORMAT_VERSION as usize);
+        writer.write_u64(fingerprint);
+        writer.write_u32(self.jobs.len());
+        writer.write_float(self.cluster_epsilon);
+
+        // NOTE neighbourhood shared by multiple profiles is written once
+        let mut profiles = self.index.iter().collect::<Vec<_>>();
+        profiles.sort_by_key(|(profile_idx, _)| **profile_idx);
+        let mut groups: Vec<&Arc<JobNeighbours>> = vec![];
+        let profiles = profiles
+            .into_iter()
+            .map(|(profile_idx, index)| {
+                let group_idx =
+                    groups.iter().position(|group| Arc::ptr_eq(group, &index.neighbours)).unwrap_or_else(|| {
+                        groups.push(&index.neighbours);
+                        groups.len() - 1
+                    });
+
+                (*profile_idx, group_idx, index)
+            })
+            .collect::<Vec<_>>();
+
+        writer.write_u32(groups.len());
+        for neighbours in groups {
+            for job in self.jobs.iter() {
+                let job_neighbours = neighbours.get(job).map(|job_neighbours| job_neighbours.as_slice()).unwrap_or(&[]);
+                writer.write_u32(job_neighbours.len());
+                for (other, cost) in job_neighbours {
+                    writer.write_u32(positions[other]);
+                    writer.write_f32(*cost);
+                }
+            }
+        }
+
+        writer.write_u32(profiles.len());
+        for (profile_idx, group_idx, index) in profiles {
+            writer.write_u32(profile_idx);
+            writer.write_u32(group_idx);
+            for job in self.jobs.iter() {
+                writer.write_f32(index.ranks.get(job).copied().unwrap_or(DEFAULT_COST));
+            }
+        }
+
+        writer.write_u32(self.clusters.len());
+        for cluster in self.clusters.iter() {
+            let mut cluster = cluster.iter().map(|job| positions[job]).collect::<Vec<_>>();
+            cluster.sort_unstable();
+
+            writer.write_u32(cluster.len());
+            cluster.into_iter().for_each(|idx| writer.write_u32(idx));
+        }
+
+        writer.into_bytes()
+    }
+
+    /// Returns minimum size of a cluster which can be kept after job removal.
+    fn get_min_cluster_size(&self) -> usize {
+        match &self.clustering_mode {
+            JobClusteringMode::Dbscan { min_points, .. } => (*min_points).max(2),
+            JobClusteringMode::KMedoids { .. } => 2,
+            JobClusteringMode::Grid { min_points, .. } => *min_points,
+        }
+    }
+
+    /// Inserts the job into clusters using current clustering mode.
+    fn insert_into_clusters(&mut self, fleet: &Fleet, job: Job) {
+        // NOTE use first profile as it is done when clusters are created
+        let Some(profile) = fleet.profiles.first() else { return };
+
+        if get_job_locations(&job).flatten().next().is_none() {
+            return;
+        }
+
+        match &self.clustering_mode {
+            JobClusteringMode::Dbscan { min_points, .. } => {
+                let min_points = (*min_points).max(2);
+                self.insert_into_dbscan_clusters(profile, job, min_points)
+            }
+            JobClusteringMode::KMedoids { .. } => self.insert_into_medoid_clusters(profile, job),
+            JobClusteringMode::Grid { coordinates, cell_size, min_points } => {
+                let (coordinates, cell_size, min_points) = (coordinates.clone(), *cell_size, *min_points);
+                self.insert_into_grid_clusters(job, coordinates.as_slice(), cell_size, min_points)
+            }
+        }
+    }
+
+    /// Inserts the job into DBSCAN clusters: a job with enough neighbours within epsilon merges all
+    /// clusters of its neighbours, otherwise, it joins the cluster of its nearest clustered neighbour.
+    fn insert_into_dbscan_clusters(&mut self, profile: &Profile, job: Job, min_points: usize) {
+        let epsilon_neighbours = neighbors(&self.index, profile, &job)
+            .filter(|(other, _)| get_job_locations(other).flatten().next().is_some())
+            .take_while(|(_, cost)| *cost < self.cluster_epsilon)
+            .map(|(other, _)| other.clone())
+            .collect::<Vec<_>>();
+
+        let get_cluster_idx = |other: &Job| self.clusters.iter().position(|cluster| cluster.contains(other));
+
+        if epsilon_neighbours.len() >= min_points {
+            let cluster_indices = epsilon_neighbours.iter().filter_map(get_cluster_idx).collect::<HashSet<_>>();
+
+            let mut merged = epsilon_neighbours.into_iter().chain(std::iter::once(job)).collect::<HashSet<_>>();
+            let mut idx = 0;
+            self.clusters.retain(|cluster| {
+                let is_merged = cluster_indices.contains(&idx);
+                if is_merged {
+                    merged.extend(cluster.iter().cloned());
+                }
+                idx += 1;
+
+                !is_merged
+            });
+
+            // NOTE border jobs might already belong to other clusters
+            self.clusters.iter_mut().for_each(|cluster| cluster.retain(|other| !merged.contains(other)));
+            self.clusters.retain(|cluster| !cluster.is_empty());
+            self.clusters.push(merged);
+        } else if let Some(cluster_idx) = epsilon_neighbours.iter().find_map(get_cluster_idx) {
+            self.clusters[cluster_idx].insert(job);
+        }
+    }
+
+    /// Inserts the job into the cluster of its nearest clustered neighbour. Medoids are not updated.
+    fn insert_into_medoid_clusters(&mut self, profile: &Profile, job: Job) {
+        let cluster_idx = neighbors(&self.index, profile, &job)
+            .find_map(|(other, _)| self.clusters.iter().position(|cluster| cluster.contains(other)));
+
+        if let Some(cluster_idx) = cluster_idx {
+            self.clusters[cluster_idx].insert(job);
+        }
+    }
+
+    /// Inserts the job into the cluster of its grid cell. A new cluster is formed when the cell has
+    /// enough unclustered jobs.
+    fn insert_into_grid_clusters(
+        &mut self,
+        job: Job,
+        coordinates: &[(Float, Float)],
+        cell_size: Float,
+        min_points: usize,
+    ) {
+        let Some(cell) = grid::get_grid_cell(&job, coordinates, cell_size) else { return };
+        let cell_jobs = self
+            .jobs
+            .iter()
+            .filter(|other| grid::get_grid_cell(other, coordinates, cell_size) == Some(cell))
+            .cloned()
+            .collect::<HashSet<_>>();
+
+        // NOTE all jobs in the cell belong to the same cluster, if any
+        if let Some(cluster) =
+            self.clusters.iter_mut().find(|cluster| cluster.iter().any(|other| cell_jobs.contains(other)))
+        {
+            cluster.insert(job);
+        } else if cell_jobs.len() >= min_points {
+            self.clusters.push(cell_jobs);
+        }
+    }
 }
 
 impl PartialEq<Job> for Job {
@@ -317,9 +771,9 @@ fn neighbors<'a>(
 ) -> impl Iterator<Item = (&'a Job, Cost)> + use<'a> {
     index
         .get(&profile.index)
-        .and_then(|index| index.get(job))
+        .and_then(|index| index.neighbours.get(job))
         .into_iter()
-        .flat_map(|(info, _)| info.iter().map(|(job, cost)| (job, *cost as Float)))
+        .flat_map(|info| info.iter().map(|(job, cost)| (job, *cost as Float)))
 }
 
 /// Returns job locations.
@@ -330,53 +784,155 @@ pub fn get_job_locations(job: &Job) -> impl Iterator<Item = Option<Location>> +
     }
 }
 
-/// Creates job index.
+/// Keeps k-d tree built on job centroids which is used to select neighbour candidates.
+struct SpatialIndex {
+    tree: KdTree,
+    /// Jobs with known centroid in the same order as they are stored in the tree.
+    located: Vec<Job>,
+    /// Jobs without any location: they are candidates for any job.
+    unlocated: Vec<Job>,
+    coordinates: Arc<Vec<(Float, Float)>>,
+    max_candidates: usize,
+}
+
+impl SpatialIndex {
+    /// Returns neighbour candidates for given job or `None` if job has no locations.
+    fn get_candidates(&self, job: &Job) -> Option<HashSet<&Job>> {
+        let centroid = get_job_centroid(job, self.coordinates.as_slice())?;
+
+        // NOTE request one more as the job itself is returned
+        let nearest = self.tree.nearest(&centroid, self.max_candidates + 1);
+
+        Some(nearest.into_iter().map(|idx| &self.located[idx]).chain(self.unlocated.iter()).collect())
+    }
+}
+
+/// Creates spatial index if it is requested by job index mode.
+fn create_spatial_index(jobs: &[Job], index_mode: &JobIndexMode) -> GenericResult<Option<SpatialIndex>> {
+    let JobIndexMode::Approximate { coordinates, max_candidates } = index_mode else {
+        return Ok(None);
+    };
+
+    if *max_candidates == 0 {
+        return Err("max candidates of approximate job index should be greater than zero".into());
+    }
+
+    if jobs.iter().flat_map(|job| get_job_locations(job).flatten()).any(|location| location >= coordinates.len()) {
+        return Err("approximate job index requires coordinates for all job locations".into());
+    }
+
+    let (located, centroids, unlocated) =
+        jobs.iter().fold((vec![], vec![], vec![]), |(mut located, mut centroids, mut unlocated), job| {
+            match get_job_centroid(job, coordinates.as_slice()) {
+                Some(centroid) => {
+                    located.push(job.clone());
+                    centroids.push(centroid);
+                }
+                None => unlocated.push(job.clone()),
+            }
+
+            (located, centroids, unlocated)
+        });
+
+    Ok(Some(SpatialIndex {
+        tree: KdTree::new(centroids),
+        located,
+        unlocated,
+        coordinates: coordinates.clone(),
+        max_candidates: *max_candidates,
+    }))
+}
+
+/// Returns an average point of all job's locations.
+pub(crate) fn get_job_centroid(job: &Job, coordinates: &[(Float, Float)]) -> Option<Point> {
+    let (count, x, y) = get_job_locations(job)
+        .flatten()
+        .filter_map(|location| coordinates.get(location))
+        .fold((0, 0., 0.), |(count, x, y), &(lhs, rhs)| (count + 1, x + lhs, y + rhs));
+
+    (count > 0).then(|| Point::new(x / count as Float, y / count as Float))
+}
+
+/// Creates job clusters using given clustering mode. Returns clusters and DBSCAN epsilon used to create them.
+fn create_clusters(
+    fleet: &Fleet,
+    jobs: &[Job],
+    transport: &dyn TransportCost,
+    index: &HashMap<usize, JobIndex>,
+    clustering_mode: &JobClusteringMode,
+) -> GenericResult<(Vec<HashSet<Job>>, Float)> {
+    match clustering_mode {
+        JobClusteringMode::Dbscan { min_points, epsilon } => {
+            let min_points = (*min_points).max(2);
+
+            if epsilon.is_some_and(|epsilon| !epsilon.is_finite() || epsilon <= 0.) {
+                return Err("epsilon of DBSCAN job clustering should be positive".into());
+            }
+
+            let neighbour_fn = |profile: &Profile, job: &Job| neighbors(index, profile, job);
+            let epsilon = epsilon.unwrap_or_else(|| estimate_epsilon(jobs, fleet, min_points, &neighbour_fn));
+            let clusters = dbscan::create_job_clusters(jobs, fleet, Some(min_points), Some(epsilon), neighbour_fn)?;
+
+            Ok((clusters, epsilon))
+        }
+        JobClusteringMode::KMedoids { k } => {
+            let avg_profile_costs = get_avg_profile_costs(fleet);
+            let clusters = kmedoids::create_job_clusters(jobs, fleet, *k, |profile, from, to| {
+                avg_profile_costs
+                    .get(&profile.index)
+                    .map_or(DEFAULT_COST, |costs| get_cost_between_jobs(profile, costs, transport, from, to))
+                    as Cost
+            })?;
+
+            Ok((clusters, 0.))
+        }
+        JobClusteringMode::Grid { coordinates, cell_size, min_points } => {
+            Ok((grid::create_job_clusters(jobs, coordinates.as_slice(), *cell_size, *min_points)?, 0.))
+        }
+    }
+}
+
+/// Creates job index. Job neighbourhood is created once for all profiles with identical
+/// routing data and costs.
 fn create_index(
     fleet: &Fleet,
     jobs: Vec<Job>,
     transport: &dyn TransportCost,
+    spatial_index: Option<&SpatialIndex>,
     logger: &InfoLogger,
 ) -> HashMap<usize, JobIndex> {
     let avg_profile_costs = get_avg_profile_costs(fleet);
+    let location_jobs = get_location_jobs(&jobs);
 
     Timer::measure_duration_with_callback(
         || {
+            let mut shared_neighbours = HashMap::<NeighboursKey, Arc<JobNeighbours>>::new();
+
             fleet.profiles.iter().fold(HashMap::new(), |mut acc, profile| {
                 let avg_costs = avg_profile_costs.get(&profile.index).unwrap();
-                // get all possible start positions for given profile
-                let starts: Vec<Location> = fleet
-                    .vehicles
-                    .iter()
-                    .filter(|v| v.profile.index == profile.index)
-                    .flat_map(|v| v.details.iter().map(|d| d.start.as_ref().map(|s| s.location)))
-                    .flatten()
-                    .collect();
-
-                // create job index
-                let item = parallel_collect(&jobs, |job| {
-                    let mut sorted_job_costs: Vec<(Job, LowPrecisionCost)> = jobs
-                        .iter()
-                        .filter(|j| **j != *job)
-                        .map(|j| (j.clone(), get_cost_between_jobs(profile, avg_costs, transport, job, j)))
-                        .collect();
-                    sorted_job_costs.sort_unstable_by(|(_, a), (_, b)| a.total_cmp(b));
-
-                    sorted_job_costs.truncate(MAX_NEIGHBOURS);
-                    sorted_job_costs.shrink_to_fit();
-
-                    let fleet_costs = starts
-                        .iter()
-                        .cloned()
-                        .map(|s| get_cost_between_job_and_location(profile, avg_costs, transport, job, s))
-                        .min_by(|a, b| a.partial_cmp(b).unwrap_or(Less))
-                        .unwrap_or(DEFAULT_COST);
-
-                    (job.clone(), (sorted_job_costs, fleet_costs))
+
+                let neighbours = shared_neighbours
+                    .entry(NeighboursKey::new(profile, avg_costs, transport))
+                    .or_insert_with(|| {
+                        Arc::new(create_job_neighbours(
+                            profile,
+                            avg_costs,
+                            &jobs,
+                            transport,
+                            spatial_index,
+                            &location_jobs,
+                        ))
+                    })
+                    .clone();
+
+                let starts = get_profile_starts(fleet, profile);
+                let ranks = parallel_collect(&jobs, |job| {
+                    (job.clone(), get_job_rank(profile, avg_costs, transport, &starts, job))
                 })
                 .into_iter()
                 .collect::<HashMap<_, _>>();
 
-                acc.insert(profile.index, item);
+                acc.insert(profile.index, JobIndex { neighbours, ranks });
                 acc
             })
         },
@@ -384,6 +940,192 @@ fn create_index(
     )
 }
 
+/// Job index, clusters and clusters epsilon restored from serialized data.
+type DecodedIndex = (HashMap<usize, JobIndex>, Vec<HashSet<Job>>, Float);
+
+/// Deserializes job index and clusters written by [`Jobs::encode_index`].
+fn decode_index(data: &[u8], fingerprint: u64, jobs: &[Job]) -> GenericResult<DecodedIndex> {
+    let mut reader = IndexReader::new(data);
+
+    if reader.read_u32()? != INDEX_FORMAT_VERSION as usize {
+        return Err("unsupported index format version".into());
+    }
+
+    if reader.read_u64()? != fingerprint {
+        return Err("index fingerprint mismatch".into());
+    }
+
+    if reader.read_u32()? != jobs.len() {
+        return Err("amount of jobs mismatch".into());
+    }
+
+    let cluster_epsilon = reader.read_float()?;
+
+    let mut groups = vec![];
+    for _ in 0..reader.read_u32()? {
+        let mut neighbours = JobNeighbours::with_capacity(jobs.len());
+        for job in jobs.iter() {
+            let size = reader.read_u32()?;
+            if size > jobs.len() {
+                return Err("amount of job neighbours is out of range".into());
+            }
+
+            let mut job_neighbours = Vec::with_capacity(size);
+            for _ in 0..size {
+                let other = jobs[reader.read_index(jobs.len())?].clone();
+                job_neighbours.push((other, reader.read_f32()?));
+            }
+            neighbours.insert(job.clone(), job_neighbours);
+        }
+        groups.push(Arc::new(neighbours));
+    }
+
+    let mut index = HashMap::new();
+    for _ in 0..reader.read_u32()? {
+        let profile_idx = reader.read_u32()?;
+        let neighbours = groups[reader.read_index(groups.len())?].clone();
+        let mut ranks = HashMap::with_capacity(jobs.len());
+        for job in jobs.iter() {
+            ranks.insert(job.clone(), reader.read_f32()?);
+        }
+        index.insert(profile_idx, JobIndex { neighbours, ranks });
+    }
+
+    let mut clusters = vec![];
+    for _ in 0..reader.read_u32()? {
+        let mut cluster = HashSet::new();
+        for _ in 0..reader.read_u32()? {
+            cluster.insert(jobs[reader.read_index(jobs.len())?].clone());
+        }
+        clusters.push(cluster);
+    }
+
+    if !reader.is_empty() {
+        return Err("unexpected index data at the end".into());
+    }
+
+    Ok((index, clusters, cluster_epsilon))
+}
+
+/// Applies update function once to each unique neighbourhood shared by one or many profiles.
+fn update_neighbours<F>(index: &mut HashMap<usize, JobIndex>, fleet: &Fleet, update_fn: F)
+where
+    F: Fn(&Profile, &mut JobNeighbours),
+{
+    // NOTE detach shared neighbourhood from profiles to avoid its copy on write
+    let groups = fleet.profiles.iter().fold(Vec::<(Arc<JobNeighbours>, Vec<&Profile>)>::new(), |mut acc, profile| {
+        if let Some(index) = index.get_mut(&profile.index) {
+            let neighbours = std::mem::take(&mut index.neighbours);
+            match acc.iter_mut().find(|(known, _)| Arc::ptr_eq(known, &neighbours)) {
+                Some((_, profiles)) => profiles.push(profile),
+                None => acc.push((neighbours, vec![profile])),
+            }
+        }
+
+        acc
+    });
+
+    groups.into_iter().for_each(|(mut neighbours, profiles)| {
+        update_fn(profiles[0], Arc::make_mut(&mut neighbours));
+
+        profiles.into_iter().for_each(|profile| {
+            if let Some(index) = index.get_mut(&profile.index) {
+                index.neighbours = neighbours.clone();
+            }
+        });
+    });
+}
+
+/// Returns all possible start positions for given profile.
+fn get_profile_starts(fleet: &Fleet, profile: &Profile) -> Vec<Location> {
+    fleet
+        .vehicles
+        .iter()
+        .filter(|v| v.profile.index == profile.index)
+        .flat_map(|v| v.details.iter().map(|d| d.start.as_ref().map(|s| s.location)))
+        .flatten()
+        .collect()
+}
+
+/// Returns job rank as min cost from any of start positions.
+fn get_job_rank(
+    profile: &Profile,
+    avg_costs: &Costs,
+    transport: &dyn TransportCost,
+    starts: &[Location],
+    job: &Job,
+) -> LowPrecisionCost {
+    starts
+        .iter()
+        .cloned()
+        .map(|s| get_cost_between_job_and_location(profile, avg_costs, transport, job, s))
+        .min_by(|a, b| a.partial_cmp(b).unwrap_or(Less))
+        .unwrap_or(DEFAULT_COST)
+}
+
+/// Creates sorted neighbours of each job for given profile.
+fn create_job_neighbours(
+    profile: &Profile,
+    avg_costs: &Costs,
+    jobs: &[Job],
+    transport: &dyn TransportCost,
+    spatial_index: Option<&SpatialIndex>,
+    location_jobs: &HashMap<Location, Vec<&Job>>,
+) -> JobNeighbours {
+    parallel_collect(jobs, |job| {
+        // NOTE with approximate index or sparse routing data, only some candidates are considered
+        let candidates = spatial_index
+            .and_then(|spatial_index| spatial_index.get_candidates(job))
+            .or_else(|| get_sparse_candidates(profile, transport, job, location_jobs));
+        let candidates = match candidates {
+            Some(candidates) => Either::Left(candidates.into_iter()),
+            None => Either::Right(jobs.iter()),
+        };
+
+        let mut sorted_job_costs: Vec<(Job, LowPrecisionCost)> = candidates
+            .filter(|j| **j != *job)
+            .map(|j| (j.clone(), get_cost_between_jobs(profile, avg_costs, transport, job, j)))
+            .collect();
+        sorted_job_costs.sort_unstable_by(|(_, a), (_, b)| a.total_cmp(b));
+
+        sorted_job_costs.truncate(MAX_NEIGHBOURS);
+        sorted_job_costs.shrink_to_fit();
+
+        (job.clone(), sorted_job_costs)
+    })
+    .into_iter()
+    .collect()
+}
+
+/// Groups jobs by their locations.
+fn get_location_jobs(jobs: &[Job]) -> HashMap<Location, Vec<&Job>> {
+    jobs.iter().fold(HashMap::new(), |mut acc, job| {
+        get_job_locations(job).flatten().collect::<HashSet<_>>().into_iter().for_each(|location| {
+            acc.entry(location).or_insert_with(Vec::new).push(job);
+        });
+
+        acc
+    })
+}
+
+/// Returns jobs located at sparse neighbour locations of the given job or `None` if routing data
+/// is dense for any of job's locations.
+fn get_sparse_candidates<'a>(
+    profile: &Profile,
+    transport: &dyn TransportCost,
+    job: &Job,
+    location_jobs: &HashMap<Location, Vec<&'a Job>>,
+) -> Option<HashSet<&'a Job>> {
+    let locations = get_job_locations(job).collect::<Option<Vec<_>>>().filter(|locations| !locations.is_empty())?;
+
+    locations.into_iter().try_fold(HashSet::new(), |mut acc, location| {
+        let neighbours = transport.sparse_neighbours(profile, location)?;
+        acc.extend(neighbours.iter().filter_map(|to| location_jobs.get(to)).flatten().copied());
+
+        Some(acc)
+    })
+}
+
 fn get_cost_between_locations(
     profile: &Profile,
     costs: &Costs,
@@ -394,8 +1136,9 @@ fn get_cost_between_locations(
     let distance = transport.distance_approx(profile, from, to);
     let duration = transport.duration_approx(profile, from, to);
 
-    if distance < 0. || duration < 0. {
+    if distance < 0. || duration < 0. || !distance.is_finite() || !duration.is_finite() {
         // NOTE this happens if matrix uses negative values as a marker of unreachable location
+        // or routing data is sparse and its fallback cannot estimate the value
         UNREACHABLE_COST
     } else {
         (distance * costs.per_distance + duration * costs.per_driving_time) as LowPrecisionCost
@@ -9,5 +9,8 @@ pub use self::costs::*;
 mod fleet;
 pub use self::fleet::*;
 
+mod index_cache;
+pub use self::index_cache::{FileJobIndexCache, JobIndexCache};
+
 mod jobs;
 pub use self::jobs::*;
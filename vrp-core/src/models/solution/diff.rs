@@ -0,0 +1,110 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/models/solution/diff_test.rs"]
+mod diff_test;
+
+use crate::construction::heuristics::InsertionContext;
+use crate::models::common::Timestamp;
+use crate::models::problem::{Actor, Job};
+use crate::models::{Problem, Solution};
+use rosomaxa::prelude::{Environment, Float};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Specifies a job which is assigned differently in two solutions.
+pub struct JobMove {
+    /// A moved job.
+    pub job: Job,
+    /// An actor which serves the job in the old solution. None means that the job is not assigned.
+    pub from: Option<Arc<Actor>>,
+    /// An actor which serves the job in the new solution. None means that the job is not assigned.
+    pub to: Option<Arc<Actor>>,
+}
+
+/// Specifies a job which is served by the same actor in both solutions, but at a different time.
+pub struct JobTimeShift {
+    /// A shifted job.
+    pub job: Job,
+    /// An actor which serves the job in both solutions.
+    pub actor: Arc<Actor>,
+    /// An arrival time at the job's first activity in the old solution.
+    pub old_arrival: Timestamp,
+    /// An arrival time at the job's first activity in the new solution.
+    pub new_arrival: Timestamp,
+}
+
+/// A structured difference between two solutions of the same problem.
+pub struct SolutionDiff {
+    /// Jobs moved between tours, including jobs which became assigned or unassigned.
+    pub moved: Vec<JobMove>,
+    /// Jobs which kept their tour, but changed their arrival time.
+    pub shifted: Vec<JobTimeShift>,
+    /// Fitness values of the old solution in the order of problem's goal objectives.
+    pub old_fitness: Vec<Float>,
+    /// Fitness values of the new solution in the order of problem's goal objectives.
+    pub new_fitness: Vec<Float>,
+}
+
+impl SolutionDiff {
+    /// Compares two solutions of the same problem. Time shifts are detected using schedules stored
+    /// in the solutions, while fitness values are recalculated using problem's goal.
+    pub fn new(problem: Arc<Problem>, old: &Solution, new: &Solution) -> Self {
+        let old_jobs = get_assigned_jobs(old);
+        let new_jobs = get_assigned_jobs(new);
+
+        let (moved, shifted) = problem.jobs.all().iter().fold((vec![], vec![]), |(mut moved, mut shifted), job| {
+            match (old_jobs.get(job), new_jobs.get(job)) {
+                (None, None) => {}
+                (Some((old_actor, old_arrival)), Some((new_actor, new_arrival)))
+                    if Arc::ptr_eq(old_actor, new_actor) =>
+                {
+                    if old_arrival != new_arrival {
+                        shifted.push(JobTimeShift {
+                            job: job.clone(),
+                            actor: old_actor.clone(),
+                            old_arrival: *old_arrival,
+                            new_arrival: *new_arrival,
+                        })
+                    }
+                }
+                (from, to) => moved.push(JobMove {
+                    job: job.clone(),
+                    from: from.map(|(actor, _)| actor.clone()),
+                    to: to.map(|(actor, _)| actor.clone()),
+                }),
+            }
+
+            (moved, shifted)
+        });
+
+        Self { moved, shifted, old_fitness: get_fitness(problem.clone(), old), new_fitness: get_fitness(problem, new) }
+    }
+
+    /// Returns fitness delta (new minus old) for each objective of the problem's goal.
+    pub fn fitness_delta(&self) -> impl Iterator<Item = Float> + '_ {
+        self.old_fitness.iter().zip(self.new_fitness.iter()).map(|(old, new)| new - old)
+    }
+
+    /// Returns true if solutions have the same assignment, schedule and fitness.
+    pub fn is_empty(&self) -> bool {
+        self.moved.is_empty() && self.shifted.is_empty() && self.fitness_delta().all(|delta| delta == 0.)
+    }
+}
+
+fn get_assigned_jobs(solution: &Solution) -> HashMap<Job, (Arc<Actor>, Timestamp)> {
+    solution.routes.iter().fold(HashMap::new(), |mut acc, route| {
+        route.tour.all_activities().for_each(|activity| {
+            if let Some(job) = activity.retrieve_job() {
+                acc.entry(job).or_insert_with(|| (route.actor.clone(), activity.schedule.arrival));
+            }
+        });
+
+        acc
+    })
+}
+
+fn get_fitness(problem: Arc<Problem>, solution: &Solution) -> Vec<Float> {
+    let insertion_ctx =
+        InsertionContext::new_from_solution(problem, (solution.deep_copy(), None), Arc::new(Environment::default()));
+
+    insertion_ctx.problem.goal.fitness(&insertion_ctx).collect()
+}
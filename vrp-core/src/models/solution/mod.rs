@@ -1,5 +1,8 @@
 //! Solution domain models.
 
+mod diff;
+pub use self::diff::{JobMove, JobTimeShift, SolutionDiff};
+
 mod route;
 pub use self::route::{Activity, Commute, CommuteInfo, Place, Route};
 
@@ -4,6 +4,7 @@ mod actor_test;
 
 use crate::models::problem::{Actor, Fleet};
 use rosomaxa::prelude::Random;
+use rosomaxa::utils::is_deterministic_mode;
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
@@ -46,16 +47,37 @@ impl Registry {
 
     /// Returns list of all available actors.
     pub fn available(&'_ self) -> impl Iterator<Item = Arc<Actor>> + '_ {
-        self.available.iter().flat_map(|(_, set)| set.iter().cloned())
+        self.available.values().flat_map(|set| set.iter().cloned())
     }
 
     /// Returns next available actors from each different type.
     pub fn next(&'_ self) -> impl Iterator<Item = Arc<Actor>> + '_ {
-        self.available.iter().flat_map(move |(_, set)| {
-            // NOTE pick a random actor from set of available actors.
-            let skip_amount = if set.len() < 2 { 0 } else { self.random.uniform_int(0, set.len() as i32 - 1) as usize };
-            set.iter().skip(skip_amount).take(1).cloned()
-        })
+        let get_skip_amount = |set: &HashSet<Arc<Actor>>| {
+            if set.len() < 2 { 0 } else { self.random.uniform_int(0, set.len() as i32 - 1) as usize }
+        };
+
+        if is_deterministic_mode() {
+            // NOTE hash set iteration order depends on actor addresses, so use fleet order as a canonical one
+            let mut groups = self.available.iter().filter(|(_, set)| !set.is_empty()).collect::<Vec<_>>();
+            groups.sort_by_key(|(idx, _)| **idx);
+
+            groups
+                .into_iter()
+                .filter_map(|(_, set)| {
+                    let skip_amount = get_skip_amount(set);
+                    self.all.iter().filter(|actor| set.contains(*actor)).nth(skip_amount).cloned()
+                })
+                .collect::<Vec<_>>()
+        } else {
+            self.available
+                .values()
+                .flat_map(|set| {
+                    // NOTE pick a random actor from set of available actors.
+                    set.iter().skip(get_skip_amount(set)).take(1).cloned()
+                })
+                .collect::<Vec<_>>()
+        }
+        .into_iter()
     }
 
     /// Creates a deep copy of registry.
@@ -6,8 +6,10 @@ use crate::models::OP_START_MSG;
 use crate::models::common::Schedule;
 use crate::models::problem::{Actor, Job, JobIdDimension};
 use crate::models::solution::{Activity, Place};
-use crate::utils::{Either, short_type_name};
+use crate::utils::{AllocationPool, Either, release_pooled, short_type_name, take_pooled};
+use rosomaxa::utils::is_deterministic_mode;
 use rustc_hash::FxHasher;
+use std::cell::RefCell;
 use std::collections::HashSet;
 use std::fmt::{Debug, Formatter};
 use std::hash::BuildHasherDefault;
@@ -18,6 +20,14 @@ use std::slice::{Iter, IterMut};
 /// A tour leg.
 pub type Leg<'a> = (&'a [Activity], usize);
 
+type JobSet = HashSet<Job, BuildHasherDefault<FxHasher>>;
+
+thread_local! {
+    /// Keeps allocations of dropped tours to reuse them in deep copies.
+    static ACTIVITIES_POOL: RefCell<AllocationPool<Vec<Activity>>> = RefCell::new(AllocationPool::default());
+    static JOBS_POOL: RefCell<AllocationPool<JobSet>> = RefCell::new(AllocationPool::default());
+}
+
 /// Represents a tour, a smart container for jobs with their associated activities.
 #[derive(Default)]
 pub struct Tour {
@@ -25,7 +35,7 @@ pub struct Tour {
     activities: Vec<Activity>,
 
     /// Stores jobs in the order of their activities added.
-    jobs: HashSet<Job, BuildHasherDefault<FxHasher>>,
+    jobs: JobSet,
 
     /// Keeps track whether tour is set as closed.
     is_closed: bool,
@@ -138,7 +148,19 @@ impl Tour {
 
     /// Returns all jobs.
     pub fn jobs(&'_ self) -> impl Iterator<Item = &Job> + '_ {
-        self.jobs.iter()
+        if is_deterministic_mode() {
+            // NOTE hash set iteration order depends on job addresses, so use the order of activities instead
+            let mut visited = HashSet::<&Job, BuildHasherDefault<FxHasher>>::default();
+            Either::Left(
+                self.activities
+                    .iter()
+                    .filter_map(|activity| activity.retrieve_job())
+                    .filter_map(|job| self.jobs.get(&job))
+                    .filter(move |job| visited.insert(*job)),
+            )
+        } else {
+            Either::Right(self.jobs.iter())
+        }
     }
 
     /// Returns activity by its index in tour.
@@ -207,11 +229,30 @@ impl Tour {
     }
 
     /// Creates a copy of existing tour deeply copying all activities and jobs.
+    /// NOTE allocations of dropped tours are reused, see [`next_pool_generation`](crate::utils::next_pool_generation).
     pub fn deep_copy(&self) -> Tour {
-        Tour {
-            activities: self.activities.iter().map(|a| a.deep_copy()).collect(),
-            jobs: self.jobs.clone(),
-            is_closed: self.is_closed,
+        let mut activities = take_pooled(&ACTIVITIES_POOL);
+        activities.extend(self.activities.iter().map(|a| a.deep_copy()));
+
+        let mut jobs = take_pooled(&JOBS_POOL);
+        jobs.clone_from(&self.jobs);
+
+        Tour { activities, jobs, is_closed: self.is_closed }
+    }
+}
+
+impl Drop for Tour {
+    fn drop(&mut self) {
+        if self.activities.capacity() > 0 {
+            let mut activities = std::mem::take(&mut self.activities);
+            activities.clear();
+            release_pooled(&ACTIVITIES_POOL, activities);
+        }
+
+        if self.jobs.capacity() > 0 {
+            let mut jobs = std::mem::take(&mut self.jobs);
+            jobs.clear();
+            release_pooled(&JOBS_POOL, jobs);
         }
     }
 }
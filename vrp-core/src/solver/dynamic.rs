@@ -0,0 +1,237 @@
+//! Provides the way to insert new jobs into an existing solution with a bounded re-optimization,
+//! e.g. when new orders arrive while vehicles are already dispatched.
+
+#[cfg(test)]
+#[path = "../../tests/unit/solver/dynamic_test.rs"]
+mod dynamic_test;
+
+use crate::construction::heuristics::*;
+use crate::models::problem::{Actor, Job, sort_jobs_canonically};
+use crate::models::{Problem, Solution};
+use rand::prelude::SliceRandom;
+use rosomaxa::prelude::*;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+/// Represents changes of a single tour caused by dynamic job insertion.
+pub struct TourChange {
+    /// An actor which serves the tour.
+    pub actor: Arc<Actor>,
+    /// Jobs which were added to the tour.
+    pub added: Vec<Job>,
+    /// Jobs which were removed from the tour.
+    pub removed: Vec<Job>,
+}
+
+/// Represents a result of dynamic job insertion.
+pub struct DynamicSolution {
+    /// A new solution.
+    pub solution: Solution,
+    /// Changed tours only: tours which are not listed here are kept as they were. Changes are ordered
+    /// by actor index in the fleet, added and removed jobs are ordered by job id.
+    pub changes: Vec<TourChange>,
+}
+
+/// Inserts new jobs into an existing solution and re-optimizes only tours affected by insertion,
+/// so that other tours are not perturbed. Jobs locked by the problem (e.g. already executed part
+/// of the tours) are never moved.
+///
+/// A problem is expected to be created from the original one using [`Problem::with_added_jobs`]:
+/// all jobs which are neither assigned nor unassigned in the original solution are considered as new.
+pub struct DynamicSolver {
+    problem: Arc<Problem>,
+    environment: Arc<Environment>,
+    max_iterations: usize,
+    max_removed_jobs: usize,
+}
+
+impl DynamicSolver {
+    /// Creates a new instance of `DynamicSolver` with default re-optimization limits.
+    pub fn new(problem: Arc<Problem>, environment: Arc<Environment>) -> Self {
+        Self { problem, environment, max_iterations: 100, max_removed_jobs: 4 }
+    }
+
+    /// Sets the maximum amount of ruin and recreate iterations applied to affected tours.
+    /// Zero means that new jobs are only inserted without further re-optimization.
+    pub fn with_max_iterations(mut self, max_iterations: usize) -> Self {
+        self.max_iterations = max_iterations;
+        self
+    }
+
+    /// Sets the maximum amount of jobs removed from affected tours on each iteration.
+    pub fn with_max_removed_jobs(mut self, max_removed_jobs: usize) -> Self {
+        self.max_removed_jobs = max_removed_jobs.max(1);
+        self
+    }
+
+    /// Inserts new jobs into given solution and returns a new solution within tour changes.
+    pub fn solve(&self, solution: Solution) -> GenericResult<DynamicSolution> {
+        let known_jobs = solution
+            .routes
+            .iter()
+            .flat_map(|route| route.tour.jobs())
+            .chain(solution.unassigned.iter().map(|(job, _)| job))
+            .cloned()
+            .collect::<HashSet<_>>();
+
+        let new_jobs =
+            self.problem.jobs.all().iter().filter(|job| !known_jobs.contains(*job)).cloned().collect::<Vec<_>>();
+
+        if new_jobs.is_empty() {
+            return Err("solution has no new jobs to insert".into());
+        }
+
+        let original_tours = get_tour_jobs(solution.routes.iter().map(|route| (&route.actor, route.tour.jobs())));
+
+        let mut insertion_ctx =
+            InsertionContext::new_from_solution(self.problem.clone(), (solution, None), self.environment.clone());
+        insertion_ctx.solution.required.extend(new_jobs.iter().cloned());
+
+        let mut insertion_ctx = self.recreate(insertion_ctx, &AllRouteSelector::default());
+
+        let new_jobs = new_jobs.into_iter().collect::<HashSet<_>>();
+        (0..self.max_iterations).for_each(|_| {
+            let affected_actors = get_affected_actors(&insertion_ctx, &original_tours);
+            let candidate = self.ruin(insertion_ctx.deep_copy(), &affected_actors, &new_jobs);
+            let candidate = self.recreate(candidate, &AffectedRouteSelector { actors: affected_actors });
+
+            if self.problem.goal.total_order(&candidate, &insertion_ctx) == Ordering::Less {
+                insertion_ctx = candidate;
+            }
+        });
+
+        let changes = get_tour_changes(&insertion_ctx, &original_tours);
+
+        Ok(DynamicSolution { solution: Solution::from((insertion_ctx, None)), changes })
+    }
+
+    fn ruin(
+        &self,
+        mut insertion_ctx: InsertionContext,
+        affected_actors: &HashSet<Arc<Actor>>,
+        new_jobs: &HashSet<Job>,
+    ) -> InsertionContext {
+        let random = self.environment.random.as_ref();
+        let solution = &mut insertion_ctx.solution;
+
+        // NOTE give new jobs which were not inserted one more chance
+        let unassigned = solution.unassigned.keys().filter(|job| new_jobs.contains(*job)).cloned().collect::<Vec<_>>();
+        unassigned.into_iter().for_each(|job| {
+            solution.unassigned.remove(&job);
+            solution.required.push(job);
+        });
+
+        let mut candidates = solution
+            .routes
+            .iter()
+            .filter(|route_ctx| affected_actors.contains(&route_ctx.route().actor))
+            .flat_map(|route_ctx| {
+                route_ctx.route().tour.jobs().map(|job| (route_ctx.route().actor.clone(), job.clone()))
+            })
+            .filter(|(_, job)| !solution.locked.contains(job))
+            .collect::<Vec<_>>();
+        candidates.shuffle(&mut random.get_rng());
+
+        let amount = random.uniform_int(1, self.max_removed_jobs as i32) as usize;
+        candidates.into_iter().take(amount).for_each(|(actor, job)| {
+            let route_ctx = solution.routes.iter_mut().find(|route_ctx| route_ctx.route().actor == actor);
+            if route_ctx.is_some_and(|route_ctx| route_ctx.route_mut().tour.remove(&job)) {
+                solution.required.push(job);
+            }
+        });
+
+        insertion_ctx.restore();
+
+        insertion_ctx
+    }
+
+    fn recreate(&self, insertion_ctx: InsertionContext, route_selector: &dyn RouteSelector) -> InsertionContext {
+        InsertionHeuristic::default().process(
+            insertion_ctx,
+            &AllJobSelector::default(),
+            route_selector,
+            &LegSelection::Exhaustive,
+            &BestResultSelector::default(),
+        )
+    }
+}
+
+/// Selects only routes of affected actors and a new route if fleet allows it.
+struct AffectedRouteSelector {
+    actors: HashSet<Arc<Actor>>,
+}
+
+impl RouteSelector for AffectedRouteSelector {
+    fn select<'a>(
+        &'a self,
+        insertion_ctx: &'a InsertionContext,
+        _: &[&'a Job],
+    ) -> Box<dyn Iterator<Item = &'a RouteContext> + 'a> {
+        Box::new(
+            insertion_ctx
+                .solution
+                .routes
+                .iter()
+                .filter(|route_ctx| self.actors.contains(&route_ctx.route().actor))
+                .chain(insertion_ctx.solution.registry.next_route()),
+        )
+    }
+}
+
+type TourJobs = HashMap<Arc<Actor>, HashSet<Job>>;
+
+fn get_tour_jobs<'a, I>(tours: impl Iterator<Item = (&'a Arc<Actor>, I)>) -> TourJobs
+where
+    I: Iterator<Item = &'a Job>,
+{
+    tours.map(|(actor, jobs)| (actor.clone(), jobs.cloned().collect())).collect()
+}
+
+fn get_affected_actors(insertion_ctx: &InsertionContext, original_tours: &TourJobs) -> HashSet<Arc<Actor>> {
+    get_tour_changes(insertion_ctx, original_tours).into_iter().map(|change| change.actor).collect()
+}
+
+fn get_tour_changes(insertion_ctx: &InsertionContext, original_tours: &TourJobs) -> Vec<TourChange> {
+    let empty = HashSet::default();
+    let current_tours = get_tour_jobs(
+        insertion_ctx.solution.routes.iter().map(|route_ctx| (&route_ctx.route().actor, route_ctx.route().tour.jobs())),
+    );
+
+    let actor_indices = insertion_ctx
+        .problem
+        .fleet
+        .actors
+        .iter()
+        .enumerate()
+        .map(|(idx, actor)| (actor.clone(), idx))
+        .collect::<HashMap<_, _>>();
+
+    let mut changes = insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .map(|route_ctx| &route_ctx.route().actor)
+        .chain(original_tours.keys().filter(|actor| !current_tours.contains_key(*actor)))
+        .filter_map(|actor| {
+            let original = original_tours.get(actor).unwrap_or(&empty);
+            let current = current_tours.get(actor).unwrap_or(&empty);
+
+            let mut added = current.difference(original).cloned().collect::<Vec<_>>();
+            let mut removed = original.difference(current).cloned().collect::<Vec<_>>();
+            sort_jobs_canonically(&mut added);
+            sort_jobs_canonically(&mut removed);
+
+            if added.is_empty() && removed.is_empty() {
+                None
+            } else {
+                Some(TourChange { actor: actor.clone(), added, removed })
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // NOTE tours and jobs are kept in hash based collections, so sort changes to have a stable output
+    changes.sort_by_key(|change| actor_indices.get(&change.actor).copied().unwrap_or(usize::MAX));
+
+    changes
+}
@@ -0,0 +1,50 @@
+#[cfg(test)]
+#[path = "../../tests/unit/solver/error_test.rs"]
+mod error_test;
+
+use rosomaxa::prelude::GenericError;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// Specifies an error returned when problem cannot be solved.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SolverError {
+    /// Search cannot be started or has failed, e.g. because of invalid configuration.
+    Search(GenericError),
+    /// Search has finished without any solution found.
+    NoSolution,
+}
+
+impl SolverError {
+    /// Returns an error code which is stable across releases.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Search(_) => "E3000",
+            Self::NoSolution => "E3001",
+        }
+    }
+}
+
+impl Display for SolverError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Search(err) => write!(f, "{err}"),
+            Self::NoSolution => write!(f, "cannot find any solution"),
+        }
+    }
+}
+
+impl Error for SolverError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Search(err) => Some(err),
+            Self::NoSolution => None,
+        }
+    }
+}
+
+impl From<SolverError> for GenericError {
+    fn from(value: SolverError) -> Self {
+        value.to_string().into()
+    }
+}
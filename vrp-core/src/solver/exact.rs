@@ -0,0 +1,247 @@
+//! Provides the way to solve tiny problems exactly using branch and bound search over job sequences.
+
+#[cfg(test)]
+#[path = "../../tests/unit/solver/exact_test.rs"]
+mod exact_test;
+
+use crate::construction::heuristics::*;
+use crate::models::problem::{Actor, Job};
+use crate::models::{Problem, Solution};
+use rosomaxa::prelude::*;
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+/// Default maximum amount of jobs which are sequenced exactly.
+pub const DEFAULT_EXACT_MAX_JOBS: usize = 10;
+
+/// Default maximum amount of search nodes explored by exact search.
+const DEFAULT_EXACT_MAX_NODES: usize = 200_000;
+
+/// Solves tiny problems (a single actor and a few single jobs) exactly using depth-first branch and
+/// bound search: jobs are appended to the route one by one and infeasible prefixes are cut. When cost
+/// is the sole objective of the goal, prefixes which cannot assign more jobs than the best known
+/// solution with lower cost are pruned too.
+///
+/// The search is exact when route cost does not decrease when a job is appended, e.g. routing
+/// matrix satisfies triangle inequality, and the search is completed within the nodes limit.
+/// Otherwise, no solution is returned.
+pub struct ExactSolver {
+    problem: Arc<Problem>,
+    environment: Arc<Environment>,
+    max_nodes: usize,
+}
+
+impl ExactSolver {
+    /// Creates a new instance of `ExactSolver` with default search limits.
+    pub fn new(problem: Arc<Problem>, environment: Arc<Environment>) -> Self {
+        Self { problem, environment, max_nodes: DEFAULT_EXACT_MAX_NODES }
+    }
+
+    /// Sets the maximum amount of search nodes.
+    pub fn with_max_nodes(mut self, max_nodes: usize) -> Self {
+        self.max_nodes = max_nodes.max(1);
+        self
+    }
+
+    /// Checks whether the problem is tiny enough to be solved exactly: it has a single actor,
+    /// no job locks and at most `DEFAULT_EXACT_MAX_JOBS` jobs which are all single.
+    pub fn is_applicable(problem: &Problem) -> bool {
+        problem.fleet.actors.len() == 1
+            && problem.locks.is_empty()
+            && problem.jobs.size() <= DEFAULT_EXACT_MAX_JOBS
+            && problem.jobs.all().iter().all(|job| job.as_single().is_some())
+    }
+
+    /// Solves the problem exactly. Returns None if the problem is not applicable, has conditional
+    /// jobs (e.g. breaks or reloads) which cannot be sequenced by exact search or the search is not
+    /// completed within the nodes limit.
+    pub fn solve(&self) -> Option<Solution> {
+        if !Self::is_applicable(self.problem.as_ref()) {
+            return None;
+        }
+
+        let mut insertion_ctx = InsertionContext::new(self.problem.clone(), self.environment.clone());
+        if !insertion_ctx.solution.ignored.is_empty() {
+            return None;
+        }
+
+        let actor = self.problem.fleet.actors.first()?.clone();
+        // NOTE all jobs are promoted to unassigned when insertion context is created
+        insertion_ctx.solution.unassigned.clear();
+        let jobs = self.problem.jobs.all().to_vec();
+
+        let (insertion_ctx, is_completed) = search_exactly(insertion_ctx, &actor, jobs, self.max_nodes)?;
+
+        if !is_completed {
+            (self.environment.logger)("exact search is stopped before completion, solution is discarded");
+            return None;
+        }
+
+        (self.environment.logger)("exact search is completed");
+
+        Some(Solution::from((insertion_ctx, None)))
+    }
+}
+
+/// Re-sequences jobs of the route served by given actor using exact search. Returns a new solution
+/// only if it is better than the original one.
+pub(crate) fn sequence_route_exactly(
+    insertion_ctx: &InsertionContext,
+    actor: &Arc<Actor>,
+    max_jobs: usize,
+    max_nodes: usize,
+) -> Option<InsertionContext> {
+    let solution = &insertion_ctx.solution;
+    if !solution.ignored.is_empty() {
+        return None;
+    }
+
+    let route_idx = solution.routes.iter().position(|route_ctx| route_ctx.route().actor == *actor)?;
+    let jobs = solution.routes[route_idx].route().tour.jobs().cloned().collect::<Vec<_>>();
+
+    if jobs.len() < 2
+        || jobs.len() > max_jobs
+        || jobs.iter().any(|job| job.as_single().is_none() || solution.locked.contains(job))
+    {
+        return None;
+    }
+
+    let mut new_insertion_ctx = insertion_ctx.deep_copy();
+    let route_ctx = new_insertion_ctx.solution.routes.remove(route_idx);
+    new_insertion_ctx.solution.registry.free_route(route_ctx);
+
+    let (new_insertion_ctx, _) = search_exactly(new_insertion_ctx, actor, jobs, max_nodes)?;
+
+    let is_better = insertion_ctx.problem.goal.total_order(&new_insertion_ctx, insertion_ctx) == Ordering::Less;
+
+    is_better.then_some(new_insertion_ctx)
+}
+
+/// Keeps the best known leaf of the search tree.
+struct BestLeaf {
+    insertion_ctx: InsertionContext,
+    assigned: usize,
+    cost: Float,
+}
+
+struct SearchContext<'a> {
+    actor: &'a Arc<Actor>,
+    max_nodes: usize,
+    nodes: usize,
+    is_stopped: bool,
+    best: Option<BestLeaf>,
+}
+
+/// Runs branch and bound search which appends given jobs to the route of the actor. Returns the best
+/// found solution and a flag whether the search space was explored completely.
+fn search_exactly(
+    mut insertion_ctx: InsertionContext,
+    actor: &Arc<Actor>,
+    jobs: Vec<Job>,
+    max_nodes: usize,
+) -> Option<(InsertionContext, bool)> {
+    let mut route_ctx = insertion_ctx.solution.registry.get_route(actor)?;
+    insertion_ctx.problem.goal.accept_route_state(&mut route_ctx);
+    insertion_ctx.solution.routes.push(route_ctx);
+    insertion_ctx.solution.required.extend(jobs.iter().cloned());
+
+    let mut search_ctx = SearchContext { actor, max_nodes, nodes: 0, is_stopped: false, best: None };
+    search_node(&mut search_ctx, insertion_ctx, jobs);
+
+    let is_completed = !search_ctx.is_stopped;
+
+    search_ctx.best.map(|best| (best.insertion_ctx, is_completed))
+}
+
+fn search_node(search_ctx: &mut SearchContext, insertion_ctx: InsertionContext, remaining: Vec<Job>) {
+    search_ctx.nodes += 1;
+
+    let route_ctx = insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .find(|route_ctx| route_ctx.route().actor == *search_ctx.actor)
+        .expect("route should be present in the solution");
+    let assigned = route_ctx.route().tour.job_count();
+    let cost = insertion_ctx.get_total_cost().unwrap_or(Float::MAX);
+
+    // NOTE the prefix cannot be extended to a better solution when even all remaining jobs are assigned,
+    // but only cost is assumed to be monotonic, so other objectives cannot be used for pruning
+    let is_cost_only = is_cost_sole_objective(&insertion_ctx, cost);
+    if is_cost_only
+        && search_ctx.best.as_ref().is_some_and(|best| best.assigned >= assigned + remaining.len() && best.cost <= cost)
+    {
+        return;
+    }
+
+    let mut successes = remaining
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, job)| {
+            let eval_ctx = EvaluationContext {
+                goal: insertion_ctx.problem.goal.as_ref(),
+                job,
+                leg_selection: &LegSelection::Exhaustive,
+                result_selector: &BestResultSelector::default(),
+            };
+
+            let result = eval_job_insertion_in_route(
+                &insertion_ctx,
+                &eval_ctx,
+                route_ctx,
+                InsertionPosition::Last,
+                InsertionResult::make_failure(),
+            );
+
+            InsertionSuccess::try_from(result).ok().map(|success| (idx, success))
+        })
+        .collect::<Vec<_>>();
+
+    if successes.is_empty() {
+        accept_leaf(search_ctx, insertion_ctx, assigned);
+        return;
+    }
+
+    // NOTE explore the cheapest extensions first to get a good upper bound early
+    successes.sort_by(|(_, a), (_, b)| a.cost.cmp(&b.cost));
+
+    for (idx, success) in successes {
+        let is_quota_reached = insertion_ctx.environment.quota.as_ref().is_some_and(|quota| quota.is_reached());
+        if search_ctx.nodes >= search_ctx.max_nodes || is_quota_reached {
+            search_ctx.is_stopped = true;
+            break;
+        }
+
+        let mut child_ctx = insertion_ctx.deep_copy();
+        apply_insertion_success(&mut child_ctx, success);
+
+        let mut child_remaining = remaining.clone();
+        child_remaining.remove(idx);
+
+        search_node(search_ctx, child_ctx, child_remaining);
+    }
+}
+
+/// Checks whether the goal has a single objective which is the total cost of the solution.
+fn is_cost_sole_objective(insertion_ctx: &InsertionContext, cost: Float) -> bool {
+    let mut fitness = insertion_ctx.problem.goal.fitness(insertion_ctx);
+
+    match (fitness.next(), fitness.next()) {
+        (Some(value), None) => (value - cost).abs() <= 1E-6 * cost.abs().max(1.),
+        _ => false,
+    }
+}
+
+fn accept_leaf(search_ctx: &mut SearchContext, mut insertion_ctx: InsertionContext, assigned: usize) {
+    insertion_ctx.restore();
+    finalize_insertion_ctx(&mut insertion_ctx);
+
+    let is_better = search_ctx.best.as_ref().is_none_or(|best| {
+        insertion_ctx.problem.goal.total_order(&insertion_ctx, &best.insertion_ctx) == Ordering::Less
+    });
+
+    if is_better {
+        let cost = insertion_ctx.get_total_cost().unwrap_or(Float::MAX);
+        search_ctx.best = Some(BestLeaf { insertion_ctx, assigned, cost });
+    }
+}
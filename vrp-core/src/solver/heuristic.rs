@@ -1,8 +1,13 @@
+#[cfg(test)]
+#[path = "../../tests/unit/solver/heuristic_test.rs"]
+mod heuristic_test;
+
 use super::*;
 use crate::construction::heuristics::*;
 use crate::models::common::FootprintSolutionState;
 use crate::models::{Extras, GoalContext};
 use crate::rosomaxa::get_default_selection_size;
+use crate::solver::processing::ClusterConfigExtraProperty;
 use crate::solver::search::*;
 use rosomaxa::algorithms::gsom::Input;
 use rosomaxa::hyper::*;
@@ -124,12 +129,134 @@ pub fn get_default_heuristic(problem: Arc<Problem>, environment: Arc<Environment
     )
 }
 
+/// Provides the way to configure ruin and recreate operators used by static heuristic: operators are
+/// identified by name and can be added, removed, reweighted or replaced with differently parameterized ones.
+/// Each ruin method is combined with a small random job removal.
+///
+/// Default ruin methods are: `adjusted-string`, `neighbour`, `worst-job`, `cluster`, `cluster-bridge`,
+/// `close-route`, `worst-route`, `random-route`, `random-job`.
+///
+/// Default recreate methods are: `blinks`, `skip-best`, `regret`, `cheapest`, `perturbation`, `skip-best-medium`,
+/// `gaps`, `farthest`, `skip-best-large`, `slice`, `skip-random`.
+pub struct RuinRecreateBuilder {
+    problem: Arc<Problem>,
+    ruins: Vec<(String, Arc<dyn Ruin>, usize)>,
+    recreates: Vec<(String, Arc<dyn Recreate>, usize)>,
+}
+
+impl RuinRecreateBuilder {
+    /// Creates a new instance of `RuinRecreateBuilder` with default ruin and recreate methods.
+    pub fn new(problem: Arc<Problem>, environment: Arc<Environment>) -> Self {
+        let ruins = statik::get_default_ruins(&problem);
+        let recreates = statik::get_default_recreates(environment.random.clone());
+
+        Self { problem, ruins, recreates }
+    }
+
+    /// Creates a new instance of `RuinRecreateBuilder` without any ruin and recreate methods.
+    pub fn empty(problem: Arc<Problem>) -> Self {
+        Self { problem, ruins: vec![], recreates: vec![] }
+    }
+
+    /// Returns names of configured ruin methods.
+    pub fn ruin_names(&self) -> impl Iterator<Item = &str> + '_ {
+        self.ruins.iter().map(|(name, _, _)| name.as_str())
+    }
+
+    /// Returns names of configured recreate methods.
+    pub fn recreate_names(&self) -> impl Iterator<Item = &str> + '_ {
+        self.recreates.iter().map(|(name, _, _)| name.as_str())
+    }
+
+    /// Adds ruin method with given name or replaces the existing one.
+    pub fn with_ruin(mut self, name: &str, ruin: Arc<dyn Ruin>, weight: usize) -> Self {
+        add_or_replace(&mut self.ruins, name, ruin, weight);
+        self
+    }
+
+    /// Adds recreate method with given name or replaces the existing one.
+    pub fn with_recreate(mut self, name: &str, recreate: Arc<dyn Recreate>, weight: usize) -> Self {
+        add_or_replace(&mut self.recreates, name, recreate, weight);
+        self
+    }
+
+    /// Sets weight of the existing ruin method. Zero weight disables the method.
+    pub fn set_ruin_weight(mut self, name: &str, weight: usize) -> GenericResult<Self> {
+        if set_weight(&mut self.ruins, name, weight) {
+            Ok(self)
+        } else {
+            Err(format!("unknown ruin method: '{name}'").into())
+        }
+    }
+
+    /// Sets weight of the existing recreate method. Zero weight disables the method.
+    pub fn set_recreate_weight(mut self, name: &str, weight: usize) -> GenericResult<Self> {
+        if set_weight(&mut self.recreates, name, weight) {
+            Ok(self)
+        } else {
+            Err(format!("unknown recreate method: '{name}'").into())
+        }
+    }
+
+    /// Builds ruin and recreate search operator.
+    pub fn build(self) -> GenericResult<TargetSearchOperator> {
+        let ruins = self.ruins.into_iter().filter(|(_, _, weight)| *weight > 0).collect::<Vec<_>>();
+        let recreates = self.recreates.into_iter().filter(|(_, _, weight)| *weight > 0).collect::<Vec<_>>();
+
+        if ruins.is_empty() {
+            return Err("at least one ruin method with non-zero weight should be specified".into());
+        }
+
+        if recreates.is_empty() {
+            return Err("at least one recreate method with non-zero weight should be specified".into());
+        }
+
+        Ok(statik::create_ruin_recreate(self.problem.as_ref(), ruins, recreates))
+    }
+}
+
+fn add_or_replace<T: ?Sized>(items: &mut Vec<(String, Arc<T>, usize)>, name: &str, item: Arc<T>, weight: usize) {
+    match items.iter_mut().find(|(item_name, _, _)| item_name == name) {
+        Some(entry) => *entry = (name.to_string(), item, weight),
+        None => items.push((name.to_string(), item, weight)),
+    }
+}
+
+fn set_weight<T: ?Sized>(items: &mut [(String, Arc<T>, usize)], name: &str, weight: usize) -> bool {
+    items
+        .iter_mut()
+        .find(|(item_name, _, _)| item_name == name)
+        .map(|(_, _, item_weight)| *item_weight = weight)
+        .is_some()
+}
+
 /// Gets static heuristic using default settings.
 pub fn get_static_heuristic(
     problem: Arc<Problem>,
     environment: Arc<Environment>,
 ) -> StaticSelective<RefinementContext, GoalContext, InsertionContext> {
     let default_operator = statik::create_default_heuristic_operator(problem.clone(), environment.clone());
+
+    get_static_heuristic_from_operator(problem, environment, default_operator)
+}
+
+/// Gets static heuristic using ruin and recreate operator, e.g. built by [RuinRecreateBuilder].
+/// Other operators use default settings.
+pub fn get_static_heuristic_with_ruin_recreate(
+    problem: Arc<Problem>,
+    environment: Arc<Environment>,
+    ruin_recreate: TargetSearchOperator,
+) -> StaticSelective<RefinementContext, GoalContext, InsertionContext> {
+    let default_operator = statik::create_heuristic_operator(ruin_recreate, environment.random.clone());
+
+    get_static_heuristic_from_operator(problem, environment, default_operator)
+}
+
+fn get_static_heuristic_from_operator(
+    problem: Arc<Problem>,
+    environment: Arc<Environment>,
+    default_operator: TargetSearchOperator,
+) -> StaticSelective<RefinementContext, GoalContext, InsertionContext> {
     let local_search = statik::create_default_local_search(environment.random.clone());
 
     let heuristic_group: TargetHeuristicGroup = vec![
@@ -142,6 +269,18 @@ pub fn get_static_heuristic(
                 environment.random.clone(),
             ),
         ),
+        (
+            Arc::new(
+                DecomposeSearch::new_geographic(default_operator.clone(), (2, 8), 2)
+                    .with_exact_polishing(DEFAULT_EXACT_MAX_JOBS),
+            ),
+            create_context_operator_probability(
+                1000,
+                20,
+                vec![(SelectionPhase::Exploration, 0.02), (SelectionPhase::Exploitation, 0.05)],
+                environment.random.clone(),
+            ),
+        ),
         (
             Arc::new(LKHSearch::new(LKHSearchMode::ImprovementOnly)),
             create_scalar_operator_probability(0.05, environment.random.clone()),
@@ -181,6 +320,45 @@ pub fn get_dynamic_heuristic(
     )
 }
 
+/// Gets heuristic which wraps given one and restarts the search from heavily perturbed best known
+/// solution when it is not improved during `stagnation_window` generations. On restart, operator
+/// learning of the inner heuristic is reset and the perturbed solution is refined during
+/// `trajectory_size` generations independently from the population.
+pub fn get_warm_restart_heuristic(
+    problem: Arc<Problem>,
+    environment: Arc<Environment>,
+    inner: TargetHeuristic,
+    stagnation_window: usize,
+    trajectory_size: usize,
+) -> WarmRestart<RefinementContext, GoalContext, InsertionContext> {
+    let restart_operator = create_restart_operator(problem, environment);
+
+    WarmRestart::new(inner, restart_operator, stagnation_window, trajectory_size)
+}
+
+/// Creates a ruin and recreate operator which removes a large portion of the solution.
+fn create_restart_operator(problem: Arc<Problem>, environment: Arc<Environment>) -> TargetSearchOperator {
+    let random = environment.random.clone();
+    let jobs_size = problem.jobs.size();
+    let large_limits = RemovalLimits {
+        removed_activities_range: (jobs_size / 3).max(1)..(jobs_size / 2).max(2),
+        affected_routes_range: 2..(problem.fleet.actors.len() / 2).max(3),
+    };
+
+    let ruin = Arc::new(WeightedRuin::new(vec![
+        (Arc::new(AdjustedStringRemoval::new_with_defaults(large_limits.clone())), 2),
+        (Arc::new(RandomRouteRemoval::new(large_limits.clone())), 1),
+        (Arc::new(RandomJobRemoval::new(large_limits)), 1),
+    ]));
+    let recreate = Arc::new(WeightedRecreate::new(vec![
+        (Arc::new(RecreateWithCheapest::new(random.clone())), 1),
+        (Arc::new(RecreateWithBlinks::new_with_defaults(random.clone())), 1),
+        (Arc::new(RecreateWithRegret::new(2, random).with_max_k(3)), 1),
+    ]));
+
+    Arc::new(RuinAndRecreate::new(ruin, recreate))
+}
+
 /// Creates elitism population algorithm.
 pub fn create_elitism_population(
     objective: Arc<GoalContext>,
@@ -317,7 +495,7 @@ mod builder {
         .chain([
             // additional constructive heuristics
             (wrap(Arc::new(RecreateWithFarthest::new(random.clone()))), 1),
-            (wrap(Arc::new(RecreateWithRegret::new(2, 3, random.clone()))), 1),
+            (wrap(Arc::new(RecreateWithRegret::new(2, random.clone()).with_max_k(3))), 1),
             (wrap(Arc::new(RecreateWithGaps::new(1, (problem.jobs.size() / 10).max(1), random.clone()))), 1),
             (wrap(Arc::new(RecreateWithSkipBest::new(1, 2, random.clone()))), 1),
             (wrap(Arc::new(RecreateWithBlinks::new_with_defaults(random.clone()))), 1),
@@ -349,7 +527,7 @@ fn create_diversify_operators(
 
     let recreates: Vec<(Arc<dyn Recreate>, usize)> = vec![
         (Arc::new(RecreateWithSkipBest::new(1, 2, random.clone())), 1),
-        (Arc::new(RecreateWithRegret::new(1, 3, random.clone())), 1),
+        (Arc::new(RecreateWithRegret::new(1, random.clone()).with_max_k(3)), 1),
         (Arc::new(RecreateWithPerturbation::new_with_defaults(random.clone())), 1),
         (Arc::new(RecreateWithGaps::new(2, 20, random.clone())), 1),
         (Arc::new(RecreateWithNearestNeighbor::new(random.clone())), 1),
@@ -392,81 +570,99 @@ mod statik {
         problem: Arc<Problem>,
         environment: Arc<Environment>,
     ) -> TargetSearchOperator {
-        let (normal_limits, small_limits) = get_limits(problem.as_ref());
-        let random = environment.random.clone();
+        let ruins = get_default_ruins(&problem);
+        let recreates = get_default_recreates(environment.random.clone());
+        let ruin_recreate = create_ruin_recreate(problem.as_ref(), ruins, recreates);
 
-        // initialize recreate
-        let recreate = Arc::new(WeightedRecreate::new(vec![
-            (Arc::new(RecreateWithBlinks::new_with_defaults(random.clone())), 50),
-            (Arc::new(RecreateWithSkipBest::new(1, 2, random.clone())), 20),
-            (Arc::new(RecreateWithRegret::new(2, 3, random.clone())), 20),
-            (Arc::new(RecreateWithCheapest::new(random.clone())), 20),
-            (Arc::new(RecreateWithPerturbation::new_with_defaults(random.clone())), 10),
-            (Arc::new(RecreateWithSkipBest::new(3, 4, random.clone())), 5),
-            (Arc::new(RecreateWithGaps::new(2, 20, random.clone())), 5),
-            (Arc::new(RecreateWithFarthest::new(random.clone())), 2),
-            (Arc::new(RecreateWithSkipBest::new(4, 8, random.clone())), 2),
-            (Arc::new(RecreateWithSlice::new(random.clone())), 1),
-            (
-                Arc::new(RecreateWithSkipRandom::default_explorative_phased(
-                    Arc::new(RecreateWithCheapest::new(random.clone())),
-                    random.clone(),
-                )),
-                1,
-            ),
-        ]));
+        create_heuristic_operator(ruin_recreate, environment.random.clone())
+    }
 
-        // initialize ruin
-        let close_route = Arc::new(CloseRouteRemoval::new(normal_limits.clone()));
-        let worst_route = Arc::new(WorstRouteRemoval::new(normal_limits.clone()));
-        let random_route = Arc::new(RandomRouteRemoval::new(normal_limits.clone()));
+    /// Creates heuristic operator which combines given ruin and recreate operator with default local search.
+    pub fn create_heuristic_operator(
+        ruin_recreate: TargetSearchOperator,
+        random: Arc<dyn Random>,
+    ) -> TargetSearchOperator {
+        Arc::new(WeightedHeuristicOperator::new(
+            vec![ruin_recreate, create_default_local_search(random)],
+            vec![100, 10],
+        ))
+    }
 
-        let random_job = Arc::new(RandomJobRemoval::new(normal_limits.clone()));
+    /// Creates ruin and recreate operator from named weighted ruin and recreate methods.
+    /// Each ruin method is combined with small random job removal.
+    pub fn create_ruin_recreate(
+        problem: &Problem,
+        ruins: Vec<(String, Arc<dyn Ruin>, usize)>,
+        recreates: Vec<(String, Arc<dyn Recreate>, usize)>,
+    ) -> TargetSearchOperator {
+        let (_, small_limits) = get_limits(problem);
         let extra_random_job = Arc::new(RandomJobRemoval::new(small_limits));
 
-        let ruin = Arc::new(WeightedRuin::new(vec![
+        let ruin = Arc::new(WeightedRuin::new(
+            ruins
+                .into_iter()
+                .map::<(Arc<dyn Ruin>, usize), _>(|(_, ruin, weight)| {
+                    (Arc::new(CompositeRuin::new(vec![(ruin, 1.), (extra_random_job.clone(), 0.1)])), weight)
+                })
+                .collect(),
+        ));
+        let recreate = Arc::new(WeightedRecreate::new(
+            recreates.into_iter().map(|(_, recreate, weight)| (recreate, weight)).collect(),
+        ));
+
+        Arc::new(RuinAndRecreate::new(ruin, recreate))
+    }
+
+    /// Returns default named ruin methods with their weights.
+    pub fn get_default_ruins(problem: &Arc<Problem>) -> Vec<(String, Arc<dyn Ruin>, usize)> {
+        let (normal_limits, _) = get_limits(problem.as_ref());
+
+        vec![
             (
-                Arc::new(CompositeRuin::new(vec![
-                    (Arc::new(AdjustedStringRemoval::new_with_defaults(normal_limits.clone())), 2.),
-                    (extra_random_job.clone(), 0.1),
-                ])),
+                "adjusted-string",
+                Arc::new(AdjustedStringRemoval::new_with_defaults(normal_limits.clone())) as Arc<dyn Ruin>,
                 100,
             ),
+            ("neighbour", Arc::new(NeighbourRemoval::new(normal_limits.clone())), 10),
+            ("worst-job", Arc::new(WorstJobRemoval::new(4, normal_limits.clone())), 10),
+            // TODO avoid unwrap
+            ("cluster", Arc::new(ClusterRemoval::new_with_defaults(problem.clone()).unwrap()), 5),
+            ("cluster-bridge", Arc::new(ClusterBridgeRemoval::new(problem.clone(), normal_limits.clone())), 5),
+            ("close-route", Arc::new(CloseRouteRemoval::new(normal_limits.clone())), 2),
+            ("worst-route", Arc::new(WorstRouteRemoval::new(normal_limits.clone())), 1),
+            ("random-route", Arc::new(RandomRouteRemoval::new(normal_limits.clone())), 1),
+            ("random-job", Arc::new(RandomJobRemoval::new(normal_limits)), 1),
+        ]
+        .into_iter()
+        .map(|(name, ruin, weight)| (name.to_string(), ruin, weight))
+        .collect()
+    }
+
+    /// Returns default named recreate methods with their weights.
+    pub fn get_default_recreates(random: Arc<dyn Random>) -> Vec<(String, Arc<dyn Recreate>, usize)> {
+        vec![
+            ("blinks", Arc::new(RecreateWithBlinks::new_with_defaults(random.clone())) as Arc<dyn Recreate>, 50),
+            ("skip-best", Arc::new(RecreateWithSkipBest::new(1, 2, random.clone())), 20),
+            ("regret", Arc::new(RecreateWithRegret::new(2, random.clone()).with_max_k(3)), 20),
+            ("cheapest", Arc::new(RecreateWithCheapest::new(random.clone())), 20),
+            ("perturbation", Arc::new(RecreateWithPerturbation::new_with_defaults(random.clone())), 10),
+            ("skip-best-medium", Arc::new(RecreateWithSkipBest::new(3, 4, random.clone())), 5),
+            ("gaps", Arc::new(RecreateWithGaps::new(2, 20, random.clone())), 5),
+            ("farthest", Arc::new(RecreateWithFarthest::new(random.clone())), 2),
+            ("skip-best-large", Arc::new(RecreateWithSkipBest::new(4, 8, random.clone())), 2),
+            ("slice", Arc::new(RecreateWithSlice::new(random.clone())), 1),
             (
-                Arc::new(CompositeRuin::new(vec![
-                    (Arc::new(NeighbourRemoval::new(normal_limits.clone())), 1.),
-                    (extra_random_job.clone(), 0.1),
-                ])),
-                10,
-            ),
-            (
-                Arc::new(CompositeRuin::new(vec![
-                    (Arc::new(WorstJobRemoval::new(4, normal_limits)), 1.),
-                    (extra_random_job.clone(), 0.1),
-                ])),
-                10,
-            ),
-            (
-                Arc::new(CompositeRuin::new(vec![
-                    // TODO avoid unwrap
-                    (Arc::new(ClusterRemoval::new_with_defaults(problem.clone()).unwrap()), 1.),
-                    (extra_random_job.clone(), 0.1),
-                ])),
-                5,
+                "skip-random",
+                Arc::new(RecreateWithSkipRandom::default_explorative_phased(
+                    Arc::new(RecreateWithCheapest::new(random.clone())),
+                    random.clone(),
+                )),
+                1,
             ),
-            (Arc::new(CompositeRuin::new(vec![(close_route, 1.), (extra_random_job.clone(), 0.1)])), 2),
-            (Arc::new(CompositeRuin::new(vec![(worst_route, 1.), (extra_random_job.clone(), 0.1)])), 1),
-            (Arc::new(CompositeRuin::new(vec![(random_route, 1.), (extra_random_job.clone(), 0.1)])), 1),
-            (Arc::new(CompositeRuin::new(vec![(random_job, 1.), (extra_random_job, 0.1)])), 1),
-        ]));
-
-        Arc::new(WeightedHeuristicOperator::new(
-            vec![
-                Arc::new(RuinAndRecreate::new(ruin, recreate)),
-                create_default_local_search(environment.random.clone()),
-            ],
-            vec![100, 10],
-        ))
+        ]
+        .into_iter()
+        .map(|(name, recreate, weight)| (name.to_string(), recreate, weight))
+        .collect()
     }
 
     /// Creates default local search operator.
@@ -476,6 +672,8 @@ mod statik {
                 (Arc::new(ExchangeSwapStar::new(random)), 200),
                 (Arc::new(ExchangeInterRouteBest::default()), 100),
                 (Arc::new(ExchangeSequence::default()), 100),
+                (Arc::new(ExchangeTwoOptStar::default()), 50),
+                (Arc::new(ExchangeOrOpt::default()), 50),
                 (Arc::new(ExchangeInterRouteRandom::default()), 30),
                 (Arc::new(ExchangeIntraRouteRandom::default()), 30),
                 (Arc::new(RescheduleDeparture::default()), 20),
@@ -495,7 +693,7 @@ mod dynamic {
             (cheapest.clone(), "cheapest".to_string(), 2.),
             (Arc::new(RecreateWithBlinks::new_with_defaults(random.clone())), "blinks".to_string(), 2.),
             (Arc::new(RecreateWithSkipBest::new(1, 2, random.clone())), "skip_best".to_string(), 1.),
-            (Arc::new(RecreateWithRegret::new(1, 3, random.clone())), "regret".to_string(), 1.),
+            (Arc::new(RecreateWithRegret::new(1, random.clone()).with_max_k(3)), "regret".to_string(), 1.),
             (Arc::new(RecreateWithPerturbation::new_with_defaults(random.clone())), "perturbation".to_string(), 1.),
             (Arc::new(RecreateWithGaps::new(2, 20, random.clone())), "gaps".to_string(), 1.),
             (Arc::new(RecreateWithFarthest::new(random.clone())), "farthest".to_string(), 1.),
@@ -543,7 +741,8 @@ mod dynamic {
             (create_weighted(|limits| Arc::new(CloseRouteRemoval::new(limits))), "close_route".to_string(), 1.),
             (create_weighted(|limits| Arc::new(RandomJobRemoval::new(limits))), "random_job".to_string(), 1.),
             (create_weighted(|limits| Arc::new(RandomRouteRemoval::new(limits))), "random_route".to_string(), 1.),
-            (Arc::new(ClusterRemoval::new_with_defaults(problem).unwrap()), "cluster".to_string(), 1.),
+            (Arc::new(ClusterRemoval::new_with_defaults(problem.clone()).unwrap()), "cluster".to_string(), 1.),
+            (Arc::new(ClusterBridgeRemoval::new(problem, normal_limits)), "cluster_bridge".to_string(), 1.),
         ]
     }
 
@@ -551,7 +750,12 @@ mod dynamic {
         problem: Arc<Problem>,
         environment: Arc<Environment>,
     ) -> Vec<(TargetSearchOperator, String, Float)> {
-        vec![
+        let dissolve_cluster_search =
+            problem.extras.get_cluster_config().filter(|config| config.solving.dissolve_unassigned).map(|_| {
+                (Arc::new(DissolveClusterSearch::default()) as TargetSearchOperator, "dissolve_cluster".to_string(), 1.)
+            });
+
+        let operators: Vec<(TargetSearchOperator, String, Float)> = vec![
             (
                 Arc::new(LocalSearch::new(Arc::new(ExchangeInterRouteBest::default()))),
                 "local_exch_inter_route_best".to_string(),
@@ -573,6 +777,12 @@ mod dynamic {
                 1.,
             ),
             (Arc::new(LKHSearch::new(LKHSearchMode::ImprovementOnly)), "lkh_strict".to_string(), 1.),
+            (
+                Arc::new(SisrSearch::new_with_defaults(problem.as_ref(), environment.random.clone())),
+                "sisr".to_string(),
+                1.,
+            ),
+            (Arc::new(EjectionChainSearch::default()), "ejection_chain".to_string(), 1.),
             (
                 Arc::new(LocalSearch::new(Arc::new(ExchangeSwapStar::new(environment.random.clone())))),
                 "local_swap_star".to_string(),
@@ -583,8 +793,15 @@ mod dynamic {
                 "variable_decompose_search".to_string(),
                 2.,
             ),
-            (create_composite_decompose_search(problem, environment), "composite_decompose_search".to_string(), 2.),
-        ]
+            (
+                create_composite_decompose_search(problem.clone(), environment.clone()),
+                "composite_decompose_search".to_string(),
+                2.,
+            ),
+            (create_geographic_decompose_search(problem, environment), "geographic_decompose_search".to_string(), 1.),
+        ];
+
+        operators.into_iter().chain(dissolve_cluster_search).collect()
     }
 
     pub fn get_operators(
@@ -670,6 +887,8 @@ mod dynamic {
                 (Arc::new(ExchangeInterRouteRandom::default()), 1),
                 (Arc::new(ExchangeIntraRouteRandom::default()), 1),
                 (Arc::new(ExchangeSequence::default()), 1),
+                (Arc::new(ExchangeTwoOptStar::default()), 1),
+                (Arc::new(ExchangeOrOpt::default()), 1),
             ],
             1,
             1,
@@ -711,6 +930,26 @@ mod dynamic {
             2,
         ))
     }
+
+    fn create_geographic_decompose_search(
+        problem: Arc<Problem>,
+        environment: Arc<Environment>,
+    ) -> TargetSearchOperator {
+        Arc::new(
+            DecomposeSearch::new_geographic(
+                Arc::new(WeightedHeuristicOperator::new(
+                    vec![
+                        create_default_inner_ruin_recreate(problem.clone(), environment.clone()),
+                        create_default_local_search(environment.random.clone()),
+                    ],
+                    vec![3, 1],
+                )),
+                (2, 8),
+                2,
+            )
+            .with_exact_polishing(DEFAULT_EXACT_MAX_JOBS),
+        )
+    }
 }
 
 fn get_recreate_with_alternative_goal<T, F>(
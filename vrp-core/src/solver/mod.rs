@@ -71,7 +71,9 @@ extern crate rand;
 use crate::construction::heuristics::InsertionContext;
 use crate::models::common::{Footprint, FootprintSolutionState, Shadow};
 use crate::models::{GoalContext, Problem, Solution};
-use crate::solver::search::Recreate;
+use crate::solver::processing::{ROUTE_POOL_STATE_KEY, RoutePool};
+use crate::solver::search::{ArcPenaltiesExtraProperty, Recreate};
+use crate::utils::next_pool_generation;
 use rosomaxa::evolution::*;
 use rosomaxa::prelude::*;
 use rosomaxa::utils::Timer;
@@ -83,6 +85,16 @@ use std::sync::Arc;
 pub mod processing;
 pub mod search;
 
+mod dynamic;
+pub use self::dynamic::*;
+
+mod error;
+pub use self::error::SolverError;
+
+mod exact;
+pub(crate) use self::exact::sequence_route_exactly;
+pub use self::exact::{DEFAULT_EXACT_MAX_JOBS, ExactSolver};
+
 mod heuristic;
 pub use self::heuristic::*;
 
@@ -120,7 +132,8 @@ impl RefinementContext {
     ) -> Self {
         let initial_footprint = Footprint::new(&problem);
         let inner_context =
-            TelemetryHeuristicContext::new(problem.goal.clone(), population, telemetry_mode, environment.clone());
+            TelemetryHeuristicContext::new(problem.goal.clone(), population, telemetry_mode, environment.clone())
+                .with_memory_baseline(problem.jobs.memory_usage());
         Self { problem, environment, inner_context, state: Default::default(), initial_footprint }
     }
 
@@ -133,6 +146,11 @@ impl RefinementContext {
     pub fn add_solution(&mut self, solution: InsertionContext) {
         self.inner_context.add_solution(solution);
     }
+
+    /// Reports a named value which is kept in telemetry metrics.
+    pub fn on_property(&mut self, name: &str, value: Float) {
+        self.inner_context.on_property(name, value);
+    }
 }
 
 impl HeuristicContext for RefinementContext {
@@ -171,7 +189,17 @@ impl HeuristicContext for RefinementContext {
     }
 
     fn on_generation(&mut self, offspring: Vec<Self::Solution>, termination_estimate: Float, generation_time: Timer) {
-        self.inner_context.on_generation(offspring, termination_estimate, generation_time)
+        if let Some(route_pool) = self.get_state::<Arc<RoutePool>>(&ROUTE_POOL_STATE_KEY.to_string()) {
+            route_pool.add(offspring.as_slice());
+        }
+
+        self.inner_context.on_generation(offspring, termination_estimate, generation_time);
+        // NOTE release allocations kept for reuse by deep copies, but not requested within the generation
+        next_pool_generation();
+    }
+
+    fn on_search(&self, operator_name: &str, solution: &Self::Solution, is_improvement: bool) {
+        self.inner_context.on_search(operator_name, solution, is_improvement)
     }
 
     fn on_result(self) -> HeuristicResult<Self::Objective, Self::Solution> {
@@ -239,18 +267,26 @@ impl Solver {
 
     /// Solves a Vehicle Routing Problem and returns a feasible solution in case of success
     /// or error description if solution cannot be found.
-    pub fn solve(self) -> GenericResult<Solution> {
+    pub fn solve(self) -> Result<Solution, SolverError> {
         (self.config.context.environment.logger)(&format!(
             "total jobs: {}, actors: {}",
             self.problem.jobs.size(),
             self.problem.fleet.actors.len()
         ));
 
-        let (mut solutions, metrics) = EvolutionSimulator::new(self.config)?.run()?;
+        // NOTE arc penalties bias transport costs only during the search, so they should not leak into
+        // the final solution costs
+        let arc_penalties = self.problem.extras.get_arc_penalties();
+        arc_penalties.iter().for_each(|penalties| penalties.clear());
+
+        let result =
+            EvolutionSimulator::new(self.config).and_then(|simulator| simulator.run()).map_err(SolverError::Search);
+        arc_penalties.iter().for_each(|penalties| penalties.clear());
+        let (mut solutions, metrics) = result?;
 
         // NOTE select the first best individual from population
-        let insertion_ctx = if solutions.is_empty() { None } else { solutions.drain(0..1).next() }
-            .ok_or_else(|| "cannot find any solution".to_string())?;
+        let insertion_ctx =
+            if solutions.is_empty() { None } else { solutions.drain(0..1).next() }.ok_or(SolverError::NoSolution)?;
 
         let solution = (insertion_ctx, metrics).into();
 
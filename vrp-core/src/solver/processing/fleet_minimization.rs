@@ -0,0 +1,232 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/processing/fleet_minimization_test.rs"]
+mod fleet_minimization_test;
+
+use super::*;
+use crate::construction::features::{JobDemandDimension, VehicleCapacityDimension};
+use crate::models::GoalContext;
+use crate::models::common::{LoadOps, MultiDimLoad, SingleDimLoad};
+use crate::models::problem::{Actor, Job, Single};
+use crate::solver::search::*;
+use crate::solver::{RecreateInitialOperator, RefinementContext};
+use rosomaxa::evolution::InitialOperator;
+use rosomaxa::utils::Timer;
+use std::cmp::Ordering;
+use std::ops::ControlFlow;
+use std::sync::Arc;
+
+/// A name of telemetry property which keeps the fleet size lower bound.
+pub const FLEET_LOWER_BOUND_PROPERTY: &str = "fleet_lower_bound";
+
+/// A name of telemetry property which keeps the fleet size achieved by fleet minimization.
+pub const FLEET_SIZE_PROPERTY: &str = "fleet_size";
+
+/// Default amount of fleet minimization iterations.
+const DEFAULT_MAX_ITERATIONS: usize = 200;
+
+/// Runs a dedicated fleet minimization search before the main one: an initial solution is built
+/// and its routes are eliminated one by one until the fleet size reaches its lower bound or the
+/// iteration limit. The result is added to the initial population, lower bound and achieved fleet
+/// size are reported in telemetry.
+pub struct FleetMinimization {
+    max_iterations: usize,
+}
+
+impl FleetMinimization {
+    /// Creates a new instance of `FleetMinimization` with given maximum amount of iterations.
+    pub fn new(max_iterations: usize) -> Self {
+        Self { max_iterations }
+    }
+}
+
+impl Default for FleetMinimization {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_ITERATIONS)
+    }
+}
+
+impl HeuristicContextProcessing for FleetMinimization {
+    type Context = RefinementContext;
+    type Objective = GoalContext;
+    type Solution = InsertionContext;
+
+    fn pre_process(&self, context: Self::Context) -> Self::Context {
+        let mut context = context;
+        let timer = Timer::start();
+
+        let random = context.environment.random.clone();
+        let insertion_ctx = RecreateInitialOperator::new(Arc::new(RecreateWithCheapest::new(random))).create(&context);
+        let lower_bound = get_fleet_lower_bound(&insertion_ctx);
+
+        let insertion_ctx = minimize_fleet(&context, insertion_ctx, lower_bound, self.max_iterations);
+
+        context.on_property(FLEET_LOWER_BOUND_PROPERTY, lower_bound as Float);
+        context.on_property(FLEET_SIZE_PROPERTY, insertion_ctx.solution.routes.len() as Float);
+        context.on_initial(insertion_ctx, timer);
+
+        context
+    }
+}
+
+/// Returns a lower bound of amount of routes needed to serve all jobs of the solution. It is a maximum
+/// of two bin-packing bounds: total static demand against the largest vehicle capacities and total
+/// service duration against the longest shifts.
+/// NOTE conditional jobs (e.g. reloads) can change vehicle load within the route, so capacity bound
+/// is used only when there are no such jobs.
+pub fn get_fleet_lower_bound(insertion_ctx: &InsertionContext) -> usize {
+    let solution = &insertion_ctx.solution;
+    let jobs = solution
+        .required
+        .iter()
+        .chain(solution.unassigned.keys())
+        .chain(solution.routes.iter().flat_map(|route_ctx| route_ctx.route().tour.jobs()))
+        .cloned()
+        .collect::<Vec<_>>();
+
+    if jobs.is_empty() {
+        return 0;
+    }
+
+    let actors = insertion_ctx.problem.fleet.actors.as_slice();
+
+    let capacity_bound = if solution.ignored.is_empty() {
+        get_capacity_bound::<SingleDimLoad>(&jobs, actors, |load| vec![load.value]).max(get_capacity_bound::<
+            MultiDimLoad,
+        >(
+            &jobs,
+            actors,
+            |load| load.load[..load.size].to_vec(),
+        ))
+    } else {
+        0
+    };
+
+    let total_duration = jobs
+        .iter()
+        .map(|job| match job {
+            Job::Single(single) => get_min_duration(single),
+            Job::Multi(multi) => multi.jobs.iter().map(|single| get_min_duration(single)).sum(),
+        })
+        .sum::<Float>();
+    let time_bound = get_bin_amount(actors.iter().map(|actor| actor.detail.time.duration()).collect(), total_duration);
+
+    capacity_bound.max(time_bound).max(1)
+}
+
+fn minimize_fleet(
+    refinement_ctx: &RefinementContext,
+    insertion_ctx: InsertionContext,
+    lower_bound: usize,
+    max_iterations: usize,
+) -> InsertionContext {
+    let environment = refinement_ctx.environment.clone();
+    let jobs_size = refinement_ctx.problem.jobs.size();
+    // NOTE allow to remove the whole route
+    let limits =
+        RemovalLimits { removed_activities_range: jobs_size.max(1)..jobs_size + 2, affected_routes_range: 1..2 };
+    let operator = RuinAndRecreate::new(
+        Arc::new(WeightedRuin::new(vec![
+            (Arc::new(WorstRouteRemoval::new(limits.clone())), 2),
+            (Arc::new(RandomRouteRemoval::new(limits)), 1),
+        ])),
+        Arc::new(RecreateWithBlinks::new_with_defaults(environment.random.clone())),
+    );
+
+    let result = (0..max_iterations).try_fold(insertion_ctx, |best, _| {
+        let is_quota_reached = environment.quota.as_ref().is_some_and(|quota| quota.is_reached());
+        if best.solution.routes.len() <= lower_bound || is_quota_reached {
+            return ControlFlow::Break(best);
+        }
+
+        let candidate = operator.search(refinement_ctx, &best);
+
+        ControlFlow::Continue(if compare_fleet(&candidate, &best) == Ordering::Less { candidate } else { best })
+    });
+
+    match result {
+        ControlFlow::Break(insertion_ctx) | ControlFlow::Continue(insertion_ctx) => insertion_ctx,
+    }
+}
+
+/// Compares solutions by amount of unassigned jobs, then by amount of routes. Solutions with the same
+/// fleet size are compared by sum of squared route sizes: the larger value means that some routes are
+/// small and easier to eliminate.
+fn compare_fleet(a: &InsertionContext, b: &InsertionContext) -> Ordering {
+    let get_key = |insertion_ctx: &InsertionContext| {
+        let routes = &insertion_ctx.solution.routes;
+        let sizes = routes.iter().map(|route_ctx| route_ctx.route().tour.job_count().pow(2)).sum::<usize>();
+
+        (insertion_ctx.solution.unassigned.len(), routes.len(), usize::MAX - sizes)
+    };
+
+    get_key(a).cmp(&get_key(b))
+}
+
+/// Returns amount of vehicles with the largest capacities needed to fit total static demand of the jobs.
+/// Returns zero if vehicles have no capacity of the given type.
+fn get_capacity_bound<T: LoadOps>(jobs: &[Job], actors: &[Arc<Actor>], get_values: fn(&T) -> Vec<i32>) -> usize {
+    let capacities = actors
+        .iter()
+        .filter_map(|actor| actor.vehicle.dimens.get_vehicle_capacity::<T>().map(get_values))
+        .collect::<Vec<_>>();
+
+    if capacities.len() != actors.len() {
+        return 0;
+    }
+
+    let (deliveries, pickups) = jobs
+        .iter()
+        .flat_map(|job| match job {
+            Job::Single(single) => vec![single.as_ref()],
+            Job::Multi(multi) => multi.jobs.iter().map(|single| single.as_ref()).collect(),
+        })
+        .filter_map(|single| single.dimens.get_job_demand::<T>())
+        .fold((Vec::<i32>::new(), Vec::<i32>::new()), |(deliveries, pickups), demand| {
+            (add_values(deliveries, get_values(&demand.delivery.0)), add_values(pickups, get_values(&demand.pickup.0)))
+        });
+
+    deliveries
+        .iter()
+        .enumerate()
+        .chain(pickups.iter().enumerate())
+        .map(|(dim_idx, &total)| {
+            let sizes =
+                capacities.iter().map(|capacity| capacity.get(dim_idx).copied().unwrap_or(0) as Float).collect();
+            get_bin_amount(sizes, total as Float)
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+/// Returns minimum amount of bins with given sizes which total size is not less than required one.
+/// Returns amount of all bins if they cannot fit required size.
+fn get_bin_amount(mut sizes: Vec<Float>, total: Float) -> usize {
+    if total <= 0. {
+        return 0;
+    }
+
+    sizes.sort_by(|a, b| b.total_cmp(a));
+
+    let mut accumulated = 0.;
+    sizes
+        .iter()
+        .position(|size| {
+            accumulated += size;
+            accumulated >= total
+        })
+        .map_or(sizes.len(), |idx| idx + 1)
+}
+
+pub(super) fn get_min_duration(single: &Single) -> Float {
+    single.places.iter().map(|place| place.duration).min_by(|a, b| a.total_cmp(b)).unwrap_or(0.)
+}
+
+fn add_values(mut values: Vec<i32>, other: Vec<i32>) -> Vec<i32> {
+    if values.len() < other.len() {
+        values.resize(other.len(), 0);
+    }
+
+    values.iter_mut().zip(other).for_each(|(value, other)| *value += other);
+
+    values
+}
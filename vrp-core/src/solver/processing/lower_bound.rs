@@ -0,0 +1,161 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/processing/lower_bound_test.rs"]
+mod lower_bound_test;
+
+use super::fleet_minimization::get_min_duration;
+use super::*;
+use crate::models::GoalContext;
+use crate::models::common::{Cost, Location, Profile};
+use crate::models::problem::{Costs, Job};
+use crate::solver::RefinementContext;
+
+/// A name of telemetry property which keeps the solution cost lower bound.
+pub const COST_LOWER_BOUND_PROPERTY: &str = "cost_lower_bound";
+
+/// Maximum amount of job locations for which travel distance and duration bounds are estimated.
+const MAX_BOUND_LOCATIONS: usize = 2000;
+
+/// Estimates a lower bound of the solution cost before the search starts and reports it in telemetry,
+/// so the optimality gap of the final solution can be estimated. The estimation is not a part of default
+/// processing as it takes a noticeable part of the time budget on large problems.
+#[derive(Default)]
+pub struct CostLowerBound {}
+
+impl HeuristicContextProcessing for CostLowerBound {
+    type Context = RefinementContext;
+    type Objective = GoalContext;
+    type Solution = InsertionContext;
+
+    fn pre_process(&self, context: Self::Context) -> Self::Context {
+        let mut context = context;
+
+        let insertion_ctx = InsertionContext::new(context.problem.clone(), context.environment.clone());
+        let lower_bound = get_cost_lower_bound(&insertion_ctx);
+
+        context.on_property(COST_LOWER_BOUND_PROPERTY, lower_bound);
+
+        context
+    }
+}
+
+/// Returns a lower bound of the cost needed to serve all jobs of the solution. It is a sum of:
+/// - fixed costs of the cheapest vehicles needed according to fleet size lower bound;
+/// - travel distance and driving time bounds: maximum of minimum spanning tree (all vehicle starts
+///   are merged into one node) and assignment (each job location is entered once by the cheapest
+///   edge) relaxations on the routing matrix;
+/// - service time of the jobs.
+///
+/// Each part is multiplied by the lowest corresponding unit cost in the fleet. Conditional jobs
+/// (e.g. breaks) are not considered. Travel bounds are skipped for problems with too many job locations.
+pub fn get_cost_lower_bound(insertion_ctx: &InsertionContext) -> Cost {
+    let solution = &insertion_ctx.solution;
+    let problem = insertion_ctx.problem.as_ref();
+    let actors = problem.fleet.actors.as_slice();
+
+    if actors.is_empty() {
+        return 0.;
+    }
+
+    let get_actor_costs = |get_cost: fn(&Costs) -> Float| {
+        actors.iter().map(move |actor| get_cost(&actor.vehicle.costs) + get_cost(&actor.driver.costs))
+    };
+    let get_min_cost = |get_cost: fn(&Costs) -> Float| get_actor_costs(get_cost).fold(Float::MAX, Float::min);
+
+    let singles = solution
+        .required
+        .iter()
+        .chain(solution.unassigned.keys())
+        .chain(solution.routes.iter().flat_map(|route_ctx| route_ctx.route().tour.jobs()))
+        .flat_map(|job| match job {
+            Job::Single(single) => vec![single.clone()],
+            Job::Multi(multi) => multi.jobs.clone(),
+        })
+        .collect::<Vec<_>>();
+
+    let mut fixed_costs = get_actor_costs(|costs| costs.fixed).collect::<Vec<_>>();
+    fixed_costs.sort_by(|a, b| a.total_cmp(b));
+    let fixed = fixed_costs.iter().take(get_fleet_lower_bound(insertion_ctx)).sum::<Cost>();
+
+    let service = singles.iter().map(|single| get_min_duration(single)).sum::<Float>();
+
+    let nodes = singles
+        .iter()
+        .map(|single| single.places.iter().filter_map(|place| place.location).collect::<Vec<_>>())
+        .filter(|locations| !locations.is_empty())
+        .collect::<Vec<_>>();
+
+    let (distance, duration) = if nodes.len() <= MAX_BOUND_LOCATIONS {
+        let starts = actors.iter().filter_map(|actor| actor.detail.start.as_ref()).map(|start| start.location);
+        let nodes = std::iter::once(starts.collect::<Vec<_>>()).chain(nodes).collect::<Vec<_>>();
+        let transport = problem.transport.as_ref();
+        let profiles = problem.fleet.profiles.as_slice();
+
+        (
+            get_travel_bound(&nodes, &|profile, from, to| transport.distance_approx(profile, from, to), profiles),
+            get_travel_bound(&nodes, &|profile, from, to| transport.duration_approx(profile, from, to), profiles),
+        )
+    } else {
+        (0., 0.)
+    };
+
+    fixed
+        + get_min_cost(|costs| costs.per_distance) * distance
+        + get_min_cost(|costs| costs.per_driving_time) * duration
+        + get_min_cost(|costs| costs.per_service_time) * service
+}
+
+/// Returns a lower bound of total travel value (distance or duration) needed to visit all nodes where
+/// the first node represents vehicle starts. Each node can have multiple alternative locations.
+fn get_travel_bound(
+    nodes: &[Vec<Location>],
+    travel_fn: &dyn Fn(&Profile, Location, Location) -> Float,
+    profiles: &[Profile],
+) -> Float {
+    if nodes.len() < 2 || nodes[0].is_empty() || profiles.is_empty() {
+        return 0.;
+    }
+
+    let get_value = |from: usize, to: usize| {
+        nodes[from]
+            .iter()
+            .flat_map(|&from| nodes[to].iter().map(move |&to| (from, to)))
+            .flat_map(|(from, to)| profiles.iter().map(move |profile| travel_fn(profile, from, to)))
+            .fold(Float::MAX, Float::min)
+            .max(0.)
+    };
+
+    get_spanning_tree_bound(nodes.len(), &get_value).max(get_assignment_bound(nodes.len(), &get_value))
+}
+
+/// Returns weight of minimum spanning tree built using Prim's algorithm.
+fn get_spanning_tree_bound(size: usize, get_value: &dyn Fn(usize, usize) -> Float) -> Float {
+    // NOTE travel between nodes is not symmetric in general, so the cheapest direction is used
+    let get_edge_value = |from: usize, to: usize| get_value(from, to).min(get_value(to, from));
+
+    let mut is_connected = vec![false; size];
+    let mut values = (0..size).map(|idx| if idx == 0 { 0. } else { get_edge_value(0, idx) }).collect::<Vec<_>>();
+    is_connected[0] = true;
+
+    (1..size).fold(0., |total, _| {
+        let Some(next) = (0..size)
+            .filter(|&idx| !is_connected[idx])
+            .min_by(|&a, &b| values[a].total_cmp(&values[b]).then(a.cmp(&b)))
+        else {
+            return total;
+        };
+
+        is_connected[next] = true;
+        (0..size).filter(|&idx| !is_connected[idx]).for_each(|idx| {
+            values[idx] = values[idx].min(get_edge_value(next, idx));
+        });
+
+        total + values[next]
+    })
+}
+
+/// Returns sum of the cheapest incoming travel values of all job nodes.
+fn get_assignment_bound(size: usize, get_value: &dyn Fn(usize, usize) -> Float) -> Float {
+    (1..size)
+        .map(|to| (0..size).filter(|&from| from != to).map(|from| get_value(from, to)).fold(Float::MAX, Float::min))
+        .sum()
+}
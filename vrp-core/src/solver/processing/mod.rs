@@ -6,9 +6,21 @@ use rosomaxa::prelude::*;
 mod advance_departure;
 pub use self::advance_departure::AdvanceDeparture;
 
+mod fleet_minimization;
+pub use self::fleet_minimization::*;
+
+mod lower_bound;
+pub use self::lower_bound::*;
+
 mod reschedule_reserved_time;
 pub use self::reschedule_reserved_time::{RescheduleReservedTime, ReservedTimesExtraProperty};
 
+mod route_polishing;
+pub use self::route_polishing::{MAX_EXACT_POLISHING_SIZE, RoutePolishing};
+
+mod set_partitioning;
+pub use self::set_partitioning::*;
+
 mod unassignment_reason;
 pub use self::unassignment_reason::UnassignmentReason;
 
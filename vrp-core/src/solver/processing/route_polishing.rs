@@ -0,0 +1,161 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/processing/route_polishing_test.rs"]
+mod route_polishing_test;
+
+use super::*;
+use crate::algorithms::lkh::Path;
+use crate::construction::heuristics::RouteContext;
+use crate::construction::probing::repair_solution_from_unknown;
+use crate::models::problem::{Actor, TransportCost};
+use crate::solver::search::{get_activity_range, optimize_route, rearrange_route};
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+/// Polishes the final solution by re-ordering activities inside each route: routes with small amount
+/// of activities are solved to optimality using dynamic programming (Held-Karp), larger routes are
+/// optimized with Lin-Kernighan style heuristic. A new route order is accepted only if the repaired
+/// solution is better than the original one.
+pub struct RoutePolishing {
+    max_exact_size: usize,
+}
+
+/// Specifies the largest allowed amount of activities optimized exactly: memory and time of dynamic
+/// programming grow exponentially with the route size.
+pub const MAX_EXACT_POLISHING_SIZE: usize = 15;
+
+impl RoutePolishing {
+    /// Creates a new instance of `RoutePolishing` where `max_exact_size` specifies maximum amount of
+    /// activities in the route which is optimized exactly. It cannot exceed [`MAX_EXACT_POLISHING_SIZE`].
+    pub fn new(max_exact_size: usize) -> Self {
+        assert!(max_exact_size <= MAX_EXACT_POLISHING_SIZE);
+
+        Self { max_exact_size }
+    }
+}
+
+impl Default for RoutePolishing {
+    fn default() -> Self {
+        Self::new(12)
+    }
+}
+
+impl HeuristicSolutionProcessing for RoutePolishing {
+    type Solution = InsertionContext;
+
+    fn post_process(&self, solution: Self::Solution) -> Self::Solution {
+        let actors =
+            solution.solution.routes.iter().map(|route_ctx| route_ctx.route().actor.clone()).collect::<Vec<_>>();
+
+        actors
+            .into_iter()
+            .fold(solution, |insertion_ctx, actor| self.polish_route(&insertion_ctx, &actor).unwrap_or(insertion_ctx))
+    }
+}
+
+impl RoutePolishing {
+    fn polish_route(&self, insertion_ctx: &InsertionContext, actor: &Arc<Actor>) -> Option<InsertionContext> {
+        let transport = insertion_ctx.problem.transport.as_ref();
+
+        let mut new_insertion_ctx = insertion_ctx.deep_copy();
+        let route_ctx = new_insertion_ctx
+            .solution
+            .routes
+            .iter_mut()
+            .find(|route_ctx| route_ctx.route().actor == *actor)
+            .filter(|route_ctx| route_ctx.route().tour.total() > 3)?;
+
+        let original = route_ctx.route().tour.all_activities().map(|a| a.place.location).collect::<Vec<_>>();
+
+        if get_activity_range(&route_ctx.route().tour).len() <= self.max_exact_size + 1 {
+            optimize_route_exactly(route_ctx, transport);
+        } else {
+            optimize_route(route_ctx, transport);
+        }
+
+        if route_ctx.route().tour.all_activities().map(|a| a.place.location).eq(original) {
+            return None;
+        }
+
+        let new_insertion_ctx = repair_solution_from_unknown(&new_insertion_ctx, &|| {
+            InsertionContext::new(insertion_ctx.problem.clone(), insertion_ctx.environment.clone())
+        });
+
+        let is_better = insertion_ctx.problem.goal.total_order(&new_insertion_ctx, insertion_ctx) == Ordering::Less;
+
+        is_better.then_some(new_insertion_ctx)
+    }
+}
+
+/// Re-orders route activities to minimize total distance using Held-Karp algorithm.
+fn optimize_route_exactly(route_ctx: &mut RouteContext, transport: &dyn TransportCost) {
+    let tour = &route_ctx.route().tour;
+    let profile = &route_ctx.route().actor.vehicle.profile;
+
+    let range = get_activity_range(tour);
+    let locations = range.clone().filter_map(|idx| tour.get(idx)).map(|a| a.place.location).collect::<Vec<_>>();
+    let distance = |from: usize, to: usize| transport.distance_approx(profile, locations[from], locations[to]);
+
+    // the route either returns to start, finishes at fixed end or is open
+    let is_closed = range.end < tour.total();
+    let fixed_end = (!is_closed && tour.end().is_some_and(|end| end.job.is_none())).then_some(locations.len() - 1);
+    let free = (1..locations.len()).filter(|&idx| Some(idx) != fixed_end).collect::<Vec<_>>();
+    let size = free.len();
+
+    if size < 2 {
+        return;
+    }
+
+    // costs[mask][last] keeps the shortest distance of the path which starts at the route start,
+    // visits all free nodes from the mask and ends at the given free node
+    let full_mask = (1_usize << size) - 1;
+    let mut costs = vec![vec![Float::MAX; size]; full_mask + 1];
+    let mut parents = vec![vec![usize::MAX; size]; full_mask + 1];
+
+    (0..size).for_each(|idx| costs[1 << idx][idx] = distance(0, free[idx]));
+
+    for mask in 1..=full_mask {
+        for last in (0..size).filter(|&last| mask & (1 << last) != 0) {
+            let cost = costs[mask][last];
+            if cost == Float::MAX {
+                continue;
+            }
+
+            for next in (0..size).filter(|&next| mask & (1 << next) == 0) {
+                let next_mask = mask | (1 << next);
+                let next_cost = cost + distance(free[last], free[next]);
+
+                if next_cost < costs[next_mask][next] {
+                    costs[next_mask][next] = next_cost;
+                    parents[next_mask][next] = last;
+                }
+            }
+        }
+    }
+
+    let get_end_cost = |last: usize| match (is_closed, fixed_end) {
+        (true, _) => distance(free[last], 0),
+        (false, Some(end)) => distance(free[last], end),
+        (false, None) => 0.,
+    };
+
+    let Some(mut last) = (0..size)
+        .min_by(|&a, &b| (costs[full_mask][a] + get_end_cost(a)).total_cmp(&(costs[full_mask][b] + get_end_cost(b))))
+    else {
+        return;
+    };
+
+    let mut order = Vec::with_capacity(size);
+    let mut mask = full_mask;
+    while last != usize::MAX {
+        order.push(free[last]);
+        let parent = parents[mask][last];
+        mask &= !(1 << last);
+        last = parent;
+    }
+
+    let path: Path = std::iter::once(0).chain(order.into_iter().rev()).chain(fixed_end).collect();
+
+    if path.iter().enumerate().any(|(idx, &node)| idx != node) {
+        rearrange_route(route_ctx, path);
+    }
+}
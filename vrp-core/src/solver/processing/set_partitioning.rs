@@ -0,0 +1,380 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/processing/set_partitioning_test.rs"]
+mod set_partitioning_test;
+
+use super::*;
+use crate::construction::heuristics::*;
+use crate::construction::probing::repair_solution_from_unknown;
+use crate::models::common::Cost;
+use crate::models::problem::{Actor, Job};
+use crate::models::{GoalContext, Problem};
+use crate::solver::RefinementContext;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+
+/// A key of refinement context state which keeps the route pool used by `SetPartitioning`.
+pub const ROUTE_POOL_STATE_KEY: &str = "route_pool";
+
+/// Default maximum amount of routes kept in the pool.
+pub const DEFAULT_ROUTE_POOL_SIZE: usize = 1000;
+
+/// Default amount of subgradient iterations used to solve set partitioning problem.
+pub const DEFAULT_SET_PARTITIONING_ITERATIONS: usize = 100;
+
+/// Recombines routes discovered during the search into a better solution: routes of generated
+/// solutions are collected in a bounded pool and, when the search is finished, a set partitioning
+/// problem over the pool is solved heuristically using lagrangian relaxation. A recombined solution
+/// is accepted only if it is better than the final one.
+///
+/// The pool is installed into refinement context on pre processing, so the same instance (or its
+/// clone) has to be used for context and solution processing.
+#[derive(Clone)]
+pub struct SetPartitioning {
+    pool: Arc<RoutePool>,
+    max_iterations: usize,
+}
+
+impl SetPartitioning {
+    /// Creates a new instance of `SetPartitioning` with given maximum pool size and amount of
+    /// subgradient iterations.
+    pub fn new(max_pool_size: usize, max_iterations: usize) -> Self {
+        Self { pool: Arc::new(RoutePool::new(max_pool_size)), max_iterations }
+    }
+}
+
+impl Default for SetPartitioning {
+    fn default() -> Self {
+        Self::new(DEFAULT_ROUTE_POOL_SIZE, DEFAULT_SET_PARTITIONING_ITERATIONS)
+    }
+}
+
+impl HeuristicContextProcessing for SetPartitioning {
+    type Context = RefinementContext;
+    type Objective = GoalContext;
+    type Solution = InsertionContext;
+
+    fn pre_process(&self, context: Self::Context) -> Self::Context {
+        let mut context = context;
+
+        self.pool.reset(&InsertionContext::new(context.problem.clone(), context.environment.clone()));
+        context.set_state(ROUTE_POOL_STATE_KEY.to_string(), self.pool.clone());
+
+        context
+    }
+}
+
+impl HeuristicSolutionProcessing for SetPartitioning {
+    type Solution = InsertionContext;
+
+    fn post_process(&self, solution: Self::Solution) -> Self::Solution {
+        self.recombine(&solution).unwrap_or(solution)
+    }
+}
+
+impl SetPartitioning {
+    fn recombine(&self, insertion_ctx: &InsertionContext) -> Option<InsertionContext> {
+        if !insertion_ctx.solution.locked.is_empty() {
+            return None;
+        }
+
+        // NOTE routes of the final solution are always part of the pool
+        self.pool.add(std::slice::from_ref(insertion_ctx));
+
+        let columns = self.pool.get_columns(insertion_ctx.problem.as_ref());
+        if columns.is_empty() {
+            return None;
+        }
+
+        let incumbent = insertion_ctx
+            .solution
+            .routes
+            .iter()
+            .filter_map(|route_ctx| {
+                let (actor, jobs) = self.pool.get_key(route_ctx)?;
+                let idx =
+                    columns.iter().position(|column| column.route_ctx.route().actor == actor && column.jobs == jobs)?;
+                Some((idx, route_ctx.get_route_cost().is_some_and(|cost| cost > columns[idx].cost)))
+            })
+            .collect::<Vec<_>>();
+
+        // NOTE the pool can keep a cheaper route with the same jobs as the route of the final solution
+        let has_cheaper_routes = incumbent.iter().any(|(_, is_cheaper)| *is_cheaper);
+        let mut incumbent = incumbent.into_iter().map(|(idx, _)| idx).collect::<Vec<_>>();
+        incumbent.sort_unstable();
+
+        let selected = solve_set_partitioning(&columns, incumbent.clone(), self.max_iterations);
+        if selected == incumbent && !has_cheaper_routes {
+            return None;
+        }
+
+        let problem = insertion_ctx.problem.clone();
+        let environment = insertion_ctx.environment.clone();
+
+        let mut container = insertion_ctx.deep_copy();
+        container.solution.routes = selected.iter().map(|&idx| columns[idx].route_ctx.deep_copy()).collect();
+
+        let mut new_insertion_ctx =
+            repair_solution_from_unknown(&container, &|| InsertionContext::new(problem.clone(), environment.clone()));
+
+        // NOTE try to insert jobs which are not covered by selected routes, ignore quota as it is most
+        // likely reached when search is finished
+        let jobs = new_insertion_ctx.solution.unassigned.drain().map(|(job, _)| job).collect::<Vec<_>>();
+        new_insertion_ctx.solution.required.extend(jobs);
+        new_insertion_ctx.environment = Arc::new(Environment { quota: None, ..environment.as_ref().clone() });
+
+        let mut new_insertion_ctx = InsertionHeuristic::default().process(
+            new_insertion_ctx,
+            &AllJobSelector::default(),
+            &AllRouteSelector::default(),
+            &LegSelection::Exhaustive,
+            &BestResultSelector::default(),
+        );
+        new_insertion_ctx.environment = environment;
+
+        let is_better = problem.goal.total_order(&new_insertion_ctx, insertion_ctx) == Ordering::Less;
+
+        is_better.then_some(new_insertion_ctx)
+    }
+}
+
+/// A key of the pool: an actor and sorted indices of the route jobs.
+type ColumnKey = (Arc<Actor>, Vec<usize>);
+
+/// Keeps the cheapest known route for each combination of actor and job set.
+pub struct RoutePool {
+    max_size: usize,
+    inner: Mutex<RoutePoolInner>,
+}
+
+#[derive(Default)]
+struct RoutePoolInner {
+    job_index: HashMap<Job, usize>,
+    columns: HashMap<ColumnKey, (RouteContext, Cost)>,
+}
+
+/// A route of the pool used as a column of set partitioning problem.
+struct Column {
+    route_ctx: RouteContext,
+    actor_idx: usize,
+    jobs: Vec<usize>,
+    cost: Cost,
+}
+
+impl RoutePool {
+    /// Creates a new instance of `RoutePool` with given maximum size.
+    pub fn new(max_size: usize) -> Self {
+        Self { max_size: max_size.max(1), inner: Mutex::new(RoutePoolInner::default()) }
+    }
+
+    /// Returns amount of routes in the pool.
+    pub fn size(&self) -> usize {
+        self.inner.lock().unwrap().columns.len()
+    }
+
+    /// Adds routes of given solutions to the pool.
+    pub fn add(&self, solutions: &[InsertionContext]) {
+        let mut inner = self.inner.lock().unwrap();
+        if inner.job_index.is_empty() {
+            return;
+        }
+
+        solutions.iter().flat_map(|insertion_ctx| insertion_ctx.solution.routes.iter()).for_each(|route_ctx| {
+            let Some((key, cost)) = get_key(&inner.job_index, route_ctx).zip(route_ctx.get_route_cost()) else {
+                return;
+            };
+
+            let is_better = inner.columns.get(&key).is_none_or(|(_, known_cost)| cost < *known_cost);
+            if is_better {
+                inner.columns.insert(key, (route_ctx.deep_copy(), cost));
+            }
+        });
+
+        // NOTE shrink the pool lazily, keeping routes with the lowest cost per job
+        if inner.columns.len() > 2 * self.max_size {
+            let mut columns = inner.columns.drain().collect::<Vec<_>>();
+            columns.sort_by(|((_, a_jobs), (_, a_cost)), ((_, b_jobs), (_, b_cost))| {
+                (a_cost / a_jobs.len() as Cost)
+                    .total_cmp(&(b_cost / b_jobs.len() as Cost))
+                    .then_with(|| a_jobs.cmp(b_jobs))
+            });
+            columns.truncate(self.max_size);
+            inner.columns.extend(columns);
+        }
+    }
+
+    /// Removes all routes from the pool and prepares it to be used with the problem of given context.
+    /// NOTE conditional jobs (e.g. breaks) are not part of the pool keys as they are ignored initially.
+    fn reset(&self, insertion_ctx: &InsertionContext) {
+        let mut inner = self.inner.lock().unwrap();
+        let ignored = &insertion_ctx.solution.ignored;
+
+        inner.columns.clear();
+        inner.job_index = insertion_ctx
+            .problem
+            .jobs
+            .all()
+            .iter()
+            .filter(|job| !ignored.contains(*job))
+            .enumerate()
+            .map(|(idx, job)| (job.clone(), idx))
+            .collect();
+    }
+
+    fn get_key(&self, route_ctx: &RouteContext) -> Option<ColumnKey> {
+        get_key(&self.inner.lock().unwrap().job_index, route_ctx)
+    }
+
+    /// Returns routes of the pool ordered deterministically.
+    fn get_columns(&self, problem: &Problem) -> Vec<Column> {
+        let inner = self.inner.lock().unwrap();
+        let actors = problem.fleet.actors.as_slice();
+
+        let mut columns = inner
+            .columns
+            .iter()
+            .filter_map(|((actor, jobs), (route_ctx, cost))| {
+                let actor_idx = actors.iter().position(|other| other == actor)?;
+                Some(Column { route_ctx: route_ctx.deep_copy(), actor_idx, jobs: jobs.clone(), cost: *cost })
+            })
+            .collect::<Vec<_>>();
+
+        columns.sort_by(|a, b| {
+            a.cost.total_cmp(&b.cost).then_with(|| a.actor_idx.cmp(&b.actor_idx)).then_with(|| a.jobs.cmp(&b.jobs))
+        });
+
+        columns
+    }
+}
+
+fn get_key(job_index: &HashMap<Job, usize>, route_ctx: &RouteContext) -> Option<ColumnKey> {
+    let mut jobs = route_ctx.route().tour.jobs().filter_map(|job| job_index.get(job).copied()).collect::<Vec<_>>();
+    if jobs.is_empty() {
+        return None;
+    }
+
+    jobs.sort_unstable();
+
+    Some((route_ctx.route().actor.clone(), jobs))
+}
+
+/// Solves set partitioning problem over given columns: jobs should be covered at most once, each actor
+/// is used at most once, not covered jobs are penalized by the cost of the most expensive column.
+/// Lagrangian relaxation of job constraints is solved using subgradient optimization, a feasible
+/// selection is built greedily from reduced costs on each iteration. Returns sorted indices of the
+/// best found selection of columns.
+fn solve_set_partitioning(columns: &[Column], initial: Vec<usize>, max_iterations: usize) -> Vec<usize> {
+    let jobs_size = columns.iter().flat_map(|column| column.jobs.iter()).max().map_or(0, |max| max + 1);
+    let actors_size = columns.iter().map(|column| column.actor_idx).max().map_or(0, |max| max + 1);
+
+    let penalty = columns.iter().map(|column| column.cost).fold(1., Cost::max);
+
+    let get_primal_cost = |selection: &[usize]| {
+        let covered = selection.iter().flat_map(|&idx| columns[idx].jobs.iter().copied()).collect::<HashSet<_>>();
+        let uncovered = (0..jobs_size).filter(|job| !covered.contains(job)).count() as Cost * penalty;
+
+        selection.iter().map(|&idx| columns[idx].cost).sum::<Cost>() + uncovered
+    };
+
+    let mut best = initial;
+    best.sort_unstable();
+    let mut best_cost = get_primal_cost(&best);
+
+    // NOTE start from the cheapest cost per job of the column covering the job
+    let mut multipliers = columns.iter().fold(vec![penalty; jobs_size], |mut acc, column| {
+        let value = column.cost / column.jobs.len() as Cost;
+        column.jobs.iter().for_each(|&job| acc[job] = acc[job].min(value));
+        acc
+    });
+
+    let mut step_scale = 2.;
+    let mut best_bound = Cost::MIN;
+    let mut no_improvement = 0;
+
+    for _ in 0..max_iterations {
+        let reduced_costs = columns
+            .iter()
+            .map(|column| column.cost - column.jobs.iter().map(|&job| multipliers[job]).sum::<Cost>())
+            .collect::<Vec<_>>();
+
+        // solve lagrangian subproblem: select a column with the lowest negative reduced cost per actor
+        let relaxed = (0..columns.len()).fold(vec![None::<usize>; actors_size], |mut acc, idx| {
+            let actor_idx = columns[idx].actor_idx;
+            if reduced_costs[idx] < 0. && acc[actor_idx].is_none_or(|other| reduced_costs[idx] < reduced_costs[other]) {
+                acc[actor_idx] = Some(idx);
+            }
+            acc
+        });
+        let relaxed = relaxed.into_iter().flatten().collect::<Vec<_>>();
+
+        let bound = multipliers.iter().map(|value| value.min(penalty)).sum::<Cost>()
+            + relaxed.iter().map(|&idx| reduced_costs[idx]).sum::<Cost>();
+
+        if bound > best_bound {
+            best_bound = bound;
+            no_improvement = 0;
+        } else {
+            no_improvement += 1;
+            if no_improvement >= 10 {
+                step_scale /= 2.;
+                no_improvement = 0;
+            }
+        }
+
+        let selection = select_greedy(columns, &reduced_costs, actors_size, jobs_size);
+        let cost = get_primal_cost(&selection);
+        if cost < best_cost {
+            best = selection;
+            best_cost = cost;
+        }
+
+        let subgradient = relaxed.iter().fold(vec![1_i32; jobs_size], |mut acc, &idx| {
+            columns[idx].jobs.iter().for_each(|&job| acc[job] -= 1);
+            acc
+        });
+        let norm = subgradient.iter().map(|&value| (value * value) as Cost).sum::<Cost>();
+
+        if norm == 0. || best_cost - bound <= 1E-6 || step_scale < 1E-4 {
+            break;
+        }
+
+        let step = step_scale * (best_cost - bound) / norm;
+        multipliers.iter_mut().zip(subgradient.iter()).for_each(|(value, &gradient)| {
+            *value = (*value + step * gradient as Cost).clamp(-penalty, penalty);
+        });
+    }
+
+    best
+}
+
+/// Selects columns which do not share jobs and actors in order of their reduced cost per job.
+fn select_greedy(columns: &[Column], reduced_costs: &[Cost], actors_size: usize, jobs_size: usize) -> Vec<usize> {
+    let mut order = (0..columns.len()).collect::<Vec<_>>();
+    order.sort_by(|&a, &b| {
+        let a_value = reduced_costs[a] / columns[a].jobs.len() as Cost;
+        let b_value = reduced_costs[b] / columns[b].jobs.len() as Cost;
+        a_value.total_cmp(&b_value).then_with(|| a.cmp(&b))
+    });
+
+    let mut used_actors = vec![false; actors_size];
+    let mut covered_jobs = vec![false; jobs_size];
+
+    let mut selection = order
+        .into_iter()
+        .filter(|&idx| {
+            let column = &columns[idx];
+            if used_actors[column.actor_idx] || column.jobs.iter().any(|&job| covered_jobs[job]) {
+                return false;
+            }
+
+            used_actors[column.actor_idx] = true;
+            column.jobs.iter().for_each(|&job| covered_jobs[job] = true);
+
+            true
+        })
+        .collect::<Vec<_>>();
+
+    selection.sort_unstable();
+
+    selection
+}
@@ -4,6 +4,8 @@ mod unassignment_reason_test;
 
 use super::*;
 use crate::construction::heuristics::*;
+use crate::models::common::TimeWindow;
+use crate::models::solution::Tour;
 use rosomaxa::utils::{CollectGroupBy, parallel_into_collect};
 
 /// Tries to improve job unassignment reason.
@@ -33,24 +35,27 @@ impl HeuristicSolutionProcessing for UnassignmentReason {
                 .iter()
                 .filter_map(|route_ctx| {
                     (0..route_ctx.route().tour.legs().count())
-                        .map(|leg_idx| {
-                            eval_job_insertion_in_route(
+                        .filter_map(|leg_idx| {
+                            match eval_job_insertion_in_route(
                                 &insertion_ctx,
                                 &eval_ctx,
                                 route_ctx,
                                 InsertionPosition::Concrete(leg_idx),
                                 InsertionResult::make_failure(),
-                            )
+                            ) {
+                                InsertionResult::Failure(failure) => Some((leg_idx, failure.constraint)),
+                                _ => None,
+                            }
                         })
-                        .filter_map(|result| match result {
-                            InsertionResult::Failure(failure) => Some(failure),
-                            _ => None,
-                        })
-                        .collect_group_by_key(|code| code.constraint)
+                        .collect_group_by_key(|(_, code)| *code)
                         .into_iter()
                         // NOTE: pick only the most frequent reason
                         .max_by(|(_, a), (_, b)| a.len().cmp(&b.len()))
-                        .map(|(code, _)| (route_ctx.route().actor.clone(), code))
+                        .map(|(code, legs)| UnassignmentDetail {
+                            actor: route_ctx.route().actor.clone(),
+                            code,
+                            time: get_legs_time(&route_ctx.route().tour, legs.iter().map(|(leg_idx, _)| *leg_idx)),
+                        })
                 })
                 .collect::<Vec<_>>();
 
@@ -64,3 +69,15 @@ impl HeuristicSolutionProcessing for UnassignmentReason {
         insertion_ctx
     }
 }
+
+/// Returns a time span which covers all given tour legs.
+fn get_legs_time(tour: &Tour, leg_indices: impl Iterator<Item = usize>) -> Option<TimeWindow> {
+    leg_indices
+        .filter_map(|leg_idx| {
+            let start = tour.get(leg_idx)?.schedule.departure;
+            let end = tour.get(leg_idx + 1).map_or(start, |activity| activity.schedule.arrival);
+
+            Some(TimeWindow::new(start, end))
+        })
+        .reduce(|acc, time| TimeWindow::new(acc.start.min(time.start), acc.end.max(time.end)))
+}
@@ -4,7 +4,7 @@ mod vicinity_clustering_test;
 
 use super::*;
 use crate::construction::clustering::vicinity::*;
-use crate::models::common::Schedule;
+use crate::models::common::{Schedule, TimeWindow, Timestamp};
 use crate::models::problem::Jobs;
 use crate::models::solution::{Activity, Place};
 use crate::models::{Extras, GoalContext, Problem};
@@ -63,7 +63,17 @@ impl HeuristicContextProcessing for VicinityClustering {
 
             let problem = Arc::new(Problem {
                 fleet: problem.fleet.clone(),
-                jobs: Arc::new(Jobs::new(problem.fleet.as_ref(), jobs, problem.transport.as_ref(), &logger).unwrap()),
+                jobs: Arc::new(
+                    Jobs::new_with_modes(
+                        problem.fleet.as_ref(),
+                        jobs,
+                        problem.transport.as_ref(),
+                        problem.jobs.index_mode().clone(),
+                        problem.jobs.clustering_mode().clone(),
+                        &logger,
+                    )
+                    .unwrap(),
+                ),
                 locks: problem.locks.clone(),
                 goal: problem.goal.clone(),
                 activity: problem.activity.clone(),
@@ -92,6 +102,8 @@ impl HeuristicSolutionProcessing for VicinityClustering {
             }
         };
 
+        let transport = insertion_ctx.problem.transport.clone();
+
         insertion_ctx.solution.routes.iter_mut().for_each(|route_ctx| {
             #[allow(clippy::needless_collect)]
             let clusters = route_ctx
@@ -111,39 +123,24 @@ impl HeuristicSolutionProcessing for VicinityClustering {
                 let cluster_activity = route_ctx.route().tour.get(activity_idx).unwrap();
                 let cluster_time = cluster_activity.place.time.clone();
                 let cluster_arrival = cluster_activity.schedule.arrival;
-                let last_job = cluster.last().unwrap().job.clone();
-
-                let (_, activities) =
-                    cluster.into_iter().fold((cluster_arrival, Vec::new()), |(arrival, mut activities), info| {
-                        // NOTE assumption: no waiting time possible in between of clustered jobs
-                        let job = info.job.to_single().clone();
-                        let place_idx = 0;
-                        let place = &job.places[place_idx];
-
-                        let backward = match config.visiting {
-                            VisitPolicy::Return => info.commute.backward.duration,
-                            VisitPolicy::ClosedContinuation if info.job == last_job => info.commute.backward.duration,
-                            _ => 0.,
-                        };
-
-                        let service_time = info.service_time;
-                        let service_start = (arrival + info.commute.forward.duration).max(cluster_time.start);
-                        let departure = service_start + service_time + backward;
-
-                        activities.push(Activity {
-                            place: Place {
-                                idx: place_idx,
-                                location: place.location.unwrap(),
-                                duration: info.service_time,
-                                time: cluster_time.clone(),
-                            },
-                            schedule: Schedule::new(arrival, departure),
-                            job: Some(job),
-                            commute: Some(info.commute),
-                        });
-
-                        (departure, activities)
-                    });
+
+                let optimized = if config.solving.flexible_order {
+                    get_optimized_order(cluster.as_slice(), config.as_ref(), transport.as_ref())
+                } else {
+                    None
+                };
+
+                let (activities, _) = get_cluster_activities(cluster, config.as_ref(), cluster_arrival, &cluster_time);
+
+                // NOTE use optimized order only if it does not finish later and respects time windows of the jobs
+                let activities = optimized
+                    .map(|cluster| get_cluster_activities(cluster, config.as_ref(), cluster_arrival, &cluster_time))
+                    .filter(|(optimized, is_feasible)| {
+                        let get_departure = |activities: &[Activity]| activities.last().map(|a| a.schedule.departure);
+
+                        *is_feasible && get_departure(optimized) <= get_departure(activities.as_slice())
+                    })
+                    .map_or(activities, |(optimized, _)| optimized);
 
                 route_ctx.route_mut().tour.remove_activity_at(activity_idx);
                 activities.into_iter().enumerate().for_each(|(seq_idx, activity)| {
@@ -170,3 +167,57 @@ impl HeuristicSolutionProcessing for VicinityClustering {
         insertion_ctx
     }
 }
+
+/// Creates activities of clustered jobs visited in the given order. Returns also a flag whether
+/// service of all jobs starts within their own time windows.
+fn get_cluster_activities(
+    cluster: Vec<ClusterInfo>,
+    config: &ClusterConfig,
+    cluster_arrival: Timestamp,
+    cluster_time: &TimeWindow,
+) -> (Vec<Activity>, bool) {
+    let last_job = cluster.last().unwrap().job.clone();
+
+    let (_, activities, is_feasible) = cluster.into_iter().fold(
+        (cluster_arrival, Vec::new(), true),
+        |(arrival, mut activities, is_feasible), info| {
+            // NOTE assumption: no waiting time possible in between of clustered jobs
+            let job = info.job.to_single().clone();
+            let place_idx = 0;
+            let place = &job.places[place_idx];
+
+            let backward = match config.visiting {
+                VisitPolicy::Return => info.commute.backward.duration,
+                VisitPolicy::ClosedContinuation if info.job == last_job => info.commute.backward.duration,
+                _ => 0.,
+            };
+
+            let service_time = info.service_time;
+            let service_start = (arrival + info.commute.forward.duration).max(cluster_time.start);
+            let departure = service_start + service_time + backward;
+
+            let is_feasible = is_feasible
+                && (place.times.is_empty()
+                    || place
+                        .times
+                        .iter()
+                        .any(|time| time.as_time_window().is_none_or(|tw| tw.contains(service_start))));
+
+            activities.push(Activity {
+                place: Place {
+                    idx: place_idx,
+                    location: place.location.unwrap(),
+                    duration: info.service_time,
+                    time: cluster_time.clone(),
+                },
+                schedule: Schedule::new(arrival, departure),
+                job: Some(job),
+                commute: Some(info.commute),
+            });
+
+            (departure, activities, is_feasible)
+        },
+    );
+
+    (activities, is_feasible)
+}
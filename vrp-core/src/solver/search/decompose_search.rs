@@ -4,20 +4,32 @@ mod decompose_search_test;
 
 use crate::construction::heuristics::*;
 use crate::models::GoalContext;
+use crate::models::problem::Job;
 use crate::solver::search::create_environment_with_custom_quota;
 use crate::solver::*;
 use crate::utils::Either;
+use rand::prelude::SliceRandom;
 use rosomaxa::utils::parallel_into_collect;
 use std::cmp::Ordering;
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::iter::{empty, once};
 
 /// A search operator which decomposes an original solution into multiple partial solutions,
 /// performs search independently, and then merges partial solutions back into one solution.
 pub struct DecomposeSearch {
     inner_search: TargetSearchOperator,
-    max_routes_range: (i32, i32),
+    grouping: RouteGrouping,
     repeat_count: usize,
+    exact_max_jobs: Option<usize>,
+}
+
+/// Specifies how routes are grouped into partial solutions.
+enum RouteGrouping {
+    /// Each route is grouped with its closest routes, amount of routes in a group is in the given range.
+    Proximity { max_routes_range: (i32, i32) },
+    /// Routes are assigned to job clusters which are merged into partitions around randomly selected
+    /// seed clusters, amount of partitions is in the given range.
+    Geographic { partitions_range: (i32, i32) },
 }
 
 impl DecomposeSearch {
@@ -26,7 +38,39 @@ impl DecomposeSearch {
         assert!(max_routes_range.0 > 1);
         let max_routes_range = (max_routes_range.0 as i32, max_routes_range.1 as i32);
 
-        Self { inner_search, max_routes_range, repeat_count }
+        Self {
+            inner_search,
+            grouping: RouteGrouping::Proximity { max_routes_range },
+            repeat_count,
+            exact_max_jobs: None,
+        }
+    }
+
+    /// Create a new instance of `DecomposeSearch` which partitions the problem geographically using
+    /// job clusters. Such decomposition keeps each sub-problem compact regardless of the problem size,
+    /// so it scales better on large problems. Falls back to inner search when there are not enough clusters.
+    pub fn new_geographic(
+        inner_search: TargetSearchOperator,
+        partitions_range: (usize, usize),
+        repeat_count: usize,
+    ) -> Self {
+        assert!(partitions_range.0 > 1 && partitions_range.0 <= partitions_range.1);
+        let partitions_range = (partitions_range.0 as i32, partitions_range.1 as i32);
+
+        Self {
+            inner_search,
+            grouping: RouteGrouping::Geographic { partitions_range },
+            repeat_count,
+            exact_max_jobs: None,
+        }
+    }
+
+    /// Enables exact re-sequencing of routes with up to `max_jobs` jobs in each refined partial
+    /// solution. It is applied after inner search, so small routes are guaranteed to have the best
+    /// order of their jobs when exact search is completed within its limits.
+    pub fn with_exact_polishing(mut self, max_jobs: usize) -> Self {
+        self.exact_max_jobs = Some(max_jobs);
+        self
     }
 }
 
@@ -39,7 +83,7 @@ impl HeuristicSearchOperator for DecomposeSearch {
         let refinement_ctx = heuristic_ctx;
         let insertion_ctx = solution;
 
-        decompose_insertion_context(refinement_ctx, insertion_ctx, self.max_routes_range, self.repeat_count)
+        decompose_insertion_context(refinement_ctx, insertion_ctx, &self.grouping, self.repeat_count)
             .map(|contexts| self.refine_decomposed(refinement_ctx, contexts))
             .unwrap_or_else(|| self.inner_search.search(heuristic_ctx, insertion_ctx))
     }
@@ -47,6 +91,9 @@ impl HeuristicSearchOperator for DecomposeSearch {
 
 const GREEDY_ERROR: &str = "greedy population has no insertion_ctxs";
 
+/// Limits the exact search per route as it is performed on each decomposition.
+const EXACT_POLISHING_MAX_NODES: usize = 2000;
+
 impl DecomposeSearch {
     fn refine_decomposed(
         &self,
@@ -76,6 +123,11 @@ impl DecomposeSearch {
 
                 if is_quota_reached { Err(()) } else { Ok(()) }
             });
+
+            if let Some(max_jobs) = self.exact_max_jobs {
+                polish_exactly(&mut refinement_ctx, max_jobs);
+            }
+
             (refinement_ctx, route_indices)
         });
 
@@ -106,6 +158,22 @@ impl DecomposeSearch {
     }
 }
 
+/// Re-sequences small routes of the best partial solution exactly and adds the result to the population.
+fn polish_exactly(refinement_ctx: &mut RefinementContext, max_jobs: usize) {
+    let insertion_ctx = refinement_ctx.selected().next().expect(GREEDY_ERROR);
+    let actors =
+        insertion_ctx.solution.routes.iter().map(|route_ctx| route_ctx.route().actor.clone()).collect::<Vec<_>>();
+
+    let polished = actors.iter().fold(None, |polished: Option<InsertionContext>, actor| {
+        let current = polished.as_ref().unwrap_or(insertion_ctx);
+        sequence_route_exactly(current, actor, max_jobs, EXACT_POLISHING_MAX_NODES).or(polished)
+    });
+
+    if let Some(polished) = polished {
+        refinement_ctx.add_solution(polished);
+    }
+}
+
 fn create_population(insertion_ctx: InsertionContext) -> TargetPopulation {
     // Keep baseline and (optionally) best/last candidate without reconstructing baseline later.
     Box::new(DecomposePopulation::new(insertion_ctx.problem.goal.clone(), 1, insertion_ctx))
@@ -135,19 +203,42 @@ fn get_repeat_count(max_repeat_count: usize, random: &dyn Random) -> usize {
 fn create_multiple_insertion_contexts(
     insertion_ctx: &InsertionContext,
     environment: Arc<Environment>,
-    max_routes_range: (i32, i32),
+    grouping: &RouteGrouping,
 ) -> Option<Vec<(InsertionContext, HashSet<usize>)>> {
     if insertion_ctx.solution.routes.is_empty() {
         return None;
     }
 
+    let route_groups = match grouping {
+        RouteGrouping::Proximity { max_routes_range } => {
+            group_routes_with_neighbours(insertion_ctx, environment.as_ref(), *max_routes_range)
+        }
+        RouteGrouping::Geographic { partitions_range } => {
+            group_routes_by_clusters(insertion_ctx, environment.as_ref(), *partitions_range)
+        }
+    };
+
+    let insertion_ctxs = route_groups
+        .into_iter()
+        .map(|route_group| create_partial_insertion_ctx(insertion_ctx, environment.clone(), route_group))
+        .chain(create_empty_insertion_ctxs(insertion_ctx, environment.clone()))
+        .collect();
+
+    Some(insertion_ctxs)
+}
+
+fn group_routes_with_neighbours(
+    insertion_ctx: &InsertionContext,
+    environment: &Environment,
+    max_routes_range: (i32, i32),
+) -> Vec<HashSet<usize>> {
     let route_groups = group_routes_by_proximity(insertion_ctx);
     let (min, max) = max_routes_range;
     let max = if insertion_ctx.solution.routes.len() < max as usize { (max / 2).max(min) } else { max };
 
-    // identify route groups and create contexts from them
+    // identify route groups
     let mut used_indices: HashSet<usize> = HashSet::new();
-    let insertion_ctxs = route_groups
+    route_groups
         .into_iter()
         .enumerate()
         .filter_map(|(outer_idx, route_group)| {
@@ -163,12 +254,97 @@ fn create_multiple_insertion_contexts(
 
             used_indices.extend(route_group.iter().copied());
 
-            Some(create_partial_insertion_ctx(insertion_ctx, environment.clone(), route_group))
+            Some(route_group)
         })
-        .chain(create_empty_insertion_ctxs(insertion_ctx, environment.clone()))
-        .collect();
+        .collect()
+}
 
-    Some(insertion_ctxs)
+/// Groups routes into geographic partitions: each route is assigned to the job cluster which contains
+/// most of its jobs, then clusters are merged into partitions around randomly selected seed clusters
+/// using distance between their representative locations. Routes without clustered jobs are treated as
+/// separate clusters.
+fn group_routes_by_clusters(
+    insertion_ctx: &InsertionContext,
+    environment: &Environment,
+    partitions_range: (i32, i32),
+) -> Vec<HashSet<usize>> {
+    let routes = &insertion_ctx.solution.routes;
+    let transport = insertion_ctx.problem.transport.as_ref();
+    let job_clusters = insertion_ctx
+        .problem
+        .jobs
+        .clusters()
+        .iter()
+        .enumerate()
+        .flat_map(|(cluster_idx, cluster)| cluster.iter().map(move |job| (job.clone(), cluster_idx)))
+        .collect::<HashMap<Job, usize>>();
+
+    // NOTE routes without clustered jobs form their own buckets, use ordered map to keep order stable
+    let buckets =
+        routes.iter().enumerate().fold(BTreeMap::<usize, Vec<usize>>::new(), |mut buckets, (idx, route_ctx)| {
+            let key = get_majority_cluster(route_ctx, &job_clusters).unwrap_or(job_clusters.len() + idx);
+            buckets.entry(key).or_default().push(idx);
+            buckets
+        });
+
+    // NOTE keep all routes within one partition, so unassigned jobs are still refined separately
+    if buckets.len() < 2 {
+        return vec![(0..routes.len()).collect()];
+    }
+
+    let buckets = buckets
+        .into_values()
+        .map(|route_indices| {
+            let route_ctx = &routes[route_indices[0]];
+            let location = route_ctx.route().tour.all_activities().find(|a| a.job.is_some()).map(|a| a.place.location);
+            (route_indices, location, &route_ctx.route().actor.vehicle.profile)
+        })
+        .collect::<Vec<_>>();
+
+    let (min, max) = partitions_range;
+    let partitions = (environment.random.uniform_int(min, max) as usize).min(buckets.len());
+    let mut seeds = (0..buckets.len()).collect::<Vec<_>>();
+    seeds.shuffle(&mut environment.random.get_rng());
+    seeds.truncate(partitions);
+
+    let mut route_groups = vec![HashSet::new(); partitions];
+    buckets.iter().enumerate().for_each(|(bucket_idx, (route_indices, location, profile))| {
+        let partition_idx = seeds.iter().position(|&seed_idx| seed_idx == bucket_idx).unwrap_or_else(|| {
+            seeds
+                .iter()
+                .enumerate()
+                .map(|(partition_idx, &seed_idx)| {
+                    let distance = match (location, buckets[seed_idx].1) {
+                        (Some(from), Some(to)) => transport.distance_approx(profile, *from, to).max(0.),
+                        _ => Float::MAX,
+                    };
+                    (partition_idx, distance)
+                })
+                .min_by(|(_, a), (_, b)| a.total_cmp(b))
+                .map(|(partition_idx, _)| partition_idx)
+                .unwrap_or_default()
+        });
+
+        route_groups[partition_idx].extend(route_indices.iter().copied());
+    });
+
+    route_groups
+}
+
+/// Returns index of the cluster which contains the most jobs of the route.
+fn get_majority_cluster(route_ctx: &RouteContext, job_clusters: &HashMap<Job, usize>) -> Option<usize> {
+    route_ctx
+        .route()
+        .tour
+        .jobs()
+        .filter_map(|job| job_clusters.get(job))
+        .fold(BTreeMap::<usize, usize>::new(), |mut counts, &cluster_idx| {
+            *counts.entry(cluster_idx).or_default() += 1;
+            counts
+        })
+        .into_iter()
+        .max_by(|(a_idx, a_count), (b_idx, b_count)| a_count.cmp(b_count).then(b_idx.cmp(a_idx)))
+        .map(|(cluster_idx, _)| cluster_idx)
 }
 
 fn create_partial_insertion_ctx(
@@ -178,7 +354,11 @@ fn create_partial_insertion_ctx(
 ) -> (InsertionContext, HashSet<usize>) {
     let solution = &insertion_ctx.solution;
 
-    let routes = route_indices.iter().map(|idx| solution.routes[*idx].deep_copy()).collect::<Vec<_>>();
+    // NOTE keep routes in the original order, so it does not depend on hashing
+    let mut ordered_indices = route_indices.iter().copied().collect::<Vec<_>>();
+    ordered_indices.sort_unstable();
+
+    let routes = ordered_indices.into_iter().map(|idx| solution.routes[idx].deep_copy()).collect::<Vec<_>>();
     let actors = routes.iter().map(|route_ctx| route_ctx.route().actor.clone()).collect::<HashSet<_>>();
     let registry = solution.registry.deep_slice(|actor| actors.contains(actor));
 
@@ -247,7 +427,7 @@ fn create_empty_insertion_ctxs(
 fn decompose_insertion_context(
     refinement_ctx: &RefinementContext,
     insertion_ctx: &InsertionContext,
-    max_routes_range: (i32, i32),
+    grouping: &RouteGrouping,
     repeat: usize,
 ) -> Option<Vec<(RefinementContext, HashSet<usize>)>> {
     // NOTE make limit a bit higher than median
@@ -255,7 +435,7 @@ fn decompose_insertion_context(
     let limit = median.map(|median| (((median.max(10) * repeat) as f64) * 1.5) as usize);
     let environment = create_environment_with_custom_quota(limit, refinement_ctx.environment.as_ref());
 
-    create_multiple_insertion_contexts(insertion_ctx, environment.clone(), max_routes_range)
+    create_multiple_insertion_contexts(insertion_ctx, environment.clone(), grouping)
         .map(|insertion_ctxs| {
             insertion_ctxs
                 .into_iter()
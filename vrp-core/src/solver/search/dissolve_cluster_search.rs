@@ -0,0 +1,51 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/search/dissolve_cluster_search_test.rs"]
+mod dissolve_cluster_search_test;
+
+use super::*;
+use crate::construction::clustering::vicinity::ClusterInfoDimension;
+use crate::construction::heuristics::*;
+use crate::models::GoalContext;
+
+/// A search operator which dissolves unassigned clusters of jobs created by vicinity clustering and
+/// tries to insert their jobs individually, so the rest of the search can improve their placement.
+#[derive(Default)]
+pub struct DissolveClusterSearch {}
+
+impl HeuristicSearchOperator for DissolveClusterSearch {
+    type Context = RefinementContext;
+    type Objective = GoalContext;
+    type Solution = InsertionContext;
+
+    fn search(&self, _: &Self::Context, solution: &Self::Solution) -> Self::Solution {
+        let mut insertion_ctx = solution.deep_copy();
+
+        let (clusters, others): (Vec<_>, Vec<_>) = std::mem::take(&mut insertion_ctx.solution.unassigned)
+            .into_iter()
+            .partition(|(job, _)| job.dimens().get_cluster_info().is_some());
+
+        if clusters.is_empty() {
+            insertion_ctx.solution.unassigned.extend(others);
+            return insertion_ctx;
+        }
+
+        insertion_ctx.solution.required.extend(
+            clusters
+                .iter()
+                .flat_map(|(job, _)| job.dimens().get_cluster_info().into_iter().flatten())
+                .map(|info| info.job.clone()),
+        );
+
+        let mut insertion_ctx = InsertionHeuristic::default().process(
+            insertion_ctx,
+            &AllJobSelector::default(),
+            &AllRouteSelector::default(),
+            &LegSelection::Exhaustive,
+            &BestResultSelector::default(),
+        );
+
+        insertion_ctx.solution.unassigned.extend(others);
+
+        insertion_ctx
+    }
+}
@@ -0,0 +1,259 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/search/ejection_chain_search_test.rs"]
+mod ejection_chain_search_test;
+
+use super::*;
+use crate::construction::heuristics::*;
+use crate::models::problem::{Job, sort_jobs_canonically};
+use rand::prelude::SliceRandom;
+use rosomaxa::prelude::HeuristicObjective;
+use rosomaxa::utils::{Timer, is_deterministic_mode};
+use std::cmp::Ordering;
+use std::collections::{HashMap, VecDeque};
+
+/// A repair operator based on ejection chains as described in "A powerful route minimization heuristic
+/// for the vehicle routing problem with time windows" by Yuichi Nagata, Olli Bräysy.
+///
+/// Unassigned jobs are put into an ejection pool and inserted one by one. When a job cannot be inserted
+/// directly, up to `max_ejections` of its nearest neighbours are ejected from a route to make space for it.
+/// Ejections are enumerated in lexicographic order and the one with minimal sum of penalties is chosen,
+/// where penalty of the job is a number of times it failed to be inserted. Ejected jobs are returned back
+/// to the pool, so are jobs which failed to be inserted until their penalty reaches `max_penalty`.
+/// The search is bounded by amount of iterations and time budget.
+pub struct EjectionChainSearch {
+    max_ejections: usize,
+    neighbours: usize,
+    max_penalty: usize,
+    max_iterations: usize,
+    time_budget_ms: u128,
+}
+
+impl EjectionChainSearch {
+    /// Creates a new instance of `EjectionChainSearch`.
+    pub fn new(
+        max_ejections: usize,
+        neighbours: usize,
+        max_penalty: usize,
+        max_iterations: usize,
+        time_budget_ms: u128,
+    ) -> Self {
+        assert!(max_ejections > 0);
+
+        Self { max_ejections, neighbours: neighbours.max(1), max_penalty, max_iterations, time_budget_ms }
+    }
+}
+
+impl Default for EjectionChainSearch {
+    fn default() -> Self {
+        Self::new(2, 16, 5, 200, 100)
+    }
+}
+
+impl HeuristicSearchOperator for EjectionChainSearch {
+    type Context = RefinementContext;
+    type Objective = GoalContext;
+    type Solution = InsertionContext;
+
+    fn search(&self, _: &Self::Context, solution: &Self::Solution) -> Self::Solution {
+        let timer = Timer::start();
+        let goal = solution.problem.goal.as_ref();
+
+        let mut insertion_ctx = solution.deep_copy();
+        let mut pool = insertion_ctx.solution.unassigned.drain().map(|(job, _)| job).collect::<Vec<_>>();
+        if is_deterministic_mode() {
+            sort_jobs_canonically(&mut pool);
+        }
+        pool.shuffle(&mut insertion_ctx.environment.random.get_rng());
+        insertion_ctx.solution.required.extend(pool.iter().cloned());
+
+        let mut pool = VecDeque::from(pool);
+        let mut penalties = HashMap::<Job, usize>::default();
+        let mut best = solution.deep_copy();
+
+        for _ in 0..self.max_iterations {
+            // NOTE time budget is ignored in deterministic mode as elapsed time depends on hardware and load
+            if !is_deterministic_mode() && timer.elapsed_millis() > self.time_budget_ms {
+                break;
+            }
+
+            let Some(job) = pool.pop_front() else { break };
+            let penalty = {
+                let penalty = penalties.entry(job.clone()).or_default();
+                *penalty += 1;
+                *penalty
+            };
+
+            let is_inserted = if let Some(success) = eval_best_insertion(&insertion_ctx, &job) {
+                apply_insertion_success(&mut insertion_ctx, success);
+                true
+            } else if let Some((route_idx, ejected)) = self.eval_ejection(&insertion_ctx, &job, &penalties) {
+                // NOTE ejection is evaluated on the route copy without solution level state (e.g. shared
+                // resources), so insertion has to be confirmed once the jobs are ejected from the solution
+                let mut ejected_ctx = insertion_ctx.deep_copy();
+                eject_jobs(&mut ejected_ctx, route_idx, ejected.as_slice());
+
+                let route_ctx = ejected_ctx.solution.routes.get(route_idx).expect("invalid route index");
+                if let InsertionResult::Success(success) = eval_insertion_in_route(&ejected_ctx, route_ctx, &job) {
+                    apply_insertion_success(&mut ejected_ctx, success);
+                    insertion_ctx = ejected_ctx;
+                    pool.extend(ejected);
+                    true
+                } else {
+                    false
+                }
+            } else {
+                false
+            };
+
+            if !is_inserted {
+                // NOTE job can become insertable once other jobs are inserted, so it is kept in the pool
+                // until its penalty reaches the limit
+                if penalty < self.max_penalty {
+                    pool.push_back(job);
+                }
+                continue;
+            }
+
+            let mut candidate = insertion_ctx.deep_copy();
+            finalize_insertion_ctx(&mut candidate);
+
+            if goal.total_order(&candidate, &best) == Ordering::Less {
+                best = candidate;
+            }
+        }
+
+        best
+    }
+}
+
+impl EjectionChainSearch {
+    /// Finds a feasible ejection of nearest neighbours with minimal penalty sum which allows to insert the job.
+    fn eval_ejection(
+        &self,
+        insertion_ctx: &InsertionContext,
+        job: &Job,
+        penalties: &HashMap<Job, usize>,
+    ) -> Option<(usize, Vec<Job>)> {
+        let solution = &insertion_ctx.solution;
+        let get_penalty = |jobs: &[Job]| jobs.iter().map(|job| penalties.get(job).copied().unwrap_or(0)).sum::<usize>();
+
+        let mut best: Option<(usize, Vec<Job>)> = None;
+
+        solution.routes.iter().enumerate().for_each(|(route_idx, route_ctx)| {
+            let profile = &route_ctx.route().actor.vehicle.profile;
+            let candidates = insertion_ctx
+                .problem
+                .jobs
+                .neighbors(profile, job, Default::default())
+                .take(self.neighbours)
+                .map(|(neighbour, _)| neighbour)
+                .filter(|neighbour| !solution.locked.contains(*neighbour) && route_ctx.route().tour.contains(neighbour))
+                .cloned()
+                .collect::<Vec<_>>();
+
+            get_combinations(candidates.len(), self.max_ejections).into_iter().for_each(|indices| {
+                let ejected = indices.into_iter().map(|idx| candidates[idx].clone()).collect::<Vec<_>>();
+                let penalty = get_penalty(ejected.as_slice());
+
+                let is_better = best.as_ref().is_none_or(|(_, best_ejected)| {
+                    (penalty, ejected.len()) < (get_penalty(best_ejected.as_slice()), best_ejected.len())
+                });
+
+                if !is_better {
+                    return;
+                }
+
+                let mut route_ctx = route_ctx.deep_copy();
+                ejected.iter().for_each(|job| {
+                    route_ctx.route_mut().tour.remove(job);
+                });
+                insertion_ctx.problem.goal.accept_route_state(&mut route_ctx);
+
+                if let InsertionResult::Success(_) = eval_insertion_in_route(insertion_ctx, &route_ctx, job) {
+                    best = Some((route_idx, ejected));
+                }
+            });
+        });
+
+        best
+    }
+}
+
+/// Evaluates the best insertion of the job into existing routes or a new one.
+fn eval_best_insertion(insertion_ctx: &InsertionContext, job: &Job) -> Option<InsertionSuccess> {
+    let leg_selection = LegSelection::Exhaustive;
+    let result_selector = BestResultSelector::default();
+    let eval_ctx = EvaluationContext {
+        goal: &insertion_ctx.problem.goal,
+        job,
+        leg_selection: &leg_selection,
+        result_selector: &result_selector,
+    };
+
+    let result =
+        insertion_ctx.solution.routes.iter().chain(insertion_ctx.solution.registry.next_route()).fold(
+            InsertionResult::make_failure(),
+            |acc, route_ctx| {
+                eval_job_insertion_in_route(insertion_ctx, &eval_ctx, route_ctx, InsertionPosition::Any, acc)
+            },
+        );
+
+    match result {
+        InsertionResult::Success(success) => Some(success),
+        InsertionResult::Failure(_) => None,
+    }
+}
+
+fn eval_insertion_in_route(insertion_ctx: &InsertionContext, route_ctx: &RouteContext, job: &Job) -> InsertionResult {
+    let leg_selection = LegSelection::Exhaustive;
+    let result_selector = BestResultSelector::default();
+    let eval_ctx = EvaluationContext {
+        goal: &insertion_ctx.problem.goal,
+        job,
+        leg_selection: &leg_selection,
+        result_selector: &result_selector,
+    };
+
+    eval_job_insertion_in_route(
+        insertion_ctx,
+        &eval_ctx,
+        route_ctx,
+        InsertionPosition::Any,
+        InsertionResult::make_failure(),
+    )
+}
+
+/// Removes jobs from the route and returns them back to the required list.
+fn eject_jobs(insertion_ctx: &mut InsertionContext, route_idx: usize, jobs: &[Job]) {
+    let route_ctx = insertion_ctx.solution.routes.get_mut(route_idx).expect("invalid route index");
+
+    jobs.iter().for_each(|job| {
+        route_ctx.route_mut().tour.remove(job);
+    });
+
+    insertion_ctx.problem.goal.accept_route_state(route_ctx);
+    insertion_ctx.solution.required.extend(jobs.iter().cloned());
+    insertion_ctx.problem.goal.accept_solution_state(&mut insertion_ctx.solution);
+}
+
+/// Returns all combinations of indices with size from 1 to `max_size` in lexicographic order.
+fn get_combinations(size: usize, max_size: usize) -> Vec<Vec<usize>> {
+    let mut combinations = Vec::default();
+    let mut stack = (0..size).map(|idx| vec![idx]).rev().collect::<Vec<_>>();
+
+    while let Some(combination) = stack.pop() {
+        let last = *combination.last().unwrap();
+
+        if combination.len() < max_size {
+            (last + 1..size).rev().for_each(|idx| {
+                let mut next = combination.clone();
+                next.push(idx);
+                stack.push(next);
+            });
+        }
+
+        combinations.push(combination);
+    }
+
+    combinations
+}
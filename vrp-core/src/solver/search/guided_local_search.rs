@@ -0,0 +1,238 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/search/guided_local_search_test.rs"]
+mod guided_local_search_test;
+
+use super::*;
+use crate::models::Extras;
+use crate::models::common::{Cost, Distance, Duration, Location, Profile};
+use crate::models::problem::{TransportCost, TravelTime};
+use crate::models::solution::Route;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, RwLock};
+
+custom_extra_property!(pub ArcPenalties typeof ArcPenalties);
+
+/// Keeps penalties of arcs (pairs of locations) which were frequently present in local optima.
+/// Penalties are shared between [`GuidedLocalSearch`] which accumulates them and [`GuidedTransportCost`]
+/// which uses them to bias transport costs.
+///
+/// Penalties are published as immutable snapshots: readers keep a per-thread copy of the latest snapshot
+/// and touch the lock only when a new version is published, so cost calls stay lock-free.
+pub struct ArcPenalties {
+    /// Specifies a weight of penalties relatively to the average arc distance in local optimum.
+    alpha: Float,
+    /// Specifies a multiplier applied to all penalties each time a new local optimum is processed.
+    decay: Float,
+    id: usize,
+    version: AtomicUsize,
+    snapshot: RwLock<Arc<PenaltySnapshot>>,
+}
+
+#[derive(Clone, Default)]
+struct PenaltySnapshot {
+    arcs: HashMap<(Location, Location), Float>,
+    lambda: Float,
+}
+
+static PENALTIES_ID: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    /// Keeps the latest seen snapshot as (penalties id, version, snapshot).
+    static SNAPSHOT_CACHE: RefCell<Option<(usize, usize, Arc<PenaltySnapshot>)>> = const { RefCell::new(None) };
+}
+
+impl Default for ArcPenalties {
+    fn default() -> Self {
+        Self::new(0.1, 0.95)
+    }
+}
+
+impl ArcPenalties {
+    /// Creates a new instance of `ArcPenalties`.
+    pub fn new(alpha: Float, decay: Float) -> Self {
+        assert!(alpha >= 0.);
+        assert!(decay > 0. && decay <= 1.);
+
+        Self {
+            alpha,
+            decay,
+            id: PENALTIES_ID.fetch_add(1, Ordering::Relaxed),
+            version: AtomicUsize::new(0),
+            snapshot: RwLock::new(Arc::new(PenaltySnapshot::default())),
+        }
+    }
+
+    /// Returns penalty of the arc expressed in distance units.
+    pub fn get(&self, from: Location, to: Location) -> Distance {
+        let version = self.version.load(Ordering::Acquire);
+
+        // NOTE nothing is penalized yet
+        if version == 0 {
+            return Distance::default();
+        }
+
+        SNAPSHOT_CACHE.with(|cache| {
+            let mut cache = cache.borrow_mut();
+
+            let is_stale = !matches!(cache.as_ref(), Some((id, cached, _)) if *id == self.id && *cached == version);
+            if is_stale {
+                *cache = Some((self.id, version, self.snapshot.read().unwrap().clone()));
+            }
+
+            let (_, _, snapshot) = cache.as_ref().expect("snapshot is cached");
+
+            snapshot.arcs.get(&(from, to)).map_or(Distance::default(), |penalty| snapshot.lambda * penalty)
+        })
+    }
+
+    /// Removes all penalties, so transport costs are not biased anymore.
+    pub fn clear(&self) {
+        let mut snapshot_guard = self.snapshot.write().unwrap();
+        *snapshot_guard = Arc::new(PenaltySnapshot::default());
+        self.version.fetch_add(1, Ordering::Release);
+    }
+
+    /// Decays existing penalties and penalizes arcs with maximum utility in given local optimum.
+    /// Following GLS, the utility of an arc is its distance divided by one plus its current penalty.
+    pub fn penalize(&self, insertion_ctx: &InsertionContext) {
+        let transport = insertion_ctx.problem.transport.as_ref();
+
+        let arcs = insertion_ctx
+            .solution
+            .routes
+            .iter()
+            .flat_map(|route_ctx| {
+                let route = route_ctx.route();
+                route.tour.legs().filter_map(move |(activities, _)| match activities {
+                    [from, to] => Some((
+                        (from.place.location, to.place.location),
+                        transport.distance(
+                            route,
+                            from.place.location,
+                            to.place.location,
+                            TravelTime::Departure(from.schedule.departure),
+                        ),
+                    )),
+                    _ => None,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let mut snapshot_guard = self.snapshot.write().unwrap();
+        let mut state = snapshot_guard.as_ref().clone();
+
+        state.arcs.values_mut().for_each(|penalty| *penalty *= self.decay);
+        state.arcs.retain(|_, penalty| *penalty > 0.01);
+
+        if !arcs.is_empty() {
+            let total_distance = arcs.iter().map(|(_, distance)| *distance).sum::<Distance>();
+            state.lambda = self.alpha * total_distance / arcs.len() as Float;
+
+            let utilities = arcs
+                .iter()
+                .map(|(arc, distance)| (*arc, distance / (1. + state.arcs.get(arc).copied().unwrap_or_default())))
+                .collect::<Vec<_>>();
+            let max_utility = utilities.iter().map(|(_, utility)| *utility).fold(Float::MIN, Float::max);
+
+            utilities.into_iter().filter(|(_, utility)| (max_utility - utility).abs() < Float::EPSILON).for_each(
+                |(arc, _)| {
+                    *state.arcs.entry(arc).or_default() += 1.;
+                },
+            );
+        }
+
+        *snapshot_guard = Arc::new(state);
+        self.version.fetch_add(1, Ordering::Release);
+    }
+}
+
+/// A transport cost decorator which adds penalties of [`ArcPenalties`] to the cost of the arc. Distances and
+/// durations are not changed, so only insertion cost estimations are biased, while solution fitness stays
+/// the same.
+pub struct GuidedTransportCost {
+    inner: Arc<dyn TransportCost>,
+    penalties: Arc<ArcPenalties>,
+}
+
+impl GuidedTransportCost {
+    /// Creates a new instance of `GuidedTransportCost`.
+    pub fn new(inner: Arc<dyn TransportCost>, penalties: Arc<ArcPenalties>) -> Self {
+        Self { inner, penalties }
+    }
+}
+
+impl TransportCost for GuidedTransportCost {
+    fn cost(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Cost {
+        let actor = route.actor.as_ref();
+        let penalty = self.penalties.get(from, to);
+
+        self.inner.cost(route, from, to, travel_time)
+            + penalty * (actor.driver.costs.per_distance + actor.vehicle.costs.per_distance)
+    }
+
+    fn duration_approx(&self, profile: &Profile, from: Location, to: Location) -> Duration {
+        self.inner.duration_approx(profile, from, to)
+    }
+
+    fn distance_approx(&self, profile: &Profile, from: Location, to: Location) -> Distance {
+        self.inner.distance_approx(profile, from, to)
+    }
+
+    fn duration(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Duration {
+        self.inner.duration(route, from, to, travel_time)
+    }
+
+    fn distance(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Distance {
+        self.inner.distance(route, from, to, travel_time)
+    }
+
+    fn size(&self) -> usize {
+        self.inner.size()
+    }
+
+    fn sparse_neighbours(&self, profile: &Profile, from: Location) -> Option<Vec<Location>> {
+        self.inner.sparse_neighbours(profile, from)
+    }
+
+    fn matrix_key(&self, profile: &Profile) -> Option<usize> {
+        self.inner.matrix_key(profile)
+    }
+
+    fn fingerprint(&self, profile: &Profile) -> Option<u64> {
+        self.inner.fingerprint(profile)
+    }
+}
+
+/// A guided local search operator: runs inner search and treats its result as a local optimum whose
+/// arcs are penalized. To have an effect, the same penalties have to be used by [`GuidedTransportCost`]
+/// which wraps the problem's transport costs.
+///
+/// As penalties bias costs seen by all other operators, the operator is not used by default heuristic:
+/// both, transport wrapping and the operator, are opt-in.
+pub struct GuidedLocalSearch {
+    inner: TargetSearchOperator,
+    penalties: Arc<ArcPenalties>,
+}
+
+impl GuidedLocalSearch {
+    /// Creates a new instance of `GuidedLocalSearch`.
+    pub fn new(inner: TargetSearchOperator, penalties: Arc<ArcPenalties>) -> Self {
+        Self { inner, penalties }
+    }
+}
+
+impl HeuristicSearchOperator for GuidedLocalSearch {
+    type Context = RefinementContext;
+    type Objective = GoalContext;
+    type Solution = InsertionContext;
+
+    fn search(&self, heuristic_ctx: &Self::Context, solution: &Self::Solution) -> Self::Solution {
+        let local_optimum = self.inner.search(heuristic_ctx, solution);
+
+        self.penalties.penalize(&local_optimum);
+
+        local_optimum
+    }
+}
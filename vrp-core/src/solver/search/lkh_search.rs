@@ -105,7 +105,7 @@ impl LKHSearch {
     }
 }
 
-fn optimize_route(route_ctx: &mut RouteContext, transport: &dyn TransportCost) {
+pub(crate) fn optimize_route(route_ctx: &mut RouteContext, transport: &dyn TransportCost) {
     // skip routes that are too small for optimization
     if route_ctx.route().tour.total() <= 3 {
         return;
@@ -133,7 +133,7 @@ fn route_to_path(route_ctx: &RouteContext) -> Path {
 }
 
 /// Reshufles [RouteContext] according to [Path] ordering.
-fn rearrange_route(route_ctx: &mut RouteContext, mut path: Path) {
+pub(crate) fn rearrange_route(route_ctx: &mut RouteContext, mut path: Path) {
     let range = get_activity_range(&route_ctx.route().tour);
     let activities = route_ctx.route_mut().tour.activities_mut();
 
@@ -155,7 +155,7 @@ fn rearrange_route(route_ctx: &mut RouteContext, mut path: Path) {
 }
 
 /// Gets a range of activity indices for usage.
-fn get_activity_range(tour: &Tour) -> Range<usize> {
+pub(crate) fn get_activity_range(tour: &Tour) -> Range<usize> {
     debug_assert!(tour.total() > 1);
 
     // offset is used to skip the last activity if it has the same location as the first one
@@ -0,0 +1,87 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/search/local/exchange_or_opt_test.rs"]
+mod exchange_or_opt_test;
+
+use super::*;
+use crate::solver::search::{get_route_jobs, select_seed_job};
+
+/// A local search operator which implements inter-route Or-opt move: a segment of consecutive jobs
+/// is relocated to another route right before or after one of the nearest neighbours of the segment's
+/// first job. The order of jobs in the segment is preserved.
+pub struct ExchangeOrOpt {
+    max_segment_size: usize,
+    neighbours: usize,
+}
+
+impl ExchangeOrOpt {
+    /// Creates a new instance of `ExchangeOrOpt`.
+    pub fn new(max_segment_size: usize, neighbours: usize) -> Self {
+        assert!(max_segment_size > 0);
+
+        Self { max_segment_size, neighbours: neighbours.max(1) }
+    }
+}
+
+impl Default for ExchangeOrOpt {
+    fn default() -> Self {
+        Self::new(3, 8)
+    }
+}
+
+impl LocalOperator for ExchangeOrOpt {
+    fn explore(&self, _: &RefinementContext, insertion_ctx: &InsertionContext) -> Option<InsertionContext> {
+        let random = insertion_ctx.environment.random.as_ref();
+        let route_jobs = get_route_jobs(&insertion_ctx.solution);
+        let (_, seed_route_idx, seed_job) =
+            select_seed_job(insertion_ctx.solution.routes.as_slice(), random, &|_| true, &|job| {
+                is_movable_job(insertion_ctx, job)
+            })?;
+
+        let segment_size = random.uniform_int(1, self.max_segment_size as i32) as usize;
+        let segment = get_segment(insertion_ctx, seed_route_idx, &seed_job, segment_size)?;
+
+        select_best_candidate(
+            get_job_neighbours(insertion_ctx, &route_jobs, seed_route_idx, &seed_job, self.neighbours)
+                .filter_map(|(route_idx, job)| {
+                    insertion_ctx.solution.routes[route_idx].route().tour.index(&job).map(|idx| (route_idx, idx))
+                })
+                .flat_map(|(route_idx, idx)| [(route_idx, idx - 1), (route_idx, idx)])
+                .filter_map(|target| relocate_segment(insertion_ctx, seed_route_idx, segment.as_slice(), target)),
+        )
+    }
+}
+
+/// Returns up to `size` consecutive movable jobs starting from the seed one.
+fn get_segment(insertion_ctx: &InsertionContext, route_idx: usize, seed_job: &Job, size: usize) -> Option<Vec<Job>> {
+    let tour = &insertion_ctx.solution.routes[route_idx].route().tour;
+    let seed_idx = tour.index(seed_job)?;
+
+    let segment = tour
+        .all_activities()
+        .skip(seed_idx)
+        .map_while(|activity| activity.retrieve_job().filter(|job| is_movable_job(insertion_ctx, job)))
+        .take(size)
+        .collect::<Vec<_>>();
+
+    if segment.is_empty() { None } else { Some(segment) }
+}
+
+fn relocate_segment(
+    insertion_ctx: &InsertionContext,
+    source_route_idx: usize,
+    segment: &[Job],
+    (target_route_idx, index): (usize, usize),
+) -> Option<InsertionContext> {
+    let mut new_insertion_ctx = insertion_ctx.deep_copy();
+
+    remove_jobs_from_route(&mut new_insertion_ctx, source_route_idx, segment);
+
+    if insert_jobs_into_route(&mut new_insertion_ctx, target_route_idx, index, segment) {
+        finalize_insertion_ctx(&mut new_insertion_ctx);
+        new_insertion_ctx.solution.remove_empty_routes();
+
+        Some(new_insertion_ctx)
+    } else {
+        None
+    }
+}
@@ -24,12 +24,24 @@ use std::iter::once;
 pub struct ExchangeSwapStar {
     leg_selection: LegSelection,
     result_selector: Box<dyn ResultSelector>,
+    route_pairs_threshold: usize,
 }
 
 impl ExchangeSwapStar {
     /// Creates a new instance of `ExchangeSwapStar`.
     pub fn new(random: Arc<dyn Random>) -> Self {
-        Self { leg_selection: LegSelection::Stochastic(random), result_selector: Box::<BestResultSelector>::default() }
+        // NOTE higher value affects performance
+        Self::new_with_route_pairs(random, 8)
+    }
+
+    /// Creates a new instance of `ExchangeSwapStar` which explores at most given amount of route pairs
+    /// on each call.
+    pub fn new_with_route_pairs(random: Arc<dyn Random>, route_pairs_threshold: usize) -> Self {
+        Self {
+            leg_selection: LegSelection::Stochastic(random),
+            result_selector: Box::<BestResultSelector>::default(),
+            route_pairs_threshold: route_pairs_threshold.max(1),
+        }
     }
 }
 
@@ -39,10 +51,7 @@ impl LocalOperator for ExchangeSwapStar {
         refinement_ctx: &RefinementContext,
         insertion_ctx: &InsertionContext,
     ) -> Option<InsertionContext> {
-        // NOTE higher value affects performance
-        const ROUTE_PAIRS_THRESHOLD: usize = 8;
-
-        let route_pairs = create_route_pairs(insertion_ctx, ROUTE_PAIRS_THRESHOLD);
+        let route_pairs = create_route_pairs(insertion_ctx, self.route_pairs_threshold);
 
         // modify environment to include median as an extra quota to prevent long runs
         let limit =
@@ -0,0 +1,90 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/search/local/exchange_two_opt_star_test.rs"]
+mod exchange_two_opt_star_test;
+
+use super::*;
+use crate::solver::search::{get_route_jobs, select_seed_job};
+
+/// A local search operator which implements 2-opt* move: tails of two routes are exchanged, so that
+/// the first route continues with the tail of the second one and vice versa. Route pairs are selected
+/// using job neighbourhood: a tail of the seed job route is exchanged with a tail of its neighbour's route.
+pub struct ExchangeTwoOptStar {
+    neighbours: usize,
+}
+
+impl ExchangeTwoOptStar {
+    /// Creates a new instance of `ExchangeTwoOptStar` which considers given amount of nearest neighbours.
+    pub fn new(neighbours: usize) -> Self {
+        Self { neighbours: neighbours.max(1) }
+    }
+}
+
+impl Default for ExchangeTwoOptStar {
+    fn default() -> Self {
+        Self::new(8)
+    }
+}
+
+impl LocalOperator for ExchangeTwoOptStar {
+    fn explore(&self, _: &RefinementContext, insertion_ctx: &InsertionContext) -> Option<InsertionContext> {
+        let route_jobs = get_route_jobs(&insertion_ctx.solution);
+        let (_, seed_route_idx, seed_job) = select_seed_job(
+            insertion_ctx.solution.routes.as_slice(),
+            insertion_ctx.environment.random.as_ref(),
+            &|_| true,
+            &|job| is_movable_job(insertion_ctx, job),
+        )?;
+        let seed_idx = insertion_ctx.solution.routes[seed_route_idx].route().tour.index(&seed_job)?;
+
+        select_best_candidate(
+            get_job_neighbours(insertion_ctx, &route_jobs, seed_route_idx, &seed_job, self.neighbours).filter_map(
+                |(route_idx, job)| {
+                    let idx = insertion_ctx.solution.routes[route_idx].route().tour.index(&job)?;
+                    exchange_tails(insertion_ctx, (seed_route_idx, seed_idx), (route_idx, idx))
+                },
+            ),
+        )
+    }
+}
+
+/// Returns jobs served after activity with given index or None if some of them cannot be moved.
+fn get_tail(insertion_ctx: &InsertionContext, (route_idx, index): (usize, usize)) -> Option<Vec<Job>> {
+    insertion_ctx.solution.routes[route_idx]
+        .route()
+        .tour
+        .all_activities()
+        .skip(index + 1)
+        .filter_map(|activity| activity.retrieve_job())
+        .map(|job| if is_movable_job(insertion_ctx, &job) { Some(job) } else { None })
+        .collect()
+}
+
+fn exchange_tails(
+    insertion_ctx: &InsertionContext,
+    first: (usize, usize),
+    second: (usize, usize),
+) -> Option<InsertionContext> {
+    let first_tail = get_tail(insertion_ctx, first)?;
+    let second_tail = get_tail(insertion_ctx, second)?;
+
+    if first_tail.is_empty() && second_tail.is_empty() {
+        return None;
+    }
+
+    let mut new_insertion_ctx = insertion_ctx.deep_copy();
+
+    remove_jobs_from_route(&mut new_insertion_ctx, first.0, first_tail.as_slice());
+    remove_jobs_from_route(&mut new_insertion_ctx, second.0, second_tail.as_slice());
+
+    let is_success = insert_jobs_into_route(&mut new_insertion_ctx, first.0, first.1, second_tail.as_slice())
+        && insert_jobs_into_route(&mut new_insertion_ctx, second.0, second.1, first_tail.as_slice());
+
+    if is_success {
+        finalize_insertion_ctx(&mut new_insertion_ctx);
+        new_insertion_ctx.solution.remove_empty_routes();
+
+        Some(new_insertion_ctx)
+    } else {
+        None
+    }
+}
@@ -1,9 +1,11 @@
 //! This module contains various Local Search operators.
 
 use crate::construction::heuristics::*;
+use crate::models::problem::Job;
 use crate::solver::RefinementContext;
 use rosomaxa::prelude::*;
 use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 mod exchange_inter_route;
@@ -12,12 +14,18 @@ pub use self::exchange_inter_route::*;
 mod exchange_intra_route;
 pub use self::exchange_intra_route::*;
 
+mod exchange_or_opt;
+pub use self::exchange_or_opt::*;
+
 mod exchange_sequence;
 pub use self::exchange_sequence::*;
 
 mod exchange_swap_star;
 pub use self::exchange_swap_star::*;
 
+mod exchange_two_opt_star;
+pub use self::exchange_two_opt_star::*;
+
 mod reschedule_departure;
 pub use self::reschedule_departure::*;
 
@@ -94,3 +102,83 @@ fn apply_insertion_with_route(insertion_ctx: &mut InsertionContext, result: (Ins
 
     apply_insertion_success(insertion_ctx, success)
 }
+
+/// Returns up to `limit` nearest neighbours of the job which are served by other routes as (route index, job) pairs.
+fn get_job_neighbours<'a>(
+    insertion_ctx: &'a InsertionContext,
+    route_jobs: &'a HashMap<Job, usize>,
+    route_idx: usize,
+    job: &'a Job,
+    limit: usize,
+) -> impl Iterator<Item = (usize, Job)> + 'a {
+    let profile = &insertion_ctx.solution.routes[route_idx].route().actor.vehicle.profile;
+
+    insertion_ctx
+        .problem
+        .jobs
+        .neighbors(profile, job, Default::default())
+        .take(limit)
+        .filter(|(neighbour, _)| !insertion_ctx.solution.locked.contains(*neighbour))
+        .filter_map(move |(neighbour, _)| {
+            route_jobs
+                .get(neighbour)
+                .filter(|&&neighbour_route_idx| neighbour_route_idx != route_idx)
+                .map(|&neighbour_route_idx| (neighbour_route_idx, neighbour.clone()))
+        })
+}
+
+/// Checks whether the job can be moved as a part of a segment: it should be a single and not locked job.
+fn is_movable_job(insertion_ctx: &InsertionContext, job: &Job) -> bool {
+    job.as_single().is_some() && !insertion_ctx.solution.locked.contains(job)
+}
+
+/// Removes jobs from the route and updates its state.
+fn remove_jobs_from_route(insertion_ctx: &mut InsertionContext, route_idx: usize, jobs: &[Job]) {
+    let route_ctx = insertion_ctx.solution.routes.get_mut(route_idx).expect("invalid route index");
+
+    jobs.iter().for_each(|job| {
+        route_ctx.route_mut().tour.remove(job);
+    });
+
+    insertion_ctx.problem.goal.accept_route_state(route_ctx);
+}
+
+/// Inserts jobs one by one right after the activity with given index keeping their order.
+/// Returns false if some of the jobs cannot be inserted there.
+fn insert_jobs_into_route(insertion_ctx: &mut InsertionContext, route_idx: usize, index: usize, jobs: &[Job]) -> bool {
+    let leg_selection = LegSelection::Exhaustive;
+    let result_selector = BestResultSelector::default();
+
+    jobs.iter()
+        .try_fold(index, |index, job| {
+            let eval_ctx = EvaluationContext {
+                goal: &insertion_ctx.problem.goal,
+                job,
+                leg_selection: &leg_selection,
+                result_selector: &result_selector,
+            };
+
+            match eval_job_insertion_in_route(
+                insertion_ctx,
+                &eval_ctx,
+                insertion_ctx.solution.routes.get(route_idx)?,
+                InsertionPosition::Concrete(index),
+                InsertionResult::make_failure(),
+            ) {
+                InsertionResult::Success(success) => {
+                    let next_index = index + success.activities.len();
+                    apply_insertion_success(insertion_ctx, success);
+                    Some(next_index)
+                }
+                InsertionResult::Failure(_) => None,
+            }
+        })
+        .is_some()
+}
+
+/// Selects the best solution from candidates according to the goal.
+fn select_best_candidate(candidates: impl Iterator<Item = InsertionContext>) -> Option<InsertionContext> {
+    candidates.reduce(|best, candidate| {
+        if candidate.problem.goal.total_order(&candidate, &best) == Ordering::Less { candidate } else { best }
+    })
+}
@@ -28,11 +28,21 @@ pub(crate) use self::utils::*;
 mod decompose_search;
 pub use self::decompose_search::DecomposeSearch;
 
+mod dissolve_cluster_search;
+pub use self::dissolve_cluster_search::DissolveClusterSearch;
+
+mod ejection_chain_search;
+pub use self::ejection_chain_search::EjectionChainSearch;
+
+mod guided_local_search;
+pub use self::guided_local_search::{ArcPenalties, ArcPenaltiesExtraProperty, GuidedLocalSearch, GuidedTransportCost};
+
 mod infeasible_search;
 pub use self::infeasible_search::InfeasibleSearch;
 
 mod lkh_search;
 pub use self::lkh_search::{LKHSearch, LKHSearchMode};
+pub(crate) use self::lkh_search::{get_activity_range, optimize_route, rearrange_route};
 
 mod local_search;
 pub use self::local_search::LocalSearch;
@@ -43,6 +53,9 @@ pub use self::redistribute_search::RedistributeSearch;
 mod ruin_recreate;
 pub use self::ruin_recreate::RuinAndRecreate;
 
+mod sisr_search;
+pub use self::sisr_search::SisrSearch;
+
 /// Provides the way to pick one heuristic operator from the group.
 pub struct WeightedHeuristicOperator {
     mutations: Vec<TargetSearchOperator>,
@@ -14,13 +14,23 @@ pub struct RecreateWithCheapest {
 impl RecreateWithCheapest {
     /// Creates a new instance of `RecreateWithCheapest`.
     pub fn new(random: Arc<dyn Random>) -> Self {
+        Self::new_with_evaluator(random, PositionInsertionEvaluator::default())
+    }
+
+    /// Creates a new instance of `RecreateWithCheapest` which evaluates insertion of each job into
+    /// different routes in parallel.
+    pub fn new_route_parallel(random: Arc<dyn Random>) -> Self {
+        Self::new_with_evaluator(random, PositionInsertionEvaluator::new_route_parallel(InsertionPosition::Any))
+    }
+
+    fn new_with_evaluator(random: Arc<dyn Random>, evaluator: PositionInsertionEvaluator) -> Self {
         Self {
             recreate: ConfigurableRecreate::new(
                 Box::<AllJobSelector>::default(),
                 Box::<AllRouteSelector>::default(),
                 LegSelection::Stochastic(random),
                 ResultSelection::Concrete(Box::<BestResultSelector>::default()),
-                Default::default(),
+                InsertionHeuristic::new(Box::new(evaluator)),
             ),
         }
     }
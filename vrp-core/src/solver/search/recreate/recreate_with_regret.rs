@@ -1,17 +1,25 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/search/recreate/recreate_with_regret_test.rs"]
+mod recreate_with_regret_test;
+
 use crate::construction::heuristics::*;
 use crate::construction::heuristics::{InsertionContext, InsertionResult};
 use crate::models::problem::Job;
 use crate::solver::RefinementContext;
 use crate::solver::search::{ConfigurableRecreate, Recreate};
-use rosomaxa::utils::{CollectGroupBy, Random};
-use std::collections::HashSet;
+use rosomaxa::utils::{Random, parallel_collect};
+use std::cmp::Ordering;
 use std::sync::Arc;
 
-/// A recreate strategy which computes the difference in cost of inserting customer in its
-/// best and kth best route, where `k` is a user-defined parameter. Then it inserts the
-/// customer with the max difference in its least cost position.
+/// A recreate strategy which implements regret-k insertion heuristic: for each job, it finds the cheapest
+/// insertion in each route and computes the regret value as a sum of differences in cost between
+/// the best route and each of the next `k - 1` best routes. Then it inserts the job with the max regret
+/// value in its least cost position, so the jobs which would be expensive to insert later are inserted first.
+/// Jobs which can be inserted in less than `k` routes are prioritized.
 pub struct RecreateWithRegret {
     recreate: ConfigurableRecreate,
+    k: usize,
+    random: Arc<dyn Random>,
 }
 
 impl Recreate for RecreateWithRegret {
@@ -21,16 +29,27 @@ impl Recreate for RecreateWithRegret {
 }
 
 impl RecreateWithRegret {
-    /// Creates a new instance of `RecreateWithRegret`.
-    pub fn new(min: usize, max: usize, random: Arc<dyn Random>) -> Self {
+    /// Creates a new instance of `RecreateWithRegret` with given `k`, e.g. 2 for regret-2 or 3 for regret-3.
+    pub fn new(k: usize, random: Arc<dyn Random>) -> Self {
+        Self::new_with_range(k, k, random)
+    }
+
+    /// Sets the maximum value of `k`: on each insertion step, it is randomly chosen from `[k, max_k]` range.
+    pub fn with_max_k(self, max_k: usize) -> Self {
+        Self::new_with_range(self.k, max_k, self.random)
+    }
+
+    fn new_with_range(min: usize, max: usize, random: Arc<dyn Random>) -> Self {
         Self {
             recreate: ConfigurableRecreate::new(
                 Box::<AllJobSelector>::default(),
                 Box::<AllRouteSelector>::default(),
                 LegSelection::Stochastic(random.clone()),
-                ResultSelection::Stochastic(ResultSelectorProvider::new_default(random)),
+                ResultSelection::Stochastic(ResultSelectorProvider::new_default(random.clone())),
                 InsertionHeuristic::new(Box::new(RegretInsertionEvaluator::new(min, max))),
             ),
+            k: min,
+            random,
         }
     }
 }
@@ -60,62 +79,58 @@ impl InsertionEvaluator for RegretInsertionEvaluator {
         leg_selection: &LegSelection,
         result_selector: &dyn ResultSelector,
     ) -> InsertionResult {
-        let regret_index = insertion_ctx.environment.random.uniform_int(self.min as i32, self.max as i32) as usize;
+        let k = insertion_ctx.environment.random.uniform_int(self.min as i32, self.max as i32) as usize;
 
         // NOTE no need to proceed with regret, fallback to more performant reducer
-        if regret_index == 1 || jobs.len() == 1 || routes.is_empty() || insertion_ctx.solution.routes.len() < 2 {
+        if k == 1 || jobs.len() == 1 || routes.len() < 2 {
             return self.fallback_evaluator.evaluate_all(insertion_ctx, jobs, routes, leg_selection, result_selector);
         }
 
-        let mut results = self
-            .fallback_evaluator
-            .evaluate_and_collect_all(insertion_ctx, jobs, routes, leg_selection, result_selector)
+        let goal = &insertion_ctx.problem.goal;
+        let candidates = parallel_collect(jobs, |job| {
+            let eval_ctx = EvaluationContext { goal, job, leg_selection, result_selector };
+            let mut successes = routes
+                .iter()
+                .filter_map(|route_ctx| {
+                    match eval_job_insertion_in_route(
+                        insertion_ctx,
+                        &eval_ctx,
+                        route_ctx,
+                        InsertionPosition::Any,
+                        InsertionResult::make_failure(),
+                    ) {
+                        InsertionResult::Success(success) => Some(success),
+                        InsertionResult::Failure(_) => None,
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            successes.sort_by(|a, b| a.cost.cmp(&b.cost));
+
+            let regret = successes
+                .iter()
+                .skip(1)
+                .take(k - 1)
+                .fold(InsertionCost::default(), |acc, success| acc + (&success.cost - &successes[0].cost));
+            let is_limited = successes.len() < k;
+
+            successes.into_iter().next().map(|best| (is_limited, regret, best))
+        });
+
+        candidates
             .into_iter()
-            .filter_map(|result| match result {
-                InsertionResult::Success(success) => Some(success),
-                _ => None,
-            })
-            .collect_group_by_key::<Job, InsertionSuccess, _>(|success| success.job.clone())
-            .into_iter()
-            .filter_map(|(_, mut successes)| {
-                if successes.len() < regret_index {
-                    return None;
-                }
-
-                successes.sort_by(|a, b| a.cost.cmp(&b.cost));
-
-                let (_, mut job_results) = successes.into_iter().fold(
-                    (HashSet::with_capacity(insertion_ctx.solution.routes.len()), Vec::default()),
-                    |(mut actors, mut results), result| {
-                        if !actors.contains(&result.actor) {
-                            results.push(result);
-                        } else {
-                            actors.insert(result.actor);
-                        }
-
-                        (actors, results)
-                    },
-                );
-
-                if regret_index < job_results.len() {
-                    let worst = job_results.swap_remove(regret_index);
-                    let best = job_results.swap_remove(0);
-
-                    Some((worst.cost - &best.cost, best))
-                } else {
-                    None
+            .flatten()
+            .min_by(|(a_limited, a_regret, a_best), (b_limited, b_regret, b_best)| {
+                // prefer jobs with less options, then with higher regret, then with lower cost
+                match (a_limited, b_limited) {
+                    (true, false) => Ordering::Less,
+                    (false, true) => Ordering::Greater,
+                    _ => b_regret.cmp(a_regret).then_with(|| a_best.cost.cmp(&b_best.cost)),
                 }
             })
-            .collect::<Vec<_>>();
-
-        if !results.is_empty() {
-            results.sort_by(|a, b| b.0.cmp(&a.0));
-
-            let (_, best_success) = results.swap_remove(0);
-
-            InsertionResult::Success(best_success)
-        } else {
-            self.fallback_evaluator.evaluate_all(insertion_ctx, jobs, routes, leg_selection, result_selector)
-        }
+            .map(|(_, _, best)| InsertionResult::Success(best))
+            .unwrap_or_else(|| {
+                self.fallback_evaluator.evaluate_all(insertion_ctx, jobs, routes, leg_selection, result_selector)
+            })
     }
 }
@@ -0,0 +1,136 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/search/ruin/cluster_bridge_removal_test.rs"]
+mod cluster_bridge_removal_test;
+
+use super::*;
+use crate::construction::heuristics::{InsertionContext, SolutionContext};
+use crate::models::Problem;
+use crate::models::problem::Job;
+use crate::solver::RefinementContext;
+use crate::solver::search::{JobRemovalTracker, TabuList, get_route_jobs, get_sorted_clusters, select_neighbors};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+/// A ruin strategy which removes the whole job cluster together with its route "bridges": jobs which
+/// are served right before or after cluster jobs and connect the cluster with the rest of the route.
+/// A cluster size is adapted to the removal budget: the cluster which fits the budget is preferred,
+/// otherwise only a part of the cluster around a random seed job is removed.
+pub struct ClusterBridgeRemoval {
+    problem: Arc<Problem>,
+    clusters: Vec<Vec<Job>>,
+    limits: RemovalLimits,
+}
+
+impl ClusterBridgeRemoval {
+    /// Creates a new instance of `ClusterBridgeRemoval`.
+    pub fn new(problem: Arc<Problem>, limits: RemovalLimits) -> Self {
+        let clusters = get_sorted_clusters(problem.as_ref());
+
+        Self { problem, clusters, limits }
+    }
+
+    /// Creates a new instance of `ClusterBridgeRemoval` with default parameters.
+    pub fn new_with_defaults(problem: Arc<Problem>) -> Self {
+        let limits = RemovalLimits::new(problem.as_ref());
+        Self::new(problem, limits)
+    }
+
+    fn select_cluster(
+        &self,
+        insertion_ctx: &InsertionContext,
+        route_jobs: &HashMap<Job, usize>,
+        budget: usize,
+    ) -> Option<Vec<Job>> {
+        let solution = &insertion_ctx.solution;
+        let random = insertion_ctx.environment.random.as_ref();
+
+        let mut candidates = self
+            .clusters
+            .iter()
+            .map(|cluster| {
+                cluster
+                    .iter()
+                    .filter(|job| route_jobs.contains_key(*job) && !solution.locked.contains(*job))
+                    .cloned()
+                    .collect::<Vec<_>>()
+            })
+            .filter(|cluster| !cluster.is_empty())
+            .collect::<Vec<_>>();
+
+        if candidates.is_empty() {
+            return None;
+        }
+
+        candidates.shuffle(&mut random.get_rng());
+
+        // NOTE prefer the cluster which can be removed completely leaving some budget for its bridges
+        if let Some(idx) = candidates.iter().position(|cluster| cluster.len() < budget) {
+            return Some(candidates.swap_remove(idx));
+        }
+
+        let cluster = candidates.swap_remove(0);
+        let seed = cluster.get(random.uniform_int(0, cluster.len() as i32 - 1) as usize).cloned()?;
+        let profile = solution.routes[*route_jobs.get(&seed)?].route().actor.vehicle.profile.clone();
+        let members = cluster.into_iter().collect::<HashSet<_>>();
+
+        Some(
+            select_neighbors(self.problem.as_ref(), Some((profile, seed)))
+                .filter(|job| members.contains(job))
+                .take(budget.max(1))
+                .collect(),
+        )
+    }
+}
+
+impl Ruin for ClusterBridgeRemoval {
+    fn run(&self, _: &RefinementContext, mut insertion_ctx: InsertionContext) -> InsertionContext {
+        let route_jobs = get_route_jobs(&insertion_ctx.solution);
+        let random = insertion_ctx.environment.random.clone();
+        let mut tracker = JobRemovalTracker::new(&self.limits, random.as_ref());
+        let mut tabu_list = TabuList::from(&insertion_ctx);
+
+        let Some(cluster) = self.select_cluster(&insertion_ctx, &route_jobs, tracker.get_activities_left()) else {
+            return insertion_ctx;
+        };
+        let bridges = get_bridges(&insertion_ctx.solution, &route_jobs, &cluster);
+
+        cluster.into_iter().chain(bridges).for_each(|job| {
+            if let Some(&route_idx) = route_jobs.get(&job)
+                && tracker.try_remove_job(&mut insertion_ctx.solution, route_idx, &job)
+            {
+                tabu_list.add_job(job);
+                tabu_list.add_actor(insertion_ctx.solution.routes[route_idx].route().actor.clone());
+            }
+        });
+
+        tabu_list.inject(&mut insertion_ctx);
+
+        insertion_ctx
+    }
+}
+
+/// Returns jobs which are served right before or after cluster jobs, but do not belong to the cluster.
+fn get_bridges(solution: &SolutionContext, route_jobs: &HashMap<Job, usize>, cluster: &[Job]) -> Vec<Job> {
+    let members = cluster.iter().collect::<HashSet<_>>();
+    let mut bridges = Vec::default();
+
+    cluster.iter().for_each(|job| {
+        let Some(tour) = route_jobs.get(job).map(|&route_idx| &solution.routes[route_idx].route().tour) else {
+            return;
+        };
+
+        tour.index(job)
+            .into_iter()
+            .flat_map(|idx| [idx.checked_sub(1), Some(idx + 1)])
+            .flatten()
+            .filter_map(|idx| tour.get(idx).and_then(|activity| activity.retrieve_job()))
+            .filter(|bridge| !members.contains(bridge) && !solution.locked.contains(bridge))
+            .for_each(|bridge| {
+                if !bridges.contains(&bridge) {
+                    bridges.push(bridge);
+                }
+            });
+    });
+
+    bridges
+}
@@ -7,7 +7,7 @@ use crate::construction::heuristics::InsertionContext;
 use crate::models::Problem;
 use crate::models::problem::Job;
 use crate::solver::RefinementContext;
-use crate::solver::search::{JobRemovalTracker, TabuList, get_route_jobs};
+use crate::solver::search::{JobRemovalTracker, TabuList, get_route_jobs, get_sorted_clusters};
 use std::cell::RefCell;
 use std::sync::Arc;
 
@@ -20,13 +20,7 @@ pub struct ClusterRemoval {
 impl ClusterRemoval {
     /// Creates a new instance of `ClusterRemoval`.
     pub fn new(problem: Arc<Problem>, limits: RemovalLimits) -> GenericResult<Self> {
-        let clusters = problem
-            .jobs
-            .clusters()
-            .iter()
-            .cloned()
-            .map(|cluster| cluster.into_iter().collect::<Vec<_>>())
-            .collect::<Vec<_>>();
+        let clusters = get_sorted_clusters(problem.as_ref());
 
         Ok(Self { clusters, limits })
     }
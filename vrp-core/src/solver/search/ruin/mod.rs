@@ -21,6 +21,9 @@ pub use self::adjusted_string_removal::AdjustedStringRemoval;
 mod cluster_removal;
 pub use self::cluster_removal::ClusterRemoval;
 
+mod cluster_bridge_removal;
+pub use self::cluster_bridge_removal::ClusterBridgeRemoval;
+
 mod neighbour_removal;
 pub use self::neighbour_removal::NeighbourRemoval;
 
@@ -5,11 +5,11 @@ mod worst_jobs_removal_test;
 use super::*;
 use crate::construction::heuristics::InsertionContext;
 use crate::models::common::{Cost, Profile, Timestamp};
-use crate::models::problem::{Job, TransportCost, TravelTime};
+use crate::models::problem::{Job, TransportCost, TravelTime, compare_jobs_canonically};
 use crate::models::solution::{Activity, Route};
 use crate::solver::RefinementContext;
 use crate::solver::search::{JobRemovalTracker, TabuList, get_route_jobs};
-use rosomaxa::utils::parallel_collect;
+use rosomaxa::utils::{is_deterministic_mode, parallel_collect};
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::iter::once;
@@ -102,6 +102,11 @@ fn get_routes_cost_savings(insertion_ctx: &InsertionContext) -> Vec<(Profile, Ve
             })
             .drain()
             .collect();
+
+        if is_deterministic_mode() {
+            // NOTE savings ties are resolved by hash map iteration order otherwise
+            savings.sort_by(|(a, _), (b, _)| compare_jobs_canonically(a, b));
+        }
         savings.sort_by(|(_, a), (_, b)| b.total_cmp(a));
 
         (route_ctx.route().actor.vehicle.profile.clone(), savings)
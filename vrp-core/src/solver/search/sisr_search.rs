@@ -0,0 +1,123 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/search/sisr_search_test.rs"]
+mod sisr_search_test;
+
+use super::*;
+use crate::construction::heuristics::finalize_insertion_ctx;
+use crate::models::Problem;
+use rosomaxa::prelude::{HeuristicObjective, Random};
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+/// A large neighborhood search based on "Slack Induction by String Removals for Vehicle Routing Problems"
+/// by Jan Christiaens, Greet Vanden Berghe.
+///
+/// Runs a short chain of string removals followed by insertion with blinks and accepts worse solutions
+/// using simulated annealing criteria. Temperature is specified relatively to the cost of the current
+/// solution and decreases exponentially from initial to final value. The best found solution is returned.
+pub struct SisrSearch {
+    ruin: Arc<dyn Ruin>,
+    recreate: Arc<dyn Recreate>,
+    iterations: usize,
+    temperatures: (Float, Float),
+}
+
+impl SisrSearch {
+    /// Creates a new instance of `SisrSearch`.
+    pub fn new(
+        ruin: Arc<dyn Ruin>,
+        recreate: Arc<dyn Recreate>,
+        iterations: usize,
+        temperatures: (Float, Float),
+    ) -> Self {
+        assert!(temperatures.0 >= temperatures.1 && temperatures.1 > 0.);
+
+        Self { ruin, recreate, iterations: iterations.max(1), temperatures }
+    }
+
+    /// Creates a new instance of `SisrSearch` with default parameters.
+    pub fn new_with_defaults(problem: &Problem, random: Arc<dyn Random>) -> Self {
+        Self::new(
+            Arc::new(AdjustedStringRemoval::new_with_defaults(RemovalLimits::new(problem))),
+            Arc::new(RecreateWithBlinks::new_with_defaults(random)),
+            10,
+            (0.01, 0.0001),
+        )
+    }
+
+    fn get_temperature(&self, iteration: usize) -> Float {
+        let (initial, last) = self.temperatures;
+        let progress = iteration as Float / self.iterations as Float;
+
+        initial * (last / initial).powf(progress)
+    }
+}
+
+impl HeuristicSearchOperator for SisrSearch {
+    type Context = RefinementContext;
+    type Objective = GoalContext;
+    type Solution = InsertionContext;
+
+    fn search(&self, heuristic_ctx: &Self::Context, solution: &Self::Solution) -> Self::Solution {
+        let refinement_ctx = heuristic_ctx;
+        let goal = solution.problem.goal.as_ref();
+        let random = solution.environment.random.clone();
+
+        let mut best = solution.deep_copy();
+        let mut current = solution.deep_copy();
+
+        (0..self.iterations).for_each(|iteration| {
+            let mut candidate = self.ruin.run(refinement_ctx, current.deep_copy());
+            candidate.restore();
+
+            let mut candidate = self.recreate.run(refinement_ctx, candidate);
+            finalize_insertion_ctx(&mut candidate);
+
+            let temperature = self.get_temperature(iteration);
+            if !is_accepted(&candidate, &current, temperature, random.as_ref()) {
+                return;
+            }
+
+            if goal.total_order(&candidate, &best) == Ordering::Less {
+                best = candidate.deep_copy();
+            }
+
+            current = candidate;
+        });
+
+        best
+    }
+}
+
+/// Checks whether candidate should replace current solution. Worse candidates are accepted only when
+/// they are different from current solution in the last (usually cost) objective.
+fn is_accepted(
+    candidate: &InsertionContext,
+    current: &InsertionContext,
+    temperature: Float,
+    random: &dyn Random,
+) -> bool {
+    let goal = candidate.problem.goal.as_ref();
+
+    match goal.total_order(candidate, current) {
+        Ordering::Less | Ordering::Equal => true,
+        Ordering::Greater => {
+            let candidate = candidate.fitness().collect::<Vec<_>>();
+            let current = current.fitness().collect::<Vec<_>>();
+
+            let (Some((candidate_last, candidate_rest)), Some((current_last, current_rest))) =
+                (candidate.split_last(), current.split_last())
+            else {
+                return false;
+            };
+
+            if candidate_rest != current_rest {
+                return false;
+            }
+
+            let threshold = current_last.abs() * temperature * random.uniform_real(0., 1.).max(Float::EPSILON).ln();
+
+            *candidate_last < current_last - threshold
+        }
+    }
+}
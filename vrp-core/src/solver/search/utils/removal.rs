@@ -47,6 +47,10 @@ impl JobRemovalTracker {
         self.affected_actors.len()
     }
 
+    pub fn get_activities_left(&self) -> usize {
+        self.activities_left as usize
+    }
+
     /// Tries to remove a job from the route of given index.
     pub fn try_remove_job(&mut self, solution: &mut SolutionContext, route_idx: usize, job: &Job) -> bool {
         if self.activities_left == 0 {
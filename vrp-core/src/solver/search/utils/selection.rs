@@ -1,7 +1,7 @@
 use crate::construction::heuristics::{InsertionContext, RouteContext, SolutionContext};
 use crate::models::Problem;
 use crate::models::common::Profile;
-use crate::models::problem::Job;
+use crate::models::problem::{Job, sort_jobs_canonically};
 use crate::solver::search::TabuList;
 use crate::utils::Either;
 use rosomaxa::prelude::Random;
@@ -17,6 +17,21 @@ pub(crate) fn get_route_jobs(solution: &SolutionContext) -> HashMap<Job, usize>
         .collect()
 }
 
+/// Returns problem's job clusters with jobs sorted in a canonical order, so cluster membership
+/// order does not depend on hashing.
+pub(crate) fn get_sorted_clusters(problem: &Problem) -> Vec<Vec<Job>> {
+    problem
+        .jobs
+        .clusters()
+        .iter()
+        .map(|cluster| {
+            let mut cluster = cluster.iter().cloned().collect::<Vec<_>>();
+            sort_jobs_canonically(&mut cluster);
+            cluster
+        })
+        .collect()
+}
+
 /// Returns seed job within all its neighbours.
 pub(crate) fn select_neighbors(problem: &Problem, seed: Option<(Profile, Job)>) -> impl Iterator<Item = Job> + '_ {
     match seed {
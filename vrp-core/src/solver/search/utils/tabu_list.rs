@@ -1,6 +1,6 @@
 use crate::construction::heuristics::*;
 use crate::models::problem::{Actor, Job};
-use rand::prelude::IteratorRandom;
+use rand::Rng;
 use rosomaxa::prelude::{Float, Random};
 use std::collections::HashSet;
 use std::hash::Hash;
@@ -11,8 +11,8 @@ custom_solution_state!(TabuList typeof TabuList);
 /// A simple solution's tabu list to keep track of recently affected jobs and actors.
 #[derive(Clone)]
 pub struct TabuList {
-    actors: HashSet<Arc<Actor>>,
-    jobs: HashSet<Job>,
+    actors: TabuItems<Arc<Actor>>,
+    jobs: TabuItems<Job>,
     max_actors: usize,
     max_jobs: usize,
     random: Arc<dyn Random>,
@@ -21,22 +21,22 @@ pub struct TabuList {
 impl TabuList {
     /// Adds a job to the tabu list.
     pub fn add_job(&mut self, job: Job) {
-        add_with_limits(job, &mut self.jobs, self.max_jobs, self.random.as_ref());
+        self.jobs.add_with_limits(job, self.max_jobs, self.random.as_ref());
     }
 
     /// Adds an actor to the tabu list.
     pub fn add_actor(&mut self, actor: Arc<Actor>) {
-        add_with_limits(actor, &mut self.actors, self.max_actors, self.random.as_ref());
+        self.actors.add_with_limits(actor, self.max_actors, self.random.as_ref());
     }
 
     /// Checks whether given an actor is in the tabu list.
     pub fn is_actor_tabu(&self, actor: &Actor) -> bool {
-        self.actors.contains(actor)
+        self.actors.index.contains(actor)
     }
 
     /// Checks whether given a job is in the tabu list.
     pub fn is_job_tabu(&self, job: &Job) -> bool {
-        self.jobs.contains(job)
+        self.jobs.index.contains(job)
     }
 
     /// Stores tabu list in insertion ctx.
@@ -73,22 +73,32 @@ impl From<&InsertionContext> for TabuList {
     }
 }
 
-fn add_with_limits<T: Clone + Eq + PartialEq + Hash>(
-    new_item: T,
-    old_items: &mut HashSet<T>,
-    limits: usize,
-    random: &dyn Random,
-) {
-    // NOTE do not use tabu list when limit is zero
-    if limits == 0 {
-        return;
-    }
+/// Keeps tabu items in insertion order, so an evicted item does not depend on hashing.
+#[derive(Clone)]
+struct TabuItems<T> {
+    index: HashSet<T>,
+    items: Vec<T>,
+}
 
-    if old_items.len() == limits
-        && let Some(item) = old_items.iter().choose(&mut random.get_rng()).cloned()
-    {
-        old_items.remove(&item);
+impl<T> Default for TabuItems<T> {
+    fn default() -> Self {
+        Self { index: HashSet::default(), items: Vec::default() }
     }
+}
 
-    old_items.insert(new_item);
+impl<T: Clone + Eq + PartialEq + Hash> TabuItems<T> {
+    fn add_with_limits(&mut self, new_item: T, limits: usize, random: &dyn Random) {
+        // NOTE do not use tabu list when limit is zero
+        if limits == 0 || self.index.contains(&new_item) {
+            return;
+        }
+
+        if self.items.len() >= limits {
+            let item = self.items.swap_remove(random.get_rng().gen_range(0..self.items.len()));
+            self.index.remove(&item);
+        }
+
+        self.index.insert(new_item.clone());
+        self.items.push(new_item);
+    }
 }
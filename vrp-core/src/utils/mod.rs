@@ -5,3 +5,7 @@ pub use rosomaxa::utils::*;
 
 mod types;
 pub use self::types::Either;
+
+mod pool;
+pub use self::pool::next_pool_generation;
+pub(crate) use self::pool::{AllocationPool, release_pooled, take_pooled};
@@ -0,0 +1,89 @@
+#[cfg(test)]
+#[path = "../../tests/unit/utils/pool_test.rs"]
+mod pool_test;
+
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread::LocalKey;
+
+/// Max amount of allocations kept by a pool.
+const MAX_POOLED_ALLOCATIONS: usize = 512;
+
+/// Min amount of allocations kept by a pool when a new generation starts.
+const MIN_POOLED_ALLOCATIONS: usize = 8;
+
+/// A global generation counter used to release allocations which were not reused.
+static POOL_GENERATION: AtomicUsize = AtomicUsize::new(0);
+
+/// Starts a new generation of allocation pools: on the next access, each pool keeps only as many
+/// allocations as were requested from it during the previous generation.
+pub fn next_pool_generation() {
+    POOL_GENERATION.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Keeps allocations of released objects to reuse them later. Intended to be used as a thread local
+/// storage to reduce allocator pressure caused by deep copies of solutions during search.
+pub(crate) struct AllocationPool<T> {
+    items: Vec<T>,
+    generation: usize,
+    requested: usize,
+}
+
+/// A thread local allocation pool.
+pub(crate) type LocalAllocationPool<T> = LocalKey<RefCell<AllocationPool<T>>>;
+
+impl<T> Default for AllocationPool<T> {
+    fn default() -> Self {
+        Self { items: Vec::default(), generation: POOL_GENERATION.load(Ordering::Relaxed), requested: 0 }
+    }
+}
+
+impl<T> AllocationPool<T> {
+    /// Returns a pooled allocation if any.
+    pub fn take(&mut self) -> Option<T> {
+        self.sync_generation();
+        self.requested += 1;
+
+        self.items.pop()
+    }
+
+    /// Returns allocation back to the pool. It is expected to be cleared by caller.
+    pub fn release(&mut self, item: T) {
+        self.sync_generation();
+
+        if self.items.len() < MAX_POOLED_ALLOCATIONS {
+            self.items.push(item);
+        }
+    }
+
+    /// Returns amount of pooled allocations.
+    #[cfg(test)]
+    pub fn size(&self) -> usize {
+        self.items.len()
+    }
+
+    fn sync_generation(&mut self) {
+        let generation = POOL_GENERATION.load(Ordering::Relaxed);
+
+        if self.generation != generation {
+            self.items.truncate(self.requested.max(MIN_POOLED_ALLOCATIONS));
+            self.generation = generation;
+            self.requested = 0;
+        }
+    }
+}
+
+/// Takes an allocation from the thread local pool or creates a new one.
+pub(crate) fn take_pooled<T: Default + 'static>(pool: &'static LocalAllocationPool<T>) -> T {
+    // NOTE pool can be already destroyed when called from thread local destructors
+    pool.try_with(|pool| pool.try_borrow_mut().ok().and_then(|mut pool| pool.take())).ok().flatten().unwrap_or_default()
+}
+
+/// Returns an allocation to the thread local pool. The item should be cleared by caller.
+pub(crate) fn release_pooled<T: 'static>(pool: &'static LocalAllocationPool<T>, item: T) {
+    let _ = pool.try_with(|pool| {
+        if let Ok(mut pool) = pool.try_borrow_mut() {
+            pool.release(item);
+        }
+    });
+}
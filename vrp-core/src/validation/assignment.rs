@@ -0,0 +1,70 @@
+use super::*;
+use crate::models::problem::Single;
+use std::collections::{HashMap, HashSet};
+
+/// Checks that each job is assigned at most once and completely.
+pub(super) fn check_assignment(problem: &Problem, solution: &Solution) -> Vec<SolutionViolation> {
+    let known = problem.jobs.all().iter().collect::<HashSet<_>>();
+    let unassigned = solution.unassigned.iter().map(|(job, _)| job).collect::<HashSet<_>>();
+
+    let mut violations = vec![];
+    let mut assigned: HashMap<Job, usize> = HashMap::new();
+
+    solution.routes.iter().enumerate().for_each(|(route_index, route)| {
+        let mut route_jobs = HashMap::<Job, Vec<&Arc<Single>>>::new();
+
+        route
+            .tour
+            .all_activities()
+            .filter_map(|activity| activity.job.as_ref().map(|single| (activity, single)))
+            .for_each(|(activity, single)| {
+                if let Some(job) = activity.retrieve_job() {
+                    route_jobs.entry(job).or_default().push(single);
+                }
+            });
+
+        route_jobs.into_iter().for_each(|(job, singles)| {
+            let create_violation = |message: String| {
+                SolutionViolation::new(ViolationKind::Assignment, Some(route_index), message).with_job(job.clone())
+            };
+
+            if !known.contains(&job) {
+                violations.push(create_violation(format!("job '{}' is not defined in the problem", get_job_id(&job))));
+            }
+
+            if unassigned.contains(&job) {
+                violations.push(create_violation(format!("job '{}' is assigned and unassigned", get_job_id(&job))));
+            }
+
+            let expected_activities = match &job {
+                Job::Single(_) => 1,
+                Job::Multi(multi) => multi.jobs.len(),
+            };
+            let unique_activities = singles.iter().map(|single| Arc::as_ptr(single)).collect::<HashSet<_>>().len();
+
+            if singles.len() != unique_activities {
+                violations.push(create_violation(format!("job '{}' is assigned more than once", get_job_id(&job))));
+            } else if unique_activities != expected_activities {
+                violations.push(create_violation(format!(
+                    "job '{}' is partially assigned: {unique_activities} of {expected_activities} activities",
+                    get_job_id(&job)
+                )));
+            }
+
+            *assigned.entry(job).or_default() += 1;
+        });
+    });
+
+    assigned.into_iter().filter(|(_, count)| *count > 1).for_each(|(job, count)| {
+        violations.push(
+            SolutionViolation::new(
+                ViolationKind::Assignment,
+                None,
+                format!("job '{}' is assigned to {count} routes", get_job_id(&job)),
+            )
+            .with_job(job),
+        );
+    });
+
+    violations
+}
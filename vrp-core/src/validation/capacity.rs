@@ -0,0 +1,42 @@
+use super::*;
+use crate::construction::features::{CurrentCapacityActivityState, VehicleCapacityDimension};
+use crate::models::common::{LoadOps, MultiDimLoad, SingleDimLoad};
+
+/// Checks that vehicle load doesn't exceed its capacity at any activity. The load is taken from the
+/// route state maintained by capacity feature, so multi trip (reload) routes are supported. Only the first
+/// overloaded activity is reported per route.
+pub(super) fn check_capacity(ctx: &ValidationContext) -> Vec<SolutionViolation> {
+    ctx.routes()
+        .flat_map(|(route_index, route_ctx)| {
+            check_route_capacity::<SingleDimLoad>(route_index, route_ctx).into_iter().chain(check_route_capacity::<
+                MultiDimLoad,
+            >(
+                route_index, route_ctx
+            ))
+        })
+        .collect()
+}
+
+fn check_route_capacity<T: LoadOps>(route_index: Option<usize>, route_ctx: &RouteContext) -> Option<SolutionViolation> {
+    let capacity = route_ctx.route().actor.vehicle.dimens.get_vehicle_capacity::<T>()?;
+
+    (0..route_ctx.route().tour.total()).find_map(|activity_idx| {
+        let load = route_ctx.state().get_current_capacity_at::<T>(activity_idx)?;
+
+        if capacity.can_fit(load) {
+            None
+        } else {
+            let violation = SolutionViolation::new(
+                ViolationKind::Capacity,
+                route_index,
+                format!("load {load:?} exceeds vehicle capacity {capacity:?}"),
+            )
+            .with_activity(activity_idx);
+
+            Some(match route_ctx.route().tour.get(activity_idx).and_then(|activity| activity.retrieve_job()) {
+                Some(job) => violation.with_job(job),
+                None => violation,
+            })
+        }
+    })
+}
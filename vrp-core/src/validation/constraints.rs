@@ -0,0 +1,71 @@
+use super::*;
+use crate::construction::heuristics::{ActivityContext, MoveContext};
+use crate::models::solution::Activity;
+use std::collections::HashSet;
+
+/// Checks that each assigned job satisfies hard constraints of the goal: the job is removed from its route
+/// and then its activities are evaluated for insertion back at their original positions.
+pub(super) fn check_constraints(ctx: &ValidationContext) -> Vec<SolutionViolation> {
+    let goal = ctx.insertion_ctx.problem.goal.as_ref();
+    let solution_ctx = &ctx.insertion_ctx.solution;
+
+    ctx.routes()
+        .flat_map(|(route_index, route_ctx)| {
+            // NOTE keep jobs in the order of their first activity to have deterministic results
+            let mut jobs = route_ctx.route().tour.all_activities().filter_map(|a| a.retrieve_job()).collect::<Vec<_>>();
+            let mut seen = HashSet::new();
+            jobs.retain(|job| seen.insert(job.clone()));
+
+            jobs.into_iter().filter_map(move |job| {
+                let tour = &route_ctx.route().tour;
+                let activities = tour
+                    .all_activities()
+                    .enumerate()
+                    .filter(|(_, activity)| activity.has_same_job(&job))
+                    .map(|(activity_idx, activity)| (activity_idx, activity.deep_copy()))
+                    .collect::<Vec<(usize, Activity)>>();
+
+                let mut route_ctx = route_ctx.deep_copy();
+                route_ctx.route_mut().tour.remove(&job);
+                goal.accept_route_state(&mut route_ctx);
+
+                let create_violation = |code: ViolationCode, activity_idx: Option<usize>| {
+                    let message = format!("job '{}' violates constraint with code {code}", get_job_id(&job));
+                    let violation = SolutionViolation::new(ViolationKind::Constraint(code), route_index, message)
+                        .with_job(job.clone());
+
+                    match activity_idx {
+                        Some(activity_idx) => violation.with_activity(activity_idx),
+                        None => violation,
+                    }
+                };
+
+                if let Some(violation) = goal.evaluate(&MoveContext::route(solution_ctx, &route_ctx, &job)) {
+                    return Some(create_violation(violation.code, None));
+                }
+
+                for (activity_idx, activity) in activities {
+                    let tour = &route_ctx.route().tour;
+                    let prev = tour.get(activity_idx - 1)?;
+                    let activity_ctx = ActivityContext {
+                        index: activity_idx - 1,
+                        prev,
+                        target: &activity,
+                        next: tour.get(activity_idx),
+                    };
+
+                    if let Some(violation) =
+                        goal.evaluate(&MoveContext::activity(solution_ctx, &route_ctx, &activity_ctx))
+                    {
+                        return Some(create_violation(violation.code, Some(activity_idx)));
+                    }
+
+                    route_ctx.route_mut().tour.insert_at(activity, activity_idx);
+                    goal.accept_route_state(&mut route_ctx);
+                }
+
+                None
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,145 @@
+//! This module provides a way to check feasibility of an existing solution, e.g. a plan which was
+//! modified outside the solver.
+//!
+//! The checks are performed on the core model level:
+//! - **assignment**: each job is assigned at most once and is not reported as assigned and unassigned
+//! - **capacity**: vehicle load does not exceed its capacity (if capacity feature is used)
+//! - **time windows**: each activity is started within its time window, routes end within shift time
+//! - **relations**: locked jobs are assigned according to lock rules
+//! - **constraints**: each assigned job satisfies hard constraints of the problem's goal at its position.
+//!   Violations of features without a dedicated check (e.g. breaks, skills, tour limits) are reported
+//!   with the violation code of the corresponding feature.
+
+#[cfg(test)]
+#[path = "../../tests/unit/validation/validation_test.rs"]
+mod validation_test;
+
+mod assignment;
+mod capacity;
+mod constraints;
+mod relations;
+mod schedule;
+
+use crate::construction::heuristics::{InsertionContext, RouteContext};
+use crate::models::problem::{Job, JobIdDimension};
+use crate::models::{Problem, Solution, ViolationCode};
+use rosomaxa::prelude::Environment;
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+
+/// Specifies a kind of the solution violation.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ViolationKind {
+    /// Job is assigned more than once, partially or both assigned and unassigned.
+    Assignment,
+    /// Vehicle load exceeds its capacity.
+    Capacity,
+    /// Activity or route is scheduled outside its time window.
+    TimeWindow,
+    /// Locked jobs are not assigned according to lock rules.
+    Relation,
+    /// Job violates a hard constraint of the goal with the given code.
+    Constraint(ViolationCode),
+}
+
+/// Describes a single violation found in the solution.
+#[derive(Clone, Debug)]
+pub struct SolutionViolation {
+    /// A violation kind.
+    pub kind: ViolationKind,
+    /// An index of the route in the solution's routes collection, if violation is related to the route.
+    pub route_index: Option<usize>,
+    /// An index of the activity in the route's tour, if violation is related to the activity.
+    pub activity_index: Option<usize>,
+    /// A job, if violation is related to the job.
+    pub job: Option<Job>,
+    /// A human-readable description of the violation.
+    pub message: String,
+}
+
+impl Display for SolutionViolation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.kind)?;
+
+        if let Some(route_index) = self.route_index {
+            write!(f, " in route {route_index}")?;
+        }
+
+        if let Some(activity_index) = self.activity_index {
+            write!(f, " at activity {activity_index}")?;
+        }
+
+        write!(f, ": {}", self.message)
+    }
+}
+
+/// Checks the solution against the problem definition and returns a list of found violations.
+/// An empty list means that the solution is feasible.
+///
+/// Activity schedules are recalculated using problem's transport and activity costs, so the check
+/// doesn't rely on schedules stored in the solution.
+pub fn check_solution(problem: Arc<Problem>, solution: &Solution) -> Vec<SolutionViolation> {
+    let mut violations = assignment::check_assignment(problem.as_ref(), solution);
+
+    // NOTE other checks rely on job uniqueness within the solution
+    if !violations.is_empty() {
+        return violations;
+    }
+
+    let ctx = ValidationContext::new(problem, solution);
+
+    violations.extend(capacity::check_capacity(&ctx));
+    violations.extend(schedule::check_time_windows(&ctx));
+    violations.extend(relations::check_relations(&ctx));
+    violations.extend(constraints::check_constraints(&ctx));
+
+    violations
+}
+
+/// Keeps the solution with its restored insertion context representation.
+struct ValidationContext<'a> {
+    solution: &'a Solution,
+    insertion_ctx: InsertionContext,
+}
+
+impl<'a> ValidationContext<'a> {
+    fn new(problem: Arc<Problem>, solution: &'a Solution) -> Self {
+        let insertion_ctx = InsertionContext::new_from_solution(
+            problem,
+            (solution.deep_copy(), None),
+            Arc::new(Environment::default()),
+        );
+
+        Self { solution, insertion_ctx }
+    }
+
+    /// Returns restored route contexts with their indices in the original solution.
+    fn routes(&self) -> impl Iterator<Item = (Option<usize>, &RouteContext)> + '_ {
+        self.insertion_ctx.solution.routes.iter().map(|route_ctx| {
+            let actor = &route_ctx.route().actor;
+            let route_index = self.solution.routes.iter().position(|route| Arc::ptr_eq(&route.actor, actor));
+
+            (route_index, route_ctx)
+        })
+    }
+}
+
+impl SolutionViolation {
+    fn new(kind: ViolationKind, route_index: Option<usize>, message: String) -> Self {
+        Self { kind, route_index, activity_index: None, job: None, message }
+    }
+
+    fn with_activity(mut self, activity_index: usize) -> Self {
+        self.activity_index = Some(activity_index);
+        self
+    }
+
+    fn with_job(mut self, job: Job) -> Self {
+        self.job = Some(job);
+        self
+    }
+}
+
+fn get_job_id(job: &Job) -> &str {
+    job.dimens().get_job_id().map(|id| id.as_str()).unwrap_or("<unknown>")
+}
@@ -0,0 +1,67 @@
+use super::*;
+use crate::models::solution::Tour;
+use crate::models::{LockDetail, LockOrder, LockPosition};
+
+/// Checks that locked jobs are assigned according to lock rules: the same route served by an actor which
+/// satisfies lock condition, expected order and position within the tour. Unassigned jobs are ignored.
+pub(super) fn check_relations(ctx: &ValidationContext) -> Vec<SolutionViolation> {
+    let problem = ctx.insertion_ctx.problem.as_ref();
+
+    problem
+        .locks
+        .iter()
+        .flat_map(|lock| lock.details.iter().map(move |detail| (lock, detail)))
+        .filter_map(|(lock, detail)| {
+            let routes = detail
+                .jobs
+                .iter()
+                .filter_map(|job| ctx.routes().find(|(_, route_ctx)| route_ctx.route().tour.contains(job)))
+                .collect::<Vec<_>>();
+
+            let (route_index, route_ctx) = routes.first().cloned()?;
+            let create_violation = |message: &str| {
+                SolutionViolation::new(ViolationKind::Relation, route_index, message.to_string())
+                    .with_job(detail.jobs.first().cloned().expect("lock detail without jobs"))
+            };
+
+            if routes.iter().any(|(_, other)| !std::ptr::eq(*other, route_ctx)) {
+                return Some(create_violation("locked jobs are assigned to different routes"));
+            }
+
+            if !(lock.condition_fn)(route_ctx.route().actor.as_ref()) {
+                return Some(create_violation("locked jobs are assigned to a route with not allowed actor"));
+            }
+
+            check_order_and_position(&route_ctx.route().tour, detail).map(create_violation)
+        })
+        .collect()
+}
+
+fn check_order_and_position(tour: &Tour, detail: &LockDetail) -> Option<&'static str> {
+    let indices = detail.jobs.iter().filter_map(|job| tour.index(job).zip(tour.index_last(job))).collect::<Vec<_>>();
+
+    let is_ordered = indices.windows(2).all(|pair| pair[0].1 < pair[1].0);
+    let is_strict = indices.windows(2).all(|pair| pair[0].1 + 1 == pair[1].0);
+
+    match detail.order {
+        LockOrder::Sequence | LockOrder::Strict if !is_ordered => return Some("locked jobs are not in expected order"),
+        LockOrder::Strict if !is_strict => return Some("other jobs are inserted between strictly locked jobs"),
+        _ => {}
+    }
+
+    let first_job_idx = tour.all_activities().position(|activity| activity.job.is_some());
+    let last_job_idx = tour.all_activities().rposition(|activity| activity.job.is_some());
+
+    let is_departure_ok = indices.iter().map(|(first, _)| *first).min() == first_job_idx;
+    let is_arrival_ok = indices.iter().map(|(_, last)| *last).max() == last_job_idx;
+
+    match detail.position {
+        LockPosition::Departure | LockPosition::Fixed if !is_departure_ok => {
+            Some("locked jobs are not assigned right after departure")
+        }
+        LockPosition::Arrival | LockPosition::Fixed if !is_arrival_ok => {
+            Some("locked jobs are not assigned right before arrival")
+        }
+        _ => None,
+    }
+}
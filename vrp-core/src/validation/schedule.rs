@@ -0,0 +1,29 @@
+use super::*;
+
+/// Checks that each activity is started within its time window and that routes end within vehicle shift.
+pub(super) fn check_time_windows(ctx: &ValidationContext) -> Vec<SolutionViolation> {
+    ctx.routes()
+        .flat_map(|(route_index, route_ctx)| {
+            let tour = &route_ctx.route().tour;
+
+            // NOTE start activity is skipped as its departure is defined by the shift start
+            tour.all_activities()
+                .enumerate()
+                .skip(1)
+                .filter(|(_, activity)| activity.schedule.arrival > activity.place.time.end)
+                .map(move |(activity_idx, activity)| {
+                    let message = format!(
+                        "arrival {} is after time window end {}",
+                        activity.schedule.arrival, activity.place.time.end
+                    );
+                    let violation = SolutionViolation::new(ViolationKind::TimeWindow, route_index, message)
+                        .with_activity(activity_idx);
+
+                    match activity.retrieve_job() {
+                        Some(job) => violation.with_job(job),
+                        None => violation,
+                    }
+                })
+        })
+        .collect()
+}
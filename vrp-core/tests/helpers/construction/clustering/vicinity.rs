@@ -124,5 +124,6 @@ pub fn create_cluster_config() -> ClusterConfig {
                 )
             }),
         },
+        solving: SolvingPolicy::default(),
     }
 }
@@ -60,6 +60,17 @@ pub fn generate_matrix_routes_with_disallow_list(
     cols: usize,
     is_open_vrp: bool,
     disallowed_pairs: Vec<(&str, &str)>,
+) -> (Problem, Solution) {
+    generate_matrix_routes_with_disallow_list_and_features(rows, cols, is_open_vrp, disallowed_pairs, vec![])
+}
+
+/// Generates matrix routes with disallow list and additional features which precede default ones.
+pub fn generate_matrix_routes_with_disallow_list_and_features(
+    rows: usize,
+    cols: usize,
+    is_open_vrp: bool,
+    disallowed_pairs: Vec<(&str, &str)>,
+    features: Vec<Feature>,
 ) -> (Problem, Solution) {
     let disallowed_pairs =
         disallowed_pairs.into_iter().map(|(prev, next)| (prev.to_string(), next.to_string())).collect();
@@ -70,6 +81,7 @@ pub fn generate_matrix_routes_with_disallow_list(
         is_open_vrp,
         move |transport, activity, _| {
             TestGoalContextBuilder::empty()
+                .add_features(features)
                 .add_feature(
                     TransportFeatureBuilder::new("transport")
                         .set_violation_code(ViolationCode(1))
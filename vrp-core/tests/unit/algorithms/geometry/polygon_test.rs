@@ -0,0 +1,59 @@
+use super::*;
+
+fn to_points(coordinates: &[(Float, Float)]) -> Vec<Point> {
+    coordinates.iter().map(|&(x, y)| Point::new(x, y)).collect()
+}
+
+#[test]
+fn can_build_convex_hull() {
+    let points = to_points(&[(0., 0.), (2., 0.), (1., 1.), (2., 2.), (0., 2.), (1., 0.), (0., 0.)]);
+
+    let hull = get_convex_hull(points.as_slice());
+
+    assert_eq!(hull, to_points(&[(0., 0.), (2., 0.), (2., 2.), (0., 2.)]));
+}
+
+parameterized_test! {can_build_degenerate_convex_hull, (points, expected), {
+    can_build_degenerate_convex_hull_impl(to_points(points), expected);
+}}
+
+can_build_degenerate_convex_hull! {
+    case_01: (&[], 0),
+    case_02: (&[(1., 1.), (1., 1.)], 1),
+    case_03: (&[(0., 0.), (1., 1.)], 2),
+    case_04: (&[(0., 0.), (1., 1.), (2., 2.)], 2),
+}
+
+fn can_build_degenerate_convex_hull_impl(points: Vec<Point>, expected: usize) {
+    let hull = get_convex_hull(points.as_slice());
+
+    assert_eq!(hull.len(), expected);
+    assert_eq!(get_polygon_area(hull.as_slice()), 0.);
+}
+
+#[test]
+fn can_calculate_polygon_area() {
+    let polygon = to_points(&[(0., 0.), (4., 0.), (4., 3.), (0., 3.)]);
+
+    assert_eq!(get_polygon_area(polygon.as_slice()), 12.);
+}
+
+parameterized_test! {can_calculate_convex_intersection_area, (subject, clip, expected), {
+    can_calculate_convex_intersection_area_impl(to_points(subject), to_points(clip), expected);
+}}
+
+can_calculate_convex_intersection_area! {
+    case_01_partial: (&[(0., 0.), (2., 0.), (2., 2.), (0., 2.)], &[(1., 1.), (3., 1.), (3., 3.), (1., 3.)], 1.),
+    case_02_inside: (&[(0., 0.), (4., 0.), (4., 4.), (0., 4.)], &[(1., 1.), (2., 1.), (2., 2.), (1., 2.)], 1.),
+    case_03_disjoint: (&[(0., 0.), (1., 0.), (1., 1.), (0., 1.)], &[(2., 2.), (3., 2.), (3., 3.), (2., 3.)], 0.),
+    case_04_triangle: (&[(0., 0.), (2., 0.), (2., 2.), (0., 2.)], &[(0., 0.), (2., 0.), (0., 2.)], 2.),
+    case_05_degenerate: (&[(0., 0.), (2., 0.), (2., 2.), (0., 2.)], &[(0., 0.), (2., 2.)], 0.),
+}
+
+fn can_calculate_convex_intersection_area_impl(subject: Vec<Point>, clip: Vec<Point>, expected: Float) {
+    let result = get_convex_intersection_area(subject.as_slice(), clip.as_slice());
+    let reverse = get_convex_intersection_area(clip.as_slice(), subject.as_slice());
+
+    assert!((result - expected).abs() < 1E-9, "{result} != {expected}");
+    assert!((reverse - expected).abs() < 1E-9, "{reverse} != {expected}");
+}
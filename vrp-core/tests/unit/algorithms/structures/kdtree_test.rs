@@ -0,0 +1,48 @@
+use super::*;
+
+fn create_points(size: usize) -> Vec<Point> {
+    // NOTE use a deterministic pseudo random sequence to avoid dependency on random generator
+    (0..size).map(|idx| Point::new(((idx * 7919) % 101) as Float, ((idx * 104_729) % 97) as Float)).collect()
+}
+
+fn get_nearest_brute_force(points: &[Point], point: &Point, k: usize) -> Vec<usize> {
+    let mut neighbours = points
+        .iter()
+        .enumerate()
+        .map(|(idx, other)| Neighbour { distance: other.distance_to_point(point), idx })
+        .collect::<Vec<_>>();
+    neighbours.sort();
+
+    neighbours.into_iter().take(k).map(|neighbour| neighbour.idx).collect()
+}
+
+parameterized_test! {can_find_nearest_points, (size, k), {
+    can_find_nearest_points_impl(size, k);
+}}
+
+can_find_nearest_points! {
+    case01: (0, 3),
+    case02: (1, 3),
+    case03: (10, 0),
+    case04: (10, 1),
+    case05: (10, 10),
+    case06: (100, 5),
+    case07: (500, 32),
+}
+
+fn can_find_nearest_points_impl(size: usize, k: usize) {
+    let points = create_points(size);
+    let tree = KdTree::new(points.clone());
+
+    assert_eq!(tree.size(), size);
+    for query in create_points(50).iter().map(|point| Point::new(point.x + 0.5, point.y - 0.25)) {
+        assert_eq!(tree.nearest(&query, k), get_nearest_brute_force(&points, &query, k));
+    }
+}
+
+#[test]
+fn can_handle_duplicate_points() {
+    let tree = KdTree::new(vec![Point::new(1., 1.); 5]);
+
+    assert_eq!(tree.nearest(&Point::new(1., 1.), 3), vec![0, 1, 2]);
+}
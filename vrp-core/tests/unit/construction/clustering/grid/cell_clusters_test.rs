@@ -0,0 +1,63 @@
+use super::*;
+use crate::helpers::models::problem::{TestSingleBuilder, get_job_id};
+
+fn create_jobs(locations: &[Option<usize>]) -> Vec<Job> {
+    locations
+        .iter()
+        .enumerate()
+        .map(|(idx, &location)| {
+            TestSingleBuilder::default().id(&format!("s{idx}")).location(location).build_as_job_ref()
+        })
+        .collect()
+}
+
+fn get_cluster_ids(clusters: Vec<HashSet<Job>>) -> Vec<Vec<String>> {
+    clusters
+        .into_iter()
+        .map(|cluster| {
+            let mut ids = cluster.iter().map(|job| get_job_id(job).clone()).collect::<Vec<_>>();
+            ids.sort();
+            ids
+        })
+        .collect()
+}
+
+parameterized_test! {can_create_job_clusters, (cell_size, min_points, expected), {
+    can_create_job_clusters_impl(cell_size, min_points, expected);
+}}
+
+can_create_job_clusters! {
+    case01_small_cells: (10., 2, vec![vec!["s0", "s1", "s2"], vec!["s3", "s4"]]),
+    case02_min_points: (10., 3, vec![vec!["s0", "s1", "s2"]]),
+    case03_large_cells: (100., 3, vec![vec!["s0", "s1", "s2", "s3", "s4", "s5"]]),
+    case04_single_points: (1., 1, vec![vec!["s0", "s1"], vec!["s2"], vec!["s3"], vec!["s4"], vec!["s5"]]),
+}
+
+fn can_create_job_clusters_impl(cell_size: Float, min_points: usize, expected: Vec<Vec<&str>>) {
+    let coordinates = (0..100).map(|location| (location as Float, 0.)).collect::<Vec<_>>();
+    let jobs = create_jobs(&[Some(0), Some(0), Some(5), Some(31), Some(35), Some(60), None]);
+
+    let clusters = create_job_clusters(&jobs, &coordinates, cell_size, min_points).unwrap();
+
+    assert_eq!(get_cluster_ids(clusters), expected);
+}
+
+parameterized_test! {can_detect_invalid_parameters, (coordinates_size, cell_size, min_points, expected), {
+    can_detect_invalid_parameters_impl(coordinates_size, cell_size, min_points, expected);
+}}
+
+can_detect_invalid_parameters! {
+    case01_zero_cell: (10, 0., 2, "cell size of grid job clustering should be positive"),
+    case02_nan_cell: (10, Float::NAN, 2, "cell size of grid job clustering should be positive"),
+    case03_zero_points: (10, 1., 0, "min points of grid job clustering should be greater than zero"),
+    case04_no_coordinates: (5, 1., 2, "grid job clustering requires coordinates for all job locations"),
+}
+
+fn can_detect_invalid_parameters_impl(coordinates_size: usize, cell_size: Float, min_points: usize, expected: &str) {
+    let coordinates = vec![(0., 0.); coordinates_size];
+    let jobs = create_jobs(&[Some(0), Some(7)]);
+
+    let result = create_job_clusters(&jobs, &coordinates, cell_size, min_points);
+
+    assert_eq!(result.err(), Some(expected.into()));
+}
@@ -0,0 +1,52 @@
+use super::*;
+use crate::helpers::models::problem::{TestSingleBuilder, get_job_id, test_fleet};
+
+fn create_jobs(locations: &[Option<usize>]) -> Vec<Job> {
+    locations
+        .iter()
+        .enumerate()
+        .map(|(idx, &location)| {
+            TestSingleBuilder::default().id(&format!("s{idx}")).location(location).build_as_job_ref()
+        })
+        .collect()
+}
+
+fn get_distance(_: &Profile, from: &Job, to: &Job) -> Cost {
+    let get_location = |job: &Job| get_job_locations(job).flatten().next().unwrap() as Cost;
+
+    (get_location(from) - get_location(to)).abs()
+}
+
+parameterized_test! {can_create_job_clusters, (k, expected), {
+    can_create_job_clusters_impl(k, expected);
+}}
+
+can_create_job_clusters! {
+    case01_single: (1, vec![vec!["s0", "s1", "s2", "s3", "s4", "s5", "s6"]]),
+    case02_omit_single: (3, vec![vec!["s0", "s1", "s2"], vec!["s3", "s4", "s5"]]),
+}
+
+fn can_create_job_clusters_impl(k: usize, expected: Vec<Vec<&str>>) {
+    let jobs = create_jobs(&[Some(0), Some(1), Some(3), Some(40), Some(42), Some(45), Some(90), None]);
+
+    let clusters = create_job_clusters(&jobs, &test_fleet(), k, get_distance).unwrap();
+
+    let clusters = clusters
+        .into_iter()
+        .map(|cluster| {
+            let mut ids = cluster.iter().map(|job| get_job_id(job).clone()).collect::<Vec<_>>();
+            ids.sort();
+            ids
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(clusters, expected);
+}
+
+#[test]
+fn can_detect_zero_k() {
+    let jobs = create_jobs(&[Some(0), Some(1)]);
+
+    let result = create_job_clusters(&jobs, &test_fleet(), 0, get_distance);
+
+    assert_eq!(result.err(), Some("k of k-medoids job clustering should be greater than zero".into()));
+}
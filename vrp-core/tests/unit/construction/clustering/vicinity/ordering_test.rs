@@ -0,0 +1,60 @@
+use super::*;
+use crate::helpers::construction::clustering::vicinity::*;
+use crate::helpers::models::problem::{TestSingleBuilder, TestTransportCost, get_job_id};
+
+fn create_cluster(locations: &[Location]) -> Vec<ClusterInfo> {
+    locations
+        .iter()
+        .enumerate()
+        .map(|(idx, &location)| {
+            let job = TestSingleBuilder::default().id(&format!("job{idx}")).location(Some(location)).build_as_job_ref();
+            let commute = CommuteInfo { location, distance: 0., duration: 0. };
+
+            ClusterInfo {
+                job,
+                service_time: 1.,
+                place_idx: 0,
+                commute: Commute { forward: commute.clone(), backward: commute },
+            }
+        })
+        .collect()
+}
+
+parameterized_test! {can_get_optimized_order, (visiting, locations, expected), {
+    can_get_optimized_order_impl(visiting, locations, expected);
+}}
+
+can_get_optimized_order! {
+    case01_open: (VisitPolicy::OpenContinuation, vec![5, 3, 8, 4, 9], Some((vec!["job0", "job3", "job1", "job2", "job4"], 8., 0.))),
+    case02_closed: (VisitPolicy::ClosedContinuation, vec![5, 3, 8, 4, 9], Some((vec!["job0", "job3", "job1", "job2", "job4"], 8., 4.))),
+    case03_return: (VisitPolicy::Return, vec![5, 3, 8, 4, 9], None),
+    case04_optimal: (VisitPolicy::OpenContinuation, vec![5, 4, 3, 8, 9], None),
+    case05_too_small: (VisitPolicy::ClosedContinuation, vec![5, 3], None),
+}
+
+fn can_get_optimized_order_impl(
+    visiting: VisitPolicy,
+    locations: Vec<Location>,
+    expected: Option<(Vec<&str>, Duration, Duration)>,
+) {
+    let config = ClusterConfig { visiting, ..create_cluster_config() };
+    let cluster = create_cluster(locations.as_slice());
+
+    let result = get_optimized_order(cluster.as_slice(), &config, &TestTransportCost::default());
+
+    let result = result.map(|cluster| {
+        let ids = cluster.iter().map(|info| get_job_id(&info.job).as_str()).collect::<Vec<_>>();
+        let forward = cluster.iter().map(|info| info.commute.forward.duration).sum::<Duration>();
+        let backward = cluster.iter().map(|info| info.commute.backward.duration).sum::<Duration>();
+
+        assert!(cluster.windows(2).all(|pair| {
+            let location = pair[0].job.to_single().places[0].location.unwrap();
+            pair[1].commute.forward.location == location
+        }));
+
+        (ids.into_iter().map(|id| id.to_string()).collect::<Vec<_>>(), forward, backward)
+    });
+    let expected = expected
+        .map(|(ids, forward, backward)| (ids.into_iter().map(|id| id.to_string()).collect(), forward, backward));
+    assert_eq!(result, expected);
+}
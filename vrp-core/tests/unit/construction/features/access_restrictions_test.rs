@@ -0,0 +1,123 @@
+use super::*;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::solution::Activity;
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+
+fn create_restriction(profile: usize, locations: Vec<Location>, time: (Timestamp, Timestamp)) -> AccessRestriction {
+    AccessRestriction { profile, locations, time: TimeWindow::new(time.0, time.1) }
+}
+
+fn create_feature(restrictions: &[AccessRestriction]) -> Feature {
+    create_access_restriction_feature(
+        "access_restriction",
+        VIOLATION_CODE,
+        restrictions,
+        TestTransportCost::new_shared(),
+        TestActivityCost::new_shared(),
+    )
+    .unwrap()
+}
+
+fn create_route_ctx(activities: Vec<Activity>) -> RouteContext {
+    RouteContextBuilder::default()
+        .with_route(RouteBuilder::with_default_vehicle().add_activities(activities).build())
+        .build()
+}
+
+fn create_test_route_ctx() -> RouteContext {
+    create_route_ctx(vec![
+        ActivityBuilder::with_location(10).build(),
+        ActivityBuilder::with_location(20).build(),
+        ActivityBuilder::with_location(30).build(),
+    ])
+}
+
+parameterized_test! {can_evaluate_activity_insertion, (profile, location, prev_index, expected), {
+    can_evaluate_activity_insertion_impl(profile, location, prev_index, expected);
+}}
+
+can_evaluate_activity_insertion! {
+    case01_no_delay: (0, 5, 0, None),
+    case02_delay_within_limit: (0, 22, 1, None),
+    case03_delay_above_limit: (0, 24, 1, ConstraintViolation::skip(VIOLATION_CODE)),
+    case04_propagated_delay_within_limit: (0, 12, 0, None),
+    case05_propagated_delay_above_limit: (0, 13, 0, ConstraintViolation::skip(VIOLATION_CODE)),
+    case06_restricted_target: (0, 35, 3, ConstraintViolation::skip(VIOLATION_CODE)),
+    case07_other_profile: (1, 35, 3, None),
+}
+
+fn can_evaluate_activity_insertion_impl(
+    profile: usize,
+    location: Location,
+    prev_index: usize,
+    expected: Option<ConstraintViolation>,
+) {
+    let feature = create_feature(&[create_restriction(profile, vec![20, 35], (25., 40.))]);
+    let mut route_ctx = create_test_route_ctx();
+    feature.state.as_ref().unwrap().accept_route_state(&mut route_ctx);
+    let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+
+    let prev = route_ctx.route().tour.get(prev_index).unwrap();
+    let target = ActivityBuilder::with_location(location).build();
+    let next = route_ctx.route().tour.get(prev_index + 1);
+    let activity_ctx = ActivityContext { index: prev_index, prev, target: &target, next };
+
+    let result = feature.constraint.unwrap().evaluate(&MoveContext::activity(&solution_ctx, &route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_calculate_shift_limits_considering_waiting_time() {
+    let feature = create_feature(&[create_restriction(0, vec![20], (25., 40.))]);
+    let mut route_ctx = create_route_ctx(vec![
+        ActivityBuilder::with_location_and_tw(10, TimeWindow::new(15., 100.)).schedule(Schedule::new(10., 15.)).build(),
+        ActivityBuilder::with_location(20).schedule(Schedule::new(20., 20.)).build(),
+        ActivityBuilder::with_location(30).build(),
+    ]);
+
+    feature.state.unwrap().accept_route_state(&mut route_ctx);
+
+    let limits = (0..4).map(|idx| *route_ctx.state().get_access_shift_limit_at(idx).unwrap()).collect::<Vec<_>>();
+    assert_eq!(limits, vec![10., 10., 5., Float::MAX]);
+}
+
+#[test]
+fn can_remove_jobs_violating_restrictions() {
+    let feature = create_feature(&[create_restriction(0, vec![20], (15., 40.))]);
+    let mut solution_ctx =
+        TestInsertionContextBuilder::default().with_routes(vec![create_test_route_ctx()]).build().solution;
+
+    feature.state.unwrap().accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(solution_ctx.unassigned.len(), 1);
+    let (job, info) = solution_ctx.unassigned.iter().next().unwrap();
+    assert_eq!(job.places().next().and_then(|place| place.location), Some(20));
+    assert!(matches!(info, UnassignmentInfo::Simple(code) if *code == VIOLATION_CODE));
+    assert_eq!(solution_ctx.routes.first().unwrap().route().tour.job_count(), 2);
+}
+
+parameterized_test! {can_validate_restrictions, (restrictions, is_ok), {
+    can_validate_restrictions_impl(restrictions, is_ok);
+}}
+
+can_validate_restrictions! {
+    case01_valid: (vec![create_restriction(0, vec![1], (10., 20.))], true),
+    case02_empty: (vec![], false),
+    case03_invalid_time: (vec![create_restriction(0, vec![1], (20., 10.))], false),
+}
+
+fn can_validate_restrictions_impl(restrictions: Vec<AccessRestriction>, is_ok: bool) {
+    let result = create_access_restriction_feature(
+        "access_restriction",
+        VIOLATION_CODE,
+        restrictions.as_slice(),
+        TestTransportCost::new_shared(),
+        TestActivityCost::new_shared(),
+    );
+
+    assert_eq!(result.is_ok(), is_ok);
+}
@@ -0,0 +1,177 @@
+use super::*;
+use crate::construction::heuristics::ActivityContext;
+use crate::helpers::construction::features::create_simple_demand;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::{Schedule, SingleDimLoad};
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+
+fn is_change_single(single: &Single) -> bool {
+    single.dimens.get_changed_capacity::<SingleDimLoad>().is_some()
+}
+
+fn create_features() -> (Feature, Feature) {
+    let capacity = CapacityFeatureBuilder::<SingleDimLoad>::new("capacity").build().unwrap();
+    let change = CapacityChangeFeatureBuilder::<SingleDimLoad>::new("capacity_change")
+        .set_violation_code(VIOLATION_CODE)
+        .set_transport(TestTransportCost::new_shared())
+        .set_activity(TestActivityCost::new_shared())
+        .set_is_change_single(is_change_single)
+        .set_belongs_to_route(|_, job| job.as_single().is_some_and(|single| is_change_single(single)))
+        .build()
+        .unwrap();
+
+    (capacity, change)
+}
+
+fn create_change_activity(capacity: i32) -> Activity {
+    create_change_activity_with_profile(capacity, None)
+}
+
+fn create_change_activity_with_profile(capacity: i32, profile: Option<Profile>) -> Activity {
+    let mut builder = TestSingleBuilder::default();
+    builder.dimens_mut().set_changed_capacity(SingleDimLoad::new(capacity));
+    if let Some(profile) = profile {
+        builder.dimens_mut().set_changed_profile(profile);
+    }
+
+    ActivityBuilder::default().job(Some(builder.build_shared())).build()
+}
+
+fn create_demand_activity(size: i32) -> Activity {
+    ActivityBuilder::default()
+        .job(Some(TestSingleBuilder::default().demand(create_simple_demand(size)).build_shared()))
+        .build()
+}
+
+fn create_route_ctx(activities: Vec<Activity>) -> RouteContext {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(TestVehicleBuilder::default().id("v1").capacity(10).build())
+        .build();
+    let mut route_ctx = RouteContextBuilder::default()
+        .with_route(RouteBuilder::default().with_vehicle(&fleet, "v1").add_activities(activities).build())
+        .build();
+
+    let (capacity, change) = create_features();
+    capacity.state.unwrap().accept_route_state(&mut route_ctx);
+    change.state.unwrap().accept_route_state(&mut route_ctx);
+
+    route_ctx
+}
+
+fn evaluate_activity(route_ctx: &RouteContext, index: usize, target: &Activity) -> Option<ConstraintViolation> {
+    let (_, change) = create_features();
+    let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route().tour.get(index).unwrap(),
+        target,
+        next: route_ctx.route().tour.get(index + 1),
+    };
+
+    change.constraint.unwrap().evaluate(&MoveContext::activity(&solution_ctx, route_ctx, &activity_ctx))
+}
+
+parameterized_test! {can_evaluate_demand_with_capacity_change, (index, size, expected), {
+    can_evaluate_demand_with_capacity_change_impl(index, size, expected);
+}}
+
+can_evaluate_demand_with_capacity_change! {
+    case01_delivery_fits_after_change: (2, -1, None),
+    case02_delivery_exceeds_after_change: (2, -3, Some(true)),
+    case03_delivery_before_change: (1, -3, None),
+    case04_pickup_exceeds_before_change: (0, 3, Some(false)),
+    case05_pickup_fits_after_change: (3, 2, None),
+    case06_pickup_exceeds_after_change: (3, 5, Some(false)),
+}
+
+fn can_evaluate_demand_with_capacity_change_impl(index: usize, size: i32, expected: Option<bool>) {
+    let route_ctx =
+        create_route_ctx(vec![create_demand_activity(-2), create_change_activity(4), create_demand_activity(-2)]);
+
+    let result = evaluate_activity(&route_ctx, index, &create_demand_activity(size));
+
+    assert_eq!(result, expected.map(|stopped| ConstraintViolation { code: VIOLATION_CODE, stopped }));
+}
+
+parameterized_test! {can_evaluate_change_insertion, (index, expected), {
+    can_evaluate_change_insertion_impl(index, expected);
+}}
+
+can_evaluate_change_insertion! {
+    case01_full_load: (0, Some(false)),
+    case02_partial_load: (1, None),
+    case03_empty: (2, None),
+}
+
+fn can_evaluate_change_insertion_impl(index: usize, expected: Option<bool>) {
+    let route_ctx = create_route_ctx(vec![create_demand_activity(-3), create_demand_activity(-3)]);
+
+    let result = evaluate_activity(&route_ctx, index, &create_change_activity(4));
+
+    assert_eq!(result, expected.map(|stopped| ConstraintViolation { code: VIOLATION_CODE, stopped }));
+}
+
+#[test]
+fn can_reject_second_change_in_route() {
+    let (_, change) = create_features();
+    let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+    let route_ctx = create_route_ctx(vec![create_demand_activity(-1), create_change_activity(4)]);
+    let job = Job::Single(create_change_activity(4).job.unwrap());
+
+    let result = change.constraint.unwrap().evaluate(&MoveContext::route(&solution_ctx, &route_ctx, &job));
+
+    assert_eq!(result, ConstraintViolation::fail(VIOLATION_CODE));
+}
+
+struct ProfileAwareTransportCost;
+
+impl TransportCost for ProfileAwareTransportCost {
+    fn duration_approx(&self, profile: &Profile, from: Location, to: Location) -> Duration {
+        fake_routing(from, to) * (profile.index + 1) as Float
+    }
+
+    fn distance_approx(&self, profile: &Profile, from: Location, to: Location) -> Distance {
+        self.duration_approx(profile, from, to)
+    }
+
+    fn duration(&self, route: &Route, from: Location, to: Location, _: TravelTime) -> Duration {
+        self.duration_approx(&route.actor.vehicle.profile, from, to)
+    }
+
+    fn distance(&self, route: &Route, from: Location, to: Location, _: TravelTime) -> Distance {
+        self.distance_approx(&route.actor.vehicle.profile, from, to)
+    }
+
+    fn size(&self) -> usize {
+        1
+    }
+}
+
+parameterized_test! {can_switch_profile_after_change, (travel_time, expected), {
+    can_switch_profile_after_change_impl(travel_time, expected);
+}}
+
+can_switch_profile_after_change! {
+    case01_departure_before: (TravelTime::Departure(5.), 10.),
+    case02_departure_at: (TravelTime::Departure(10.), 20.),
+    case03_arrival_at: (TravelTime::Arrival(10.), 10.),
+    case04_arrival_after: (TravelTime::Arrival(15.), 20.),
+}
+
+fn can_switch_profile_after_change_impl(travel_time: TravelTime, expected: Duration) {
+    let fleet = test_fleet();
+    let actor = get_test_actor_from_fleet(&fleet, "v1");
+    let mut change = create_change_activity_with_profile(4, Some(Profile::new(1, None)));
+    change.schedule = Schedule::new(5., 10.);
+    let route = RouteBuilder::default().with_vehicle(&fleet, "v1").add_activity(change).build();
+    let transport =
+        ProfileChangeTransportCost::new(Arc::new(ProfileAwareTransportCost), vec![(actor, Profile::new(1, None))]);
+
+    let duration = transport.duration(&route, 0, 10, travel_time);
+
+    assert_eq!(duration, expected);
+}
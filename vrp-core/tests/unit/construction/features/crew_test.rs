@@ -0,0 +1,181 @@
+use super::*;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+
+fn create_feature() -> Feature {
+    create_crew_feature("crew", TestTransportCost::new_shared(), TestActivityCost::new_shared(), VIOLATION_CODE)
+        .unwrap()
+}
+
+fn create_crew_single(id: &str, crew: Option<(&str, usize)>, location: Location, tw: (Float, Float)) -> Arc<Single> {
+    let mut builder = TestSingleBuilder::default();
+
+    if let Some((crew_id, size)) = crew {
+        builder.dimens_mut().set_job_crew(CrewMember { id: crew_id.to_string(), size });
+    }
+
+    builder.id(id).location(Some(location)).times(vec![TimeWindow::new(tw.0, tw.1)]).build_shared()
+}
+
+fn create_route_ctx(singles: Vec<Arc<Single>>) -> RouteContext {
+    RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::with_default_vehicle()
+                .add_activities(singles.into_iter().map(|single| {
+                    let location = single.places[0].location.unwrap();
+                    let time = single.places[0].times[0].as_time_window().unwrap();
+                    ActivityBuilder::with_location_and_tw(location, time).job(Some(single)).build()
+                }))
+                .build(),
+        )
+        .build()
+}
+
+parameterized_test! {can_evaluate_route_insertion, (route_crew, job_crew, expected), {
+    can_evaluate_route_insertion_impl(route_crew, job_crew, expected);
+}}
+
+can_evaluate_route_insertion! {
+    case01_same_crew: (Some("c1"), Some("c1"), ConstraintViolation::fail(VIOLATION_CODE)),
+    case02_different_crew: (Some("c1"), Some("c2"), None),
+    case03_no_crew_in_route: (None, Some("c1"), None),
+    case04_no_crew_in_job: (Some("c1"), None, None),
+}
+
+fn can_evaluate_route_insertion_impl(
+    route_crew: Option<&str>,
+    job_crew: Option<&str>,
+    expected: Option<ConstraintViolation>,
+) {
+    let feature = create_feature();
+    let mut route_ctx =
+        create_route_ctx(vec![create_crew_single("job1", route_crew.map(|id| (id, 2)), 10, (0., 1000.))]);
+    feature.state.as_ref().unwrap().accept_route_state(&mut route_ctx);
+    let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+    let job = Job::Single(create_crew_single("job2", job_crew.map(|id| (id, 2)), 5, (0., 1000.)));
+
+    let result = feature.constraint.unwrap().evaluate(&MoveContext::route(&solution_ctx, &route_ctx, &job));
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_evaluate_activity_insertion, (crew_start, target_tw, expected), {
+    can_evaluate_activity_insertion_impl(crew_start, target_tw, expected);
+}}
+
+can_evaluate_activity_insertion! {
+    case01_no_crew_start: (None, (0., 1000.), None),
+    case02_start_at_arrival: (Some(5.), (0., 1000.), None),
+    case03_start_with_waiting: (Some(15.), (0., 1000.), None),
+    case04_start_before_arrival: (Some(3.), (0., 1000.), ConstraintViolation::skip(VIOLATION_CODE)),
+    case05_start_delays_next: (Some(16.), (0., 1000.), ConstraintViolation::skip(VIOLATION_CODE)),
+    case06_start_outside_tw: (Some(12.), (0., 10.), ConstraintViolation::skip(VIOLATION_CODE)),
+}
+
+fn can_evaluate_activity_insertion_impl(
+    crew_start: Option<Timestamp>,
+    target_tw: (Float, Float),
+    expected: Option<ConstraintViolation>,
+) {
+    let feature = create_feature();
+    let route_ctx = create_route_ctx(vec![create_crew_single("job1", None, 10, (0., 20.))]);
+    let solution_ctx = TestInsertionContextBuilder::default()
+        .with_state(|state| {
+            if let Some(start) = crew_start {
+                state.set_crew_times(HashMap::from([("c1".to_string(), start)]));
+            }
+        })
+        .build()
+        .solution;
+
+    let prev = route_ctx.route().tour.get(0).unwrap();
+    let next = route_ctx.route().tour.get(1);
+    let single = create_crew_single("job2", Some(("c1", 2)), 5, target_tw);
+    let target =
+        ActivityBuilder::with_location_and_tw(5, TimeWindow::new(target_tw.0, target_tw.1)).job(Some(single)).build();
+    let activity_ctx = ActivityContext { index: 0, prev, target: &target, next };
+
+    let result = feature.constraint.unwrap().evaluate(&MoveContext::activity(&solution_ctx, &route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_pin_service_start_on_insertion, (crew_start, expected), {
+    can_pin_service_start_on_insertion_impl(crew_start, expected);
+}}
+
+can_pin_service_start_on_insertion! {
+    case01_first_member: (None, (10., 10.)),
+    case02_other_member: (Some(15.), (15., 15.)),
+}
+
+fn can_pin_service_start_on_insertion_impl(crew_start: Option<Timestamp>, expected: (Float, Float)) {
+    let feature = create_feature();
+    let single = create_crew_single("job1", Some(("c1", 2)), 10, (0., 100.));
+    let job = Job::Single(single.clone());
+    let mut solution_ctx = TestInsertionContextBuilder::default()
+        .with_routes(vec![create_route_ctx(vec![single])])
+        .with_state(|state| {
+            if let Some(start) = crew_start {
+                state.set_crew_times(HashMap::from([("c1".to_string(), start)]));
+            }
+        })
+        .build()
+        .solution;
+
+    feature.state.as_ref().unwrap().accept_insertion(&mut solution_ctx, 0, &job);
+
+    let activity = solution_ctx.routes[0].route().tour.get(1).unwrap();
+    assert_eq!((activity.place.time.start, activity.place.time.end), expected);
+    assert_eq!(activity.schedule.departure, expected.1);
+    assert_eq!(solution_ctx.state.get_crew_times().and_then(|times| times.get("c1")).copied(), Some(expected.0));
+    assert!(solution_ctx.routes[0].state().get_crew_ids().is_some_and(|ids| ids.contains("c1")));
+}
+
+#[test]
+fn can_remove_partially_assigned_crews() {
+    let feature = create_feature();
+    let partial = vec![
+        create_crew_single("job1_1", Some(("job1", 3)), 10, (0., 100.)),
+        create_crew_single("job1_2", Some(("job1", 3)), 10, (0., 100.)),
+    ];
+    let full = vec![
+        create_crew_single("job2_1", Some(("job2", 2)), 20, (0., 100.)),
+        create_crew_single("job2_2", Some(("job2", 2)), 20, (0., 100.)),
+    ];
+    let mut solution_ctx = TestInsertionContextBuilder::default()
+        .with_routes(vec![
+            create_route_ctx(vec![partial[0].clone(), full[0].clone()]),
+            create_route_ctx(vec![partial[1].clone(), full[1].clone()]),
+        ])
+        .build()
+        .solution;
+
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(solution_ctx.unassigned.len(), 2);
+    partial.into_iter().map(Job::Single).for_each(|job| {
+        assert!(solution_ctx.unassigned.contains_key(&job));
+        assert!(solution_ctx.routes.iter().all(|route_ctx| !route_ctx.route().tour.contains(&job)));
+    });
+    full.into_iter().map(Job::Single).for_each(|job| {
+        assert!(solution_ctx.routes.iter().any(|route_ctx| route_ctx.route().tour.contains(&job)));
+    });
+    assert!(solution_ctx.routes.iter().all(|route_ctx| {
+        route_ctx.state().get_crew_ids().is_some_and(|ids| ids.len() == 1 && ids.contains("job2"))
+    }));
+}
+
+#[test]
+fn can_reject_merge_of_crew_jobs() {
+    let constraint = create_feature().constraint.unwrap();
+    let crew_job = Job::Single(create_crew_single("job1", Some(("job1", 2)), 10, (0., 100.)));
+    let other_job = Job::Single(create_crew_single("job2", None, 10, (0., 100.)));
+
+    assert!(constraint.merge(crew_job.clone(), other_job.clone()).is_err());
+    assert!(constraint.merge(other_job.clone(), crew_job).is_err());
+    assert!(constraint.merge(other_job.clone(), other_job).is_ok());
+}
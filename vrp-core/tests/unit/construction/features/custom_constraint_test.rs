@@ -0,0 +1,103 @@
+use super::*;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::{TestSingleBuilder, test_fleet};
+use crate::helpers::models::solution::{ActivityBuilder, RouteBuilder, RouteContextBuilder};
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+
+struct JobCountKey;
+
+fn create_route_ctx(locations: &[Location]) -> RouteContext {
+    RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::default()
+                .with_vehicle(&test_fleet(), "v1")
+                .add_activities(locations.iter().map(|&location| ActivityBuilder::with_location(location).build()))
+                .build(),
+        )
+        .build()
+}
+
+fn create_job_count_feature(max_jobs: usize) -> Feature {
+    CustomConstraintBuilder::new("max_jobs")
+        .set_violation_code(VIOLATION_CODE)
+        .add_route_state::<JobCountKey, _, _>(|route_ctx| route_ctx.route().tour.job_count())
+        .add_route_rule(move |route_ctx, _| {
+            route_ctx.state().get_tour_state::<JobCountKey, usize>().is_none_or(|&count| count < max_jobs)
+        })
+        .build()
+        .unwrap()
+}
+
+parameterized_test! {can_use_route_rule_with_state, (locations, max_jobs, expected), {
+    can_use_route_rule_with_state_impl(locations, max_jobs, expected);
+}}
+
+can_use_route_rule_with_state! {
+    case01_empty: (&[], 1, None),
+    case02_below_limit: (&[1, 2], 3, None),
+    case03_at_limit: (&[1, 2, 3], 3, ConstraintViolation::fail(VIOLATION_CODE)),
+}
+
+fn can_use_route_rule_with_state_impl(locations: &[Location], max_jobs: usize, expected: Option<ConstraintViolation>) {
+    let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+    let feature = create_job_count_feature(max_jobs);
+    let mut route_ctx = create_route_ctx(locations);
+    feature.state.as_ref().unwrap().accept_route_state(&mut route_ctx);
+
+    let result = feature.constraint.unwrap().evaluate(&MoveContext::route(
+        &solution_ctx,
+        &route_ctx,
+        &TestSingleBuilder::default().build_as_job_ref(),
+    ));
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_use_activity_rule, (target_location, expected), {
+    can_use_activity_rule_impl(target_location, expected);
+}}
+
+can_use_activity_rule! {
+    case01_allowed: (5, None),
+    case02_not_allowed: (15, ConstraintViolation::skip(VIOLATION_CODE)),
+}
+
+fn can_use_activity_rule_impl(target_location: Location, expected: Option<ConstraintViolation>) {
+    let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+    let route_ctx = create_route_ctx(&[1]);
+    let constraint = CustomConstraintBuilder::new("max_location")
+        .set_violation_code(VIOLATION_CODE)
+        .add_activity_rule(|_, activity_ctx| activity_ctx.target.place.location < 10)
+        .build()
+        .unwrap()
+        .constraint
+        .unwrap();
+    let prev = ActivityBuilder::with_location(1).build();
+    let target = ActivityBuilder::with_location(target_location).build();
+    let activity_ctx = ActivityContext { index: 1, prev: &prev, target: &target, next: None };
+
+    let result = constraint.evaluate(&MoveContext::activity(&solution_ctx, &route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_merge_jobs_only_with_merge_rule() {
+    let create_builder = || CustomConstraintBuilder::new("custom").add_route_rule(|_, _| true);
+    let source = TestSingleBuilder::default().id("source").build_as_job_ref();
+    let candidate = TestSingleBuilder::default().id("candidate").build_as_job_ref();
+
+    let without_rule = create_builder().build().unwrap().constraint.unwrap();
+    let with_rule = create_builder().set_merge_rule(|_, _| true).build().unwrap().constraint.unwrap();
+
+    assert!(without_rule.merge(source.clone(), candidate.clone()).is_err());
+    assert!(with_rule.merge(source.clone(), candidate).is_ok_and(|job| job == source));
+}
+
+#[test]
+fn can_detect_no_rules() {
+    let result = CustomConstraintBuilder::new("custom").add_route_state::<JobCountKey, _, _>(|_| 0_usize).build();
+
+    assert_eq!(result.err(), Some(GenericError::from("custom constraint 'custom' has no rules")));
+}
@@ -0,0 +1,110 @@
+use super::*;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::TestSingleBuilder;
+use crate::helpers::models::solution::{ActivityBuilder, RouteBuilder, RouteContextBuilder};
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+
+fn create_feature() -> Feature {
+    let incompatibilities = vec![
+        vec!["food".to_string(), "chemicals".to_string()],
+        vec!["pets".to_string(), "chemicals".to_string(), "medicine".to_string()],
+    ];
+
+    create_incompatibility_feature("incompatibility", VIOLATION_CODE, incompatibilities.as_slice()).unwrap()
+}
+
+fn create_test_single(category: Option<&str>) -> Arc<Single> {
+    let mut builder = TestSingleBuilder::default();
+
+    if let Some(category) = category {
+        builder.dimens_mut().set_job_category(category.to_string());
+    }
+
+    builder.location(Some(1)).build_shared()
+}
+
+fn create_test_route_ctx(feature: &Feature, categories: &[Option<&str>]) -> RouteContext {
+    let mut route_ctx =
+        RouteContextBuilder::default()
+            .with_route(
+                RouteBuilder::with_default_vehicle()
+                    .add_activities(categories.iter().map(|category| {
+                        ActivityBuilder::with_location(1).job(Some(create_test_single(*category))).build()
+                    }))
+                    .build(),
+            )
+            .build();
+
+    feature.state.as_ref().unwrap().accept_route_state(&mut route_ctx);
+
+    route_ctx
+}
+
+parameterized_test! {can_check_incompatibility, (job_category, route_categories, expected), {
+    can_check_incompatibility_impl(job_category, route_categories, expected);
+}}
+
+can_check_incompatibility! {
+    case_01: (Some("food"), vec![Some("chemicals")], Some(())),
+    case_02: (Some("chemicals"), vec![None, Some("food")], Some(())),
+    case_03: (Some("medicine"), vec![Some("pets"), Some("food")], Some(())),
+    case_04: (Some("food"), vec![Some("pets"), Some("medicine")], None),
+    case_05: (Some("food"), vec![Some("food")], None),
+    case_06: (None, vec![Some("chemicals")], None),
+    case_07: (Some("chemicals"), vec![None], None),
+    case_08: (Some("unknown"), vec![Some("chemicals")], None),
+}
+
+fn can_check_incompatibility_impl(
+    job_category: Option<&str>,
+    route_categories: Vec<Option<&str>>,
+    expected: Option<()>,
+) {
+    let feature = create_feature();
+    let route_ctx = create_test_route_ctx(&feature, route_categories.as_slice());
+    let solution_ctx = TestInsertionContextBuilder::default().with_routes(vec![route_ctx]).build().solution;
+    let job = Job::Single(create_test_single(job_category));
+
+    let result = feature
+        .constraint
+        .unwrap()
+        .evaluate(&MoveContext::route(&solution_ctx, &solution_ctx.routes[0], &job))
+        .map(|_| ());
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_merge_jobs, (source_category, candidate_category, expected), {
+    can_merge_jobs_impl(source_category, candidate_category, expected);
+}}
+
+can_merge_jobs! {
+    case_01: (Some("food"), Some("food"), Ok(())),
+    case_02: (Some("food"), Some("chemicals"), Err(VIOLATION_CODE)),
+    case_03: (Some("medicine"), Some("pets"), Err(VIOLATION_CODE)),
+    case_04: (Some("food"), None, Ok(())),
+    case_05: (None, None, Ok(())),
+}
+
+fn can_merge_jobs_impl(
+    source_category: Option<&str>,
+    candidate_category: Option<&str>,
+    expected: Result<(), ViolationCode>,
+) {
+    let source = Job::Single(create_test_single(source_category));
+    let candidate = Job::Single(create_test_single(candidate_category));
+
+    let result = create_feature().constraint.unwrap().merge(source, candidate).map(|_| ());
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_return_err_without_incompatible_categories() {
+    let incompatibilities = vec![vec!["food".to_string()], vec![]];
+
+    let result = create_incompatibility_feature("incompatibility", VIOLATION_CODE, incompatibilities.as_slice());
+
+    assert!(result.is_err());
+}
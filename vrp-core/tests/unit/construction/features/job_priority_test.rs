@@ -0,0 +1,92 @@
+use super::*;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::TestSingleBuilder;
+use crate::helpers::models::solution::RouteContextBuilder;
+
+fn create_test_job(id: &str, priority: Option<usize>) -> Job {
+    let mut builder = TestSingleBuilder::default();
+    builder.id(id);
+
+    if let Some(priority) = priority {
+        builder.dimens_mut().set_job_priority(priority);
+    }
+
+    builder.build_as_job_ref()
+}
+
+fn create_test_jobs() -> Vec<Job> {
+    vec![
+        create_test_job("job1", Some(1)),
+        create_test_job("job2", Some(2)),
+        create_test_job("job3", Some(2)),
+        create_test_job("job4", None),
+        create_test_job("job5", None),
+        create_test_job("job6", None),
+    ]
+}
+
+fn get_fitness(features: &[Feature], jobs: &[Job], unassigned: &[&str]) -> Vec<Float> {
+    let unassigned = jobs
+        .iter()
+        .filter(|job| unassigned.contains(&job.dimens().get_job_id().unwrap().as_str()))
+        .map(|job| (job.clone(), UnassignmentInfo::Unknown))
+        .collect();
+    let insertion_ctx = TestInsertionContextBuilder::default().with_unassigned(unassigned).build();
+
+    features.iter().map(|feature| feature.objective.as_ref().unwrap().fitness(&insertion_ctx)).collect()
+}
+
+#[test]
+fn can_create_feature_per_priority_tier() {
+    let features = create_job_priority_features("priority", &create_test_jobs()).unwrap();
+
+    let names = features.iter().map(|feature| feature.name.as_str()).collect::<Vec<_>>();
+    assert_eq!(names, vec!["priority_1", "priority_2", "priority_default"]);
+}
+
+parameterized_test! {can_compare_unassigned_jobs_lexicographically, (better, worse), {
+    can_compare_unassigned_jobs_lexicographically_impl(better, worse);
+}}
+
+can_compare_unassigned_jobs_lexicographically! {
+    case_01: (vec!["job4", "job5", "job6"], vec!["job2"]),
+    case_02: (vec!["job2", "job3", "job4", "job5", "job6"], vec!["job1"]),
+    case_03: (vec!["job3"], vec!["job2", "job4"]),
+    case_04: (vec![], vec!["job6"]),
+}
+
+fn can_compare_unassigned_jobs_lexicographically_impl(better: Vec<&str>, worse: Vec<&str>) {
+    let jobs = create_test_jobs();
+    let features = create_job_priority_features("priority", &jobs).unwrap();
+
+    let better = get_fitness(&features, &jobs, better.as_slice());
+    let worse = get_fitness(&features, &jobs, worse.as_slice());
+
+    assert!(better < worse, "{better:?} vs {worse:?}");
+}
+
+parameterized_test! {can_estimate_job_insertion, (priority, expected), {
+    can_estimate_job_insertion_impl(priority, expected);
+}}
+
+can_estimate_job_insertion! {
+    case_01: (None, vec![0., 0., -1.]),
+    case_02: (Some(2), vec![0., -1., 0.]),
+    case_03: (Some(1), vec![-1., 0., 0.]),
+}
+
+fn can_estimate_job_insertion_impl(priority: Option<usize>, expected: Vec<Float>) {
+    let features = create_job_priority_features("priority", &create_test_jobs()).unwrap();
+    let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+    let route_ctx = RouteContextBuilder::default().build();
+    let job = create_test_job("job", priority);
+
+    let result = features
+        .iter()
+        .map(|feature| {
+            feature.objective.as_ref().unwrap().estimate(&MoveContext::route(&solution_ctx, &route_ctx, &job))
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(result, expected);
+}
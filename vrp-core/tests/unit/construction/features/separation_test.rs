@@ -0,0 +1,104 @@
+use super::*;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::{ActivityBuilder, RouteBuilder, RouteContextBuilder};
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+
+fn create_test_single(separations: &[(usize, SeparationScope)]) -> Arc<Single> {
+    let mut builder = TestSingleBuilder::default();
+
+    if !separations.is_empty() {
+        builder
+            .dimens_mut()
+            .set_job_separations(separations.iter().map(|&(group, scope)| JobSeparation { group, scope }).collect());
+    }
+
+    builder.location(Some(1)).build_shared()
+}
+
+fn create_test_route_ctx(feature: &Feature, fleet: &Fleet, vehicle_id: &str, groups: &[usize]) -> RouteContext {
+    let mut route_ctx = RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::default()
+                .with_vehicle(fleet, vehicle_id)
+                .add_activities(groups.iter().map(|&group| {
+                    ActivityBuilder::with_location(1)
+                        .job(Some(create_test_single(&[(group, SeparationScope::Vehicle)])))
+                        .build()
+                }))
+                .build(),
+        )
+        .build();
+
+    feature.state.as_ref().unwrap().accept_route_state(&mut route_ctx);
+
+    route_ctx
+}
+
+parameterized_test! {can_check_separation, (job_separations, route_groups, other_route, expected), {
+    can_check_separation_impl(job_separations, route_groups, other_route, expected);
+}}
+
+can_check_separation! {
+    case_01_same_tour: (vec![(1, SeparationScope::Tour)], vec![1], None, Some(())),
+    case_02_different_groups: (vec![(1, SeparationScope::Tour)], vec![2], None, None),
+    case_03_no_job_groups: (vec![], vec![1], None, None),
+    case_04_empty_route: (vec![(1, SeparationScope::Tour)], vec![], None, None),
+    case_05_tour_other_route: (vec![(1, SeparationScope::Tour)], vec![], Some(("v1", 1)), None),
+    case_06_vehicle_same_tour: (vec![(1, SeparationScope::Vehicle)], vec![1], None, Some(())),
+    case_07_vehicle_same_vehicle: (vec![(1, SeparationScope::Vehicle)], vec![], Some(("v1", 1)), Some(())),
+    case_08_vehicle_other_vehicle: (vec![(1, SeparationScope::Vehicle)], vec![], Some(("v2", 1)), None),
+    case_09_multiple_groups: (vec![(2, SeparationScope::Tour), (1, SeparationScope::Vehicle)], vec![], Some(("v1", 1)), Some(())),
+}
+
+fn can_check_separation_impl(
+    job_separations: Vec<(usize, SeparationScope)>,
+    route_groups: Vec<usize>,
+    other_route: Option<(&str, usize)>,
+    expected: Option<()>,
+) {
+    let feature = create_separation_feature("separation", VIOLATION_CODE).unwrap();
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![test_vehicle_with_id("v1"), test_vehicle_with_id("v2")])
+        .build();
+    let routes = std::iter::once(create_test_route_ctx(&feature, &fleet, "v1", route_groups.as_slice()))
+        .chain(other_route.map(|(vehicle_id, group)| create_test_route_ctx(&feature, &fleet, vehicle_id, &[group])))
+        .collect();
+    let solution_ctx = TestInsertionContextBuilder::default().with_routes(routes).build().solution;
+    let job = Job::Single(create_test_single(job_separations.as_slice()));
+
+    let result = feature
+        .constraint
+        .unwrap()
+        .evaluate(&MoveContext::route(&solution_ctx, &solution_ctx.routes[0], &job))
+        .map(|_| ());
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_merge_jobs, (source_groups, candidate_groups, expected), {
+    can_merge_jobs_impl(source_groups, candidate_groups, expected);
+}}
+
+can_merge_jobs! {
+    case_01: (vec![1], vec![1], Err(VIOLATION_CODE)),
+    case_02: (vec![1, 2], vec![3, 2], Err(VIOLATION_CODE)),
+    case_03: (vec![1], vec![2], Ok(())),
+    case_04: (vec![], vec![1], Ok(())),
+    case_05: (vec![1], vec![], Ok(())),
+}
+
+fn can_merge_jobs_impl(source_groups: Vec<usize>, candidate_groups: Vec<usize>, expected: Result<(), ViolationCode>) {
+    let create_job = |groups: &[usize]| {
+        Job::Single(create_test_single(
+            groups.iter().map(|&group| (group, SeparationScope::Tour)).collect::<Vec<_>>().as_slice(),
+        ))
+    };
+    let constraint = create_separation_feature("separation", VIOLATION_CODE).unwrap().constraint.unwrap();
+
+    let result = constraint.merge(create_job(&source_groups), create_job(&candidate_groups)).map(|_| ());
+
+    assert_eq!(result, expected);
+}
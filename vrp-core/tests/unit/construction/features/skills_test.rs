@@ -12,6 +12,7 @@ fn create_job_with_skills(all_of: Option<Vec<&str>>, one_of: Option<Vec<&str>>,
         all_of: all_of.map(|skills| skills.iter().map(|s| s.to_string()).collect()),
         one_of: one_of.map(|skills| skills.iter().map(|s| s.to_string()).collect()),
         none_of: none_of.map(|skills| skills.iter().map(|s| s.to_string()).collect()),
+        levels: None,
     });
 
     builder.build_as_job_ref()
@@ -83,15 +84,18 @@ fn can_check_skills_impl(
     let route_ctx =
         RouteContextBuilder::default().with_route(RouteBuilder::default().with_vehicle(&fleet, "v1").build()).build();
 
+    let job = create_job_with_skills(all_of, one_of, none_of);
     let constraint = create_skills_feature("skills", VIOLATION_CODE).unwrap().constraint.unwrap();
 
     let actual = constraint.evaluate(&MoveContext::route(
         &TestInsertionContextBuilder::default().build().solution,
         &route_ctx,
-        &create_job_with_skills(all_of, one_of, none_of),
+        &job,
     ));
+    let compatibility = constraint.check_compatibility(route_ctx.route().actor.as_ref(), &job);
 
-    assert_eq!(actual, expected)
+    assert_eq!(actual, expected);
+    assert_eq!(compatibility, expected.map(|violation| violation.code));
 }
 
 parameterized_test! {can_merge_skills, (source, candidate, expected), {
@@ -131,3 +135,126 @@ fn can_create_empty_skills_as_none() {
     assert!(skills.one_of.is_none());
     assert!(skills.none_of.is_none());
 }
+
+fn create_job_with_levels(levels: Vec<(&str, usize, Option<usize>)>) -> Job {
+    let mut builder = TestSingleBuilder::default();
+    builder.dimens_mut().set_job_skills(
+        JobSkills::new(None, None, None).with_levels(
+            levels
+                .into_iter()
+                .map(|(skill, min, preferred)| (skill.to_string(), JobSkillLevel { min, preferred }))
+                .collect(),
+        ),
+    );
+
+    builder.build_as_job_ref()
+}
+
+fn create_vehicle_with_levels(skills: Vec<&str>, levels: Vec<(&str, usize)>) -> Vehicle {
+    let mut builder = TestVehicleBuilder::default();
+    builder.dimens_mut().set_vehicle_skills(skills.into_iter().map(|skill| skill.to_string()).collect());
+    builder.dimens_mut().set_vehicle_skill_levels(
+        levels.into_iter().map(|(skill, level)| (skill.to_string(), level)).collect::<HashMap<_, _>>(),
+    );
+
+    builder.id("v1").build()
+}
+
+parameterized_test! {can_check_skill_levels, (job_levels, vehicle_skills, vehicle_levels, expected), {
+    can_check_skill_levels_impl(job_levels, vehicle_skills, vehicle_levels, expected);
+}}
+
+can_check_skill_levels! {
+    case01_enough_level: (vec![("s1", 2, None)], vec!["s1"], vec![("s1", 2)], None),
+    case02_higher_level: (vec![("s1", 2, None)], vec!["s1"], vec![("s1", 3)], None),
+    case03_lower_level: (vec![("s1", 2, None)], vec!["s1"], vec![("s1", 1)], failure()),
+    case04_missing_skill: (vec![("s1", 1, None)], vec!["s2"], vec![], failure()),
+    case05_default_level: (vec![("s1", 1, None)], vec!["s1"], vec![], None),
+    case06_default_level_too_low: (vec![("s1", 2, None)], vec!["s1"], vec![], failure()),
+    case07_preference_only: (vec![("s1", 0, Some(3))], vec![], vec![], None),
+    case08_multiple_levels: (vec![("s1", 1, None), ("s2", 2, None)], vec!["s1", "s2"], vec![("s2", 1)], failure()),
+}
+
+fn can_check_skill_levels_impl(
+    job_levels: Vec<(&str, usize, Option<usize>)>,
+    vehicle_skills: Vec<&str>,
+    vehicle_levels: Vec<(&str, usize)>,
+    expected: Option<ConstraintViolation>,
+) {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(create_vehicle_with_levels(vehicle_skills, vehicle_levels))
+        .build();
+    let route_ctx =
+        RouteContextBuilder::default().with_route(RouteBuilder::default().with_vehicle(&fleet, "v1").build()).build();
+    let job = create_job_with_levels(job_levels);
+    let constraint = create_skills_feature("skills", VIOLATION_CODE).unwrap().constraint.unwrap();
+
+    let actual = constraint.evaluate(&MoveContext::route(
+        &TestInsertionContextBuilder::default().build().solution,
+        &route_ctx,
+        &job,
+    ));
+
+    assert_eq!(actual, expected);
+}
+
+parameterized_test! {can_estimate_skill_preference, (job_levels, vehicle_levels, expected), {
+    can_estimate_skill_preference_impl(job_levels, vehicle_levels, expected);
+}}
+
+can_estimate_skill_preference! {
+    case01_no_preference: (vec![("s1", 1, None)], vec![("s1", 1)], 0.),
+    case02_preferred_level: (vec![("s1", 1, Some(3))], vec![("s1", 3)], 0.),
+    case03_higher_level: (vec![("s1", 1, Some(3))], vec![("s1", 5)], 0.),
+    case04_lower_level: (vec![("s1", 1, Some(3))], vec![("s1", 1)], 2.),
+    case05_missing_skill: (vec![("s1", 0, Some(3))], vec![], 3.),
+    case06_multiple_levels: (vec![("s1", 1, Some(3)), ("s2", 1, Some(2))], vec![("s1", 2), ("s2", 1)], 2.),
+}
+
+fn can_estimate_skill_preference_impl(
+    job_levels: Vec<(&str, usize, Option<usize>)>,
+    vehicle_levels: Vec<(&str, usize)>,
+    expected: Cost,
+) {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(create_vehicle_with_levels(vec![], vehicle_levels))
+        .build();
+    let route_ctx =
+        RouteContextBuilder::default().with_route(RouteBuilder::default().with_vehicle(&fleet, "v1").build()).build();
+    let job = create_job_with_levels(job_levels);
+    let objective = create_skill_preference_feature("skill_preference").unwrap().objective.unwrap();
+
+    let actual = objective.estimate(&MoveContext::route(
+        &TestInsertionContextBuilder::default().build().solution,
+        &route_ctx,
+        &job,
+    ));
+
+    assert_eq!(actual, expected);
+}
+
+parameterized_test! {can_merge_skill_levels, (source, candidate, expected), {
+    can_merge_skill_levels_impl(source, candidate, expected);
+}}
+
+can_merge_skill_levels! {
+    case01_same: (vec![("s1", 2, None)], vec![("s1", 2, None)], Ok(())),
+    case02_higher_source: (vec![("s1", 3, None)], vec![("s1", 2, None)], Ok(())),
+    case03_lower_source: (vec![("s1", 1, None)], vec![("s1", 2, None)], Err(VIOLATION_CODE)),
+    case04_missing_source: (vec![("s2", 2, None)], vec![("s1", 1, None)], Err(VIOLATION_CODE)),
+    case05_no_candidate: (vec![("s1", 2, None)], vec![], Ok(())),
+}
+
+fn can_merge_skill_levels_impl(
+    source: Vec<(&str, usize, Option<usize>)>,
+    candidate: Vec<(&str, usize, Option<usize>)>,
+    expected: Result<(), ViolationCode>,
+) {
+    let constraint = create_skills_feature("skills", VIOLATION_CODE).unwrap().constraint.unwrap();
+
+    let result = constraint.merge(create_job_with_levels(source), create_job_with_levels(candidate)).map(|_| ());
+
+    assert_eq!(result, expected);
+}
@@ -56,6 +56,109 @@ mod activity {
     }
 }
 
+mod stops {
+    use super::*;
+    use crate::construction::heuristics::RouteContext;
+    use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+    use crate::models::problem::Single;
+
+    const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+
+    fn create_stop_filter() -> StopFilterFn {
+        Arc::new(|single: &Single| single.dimens.get_job_id().is_none_or(|id| id != "break"))
+    }
+
+    fn create_route_ctx(feature: &Feature, stops: usize, breaks: usize) -> RouteContext {
+        let activities = (0..stops + breaks).map(|idx| {
+            let id = if idx < stops { format!("job{idx}") } else { "break".to_string() };
+            ActivityBuilder::with_location(idx)
+                .job(Some(TestSingleBuilder::default().id(id.as_str()).location(Some(idx)).build_shared()))
+                .build()
+        });
+        let mut route_ctx = RouteContextBuilder::default()
+            .with_route(RouteBuilder::default().with_vehicle(&test_fleet(), "v1").add_activities(activities).build())
+            .build();
+        feature.state.as_ref().unwrap().accept_route_state(&mut route_ctx);
+
+        route_ctx
+    }
+
+    fn create_job(id: &str, job_size: usize) -> Job {
+        if job_size == 1 {
+            TestSingleBuilder::default().id(id).build_as_job_ref()
+        } else {
+            Job::Multi(test_multi_job_with_locations((0..job_size).map(|idx| vec![Some(idx as Location)]).collect()))
+        }
+    }
+
+    parameterized_test! {can_limit_by_stops, (stops, breaks, job, limit, expected), {
+        can_limit_by_stops_impl(stops, breaks, job, limit, expected);
+    }}
+
+    can_limit_by_stops! {
+        case01: (3, 0, ("job", 1), Some(3), ConstraintViolation::fail(VIOLATION_CODE)),
+        case02: (3, 0, ("job", 1), None, None),
+        case03: (2, 1, ("job", 1), Some(3), None),
+        case04: (3, 0, ("break", 1), Some(3), None),
+        case05: (2, 2, ("job", 2), Some(3), ConstraintViolation::fail(VIOLATION_CODE)),
+        case06: (1, 2, ("job", 2), Some(3), None),
+    }
+
+    fn can_limit_by_stops_impl(
+        stops: usize,
+        breaks: usize,
+        job: (&str, usize),
+        limit: Option<usize>,
+        expected: Option<ConstraintViolation>,
+    ) {
+        let feature = create_stop_limit_hard_feature(
+            "stop_limit",
+            VIOLATION_CODE,
+            Arc::new(move |_| limit),
+            create_stop_filter(),
+        )
+        .unwrap();
+        let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+        let route_ctx = create_route_ctx(&feature, stops, breaks);
+        let job = create_job(job.0, job.1);
+
+        let result = feature.constraint.unwrap().evaluate(&MoveContext::route(&solution_ctx, &route_ctx, &job));
+
+        assert_eq!(result, expected);
+    }
+
+    parameterized_test! {can_penalize_stops_above_limit, (stops, job_size, limit, expected), {
+        can_penalize_stops_above_limit_impl(stops, job_size, limit, expected);
+    }}
+
+    can_penalize_stops_above_limit! {
+        case01: (3, 1, Some(3), (0., 1.)),
+        case02: (4, 1, Some(3), (1., 1.)),
+        case03: (2, 2, Some(3), (0., 1.)),
+        case04: (4, 2, None, (0., 0.)),
+    }
+
+    fn can_penalize_stops_above_limit_impl(
+        stops: usize,
+        job_size: usize,
+        limit: Option<usize>,
+        expected: (Float, Float),
+    ) {
+        let feature =
+            create_stop_limit_soft_feature("stop_limit", Arc::new(move |_| limit), create_stop_filter()).unwrap();
+        let route_ctx = create_route_ctx(&feature, stops, 1);
+        let insertion_ctx = TestInsertionContextBuilder::default().with_routes(vec![route_ctx]).build();
+        let objective = feature.objective.unwrap();
+        let job = create_job("job", job_size);
+
+        let fitness = objective.fitness(&insertion_ctx);
+        let estimate =
+            objective.estimate(&MoveContext::route(&insertion_ctx.solution, &insertion_ctx.solution.routes[0], &job));
+
+        assert_eq!((fitness, estimate), expected);
+    }
+}
+
 mod traveling {
     use super::*;
     use crate::construction::enablers::{TotalDistanceTourState, TotalDurationTourState};
@@ -165,6 +268,119 @@ mod traveling {
     }
 }
 
+mod cumulative {
+    use super::*;
+    use crate::construction::enablers::TotalDistanceTourState;
+    use crate::construction::features::tour_limits::TotalDrivingTimeTourState;
+    use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+    use crate::models::problem::{Actor, Fleet};
+
+    const DISTANCE_CODE: ViolationCode = ViolationCode(2);
+    const DURATION_CODE: ViolationCode = ViolationCode(3);
+
+    fn create_route_ctx(actor: Arc<Actor>, totals: (Distance, Duration)) -> RouteContext {
+        let mut state = RouteState::default();
+        state.set_total_distance(totals.0);
+        state.set_total_driving_time(totals.1);
+
+        let mut route = RouteBuilder::default().with_vehicle(&test_fleet(), "v1").build();
+        route.actor = actor;
+
+        RouteContextBuilder::default().with_route(route).with_state(state).build()
+    }
+
+    fn get_actors(fleet: &Fleet, vehicle_id: &str) -> Vec<Arc<Actor>> {
+        fleet.actors.iter().filter(|actor| get_vehicle_id(actor.vehicle.as_ref()) == vehicle_id).cloned().collect()
+    }
+
+    parameterized_test! {can_check_cumulative_limits, (other_route, limit, expected), {
+        can_check_cumulative_limits_impl(other_route, limit, expected);
+    }}
+
+    can_check_cumulative_limits! {
+        case01_own_tour_distance: (None, (Some(100.), None), None),
+        case02_own_tour_driving: (None, (None, Some(100.)), None),
+        case03_same_vehicle_distance: (Some(("v1", 1)), (Some(100.), None), ConstraintViolation::skip(DISTANCE_CODE)),
+        case04_same_vehicle_driving: (Some(("v1", 1)), (None, Some(100.)), ConstraintViolation::skip(DURATION_CODE)),
+        case05_other_vehicle_distance: (Some(("v2", 0)), (Some(100.), None), None),
+        case06_other_vehicle_driving: (Some(("v2", 0)), (None, Some(100.)), None),
+        case07_same_vehicle_within_limit: (Some(("v1", 1)), (Some(130.), Some(130.)), None),
+        case08_no_limits: (Some(("v1", 1)), (None, None), None),
+    }
+
+    fn can_check_cumulative_limits_impl(
+        other_route: Option<(&str, usize)>,
+        limit: (Option<Distance>, Option<Duration>),
+        expected: Option<ConstraintViolation>,
+    ) {
+        let mut v1 = test_vehicle_with_id("v1");
+        v1.details = vec![test_vehicle_detail(), test_vehicle_detail()];
+        let fleet = FleetBuilder::default()
+            .add_driver(test_driver())
+            .add_vehicles(vec![v1, test_vehicle_with_id("v2")])
+            .build();
+        let routes = std::iter::once(create_route_ctx(get_actors(&fleet, "v1")[0].clone(), (40., 40.)))
+            .chain(
+                other_route
+                    .map(|(vehicle_id, idx)| create_route_ctx(get_actors(&fleet, vehicle_id)[idx].clone(), (40., 40.))),
+            )
+            .collect();
+        let solution_ctx = TestInsertionContextBuilder::default().with_routes(routes).build().solution;
+        let feature = create_cumulative_travel_limit_feature(
+            "cumulative_limit",
+            TestTransportCost::new_shared(),
+            DISTANCE_CODE,
+            DURATION_CODE,
+            Arc::new(move |_| limit.0),
+            Arc::new(move |_| limit.1),
+        )
+        .unwrap();
+
+        let result = feature.constraint.unwrap().evaluate(&MoveContext::activity(
+            &solution_ctx,
+            &solution_ctx.routes[0],
+            &ActivityContext {
+                index: 0,
+                prev: &ActivityBuilder::with_location(50).build(),
+                target: &ActivityBuilder::with_location(75).build(),
+                next: Some(&ActivityBuilder::with_location(50).build()),
+            },
+        ));
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn can_calculate_driving_time_without_service_and_waiting() {
+        let feature = create_cumulative_travel_limit_feature(
+            "cumulative_limit",
+            TestTransportCost::new_shared(),
+            DISTANCE_CODE,
+            DURATION_CODE,
+            Arc::new(|_| None),
+            Arc::new(|_| None),
+        )
+        .unwrap();
+        let mut route_ctx = RouteContextBuilder::default()
+            .with_route(
+                RouteBuilder::default()
+                    .with_vehicle(&test_fleet(), "v1")
+                    .add_activity(
+                        ActivityBuilder::with_location_tw_and_duration(10, TimeWindow::new(100., 200.), 5.)
+                            .schedule(Schedule::new(100., 105.))
+                            .build(),
+                    )
+                    .add_activity(ActivityBuilder::with_location(20).schedule(Schedule::new(115., 115.)).build())
+                    .build(),
+            )
+            .build();
+
+        feature.state.unwrap().accept_route_state(&mut route_ctx);
+
+        assert_eq!(route_ctx.state().get_total_driving_time().copied(), Some(40.));
+    }
+}
+
 mod notify_failure {
     use super::super::*;
     use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
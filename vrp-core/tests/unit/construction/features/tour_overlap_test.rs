@@ -0,0 +1,86 @@
+use super::*;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::TestSingleBuilder;
+use crate::helpers::models::solution::{ActivityBuilder, RouteBuilder, RouteContextBuilder};
+
+fn to_point(location: Location) -> Option<Point> {
+    Some(Point::new((location % 10) as Float, (location / 10) as Float))
+}
+
+fn create_test_insertion_ctx(feature: &Feature, routes: Vec<Vec<Location>>) -> InsertionContext {
+    let state = feature.state.as_ref().unwrap();
+    let routes = routes
+        .into_iter()
+        .map(|locations| {
+            let mut route_ctx = RouteContextBuilder::default()
+                .with_route(
+                    RouteBuilder::default()
+                        .add_activities(locations.into_iter().map(|l| ActivityBuilder::with_location(l).build()))
+                        .build(),
+                )
+                .build();
+            state.accept_route_state(&mut route_ctx);
+
+            route_ctx
+        })
+        .collect();
+
+    let mut insertion_ctx = TestInsertionContextBuilder::default().with_routes(routes).build();
+    state.accept_solution_state(&mut insertion_ctx.solution);
+
+    insertion_ctx
+}
+
+parameterized_test! {can_calculate_tour_overlap_fitness, (routes, threshold, expected), {
+    can_calculate_tour_overlap_fitness_impl(routes, threshold, expected);
+}}
+
+can_calculate_tour_overlap_fitness! {
+    case_01_partial_overlap: (vec![vec![0, 2, 22, 20], vec![11, 13, 33, 31]], 0., 0.25),
+    case_02_partial_overlap_with_threshold: (vec![vec![0, 2, 22, 20], vec![11, 13, 33, 31]], 0.2, 0.05),
+    case_03_overlap_below_threshold: (vec![vec![0, 2, 22, 20], vec![11, 13, 33, 31]], 0.5, 0.),
+    case_04_full_overlap: (vec![vec![0, 4, 44, 40], vec![11, 13, 33, 31]], 0., 1.),
+    case_05_no_overlap: (vec![vec![0, 2, 22, 20], vec![5, 7, 27, 25]], 0., 0.),
+    case_06_degenerate_hull: (vec![vec![0, 4, 44, 40], vec![11, 33]], 0., 0.),
+    case_07_three_routes: (vec![vec![0, 2, 22, 20], vec![11, 13, 33, 31], vec![1, 3, 23, 21]], 0., 1.25),
+}
+
+fn can_calculate_tour_overlap_fitness_impl(routes: Vec<Vec<Location>>, threshold: Float, expected: Cost) {
+    let feature = create_tour_overlap_feature("overlap", threshold, to_point).unwrap();
+    let insertion_ctx = create_test_insertion_ctx(&feature, routes);
+
+    let fitness = feature.objective.unwrap().fitness(&insertion_ctx);
+
+    assert!((fitness - expected).abs() < 1E-9, "{fitness} != {expected}");
+}
+
+parameterized_test! {can_estimate_job_insertion, (location, route_idx, expected), {
+    can_estimate_job_insertion_impl(location, route_idx, expected);
+}}
+
+can_estimate_job_insertion! {
+    case_01_inside_hull: (11, 0, 0.),
+    case_02_outside_hull_no_overlap: (4, 0, 0.),
+    case_03_outside_hull_with_overlap: (26, 0, 0.0625),
+    case_04_outside_hull_with_overlap: (21, 1, 0.0625),
+}
+
+fn can_estimate_job_insertion_impl(location: Location, route_idx: usize, expected: Cost) {
+    let feature = create_tour_overlap_feature("overlap", 0., to_point).unwrap();
+    let insertion_ctx = create_test_insertion_ctx(&feature, vec![vec![0, 2, 22, 20], vec![5, 7, 27, 25]]);
+    let job = TestSingleBuilder::default().location(Some(location)).build_as_job_ref();
+
+    let result = feature.objective.unwrap().estimate(&MoveContext::route(
+        &insertion_ctx.solution,
+        &insertion_ctx.solution.routes[route_idx],
+        &job,
+    ));
+
+    assert!((result - expected).abs() < 1E-9, "{result} != {expected}");
+}
+
+#[test]
+fn can_return_err_if_threshold_is_invalid() {
+    assert!(create_tour_overlap_feature("overlap", 1., to_point).is_err());
+    assert!(create_tour_overlap_feature("overlap", -0.1, to_point).is_err());
+}
@@ -293,3 +293,118 @@ mod timing {
         assert_eq!(result, ConstraintViolation::fail(VIOLATION_CODE));
     }
 }
+
+mod place_cost {
+    use super::*;
+    use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+    use crate::models::solution::Place;
+
+    fn create_activity(place_idx: usize, costs: Option<Vec<Cost>>) -> Activity {
+        let mut single = TestSingleBuilder::default();
+        if let Some(costs) = costs {
+            single.dimens_mut().set_job_place_costs(costs);
+        }
+
+        ActivityBuilder::default()
+            .place(Place { idx: place_idx, location: 5, duration: 1.0, time: DEFAULT_ACTIVITY_TIME_WINDOW })
+            .job(Some(single.build_shared()))
+            .build()
+    }
+
+    parameterized_test! {can_get_place_cost, (place_idx, costs, expected), {
+        can_get_place_cost_impl(place_idx, costs, expected);
+    }}
+
+    can_get_place_cost! {
+        case01_no_costs: (0, None, 0.),
+        case02_first_place: (0, Some(vec![10., 0.]), 10.),
+        case03_second_place: (1, Some(vec![10., 0.]), 0.),
+        case04_unknown_place: (2, Some(vec![10., 0.]), 0.),
+    }
+
+    fn can_get_place_cost_impl(place_idx: usize, costs: Option<Vec<Cost>>, expected: Cost) {
+        assert_eq!(get_place_cost(&create_activity(place_idx, costs)), expected);
+    }
+
+    parameterized_test! {can_estimate_activity_with_place_cost, (has_place_costs, expected), {
+        can_estimate_activity_with_place_cost_impl(has_place_costs, expected);
+    }}
+
+    can_estimate_activity_with_place_cost! {
+        case01_considered: (true, 31.),
+        case02_ignored: (false, 21.),
+    }
+
+    fn can_estimate_activity_with_place_cost_impl(has_place_costs: bool, expected: Cost) {
+        let fleet = FleetBuilder::default()
+            .add_driver(test_driver_with_costs(empty_costs()))
+            .add_vehicles(vec![TestVehicleBuilder::default().id("v1").build()])
+            .build();
+        let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+        let route_ctx = RouteContextBuilder::default()
+            .with_route(RouteBuilder::default().with_vehicle(&fleet, "v1").build())
+            .build();
+        let target = create_activity(0, Some(vec![10.]));
+        let activity_ctx = ActivityContext {
+            index: 0,
+            prev: route_ctx.route().tour.get(0).unwrap(),
+            target: &target,
+            next: route_ctx.route().tour.get(1),
+        };
+        let feature = TransportFeatureBuilder::new("transport")
+            .set_transport_cost(TestTransportCost::new_shared())
+            .set_activity_cost(TestActivityCost::new_shared())
+            .set_place_costs(has_place_costs)
+            .build_minimize_cost()
+            .unwrap();
+
+        let result =
+            feature.objective.unwrap().estimate(&MoveContext::activity(&solution_ctx, &route_ctx, &activity_ctx));
+
+        assert_eq!(result, expected);
+    }
+}
+
+mod cost_tiers {
+    use super::*;
+
+    type TierData = (Cost, Option<Duration>, Option<Distance>);
+
+    fn create_vehicle(tiers: Option<Vec<TierData>>) -> Vehicle {
+        let mut vehicle = TestVehicleBuilder::default();
+        if let Some(tiers) = tiers {
+            vehicle.dimens_mut().set_vehicle_cost_tiers(
+                tiers
+                    .into_iter()
+                    .map(|(fixed, max_duration, max_distance)| CostTier { fixed, max_duration, max_distance })
+                    .collect(),
+            );
+        }
+
+        Vehicle { costs: Costs { fixed: 100., ..test_costs() }, ..vehicle.build() }
+    }
+
+    parameterized_test! {can_get_vehicle_fixed_cost, (tiers, duration, distance, expected), {
+        can_get_vehicle_fixed_cost_impl(tiers, duration, distance, expected);
+    }}
+
+    can_get_vehicle_fixed_cost! {
+        case01_no_tiers: (None, 10., 10., 100.),
+        case02_within_tier: (Some(vec![(20., Some(100.), None)]), 50., 1000., 20.),
+        case03_exceeds_tier: (Some(vec![(20., Some(100.), None)]), 150., 10., 100.),
+        case04_both_limits: (Some(vec![(20., Some(100.), Some(50.))]), 50., 60., 100.),
+        case05_cheapest_tier: (Some(vec![(50., Some(200.), None), (20., Some(100.), None)]), 50., 10., 20.),
+        case06_next_tier: (Some(vec![(50., Some(200.), None), (20., Some(100.), None)]), 150., 10., 50.),
+    }
+
+    fn can_get_vehicle_fixed_cost_impl(
+        tiers: Option<Vec<TierData>>,
+        duration: Duration,
+        distance: Distance,
+        expected: Cost,
+    ) {
+        let vehicle = create_vehicle(tiers);
+
+        assert_eq!(get_vehicle_fixed_cost(&vehicle, duration, distance), expected);
+    }
+}
@@ -0,0 +1,40 @@
+use super::*;
+use crate::construction::enablers::update_route_schedule;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::TimeWindow;
+
+parameterized_test! {can_calculate_waiting_time_with_flexible_departure, (tws, expected), {
+    let tws = tws.into_iter().map(|(start, end)| TimeWindow::new(start, end)).collect::<Vec<_>>();
+    can_calculate_waiting_time_with_flexible_departure_impl(tws, expected);
+}}
+
+can_calculate_waiting_time_with_flexible_departure! {
+    case01: (vec![(0., 100.), (25., 100.), (0., 100.)], 0.),
+    case02: (vec![(0., 100.), (10., 100.), (42., 100.)], 0.),
+    case03: (vec![(0., 10.), (0., 100.), (50., 100.)], 20.),
+    case04: (vec![(0., 15.), (0., 100.), (50., 100.)], 15.),
+}
+
+fn can_calculate_waiting_time_with_flexible_departure_impl(tws: Vec<TimeWindow>, expected: Float) {
+    let transport = TestTransportCost::new_shared();
+    let mut route_ctx = RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::with_default_vehicle()
+                .add_activities(
+                    tws.into_iter()
+                        .zip([10, 20, 30])
+                        .map(|(tw, location)| ActivityBuilder::with_location_and_tw(location, tw).build()),
+                )
+                .build(),
+        )
+        .build();
+    update_route_schedule(&mut route_ctx, &TestActivityCost::default(), transport.as_ref());
+    let insertion_ctx = TestInsertionContextBuilder::default().with_routes(vec![route_ctx]).build();
+    let objective = create_minimize_waiting_time_feature("waiting_time", transport).unwrap().objective.unwrap();
+
+    let result = objective.fitness(&insertion_ctx);
+
+    assert_eq!(result, expected);
+}
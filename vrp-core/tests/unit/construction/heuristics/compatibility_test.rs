@@ -0,0 +1,91 @@
+use super::*;
+use crate::construction::heuristics::MoveContext;
+use crate::helpers::models::problem::*;
+use crate::models::ConstraintViolation;
+use crate::models::problem::{JobIdDimension, VehicleIdDimension};
+
+struct TestCompatibilityConstraint {
+    rules: Vec<(&'static str, &'static str, ViolationCode)>,
+}
+
+impl FeatureConstraint for TestCompatibilityConstraint {
+    fn evaluate(&self, _: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        None
+    }
+
+    fn check_compatibility(&self, actor: &Actor, job: &Job) -> Option<ViolationCode> {
+        let vehicle_id = actor.vehicle.dimens.get_vehicle_id()?;
+        let job_id = job.dimens().get_job_id()?;
+
+        self.rules.iter().find(|(v_id, j_id, _)| v_id == vehicle_id && j_id == job_id).map(|(_, _, code)| *code)
+    }
+}
+
+fn create_test_data() -> (Fleet, Vec<Job>) {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![
+            test_vehicle_with_id("v1"),
+            test_vehicle_with_id("v2"),
+            test_vehicle_with_id("v3"),
+            test_vehicle_with_id("v4"),
+        ])
+        .build();
+    let jobs = vec![
+        TestSingleBuilder::default().id("job1").build_as_job_ref(),
+        TestSingleBuilder::default().id("job2").build_as_job_ref(),
+    ];
+
+    (fleet, jobs)
+}
+
+fn create_test_constraints() -> Vec<Arc<dyn FeatureConstraint>> {
+    vec![
+        Arc::new(TestCompatibilityConstraint { rules: vec![("v2", "job1", ViolationCode(2))] }),
+        Arc::new(TestCompatibilityConstraint {
+            rules: vec![("v2", "job1", ViolationCode(3)), ("v3", "job2", ViolationCode(3))],
+        }),
+    ]
+}
+
+parameterized_test! {can_get_violation, (vehicle_id, job_idx, expected), {
+    can_get_violation_impl(vehicle_id, job_idx, expected);
+}}
+
+can_get_violation! {
+    case01_compatible: ("v1", 0, None),
+    case02_first_constraint: ("v2", 0, Some(ViolationCode(2))),
+    case03_compatible_other_job: ("v2", 1, None),
+    case04_second_constraint: ("v3", 1, Some(ViolationCode(3))),
+    case05_compatible_same_type: ("v4", 1, None),
+}
+
+fn can_get_violation_impl(vehicle_id: &str, job_idx: usize, expected: Option<ViolationCode>) {
+    let (fleet, jobs) = create_test_data();
+    let actor = get_test_actor_from_fleet(&fleet, vehicle_id);
+
+    let index = JobCompatibilityIndex::new(&create_test_constraints(), &fleet, &jobs);
+
+    assert_eq!(index.get_violation(&actor, &jobs[job_idx]), expected);
+}
+
+#[test]
+fn can_share_storage_between_actors_of_same_type() {
+    let (fleet, jobs) = create_test_data();
+
+    let index = JobCompatibilityIndex::new(&create_test_constraints(), &fleet, &jobs);
+
+    assert_eq!(index.types.len(), 3);
+    assert_eq!(index.codes, vec![ViolationCode(2), ViolationCode(3)]);
+}
+
+#[test]
+fn can_handle_unknown_job() {
+    let (fleet, jobs) = create_test_data();
+    let actor = get_test_actor_from_fleet(&fleet, "v2");
+    let unknown_job = TestSingleBuilder::default().id("job1").build_as_job_ref();
+
+    let index = JobCompatibilityIndex::new(&create_test_constraints(), &fleet, &jobs);
+
+    assert_eq!(index.get_violation(&actor, &unknown_job), None);
+}
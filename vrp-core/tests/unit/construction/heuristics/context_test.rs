@@ -1,8 +1,9 @@
-use crate::construction::heuristics::{RouteState, UnassignmentInfo};
+use crate::construction::heuristics::{InsertionContext, RouteState, UnassignmentInfo};
 use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
 use crate::helpers::models::domain::TestGoalContextBuilder;
 use crate::helpers::models::problem::{TestSingleBuilder, test_fleet};
 use crate::helpers::models::solution::*;
+use rosomaxa::prelude::HeuristicSolution;
 
 #[test]
 fn can_set_and_get_activity_states_with_different_type_keys() {
@@ -56,6 +57,29 @@ fn can_use_stale_flag() {
     assert!(route_ctx.is_stale());
 }
 
+#[test]
+fn can_share_route_between_copies_until_modified() {
+    let mut route = RouteBuilder::default();
+    route.with_vehicle(&test_fleet(), "v1").add_activity(ActivityBuilder::default().build());
+    let original = RouteContextBuilder::default().with_route(route.build()).build();
+    let mut copy = original.deep_copy();
+
+    assert!(std::ptr::eq(original.route(), copy.route()));
+    assert!(std::ptr::eq(original.state(), copy.state()));
+
+    copy.route_mut().tour.remove_activity_at(1);
+
+    assert!(!std::ptr::eq(original.route(), copy.route()));
+    assert!(std::ptr::eq(original.state(), copy.state()));
+    assert_eq!(original.route().tour.total(), 3);
+    assert_eq!(copy.route().tour.total(), 2);
+
+    copy.state_mut().set_tour_state::<i32, _>(1);
+
+    assert!(!std::ptr::eq(original.state(), copy.state()));
+    assert!(original.state().get_tour_state::<i32, i32>().is_none());
+}
+
 #[test]
 fn can_use_debug_fmt_for_insertion_ctx() {
     let insertion_ctx = TestInsertionContextBuilder::default()
@@ -85,3 +109,26 @@ fn can_use_debug_fmt_for_insertion_ctx() {
     assert!(result.contains("unassigned"));
     assert!(result.contains("id: \"single\""));
 }
+
+#[test]
+fn can_estimate_memory_usage_of_insertion_ctx() {
+    let create_insertion_ctx = |activities: usize| {
+        let mut builder = RouteBuilder::default();
+        builder.with_vehicle(&test_fleet(), "v1");
+        (0..activities).for_each(|_| {
+            builder.add_activity(ActivityBuilder::default().build());
+        });
+        let route = builder.build();
+
+        TestInsertionContextBuilder::default()
+            .with_goal(TestGoalContextBuilder::with_transport_feature().build())
+            .with_routes(vec![RouteContextBuilder::default().with_route(route).build()])
+            .build()
+    };
+
+    let small = create_insertion_ctx(1);
+    let large = create_insertion_ctx(10);
+
+    assert!(small.memory_usage() > size_of::<InsertionContext>());
+    assert!(large.memory_usage() > small.memory_usage());
+}
@@ -112,3 +112,45 @@ mod selections {
         assert!(counter < expected_threshold);
     }
 }
+
+mod evaluators {
+    use super::*;
+    use rosomaxa::utils::DefaultRandom;
+
+    parameterized_test! {can_evaluate_routes_in_parallel, is_repeatable, {
+        can_evaluate_routes_in_parallel_impl(is_repeatable);
+    }}
+
+    can_evaluate_routes_in_parallel! {
+        case_01_default_random: false,
+        case_02_repeatable_random: true,
+    }
+
+    fn can_evaluate_routes_in_parallel_impl(is_repeatable: bool) {
+        let random: Arc<dyn Random> =
+            if is_repeatable { Arc::new(DefaultRandom::new_repeatable()) } else { Arc::new(DefaultRandom::default()) };
+        let environment = Arc::new(Environment { random, ..Environment::default() });
+        let (problem, solution) = generate_matrix_routes_with_defaults(4, 3, false);
+        let mut insertion_ctx = InsertionContext::new_from_solution(Arc::new(problem), (solution, None), environment);
+        let job = insertion_ctx.solution.routes[1].route().tour.jobs().nth(1).cloned().unwrap();
+        insertion_ctx.solution.routes[1].route_mut().tour.remove(&job);
+        insertion_ctx.solution.required.push(job.clone());
+        insertion_ctx.restore();
+        let routes = insertion_ctx.solution.routes.iter().collect::<Vec<_>>();
+        let evaluate = |evaluator: PositionInsertionEvaluator| match evaluator.evaluate_all(
+            &insertion_ctx,
+            &[&job],
+            routes.as_slice(),
+            &LegSelection::Exhaustive,
+            &BestResultSelector::default(),
+        ) {
+            InsertionResult::Success(success) => (success.actor.clone(), success.cost),
+            InsertionResult::Failure(_) => unreachable!(),
+        };
+
+        let expected = evaluate(PositionInsertionEvaluator::default());
+        let actual = evaluate(PositionInsertionEvaluator::new_route_parallel(InsertionPosition::Any));
+
+        assert_eq!(actual, expected);
+    }
+}
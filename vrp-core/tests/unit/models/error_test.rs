@@ -0,0 +1,49 @@
+use super::*;
+use crate::helpers::models::problem::{TestSingleBuilder, test_vehicle_with_id};
+use crate::models::ProblemBuilder;
+use crate::models::problem::{Job, MatrixData, MatrixPrecision};
+
+fn create_builder() -> ProblemBuilder {
+    ProblemBuilder::default()
+        .add_job(Job::Single(TestSingleBuilder::default().id("job1").build_shared()))
+        .add_vehicle(test_vehicle_with_id("v1"))
+}
+
+#[test]
+fn can_return_typed_problem_build_errors() {
+    let get_error = |builder: ProblemBuilder| builder.build().err().unwrap();
+
+    assert_eq!(get_error(ProblemBuilder::default()), ProblemBuildError::NoJobs);
+    assert_eq!(
+        get_error(ProblemBuilder::default().add_job(Job::Single(TestSingleBuilder::default().build_shared()))),
+        ProblemBuildError::NoVehicles
+    );
+    assert_eq!(get_error(create_builder()), ProblemBuildError::NoRoutingData);
+    assert_eq!(
+        get_error(create_builder().with_transport_matrices(vec![], MatrixPrecision::default())),
+        ProblemBuildError::InvalidRoutingData("no matrix data found".into())
+    );
+    assert_eq!(
+        get_error(
+            create_builder().with_transport_matrices(
+                vec![MatrixData::new(0, None, vec![0.], vec![0.])],
+                MatrixPrecision::default()
+            )
+        ),
+        ProblemBuildError::NoGoal
+    );
+}
+
+#[test]
+fn can_preserve_display_text_and_source_of_problem_build_error() {
+    let error = ProblemBuildError::InvalidRoutingData("no matrix data found".into());
+
+    assert_eq!(error.code(), "E2003");
+    assert_eq!(error.to_string(), "no matrix data found");
+    assert_eq!(error.source().map(|err| err.to_string()), Some("no matrix data found".to_string()));
+    assert_eq!(
+        GenericError::from(ProblemBuildError::NoVehicles).to_string(),
+        ProblemBuildError::NoVehicles.to_string()
+    );
+    assert!(ProblemBuildError::NoJobs.source().is_none());
+}
@@ -193,6 +193,62 @@ fn can_use_objective_total_order_impl(left_fitness: Vec<Float>, right_fitness: V
     assert_eq!(goal_ctx.total_order(&left, &right), expected);
 }
 
+parameterized_test! {can_use_weighted_layer_total_order, (weights, left_fitness, right_fitness, expected), {
+    can_use_weighted_layer_total_order_impl(weights, left_fitness, right_fitness, expected);
+}}
+
+can_use_weighted_layer_total_order! {
+    case01_equal_weights_great: (vec![1., 1.], vec![3., 5.], vec![5., 2.], Ordering::Greater),
+    case02_small_second_weight: (vec![1., 0.1], vec![3., 5.], vec![5., 2.], Ordering::Less),
+    case03_equal_weighted_sum: (vec![1., 1.], vec![3., 4.], vec![4., 3.], Ordering::Equal),
+    case04_zero_first_weight: (vec![0., 1.], vec![9., 4.], vec![1., 5.], Ordering::Less),
+}
+
+fn can_use_weighted_layer_total_order_impl(
+    weights: Vec<Float>,
+    left_fitness: Vec<Float>,
+    right_fitness: Vec<Float>,
+    expected: Ordering,
+) {
+    let fitness_fn = Arc::new(move |name: &str, insertion_ctx: &InsertionContext| {
+        let idx = name.parse::<usize>().unwrap();
+        insertion_ctx.solution.state.get_value::<(), Vec<Float>>().unwrap()[idx]
+    });
+    let create_insertion_ctx_with_fitness_state = |fitness: Vec<Float>| {
+        let mut insertion_ctx = TestInsertionContextBuilder::default().build();
+        insertion_ctx.solution.state.set_value::<(), _>(fitness);
+        insertion_ctx
+    };
+    let objectives = ["0", "1"]
+        .iter()
+        .map(|name| create_objective_feature_with_dynamic_cost(name, fitness_fn.clone()).objective.unwrap())
+        .collect::<Vec<_>>();
+    let goal = GoalBuilder::default().add_weighted(&objectives, &weights).unwrap().build().unwrap();
+    let left = create_insertion_ctx_with_fitness_state(left_fitness);
+    let right = create_insertion_ctx_with_fitness_state(right_fitness);
+
+    assert_eq!(goal.total_order(&left, &right), expected);
+}
+
+parameterized_test! {can_detect_invalid_weighted_layer, (weights, expected_error), {
+    can_detect_invalid_weighted_layer_impl(weights, expected_error);
+}}
+
+can_detect_invalid_weighted_layer! {
+    case01_wrong_size: (vec![1.], "same non-zero amount of weights"),
+    case02_negative_weight: (vec![1., -1.], "finite and non-negative"),
+    case03_infinite_weight: (vec![1., Float::INFINITY], "finite and non-negative"),
+}
+
+fn can_detect_invalid_weighted_layer_impl(weights: Vec<Float>, expected_error: &str) {
+    let objectives =
+        ["0", "1"].iter().map(|name| create_feature(name, 1., None).objective.unwrap()).collect::<Vec<_>>();
+
+    let result = GoalBuilder::default().add_weighted(&objectives, &weights);
+
+    assert!(result.err().is_some_and(|err| err.to_string().contains(expected_error)));
+}
+
 #[test]
 fn can_detect_same_name_usage() {
     let goal_ctx = GoalContextBuilder::with_features(&[
@@ -213,3 +269,130 @@ fn can_detect_same_name_usage() {
         }
     }
 }
+
+fn create_hard_feature(name: &str, state: Option<Arc<dyn FeatureState>>) -> Feature {
+    struct TestFeatureConstraint;
+
+    impl FeatureConstraint for TestFeatureConstraint {
+        fn evaluate(&self, _: &MoveContext<'_>) -> Option<ConstraintViolation> {
+            None
+        }
+
+        fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+            Ok(source)
+        }
+    }
+
+    Feature { name: name.to_string(), constraint: Some(Arc::new(TestFeatureConstraint)), objective: None, state }
+}
+
+fn create_test_state() -> Arc<dyn FeatureState> {
+    struct TestFeatureState;
+
+    impl FeatureState for TestFeatureState {
+        fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
+
+        fn accept_route_state(&self, _: &mut RouteContext) {}
+
+        fn accept_solution_state(&self, _: &mut SolutionContext) {}
+    }
+
+    Arc::new(TestFeatureState)
+}
+
+parameterized_test! {can_use_declarative_goal_builder_with_soft_objective, (left_fitness, right_fitness, expected), {
+    can_use_declarative_goal_builder_with_soft_objective_impl(left_fitness, right_fitness, expected);
+}}
+
+can_use_declarative_goal_builder_with_soft_objective! {
+    case01_sum_less: (vec![1., 1.], vec![0., 3.], Ordering::Less),
+    case02_sum_greater: (vec![3., 0.], vec![1., 1.], Ordering::Greater),
+    case03_sum_equal: (vec![2., 1.], vec![1., 2.], Ordering::Equal),
+}
+
+fn can_use_declarative_goal_builder_with_soft_objective_impl(
+    left_fitness: Vec<Float>,
+    right_fitness: Vec<Float>,
+    expected: Ordering,
+) {
+    let fitness_fn: FitnessFn = Arc::new(move |name: &str, insertion_ctx: &InsertionContext| {
+        let idx = name.parse::<usize>().unwrap();
+        insertion_ctx.solution.state.get_value::<(), Vec<Float>>().unwrap()[idx]
+    });
+    let create_insertion_ctx_with_fitness_state = |fitness: Vec<Float>| {
+        let mut insertion_ctx = TestInsertionContextBuilder::default().build();
+        insertion_ctx.solution.state.set_value::<(), _>(fitness);
+        insertion_ctx
+    };
+    let goal_ctx = DeclarativeGoalBuilder::minimize_unassigned()
+        .then(create_objective_feature_with_dynamic_cost("0", fitness_fn.clone()))
+        .with_soft(create_objective_feature_with_dynamic_cost("1", fitness_fn))
+        .with_hard(create_hard_feature("hard", Some(create_test_state())))
+        .build()
+        .expect("cannot build goal context");
+    let left = create_insertion_ctx_with_fitness_state(left_fitness.clone());
+    let right = create_insertion_ctx_with_fitness_state(right_fitness);
+
+    assert_eq!(goal_ctx.total_order(&left, &right), expected);
+    assert_eq!(goal_ctx.fitness(&left).collect::<Vec<_>>(), once(0.).chain(left_fitness).collect::<Vec<_>>());
+    assert_eq!(goal_ctx.constraints().count(), 1);
+}
+
+#[test]
+fn can_use_declarative_goal_builder_in_lexicographical_order() {
+    let goal_ctx = DeclarativeGoalBuilder::minimize(create_feature("first", 2., None))
+        .then(create_feature("second", 1., None))
+        .then_minimize_tours()
+        .build()
+        .expect("cannot build goal context");
+    let insertion_ctx = TestInsertionContextBuilder::default().build();
+
+    assert_eq!(goal_ctx.fitness(&insertion_ctx).collect::<Vec<_>>(), vec![2., 1., 0.]);
+}
+
+parameterized_test! {can_detect_invalid_declarative_goal, (create_builder, expected_error), {
+    can_detect_invalid_declarative_goal_impl(create_builder, expected_error);
+}}
+
+can_detect_invalid_declarative_goal! {
+    case01_empty: (|| DeclarativeGoalBuilder::default(), "no objectives specified in the goal"),
+    case02_soft_first: (
+        || DeclarativeGoalBuilder::default().with_soft(create_feature("soft", 1., None)),
+        "soft constraint 'soft' should follow an objective"
+    ),
+    case03_no_objective: (
+        || DeclarativeGoalBuilder::minimize_tours().then(create_hard_feature("hard", None)),
+        "feature 'hard' has no objective"
+    ),
+    case04_hard_with_objective: (
+        || DeclarativeGoalBuilder::minimize_tours().with_hard(create_feature("soft", 1., None)),
+        "feature 'soft' has objective, use it as objective or soft constraint"
+    ),
+    case05_same_name: (
+        || DeclarativeGoalBuilder::minimize_tours().with_soft(create_feature("min_tours", 1., None)),
+        "some of the features are defined more than once, check ids list: min_tours,min_tours"
+    ),
+    case06_same_state: (
+        || {
+            let state = create_test_state();
+            DeclarativeGoalBuilder::minimize_tours()
+                .with_hard(create_hard_feature("hard_1", Some(state.clone())))
+                .with_hard(create_hard_feature("hard_2", Some(state)))
+        },
+        "features 'hard_1' and 'hard_2' have the same state type"
+    ),
+    case07_same_state_type: (
+        || {
+            DeclarativeGoalBuilder::minimize_tours()
+                .with_hard(create_hard_feature("hard_1", Some(create_test_state())))
+                .with_hard(create_hard_feature("hard_2", Some(create_test_state())))
+        },
+        "features 'hard_1' and 'hard_2' have the same state type"
+    ),
+}
+
+fn can_detect_invalid_declarative_goal_impl(create_builder: fn() -> DeclarativeGoalBuilder, expected_error: &str) {
+    let result = create_builder().build();
+
+    assert_eq!(result.err(), Some(GenericError::from(expected_error)));
+}
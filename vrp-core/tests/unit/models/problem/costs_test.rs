@@ -15,6 +15,10 @@ fn create_matrix_data(
     }
 }
 
+fn create_storages(data: Vec<MatrixData>) -> Vec<MatrixStorage> {
+    data.into_iter().map(|data| MatrixStorage::new(data, MatrixPrecision::default())).collect()
+}
+
 #[test]
 fn can_detect_dimensions_mismatch() {
     assert_eq!(
@@ -34,7 +38,7 @@ fn can_return_error_when_mixing_timestamps() {
 
     assert_eq!(
         TimeAwareMatrixTransportCost::new(
-            vec![create_matrix_data(Profile::default(), None, (0., 1), (0., 1))],
+            create_storages(vec![create_matrix_data(Profile::default(), None, (0., 1), (0., 1))]),
             1,
             NoFallback
         )
@@ -44,10 +48,10 @@ fn can_return_error_when_mixing_timestamps() {
 
     assert_eq!(
         TimeAwareMatrixTransportCost::new(
-            vec![
+            create_storages(vec![
                 create_matrix_data(p0.clone(), Some(0.), (0., 1), (0., 1)),
                 create_matrix_data(p0.clone(), None, (0., 1), (0., 1))
-            ],
+            ]),
             1,
             NoFallback
         )
@@ -57,7 +61,7 @@ fn can_return_error_when_mixing_timestamps() {
 
     assert_eq!(
         TimeAwareMatrixTransportCost::new(
-            vec![create_matrix_data(p0.clone(), Some(0.), (0., 1), (0., 1))],
+            create_storages(vec![create_matrix_data(p0.clone(), Some(0.), (0., 1), (0., 1))]),
             1,
             NoFallback
         )
@@ -67,11 +71,11 @@ fn can_return_error_when_mixing_timestamps() {
 
     assert_eq!(
         TimeAwareMatrixTransportCost::new(
-            vec![
+            create_storages(vec![
                 create_matrix_data(p0.clone(), Some(0.), (1., 1), (1., 1)), //
                 create_matrix_data(p0, Some(1.), (1., 1), (1., 1)),         //
                 create_matrix_data(p1, Some(0.), (1., 1), (1., 1)),         //
-            ],
+            ]),
             1,
             NoFallback
         )
@@ -88,12 +92,12 @@ fn can_interpolate_durations() {
     let p1 = route1.actor.vehicle.profile.clone();
 
     let costs = TimeAwareMatrixTransportCost::new(
-        vec![
+        create_storages(vec![
             create_matrix_data(p0.clone(), Some(0.), (100., 2), (1., 2)),
             create_matrix_data(p0.clone(), Some(10.), (200., 2), (1., 2)),
             create_matrix_data(p1.clone(), Some(0.), (300., 2), (5., 2)),
             create_matrix_data(p1.clone(), Some(10.), (400., 2), (5., 2)),
-        ],
+        ]),
         2,
         NoFallback,
     )
@@ -114,6 +118,253 @@ fn can_interpolate_durations() {
     assert_eq!(costs.distance_approx(&p1, 0, 1), 5.);
 }
 
+parameterized_test! {can_interpolate_durations_by_travel_time, (travel_time, expected), {
+    can_interpolate_durations_by_travel_time_impl(travel_time, expected);
+}}
+
+can_interpolate_durations_by_travel_time! {
+    case01_departure_fractional: (TravelTime::Departure(2.5), 102.5),
+    case02_departure_before_first: (TravelTime::Departure(-10.), 100.),
+    case03_departure_after_last: (TravelTime::Departure(1000.), 300.),
+    case04_arrival_between: (TravelTime::Arrival(150.), 125.),
+    case05_arrival_before_first: (TravelTime::Arrival(50.), 100.),
+    case06_arrival_second_range: (TravelTime::Arrival(1000.), 300.),
+}
+
+fn can_interpolate_durations_by_travel_time_impl(travel_time: TravelTime, expected: Duration) {
+    let route = Route { actor: test_actor_with_profile(0), tour: Default::default() };
+    let profile = route.actor.vehicle.profile.clone();
+    let costs = TimeAwareMatrixTransportCost::new(
+        create_storages(vec![
+            create_matrix_data(profile.clone(), Some(100.), (200., 4), (1., 4)),
+            create_matrix_data(profile.clone(), Some(0.), (100., 4), (1., 4)),
+            create_matrix_data(profile, Some(200.), (300., 4), (1., 4)),
+        ]),
+        2,
+        NoFallback,
+    )
+    .unwrap();
+
+    let result = costs.duration(&route, 0, 1, travel_time);
+
+    assert!((result - expected).abs() < 1E-6, "expected {expected}, got {result}");
+}
+
+#[test]
+fn can_use_mean_duration_as_approximation_for_time_aware_matrices() {
+    let profile = Profile::default();
+    let costs = TimeAwareMatrixTransportCost::new(
+        create_storages(vec![
+            create_matrix_data(profile.clone(), Some(0.), (100., 4), (1., 4)),
+            create_matrix_data(profile.clone(), Some(10.), (300., 4), (1., 4)),
+        ]),
+        2,
+        NoFallback,
+    )
+    .unwrap();
+
+    assert_eq!(costs.duration_approx(&profile, 0, 1), 200.);
+    assert_eq!(costs.distance_approx(&profile, 0, 1), 1.);
+}
+
+#[test]
+fn can_keep_unreachable_marker_when_interpolating() {
+    let route = Route { actor: test_actor_with_profile(0), tour: Default::default() };
+    let profile = route.actor.vehicle.profile.clone();
+    let costs = TimeAwareMatrixTransportCost::new(
+        create_storages(vec![
+            create_matrix_data(profile.clone(), Some(0.), (100., 4), (1., 4)),
+            create_matrix_data(profile.clone(), Some(10.), (-1., 4), (-1., 4)),
+        ]),
+        2,
+        NoFallback,
+    )
+    .unwrap();
+
+    assert_eq!(costs.duration(&route, 0, 1, TravelTime::Departure(5.)), -1.);
+    assert_eq!(costs.duration_approx(&profile, 0, 1), -1.);
+}
+
+#[test]
+fn can_return_error_when_timestamps_are_duplicated() {
+    let profile = Profile::default();
+
+    let result = TimeAwareMatrixTransportCost::new(
+        create_storages(vec![
+            create_matrix_data(profile.clone(), Some(0.), (100., 1), (1., 1)),
+            create_matrix_data(profile, Some(0.), (300., 1), (1., 1)),
+        ]),
+        1,
+        NoFallback,
+    );
+
+    assert_eq!(result.err(), Some("time-aware routing requires unique timestamps per profile".into()));
+}
+
+#[test]
+fn can_share_identical_matrices() {
+    let (p0, p1, p2) = (Profile::new(0, None), Profile::new(1, None), Profile::new(2, None));
+
+    let costs = create_matrix_transport_cost(vec![
+        create_matrix_data(p0.clone(), None, (1., 4), (2., 4)),
+        create_matrix_data(p1.clone(), None, (3., 4), (2., 4)),
+        create_matrix_data(p2.clone(), None, (1., 4), (2., 4)),
+    ])
+    .unwrap();
+
+    assert_eq!(costs.matrix_key(&p0), Some(0));
+    assert_eq!(costs.matrix_key(&p1), Some(1));
+    assert_eq!(costs.matrix_key(&p2), Some(0));
+    assert_eq!(costs.duration_approx(&p1, 0, 1), 3.);
+    assert_eq!(costs.duration_approx(&p2, 0, 1), 1.);
+    assert_eq!(costs.distance_approx(&p2, 0, 1), 2.);
+}
+
+parameterized_test! {can_use_matrix_precision, (precision, timestamps, expected), {
+    can_use_matrix_precision_impl(precision, timestamps, expected);
+}}
+
+can_use_matrix_precision! {
+    case01_double_agnostic: (MatrixPrecision::Double, (None, None), (0.1, 1e9 + 1.)),
+    case02_single_agnostic: (MatrixPrecision::Single, (None, None), (0.1_f32 as Float, 1e9)),
+    case03_double_aware: (MatrixPrecision::Double, (Some(0.), Some(10.)), (0.1, 1e9 + 1.)),
+    case04_single_aware: (MatrixPrecision::Single, (Some(0.), Some(10.)), (0.1_f32 as Float, 1e9)),
+}
+
+fn can_use_matrix_precision_impl(
+    precision: MatrixPrecision,
+    timestamps: (Option<Timestamp>, Option<Timestamp>),
+    expected: (Duration, Distance),
+) {
+    let profile = Profile::new(0, None);
+    let matrices = vec![
+        create_matrix_data(profile.clone(), timestamps.0, (0.1, 4), (1e9 + 1., 4)),
+        create_matrix_data(profile.clone(), timestamps.1, (0.1, 4), (1e9 + 1., 4)),
+    ];
+    let matrices = if timestamps.0.is_some() { matrices } else { matrices.into_iter().take(1).collect() };
+
+    let costs = create_matrix_transport_cost_with_precision(matrices, NoFallback, precision).unwrap();
+
+    assert_eq!(costs.duration_approx(&profile, 0, 1), expected.0);
+    assert_eq!(costs.distance_approx(&profile, 0, 1), expected.1);
+}
+
+#[test]
+fn can_use_shared_matrix_data() {
+    let (p0, p1) = (Profile::new(0, None), Profile::new(1, Some(2.)));
+    let durations: Arc<dyn MatrixValuesSource> = Arc::new(vec![0., 1.5, 2.5, 0.]);
+    let distances: Arc<dyn MatrixValuesSource> = Arc::new(vec![0., 10., 20., 0.]);
+
+    let costs = create_shared_matrix_transport_cost(
+        vec![
+            SharedMatrixData::new(0, None, durations.clone(), distances.clone()),
+            SharedMatrixData::new(1, None, durations, distances),
+        ],
+        NoFallback,
+    )
+    .unwrap();
+
+    assert_eq!(costs.size(), 2);
+    assert_eq!(costs.matrix_key(&p0), Some(0));
+    assert_eq!(costs.matrix_key(&p1), Some(0));
+    assert_eq!(costs.duration_approx(&p0, 0, 1), 1.5);
+    assert_eq!(costs.duration_approx(&p1, 1, 0), 5.);
+    assert_eq!(costs.distance_approx(&p1, 1, 0), 20.);
+}
+
+#[test]
+fn can_detect_shared_matrix_dimensions_mismatch() {
+    let result = create_shared_matrix_transport_cost(
+        vec![SharedMatrixData::new(0, None, Arc::new(vec![0.; 4]), Arc::new(vec![0.; 9]))],
+        NoFallback,
+    );
+
+    assert_eq!(result.err(), Some("distance and duration collections have different length".into()));
+}
+
+#[test]
+fn can_detect_invalid_sparse_matrix_data() {
+    let fallback = || CoordinateFallback::new(vec![(0., 0.), (1., 0.)], DistanceFormula::Euclidean, 1.).unwrap();
+
+    let get_error = |costs: Vec<SparseMatrixData>, max_neighbours: usize| {
+        SparseMatrixTransportCost::new(costs, 2, max_neighbours, fallback()).err()
+    };
+
+    assert_eq!(get_error(vec![], 1), Some("no matrix data found".into()));
+    assert_eq!(
+        get_error(vec![SparseMatrixData::new(0, vec![])], 0),
+        Some("max neighbours should be greater than zero".into())
+    );
+    assert_eq!(
+        get_error(vec![SparseMatrixData::new(1, vec![])], 1),
+        Some("duplicate or missing profiles in sparse matrix data".into())
+    );
+    assert_eq!(
+        get_error(vec![SparseMatrixData::new(0, vec![(0, 2, 1., 1.)])], 1),
+        Some("sparse matrix entry location is out of range".into())
+    );
+    assert_eq!(
+        SparseMatrixTransportCost::new(vec![SparseMatrixData::new(0, vec![])], 3, 1, fallback()).err(),
+        Some("fallback coordinates are specified for 2 locations, but 3 are required".into())
+    );
+    assert!(CoordinateFallback::new(vec![], DistanceFormula::Euclidean, 0.).is_err());
+}
+
+#[test]
+fn can_use_sparse_matrix_with_fallback() {
+    let profile = Profile::default();
+    let coordinates = vec![(0., 0.), (3., 4.), (6., 8.), (30., 40.)];
+    let fallback = CoordinateFallback::new(coordinates, DistanceFormula::Euclidean, 2.).unwrap();
+    let entries = vec![(0, 1, 10., 7.), (0, 2, 20., 12.), (0, 3, 100., 70.), (1, 0, 10., 7.)];
+
+    let costs = SparseMatrixTransportCost::new(vec![SparseMatrixData::new(0, entries)], 4, 2, fallback).unwrap();
+
+    assert_eq!(costs.sparse_neighbours(&profile, 0), Some(vec![1, 2]));
+    assert_eq!(costs.sparse_neighbours(&profile, 2), Some(vec![]));
+    assert_eq!((costs.duration_approx(&profile, 0, 1), costs.distance_approx(&profile, 0, 1)), (10., 7.));
+    assert_eq!((costs.duration_approx(&profile, 0, 2), costs.distance_approx(&profile, 0, 2)), (20., 12.));
+    // entry is truncated as it is not among nearest neighbours, so fallback is used
+    assert_eq!((costs.duration_approx(&profile, 0, 3), costs.distance_approx(&profile, 0, 3)), (25., 50.));
+    assert_eq!((costs.duration_approx(&profile, 2, 1), costs.distance_approx(&profile, 2, 1)), (2.5, 5.));
+}
+
+#[test]
+fn can_estimate_haversine_distance() {
+    let profile = Profile::default();
+    let fallback =
+        CoordinateFallback::new(vec![(52.52, 13.405), (48.8566, 2.3522)], DistanceFormula::Haversine, 10.).unwrap();
+
+    let distance = fallback.distance(&profile, 0, 1);
+
+    assert!((distance - 877_460.).abs() < 1000., "unexpected distance: {distance}");
+    assert_eq!(fallback.duration(&profile, 0, 1), distance / 10.);
+    assert_eq!(fallback.distance(&profile, 1, 1), 0.);
+}
+
+#[test]
+fn can_use_geo_transport_cost() {
+    let (p0, p1) = (Profile::new(0, None), Profile::new(1, Some(2.)));
+    let coordinates = vec![(0., 0.), (3., 4.)];
+    let fallback = CoordinateFallback::new(vec![], DistanceFormula::Euclidean, 1.).unwrap();
+
+    let costs = GeoTransportCost::new(coordinates, DistanceFormula::Euclidean, vec![5., 10.], fallback).unwrap();
+
+    assert_eq!(costs.size(), 2);
+    assert_eq!((costs.duration_approx(&p0, 0, 1), costs.distance_approx(&p0, 0, 1)), (1., 5.));
+    assert_eq!((costs.duration_approx(&p1, 1, 0), costs.distance_approx(&p1, 1, 0)), (1., 5.));
+    assert_eq!((costs.duration_approx(&p1, 1, 1), costs.distance_approx(&p1, 1, 1)), (0., 0.));
+}
+
+#[test]
+fn can_detect_invalid_geo_transport_cost_speeds() {
+    let get_error =
+        |speeds: Vec<Float>| GeoTransportCost::new(vec![], DistanceFormula::Haversine, speeds, NoFallback).err();
+
+    assert_eq!(get_error(vec![]), Some("no profile speed found".into()));
+    assert_eq!(get_error(vec![10., 0.]), Some("speed should be positive".into()));
+    assert_eq!(get_error(vec![10.]), None);
+}
+
 mod objective {
     use super::*;
     use crate::construction::heuristics::{InsertionContext, MoveContext};
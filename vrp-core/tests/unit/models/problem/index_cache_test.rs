@@ -0,0 +1,252 @@
+use super::*;
+use crate::helpers::models::domain::test_logger;
+use crate::helpers::models::problem::*;
+use crate::models::common::Profile;
+use crate::models::problem::{Jobs, MatrixData, create_matrix_transport_cost};
+use std::sync::{Arc, Mutex};
+
+#[derive(Default)]
+struct MemoryJobIndexCache {
+    data: Mutex<HashMap<u64, Vec<u8>>>,
+    stores: Mutex<usize>,
+}
+
+impl JobIndexCache for MemoryJobIndexCache {
+    fn load(&self, fingerprint: u64) -> Option<Vec<u8>> {
+        self.data.lock().unwrap().get(&fingerprint).cloned()
+    }
+
+    fn store(&self, fingerprint: u64, data: &[u8]) -> GenericResult<()> {
+        *self.stores.lock().unwrap() += 1;
+        self.data.lock().unwrap().insert(fingerprint, data.to_vec());
+
+        Ok(())
+    }
+}
+
+fn create_transport(scale: Float) -> Arc<dyn TransportCost> {
+    let size = 10;
+    let data = (0..size).flat_map(|from| (0..size).map(move |to| fake_routing(from, to) * scale)).collect::<Vec<_>>();
+
+    create_matrix_transport_cost(vec![MatrixData::new(0, None, data.clone(), data)]).unwrap()
+}
+
+fn create_jobs(locations: &[usize]) -> Vec<Job> {
+    locations
+        .iter()
+        .enumerate()
+        .map(|(idx, &location)| {
+            TestSingleBuilder::default().id(&format!("s{idx}")).location(Some(location)).build_as_job_ref()
+        })
+        .collect()
+}
+
+type IndexData = Vec<(Vec<(String, Float)>, Option<Float>)>;
+
+fn get_index_data(jobs: &Jobs, species: &[Job]) -> IndexData {
+    let profile = Profile::default();
+
+    species
+        .iter()
+        .map(|job| {
+            let neighbours = jobs.neighbors(&profile, job, 0.).map(|(j, cost)| (get_job_id(j).clone(), cost)).collect();
+            (neighbours, jobs.rank(&profile, job))
+        })
+        .collect()
+}
+
+fn get_cluster_ids(jobs: &Jobs) -> Vec<Vec<String>> {
+    let mut clusters = jobs
+        .clusters()
+        .iter()
+        .map(|cluster| {
+            let mut ids = cluster.iter().map(|job| get_job_id(job).clone()).collect::<Vec<_>>();
+            ids.sort();
+            ids
+        })
+        .collect::<Vec<_>>();
+    clusters.sort();
+
+    clusters
+}
+
+fn get_fingerprint(jobs: &[Job], transport: &dyn TransportCost, index_mode: &JobIndexMode) -> u64 {
+    let fleet = test_fleet();
+    let avg_costs = HashMap::from([(0, test_costs())]);
+
+    get_index_fingerprint(&fleet, jobs, transport, index_mode, &JobClusteringMode::default(), &avg_costs)
+        .expect("cannot get fingerprint")
+}
+
+#[test]
+fn can_reuse_cached_index() {
+    let fleet = test_fleet();
+    let transport = create_transport(1.);
+    let cache = MemoryJobIndexCache::default();
+    let locations = [0, 0, 0, 0, 1, 5, 5, 5, 5, 9];
+    let create = |species: Vec<Job>| {
+        Jobs::new_with_cache(
+            &fleet,
+            species,
+            transport.as_ref(),
+            JobIndexMode::Exact,
+            JobClusteringMode::default(),
+            &cache,
+            &test_logger(),
+        )
+        .unwrap()
+    };
+
+    let original_species = create_jobs(&locations);
+    let original = create(original_species.clone());
+    let cached_species = create_jobs(&locations);
+    let cached = create(cached_species.clone());
+
+    assert_eq!(*cache.stores.lock().unwrap(), 1);
+    assert_eq!(cache.data.lock().unwrap().len(), 1);
+    assert_eq!(get_index_data(&cached, &cached_species), get_index_data(&original, &original_species));
+    assert_eq!(get_cluster_ids(&cached), get_cluster_ids(&original));
+    assert!(!cached.clusters().is_empty());
+}
+
+#[test]
+fn can_recreate_index_when_cached_data_is_invalid() {
+    let fleet = test_fleet();
+    let transport = create_transport(1.);
+    let cache = MemoryJobIndexCache::default();
+    let species = create_jobs(&[0, 1, 2]);
+    let fingerprint = get_fingerprint(&species, transport.as_ref(), &JobIndexMode::Exact);
+    cache.data.lock().unwrap().insert(fingerprint, vec![INDEX_FORMAT_VERSION, 0, 0]);
+
+    let jobs = Jobs::new_with_cache(
+        &fleet,
+        species.clone(),
+        transport.as_ref(),
+        JobIndexMode::Exact,
+        JobClusteringMode::default(),
+        &cache,
+        &test_logger(),
+    )
+    .unwrap();
+
+    assert_eq!(*cache.stores.lock().unwrap(), 1);
+    assert_eq!(jobs.neighbors(&Profile::default(), &species[0], 0.).count(), 2);
+}
+
+#[test]
+fn can_recreate_index_when_cached_neighbours_size_is_out_of_range() {
+    let fleet = test_fleet();
+    let transport = create_transport(1.);
+    let cache = MemoryJobIndexCache::default();
+    let species = create_jobs(&[0, 1, 2]);
+    let fingerprint = get_fingerprint(&species, transport.as_ref(), &JobIndexMode::Exact);
+    let mut writer = IndexWriter::default();
+    writer.write_u32(INDEX_FORMAT_VERSION as usize);
+    writer.write_u64(fingerprint);
+    writer.write_u32(species.len());
+    writer.write_float(0.);
+    writer.write_u32(1);
+    writer.write_u32(u32::MAX as usize);
+    cache.data.lock().unwrap().insert(fingerprint, writer.into_bytes());
+
+    let jobs = Jobs::new_with_cache(
+        &fleet,
+        species.clone(),
+        transport.as_ref(),
+        JobIndexMode::Exact,
+        JobClusteringMode::default(),
+        &cache,
+        &test_logger(),
+    )
+    .unwrap();
+
+    assert_eq!(*cache.stores.lock().unwrap(), 1);
+    assert_eq!(jobs.neighbors(&Profile::default(), &species[0], 0.).count(), 2);
+}
+
+#[test]
+fn can_detect_changes_in_fingerprint() {
+    let transport = create_transport(1.);
+    let species = create_jobs(&[0, 1, 2]);
+    let fingerprint = get_fingerprint(&species, transport.as_ref(), &JobIndexMode::Exact);
+
+    assert_eq!(get_fingerprint(&create_jobs(&[0, 1, 2]), transport.as_ref(), &JobIndexMode::Exact), fingerprint);
+    assert_ne!(get_fingerprint(&create_jobs(&[0, 1, 3]), transport.as_ref(), &JobIndexMode::Exact), fingerprint);
+    assert_ne!(get_fingerprint(&create_jobs(&[0, 1]), transport.as_ref(), &JobIndexMode::Exact), fingerprint);
+    assert_ne!(get_fingerprint(&species, create_transport(2.).as_ref(), &JobIndexMode::Exact), fingerprint);
+    assert_ne!(
+        get_fingerprint(
+            &species,
+            transport.as_ref(),
+            &JobIndexMode::Approximate { coordinates: Arc::new(vec![(0., 0.); 10]), max_candidates: 2 }
+        ),
+        fingerprint
+    );
+}
+
+#[test]
+fn can_detect_changes_in_matrix_fingerprint() {
+    let species = create_jobs(&[0, 1, 2]);
+    let create_transport_with_change = |value: Float| {
+        let size = 10;
+        let mut data = (0..size).flat_map(|from| (0..size).map(move |to| fake_routing(from, to))).collect::<Vec<_>>();
+        data[size * size - 1] = value;
+
+        create_matrix_transport_cost(vec![MatrixData::new(0, None, data.clone(), data)]).unwrap()
+    };
+    let fingerprint = get_fingerprint(&species, create_transport_with_change(0.).as_ref(), &JobIndexMode::Exact);
+
+    assert_eq!(get_fingerprint(&species, create_transport_with_change(0.).as_ref(), &JobIndexMode::Exact), fingerprint);
+    assert_ne!(get_fingerprint(&species, create_transport_with_change(1.).as_ref(), &JobIndexMode::Exact), fingerprint);
+}
+
+#[test]
+fn can_skip_cache_when_routing_data_has_no_fingerprint() {
+    let fleet = test_fleet();
+    let cache = MemoryJobIndexCache::default();
+    let species = create_jobs(&[0, 1, 2]);
+
+    let jobs = Jobs::new_with_cache(
+        &fleet,
+        species.clone(),
+        &TestTransportCost::default(),
+        JobIndexMode::Exact,
+        JobClusteringMode::default(),
+        &cache,
+        &test_logger(),
+    )
+    .unwrap();
+
+    assert_eq!(*cache.stores.lock().unwrap(), 0);
+    assert_eq!(jobs.neighbors(&Profile::default(), &species[0], 0.).count(), 2);
+}
+
+#[test]
+fn can_use_file_cache() {
+    let directory = std::env::temp_dir().join(format!("vrp_job_index_cache_{}", std::process::id()));
+    let cache = FileJobIndexCache::new(directory.clone());
+
+    assert!(cache.load(42).is_none());
+    cache.store(42, &[1, 2, 3]).unwrap();
+    assert_eq!(cache.load(42), Some(vec![1, 2, 3]));
+    assert!(cache.load(43).is_none());
+
+    std::fs::remove_dir_all(directory).unwrap();
+}
+
+#[test]
+fn can_detect_truncated_data() {
+    let mut writer = IndexWriter::default();
+    writer.write_u32(7);
+    writer.write_float(1.5);
+    let data = writer.into_bytes();
+
+    let mut reader = IndexReader::new(&data);
+    assert_eq!(reader.read_index(8).unwrap(), 7);
+    assert_eq!(reader.read_float().unwrap(), 1.5);
+    assert!(reader.is_empty());
+    assert!(reader.read_u64().is_err());
+
+    let mut reader = IndexReader::new(&data);
+    assert!(reader.read_index(7).is_err());
+}
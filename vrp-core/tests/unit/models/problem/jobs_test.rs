@@ -1,7 +1,10 @@
 use super::*;
 use crate::helpers::models::domain::test_logger;
 use crate::helpers::models::problem::*;
-use crate::models::problem::{TravelTime, VehicleDetail, VehiclePlace};
+use crate::models::problem::{
+    CoordinateFallback, DistanceFormula, JobClusteringMode, JobIndexMode, MatrixData, SparseMatrixData, TravelTime,
+    VehicleDetail, VehiclePlace, create_matrix_transport_cost, create_sparse_matrix_transport_cost,
+};
 use crate::models::solution::Route;
 
 #[derive(Default)]
@@ -259,6 +262,8 @@ can_handle_negative_distances_durations! {
     case02: (1., -1.),
     case03: (-1., -1.),
     case04: (-1., 0.),
+    case05: (Float::INFINITY, 1.),
+    case06: (1., Float::NAN),
 }
 
 fn can_handle_negative_distances_durations_impl(transport_costs: Arc<dyn TransportCost>) {
@@ -277,3 +282,411 @@ fn can_handle_negative_distances_durations_impl(transport_costs: Arc<dyn Transpo
         );
     }
 }
+
+parameterized_test! {can_use_sparse_neighbours, (index, expected), {
+    can_use_sparse_neighbours_impl(index, expected);
+}}
+
+can_use_sparse_neighbours! {
+    case01: (0, vec!["s1", "s2"]),
+    case02: (4, vec!["s3", "s2"]),
+}
+
+fn can_use_sparse_neighbours_impl(index: usize, expected: Vec<&str>) {
+    let size = 5;
+    let entries = (0..size)
+        .flat_map(|from| (0..size).filter(move |&to| to != from).map(move |to| (from, to)))
+        .map(|(from, to)| (from, to, 0., fake_routing(from, to)))
+        .collect();
+    let fallback = CoordinateFallback::new(
+        (0..size).map(|location| (location as Float, 0.)).collect(),
+        DistanceFormula::Euclidean,
+        1.,
+    )
+    .unwrap();
+    let transport = create_sparse_matrix_transport_cost(vec![SparseMatrixData::new(0, entries)], size, 2, fallback)
+        .expect("cannot create transport");
+    let species = (0..size)
+        .map(|location| {
+            TestSingleBuilder::default().id(&format!("s{location}")).location(Some(location)).build_as_job_ref()
+        })
+        .collect::<Vec<_>>();
+
+    let jobs = Jobs::new(&test_fleet(), species.clone(), transport.as_ref(), &test_logger()).unwrap();
+
+    let result = jobs
+        .neighbors(&Profile::default(), species.get(index).unwrap(), 0.0)
+        .map(|(j, _)| get_job_id(j).clone())
+        .collect::<Vec<_>>();
+    assert_eq!(result, expected);
+}
+
+fn create_approximate_index_mode(size: usize, max_candidates: usize) -> JobIndexMode {
+    JobIndexMode::Approximate {
+        coordinates: Arc::new((0..size).map(|location| (location as Float, 0.)).collect()),
+        max_candidates,
+    }
+}
+
+parameterized_test! {can_use_approximate_index, (index, max_candidates, expected), {
+    can_use_approximate_index_impl(index, max_candidates, expected);
+}}
+
+can_use_approximate_index! {
+    case01: (0, 3, vec!["s1", "s2", "s3"]),
+    case02: (9, 2, vec!["s8", "s7"]),
+    case03: (0, 20, vec!["s1", "s2", "s3", "s4", "s5", "s6", "s7", "s8", "s9"]),
+}
+
+fn can_use_approximate_index_impl(index: usize, max_candidates: usize, expected: Vec<&str>) {
+    let size = 10;
+    let species = (0..size)
+        .map(|location| {
+            TestSingleBuilder::default().id(&format!("s{location}")).location(Some(location)).build_as_job_ref()
+        })
+        .collect::<Vec<_>>();
+    let index_mode = create_approximate_index_mode(size, max_candidates);
+
+    let jobs = Jobs::new_with_index_mode(
+        &test_fleet(),
+        species.clone(),
+        create_only_distance_transport_cost().as_ref(),
+        index_mode,
+        &test_logger(),
+    )
+    .unwrap();
+
+    let result = jobs
+        .neighbors(&Profile::default(), species.get(index).unwrap(), 0.0)
+        .map(|(j, _)| get_job_id(j).clone())
+        .collect::<Vec<_>>();
+    assert_eq!(result, expected);
+    assert!(matches!(jobs.index_mode(), JobIndexMode::Approximate { .. }));
+}
+
+#[test]
+fn can_use_approximate_index_with_unlocated_jobs() {
+    let species = vec![
+        TestSingleBuilder::default().id("s0").location(Some(0)).build_as_job_ref(),
+        TestSingleBuilder::default().id("s1").location(Some(5)).build_as_job_ref(),
+        TestSingleBuilder::default().id("s2").location(None).build_as_job_ref(),
+    ];
+    let index_mode = create_approximate_index_mode(10, 1);
+
+    let jobs = Jobs::new_with_index_mode(
+        &test_fleet(),
+        species.clone(),
+        create_only_distance_transport_cost().as_ref(),
+        index_mode,
+        &test_logger(),
+    )
+    .unwrap();
+
+    let get_neighbours = |job: &Job| {
+        let mut ids =
+            jobs.neighbors(&Profile::default(), job, 0.0).map(|(j, _)| get_job_id(j).clone()).collect::<Vec<_>>();
+        ids.sort();
+        ids
+    };
+    assert_eq!(get_neighbours(&species[0]), vec!["s1", "s2"]);
+    assert_eq!(get_neighbours(&species[2]), vec!["s0", "s1"]);
+}
+
+parameterized_test! {can_detect_invalid_approximate_index, (size, max_candidates, expected), {
+    let species = vec![TestSingleBuilder::default().location(Some(5)).build_as_job_ref()];
+
+    let result = Jobs::new_with_index_mode(
+        &test_fleet(),
+        species,
+        create_only_distance_transport_cost().as_ref(),
+        create_approximate_index_mode(size, max_candidates),
+        &test_logger(),
+    );
+
+    assert_eq!(result.err(), Some(expected.into()));
+}}
+
+can_detect_invalid_approximate_index! {
+    case01: (10, 0, "max candidates of approximate job index should be greater than zero"),
+    case02: (5, 1, "approximate job index requires coordinates for all job locations"),
+}
+
+parameterized_test! {can_share_job_neighbours_between_profiles, (scales, per_distance, expected), {
+    can_share_job_neighbours_between_profiles_impl(scales, per_distance, expected);
+}}
+
+can_share_job_neighbours_between_profiles! {
+    case01_same_matrices: ((1., 1.), 1., true),
+    case02_different_matrices: ((1., 2.), 1., false),
+    case03_different_costs: ((1., 1.), 2., false),
+}
+
+fn can_share_job_neighbours_between_profiles_impl(scales: (Float, Float), per_distance: Float, expected: bool) {
+    let size = 3;
+    let create_matrix = |index: usize, scale: Float| {
+        let data =
+            (0..size).flat_map(|from| (0..size).map(move |to| fake_routing(from, to) * scale)).collect::<Vec<_>>();
+        MatrixData::new(index, None, data.clone(), data)
+    };
+    let transport = create_matrix_transport_cost(vec![create_matrix(0, scales.0), create_matrix(1, scales.1)]).unwrap();
+    let (p0, p1) = (Profile::new(0, None), Profile::new(1, None));
+    let mut v2 =
+        TestVehicleBuilder::default().id("v2").profile(p1.clone()).details(vec![test_vehicle_detail()]).build();
+    v2.costs.per_distance = per_distance;
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![
+            TestVehicleBuilder::default().id("v1").profile(p0.clone()).details(vec![test_vehicle_detail()]).build(),
+            v2,
+        ])
+        .build();
+    let species = (0..size)
+        .map(|location| {
+            TestSingleBuilder::default().id(&format!("s{location}")).location(Some(location)).build_as_job_ref()
+        })
+        .collect::<Vec<_>>();
+
+    let jobs = Jobs::new(&fleet, species.clone(), transport.as_ref(), &test_logger()).unwrap();
+
+    let get_index = |profile: &Profile| jobs.index.get(&profile.index).unwrap();
+    assert_eq!(Arc::ptr_eq(&get_index(&p0).neighbours, &get_index(&p1).neighbours), expected);
+    for job in species.iter() {
+        assert_eq!(jobs.neighbors(&p0, job, 0.).count(), size - 1);
+        assert_eq!(jobs.neighbors(&p1, job, 0.).count(), size - 1);
+        assert!(jobs.rank(&p0, job).is_some() && jobs.rank(&p1, job).is_some());
+    }
+}
+
+fn get_sorted_neighbours(jobs: &Jobs, profile: &Profile, job: &Job) -> Vec<(String, Cost)> {
+    let mut neighbours =
+        jobs.neighbors(profile, job, 0.).map(|(j, cost)| (get_job_id(j).clone(), cost)).collect::<Vec<_>>();
+    neighbours.sort_by(|(a_id, a_cost), (b_id, b_cost)| a_cost.total_cmp(b_cost).then(a_id.cmp(b_id)));
+
+    neighbours
+}
+
+fn get_cluster_ids(jobs: &Jobs) -> Vec<Vec<String>> {
+    let mut clusters = jobs
+        .clusters()
+        .iter()
+        .map(|cluster| {
+            let mut ids = cluster.iter().map(|job| get_job_id(job).clone()).collect::<Vec<_>>();
+            ids.sort();
+            ids
+        })
+        .collect::<Vec<_>>();
+    clusters.sort();
+
+    clusters
+}
+
+fn create_jobs_with_locations(locations: &[Location]) -> Vec<Job> {
+    locations
+        .iter()
+        .enumerate()
+        .map(|(idx, &location)| {
+            TestSingleBuilder::default().id(&format!("s{idx}")).location(Some(location)).build_as_job_ref()
+        })
+        .collect()
+}
+
+parameterized_test! {can_update_index_incrementally, (locations, job_idx), {
+    can_update_index_incrementally_impl(locations, job_idx);
+}}
+
+can_update_index_incrementally! {
+    case01_first: (vec![0, 0, 0, 0, 1, 30, 30, 30, 30, 31, 60, 70, 90], 0),
+    case02_middle: (vec![0, 0, 0, 0, 1, 30, 30, 30, 30, 31, 60, 70, 90], 7),
+    case03_last: (vec![0, 0, 0, 0, 1, 30, 30, 30, 30, 31, 60, 70, 90], 12),
+}
+
+fn can_update_index_incrementally_impl(locations: Vec<Location>, job_idx: usize) {
+    let fleet = test_fleet();
+    let transport = create_only_distance_transport_cost();
+    let species = create_jobs_with_locations(&locations);
+    let job = species[job_idx].clone();
+    let others = species.iter().filter(|other| **other != job).cloned().collect::<Vec<_>>();
+    let expected_all = Jobs::new(&fleet, species.clone(), transport.as_ref(), &test_logger()).unwrap();
+    let expected_others = Jobs::new(&fleet, others.clone(), transport.as_ref(), &test_logger()).unwrap();
+    let profile = Profile::default();
+
+    let mut jobs = expected_others.clone();
+    jobs.insert(&fleet, transport.as_ref(), job.clone()).unwrap();
+
+    assert_eq!(jobs.size(), species.len());
+    for species_job in species.iter() {
+        assert_eq!(
+            get_sorted_neighbours(&jobs, &profile, species_job),
+            get_sorted_neighbours(&expected_all, &profile, species_job)
+        );
+        assert_eq!(jobs.rank(&profile, species_job), expected_all.rank(&profile, species_job));
+    }
+    assert_eq!(get_cluster_ids(&jobs), get_cluster_ids(&expected_all));
+    assert!(!expected_all.clusters().is_empty());
+    assert_eq!(get_sorted_neighbours(&expected_others, &profile, &others[0]).len(), others.len() - 1);
+
+    jobs.remove(&fleet, &job).unwrap();
+
+    assert_eq!(jobs.size(), others.len());
+    assert!(jobs.rank(&profile, &job).is_none());
+    for other in others.iter() {
+        assert_eq!(
+            get_sorted_neighbours(&jobs, &profile, other),
+            get_sorted_neighbours(&expected_others, &profile, other)
+        );
+    }
+}
+
+#[test]
+fn can_detect_invalid_incremental_updates() {
+    let fleet = test_fleet();
+    let transport = create_only_distance_transport_cost();
+    let species = create_jobs_with_locations(&[0, 1, 2]);
+    let mut jobs = Jobs::new(&fleet, species[..2].to_vec(), transport.as_ref(), &test_logger()).unwrap();
+
+    assert_eq!(
+        jobs.insert(&fleet, transport.as_ref(), species[0].clone()).err(),
+        Some("job is already present".into())
+    );
+    assert_eq!(jobs.remove(&fleet, &species[2]).err(), Some("job is not found".into()));
+}
+
+#[test]
+fn can_update_shared_neighbours_without_affecting_original() {
+    let size = 4;
+    let data = (0..size).flat_map(|from| (0..size).map(move |to| fake_routing(from, to))).collect::<Vec<_>>();
+    let transport = create_matrix_transport_cost(vec![
+        MatrixData::new(0, None, data.clone(), data.clone()),
+        MatrixData::new(1, None, data.clone(), data),
+    ])
+    .unwrap();
+    let (p0, p1) = (Profile::new(0, None), Profile::new(1, None));
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![
+            TestVehicleBuilder::default().id("v1").profile(p0.clone()).details(vec![test_vehicle_detail()]).build(),
+            TestVehicleBuilder::default().id("v2").profile(p1.clone()).details(vec![test_vehicle_detail()]).build(),
+        ])
+        .build();
+    let species = create_jobs_with_locations(&[0, 1, 2, 3]);
+    let original = Jobs::new(&fleet, species[..3].to_vec(), transport.as_ref(), &test_logger()).unwrap();
+
+    let mut jobs = original.clone();
+    jobs.insert(&fleet, transport.as_ref(), species[3].clone()).unwrap();
+
+    let get_index = |jobs: &Jobs, profile: &Profile| jobs.index.get(&profile.index).unwrap().neighbours.clone();
+    assert!(Arc::ptr_eq(&get_index(&jobs, &p0), &get_index(&jobs, &p1)));
+    assert!(!Arc::ptr_eq(&get_index(&jobs, &p0), &get_index(&original, &p0)));
+    assert_eq!(original.neighbors(&p0, &species[0], 0.).count(), 2);
+    assert_eq!(jobs.neighbors(&p0, &species[0], 0.).count(), 3);
+    assert_eq!(jobs.neighbors(&p1, &species[0], 0.).count(), 3);
+}
+
+fn create_grid_clustering_mode(size: usize, cell_size: Float, min_points: usize) -> JobClusteringMode {
+    JobClusteringMode::Grid {
+        coordinates: Arc::new((0..size).map(|location| (location as Float, 0.)).collect()),
+        cell_size,
+        min_points,
+    }
+}
+
+parameterized_test! {can_use_clustering_mode, clustering_mode, {
+    can_use_clustering_mode_impl(clustering_mode);
+}}
+
+can_use_clustering_mode! {
+    case01_dbscan: JobClusteringMode::Dbscan { min_points: 2, epsilon: Some(5.) },
+    case02_kmedoids: JobClusteringMode::KMedoids { k: 3 },
+    case03_grid: create_grid_clustering_mode(100, 10., 2),
+}
+
+fn can_use_clustering_mode_impl(clustering_mode: JobClusteringMode) {
+    let fleet = test_fleet();
+    let transport = create_only_distance_transport_cost();
+    let species = create_jobs_with_locations(&[0, 0, 0, 1, 30, 30, 31, 90]);
+
+    let jobs =
+        Jobs::new_with_modes(&fleet, species, transport.as_ref(), JobIndexMode::Exact, clustering_mode, &test_logger())
+            .unwrap();
+
+    assert_eq!(get_cluster_ids(&jobs), vec![vec!["s0", "s1", "s2", "s3"], vec!["s4", "s5", "s6"]]);
+}
+
+parameterized_test! {can_detect_invalid_clustering_mode, (clustering_mode, expected), {
+    can_detect_invalid_clustering_mode_impl(clustering_mode, expected);
+}}
+
+can_detect_invalid_clustering_mode! {
+    case01_dbscan_epsilon: (JobClusteringMode::Dbscan { min_points: 2, epsilon: Some(-1.) },
+                            "epsilon of DBSCAN job clustering should be positive"),
+    case02_kmedoids_k: (JobClusteringMode::KMedoids { k: 0 }, "k of k-medoids job clustering should be greater than zero"),
+    case03_grid_cell: (create_grid_clustering_mode(10, 0., 2), "cell size of grid job clustering should be positive"),
+}
+
+fn can_detect_invalid_clustering_mode_impl(clustering_mode: JobClusteringMode, expected: &str) {
+    let fleet = test_fleet();
+    let transport = create_only_distance_transport_cost();
+    let species = create_jobs_with_locations(&[0, 1, 2]);
+
+    let result =
+        Jobs::new_with_modes(&fleet, species, transport.as_ref(), JobIndexMode::Exact, clustering_mode, &test_logger());
+
+    assert_eq!(result.err(), Some(expected.into()));
+}
+
+#[test]
+fn can_update_grid_clusters_incrementally() {
+    let fleet = test_fleet();
+    let transport = create_only_distance_transport_cost();
+    let species = create_jobs_with_locations(&[0, 0, 1, 50, 52, 55, 5]);
+    let create_jobs = |species: &[Job]| {
+        Jobs::new_with_modes(
+            &fleet,
+            species.to_vec(),
+            transport.as_ref(),
+            JobIndexMode::Exact,
+            create_grid_clustering_mode(100, 10., 3),
+            &test_logger(),
+        )
+        .unwrap()
+    };
+
+    let mut jobs = create_jobs(&species[..5]);
+    assert_eq!(get_cluster_ids(&jobs), vec![vec!["s0", "s1", "s2"]]);
+
+    jobs.insert(&fleet, transport.as_ref(), species[5].clone()).unwrap();
+    jobs.insert(&fleet, transport.as_ref(), species[6].clone()).unwrap();
+    assert_eq!(get_cluster_ids(&jobs), vec![vec!["s0", "s1", "s2", "s6"], vec!["s3", "s4", "s5"]]);
+    assert_eq!(get_cluster_ids(&jobs), get_cluster_ids(&create_jobs(&species)));
+
+    jobs.remove(&fleet, &species[3]).unwrap();
+    assert_eq!(get_cluster_ids(&jobs), vec![vec!["s0", "s1", "s2", "s6"]]);
+}
+
+#[test]
+fn can_estimate_memory_usage() {
+    let fleet = test_fleet();
+    let transport = create_only_distance_transport_cost();
+    let species = create_jobs_with_locations(&[0, 1, 2, 3, 4, 5]);
+
+    let small = Jobs::new(&fleet, species[..2].to_vec(), transport.as_ref(), &test_logger()).unwrap();
+    let large = Jobs::new(&fleet, species, transport.as_ref(), &test_logger()).unwrap();
+
+    assert!(small.memory_usage() > 0);
+    assert!(large.memory_usage() > small.memory_usage());
+}
+
+#[test]
+fn can_sort_jobs_canonically() {
+    let mut jobs = ["job3", "job1", "job2"]
+        .iter()
+        .map(|id| TestSingleBuilder::default().id(id).build_as_job_ref())
+        .collect::<Vec<_>>();
+
+    sort_jobs_canonically(&mut jobs);
+
+    assert_eq!(
+        jobs.iter().filter_map(|job| job.dimens().get_job_id().cloned()).collect::<Vec<_>>(),
+        vec!["job1", "job2", "job3"]
+    );
+}
@@ -0,0 +1,64 @@
+use super::*;
+use crate::construction::heuristics::UnassignmentInfo;
+use crate::helpers::models::domain::get_customer_id;
+use crate::helpers::solver::generate_matrix_routes_with_defaults;
+use crate::models::problem::VehicleIdDimension;
+
+fn get_vehicle_id(actor: &Option<Arc<Actor>>) -> Option<String> {
+    actor.as_ref().and_then(|actor| actor.vehicle.dimens.get_vehicle_id().cloned())
+}
+
+#[test]
+fn can_get_empty_diff_for_same_solution() {
+    let (problem, solution) = generate_matrix_routes_with_defaults(3, 2, false);
+
+    let diff = SolutionDiff::new(Arc::new(problem), &solution, &solution.deep_copy());
+
+    assert!(diff.is_empty());
+    assert!(!diff.old_fitness.is_empty());
+    assert_eq!(diff.old_fitness, diff.new_fitness);
+}
+
+#[test]
+fn can_detect_moved_jobs() {
+    let (problem, old) = generate_matrix_routes_with_defaults(3, 2, false);
+    let mut new = old.deep_copy();
+    let c0 = new.routes[0].tour.remove_activity_at(1);
+    let c5 = new.routes[1].tour.remove_activity_at(3);
+    let activity = old.routes[0].tour.get(1).unwrap().deep_copy();
+    new.routes[1].tour.insert_last(activity);
+    new.unassigned.push((c5, UnassignmentInfo::Unknown));
+
+    let diff = SolutionDiff::new(Arc::new(problem), &old, &new);
+
+    let moved = diff
+        .moved
+        .iter()
+        .map(|job_move| (get_customer_id(&job_move.job), get_vehicle_id(&job_move.from), get_vehicle_id(&job_move.to)))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        moved,
+        vec![
+            (get_customer_id(&c0), Some("0".to_string()), Some("1".to_string())),
+            ("c5".to_string(), Some("1".to_string()), None),
+        ]
+    );
+    assert!(diff.fitness_delta().any(|delta| delta != 0.));
+}
+
+#[test]
+fn can_detect_time_shifts() {
+    let (problem, old) = generate_matrix_routes_with_defaults(3, 2, false);
+    let mut new = old.deep_copy();
+    new.routes[0].tour.get_mut(2).unwrap().schedule.arrival += 10.;
+
+    let diff = SolutionDiff::new(Arc::new(problem), &old, &new);
+
+    assert!(diff.moved.is_empty());
+    assert_eq!(diff.shifted.len(), 1);
+    let shift = &diff.shifted[0];
+    assert_eq!(get_customer_id(&shift.job), "c1");
+    assert_eq!(get_vehicle_id(&Some(shift.actor.clone())), Some("0".to_string()));
+    assert_eq!(shift.new_arrival - shift.old_arrival, 10.);
+    assert!(diff.fitness_delta().all(|delta| delta == 0.));
+}
@@ -187,3 +187,37 @@ fn can_get_start_and_end() {
     assert_eq!(get_memory_address(tour.start().unwrap()), get_memory_address(&tour.activities[0]));
     assert_eq!(get_memory_address(tour.end().unwrap()), get_memory_address(&tour.activities[3]));
 }
+
+#[test]
+fn can_get_jobs_in_activity_order_in_deterministic_mode() {
+    let jobs = (0..10).map(|_| TestSingleBuilder::default().build_shared()).collect::<Vec<_>>();
+    let mut tour = Tour::default();
+    tour.set_start(ActivityBuilder::default().job(None).build());
+    tour.set_end(ActivityBuilder::default().job(None).build());
+    jobs.iter().rev().for_each(|job| {
+        tour.insert_at(ActivityBuilder::default().job(Some(job.clone())).build(), 1);
+    });
+
+    let actual = rosomaxa::utils::with_deterministic_mode(|| tour.jobs().cloned().collect::<Vec<_>>());
+
+    assert_eq!(actual, jobs.into_iter().map(Job::Single).collect::<Vec<_>>());
+}
+
+#[test]
+fn can_reuse_allocations_of_dropped_tours_in_deep_copy() {
+    let tour = get_test_tour();
+    let pool_size = || ACTIVITIES_POOL.with(|pool| pool.borrow().size());
+
+    let copy = tour.deep_copy();
+    let before_drop = pool_size();
+    drop(copy);
+    let after_drop = pool_size();
+    let copy = tour.deep_copy();
+
+    assert_eq!(after_drop, before_drop + 1);
+    assert_eq!(pool_size(), before_drop);
+    assert_eq!(copy.total(), tour.total());
+    assert!(
+        copy.all_activities().zip(tour.all_activities()).all(|(a, b)| get_memory_address(a) != get_memory_address(b))
+    );
+}
@@ -0,0 +1,89 @@
+use super::*;
+use crate::helpers::models::domain::{get_customer_id, test_logger};
+use crate::helpers::models::problem::TestSingleBuilder;
+use crate::helpers::solver::generate_matrix_routes_with_defaults;
+use crate::models::problem::VehicleIdDimension;
+
+fn create_new_job(id: &str, location: usize) -> Job {
+    TestSingleBuilder::default().id(id).location(Some(location)).build_as_job_ref()
+}
+
+fn get_vehicle_id(actor: &Actor) -> &str {
+    actor.vehicle.dimens.get_vehicle_id().unwrap().as_str()
+}
+
+fn create_dynamic_solver(max_iterations: usize) -> (DynamicSolver, Solution) {
+    let (problem, solution) = generate_matrix_routes_with_defaults(3, 2, false);
+    let problem = problem.with_added_jobs(vec![create_new_job("new", 5)], &test_logger()).unwrap();
+
+    let solver = DynamicSolver::new(Arc::new(problem), Arc::new(Environment::default()))
+        .with_max_iterations(max_iterations)
+        .with_max_removed_jobs(2);
+
+    (solver, solution)
+}
+
+#[test]
+fn can_add_jobs_to_problem() {
+    let (problem, _) = generate_matrix_routes_with_defaults(3, 2, false);
+    let existing_job = problem.jobs.all().first().cloned().unwrap();
+
+    let new_problem = problem.with_added_jobs(vec![create_new_job("new", 2)], &test_logger()).unwrap();
+
+    assert_eq!(new_problem.jobs.size(), problem.jobs.size() + 1);
+    assert!(new_problem.jobs.all().contains(&existing_job));
+    assert!(problem.with_added_jobs(vec![], &test_logger()).is_err());
+    assert!(problem.with_added_jobs(vec![existing_job], &test_logger()).is_err());
+}
+
+parameterized_test! {can_insert_new_job_changing_only_affected_tour, max_iterations, {
+    can_insert_new_job_changing_only_affected_tour_impl(max_iterations);
+}}
+
+can_insert_new_job_changing_only_affected_tour! {
+    case01_insertion_only: 0,
+    case02_with_reoptimization: 10,
+}
+
+fn can_insert_new_job_changing_only_affected_tour_impl(max_iterations: usize) {
+    let (solver, solution) = create_dynamic_solver(max_iterations);
+
+    let result = solver.solve(solution).unwrap();
+
+    assert!(result.solution.unassigned.is_empty());
+    assert_eq!(result.solution.routes.iter().map(|route| route.tour.job_count()).sum::<usize>(), 7);
+    assert_eq!(result.changes.len(), 1);
+    let change = result.changes.first().unwrap();
+    assert_eq!(get_vehicle_id(change.actor.as_ref()), "1");
+    assert_eq!(change.added.iter().map(get_customer_id).collect::<Vec<_>>(), vec!["new".to_string()]);
+    assert!(change.removed.is_empty());
+    let untouched = result.solution.routes.iter().find(|route| get_vehicle_id(route.actor.as_ref()) == "0").unwrap();
+    let untouched_ids =
+        untouched.tour.all_activities().filter_map(|activity| activity.retrieve_job()).map(|job| get_customer_id(&job));
+    assert_eq!(untouched_ids.collect::<Vec<_>>(), vec!["c0", "c1", "c2"]);
+}
+
+#[test]
+fn can_return_error_when_no_new_jobs() {
+    let (problem, solution) = generate_matrix_routes_with_defaults(3, 2, false);
+    let solver = DynamicSolver::new(Arc::new(problem), Arc::new(Environment::default()));
+
+    assert!(solver.solve(solution).is_err());
+}
+
+#[test]
+fn can_get_tour_changes_in_stable_order() {
+    let (problem, solution) = generate_matrix_routes_with_defaults(3, 2, false);
+    let mut insertion_ctx =
+        InsertionContext::new_from_solution(Arc::new(problem), (solution, None), Arc::new(Environment::default()));
+    insertion_ctx.solution.routes.reverse();
+
+    let changes = get_tour_changes(&insertion_ctx, &TourJobs::default());
+
+    assert_eq!(changes.iter().map(|change| get_vehicle_id(change.actor.as_ref())).collect::<Vec<_>>(), vec!["0", "1"]);
+    assert_eq!(
+        changes.iter().map(|change| change.added.iter().map(get_customer_id).collect::<Vec<_>>()).collect::<Vec<_>>(),
+        vec![vec!["c0", "c1", "c2"], vec!["c3", "c4", "c5"]]
+    );
+    assert!(changes.iter().all(|change| change.removed.is_empty()));
+}
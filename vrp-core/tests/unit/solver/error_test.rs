@@ -0,0 +1,14 @@
+use super::*;
+
+#[test]
+fn can_preserve_display_text_and_source_of_solver_error() {
+    let search = SolverError::Search("invalid config".into());
+    let no_solution = SolverError::NoSolution;
+
+    assert_eq!((search.code(), no_solution.code()), ("E3000", "E3001"));
+    assert_eq!(search.to_string(), "invalid config");
+    assert_eq!(no_solution.to_string(), "cannot find any solution");
+    assert_eq!(search.source().map(|err| err.to_string()), Some("invalid config".to_string()));
+    assert!(no_solution.source().is_none());
+    assert_eq!(GenericError::from(no_solution), GenericError::from("cannot find any solution"));
+}
@@ -0,0 +1,123 @@
+use super::*;
+use crate::construction::features::TransportFeatureBuilder;
+use crate::helpers::models::domain::{TestGoalContextBuilder, get_customer_ids_from_routes};
+use crate::helpers::models::problem::TestSingleBuilder;
+use crate::helpers::solver::{generate_matrix_routes, generate_matrix_routes_with_defaults};
+use crate::models::ViolationCode;
+
+parameterized_test! {can_check_whether_problem_is_applicable, (rows, cols, expected), {
+    can_check_whether_problem_is_applicable_impl(rows, cols, expected);
+}}
+
+can_check_whether_problem_is_applicable! {
+    case01_single_actor: (6, 1, true),
+    case02_max_jobs: (DEFAULT_EXACT_MAX_JOBS, 1, true),
+    case03_too_many_jobs: (DEFAULT_EXACT_MAX_JOBS + 1, 1, false),
+    case04_multiple_actors: (2, 2, false),
+}
+
+fn can_check_whether_problem_is_applicable_impl(rows: usize, cols: usize, expected: bool) {
+    let (problem, _) = generate_matrix_routes_with_defaults(rows, cols, false);
+
+    assert_eq!(ExactSolver::is_applicable(&problem), expected);
+}
+
+#[test]
+fn can_solve_tiny_problem_exactly() {
+    let environment = Arc::new(Environment::default());
+    let (problem, optimal) = generate_matrix_routes_with_defaults(7, 1, false);
+    let problem = Arc::new(problem);
+    let optimal_cost = InsertionContext::new_from_solution(problem.clone(), (optimal, None), environment.clone())
+        .get_total_cost()
+        .unwrap();
+
+    let solution = ExactSolver::new(problem, environment).solve().expect("no solution");
+
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(solution.routes.len(), 1);
+    assert_eq!(solution.routes[0].tour.job_count(), 7);
+    assert_eq!(solution.cost, optimal_cost);
+}
+
+#[test]
+fn can_return_none_for_not_applicable_problem() {
+    let (problem, _) = generate_matrix_routes_with_defaults(2, 2, false);
+
+    assert!(ExactSolver::new(Arc::new(problem), Arc::new(Environment::default())).solve().is_none());
+}
+
+#[test]
+fn can_return_none_when_search_is_not_completed() {
+    let (problem, _) = generate_matrix_routes_with_defaults(7, 1, false);
+
+    let solver = ExactSolver::new(Arc::new(problem), Arc::new(Environment::default())).with_max_nodes(10);
+
+    assert!(solver.solve().is_none());
+}
+
+#[test]
+fn can_detect_cost_as_sole_objective() {
+    let environment = Arc::new(Environment::default());
+    let create_insertion_ctx = |problem: Problem, solution: Solution| {
+        InsertionContext::new_from_solution(Arc::new(problem), (solution, None), environment.clone())
+    };
+    let (problem, solution) = generate_matrix_routes(
+        5,
+        1,
+        false,
+        |transport, activity, _| {
+            TestGoalContextBuilder::empty()
+                .add_feature(
+                    TransportFeatureBuilder::new("transport")
+                        .set_violation_code(ViolationCode(1))
+                        .set_transport_cost(transport)
+                        .set_activity_cost(activity)
+                        .build_minimize_cost()
+                        .unwrap(),
+                )
+                .build()
+        },
+        |id, location| TestSingleBuilder::default().id(id).location(location).build_shared(),
+        |v| v,
+        |data| (data.clone(), data),
+    );
+    let cost_only_ctx = create_insertion_ctx(problem, solution);
+    let (problem, solution) = generate_matrix_routes_with_defaults(5, 1, false);
+    let multi_objective_ctx = create_insertion_ctx(problem, solution);
+
+    assert!(is_cost_sole_objective(&cost_only_ctx, cost_only_ctx.get_total_cost().unwrap()));
+    assert!(!is_cost_sole_objective(&multi_objective_ctx, multi_objective_ctx.get_total_cost().unwrap()));
+}
+
+#[test]
+fn can_sequence_route_exactly() {
+    let environment = Arc::new(Environment::default());
+    let (problem, solution) = generate_matrix_routes_with_defaults(6, 1, false);
+    let mut insertion_ctx = InsertionContext::new_from_solution(Arc::new(problem), (solution, None), environment);
+    let optimal_cost = insertion_ctx.get_total_cost().unwrap();
+    let tour = &mut insertion_ctx.solution.routes[0].route_mut().tour;
+    let activity = tour.get(1).unwrap().deep_copy();
+    tour.remove_activity_at(1);
+    tour.insert_at(activity, 4);
+    insertion_ctx.restore();
+    let actor = insertion_ctx.solution.routes[0].route().actor.clone();
+    assert!(insertion_ctx.get_total_cost().unwrap() > optimal_cost);
+
+    let result = sequence_route_exactly(&insertion_ctx, &actor, DEFAULT_EXACT_MAX_JOBS, 1000).expect("no result");
+
+    let ids = get_customer_ids_from_routes(&result);
+    assert_eq!(ids.len(), 1);
+    assert_eq!(ids[0].len(), 6);
+    assert_eq!(result.get_total_cost().unwrap(), optimal_cost);
+    assert!(sequence_route_exactly(&result, &actor, DEFAULT_EXACT_MAX_JOBS, 1000).is_none());
+}
+
+#[test]
+fn can_skip_route_with_too_many_jobs() {
+    let environment = Arc::new(Environment::default());
+    let (problem, solution) = generate_matrix_routes_with_defaults(6, 1, false);
+    let insertion_ctx = InsertionContext::new_from_solution(Arc::new(problem), (solution, None), environment);
+    let actor = insertion_ctx.solution.routes[0].route().actor.clone();
+
+    assert!(sequence_route_exactly(&insertion_ctx, &actor, 5, 1000).is_none());
+}
@@ -0,0 +1,125 @@
+use super::*;
+use crate::helpers::solver::{create_default_refinement_ctx, generate_matrix_routes_with_defaults};
+
+fn create_builder() -> RuinRecreateBuilder {
+    let (problem, _) = generate_matrix_routes_with_defaults(5, 4, false);
+
+    RuinRecreateBuilder::new(Arc::new(problem), Arc::new(Environment::default()))
+}
+
+#[test]
+fn can_create_builder_with_default_methods() {
+    let builder = create_builder();
+
+    assert_eq!(builder.ruin_names().count(), 9);
+    assert_eq!(builder.recreate_names().count(), 11);
+    assert!(builder.ruin_names().any(|name| name == "worst-job"));
+    assert!(builder.recreate_names().any(|name| name == "regret"));
+}
+
+#[test]
+fn can_replace_and_add_methods() {
+    let builder = create_builder();
+    let problem = builder.problem.clone();
+    let random = Arc::new(Environment::default()).random.clone();
+    let limits = RemovalLimits::new(problem.as_ref());
+
+    let builder = builder
+        .with_ruin("neighbour", Arc::new(NeighbourRemoval::new(limits.clone())), 20)
+        .with_ruin("custom", Arc::new(RandomJobRemoval::new(limits)), 1)
+        .with_recreate("regret", Arc::new(RecreateWithRegret::new(2, random).with_max_k(4)), 30);
+
+    assert_eq!(builder.ruin_names().count(), 10);
+    assert_eq!(builder.recreate_names().count(), 11);
+    assert_eq!(builder.ruins.iter().find(|(name, _, _)| name == "neighbour").map(|(_, _, weight)| *weight), Some(20));
+}
+
+#[test]
+fn can_disable_methods_with_zero_weight() {
+    let builder = create_builder().set_ruin_weight("worst-job", 0).unwrap();
+
+    assert_eq!(builder.ruins.iter().find(|(name, _, _)| name == "worst-job").map(|(_, _, weight)| *weight), Some(0));
+    assert!(builder.build().is_ok());
+}
+
+#[test]
+fn can_return_error_for_unknown_method() {
+    assert!(create_builder().set_ruin_weight("unknown", 1).is_err());
+    assert!(create_builder().set_recreate_weight("unknown", 1).is_err());
+}
+
+#[test]
+fn can_return_error_when_all_methods_are_disabled() {
+    let (problem, _) = generate_matrix_routes_with_defaults(5, 4, false);
+    let problem = Arc::new(problem);
+    let limits = RemovalLimits::new(problem.as_ref());
+
+    let ruin_only =
+        RuinRecreateBuilder::empty(problem.clone()).with_ruin("random-job", Arc::new(RandomJobRemoval::new(limits)), 1);
+    let builder = create_builder();
+    let names = builder.recreate_names().map(|name| name.to_string()).collect::<Vec<_>>();
+    let disabled = names.iter().try_fold(builder, |builder, name| builder.set_recreate_weight(name, 0)).unwrap();
+
+    assert!(ruin_only.build().is_err());
+    assert!(disabled.build().is_err());
+}
+
+#[test]
+fn can_use_built_operator_in_static_heuristic() {
+    let (problem, solution) = generate_matrix_routes_with_defaults(5, 4, false);
+    let problem = Arc::new(problem);
+    let environment = Arc::new(Environment::default());
+    let insertion_ctx = InsertionContext::new_from_solution(problem.clone(), (solution, None), environment.clone());
+    let ruin_recreate = RuinRecreateBuilder::new(problem.clone(), environment.clone())
+        .set_ruin_weight("adjusted-string", 0)
+        .unwrap()
+        .build()
+        .unwrap();
+
+    let result = ruin_recreate.search(&create_default_refinement_ctx(problem.clone()), &insertion_ctx);
+    let _ = get_static_heuristic_with_ruin_recreate(problem, environment, ruin_recreate);
+
+    assert!(result.solution.unassigned.is_empty());
+}
+
+#[test]
+fn can_create_warm_restart_heuristic() {
+    let (problem, _) = generate_matrix_routes_with_defaults(5, 4, false);
+    let problem = Arc::new(problem);
+    let environment = Arc::new(Environment::default());
+    let inner = Box::new(get_static_heuristic(problem.clone(), environment.clone()));
+
+    let heuristic = get_warm_restart_heuristic(problem, environment, inner, 10, 5);
+
+    assert_eq!(heuristic.restarts(), 0);
+}
+
+#[test]
+fn can_build_initial_solution_when_pre_processing_exceeds_initial_quota() {
+    struct SlowProcessing;
+
+    impl HeuristicContextProcessing for SlowProcessing {
+        type Context = RefinementContext;
+        type Objective = GoalContext;
+        type Solution = InsertionContext;
+
+        fn pre_process(&self, context: Self::Context) -> Self::Context {
+            std::thread::sleep(std::time::Duration::from_millis(300));
+            context
+        }
+    }
+
+    let (problem, _) = generate_matrix_routes_with_defaults(10, 10, false);
+    let problem = Arc::new(problem);
+    let config = VrpConfigBuilder::new(problem.clone())
+        .prebuild()
+        .unwrap()
+        .with_max_time(Some(1))
+        .with_processing(ProcessingConfig { context: vec![Box::new(SlowProcessing)], solution: vec![] })
+        .build()
+        .unwrap();
+
+    let solution = Solver::new(problem, config).solve().expect("no solution");
+
+    assert!(!solution.routes.is_empty());
+}
@@ -0,0 +1,72 @@
+use super::*;
+use crate::construction::heuristics::UnassignmentInfo;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::*;
+use crate::helpers::solver::generate_matrix_routes_with_defaults;
+use crate::models::common::Demand;
+use crate::solver::GreedyPopulation;
+use rosomaxa::prelude::*;
+
+fn create_insertion_ctx(capacities: &[i32], jobs: &[(i32, Float)]) -> InsertionContext {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(
+            capacities
+                .iter()
+                .enumerate()
+                .map(|(idx, &capacity)| TestVehicleBuilder::default().id(&format!("v{idx}")).capacity(capacity).build())
+                .collect(),
+        )
+        .build();
+    let jobs = jobs
+        .iter()
+        .map(|&(demand, duration)| {
+            let job =
+                TestSingleBuilder::default().demand(Demand::delivery(demand)).duration(duration).build_as_job_ref();
+            (job, UnassignmentInfo::Unknown)
+        })
+        .collect();
+
+    TestInsertionContextBuilder::default().with_fleet(Arc::new(fleet)).with_unassigned(jobs).build()
+}
+
+parameterized_test! {can_estimate_fleet_lower_bound, (capacities, jobs, expected), {
+    can_estimate_fleet_lower_bound_impl(capacities, jobs, expected);
+}}
+
+can_estimate_fleet_lower_bound! {
+    case01_capacity: (&[10, 10, 10], &[(6, 0.), (6, 0.), (6, 0.)], 2),
+    case02_heterogeneous_capacity: (&[5, 20, 5], &[(10, 0.), (10, 0.)], 1),
+    case03_not_enough_capacity: (&[5, 5], &[(10, 0.), (10, 0.)], 2),
+    case04_duration: (&[100, 100, 100], &[(1, 600.), (1, 600.), (1, 600.)], 2),
+    case05_no_jobs: (&[10], &[], 0),
+}
+
+fn can_estimate_fleet_lower_bound_impl(capacities: &[i32], jobs: &[(i32, Float)], expected: usize) {
+    let insertion_ctx = create_insertion_ctx(capacities, jobs);
+
+    let result = get_fleet_lower_bound(&insertion_ctx);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_minimize_fleet_in_pre_process() {
+    let environment = Arc::new(Environment::default());
+    let (problem, _) = generate_matrix_routes_with_defaults(5, 7, false);
+    let problem = Arc::new(problem);
+    let population = Box::new(GreedyPopulation::new(problem.goal.clone(), 1, None));
+    let telemetry_mode = TelemetryMode::OnlyMetrics { track_population: 1000 };
+    let refinement_ctx = RefinementContext::new(problem, population, telemetry_mode, environment);
+
+    let refinement_ctx = FleetMinimization::new(20).pre_process(refinement_ctx);
+
+    let routes = refinement_ctx.ranked().next().expect("no solution").solution.routes.len();
+    let (_, metrics) = refinement_ctx.on_result().expect("cannot get result");
+    let properties = metrics.expect("no metrics").properties;
+    assert_eq!(
+        properties,
+        vec![(FLEET_LOWER_BOUND_PROPERTY.to_string(), 1.), (FLEET_SIZE_PROPERTY.to_string(), routes as Float)]
+    );
+    assert!(routes < 7);
+}
@@ -0,0 +1,66 @@
+use super::*;
+use crate::helpers::solver::generate_matrix_routes_with_defaults;
+use crate::solver::GreedyPopulation;
+use rosomaxa::prelude::*;
+use std::sync::Arc;
+
+fn create_get_value(matrix: Vec<Vec<Float>>) -> impl Fn(usize, usize) -> Float {
+    move |from, to| matrix[from][to]
+}
+
+parameterized_test! {can_estimate_travel_bounds, (matrix, expected_tree, expected_assignment), {
+    can_estimate_travel_bounds_impl(matrix, expected_tree, expected_assignment);
+}}
+
+can_estimate_travel_bounds! {
+    case01_line: (vec![vec![0., 1., 2.], vec![1., 0., 1.], vec![2., 1., 0.]], 2., 2.),
+    case02_asymmetric: (vec![vec![0., 5., 9.], vec![1., 0., 7.], vec![9., 2., 0.]], 3., 9.),
+    case03_single_job: (vec![vec![0., 3.], vec![4., 0.]], 3., 3.),
+}
+
+fn can_estimate_travel_bounds_impl(matrix: Vec<Vec<Float>>, expected_tree: Float, expected_assignment: Float) {
+    let size = matrix.len();
+    let get_value = create_get_value(matrix);
+
+    assert_eq!(get_spanning_tree_bound(size, &get_value), expected_tree);
+    assert_eq!(get_assignment_bound(size, &get_value), expected_assignment);
+}
+
+parameterized_test! {can_estimate_cost_lower_bound_not_greater_than_optimal, (rows, cols, is_open_vrp), {
+    can_estimate_cost_lower_bound_not_greater_than_optimal_impl(rows, cols, is_open_vrp);
+}}
+
+can_estimate_cost_lower_bound_not_greater_than_optimal! {
+    case01_closed_vrp: (3, 2, false),
+    case02_open_vrp: (4, 2, true),
+    case03_single_route: (5, 1, false),
+}
+
+fn can_estimate_cost_lower_bound_not_greater_than_optimal_impl(rows: usize, cols: usize, is_open_vrp: bool) {
+    let environment = Arc::new(Environment::default());
+    let (problem, solution) = generate_matrix_routes_with_defaults(rows, cols, is_open_vrp);
+    let optimal = InsertionContext::new_from_solution(Arc::new(problem), (solution, None), environment);
+    let optimal_cost = optimal.get_total_cost().unwrap();
+
+    let lower_bound = get_cost_lower_bound(&optimal);
+
+    assert!(lower_bound > 0.);
+    assert!(lower_bound <= optimal_cost, "lower bound {lower_bound} is greater than optimal cost {optimal_cost}");
+}
+
+#[test]
+fn can_report_cost_lower_bound_in_pre_process() {
+    let environment = Arc::new(Environment::default());
+    let (problem, solution) = generate_matrix_routes_with_defaults(3, 2, false);
+    let problem = Arc::new(problem);
+    let optimal = InsertionContext::new_from_solution(problem.clone(), (solution, None), environment.clone());
+    let population = Box::new(GreedyPopulation::new(problem.goal.clone(), 1, None));
+    let telemetry_mode = TelemetryMode::OnlyMetrics { track_population: 1000 };
+    let refinement_ctx = RefinementContext::new(problem, population, telemetry_mode, environment);
+
+    let refinement_ctx = CostLowerBound::default().pre_process(refinement_ctx);
+
+    let (_, metrics) = refinement_ctx.on_result().expect("cannot get result");
+    let properties = metrics.expect("no metrics").properties;
+    assert_eq!(properties, vec![(COST_LOWER_BOUND_PROPERTY.to_string(), get_cost_lower_bound(&optimal))]);
+}
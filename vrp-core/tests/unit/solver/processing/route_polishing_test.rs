@@ -0,0 +1,92 @@
+use super::*;
+use crate::helpers::models::domain::get_customer_ids_from_routes;
+use crate::helpers::models::solution::*;
+use crate::helpers::solver::generate_matrix_routes_with_defaults;
+use crate::models::common::{Cost, Distance, Duration, Location, Profile};
+use crate::models::problem::TravelTime;
+use crate::models::solution::Route;
+use rosomaxa::prelude::{Environment, HeuristicObjective};
+
+struct LineTransport;
+
+impl TransportCost for LineTransport {
+    fn distance_approx(&self, _: &Profile, from: Location, to: Location) -> Distance {
+        (from as Cost - to as Cost).abs()
+    }
+
+    fn distance(&self, _: &Route, _: Location, _: Location, _: TravelTime) -> Distance {
+        unreachable!()
+    }
+
+    fn duration(&self, _: &Route, _: Location, _: Location, _: TravelTime) -> Duration {
+        unreachable!()
+    }
+
+    fn duration_approx(&self, _: &Profile, _: Location, _: Location) -> Duration {
+        unreachable!()
+    }
+
+    fn size(&self) -> usize {
+        unreachable!()
+    }
+}
+
+fn create_shuffled_insertion_ctx(order: &[usize]) -> InsertionContext {
+    let (problem, solution) = generate_matrix_routes_with_defaults(order.len(), 1, true);
+    let mut insertion_ctx =
+        InsertionContext::new_from_solution(Arc::new(problem), (solution, None), Arc::new(Environment::default()));
+
+    let route_ctx = insertion_ctx.solution.routes.first_mut().unwrap();
+    let activities = route_ctx.route_mut().tour.activities_mut();
+    let jobs = activities.drain(1..).collect::<Vec<_>>();
+    activities.extend(order.iter().map(|&idx| jobs[idx].deep_copy()));
+    insertion_ctx.restore();
+
+    insertion_ctx
+}
+
+parameterized_test! {can_optimize_route_exactly, (locations, end, expected), {
+    can_optimize_route_exactly_impl(locations, end, expected);
+}}
+
+can_optimize_route_exactly! {
+    case01_fixed_end: (&[3, 1, 4, 2], Some(5), vec![0, 1, 2, 3, 4, 5]),
+    case02_fixed_end_already_optimal: (&[1, 2, 3], Some(4), vec![0, 1, 2, 3, 4]),
+    case03_open_route: (&[3, 1, 4, 2], None, vec![0, 1, 2, 3, 4]),
+    case04_open_route_single_job: (&[1], None, vec![0, 1]),
+}
+
+fn can_optimize_route_exactly_impl(locations: &[usize], end: Option<Location>, expected: Vec<Location>) {
+    let mut route = RouteBuilder::default()
+        .with_start(ActivityBuilder::with_location(0).job(None).build())
+        .add_activities(locations.iter().map(|&i| ActivityBuilder::with_location(i).build()))
+        .build();
+    if let Some(end) = end {
+        route.tour.set_end(ActivityBuilder::with_location(end).job(None).build());
+    }
+    let mut route_ctx = RouteContextBuilder::default().with_route(route).build();
+
+    optimize_route_exactly(&mut route_ctx, &LineTransport);
+
+    let result = route_ctx.route().tour.all_activities().map(|a| a.place.location).collect::<Vec<_>>();
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_polish_shuffled_route() {
+    let insertion_ctx = create_shuffled_insertion_ctx(&[3, 0, 5, 1, 4, 2]);
+
+    let result = RoutePolishing::default().post_process(insertion_ctx);
+
+    assert_eq!(get_customer_ids_from_routes(&result), vec![vec!["c0", "c1", "c2", "c3", "c4", "c5"]]);
+}
+
+#[test]
+fn can_keep_solution_when_no_improvement_with_heuristic_optimization() {
+    let insertion_ctx = create_shuffled_insertion_ctx(&[3, 0, 5, 1, 4, 2]);
+    let original = insertion_ctx.deep_copy();
+
+    let result = RoutePolishing::new(2).post_process(insertion_ctx);
+
+    assert_ne!(result.problem.goal.total_order(&result, &original), Ordering::Greater);
+}
@@ -0,0 +1,115 @@
+use super::*;
+use crate::helpers::models::solution::test_actor;
+use crate::helpers::solver::generate_matrix_routes_with_defaults;
+use crate::solver::GreedyPopulation;
+use rosomaxa::utils::Timer;
+
+fn create_columns(columns: Vec<(usize, Vec<usize>, Float)>) -> Vec<Column> {
+    columns
+        .into_iter()
+        .map(|(actor_idx, jobs, cost)| Column { route_ctx: RouteContext::new(test_actor()), actor_idx, jobs, cost })
+        .collect()
+}
+
+fn create_refinement_ctx(problem: Arc<Problem>, environment: Arc<Environment>) -> RefinementContext {
+    let population = Box::new(GreedyPopulation::new(problem.goal.clone(), 1, None));
+
+    RefinementContext::new(problem, population, TelemetryMode::None, environment)
+}
+
+/// Swaps the first two jobs of the route, so the route becomes more expensive.
+fn create_scrambled_solution(insertion_ctx: &InsertionContext, route_idx: usize) -> InsertionContext {
+    let mut insertion_ctx = insertion_ctx.deep_copy();
+    let tour = &mut insertion_ctx.solution.routes[route_idx].route_mut().tour;
+    let activity = tour.get(1).unwrap().deep_copy();
+    tour.remove_activity_at(1);
+    tour.insert_at(activity, 2);
+    insertion_ctx.restore();
+
+    insertion_ctx
+}
+
+parameterized_test! {can_solve_set_partitioning, (columns, initial, expected), {
+    can_solve_set_partitioning_impl(columns, initial, expected);
+}}
+
+can_solve_set_partitioning! {
+    case01_keep_optimal: (
+        vec![(0, vec![0, 1], 10.), (1, vec![2, 3], 10.), (0, vec![0], 8.), (1, vec![1, 2, 3], 15.)],
+        vec![0, 1], vec![0, 1],
+    ),
+    case02_recombine_cheaper: (
+        vec![(0, vec![0, 1], 12.), (1, vec![2, 3], 12.), (0, vec![0, 1], 10.), (1, vec![2, 3], 9.)],
+        vec![0, 1], vec![2, 3],
+    ),
+    case03_use_less_actors: (
+        vec![(0, vec![0, 1], 10.), (1, vec![2, 3], 10.), (0, vec![0, 1, 2, 3], 15.)],
+        vec![0, 1], vec![2],
+    ),
+    case04_respect_actor_usage: (
+        vec![(0, vec![0, 1], 5.), (0, vec![2, 3], 5.), (1, vec![0, 1, 2, 3], 30.)],
+        vec![2], vec![2],
+    ),
+}
+
+fn can_solve_set_partitioning_impl(
+    columns: Vec<(usize, Vec<usize>, Float)>,
+    initial: Vec<usize>,
+    expected: Vec<usize>,
+) {
+    let columns = create_columns(columns);
+
+    let result = solve_set_partitioning(&columns, initial, 100);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_add_routes_to_pool_on_generation() {
+    let environment = Arc::new(Environment::default());
+    let (problem, solution) = generate_matrix_routes_with_defaults(3, 2, false);
+    let problem = Arc::new(problem);
+    let insertion_ctx = InsertionContext::new_from_solution(problem.clone(), (solution, None), environment.clone());
+    let scrambled = create_scrambled_solution(&insertion_ctx, 0);
+    let set_partitioning = SetPartitioning::default();
+
+    let mut refinement_ctx = set_partitioning.pre_process(create_refinement_ctx(problem, environment));
+    refinement_ctx.on_generation(vec![insertion_ctx, scrambled], 0., Timer::start());
+
+    assert_eq!(set_partitioning.pool.size(), 2);
+}
+
+#[test]
+fn can_recombine_routes_from_pool() {
+    let environment = Arc::new(Environment::default());
+    let (problem, solution) = generate_matrix_routes_with_defaults(4, 2, false);
+    let problem = Arc::new(problem);
+    let optimal = InsertionContext::new_from_solution(problem.clone(), (solution, None), environment.clone());
+    let optimal_cost = optimal.get_total_cost().unwrap();
+    let first = create_scrambled_solution(&optimal, 0);
+    let second = create_scrambled_solution(&optimal, 1);
+    assert!(first.get_total_cost().unwrap() > optimal_cost);
+    assert!(second.get_total_cost().unwrap() > optimal_cost);
+    let set_partitioning = SetPartitioning::default();
+    let mut refinement_ctx = set_partitioning.pre_process(create_refinement_ctx(problem, environment));
+    refinement_ctx.on_generation(vec![second], 0., Timer::start());
+
+    let result = set_partitioning.post_process(first);
+
+    assert!(result.solution.unassigned.is_empty());
+    assert_eq!(result.solution.routes.len(), 2);
+    assert_eq!(result.get_total_cost().unwrap(), optimal_cost);
+}
+
+#[test]
+fn can_keep_solution_without_pool() {
+    let environment = Arc::new(Environment::default());
+    let (problem, solution) = generate_matrix_routes_with_defaults(4, 2, false);
+    let insertion_ctx = InsertionContext::new_from_solution(Arc::new(problem), (solution, None), environment);
+    let scrambled = create_scrambled_solution(&insertion_ctx, 0);
+    let scrambled_cost = scrambled.get_total_cost().unwrap();
+
+    let result = SetPartitioning::default().post_process(scrambled);
+
+    assert_eq!(result.get_total_cost().unwrap(), scrambled_cost);
+}
@@ -2,7 +2,7 @@ use crate::construction::heuristics::{InsertionContext, UnassignmentInfo};
 use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
 use crate::helpers::models::domain::{ProblemBuilder, TestGoalContextBuilder};
 use crate::helpers::models::problem::*;
-use crate::helpers::models::solution::{RouteBuilder, RouteContextBuilder};
+use crate::helpers::models::solution::{ActivityBuilder, RouteBuilder, RouteContextBuilder};
 use crate::models::ViolationCode;
 use crate::models::common::TimeWindow;
 use crate::models::problem::{Job, JobIdDimension, VehicleIdDimension};
@@ -81,7 +81,7 @@ fn can_combine_vehicle_details_impl(
             UnassignmentInfo::Detailed(details) => {
                 let details = details
                     .iter()
-                    .map(|(actor, code)| (actor.vehicle.dimens.get_vehicle_id().unwrap().as_str(), *code))
+                    .map(|detail| (detail.actor.vehicle.dimens.get_vehicle_id().unwrap().as_str(), detail.code))
                     .collect::<Vec<_>>();
                 assert_eq!(details, expected_details);
             }
@@ -119,3 +119,29 @@ fn can_handle_assignable_job_impl(code: UnassignmentInfo) {
         _ => unreachable!(),
     }
 }
+
+#[test]
+fn can_detect_time_of_failed_legs() {
+    let mut insertion_ctx = create_test_insertion_ctx(vec![(create_early_delivery("job1"), UnassignmentInfo::Unknown)]);
+    let job = TestSingleBuilder::default().id("job2").location(Some(5)).build_shared();
+    insertion_ctx.solution.routes[0]
+        .route_mut()
+        .tour
+        .insert_last(ActivityBuilder::with_location(5).job(Some(job)).build());
+    insertion_ctx.problem.goal.accept_solution_state(&mut insertion_ctx.solution);
+
+    let insertion_ctx = UnassignmentReason::default().post_process(insertion_ctx);
+
+    let details = match insertion_ctx.solution.unassigned.into_values().next() {
+        Some(UnassignmentInfo::Detailed(details)) => details,
+        _ => unreachable!(),
+    };
+    let times = details
+        .iter()
+        .map(|detail| {
+            let vehicle_id = detail.actor.vehicle.dimens.get_vehicle_id().unwrap().clone();
+            (vehicle_id, detail.time.as_ref().map(|time| (time.start, time.end)))
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(times, vec![("v1".to_string(), Some((0., 10.))), ("v2".to_string(), Some((0., 0.)))]);
+}
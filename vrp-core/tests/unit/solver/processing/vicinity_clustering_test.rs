@@ -6,7 +6,8 @@ use crate::helpers::models::domain::*;
 use crate::helpers::models::problem::*;
 use crate::helpers::models::solution::*;
 use crate::helpers::solver::create_default_refinement_ctx;
-use crate::models::problem::{Job, JobIdDimension};
+use crate::models::common::{Dimensions, Location};
+use crate::models::problem::{Job, JobIdDimension, Single};
 use crate::models::solution::{Commute, CommuteInfo};
 use crate::prelude::ViolationCode;
 
@@ -40,6 +41,45 @@ fn create_problems(config: ClusterConfig, jobs: Vec<Job>) -> (Arc<Problem>, Arc<
     (orig_problem, new_refinement_cxt.problem)
 }
 
+fn create_insertion_ctx_with_cluster(
+    problem: Arc<Problem>,
+    cluster: Arc<Single>,
+    departure: Float,
+) -> InsertionContext {
+    let clustered_time = cluster.places.first().unwrap().clone().times.first().unwrap().to_time_window(0.);
+
+    InsertionContext {
+        problem: problem.clone(),
+        ..TestInsertionContextBuilder::default()
+            .with_routes(vec![
+                RouteContextBuilder::default()
+                    .with_route(
+                        RouteBuilder::default()
+                            .with_vehicle(problem.fleet.as_ref(), "v1")
+                            .with_start(ActivityBuilder::default().schedule(Schedule::new(0., 0.)).job(None).build())
+                            .with_end(ActivityBuilder::default().schedule(Schedule::new(0., 0.)).job(None).build())
+                            .add_activity(Activity {
+                                place: Place {
+                                    idx: 0,
+                                    location: 3,
+                                    duration: DEFAULT_JOB_DURATION * 3.,
+                                    time: clustered_time,
+                                },
+                                schedule: Schedule::new(3., departure),
+                                job: Some(cluster),
+                                commute: Some(Commute {
+                                    forward: CommuteInfo { location: 3, duration: 0., distance: 0. },
+                                    backward: CommuteInfo { location: 3, duration: 0., distance: 0. },
+                                }),
+                            })
+                            .build(),
+                    )
+                    .build(),
+            ])
+            .build()
+    }
+}
+
 #[test]
 fn can_create_problem_with_clusters_on_pre_process() {
     let (_, problem) = create_problems(create_cluster_config(), create_test_jobs());
@@ -77,37 +117,7 @@ fn can_unwrap_clusters_in_route_on_post_process_impl(
     let (_, new_problem) = create_problems(ClusterConfig { visiting, ..create_cluster_config() }, problem_jobs);
     let clustered_single =
         new_problem.jobs.all().iter().find(|job| get_job_id(job) == "job3").unwrap().to_single().clone();
-    let clustered_time = clustered_single.places.first().unwrap().clone().times.first().unwrap().to_time_window(0.);
-    let insertion_ctx = InsertionContext {
-        problem: new_problem.clone(),
-        ..TestInsertionContextBuilder::default()
-            .with_routes(vec![
-                RouteContextBuilder::default()
-                    .with_route(
-                        RouteBuilder::default()
-                            .with_vehicle(new_problem.fleet.as_ref(), "v1")
-                            .with_start(ActivityBuilder::default().schedule(Schedule::new(0., 0.)).job(None).build())
-                            .with_end(ActivityBuilder::default().schedule(Schedule::new(0., 0.)).job(None).build())
-                            .add_activity(Activity {
-                                place: Place {
-                                    idx: 0,
-                                    location: 3,
-                                    duration: DEFAULT_JOB_DURATION * 3.,
-                                    time: clustered_time,
-                                },
-                                schedule: Schedule::new(3., 3. + duration),
-                                job: Some(clustered_single),
-                                commute: Some(Commute {
-                                    forward: CommuteInfo { location: 3, duration: 0., distance: 0. },
-                                    backward: CommuteInfo { location: 3, duration: 0., distance: 0. },
-                                }),
-                            })
-                            .build(),
-                    )
-                    .build(),
-            ])
-            .build()
-    };
+    let insertion_ctx = create_insertion_ctx_with_cluster(new_problem, clustered_single, 3. + duration);
 
     let insertion_ctx = VicinityClustering::default().post_process(insertion_ctx);
 
@@ -148,3 +158,52 @@ fn can_unwrap_clusters_in_unassigned_on_post_process() {
 
     assert_eq!(insertion_ctx.solution.unassigned.len(), 4);
 }
+
+parameterized_test! {can_use_flexible_order_on_post_process, (flexible_order, expected), {
+    can_use_flexible_order_on_post_process_impl(flexible_order, expected);
+}}
+
+can_use_flexible_order_on_post_process! {
+    case_01_fixed: (false, vec![("job3", (3., 5.)), ("job1", (5., 9.)), ("job2", (9., 12.))]),
+    case_02_flexible: (true, vec![("job3", (3., 5.)), ("job2", (5., 8.)), ("job1", (8., 11.))]),
+}
+
+fn can_use_flexible_order_on_post_process_impl(flexible_order: bool, expected: Vec<(&str, (Float, Float))>) {
+    let solving = SolvingPolicy { flexible_order, ..SolvingPolicy::default() };
+    let config = ClusterConfig { visiting: VisitPolicy::OpenContinuation, solving, ..create_cluster_config() };
+    let (orig_problem, new_problem) = create_problems(config, create_test_jobs());
+    let create_info = |id: &str, from: Location, to: Location| {
+        let job = orig_problem.jobs.all().iter().find(|job| get_job_id(job) == id).unwrap().clone();
+        let duration = from.abs_diff(to) as Float;
+
+        ClusterInfo {
+            job,
+            service_time: 2.,
+            place_idx: 0,
+            commute: Commute {
+                forward: CommuteInfo { location: from, distance: duration, duration },
+                backward: CommuteInfo { location: to, distance: 0., duration: 0. },
+            },
+        }
+    };
+    let mut dimens = Dimensions::default();
+    dimens.set_cluster_info(vec![create_info("job3", 3, 3), create_info("job1", 3, 1), create_info("job2", 1, 2)]);
+    let cluster = TestSingleBuilder::default().dimens(dimens).id("job3").location(Some(3)).build_shared();
+    let insertion_ctx = create_insertion_ctx_with_cluster(new_problem, cluster, 12.);
+
+    let insertion_ctx = VicinityClustering::default().post_process(insertion_ctx);
+
+    let route_ctx = insertion_ctx.solution.routes.first().unwrap();
+    let result = route_ctx
+        .route()
+        .tour
+        .all_activities()
+        .skip(1)
+        .take(3)
+        .map(|activity| {
+            let id = activity.job.as_ref().unwrap().dimens.get_job_id().unwrap().as_str();
+            (id, (activity.schedule.arrival, activity.schedule.departure))
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(result, expected);
+}
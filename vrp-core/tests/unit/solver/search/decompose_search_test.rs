@@ -9,7 +9,12 @@ fn can_create_multiple_insertion_ctxs_without_unassigned() {
     let (problem, solution) = generate_matrix_routes_with_defaults(5, 7, false);
     let individual = InsertionContext::new_from_solution(Arc::new(problem), (solution, None), environment.clone());
 
-    let individuals = create_multiple_insertion_contexts(&individual, environment, (2, 2)).unwrap();
+    let individuals = create_multiple_insertion_contexts(
+        &individual,
+        environment,
+        &RouteGrouping::Proximity { max_routes_range: (2, 2) },
+    )
+    .unwrap();
 
     assert_eq!(individuals.len(), 4);
     assert_eq!(individuals[0].0.solution.routes.len(), 2);
@@ -27,7 +32,12 @@ fn can_create_multiple_insertion_ctxs_with_unassigned() {
     solution.routes.remove(0);
     let individual = InsertionContext::new_from_solution(Arc::new(problem), (solution, None), environment.clone());
 
-    let individuals = create_multiple_insertion_contexts(&individual, environment, (2, 2)).unwrap();
+    let individuals = create_multiple_insertion_contexts(
+        &individual,
+        environment,
+        &RouteGrouping::Proximity { max_routes_range: (2, 2) },
+    )
+    .unwrap();
 
     assert_eq!(individuals.len(), 4);
 
@@ -68,3 +78,51 @@ fn can_perform_search() {
             + solution.unassigned.len();
     assert_eq!(total_jobs, 35);
 }
+
+#[test]
+fn can_group_routes_by_clusters_into_partitions() {
+    let environment = Arc::new(Environment::default());
+    let (problem, solution) = generate_matrix_routes_with_defaults(5, 7, false);
+    let individual = InsertionContext::new_from_solution(Arc::new(problem), (solution, None), environment.clone());
+
+    let groups = group_routes_by_clusters(&individual, environment.as_ref(), (3, 3));
+
+    assert_eq!(groups.len(), 3);
+    assert!(groups.iter().all(|group| !group.is_empty()));
+    assert_eq!(groups.iter().flatten().collect::<HashSet<_>>().len(), 7);
+    assert_eq!(groups.iter().map(|group| group.len()).sum::<usize>(), 7);
+}
+
+parameterized_test! {can_perform_geographic_search, exact_max_jobs, {
+    can_perform_geographic_search_impl(exact_max_jobs);
+}}
+
+can_perform_geographic_search! {
+    case01_without_exact_polishing: None,
+    case02_with_exact_polishing: Some(5),
+}
+
+fn can_perform_geographic_search_impl(exact_max_jobs: Option<usize>) {
+    let environment = Arc::new(Environment::default());
+    let (problem, solution) = generate_matrix_routes_with_defaults(5, 7, false);
+    let problem = Arc::new(problem);
+    let population = Box::new(GreedyPopulation::new(problem.goal.clone(), 1, None));
+
+    let refinement_ctx = RefinementContext::new(problem.clone(), population, TelemetryMode::None, environment.clone());
+    let insertion_ctx = InsertionContext::new_from_solution(problem.clone(), (solution, None), environment.clone());
+    let inner_search = create_default_heuristic_operator(problem, environment);
+    let decompose_search = DecomposeSearch::new_geographic(inner_search, (2, 4), 10);
+    let decompose_search = match exact_max_jobs {
+        Some(max_jobs) => decompose_search.with_exact_polishing(max_jobs),
+        None => decompose_search,
+    };
+
+    let result = decompose_search.search(&refinement_ctx, &insertion_ctx);
+
+    let solution = &result.solution;
+    assert!(!solution.routes.is_empty());
+    let total_jobs =
+        solution.routes.iter().flat_map(|route_ctx| route_ctx.route().tour.jobs()).collect::<HashSet<_>>().len()
+            + solution.unassigned.len();
+    assert_eq!(total_jobs, 35);
+}
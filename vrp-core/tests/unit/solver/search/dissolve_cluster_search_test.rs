@@ -0,0 +1,88 @@
+use super::*;
+use crate::construction::clustering::vicinity::{ClusterConfig, SolvingPolicy};
+use crate::helpers::construction::clustering::vicinity::*;
+use crate::helpers::models::domain::ProblemBuilder;
+use crate::helpers::models::problem::*;
+use crate::helpers::solver::create_default_refinement_ctx;
+use crate::models::Problem;
+use crate::models::problem::{Job, JobIdDimension};
+use crate::prelude::ViolationCode;
+use crate::solver::processing::{ClusterConfigExtraProperty, VicinityClustering};
+use rosomaxa::prelude::HeuristicContextProcessing;
+use std::sync::Arc;
+
+fn create_clustered_refinement_ctx() -> RefinementContext {
+    let jobs = vec![
+        TestSingleBuilder::default().id("job1").location(Some(1)).duration(2.).build_as_job_ref(),
+        TestSingleBuilder::default().id("job2").location(Some(2)).duration(2.).build_as_job_ref(),
+        TestSingleBuilder::default().id("job3").location(Some(3)).duration(2.).build_as_job_ref(),
+        TestSingleBuilder::default().id("job4_outlier").location(Some(20)).duration(2.).build_as_job_ref(),
+    ];
+    let solving = SolvingPolicy { dissolve_unassigned: true, ..SolvingPolicy::default() };
+    let config = ClusterConfig { solving, ..create_cluster_config() };
+
+    let problem =
+        ProblemBuilder::default().with_goal(create_goal_context_with_vicinity(vec![])).with_jobs(jobs).build();
+    let problem = Arc::new(Problem {
+        extras: Arc::new({
+            let mut extras = problem.extras.as_ref().clone();
+            extras.set_cluster_config(Arc::new(config));
+            extras
+        }),
+        ..problem
+    });
+
+    VicinityClustering::default().pre_process(create_default_refinement_ctx(problem))
+}
+
+fn get_job(problem: &Problem, id: &str) -> Job {
+    problem.jobs.all().iter().find(|job| job.dimens().get_job_id().unwrap() == id).unwrap().clone()
+}
+
+fn get_unassigned_ids(insertion_ctx: &InsertionContext) -> Vec<String> {
+    let mut unassigned = insertion_ctx
+        .solution
+        .unassigned
+        .keys()
+        .map(|job| job.dimens().get_job_id().unwrap().clone())
+        .collect::<Vec<_>>();
+    unassigned.sort();
+
+    unassigned
+}
+
+#[test]
+fn can_dissolve_unassigned_clusters() {
+    let refinement_ctx = create_clustered_refinement_ctx();
+    let problem = refinement_ctx.problem.clone();
+    let mut insertion_ctx = InsertionContext::new_empty(problem.clone(), refinement_ctx.environment.clone());
+    insertion_ctx.solution.unassigned = vec![
+        (get_job(problem.as_ref(), "job3"), UnassignmentInfo::Simple(ViolationCode(1))),
+        (get_job(problem.as_ref(), "job4_outlier"), UnassignmentInfo::Simple(ViolationCode(2))),
+    ]
+    .into_iter()
+    .collect();
+
+    let result = DissolveClusterSearch::default().search(&refinement_ctx, &insertion_ctx);
+
+    assert_eq!(get_unassigned_ids(&result), vec!["job4_outlier"]);
+    let assigned = result.solution.routes.iter().map(|route_ctx| route_ctx.route().tour.job_count()).sum::<usize>();
+    assert_eq!(assigned, 3);
+    assert_eq!(get_unassigned_ids(&insertion_ctx), vec!["job3", "job4_outlier"]);
+}
+
+#[test]
+fn can_keep_solution_without_unassigned_clusters() {
+    let refinement_ctx = create_clustered_refinement_ctx();
+    let problem = refinement_ctx.problem.clone();
+    let mut insertion_ctx = InsertionContext::new_empty(problem.clone(), refinement_ctx.environment.clone());
+    insertion_ctx
+        .solution
+        .unassigned
+        .insert(get_job(problem.as_ref(), "job4_outlier"), UnassignmentInfo::Simple(ViolationCode(2)));
+
+    let result = DissolveClusterSearch::default().search(&refinement_ctx, &insertion_ctx);
+
+    assert_eq!(get_unassigned_ids(&result), vec!["job4_outlier"]);
+    assert!(result.solution.routes.is_empty());
+}
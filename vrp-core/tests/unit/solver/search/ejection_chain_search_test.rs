@@ -0,0 +1,153 @@
+use super::*;
+use crate::construction::features::MinimizeUnassignedBuilder;
+use crate::helpers::models::domain::{get_customer_id, get_customer_ids_from_routes, get_customer_ids_from_unassigned};
+use crate::helpers::solver::{
+    create_default_refinement_ctx, generate_matrix_routes_with_defaults,
+    generate_matrix_routes_with_disallow_list_and_features,
+};
+use crate::models::*;
+use rosomaxa::prelude::Environment;
+use std::sync::Arc;
+
+fn create_insertion_ctx_with_unassigned(
+    (rows, cols): (usize, usize),
+    disallowed_pairs: Vec<(&str, &str)>,
+    job_ids: &[&str],
+) -> InsertionContext {
+    create_insertion_ctx(
+        generate_matrix_routes_with_disallow_list_and_features(
+            rows,
+            cols,
+            false,
+            disallowed_pairs,
+            vec![MinimizeUnassignedBuilder::new("min_unassigned").build().unwrap()],
+        ),
+        job_ids,
+    )
+}
+
+fn create_insertion_ctx_with_unassigned_and_features(
+    (rows, cols): (usize, usize),
+    features: Vec<Feature>,
+    job_ids: &[&str],
+) -> InsertionContext {
+    let features =
+        std::iter::once(MinimizeUnassignedBuilder::new("min_unassigned").build().unwrap()).chain(features).collect();
+
+    create_insertion_ctx(
+        generate_matrix_routes_with_disallow_list_and_features(rows, cols, false, vec![], features),
+        job_ids,
+    )
+}
+
+fn create_insertion_ctx((problem, solution): (Problem, Solution), job_ids: &[&str]) -> InsertionContext {
+    let environment = Arc::new(Environment::default());
+    let mut insertion_ctx = InsertionContext::new_from_solution(Arc::new(problem), (solution, None), environment);
+
+    insertion_ctx.solution.routes.iter_mut().for_each(|route_ctx| {
+        let jobs = route_ctx
+            .route()
+            .tour
+            .jobs()
+            .filter(|job| job_ids.contains(&get_customer_id(job).as_str()))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        jobs.iter().for_each(|job| {
+            route_ctx.route_mut().tour.remove(job);
+        });
+        insertion_ctx.solution.unassigned.extend(jobs.into_iter().map(|job| (job, UnassignmentInfo::Unknown)));
+    });
+    finalize_insertion_ctx(&mut insertion_ctx);
+
+    insertion_ctx
+}
+
+#[test]
+fn can_insert_unassigned_jobs_without_ejections() {
+    let insertion_ctx = create_insertion_ctx_with_unassigned((3, 2), vec![], &["c1"]);
+    let problem = insertion_ctx.problem.clone();
+
+    let result = EjectionChainSearch::default().search(&create_default_refinement_ctx(problem), &insertion_ctx);
+
+    assert!(result.solution.unassigned.is_empty());
+    assert!(result.solution.required.is_empty());
+    assert_eq!(result.solution.routes.iter().map(|route_ctx| route_ctx.route().tour.job_count()).sum::<usize>(), 6);
+}
+
+#[test]
+fn can_insert_unassigned_job_using_ejection() {
+    let disallowed_pairs = vec![("cX", "c0"), ("c0", "c2"), ("c2", "cX")];
+    let insertion_ctx = create_insertion_ctx_with_unassigned((3, 1), disallowed_pairs, &["c1"]);
+    let problem = insertion_ctx.problem.clone();
+    assert_eq!(get_customer_ids_from_unassigned(&insertion_ctx), vec!["c1"]);
+
+    let result = EjectionChainSearch::default().search(&create_default_refinement_ctx(problem), &insertion_ctx);
+
+    assert!(get_customer_ids_from_unassigned(&result).is_empty());
+    assert_eq!(get_customer_ids_from_routes(&result), vec![vec!["c0", "c1", "c2"]]);
+}
+
+/// Allows to insert the dependent job only into the route which already has the required one.
+struct DependencyConstraint {
+    dependent: String,
+    required: String,
+}
+
+impl FeatureConstraint for DependencyConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { route_ctx, job, .. } if get_customer_id(job) == self.dependent => {
+                let has_required = route_ctx.route().tour.jobs().any(|job| get_customer_id(job) == self.required);
+                if has_required { None } else { ConstraintViolation::fail(ViolationCode(8)) }
+            }
+            _ => None,
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+}
+
+#[test]
+fn can_retry_failed_job_after_other_jobs_are_inserted() {
+    let dependency = FeatureBuilder::default()
+        .with_name("dependency")
+        .with_constraint(DependencyConstraint { dependent: "c0".to_string(), required: "c1".to_string() })
+        .build()
+        .unwrap();
+    let insertion_ctx = create_insertion_ctx_with_unassigned_and_features((3, 1), vec![dependency], &["c0", "c1"]);
+    let problem = insertion_ctx.problem.clone();
+
+    let result = EjectionChainSearch::default().search(&create_default_refinement_ctx(problem), &insertion_ctx);
+
+    assert!(get_customer_ids_from_unassigned(&result).is_empty());
+    assert_eq!(result.solution.routes.iter().map(|route_ctx| route_ctx.route().tour.job_count()).sum::<usize>(), 3);
+}
+
+#[test]
+fn can_return_original_solution_when_no_improvement() {
+    let (problem, solution) = generate_matrix_routes_with_defaults(3, 2, false);
+    let problem = Arc::new(problem);
+    let insertion_ctx =
+        InsertionContext::new_from_solution(problem.clone(), (solution, None), Arc::new(Environment::default()));
+
+    let result = EjectionChainSearch::default().search(&create_default_refinement_ctx(problem), &insertion_ctx);
+
+    assert_eq!(get_customer_ids_from_routes(&result), get_customer_ids_from_routes(&insertion_ctx));
+}
+
+parameterized_test! {can_get_combinations, (size, max_size, expected), {
+    can_get_combinations_impl(size, max_size, expected);
+}}
+
+can_get_combinations! {
+    case01_single: (3, 1, vec![vec![0], vec![1], vec![2]]),
+    case02_pairs: (3, 2, vec![vec![0], vec![0, 1], vec![0, 2], vec![1], vec![1, 2], vec![2]]),
+    case03_empty: (0, 2, vec![]),
+}
+
+fn can_get_combinations_impl(size: usize, max_size: usize, expected: Vec<Vec<usize>>) {
+    assert_eq!(get_combinations(size, max_size), expected);
+}
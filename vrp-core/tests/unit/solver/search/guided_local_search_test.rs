@@ -0,0 +1,107 @@
+use super::*;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::TestTransportCost;
+use crate::helpers::models::solution::*;
+
+fn create_insertion_ctx(locations: &[Location]) -> InsertionContext {
+    let route_ctx = RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::default()
+                .with_start(ActivityBuilder::with_location(0).job(None).build())
+                .with_end(ActivityBuilder::with_location(0).job(None).build())
+                .add_activities(locations.iter().map(|&location| ActivityBuilder::with_location(location).build()))
+                .build(),
+        )
+        .build();
+
+    TestInsertionContextBuilder::default().with_routes(vec![route_ctx]).build()
+}
+
+#[test]
+fn can_penalize_arcs_with_max_utility() {
+    let penalties = ArcPenalties::new(0.5, 1.);
+    let insertion_ctx = create_insertion_ctx(&[10, 12]);
+
+    penalties.penalize(&insertion_ctx);
+
+    assert_eq!(penalties.get(12, 0), 4.);
+    assert_eq!(penalties.get(0, 10), 0.);
+    assert_eq!(penalties.get(10, 12), 0.);
+
+    penalties.penalize(&insertion_ctx);
+
+    // NOTE utility of penalized arc is reduced: 12 / 2 < 10 / 1
+    assert_eq!(penalties.get(12, 0), 4.);
+    assert_eq!(penalties.get(0, 10), 4.);
+}
+
+#[test]
+fn can_decay_penalties() {
+    let penalties = ArcPenalties::new(0.5, 0.5);
+    penalties.penalize(&create_insertion_ctx(&[10, 12]));
+    let empty_ctx = TestInsertionContextBuilder::default().build();
+
+    penalties.penalize(&empty_ctx);
+    assert_eq!(penalties.get(12, 0), 2.);
+
+    (0..10).for_each(|_| penalties.penalize(&empty_ctx));
+    assert_eq!(penalties.get(12, 0), 0.);
+}
+
+#[test]
+fn can_add_penalty_only_to_transport_cost() {
+    let penalties = Arc::new(ArcPenalties::new(0.5, 1.));
+    let insertion_ctx = create_insertion_ctx(&[10, 12]);
+    penalties.penalize(&insertion_ctx);
+    let transport = GuidedTransportCost::new(TestTransportCost::new_shared(), penalties);
+    let route = insertion_ctx.solution.routes[0].route();
+    let travel_time = TravelTime::Departure(0.);
+
+    assert_eq!(transport.distance(route, 12, 0, travel_time), 12.);
+    assert_eq!(transport.duration(route, 12, 0, travel_time), 12.);
+    assert_eq!(
+        transport.cost(route, 12, 0, travel_time) - TestTransportCost::default().cost(route, 12, 0, travel_time),
+        4. * (route.actor.driver.costs.per_distance + route.actor.vehicle.costs.per_distance)
+    );
+    assert_eq!(transport.cost(route, 0, 10, travel_time), TestTransportCost::default().cost(route, 0, 10, travel_time));
+}
+
+#[test]
+fn can_read_latest_penalties_from_different_threads() {
+    let penalties = Arc::new(ArcPenalties::new(0.5, 1.));
+    assert_eq!(penalties.get(12, 0), 0.);
+
+    penalties.penalize(&create_insertion_ctx(&[10, 12]));
+    let from_thread = {
+        let penalties = penalties.clone();
+        std::thread::spawn(move || penalties.get(12, 0)).join().unwrap()
+    };
+    assert_eq!(from_thread, 4.);
+
+    penalties.penalize(&create_insertion_ctx(&[10, 12]));
+    assert_eq!(penalties.get(0, 10), 4.);
+}
+
+#[test]
+fn can_keep_snapshots_of_different_penalties_separately() {
+    let penalties = ArcPenalties::new(0.5, 1.);
+    let other = ArcPenalties::new(1., 1.);
+
+    penalties.penalize(&create_insertion_ctx(&[10, 12]));
+    other.penalize(&create_insertion_ctx(&[10, 12]));
+
+    assert_eq!(penalties.get(12, 0), 4.);
+    assert_eq!(other.get(12, 0), 8.);
+    assert_eq!(penalties.get(12, 0), 4.);
+}
+
+#[test]
+fn can_clear_penalties() {
+    let penalties = ArcPenalties::new(0.5, 1.);
+    penalties.penalize(&create_insertion_ctx(&[10, 12]));
+    assert_eq!(penalties.get(12, 0), 4.);
+
+    penalties.clear();
+
+    assert_eq!(penalties.get(12, 0), 0.);
+}
@@ -0,0 +1,34 @@
+use super::*;
+use crate::helpers::models::domain::get_customer_ids_from_routes;
+use crate::helpers::solver::*;
+use crate::helpers::utils::create_test_environment_with_random;
+use crate::helpers::utils::random::FakeRandom;
+
+parameterized_test! {can_relocate_segment, (jobs_order, seed, expected), {
+    can_relocate_segment_impl(jobs_order, seed, expected);
+}}
+
+can_relocate_segment! {
+    case_01_single_job: (
+        vec![vec!["c0", "c1", "c2", "c5"], vec!["c3", "c4"], vec!["c6", "c7", "c8"]], (0, 4, 1),
+        vec![vec!["c0", "c1", "c2"], vec!["c3", "c4"], vec!["c5", "c6", "c7", "c8"]],
+    ),
+    case_02_two_jobs: (
+        vec![vec!["c0", "c1", "c2", "c4", "c5"], vec!["c3"], vec!["c6", "c7", "c8"]], (0, 4, 2),
+        vec![vec!["c0", "c1", "c2"], vec!["c3"], vec!["c4", "c5", "c6", "c7", "c8"]],
+    ),
+}
+
+fn can_relocate_segment_impl(jobs_order: Vec<Vec<&str>>, seed: (i32, i32, i32), expected: Vec<Vec<&str>>) {
+    let environment =
+        create_test_environment_with_random(Arc::new(FakeRandom::new(vec![seed.0, seed.1, seed.2], vec![])));
+    let (problem, solution) = generate_matrix_routes_with_defaults(3, 3, true);
+    let mut insertion_ctx = InsertionContext::new_from_solution(Arc::new(problem), (solution, None), environment);
+    rearrange_jobs_in_routes(&mut insertion_ctx, jobs_order.as_slice());
+
+    let result = ExchangeOrOpt::default()
+        .explore(&create_default_refinement_ctx(insertion_ctx.problem.clone()), &insertion_ctx)
+        .expect("cannot find new solution");
+
+    compare_with_ignore(get_customer_ids_from_routes(&result).as_slice(), expected.as_slice(), "");
+}
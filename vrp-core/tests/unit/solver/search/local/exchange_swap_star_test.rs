@@ -88,6 +88,30 @@ fn can_use_exchange_swap_star_impl(jobs_order: Vec<Vec<&str>>, expected: Vec<Vec
     compare_with_ignore(get_customer_ids_from_routes(&insertion_ctx).as_slice(), expected.as_slice(), "");
 }
 
+parameterized_test! { can_limit_explored_route_pairs, (route_pairs_threshold, expected), {
+    can_limit_explored_route_pairs_impl(route_pairs_threshold, expected);
+}}
+
+can_limit_explored_route_pairs! {
+    case_01_single_pair: (1, vec![vec!["c0", "c2", "c3"], vec!["c4", "c7", "c8"], vec!["c6", "c5", "c1"]]),
+    case_02_all_pairs: (3, vec![vec!["c0", "c1", "c2"], vec!["c6", "c7", "c8"], vec!["c3", "c4", "c5"]]),
+}
+
+fn can_limit_explored_route_pairs_impl(route_pairs_threshold: usize, expected: Vec<Vec<&str>>) {
+    let jobs_order = vec![vec!["c0", "c8", "c3"], vec!["c4", "c7", "c2"], vec!["c6", "c5", "c1"]];
+    let environment = create_test_environment_with_random(Arc::new(FakeRandom::new(vec![], vec![0.; 9])));
+    let (problem, solution) = generate_matrix_routes_with_defaults(3, 3, true);
+    let mut insertion_ctx =
+        InsertionContext::new_from_solution(Arc::new(problem), (solution, None), environment.clone());
+    rearrange_jobs_in_routes(&mut insertion_ctx, jobs_order.as_slice());
+
+    let insertion_ctx = ExchangeSwapStar::new_with_route_pairs(environment.random.clone(), route_pairs_threshold)
+        .explore(&create_default_refinement_ctx(insertion_ctx.problem.clone()), &insertion_ctx)
+        .expect("cannot find new solution");
+
+    compare_with_ignore(get_customer_ids_from_routes(&insertion_ctx).as_slice(), expected.as_slice(), "");
+}
+
 #[test]
 fn can_keep_locked_jobs_in_place() {
     let jobs_order = vec![vec!["c0", "c1", "c3"], vec!["c4", "c7", "c2"], vec!["c6", "c5", "c8"]];
@@ -249,8 +273,8 @@ fn can_find_top_results_impl(job_id: &str, disallowed_pairs: Vec<(&str, &str)>,
     let route_ctx = insertion_ctx.solution.routes.first().unwrap();
 
     let results = find_top_results(&search_ctx, route_ctx, job_ids.as_slice())
-        .iter()
-        .flat_map(|(_, results)| results.iter())
+        .values()
+        .flat_map(|results| results.iter())
         .map(|result| result.as_success().map(|success| success.activities.first().unwrap().1))
         .collect::<Vec<_>>();
 
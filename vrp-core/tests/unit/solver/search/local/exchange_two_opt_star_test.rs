@@ -0,0 +1,44 @@
+use super::*;
+use crate::helpers::models::domain::get_customer_ids_from_routes;
+use crate::helpers::solver::*;
+use crate::helpers::utils::create_test_environment_with_random;
+use crate::helpers::utils::random::FakeRandom;
+
+parameterized_test! {can_exchange_route_tails, (jobs_order, locked_ids, seed, expected), {
+    can_exchange_route_tails_impl(jobs_order, locked_ids, seed, expected);
+}}
+
+can_exchange_route_tails! {
+    case_01_improvement: (
+        vec![vec!["c0", "c1", "c5"], vec!["c3", "c4", "c2"], vec!["c6", "c7", "c8"]], vec![], (0, 2),
+        Some(vec![vec!["c0", "c1", "c2"], vec!["c3", "c4", "c5"], vec!["c6", "c7", "c8"]]),
+    ),
+    case_02_locked_tail: (
+        vec![vec!["c0", "c1", "c5"], vec!["c3", "c4", "c2"], vec!["c6", "c7", "c8"]], vec!["c2", "c5", "c8"], (0, 2),
+        None,
+    ),
+}
+
+fn can_exchange_route_tails_impl(
+    jobs_order: Vec<Vec<&str>>,
+    locked_ids: Vec<&str>,
+    seed: (i32, i32),
+    expected: Option<Vec<Vec<&str>>>,
+) {
+    let environment = create_test_environment_with_random(Arc::new(FakeRandom::new(vec![seed.0, seed.1], vec![])));
+    let (problem, solution) = generate_matrix_routes_with_defaults(3, 3, true);
+    let mut insertion_ctx = InsertionContext::new_from_solution(Arc::new(problem), (solution, None), environment);
+    rearrange_jobs_in_routes(&mut insertion_ctx, jobs_order.as_slice());
+    let insertion_ctx = promote_to_locked(insertion_ctx, locked_ids.as_slice());
+
+    let result = ExchangeTwoOptStar::default()
+        .explore(&create_default_refinement_ctx(insertion_ctx.problem.clone()), &insertion_ctx);
+
+    match (result, expected) {
+        (Some(result), Some(expected)) => {
+            compare_with_ignore(get_customer_ids_from_routes(&result).as_slice(), expected.as_slice(), "")
+        }
+        (None, None) => {}
+        (result, _) => panic!("unexpected result: {:?}", result.map(|ctx| get_customer_ids_from_routes(&ctx))),
+    }
+}
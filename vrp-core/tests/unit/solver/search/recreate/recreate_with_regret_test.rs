@@ -0,0 +1,84 @@
+use super::*;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::domain::{TestGoalContextBuilder, get_customer_id, test_random};
+use crate::helpers::models::problem::*;
+use crate::helpers::solver::{create_default_refinement_ctx, generate_matrix_routes_with_defaults};
+use crate::models::problem::{VehicleDetail, VehiclePlace};
+use crate::models::solution::Registry;
+use rosomaxa::prelude::Environment;
+
+parameterized_test! {can_insert_all_jobs, (min, max), {
+    can_insert_all_jobs_impl(min, max);
+}}
+
+can_insert_all_jobs! {
+    case01_regret_1: (1, 1),
+    case02_regret_2: (2, 2),
+    case03_regret_3: (3, 3),
+    case04_regret_range: (2, 4),
+}
+
+fn can_insert_all_jobs_impl(min: usize, max: usize) {
+    let (problem, _) = generate_matrix_routes_with_defaults(4, 3, false);
+    let problem = Arc::new(problem);
+    let environment = Arc::new(Environment::default());
+    let refinement_ctx = create_default_refinement_ctx(problem.clone());
+    let insertion_ctx = InsertionContext::new(problem.clone(), environment.clone());
+
+    let result =
+        RecreateWithRegret::new(min, environment.random.clone()).with_max_k(max).run(&refinement_ctx, insertion_ctx);
+
+    assert!(result.solution.required.is_empty());
+    assert!(result.solution.unassigned.is_empty());
+    assert_eq!(result.solution.routes.iter().map(|route_ctx| route_ctx.route().tour.job_count()).sum::<usize>(), 12);
+}
+
+parameterized_test! {can_select_job_with_max_regret, (k, expected), {
+    can_select_job_with_max_regret_impl(k, expected);
+}}
+
+can_select_job_with_max_regret! {
+    case01_regret_1_selects_cheapest: (1, ("job1", "v1")),
+    case02_regret_2_selects_max_regret: (2, ("job2", "v2")),
+}
+
+fn can_select_job_with_max_regret_impl(k: usize, expected: (&str, &str)) {
+    let create_vehicle = |id: &str, location: usize| {
+        let detail = test_vehicle_detail();
+        let start = detail.start.map(|start| VehiclePlace { location, ..start });
+        let end = detail.end.map(|end| VehiclePlace { location, ..end });
+
+        TestVehicleBuilder::default().id(id).details(vec![VehicleDetail { start, end }]).build()
+    };
+    let fleet = Arc::new(
+        FleetBuilder::default()
+            .add_driver(test_driver())
+            .add_vehicles(vec![create_vehicle("v1", 0), create_vehicle("v2", 3)])
+            .build(),
+    );
+    let insertion_ctx = TestInsertionContextBuilder::default()
+        .with_fleet(fleet.clone())
+        .with_goal(TestGoalContextBuilder::with_transport_feature().build())
+        .with_registry(Registry::new(fleet.as_ref(), test_random()))
+        .build();
+    let routes = insertion_ctx.solution.registry.next_route().collect::<Vec<_>>();
+    // NOTE job1 is cheap in both routes, job2 is expensive in both, but much more in the first one
+    let job1 = TestSingleBuilder::default().id("job1").location(Some(1)).build_as_job_ref();
+    let job2 = TestSingleBuilder::default().id("job2").location(Some(10)).build_as_job_ref();
+
+    let result = RegretInsertionEvaluator::new(k, k).evaluate_all(
+        &insertion_ctx,
+        &[&job1, &job2],
+        routes.as_slice(),
+        &LegSelection::Exhaustive,
+        &BestResultSelector::default(),
+    );
+
+    match result {
+        InsertionResult::Success(success) => {
+            assert_eq!(get_customer_id(&success.job), expected.0);
+            assert_eq!(get_vehicle_id(&success.actor.vehicle), expected.1);
+        }
+        InsertionResult::Failure(_) => unreachable!("job should be inserted"),
+    }
+}
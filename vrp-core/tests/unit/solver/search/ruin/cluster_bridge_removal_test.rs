@@ -0,0 +1,52 @@
+use super::*;
+use crate::helpers::construction::clustering::dbscan::create_test_distances;
+use crate::helpers::models::domain::{TestGoalContextBuilder, get_customer_id};
+use crate::helpers::models::problem::TestSingleBuilder;
+use crate::helpers::solver::*;
+use rosomaxa::prelude::Environment;
+
+fn create_insertion_ctx() -> InsertionContext {
+    let (problem, solution) = generate_matrix_routes(
+        8,
+        1,
+        false,
+        |_, _, _| TestGoalContextBuilder::with_transport_feature().build(),
+        |id, location| TestSingleBuilder::default().id(id).location(location).build_shared(),
+        |v| v,
+        |_| (vec![0.; 64], create_test_distances()),
+    );
+
+    InsertionContext::new_from_solution(Arc::new(problem), (solution, None), Arc::new(Environment::default()))
+}
+
+parameterized_test! {can_ruin_cluster_with_bridges, (limit, expected), {
+    can_ruin_cluster_with_bridges_impl(limit, expected);
+}}
+
+can_ruin_cluster_with_bridges! {
+    case_01_whole_cluster: (8, vec![
+        vec!["c0", "c1", "c2", "c3", "c4"],
+        vec!["c4", "c5", "c6", "c7"],
+    ]),
+    case_02_part_of_cluster: (2, vec![
+        vec!["c0", "c1"], vec!["c0", "c2"], vec!["c0", "c3"], vec!["c1", "c2"], vec!["c1", "c3"], vec!["c2", "c3"],
+        vec!["c5", "c6"], vec!["c5", "c7"], vec!["c6", "c7"],
+    ]),
+}
+
+fn can_ruin_cluster_with_bridges_impl(limit: usize, expected: Vec<Vec<&str>>) {
+    let limits = RemovalLimits { removed_activities_range: limit..limit, affected_routes_range: 8..8 };
+    let insertion_ctx = create_insertion_ctx();
+    let refinement_ctx = create_default_refinement_ctx(insertion_ctx.problem.clone());
+
+    let insertion_ctx =
+        ClusterBridgeRemoval::new(insertion_ctx.problem.clone(), limits).run(&refinement_ctx, insertion_ctx);
+
+    let mut removed = insertion_ctx.solution.required.iter().map(get_customer_id).collect::<Vec<_>>();
+    removed.sort();
+    assert!(expected.iter().any(|ids| *ids == removed), "unexpected removed jobs: {removed:?}");
+    assert_eq!(
+        insertion_ctx.solution.routes.iter().map(|route_ctx| route_ctx.route().tour.job_count()).sum::<usize>(),
+        8 - removed.len()
+    );
+}
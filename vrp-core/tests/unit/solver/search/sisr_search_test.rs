@@ -0,0 +1,40 @@
+use super::*;
+use crate::helpers::solver::{create_default_refinement_ctx, generate_matrix_routes_with_defaults};
+use rosomaxa::prelude::Environment;
+
+#[test]
+fn can_return_solution_which_is_not_worse() {
+    let environment = Arc::new(Environment::default());
+    let (problem, solution) = generate_matrix_routes_with_defaults(5, 4, false);
+    let problem = Arc::new(problem);
+    let insertion_ctx = InsertionContext::new_from_solution(problem.clone(), (solution, None), environment.clone());
+    let search = SisrSearch::new_with_defaults(problem.as_ref(), environment.random.clone());
+
+    let result = search.search(&create_default_refinement_ctx(problem.clone()), &insertion_ctx);
+
+    assert_ne!(problem.goal.total_order(&result, &insertion_ctx), Ordering::Greater);
+    assert!(result.solution.required.is_empty());
+}
+
+parameterized_test! {can_calculate_temperature, (iteration, expected), {
+    can_calculate_temperature_impl(iteration, expected);
+}}
+
+can_calculate_temperature! {
+    case01_initial: (0, 0.1),
+    case02_middle: (5, 0.01),
+    case03_last: (10, 0.001),
+}
+
+fn can_calculate_temperature_impl(iteration: usize, expected: Float) {
+    let environment = Environment::default();
+    let (problem, _) = generate_matrix_routes_with_defaults(5, 4, false);
+    let search = SisrSearch::new(
+        Arc::new(AdjustedStringRemoval::new_with_defaults(RemovalLimits::new(&problem))),
+        Arc::new(RecreateWithBlinks::new_with_defaults(environment.random.clone())),
+        10,
+        (0.1, 0.001),
+    );
+
+    assert!((search.get_temperature(iteration) - expected).abs() < 1E-9);
+}
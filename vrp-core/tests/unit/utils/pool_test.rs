@@ -0,0 +1,35 @@
+use super::*;
+
+#[test]
+fn can_take_and_release_allocations() {
+    let mut pool = AllocationPool::<Vec<usize>>::default();
+
+    assert!(pool.take().is_none());
+
+    pool.release(Vec::with_capacity(10));
+    let item = pool.take();
+
+    assert_eq!(pool.size(), 0);
+    assert!(item.is_some_and(|item| item.capacity() >= 10));
+}
+
+#[test]
+fn can_limit_amount_of_pooled_allocations() {
+    let mut pool = AllocationPool::<Vec<usize>>::default();
+
+    (0..MAX_POOLED_ALLOCATIONS + 10).for_each(|_| pool.release(Vec::default()));
+
+    assert_eq!(pool.size(), MAX_POOLED_ALLOCATIONS);
+}
+
+#[test]
+fn can_release_unused_allocations_on_next_generation() {
+    let mut pool = AllocationPool::<Vec<usize>>::default();
+    (0..20).for_each(|_| pool.release(Vec::default()));
+    pool.take();
+
+    next_pool_generation();
+    pool.release(Vec::default());
+
+    assert!(pool.size() <= MIN_POOLED_ALLOCATIONS + 1);
+}
@@ -0,0 +1,194 @@
+use super::*;
+use crate::construction::features::{CapacityFeatureBuilder, TransportFeatureBuilder, VehicleCapacityDimension};
+use crate::construction::heuristics::UnassignmentInfo;
+use crate::helpers::models::domain::{TestGoalContextBuilder, get_customer_id};
+use crate::helpers::models::problem::TestSingleBuilder;
+use crate::helpers::solver::{generate_matrix_routes, generate_matrix_routes_with_defaults};
+use crate::models::common::{Demand, SingleDimLoad, TimeWindow};
+use crate::models::{Lock, LockDetail, LockOrder, LockPosition};
+
+fn get_job(problem: &Problem, id: &str) -> Job {
+    problem.jobs.all().iter().find(|job| get_customer_id(job) == id).cloned().unwrap()
+}
+
+fn get_kinds(violations: &[SolutionViolation]) -> Vec<ViolationKind> {
+    violations.iter().map(|violation| violation.kind).collect()
+}
+
+#[test]
+fn can_check_feasible_solution() {
+    let (problem, solution) = generate_matrix_routes_with_defaults(3, 2, false);
+
+    let violations = check_solution(Arc::new(problem), &solution);
+
+    assert!(violations.is_empty(), "unexpected violations: {violations:?}");
+}
+
+#[test]
+fn can_detect_job_assigned_twice() {
+    let (problem, mut solution) = generate_matrix_routes_with_defaults(3, 2, false);
+    let activity = solution.routes[0].tour.get(1).unwrap().deep_copy();
+    solution.routes[1].tour.insert_last(activity);
+
+    let violations = check_solution(Arc::new(problem), &solution);
+
+    assert_eq!(get_kinds(&violations), vec![ViolationKind::Assignment]);
+    assert_eq!(violations[0].job.as_ref().map(get_customer_id), Some("c0".to_string()));
+    assert_eq!(violations[0].message, "job 'c0' is assigned to 2 routes");
+}
+
+#[test]
+fn can_detect_job_assigned_and_unassigned() {
+    let (problem, mut solution) = generate_matrix_routes_with_defaults(3, 2, false);
+    solution.unassigned.push((get_job(&problem, "c4"), UnassignmentInfo::Unknown));
+
+    let violations = check_solution(Arc::new(problem), &solution);
+
+    assert_eq!(get_kinds(&violations), vec![ViolationKind::Assignment]);
+    assert_eq!(violations[0].route_index, Some(1));
+    assert_eq!(violations[0].message, "job 'c4' is assigned and unassigned");
+}
+
+#[test]
+fn can_detect_time_window_violation() {
+    let (problem, mut solution) = generate_matrix_routes_with_defaults(3, 2, false);
+    solution.routes[0].tour.get_mut(2).unwrap().place.time = TimeWindow::new(0., 0.);
+
+    let violations = check_solution(Arc::new(problem), &solution);
+
+    let get_violations = |kind: ViolationKind| {
+        violations
+            .iter()
+            .filter(|violation| violation.kind == kind)
+            .map(|violation| {
+                (violation.route_index, violation.activity_index, violation.job.as_ref().map(get_customer_id))
+            })
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(get_violations(ViolationKind::TimeWindow), vec![(Some(0), Some(2), Some("c1".to_string()))]);
+    // NOTE c0 cannot be inserted before c1 without violating its time window
+    assert_eq!(
+        get_violations(ViolationKind::Constraint(ViolationCode(1))),
+        vec![(Some(0), Some(1), Some("c0".to_string())), (Some(0), Some(2), Some("c1".to_string()))]
+    );
+}
+
+parameterized_test! {can_detect_capacity_violation, (capacity, expected_capacity, expected_constraint), {
+    can_detect_capacity_violation_impl(capacity, expected_capacity, expected_constraint);
+}}
+
+can_detect_capacity_violation! {
+    case01_enough_capacity: (3, 0, 0),
+    case02_not_enough_capacity: (2, 2, 6),
+}
+
+fn can_detect_capacity_violation_impl(capacity: i32, expected_capacity: usize, expected_constraint: usize) {
+    let (problem, solution) = generate_matrix_routes(
+        3,
+        2,
+        false,
+        |transport, activity, _| {
+            TestGoalContextBuilder::default()
+                .add_feature(
+                    TransportFeatureBuilder::new("transport")
+                        .set_transport_cost(transport)
+                        .set_activity_cost(activity)
+                        .build_minimize_cost()
+                        .unwrap(),
+                )
+                .add_feature(
+                    CapacityFeatureBuilder::<SingleDimLoad>::new("capacity")
+                        .set_violation_code(ViolationCode(2))
+                        .build()
+                        .unwrap(),
+                )
+                .build()
+        },
+        |id, location| {
+            TestSingleBuilder::default().id(id).location(location).demand(Demand::delivery(1)).build_shared()
+        },
+        |mut vehicle| {
+            vehicle.dimens.set_vehicle_capacity(SingleDimLoad::new(capacity));
+            vehicle
+        },
+        |data| (data.clone(), data),
+    );
+
+    let violations = check_solution(Arc::new(problem), &solution);
+
+    let capacity_violations = violations.iter().filter(|v| v.kind == ViolationKind::Capacity).collect::<Vec<_>>();
+    assert_eq!(capacity_violations.len(), expected_capacity);
+    assert!(capacity_violations.iter().all(|violation| violation.activity_index == Some(0)));
+    assert_eq!(
+        violations.iter().filter(|v| v.kind == ViolationKind::Constraint(ViolationCode(2))).count(),
+        expected_constraint
+    );
+    assert_eq!(violations.len(), expected_capacity + expected_constraint);
+}
+
+parameterized_test! {can_detect_relation_violation, (order, position, job_ids, expected), {
+    can_detect_relation_violation_impl(order, position, job_ids, expected);
+}}
+
+can_detect_relation_violation! {
+    case01_sequence_ok: (LockOrder::Sequence, LockPosition::Any, &["c0", "c2"], None),
+    case02_sequence_wrong_order: (LockOrder::Sequence, LockPosition::Any, &["c2", "c0"], Some("locked jobs are not in expected order")),
+    case03_strict_ok: (LockOrder::Strict, LockPosition::Any, &["c1", "c2"], None),
+    case04_strict_gap: (LockOrder::Strict, LockPosition::Any, &["c0", "c2"], Some("other jobs are inserted between strictly locked jobs")),
+    case05_departure_ok: (LockOrder::Any, LockPosition::Departure, &["c1", "c0"], None),
+    case06_departure_wrong: (LockOrder::Any, LockPosition::Departure, &["c1", "c2"], Some("locked jobs are not assigned right after departure")),
+    case07_arrival_ok: (LockOrder::Any, LockPosition::Arrival, &["c1", "c2"], None),
+    case08_arrival_wrong: (LockOrder::Any, LockPosition::Arrival, &["c0", "c1"], Some("locked jobs are not assigned right before arrival")),
+    case09_fixed_ok: (LockOrder::Sequence, LockPosition::Fixed, &["c0", "c1", "c2"], None),
+    case10_different_routes: (LockOrder::Any, LockPosition::Any, &["c0", "c3"], Some("locked jobs are assigned to different routes")),
+}
+
+fn can_detect_relation_violation_impl(
+    order: LockOrder,
+    position: LockPosition,
+    job_ids: &[&str],
+    expected: Option<&str>,
+) {
+    let (mut problem, solution) = generate_matrix_routes_with_defaults(3, 2, false);
+    let jobs = job_ids.iter().map(|id| get_job(&problem, id)).collect();
+    problem.locks = vec![Arc::new(Lock::new(Arc::new(|_| true), vec![LockDetail::new(order, position, jobs)], false))];
+
+    let violations = check_solution(Arc::new(problem), &solution);
+
+    assert_eq!(violations.iter().map(|violation| violation.message.as_str()).next(), expected);
+    assert!(violations.iter().all(|violation| violation.kind == ViolationKind::Relation));
+}
+
+#[test]
+fn can_detect_relation_with_not_allowed_actor() {
+    let (mut problem, solution) = generate_matrix_routes_with_defaults(3, 2, false);
+    let jobs = vec![get_job(&problem, "c0")];
+    problem.locks = vec![Arc::new(Lock::new(
+        Arc::new(|_| false),
+        vec![LockDetail::new(LockOrder::Any, LockPosition::Any, jobs)],
+        false,
+    ))];
+
+    let violations = check_solution(Arc::new(problem), &solution);
+
+    assert_eq!(get_kinds(&violations), vec![ViolationKind::Relation]);
+    assert_eq!(
+        violations[0].to_string(),
+        "Relation in route 0: locked jobs are assigned to a route with not allowed actor"
+    );
+}
+
+#[test]
+fn can_check_solution_from_solver() {
+    use crate::models::examples::create_example_problem;
+    use crate::prelude::{Solver, VrpConfigBuilder};
+
+    let problem = create_example_problem();
+    let config =
+        VrpConfigBuilder::new(problem.clone()).prebuild().unwrap().with_max_generations(Some(10)).build().unwrap();
+    let solution = Solver::new(problem.clone(), config).solve().unwrap();
+
+    let violations = check_solution(problem, &solution);
+
+    assert!(violations.is_empty(), "unexpected violations: {violations:?}");
+}
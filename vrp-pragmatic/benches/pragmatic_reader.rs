@@ -0,0 +1,134 @@
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::io::BufReader;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use vrp_pragmatic::format::Location;
+use vrp_pragmatic::format::problem::*;
+use vrp_pragmatic::{get_unique_locations, get_unique_locations_from_reader};
+
+/// Tracks current and peak amount of allocated memory.
+struct PeakAllocator {
+    current: AtomicUsize,
+    peak: AtomicUsize,
+}
+
+unsafe impl GlobalAlloc for PeakAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let current = self.current.fetch_add(layout.size(), Ordering::Relaxed) + layout.size();
+        self.peak.fetch_max(current, Ordering::Relaxed);
+
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        self.current.fetch_sub(layout.size(), Ordering::Relaxed);
+
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: PeakAllocator = PeakAllocator { current: AtomicUsize::new(0), peak: AtomicUsize::new(0) };
+
+const JOBS_SIZE: usize = 100_000;
+
+fn create_problem_json(size: usize) -> String {
+    let create_place = |idx: usize| JobPlace {
+        location: Location::Coordinate {
+            lat: 52.4 + (idx % 1000) as f64 * 0.0001,
+            lng: 13.3 + (idx / 1000) as f64 * 0.0001,
+        },
+        duration: 300.,
+        times: Some(vec![
+            vec!["2020-07-04T09:00:00Z".to_string(), "2020-07-04T12:00:00Z".to_string()],
+            vec!["2020-07-04T14:00:00Z".to_string(), "2020-07-04T18:00:00Z".to_string()],
+        ]),
+        tag: Some(format!("tag{idx}")),
+        cost: None,
+    };
+
+    let problem = Problem {
+        plan: Plan {
+            jobs: (0..size)
+                .map(|idx| Job {
+                    id: format!("job{idx}"),
+                    pickups: None,
+                    deliveries: Some(vec![JobTask {
+                        places: vec![create_place(idx)],
+                        demand: Some(vec![1]),
+                        order: None,
+                    }]),
+                    replacements: None,
+                    services: None,
+                    skills: None,
+                    value: None,
+                    group: None,
+                    compatibility: None,
+                    priority: None,
+                    category: None,
+                    crew: None,
+                })
+                .collect(),
+            relations: None,
+            clustering: None,
+            incompatibilities: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![],
+            profiles: vec![MatrixProfile { name: "car".to_string(), speed: None, restrictions: None }],
+            resources: None,
+            depots: None,
+        },
+        objectives: None,
+        timezone: None,
+    };
+
+    serde_json::to_string(&problem).expect("cannot serialize problem")
+}
+
+fn get_locations(json: &str) -> Vec<Location> {
+    let problem = deserialize_problem(BufReader::new(json.as_bytes())).expect("cannot deserialize problem");
+
+    get_unique_locations(&problem)
+}
+
+fn get_locations_streaming(json: &str) -> Vec<Location> {
+    get_unique_locations_from_reader(BufReader::new(json.as_bytes())).expect("cannot read problem")
+}
+
+/// Returns amount of memory allocated on top of already allocated by the time of the call.
+fn measure_peak_memory(action: impl FnOnce()) -> usize {
+    let baseline = ALLOCATOR.current.load(Ordering::Relaxed);
+    ALLOCATOR.peak.store(baseline, Ordering::Relaxed);
+
+    action();
+
+    ALLOCATOR.peak.load(Ordering::Relaxed) - baseline
+}
+
+fn bench_read_jobs(c: &mut Criterion) {
+    let json = create_problem_json(JOBS_SIZE);
+
+    let regular = measure_peak_memory(|| {
+        black_box(get_locations(json.as_str()));
+    });
+    let streaming = measure_peak_memory(|| {
+        black_box(get_locations_streaming(json.as_str()));
+    });
+    println!(
+        "peak memory to read {JOBS_SIZE} jobs ({} MiB of json): regular={} MiB, streaming={} MiB",
+        json.len() / (1024 * 1024),
+        regular / (1024 * 1024),
+        streaming / (1024 * 1024)
+    );
+    assert!(streaming < regular, "streaming reader should allocate less memory");
+
+    let mut group = c.benchmark_group("reading problem with 100k jobs");
+    group.sample_size(10);
+    group.bench_function("regular reader", |b| b.iter(|| black_box(get_locations(json.as_str()))));
+    group.bench_function("streaming reader", |b| b.iter(|| black_box(get_locations_streaming(json.as_str()))));
+    group.finish();
+}
+
+criterion_group!(benches, bench_read_jobs);
+criterion_main!(benches);
@@ -14,7 +14,14 @@ use vrp_core::utils::GenericError;
 
 /// Checks assignment of jobs and vehicles.
 pub fn check_assignment(ctx: &CheckerContext) -> Result<(), Vec<GenericError>> {
-    combine_error_results(&[check_vehicles(ctx), check_jobs_presence(ctx), check_jobs_match(ctx), check_groups(ctx)])
+    combine_error_results(&[
+        check_vehicles(ctx),
+        check_jobs_presence(ctx),
+        check_crew_jobs(ctx),
+        check_jobs_match(ctx),
+        check_groups(ctx),
+        check_incompatibilities(ctx),
+    ])
 }
 
 /// Checks that vehicles in each tour are used once per shift and they are known in problem.
@@ -37,6 +44,54 @@ fn check_vehicles(ctx: &CheckerContext) -> GenericResult<()> {
     Ok(())
 }
 
+/// Checks that crew jobs are served by required amount of different vehicles at the same time.
+fn check_crew_jobs(ctx: &CheckerContext) -> GenericResult<()> {
+    let crews = ctx
+        .problem
+        .plan
+        .jobs
+        .iter()
+        .filter_map(|job| job.crew.filter(|&crew| crew > 1).map(|crew| (job.id.clone(), crew)))
+        .collect::<HashMap<_, _>>();
+
+    if crews.is_empty() {
+        return Ok(());
+    }
+
+    let assignments = ctx.solution.tours.iter().fold(HashMap::<_, Vec<_>>::new(), |mut acc, tour| {
+        tour.stops
+            .iter()
+            .flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+            .filter(|(_, activity)| crews.contains_key(&activity.job_id))
+            .for_each(|(stop, activity)| {
+                let departure = activity.time.as_ref().map_or(&stop.schedule().departure, |time| &time.end);
+                acc.entry(activity.job_id.clone())
+                    .or_default()
+                    .push(((tour.vehicle_id.clone(), tour.shift_index), parse_time(departure)));
+            });
+
+        acc
+    });
+
+    assignments.iter().try_for_each(|(job_id, members)| {
+        let crew = crews.get(job_id).copied().unwrap_or_default();
+        let tours = members.iter().map(|(tour_info, _)| tour_info).collect::<HashSet<_>>();
+
+        if tours.len() != crew || members.len() != crew {
+            return Err(GenericError::from(format!(
+                "crew job '{job_id}' is served by {} vehicles, expected: {crew}",
+                tours.len()
+            )));
+        }
+
+        if members.iter().any(|(_, departure)| (departure - members[0].1).abs() > 1E-3) {
+            return Err(GenericError::from(format!("crew job '{job_id}' is not served at the same time")));
+        }
+
+        Ok(())
+    })
+}
+
 /// Checks job task rules.
 fn check_jobs_presence(ctx: &CheckerContext) -> GenericResult<()> {
     struct JobAssignment {
@@ -69,7 +124,9 @@ fn check_jobs_presence(ctx: &CheckerContext) -> GenericResult<()> {
                 let asgn =
                     used_jobs.entry(activity.job_id.clone()).or_insert_with(|| new_assignment(tour_info.clone()));
 
-                if asgn.tour_info != tour_info {
+                let is_crew = all_jobs.get(&activity.job_id).and_then(|job| job.crew).is_some_and(|crew| crew > 1);
+
+                if asgn.tour_info != tour_info && !is_crew {
                     return Err(GenericError::from(format!("job served in multiple tours: '{}'", activity.job_id)));
                 }
 
@@ -92,6 +149,7 @@ fn check_jobs_presence(ctx: &CheckerContext) -> GenericResult<()> {
             + job.deliveries.as_ref().map_or(0, |d| d.len())
             + job.services.as_ref().map_or(0, |s| s.len())
             + job.replacements.as_ref().map_or(0, |r| r.len());
+        let expected_tasks = expected_tasks * job.crew.unwrap_or(1).max(1);
         let assigned_tasks = asgn.pickups.len() + asgn.deliveries.len() + asgn.services.len() + asgn.replacements.len();
 
         if expected_tasks != assigned_tasks {
@@ -287,3 +345,34 @@ fn check_groups(ctx: &CheckerContext) -> GenericResult<()> {
         Err(format!("job groups are not respected: '{err_info}'").into())
     }
 }
+
+fn check_incompatibilities(ctx: &CheckerContext) -> GenericResult<()> {
+    let Some(incompatibilities) = ctx.problem.plan.incompatibilities.as_ref() else {
+        return Ok(());
+    };
+
+    ctx.solution.tours.iter().try_for_each(|tour| {
+        let categories = tour
+            .stops
+            .iter()
+            .flat_map(|stop| stop.activities().iter())
+            .flat_map(|activity| ctx.get_job_by_id(&activity.job_id))
+            .flat_map(|job| job.category.as_ref())
+            .collect::<HashSet<_>>();
+
+        let violation = incompatibilities.iter().find(|incompatibility| {
+            incompatibility.categories.iter().collect::<HashSet<_>>().intersection(&categories).count() > 1
+        });
+
+        match violation {
+            Some(incompatibility) => Err(format!(
+                "incompatible job categories '{}' are served by the same tour: vehicle id '{}', shift index: {}",
+                incompatibility.categories.join(","),
+                tour.vehicle_id,
+                tour.shift_index
+            )
+            .into()),
+            None => Ok(()),
+        }
+    })
+}
@@ -10,6 +10,7 @@ use vrp_core::prelude::GenericResult;
 
 /// Checks that vehicle load is assigned correctly. The following rules are checked:
 /// * max vehicle's capacity is not violated
+/// * vehicle's capacity after its change is not violated
 /// * load change is correct
 pub fn check_vehicle_load(context: &CheckerContext) -> Result<(), Vec<GenericError>> {
     combine_error_results(&[check_vehicle_load_assignment(context), check_resource_consumption(context)])
@@ -18,6 +19,11 @@ pub fn check_vehicle_load(context: &CheckerContext) -> Result<(), Vec<GenericErr
 fn check_vehicle_load_assignment(context: &CheckerContext) -> GenericResult<()> {
     context.solution.tours.iter().try_for_each::<_, GenericResult<_>>(|tour| {
         let capacity = MultiDimLoad::new(context.get_vehicle(&tour.vehicle_id)?.capacity.clone());
+        let changed_capacity = get_changed_capacity(context, tour)?;
+        let get_capacity = |stop_idx: usize| match &changed_capacity {
+            Some((change_idx, changed_capacity)) if stop_idx >= *change_idx => changed_capacity,
+            _ => &capacity,
+        };
         let intervals = get_intervals(context, tour);
 
         intervals
@@ -43,7 +49,7 @@ fn check_vehicle_load_assignment(context: &CheckerContext) -> GenericResult<()>
                         let from_load = MultiDimLoad::new(from.load().clone());
                         let to_load = MultiDimLoad::new(to.load().clone());
 
-                        if !capacity.can_fit(&from_load) || !capacity.can_fit(&to_load) {
+                        if !get_capacity(*idx).can_fit(&from_load) || !get_capacity(*idx + 1).can_fit(&to_load) {
                             return Err(format!("load exceeds capacity in tour '{}'", tour.vehicle_id).into());
                         }
 
@@ -156,6 +162,17 @@ fn check_resource_consumption(context: &CheckerContext) -> GenericResult<()> {
     })
 }
 
+fn get_changed_capacity(context: &CheckerContext, tour: &Tour) -> GenericResult<Option<(usize, MultiDimLoad)>> {
+    let Some(change_idx) = context.get_capacity_change_stop_idx(tour) else { return Ok(None) };
+
+    let change = context
+        .get_vehicle_shift(tour)?
+        .capacity_change
+        .ok_or_else(|| GenericError::from(format!("cannot find capacity change for tour '{}'", tour.vehicle_id)))?;
+
+    Ok(Some((change_idx, MultiDimLoad::new(change.capacity))))
+}
+
 enum DemandType {
     None,
     StaticPickup,
@@ -4,17 +4,27 @@ mod limits_test;
 
 use super::*;
 use crate::utils::combine_error_results;
+use std::collections::BTreeMap;
 use vrp_core::models::common::Distance;
 use vrp_core::prelude::GenericResult;
 
 /// NOTE to ensure distance/duration correctness, routing check should be performed first.
 pub fn check_limits(context: &CheckerContext) -> Result<(), Vec<GenericError>> {
-    combine_error_results(&[check_shift_limits(context), check_shift_time(context), check_recharge_limits(context)])
+    combine_error_results(&[
+        check_shift_limits(context),
+        check_cumulative_limits(context),
+        check_shift_time(context),
+        check_recharge_limits(context),
+        check_access_restrictions(context),
+        check_depot_hours(context),
+    ])
 }
 
 /// Check that shift limits are not violated:
 /// * max shift time
 /// * max distance
+/// * tour size
+/// * max stops
 fn check_shift_limits(context: &CheckerContext) -> GenericResult<()> {
     context.solution.tours.iter().try_for_each::<_, GenericResult<_>>(|tour| {
         let vehicle = context.get_vehicle(&tour.vehicle_id)?;
@@ -22,18 +32,18 @@ fn check_shift_limits(context: &CheckerContext) -> GenericResult<()> {
         if let Some(ref limits) = vehicle.limits {
             if let Some(max_distance) = limits.max_distance
                 && tour.statistic.distance as Float > max_distance {
-                    return Err(format!(
+                    context.report_distance_mismatch(tour.statistic.distance as Float - max_distance, format!(
                         "max distance limit violation, expected: not more than {}, got: {}, vehicle id '{}', shift index: {}",
                         max_distance, tour.statistic.distance, tour.vehicle_id, tour.shift_index
-                    ).into());
+                    ))?;
                 }
 
             if let Some(max_duration) = limits.max_duration
                 && tour.statistic.duration as Float > max_duration {
-                    return Err(format!(
+                    context.report_time_mismatch(tour.statistic.duration as Float - max_duration, format!(
                         "shift time limit violation, expected: not more than {}, got: {}, vehicle id '{}', shift index: {}",
                         max_duration, tour.statistic.duration, tour.vehicle_id, tour.shift_index
-                    ).into());
+                    ))?;
                 }
 
             if let Some(tour_size_limit) = limits.tour_size {
@@ -50,6 +60,69 @@ fn check_shift_limits(context: &CheckerContext) -> GenericResult<()> {
                     ).into())
                 }
             }
+
+            if let Some(max_stops) = limits.max_stops.filter(|_| !has_soft_stop_limit(context)) {
+                let tour_stops = tour
+                    .stops
+                    .iter()
+                    .flat_map(|stop| stop.activities())
+                    .filter(|activity| {
+                        !matches!(
+                            activity.activity_type.as_str(),
+                            "departure" | "arrival" | "break" | "reload" | "recharge" | "capacityChange"
+                        )
+                    })
+                    .count();
+
+                if tour_stops > max_stops {
+                    return Err(format!(
+                        "max stops limit violation, expected: not more than {}, got: {}, vehicle id '{}', shift index: {}",
+                        max_stops, tour_stops, tour.vehicle_id, tour.shift_index
+                    ).into())
+                }
+            }
+        }
+
+        Ok(())
+    })
+}
+
+/// Returns true if max stops limit is used as an objective, so it can be violated.
+fn has_soft_stop_limit(context: &CheckerContext) -> bool {
+    context.problem.objectives.iter().flatten().any(|objective| matches!(objective, Objective::MaxStops))
+}
+
+/// Check that cumulative limits of the vehicle across all its shifts are not violated:
+/// * max total distance
+/// * max total driving time
+fn check_cumulative_limits(context: &CheckerContext) -> GenericResult<()> {
+    let totals = context.solution.tours.iter().fold(BTreeMap::<_, (i64, i64)>::new(), |mut totals, tour| {
+        let (distance, driving) = totals.entry(tour.vehicle_id.as_str()).or_default();
+        *distance += tour.statistic.distance;
+        *driving += tour.statistic.times.driving;
+
+        totals
+    });
+
+    totals.into_iter().try_for_each::<_, GenericResult<_>>(|(vehicle_id, (distance, driving))| {
+        let vehicle = context.get_vehicle(vehicle_id)?;
+
+        let Some(limits) = vehicle.limits.as_ref() else { return Ok(()) };
+
+        if let Some(max_total_distance) = limits.max_total_distance
+            && distance as Float > max_total_distance
+        {
+            context.report_distance_mismatch(distance as Float - max_total_distance, format!(
+                "max total distance limit violation, expected: not more than {max_total_distance}, got: {distance}, vehicle id '{vehicle_id}'"
+            ))?;
+        }
+
+        if let Some(max_total_driving_time) = limits.max_total_driving_time
+            && driving as Float > max_total_driving_time
+        {
+            context.report_time_mismatch(driving as Float - max_total_driving_time, format!(
+                "max total driving time limit violation, expected: not more than {max_total_driving_time}, got: {driving}, vehicle id '{vehicle_id}'"
+            ))?;
         }
 
         Ok(())
@@ -125,3 +198,104 @@ fn check_recharge_limits(context: &CheckerContext) -> GenericResult<()> {
             .map(|_| ())
     })
 }
+
+fn check_access_restrictions(context: &CheckerContext) -> GenericResult<()> {
+    context.solution.tours.iter().try_for_each::<_, GenericResult<_>>(|tour| {
+        let vehicle = context.get_vehicle(&tour.vehicle_id)?;
+        let Some(restrictions) = context
+            .problem
+            .fleet
+            .profiles
+            .iter()
+            .find(|profile| profile.name == vehicle.profile.matrix)
+            .and_then(|profile| profile.restrictions.as_ref())
+        else {
+            return Ok(());
+        };
+
+        let restrictions = restrictions
+            .iter()
+            .map(|restriction| {
+                let locations = restriction
+                    .locations
+                    .iter()
+                    .filter_map(|location| context.get_location_index(location).ok())
+                    .collect::<HashSet<_>>();
+
+                (locations, parse_time_window(&restriction.time))
+            })
+            .collect::<Vec<_>>();
+
+        tour.stops.iter().skip(1).filter_map(|stop| stop.as_point()).try_for_each(|stop| {
+            let location = context.get_location_index(&stop.location)?;
+            let arrival = parse_time(&stop.time.arrival);
+
+            let is_restricted = restrictions
+                .iter()
+                .any(|(locations, tw)| locations.contains(&location) && arrival > tw.start && arrival < tw.end);
+
+            if is_restricted {
+                Err(format!(
+                    "access restriction violation at location '{:?}', arrival: '{}', vehicle id '{}', shift index: {}",
+                    stop.location, stop.time.arrival, tour.vehicle_id, tour.shift_index
+                )
+                .into())
+            } else {
+                Ok(())
+            }
+        })
+    })
+}
+
+/// Checks that vehicles depart from, return to and reload at depots only within their operating hours.
+fn check_depot_hours(context: &CheckerContext) -> GenericResult<()> {
+    let Some(depots) = context.problem.fleet.depots.as_ref() else { return Ok(()) };
+
+    let get_hours = |location: &Location| {
+        depots
+            .iter()
+            .filter(|depot| depot.location == *location)
+            .flat_map(|depot| depot.times.iter().map(|time| parse_time_window(time)))
+            .collect::<Vec<_>>()
+    };
+
+    context.solution.tours.iter().try_for_each::<_, GenericResult<_>>(|tour| {
+        let create_error = |activity_type: &str, time: &str| -> GenericResult<()> {
+            Err(format!(
+                "depot operating hours violation: {activity_type} at '{time}', vehicle id '{}', shift index: {}",
+                tour.vehicle_id, tour.shift_index
+            )
+            .into())
+        };
+
+        tour.stops.iter().try_for_each(|stop| {
+            let Some(point) = stop.as_point() else { return Ok(()) };
+
+            let hours = get_hours(&point.location);
+            if hours.is_empty() {
+                return Ok(());
+            }
+
+            point.activities.iter().try_for_each(|activity| {
+                let (time, is_valid) = match activity.activity_type.as_str() {
+                    "departure" => {
+                        let departure = parse_time(&point.time.departure);
+                        (&point.time.departure, hours.iter().any(|tw| tw.start <= departure && departure <= tw.end))
+                    }
+                    "arrival" => {
+                        let arrival = parse_time(&point.time.arrival);
+                        (&point.time.arrival, hours.iter().any(|tw| arrival <= tw.end))
+                    }
+                    "reload" => {
+                        // NOTE vehicle can wait for depot opening, so only intersection is checked
+                        let activity_time = context.get_activity_time(stop, activity);
+                        (&point.time.arrival, hours.iter().any(|tw| tw.intersects(&activity_time)))
+                    }
+                    _ => return Ok(()),
+                };
+
+                if is_valid { Ok(()) } else { create_error(activity.activity_type.as_str(), time) }
+            })
+        })
+    })
+}
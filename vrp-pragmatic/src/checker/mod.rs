@@ -7,8 +7,10 @@ mod checker_test;
 
 use crate::format::problem::*;
 use crate::format::solution::*;
-use crate::format::{CoordIndex, Location};
+use crate::format::{CoordIndex, Location, normalize_times};
 use crate::parse_time;
+use serde::Serialize;
+use std::cell::RefCell;
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use vrp_core::construction::clustering::vicinity::ClusterConfig;
@@ -34,16 +36,41 @@ pub struct CheckerContext {
     profile_index: HashMap<String, usize>,
     core_problem: Arc<CoreProblem>,
     clustering: Option<ClusterConfig>,
+    options: CheckerOptions,
+    warnings: RefCell<Vec<GenericError>>,
+}
+
+/// Specifies numeric tolerances used by the checker. Mismatches of time and distance values which do not exceed
+/// the tolerance are reported as warnings instead of errors.
+#[derive(Clone, Debug, Default)]
+pub struct CheckerOptions {
+    /// A time tolerance in seconds.
+    pub time_tolerance: Float,
+    /// A distance tolerance in meters.
+    pub distance_tolerance: Float,
+}
+
+/// A report of solution check.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckerReport {
+    /// True if no errors are found.
+    pub is_feasible: bool,
+    /// A list of found errors: constraint violations or inconsistencies which exceed tolerances.
+    pub errors: Vec<String>,
+    /// A list of found warnings: inconsistencies which do not affect solution feasibility.
+    pub warnings: Vec<String>,
 }
 
 /// Represents all possible activity types.
 #[allow(dead_code)] // NOTE: keep data in each variant for future use
 enum ActivityType {
     Terminal,
-    Job(Job),
+    Job(Box<Job>),
     Break(VehicleBreak),
     Reload(VehicleReload),
     Recharge(VehicleRechargeStation),
+    CapacityChange(VehicleCapacityChange),
 }
 
 impl CheckerContext {
@@ -54,6 +81,7 @@ impl CheckerContext {
         matrices: Option<Vec<Matrix>>,
         solution: Solution,
     ) -> Result<Self, Vec<GenericError>> {
+        let problem = normalize_times(problem).map_err(|err| vec![err.to_string().into()])?;
         let job_map = problem.plan.jobs.iter().map(|job| (job.id.clone(), job.clone())).collect();
         let clustering = core_problem.extras.get_cluster_config().map(|config| config.as_ref().clone());
         let coord_index = CoordIndex::new(&problem);
@@ -65,13 +93,45 @@ impl CheckerContext {
                 .map_err(|err| vec![err])?
         };
 
-        Ok(Self { problem, matrices, solution, job_map, coord_index, profile_index, core_problem, clustering })
+        Ok(Self {
+            problem,
+            matrices,
+            solution,
+            job_map,
+            coord_index,
+            profile_index,
+            core_problem,
+            clustering,
+            options: CheckerOptions::default(),
+            warnings: RefCell::new(Vec::default()),
+        })
+    }
+
+    /// Sets checker options.
+    pub fn with_options(mut self, options: CheckerOptions) -> Self {
+        self.options = options;
+        self
     }
 
     /// Performs solution check.
     pub fn check(&self) -> Result<(), Vec<GenericError>> {
-        // avoid duplicates keeping original order
-        let (_, errors) = check_vehicle_load(self)
+        let (errors, _) = self.check_all();
+
+        if errors.is_empty() { Ok(()) } else { Err(errors) }
+    }
+
+    /// Performs solution check and returns a report with found errors and warnings.
+    pub fn check_with_report(&self) -> CheckerReport {
+        let (errors, warnings) = self.check_all();
+        let to_strings = |errors: Vec<GenericError>| errors.into_iter().map(|err| err.to_string()).collect::<Vec<_>>();
+
+        CheckerReport { is_feasible: errors.is_empty(), errors: to_strings(errors), warnings: to_strings(warnings) }
+    }
+
+    fn check_all(&self) -> (Vec<GenericError>, Vec<GenericError>) {
+        self.warnings.borrow_mut().clear();
+
+        let errors = check_vehicle_load(self)
             .err()
             .into_iter()
             .chain(check_relations(self).err())
@@ -79,17 +139,34 @@ impl CheckerContext {
             .chain(check_assignment(self).err())
             .chain(check_routing(self).err())
             .chain(check_limits(self).err())
-            .flatten()
-            .fold((HashSet::new(), Vec::default()), |(mut used, mut errors), error| {
-                if !used.contains(&error) {
-                    errors.push(error.clone());
-                    used.insert(error);
-                }
+            .flatten();
 
-                (used, errors)
-            });
+        (deduplicate(errors), deduplicate(self.warnings.take()))
+    }
 
-        if errors.is_empty() { Ok(()) } else { Err(errors) }
+    /// Reports time mismatch in seconds as a warning if it does not exceed the time tolerance, otherwise as an error.
+    fn report_time_mismatch(&self, mismatch: Float, message: String) -> GenericResult<()> {
+        self.report_mismatch(mismatch, self.options.time_tolerance, message)
+    }
+
+    /// Reports distance mismatch in meters as a warning if it does not exceed the distance tolerance, otherwise
+    /// as an error.
+    fn report_distance_mismatch(&self, mismatch: Float, message: String) -> GenericResult<()> {
+        self.report_mismatch(mismatch, self.options.distance_tolerance, message)
+    }
+
+    fn report_mismatch(&self, mismatch: Float, tolerance: Float, message: String) -> GenericResult<()> {
+        if mismatch.abs() <= tolerance {
+            self.report_warning(message);
+            Ok(())
+        } else {
+            Err(message.into())
+        }
+    }
+
+    /// Reports an inconsistency which does not affect solution feasibility.
+    fn report_warning(&self, message: String) {
+        self.warnings.borrow_mut().push(message.into());
     }
 
     /// Gets vehicle by its id.
@@ -103,7 +180,10 @@ impl CheckerContext {
     }
 
     fn get_vehicle_profile(&self, vehicle_id: &str) -> GenericResult<Profile> {
-        let profile = &self.get_vehicle(vehicle_id)?.profile;
+        self.get_profile(&self.get_vehicle(vehicle_id)?.profile)
+    }
+
+    fn get_profile(&self, profile: &VehicleProfile) -> GenericResult<Profile> {
         let index = self
             .profile_index
             .get(profile.matrix.as_str())
@@ -113,6 +193,13 @@ impl CheckerContext {
         Ok(Profile { index, scale: profile.scale.unwrap_or(1.) })
     }
 
+    /// Gets index of the stop where vehicle capacity change happens.
+    fn get_capacity_change_stop_idx(&self, tour: &Tour) -> Option<usize> {
+        tour.stops
+            .iter()
+            .position(|stop| stop.activities().iter().any(|activity| activity.activity_type == "capacityChange"))
+    }
+
     /// Gets activity operation time range in seconds since Unix epoch.
     fn get_activity_time(&self, stop: &Stop, activity: &Activity) -> TimeWindow {
         let schedule = stop.schedule();
@@ -175,7 +262,7 @@ impl CheckerContext {
             "pickup" | "delivery" | "service" | "replacement" => {
                 self.job_map.get(activity.job_id.as_str()).map_or_else(
                     || Err(format!("cannot find job with id '{}'", activity.job_id).into()),
-                    |job| Ok(ActivityType::Job(job.clone())),
+                    |job| Ok(ActivityType::Job(Box::new(job.clone()))),
                 )
             }
 
@@ -214,6 +301,16 @@ impl CheckerContext {
                 .map(|r| ActivityType::Recharge(r.clone()))
                 .ok_or_else(|| format!("cannot find recharge for tour '{}'", tour.vehicle_id).into()),
 
+            "capacityChange" => shift
+                .capacity_change
+                .as_ref()
+                .filter(|change| {
+                    location.as_ref().is_some_and(|location| change.location == *location)
+                        && change.tag == activity.job_tag
+                })
+                .map(|change| ActivityType::CapacityChange(change.clone()))
+                .ok_or_else(|| format!("cannot find capacity change for tour '{}'", tour.vehicle_id).into()),
+
             _ => Err(format!("unknown activity type: '{}'", activity.activity_type).into()),
         }
     }
@@ -373,6 +470,20 @@ impl CheckerContext {
     }
 }
 
+/// Removes duplicates keeping original order.
+fn deduplicate(errors: impl IntoIterator<Item = GenericError>) -> Vec<GenericError> {
+    let (_, errors) = errors.into_iter().fold((HashSet::new(), Vec::default()), |(mut used, mut errors), error| {
+        if !used.contains(&error) {
+            errors.push(error.clone());
+            used.insert(error);
+        }
+
+        (used, errors)
+    });
+
+    errors
+}
+
 fn job_task_size(tasks: &Option<Vec<JobTask>>) -> usize {
     tasks.as_ref().map_or(0, |p| p.len())
 }
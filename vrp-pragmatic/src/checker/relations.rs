@@ -18,6 +18,10 @@ fn check_relations_assignment(context: &CheckerContext) -> GenericResult<()> {
     (0_usize..)
         .zip(context.problem.plan.relations.as_ref().map_or([].iter(), |relations| relations.iter()))
         .try_for_each(|(idx, relation)| {
+            if relation.type_field.is_negative() {
+                return check_negative_relation(context, idx, relation);
+            }
+
             let tour = get_tour_by_vehicle_id(&relation.vehicle_id, relation.shift_index, &context.solution);
             // NOTE tour can be absent for tour relation
             let tour = if let Ok(tour) = tour {
@@ -75,6 +79,7 @@ fn check_relations_assignment(context: &CheckerContext) -> GenericResult<()> {
                         Ok(())
                     }
                 }
+                RelationType::NotSameTour | RelationType::DifferentVehicle => unreachable!("negative relation"),
                 RelationType::Any => {
                     let has_wrong_assignment = context
                         .solution
@@ -95,6 +100,37 @@ fn check_relations_assignment(context: &CheckerContext) -> GenericResult<()> {
     Ok(())
 }
 
+fn check_negative_relation(context: &CheckerContext, idx: usize, relation: &Relation) -> GenericResult<()> {
+    let relation_ids = relation.jobs.iter().collect::<HashSet<_>>();
+
+    if let Some(job_id) = relation_ids.iter().find(|job_id| context.get_job_by_id(job_id).is_none()) {
+        return Err(format!("relation has unknown job id: {job_id}").into());
+    }
+
+    let (by_tour, by_vehicle) = context.solution.tours.iter().fold(
+        (HashMap::<(String, usize), HashSet<String>>::default(), HashMap::<String, HashSet<String>>::default()),
+        |(mut by_tour, mut by_vehicle), tour| {
+            get_activity_ids(tour).into_iter().filter(|id| relation_ids.contains(id)).for_each(|id| {
+                by_tour.entry((tour.vehicle_id.clone(), tour.shift_index)).or_default().insert(id.clone());
+                by_vehicle.entry(tour.vehicle_id.clone()).or_default().insert(id);
+            });
+
+            (by_tour, by_vehicle)
+        },
+    );
+
+    let (scope_name, has_violation) = match relation.type_field {
+        RelationType::NotSameTour => ("tour", by_tour.values().any(|ids| ids.len() > 1)),
+        _ => ("vehicle", by_vehicle.values().any(|ids| ids.len() > 1)),
+    };
+
+    if has_violation {
+        Err(format!("relation {idx} has jobs assigned to the same {scope_name}").into())
+    } else {
+        Ok(())
+    }
+}
+
 fn get_tour_by_vehicle_id(vehicle_id: &str, shift_index: Option<usize>, solution: &Solution) -> GenericResult<Tour> {
     solution
         .tours
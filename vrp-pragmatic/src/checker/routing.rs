@@ -20,11 +20,17 @@ fn check_routing_rules(context: &CheckerContext) -> GenericResult<()> {
 
     context.solution.tours.iter().try_for_each::<_, GenericResult<_>>(|tour| {
         let profile = context.get_vehicle_profile(&tour.vehicle_id)?;
-
-        let get_matrix_data = |from: &PointStop, to: &PointStop| -> GenericResult<(i64, i64)> {
+        let changed_profile = get_changed_profile(context, tour)?;
+
+        // NOTE vehicle travels with changed profile once it leaves the capacity change stop
+        let get_matrix_data = |stop_idx: usize, from: &PointStop, to: &PointStop| -> GenericResult<(i64, i64)> {
+            let profile = match &changed_profile {
+                Some((change_idx, changed_profile)) if stop_idx >= *change_idx => changed_profile,
+                _ => &profile,
+            };
             let from_idx = context.get_location_index(&from.location)?;
             let to_idx = context.get_location_index(&to.location)?;
-            context.get_matrix_data(&profile, from_idx, to_idx)
+            context.get_matrix_data(profile, from_idx, to_idx)
         };
 
         let first_stop = tour.stops.first().ok_or_else(|| "empty tour".to_string())?;
@@ -48,7 +54,7 @@ fn check_routing_rules(context: &CheckerContext) -> GenericResult<()> {
 
                 let (distance, duration, to_distance) = match (from, to) {
                     (Stop::Point(from), Stop::Point(to)) => {
-                        let (distance, duration) = get_matrix_data(from, to)?;
+                        let (distance, duration) = get_matrix_data(leg_idx, from, to)?;
                         (distance, duration, to.distance)
                     }
                     (prev, Stop::Transit(transit)) => {
@@ -70,7 +76,7 @@ fn check_routing_rules(context: &CheckerContext) -> GenericResult<()> {
                             .unwrap()
                             .as_point()
                             .expect("two consistent transit stops are not supported");
-                        let (distance, duration) = get_matrix_data(from, to)?;
+                        let (distance, duration) = get_matrix_data(leg_idx - 1, from, to)?;
                         (distance, duration, to.distance)
                     }
                 };
@@ -79,6 +85,7 @@ fn check_routing_rules(context: &CheckerContext) -> GenericResult<()> {
                 let total_distance = total_distance + distance;
 
                 check_stop_statistic(
+                    context,
                     arrival_time,
                     total_distance,
                     to.schedule(),
@@ -92,13 +99,25 @@ fn check_routing_rules(context: &CheckerContext) -> GenericResult<()> {
             },
         )?;
 
-        check_tour_statistic(departure_time, total_distance, time_offset, tour, skip_distance_check)
+        check_tour_statistic(context, departure_time, total_distance, time_offset, tour, skip_distance_check)
     })?;
 
-    check_solution_statistic(&context.solution)
+    check_solution_statistic(context)
+}
+
+fn get_changed_profile(context: &CheckerContext, tour: &Tour) -> GenericResult<Option<(usize, Profile)>> {
+    let Some(change_idx) = context.get_capacity_change_stop_idx(tour) else { return Ok(None) };
+
+    context
+        .get_vehicle_shift(tour)?
+        .capacity_change
+        .and_then(|change| change.profile)
+        .map(|profile| context.get_profile(&profile).map(|profile| (change_idx, profile)))
+        .transpose()
 }
 
 fn check_stop_statistic(
+    context: &CheckerContext,
     arrival_time: i64,
     total_distance: i64,
     schedule: &Schedule,
@@ -107,29 +126,35 @@ fn check_stop_statistic(
     tour: &Tour,
     skip_distance_check: bool,
 ) -> GenericResult<()> {
-    #![allow(clippy::unnecessary_cast)]
-    if (arrival_time - parse_time(&schedule.arrival) as i64).abs() > 1 {
-        return Err(format!(
-            "arrival time mismatch for {stop_idx} stop in the tour: {}, expected: '{}', got: '{}'",
-            tour.vehicle_id,
-            format_time(arrival_time as Float),
-            schedule.arrival
-        )
-        .into());
+    #![allow(clippy::unnecessary_cast, clippy::too_many_arguments)]
+    let time_mismatch = arrival_time - parse_time(&schedule.arrival) as i64;
+    if time_mismatch.abs() > 1 {
+        context.report_time_mismatch(
+            time_mismatch as Float,
+            format!(
+                "arrival time mismatch for {stop_idx} stop in the tour: {}, expected: '{}', got: '{}'",
+                tour.vehicle_id,
+                format_time(arrival_time as Float),
+                schedule.arrival
+            ),
+        )?;
     }
 
     if !skip_distance_check && (total_distance - distance).abs() > 1 {
-        return Err(format!(
-            "distance mismatch for {stop_idx} stop in the tour: {}, expected: '{total_distance}', got: '{distance}'",
-            tour.vehicle_id
-        )
-        .into());
+        context.report_distance_mismatch(
+            (total_distance - distance) as Float,
+            format!(
+                "distance mismatch for {stop_idx} stop in the tour: {}, expected: '{total_distance}', got: '{distance}'",
+                tour.vehicle_id
+            ),
+        )?;
     }
 
     Ok(())
 }
 
 fn check_tour_statistic(
+    context: &CheckerContext,
     departure_time: i64,
     total_distance: i64,
     time_offset: i64,
@@ -137,34 +162,58 @@ fn check_tour_statistic(
     skip_distance_check: bool,
 ) -> GenericResult<()> {
     if !skip_distance_check && (total_distance - tour.statistic.distance).abs() > 1 {
-        return Err(format!(
-            "distance mismatch for tour statistic: {}, expected: '{}', got: '{}'",
-            tour.vehicle_id, total_distance, tour.statistic.distance,
-        )
-        .into());
+        context.report_distance_mismatch(
+            (total_distance - tour.statistic.distance) as Float,
+            format!(
+                "distance mismatch for tour statistic: {}, expected: '{}', got: '{}'",
+                tour.vehicle_id, total_distance, tour.statistic.distance,
+            ),
+        )?;
     }
 
     let total_duration = departure_time - time_offset;
     if (total_duration - tour.statistic.duration).abs() > 1 {
-        return Err(format!(
-            "duration mismatch for tour statistic: {}, expected: '{}', got: '{}'",
-            tour.vehicle_id, total_duration, tour.statistic.duration,
-        )
-        .into());
+        context.report_time_mismatch(
+            (total_duration - tour.statistic.duration) as Float,
+            format!(
+                "duration mismatch for tour statistic: {}, expected: '{}', got: '{}'",
+                tour.vehicle_id, total_duration, tour.statistic.duration,
+            ),
+        )?;
+    }
+
+    // NOTE time breakdown is informational and doesn't affect feasibility
+    let times = &tour.statistic.times;
+    let total_time = times.driving + times.serving + times.waiting + times.break_time + times.commuting + times.parking;
+    if (total_time - tour.statistic.duration).abs() > 1 {
+        context.report_warning(format!(
+            "time breakdown mismatch for tour statistic: {}, expected: '{}', got: '{}'",
+            tour.vehicle_id, tour.statistic.duration, total_time
+        ));
     }
 
     Ok(())
 }
 
-fn check_solution_statistic(solution: &Solution) -> GenericResult<()> {
+fn check_solution_statistic(context: &CheckerContext) -> GenericResult<()> {
+    let solution = &context.solution;
     let statistic = solution.tours.iter().fold(Statistic::default(), |acc, tour| acc + tour.statistic.clone());
 
     // NOTE cost should be ignored due to floating point issues
-    if statistic.duration != solution.statistic.duration || statistic.distance != solution.statistic.distance {
-        Err(format!("solution statistic mismatch, expected: '{:?}', got: '{:?}'", statistic, solution.statistic).into())
-    } else {
-        Ok(())
+    let duration_mismatch = statistic.duration - solution.statistic.duration;
+    let distance_mismatch = statistic.distance - solution.statistic.distance;
+    let get_message =
+        || format!("solution statistic mismatch, expected: '{:?}', got: '{:?}'", statistic, solution.statistic);
+
+    if duration_mismatch != 0 {
+        context.report_time_mismatch(duration_mismatch as Float, get_message())?;
     }
+
+    if distance_mismatch != 0 {
+        context.report_distance_mismatch(distance_mismatch as Float, get_message())?;
+    }
+
+    Ok(())
 }
 
 /// A workaround method for hre format output where distance is not defined.
@@ -0,0 +1,182 @@
+#[cfg(test)]
+#[path = "../../tests/unit/format/compatibility_test.rs"]
+mod compatibility_test;
+
+use super::problem::Problem;
+use super::solution::{Solution, map_code_reason};
+use super::{FormatError, MultiFormatError};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+use std::io::{BufReader, Error, Read};
+use vrp_core::models::ViolationCode;
+use vrp_core::prelude::{GenericError, GenericResult};
+
+/// A version of pragmatic format schema written into `schemaVersion` property of serialized documents.
+/// Documents without this property are treated as written before versioning was introduced.
+pub const SCHEMA_VERSION: u64 = 1;
+
+const SCHEMA_VERSION_KEY: &str = "schemaVersion";
+
+/// Wraps a document to be serialized with `schemaVersion` property.
+#[derive(Serialize)]
+pub(crate) struct Versioned<'a, T: Serialize> {
+    #[serde(rename = "schemaVersion")]
+    schema_version: u64,
+    #[serde(flatten)]
+    document: &'a T,
+}
+
+impl<'a, T: Serialize> Versioned<'a, T> {
+    /// Creates a new instance of `Versioned` with the current schema version.
+    pub fn new(document: &'a T) -> Self {
+        Self { schema_version: SCHEMA_VERSION, document }
+    }
+}
+
+/// Upgrades problem document in json format written by an older version of the crate to the current schema.
+/// Returns true if the document had to be changed (apart from setting schema version).
+/// Supports the following changes:
+/// - vehicle's `profile` as a plain matrix name is converted into an object;
+/// - vehicle's `limits.shiftTime` is renamed to `limits.maxDuration`;
+/// - task's `tag` is moved to each of the task's places;
+/// - `type` of routing profile is removed.
+pub fn upgrade_problem(document: &mut Value) -> GenericResult<bool> {
+    let mut is_changed = false;
+
+    if get_schema_version(document)? < 1 {
+        get_array_mut(document, &["fleet", "vehicles"]).filter_map(Value::as_object_mut).for_each(|vehicle| {
+            if let Some(matrix) = vehicle.get("profile").and_then(Value::as_str).map(str::to_string) {
+                let profile = Map::from_iter([("matrix".to_string(), matrix.into())]);
+                vehicle.insert("profile".to_string(), Value::Object(profile));
+                is_changed = true;
+            }
+
+            if let Some(limits) = vehicle.get_mut("limits").and_then(Value::as_object_mut) {
+                is_changed |= rename_key(limits, "shiftTime", "maxDuration");
+            }
+        });
+
+        get_array_mut(document, &["fleet", "profiles"]).filter_map(Value::as_object_mut).for_each(|profile| {
+            is_changed |= profile.remove("type").is_some();
+        });
+
+        get_array_mut(document, &["plan", "jobs"])
+            .filter_map(Value::as_object_mut)
+            .flat_map(|job| {
+                job.iter_mut()
+                    .filter(|(key, _)| matches!(key.as_str(), "pickups" | "deliveries" | "replacements" | "services"))
+                    .filter_map(|(_, tasks)| tasks.as_array_mut())
+                    .flatten()
+            })
+            .filter_map(Value::as_object_mut)
+            .for_each(|task| {
+                let Some(tag) = task.remove("tag") else { return };
+                is_changed = true;
+                task.get_mut("places").and_then(Value::as_array_mut).into_iter().flatten().for_each(|place| {
+                    if let Some(place) = place.as_object_mut() {
+                        place.entry("tag").or_insert_with(|| tag.clone());
+                    }
+                });
+            });
+    }
+
+    set_schema_version(document);
+
+    Ok(is_changed)
+}
+
+/// Upgrades solution document in json format written by an older version of the crate to the current schema.
+/// Returns true if the document had to be changed (apart from setting schema version).
+/// Supports the following changes:
+/// - numeric unassigned reason code is converted into its string representation.
+pub fn upgrade_solution(document: &mut Value) -> GenericResult<bool> {
+    let mut is_changed = false;
+
+    if get_schema_version(document)? < 1 {
+        get_array_mut(document, &["unassigned"])
+            .filter_map(|job| job.get_mut("reasons").and_then(Value::as_array_mut))
+            .flatten()
+            .filter_map(Value::as_object_mut)
+            .for_each(|reason| {
+                let Some(code) = reason.get("code").and_then(Value::as_i64) else { return };
+                let (code, description) = map_code_reason(ViolationCode(code as i32));
+                reason.insert("code".to_string(), code.into());
+                reason.entry("description").or_insert_with(|| description.into());
+                is_changed = true;
+            });
+    }
+
+    set_schema_version(document);
+
+    Ok(is_changed)
+}
+
+/// Deserializes problem in json format from `BufReader` upgrading it to the current schema if necessary.
+pub fn deserialize_problem_compat<R: Read>(reader: BufReader<R>) -> Result<Problem, MultiFormatError> {
+    let to_error = |err: String| -> MultiFormatError {
+        vec![FormatError::new(
+            "E0000".to_string(),
+            "cannot deserialize problem".to_string(),
+            format!("check input json: '{err}'"),
+        )]
+        .into()
+    };
+
+    deserialize_compat(reader, upgrade_problem).map_err(|err| to_error(err.to_string()))
+}
+
+/// Deserializes solution in json format from `BufReader` upgrading it to the current schema if necessary.
+pub fn deserialize_solution_compat<R: Read>(reader: BufReader<R>) -> Result<Solution, Error> {
+    deserialize_compat(reader, upgrade_solution).map_err(Error::other)
+}
+
+fn deserialize_compat<R, T>(
+    mut reader: BufReader<R>,
+    upgrade_fn: fn(&mut Value) -> GenericResult<bool>,
+) -> GenericResult<T>
+where
+    R: Read,
+    T: DeserializeOwned,
+{
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes).map_err(|err| err.to_string())?;
+
+    let mut document: Value = serde_json::from_slice(bytes.as_slice()).map_err(|err| err.to_string())?;
+
+    // NOTE deserialize from original input when no upgrade is needed to keep error positions
+    let result = if upgrade_fn(&mut document)? {
+        serde_json::from_value(document)
+    } else {
+        serde_json::from_slice(bytes.as_slice())
+    };
+
+    result.map_err(|err| err.to_string().into())
+}
+
+fn get_schema_version(document: &Value) -> GenericResult<u64> {
+    match document.get(SCHEMA_VERSION_KEY) {
+        None => Ok(0),
+        Some(version) => match version.as_u64() {
+            Some(version) if version <= SCHEMA_VERSION => Ok(version),
+            Some(version) => Err(GenericError::from(format!(
+                "unsupported schema version: {version}, the latest supported is {SCHEMA_VERSION}"
+            ))),
+            None => Err(format!("invalid schema version: '{version}'").into()),
+        },
+    }
+}
+
+fn set_schema_version(document: &mut Value) {
+    if let Some(document) = document.as_object_mut() {
+        document.insert(SCHEMA_VERSION_KEY.to_string(), SCHEMA_VERSION.into());
+    }
+}
+
+fn get_array_mut<'a>(document: &'a mut Value, path: &[&str]) -> impl Iterator<Item = &'a mut Value> {
+    path.iter().try_fold(document, |value, key| value.get_mut(*key)).and_then(Value::as_array_mut).into_iter().flatten()
+}
+
+fn rename_key(object: &mut Map<String, Value>, old_key: &str, new_key: &str) -> bool {
+    object.remove(old_key).map(|value| object.entry(new_key).or_insert(value)).is_some()
+}
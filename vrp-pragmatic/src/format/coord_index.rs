@@ -4,7 +4,7 @@
 #[path = "../../tests/unit/format/coord_index_test.rs"]
 mod coord_index_test;
 
-use crate::format::problem::{Problem, VehicleBreak};
+use crate::format::problem::{Fleet, Job, Problem, VehicleBreak};
 use crate::format::{CustomLocationType, Location};
 use std::collections::{HashMap, HashSet};
 use std::hash::{Hash, Hasher};
@@ -21,34 +21,47 @@ pub struct CoordIndex {
 impl CoordIndex {
     /// Creates a new instance of `CoordIndex`.
     pub fn new(problem: &Problem) -> Self {
-        let mut index = Self {
+        let mut index = Self::empty();
+
+        problem.plan.jobs.iter().for_each(|job| index.add_job(job));
+        index.add_fleet(&problem.fleet);
+        index.seal();
+
+        index
+    }
+
+    /// Creates an empty instance of `CoordIndex`.
+    pub(crate) fn empty() -> Self {
+        Self {
             direct_index: Default::default(),
             reverse_index: Default::default(),
             custom_locations: Default::default(),
             max_matrix_index: 0,
             flags: 0,
-        };
-
-        // process plan
-        problem.plan.jobs.iter().for_each(|job| {
-            job.pickups
-                .iter()
-                .chain(job.deliveries.iter())
-                .chain(job.replacements.iter())
-                .chain(job.services.iter())
-                .flat_map(|tasks| tasks.iter().flat_map(|task| task.places.iter()))
-                .for_each(|place| {
-                    index.add(&place.location);
-                });
-        });
+        }
+    }
+
+    /// Adds job locations to indices.
+    pub(crate) fn add_job(&mut self, job: &Job) {
+        job.pickups
+            .iter()
+            .chain(job.deliveries.iter())
+            .chain(job.replacements.iter())
+            .chain(job.services.iter())
+            .flat_map(|tasks| tasks.iter().flat_map(|task| task.places.iter()))
+            .for_each(|place| {
+                self.add(&place.location);
+            });
+    }
 
-        // process fleet
-        problem.fleet.vehicles.iter().for_each(|vehicle| {
+    /// Adds fleet locations to indices.
+    pub(crate) fn add_fleet(&mut self, fleet: &Fleet) {
+        fleet.vehicles.iter().for_each(|vehicle| {
             vehicle.shifts.iter().for_each(|shift| {
-                index.add(&shift.start.location);
+                self.add(&shift.start.location);
 
                 if let Some(end) = &shift.end {
-                    index.add(&end.location);
+                    self.add(&end.location);
                 }
 
                 if let Some(breaks) = &shift.breaks {
@@ -60,32 +73,37 @@ impl CoordIndex {
                         })
                         .flat_map(|places| places.iter())
                         .filter_map(|place| place.location.as_ref())
-                        .for_each(|location| index.add(location));
+                        .for_each(|location| self.add(location));
                 }
 
                 if let Some(reloads) = &shift.reloads {
-                    reloads.iter().for_each(|reload| index.add(&reload.location));
+                    reloads.iter().for_each(|reload| self.add(&reload.location));
                 }
 
                 if let Some(recharges) = &shift.recharges {
-                    recharges.stations.iter().for_each(|station| index.add(&station.location));
+                    recharges.stations.iter().for_each(|station| self.add(&station.location));
+                }
+
+                if let Some(change) = &shift.capacity_change {
+                    self.add(&change.location);
                 }
             });
         });
+    }
 
-        index.max_matrix_index = index.direct_index.len().max(1) - 1;
+    /// Finishes index construction: no locations should be added after this call.
+    pub(crate) fn seal(&mut self) {
+        self.max_matrix_index = self.direct_index.len().max(1) - 1;
 
-        let start_offset = index.direct_index.len() * index.direct_index.len();
+        let start_offset = self.direct_index.len() * self.direct_index.len();
         // NOTE promote custom locations to the index to use usize outside
-        index.custom_locations.iter().enumerate().for_each(|(offset, location)| {
+        self.custom_locations.iter().enumerate().for_each(|(offset, location)| {
             debug_assert!(matches!(location, Location::Custom { .. }));
 
             let value = start_offset + offset;
-            index.direct_index.insert(location.clone(), value);
-            index.reverse_index.insert(value, location.clone());
+            self.direct_index.insert(location.clone(), value);
+            self.reverse_index.insert(value, location.clone());
         });
-
-        index
     }
 
     /// Adds location to indices.
@@ -126,7 +144,7 @@ impl CoordIndex {
     /// Gets unique locations.
     pub fn unique(&self) -> Vec<Location> {
         let mut sorted_pairs: Vec<_> = self.reverse_index.iter().collect();
-        sorted_pairs.sort_by(|(a, _), (b, _)| a.cmp(b));
+        sorted_pairs.sort_by_key(|(a, _)| *a);
         sorted_pairs.iter().map(|pair| pair.1.clone()).collect()
     }
 
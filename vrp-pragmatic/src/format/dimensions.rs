@@ -11,6 +11,8 @@ custom_dimension!(pub ShiftIndex typeof usize);
 
 custom_dimension!(pub TourSize typeof usize);
 
+custom_dimension!(pub MaxStops typeof usize);
+
 custom_dimension!(pub PlaceTags typeof Vec<(usize, String)>);
 
 custom_dimension!(pub JobOrder typeof i32);
@@ -14,6 +14,9 @@ use vrp_core::models::solution::Route;
 use vrp_core::models::{Extras as CoreExtras, Problem as CoreProblem, ViolationCode};
 use vrp_core::prelude::{Float, GenericError};
 
+mod compatibility;
+pub use self::compatibility::*;
+
 mod coord_index;
 pub use self::coord_index::CoordIndex;
 
@@ -23,6 +26,10 @@ pub use self::dimensions::*;
 mod location_fallback;
 pub use self::location_fallback::*;
 
+mod time_format;
+pub use self::time_format::TimeFormat;
+pub(crate) use self::time_format::{apply_time_format, normalize_times, parse_date_time, set_time_format};
+
 pub mod problem;
 pub mod solution;
 
@@ -195,19 +202,26 @@ const GROUP_CONSTRAINT_CODE: ViolationCode = ViolationCode(12);
 const COMPATIBILITY_CONSTRAINT_CODE: ViolationCode = ViolationCode(13);
 const RELOAD_RESOURCE_CONSTRAINT_CODE: ViolationCode = ViolationCode(14);
 const RECHARGE_CONSTRAINT_CODE: ViolationCode = ViolationCode(15);
+const STOP_LIMIT_CONSTRAINT_CODE: ViolationCode = ViolationCode(16);
+const INCOMPATIBILITY_CONSTRAINT_CODE: ViolationCode = ViolationCode(17);
+const ACCESS_RESTRICTION_CONSTRAINT_CODE: ViolationCode = ViolationCode(18);
+const CREW_CONSTRAINT_CODE: ViolationCode = ViolationCode(19);
+const SEPARATION_CONSTRAINT_CODE: ViolationCode = ViolationCode(20);
+const CAPACITY_CHANGE_CONSTRAINT_CODE: ViolationCode = ViolationCode(21);
 
 /// An job id to job index.
 pub type JobIndex = HashMap<String, CoreJob>;
 
-pub use self::properties::{CoordIndexExtraProperty, JobIndexExtraProperty};
+pub use self::properties::{CoordIndexExtraProperty, JobIndexExtraProperty, TimeFormatExtraProperty};
 
 mod properties {
-    use crate::format::{CoordIndex, JobIndex};
+    use crate::format::{CoordIndex, JobIndex, TimeFormat};
     use vrp_core::custom_extra_property;
     use vrp_core::models::Extras;
 
     custom_extra_property!(pub JobIndex typeof JobIndex);
     custom_extra_property!(pub CoordIndex typeof CoordIndex);
+    custom_extra_property!(pub TimeFormat typeof TimeFormat);
 }
 
 /// Get job and coord indices from extras
@@ -0,0 +1,225 @@
+//! Provides a binary routing matrix format which can be used without parsing and copying matrix values,
+//! e.g. when the data is memory mapped from a file.
+//!
+//! All numbers are stored in little-endian byte order:
+//! - magic bytes `VRPM`
+//! - format version (u32)
+//! - flags (u32): the first bit is set when the matrix has unreachable entries
+//! - matrix size (u64): amount of locations
+//! - profile name: length (u32) followed by utf-8 bytes, zero length means no profile
+//! - timestamp: length (u32) followed by utf-8 bytes, zero length means no timestamp
+//! - zero padding up to 8 byte alignment
+//! - size * size travel durations (f32) in row-major order
+//! - size * size travel distances (f32) in row-major order
+//!
+//! Unreachable entries are encoded as negative values.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/format/problem/binary_matrix_test.rs"]
+mod binary_matrix_test;
+
+use super::*;
+use std::io::{BufWriter, Write};
+use vrp_core::models::problem::MatrixValuesSource;
+
+/// Magic bytes which identify binary matrix format.
+pub const BINARY_MATRIX_MAGIC: &[u8; 4] = b"VRPM";
+
+const BINARY_MATRIX_VERSION: u32 = 1;
+const HAS_UNREACHABLE_FLAG: u32 = 1;
+
+/// Keeps bytes of binary matrix, e.g. loaded into memory or memory mapped.
+pub type BinaryMatrixBytes = Arc<dyn AsRef<[u8]> + Send + Sync>;
+
+/// A routing matrix in binary format which values are not copied.
+pub struct BinaryMatrix {
+    /// A name of profile.
+    pub profile: Option<String>,
+    /// A date in RFC3999 for which routing info is applicable.
+    pub timestamp: Option<String>,
+    /// Amount of locations.
+    pub size: usize,
+    /// Specifies whether matrix has unreachable entries.
+    pub has_unreachable: bool,
+
+    durations: Arc<dyn MatrixValuesSource>,
+    distances: Arc<dyn MatrixValuesSource>,
+}
+
+impl BinaryMatrix {
+    /// Creates binary matrix from given bytes. Matrix values are used without copying when bytes are
+    /// properly aligned and platform is little-endian, otherwise they are copied.
+    pub fn from_bytes(bytes: BinaryMatrixBytes) -> GenericResult<Self> {
+        let header = read_header((*bytes).as_ref())?;
+
+        let length = header.size * header.size;
+        let durations = create_values_source(bytes.clone(), header.offset, length);
+        let distances = create_values_source(bytes, header.offset + length * 4, length);
+
+        Ok(Self {
+            profile: header.profile,
+            timestamp: header.timestamp,
+            size: header.size,
+            has_unreachable: header.has_unreachable,
+            durations,
+            distances,
+        })
+    }
+
+    /// Returns travel durations in row-major order.
+    pub fn durations(&self) -> Arc<dyn MatrixValuesSource> {
+        self.durations.clone()
+    }
+
+    /// Returns travel distances in row-major order.
+    pub fn distances(&self) -> Arc<dyn MatrixValuesSource> {
+        self.distances.clone()
+    }
+}
+
+/// Checks whether given bytes start with binary matrix magic bytes.
+pub fn is_binary_matrix(bytes: &[u8]) -> bool {
+    bytes.starts_with(BINARY_MATRIX_MAGIC)
+}
+
+/// Writes routing matrix in binary format. Entries marked by error codes are written as unreachable.
+pub fn write_binary_matrix<W: Write>(matrix: &Matrix, writer: &mut BufWriter<W>) -> GenericResult<()> {
+    let length = matrix.travel_times.len();
+    let size = (length as Float).sqrt().round() as usize;
+
+    if size * size != length || matrix.distances.len() != length {
+        return Err(format!(
+            "invalid matrix dimensions: travel times '{}', distances '{}'",
+            length,
+            matrix.distances.len()
+        )
+        .into());
+    }
+
+    let error_codes = matrix.error_codes.as_deref();
+    if error_codes.is_some_and(|codes| codes.len() != length) {
+        return Err("error codes length does not match matrix dimensions".into());
+    }
+
+    let write_err = |err: std::io::Error| GenericError::from(format!("cannot write binary matrix: '{err}'"));
+    let is_unreachable = |idx: usize| error_codes.is_some_and(|codes| codes[idx] > 0);
+    let write_values = |writer: &mut BufWriter<W>, values: &[i64]| {
+        values.iter().enumerate().try_for_each(|(idx, &value)| {
+            let value = if is_unreachable(idx) { -1. } else { value as f32 };
+            writer.write_all(&value.to_le_bytes())
+        })
+    };
+
+    let flags = if error_codes.is_some() { HAS_UNREACHABLE_FLAG } else { 0 };
+    let profile = matrix.profile.as_deref().unwrap_or_default().as_bytes();
+    let timestamp = matrix.timestamp.as_deref().unwrap_or_default().as_bytes();
+
+    let mut header = Vec::with_capacity(32 + profile.len() + timestamp.len());
+    header.extend_from_slice(BINARY_MATRIX_MAGIC);
+    header.extend_from_slice(&BINARY_MATRIX_VERSION.to_le_bytes());
+    header.extend_from_slice(&flags.to_le_bytes());
+    header.extend_from_slice(&(size as u64).to_le_bytes());
+    header.extend_from_slice(&(profile.len() as u32).to_le_bytes());
+    header.extend_from_slice(profile);
+    header.extend_from_slice(&(timestamp.len() as u32).to_le_bytes());
+    header.extend_from_slice(timestamp);
+    header.resize(header.len().next_multiple_of(8), 0);
+
+    writer.write_all(header.as_slice()).map_err(write_err)?;
+    write_values(writer, matrix.travel_times.as_slice()).map_err(write_err)?;
+    write_values(writer, matrix.distances.as_slice()).map_err(write_err)?;
+
+    writer.flush().map_err(write_err)
+}
+
+struct BinaryMatrixHeader {
+    profile: Option<String>,
+    timestamp: Option<String>,
+    size: usize,
+    has_unreachable: bool,
+    offset: usize,
+}
+
+fn read_header(bytes: &[u8]) -> GenericResult<BinaryMatrixHeader> {
+    if !is_binary_matrix(bytes) {
+        return Err("not a binary matrix: unexpected magic bytes".into());
+    }
+
+    let mut offset = BINARY_MATRIX_MAGIC.len();
+    let mut read_bytes = |length: usize| {
+        let slice = offset
+            .checked_add(length)
+            .and_then(|end| bytes.get(offset..end))
+            .ok_or_else(|| GenericError::from("unexpected end of binary matrix header"))?;
+        offset += length;
+        Ok::<_, GenericError>(slice)
+    };
+
+    let read_u32 = |bytes: &[u8]| u32::from_le_bytes(bytes.try_into().expect("unexpected slice size"));
+
+    let version = read_u32(read_bytes(4)?);
+    if version != BINARY_MATRIX_VERSION {
+        return Err(format!("unsupported binary matrix version: '{version}'").into());
+    }
+
+    let flags = read_u32(read_bytes(4)?);
+    let size = u64::from_le_bytes(read_bytes(8)?.try_into().expect("unexpected slice size"));
+    let size = usize::try_from(size).map_err(|_| GenericError::from(format!("too big matrix size: '{size}'")))?;
+
+    let mut read_string = || -> GenericResult<Option<String>> {
+        let length = read_u32(read_bytes(4)?) as usize;
+        let value = std::str::from_utf8(read_bytes(length)?)
+            .map_err(|err| GenericError::from(format!("invalid string in binary matrix header: '{err}'")))?;
+
+        Ok(Some(value.to_string()).filter(|value| !value.is_empty()))
+    };
+
+    let profile = read_string()?;
+    let timestamp = read_string()?;
+
+    let offset = offset.next_multiple_of(8);
+    let expected = size
+        .checked_mul(size)
+        .and_then(|length| length.checked_mul(8))
+        .and_then(|length| length.checked_add(offset))
+        .ok_or_else(|| GenericError::from(format!("too big matrix size: '{size}'")))?;
+
+    if bytes.len() != expected {
+        return Err(format!(
+            "binary matrix size mismatch: expected '{expected}' bytes for '{size}' locations, got '{}'",
+            bytes.len()
+        )
+        .into());
+    }
+
+    Ok(BinaryMatrixHeader { profile, timestamp, size, has_unreachable: flags & HAS_UNREACHABLE_FLAG != 0, offset })
+}
+
+/// Provides matrix values directly from underlying bytes.
+struct BytesValuesSource {
+    bytes: BinaryMatrixBytes,
+    offset: usize,
+    length: usize,
+}
+
+impl MatrixValuesSource for BytesValuesSource {
+    fn values(&self) -> &[f32] {
+        let bytes: &[u8] = (*self.bytes).as_ref();
+        bytemuck::cast_slice(&bytes[self.offset..self.offset + self.length * 4])
+    }
+}
+
+fn create_values_source(bytes: BinaryMatrixBytes, offset: usize, length: usize) -> Arc<dyn MatrixValuesSource> {
+    let slice = &(*bytes).as_ref()[offset..offset + length * 4];
+
+    if cfg!(target_endian = "little") && bytemuck::try_cast_slice::<u8, f32>(slice).is_ok() {
+        Arc::new(BytesValuesSource { bytes, offset, length })
+    } else {
+        Arc::new(
+            slice
+                .chunks_exact(4)
+                .map(|chunk| f32::from_le_bytes(chunk.try_into().expect("unexpected chunk size")))
+                .collect::<Vec<_>>(),
+        )
+    }
+}
@@ -32,6 +32,7 @@ pub(super) fn create_cluster_config(api_problem: &ApiProblem) -> Result<Option<C
                 },
                 filtering: get_filter_policy(api_problem, filtering.as_ref()),
                 building: get_builder_policy(),
+                solving: SolvingPolicy::default(),
             })),
         }
     } else {
@@ -7,12 +7,17 @@ use crate::Location as ApiLocation;
 use crate::format::UnknownLocationFallback;
 use crate::get_unique_locations;
 use crate::utils::get_approx_transportation;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use vrp_core::construction::enablers::create_typed_actor_groups;
-use vrp_core::construction::features::{VehicleCapacityDimension, VehicleSkillsDimension};
+use vrp_core::construction::features::{
+    CostTier, VehicleCapacityDimension, VehicleCostTiersDimension, VehicleSkillLevelsDimension, VehicleSkillsDimension,
+};
 use vrp_core::models::common::*;
 use vrp_core::models::problem::*;
 
+/// A default speed of the profile used to approximate routing data.
+const DEFAULT_SPEED: Float = 10.;
+
 pub(super) fn get_profile_index_map(api_problem: &ApiProblem) -> HashMap<String, usize> {
     api_problem.fleet.profiles.iter().fold(Default::default(), |mut acc, profile| {
         if !acc.contains_key(&profile.name) {
@@ -22,11 +27,8 @@ pub(super) fn get_profile_index_map(api_problem: &ApiProblem) -> HashMap<String,
     })
 }
 
-pub(super) fn create_transport_costs(
-    api_problem: &ApiProblem,
-    matrices: &[Matrix],
-    coord_index: Arc<CoordIndex>,
-) -> GenericResult<Arc<dyn TransportCost>> {
+/// Returns profile index for each routing matrix checking that matrices match fleet profiles.
+fn get_matrix_profile_indices(api_problem: &ApiProblem, matrices: &[Matrix]) -> GenericResult<Vec<usize>> {
     if !matrices.iter().all(|m| m.profile.is_some()) && !matrices.iter().all(|m| m.profile.is_none()) {
         return Err("all matrices should have profile set or none of them".into());
     }
@@ -46,13 +48,31 @@ pub(super) fn create_transport_costs(
         .into());
     }
 
-    let matrix_data = matrices
+    let profile_indices = matrices
         .iter()
         .enumerate()
-        .map(|(idx, matrix)| {
-            let profile = matrix.profile.as_ref().and_then(|p| matrix_profiles.get(p)).cloned().unwrap_or(idx);
-            (profile, matrix.timestamp.clone(), matrix)
-        })
+        .map(|(idx, matrix)| matrix.profile.as_ref().and_then(|p| matrix_profiles.get(p)).cloned().unwrap_or(idx))
+        .collect::<Vec<_>>();
+
+    if matrix_profiles.len() != profile_indices.iter().collect::<HashSet<_>>().len() {
+        return Err("amount of fleet profiles does not match matrix profiles".into());
+    }
+
+    Ok(profile_indices)
+}
+
+pub(super) fn create_transport_costs(
+    api_problem: &ApiProblem,
+    matrices: &[Matrix],
+    coord_index: Arc<CoordIndex>,
+    precision: MatrixPrecision,
+) -> GenericResult<Arc<dyn TransportCost>> {
+    let profile_indices = get_matrix_profile_indices(api_problem, matrices)?;
+
+    let matrix_data = matrices
+        .iter()
+        .zip(profile_indices)
+        .map(|(matrix, profile)| (profile, matrix.timestamp.clone(), matrix))
         .map(|(profile, timestamp, matrix)| {
             let (durations, distances) = if let Some(error_codes) = &matrix.error_codes {
                 let capacity = matrix.distances.len();
@@ -83,20 +103,88 @@ pub(super) fn create_transport_costs(
         })
         .collect::<Result<Vec<_>, GenericError>>()?;
 
-    let matrix_indices = matrix_data.iter().map(|data| data.index).collect::<HashSet<_>>().len();
-    if matrix_profiles.len() != matrix_indices {
-        return Err("amount of fleet profiles does not match matrix profiles".into());
+    if coord_index.has_custom() {
+        create_matrix_transport_cost_with_precision(matrix_data, UnknownLocationFallback::new(coord_index), precision)
+    } else {
+        create_matrix_transport_cost_with_precision(matrix_data, NoFallback, precision)
     }
+}
+
+pub(super) fn create_binary_transport_costs(
+    api_problem: &ApiProblem,
+    matrices: &[Matrix],
+    binary_matrices: &[BinaryMatrix],
+    coord_index: Arc<CoordIndex>,
+) -> GenericResult<Arc<dyn TransportCost>> {
+    let profile_indices = get_matrix_profile_indices(api_problem, matrices)?;
+
+    let matrix_data = binary_matrices
+        .iter()
+        .zip(profile_indices)
+        .map(|(matrix, profile)| {
+            let timestamp = matrix.timestamp.as_ref().map(|t| parse_time(t));
+            SharedMatrixData::new(profile, timestamp, matrix.durations(), matrix.distances())
+        })
+        .collect::<Vec<_>>();
 
     if coord_index.has_custom() {
-        create_matrix_transport_cost_with_fallback(matrix_data, UnknownLocationFallback::new(coord_index))
+        create_shared_matrix_transport_cost(matrix_data, UnknownLocationFallback::new(coord_index))
     } else {
-        create_matrix_transport_cost(matrix_data)
+        create_shared_matrix_transport_cost(matrix_data, NoFallback)
     }
 }
 
+pub(super) fn create_geo_transport_costs(
+    api_problem: &ApiProblem,
+    coord_index: Arc<CoordIndex>,
+) -> GenericResult<Arc<dyn TransportCost>> {
+    let coordinates = if coord_index.has_coordinates() {
+        (0..=coord_index.max_matrix_index())
+            .map(|idx| match coord_index.get_by_idx(idx) {
+                Some(ApiLocation::Coordinate { lat, lng }) => Ok((lat, lng)),
+                _ => Err(GenericError::from(format!("cannot get coordinate for location index: {idx}"))),
+            })
+            .collect::<GenericResult<Vec<_>>>()?
+    } else {
+        vec![]
+    };
+
+    let profile_indices = get_profile_index_map(api_problem);
+    let mut speeds = vec![DEFAULT_SPEED; profile_indices.len()];
+    api_problem.fleet.profiles.iter().rev().for_each(|profile| {
+        speeds[profile_indices[&profile.name]] = profile.speed.unwrap_or(DEFAULT_SPEED);
+    });
+
+    create_geo_transport_cost(
+        coordinates,
+        DistanceFormula::Haversine,
+        speeds,
+        UnknownLocationFallback::new(coord_index),
+    )
+}
+
+/// Keeps depot operating hours sorted by start time per location index.
+pub(super) type DepotHours = HashMap<usize, Vec<TimeWindow>>;
+
+pub(super) fn read_depot_hours(api_problem: &ApiProblem, coord_index: &CoordIndex) -> DepotHours {
+    api_problem
+        .fleet
+        .depots
+        .iter()
+        .flatten()
+        .filter_map(|depot| coord_index.get_by_loc(&depot.location).map(|location| (location, depot)))
+        .fold(DepotHours::new(), |mut acc, (location, depot)| {
+            let hours = acc.entry(location).or_default();
+            hours.extend(depot.times.iter().map(|time| parse_time_window(time)));
+            hours.sort_by(|a, b| a.start.total_cmp(&b.start));
+
+            acc
+        })
+}
+
 pub(super) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, coord_index: &CoordIndex) -> CoreFleet {
     let profile_indices = get_profile_index_map(api_problem);
+    let depot_hours = read_depot_hours(api_problem, coord_index);
     let mut vehicles: Vec<Arc<Vehicle>> = Default::default();
 
     api_problem.fleet.vehicles.iter().for_each(|vehicle| {
@@ -108,22 +196,38 @@ pub(super) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
             per_service_time: vehicle.costs.time,
         };
 
+        let cost_tiers = vehicle.costs.tiers.as_ref().filter(|tiers| !tiers.is_empty()).map(|tiers| {
+            tiers
+                .iter()
+                .map(|tier| CostTier {
+                    fixed: tier.fixed,
+                    max_duration: tier.max_duration,
+                    max_distance: tier.max_distance,
+                })
+                .collect::<Vec<_>>()
+        });
+
         let index = *profile_indices.get(&vehicle.profile.matrix).unwrap();
         let profile = Profile::new(index, vehicle.profile.scale);
 
         let tour_size = vehicle.limits.as_ref().and_then(|l| l.tour_size);
+        let max_stops = vehicle.limits.as_ref().and_then(|l| l.max_stops);
 
         for (shift_index, shift) in vehicle.shifts.iter().enumerate() {
             let start = {
                 let location = coord_index.get_by_loc(&shift.start.location).unwrap();
                 let earliest = parse_time(&shift.start.earliest);
                 let latest = shift.start.latest.as_ref().map(|time| parse_time(time));
+                let (earliest, latest) = depot_hours
+                    .get(&location)
+                    .map_or((earliest, latest), |hours| restrict_departure(hours, earliest, latest));
                 (location, earliest, latest)
             };
 
             let end = shift.end.as_ref().map(|end| {
                 let location = coord_index.get_by_loc(&end.location).unwrap();
                 let time = parse_time(&end.latest);
+                let time = depot_hours.get(&location).map_or(time, |hours| restrict_arrival(hours, time));
                 (location, time)
             });
 
@@ -150,6 +254,14 @@ pub(super) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
                     dimens.set_tour_size(tour_size);
                 }
 
+                if let Some(max_stops) = max_stops {
+                    dimens.set_max_stops(max_stops);
+                }
+
+                if let Some(cost_tiers) = cost_tiers.clone() {
+                    dimens.set_vehicle_cost_tiers(cost_tiers);
+                }
+
                 if props.has_multi_dimen_capacity {
                     dimens.set_vehicle_capacity(MultiDimLoad::new(vehicle.capacity.clone()));
                 } else {
@@ -157,7 +269,17 @@ pub(super) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
                 }
 
                 if let Some(skills) = vehicle.skills.as_ref() {
-                    dimens.set_vehicle_skills(skills.iter().cloned().collect::<HashSet<_>>());
+                    dimens.set_vehicle_skills(
+                        skills.iter().map(|skill| skill.name().to_string()).collect::<HashSet<_>>(),
+                    );
+
+                    let levels = skills
+                        .iter()
+                        .filter_map(|skill| skill.level().map(|level| (skill.name().to_string(), level)))
+                        .collect::<HashMap<_, _>>();
+                    if !levels.is_empty() {
+                        dimens.set_vehicle_skill_levels(levels);
+                    }
                 }
 
                 vehicles.push(Arc::new(Vehicle {
@@ -189,9 +311,24 @@ pub(super) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
     })
 }
 
+/// Restricts departure to the first depot operating window which is not closed at the earliest departure time.
+fn restrict_departure(
+    hours: &[TimeWindow],
+    earliest: Timestamp,
+    latest: Option<Timestamp>,
+) -> (Timestamp, Option<Timestamp>) {
+    hours.iter().find(|tw| tw.end >= earliest).map_or((earliest, latest), |tw| {
+        (earliest.max(tw.start), Some(latest.map_or(tw.end, |latest| latest.min(tw.end))))
+    })
+}
+
+/// Restricts arrival to the closing time of the last depot operating window which opens before the latest arrival.
+fn restrict_arrival(hours: &[TimeWindow], latest: Timestamp) -> Timestamp {
+    hours.iter().rev().find(|tw| tw.start <= latest).map_or(latest, |tw| tw.end.min(latest))
+}
+
 /// Creates a matrices using approximation.
 pub fn create_approx_matrices(problem: &ApiProblem) -> Vec<Matrix> {
-    const DEFAULT_SPEED: Float = 10.;
     // get each speed value once
     let speeds = problem
         .fleet
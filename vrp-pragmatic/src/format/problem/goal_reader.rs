@@ -1,8 +1,11 @@
 use super::*;
+use crate::format::problem::fleet_reader::get_profile_index_map;
 use std::ops::Mul;
 use vrp_core::algorithms::clustering::kmedoids::create_hierarchical_kmedoids;
+use vrp_core::algorithms::geometry::Point;
 use vrp_core::construction::clustering::vicinity::ClusterInfoDimension;
 use vrp_core::construction::enablers::FeatureCombinator;
+use vrp_core::construction::features::AccessRestriction as CoreAccessRestriction;
 use vrp_core::construction::features::*;
 use vrp_core::models::common::{Demand, LoadOps, MultiDimLoad, SingleDimLoad};
 use vrp_core::models::problem::{Actor, Single, TransportCost};
@@ -25,6 +28,10 @@ pub(super) fn create_goal_context(
 
     features.push(get_capacity_feature("capacity", api_problem, blocks, props)?);
 
+    if props.has_capacity_changes {
+        features.push(get_capacity_change_feature("capacity_change", blocks, props)?);
+    }
+
     if props.has_tour_travel_limits {
         features.push(get_tour_limit_feature(
             "tour_limit",
@@ -34,6 +41,10 @@ pub(super) fn create_goal_context(
         )?)
     }
 
+    if props.has_cumulative_travel_limits {
+        features.push(get_cumulative_limit_feature("cumulative_limit", api_problem, blocks.transport.clone())?)
+    }
+
     if props.has_breaks {
         features.push(create_optional_break_feature("break")?)
     }
@@ -50,6 +61,35 @@ pub(super) fn create_goal_context(
         features.push(create_compatibility_feature("compatibility", COMPATIBILITY_CONSTRAINT_CODE)?);
     }
 
+    if props.has_incompatibilities {
+        let incompatibilities = api_problem
+            .plan
+            .incompatibilities
+            .iter()
+            .flatten()
+            .map(|incompatibility| incompatibility.categories.clone())
+            .collect::<Vec<_>>();
+
+        features.push(create_incompatibility_feature(
+            "incompatibility",
+            INCOMPATIBILITY_CONSTRAINT_CODE,
+            incompatibilities.as_slice(),
+        )?);
+    }
+
+    if props.has_crews {
+        features.push(create_crew_feature(
+            "crew",
+            blocks.transport.clone(),
+            blocks.activity.clone(),
+            CREW_CONSTRAINT_CODE,
+        )?);
+    }
+
+    if props.has_separations {
+        features.push(create_separation_feature("separation", SEPARATION_CONSTRAINT_CODE)?);
+    }
+
     if props.has_group {
         features.push(create_group_feature("group", blocks.jobs.size(), GROUP_CONSTRAINT_CODE)?);
     }
@@ -75,6 +115,19 @@ pub(super) fn create_goal_context(
         )?);
     }
 
+    if props.has_stop_limits && !features.iter().any(|f| f.name == "stop_limit") {
+        features.push(create_stop_limit_hard_feature(
+            "stop_limit",
+            STOP_LIMIT_CONSTRAINT_CODE,
+            get_max_stops_fn(),
+            get_stop_filter_fn(),
+        )?);
+    }
+
+    if props.has_access_restrictions {
+        features.push(get_access_restriction_feature("access_restriction", api_problem, blocks)?);
+    }
+
     GoalContextBuilder::with_features(&features)?.set_main_goal(goal_builder.build()?).build()
 }
 
@@ -85,6 +138,9 @@ enum FeatureLayer {
 
     /// A multi feature layer: multiple competitive objectives are available for multiple features.
     Multi { composition_type: MultiStrategy, features: Vec<Feature> },
+
+    /// A weighted feature layer: multiple competitive objectives are scalarized using their weights.
+    Weighted { weights: Vec<Float>, features: Vec<Feature> },
 }
 
 fn get_objective_feature_layers(
@@ -94,10 +150,19 @@ fn get_objective_feature_layers(
 ) -> GenericResult<Vec<FeatureLayer>> {
     let objectives = get_objectives(api_problem, props);
 
-    objectives
-        .iter()
-        .map(|objective| get_objective_feature_layer(objective, blocks, props))
-        .collect::<GenericResult<_>>()
+    objectives.iter().try_fold(Vec::default(), |mut layers, objective| {
+        match objective {
+            // NOTE each priority tier has its own layer to keep strict lexicographic order between tiers
+            Objective::StrictPriority => layers.extend(
+                create_job_priority_features("strict_priority", blocks.jobs.all())?
+                    .into_iter()
+                    .map(FeatureLayer::Single),
+            ),
+            _ => layers.push(get_objective_feature_layer(objective, blocks, props)?),
+        }
+
+        Ok(layers)
+    })
 }
 
 fn get_objective_feature_layer(
@@ -110,6 +175,7 @@ fn get_objective_feature_layer(
             .set_violation_code(TIME_CONSTRAINT_CODE)
             .set_transport_cost(blocks.transport.clone())
             .set_activity_cost(blocks.activity.clone())
+            .set_place_costs(props.has_place_costs)
             .build_minimize_cost(),
         Objective::MinimizeDistance => TransportFeatureBuilder::new("min_distance")
             .set_violation_code(TIME_CONSTRAINT_CODE)
@@ -167,6 +233,9 @@ fn get_objective_feature_layer(
             .build(),
 
         Objective::MinimizeArrivalTime => create_minimize_arrival_time_feature("min_arrival_time"),
+        Objective::MinimizeWaitingTimeWithFlexibleDeparture => {
+            create_minimize_waiting_time_feature("min_waiting_time", blocks.transport.clone())
+        }
         Objective::BalanceMaxLoad => {
             if props.has_multi_dimen_capacity {
                 create_max_load_balanced_feature::<MultiDimLoad>(
@@ -198,29 +267,58 @@ fn get_objective_feature_layer(
             create_tour_compactness_feature("tour_compact", blocks.jobs.clone(), *job_radius)
         }
         Objective::TourOrder => create_tour_order_soft_feature("tour_order", get_tour_order_fn()),
+        Objective::MaxStops => create_stop_limit_soft_feature("stop_limit", get_max_stops_fn(), get_stop_filter_fn()),
+        Objective::MinimizeTourOverlap { threshold } => {
+            let coord_index = blocks.coord_index.clone();
+            create_tour_overlap_feature(
+                "tour_overlap",
+                threshold.unwrap_or_default(),
+                move |location| match coord_index.get_by_idx(location) {
+                    Some(Location::Coordinate { lat, lng }) => Some(Point::new(lng, lat)),
+                    _ => None,
+                },
+            )
+        }
+        Objective::StrictPriority => {
+            Err(GenericError::from("strict priority objective cannot be combined with other objectives"))
+        }
         Objective::FastService => get_fast_service_feature("fast_service", blocks),
+        Objective::SkillPreference => create_skill_preference_feature("skill_preference"),
         Objective::HierarchicalAreas { levels } => get_hierarchical_areas_feature(blocks, *levels),
         Objective::MultiObjective { objectives, strategy: composition_type } => {
-            let features = objectives
-                .iter()
-                .map(|o| get_objective_feature_layer(o, blocks, props))
-                .map(|layer| match layer {
-                    Ok(FeatureLayer::Single(feature)) => Ok(feature),
-                    Ok(FeatureLayer::Multi { .. }) => {
-                        Err(GenericError::from("nested composite objectives are not supported"))
-                    }
-                    Err(err) => Err(err),
-                })
-                .collect::<GenericResult<Vec<_>>>()?;
+            let features = get_competitive_features(objectives.iter(), blocks, props)?;
             let composition_type = composition_type.clone();
 
             return Ok(FeatureLayer::Multi { features, composition_type });
         }
+        Objective::WeightedTier { objectives } => {
+            let features = get_competitive_features(objectives.iter().map(|o| &o.objective), blocks, props)?;
+            let weights = objectives.iter().map(|o| o.weight).collect();
+
+            return Ok(FeatureLayer::Weighted { weights, features });
+        }
     }?;
 
     Ok(FeatureLayer::Single(feature))
 }
 
+fn get_competitive_features<'a>(
+    objectives: impl Iterator<Item = &'a Objective>,
+    blocks: &ProblemBlocks,
+    props: &ProblemProperties,
+) -> GenericResult<Vec<Feature>> {
+    objectives
+        .map(|o| get_objective_feature_layer(o, blocks, props))
+        .map(|layer| match layer {
+            Ok(FeatureLayer::Single(feature)) => Ok(feature),
+            Ok(FeatureLayer::Multi { .. } | FeatureLayer::Weighted { .. }) => {
+                Err(GenericError::from("nested composite objectives are not supported"))
+            }
+            Err(err) => Err(err),
+        })
+        .collect()
+}
+
 fn get_hierarchical_areas_feature(blocks: &ProblemBlocks, levels: usize) -> GenericResult<Feature> {
     let locations = (0..blocks.transport.size()).collect::<Vec<_>>();
     let profile =
@@ -250,10 +348,18 @@ fn get_objectives(api_problem: &ApiProblem, props: &ProblemProperties) -> Vec<Ob
         let mut objectives =
             vec![Objective::MinimizeUnassigned { breaks: Some(1.) }, Objective::MinimizeTours, Objective::MinimizeCost];
 
+        if props.has_skill_preferences {
+            objectives.insert(2, Objective::SkillPreference)
+        }
+
         if props.has_value {
             objectives.insert(0, Objective::MaximizeValue { breaks: None })
         }
 
+        if props.has_priority {
+            objectives.insert(0, Objective::StrictPriority)
+        }
+
         objectives
     }
 }
@@ -272,29 +378,42 @@ fn get_features_with_goal(feature_layers: &[FeatureLayer]) -> GenericResult<(Vec
                 (all_features, builder.add_single(objective))
             }
             FeatureLayer::Multi { composition_type, features } => {
-                let objectives = features
-                    .iter()
-                    .map(|f| {
-                        f.objective.clone().ok_or_else(|| {
-                            format!("feature '{}' has no objective while used as objective", f.name).into()
-                        })
-                    })
-                    .collect::<GenericResult<Vec<_>>>()?;
+                let objectives = get_competitive_objectives(features)?;
 
-                all_features.push(
-                    FeatureCombinator::default()
-                        .add_features(features)
-                        // objectives will be combined below using [eval_multi_objective_strategy] function
-                        .set_objective_combinator(|_| Ok(None))
-                        .combine()?,
-                );
+                all_features.push(get_combined_feature(features)?);
 
                 (all_features, eval_multi_objective_strategy(&objectives, composition_type, builder)?)
             }
+            FeatureLayer::Weighted { weights, features } => {
+                let objectives = get_competitive_objectives(features)?;
+
+                all_features.push(get_combined_feature(features)?);
+
+                (all_features, builder.add_weighted(&objectives, weights)?)
+            }
         })
     })
 }
 
+fn get_competitive_objectives(features: &[Feature]) -> GenericResult<Vec<Arc<dyn FeatureObjective>>> {
+    features
+        .iter()
+        .map(|f| {
+            f.objective
+                .clone()
+                .ok_or_else(|| format!("feature '{}' has no objective while used as objective", f.name).into())
+        })
+        .collect()
+}
+
+fn get_combined_feature(features: &[Feature]) -> GenericResult<Feature> {
+    FeatureCombinator::default()
+        .add_features(features)
+        // objectives are combined by the goal layer using [eval_multi_objective_strategy] or weights
+        .set_objective_combinator(|_| Ok(None))
+        .combine()
+}
+
 fn eval_multi_objective_strategy(
     objectives: &[Arc<dyn FeatureObjective>],
     composition_type: &MultiStrategy,
@@ -351,6 +470,35 @@ fn get_capacity_feature(
     }
 }
 
+fn get_capacity_change_feature(
+    name: &str,
+    blocks: &ProblemBlocks,
+    props: &ProblemProperties,
+) -> GenericResult<Feature> {
+    fn is_change_single(single: &Single) -> bool {
+        single.dimens.get_job_type().is_some_and(|job_type| job_type == "capacityChange")
+    }
+
+    fn create_feature<T: LoadOps>(name: &str, blocks: &ProblemBlocks) -> GenericResult<Feature> {
+        CapacityChangeFeatureBuilder::<T>::new(name)
+            .set_violation_code(CAPACITY_CHANGE_CONSTRAINT_CODE)
+            .set_transport(blocks.transport.clone())
+            .set_activity(blocks.activity.clone())
+            .set_is_change_single(is_change_single)
+            .set_belongs_to_route(|route, job| {
+                job.as_single()
+                    .is_some_and(|single| is_change_single(single.as_ref()) && is_correct_vehicle(route, single))
+            })
+            .build()
+    }
+
+    if props.has_multi_dimen_capacity {
+        create_feature::<MultiDimLoad>(name, blocks)
+    } else {
+        create_feature::<SingleDimLoad>(name, blocks)
+    }
+}
+
 fn get_fast_service_feature(name: &str, blocks: &ProblemBlocks) -> GenericResult<Feature> {
     let (transport, activity) = (blocks.transport.clone(), blocks.activity.clone());
 
@@ -450,6 +598,44 @@ fn get_tour_limit_feature(
     )
 }
 
+fn get_cumulative_limit_feature(
+    name: &str,
+    api_problem: &ApiProblem,
+    transport: Arc<dyn TransportCost>,
+) -> GenericResult<Feature> {
+    let (distances, durations) = api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .filter_map(|vehicle| vehicle.limits.as_ref().map(|limits| (vehicle, limits)))
+        .fold((HashMap::new(), HashMap::new()), |(mut distances, mut durations), (vehicle, limits)| {
+            limits.max_total_distance.iter().for_each(|max_distance| {
+                distances.insert(vehicle.type_id.clone(), *max_distance);
+            });
+
+            limits.max_total_driving_time.iter().for_each(|max_driving_time| {
+                durations.insert(vehicle.type_id.clone(), *max_driving_time);
+            });
+
+            (distances, durations)
+        });
+
+    let get_limit = |limit_map: HashMap<String, Float>| {
+        Arc::new(move |actor: &Actor| {
+            actor.vehicle.dimens.get_vehicle_type().and_then(|v_type| limit_map.get(v_type)).cloned()
+        })
+    };
+
+    create_cumulative_travel_limit_feature(
+        name,
+        transport,
+        DISTANCE_LIMIT_CONSTRAINT_CODE,
+        DURATION_LIMIT_CONSTRAINT_CODE,
+        get_limit(distances),
+        get_limit(durations),
+    )
+}
+
 fn get_recharge_feature(
     name: &str,
     api_problem: &ApiProblem,
@@ -491,6 +677,42 @@ fn get_recharge_feature(
         .build()
 }
 
+fn get_access_restriction_feature(
+    name: &str,
+    api_problem: &ApiProblem,
+    blocks: &ProblemBlocks,
+) -> GenericResult<Feature> {
+    let profile_indices = get_profile_index_map(api_problem);
+
+    let restrictions = api_problem
+        .fleet
+        .profiles
+        .iter()
+        .filter_map(|profile| profile.restrictions.as_ref().map(|restrictions| (profile, restrictions)))
+        .flat_map(|(profile, restrictions)| {
+            let profile_idx = *profile_indices.get(&profile.name).expect("cannot find profile index");
+
+            restrictions.iter().map(move |restriction| CoreAccessRestriction {
+                profile: profile_idx,
+                locations: restriction
+                    .locations
+                    .iter()
+                    .filter_map(|location| blocks.coord_index.get_by_loc(location))
+                    .collect(),
+                time: parse_time_window(&restriction.time),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    create_access_restriction_feature(
+        name,
+        ACCESS_RESTRICTION_CONSTRAINT_CODE,
+        restrictions.as_slice(),
+        blocks.transport.clone(),
+        blocks.activity.clone(),
+    )
+}
+
 fn get_reload_resources<T>(
     api_problem: &ApiProblem,
     job_index: &JobIndex,
@@ -570,12 +792,25 @@ fn create_optional_break_feature(name: &str) -> GenericResult<Feature> {
         .build()
 }
 
+fn get_max_stops_fn() -> ActivitySizeResolver {
+    Arc::new(|actor| actor.vehicle.dimens.get_max_stops().copied())
+}
+
+fn get_stop_filter_fn() -> StopFilterFn {
+    Arc::new(|single| {
+        single
+            .dimens
+            .get_job_type()
+            .is_none_or(|job_type| !matches!(job_type.as_str(), "break" | "reload" | "recharge" | "capacityChange"))
+    })
+}
+
 fn get_tour_order_fn() -> TourOrderFn {
     TourOrderFn::Left(Arc::new(|single| {
         single.dimens.get_job_order().copied().map(|order| OrderResult::Value(order as Float)).unwrap_or_else(|| {
             single.dimens.get_job_type().map_or(OrderResult::Default, |v| {
                 match v.as_str() {
-                    "break" | "reload" => OrderResult::Ignored,
+                    "break" | "reload" | "capacityChange" => OrderResult::Ignored,
                     // job without value
                     _ => OrderResult::Default,
                 }
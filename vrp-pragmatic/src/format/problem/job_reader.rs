@@ -1,5 +1,6 @@
 use crate::format::coord_index::CoordIndex;
 use crate::format::problem::JobSkills as ApiJobSkills;
+use crate::format::problem::fleet_reader::{DepotHours, get_profile_index_map, read_depot_hours};
 use crate::format::problem::*;
 use crate::format::{JobIndex, Location};
 use crate::utils::VariableJobPermutation;
@@ -7,13 +8,13 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use vrp_core::{
     construction::features::{
-        BreakPolicy, JobCompatibilityDimension, JobDemandDimension, JobGroupDimension, JobSkills as FeatureJobSkills,
-        JobSkillsDimension,
+        BreakPolicy, ChangedCapacityDimension, ChangedProfileDimension, CrewMember, JobCategoryDimension,
+        JobCompatibilityDimension, JobCrewDimension, JobDemandDimension, JobGroupDimension, JobPlaceCostsDimension,
+        JobPriorityDimension, JobSeparation, JobSeparationsDimension, JobSkillLevel, JobSkills as FeatureJobSkills,
+        JobSkillsDimension, SeparationScope,
     },
     models::common::*,
-    models::problem::{
-        Actor, Fleet, Job, JobIdDimension, Jobs, Multi, Place, Single, TransportCost, VehicleIdDimension,
-    },
+    models::problem::{Actor, Job, JobIdDimension, Multi, Place, Single, VehicleIdDimension},
     models::{Lock, LockDetail, LockOrder, LockPosition},
 };
 
@@ -27,20 +28,17 @@ pub(super) fn read_jobs_with_extra_locks(
     api_problem: &ApiProblem,
     props: &ProblemProperties,
     coord_index: &CoordIndex,
-    fleet: &Fleet,
-    transport: &(dyn TransportCost + Sync + Send),
     job_index: &mut JobIndex,
     environment: &Environment,
-) -> (Jobs, Vec<Arc<Lock>>) {
+) -> (Vec<Job>, Vec<Arc<Lock>>) {
     let random = &environment.random;
-    let logger = &environment.logger;
 
     let (mut jobs, locks) = read_required_jobs(api_problem, props, coord_index, job_index, random);
-    let conditional_jobs = read_conditional_jobs(api_problem, coord_index, job_index);
+    let conditional_jobs = read_conditional_jobs(api_problem, props, coord_index, job_index);
 
     jobs.extend(conditional_jobs);
 
-    (Jobs::new(fleet, jobs, transport, logger).unwrap(), locks)
+    (jobs, locks)
 }
 
 pub(super) fn read_locks(api_problem: &ApiProblem, job_index: &JobIndex) -> Vec<Arc<Lock>> {
@@ -49,12 +47,15 @@ pub(super) fn read_locks(api_problem: &ApiProblem, job_index: &JobIndex) -> Vec<
     }
 
     let relations: HashMap<_, Vec<_>> =
-        api_problem.plan.relations.as_ref().unwrap().iter().fold(HashMap::new(), |mut acc, r| {
-            let shift_index = r.shift_index.unwrap_or_default();
-            acc.entry((r.vehicle_id.clone(), shift_index)).or_default().push(r.clone());
+        api_problem.plan.relations.as_ref().unwrap().iter().filter(|r| !r.type_field.is_negative()).fold(
+            HashMap::new(),
+            |mut acc, r| {
+                let shift_index = r.shift_index.unwrap_or_default();
+                acc.entry((r.vehicle_id.clone(), shift_index)).or_default().push(r.clone());
 
-            acc
-        });
+                acc
+            },
+        );
 
     relations.into_iter().fold(vec![], |mut acc, ((vehicle_id, shift_index), rels)| {
         let condition = create_condition(vehicle_id.clone(), shift_index);
@@ -63,6 +64,7 @@ pub(super) fn read_locks(api_problem: &ApiProblem, job_index: &JobIndex) -> Vec<
                 RelationType::Any => LockOrder::Any,
                 RelationType::Sequence => LockOrder::Sequence,
                 RelationType::Strict => LockOrder::Strict,
+                RelationType::NotSameTour | RelationType::DifferentVehicle => unreachable!("negative relation"),
             };
 
             let position = match (rel.jobs.first().map(|s| s.as_str()), rel.jobs.last().map(|s| s.as_str())) {
@@ -78,7 +80,7 @@ pub(super) fn read_locks(api_problem: &ApiProblem, job_index: &JobIndex) -> Vec<
                 .filter(|job| job.as_str() != "departure" && job.as_str() != "arrival")
                 .fold((HashMap::<String, _>::default(), vec![]), |(mut indexer, mut jobs), job| {
                     let job_id = match job.as_str() {
-                        "break" | "reload" | "recharge" => {
+                        "break" | "reload" | "recharge" | "capacityChange" => {
                             let entry = indexer.entry(job.clone()).or_insert(1_usize);
                             let job_index = *entry;
                             *entry += 1;
@@ -114,6 +116,7 @@ fn read_required_jobs(
 ) -> (Vec<Job>, Vec<Arc<Lock>>) {
     let mut jobs = vec![];
     let has_multi_dimens = props.has_multi_dimen_capacity;
+    let separations = read_separations(api_problem);
 
     let get_single_from_task = |task: &JobTask, activity_type: &str, is_static_demand: bool| {
         let absent = (empty(), empty());
@@ -134,7 +137,14 @@ fn read_required_jobs(
             .map(|p| (Some(p.location.clone()), p.duration, parse_times(&p.times), p.tag.clone()))
             .collect();
 
-        get_single_with_dimens(places, demand, &task.order, activity_type, has_multi_dimens, coord_index)
+        let mut single =
+            get_single_with_dimens(places, demand, &task.order, activity_type, has_multi_dimens, coord_index);
+
+        if task.places.iter().any(|place| place.cost.is_some()) {
+            single.dimens.set_job_place_costs(task.places.iter().map(|place| place.cost.unwrap_or_default()).collect());
+        }
+
+        single
     };
 
     api_problem.plan.jobs.iter().for_each(|job| {
@@ -163,11 +173,29 @@ fn read_required_jobs(
 
         assert!(!singles.is_empty());
 
+        if let Some(size) = job.crew.filter(|&size| size > 1 && singles.len() == 1) {
+            let single = singles.into_iter().next().unwrap();
+
+            // NOTE each crew member is a separate job, only the first one is accessible by the original id
+            (1..=size).for_each(|member_idx| {
+                let mut member = Single { places: single.places.clone(), dimens: single.dimens.clone() };
+                member.dimens.set_job_crew(CrewMember { id: job.id.clone(), size });
+
+                let member = get_single_job(job, separations.get(&job.id), member);
+                let member_id = if member_idx == 1 { job.id.clone() } else { format!("{}_crew_{member_idx}", job.id) };
+
+                job_index.insert(member_id, member.clone());
+                jobs.push(member);
+            });
+
+            return;
+        }
+
         let problem_job = if singles.len() > 1 {
             let deliveries_start_index = job.pickups.as_ref().map_or(0, |p| p.len());
-            get_multi_job(job, singles, deliveries_start_index, random)
+            get_multi_job(job, separations.get(&job.id), singles, deliveries_start_index, random)
         } else {
-            get_single_job(job, singles.into_iter().next().unwrap())
+            get_single_job(job, separations.get(&job.id), singles.into_iter().next().unwrap())
         };
 
         job_index.insert(job.id.clone(), problem_job.clone());
@@ -177,8 +205,15 @@ fn read_required_jobs(
     (jobs, vec![])
 }
 
-fn read_conditional_jobs(api_problem: &ApiProblem, coord_index: &CoordIndex, job_index: &mut JobIndex) -> Vec<Job> {
+fn read_conditional_jobs(
+    api_problem: &ApiProblem,
+    props: &ProblemProperties,
+    coord_index: &CoordIndex,
+    job_index: &mut JobIndex,
+) -> Vec<Job> {
     let mut jobs = vec![];
+    let depot_hours = read_depot_hours(api_problem, coord_index);
+    let profile_indices = get_profile_index_map(api_problem);
 
     api_problem.fleet.vehicles.iter().for_each(|vehicle| {
         for (shift_index, shift) in vehicle.shifts.iter().enumerate() {
@@ -187,12 +222,18 @@ fn read_conditional_jobs(api_problem: &ApiProblem, coord_index: &CoordIndex, job
             }
 
             if let Some(reloads) = &shift.reloads {
-                read_reloads(coord_index, job_index, &mut jobs, vehicle, shift_index, reloads);
+                read_reloads(coord_index, &depot_hours, job_index, &mut jobs, vehicle, shift_index, reloads);
             }
 
             if let Some(recharges) = &shift.recharges {
                 read_recharges(coord_index, job_index, &mut jobs, vehicle, shift_index, recharges);
             }
+
+            if let Some(change) = &shift.capacity_change {
+                read_capacity_change(coord_index, props, &profile_indices, vehicle, shift_index, change)
+                    .into_iter()
+                    .for_each(|(job_id, single)| add_conditional_job(job_index, &mut jobs, job_id, single));
+            }
         }
     });
 
@@ -257,6 +298,7 @@ fn read_optional_breaks(
 
 fn read_reloads(
     coord_index: &CoordIndex,
+    depot_hours: &DepotHours,
     job_index: &mut JobIndex,
     jobs: &mut Vec<Job>,
     vehicle: &VehicleType,
@@ -270,11 +312,29 @@ fn read_reloads(
         jobs,
         vehicle,
         shift_index,
-        reloads.iter().map(|reload| JobPlace {
-            location: reload.location.clone(),
-            duration: reload.duration,
-            times: reload.times.clone(),
-            tag: reload.tag.clone(),
+        reloads.iter().map(|reload| {
+            let hours = coord_index.get_by_loc(&reload.location).and_then(|location| depot_hours.get(&location));
+            let times = match (hours, reload.times.as_ref()) {
+                (Some(hours), None) => hours.iter().cloned().map(TimeSpan::Window).collect(),
+                // NOTE reload cannot be served when there is no overlap with depot hours
+                (Some(hours), Some(times)) => times
+                    .iter()
+                    .map(|time| parse_time_window(time))
+                    .flat_map(|time| hours.iter().filter_map(move |hour| time.overlapping(hour)))
+                    .map(TimeSpan::Window)
+                    .collect(),
+                (None, _) => parse_times(&reload.times),
+            };
+
+            let place = JobPlace {
+                location: reload.location.clone(),
+                duration: reload.duration,
+                times: reload.times.clone(),
+                tag: reload.tag.clone(),
+                cost: None,
+            };
+
+            (place, times)
         }),
     )
 }
@@ -294,10 +354,53 @@ fn read_recharges(
         jobs,
         vehicle,
         shift_index,
-        recharges.stations.iter().cloned(),
+        recharges.stations.iter().map(|station| (station.clone(), parse_times(&station.times))),
     )
 }
 
+fn read_capacity_change(
+    coord_index: &CoordIndex,
+    props: &ProblemProperties,
+    profile_indices: &HashMap<String, usize>,
+    vehicle: &VehicleType,
+    shift_index: usize,
+    change: &VehicleCapacityChange,
+) -> Vec<(String, Single)> {
+    let job_type = "capacityChange";
+    let profile = change.profile.as_ref().and_then(|profile| {
+        profile_indices.get(&profile.matrix).map(|&index| Profile { index, scale: profile.scale.unwrap_or(1.) })
+    });
+
+    vehicle
+        .vehicle_ids
+        .iter()
+        .map(|vehicle_id| {
+            let job_id = format!("{vehicle_id}_{job_type}_{shift_index}_1");
+
+            let mut single = get_conditional_job(
+                coord_index,
+                vehicle_id.clone(),
+                &job_id,
+                job_type,
+                shift_index,
+                vec![(Some(change.location.clone()), change.duration, parse_times(&change.times), change.tag.clone())],
+            );
+
+            if props.has_multi_dimen_capacity {
+                single.dimens.set_changed_capacity(MultiDimLoad::new(change.capacity.clone()));
+            } else {
+                single.dimens.set_changed_capacity(SingleDimLoad::new(change.capacity[0]));
+            }
+
+            if let Some(profile) = profile.clone() {
+                single.dimens.set_changed_profile(profile);
+            }
+
+            (job_id, single)
+        })
+        .collect()
+}
+
 fn read_specific_job_places(
     job_type: &str,
     coord_index: &CoordIndex,
@@ -305,17 +408,16 @@ fn read_specific_job_places(
     jobs: &mut Vec<Job>,
     vehicle: &VehicleType,
     shift_index: usize,
-    get_places: impl Iterator<Item = JobPlace>,
+    get_places: impl Iterator<Item = (JobPlace, Vec<TimeSpan>)>,
 ) {
     (1..)
         .zip(get_places)
-        .flat_map(|(place_idx, place)| {
+        .flat_map(|(place_idx, (place, times))| {
             vehicle
                 .vehicle_ids
                 .iter()
                 .map(|vehicle_id| {
                     let job_id = format!("{vehicle_id}_{job_type}_{shift_index}_{place_idx}");
-                    let times = parse_times(&place.times);
 
                     let job = get_conditional_job(
                         coord_index,
@@ -323,7 +425,7 @@ fn read_specific_job_places(
                         &job_id,
                         job_type,
                         shift_index,
-                        vec![(Some(place.location.clone()), place.duration, times, place.tag.clone())],
+                        vec![(Some(place.location.clone()), place.duration, times.clone(), place.tag.clone())],
                     );
 
                     (job_id, job)
@@ -410,7 +512,30 @@ fn get_single_with_dimens(
     single
 }
 
-fn fill_dimens(job: &ApiJob, dimens: &mut Dimensions) {
+fn read_separations(api_problem: &ApiProblem) -> HashMap<String, Vec<JobSeparation>> {
+    api_problem
+        .plan
+        .relations
+        .iter()
+        .flatten()
+        .enumerate()
+        .filter_map(|(group, relation)| {
+            let scope = match relation.type_field {
+                RelationType::NotSameTour => SeparationScope::Tour,
+                RelationType::DifferentVehicle => SeparationScope::Vehicle,
+                _ => return None,
+            };
+
+            Some(relation.jobs.iter().map(move |job_id| (job_id.clone(), JobSeparation { group, scope })))
+        })
+        .flatten()
+        .fold(HashMap::new(), |mut acc, (job_id, separation)| {
+            acc.entry(job_id).or_insert_with(Vec::new).push(separation);
+            acc
+        })
+}
+
+fn fill_dimens(job: &ApiJob, separations: Option<&Vec<JobSeparation>>, dimens: &mut Dimensions) {
     dimens.set_job_id(job.id.clone());
 
     if let Some(value) = job.value {
@@ -428,18 +553,36 @@ fn fill_dimens(job: &ApiJob, dimens: &mut Dimensions) {
     if let Some(skills) = get_skills(&job.skills) {
         dimens.set_job_skills(skills);
     }
+
+    if let Some(priority) = job.priority {
+        dimens.set_job_priority(priority);
+    }
+
+    if let Some(category) = job.category.clone() {
+        dimens.set_job_category(category);
+    }
+
+    if let Some(separations) = separations {
+        dimens.set_job_separations(separations.clone());
+    }
 }
 
-fn get_single_job(job: &ApiJob, single: Single) -> Job {
+fn get_single_job(job: &ApiJob, separations: Option<&Vec<JobSeparation>>, single: Single) -> Job {
     let mut single = single;
-    fill_dimens(job, &mut single.dimens);
+    fill_dimens(job, separations, &mut single.dimens);
 
     Job::Single(Arc::new(single))
 }
 
-fn get_multi_job(job: &ApiJob, singles: Vec<Single>, deliveries_start_index: usize, random: &Arc<dyn Random>) -> Job {
+fn get_multi_job(
+    job: &ApiJob,
+    separations: Option<&Vec<JobSeparation>>,
+    singles: Vec<Single>,
+    deliveries_start_index: usize,
+    random: &Arc<dyn Random>,
+) -> Job {
     let mut dimens: Dimensions = Default::default();
-    fill_dimens(job, &mut dimens);
+    fill_dimens(job, separations, &mut dimens);
 
     let singles = singles.into_iter().map(Arc::new).collect::<Vec<_>>();
 
@@ -470,9 +613,19 @@ fn create_condition(vehicle_id: String, shift_index: usize) -> Arc<dyn Fn(&Actor
 }
 
 fn get_skills(skills: &Option<ApiJobSkills>) -> Option<FeatureJobSkills> {
-    skills
-        .as_ref()
-        .map(|skills| FeatureJobSkills::new(skills.all_of.clone(), skills.one_of.clone(), skills.none_of.clone()))
+    skills.as_ref().map(|skills| {
+        let levels = skills
+            .levels
+            .iter()
+            .flatten()
+            .map(|level| {
+                let min = level.min_level.unwrap_or_default();
+                (level.name.clone(), JobSkillLevel { min, preferred: level.preferred_level })
+            })
+            .collect();
+
+        FeatureJobSkills::new(skills.all_of.clone(), skills.one_of.clone(), skills.none_of.clone()).with_levels(levels)
+    })
 }
 
 fn empty() -> MultiDimLoad {
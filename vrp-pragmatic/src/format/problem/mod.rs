@@ -6,7 +6,9 @@ use std::io::{BufReader, Read};
 use std::sync::Arc;
 use vrp_core::models::Lock;
 use vrp_core::models::common::TimeWindow;
+use vrp_core::models::problem::{JobIndexCache, MatrixPrecision};
 use vrp_core::prelude::{ActivityCost, Fleet as CoreFleet, Jobs as CoreJobs, TransportCost};
+use vrp_core::solver::search::ArcPenalties;
 use vrp_core::utils::*;
 
 pub(crate) type ApiProblem = Problem;
@@ -18,6 +20,9 @@ pub use self::model::*;
 #[path = "../../../tests/unit/format/problem/reader_test.rs"]
 mod reader_test;
 
+mod binary_matrix;
+pub use self::binary_matrix::*;
+
 mod clustering_reader;
 
 mod fleet_reader;
@@ -27,7 +32,34 @@ mod goal_reader;
 mod job_reader;
 
 mod problem_reader;
-use self::problem_reader::{map_to_problem_with_approx, map_to_problem_with_matrices};
+use self::problem_reader::{
+    map_to_problem_with_approx, map_to_problem_with_binary_matrices, map_to_problem_with_matrices,
+};
+
+mod stream_reader;
+pub use self::stream_reader::deserialize_problem_with_jobs;
+
+/// Specifies how routing data is estimated when no routing matrices are provided.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum RoutingApproximation {
+    /// Routing matrices are precalculated from location coordinates using profile speed.
+    /// Matrix values are rounded.
+    #[default]
+    Matrix,
+    /// Routing data is calculated on demand from location coordinates using great-circle distance
+    /// and profile speed, so no routing matrices are kept in memory.
+    Geo,
+}
+
+/// Specifies optional settings used to read a problem.
+#[derive(Clone, Default)]
+pub struct ProblemReadOptions {
+    /// A cache of job index which is used to skip its calculation for already seen problems.
+    pub job_index_cache: Option<Arc<dyn JobIndexCache>>,
+    /// Arc penalties used by guided local search. When specified, transport costs are wrapped to be
+    /// biased by them and penalties are stored within problem extras.
+    pub arc_penalties: Option<Arc<ArcPenalties>>,
+}
 
 /// Reads specific problem definition from various sources.
 pub trait PragmaticProblem {
@@ -44,7 +76,20 @@ impl<R: Read> PragmaticProblem for (BufReader<R>, Vec<BufReader<R>>) {
             matrices.push(deserialize_matrix(matrix)?);
         }
 
-        map_to_problem_with_matrices(problem, matrices)
+        map_to_problem_with_matrices(problem, matrices, MatrixPrecision::default(), Default::default())
+    }
+}
+
+impl<R: Read> PragmaticProblem for (BufReader<R>, Vec<BufReader<R>>, MatrixPrecision) {
+    fn read_pragmatic(self) -> Result<CoreProblem, MultiFormatError> {
+        let problem = deserialize_problem(self.0)?;
+
+        let mut matrices = vec![];
+        for matrix in self.1 {
+            matrices.push(deserialize_matrix(matrix)?);
+        }
+
+        map_to_problem_with_matrices(problem, matrices, self.2, Default::default())
     }
 }
 
@@ -52,7 +97,7 @@ impl<R: Read> PragmaticProblem for BufReader<R> {
     fn read_pragmatic(self) -> Result<CoreProblem, MultiFormatError> {
         let problem = deserialize_problem(self)?;
 
-        map_to_problem_with_approx(problem)
+        map_to_problem_with_approx(problem, RoutingApproximation::default(), Default::default())
     }
 }
 
@@ -65,7 +110,7 @@ impl PragmaticProblem for (String, Vec<String>) {
             matrices.push(deserialize_matrix(BufReader::new(matrix.as_bytes()))?);
         }
 
-        map_to_problem_with_matrices(problem, matrices)
+        map_to_problem_with_matrices(problem, matrices, MatrixPrecision::default(), Default::default())
     }
 }
 
@@ -73,19 +118,70 @@ impl PragmaticProblem for String {
     fn read_pragmatic(self) -> Result<CoreProblem, MultiFormatError> {
         let problem = deserialize_problem(BufReader::new(self.as_bytes()))?;
 
-        map_to_problem_with_approx(problem)
+        map_to_problem_with_approx(problem, RoutingApproximation::default(), Default::default())
     }
 }
 
 impl PragmaticProblem for (ApiProblem, Vec<Matrix>) {
     fn read_pragmatic(self) -> Result<CoreProblem, MultiFormatError> {
-        map_to_problem_with_matrices(self.0, self.1)
+        map_to_problem_with_matrices(self.0, self.1, MatrixPrecision::default(), Default::default())
+    }
+}
+
+impl PragmaticProblem for (ApiProblem, Vec<Matrix>, MatrixPrecision) {
+    fn read_pragmatic(self) -> Result<CoreProblem, MultiFormatError> {
+        map_to_problem_with_matrices(self.0, self.1, self.2, Default::default())
+    }
+}
+
+impl PragmaticProblem for (ApiProblem, Vec<BinaryMatrix>) {
+    fn read_pragmatic(self) -> Result<CoreProblem, MultiFormatError> {
+        map_to_problem_with_binary_matrices(self.0, self.1, Default::default())
     }
 }
 
 impl PragmaticProblem for ApiProblem {
     fn read_pragmatic(self) -> Result<CoreProblem, MultiFormatError> {
-        map_to_problem_with_approx(self)
+        map_to_problem_with_approx(self, RoutingApproximation::default(), Default::default())
+    }
+}
+
+impl PragmaticProblem for (ApiProblem, RoutingApproximation) {
+    fn read_pragmatic(self) -> Result<CoreProblem, MultiFormatError> {
+        map_to_problem_with_approx(self.0, self.1, Default::default())
+    }
+}
+
+impl<R: Read> PragmaticProblem for (BufReader<R>, ProblemReadOptions) {
+    fn read_pragmatic(self) -> Result<CoreProblem, MultiFormatError> {
+        let problem = deserialize_problem(self.0)?;
+
+        map_to_problem_with_approx(problem, RoutingApproximation::default(), self.1)
+    }
+}
+
+impl<R: Read> PragmaticProblem for (BufReader<R>, Vec<BufReader<R>>, ProblemReadOptions) {
+    fn read_pragmatic(self) -> Result<CoreProblem, MultiFormatError> {
+        let problem = deserialize_problem(self.0)?;
+
+        let mut matrices = vec![];
+        for matrix in self.1 {
+            matrices.push(deserialize_matrix(matrix)?);
+        }
+
+        map_to_problem_with_matrices(problem, matrices, MatrixPrecision::default(), self.2)
+    }
+}
+
+impl PragmaticProblem for (ApiProblem, Vec<Matrix>, ProblemReadOptions) {
+    fn read_pragmatic(self) -> Result<CoreProblem, MultiFormatError> {
+        map_to_problem_with_matrices(self.0, self.1, MatrixPrecision::default(), self.2)
+    }
+}
+
+impl PragmaticProblem for (ApiProblem, Vec<BinaryMatrix>, ProblemReadOptions) {
+    fn read_pragmatic(self) -> Result<CoreProblem, MultiFormatError> {
+        map_to_problem_with_binary_matrices(self.0, self.1, self.2)
     }
 }
 
@@ -100,15 +196,25 @@ struct ProblemProperties {
     has_multi_dimen_capacity: bool,
     has_breaks: bool,
     has_skills: bool,
+    has_skill_preferences: bool,
     has_unreachable_locations: bool,
     has_reloads: bool,
     has_recharges: bool,
+    has_capacity_changes: bool,
     has_order: bool,
     has_group: bool,
     has_value: bool,
+    has_priority: bool,
     has_compatibility: bool,
+    has_incompatibilities: bool,
     has_tour_size_limits: bool,
+    has_stop_limits: bool,
+    has_access_restrictions: bool,
     has_tour_travel_limits: bool,
+    has_cumulative_travel_limits: bool,
+    has_place_costs: bool,
+    has_crews: bool,
+    has_separations: bool,
 }
 
 /// Keeps track of materialized problem building blocks.
@@ -120,6 +226,8 @@ struct ProblemBlocks {
     activity: Arc<dyn ActivityCost>,
     locks: Vec<Arc<Lock>>,
     reserved_times_index: ReservedTimesIndex,
+    coord_index: Arc<CoordIndex>,
+    arc_penalties: Option<Arc<ArcPenalties>>,
 }
 
 fn parse_time_window(tw: &[String]) -> TimeWindow {
@@ -4,7 +4,7 @@ mod model_test;
 
 extern crate serde_json;
 
-use crate::format::{FormatError, Location, MultiFormatError};
+use crate::format::{FormatError, Location, MultiFormatError, Versioned};
 use serde::{Deserialize, Serialize};
 use std::io::{BufReader, BufWriter, Error, Read, Write};
 use vrp_core::prelude::Float;
@@ -20,9 +20,22 @@ pub enum RelationType {
     Sequence,
     /// Relation type which locks jobs in strict order, no insertions in between are allowed.
     Strict,
+    /// Relation type which forbids jobs to be assigned to the same tour.
+    #[serde(rename = "not-same-tour")]
+    NotSameTour,
+    /// Relation type which forbids jobs to be assigned to the same vehicle, including its different shifts.
+    #[serde(rename = "different-vehicle")]
+    DifferentVehicle,
+}
+
+impl RelationType {
+    /// Returns true if relation type forbids jobs to be assigned together instead of locking them to a vehicle.
+    pub fn is_negative(&self) -> bool {
+        matches!(self, RelationType::NotSameTour | RelationType::DifferentVehicle)
+    }
 }
 
-/// Relation is the way to lock specific jobs to specific vehicles.
+/// Relation is the way to lock specific jobs to specific vehicles or to keep them apart.
 #[derive(Clone, Deserialize, Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Relation {
@@ -31,7 +44,8 @@ pub struct Relation {
     pub type_field: RelationType,
     /// List of job ids.
     pub jobs: Vec<String>,
-    /// Vehicle id.
+    /// Vehicle id. Not used by negative relation types.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub vehicle_id: String,
     /// Vehicle shift index.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -51,6 +65,25 @@ pub struct JobSkills {
     /// Vehicle should have none of these skills defined.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub none_of: Option<Vec<String>>,
+    /// Vehicle should have these skills with at least minimum level, higher levels can be preferred.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub levels: Option<Vec<JobSkillLevel>>,
+}
+
+/// A job requirement for a vehicle skill level.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobSkillLevel {
+    /// A skill name.
+    pub name: String,
+    /// A minimum skill level which vehicle should have.
+    /// No hard requirement when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_level: Option<usize>,
+    /// A preferred skill level: vehicles with lower level are penalized by skill preference objective.
+    /// No preference when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preferred_level: Option<usize>,
 }
 
 /// Specifies a place for sub job.
@@ -67,6 +100,10 @@ pub struct JobPlace {
     /// You can use it to identify used place in solution.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tag: Option<String>,
+    /// An extra cost of serving the job at this place. Allows to express a preference between
+    /// alternative places, e.g. locker vs home delivery.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cost: Option<Float>,
 }
 
 /// Specifies a job task.
@@ -122,6 +159,21 @@ pub struct Job {
     /// A compatibility group: jobs with different compatibility cannot be assigned to the same tour.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub compatibility: Option<String>,
+
+    /// A priority tier, lower value - higher priority. Jobs of lower tier are not assigned while
+    /// jobs of higher tier can be assigned instead.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<usize>,
+
+    /// A job category: jobs of incompatible categories, defined on plan level, cannot be assigned
+    /// to the same tour.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+
+    /// An amount of vehicles which have to serve the job simultaneously: each vehicle visits the
+    /// job place and starts its service at the same time. Default is one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub crew: Option<usize>,
 }
 
 // region Clustering
@@ -225,6 +277,18 @@ pub struct Plan {
     /// Specifies clustering parameters.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub clustering: Option<Clustering>,
+
+    /// List of incompatibilities between job categories.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub incompatibilities: Option<Vec<JobIncompatibility>>,
+}
+
+/// Specifies job categories which are incompatible with each other: jobs of any two different
+/// categories from the list cannot be assigned to the same tour.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct JobIncompatibility {
+    /// Incompatible job categories.
+    pub categories: Vec<String>,
 }
 
 // endregion
@@ -243,6 +307,27 @@ pub struct VehicleCosts {
 
     /// Cost per time unit.
     pub time: Float,
+
+    /// Fixed cost tiers: the cheapest tier whose limits are not exceeded by the tour is used instead
+    /// of the default fixed cost.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tiers: Option<Vec<VehicleCostTier>>,
+}
+
+/// Specifies vehicle fixed cost tier, e.g. a rental contract which is cheaper for short tours.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleCostTier {
+    /// Fixed cost of vehicle usage per tour which stays within the tier limits.
+    pub fixed: Float,
+
+    /// Max tour duration of the tier. No duration limit when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_duration: Option<Float>,
+
+    /// Max tour distance of the tier. No distance limit when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_distance: Option<Float>,
 }
 
 /// Specifies vehicle shift start.
@@ -298,6 +383,10 @@ pub struct VehicleShift {
     /// Vehicle recharge stations information.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub recharges: Option<VehicleRecharges>,
+
+    /// A planned capacity change in the middle of the shift, e.g. a trailer drop.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capacity_change: Option<VehicleCapacityChange>,
 }
 
 /// Specifies a place where vehicle can load or unload cargo.
@@ -337,6 +426,71 @@ pub struct VehicleRecharges {
 /// Specifies type alias for vehicle recharge station.
 pub type VehicleRechargeStation = JobPlace;
 
+/// Specifies a place where vehicle changes its capacity and, optionally, routing profile.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleCapacityChange {
+    /// A place location.
+    pub location: Location,
+
+    /// A capacity change duration (service time).
+    pub duration: Float,
+
+    /// A list of time windows with time specified in RFC3339 format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub times: Option<Vec<Vec<String>>>,
+
+    /// Vehicle capacity after the change.
+    pub capacity: Vec<i32>,
+
+    /// Vehicle routing profile after the change. If not specified, the vehicle profile is kept.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile: Option<VehicleProfile>,
+
+    /// A tag which will be propagated back within corresponding activity in solution.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+}
+
+/// Vehicle skill: either a skill name or a skill with its level.
+#[derive(Clone, Deserialize, Debug, Serialize, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum VehicleSkill {
+    /// A skill name, its level is considered as the lowest one.
+    Name(String),
+    /// A skill with level.
+    Level {
+        /// A skill name.
+        name: String,
+        /// A skill level.
+        level: usize,
+    },
+}
+
+impl VehicleSkill {
+    /// Returns skill name.
+    pub fn name(&self) -> &str {
+        match self {
+            VehicleSkill::Name(name) => name,
+            VehicleSkill::Level { name, .. } => name,
+        }
+    }
+
+    /// Returns skill level, if specified.
+    pub fn level(&self) -> Option<usize> {
+        match self {
+            VehicleSkill::Name(_) => None,
+            VehicleSkill::Level { level, .. } => Some(*level),
+        }
+    }
+}
+
+impl From<&str> for VehicleSkill {
+    fn from(name: &str) -> Self {
+        VehicleSkill::Name(name.to_string())
+    }
+}
+
 /// Vehicle limits.
 #[derive(Clone, Deserialize, Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -356,6 +510,20 @@ pub struct VehicleLimits {
     /// No job activities restrictions when omitted.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tour_size: Option<usize>,
+
+    /// Max amount of stops: job activities except breaks, reloads and recharges.
+    /// No stop restrictions when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_stops: Option<usize>,
+    /// Max traveling distance of the vehicle across all its shifts.
+    /// No cumulative distance restrictions when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_total_distance: Option<Float>,
+
+    /// Max driving time of the vehicle across all its shifts. Waiting, service and break times are not counted.
+    /// No cumulative driving time restrictions when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_total_driving_time: Option<Float>,
 }
 
 /// Vehicle optional break time variant.
@@ -460,7 +628,7 @@ pub struct VehicleType {
 
     /// Vehicle skills.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub skills: Option<Vec<String>>,
+    pub skills: Option<Vec<VehicleSkill>>,
 
     /// Vehicle limits.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -489,6 +657,21 @@ pub struct MatrixProfile {
     /// Default value is 10.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub speed: Option<Float>,
+
+    /// Time dependent access restrictions for vehicles which use the profile.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restrictions: Option<Vec<AccessRestriction>>,
+}
+
+/// Specifies time dependent access restriction: vehicles are not allowed to arrive at
+/// given locations within given time window.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct AccessRestriction {
+    /// Restricted locations.
+    pub locations: Vec<Location>,
+
+    /// A time window when arrival at restricted locations is not allowed.
+    pub time: Vec<String>,
 }
 
 /// Specifies vehicle resource type.
@@ -517,6 +700,20 @@ pub struct Fleet {
     /// Specifies vehicle resources.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub resources: Option<Vec<VehicleResource>>,
+
+    /// Specifies depots with operating hours.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depots: Option<Vec<Depot>>,
+}
+
+/// Specifies a depot: a location where vehicles can depart, return or reload only within its operating hours.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct Depot {
+    /// A depot location.
+    pub location: Location,
+
+    /// A list of operating time windows with time specified in RFC3339 format.
+    pub times: Vec<Vec<String>>,
 }
 
 // endregion
@@ -560,6 +757,10 @@ pub enum Objective {
     /// An objective to minimize sum of arrival times from all routes.
     MinimizeArrivalTime,
 
+    /// An objective to minimize total waiting time considering that tours can depart later
+    /// than their shift start in order to avoid waiting.
+    MinimizeWaitingTimeWithFlexibleDeparture,
+
     /// An objective to balance max load across all tours.
     BalanceMaxLoad,
 
@@ -581,9 +782,25 @@ pub enum Objective {
     /// An objective to control order of job activities in the tour.
     TourOrder,
 
+    /// An objective to penalize stops above vehicle's `maxStops` limit instead of rejecting them.
+    MaxStops,
+
+    /// An objective to penalize tours which territories (convex hulls of job locations) overlap.
+    MinimizeTourOverlap {
+        /// Specifies a tolerated relative overlap of two tours in `[0, 1)` range. Default is 0.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        threshold: Option<Float>,
+    },
+
+    /// An objective to assign jobs strictly according to their priority tiers.
+    StrictPriority,
+
     /// An objective to prefer jobs to be served as soon as possible.
     FastService,
 
+    /// An objective to prefer vehicles with skill levels not lower than preferred by jobs.
+    SkillPreference,
+
     /// An objective to consider hierarchy of areas while serving jobs.
     HierarchicalAreas {
         /// Number of levels in area hierarchy.
@@ -597,6 +814,25 @@ pub enum Objective {
         /// Competitive objectives except `Composite` type (nesting is currently not supported).
         objectives: Vec<Objective>,
     },
+
+    /// A weighted tier defines multiple competitive objectives at the same layer of hierarchy which are
+    /// scalarized into a single value using their weights: solutions are compared by weighted sum of
+    /// objective values, so a worse value of one objective can be traded for a better value of another.
+    WeightedTier {
+        /// Weighted objectives except composite types (nesting is currently not supported).
+        objectives: Vec<WeightedObjective>,
+    },
+}
+
+/// An objective with its weight used within weighted tier.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct WeightedObjective {
+    /// An objective.
+    #[serde(flatten)]
+    pub objective: Objective,
+
+    /// A non-negative weight of the objective.
+    pub weight: Float,
 }
 
 /// An mupltiple objective strategy type specifies how competitive objective functions are compared
@@ -630,6 +866,10 @@ pub struct Problem {
     /// Specifies objective functions in lexicographical order.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub objectives: Option<Vec<Objective>>,
+
+    /// Specifies UTC offset, e.g. `+02:00`, used to interpret date-only values. Default is UTC.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
 }
 
 /// A routing matrix.
@@ -704,7 +944,7 @@ pub fn deserialize_locations<R: Read>(reader: BufReader<R>) -> Result<Vec<Locati
     })
 }
 
-/// Serializes `problem` in json from `writer`.
+/// Serializes `problem` in json from `writer` with `schemaVersion` property.
 pub fn serialize_problem<W: Write>(problem: &Problem, writer: &mut BufWriter<W>) -> Result<(), Error> {
-    serde_json::to_writer_pretty(writer, problem).map_err(Error::from)
+    serde_json::to_writer_pretty(writer, &Versioned::new(problem)).map_err(Error::from)
 }
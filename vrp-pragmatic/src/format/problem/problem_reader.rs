@@ -3,36 +3,94 @@ use crate::format::problem::clustering_reader::create_cluster_config;
 use crate::format::problem::fleet_reader::*;
 use crate::format::problem::goal_reader::create_goal_context;
 use crate::format::problem::job_reader::{read_jobs_with_extra_locks, read_locks};
-use crate::format::{FormatError, JobIndex};
+use crate::format::{FormatError, JobIndex, TimeFormat, normalize_times, set_time_format};
 use crate::validation::ValidationContext;
 use crate::{CoordIndex, parse_time};
 use vrp_core::construction::enablers::*;
+use vrp_core::construction::features::ProfileChangeTransportCost;
 use vrp_core::models::Extras;
-use vrp_core::models::common::{TimeOffset, TimeSpan, TimeWindow};
+use vrp_core::models::common::{Profile, TimeOffset, TimeSpan, TimeWindow};
+use vrp_core::models::problem::{Actor, JobClusteringMode, JobIndexMode};
 use vrp_core::solver::processing::{ClusterConfigExtraProperty, ReservedTimesExtraProperty};
+use vrp_core::solver::search::{ArcPenaltiesExtraProperty, GuidedTransportCost};
+
+/// Specifies how transport costs are created.
+pub(super) enum TransportMode {
+    /// Transport costs are created from routing matrices stored with given precision.
+    Matrix(MatrixPrecision),
+    /// Transport costs are created from binary routing matrices without copying their values.
+    Binary(Vec<BinaryMatrix>),
+    /// Transport costs are estimated from location coordinates on demand.
+    Geo,
+}
 
-pub(super) fn map_to_problem_with_approx(problem: ApiProblem) -> Result<CoreProblem, MultiFormatError> {
+pub(super) fn map_to_problem_with_approx(
+    problem: ApiProblem,
+    approximation: RoutingApproximation,
+    options: ProblemReadOptions,
+) -> Result<CoreProblem, MultiFormatError> {
     let coord_index = CoordIndex::new(&problem);
-    let matrices = if coord_index.has_indices() { vec![] } else { create_approx_matrices(&problem) };
-    map_to_problem(problem, matrices, coord_index)
+
+    match approximation {
+        RoutingApproximation::Matrix => {
+            let matrices = if coord_index.has_indices() { vec![] } else { create_approx_matrices(&problem) };
+            let transport_mode = TransportMode::Matrix(MatrixPrecision::default());
+            map_to_problem(problem, matrices, coord_index, transport_mode, options)
+        }
+        RoutingApproximation::Geo => map_to_problem(problem, vec![], coord_index, TransportMode::Geo, options),
+    }
 }
 
 pub(super) fn map_to_problem_with_matrices(
     problem: ApiProblem,
     matrices: Vec<Matrix>,
+    precision: MatrixPrecision,
+    options: ProblemReadOptions,
+) -> Result<CoreProblem, MultiFormatError> {
+    let coord_index = CoordIndex::new(&problem);
+    map_to_problem(problem, matrices, coord_index, TransportMode::Matrix(precision), options)
+}
+
+pub(super) fn map_to_problem_with_binary_matrices(
+    problem: ApiProblem,
+    binary_matrices: Vec<BinaryMatrix>,
+    options: ProblemReadOptions,
 ) -> Result<CoreProblem, MultiFormatError> {
     let coord_index = CoordIndex::new(&problem);
-    map_to_problem(problem, matrices, coord_index)
+    // NOTE matrices without values are used to validate and analyze routing metadata
+    let matrices = binary_matrices
+        .iter()
+        .map(|matrix| Matrix {
+            profile: matrix.profile.clone(),
+            timestamp: matrix.timestamp.clone(),
+            travel_times: vec![],
+            distances: vec![],
+            error_codes: matrix.has_unreachable.then(Vec::new),
+        })
+        .collect();
+
+    map_to_problem(problem, matrices, coord_index, TransportMode::Binary(binary_matrices), options)
 }
 
 pub(super) fn map_to_problem(
     api_problem: ApiProblem,
     matrices: Vec<Matrix>,
     coord_index: CoordIndex,
+    transport_mode: TransportMode,
+    options: ProblemReadOptions,
 ) -> Result<CoreProblem, MultiFormatError> {
-    ValidationContext::new(&api_problem, Some(&matrices), &coord_index).validate()?;
+    let time_format = TimeFormat::detect(&api_problem);
+    let api_problem = normalize_times(api_problem).map_err(|err| MultiFormatError::from(vec![err]))?;
+
+    let matrix_size = match &transport_mode {
+        TransportMode::Binary(matrices) => matrices.first().map(|matrix| matrix.size),
+        _ => None,
+    };
+
+    ValidationContext::new(&api_problem, Some(&matrices), &coord_index).with_matrix_size(matrix_size).validate()?;
 
     let mut extras = Extras::default();
+    set_time_format(&mut extras, time_format);
 
     extras.set_coord_index(Arc::new(coord_index));
 
@@ -40,7 +98,8 @@ pub(super) fn map_to_problem(
     let mut job_index = JobIndex::default();
 
     let props = get_problem_properties(&api_problem, &matrices);
-    let mut blocks = get_problem_blocks(&api_problem, matrices, coord_index, &mut job_index, &props)?;
+    let mut blocks =
+        get_problem_blocks(&api_problem, matrices, coord_index, &mut job_index, &props, transport_mode, options)?;
 
     let job_index = Arc::new(job_index);
     extras.set_job_index(job_index.clone());
@@ -48,7 +107,11 @@ pub(super) fn map_to_problem(
 
     let goal = Arc::new(create_goal_context(&api_problem, &blocks, &props).map_err(to_multi_format_error)?);
 
-    let ProblemBlocks { jobs, fleet, transport, activity, locks, reserved_times_index, .. } = blocks;
+    let ProblemBlocks { jobs, fleet, transport, activity, locks, reserved_times_index, arc_penalties, .. } = blocks;
+
+    if let Some(arc_penalties) = arc_penalties {
+        extras.set_arc_penalties(arc_penalties);
+    }
 
     if let Some(config) = create_cluster_config(&api_problem).map_err(to_multi_format_error)? {
         extras.set_cluster_config(Arc::new(config));
@@ -109,6 +172,40 @@ fn read_reserved_times_index(api_problem: &ApiProblem, fleet: &CoreFleet) -> Res
         .collect()
 }
 
+fn read_profile_changes(api_problem: &ApiProblem, fleet: &CoreFleet) -> Vec<(Arc<Actor>, Profile)> {
+    let profile_indices = get_profile_index_map(api_problem);
+    let profiles_map = api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .flat_map(|vehicle| {
+            vehicle.shifts.iter().enumerate().filter_map(|(shift_idx, shift)| {
+                shift
+                    .capacity_change
+                    .as_ref()
+                    .and_then(|change| change.profile.as_ref())
+                    .and_then(|profile| {
+                        profile_indices
+                            .get(&profile.matrix)
+                            .map(|&index| Profile { index, scale: profile.scale.unwrap_or(1.) })
+                    })
+                    .map(|profile| ((vehicle.type_id.clone(), shift_idx), profile))
+            })
+        })
+        .collect::<HashMap<_, _>>();
+
+    fleet
+        .actors
+        .iter()
+        .filter_map(|actor| {
+            let type_id = actor.vehicle.dimens.get_vehicle_type().unwrap().clone();
+            let shift_idx = actor.vehicle.dimens.get_shift_index().copied().unwrap();
+
+            profiles_map.get(&(type_id, shift_idx)).map(|profile| (actor.clone(), profile.clone()))
+        })
+        .collect()
+}
+
 fn to_multi_format_error(error: GenericError) -> MultiFormatError {
     vec![FormatError::new(
         "E0000".to_string(),
@@ -127,6 +224,13 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
             .iter()
             .any(|job| job.all_tasks_iter().any(|task| task.demand.as_ref().is_some_and(|d| d.len() > 1)));
     let has_skills = api_problem.plan.jobs.iter().any(|job| job.skills.is_some());
+    let has_skill_preferences = api_problem
+        .plan
+        .jobs
+        .iter()
+        .filter_map(|job| job.skills.as_ref())
+        .flat_map(|skills| skills.levels.iter().flatten())
+        .any(|level| level.preferred_level.is_some());
 
     let shift_has_fn = |shift_has: fn(&VehicleShift) -> bool| {
         api_problem.fleet.vehicles.iter().any(|t| t.shifts.iter().any(shift_has))
@@ -135,6 +239,7 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
     let has_breaks = shift_has_fn(|s| s.breaks.as_ref().is_some_and(|b| !b.is_empty()));
     let has_reloads = shift_has_fn(|s| s.reloads.as_ref().is_some_and(|r| !r.is_empty()));
     let has_recharges = shift_has_fn(|s| s.recharges.as_ref().is_some());
+    let has_capacity_changes = shift_has_fn(|s| s.capacity_change.is_some());
 
     let has_order = api_problem
         .plan
@@ -146,9 +251,16 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
 
     let has_group = api_problem.plan.jobs.iter().any(|job| job.group.is_some());
     let has_value = api_problem.plan.jobs.iter().filter_map(|job| job.value).any(|value| value != 0.);
+    let has_priority = api_problem.plan.jobs.iter().any(|job| job.priority.is_some());
     let has_compatibility = api_problem.plan.jobs.iter().any(|job| job.compatibility.is_some());
+    let has_incompatibilities = api_problem.plan.incompatibilities.as_ref().is_some_and(|items| !items.is_empty())
+        && api_problem.plan.jobs.iter().any(|job| job.category.is_some());
     let has_tour_size_limits =
         api_problem.fleet.vehicles.iter().any(|v| v.limits.as_ref().is_some_and(|l| l.tour_size.is_some()));
+    let has_stop_limits =
+        api_problem.fleet.vehicles.iter().any(|v| v.limits.as_ref().is_some_and(|l| l.max_stops.is_some()));
+    let has_access_restrictions =
+        api_problem.fleet.profiles.iter().any(|p| p.restrictions.as_ref().is_some_and(|r| !r.is_empty()));
 
     let has_tour_travel_limits = api_problem
         .fleet
@@ -156,19 +268,46 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
         .iter()
         .any(|v| v.limits.as_ref().is_some_and(|l| l.max_duration.or(l.max_distance).is_some()));
 
+    let has_cumulative_travel_limits = api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .any(|v| v.limits.as_ref().is_some_and(|l| l.max_total_distance.or(l.max_total_driving_time).is_some()));
+
+    let has_place_costs = api_problem
+        .plan
+        .jobs
+        .iter()
+        .flat_map(|job| job.all_tasks_iter())
+        .flat_map(|job_task| job_task.places.iter())
+        .any(|place| place.cost.is_some_and(|cost| cost != 0.));
+
+    let has_crews = api_problem.plan.jobs.iter().any(|job| job.crew.is_some_and(|crew| crew > 1));
+    let has_separations = api_problem.plan.relations.iter().flatten().any(|relation| relation.type_field.is_negative());
+
     ProblemProperties {
         has_multi_dimen_capacity,
         has_breaks,
         has_skills,
+        has_skill_preferences,
         has_unreachable_locations,
         has_reloads,
         has_recharges,
+        has_capacity_changes,
         has_order,
         has_group,
         has_value,
+        has_priority,
         has_compatibility,
+        has_incompatibilities,
         has_tour_size_limits,
+        has_stop_limits,
+        has_access_restrictions,
         has_tour_travel_limits,
+        has_cumulative_travel_limits,
+        has_place_costs,
+        has_crews,
+        has_separations,
     }
 }
 
@@ -178,6 +317,8 @@ fn get_problem_blocks(
     coord_index: Arc<CoordIndex>,
     job_index: &mut JobIndex,
     problem_props: &ProblemProperties,
+    transport_mode: TransportMode,
+    options: ProblemReadOptions,
 ) -> Result<ProblemBlocks, MultiFormatError> {
     // TODO pass environment from outside to allow parametrization
     let environment = Environment::default();
@@ -187,7 +328,16 @@ fn get_problem_blocks(
 
     let transport = Timer::measure_duration_with_callback(
         || {
-            create_transport_costs(api_problem, &matrices, coord_index.clone()).map_err(|err| {
+            match transport_mode {
+                TransportMode::Matrix(precision) => {
+                    create_transport_costs(api_problem, &matrices, coord_index.clone(), precision)
+                }
+                TransportMode::Binary(binary_matrices) => {
+                    create_binary_transport_costs(api_problem, &matrices, &binary_matrices, coord_index.clone())
+                }
+                TransportMode::Geo => create_geo_transport_costs(api_problem, coord_index.clone()),
+            }
+            .map_err(|err| {
                 vec![FormatError::new(
                     "E0002".to_string(),
                     "cannot create transport costs".to_string(),
@@ -201,6 +351,13 @@ fn get_problem_blocks(
     )?;
     let activity: Arc<dyn ActivityCost> = Arc::new(OnlyVehicleActivityCost::default());
 
+    let profile_changes = read_profile_changes(api_problem, &fleet);
+    let transport: Arc<dyn TransportCost> = if profile_changes.is_empty() {
+        transport
+    } else {
+        Arc::new(ProfileChangeTransportCost::new(transport, profile_changes))
+    };
+
     let (transport, activity) = if reserved_times_index.is_empty() {
         (transport, activity)
     } else {
@@ -220,15 +377,26 @@ fn get_problem_blocks(
             })?
     };
 
-    let (jobs, locks) = read_jobs_with_extra_locks(
-        api_problem,
-        problem_props,
-        &coord_index,
-        &fleet,
-        transport.as_ref(),
-        job_index,
-        &environment,
-    );
+    let ProblemReadOptions { job_index_cache, arc_penalties } = options;
+    let transport: Arc<dyn TransportCost> = match arc_penalties.clone() {
+        Some(arc_penalties) => Arc::new(GuidedTransportCost::new(transport, arc_penalties)),
+        None => transport,
+    };
+
+    let (jobs, locks) = read_jobs_with_extra_locks(api_problem, problem_props, &coord_index, job_index, &environment);
+    let jobs = match job_index_cache.as_deref() {
+        Some(cache) => CoreJobs::new_with_cache(
+            &fleet,
+            jobs,
+            transport.as_ref(),
+            JobIndexMode::default(),
+            JobClusteringMode::default(),
+            cache,
+            &environment.logger,
+        ),
+        None => CoreJobs::new(&fleet, jobs, transport.as_ref(), &environment.logger),
+    }
+    .unwrap();
     let locks = locks.into_iter().chain(read_locks(api_problem, job_index)).collect::<Vec<_>>();
 
     Ok(ProblemBlocks {
@@ -239,5 +407,7 @@ fn get_problem_blocks(
         activity,
         locks,
         reserved_times_index,
+        coord_index,
+        arc_penalties,
     })
 }
@@ -0,0 +1,158 @@
+//! Provides a way to read problem in pragmatic format without keeping all jobs in memory.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/format/problem/stream_reader_test.rs"]
+mod stream_reader_test;
+
+use super::*;
+use crate::format::FormatError;
+use serde::de::{DeserializeSeed, Deserializer, Error as DeError, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use std::fmt;
+use vrp_core::prelude::GenericError;
+
+/// Deserializes problem in json format from `BufReader` passing jobs to `job_fn` one by one as soon as they
+/// are parsed. Jobs are not kept in memory, so `plan.jobs` of the returned problem is always empty.
+/// An error returned by `job_fn` stops reading.
+pub fn deserialize_problem_with_jobs<R, F>(reader: BufReader<R>, mut job_fn: F) -> Result<Problem, MultiFormatError>
+where
+    R: Read,
+    F: FnMut(Job) -> Result<(), GenericError>,
+{
+    let mut deserializer = serde_json::Deserializer::from_reader(reader);
+
+    deserializer
+        .deserialize_map(ProblemVisitor { job_fn: &mut job_fn })
+        .and_then(|problem| deserializer.end().map(|_| problem))
+        .map_err(|err| {
+            vec![FormatError::new(
+                "E0000".to_string(),
+                "cannot deserialize problem".to_string(),
+                format!("check input json: '{err}'"),
+            )]
+            .into()
+        })
+}
+
+struct ProblemVisitor<'a, F> {
+    job_fn: &'a mut F,
+}
+
+impl<'de, F> Visitor<'de> for ProblemVisitor<'_, F>
+where
+    F: FnMut(Job) -> Result<(), GenericError>,
+{
+    type Value = Problem;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a problem definition")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let (mut plan, mut fleet, mut objectives, mut timezone) = (None, None, None, None);
+
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "plan" => plan = Some(map.next_value_seed(PlanSeed { job_fn: &mut *self.job_fn })?),
+                "fleet" => fleet = Some(map.next_value()?),
+                "objectives" => objectives = map.next_value()?,
+                "timezone" => timezone = map.next_value()?,
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+
+        Ok(Problem {
+            plan: plan.ok_or_else(|| A::Error::missing_field("plan"))?,
+            fleet: fleet.ok_or_else(|| A::Error::missing_field("fleet"))?,
+            objectives,
+            timezone,
+        })
+    }
+}
+
+struct PlanSeed<'a, F> {
+    job_fn: &'a mut F,
+}
+
+impl<'de, F> DeserializeSeed<'de> for PlanSeed<'_, F>
+where
+    F: FnMut(Job) -> Result<(), GenericError>,
+{
+    type Value = Plan;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de, F> Visitor<'de> for PlanSeed<'_, F>
+where
+    F: FnMut(Job) -> Result<(), GenericError>,
+{
+    type Value = Plan;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a plan definition")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut has_jobs = false;
+        let (mut relations, mut clustering, mut incompatibilities) = (None, None, None);
+
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "jobs" => {
+                    map.next_value_seed(JobsSeed { job_fn: &mut *self.job_fn })?;
+                    has_jobs = true;
+                }
+                "relations" => relations = map.next_value()?,
+                "clustering" => clustering = map.next_value()?,
+                "incompatibilities" => incompatibilities = map.next_value()?,
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+
+        if !has_jobs {
+            return Err(A::Error::missing_field("jobs"));
+        }
+
+        Ok(Plan { jobs: vec![], relations, clustering, incompatibilities })
+    }
+}
+
+struct JobsSeed<'a, F> {
+    job_fn: &'a mut F,
+}
+
+impl<'de, F> DeserializeSeed<'de> for JobsSeed<'_, F>
+where
+    F: FnMut(Job) -> Result<(), GenericError>,
+{
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, F> Visitor<'de> for JobsSeed<'_, F>
+where
+    F: FnMut(Job) -> Result<(), GenericError>,
+{
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a list of jobs")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        while let Some(job) = seq.next_element::<Job>()? {
+            (self.job_fn)(job).map_err(A::Error::custom)?;
+        }
+
+        Ok(())
+    }
+}
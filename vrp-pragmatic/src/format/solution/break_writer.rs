@@ -107,10 +107,13 @@ fn insert_break(
     let activities = match stop {
         Stop::Point(point) => {
             statistic.cost += break_cost;
+            statistic.costs.break_cost += break_cost;
             &mut point.activities
         }
         Stop::Transit(transit) => {
             statistic.times.driving -= break_time;
+            statistic.costs.driving -= break_cost;
+            statistic.costs.break_cost += break_cost;
             &mut transit.activities
         }
     };
@@ -119,6 +122,7 @@ fn insert_break(
         Some(BreakInsertion::TransitBreakMoved { break_tw, leg_idx }) if *leg_idx == stop_idx => {
             statistic.cost -= break_cost;
             statistic.times.driving -= break_time;
+            statistic.costs.driving -= break_cost;
             break_tw
         }
         _ => reserved_tw,
@@ -0,0 +1,145 @@
+//! Provides the way to export solution into CSV format with one row per stop.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/format/solution/csv_serializer_test.rs"]
+mod csv_serializer_test;
+
+use super::*;
+use crate::parse_time_safe;
+use std::io::{Error, ErrorKind};
+
+const CSV_HEADER: &[&str] =
+    &["tour_id", "seq", "job_id", "location", "arrival", "departure", "wait", "load_in", "load_out", "distance"];
+
+/// Represents a stop of solution tour as a table row.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StopRecord {
+    /// An id of the vehicle used by the tour.
+    pub tour_id: String,
+    /// A sequence number of the stop in the tour.
+    pub seq: usize,
+    /// Ids of jobs served at the stop.
+    pub job_ids: Vec<String>,
+    /// A stop location, absent for transit stops.
+    pub location: Option<Location>,
+    /// Arrival time.
+    pub arrival: String,
+    /// Departure time.
+    pub departure: String,
+    /// A total waiting time at the stop in seconds.
+    pub wait: i64,
+    /// A vehicle load on arrival.
+    pub load_in: Vec<i32>,
+    /// A vehicle load after departure.
+    pub load_out: Vec<i32>,
+    /// A distance driven from the previous stop.
+    pub distance: i64,
+}
+
+/// Returns solution stops as table rows in tour order.
+pub fn get_stop_records(solution: &ApiSolution) -> Result<Vec<StopRecord>, Error> {
+    let mut records = Vec::new();
+
+    solution.tours.iter().try_for_each(|tour| {
+        tour.stops.iter().enumerate().try_fold(
+            (None::<&Vec<i32>>, 0_i64),
+            |(prev_load, prev_distance), (seq, stop)| {
+                let distance = stop.as_point().map_or(prev_distance, |point| point.distance);
+
+                records.push(StopRecord {
+                    tour_id: tour.vehicle_id.clone(),
+                    seq,
+                    job_ids: stop.activities().iter().map(|activity| activity.job_id.clone()).collect(),
+                    location: stop.location().cloned(),
+                    arrival: stop.schedule().arrival.clone(),
+                    departure: stop.schedule().departure.clone(),
+                    wait: get_waiting_time(stop)?,
+                    load_in: prev_load.unwrap_or(stop.load()).clone(),
+                    load_out: stop.load().clone(),
+                    distance: distance - prev_distance,
+                });
+
+                Ok::<_, Error>((Some(stop.load()), distance))
+            },
+        )?;
+
+        Ok::<_, Error>(())
+    })?;
+
+    Ok(records)
+}
+
+/// Serializes solution as CSV table with one row per stop. Wait is a total waiting time at the stop in seconds,
+/// load in/out is a vehicle load on arrival and after departure, distance is driven from the previous stop.
+pub fn serialize_solution_as_csv<W: Write>(solution: &ApiSolution, writer: &mut BufWriter<W>) -> Result<(), Error> {
+    write_csv_row(writer, CSV_HEADER.iter().map(|value| value.to_string()))?;
+
+    get_stop_records(solution)?.into_iter().try_for_each(|record| {
+        write_csv_row(
+            writer,
+            [
+                record.tour_id,
+                record.seq.to_string(),
+                record.job_ids.join(";"),
+                record.location.as_ref().map(format_location).unwrap_or_default(),
+                record.arrival,
+                record.departure,
+                record.wait.to_string(),
+                format_load(record.load_in.as_slice()),
+                format_load(record.load_out.as_slice()),
+                record.distance.to_string(),
+            ]
+            .into_iter(),
+        )
+    })?;
+
+    writer.flush()
+}
+
+/// Estimates waiting time at the stop as a sum of gaps between arrival and activity start times.
+fn get_waiting_time(stop: &Stop) -> Result<i64, Error> {
+    let parse = |time: &str| parse_time_safe(time).map_err(|err| Error::new(ErrorKind::InvalidData, err.to_string()));
+
+    let arrival = parse(stop.schedule().arrival.as_str())?;
+
+    stop.activities()
+        .iter()
+        .try_fold((arrival, 0.), |(prev_end, waiting), activity| match activity.time.as_ref() {
+            Some(time) => {
+                let ready = match activity.commute.as_ref().and_then(|commute| commute.forward.as_ref()) {
+                    Some(forward) => parse(forward.time.end.as_str())?,
+                    None => prev_end,
+                };
+                let (start, end) = (parse(time.start.as_str())?, parse(time.end.as_str())?);
+
+                Ok((end, waiting + (start - ready).max(0.)))
+            }
+            None => Ok((prev_end, waiting)),
+        })
+        .map(|(_, waiting)| waiting.round() as i64)
+}
+
+fn format_location(location: &Location) -> String {
+    match location {
+        Location::Coordinate { lat, lng } => format!("{lat},{lng}"),
+        _ => location.to_string(),
+    }
+}
+
+fn format_load(load: &[i32]) -> String {
+    load.iter().map(|value| value.to_string()).collect::<Vec<_>>().join(",")
+}
+
+fn write_csv_row<W: Write>(writer: &mut BufWriter<W>, values: impl Iterator<Item = String>) -> Result<(), Error> {
+    let row = values.map(|value| escape_csv(value.as_str())).collect::<Vec<_>>().join(",");
+
+    writeln!(writer, "{row}")
+}
+
+fn escape_csv(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
@@ -0,0 +1,170 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/solution/diff_test.rs"]
+mod diff_test;
+
+use super::*;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+
+/// Specifies a tour where the job is assigned.
+#[derive(Clone, Serialize, Eq, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TourRef {
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Vehicle shift index.
+    pub shift_index: usize,
+}
+
+/// Specifies a job which is assigned differently in two solutions.
+#[derive(Clone, Serialize, Eq, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct JobMove {
+    /// Job id.
+    pub job_id: String,
+    /// A tour in the old solution, none if the job is not assigned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<TourRef>,
+    /// A tour in the new solution, none if the job is not assigned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<TourRef>,
+}
+
+/// Specifies a job activity which is served in the same tour, but at a different time.
+#[derive(Clone, Serialize, Eq, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct JobTimeShift {
+    /// Job id.
+    pub job_id: String,
+    /// Activity type.
+    #[serde(rename(serialize = "type"))]
+    pub activity_type: String,
+    /// Job tag.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_tag: Option<String>,
+    /// A tour which serves the job activity.
+    pub tour: TourRef,
+    /// Activity start time in the old solution.
+    pub old_time: String,
+    /// Activity start time in the new solution.
+    pub new_time: String,
+    /// A time shift in seconds: positive value means that the activity is started later.
+    pub shift: i64,
+}
+
+/// A structured difference between two solutions of the same problem.
+#[derive(Clone, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SolutionDiff {
+    /// Jobs moved between tours, including jobs which became assigned or unassigned.
+    pub moved: Vec<JobMove>,
+    /// Job activities which kept their tour, but changed their start time.
+    pub shifted: Vec<JobTimeShift>,
+    /// A statistic delta (new minus old) which decomposes total cost change.
+    pub statistic: Statistic,
+}
+
+/// Compares two solutions of the same problem and returns their structured difference.
+pub fn get_solution_diff(old: &Solution, new: &Solution) -> SolutionDiff {
+    let old_activities = get_job_activities(old);
+    let new_activities = get_job_activities(new);
+
+    let old_tours = get_job_tours(&old_activities);
+    let new_tours = get_job_tours(&new_activities);
+
+    let moved = get_ordered_job_ids(&old_activities, &new_activities)
+        .into_iter()
+        .filter_map(|job_id| {
+            let from = old_tours.get(job_id).cloned();
+            let to = new_tours.get(job_id).cloned();
+
+            if from != to { Some(JobMove { job_id: job_id.to_string(), from, to }) } else { None }
+        })
+        .collect();
+
+    let new_times = new_activities
+        .iter()
+        .map(|info| ((info.job_id, info.activity_type, info.job_tag), info))
+        .collect::<HashMap<_, _>>();
+
+    let shifted = old_activities
+        .iter()
+        .filter_map(|old_info| {
+            let new_info = new_times.get(&(old_info.job_id, old_info.activity_type, old_info.job_tag))?;
+            let shift = (parse_time(new_info.time) - parse_time(old_info.time)) as i64;
+
+            if old_info.tour != new_info.tour || shift == 0 {
+                return None;
+            }
+
+            Some(JobTimeShift {
+                job_id: old_info.job_id.to_string(),
+                activity_type: old_info.activity_type.to_string(),
+                job_tag: old_info.job_tag.map(|tag| tag.to_string()),
+                tour: old_info.tour.clone(),
+                old_time: old_info.time.to_string(),
+                new_time: new_info.time.to_string(),
+                shift,
+            })
+        })
+        .collect();
+
+    SolutionDiff { moved, shifted, statistic: new.statistic.clone() - old.statistic.clone() }
+}
+
+/// Serializes solution diff into json format.
+pub fn serialize_solution_diff<W: Write>(diff: &SolutionDiff, writer: &mut BufWriter<W>) -> Result<(), GenericError> {
+    serde_json::to_writer_pretty(writer, diff).map_err(|err| err.to_string().into())
+}
+
+struct JobActivityInfo<'a> {
+    job_id: &'a str,
+    activity_type: &'a str,
+    job_tag: Option<&'a str>,
+    tour: TourRef,
+    time: &'a str,
+}
+
+fn get_job_activities(solution: &Solution) -> Vec<JobActivityInfo<'_>> {
+    solution
+        .tours
+        .iter()
+        .flat_map(|tour| {
+            let tour_ref = TourRef { vehicle_id: tour.vehicle_id.clone(), shift_index: tour.shift_index };
+
+            tour.stops.iter().flat_map(move |stop| {
+                let tour_ref = tour_ref.clone();
+                stop.activities()
+                    .iter()
+                    .filter(|activity| {
+                        matches!(activity.activity_type.as_str(), "pickup" | "delivery" | "replacement" | "service")
+                    })
+                    .map(move |activity| JobActivityInfo {
+                        job_id: activity.job_id.as_str(),
+                        activity_type: activity.activity_type.as_str(),
+                        job_tag: activity.job_tag.as_deref(),
+                        tour: tour_ref.clone(),
+                        time: activity
+                            .time
+                            .as_ref()
+                            .map_or(stop.schedule().arrival.as_str(), |interval| interval.start.as_str()),
+                    })
+            })
+        })
+        .collect()
+}
+
+fn get_job_tours<'a>(activities: &[JobActivityInfo<'a>]) -> HashMap<&'a str, TourRef> {
+    activities.iter().fold(HashMap::new(), |mut acc, info| {
+        acc.entry(info.job_id).or_insert_with(|| info.tour.clone());
+        acc
+    })
+}
+
+fn get_ordered_job_ids<'a>(old: &[JobActivityInfo<'a>], new: &[JobActivityInfo<'a>]) -> Vec<&'a str> {
+    let mut job_ids = old.iter().chain(new.iter()).map(|info| info.job_id).collect::<Vec<_>>();
+    let mut seen = HashSet::new();
+    job_ids.retain(|job_id| seen.insert(*job_id));
+
+    job_ids
+}
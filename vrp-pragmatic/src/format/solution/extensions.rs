@@ -1,5 +1,5 @@
-use crate::format::solution::{Statistic, Timing};
-use std::ops::Add;
+use crate::format::solution::{CostBreakdown, Statistic, Timing};
+use std::ops::{Add, Sub};
 
 impl Add for Statistic {
     type Output = Statistic;
@@ -17,6 +17,42 @@ impl Add for Statistic {
                 commuting: self.times.commuting + rhs.times.commuting,
                 parking: self.times.parking + rhs.times.parking,
             },
+            costs: CostBreakdown {
+                fixed: self.costs.fixed + rhs.costs.fixed,
+                distance: self.costs.distance + rhs.costs.distance,
+                driving: self.costs.driving + rhs.costs.driving,
+                serving: self.costs.serving + rhs.costs.serving,
+                waiting: self.costs.waiting + rhs.costs.waiting,
+                break_cost: self.costs.break_cost + rhs.costs.break_cost,
+            },
+        }
+    }
+}
+
+impl Sub for Statistic {
+    type Output = Statistic;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Statistic {
+            cost: self.cost - rhs.cost,
+            distance: self.distance - rhs.distance,
+            duration: self.duration - rhs.duration,
+            times: Timing {
+                driving: self.times.driving - rhs.times.driving,
+                serving: self.times.serving - rhs.times.serving,
+                waiting: self.times.waiting - rhs.times.waiting,
+                break_time: self.times.break_time - rhs.times.break_time,
+                commuting: self.times.commuting - rhs.times.commuting,
+                parking: self.times.parking - rhs.times.parking,
+            },
+            costs: CostBreakdown {
+                fixed: self.costs.fixed - rhs.costs.fixed,
+                distance: self.costs.distance - rhs.costs.distance,
+                driving: self.costs.driving - rhs.costs.driving,
+                serving: self.costs.serving - rhs.costs.serving,
+                waiting: self.costs.waiting - rhs.costs.waiting,
+                break_cost: self.costs.break_cost - rhs.costs.break_cost,
+            },
         }
     }
 }
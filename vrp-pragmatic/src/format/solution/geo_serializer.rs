@@ -3,7 +3,7 @@
 mod geo_serializer_test;
 
 use super::Solution;
-use crate::format::solution::{Activity, PointStop, Tour, UnassignedJob};
+use crate::format::solution::{Activity, PointStop, Tour, UnassignedJob, decode_polyline};
 use crate::format::{CoordIndex, CustomLocationType, Location, get_indices};
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
@@ -147,22 +147,33 @@ fn get_marker_symbol(stop: &PointStop) -> String {
     "marker".to_string()
 }
 
-fn get_stop_point(tour_idx: usize, stop_idx: usize, stop: &PointStop, color: &str) -> Result<Feature, Error> {
-    // TODO add parking
-    Ok(Feature {
-        properties: slice_to_map(&[
-            ("marker-color", color),
-            ("marker-size", "medium"),
-            ("marker-symbol", get_marker_symbol(stop).as_str()),
-            ("tour_idx", tour_idx.to_string().as_str()),
-            ("stop_idx", stop_idx.to_string().as_str()),
-            ("arrival", stop.time.arrival.as_str()),
-            ("departure", stop.time.departure.as_str()),
-            ("distance", stop.distance.to_string().as_str()),
-            ("jobs_ids", stop.activities.iter().map(|a| a.job_id.clone()).collect::<Vec<_>>().join(",").as_str()),
-        ]),
-        geometry: Geometry::Point { coordinates: get_lng_lat(&stop.location)? },
-    })
+fn get_stop_point(
+    tour: &Tour,
+    tour_idx: usize,
+    stop_idx: usize,
+    stop: &PointStop,
+    color: &str,
+) -> Result<Feature, Error> {
+    let mut properties = slice_to_map(&[
+        ("marker-color", color),
+        ("marker-size", "medium"),
+        ("marker-symbol", get_marker_symbol(stop).as_str()),
+        ("vehicle_id", tour.vehicle_id.as_str()),
+        ("tour_idx", tour_idx.to_string().as_str()),
+        ("stop_idx", stop_idx.to_string().as_str()),
+        ("arrival", stop.time.arrival.as_str()),
+        ("departure", stop.time.departure.as_str()),
+        ("distance", stop.distance.to_string().as_str()),
+        ("load", stop.load.iter().map(|load| load.to_string()).collect::<Vec<_>>().join(",").as_str()),
+        ("jobs_ids", stop.activities.iter().map(|a| a.job_id.clone()).collect::<Vec<_>>().join(",").as_str()),
+    ]);
+
+    if let Some(parking) = stop.parking.as_ref() {
+        properties
+            .extend(slice_to_map(&[("parking_start", parking.start.as_str()), ("parking_end", parking.end.as_str())]));
+    }
+
+    Ok(Feature { properties, geometry: Geometry::Point { coordinates: get_lng_lat(&stop.location)? } })
 }
 
 fn get_activity_point(
@@ -267,7 +278,17 @@ fn get_unassigned_points(
 fn get_tour_line(tour_idx: usize, tour: &Tour, color: &str) -> Result<Feature, Error> {
     let stops = tour.stops.iter().filter_map(|stop| stop.as_point()).collect::<Vec<_>>();
 
-    let coordinates = stops.iter().map(|stop| get_lng_lat(&stop.location)).collect::<Result<_, Error>>()?;
+    let coordinates = stops.iter().try_fold(Vec::new(), |mut coordinates, stop| {
+        match stop.geometry.as_ref() {
+            Some(geometry) if !coordinates.is_empty() => {
+                let path = decode_polyline(geometry).map_err(|err| invalid_data(err.to_string().as_str()))?;
+                coordinates.extend(path.into_iter().map(|(lat, lng)| (lng, lat)));
+            }
+            _ => coordinates.push(get_lng_lat(&stop.location)?),
+        }
+
+        Ok::<_, Error>(coordinates)
+    })?;
 
     Ok(Feature {
         properties: slice_to_map(&[
@@ -297,7 +318,7 @@ pub(crate) fn create_feature_collection(problem: &Problem, solution: &Solution)
                 .enumerate()
                 .filter_map(|(stop_idx, stop)| stop.as_point().map(|stop| (stop_idx, stop)))
                 .map(move |(stop_idx, stop)| {
-                    get_stop_point(tour_idx, stop_idx, stop, get_color_inverse(tour_idx).as_str())
+                    get_stop_point(tour, tour_idx, stop_idx, stop, get_color_inverse(tour_idx).as_str())
                 })
         })
         .collect::<Result<Vec<_>, _>>()?;
@@ -353,7 +374,7 @@ pub(crate) fn create_feature_collection(problem: &Problem, solution: &Solution)
     })
 }
 
-fn get_color(idx: usize) -> String {
+pub(super) fn get_color(idx: usize) -> String {
     static COLOR_LIST: ColorList = get_color_list();
 
     let idx = idx % COLOR_LIST.len();
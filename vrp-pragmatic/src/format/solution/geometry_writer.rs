@@ -0,0 +1,107 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/solution/geometry_writer_test.rs"]
+mod geometry_writer_test;
+
+use super::*;
+use vrp_core::models::common::Profile;
+use vrp_core::models::solution::Route;
+use vrp_core::prelude::{Float, GenericResult};
+
+/// A polyline precision used to encode leg geometries.
+const POLYLINE_PRECISION: Float = 1E5;
+
+/// Provides geometry of the path between two locations, typically using some routing backend.
+pub trait LegGeometryProvider {
+    /// Returns path between two locations as a list of (lat, lng) pairs. The profile index corresponds to the
+    /// order of profiles defined in `fleet.profiles`. Returns `None` if the path is not known.
+    fn get_path(&self, profile: &Profile, from: &Location, to: &Location) -> Option<Vec<(Float, Float)>>;
+}
+
+/// Encodes a list of (lat, lng) pairs using polyline algorithm with precision of five decimal places.
+pub fn encode_polyline(points: &[(Float, Float)]) -> String {
+    let mut result = String::new();
+
+    points.iter().fold((0_i64, 0_i64), |(prev_lat, prev_lng), &(lat, lng)| {
+        let (lat, lng) = ((lat * POLYLINE_PRECISION).round() as i64, (lng * POLYLINE_PRECISION).round() as i64);
+
+        encode_polyline_value(lat - prev_lat, &mut result);
+        encode_polyline_value(lng - prev_lng, &mut result);
+
+        (lat, lng)
+    });
+
+    result
+}
+
+/// Decodes a polyline encoded with precision of five decimal places into a list of (lat, lng) pairs.
+pub fn decode_polyline(polyline: &str) -> GenericResult<Vec<(Float, Float)>> {
+    let mut bytes = polyline.bytes();
+    let mut points = Vec::new();
+    let (mut lat, mut lng) = (0_i64, 0_i64);
+
+    while let Some(lat_delta) = decode_polyline_value(&mut bytes)? {
+        let lng_delta = decode_polyline_value(&mut bytes)?.ok_or("unexpected end of polyline")?;
+
+        lat += lat_delta;
+        lng += lng_delta;
+
+        points.push((lat as Float / POLYLINE_PRECISION, lng as Float / POLYLINE_PRECISION));
+    }
+
+    Ok(points)
+}
+
+/// Sets geometry of legs between point stops using given geometry provider.
+pub(super) fn insert_leg_geometries(route: &Route, tour: &mut Tour, geometry: &dyn LegGeometryProvider) {
+    let profile = &route.actor.vehicle.profile;
+
+    tour.stops.iter_mut().filter_map(|stop| if let Stop::Point(point) = stop { Some(point) } else { None }).fold(
+        None::<Location>,
+        |prev_location, point| {
+            point.geometry = prev_location
+                .filter(|prev_location| *prev_location != point.location)
+                .and_then(|prev_location| geometry.get_path(profile, &prev_location, &point.location))
+                .filter(|path| !path.is_empty())
+                .map(|path| encode_polyline(path.as_slice()));
+
+            Some(point.location.clone())
+        },
+    );
+}
+
+fn encode_polyline_value(value: i64, result: &mut String) {
+    let mut value = if value < 0 { !(value << 1) } else { value << 1 };
+
+    while value >= 0x20 {
+        result.push((((value & 0x1f) | 0x20) as u8 + 63) as char);
+        value >>= 5;
+    }
+
+    result.push((value as u8 + 63) as char);
+}
+
+fn decode_polyline_value(bytes: &mut impl Iterator<Item = u8>) -> GenericResult<Option<i64>> {
+    let (mut result, mut shift) = (0_i64, 0);
+
+    loop {
+        let byte = match bytes.next() {
+            Some(byte) => byte,
+            None if shift == 0 => return Ok(None),
+            None => return Err("unexpected end of polyline".into()),
+        };
+
+        if !(63..127).contains(&byte) || shift > 60 {
+            return Err(format!("invalid polyline character: '{}'", byte as char).into());
+        }
+
+        let chunk = (byte - 63) as i64;
+        result |= (chunk & 0x1f) << shift;
+        shift += 5;
+
+        if chunk < 0x20 {
+            break;
+        }
+    }
+
+    Ok(Some(if result & 1 == 1 { !(result >> 1) } else { result >> 1 }))
+}
@@ -2,19 +2,21 @@
 #[path = "../../../tests/unit/format/solution/initial_reader_test.rs"]
 mod initial_reader_test;
 
+use crate::format::deserialize_solution_compat;
 use crate::format::solution::Activity as FormatActivity;
 use crate::format::solution::Stop as FormatStop;
 use crate::format::solution::Tour as FormatTour;
 use crate::format::solution::activity_matcher::{JobInfo, try_match_point_job};
-use crate::format::solution::{deserialize_solution, map_reason_code};
+use crate::format::solution::map_reason_code;
 use crate::format::{CoordIndex, JobIndex, ShiftIndexDimension, VehicleTypeDimension, get_indices};
 use crate::parse_time;
 use std::collections::{HashMap, HashSet};
 use std::io::{BufReader, Read};
 use std::sync::Arc;
+use vrp_core::construction::features::JobCrewDimension;
 use vrp_core::construction::heuristics::UnassignmentInfo;
 use vrp_core::models::common::*;
-use vrp_core::models::problem::{Actor, Job, JobIdDimension, VehicleIdDimension};
+use vrp_core::models::problem::{Actor, Job, JobIdDimension, Single, VehicleIdDimension};
 use vrp_core::models::solution::Tour as CoreTour;
 use vrp_core::models::solution::{Activity, Registry, Route};
 use vrp_core::prelude::*;
@@ -28,63 +30,116 @@ pub fn read_init_solution<R: Read>(
     problem: Arc<Problem>,
     random: Arc<dyn Random>,
 ) -> Result<Solution, GenericError> {
-    let solution = deserialize_solution(solution).map_err(|err| format!("cannot deserialize solution: {err}"))?;
+    read_solution(solution, problem, random, None)
+}
+
+/// Reads initial solution from buffer tolerating its partial mismatch with the problem: tours of unknown vehicles,
+/// activities of unknown jobs and incomplete multi jobs are skipped. Returns the solution and list of warnings
+/// describing skipped parts. Jobs which are not present in the solution are returned as unassigned.
+/// NOTE: Solution feasibility is not checked.
+pub fn read_partial_init_solution<R: Read>(
+    solution: BufReader<R>,
+    problem: Arc<Problem>,
+    random: Arc<dyn Random>,
+) -> Result<(Solution, Vec<String>), GenericError> {
+    let mut warnings = Vec::default();
+
+    read_solution(solution, problem, random, Some(&mut warnings)).map(|solution| (solution, warnings))
+}
+
+fn read_solution<R: Read>(
+    solution: BufReader<R>,
+    problem: Arc<Problem>,
+    random: Arc<dyn Random>,
+    mut warnings: Option<&mut Vec<String>>,
+) -> Result<Solution, GenericError> {
+    let solution =
+        deserialize_solution_compat(solution).map_err(|err| format!("cannot deserialize solution: {err}"))?;
 
     let mut registry = Registry::new(&problem.fleet, random);
     let mut added_jobs = HashSet::default();
+    let mut used_actors = HashSet::new();
 
     let actor_index = registry.all().map(|actor| (get_actor_key(actor.as_ref()), actor)).collect::<HashMap<_, _>>();
     let (job_index, coord_index) = get_indices(&problem.extras)?;
 
-    let routes =
-        solution.tours.iter().try_fold::<_, _, Result<_, GenericError>>(Vec::<_>::default(), |mut routes, tour| {
-            let actor_key = (tour.vehicle_id.clone(), tour.type_id.clone(), tour.shift_index);
-            let actor =
-                actor_index.get(&actor_key).ok_or_else(|| format!("cannot find vehicle for {actor_key:?}"))?.clone();
-            registry.use_actor(&actor);
-
-            let mut core_route = create_core_route(actor, tour)?;
-
-            tour.stops.iter().try_for_each(|stop| {
-                stop.activities().iter().try_for_each::<_, Result<_, GenericError>>(|activity| {
-                    try_insert_activity(
-                        &mut core_route,
-                        tour,
-                        stop,
-                        activity,
-                        job_index.as_ref(),
-                        coord_index.as_ref(),
-                        &mut added_jobs,
-                    )
-                })
-            })?;
-
-            routes.push(core_route);
-
-            Ok(routes)
-        })?;
-
-    let mut unassigned = solution
-        .unassigned
-        .unwrap_or_default()
-        .iter()
-        .try_fold::<Vec<_>, _, Result<_, GenericError>>(Default::default(), |mut acc, unassigned_job| {
-            let job = job_index
-                .get(&unassigned_job.job_id)
-                .cloned()
-                .ok_or_else(|| format!("cannot get job id for: {unassigned_job:?}"))?;
-            // NOTE we take the first reason only and map it to simple variant
-            let code = unassigned_job
-                .reasons
-                .first()
-                .map(|reason| UnassignmentInfo::Simple(map_reason_code(&reason.code)))
-                .ok_or_else(|| format!("cannot get reason for: {unassigned_job:?}"))?;
-
-            added_jobs.insert(job.clone());
-            acc.push((job, code));
-
-            Ok(acc)
-        })?;
+    let mut routes = Vec::<Route>::default();
+    for tour in solution.tours.iter() {
+        let actor_key = (tour.vehicle_id.clone(), tour.type_id.clone(), tour.shift_index);
+        let actor =
+            actor_index.get(&actor_key).cloned().ok_or_else(|| format!("cannot find vehicle for {actor_key:?}"));
+        let Some(actor) = tolerate(actor.map_err(GenericError::from), &mut warnings)? else { continue };
+
+        if warnings.is_some() && !used_actors.insert(actor_key.clone()) {
+            tolerate::<()>(Err(format!("vehicle {actor_key:?} is used more than once").into()), &mut warnings)?;
+            continue;
+        }
+
+        let Some(mut core_route) = tolerate(create_core_route(actor.clone(), tour), &mut warnings)? else { continue };
+
+        for stop in tour.stops.iter() {
+            for activity in stop.activities().iter() {
+                let result = try_insert_activity(
+                    &mut core_route,
+                    tour,
+                    stop,
+                    activity,
+                    job_index.as_ref(),
+                    coord_index.as_ref(),
+                    &mut added_jobs,
+                );
+                tolerate(result, &mut warnings)?;
+            }
+        }
+
+        if let Some(warnings) = warnings.as_mut() {
+            remove_incomplete_multi_jobs(&mut core_route, &mut added_jobs, warnings);
+
+            if !core_route.tour.has_jobs() {
+                warnings.push(format!("tour of vehicle {actor_key:?} has no jobs and is skipped"));
+                continue;
+            }
+        }
+
+        registry.use_actor(&actor);
+        routes.push(core_route);
+    }
+
+    let mut unassigned = Vec::default();
+    for unassigned_job in solution.unassigned.unwrap_or_default().iter() {
+        let job = job_index
+            .get(&unassigned_job.job_id)
+            .cloned()
+            .ok_or_else(|| GenericError::from(format!("cannot get job id for: {unassigned_job:?}")));
+        // NOTE we take the first reason only and map it to simple variant
+        let code = unassigned_job
+            .reasons
+            .first()
+            .map(|reason| UnassignmentInfo::Simple(map_reason_code(&reason.code)))
+            .ok_or_else(|| GenericError::from(format!("cannot get reason for: {unassigned_job:?}")));
+
+        let Some((job, code)) = tolerate(job.and_then(|job| code.map(|code| (job, code))), &mut warnings)? else {
+            continue;
+        };
+
+        if !added_jobs.insert(job.clone()) && warnings.is_some() {
+            let err = format!("job '{}' is both assigned and unassigned", unassigned_job.job_id);
+            tolerate::<()>(Err(err.into()), &mut warnings)?;
+            continue;
+        }
+
+        // NOTE crew members share the same job id, so all of them are unassigned
+        let members = job.dimens().get_job_crew().map_or(vec![], |crew| {
+            (2..=crew.size)
+                .filter_map(|member_idx| job_index.get(&format!("{}_crew_{member_idx}", crew.id)))
+                .filter(|member| added_jobs.insert((*member).clone()))
+                .map(|member| (member.clone(), code.clone()))
+                .collect()
+        });
+
+        unassigned.push((job, code));
+        unassigned.extend(members);
+    }
 
     unassigned.extend(
         problem
@@ -98,6 +153,42 @@ pub fn read_init_solution<R: Read>(
     Ok(Solution { cost: Cost::default(), registry, routes, unassigned, telemetry: None })
 }
 
+/// Returns an error as is in strict mode (no warnings) or stores it as a warning otherwise.
+fn tolerate<T>(
+    result: Result<T, GenericError>,
+    warnings: &mut Option<&mut Vec<String>>,
+) -> Result<Option<T>, GenericError> {
+    match (result, warnings) {
+        (Ok(value), _) => Ok(Some(value)),
+        (Err(err), Some(warnings)) => {
+            warnings.push(err.to_string());
+            Ok(None)
+        }
+        (Err(err), None) => Err(err),
+    }
+}
+
+fn remove_incomplete_multi_jobs(route: &mut Route, added_jobs: &mut HashSet<Job>, warnings: &mut Vec<String>) {
+    let incomplete = route
+        .tour
+        .jobs()
+        .filter(|job| match job {
+            Job::Multi(multi) => route.tour.job_activities(job).count() != multi.jobs.len(),
+            Job::Single(_) => false,
+        })
+        .cloned()
+        .collect::<Vec<_>>();
+
+    incomplete.into_iter().for_each(|job| {
+        route.tour.remove(&job);
+        added_jobs.remove(&job);
+        warnings.push(format!(
+            "multi job '{}' is not completely assigned and is skipped",
+            job.dimens().get_job_id().map(|id| id.as_str()).unwrap_or_default()
+        ));
+    });
+}
+
 fn try_insert_activity(
     route: &mut Route,
     tour: &FormatTour,
@@ -118,6 +209,7 @@ fn try_insert_activity(
 
     match try_match_point_job(tour, stop, activity, job_index, coord_index)? {
         Some(JobInfo(job, single, place, time)) => {
+            let (job, single) = get_crew_member(job, single, job_index, added_jobs);
             let is_inserted = added_jobs.insert(job.clone());
             if !is_inserted && matches!(job, Job::Single(_)) {
                 return Err(format!(
@@ -149,6 +241,22 @@ fn try_insert_activity(
     Ok(())
 }
 
+/// Returns the next unused crew member as crew members share the same job id.
+fn get_crew_member(
+    job: Job,
+    single: Arc<Single>,
+    job_index: &JobIndex,
+    added_jobs: &HashSet<Job>,
+) -> (Job, Arc<Single>) {
+    let Some(crew) = single.dimens.get_job_crew().filter(|_| added_jobs.contains(&job)) else { return (job, single) };
+
+    (2..=crew.size)
+        .filter_map(|member_idx| job_index.get(&format!("{}_crew_{member_idx}", crew.id)))
+        .find(|member| !added_jobs.contains(*member))
+        .and_then(|member| member.as_single().map(|member_single| (member.clone(), member_single.clone())))
+        .unwrap_or((job, single))
+}
+
 fn get_actor_key(actor: &Actor) -> ActorKey {
     let dimens = &actor.vehicle.dimens;
 
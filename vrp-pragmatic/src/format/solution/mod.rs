@@ -5,24 +5,38 @@ pub(crate) mod activity_matcher;
 mod break_writer;
 use self::break_writer::insert_reserved_times_as_breaks;
 
+mod csv_serializer;
+pub use self::csv_serializer::{StopRecord, get_stop_records, serialize_solution_as_csv};
+
+mod diff;
+pub use self::diff::*;
+
 mod extensions;
 
+mod geometry_writer;
+pub use self::geometry_writer::{LegGeometryProvider, decode_polyline, encode_polyline};
+
 mod geo_serializer;
 pub use self::geo_serializer::*;
 
 mod initial_reader;
-pub use self::initial_reader::read_init_solution;
+pub use self::initial_reader::{read_init_solution, read_partial_init_solution};
 
 mod model;
 pub use self::model::*;
 
 mod solution_writer;
-pub(crate) use self::solution_writer::create_solution;
+pub(crate) use self::solution_writer::{create_solution, create_solution_with_options};
+
+mod stop_merger;
+
+mod track_serializer;
+pub use self::track_serializer::{serialize_solution_as_gpx, serialize_solution_as_kml};
 
 use super::*;
 use crate::{format_time, parse_time};
 use std::io::{BufWriter, Write};
-use vrp_core::prelude::GenericError;
+use vrp_core::prelude::{Float, GenericError};
 
 type ApiActivity = model::Activity;
 type ApiSolution = model::Solution;
@@ -45,10 +59,22 @@ pub enum PragmaticOutputType {
     OnlyPragmatic,
     /// Only geojson is needed.
     OnlyGeoJson,
+    /// Only csv table with one row per stop is needed.
+    OnlyCsv,
     /// Pragmatic and geojson is returned. Geojson features are embedded inside extras property.
     Combined,
 }
 
+/// Specifies additional options used to write solution in pragmatic format.
+#[derive(Clone, Copy, Default)]
+pub struct PragmaticWriterOptions<'a> {
+    /// Provides geometry of the path between point stops.
+    pub geometry: Option<&'a dyn LegGeometryProvider>,
+    /// A radius in meters within which consecutive point stops are merged into one stop with multiple
+    /// activities. When omitted, stops are not merged.
+    pub stop_merge_radius: Option<Float>,
+}
+
 /// Writes solution in pragmatic format variation defined by output type argument.
 pub fn write_pragmatic<W: Write>(
     problem: &DomainProblem,
@@ -58,19 +84,58 @@ pub fn write_pragmatic<W: Write>(
 ) -> Result<(), GenericError> {
     let solution = create_solution(problem, solution, &output_type);
 
+    write_api_solution(problem, &solution, output_type, writer)
+}
+
+/// Writes solution in pragmatic format variation defined by output type argument. Point stops have geometry of
+/// the path from the previous stop which is provided by the given leg geometry provider.
+pub fn write_pragmatic_with_geometry<W: Write>(
+    problem: &DomainProblem,
+    solution: &DomainSolution,
+    output_type: PragmaticOutputType,
+    geometry: &dyn LegGeometryProvider,
+    writer: &mut BufWriter<W>,
+) -> Result<(), GenericError> {
+    let options = PragmaticWriterOptions { geometry: Some(geometry), ..Default::default() };
+
+    write_pragmatic_with_options(problem, solution, output_type, options, writer)
+}
+
+/// Writes solution in pragmatic format variation defined by output type argument using given writer options.
+pub fn write_pragmatic_with_options<W: Write>(
+    problem: &DomainProblem,
+    solution: &DomainSolution,
+    output_type: PragmaticOutputType,
+    options: PragmaticWriterOptions,
+    writer: &mut BufWriter<W>,
+) -> Result<(), GenericError> {
+    let solution = create_solution_with_options(problem, solution, &output_type, options);
+
+    write_api_solution(problem, &solution, output_type, writer)
+}
+
+fn write_api_solution<W: Write>(
+    problem: &DomainProblem,
+    solution: &ApiSolution,
+    output_type: PragmaticOutputType,
+    writer: &mut BufWriter<W>,
+) -> Result<(), GenericError> {
     match output_type {
         PragmaticOutputType::OnlyPragmatic | PragmaticOutputType::Combined => {
-            serialize_solution(&solution, writer).map_err(|err| err.to_string())?;
+            serialize_solution(solution, writer).map_err(|err| err.to_string())?;
         }
         PragmaticOutputType::OnlyGeoJson => {
-            serialize_solution_as_geojson(problem, &solution, writer).map_err(|err| err.to_string())?;
+            serialize_solution_as_geojson(problem, solution, writer).map_err(|err| err.to_string())?;
+        }
+        PragmaticOutputType::OnlyCsv => {
+            serialize_solution_as_csv(solution, writer).map_err(|err| err.to_string())?;
         }
     }
 
     Ok(())
 }
 
-fn map_code_reason(code: ViolationCode) -> (&'static str, &'static str) {
+pub(crate) fn map_code_reason(code: ViolationCode) -> (&'static str, &'static str) {
     match code {
         SKILL_CONSTRAINT_CODE => ("SKILL_CONSTRAINT", "cannot serve required skill"),
         TIME_CONSTRAINT_CODE => ("TIME_WINDOW_CONSTRAINT", "cannot be visited within time window"),
@@ -97,6 +162,22 @@ fn map_code_reason(code: ViolationCode) -> (&'static str, &'static str) {
             ("RELOAD_RESOURCE_CONSTRAINT", "cannot be assigned due to reload resource constraint")
         }
         RECHARGE_CONSTRAINT_CODE => ("RECHARGE_CONSTRAINT_CODE", "cannot be assigned due to recharge constraint"),
+        STOP_LIMIT_CONSTRAINT_CODE => {
+            ("MAX_STOPS_CONSTRAINT", "cannot be assigned due to max stops constraint of vehicle")
+        }
+        INCOMPATIBILITY_CONSTRAINT_CODE => {
+            ("INCOMPATIBILITY_CONSTRAINT", "cannot be assigned due to incompatibility with jobs in the tour")
+        }
+        ACCESS_RESTRICTION_CONSTRAINT_CODE => {
+            ("ACCESS_RESTRICTION_CONSTRAINT", "cannot be assigned due to vehicle access restriction")
+        }
+        CREW_CONSTRAINT_CODE => ("CREW_CONSTRAINT", "cannot be served by required amount of vehicles simultaneously"),
+        SEPARATION_CONSTRAINT_CODE => {
+            ("SEPARATION_CONSTRAINT", "cannot be assigned due to not-same-tour or different-vehicle relation")
+        }
+        CAPACITY_CHANGE_CONSTRAINT_CODE => {
+            ("CAPACITY_CHANGE_CONSTRAINT", "cannot be assigned due to vehicle capacity change")
+        }
         _ => ("NO_REASON_FOUND", "unknown"),
     }
 }
@@ -118,6 +199,12 @@ fn map_reason_code(reason: &str) -> ViolationCode {
         "COMPATIBILITY_CONSTRAINT" => COMPATIBILITY_CONSTRAINT_CODE,
         "RELOAD_RESOURCE_CONSTRAINT" => RELOAD_RESOURCE_CONSTRAINT_CODE,
         "RECHARGE_CONSTRAINT_CODE" => RECHARGE_CONSTRAINT_CODE,
+        "MAX_STOPS_CONSTRAINT" => STOP_LIMIT_CONSTRAINT_CODE,
+        "INCOMPATIBILITY_CONSTRAINT" => INCOMPATIBILITY_CONSTRAINT_CODE,
+        "ACCESS_RESTRICTION_CONSTRAINT" => ACCESS_RESTRICTION_CONSTRAINT_CODE,
+        "CREW_CONSTRAINT" => CREW_CONSTRAINT_CODE,
+        "SEPARATION_CONSTRAINT" => SEPARATION_CONSTRAINT_CODE,
+        "CAPACITY_CHANGE_CONSTRAINT" => CAPACITY_CHANGE_CONSTRAINT_CODE,
         _ => ViolationCode::unknown(),
     }
 }
@@ -1,5 +1,5 @@
 use super::FeatureCollection;
-use crate::format::{CoordIndex, Location};
+use crate::format::{CoordIndex, Location, Versioned};
 use crate::{format_time, parse_time};
 use serde::{Deserialize, Serialize};
 use std::io::{BufReader, BufWriter, Error, Read, Write};
@@ -28,6 +28,24 @@ pub struct Timing {
     pub parking: i64,
 }
 
+/// Cost statistic which decomposes total cost.
+#[derive(Clone, Default, Deserialize, Serialize, PartialEq, Debug)]
+pub struct CostBreakdown {
+    /// Fixed vehicle cost.
+    pub fixed: Float,
+    /// Distance cost.
+    pub distance: Float,
+    /// Driving and commuting time cost.
+    pub driving: Float,
+    /// Serving time cost.
+    pub serving: Float,
+    /// Waiting time cost.
+    pub waiting: Float,
+    /// Break time cost.
+    #[serde(rename(serialize = "break", deserialize = "break"))]
+    pub break_cost: Float,
+}
+
 /// Represents statistic.
 #[derive(Clone, Deserialize, Default, Serialize, PartialEq, Debug)]
 pub struct Statistic {
@@ -39,6 +57,9 @@ pub struct Statistic {
     pub duration: i64,
     /// Timing statistic.
     pub times: Timing,
+    /// Cost breakdown statistic.
+    #[serde(default)]
+    pub costs: CostBreakdown,
 }
 
 /// Represents a schedule.
@@ -215,6 +236,9 @@ pub struct PointStop {
     pub parking: Option<Interval>,
     /// Activities performed at the stop.
     pub activities: Vec<Activity>,
+    /// Geometry of the path from the previous point stop encoded as polyline with precision of five decimal places.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub geometry: Option<String>,
 }
 
 /// A tour is list of stops with their activities performed by specific vehicle.
@@ -254,6 +278,10 @@ pub struct UnassignedJobDetail {
     pub vehicle_id: String,
     /// Vehicle shift index.
     pub shift_index: usize,
+    /// A time span of the vehicle tour where job insertion has failed.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<Interval>,
 }
 
 /// Unassigned job.
@@ -330,13 +358,29 @@ pub struct Population {
     pub individuals: Vec<Individual>,
 }
 
+/// Contains an estimation of the solution cost lower bound.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LowerBound {
+    /// Estimated lower bound of the total cost.
+    pub cost: Float,
+    /// Relative optimality gap in percents. Absent when some jobs are unassigned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gap: Option<Float>,
+}
+
 /// Contains extra information.
 #[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct Extras {
     /// A telemetry metrics.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metrics: Option<Metrics>,
 
+    /// A cost lower bound estimated by the solver.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lower_bound: Option<LowerBound>,
+
     /// Represents solution as a collection of geo json features.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub features: Option<FeatureCollection>,
@@ -365,9 +409,9 @@ pub struct Solution {
     pub extras: Option<Extras>,
 }
 
-/// Serializes solution into json format.
+/// Serializes solution into json format with `schemaVersion` property.
 pub fn serialize_solution<W: Write>(solution: &Solution, writer: &mut BufWriter<W>) -> Result<(), Error> {
-    serde_json::to_writer_pretty(writer, solution).map_err(Error::from)
+    serde_json::to_writer_pretty(writer, &Versioned::new(solution)).map_err(Error::from)
 }
 
 /// Deserializes solution from json format.
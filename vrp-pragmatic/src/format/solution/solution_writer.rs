@@ -2,19 +2,22 @@
 #[path = "../../../tests/unit/format/solution/writer_test.rs"]
 mod writer_test;
 
-use crate::format::CoordIndex;
 use crate::format::solution::activity_matcher::get_job_tag;
+use crate::format::solution::geometry_writer::insert_leg_geometries;
 use crate::format::solution::model::Timing;
+use crate::format::solution::stop_merger::merge_nearby_stops;
 use crate::format::solution::*;
+use crate::format::{CoordIndex, TimeFormatExtraProperty, apply_time_format};
+use std::collections::HashSet;
 use vrp_core::construction::enablers::{ReservedTimesIndex, get_route_intervals};
-use vrp_core::construction::features::JobDemandDimension;
+use vrp_core::construction::features::{JobCrewDimension, JobDemandDimension, get_place_cost, get_vehicle_fixed_cost};
 use vrp_core::construction::heuristics::UnassignmentInfo;
 use vrp_core::models::common::*;
 use vrp_core::models::problem::{JobIdDimension, Multi, TravelTime, VehicleIdDimension};
 use vrp_core::models::solution::{Activity, Route};
 use vrp_core::prelude::Float;
 use vrp_core::rosomaxa::evolution::TelemetryMetrics;
-use vrp_core::solver::processing::{ClusterConfigExtraProperty, ReservedTimesExtraProperty};
+use vrp_core::solver::processing::{COST_LOWER_BOUND_PROPERTY, ClusterConfigExtraProperty, ReservedTimesExtraProperty};
 use vrp_core::utils::CollectGroupBy;
 
 struct Leg {
@@ -38,6 +41,15 @@ pub(crate) fn create_solution(
     problem: &DomainProblem,
     solution: &DomainSolution,
     output_type: &PragmaticOutputType,
+) -> ApiSolution {
+    create_solution_with_options(problem, solution, output_type, PragmaticWriterOptions::default())
+}
+
+pub(crate) fn create_solution_with_options(
+    problem: &DomainProblem,
+    solution: &DomainSolution,
+    output_type: &PragmaticOutputType,
+    options: PragmaticWriterOptions,
 ) -> ApiSolution {
     let coord_index = problem.extras.get_coord_index().expect("no coord index");
 
@@ -48,7 +60,16 @@ pub(crate) fn create_solution(
     let tours = solution
         .routes
         .iter()
-        .map(|r| create_tour(problem, r, &coord_index, reserved_times_index))
+        .map(|r| {
+            let mut tour = create_tour(problem, r, &coord_index, reserved_times_index);
+            if let Some(geometry) = options.geometry {
+                insert_leg_geometries(r, &mut tour, geometry);
+            }
+            if let Some(radius) = options.stop_merge_radius {
+                merge_nearby_stops(&mut tour, radius);
+            }
+            tour
+        })
         .collect::<Vec<Tour>>();
 
     let statistic = tours.iter().fold(Statistic::default(), |acc, tour| acc + tour.statistic.clone());
@@ -56,7 +77,10 @@ pub(crate) fn create_solution(
     let unassigned = create_unassigned(solution);
     let violations = create_violations(solution);
 
-    let api_solution = ApiSolution { statistic, tours, unassigned, violations, extras: None };
+    let mut api_solution = ApiSolution { statistic, tours, unassigned, violations, extras: None };
+    if let Some(time_format) = problem.extras.get_time_format() {
+        apply_time_format(&mut api_solution, time_format.as_ref());
+    }
 
     let extras = create_extras(problem, &api_solution, solution.telemetry.as_ref(), output_type);
 
@@ -125,6 +149,7 @@ fn create_tour(
                     commute: None,
                 }],
                 parking: None,
+                geometry: None,
             }));
             (start_idx + 1, start)
         } else {
@@ -163,15 +188,20 @@ fn create_tour(
                 let commute = act.commute.clone().unwrap_or_default();
                 let commuting = commute.duration();
 
-                let (driving, transport_cost) = if commute.is_zero_distance() {
+                let (driving, distance_cost, transport_cost) = if commute.is_zero_distance() {
                     // NOTE: use original cost traits to adapt time-based costs (except waiting/commuting)
                     let prev_departure = TravelTime::Departure(prev_departure);
                     let duration = transport.duration(route, prev_location, act.place.location, prev_departure);
+                    let distance = transport.distance(route, prev_location, act.place.location, prev_departure);
                     let transport_cost = transport.cost(route, prev_location, act.place.location, prev_departure);
-                    (duration, transport_cost)
+                    (
+                        duration,
+                        distance * (actor.driver.costs.per_distance + vehicle.costs.per_distance),
+                        transport_cost,
+                    )
                 } else {
                     // NOTE: no need to drive in case of non-zero commute, this goes to commuting time
-                    (0., commuting * vehicle.costs.per_service_time)
+                    (0., 0., commuting * vehicle.costs.per_service_time)
                 };
 
                 // NOTE two clusters at the same stop location
@@ -189,8 +219,9 @@ fn create_tour(
                 let activity_departure = service_end;
 
                 // TODO: add better support of time based activity costs
-                let serving_cost = problem.activity.cost(route, act, service_start);
-                let total_cost = serving_cost + transport_cost + waiting * vehicle.costs.per_waiting_time;
+                let serving_cost = problem.activity.cost(route, act, service_start) + get_place_cost(act);
+                let waiting_cost = waiting * vehicle.costs.per_waiting_time;
+                let total_cost = serving_cost + transport_cost + waiting_cost;
 
                 let location_distance =
                     transport.distance(route, prev_location, act.place.location, TravelTime::Departure(prev_departure))
@@ -218,6 +249,7 @@ fn create_tour(
                             None
                         },
                         activities: vec![],
+                        geometry: None,
                     }));
                 }
 
@@ -271,6 +303,14 @@ fn create_tour(
                             commuting: leg.statistic.times.commuting + commuting as i64,
                             parking: leg.statistic.times.parking + parking as i64,
                         },
+                        costs: CostBreakdown {
+                            fixed: leg.statistic.costs.fixed,
+                            distance: leg.statistic.costs.distance + distance_cost,
+                            driving: leg.statistic.costs.driving + transport_cost - distance_cost,
+                            serving: leg.statistic.costs.serving + (if is_break { 0. } else { serving_cost }),
+                            waiting: leg.statistic.costs.waiting + waiting_cost,
+                            break_cost: leg.statistic.costs.break_cost + (if is_break { serving_cost } else { 0. }),
+                        },
                     },
                     load: Some(load),
                 }
@@ -282,7 +322,9 @@ fn create_tour(
         leg
     });
 
-    leg.statistic.cost += vehicle.costs.fixed;
+    let fixed_cost = get_vehicle_fixed_cost(vehicle, leg.statistic.duration as Float, leg.statistic.distance as Float);
+    leg.statistic.cost += fixed_cost;
+    leg.statistic.costs.fixed = fixed_cost;
     tour.statistic = leg.statistic;
 
     insert_reserved_times_as_breaks(route, &mut tour, reserved_times_index);
@@ -331,10 +373,14 @@ fn create_unassigned(solution: &DomainSolution) -> Option<Vec<UnassignedJob>> {
         vec![UnassignedJobReason { code: code.to_string(), description: reason.to_string(), details: None }]
     };
 
-    let unassigned = solution
+    // NOTE crew members share the same job id, so they are reported once
+    let mut crew_ids = HashSet::new();
+
+    let mut unassigned = solution
         .unassigned
         .iter()
         .filter(|(job, _)| job.dimens().get_vehicle_id().is_none())
+        .filter(|(job, _)| job.dimens().get_job_crew().is_none_or(|crew| crew_ids.insert(crew.id.clone())))
         .map(|(job, code)| {
             let job_id = job.dimens().get_job_id().expect("job id expected").clone();
 
@@ -342,29 +388,30 @@ fn create_unassigned(solution: &DomainSolution) -> Option<Vec<UnassignedJob>> {
                 UnassignmentInfo::Simple(code) => create_simple_reasons(*code),
                 UnassignmentInfo::Detailed(details) if !details.is_empty() => details
                     .iter()
-                    .collect_group_by_key(|(_, code)| *code)
+                    .collect_group_by_key(|detail| detail.code)
                     .into_iter()
                     .map(|(code, group)| {
                         let (code, reason) = map_code_reason(code);
                         let mut vehicle_details = group
                             .iter()
-                            .map(|(actor, _)| {
-                                let dimens = &actor.vehicle.dimens;
-                                let vehicle_id = dimens.get_vehicle_id().cloned().unwrap();
-                                let shift_index = dimens.get_shift_index().copied().unwrap();
-                                (vehicle_id, shift_index)
+                            .map(|detail| {
+                                let dimens = &detail.actor.vehicle.dimens;
+                                UnassignedJobDetail {
+                                    vehicle_id: dimens.get_vehicle_id().cloned().unwrap(),
+                                    shift_index: dimens.get_shift_index().copied().unwrap(),
+                                    time: detail.time.as_ref().map(|time| Interval {
+                                        start: format_time(time.start),
+                                        end: format_time(time.end),
+                                    }),
+                                }
                             })
                             .collect::<Vec<_>>();
                         // NOTE sort to have consistent order
-                        vehicle_details.sort();
+                        vehicle_details
+                            .sort_by(|a, b| (&a.vehicle_id, a.shift_index).cmp(&(&b.vehicle_id, b.shift_index)));
 
                         UnassignedJobReason {
-                            details: Some(
-                                vehicle_details
-                                    .into_iter()
-                                    .map(|(vehicle_id, shift_index)| UnassignedJobDetail { vehicle_id, shift_index })
-                                    .collect(),
-                            ),
+                            details: Some(vehicle_details),
                             code: code.to_string(),
                             description: reason.to_string(),
                         }
@@ -376,13 +423,15 @@ fn create_unassigned(solution: &DomainSolution) -> Option<Vec<UnassignedJob>> {
             UnassignedJob { job_id, reasons }
         })
         .collect::<Vec<_>>();
+    // NOTE sort to have consistent order as unassigned jobs are kept in hash map
+    unassigned.sort_by(|a, b| a.job_id.cmp(&b.job_id));
 
     if unassigned.is_empty() { None } else { Some(unassigned) }
 }
 
 fn create_violations(solution: &DomainSolution) -> Option<Vec<Violation>> {
     // NOTE at the moment only break violation is mapped
-    let violations = solution
+    let mut violations = solution
         .unassigned
         .iter()
         .filter(|(job, _)| job.dimens().get_job_type().is_some_and(|t| t == "break"))
@@ -391,6 +440,13 @@ fn create_violations(solution: &DomainSolution) -> Option<Vec<Violation>> {
             shift_index: job.dimens().get_shift_index().copied().expect("shift index"),
         })
         .collect::<Vec<_>>();
+    // NOTE sort to have consistent order
+    violations.sort_by(|a, b| match (a, b) {
+        (
+            Violation::Break { vehicle_id: a_id, shift_index: a_idx },
+            Violation::Break { vehicle_id: b_id, shift_index: b_idx },
+        ) => (a_id, a_idx).cmp(&(b_id, b_idx)),
+    });
 
     if violations.is_empty() { None } else { Some(violations) }
 }
@@ -426,13 +482,16 @@ fn create_extras(
     output_type: &PragmaticOutputType,
 ) -> Option<Extras> {
     match output_type {
-        PragmaticOutputType::OnlyPragmatic => {
-            get_api_metrics(metrics).map(|metrics| Extras { metrics: Some(metrics), features: None })
-        }
-        PragmaticOutputType::OnlyGeoJson => None,
+        PragmaticOutputType::OnlyPragmatic => get_api_metrics(metrics).map(|api_metrics| Extras {
+            metrics: Some(api_metrics),
+            lower_bound: get_api_lower_bound(solution, metrics),
+            features: None,
+        }),
+        PragmaticOutputType::OnlyGeoJson | PragmaticOutputType::OnlyCsv => None,
         PragmaticOutputType::Combined => {
             Some(Extras {
                 metrics: get_api_metrics(metrics),
+                lower_bound: get_api_lower_bound(solution, metrics),
                 // TODO do not hide error here, propagate it to the caller
                 features: create_feature_collection(problem, solution).ok(),
             })
@@ -440,6 +499,18 @@ fn create_extras(
     }
 }
 
+fn get_api_lower_bound(solution: &ApiSolution, metrics: Option<&TelemetryMetrics>) -> Option<LowerBound> {
+    let (_, cost) = metrics?.properties.iter().find(|(name, _)| name == COST_LOWER_BOUND_PROPERTY)?;
+    let actual = solution.statistic.cost;
+    let has_unassigned = solution.unassigned.as_ref().is_some_and(|unassigned| !unassigned.is_empty());
+
+    Some(LowerBound {
+        cost: *cost,
+        // NOTE the bound is estimated for serving all jobs, so it is not comparable with partial solutions
+        gap: (!has_unassigned).then(|| if actual > 0. { ((actual - cost) / actual * 100.).max(0.) } else { 0. }),
+    })
+}
+
 fn get_api_metrics(metrics: Option<&TelemetryMetrics>) -> Option<ApiMetrics> {
     metrics.as_ref().map(|metrics| ApiMetrics {
         duration: metrics.duration,
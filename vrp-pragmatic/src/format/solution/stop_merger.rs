@@ -0,0 +1,71 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/solution/stop_merger_test.rs"]
+mod stop_merger_test;
+
+use super::*;
+use crate::utils::get_haversine_distance;
+use vrp_core::prelude::Float;
+
+/// Merges consecutive point stops which are located within given radius (in meters) from the first stop of
+/// the group into one stop with multiple activities. Locations without coordinates are merged only when equal.
+pub(super) fn merge_nearby_stops(tour: &mut Tour, radius: Float) {
+    let stops = std::mem::take(&mut tour.stops);
+
+    tour.stops = stops.into_iter().fold(Vec::<Stop>::new(), |mut stops, stop| {
+        match (stops.last_mut(), stop) {
+            (Some(Stop::Point(prev)), Stop::Point(next))
+                if is_within_radius(&prev.location, &next.location, radius) =>
+            {
+                merge_point_stops(prev, next)
+            }
+            (_, stop) => stops.push(stop),
+        }
+
+        stops
+    });
+}
+
+fn is_within_radius(first: &Location, second: &Location, radius: Float) -> bool {
+    match (first, second) {
+        (Location::Coordinate { .. }, Location::Coordinate { .. }) => get_haversine_distance(first, second) <= radius,
+        _ => first == second,
+    }
+}
+
+fn merge_point_stops(prev: &mut PointStop, next: PointStop) {
+    make_activities_explicit(prev);
+
+    let mut next = next;
+    make_activities_explicit(&mut next);
+
+    prev.geometry = match (prev.geometry.take(), next.geometry) {
+        (Some(prev_geometry), Some(next_geometry)) => {
+            match (decode_polyline(prev_geometry.as_str()), decode_polyline(next_geometry.as_str())) {
+                (Ok(mut prev_path), Ok(next_path)) => {
+                    let skip = usize::from(prev_path.last().is_some_and(|last| next_path.first() == Some(last)));
+                    prev_path.extend(next_path.into_iter().skip(skip));
+
+                    Some(encode_polyline(prev_path.as_slice()))
+                }
+                _ => Some(prev_geometry),
+            }
+        }
+        (prev_geometry, next_geometry) => prev_geometry.or(next_geometry),
+    };
+
+    prev.time.departure = next.time.departure;
+    prev.distance = next.distance;
+    prev.load = next.load;
+    prev.parking = prev.parking.take().or(next.parking);
+    prev.activities.extend(next.activities);
+}
+
+/// Sets location and time of the activities explicitly as they can differ from the merged stop ones.
+fn make_activities_explicit(stop: &mut PointStop) {
+    let (location, time) = (&stop.location, &stop.time);
+
+    stop.activities.iter_mut().for_each(|activity| {
+        activity.location.get_or_insert_with(|| location.clone());
+        activity.time.get_or_insert_with(|| Interval { start: time.arrival.clone(), end: time.departure.clone() });
+    });
+}
@@ -0,0 +1,211 @@
+//! Provides the way to export solution tours into KML and GPX formats.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/format/solution/track_serializer_test.rs"]
+mod track_serializer_test;
+
+use super::geo_serializer::get_color;
+use super::*;
+use std::io::{Error, ErrorKind};
+
+/// Serializes solution tours into KML format: each tour is a folder with stop placemarks and a tour line.
+pub fn serialize_solution_as_kml<W: Write>(solution: &ApiSolution, writer: &mut BufWriter<W>) -> Result<(), Error> {
+    let tours = get_export_tours(solution)?;
+
+    writeln!(writer, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(writer, r#"<kml xmlns="http://www.opengis.net/kml/2.2">"#)?;
+    writeln!(writer, "  <Document>")?;
+    writeln!(writer, "    <name>solution</name>")?;
+
+    for (tour_idx, _) in tours.iter().enumerate() {
+        let color = get_kml_color(get_color(tour_idx).as_str());
+        writeln!(writer, r#"    <Style id="tour{tour_idx}">"#)?;
+        writeln!(writer, "      <IconStyle><color>{color}</color></IconStyle>")?;
+        writeln!(writer, "      <LineStyle><color>{color}</color><width>4</width></LineStyle>")?;
+        writeln!(writer, "    </Style>")?;
+    }
+
+    for (tour_idx, tour) in tours.iter().enumerate() {
+        writeln!(writer, "    <Folder>")?;
+        writeln!(writer, "      <name>{}</name>", escape_xml(tour.name.as_str()))?;
+
+        for stop in tour.stops.iter() {
+            let (lat, lng) = stop.coordinate;
+            writeln!(writer, "      <Placemark>")?;
+            writeln!(writer, "        <name>{}</name>", escape_xml(stop.name.as_str()))?;
+            writeln!(writer, "        <description>{}</description>", escape_xml(stop.description.as_str()))?;
+            writeln!(writer, "        <styleUrl>#tour{tour_idx}</styleUrl>")?;
+            writeln!(writer, "        <Point><coordinates>{lng},{lat}</coordinates></Point>")?;
+            writeln!(writer, "      </Placemark>")?;
+        }
+
+        let coordinates = tour.path.iter().map(|(lat, lng)| format!("{lng},{lat}")).collect::<Vec<_>>().join(" ");
+        writeln!(writer, "      <Placemark>")?;
+        writeln!(writer, "        <name>{}</name>", escape_xml(tour.name.as_str()))?;
+        writeln!(writer, "        <styleUrl>#tour{tour_idx}</styleUrl>")?;
+        writeln!(
+            writer,
+            "        <LineString><tessellate>1</tessellate><coordinates>{coordinates}</coordinates></LineString>"
+        )?;
+        writeln!(writer, "      </Placemark>")?;
+        writeln!(writer, "    </Folder>")?;
+    }
+
+    writeln!(writer, "  </Document>")?;
+    writeln!(writer, "</kml>")?;
+
+    writer.flush()
+}
+
+/// Serializes solution tours into GPX format: each tour is a route with stops as route points and a track
+/// with the tour path.
+pub fn serialize_solution_as_gpx<W: Write>(solution: &ApiSolution, writer: &mut BufWriter<W>) -> Result<(), Error> {
+    let tours = get_export_tours(solution)?;
+
+    writeln!(writer, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(
+        writer,
+        r#"<gpx version="1.1" creator="vrp-pragmatic" xmlns="http://www.topografix.com/GPX/1/1" xmlns:gpx_style="http://www.topografix.com/GPX/gpx_style/0/2">"#
+    )?;
+
+    for (tour_idx, tour) in tours.iter().enumerate() {
+        let name = escape_xml(tour.name.as_str());
+        let extensions = format!(
+            "<extensions><gpx_style:line><gpx_style:color>{}</gpx_style:color></gpx_style:line></extensions>",
+            get_color(tour_idx).trim_start_matches('#').to_uppercase()
+        );
+
+        writeln!(writer, "  <rte>")?;
+        writeln!(writer, "    <name>{name}</name>")?;
+        writeln!(writer, "    {extensions}")?;
+        for stop in tour.stops.iter() {
+            let (lat, lng) = stop.coordinate;
+            writeln!(writer, r#"    <rtept lat="{lat}" lon="{lng}">"#)?;
+            writeln!(writer, "      <name>{}</name>", escape_xml(stop.name.as_str()))?;
+            writeln!(writer, "      <desc>{}</desc>", escape_xml(stop.description.as_str()))?;
+            writeln!(writer, "    </rtept>")?;
+        }
+        writeln!(writer, "  </rte>")?;
+
+        writeln!(writer, "  <trk>")?;
+        writeln!(writer, "    <name>{name}</name>")?;
+        writeln!(writer, "    {extensions}")?;
+        writeln!(writer, "    <trkseg>")?;
+        for (lat, lng) in tour.path.iter() {
+            writeln!(writer, r#"      <trkpt lat="{lat}" lon="{lng}"/>"#)?;
+        }
+        writeln!(writer, "    </trkseg>")?;
+        writeln!(writer, "  </trk>")?;
+    }
+
+    writeln!(writer, "</gpx>")?;
+
+    writer.flush()
+}
+
+/// Keeps tour data needed for export.
+struct ExportTour {
+    name: String,
+    stops: Vec<ExportStop>,
+    /// A tour path as (lat, lng) pairs.
+    path: Vec<(Float, Float)>,
+}
+
+/// Keeps stop data needed for export.
+struct ExportStop {
+    name: String,
+    description: String,
+    /// A stop coordinate as (lat, lng) pair.
+    coordinate: (Float, Float),
+}
+
+fn get_export_tours(solution: &ApiSolution) -> Result<Vec<ExportTour>, Error> {
+    solution
+        .tours
+        .iter()
+        .map(|tour| {
+            let stops = tour
+                .stops
+                .iter()
+                .enumerate()
+                .filter_map(|(stop_idx, stop)| stop.as_point().map(|stop| (stop_idx, stop)))
+                .map(|(stop_idx, stop)| {
+                    Ok(ExportStop {
+                        name: format!("{} #{stop_idx}: {}", tour.vehicle_id, get_job_ids(stop)),
+                        description: get_stop_description(stop),
+                        coordinate: get_lat_lng(&stop.location)?,
+                    })
+                })
+                .collect::<Result<Vec<_>, Error>>()?;
+
+            let path = tour.stops.iter().filter_map(|stop| stop.as_point()).try_fold(
+                Vec::<(Float, Float)>::new(),
+                |mut path, stop| {
+                    let points = match stop.geometry.as_ref() {
+                        Some(geometry) if !path.is_empty() => decode_polyline(geometry)
+                            .map_err(|err| Error::new(ErrorKind::InvalidData, err.to_string()))?,
+                        _ => vec![get_lat_lng(&stop.location)?],
+                    };
+
+                    points.into_iter().for_each(|point| {
+                        if path.last() != Some(&point) {
+                            path.push(point)
+                        }
+                    });
+
+                    Ok::<_, Error>(path)
+                },
+            )?;
+
+            Ok(ExportTour { name: format!("{} (shift {})", tour.vehicle_id, tour.shift_index), stops, path })
+        })
+        .collect()
+}
+
+fn get_job_ids(stop: &PointStop) -> String {
+    stop.activities.iter().map(|activity| activity.job_id.as_str()).collect::<Vec<_>>().join(", ")
+}
+
+fn get_stop_description(stop: &PointStop) -> String {
+    let activities = stop
+        .activities
+        .iter()
+        .map(|activity| format!("{} ({})", activity.job_id, activity.activity_type))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let load = stop.load.iter().map(|load| load.to_string()).collect::<Vec<_>>().join(",");
+
+    format!(
+        "arrival: {}, departure: {}, distance: {}, load: {load}, activities: {activities}",
+        stop.time.arrival, stop.time.departure, stop.distance
+    )
+}
+
+/// Converts `#rrggbb` color into KML `aabbggrr` representation.
+fn get_kml_color(color: &str) -> String {
+    let color = color.trim_start_matches('#');
+    let (red, green, blue) = (&color[0..2], &color[2..4], &color[4..6]);
+
+    format!("ff{blue}{green}{red}")
+}
+
+fn get_lat_lng(location: &Location) -> Result<(Float, Float), Error> {
+    match location {
+        Location::Coordinate { lat, lng } => Ok((*lat, *lng)),
+        _ => Err(Error::new(ErrorKind::InvalidData, "only locations with coordinates can be exported")),
+    }
+}
+
+fn escape_xml(value: &str) -> String {
+    value.chars().fold(String::with_capacity(value.len()), |mut acc, ch| {
+        match ch {
+            '&' => acc.push_str("&amp;"),
+            '<' => acc.push_str("&lt;"),
+            '>' => acc.push_str("&gt;"),
+            '"' => acc.push_str("&quot;"),
+            '\'' => acc.push_str("&apos;"),
+            _ => acc.push(ch),
+        }
+        acc
+    })
+}
@@ -0,0 +1,254 @@
+//! Provides logic to handle different date time formats used in problem definition.
+
+#[cfg(test)]
+#[path = "../../tests/unit/format/time_format_test.rs"]
+mod time_format_test;
+
+use crate::format::problem::*;
+use crate::format::solution::{Interval, Schedule, Solution, Stop};
+use crate::format::{FormatError, TimeFormatExtraProperty};
+use time::format_description::well_known::Rfc3339;
+use time::{Date, Month, OffsetDateTime, UtcOffset};
+use vrp_core::models::Extras;
+use vrp_core::prelude::{Float, GenericError, GenericResult};
+
+/// Specifies a format of date time values which is used to write solution.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TimeFormat {
+    /// A date time in RFC3339 format with given UTC offset in seconds.
+    Rfc3339 {
+        /// UTC offset in seconds.
+        offset: i32,
+    },
+    /// An amount of seconds since unix epoch.
+    EpochSeconds,
+}
+
+impl Default for TimeFormat {
+    fn default() -> Self {
+        Self::Rfc3339 { offset: 0 }
+    }
+}
+
+impl TimeFormat {
+    /// Detects time format used by the problem: the format of the first vehicle shift start is used.
+    pub fn detect(problem: &Problem) -> Self {
+        let timezone = parse_timezone(problem.timezone.as_deref()).unwrap_or(UtcOffset::UTC);
+
+        problem.fleet.vehicles.first().and_then(|vehicle| vehicle.shifts.first()).map_or(Self::default(), |shift| {
+            let time = shift.start.earliest.as_str();
+            if is_epoch_seconds(time) {
+                Self::EpochSeconds
+            } else if let Ok(time) = OffsetDateTime::parse(time, &Rfc3339) {
+                Self::Rfc3339 { offset: time.offset().whole_seconds() }
+            } else {
+                Self::Rfc3339 { offset: timezone.whole_seconds() }
+            }
+        })
+    }
+
+    /// Formats timestamp using the format.
+    pub fn format(&self, time: Float) -> String {
+        match self {
+            Self::EpochSeconds => (time as i64).to_string(),
+            Self::Rfc3339 { offset } => OffsetDateTime::from_unix_timestamp(time as i64)
+                .map_err(|err| format!("Invalid timestamp {time}: {err}"))
+                .and_then(|time| {
+                    let offset = UtcOffset::from_whole_seconds(*offset).unwrap_or(UtcOffset::UTC);
+                    time.to_offset(offset).format(&Rfc3339).map_err(|err| format!("Format error: {err}"))
+                })
+                .unwrap(),
+        }
+    }
+}
+
+/// Parses date time specified in RFC3339 format (with any UTC offset) or as seconds since unix epoch.
+pub(crate) fn parse_date_time(time: &str) -> GenericResult<Float> {
+    if is_epoch_seconds(time) {
+        return time.parse::<Float>().map_err(|err| format!("cannot parse epoch seconds: {err}").into());
+    }
+
+    OffsetDateTime::parse(time, &Rfc3339)
+        .map(|time| time.unix_timestamp() as Float)
+        .map_err(|err| format!("cannot parse date: {err}").into())
+}
+
+/// Normalizes time values of the problem: date-only values are converted to RFC3339 date time at
+/// midnight using problem timezone. Other values are kept as is.
+pub(crate) fn normalize_times(mut problem: Problem) -> Result<Problem, FormatError> {
+    let timezone = parse_timezone(problem.timezone.as_deref()).map_err(|err| {
+        FormatError::new(
+            "E0005".to_string(),
+            "cannot parse problem timezone".to_string(),
+            format!("make sure that timezone is specified as UTC offset, e.g. '+02:00': {err}"),
+        )
+    })?;
+
+    visit_times(&mut problem, &mut |time: &mut String| {
+        if let Some(date) = parse_date(time) {
+            *time = date
+                .midnight()
+                .assume_offset(timezone)
+                .format(&Rfc3339)
+                .expect("cannot format date as RFC3339 date time");
+        }
+    });
+
+    Ok(problem)
+}
+
+/// Rewrites all date time values of the solution using given time format.
+pub(crate) fn apply_time_format(solution: &mut Solution, format: &TimeFormat) {
+    let reformat = |time: &mut String| {
+        if let Ok(timestamp) = parse_date_time(time) {
+            *time = format.format(timestamp);
+        }
+    };
+    let reformat_schedule = |schedule: &mut Schedule| {
+        reformat(&mut schedule.arrival);
+        reformat(&mut schedule.departure);
+    };
+    let reformat_interval = |interval: &mut Interval| {
+        reformat(&mut interval.start);
+        reformat(&mut interval.end);
+    };
+
+    solution.tours.iter_mut().flat_map(|tour| tour.stops.iter_mut()).for_each(|stop| {
+        match stop {
+            Stop::Point(point) => {
+                reformat_schedule(&mut point.time);
+                point.parking.iter_mut().for_each(reformat_interval);
+            }
+            Stop::Transit(transit) => reformat_schedule(&mut transit.time),
+        }
+
+        stop.activities_mut().iter_mut().for_each(|activity| {
+            activity.time.iter_mut().for_each(reformat_interval);
+            activity.commute.iter_mut().for_each(|commute| {
+                commute
+                    .forward
+                    .iter_mut()
+                    .chain(commute.backward.iter_mut())
+                    .for_each(|info| reformat_interval(&mut info.time))
+            });
+        });
+    });
+}
+
+/// Sets time format used by the problem to extras.
+pub(crate) fn set_time_format(extras: &mut Extras, format: TimeFormat) {
+    if format != TimeFormat::default() {
+        extras.set_time_format(std::sync::Arc::new(format));
+    }
+}
+
+fn visit_times(problem: &mut Problem, visitor: &mut dyn FnMut(&mut String)) {
+    let visit_windows = |times: &mut Vec<Vec<String>>, visitor: &mut dyn FnMut(&mut String)| {
+        times.iter_mut().flatten().for_each(visitor)
+    };
+
+    problem
+        .plan
+        .jobs
+        .iter_mut()
+        .flat_map(|job| {
+            job.pickups
+                .iter_mut()
+                .chain(job.deliveries.iter_mut())
+                .chain(job.replacements.iter_mut())
+                .chain(job.services.iter_mut())
+                .flatten()
+        })
+        .flat_map(|task| task.places.iter_mut())
+        .filter_map(|place| place.times.as_mut())
+        .for_each(|times| visit_windows(times, visitor));
+
+    for shift in problem.fleet.vehicles.iter_mut().flat_map(|vehicle| vehicle.shifts.iter_mut()) {
+        visitor(&mut shift.start.earliest);
+        shift.start.latest.iter_mut().for_each(&mut *visitor);
+
+        if let Some(end) = shift.end.as_mut() {
+            end.earliest.iter_mut().for_each(&mut *visitor);
+            visitor(&mut end.latest);
+        }
+
+        for vehicle_break in shift.breaks.iter_mut().flatten() {
+            match vehicle_break {
+                VehicleBreak::Optional { time: VehicleOptionalBreakTime::TimeWindow(times), .. } => {
+                    times.iter_mut().for_each(&mut *visitor)
+                }
+                VehicleBreak::Required { time: VehicleRequiredBreakTime::ExactTime { earliest, latest }, .. } => {
+                    visitor(earliest);
+                    visitor(latest);
+                }
+                _ => {}
+            }
+        }
+
+        shift
+            .reloads
+            .iter_mut()
+            .flatten()
+            .filter_map(|reload| reload.times.as_mut())
+            .for_each(|times| visit_windows(times, visitor));
+
+        shift
+            .recharges
+            .iter_mut()
+            .flat_map(|recharges| recharges.stations.iter_mut())
+            .filter_map(|station| station.times.as_mut())
+            .for_each(|times| visit_windows(times, visitor));
+    }
+
+    problem.fleet.depots.iter_mut().flatten().for_each(|depot| visit_windows(&mut depot.times, visitor));
+
+    problem
+        .fleet
+        .profiles
+        .iter_mut()
+        .flat_map(|profile| profile.restrictions.iter_mut().flatten())
+        .flat_map(|restriction| restriction.time.iter_mut())
+        .for_each(visitor);
+}
+
+fn parse_timezone(timezone: Option<&str>) -> GenericResult<UtcOffset> {
+    let Some(timezone) = timezone else { return Ok(UtcOffset::UTC) };
+
+    if timezone == "Z" {
+        return Ok(UtcOffset::UTC);
+    }
+
+    let (sign, value) = match timezone.split_at_checked(1) {
+        Some(("+", value)) => (1, value),
+        Some(("-", value)) => (-1, value),
+        _ => return Err(format!("unexpected timezone: '{timezone}'").into()),
+    };
+
+    let (hours, minutes) = value
+        .split_once(':')
+        .and_then(|(hours, minutes)| Some((hours.parse::<i8>().ok()?, minutes.parse::<i8>().ok()?)))
+        .ok_or_else(|| GenericError::from(format!("unexpected timezone: '{timezone}'")))?;
+
+    UtcOffset::from_hms(sign * hours, sign * minutes, 0).map_err(|err| format!("invalid timezone: {err}").into())
+}
+
+fn parse_date(time: &str) -> Option<Date> {
+    let mut parts = time.splitn(3, '-');
+    let (year, month, day) = (parts.next()?, parts.next()?, parts.next()?);
+
+    if year.len() != 4 || month.len() != 2 || day.len() != 2 {
+        return None;
+    }
+
+    let month = Month::try_from(month.parse::<u8>().ok()?).ok()?;
+
+    Date::from_calendar_date(year.parse().ok()?, month, day.parse().ok()?).ok()
+}
+
+fn is_epoch_seconds(time: &str) -> bool {
+    let digits = time.strip_prefix('-').unwrap_or(time);
+
+    !digits.is_empty()
+        && digits.chars().all(|c| c.is_ascii_digit() || c == '.')
+        && digits.chars().filter(|&c| c == '.').count() <= 1
+}
@@ -34,8 +34,9 @@ pub mod checker;
 pub mod format;
 pub mod validation;
 
-use crate::format::problem::Problem;
-use crate::format::{CoordIndex, Location};
+use crate::format::problem::{Problem, deserialize_problem_with_jobs};
+use crate::format::{CoordIndex, Location, MultiFormatError};
+use std::io::{BufReader, Read};
 use time::OffsetDateTime;
 use time::format_description::well_known::Rfc3339;
 use vrp_core::prelude::{Float, GenericError};
@@ -46,6 +47,22 @@ pub fn get_unique_locations(problem: &Problem) -> Vec<Location> {
     CoordIndex::new(problem).unique()
 }
 
+/// Get lists of unique locations in the problem read from `BufReader` in json format. Jobs are processed one by one
+/// while being parsed, so the whole problem is never kept in memory.
+pub fn get_unique_locations_from_reader<R: Read>(reader: BufReader<R>) -> Result<Vec<Location>, MultiFormatError> {
+    let mut index = CoordIndex::empty();
+
+    let problem = deserialize_problem_with_jobs(reader, |job| {
+        index.add_job(&job);
+        Ok(())
+    })?;
+
+    index.add_fleet(&problem.fleet);
+    index.seal();
+
+    Ok(index.unique())
+}
+
 fn format_time(time: Float) -> String {
     OffsetDateTime::from_unix_timestamp(time as i64)
         .map_err(|err| format!("Invalid timestamp {}: {}", time, err))
@@ -58,7 +75,5 @@ fn parse_time(time: &str) -> Float {
 }
 
 fn parse_time_safe(time: &str) -> Result<Float, GenericError> {
-    OffsetDateTime::parse(time, &Rfc3339)
-        .map(|time| time.unix_timestamp() as Float)
-        .map_err(|err| format!("cannot parse date: {err}").into())
+    format::parse_date_time(time)
 }
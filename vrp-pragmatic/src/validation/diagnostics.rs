@@ -0,0 +1,201 @@
+#[cfg(test)]
+#[path = "../../tests/unit/validation/diagnostics_test.rs"]
+mod diagnostics_test;
+
+use super::*;
+use serde::Serialize;
+use std::collections::HashSet;
+
+/// Specifies severity of the diagnostic.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// The problem cannot be solved until the issue is fixed.
+    Error,
+    /// The problem can be solved, but the issue is likely a mistake in problem definition.
+    Warning,
+}
+
+/// A machine-readable diagnostic which points to the problematic part of problem definition.
+#[derive(Clone, Debug, Serialize)]
+pub struct Diagnostic {
+    /// A rule code from the error index.
+    pub code: String,
+    /// A diagnostic severity.
+    pub severity: Severity,
+    /// A JSON pointer to the problematic element of problem definition. Empty string points to the whole document.
+    pub path: String,
+    /// A possible cause.
+    pub cause: String,
+    /// An action to take in order to fix the issue.
+    pub action: String,
+    /// Extra details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
+}
+
+impl Diagnostic {
+    fn new(error: &FormatError, severity: Severity, path: String) -> Self {
+        Self {
+            code: error.code.clone(),
+            severity,
+            path,
+            cause: error.cause.clone(),
+            action: error.action.clone(),
+            details: error.details.clone(),
+        }
+    }
+}
+
+impl From<FormatError> for Diagnostic {
+    fn from(error: FormatError) -> Self {
+        Diagnostic::new(&error, Severity::Error, String::default())
+    }
+}
+
+/// Runs all validation rules on the problem definition without creating a problem model and returns
+/// diagnostics. A diagnostic is created for each problematic element which can be located in problem definition.
+pub fn get_problem_diagnostics(problem: &Problem, matrices: Option<&Vec<Matrix>>) -> Vec<Diagnostic> {
+    let coord_index = CoordIndex::new(problem);
+
+    ValidationContext::new(problem, matrices, &coord_index)
+        .validate()
+        .err()
+        .into_iter()
+        .flatten()
+        .flat_map(|error| create_diagnostics(problem, &error, Severity::Error))
+        .chain(
+            check_w1500_unused_profiles(problem)
+                .into_iter()
+                .flat_map(|warning| create_diagnostics(problem, &warning, Severity::Warning)),
+        )
+        .collect()
+}
+
+/// Checks that all routing profiles are used by some vehicle type or clustering.
+fn check_w1500_unused_profiles(problem: &Problem) -> Option<FormatError> {
+    let used_profiles = problem
+        .fleet
+        .vehicles
+        .iter()
+        .map(|vehicle| vehicle.profile.matrix.as_str())
+        .chain(problem.plan.clustering.iter().map(|clustering| match clustering {
+            Clustering::Vicinity { profile, .. } => profile.matrix.as_str(),
+        }))
+        .collect::<HashSet<_>>();
+
+    let unused_profiles = problem
+        .fleet
+        .profiles
+        .iter()
+        .filter(|profile| !used_profiles.contains(profile.name.as_str()))
+        .map(|profile| profile.name.clone())
+        .collect::<Vec<_>>();
+
+    if unused_profiles.is_empty() {
+        None
+    } else {
+        Some(FormatError::new(
+            "W1500".to_string(),
+            "unused routing profile".to_string(),
+            format!("remove profiles which are not used by any vehicle type: '{}'", unused_profiles.join(", ")),
+        ))
+    }
+}
+
+fn create_diagnostics(problem: &Problem, error: &FormatError, severity: Severity) -> Vec<Diagnostic> {
+    let section = match error.code.get(1..3) {
+        // NOTE these rules are about locations and routing matrices which are not part of problem definition
+        _ if matches!(error.code.as_str(), "E1502" | "E1503" | "E1504") => "",
+        Some("11") => "/plan/jobs",
+        Some("12") => "/plan/relations",
+        Some("13") => "/fleet/vehicles",
+        Some("15") => "/fleet/profiles",
+        Some("16") => "/objectives",
+        _ => "",
+    };
+
+    let mut paths = get_mentioned_ids(error.action.as_str())
+        .into_iter()
+        .flat_map(|id| resolve_paths(problem, section, id))
+        .collect::<Vec<_>>();
+
+    if paths.is_empty() {
+        paths.push(section.to_string());
+    }
+
+    let mut visited = HashSet::new();
+    paths.retain(|path| visited.insert(path.clone()));
+
+    paths.into_iter().map(|path| Diagnostic::new(error, severity, path)).collect()
+}
+
+/// Gets ids mentioned in action message: validation rules list them either quoted or at the end of message.
+fn get_mentioned_ids(action: &str) -> Vec<&str> {
+    let quoted = action.split('\'').skip(1).step_by(2).collect::<Vec<_>>();
+    let lists =
+        if quoted.is_empty() { action.rsplit_once(": ").map(|(_, ids)| vec![ids]).unwrap_or_default() } else { quoted };
+
+    lists.into_iter().flat_map(|ids| ids.split(", ")).map(|id| id.trim()).filter(|id| !id.is_empty()).collect()
+}
+
+fn resolve_paths(problem: &Problem, section: &str, id: &str) -> Vec<String> {
+    let indexed = |idx: usize| format!("{section}/{idx}");
+
+    match section {
+        "/plan/jobs" => {
+            problem.plan.jobs.iter().enumerate().filter(|(_, job)| job.id == id).map(|(idx, _)| indexed(idx)).collect()
+        }
+        "/plan/relations" => problem
+            .plan
+            .relations
+            .iter()
+            .flatten()
+            .enumerate()
+            .filter(|(_, relation)| relation.vehicle_id == id || relation.jobs.iter().any(|job_id| job_id == id))
+            .map(|(idx, _)| indexed(idx))
+            .collect(),
+        "/fleet/vehicles" => problem
+            .fleet
+            .vehicles
+            .iter()
+            .enumerate()
+            .flat_map(|(type_idx, vehicle)| {
+                let type_path = (vehicle.type_id == id).then(|| indexed(type_idx));
+                let vehicle_paths = vehicle
+                    .vehicle_ids
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, vehicle_id)| *vehicle_id == id)
+                    .map(move |(idx, _)| format!("{section}/{type_idx}/vehicleIds/{idx}"));
+
+                type_path.into_iter().chain(vehicle_paths)
+            })
+            .collect(),
+        "/fleet/profiles" => {
+            let profile_paths = problem
+                .fleet
+                .profiles
+                .iter()
+                .enumerate()
+                .filter(|(_, profile)| profile.name == id)
+                .map(|(idx, _)| indexed(idx))
+                .collect::<Vec<_>>();
+
+            if !profile_paths.is_empty() {
+                return profile_paths;
+            }
+
+            // NOTE profile is not defined, so point to the vehicle types which refer to it
+            problem
+                .fleet
+                .vehicles
+                .iter()
+                .enumerate()
+                .filter(|(_, vehicle)| vehicle.profile.matrix == id)
+                .map(|(idx, _)| format!("/fleet/vehicles/{idx}/profile/matrix"))
+                .collect()
+        }
+        _ => vec![],
+    }
+}
@@ -0,0 +1,86 @@
+#[cfg(test)]
+#[path = "../../tests/unit/validation/error_test.rs"]
+mod error_test;
+
+use crate::format::{FormatError, MultiFormatError};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// Specifies a validation error grouped by the part of problem definition where it is found.
+#[derive(Clone, Debug)]
+pub enum ValidationError {
+    /// An error in `plan.jobs` definition (E11xx).
+    Jobs(FormatError),
+    /// An error in `plan.relations` definition (E12xx).
+    Relations(FormatError),
+    /// An error in `fleet.vehicles` definition (E13xx).
+    Vehicles(FormatError),
+    /// An error in routing profiles or matrices definition (E15xx).
+    Routing(FormatError),
+    /// An error in `objectives` definition (E16xx).
+    Objectives(FormatError),
+}
+
+impl ValidationError {
+    /// Returns an error code from the error index.
+    pub fn code(&self) -> &str {
+        self.error().code.as_str()
+    }
+
+    /// Returns an underlying format error.
+    pub fn error(&self) -> &FormatError {
+        match self {
+            Self::Jobs(err)
+            | Self::Relations(err)
+            | Self::Vehicles(err)
+            | Self::Routing(err)
+            | Self::Objectives(err) => err,
+        }
+    }
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.error())
+    }
+}
+
+impl Error for ValidationError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(self.error())
+    }
+}
+
+impl TryFrom<FormatError> for ValidationError {
+    type Error = FormatError;
+
+    fn try_from(error: FormatError) -> Result<Self, Self::Error> {
+        match error.code.get(..3) {
+            Some("E11") => Ok(Self::Jobs(error)),
+            Some("E12") => Ok(Self::Relations(error)),
+            Some("E13") => Ok(Self::Vehicles(error)),
+            Some("E15") => Ok(Self::Routing(error)),
+            Some("E16") => Ok(Self::Objectives(error)),
+            _ => Err(error),
+        }
+    }
+}
+
+impl From<ValidationError> for FormatError {
+    fn from(error: ValidationError) -> Self {
+        match error {
+            ValidationError::Jobs(err)
+            | ValidationError::Relations(err)
+            | ValidationError::Vehicles(err)
+            | ValidationError::Routing(err)
+            | ValidationError::Objectives(err) => err,
+        }
+    }
+}
+
+impl MultiFormatError {
+    /// Returns errors produced by validation engine skipping any other format errors.
+    pub fn validation_errors(&self) -> Vec<ValidationError> {
+        self.errors.iter().cloned().filter_map(|err| ValidationError::try_from(err).ok()).collect()
+    }
+}
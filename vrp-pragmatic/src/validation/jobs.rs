@@ -4,6 +4,7 @@ mod jobs_test;
 
 use super::*;
 use crate::utils::combine_error_results;
+use std::collections::HashSet;
 use vrp_core::models::common::MultiDimLoad;
 
 /// Checks that plan has no jobs with duplicate ids.
@@ -179,6 +180,136 @@ fn check_e1107_negative_demand(ctx: &ValidationContext) -> Result<(), FormatErro
     }
 }
 
+/// Checks that job priority is greater than zero.
+fn check_e1108_positive_priority(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids =
+        ctx.jobs().filter(|job| job.priority.is_some_and(|p| p < 1)).map(|job| job.id.clone()).collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1108".to_string(),
+            "job priority should be greater than zero".to_string(),
+            format!("change priority of jobs to be greater than zero: '{}'", ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that each job incompatibility has at least two different categories.
+fn check_e1109_correct_incompatibilities(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let has_invalid = ctx
+        .problem
+        .plan
+        .incompatibilities
+        .iter()
+        .flatten()
+        .any(|incompatibility| incompatibility.categories.iter().collect::<HashSet<_>>().len() < 2);
+
+    if has_invalid {
+        Err(FormatError::new(
+            "E1109".to_string(),
+            "invalid job incompatibility".to_string(),
+            "specify at least two different categories in each incompatibility".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that job places have no negative cost.
+fn check_e1110_negative_place_cost(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            ctx.tasks(job)
+                .iter()
+                .flat_map(|task| task.places.iter())
+                .any(|place| place.cost.is_some_and(|cost| cost < 0.))
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1110".to_string(),
+            "job place has negative cost".to_string(),
+            format!("fix negative place cost in jobs with ids: '{}'", ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that crew jobs are defined properly.
+fn check_e1111_correct_crew(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let related_ids =
+        ctx.problem.plan.relations.iter().flatten().flat_map(|relation| relation.jobs.iter()).collect::<HashSet<_>>();
+    let all_ids = ctx.jobs().map(|job| &job.id).collect::<HashSet<_>>();
+
+    let ids = ctx
+        .jobs()
+        .filter_map(|job| job.crew.map(|crew| (job, crew)))
+        .filter(|&(job, crew)| {
+            let tasks = ctx.tasks(job);
+            let is_single_place = tasks.len() == 1 && tasks[0].places.len() == 1;
+            let has_conflicts = (2..=crew).any(|idx| all_ids.contains(&format!("{}_crew_{idx}", job.id)));
+
+            crew < 1
+                || (crew > 1
+                    && (!is_single_place || job.group.is_some() || related_ids.contains(&job.id) || has_conflicts))
+        })
+        .map(|(job, _)| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1111".to_string(),
+            "invalid crew job".to_string(),
+            format!(
+                "make sure that crew is at least one and crew job has single task with single place, no group and \
+                 no relations: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that job skill levels are defined properly.
+fn check_e1112_correct_skill_levels(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            job.skills.as_ref().and_then(|skills| skills.levels.as_ref()).is_some_and(|levels| {
+                let has_invalid_level = levels.iter().any(|level| match (level.min_level, level.preferred_level) {
+                    (None, None) => true,
+                    (Some(min), Some(preferred)) => preferred < min,
+                    _ => false,
+                });
+
+                has_invalid_level || get_duplicates(levels.iter().map(|level| &level.name)).is_some()
+            })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1112".to_string(),
+            "invalid job skill levels".to_string(),
+            format!(
+                "make sure that each skill level has unique name, min or preferred level, and preferred level is \
+                 not lower than min: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
 /// Validates jobs from the plan.
 pub fn validate_jobs(ctx: &ValidationContext) -> Result<(), MultiFormatError> {
     combine_error_results(&[
@@ -190,6 +321,11 @@ pub fn validate_jobs(ctx: &ValidationContext) -> Result<(), MultiFormatError> {
         check_e1105_empty_jobs(ctx),
         check_e1106_negative_duration(ctx),
         check_e1107_negative_demand(ctx),
+        check_e1108_positive_priority(ctx),
+        check_e1109_correct_incompatibilities(ctx),
+        check_e1110_negative_place_cost(ctx),
+        check_e1111_correct_crew(ctx),
+        check_e1112_correct_skill_levels(ctx),
     ])
     .map_err(From::from)
 }
@@ -11,6 +11,7 @@ pub struct ValidationContext<'a> {
     pub matrices: Option<&'a Vec<Matrix>>,
 
     coord_index: &'a CoordIndex,
+    matrix_size: Option<usize>,
     job_index: HashMap<String, Job>,
 }
 
@@ -31,6 +32,12 @@ use self::relations::validate_relations;
 
 mod routing;
 use self::routing::validate_routing;
+
+mod diagnostics;
+pub use self::diagnostics::*;
+
+mod error;
+pub use self::error::ValidationError;
 use std::collections::HashMap;
 
 impl<'a> ValidationContext<'a> {
@@ -40,10 +47,17 @@ impl<'a> ValidationContext<'a> {
             problem,
             matrices,
             coord_index,
+            matrix_size: None,
             job_index: problem.plan.jobs.iter().map(|job| (job.id.clone(), job.clone())).collect(),
         }
     }
 
+    /// Sets matrix size explicitly when routing matrices have no values, e.g. for binary matrices.
+    pub fn with_matrix_size(mut self, matrix_size: Option<usize>) -> Self {
+        self.matrix_size = matrix_size;
+        self
+    }
+
     /// Validates problem on set of rules.
     pub fn validate(&self) -> Result<(), MultiFormatError> {
         let multi_err: MultiFormatError = validate_jobs(self)
@@ -166,13 +166,161 @@ fn check_e1607_jobs_with_value_but_no_objective(
     }
 }
 
+/// Checks that strict priority objective can be specified only when job with priority is used.
+fn check_e1608_no_jobs_with_priority_objective(
+    ctx: &ValidationContext,
+    objectives: &[&Objective],
+) -> Result<(), FormatError> {
+    let has_priority_objective =
+        get_objectives_flattened(objectives).any(|objective| matches!(objective, StrictPriority));
+    let has_no_jobs_with_priority = !ctx.problem.plan.jobs.iter().any(|job| job.priority.is_some());
+
+    if has_priority_objective && has_no_jobs_with_priority {
+        Err(FormatError::new(
+            "E1608".to_string(),
+            "redundant strict priority objective".to_string(),
+            "specify at least one job with priority or delete 'strict-priority' objective".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that strict priority objective is specified when some jobs have priority property set.
+fn check_e1609_jobs_with_priority_but_no_objective(
+    ctx: &ValidationContext,
+    objectives: &[&Objective],
+) -> Result<(), FormatError> {
+    if objectives.is_empty() {
+        return Ok(());
+    }
+
+    let has_no_priority_objective =
+        !get_objectives_flattened(objectives).any(|objective| matches!(objective, StrictPriority));
+    let has_jobs_with_priority = ctx.problem.plan.jobs.iter().any(|job| job.priority.is_some());
+
+    if has_no_priority_objective && has_jobs_with_priority {
+        Err(FormatError::new(
+            "E1609".to_string(),
+            "missing strict priority objective".to_string(),
+            "specify 'strict-priority' objective, remove objectives property or remove priority property from jobs"
+                .to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that tour overlap objective is used only with geo coordinates.
+fn check_e1610_tour_overlap_without_coordinates(
+    ctx: &ValidationContext,
+    objectives: &[&Objective],
+) -> Result<(), FormatError> {
+    let has_overlap_objective =
+        get_objectives_flattened(objectives).any(|objective| matches!(objective, MinimizeTourOverlap { .. }));
+
+    if has_overlap_objective && !ctx.coord_index.has_coordinates() {
+        Err(FormatError::new(
+            "E1610".to_string(),
+            "tour overlap objective requires coordinates".to_string(),
+            "use geo coordinates for job locations or delete 'minimize-tour-overlap' objective".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that tour overlap objective has valid threshold.
+fn check_e1611_tour_overlap_threshold(objectives: &[&Objective]) -> Result<(), FormatError> {
+    let has_invalid_threshold = get_objectives_flattened(objectives).any(|objective| match objective {
+        MinimizeTourOverlap { threshold: Some(threshold) } => !(0. ..1.).contains(threshold),
+        _ => false,
+    });
+
+    if has_invalid_threshold {
+        Err(FormatError::new(
+            "E1611".to_string(),
+            "invalid tour overlap threshold".to_string(),
+            "specify threshold of 'minimize-tour-overlap' objective in [0, 1) range".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that weighted tier has valid weights and no composite objectives inside.
+fn check_e1612_weighted_tier(objectives: &[&Objective]) -> Result<(), FormatError> {
+    let has_invalid_tier = objectives.iter().any(|objective| match objective {
+        WeightedTier { objectives } => {
+            objectives.is_empty()
+                || objectives.iter().any(|o| {
+                    !o.weight.is_finite()
+                        || o.weight < 0.
+                        || matches!(o.objective, MultiObjective { .. } | WeightedTier { .. })
+                })
+        }
+        _ => false,
+    });
+
+    if has_invalid_tier {
+        Err(FormatError::new(
+            "E1612".to_string(),
+            "invalid weighted tier objective".to_string(),
+            "specify at least one non-composite objective with finite non-negative weight in 'weighted-tier'"
+                .to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that max stops objective can be specified only when vehicles have max stops limit.
+fn check_e1614_no_vehicles_with_max_stops_objective(
+    ctx: &ValidationContext,
+    objectives: &[&Objective],
+) -> Result<(), FormatError> {
+    let has_max_stops_objective = get_objectives_flattened(objectives).any(|objective| matches!(objective, MaxStops));
+    let has_no_vehicles_with_max_stops =
+        !ctx.vehicles().any(|vehicle| vehicle.limits.as_ref().is_some_and(|limits| limits.max_stops.is_some()));
+
+    if has_max_stops_objective && has_no_vehicles_with_max_stops {
+        Err(FormatError::new(
+            "E1614".to_string(),
+            "redundant max stops objective".to_string(),
+            "specify 'maxStops' limit for at least one vehicle type or delete 'max-stops' objective".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that strict priority objective is not used inside composite objectives.
+fn check_e1613_nested_strict_priority(objectives: &[&Objective]) -> Result<(), FormatError> {
+    let has_nested_priority = objectives.iter().any(|objective| match objective {
+        MultiObjective { objectives, .. } => objectives.iter().any(|o| matches!(o, StrictPriority)),
+        WeightedTier { objectives } => objectives.iter().any(|o| matches!(o.objective, StrictPriority)),
+        _ => false,
+    });
+
+    if has_nested_priority {
+        Err(FormatError::new(
+            "E1613".to_string(),
+            "nested strict priority objective".to_string(),
+            "move 'strict-priority' objective out of 'multi-objective' or 'weighted-tier' objective".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
 fn get_objectives<'a>(ctx: &'a ValidationContext) -> Option<Vec<&'a Objective>> {
     ctx.problem.objectives.as_ref().map(|objectives| objectives.iter().collect())
 }
 
 fn get_objectives_flattened<'a>(objectives: &'a [&Objective]) -> impl Iterator<Item = &'a Objective> + 'a {
     objectives.iter().flat_map(|&o| match o {
-        MultiObjective { objectives, .. } => Either::Left(objectives.iter()),
+        MultiObjective { objectives, .. } => Either::Left(Either::Left(objectives.iter())),
+        WeightedTier { objectives } => Either::Left(Either::Right(objectives.iter().map(|o| &o.objective))),
         _ => Either::Right(std::iter::once(o)),
     })
 }
@@ -188,6 +336,13 @@ pub fn validate_objectives(ctx: &ValidationContext) -> Result<(), MultiFormatErr
             check_e1605_check_positive_value_and_order(ctx),
             check_e1606_check_multiple_cost_objectives(&objectives),
             check_e1607_jobs_with_value_but_no_objective(ctx, &objectives),
+            check_e1608_no_jobs_with_priority_objective(ctx, &objectives),
+            check_e1609_jobs_with_priority_but_no_objective(ctx, &objectives),
+            check_e1610_tour_overlap_without_coordinates(ctx, &objectives),
+            check_e1611_tour_overlap_threshold(&objectives),
+            check_e1612_weighted_tier(&objectives),
+            check_e1613_nested_strict_priority(&objectives),
+            check_e1614_no_vehicles_with_max_stops_objective(ctx, &objectives),
         ])
         .map_err(From::from)
     } else {
@@ -244,6 +244,37 @@ fn check_e1207_no_incomplete_relation(ctx: &ValidationContext, relations: &[Rela
     }
 }
 
+/// Checks that not-same-tour and different-vehicle relations have at least two distinct jobs,
+/// no reserved job ids and no vehicle specified.
+fn check_e1208_negative_relation_is_correct(relations: &[Relation]) -> Result<(), FormatError> {
+    let indices = relations
+        .iter()
+        .enumerate()
+        .filter(|(_, relation)| relation.type_field.is_negative())
+        .filter(|(_, relation)| {
+            relation.jobs.iter().any(|job_id| is_reserved_job_id(job_id))
+                || relation.jobs.iter().collect::<HashSet<_>>().len() < 2
+                || !relation.vehicle_id.is_empty()
+                || relation.shift_index.is_some()
+        })
+        .map(|(idx, _)| idx.to_string())
+        .collect::<Vec<_>>();
+
+    if indices.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1208".to_string(),
+            "not-same-tour or different-vehicle relation is invalid".to_string(),
+            format!(
+                "specify at least two different job ids without reserved ones and no vehicle id or shift index, \
+                 relation indices: '{}'",
+                indices.join(", ")
+            ),
+        ))
+    }
+}
+
 /// Validates relations in the plan.
 pub fn validate_relations(ctx: &ValidationContext) -> Result<(), MultiFormatError> {
     let vehicle_map = ctx
@@ -252,15 +283,18 @@ pub fn validate_relations(ctx: &ValidationContext) -> Result<(), MultiFormatErro
         .collect::<HashMap<_, _>>();
 
     if let Some(relations) = ctx.problem.plan.relations.as_ref() {
+        let locks = relations.iter().filter(|relation| !relation.type_field.is_negative()).cloned().collect::<Vec<_>>();
+
         combine_error_results(&[
             check_e1200_job_existence(ctx, relations),
-            check_e1201_vehicle_existence(relations, &vehicle_map),
+            check_e1201_vehicle_existence(&locks, &vehicle_map),
             check_e1202_empty_job_list(relations),
-            check_e1203_no_multiple_places_times(ctx, relations),
-            check_e1204_job_assigned_to_multiple_vehicles(relations),
-            check_e1205_relation_has_correct_shift_index(relations, &vehicle_map),
-            check_e1206_relation_has_no_missing_shift_properties(relations, &vehicle_map),
-            check_e1207_no_incomplete_relation(ctx, relations),
+            check_e1203_no_multiple_places_times(ctx, &locks),
+            check_e1204_job_assigned_to_multiple_vehicles(&locks),
+            check_e1205_relation_has_correct_shift_index(&locks, &vehicle_map),
+            check_e1206_relation_has_no_missing_shift_properties(&locks, &vehicle_map),
+            check_e1207_no_incomplete_relation(ctx, &locks),
+            check_e1208_negative_relation_is_correct(relations),
         ])
         .map_err(From::from)
     } else {
@@ -3,8 +3,9 @@
 mod routing_test;
 
 use super::*;
+use crate::parse_time_safe;
 use crate::utils::combine_error_results;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use vrp_core::prelude::Float;
 
 /// Checks that no duplicated profile names specified.
@@ -69,9 +70,12 @@ fn check_e1504_index_size_mismatch(ctx: &ValidationContext) -> Result<(), Format
     let max_index = ctx.coord_index.max_matrix_index();
 
     let (matrix_size, is_correct_index) = ctx
-        .matrices
-        .and_then(|matrices| matrices.first())
-        .map(|matrix| (matrix.distances.len() as Float).sqrt().round() as usize)
+        .matrix_size
+        .or_else(|| {
+            ctx.matrices
+                .and_then(|matrices| matrices.first())
+                .map(|matrix| (matrix.distances.len() as Float).sqrt().round() as usize)
+        })
         .map_or((0_usize, true), |matrix_size| (matrix_size, max_index + 1 == matrix_size));
 
     if !is_correct_index {
@@ -115,6 +119,77 @@ fn check_e1505_profiles_exist(ctx: &ValidationContext) -> Result<(), FormatError
     }
 }
 
+/// Checks that access restrictions of profiles have correct time windows.
+fn check_e1506_correct_access_restrictions(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let profile_names = ctx
+        .problem
+        .fleet
+        .profiles
+        .iter()
+        .filter(|profile| {
+            profile
+                .restrictions
+                .iter()
+                .flatten()
+                .any(|restriction| get_time_window_from_vec(&restriction.time).is_none_or(|tw| tw.start >= tw.end))
+        })
+        .map(|profile| profile.name.clone())
+        .collect::<Vec<_>>();
+
+    if profile_names.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1506".to_string(),
+            "invalid access restriction time in profile".to_string(),
+            format!(
+                "ensure that access restriction time windows of profiles are valid: '{}'",
+                profile_names.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that time dependent routing matrices are consistent.
+fn check_e1507_time_dependent_matrices(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let matrices = ctx.matrices.map(|matrices| matrices.as_slice()).unwrap_or_default();
+
+    if matrices.iter().all(|matrix| matrix.timestamp.is_none()) {
+        return Ok(());
+    }
+
+    let timestamps = matrices.iter().fold(HashMap::<_, Vec<_>>::new(), |mut acc, matrix| {
+        let timestamp = matrix.timestamp.as_ref().and_then(|timestamp| parse_time_safe(timestamp).ok());
+        acc.entry(matrix.profile.clone().unwrap_or_default()).or_default().push(timestamp);
+        acc
+    });
+
+    let mut profile_names = timestamps
+        .into_iter()
+        .filter(|(_, timestamps)| {
+            let is_valid = timestamps.len() > 1 && timestamps.iter().all(|timestamp| timestamp.is_some());
+            let unique = timestamps.iter().flatten().map(|timestamp| *timestamp as i64).collect::<HashSet<_>>();
+
+            !is_valid || unique.len() != timestamps.len()
+        })
+        .map(|(name, _)| name)
+        .collect::<Vec<_>>();
+    profile_names.sort();
+
+    if profile_names.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1507".to_string(),
+            "invalid time dependent routing matrices".to_string(),
+            format!(
+                "ensure that each profile has at least two matrices with valid and unique timestamps: '{}'",
+                profile_names.join(", ")
+            ),
+        ))
+    }
+}
+
 /// Validates routing rules.
 pub fn validate_routing(ctx: &ValidationContext) -> Result<(), MultiFormatError> {
     let location_types = (ctx.coord_index.has_coordinates(), ctx.coord_index.has_indices());
@@ -126,6 +201,8 @@ pub fn validate_routing(ctx: &ValidationContext) -> Result<(), MultiFormatError>
         check_e1503_no_matrix_when_indices_used(ctx, location_types),
         check_e1504_index_size_mismatch(ctx),
         check_e1505_profiles_exist(ctx),
+        check_e1506_correct_access_restrictions(ctx),
+        check_e1507_time_dependent_matrices(ctx),
     ])
     .map_err(From::from)
 }
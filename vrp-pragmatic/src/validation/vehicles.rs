@@ -4,10 +4,11 @@ mod vehicles_test;
 
 use super::*;
 use crate::utils::combine_error_results;
-use crate::validation::common::get_time_windows;
-use crate::{parse_time, parse_time_safe};
+use crate::validation::common::{check_raw_time_windows, get_time_windows};
+use crate::{Location, parse_time, parse_time_safe};
 use std::collections::HashSet;
 use vrp_core::models::common::TimeWindow;
+use vrp_core::prelude::Float;
 
 /// Checks that fleet has no vehicle with duplicate type ids.
 fn check_e1300_no_vehicle_types_with_duplicate_type_ids(ctx: &ValidationContext) -> Result<(), FormatError> {
@@ -258,6 +259,200 @@ fn check_e1308_vehicle_reload_resources(ctx: &ValidationContext) -> Result<(), F
     }
 }
 
+/// Checks that vehicle cost tiers have non-negative fixed cost and at least one non-negative limit.
+fn check_e1309_vehicle_cost_tiers(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.costs.tiers.iter().flatten().any(|tier| {
+                let limits = [tier.max_duration, tier.max_distance];
+
+                tier.fixed < 0.
+                    || limits.iter().all(|limit| limit.is_none())
+                    || limits.iter().flatten().any(|&limit| limit < 0.)
+            })
+        })
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1309".to_string(),
+            "invalid vehicle cost tiers".to_string(),
+            format!(
+                "ensure that each cost tier has non-negative fixed cost and at least one non-negative limit, \
+                 vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that depot operating hours are correct and allow vehicles to depart and return.
+fn check_e1310_depot_operating_hours(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let depots = ctx.problem.fleet.depots.iter().flatten().collect::<Vec<_>>();
+
+    if depots.iter().any(|depot| depot.times.is_empty() || !check_raw_time_windows(&depot.times, false)) {
+        return Err(FormatError::new(
+            "E1310".to_string(),
+            "invalid depot operating hours".to_string(),
+            "make sure that each depot has at least one operating time window and all time windows are correct"
+                .to_string(),
+        ));
+    }
+
+    let get_hours = |location: &Location| {
+        depots
+            .iter()
+            .filter(|depot| depot.location == *location)
+            .flat_map(|depot| get_time_windows(&depot.times))
+            .flatten()
+            .collect::<Vec<_>>()
+    };
+
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.shifts.iter().any(|shift| {
+                let earliest = parse_time(&shift.start.earliest);
+                let latest_arrival = shift.end.as_ref().map_or(Float::MAX, |end| parse_time(&end.latest));
+                let latest_departure = shift.start.latest.as_ref().map_or(latest_arrival, |time| parse_time(time));
+
+                let start_hours = get_hours(&shift.start.location);
+                let can_depart = start_hours.is_empty()
+                    || start_hours.iter().any(|tw| tw.end >= earliest && tw.start <= latest_departure);
+
+                let can_return = shift.end.as_ref().is_none_or(|end| {
+                    let end_hours = get_hours(&end.location);
+                    end_hours.is_empty() || end_hours.iter().any(|tw| tw.end >= earliest && tw.start <= latest_arrival)
+                });
+
+                !can_depart || !can_return
+            })
+        })
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1310".to_string(),
+            "invalid depot operating hours".to_string(),
+            format!(
+                "make sure that vehicle shift times intersect with operating hours of depots used as shift start \
+                 or end, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle cumulative limits are non-negative.
+fn check_e1311_vehicle_cumulative_limits(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.limits.as_ref().is_some_and(|limits| {
+                [limits.max_total_distance, limits.max_total_driving_time]
+                    .iter()
+                    .flatten()
+                    .any(|&limit| !limit.is_finite() || limit < 0.)
+            })
+        })
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1311".to_string(),
+            "invalid vehicle cumulative limits".to_string(),
+            format!(
+                "ensure that max total distance and max total driving time are non-negative, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle skills are unique and their levels are positive.
+fn check_e1312_vehicle_skill_levels(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.skills.as_ref().is_some_and(|skills| {
+                let names = skills.iter().map(|skill| skill.name().to_string()).collect::<Vec<_>>();
+
+                skills.iter().any(|skill| skill.level() == Some(0)) || get_duplicates(names.iter()).is_some()
+            })
+        })
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1312".to_string(),
+            "invalid vehicle skills".to_string(),
+            format!(
+                "ensure that vehicle skills are unique and skill levels are positive, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle capacity change is correct.
+fn check_e1313_vehicle_capacity_change(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let profiles = ctx.problem.fleet.profiles.iter().map(|profile| profile.name.clone()).collect::<HashSet<_>>();
+
+    let type_ids = get_invalid_type_ids(
+        ctx,
+        Box::new(move |vehicle, shift, shift_time| {
+            shift
+                .capacity_change
+                .as_ref()
+                .map(|change| {
+                    let has_valid_capacity = change.capacity.len() == vehicle.capacity.len()
+                        && change
+                            .capacity
+                            .iter()
+                            .zip(vehicle.capacity.iter())
+                            .all(|(&new, &old)| new >= 0 && new <= old);
+                    let has_no_reloads = shift.reloads.as_ref().is_none_or(|reloads| reloads.is_empty());
+                    let has_valid_profile =
+                        change.profile.as_ref().is_none_or(|profile| profiles.contains(&profile.matrix));
+                    let tws = change.times.as_ref().map(|tws| get_time_windows(tws)).unwrap_or_default();
+
+                    has_valid_capacity
+                        && has_no_reloads
+                        && has_valid_profile
+                        && check_shift_time_windows(shift_time, tws, false)
+                })
+                .unwrap_or(true)
+        }),
+    );
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1313".to_string(),
+            "invalid vehicle capacity change".to_string(),
+            format!(
+                "ensure that capacity change has the same dimensions as vehicle capacity and does not exceed it, \
+                 its profile is known, its time windows are valid, and shift has no reloads, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
 type CheckShiftFn = Box<dyn Fn(&VehicleType, &VehicleShift, Option<TimeWindow>) -> bool>;
 
 fn get_invalid_type_ids(ctx: &ValidationContext, check_shift_fn: CheckShiftFn) -> Vec<String> {
@@ -301,6 +496,11 @@ pub fn validate_vehicles(ctx: &ValidationContext) -> Result<(), MultiFormatError
         check_e1306_vehicle_has_no_zero_costs(ctx),
         check_e1307_vehicle_offset_break_rescheduling(ctx),
         check_e1308_vehicle_reload_resources(ctx),
+        check_e1309_vehicle_cost_tiers(ctx),
+        check_e1310_depot_operating_hours(ctx),
+        check_e1311_vehicle_cumulative_limits(ctx),
+        check_e1312_vehicle_skill_levels(ctx),
+        check_e1313_vehicle_capacity_change(ctx),
     ])
     .map_err(From::from)
 }
@@ -34,6 +34,7 @@ fn can_assign_interval_break_between_jobs() {
             ..create_default_fleet()
         },
         objectives: create_test_objectives(),
+        timezone: None,
     };
     let matrix = create_matrix_from_problem(&problem);
 
@@ -117,6 +118,7 @@ fn can_assign_interval_break_with_reload() {
                         ..create_default_reload()
                     }]),
                     recharges: None,
+                    capacity_change: None,
                 }],
                 capacity: vec![2],
                 ..create_default_vehicle_type()
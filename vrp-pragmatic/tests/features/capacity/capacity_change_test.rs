@@ -0,0 +1,110 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_capacity_change(
+    location: (f64, f64),
+    capacity: Vec<i32>,
+    profile: Option<VehicleProfile>,
+) -> VehicleCapacityChange {
+    VehicleCapacityChange { location: location.to_loc(), duration: 1., times: None, capacity, profile, tag: None }
+}
+
+fn create_problem(jobs: Vec<Job>, capacity: Vec<i32>, change: VehicleCapacityChange, end: (f64, f64)) -> Problem {
+    Problem {
+        plan: Plan { jobs, ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    capacity_change: Some(change),
+                    ..create_default_vehicle_shift_with_locations((0., 0.), end)
+                }],
+                capacity,
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_serve_jobs_with_capacity_change() {
+    let problem = create_problem(
+        vec![
+            create_delivery_job("job1", (1., 0.)),
+            create_delivery_job("job2", (2., 0.)),
+            create_delivery_job("job3", (8., 0.)),
+            create_delivery_job("job4", (9., 0.)),
+        ],
+        vec![4],
+        create_capacity_change((5., 0.), vec![2], None),
+        (10., 0.),
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_cheapest_insertion(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(
+        get_ids_from_tour(&solution.tours[0]),
+        vec![
+            vec!["departure"],
+            vec!["job1"],
+            vec!["job2"],
+            vec!["capacityChange"],
+            vec!["job3"],
+            vec!["job4"],
+            vec!["arrival"]
+        ]
+    );
+}
+
+#[test]
+fn can_serve_job_before_capacity_change_when_capacity_is_reduced() {
+    let problem = create_problem(
+        vec![create_delivery_job("job1", (8., 0.)), create_delivery_job("job2", (9., 0.))],
+        vec![2],
+        create_capacity_change((5., 0.), vec![1], None),
+        (10., 0.),
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let tour_ids = get_ids_from_tour(&solution.tours[0]);
+    let change_idx = tour_ids.iter().position(|ids| ids[0] == "capacityChange").unwrap();
+    assert_eq!(change_idx, 2);
+}
+
+#[test]
+fn can_use_changed_profile_after_capacity_change() {
+    let profile = VehicleProfile { matrix: "car".to_string(), scale: Some(0.5) };
+    let problem = create_problem(
+        vec![create_delivery_job("job1", (20., 0.))],
+        vec![10],
+        create_capacity_change((10., 0.), vec![5], Some(profile)),
+        (20., 0.),
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_cheapest_insertion(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(
+        get_ids_from_tour(&solution.tours[0]),
+        vec![vec!["departure"], vec!["capacityChange"], vec!["job1", "arrival"]]
+    );
+    assert_eq!(solution.tours[0].statistic.times.driving, 15);
+}
+
+#[test]
+fn can_skip_capacity_change_when_vehicle_is_not_used() {
+    let problem = create_problem(vec![], vec![10], create_capacity_change((5., 0.), vec![5], None), (10., 0.));
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_cheapest_insertion(problem, Some(vec![matrix]));
+
+    assert!(solution.tours.is_empty());
+    assert!(solution.unassigned.is_none());
+}
@@ -1 +1,2 @@
+mod capacity_change_test;
 mod simple_capacity_test;
@@ -154,10 +154,7 @@ fn can_vary_cluster_size_based_on_capacity_impl(
                         reasons: vec![UnassignedJobReason {
                             code: "CAPACITY_CONSTRAINT".to_string(),
                             description: "does not fit into any vehicle due to capacity".to_string(),
-                            details: Some(vec![UnassignedJobDetail {
-                                vehicle_id: "my_vehicle_1".to_string(),
-                                shift_index: 0,
-                            }]),
+                            details: Some(vec![create_unassigned_detail("my_vehicle_1", 0, (0., 11.))]),
                         }],
                     })
                     .collect()
@@ -87,21 +87,26 @@ impl From<StopData> for Stop {
             },
             load: vec![stop.load],
             activities: stop.activities.into_iter().map(ActivityData::into).collect(),
+            geometry: None,
         })
     }
 }
 
 fn create_statistic(data: (Float, i64, i64, (i64, i64, i64, i64))) -> Statistic {
+    let (cost, distance, duration, (driving, serving, commuting, parking)) = data;
+
     Statistic {
-        cost: data.0,
-        distance: data.1,
-        duration: data.2,
-        times: Timing {
-            driving: data.3.0,
-            serving: data.3.1,
-            commuting: data.3.2,
-            parking: data.3.3,
-            ..Timing::default()
+        cost,
+        distance,
+        duration,
+        times: Timing { driving, serving, commuting, parking, ..Timing::default() },
+        // NOTE assume default vehicle costs: distance and time costs are equal to 1
+        costs: CostBreakdown {
+            fixed: cost - (distance + duration) as Float,
+            distance: distance as Float,
+            driving: (driving + commuting) as Float,
+            serving: (serving + parking) as Float,
+            ..CostBreakdown::default()
         },
     }
 }
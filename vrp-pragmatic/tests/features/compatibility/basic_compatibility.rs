@@ -1,5 +1,5 @@
 use crate::format::problem::*;
-use crate::format::solution::{UnassignedJobDetail, UnassignedJobReason};
+use crate::format::solution::UnassignedJobReason;
 use crate::helpers::*;
 
 #[test]
@@ -75,7 +75,7 @@ fn can_unassign_job_due_to_compatibility() {
         vec![UnassignedJobReason {
             code: "COMPATIBILITY_CONSTRAINT".to_string(),
             description: "cannot be assigned due to compatibility constraint".to_string(),
-            details: Some(vec![UnassignedJobDetail { vehicle_id: "my_vehicle_1".to_string(), shift_index: 0 }])
+            details: Some(vec![create_unassigned_detail("my_vehicle_1", 0, (0., 3.))])
         }]
     );
 }
@@ -0,0 +1,74 @@
+use crate::format::problem::*;
+use crate::format::solution::UnassignedJobReason;
+use crate::helpers::*;
+
+fn create_job_with_category(id: &str, location: (f64, f64), category: &str) -> Job {
+    Job { category: Some(category.to_string()), ..create_delivery_job(id, location) }
+}
+
+fn create_incompatibilities() -> Option<Vec<JobIncompatibility>> {
+    Some(vec![JobIncompatibility { categories: vec!["food".to_string(), "chemicals".to_string()] }])
+}
+
+#[test]
+fn can_separate_incompatible_jobs() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_job_with_category("food", (1., 0.), "food"),
+                create_job_with_category("pets", (2., 0.), "pets"),
+                create_job_with_category("chemicals", (3., 0.), "chemicals"),
+                create_delivery_job("job4", (4., 0.)),
+            ],
+            incompatibilities: create_incompatibilities(),
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 2);
+    assert!(solution.unassigned.is_none());
+    let food_tour = solution.tours.iter().find(|tour| get_ids_from_tour(tour).iter().flatten().any(|id| id == "food"));
+    assert!(get_ids_from_tour(food_tour.unwrap()).iter().flatten().all(|id| id != "chemicals"));
+}
+
+#[test]
+fn can_unassign_job_due_to_incompatibility() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_job_with_category("food", (1., 0.), "food"),
+                create_job_with_category("chemicals", (2., 0.), "chemicals"),
+            ],
+            incompatibilities: create_incompatibilities(),
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.unassigned.as_ref().map_or(0, |u| u.len()), 1);
+    let reasons = solution.unassigned.iter().flatten().flat_map(|u| u.reasons.iter().cloned()).collect::<Vec<_>>();
+    assert_eq!(
+        reasons,
+        vec![UnassignedJobReason {
+            code: "INCOMPATIBILITY_CONSTRAINT".to_string(),
+            description: "cannot be assigned due to incompatibility with jobs in the tour".to_string(),
+            details: Some(vec![create_unassigned_detail("my_vehicle_1", 0, (0., 3.))])
+        }]
+    );
+}
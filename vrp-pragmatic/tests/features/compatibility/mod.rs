@@ -1 +1,2 @@
 mod basic_compatibility;
+mod basic_incompatibility;
@@ -0,0 +1,98 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_crew_job(id: &str, location: (f64, f64), crew: usize) -> Job {
+    Job { crew: Some(crew), ..create_service_job(id, location) }
+}
+
+fn create_vehicle_at(id: &str, location: (f64, f64)) -> VehicleType {
+    VehicleType {
+        shifts: vec![create_default_vehicle_shift_with_locations(location, location)],
+        ..create_default_vehicle(id)
+    }
+}
+
+fn get_crew_stops<'a>(solution: &'a Solution, job_id: &str) -> Vec<(&'a String, &'a Stop)> {
+    solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter().map(move |stop| (&tour.vehicle_id, stop)))
+        .filter(|(_, stop)| stop.activities().iter().any(|activity| activity.job_id == job_id))
+        .collect()
+}
+
+#[test]
+fn can_serve_crew_job_by_multiple_vehicles_at_the_same_time() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_crew_job("job1", (3., 0.), 2), create_delivery_job("job2", (1., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_at("v1", (0., 0.)), create_vehicle_at("v2", (10., 0.))],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let stops = get_crew_stops(&solution, "job1");
+    assert_eq!(stops.len(), 2);
+    assert_ne!(stops[0].0, stops[1].0);
+    assert_eq!(stops[0].1.schedule().departure, stops[1].1.schedule().departure);
+}
+
+#[test]
+fn can_unassign_crew_job_when_not_enough_vehicles() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_crew_job("job1", (3., 0.), 3), create_delivery_job("job2", (1., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_at("v1", (0., 0.)), create_vehicle_at("v2", (10., 0.))],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(get_crew_stops(&solution, "job1").is_empty());
+    let unassigned = solution.unassigned.expect("unassigned jobs are expected");
+    assert_eq!(unassigned.len(), 1);
+    assert_eq!(unassigned[0].job_id, "job1");
+    assert_eq!(unassigned[0].reasons[0].code, "CREW_CONSTRAINT");
+}
+
+#[test]
+fn can_synchronize_crew_job_with_time_windows() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_crew_job("job1", (5., 0.), 2),
+                create_delivery_job_with_times("job2", (4., 0.), vec![(0, 10)], 1.),
+                create_delivery_job_with_times("job3", (6., 0.), vec![(0, 10)], 1.),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_at("v1", (0., 0.)), create_vehicle_at("v2", (10., 0.))],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let stops = get_crew_stops(&solution, "job1");
+    assert_eq!(stops.len(), 2);
+    assert_eq!(stops[0].1.schedule().departure, stops[1].1.schedule().departure);
+}
@@ -0,0 +1,44 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_use_vehicle_cost_tiers, (job_location, expected_vehicle, expected_cost), {
+    can_use_vehicle_cost_tiers_impl(job_location, expected_vehicle, expected_cost);
+}}
+
+can_use_vehicle_cost_tiers! {
+    case01_short_tour_rental: ((3., 0.), "rental_1", 27.),
+    case02_long_tour_own: ((20., 0.), "own_1", 91.),
+}
+
+fn can_use_vehicle_cost_tiers_impl(job_location: (f64, f64), expected_vehicle: &str, expected_cost: f64) {
+    let create_vehicle_type = |id: &str, costs: VehicleCosts| VehicleType {
+        shifts: vec![create_default_open_vehicle_shift()],
+        costs,
+        ..create_default_vehicle(id)
+    };
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", job_location)], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![
+                create_vehicle_type("own", VehicleCosts { fixed: Some(50.), ..create_default_vehicle_costs() }),
+                create_vehicle_type(
+                    "rental",
+                    VehicleCosts {
+                        fixed: Some(100.),
+                        tiers: Some(vec![VehicleCostTier { fixed: 20., max_duration: None, max_distance: Some(10.) }]),
+                        ..create_default_vehicle_costs()
+                    },
+                ),
+            ],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].vehicle_id, expected_vehicle);
+    assert_eq!(solution.statistic.cost, expected_cost);
+}
@@ -0,0 +1,79 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_depot(times: Vec<(f64, f64)>) -> Depot {
+    Depot {
+        location: (0., 0.).to_loc(),
+        times: times.into_iter().map(|(start, end)| vec![format_time(start), format_time(end)]).collect(),
+    }
+}
+
+parameterized_test! {can_restrict_departure_and_arrival_by_depot_hours, (depot_times, job_location, expected_departure, is_assigned), {
+    can_restrict_departure_and_arrival_by_depot_hours_impl(depot_times, job_location, expected_departure, is_assigned);
+}}
+
+can_restrict_departure_and_arrival_by_depot_hours! {
+    case01_late_opening: (vec![(10., 100.)], (5., 0.), Some(10.), true),
+    case02_early_closing: (vec![(0., 10.)], (20., 0.), None, false),
+    case03_return_in_second_window: (vec![(0., 10.), (50., 100.)], (20., 0.), Some(0.), true),
+}
+
+fn can_restrict_departure_and_arrival_by_depot_hours_impl(
+    depot_times: Vec<(f64, f64)>,
+    job_location: (f64, f64),
+    expected_departure: Option<f64>,
+    is_assigned: bool,
+) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", job_location)], ..create_empty_plan() },
+        fleet: Fleet { depots: Some(vec![create_depot(depot_times)]), ..create_default_fleet() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.unassigned.is_none(), is_assigned);
+    if let Some(expected_departure) = expected_departure {
+        assert_eq!(solution.tours.len(), 1);
+        assert_eq!(solution.tours[0].stops.first().unwrap().schedule().departure, format_time(expected_departure));
+    } else {
+        assert!(solution.tours.is_empty());
+    }
+}
+
+#[test]
+fn can_reload_only_when_depot_is_open() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (5., 0.)), create_delivery_job("job2", (6., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    reloads: Some(vec![create_default_reload()]),
+                    ..create_default_vehicle_shift()
+                }],
+                capacity: vec![1],
+                ..create_default_vehicle_type()
+            }],
+            depots: Some(vec![create_depot(vec![(0., 5.), (30., 100.)])]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let reload_stop = solution.tours[0]
+        .stops
+        .iter()
+        .find(|stop| stop.activities().iter().any(|activity| activity.activity_type == "reload"))
+        .expect("reload stop is expected");
+    assert!(reload_stop.schedule().departure.as_str() >= format_time(32.).as_str());
+}
@@ -1,5 +1,7 @@
 mod basic_multi_shift;
 mod basic_open_end;
+mod cost_tiers;
+mod depot_hours;
 mod multi_dimens;
 mod profile_variation;
 mod unreachable_jobs;
@@ -1,2 +1,3 @@
 mod location_custom;
 mod location_index;
+mod time_formats;
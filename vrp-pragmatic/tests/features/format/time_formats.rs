@@ -0,0 +1,67 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+type TimeData<'a> = (&'a str, &'a str, Option<(&'a str, &'a str)>);
+
+parameterized_test! {can_preserve_time_format_in_solution, (times, timezone, expected), {
+    can_preserve_time_format_in_solution_impl(times, timezone, expected);
+}}
+
+can_preserve_time_format_in_solution! {
+    case01_epoch_seconds: (("0", "1000", Some(("0", "100"))), None, ("0", "1")),
+    case02_mixed_offsets: (
+        ("1970-01-01T02:00:00+02:00", "1970-01-01T00:16:40Z", Some(("1970-01-01T05:00:00+05:00", "1969-12-31T23:01:40-01:00"))),
+        None,
+        ("1970-01-01T02:00:00+02:00", "1970-01-01T02:00:01+02:00"),
+    ),
+    case03_date_only: (
+        ("1970-01-01", "1970-01-02", None),
+        Some("-01:00"),
+        ("1970-01-01T00:00:00-01:00", "1970-01-01T00:00:01-01:00"),
+    ),
+}
+
+fn can_preserve_time_format_in_solution_impl(times: TimeData, timezone: Option<&str>, expected: (&str, &str)) {
+    let (start, end, job_times) = times;
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                deliveries: Some(vec![JobTask {
+                    places: vec![JobPlace {
+                        location: (1., 0.).to_loc(),
+                        duration: 1.,
+                        times: job_times.map(|(start, end)| vec![vec![start.to_string(), end.to_string()]]),
+                        tag: None,
+                        cost: None,
+                    }],
+                    demand: Some(vec![1]),
+                    order: None,
+                }]),
+                ..create_job("job1")
+            }],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: ShiftStart { earliest: start.to_string(), latest: None, location: (0., 0.).to_loc() },
+                    end: Some(ShiftEnd { earliest: None, latest: end.to_string(), location: (0., 0.).to_loc() }),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        timezone: timezone.map(|timezone| timezone.to_string()),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let stops = &solution.tours[0].stops;
+    assert_eq!(stops[0].schedule().departure, expected.0);
+    assert_eq!(stops[1].schedule().arrival, expected.1);
+}
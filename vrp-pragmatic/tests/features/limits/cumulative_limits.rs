@@ -0,0 +1,76 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_shift(start: f64, end: f64) -> VehicleShift {
+    VehicleShift {
+        start: ShiftStart { earliest: format_time(start), latest: None, location: (0., 0.).to_loc() },
+        end: Some(ShiftEnd { earliest: None, latest: format_time(end), location: (0., 0.).to_loc() }),
+        ..create_default_vehicle_shift()
+    }
+}
+
+fn create_test_problem(max_total_distance: Option<f64>, max_total_driving_time: Option<f64>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (10., 0.)), create_delivery_job("job2", (-10., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_shift(0., 100.), create_shift(200., 300.)],
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    max_duration: None,
+                    tour_size: None,
+                    max_stops: None,
+                    max_total_distance,
+                    max_total_driving_time,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_unassigned_reasons(solution: &Solution) -> Vec<String> {
+    solution.unassigned.iter().flatten().flat_map(|job| job.reasons.iter().map(|reason| reason.code.clone())).collect()
+}
+
+#[test]
+fn can_limit_total_distance_across_shifts() {
+    let problem = create_test_problem(Some(30.), None);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.statistic.distance, 20);
+    assert_eq!(get_unassigned_reasons(&solution), vec!["MAX_DISTANCE_CONSTRAINT".to_string()]);
+}
+
+#[test]
+fn can_limit_total_driving_time_across_shifts() {
+    let problem = create_test_problem(None, Some(30.));
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.statistic.times.driving, 20);
+    assert_eq!(get_unassigned_reasons(&solution), vec!["MAX_DURATION_CONSTRAINT".to_string()]);
+}
+
+#[test]
+fn can_serve_all_jobs_within_total_limits() {
+    let problem = create_test_problem(Some(40.), Some(40.));
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.statistic.distance, 40);
+}
@@ -5,7 +5,14 @@ use vrp_core::prelude::Float;
 
 fn create_vehicle_type_with_max_duration_limit(max_duration: Float) -> VehicleType {
     VehicleType {
-        limits: Some(VehicleLimits { max_distance: None, max_duration: Some(max_duration), tour_size: None }),
+        limits: Some(VehicleLimits {
+            max_distance: None,
+            max_duration: Some(max_duration),
+            tour_size: None,
+            max_stops: None,
+            max_total_distance: None,
+            max_total_driving_time: None,
+        }),
         ..create_default_vehicle_type()
     }
 }
@@ -95,10 +102,7 @@ fn can_skip_job_from_multiple_because_of_max_duration() {
                     reasons: vec![UnassignedJobReason {
                         code: "MAX_DURATION_CONSTRAINT".to_string(),
                         description: "cannot be assigned due to max duration constraint of vehicle".to_string(),
-                        details: Some(vec![UnassignedJobDetail {
-                            vehicle_id: "my_vehicle_1".to_string(),
-                            shift_index: 0
-                        }]),
+                        details: Some(vec![create_unassigned_detail("my_vehicle_1", 0, (0., 36.))]),
                     }]
                 },
                 UnassignedJob {
@@ -106,10 +110,7 @@ fn can_skip_job_from_multiple_because_of_max_duration() {
                     reasons: vec![UnassignedJobReason {
                         code: "MAX_DURATION_CONSTRAINT".to_string(),
                         description: "cannot be assigned due to max duration constraint of vehicle".to_string(),
-                        details: Some(vec![UnassignedJobDetail {
-                            vehicle_id: "my_vehicle_1".to_string(),
-                            shift_index: 0
-                        }]),
+                        details: Some(vec![create_unassigned_detail("my_vehicle_1", 0, (0., 36.))]),
                     }]
                 }
             ]))
@@ -0,0 +1,94 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+#[test]
+fn can_limit_stops_without_counting_breaks() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                create_delivery_job("job2", (2., 0.)),
+                create_delivery_job("job3", (3., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    breaks: Some(vec![VehicleBreak::Optional {
+                        time: VehicleOptionalBreakTime::TimeWindow(vec![format_time(0.), format_time(100.)]),
+                        places: vec![VehicleOptionalBreakPlace { duration: 2.0, location: None, tag: None }],
+                        policy: None,
+                    }]),
+                    ..create_default_open_vehicle_shift()
+                }],
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    max_duration: None,
+                    tour_size: None,
+                    max_stops: Some(2),
+                    max_total_distance: None,
+                    max_total_driving_time: None,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    let activity_types = get_ids_from_tour(&solution.tours[0]).into_iter().flatten().collect::<Vec<_>>();
+    assert_eq!(activity_types.iter().filter(|id| id.starts_with("job")).count(), 2);
+    assert!(activity_types.iter().any(|id| id == "break"));
+    let unassigned = solution.unassigned.expect("should have unassigned job");
+    assert_eq!(unassigned.len(), 1);
+    assert_eq!(unassigned[0].job_id, "job3");
+    assert_eq!(unassigned[0].reasons[0].code, "MAX_STOPS_CONSTRAINT");
+}
+
+#[test]
+fn can_exceed_stops_limit_when_it_is_objective() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                create_delivery_job("job2", (2., 0.)),
+                create_delivery_job("job3", (3., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    max_duration: None,
+                    tour_size: None,
+                    max_stops: Some(2),
+                    max_total_distance: None,
+                    max_total_driving_time: None,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        objectives: Some(vec![
+            Objective::MinimizeUnassigned { breaks: None },
+            Objective::MaxStops,
+            Objective::MinimizeCost,
+        ]),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert!(solution.unassigned.is_none());
+    let job_ids = get_ids_from_tour(&solution.tours[0]).into_iter().flatten().filter(|id| id.starts_with("job"));
+    assert_eq!(job_ids.count(), 3);
+}
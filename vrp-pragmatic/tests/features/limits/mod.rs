@@ -1,3 +1,5 @@
+mod cumulative_limits;
 mod max_distance;
 mod max_duration;
+mod max_stops;
 mod tour_size;
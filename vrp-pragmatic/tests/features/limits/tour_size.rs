@@ -16,7 +16,14 @@ fn can_skip_job_from_multiple_because_of_tour_size() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![create_default_open_vehicle_shift()],
-                limits: Some(VehicleLimits { max_distance: None, max_duration: None, tour_size: Some(2) }),
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    max_duration: None,
+                    tour_size: Some(2),
+                    max_stops: None,
+                    max_total_distance: None,
+                    max_total_driving_time: None,
+                }),
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()
@@ -59,7 +66,7 @@ fn can_skip_job_from_multiple_because_of_tour_size() {
                 reasons: vec![UnassignedJobReason {
                     code: "TOUR_SIZE_CONSTRAINT".to_string(),
                     description: "cannot be assigned due to tour size constraint of vehicle".to_string(),
-                    details: Some(vec![UnassignedJobDetail { vehicle_id: "my_vehicle_1".to_string(), shift_index: 0 }]),
+                    details: Some(vec![create_unassigned_detail("my_vehicle_1", 0, (0., 4.))]),
                 }]
             }]))
             .build()
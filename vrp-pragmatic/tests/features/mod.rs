@@ -5,12 +5,15 @@ mod breaks;
 mod capacity;
 mod clustering;
 mod compatibility;
+mod crew;
 mod fleet;
 mod format;
 mod group;
 mod limits;
 mod multjob;
+mod objectives;
 mod pickdev;
+mod places;
 mod priorities;
 mod recharge;
 mod relations;
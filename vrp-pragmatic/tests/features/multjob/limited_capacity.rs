@@ -34,6 +34,7 @@ fn can_handle_limited_capacity() {
             distance: 36,
             duration: 42,
             times: Timing { driving: 36, serving: 6, ..Timing::default() },
+            costs: CostBreakdown { fixed: 10., distance: 36., driving: 36., serving: 6., ..CostBreakdown::default() },
         }
     );
     assert!(solution.unassigned.is_none());
@@ -0,0 +1 @@
+mod weighted_tier;
@@ -0,0 +1,45 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+use vrp_core::prelude::Float;
+
+parameterized_test! {can_trade_cost_for_tours_with_weighted_tier, (tours_weight, expected_tours), {
+    can_trade_cost_for_tours_with_weighted_tier_impl(tours_weight, expected_tours);
+}}
+
+can_trade_cost_for_tours_with_weighted_tier! {
+    case01_cost_dominates: (0.001, 1),
+    case02_tours_dominate: (1000., 2),
+}
+
+fn can_trade_cost_for_tours_with_weighted_tier_impl(tours_weight: Float, expected_tours: usize) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.)), create_delivery_job("job2", (2., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        objectives: Some(vec![
+            MinimizeUnassigned { breaks: None },
+            WeightedTier {
+                objectives: vec![
+                    WeightedObjective { objective: MinimizeCost, weight: 1. },
+                    WeightedObjective { objective: MaximizeTours, weight: tours_weight },
+                ],
+            },
+        ]),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), expected_tours);
+}
@@ -0,0 +1,45 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_choose_cheapest_alternative_place, (home_cost, expected_tag, expected_cost), {
+    can_choose_cheapest_alternative_place_impl(home_cost, expected_tag, expected_cost);
+}}
+
+can_choose_cheapest_alternative_place! {
+    case01_prefer_locker: (20., "locker", 21.),
+    case02_prefer_home: (2., "home", 15.),
+}
+
+fn can_choose_cheapest_alternative_place_impl(home_cost: f64, expected_tag: &str, expected_cost: f64) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                deliveries: Some(vec![JobTask {
+                    places: vec![
+                        JobPlace { cost: Some(home_cost), ..create_job_place((1., 0.), Some("home".to_string())) },
+                        create_job_place((5., 0.), Some("locker".to_string())),
+                    ],
+                    demand: Some(vec![1]),
+                    order: None,
+                }]),
+                ..create_job("job1")
+            }],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_default_open_vehicle_shift()],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours[0].stops[1].activities()[0].job_tag.as_deref(), Some(expected_tag));
+    assert_eq!(solution.statistic.cost, expected_cost);
+}
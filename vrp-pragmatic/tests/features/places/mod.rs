@@ -0,0 +1 @@
+mod alternative_place_cost;
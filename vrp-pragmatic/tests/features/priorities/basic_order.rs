@@ -15,7 +15,14 @@ fn create_test_plan_with_three_jobs() -> Plan {
 }
 
 fn create_test_limit() -> Option<VehicleLimits> {
-    Some(VehicleLimits { max_distance: Some(15.), max_duration: None, tour_size: None })
+    Some(VehicleLimits {
+        max_distance: Some(15.),
+        max_duration: None,
+        tour_size: None,
+        max_stops: None,
+        max_total_distance: None,
+        max_total_driving_time: None,
+    })
 }
 
 fn create_order_objective(is_constrained: bool) -> Vec<Objective> {
@@ -133,7 +140,7 @@ fn can_follow_order_when_prioritized_property_set() {
             reasons: vec![UnassignedJobReason {
                 code: "TOUR_ORDER_CONSTRAINT".to_string(),
                 description: "cannot be assigned due to tour order constraint".to_string(),
-                details: Some(vec![UnassignedJobDetail { vehicle_id: "my_vehicle_1".to_string(), shift_index: 0 }]),
+                details: Some(vec![create_unassigned_detail("my_vehicle_1", 0, (0., 9.))]),
             }]
         }])
     );
@@ -143,7 +150,7 @@ fn can_follow_order_when_prioritized_property_set() {
 fn can_handle_order_between_special_activities() {
     let create_test_job = |id: &str, location: (f64, f64), order: i32| Job {
         deliveries: Some(vec![JobTask {
-            places: vec![JobPlace { times: None, location: location.to_loc(), duration: 100., tag: None }],
+            places: vec![JobPlace { times: None, location: location.to_loc(), duration: 100., tag: None, cost: None }],
             demand: Some(vec![1]),
             order: Some(order),
         }]),
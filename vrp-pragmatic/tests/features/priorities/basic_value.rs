@@ -1,6 +1,6 @@
 use crate::format::problem::Objective::*;
 use crate::format::problem::*;
-use crate::format::solution::{UnassignedJob, UnassignedJobDetail, UnassignedJobReason};
+use crate::format::solution::{UnassignedJob, UnassignedJobReason};
 use crate::helpers::*;
 
 parameterized_test! {can_prefer_jobs_with_more_value, objectives, {
@@ -44,7 +44,7 @@ fn can_prefer_jobs_with_more_value_impl(objectives: Option<Vec<Objective>>) {
             reasons: vec![UnassignedJobReason {
                 code: "CAPACITY_CONSTRAINT".to_string(),
                 description: "does not fit into any vehicle due to capacity".to_string(),
-                details: Some(vec![UnassignedJobDetail { vehicle_id: "my_vehicle_1".to_string(), shift_index: 0 }])
+                details: Some(vec![create_unassigned_detail("my_vehicle_1", 0, (0., 5.))])
             }]
         }
     );
@@ -1,2 +1,3 @@
 mod basic_order;
 mod basic_value;
+mod strict_priority;
@@ -0,0 +1,44 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_assign_jobs_by_strict_priority, objectives, {
+    can_assign_jobs_by_strict_priority_impl(objectives);
+}}
+
+can_assign_jobs_by_strict_priority! {
+    case01: Some(vec![
+        StrictPriority,
+        MinimizeUnassigned { breaks: None },
+        MinimizeCost,
+    ]),
+    case02: None,
+}
+
+fn can_assign_jobs_by_strict_priority_impl(objectives: Option<Vec<Objective>>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                create_delivery_job("job2", (2., 0.)),
+                Job { priority: Some(1), ..create_delivery_job("job3", (10., 0.)) },
+                Job { priority: Some(2), ..create_delivery_job("job4", (11., 0.)) },
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { capacity: vec![2], ..create_default_vehicle_type() }],
+            ..create_default_fleet()
+        },
+        objectives,
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    let mut unassigned = solution.unassigned.unwrap().into_iter().map(|job| job.job_id).collect::<Vec<_>>();
+    unassigned.sort();
+    assert_eq!(unassigned, vec!["job1".to_string(), "job2".to_string()]);
+}
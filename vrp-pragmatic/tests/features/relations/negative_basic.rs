@@ -0,0 +1,98 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_negative_relation(relation_type: RelationType, jobs: Vec<&str>) -> Relation {
+    Relation { type_field: relation_type, jobs: to_strings(jobs), vehicle_id: String::default(), shift_index: None }
+}
+
+fn create_vehicle_with_two_shifts() -> VehicleType {
+    let create_shift = |start: f64, end: f64| VehicleShift {
+        start: ShiftStart { earliest: format_time(start), latest: None, location: (0., 0.).to_loc() },
+        end: Some(ShiftEnd { earliest: None, latest: format_time(end), location: (0., 0.).to_loc() }),
+        ..create_default_vehicle_shift()
+    };
+
+    VehicleType { shifts: vec![create_shift(0., 100.), create_shift(200., 300.)], ..create_default_vehicle_type() }
+}
+
+fn get_tour_keys(solution: &Solution, job_id: &str) -> Vec<(String, usize)> {
+    solution
+        .tours
+        .iter()
+        .filter(|tour| tour.stops.iter().any(|stop| stop.activities().iter().any(|a| a.job_id == job_id)))
+        .map(|tour| (tour.vehicle_id.clone(), tour.shift_index))
+        .collect()
+}
+
+#[test]
+fn can_assign_not_same_tour_jobs_to_different_tours() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.)), create_delivery_job("job2", (2., 0.))],
+            relations: Some(vec![create_negative_relation(RelationType::NotSameTour, vec!["job1", "job2"])]),
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![create_vehicle_with_two_shifts()], ..create_default_fleet() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 2);
+    assert_ne!(get_tour_keys(&solution, "job1"), get_tour_keys(&solution, "job2"));
+}
+
+#[test]
+fn can_unassign_different_vehicle_job_when_only_one_vehicle() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.)), create_delivery_job("job2", (2., 0.))],
+            relations: Some(vec![create_negative_relation(RelationType::DifferentVehicle, vec!["job1", "job2"])]),
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![create_vehicle_with_two_shifts()], ..create_default_fleet() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let unassigned = solution.unassigned.expect("one job should be unassigned");
+    assert_eq!(unassigned.len(), 1);
+    assert_eq!(unassigned[0].reasons[0].code, "SEPARATION_CONSTRAINT");
+}
+
+#[test]
+fn can_assign_different_vehicle_jobs_to_different_vehicles() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                create_delivery_job("job2", (2., 0.)),
+                create_delivery_job("job3", (3., 0.)),
+            ],
+            relations: Some(vec![create_negative_relation(RelationType::DifferentVehicle, vec!["job1", "job3"])]),
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                ..create_vehicle_with_two_shifts()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let job1_vehicle = get_tour_keys(&solution, "job1")[0].0.clone();
+    let job3_vehicle = get_tour_keys(&solution, "job3")[0].0.clone();
+    assert_ne!(job1_vehicle, job3_vehicle);
+}
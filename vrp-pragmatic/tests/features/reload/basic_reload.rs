@@ -23,7 +23,7 @@ can_use_vehicle_with_two_tours_and_two_jobs! {
                     reasons: vec![UnassignedJobReason {
                         code: "CAPACITY_CONSTRAINT".to_string(),
                         description: "does not fit into any vehicle due to capacity".to_string(),
-                        details: Some(vec![UnassignedJobDetail { vehicle_id: "my_vehicle_1".to_string(), shift_index: 0 }]),
+                        details: Some(vec![create_unassigned_detail("my_vehicle_1", 0, (0., 10.))]),
                     }]
                 }
              ])),
@@ -44,6 +44,7 @@ fn can_use_vehicle_with_two_tours_and_two_jobs_impl(jobs: Vec<Job>, unassigned:
                         ..create_default_reload()
                     }]),
                     recharges: None,
+                    capacity_change: None,
                 }],
                 capacity: vec![1],
                 ..create_default_vehicle_type()
@@ -19,7 +19,7 @@ fn can_wait_for_job_start() {
                     type_id: "vehicle_with_skill".to_string(),
                     vehicle_ids: vec!["vehicle_with_skill_1".to_string()],
                     shifts: vec![create_default_vehicle_shift_with_locations((10., 0.), (10., 0.))],
-                    skills: Some(vec!["unique_skill".to_string()]),
+                    skills: Some(vec!["unique_skill".into()]),
                     ..create_default_vehicle_type()
                 },
             ],
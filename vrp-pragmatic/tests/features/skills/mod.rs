@@ -1,2 +1,3 @@
 mod basic_skill;
+mod skill_levels;
 mod unassigned_due_to_skills;
@@ -0,0 +1,87 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_job_with_level(id: &str, min_level: Option<usize>, preferred_level: Option<usize>) -> Job {
+    create_delivery_job_with_skills(
+        id,
+        (1., 0.),
+        JobSkills {
+            all_of: None,
+            one_of: None,
+            none_of: None,
+            levels: Some(vec![JobSkillLevel { name: "welding".to_string(), min_level, preferred_level }]),
+        },
+    )
+}
+
+fn create_vehicle_with_level(id: &str, location: (f64, f64), level: usize) -> VehicleType {
+    VehicleType {
+        shifts: vec![create_default_vehicle_shift_with_locations(location, location)],
+        skills: Some(vec![VehicleSkill::Level { name: "welding".to_string(), level }]),
+        ..create_default_vehicle(id)
+    }
+}
+
+fn create_test_problem(job: Job) -> Problem {
+    Problem {
+        plan: Plan { jobs: vec![job], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![
+                create_vehicle_with_level("junior", (0., 0.), 1),
+                create_vehicle_with_level("senior", (10., 0.), 3),
+            ],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_type_ids(solution: &Solution) -> Vec<String> {
+    solution.tours.iter().map(|tour| tour.type_id.clone()).collect()
+}
+
+#[test]
+fn can_assign_job_to_vehicle_with_required_skill_level() {
+    let problem = create_test_problem(create_job_with_level("job1", Some(2), None));
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_type_ids(&solution), vec!["senior".to_string()]);
+}
+
+#[test]
+fn can_unassign_job_when_no_vehicle_has_required_skill_level() {
+    let problem = create_test_problem(create_job_with_level("job1", Some(4), None));
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.tours.is_empty());
+    let unassigned = solution.unassigned.expect("unassigned jobs are expected");
+    assert_eq!(unassigned[0].reasons[0].code, "SKILL_CONSTRAINT");
+}
+
+#[test]
+fn can_prefer_vehicle_with_higher_skill_level() {
+    let problem = create_test_problem(create_job_with_level("job1", Some(1), Some(3)));
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_type_ids(&solution), vec!["senior".to_string()]);
+}
+
+#[test]
+fn can_use_cheaper_vehicle_without_skill_preference() {
+    let problem = create_test_problem(create_job_with_level("job1", Some(1), None));
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_type_ids(&solution), vec!["junior".to_string()]);
+}
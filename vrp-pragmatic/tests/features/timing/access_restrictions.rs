@@ -0,0 +1,77 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_restricted_profile(name: &str, location: (f64, f64), time: (f64, f64)) -> MatrixProfile {
+    MatrixProfile {
+        name: name.to_string(),
+        speed: None,
+        restrictions: Some(vec![AccessRestriction {
+            locations: vec![location.to_loc()],
+            time: vec![format_time(time.0), format_time(time.1)],
+        }]),
+    }
+}
+
+#[test]
+fn can_serve_restricted_location_by_vehicle_with_other_profile() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.)), create_delivery_job("job2", (2., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                VehicleType { profile: create_vehicle_profile_with_name("truck"), ..create_default_vehicle("truck") },
+                VehicleType { profile: create_vehicle_profile_with_name("car"), ..create_default_vehicle("car") },
+            ],
+            profiles: vec![
+                create_restricted_profile("truck", (2., 0.), (0., 1000.)),
+                MatrixProfile { name: "car".to_string(), speed: None, restrictions: None },
+            ],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let matrices = vec![
+        Matrix { profile: Some("truck".to_string()), ..matrix.clone() },
+        Matrix { profile: Some("car".to_string()), ..matrix },
+    ];
+
+    let solution = solve_with_metaheuristic(problem, Some(matrices));
+
+    assert!(solution.unassigned.is_none());
+    let tour = solution
+        .tours
+        .iter()
+        .find(|tour| get_ids_from_tour(tour).into_iter().flatten().any(|id| id == "job2"))
+        .expect("cannot find tour with job2");
+    assert_eq!(tour.vehicle_id, "car_1");
+}
+
+#[test]
+fn can_skip_job_with_arrival_inside_restricted_time() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.)), create_delivery_job("job2", (2., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle_type()],
+            profiles: vec![create_restricted_profile("car", (2., 0.), (1., 100.))],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(get_ids_from_tour(&solution.tours[0]).into_iter().flatten().filter(|id| id == "job1").count(), 1);
+    let unassigned = solution.unassigned.expect("should have unassigned job");
+    assert_eq!(unassigned.len(), 1);
+    assert_eq!(unassigned[0].job_id, "job2");
+    assert_eq!(unassigned[0].reasons[0].code, "ACCESS_RESTRICTION_CONSTRAINT");
+}
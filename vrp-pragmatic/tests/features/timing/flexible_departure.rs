@@ -0,0 +1,45 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_test_problem(objectives: Option<Vec<Objective>>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", (1., 0.), vec![(0, 5)], 0.),
+                create_delivery_job_with_times("job2", (2., 0.), vec![(50, 60)], 0.),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        objectives,
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_avoid_waiting_with_flexible_departure() {
+    let problem = create_test_problem(Some(vec![
+        Objective::MinimizeUnassigned { breaks: None },
+        Objective::MinimizeWaitingTimeWithFlexibleDeparture,
+        Objective::MinimizeCost,
+    ]));
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 2);
+    assert_eq!(solution.statistic.times.waiting, 0);
+    let job2_tour = solution
+        .tours
+        .iter()
+        .find(|tour| tour.stops.iter().any(|stop| stop.activities().iter().any(|activity| activity.job_id == "job2")));
+    assert_eq!(job2_tour.unwrap().stops[0].schedule().departure, format_time(48.));
+}
@@ -1,4 +1,7 @@
+mod access_restrictions;
 mod basic_multiple_times;
 mod basic_waiting_time;
+mod flexible_departure;
 mod strict_leads_to_unassigned;
 mod strict_split_into_two_tours;
+mod time_dependent_routing;
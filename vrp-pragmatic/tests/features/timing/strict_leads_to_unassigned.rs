@@ -72,7 +72,7 @@ fn can_have_unassigned_jobs_because_of_strict_times() {
                 reasons: vec![UnassignedJobReason {
                     code: "TIME_WINDOW_CONSTRAINT".to_string(),
                     description: "cannot be visited within time window".to_string(),
-                    details: Some(vec![UnassignedJobDetail { vehicle_id: "my_vehicle_1".to_string(), shift_index: 0 }]),
+                    details: Some(vec![create_unassigned_detail("my_vehicle_1", 0, (0., 80.))]),
                 }]
             }]))
             .build()
@@ -0,0 +1,54 @@
+use crate::format::Location;
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+use vrp_core::prelude::Float;
+
+fn create_time_dependent_matrix(timestamp: Float, duration: i64) -> Matrix {
+    Matrix {
+        profile: Some("car".to_string()),
+        timestamp: Some(format_time(timestamp)),
+        travel_times: vec![0, duration, duration, 0],
+        distances: vec![0, 10, 10, 0],
+        error_codes: None,
+    }
+}
+
+parameterized_test! {can_interpolate_travel_time_by_departure_time, (departure, expected_arrival), {
+    can_interpolate_travel_time_by_departure_time_impl(departure, expected_arrival);
+}}
+
+can_interpolate_travel_time_by_departure_time! {
+    case01_first_matrix: (0., 10.),
+    case02_between_matrices: (50., 70.),
+    case03_last_matrix: (200., 230.),
+}
+
+fn can_interpolate_travel_time_by_departure_time_impl(departure: Float, expected_arrival: Float) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job_with_index("job1", 1)], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: ShiftStart {
+                        earliest: format_time(departure),
+                        latest: None,
+                        location: Location::Reference { index: 0 },
+                    },
+                    ..create_default_open_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrices = vec![create_time_dependent_matrix(100., 30), create_time_dependent_matrix(0., 10)];
+
+    let solution = solve_with_metaheuristic_and_iterations_without_check(problem, Some(matrices), 10);
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let job_stop = solution.tours[0].stops.get(1).expect("no job stop");
+    assert_eq!(job_stop.schedule().arrival, format_time(expected_arrival));
+}
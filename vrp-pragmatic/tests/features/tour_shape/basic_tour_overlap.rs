@@ -0,0 +1,53 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+
+#[test]
+fn can_split_territories_with_tour_overlap_objective() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (-3., 1.)),
+                create_delivery_job("job2", (-1., 1.)),
+                create_delivery_job("job3", (-1., 3.)),
+                create_delivery_job("job4", (-3., 3.)),
+                create_delivery_job("job5", (1., 1.)),
+                create_delivery_job("job6", (3., 1.)),
+                create_delivery_job("job7", (3., 3.)),
+                create_delivery_job("job8", (1., 3.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                ..create_vehicle_with_capacity("my_vehicle", vec![4])
+            }],
+            ..create_default_fleet()
+        },
+        objectives: Some(vec![
+            MinimizeUnassigned { breaks: None },
+            MinimizeTourOverlap { threshold: None },
+            MinimizeCost,
+        ]),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let mut job_ids = solution
+        .tours
+        .iter()
+        .map(|tour| {
+            get_ids_from_tour_sorted(tour)
+                .into_iter()
+                .flatten()
+                .filter(|id| id != "arrival" && id != "departure")
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    job_ids.sort();
+    assert_eq!(job_ids, vec![vec!["job1", "job2", "job3", "job4"], vec!["job5", "job6", "job7", "job8"]]);
+}
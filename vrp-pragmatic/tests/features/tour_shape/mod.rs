@@ -1 +1,2 @@
 mod basic_tour_compactness;
+mod basic_tour_overlap;
@@ -33,8 +33,8 @@ fn can_have_multiple_unassigned_reasons() {
                 code: "SKILL_CONSTRAINT".to_string(),
                 description: "cannot serve required skill".to_string(),
                 details: Some(vec![
-                    UnassignedJobDetail { vehicle_id: "vehicle1_1".to_string(), shift_index: 0 },
-                    UnassignedJobDetail { vehicle_id: "vehicle2_1".to_string(), shift_index: 0 }
+                    create_unassigned_detail("vehicle1_1", 0, (0., 3.)),
+                    create_unassigned_detail("vehicle2_1", 0, (0., 3.))
                 ])
             }]
         }])
@@ -91,7 +91,10 @@ prop_compose! {
             skills,
             value,
             group,
-            compatibility
+            compatibility,
+            priority: None,
+            category: None,
+            crew: None,
         }
     }
 }
@@ -138,6 +141,9 @@ prop_compose! {
             value,
             group,
             compatibility,
+            priority: None,
+            category: None,
+            crew: None,
         }
     }
 }
@@ -170,7 +176,7 @@ prop_compose! {
      times in time_windows,
      tag in tags
     ) -> JobPlace {
-      JobPlace { times, location, duration, tag }
+      JobPlace { times, location, duration, tag, cost: None }
     }
 }
 
@@ -11,7 +11,7 @@ prop_compose! {
         profile_proto: impl Strategy<Value = VehicleProfile>,
         capacity_proto: impl Strategy<Value = Vec<i32>>,
         costs_proto: impl Strategy<Value = VehicleCosts>,
-        skills_proto: impl Strategy<Value = Option<Vec<String>>>,
+        skills_proto: impl Strategy<Value = Option<Vec<VehicleSkill>>>,
         limits_proto: impl Strategy<Value = Option<VehicleLimits>>,
         shifts_proto: impl Strategy<Value = Vec<VehicleShift>>,
     )
@@ -79,6 +79,7 @@ prop_compose! {
           location,
           duration,
           tag,
+          cost: None,
         }
     }
 }
@@ -110,6 +111,7 @@ prop_compose! {
           breaks,
           reloads,
           recharges,
+          capacity_change: None,
         }
     }
 }
@@ -130,7 +132,7 @@ prop_compose! {
      vehicles in vehicles_proto,
      profiles in profiles_proto
     ) -> Fleet {
-        Fleet { vehicles, profiles, resources: None }
+        Fleet { vehicles, profiles, resources: None, depots: None }
     }
 }
 
@@ -164,7 +166,7 @@ prop_compose! {
 
 prop_compose! {
     /// Generates no vehicle skills.
-    pub fn generate_no_vehicle_skills()(_ in ".*") -> Option<Vec<String>> {
+    pub fn generate_no_vehicle_skills()(_ in ".*") -> Option<Vec<VehicleSkill>> {
         None
     }
 }
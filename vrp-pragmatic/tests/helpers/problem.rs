@@ -7,7 +7,7 @@ use vrp_core::models::common::Duration;
 use vrp_core::prelude::Float;
 
 pub fn create_job_place(location: (f64, f64), tag: Option<String>) -> JobPlace {
-    JobPlace { times: None, location: location.to_loc(), duration: 1., tag }
+    JobPlace { times: None, location: location.to_loc(), duration: 1., tag, cost: None }
 }
 
 pub fn create_task(location: (f64, f64), tag: Option<String>) -> JobTask {
@@ -25,6 +25,9 @@ pub fn create_job(id: &str) -> Job {
         value: None,
         group: None,
         compatibility: None,
+        priority: None,
+        category: None,
+        crew: None,
     }
 }
 
@@ -163,7 +166,13 @@ pub fn create_pickup_delivery_job_with_params(
 pub fn create_delivery_job_with_index(id: &str, index: usize) -> Job {
     Job {
         deliveries: Some(vec![JobTask {
-            places: vec![JobPlace { times: None, location: Location::Reference { index }, duration: 1., tag: None }],
+            places: vec![JobPlace {
+                times: None,
+                location: Location::Reference { index },
+                duration: 1.,
+                tag: None,
+                cost: None,
+            }],
             demand: Some(vec![1]),
             order: None,
         }]),
@@ -211,6 +220,7 @@ pub fn create_default_open_vehicle_shift() -> VehicleShift {
         breaks: None,
         reloads: None,
         recharges: None,
+        capacity_change: None,
     }
 }
 
@@ -221,11 +231,12 @@ pub fn create_default_vehicle_shift_with_locations(start: (f64, f64), end: (f64,
         breaks: None,
         reloads: None,
         recharges: None,
+        capacity_change: None,
     }
 }
 
 pub fn create_default_vehicle_costs() -> VehicleCosts {
-    VehicleCosts { fixed: Some(10.), distance: 1., time: 1. }
+    VehicleCosts { fixed: Some(10.), distance: 1., time: 1., tiers: None }
 }
 
 pub fn create_default_vehicle_profile() -> VehicleProfile {
@@ -258,11 +269,16 @@ pub fn create_vehicle_with_capacity(id: &str, capacity: Vec<i32>) -> VehicleType
 }
 
 pub fn create_default_fleet() -> Fleet {
-    Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_matrix_profiles(), resources: None }
+    Fleet {
+        vehicles: vec![create_default_vehicle_type()],
+        profiles: create_default_matrix_profiles(),
+        resources: None,
+        depots: None,
+    }
 }
 
 pub fn create_default_matrix_profiles() -> Vec<MatrixProfile> {
-    vec![MatrixProfile { name: "car".to_string(), speed: None }]
+    vec![MatrixProfile { name: "car".to_string(), speed: None, restrictions: None }]
 }
 
 pub fn create_min_jobs_cost_objective() -> Option<Vec<Objective>> {
@@ -270,14 +286,15 @@ pub fn create_min_jobs_cost_objective() -> Option<Vec<Objective>> {
 }
 
 pub fn create_empty_plan() -> Plan {
-    Plan { jobs: vec![], relations: None, clustering: None }
+    Plan { jobs: vec![], relations: None, clustering: None, incompatibilities: None }
 }
 
 pub fn create_empty_problem() -> Problem {
     Problem {
         plan: create_empty_plan(),
-        fleet: Fleet { vehicles: vec![], profiles: vec![], resources: None },
+        fleet: Fleet { vehicles: vec![], profiles: vec![], resources: None, depots: None },
         objectives: None,
+        timezone: None,
     }
 }
 
@@ -318,7 +335,7 @@ pub fn to_strings(data: Vec<&str>) -> Vec<String> {
 }
 
 pub fn all_of_skills(skills: Vec<String>) -> JobSkills {
-    JobSkills { all_of: Some(skills), one_of: None, none_of: None }
+    JobSkills { all_of: Some(skills), one_of: None, none_of: None, levels: None }
 }
 
 fn convert_times(times: &[(i32, i32)]) -> Option<Vec<Vec<String>>> {
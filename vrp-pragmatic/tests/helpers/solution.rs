@@ -171,6 +171,7 @@ impl Default for StopBuilder {
                 load: vec![],
                 parking: None,
                 activities: vec![],
+                geometry: None,
             }),
         }
     }
@@ -267,6 +268,14 @@ impl Default for ActivityBuilder {
     }
 }
 
+pub fn create_unassigned_detail(vehicle_id: &str, shift_index: usize, time: (Float, Float)) -> UnassignedJobDetail {
+    UnassignedJobDetail {
+        vehicle_id: vehicle_id.to_string(),
+        shift_index,
+        time: Some(Interval { start: format_time(time.0), end: format_time(time.1) }),
+    }
+}
+
 pub struct StatisticBuilder {
     fixed: Cost,
     costs: (Cost, Cost),
@@ -308,6 +317,14 @@ impl StatisticBuilder {
         statistic.distance = statistic.times.driving;
         statistic.cost =
             self.fixed + statistic.distance as Float * per_distance + statistic.duration as Float * per_time;
+        statistic.costs = CostBreakdown {
+            fixed: self.fixed,
+            distance: statistic.distance as Float * per_distance,
+            driving: (times.driving + times.commuting) as Float * per_time,
+            serving: (times.serving + times.parking) as Float * per_time,
+            waiting: times.waiting as Float * per_time,
+            break_cost: times.break_time as Float * per_time,
+        };
 
         statistic
     }
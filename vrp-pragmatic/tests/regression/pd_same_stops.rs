@@ -13,6 +13,7 @@ fn can_handle_two_pd_jobs_with_same_locations_and_unusual_routing() {
             duration: 0.,
             times: None,
             tag: Some(tag.to_string()),
+            cost: None,
         }],
         demand: Some(vec![1]),
         order: None,
@@ -36,7 +37,7 @@ fn can_handle_two_pd_jobs_with_same_locations_and_unusual_routing() {
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
-                costs: VehicleCosts { fixed: None, distance: 0.0, time: 1.0 },
+                costs: VehicleCosts { fixed: None, distance: 0.0, time: 1.0, tiers: None },
                 shifts: vec![VehicleShift {
                     start: ShiftStart {
                         earliest: format_time(0.),
@@ -131,6 +131,7 @@ fn check_jobs_impl(
                     duration: 0.0,
                     times: None,
                     tag: Some(format!("{tgt}{idx}")),
+                    cost: None,
                 }],
                 demand: if tgt != "service" { Some(vec![1]) } else { None },
                 order: None,
@@ -309,3 +310,120 @@ fn can_detect_group_violations() {
 
     assert_eq!(result, Err("job groups are not respected: 'group1'".into()));
 }
+
+parameterized_test! {can_detect_incompatibility_violations, (categories, expected), {
+    can_detect_incompatibility_violations_impl(categories, expected);
+}}
+
+can_detect_incompatibility_violations! {
+    case01: (("food", "chemicals"), Err("incompatible job categories 'food,chemicals' are served by the same tour: vehicle id 'my_vehicle_1', shift index: 0".into())),
+    case02: (("food", "food"), Ok(())),
+    case03: (("food", "pets"), Ok(())),
+}
+
+fn can_detect_incompatibility_violations_impl(categories: (&str, &str), expected: GenericResult<()>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                Job { category: Some(categories.0.to_string()), ..create_delivery_job("job1", (1., 0.)) },
+                Job { category: Some(categories.1.to_string()), ..create_delivery_job("job2", (2., 0.)) },
+            ],
+            incompatibilities: Some(vec![JobIncompatibility {
+                categories: vec!["food".to_string(), "chemicals".to_string()],
+            }]),
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let solution = SolutionBuilder::default()
+        .tour(
+            TourBuilder::default()
+                .stops(vec![
+                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![2]).build_departure(),
+                    StopBuilder::default()
+                        .coordinate((1., 0.))
+                        .schedule_stamp(1., 2.)
+                        .load(vec![1])
+                        .distance(1)
+                        .build_single("job1", "delivery"),
+                    StopBuilder::default()
+                        .coordinate((2., 0.))
+                        .schedule_stamp(3., 4.)
+                        .load(vec![0])
+                        .distance(2)
+                        .build_single("job2", "delivery"),
+                    StopBuilder::default()
+                        .coordinate((0., 0.))
+                        .schedule_stamp(6., 6.)
+                        .load(vec![0])
+                        .distance(4)
+                        .build_arrival(),
+                ])
+                .statistic(StatisticBuilder::default().driving(4).serving(2).build())
+                .build(),
+        )
+        .build();
+    let core_problem = Arc::new(problem.clone().read_pragmatic().unwrap());
+    let ctx = CheckerContext::new(core_problem, problem, None, solution).unwrap();
+
+    let result = check_incompatibilities(&ctx);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_detect_crew_violations, (tours, expected), {
+    can_detect_crew_violations_impl(tours, expected);
+}}
+
+can_detect_crew_violations! {
+    case01_synchronized: (vec![("v1", 2.), ("v2", 2.)], Ok(())),
+    case02_not_synchronized: (vec![("v1", 2.), ("v2", 3.)], Err("crew job 'job1' is not served at the same time".into())),
+    case03_not_enough_vehicles: (vec![("v1", 2.)], Err("crew job 'job1' is served by 1 vehicles, expected: 2".into())),
+}
+
+fn can_detect_crew_violations_impl(tours: Vec<(&str, f64)>, expected: GenericResult<()>) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![Job { crew: Some(2), ..create_service_job("job1", (1., 0.)) }], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["v1".to_string(), "v2".to_string()],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let create_tour = |vehicle_id: &str, departure: f64| {
+        TourBuilder::default()
+            .vehicle_id(vehicle_id)
+            .stops(vec![
+                StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![0]).build_departure(),
+                StopBuilder::default()
+                    .coordinate((1., 0.))
+                    .schedule_stamp(1., departure)
+                    .load(vec![0])
+                    .distance(1)
+                    .build_single("job1", "service"),
+                StopBuilder::default()
+                    .coordinate((0., 0.))
+                    .schedule_stamp(departure + 1., departure + 1.)
+                    .load(vec![0])
+                    .distance(2)
+                    .build_arrival(),
+            ])
+            .build()
+    };
+    let solution = tours
+        .into_iter()
+        .fold(SolutionBuilder::default(), |builder, (vehicle_id, departure)| {
+            builder.tour(create_tour(vehicle_id, departure))
+        })
+        .build();
+    let core_problem = Arc::new(problem.clone().read_pragmatic().unwrap());
+    let ctx = CheckerContext::new(core_problem, problem, None, solution).unwrap();
+
+    let result = check_crew_jobs(&ctx);
+
+    assert_eq!(result, expected);
+}
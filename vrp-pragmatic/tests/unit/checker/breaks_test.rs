@@ -86,6 +86,7 @@ fn can_check_breaks_impl(
                     }]),
                     reloads: None,
                     recharges: None,
+                    capacity_change: None,
                 }],
                 capacity: vec![5],
                 ..create_default_vehicle_type()
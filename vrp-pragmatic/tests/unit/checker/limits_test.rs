@@ -59,7 +59,14 @@ pub fn can_check_shift_and_distance_limit_impl(
     actual: i64,
     expected: Result<(), GenericError>,
 ) {
-    let problem = create_test_problem(Some(VehicleLimits { max_distance, max_duration, tour_size: None }));
+    let problem = create_test_problem(Some(VehicleLimits {
+        max_distance,
+        max_duration,
+        tour_size: None,
+        max_stops: None,
+        max_total_distance: None,
+        max_total_driving_time: None,
+    }));
     let solution =
         create_test_solution(Statistic { distance: actual, duration: actual, ..Statistic::default() }, vec![]);
     let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
@@ -71,8 +78,14 @@ pub fn can_check_shift_and_distance_limit_impl(
 
 #[test]
 pub fn can_check_tour_size_limit() {
-    let problem =
-        create_test_problem(Some(VehicleLimits { max_distance: None, max_duration: None, tour_size: Some(2) }));
+    let problem = create_test_problem(Some(VehicleLimits {
+        max_distance: None,
+        max_duration: None,
+        tour_size: Some(2),
+        max_stops: None,
+        max_total_distance: None,
+        max_total_driving_time: None,
+    }));
     let solution = create_test_solution(
         Statistic::default(),
         vec![
@@ -114,6 +127,111 @@ pub fn can_check_tour_size_limit() {
     );
 }
 
+parameterized_test! {can_check_max_stops_limit, (max_stops, expected), {
+    can_check_max_stops_limit_impl(max_stops, expected);
+}}
+
+can_check_max_stops_limit! {
+    case01: (2, Err("max stops limit violation, expected: not more than 2, got: 3, vehicle id 'some_real_vehicle', shift index: 0".into())),
+    case02: (3, Ok(())),
+}
+
+fn can_check_max_stops_limit_impl(max_stops: usize, expected: GenericResult<()>) {
+    let problem = create_test_problem(Some(VehicleLimits {
+        max_distance: None,
+        max_duration: None,
+        tour_size: None,
+        max_stops: Some(max_stops),
+        max_total_distance: None,
+        max_total_driving_time: None,
+    }));
+    let solution = create_test_solution(
+        Statistic::default(),
+        vec![
+            StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![3]).build_departure(),
+            StopBuilder::default()
+                .coordinate((1., 0.))
+                .schedule_stamp(1., 1.)
+                .load(vec![2])
+                .distance(1)
+                .build_single("job1", "delivery"),
+            StopBuilder::default()
+                .coordinate((2., 0.))
+                .schedule_stamp(2., 2.)
+                .load(vec![2])
+                .distance(2)
+                .build_single("break", "break"),
+            StopBuilder::default()
+                .coordinate((3., 0.))
+                .schedule_stamp(3., 3.)
+                .load(vec![1])
+                .distance(3)
+                .build_single("job2", "delivery"),
+            StopBuilder::default()
+                .coordinate((4., 0.))
+                .schedule_stamp(4., 4.)
+                .load(vec![0])
+                .distance(4)
+                .build_single("job3", "delivery"),
+            StopBuilder::default()
+                .coordinate((0., 0.))
+                .schedule_stamp(8., 8.)
+                .load(vec![0])
+                .distance(8)
+                .build_arrival(),
+        ],
+    );
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_shift_limits(&ctx);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_check_cumulative_limits, (limits, actual, expected), {
+    can_check_cumulative_limits_impl(limits, actual, expected);
+}}
+
+can_check_cumulative_limits! {
+    case01: ((Some(10.), None), (5, 6), Err("max total distance limit violation, expected: not more than 10, got: 11, vehicle id 'some_real_vehicle'".into())),
+    case02: ((Some(11.), None), (5, 6), Ok(())),
+    case03: ((None, Some(10.)), (5, 6), Err("max total driving time limit violation, expected: not more than 10, got: 11, vehicle id 'some_real_vehicle'".into())),
+    case04: ((None, Some(11.)), (5, 6), Ok(())),
+    case05: ((None, None), (5, 6), Ok(())),
+}
+
+fn can_check_cumulative_limits_impl(
+    limits: (Option<Float>, Option<Float>),
+    actual: (i64, i64),
+    expected: GenericResult<()>,
+) {
+    let problem = create_test_problem(Some(VehicleLimits {
+        max_distance: None,
+        max_duration: None,
+        tour_size: None,
+        max_stops: None,
+        max_total_distance: limits.0,
+        max_total_driving_time: limits.1,
+    }));
+    let create_tour = |shift_index: usize, value: i64| Tour {
+        vehicle_id: "some_real_vehicle".to_string(),
+        type_id: "my_vehicle".to_string(),
+        shift_index,
+        stops: vec![],
+        statistic: Statistic {
+            distance: value,
+            times: Timing { driving: value, ..Timing::default() },
+            ..Statistic::default()
+        },
+    };
+    let solution = SolutionBuilder::default().tour(create_tour(0, actual.0)).tour(create_tour(1, actual.1)).build();
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_cumulative_limits(&ctx);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn can_check_shift_time() {
     let problem = Problem {
@@ -184,6 +302,7 @@ fn can_check_recharge_distance() {
                             duration: 0.,
                             times: None,
                             tag: None,
+                            cost: None,
                         }],
                     }),
                     ..create_default_vehicle_shift()
@@ -228,3 +347,107 @@ fn can_check_recharge_distance() {
             .into())
     );
 }
+
+parameterized_test! {can_check_access_restrictions, (time, expected), {
+    can_check_access_restrictions_impl(time, expected);
+}}
+
+can_check_access_restrictions! {
+    case01_inside: ((0., 5.), Err("access restriction violation at location 'Coordinate { lat: 1.0, lng: 0.0 }', arrival: '1970-01-01T00:00:01Z', vehicle id 'my_vehicle_1', shift index: 0".into())),
+    case02_at_end: ((0., 1.), Ok(())),
+    case03_outside: ((2., 5.), Ok(())),
+}
+
+fn can_check_access_restrictions_impl(time: (Float, Float), expected: GenericResult<()>) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (1., 0.))], ..create_empty_plan() },
+        fleet: Fleet {
+            profiles: vec![MatrixProfile {
+                name: "car".to_string(),
+                speed: None,
+                restrictions: Some(vec![AccessRestriction {
+                    locations: vec![(1., 0.).to_loc()],
+                    time: vec![format_time(time.0), format_time(time.1)],
+                }]),
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let solution = SolutionBuilder::default()
+        .tour(
+            TourBuilder::default()
+                .stops(vec![
+                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![1]).build_departure(),
+                    StopBuilder::default()
+                        .coordinate((1., 0.))
+                        .schedule_stamp(1., 2.)
+                        .load(vec![0])
+                        .distance(1)
+                        .build_single("job1", "delivery"),
+                    StopBuilder::default()
+                        .coordinate((0., 0.))
+                        .schedule_stamp(3., 3.)
+                        .load(vec![0])
+                        .distance(2)
+                        .build_arrival(),
+                ])
+                .build(),
+        )
+        .build();
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_access_restrictions(&ctx);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_check_depot_hours, (time, expected), {
+    can_check_depot_hours_impl(time, expected);
+}}
+
+can_check_depot_hours! {
+    case01_open: ((0., 10.), Ok(())),
+    case02_late_opening: ((1., 10.), Err("depot operating hours violation: departure at '1970-01-01T00:00:00Z', vehicle id 'my_vehicle_1', shift index: 0".into())),
+    case03_early_closing: ((0., 2.), Err("depot operating hours violation: arrival at '1970-01-01T00:00:03Z', vehicle id 'my_vehicle_1', shift index: 0".into())),
+}
+
+fn can_check_depot_hours_impl(time: (Float, Float), expected: GenericResult<()>) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (1., 0.))], ..create_empty_plan() },
+        fleet: Fleet {
+            depots: Some(vec![Depot {
+                location: (0., 0.).to_loc(),
+                times: vec![vec![format_time(time.0), format_time(time.1)]],
+            }]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let solution = SolutionBuilder::default()
+        .tour(
+            TourBuilder::default()
+                .stops(vec![
+                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![1]).build_departure(),
+                    StopBuilder::default()
+                        .coordinate((1., 0.))
+                        .schedule_stamp(1., 2.)
+                        .load(vec![0])
+                        .distance(1)
+                        .build_single("job1", "delivery"),
+                    StopBuilder::default()
+                        .coordinate((0., 0.))
+                        .schedule_stamp(3., 3.)
+                        .load(vec![0])
+                        .distance(2)
+                        .build_arrival(),
+                ])
+                .build(),
+        )
+        .build();
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_depot_hours(&ctx);
+
+    assert_eq!(result, expected);
+}
@@ -4,7 +4,7 @@ use crate::helpers::*;
 
 mod single {
     use super::*;
-    use RelationType::{Any, Sequence, Strict};
+    use RelationType::{Any, DifferentVehicle, NotSameTour, Sequence, Strict};
     use vrp_core::models::examples::create_example_problem;
 
     fn create_relation(job_ids: Vec<&str>, relation_type: RelationType) -> Relation {
@@ -16,6 +16,10 @@ mod single {
         }
     }
 
+    fn create_negative_relation(job_ids: Vec<&str>, relation_type: RelationType) -> Relation {
+        Relation { vehicle_id: String::default(), ..create_relation(job_ids, relation_type) }
+    }
+
     fn create_relation_with_wrong_id(vehicle_id: &str) -> Relation {
         Relation {
             type_field: Sequence,
@@ -61,6 +65,11 @@ mod single {
         case_mixed_01:    (Some(vec![create_relation(vec!["departure", "job1"], Strict),
                                      create_relation(vec!["job3", "job4"], Sequence)]), Ok(())),
 
+        case_negative_01: (Some(vec![create_negative_relation(vec!["job1", "job2"], NotSameTour)]), Err(())),
+        case_negative_02: (Some(vec![create_negative_relation(vec!["job1", "job5"], DifferentVehicle)]), Err(())),
+        case_negative_03: (Some(vec![create_negative_relation(vec!["job1", "job7"], NotSameTour)]), Err(())),
+        case_negative_04: (Some(vec![create_negative_relation(vec!["job1"], DifferentVehicle)]), Ok(())),
+
         case_wrong_vehicle_01: (Some(vec![create_relation_with_wrong_id("my_vehicle_2")]), Err(())),
         case_wrong_vehicle_02: (Some(vec![create_relation_with_wrong_id("my_vehicle_x")]), Err(())),
         case_wrong_vehicle_03: (Some(vec![create_relation_with_wrong_shift()]), Err(())),
@@ -99,6 +108,7 @@ mod single {
                             ..create_default_reload()
                         }]),
                         recharges: None,
+                        capacity_change: None,
                     }],
                     capacity: vec![5],
                     skills: None,
@@ -14,7 +14,13 @@ fn create_test_problem() -> Problem {
 }
 
 fn create_test_statistic() -> Statistic {
-    Statistic { cost: 10., distance: 4, duration: 6, times: Timing { driving: 4, serving: 2, ..Timing::default() } }
+    Statistic {
+        cost: 10.,
+        distance: 4,
+        duration: 6,
+        times: Timing { driving: 4, serving: 2, ..Timing::default() },
+        ..Statistic::default()
+    }
 }
 
 fn create_test_solution(statistic: Statistic, stop_data: &[(Float, i64); 3]) -> Solution {
@@ -141,3 +147,51 @@ fn can_check_solution_statistic() {
         ])
     );
 }
+
+parameterized_test! {can_check_stop_with_tolerance, (stop_data, time_tolerance, distance_tolerance, expected), {
+    can_check_stop_with_tolerance_impl(stop_data, time_tolerance, distance_tolerance, expected);
+}}
+
+can_check_stop_with_tolerance! {
+    case_01_time_warning: (&[(4., 1), (3., 2), (6., 4)], 3., 0., (0, 1)),
+    case_02_time_error: (&[(4., 1), (3., 2), (6., 4)], 2., 0., (1, 0)),
+    case_03_distance_warning: (&[(1., 4), (3., 2), (6., 4)], 0., 3., (0, 2)),
+    case_04_distance_error: (&[(1., 4), (3., 2), (6., 4)], 3., 2., (1, 0)),
+    case_05_no_mismatch: (&[(1., 1), (3., 2), (6., 4)], 3., 3., (0, 0)),
+}
+
+fn can_check_stop_with_tolerance_impl(
+    stop_data: &[(Float, i64); 3],
+    time_tolerance: Float,
+    distance_tolerance: Float,
+    expected: (usize, usize),
+) {
+    let problem = create_test_problem();
+    let matrix = create_matrix_from_problem(&problem);
+    let solution = create_test_solution(create_test_statistic(), stop_data);
+    let ctx = CheckerContext::new(create_example_problem(), problem, Some(vec![matrix]), solution)
+        .unwrap()
+        .with_options(CheckerOptions { time_tolerance, distance_tolerance });
+
+    let errors = check_routing(&ctx).err().map_or(0, |errs| errs.len());
+    let warnings = ctx.warnings.take().len();
+
+    assert_eq!((errors, warnings), expected);
+}
+
+#[test]
+fn can_report_time_breakdown_mismatch_as_warning() {
+    let problem = create_test_problem();
+    let matrix = create_matrix_from_problem(&problem);
+    let statistic = Statistic { times: Timing { driving: 4, ..Timing::default() }, ..create_test_statistic() };
+    let solution = create_test_solution(statistic, &[(1., 1), (3., 2), (6., 4)]);
+    let ctx = CheckerContext::new(create_example_problem(), problem, Some(vec![matrix]), solution).unwrap();
+
+    let result = check_routing(&ctx);
+
+    assert_eq!(result, Ok(()));
+    assert_eq!(
+        ctx.warnings.take(),
+        vec![GenericError::from("time breakdown mismatch for tour statistic: my_vehicle_1, expected: '6', got: '4'")]
+    );
+}
@@ -0,0 +1,147 @@
+use super::*;
+use crate::format::problem::{Plan, serialize_problem};
+use crate::format::solution::serialize_solution;
+use crate::helpers::*;
+use serde_json::json;
+use std::io::BufWriter;
+
+fn create_legacy_problem() -> Value {
+    json!({
+        "plan": {
+            "jobs": [{
+                "id": "job1",
+                "deliveries": [{
+                    "places": [{ "location": { "index": 0 }, "duration": 60 }, { "location": { "index": 1 }, "duration": 60, "tag": "p2" }],
+                    "demand": [1],
+                    "tag": "t1"
+                }]
+            }]
+        },
+        "fleet": {
+            "vehicles": [{
+                "typeId": "vehicle",
+                "vehicleIds": ["vehicle_1"],
+                "profile": "car",
+                "costs": { "fixed": 0, "distance": 1, "time": 1 },
+                "shifts": [{ "start": { "earliest": "2020-07-04T09:00:00Z", "location": { "index": 0 } } }],
+                "capacity": [10],
+                "limits": { "shiftTime": 3600 }
+            }],
+            "profiles": [{ "name": "car", "type": "car" }]
+        }
+    })
+}
+
+fn create_legacy_solution() -> Value {
+    json!({
+        "statistic": {
+            "cost": 0, "distance": 0, "duration": 0,
+            "times": { "driving": 0, "serving": 0, "waiting": 0, "break": 0 }
+        },
+        "tours": [],
+        "unassigned": [{
+            "jobId": "job1",
+            "reasons": [{ "code": 6, "description": "cannot serve required skill" }, { "code": "CAPACITY_CONSTRAINT", "description": "capacity" }]
+        }]
+    })
+}
+
+#[test]
+fn can_upgrade_legacy_problem() {
+    let mut document = create_legacy_problem();
+
+    let is_changed = upgrade_problem(&mut document).unwrap();
+
+    assert!(is_changed);
+    assert_eq!(document["schemaVersion"], json!(SCHEMA_VERSION));
+    assert_eq!(document["fleet"]["vehicles"][0]["profile"], json!({ "matrix": "car" }));
+    assert_eq!(document["fleet"]["vehicles"][0]["limits"], json!({ "maxDuration": 3600 }));
+    assert_eq!(document["fleet"]["profiles"][0], json!({ "name": "car" }));
+    let task = &document["plan"]["jobs"][0]["deliveries"][0];
+    assert!(task.get("tag").is_none());
+    assert_eq!(task["places"][0]["tag"], json!("t1"));
+    assert_eq!(task["places"][1]["tag"], json!("p2"));
+}
+
+#[test]
+fn can_deserialize_legacy_problem() {
+    let bytes = serde_json::to_vec(&create_legacy_problem()).unwrap();
+
+    let problem = deserialize_problem_compat(BufReader::new(bytes.as_slice())).unwrap();
+
+    assert_eq!(problem.fleet.vehicles[0].profile.matrix, "car");
+    assert_eq!(problem.fleet.vehicles[0].limits.as_ref().and_then(|limits| limits.max_duration), Some(3600.));
+    let places = &problem.plan.jobs[0].deliveries.as_ref().unwrap()[0].places;
+    assert_eq!(places.iter().map(|place| place.tag.as_deref()).collect::<Vec<_>>(), vec![Some("t1"), Some("p2")]);
+}
+
+#[test]
+fn can_keep_current_problem_unchanged() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (1., 0.))], ..create_empty_plan() },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let mut writer = BufWriter::new(Vec::new());
+    serialize_problem(&problem, &mut writer).unwrap();
+    let bytes = writer.into_inner().unwrap();
+    let expected: Value = serde_json::from_slice(bytes.as_slice()).unwrap();
+
+    let mut document = expected.clone();
+    let is_changed = upgrade_problem(&mut document).unwrap();
+
+    assert!(!is_changed);
+    assert_eq!(expected["schemaVersion"], json!(SCHEMA_VERSION));
+    assert_eq!(document, expected);
+    assert!(deserialize_problem_compat(BufReader::new(bytes.as_slice())).is_ok());
+}
+
+#[test]
+fn can_upgrade_legacy_solution() {
+    let bytes = serde_json::to_vec(&create_legacy_solution()).unwrap();
+
+    let solution = deserialize_solution_compat(BufReader::new(bytes.as_slice())).unwrap();
+
+    let reasons = &solution.unassigned.as_ref().unwrap()[0].reasons;
+    assert_eq!(
+        reasons.iter().map(|reason| reason.code.as_str()).collect::<Vec<_>>(),
+        vec!["SKILL_CONSTRAINT", "CAPACITY_CONSTRAINT"]
+    );
+    assert_eq!(reasons[0].description, "cannot serve required skill");
+}
+
+#[test]
+fn can_write_schema_version_into_solution() {
+    let bytes = serde_json::to_vec(&create_legacy_solution()).unwrap();
+    let solution = deserialize_solution_compat(BufReader::new(bytes.as_slice())).unwrap();
+
+    let mut writer = BufWriter::new(Vec::new());
+    serialize_solution(&solution, &mut writer).unwrap();
+    let document: Value = serde_json::from_slice(writer.into_inner().unwrap().as_slice()).unwrap();
+
+    assert_eq!(document["schemaVersion"], json!(SCHEMA_VERSION));
+    assert_eq!(document["unassigned"][0]["reasons"][0]["code"], json!("SKILL_CONSTRAINT"));
+}
+
+parameterized_test! {can_reject_unsupported_schema_version, (version, expected), {
+    can_reject_unsupported_schema_version_impl(version, expected);
+}}
+
+can_reject_unsupported_schema_version! {
+    case01: (json!(SCHEMA_VERSION + 1), Some("unsupported schema version: 2, the latest supported is 1")),
+    case02: (json!("1"), Some("invalid schema version: '\"1\"'")),
+    case03: (json!(SCHEMA_VERSION), None),
+}
+
+fn can_reject_unsupported_schema_version_impl(version: Value, expected: Option<&str>) {
+    let mut solution = create_legacy_solution();
+    solution["schemaVersion"] = version;
+    let mut problem = create_legacy_problem();
+    problem["schemaVersion"] = solution["schemaVersion"].clone();
+
+    let solution_result = upgrade_solution(&mut solution).err().map(|err| err.to_string());
+    let problem_result = upgrade_problem(&mut problem).err().map(|err| err.to_string());
+
+    assert_eq!(solution_result.as_deref(), expected);
+    assert_eq!(problem_result.as_deref(), expected);
+}
@@ -0,0 +1,130 @@
+use super::*;
+use crate::helpers::{SIMPLE_MATRIX, SIMPLE_PROBLEM, create_matrix};
+use vrp_core::models::common::Profile;
+
+fn to_binary_bytes(matrix: &Matrix) -> Vec<u8> {
+    let mut writer = BufWriter::new(Vec::new());
+    write_binary_matrix(matrix, &mut writer).unwrap();
+
+    writer.into_inner().unwrap()
+}
+
+fn to_binary_matrix(matrix: &Matrix) -> BinaryMatrix {
+    BinaryMatrix::from_bytes(Arc::new(to_binary_bytes(matrix))).unwrap()
+}
+
+fn get_error(bytes: Vec<u8>) -> String {
+    BinaryMatrix::from_bytes(Arc::new(bytes)).err().map(|err| err.to_string()).unwrap_or_default()
+}
+
+/// Keeps bytes with some offset to get misaligned matrix values.
+struct ShiftedBytes(Vec<u8>);
+
+impl AsRef<[u8]> for ShiftedBytes {
+    fn as_ref(&self) -> &[u8] {
+        &self.0[1..]
+    }
+}
+
+#[test]
+fn can_write_and_read_binary_matrix() {
+    let matrix = deserialize_matrix(BufReader::new(SIMPLE_MATRIX.as_bytes())).unwrap();
+
+    let bytes = to_binary_bytes(&matrix);
+    let binary = BinaryMatrix::from_bytes(Arc::new(bytes.clone())).unwrap();
+
+    assert!(is_binary_matrix(bytes.as_slice()));
+    assert_eq!(bytes.len() % 4, 0);
+    assert_eq!(binary.profile, Some("normal_car".to_string()));
+    assert_eq!(binary.timestamp, None);
+    assert_eq!(binary.size, 4);
+    assert!(!binary.has_unreachable);
+    assert_eq!(binary.durations().values(), matrix.travel_times.iter().map(|&v| v as f32).collect::<Vec<_>>());
+    assert_eq!(binary.distances().values(), matrix.distances.iter().map(|&v| v as f32).collect::<Vec<_>>());
+}
+
+#[test]
+fn can_read_misaligned_binary_matrix() {
+    let matrix = create_matrix(vec![0, 1, 2, 0]);
+    let mut bytes = vec![0];
+    bytes.extend(to_binary_bytes(&matrix));
+
+    let binary = BinaryMatrix::from_bytes(Arc::new(ShiftedBytes(bytes))).unwrap();
+
+    assert_eq!(binary.durations().values(), &[0., 1., 2., 0.]);
+    assert_eq!(binary.distances().values(), &[0., 1., 2., 0.]);
+}
+
+#[test]
+fn can_write_unreachable_entries() {
+    let mut matrix = create_matrix(vec![0, 1, 2, 0]);
+    matrix.timestamp = Some("2020-07-04T09:00:00Z".to_string());
+    matrix.error_codes = Some(vec![0, 1, 0, 0]);
+
+    let binary = to_binary_matrix(&matrix);
+
+    assert!(binary.has_unreachable);
+    assert_eq!(binary.timestamp, matrix.timestamp);
+    assert_eq!(binary.durations().values(), &[0., -1., 2., 0.]);
+    assert_eq!(binary.distances().values(), &[0., -1., 2., 0.]);
+}
+
+#[test]
+fn can_detect_invalid_binary_matrix() {
+    let bytes = to_binary_bytes(&create_matrix(vec![0, 1, 2, 0]));
+    let with_version = |version: u32| {
+        let mut bytes = bytes.clone();
+        bytes[4..8].copy_from_slice(&version.to_le_bytes());
+        bytes
+    };
+
+    assert!(get_error(b"{\"profile\": \"car\"}".to_vec()).contains("unexpected magic bytes"));
+    assert!(get_error(with_version(2)).contains("unsupported binary matrix version: '2'"));
+    assert!(get_error(bytes[..10].to_vec()).contains("unexpected end of binary matrix header"));
+    assert!(get_error(bytes[..bytes.len() - 4].to_vec()).contains("binary matrix size mismatch"));
+}
+
+#[test]
+fn can_detect_invalid_matrix_dimensions_on_write() {
+    let mut matrix = create_matrix(vec![0, 1, 2, 0]);
+    matrix.distances.pop();
+
+    let result = write_binary_matrix(&matrix, &mut BufWriter::new(Vec::new()));
+
+    assert!(result.err().unwrap().to_string().starts_with("invalid matrix dimensions"));
+}
+
+#[test]
+fn can_read_problem_with_binary_matrix() {
+    let problem = deserialize_problem(BufReader::new(SIMPLE_PROBLEM.as_bytes())).unwrap();
+    let matrix = deserialize_matrix(BufReader::new(SIMPLE_MATRIX.as_bytes())).unwrap();
+    let binary = to_binary_matrix(&matrix);
+
+    let expected = (problem.clone(), vec![matrix]).read_pragmatic().unwrap();
+    let actual = (problem, vec![binary]).read_pragmatic().unwrap();
+
+    let profile = Profile::default();
+    let size = expected.transport.size();
+    assert_eq!(actual.transport.size(), size);
+    (0..size).flat_map(|from| (0..size).map(move |to| (from, to))).for_each(|(from, to)| {
+        assert_eq!(
+            actual.transport.duration_approx(&profile, from, to),
+            expected.transport.duration_approx(&profile, from, to)
+        );
+        assert_eq!(
+            actual.transport.distance_approx(&profile, from, to),
+            expected.transport.distance_approx(&profile, from, to)
+        );
+    });
+}
+
+#[test]
+fn can_validate_binary_matrix_size() {
+    let problem = deserialize_problem(BufReader::new(SIMPLE_PROBLEM.as_bytes())).unwrap();
+    let mut matrix = create_matrix(vec![0, 1, 2, 0]);
+    matrix.profile = Some("normal_car".to_string());
+
+    let result = (problem, vec![to_binary_matrix(&matrix)]).read_pragmatic();
+
+    assert_eq!(result.err().unwrap().errors.first().map(|err| err.code.as_str()), Some("E1504"));
+}
@@ -5,7 +5,7 @@ use crate::helpers::*;
 use std::sync::Arc;
 use vrp_core::models::common::{Distance, Profile as CoreProfile, TimeWindow, Timestamp};
 use vrp_core::models::problem::TravelTime;
-use vrp_core::models::problem::{Actor, ActorDetail, Vehicle};
+use vrp_core::models::problem::{Actor, ActorDetail, MatrixPrecision, Vehicle};
 use vrp_core::models::solution::Route;
 
 fn matrix(profile: Option<&str>, timestamp: Option<Float>, fill_value: i64, size: usize) -> Matrix {
@@ -31,7 +31,10 @@ fn wrong_matrix(profile: Option<&str>, timestamp: Option<String>) -> Matrix {
 fn create_problem(profiles: &[&str]) -> Problem {
     Problem {
         fleet: Fleet {
-            profiles: profiles.iter().map(|p| MatrixProfile { name: p.to_string(), speed: None }).collect(),
+            profiles: profiles
+                .iter()
+                .map(|p| MatrixProfile { name: p.to_string(), speed: None, restrictions: None })
+                .collect(),
             ..create_default_fleet()
         },
         ..create_empty_problem()
@@ -99,7 +102,7 @@ fn can_create_transport_costs_negative_cases_impl(profiles: &[&str], matrices: &
     let problem = create_problem(profiles);
     let coord_index = Arc::new(CoordIndex::new(&problem));
 
-    let result = create_transport_costs(&problem, matrices, coord_index);
+    let result = create_transport_costs(&problem, matrices, coord_index, MatrixPrecision::default());
 
     assert_eq!(result.err(), Some(res_err.into()));
 }
@@ -157,19 +160,21 @@ fn can_create_transport_costs_positive_cases_impl(
     let problem = create_problem(profiles);
     let coord_index = Arc::new(CoordIndex::new(&problem));
 
-    let transport = create_transport_costs(&problem, matrices, coord_index).unwrap();
+    [MatrixPrecision::Double, MatrixPrecision::Single].into_iter().for_each(|precision| {
+        let transport = create_transport_costs(&problem, matrices, coord_index.clone(), precision).unwrap();
 
-    probes.iter().for_each(|&(profile_idx, timestamp, distance)| {
-        let route = Route {
-            actor: Arc::new(Actor {
-                vehicle: Arc::new(Vehicle { profile: CoreProfile::new(profile_idx, None), ..test_vehicle("v1") }),
-                driver: Arc::new(test_driver()),
-                detail: ActorDetail { start: None, end: None, time: TimeWindow::new(0., 1.) },
-            }),
-            tour: Default::default(),
-        };
+        probes.iter().for_each(|&(profile_idx, timestamp, distance)| {
+            let route = Route {
+                actor: Arc::new(Actor {
+                    vehicle: Arc::new(Vehicle { profile: CoreProfile::new(profile_idx, None), ..test_vehicle("v1") }),
+                    driver: Arc::new(test_driver()),
+                    detail: ActorDetail { start: None, end: None, time: TimeWindow::new(0., 1.) },
+                }),
+                tour: Default::default(),
+            };
 
-        let result = transport.distance(&route, 0, 1, TravelTime::Departure(timestamp));
-        assert_eq!(result, distance);
+            let result = transport.distance(&route, 0, 1, TravelTime::Departure(timestamp));
+            assert_eq!(result, distance);
+        });
     });
 }
@@ -64,3 +64,43 @@ fn can_deserialize_matrix() {
     assert_eq!(matrix.distances.len(), 16);
     assert_eq!(matrix.travel_times.len(), 16);
 }
+
+#[test]
+fn can_deserialize_weighted_tier_objective() {
+    let objectives = r#"[
+        { "type": "minimize-unassigned" },
+        { "type": "weighted-tier", "objectives": [
+            { "type": "minimize-cost", "weight": 1 },
+            { "type": "minimize-tour-overlap", "threshold": 0.1, "weight": 100.5 }
+        ]}
+    ]"#;
+
+    let objectives: Vec<Objective> = serde_json::from_str(objectives).unwrap();
+
+    match objectives.last() {
+        Some(Objective::WeightedTier { objectives }) => {
+            assert_eq!(objectives.len(), 2);
+            assert!(matches!(objectives[0].objective, Objective::MinimizeCost));
+            assert_eq!(objectives[0].weight, 1.);
+            assert!(matches!(objectives[1].objective, Objective::MinimizeTourOverlap { threshold: Some(0.1) }));
+            assert_eq!(objectives[1].weight, 100.5);
+        }
+        _ => unreachable!("unexpected objective"),
+    }
+
+    let serialized = serde_json::to_string(&objectives).unwrap();
+    assert!(serialized.contains(r#"{"type":"minimize-cost","weight":1.0}"#));
+}
+
+#[test]
+fn can_deserialize_vehicle_skills_with_levels() {
+    let skills = r#"["plumbing", { "name": "welding", "level": 3 }]"#;
+
+    let skills: Vec<VehicleSkill> = serde_json::from_str(skills).unwrap();
+
+    assert_eq!(
+        skills,
+        vec![VehicleSkill::Name("plumbing".to_string()), VehicleSkill::Level { name: "welding".to_string(), level: 3 }]
+    );
+    assert_eq!(serde_json::to_string(&skills).unwrap(), r#"["plumbing",{"name":"welding","level":3}]"#);
+}
@@ -1,11 +1,15 @@
+use crate::format::JobIndexExtraProperty;
 use crate::format::problem::*;
 use crate::helpers::*;
 use std::collections::HashSet;
 use std::iter::FromIterator;
 use std::sync::Arc;
-use vrp_core::construction::features::{JobDemandDimension, JobSkillsDimension, VehicleSkillsDimension};
+use vrp_core::construction::features::{
+    JobCrewDimension, JobDemandDimension, JobSkillsDimension, VehicleSkillsDimension,
+};
 use vrp_core::models::common::*;
 use vrp_core::models::problem::{JobIdDimension, Jobs, Multi, Place, Single, VehicleIdDimension};
+use vrp_core::solver::search::{ArcPenalties, ArcPenaltiesExtraProperty};
 
 fn get_job(index: usize, jobs: &Jobs) -> vrp_core::models::problem::Job {
     jobs.all().get(index).unwrap().clone()
@@ -77,6 +81,7 @@ fn can_read_complex_problem() {
                             location: (52.48325, 13.4436).to_loc(),
                             duration: 100.0,
                             tag: Some("my_delivery".to_string()),
+                            cost: None,
                         }],
                         demand: Some(vec![0, 1]),
                         order: None,
@@ -94,6 +99,7 @@ fn can_read_complex_problem() {
                             location: (52.48300, 13.4420).to_loc(),
                             duration: 110.0,
                             tag: None,
+                            cost: None,
                         }],
                         demand: Some(vec![2]),
                         order: None,
@@ -107,6 +113,7 @@ fn can_read_complex_problem() {
                             location: (52.48325, 13.4436).to_loc(),
                             duration: 120.0,
                             tag: None,
+                            cost: None,
                         }],
                         demand: Some(vec![2]),
                         order: None,
@@ -123,6 +130,7 @@ fn can_read_complex_problem() {
                             location: (52.48321, 13.4438).to_loc(),
                             duration: 90.0,
                             tag: None,
+                            cost: None,
                         }],
                         demand: Some(vec![3]),
                         order: None,
@@ -138,7 +146,7 @@ fn can_read_complex_problem() {
                 type_id: "my_vehicle".to_string(),
                 vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
                 profile: create_default_vehicle_profile(),
-                costs: VehicleCosts { fixed: Some(100.), distance: 1., time: 2. },
+                costs: VehicleCosts { fixed: Some(100.), distance: 1., time: 2., tiers: None },
                 shifts: vec![VehicleShift {
                     start: ShiftStart {
                         earliest: "1970-01-01T00:00:00Z".to_string(),
@@ -164,14 +172,23 @@ fn can_read_complex_problem() {
                     }]),
                     reloads: None,
                     recharges: None,
+                    capacity_change: None,
                 }],
                 capacity: vec![10, 1],
-                skills: Some(vec!["unique1".to_string(), "unique2".to_string()]),
-                limits: Some(VehicleLimits { max_distance: Some(123.1), max_duration: Some(100.), tour_size: Some(3) }),
+                skills: Some(vec!["unique1".into(), "unique2".into()]),
+                limits: Some(VehicleLimits {
+                    max_distance: Some(123.1),
+                    max_duration: Some(100.),
+                    tour_size: Some(3),
+                    max_stops: None,
+                    max_total_distance: None,
+                    max_total_driving_time: None,
+                }),
             }],
             ..create_default_fleet()
         },
         objectives: None,
+        timezone: None,
     };
     let matrix = Matrix {
         profile: Some("car".to_owned()),
@@ -292,10 +309,10 @@ fn can_create_approximation_matrices() {
         fleet: Fleet {
             vehicles: vec![],
             profiles: vec![
-                MatrixProfile { name: "car1".to_string(), speed: Some(8.) },
-                MatrixProfile { name: "car2".to_string(), speed: Some(10.) },
-                MatrixProfile { name: "car3".to_string(), speed: Some(5.) },
-                MatrixProfile { name: "car4".to_string(), speed: None },
+                MatrixProfile { name: "car1".to_string(), speed: Some(8.), restrictions: None },
+                MatrixProfile { name: "car2".to_string(), speed: Some(10.), restrictions: None },
+                MatrixProfile { name: "car3".to_string(), speed: Some(5.), restrictions: None },
+                MatrixProfile { name: "car4".to_string(), speed: None, restrictions: None },
             ],
             ..create_default_fleet()
         },
@@ -315,3 +332,80 @@ fn can_create_approximation_matrices() {
         assert_eq!(matrix.travel_times, &[0, duration, duration, 0]);
     }
 }
+
+#[test]
+fn can_read_problem_with_geo_routing_approximation() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (52.52599, 13.45413)),
+                create_delivery_job("job2", (52.5165, 13.3808)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            profiles: vec![MatrixProfile { name: "car".to_string(), speed: Some(8.), restrictions: None }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let problem = (problem, RoutingApproximation::Geo).read_pragmatic().expect("cannot read problem");
+
+    let profile = Profile::new(0, None);
+    let distance = problem.transport.distance_approx(&profile, 0, 1);
+    assert!((distance - 5078.).abs() < 10., "unexpected distance: {distance}");
+    assert_eq!(problem.transport.duration_approx(&profile, 0, 1), distance / 8.);
+    assert_eq!(problem.transport.distance_approx(&profile, 1, 1), 0.);
+}
+
+#[test]
+fn can_read_crew_job_as_multiple_members() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                Job { crew: Some(3), ..create_service_job("job1", (1., 0.)) },
+                create_delivery_job("job2", (2., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let problem = (problem, vec![matrix]).read_pragmatic().expect("cannot read problem");
+
+    let members = problem
+        .jobs
+        .all()
+        .iter()
+        .filter_map(|job| job.dimens().get_job_crew().map(|crew| (job.dimens().get_job_id().cloned(), crew.clone())))
+        .collect::<Vec<_>>();
+    assert_eq!(problem.jobs.all().len(), 4);
+    assert_eq!(members.len(), 3);
+    assert!(
+        members.iter().all(|(job_id, crew)| job_id.as_deref() == Some("job1") && crew.id == "job1" && crew.size == 3)
+    );
+
+    let job_index = problem.extras.get_job_index().expect("job index is expected");
+    assert!(["job1", "job1_crew_2", "job1_crew_3", "job2"].iter().all(|id| job_index.contains_key(*id)));
+}
+
+#[test]
+fn can_read_problem_with_arc_penalties_only_when_requested() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (1., 0.))], ..create_empty_plan() },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let arc_penalties = Arc::new(ArcPenalties::default());
+    let options = ProblemReadOptions { arc_penalties: Some(arc_penalties.clone()), ..Default::default() };
+
+    let default_problem = (problem.clone(), vec![matrix.clone()]).read_pragmatic().expect("cannot read problem");
+    let guided_problem = (problem, vec![matrix], options).read_pragmatic().expect("cannot read problem");
+
+    assert!(default_problem.extras.get_arc_penalties().is_none());
+    assert!(guided_problem.extras.get_arc_penalties().is_some_and(|penalties| Arc::ptr_eq(&penalties, &arc_penalties)));
+}
@@ -0,0 +1,84 @@
+use super::*;
+use crate::get_unique_locations_from_reader;
+use crate::helpers::SIMPLE_PROBLEM;
+
+fn stream_jobs(problem: &str) -> Result<(Problem, Vec<Job>), MultiFormatError> {
+    let mut jobs = vec![];
+    let problem = deserialize_problem_with_jobs(BufReader::new(problem.as_bytes()), |job| {
+        jobs.push(job);
+        Ok(())
+    })?;
+
+    Ok((problem, jobs))
+}
+
+fn get_error_details(error: MultiFormatError) -> String {
+    error.errors.first().map(|error| error.action.clone()).unwrap_or_default()
+}
+
+#[test]
+fn can_stream_jobs_of_problem() {
+    let expected = deserialize_problem(BufReader::new(SIMPLE_PROBLEM.as_bytes())).unwrap();
+
+    let (problem, jobs) = stream_jobs(SIMPLE_PROBLEM).unwrap();
+
+    assert!(problem.plan.jobs.is_empty());
+    assert_eq!(
+        jobs.iter().map(|job| job.id.as_str()).collect::<Vec<_>>(),
+        expected.plan.jobs.iter().map(|job| job.id.as_str()).collect::<Vec<_>>()
+    );
+    assert_eq!(serde_json::to_value(&problem.fleet).unwrap(), serde_json::to_value(&expected.fleet).unwrap());
+}
+
+#[test]
+fn can_stream_jobs_regardless_of_property_order() {
+    let problem = r#"{
+        "fleet": { "vehicles": [], "profiles": [] },
+        "unknown": { "some": [1, 2] },
+        "plan": {
+            "relations": [{ "type": "any", "jobs": ["job1"], "vehicleId": "v1" }],
+            "jobs": [
+                { "id": "job1", "services": [{ "places": [{ "location": { "index": 0 }, "duration": 1 }] }] },
+                { "id": "job2", "services": [{ "places": [{ "location": { "index": 1 }, "duration": 1 }] }] }
+            ]
+        },
+        "timezone": "+02:00"
+    }"#;
+
+    let (problem, jobs) = stream_jobs(problem).unwrap();
+
+    assert_eq!(jobs.len(), 2);
+    assert_eq!(problem.plan.relations.map(|relations| relations.len()), Some(1));
+    assert_eq!(problem.timezone, Some("+02:00".to_string()));
+}
+
+#[test]
+fn can_stop_streaming_on_job_error() {
+    let mut count = 0;
+
+    let result = deserialize_problem_with_jobs(BufReader::new(SIMPLE_PROBLEM.as_bytes()), |_| {
+        count += 1;
+        Err("stop".into())
+    });
+
+    assert_eq!(count, 1);
+    assert!(get_error_details(result.unwrap_err()).contains("stop"));
+}
+
+#[test]
+fn can_detect_missing_properties() {
+    let missing_jobs = r#"{ "plan": {}, "fleet": { "vehicles": [], "profiles": [] } }"#;
+    let missing_fleet = r#"{ "plan": { "jobs": [] } }"#;
+
+    assert!(get_error_details(stream_jobs(missing_jobs).unwrap_err()).contains("missing field `jobs`"));
+    assert!(get_error_details(stream_jobs(missing_fleet).unwrap_err()).contains("missing field `fleet`"));
+}
+
+#[test]
+fn can_get_same_unique_locations_from_reader() {
+    let problem = deserialize_problem(BufReader::new(SIMPLE_PROBLEM.as_bytes())).unwrap();
+
+    let locations = get_unique_locations_from_reader(BufReader::new(SIMPLE_PROBLEM.as_bytes())).unwrap();
+
+    assert_eq!(locations, crate::get_unique_locations(&problem));
+}
@@ -0,0 +1,87 @@
+use super::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn serialize(solution: &ApiSolution) -> Vec<String> {
+    let mut writer = BufWriter::new(Vec::new());
+    serialize_solution_as_csv(solution, &mut writer).unwrap();
+
+    String::from_utf8(writer.into_inner().unwrap()).unwrap().lines().map(|line| line.to_string()).collect()
+}
+
+#[test]
+fn can_serialize_solution_as_csv() {
+    let solution = SolutionBuilder::default()
+        .tour(
+            TourBuilder::default()
+                .vehicle_id("my_vehicle_1")
+                .stops(vec![
+                    StopBuilder::default()
+                        .coordinate((0., 0.))
+                        .schedule_stamp(0., 0.)
+                        .load(vec![2, 1])
+                        .build_departure(),
+                    StopBuilder::default()
+                        .coordinate((1., 0.))
+                        .schedule_stamp(1., 5.)
+                        .load(vec![1, 1])
+                        .distance(1)
+                        .build_single_time("job1", "delivery", (3., 5.)),
+                    StopBuilder::default()
+                        .coordinate((2., 0.))
+                        .schedule_stamp(6., 7.)
+                        .load(vec![0, 0])
+                        .distance(3)
+                        .build_single("job2", "delivery"),
+                ])
+                .build(),
+        )
+        .build();
+
+    let rows = serialize(&solution);
+
+    assert_eq!(rows.len(), 4);
+    assert_eq!(rows[0], "tour_id,seq,job_id,location,arrival,departure,wait,load_in,load_out,distance");
+    assert_eq!(
+        rows[1],
+        format!("my_vehicle_1,0,departure,\"0,0\",{},{},0,\"2,1\",\"2,1\",0", format_time(0.), format_time(0.))
+    );
+    assert_eq!(
+        rows[2],
+        format!("my_vehicle_1,1,job1,\"1,0\",{},{},2,\"2,1\",\"1,1\",1", format_time(1.), format_time(5.))
+    );
+    assert_eq!(
+        rows[3],
+        format!("my_vehicle_1,2,job2,\"2,0\",{},{},0,\"1,1\",\"0,0\",2", format_time(6.), format_time(7.))
+    );
+}
+
+#[test]
+fn can_serialize_transit_stop_as_csv() {
+    let solution = SolutionBuilder::default()
+        .tour(
+            TourBuilder::default()
+                .stops(vec![
+                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![1]).build_departure(),
+                    StopBuilder::new_transit().schedule_stamp(1., 2.).load(vec![1]).build_single("break", "break"),
+                ])
+                .build(),
+        )
+        .build();
+
+    let rows = serialize(&solution);
+
+    assert_eq!(rows.len(), 3);
+    assert!(rows[2].contains(",1,break,,"));
+    assert!(rows[2].ends_with(",1,1,0"));
+}
+
+parameterized_test! {can_escape_csv, (value, expected), {
+    assert_eq!(escape_csv(value), expected);
+}}
+
+can_escape_csv! {
+    case01_plain: ("job1", "job1"),
+    case02_comma: ("1,2", "\"1,2\""),
+    case03_quote: ("a\"b", "\"a\"\"b\""),
+}
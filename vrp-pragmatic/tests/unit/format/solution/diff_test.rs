@@ -0,0 +1,71 @@
+use super::*;
+use crate::helpers::*;
+
+fn create_tour(vehicle_id: &str, jobs: &[(&str, Float)], driving: i64) -> Tour {
+    TourBuilder::default()
+        .vehicle_id(vehicle_id)
+        .stops(
+            std::iter::once(StopBuilder::default().load(vec![0]).build_departure())
+                .chain(jobs.iter().map(|&(job_id, time)| {
+                    StopBuilder::default()
+                        .schedule_stamp(time, time + 1.)
+                        .load(vec![0])
+                        .build_single(job_id, "delivery")
+                }))
+                .collect(),
+        )
+        .statistic(StatisticBuilder::default().driving(driving).build())
+        .build()
+}
+
+fn tour_ref(vehicle_id: &str) -> Option<TourRef> {
+    Some(TourRef { vehicle_id: vehicle_id.to_string(), shift_index: 0 })
+}
+
+#[test]
+fn can_get_empty_diff_for_same_solution() {
+    let solution = SolutionBuilder::default().tour(create_tour("v1", &[("job1", 10.), ("job2", 20.)], 10)).build();
+
+    let diff = get_solution_diff(&solution, &solution);
+
+    assert!(diff.moved.is_empty());
+    assert!(diff.shifted.is_empty());
+    assert_eq!(diff.statistic, Statistic::default());
+}
+
+#[test]
+fn can_get_solution_diff() {
+    let old = SolutionBuilder::default()
+        .tour(create_tour("v1", &[("job1", 10.), ("job2", 20.)], 10))
+        .tour(create_tour("v2", &[("job3", 30.), ("job4", 40.)], 20))
+        .build();
+    let new = SolutionBuilder::default()
+        .tour(create_tour("v1", &[("job2", 25.)], 5))
+        .tour(create_tour("v2", &[("job3", 30.), ("job1", 40.)], 30))
+        .build();
+
+    let diff = get_solution_diff(&old, &new);
+
+    assert_eq!(
+        diff.moved,
+        vec![
+            JobMove { job_id: "job1".to_string(), from: tour_ref("v1"), to: tour_ref("v2") },
+            JobMove { job_id: "job4".to_string(), from: tour_ref("v2"), to: None },
+        ]
+    );
+    assert_eq!(
+        diff.shifted,
+        vec![JobTimeShift {
+            job_id: "job2".to_string(),
+            activity_type: "delivery".to_string(),
+            job_tag: None,
+            tour: tour_ref("v1").unwrap(),
+            old_time: format_time(20.),
+            new_time: format_time(25.),
+            shift: 5,
+        }]
+    );
+    assert_eq!(diff.statistic.times.driving, 5);
+    assert_eq!(diff.statistic.distance, 5);
+    assert_eq!(diff.statistic.cost, 10.);
+}
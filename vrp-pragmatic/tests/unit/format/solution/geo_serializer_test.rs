@@ -95,6 +95,7 @@ fn can_create_geo_json_for_cluster_geometry() {
                 }),
             },
         ],
+        geometry: None,
     };
 
     let features = get_cluster_geometry(0, 0, &stop).unwrap();
@@ -103,3 +104,57 @@ fn can_create_geo_json_for_cluster_geometry() {
     assert_eq!(features.iter().filter(|f| matches!(f.geometry, Geometry::Point { .. })).count(), 2);
     assert_eq!(features.iter().filter(|f| matches!(f.geometry, Geometry::LineString { .. })).count(), 2);
 }
+
+#[test]
+fn can_use_leg_geometry_in_tour_line() {
+    let mut tour = TourBuilder::default()
+        .stops(vec![
+            StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![1]).build_departure(),
+            StopBuilder::default()
+                .coordinate((1., 0.))
+                .schedule_stamp(1., 2.)
+                .load(vec![0])
+                .build_single("job1", "delivery"),
+            StopBuilder::default().coordinate((0., 0.)).schedule_stamp(3., 3.).load(vec![0]).build_arrival(),
+        ])
+        .build();
+    if let Stop::Point(point) = &mut tour.stops[1] {
+        point.geometry = Some(encode_polyline(&[(0., 0.), (0.5, 0.5), (1., 0.)]));
+    }
+
+    let feature = get_tour_line(0, &tour, "red").unwrap();
+
+    match feature.geometry {
+        Geometry::LineString { coordinates } => {
+            assert_eq!(coordinates, vec![(0., 0.), (0., 0.), (0.5, 0.5), (0., 1.), (0., 0.)])
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn can_create_stop_point_with_properties() {
+    let tour = TourBuilder::default()
+        .stops(vec![
+            StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![1, 2]).build_departure(),
+            StopBuilder::default()
+                .coordinate((1., 0.))
+                .schedule_stamp(1., 2.)
+                .load(vec![0, 2])
+                .build_single("job1", "delivery"),
+        ])
+        .build();
+    let mut stop = tour.stops[1].as_point().unwrap().clone();
+    stop.parking = Some(Interval { start: format_time(1.), end: format_time(2.) });
+
+    let feature = get_stop_point(&tour, 0, 1, &stop, "red").unwrap();
+
+    assert_eq!(feature.properties.get("vehicle_id").map(String::as_str), Some(tour.vehicle_id.as_str()));
+    assert_eq!(feature.properties.get("load").map(String::as_str), Some("0,2"));
+    assert_eq!(feature.properties.get("jobs_ids").map(String::as_str), Some("job1"));
+    assert_eq!(feature.properties.get("arrival"), Some(&format_time(1.)));
+    assert_eq!(feature.properties.get("departure"), Some(&format_time(2.)));
+    assert_eq!(feature.properties.get("parking_start"), Some(&format_time(1.)));
+    assert_eq!(feature.properties.get("parking_end"), Some(&format_time(2.)));
+    assert!(matches!(feature.geometry, Geometry::Point { coordinates } if coordinates == (0., 1.)));
+}
@@ -0,0 +1,94 @@
+use super::*;
+use crate::helpers::*;
+use std::cell::RefCell;
+
+struct StraightLineProvider {
+    calls: RefCell<usize>,
+}
+
+impl LegGeometryProvider for StraightLineProvider {
+    fn get_path(&self, _: &Profile, from: &Location, to: &Location) -> Option<Vec<(Float, Float)>> {
+        *self.calls.borrow_mut() += 1;
+
+        match (from, to) {
+            (
+                Location::Coordinate { lat: from_lat, lng: from_lng },
+                Location::Coordinate { lat: to_lat, lng: to_lng },
+            ) => Some(vec![
+                (*from_lat, *from_lng),
+                ((from_lat + to_lat) / 2., (from_lng + to_lng) / 2.),
+                (*to_lat, *to_lng),
+            ]),
+            _ => None,
+        }
+    }
+}
+
+parameterized_test! {can_encode_and_decode_polyline, (points, expected), {
+    can_encode_and_decode_polyline_impl(points, expected);
+}}
+
+can_encode_and_decode_polyline! {
+    case01_empty: (vec![], ""),
+    case02_single: (vec![(38.5, -120.2)], "_p~iF~ps|U"),
+    case03_reference: (vec![(38.5, -120.2), (40.7, -120.95), (43.252, -126.453)], "_p~iF~ps|U_ulLnnqC_mqNvxq`@"),
+    case04_zero: (vec![(0., 0.), (0., 0.)], "????"),
+}
+
+fn can_encode_and_decode_polyline_impl(points: Vec<(Float, Float)>, expected: &str) {
+    let encoded = encode_polyline(points.as_slice());
+    let decoded = decode_polyline(encoded.as_str()).expect("cannot decode polyline");
+
+    assert_eq!(encoded, expected);
+    assert_eq!(decoded, points);
+}
+
+parameterized_test! {can_detect_invalid_polyline, polyline, {
+    can_detect_invalid_polyline_impl(polyline);
+}}
+
+can_detect_invalid_polyline! {
+    case01_missing_lng: "_p~iF",
+    case02_unfinished_value: "_p~iF~ps|",
+    case03_invalid_character: "_p~iF ps|U",
+}
+
+fn can_detect_invalid_polyline_impl(polyline: &str) {
+    assert!(decode_polyline(polyline).is_err());
+}
+
+#[test]
+fn can_insert_leg_geometries() {
+    let route = create_route_with_activities(&test_fleet(), "v1", vec![]);
+    let mut tour = TourBuilder::default()
+        .stops(vec![
+            StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![2]).build_departure(),
+            StopBuilder::default()
+                .coordinate((1., 0.))
+                .schedule_stamp(1., 2.)
+                .load(vec![1])
+                .build_single("job1", "delivery"),
+            StopBuilder::default()
+                .coordinate((1., 0.))
+                .schedule_stamp(2., 3.)
+                .load(vec![0])
+                .build_single("job2", "delivery"),
+            StopBuilder::default().coordinate((0., 0.)).schedule_stamp(4., 4.).load(vec![0]).build_arrival(),
+        ])
+        .build();
+    let provider = StraightLineProvider { calls: RefCell::new(0) };
+
+    insert_leg_geometries(&route, &mut tour, &provider);
+
+    let geometries = tour.stops.iter().map(|stop| stop.as_point().unwrap().geometry.clone()).collect::<Vec<_>>();
+    assert_eq!(*provider.calls.borrow(), 2);
+    assert_eq!(
+        geometries,
+        vec![
+            None,
+            Some(encode_polyline(&[(0., 0.), (0.5, 0.), (1., 0.)])),
+            None,
+            Some(encode_polyline(&[(1., 0.), (0.5, 0.), (0., 0.)])),
+        ]
+    );
+}
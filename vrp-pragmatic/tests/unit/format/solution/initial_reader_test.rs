@@ -5,6 +5,7 @@ use crate::helpers::*;
 use std::io::{BufReader, BufWriter};
 use std::sync::Arc;
 use vrp_core::construction::heuristics::InsertionContext;
+use vrp_core::models::problem::JobIdDimension;
 use vrp_core::utils::Environment;
 
 fn create_basic_problem(breaks: Option<Vec<VehicleBreak>>) -> Problem {
@@ -189,3 +190,69 @@ fn can_handle_commute_error_in_init_solution() {
 
     assert_eq!(result_solution, Err("commute property in initial solution is not supported".into()));
 }
+
+#[test]
+fn can_read_partial_init_solution_with_warnings() {
+    let problem = create_basic_problem(None);
+    let matrix = create_matrix_from_problem(&problem);
+    let core_problem = Arc::new((problem, vec![matrix]).read_pragmatic().expect("cannot read core problem"));
+    let create_stops = || {
+        vec![
+            StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![1]).build_departure(),
+            StopBuilder::default()
+                .coordinate((1., 0.))
+                .schedule_stamp(1., 2.)
+                .load(vec![0])
+                .build_single("job1", "delivery"),
+            StopBuilder::default()
+                .coordinate((1., 0.))
+                .schedule_stamp(2., 3.)
+                .load(vec![0])
+                .build_single("stale_job", "delivery"),
+            StopBuilder::default()
+                .coordinate((2., 0.))
+                .schedule_stamp(4., 5.)
+                .load(vec![1])
+                .build_single_tag("job2", "pickup", "p1"),
+        ]
+    };
+    let solution = SolutionBuilder::default()
+        .tour(TourBuilder::default().stops(create_stops()).build())
+        .tour(Tour {
+            vehicle_id: "unknown_1".to_string(),
+            type_id: "unknown".to_string(),
+            shift_index: 0,
+            stops: create_stops(),
+            statistic: Default::default(),
+        })
+        .unassigned(create_unassigned_jobs(&["job3", "stale_job"]))
+        .build();
+    let mut writer = BufWriter::new(Vec::new());
+    serialize_solution(&solution, &mut writer).expect("cannot serialize solution");
+    let bytes = writer.into_inner().expect("cannot get bytes from writer");
+
+    let (core_solution, warnings) =
+        read_partial_init_solution(BufReader::new(bytes.as_slice()), core_problem, create_random())
+            .expect("cannot read partial solution");
+
+    assert_eq!(core_solution.routes.len(), 1);
+    assert_eq!(core_solution.routes[0].tour.job_count(), 1);
+    let mut unassigned =
+        core_solution.unassigned.iter().filter_map(|(job, _)| job.dimens().get_job_id().cloned()).collect::<Vec<_>>();
+    unassigned.sort();
+    assert_eq!(unassigned, vec!["job2".to_string(), "job3".to_string()]);
+    assert_eq!(warnings.len(), 4);
+    assert!(warnings.iter().any(|warning| warning.contains("unknown job id: 'stale_job'")));
+    assert!(warnings.iter().any(|warning| warning.contains("multi job 'job2'")));
+    assert!(warnings.iter().any(|warning| warning.contains("cannot find vehicle")));
+}
+
+#[test]
+fn can_keep_strict_init_solution_reading() {
+    let problem = create_basic_problem(None);
+    let solution = SolutionBuilder::default().unassigned(create_unassigned_jobs(&["stale_job"])).build();
+
+    let result_solution = get_init_solution(problem, &solution);
+
+    assert!(result_solution.unwrap_err().to_string().contains("cannot get job id"));
+}
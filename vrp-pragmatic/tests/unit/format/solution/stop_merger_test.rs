@@ -0,0 +1,91 @@
+use super::*;
+use crate::helpers::*;
+
+fn create_test_tour() -> Tour {
+    TourBuilder::default()
+        .stops(vec![
+            StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![3]).build_departure(),
+            StopBuilder::default()
+                .coordinate((1., 0.))
+                .schedule_stamp(1., 2.)
+                .load(vec![2])
+                .distance(1)
+                .build_single("job1", "delivery"),
+            StopBuilder::default()
+                .coordinate((1., 0.0001))
+                .schedule_stamp(3., 4.)
+                .load(vec![1])
+                .distance(2)
+                .build_single("job2", "delivery"),
+            StopBuilder::default()
+                .coordinate((1., 0.0001))
+                .schedule_stamp(4., 5.)
+                .load(vec![0])
+                .distance(2)
+                .build_single("job3", "delivery"),
+            StopBuilder::default()
+                .coordinate((0., 0.))
+                .schedule_stamp(6., 6.)
+                .load(vec![0])
+                .distance(3)
+                .build_arrival(),
+        ])
+        .build()
+}
+
+fn get_job_ids(tour: &Tour) -> Vec<Vec<String>> {
+    tour.stops.iter().map(|stop| stop.activities().iter().map(|a| a.job_id.clone()).collect()).collect()
+}
+
+parameterized_test! {can_merge_nearby_stops, (radius, expected), {
+    can_merge_nearby_stops_impl(radius, expected);
+}}
+
+can_merge_nearby_stops! {
+    case01_exact_location: (0., vec![vec!["departure"], vec!["job1"], vec!["job2", "job3"], vec!["arrival"]]),
+    case02_small_radius: (5., vec![vec!["departure"], vec!["job1"], vec!["job2", "job3"], vec!["arrival"]]),
+    case03_large_radius: (20., vec![vec!["departure"], vec!["job1", "job2", "job3"], vec!["arrival"]]),
+}
+
+fn can_merge_nearby_stops_impl(radius: Float, expected: Vec<Vec<&str>>) {
+    let mut tour = create_test_tour();
+
+    merge_nearby_stops(&mut tour, radius);
+
+    assert_eq!(get_job_ids(&tour), expected);
+}
+
+#[test]
+fn can_keep_stop_details_when_merging() {
+    let mut tour = create_test_tour();
+
+    merge_nearby_stops(&mut tour, 20.);
+
+    let stop = tour.stops[1].as_point().unwrap();
+    assert_eq!(stop.location, (1., 0.).to_loc());
+    assert_eq!(stop.time, Schedule { arrival: format_time(1.), departure: format_time(5.) });
+    assert_eq!(stop.load, vec![0]);
+    assert_eq!(stop.distance, 2);
+    assert_eq!(
+        stop.activities.iter().map(|a| (a.location.clone(), a.time.clone())).collect::<Vec<_>>(),
+        vec![
+            (Some((1., 0.).to_loc()), Some(Interval { start: format_time(1.), end: format_time(2.) })),
+            (Some((1., 0.0001).to_loc()), Some(Interval { start: format_time(3.), end: format_time(4.) })),
+            (Some((1., 0.0001).to_loc()), Some(Interval { start: format_time(4.), end: format_time(5.) })),
+        ]
+    );
+}
+
+#[test]
+fn can_join_geometries_when_merging() {
+    let mut tour = create_test_tour();
+    tour.stops.iter_mut().skip(1).zip([[(0., 0.), (1., 0.)], [(1., 0.), (1., 0.0001)]]).for_each(|(stop, path)| {
+        if let Stop::Point(point) = stop {
+            point.geometry = Some(encode_polyline(&path));
+        }
+    });
+
+    merge_nearby_stops(&mut tour, 20.);
+
+    assert_eq!(tour.stops[1].as_point().unwrap().geometry, Some(encode_polyline(&[(0., 0.), (1., 0.), (1., 0.0001)])));
+}
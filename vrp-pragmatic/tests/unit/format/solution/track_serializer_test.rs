@@ -0,0 +1,76 @@
+use super::*;
+use crate::helpers::*;
+
+fn create_test_solution() -> ApiSolution {
+    let mut tour = TourBuilder::default()
+        .stops(vec![
+            StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![1]).build_departure(),
+            StopBuilder::default()
+                .coordinate((1., 0.))
+                .schedule_stamp(1., 2.)
+                .load(vec![0])
+                .build_single("job<1>", "delivery"),
+            StopBuilder::default().coordinate((0., 0.)).schedule_stamp(3., 3.).load(vec![0]).build_arrival(),
+        ])
+        .build();
+    if let Stop::Point(point) = &mut tour.stops[1] {
+        point.geometry = Some(encode_polyline(&[(0., 0.), (0.5, 0.5), (1., 0.)]));
+    }
+
+    SolutionBuilder::default().tour(tour).build()
+}
+
+fn serialize(
+    solution: &ApiSolution,
+    serializer: fn(&ApiSolution, &mut BufWriter<Vec<u8>>) -> Result<(), Error>,
+) -> String {
+    let mut writer = BufWriter::new(Vec::new());
+    serializer(solution, &mut writer).unwrap();
+
+    String::from_utf8(writer.into_inner().unwrap()).unwrap()
+}
+
+#[test]
+fn can_serialize_solution_as_kml() {
+    let kml = serialize(&create_test_solution(), serialize_solution_as_kml);
+
+    assert!(kml.starts_with(r#"<?xml version="1.0" encoding="UTF-8"?>"#));
+    assert!(kml.contains(r#"<Style id="tour0">"#));
+    assert!(kml.contains("<color>ff4b19e6</color>"));
+    assert_eq!(kml.matches("<Point>").count(), 3);
+    assert!(kml.contains("<Point><coordinates>0,1</coordinates></Point>"));
+    assert!(kml.contains("job&lt;1&gt; (delivery)"));
+    assert!(kml.contains("<coordinates>0,0 0.5,0.5 0,1 0,0</coordinates>"));
+    assert!(kml.trim_end().ends_with("</kml>"));
+}
+
+#[test]
+fn can_serialize_solution_as_gpx() {
+    let gpx = serialize(&create_test_solution(), serialize_solution_as_gpx);
+
+    assert!(gpx.contains(r#"<gpx version="1.1""#));
+    assert_eq!(gpx.matches("<rtept ").count(), 3);
+    assert!(gpx.contains(r#"<rtept lat="1" lon="0">"#));
+    assert!(gpx.contains("<gpx_style:color>E6194B</gpx_style:color>"));
+    assert_eq!(gpx.matches("<trkpt ").count(), 4);
+    assert!(gpx.contains(r#"<trkpt lat="0.5" lon="0.5"/>"#));
+    assert!(gpx.trim_end().ends_with("</gpx>"));
+}
+
+#[test]
+fn can_reject_export_of_reference_locations() {
+    let tour = TourBuilder::default()
+        .stops(vec![StopBuilder::default().reference(0).schedule_stamp(0., 0.).load(vec![0]).build_departure()])
+        .build();
+    let solution = SolutionBuilder::default().tour(tour).build();
+    let mut writer = BufWriter::new(Vec::new());
+
+    let result = serialize_solution_as_kml(&solution, &mut writer);
+
+    assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidData);
+}
+
+#[test]
+fn can_escape_xml() {
+    assert_eq!(escape_xml(r#"a&b<c>"d'"#), "a&amp;b&lt;c&gt;&quot;d&apos;");
+}
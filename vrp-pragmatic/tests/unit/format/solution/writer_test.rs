@@ -1,11 +1,14 @@
 use crate::format::problem::*;
-use crate::format::solution::solution_writer::create_tour;
+use crate::format::solution::solution_writer::{create_tour, get_api_lower_bound};
 use crate::format::solution::*;
 use crate::helpers::*;
 use std::sync::Arc;
 use vrp_core::construction::enablers::ReservedTimeSpan;
 use vrp_core::models::common::{TimeSpan, TimeWindow};
 use vrp_core::models::examples::create_example_problem;
+use vrp_core::prelude::Float;
+use vrp_core::rosomaxa::evolution::TelemetryMetrics;
+use vrp_core::solver::processing::COST_LOWER_BOUND_PROPERTY;
 
 type DomainProblem = vrp_core::models::Problem;
 type DomainActivity = vrp_core::models::solution::Activity;
@@ -97,6 +100,7 @@ fn can_merge_activities_with_same_location_in_one_stop() {
             distance: 10,
             duration: 12,
             times: Timing { driving: 10, serving: 2, ..Timing::default() },
+            costs: CostBreakdown { fixed: 10., distance: 10., driving: 10., serving: 2., ..CostBreakdown::default() },
         }
     );
     assert_eq!(solution.tours.len(), 1);
@@ -208,3 +212,44 @@ fn can_merge_required_break_on_stop_arrival_time_properly() {
     assert_eq!(tour.stops.len(), 3);
     assert_eq!(get_ids_from_tour(&tour).into_iter().flatten().filter(|id| id == "break").count(), 1);
 }
+
+parameterized_test! {can_create_lower_bound_extras, (lower_bound, has_unassigned, expected), {
+    can_create_lower_bound_extras_impl(lower_bound, has_unassigned, expected);
+}}
+
+can_create_lower_bound_extras! {
+    case01_with_gap: (Some(80.), false, Some(LowerBound { cost: 80., gap: Some(20.) })),
+    case02_without_bound: (None, false, None),
+    case03_with_unassigned: (Some(80.), true, Some(LowerBound { cost: 80., gap: None })),
+}
+
+fn can_create_lower_bound_extras_impl(lower_bound: Option<Float>, has_unassigned: bool, expected: Option<LowerBound>) {
+    let metrics = TelemetryMetrics {
+        duration: 1,
+        generations: 10,
+        speed: 10.,
+        evolution: vec![],
+        termination: None,
+        best_fitness: vec![],
+        operators: vec![],
+        memory_peak: None,
+        memory_shrinks: 0,
+        properties: lower_bound.map(|cost| (COST_LOWER_BOUND_PROPERTY.to_string(), cost)).into_iter().collect(),
+    };
+    let unassigned = has_unassigned.then(|| vec![UnassignedJob { job_id: "job1".to_string(), reasons: vec![] }]);
+    let mut solution = SolutionBuilder::default().unassigned(unassigned).build();
+    solution.statistic.cost = 100.;
+
+    let result = get_api_lower_bound(&solution, Some(&metrics));
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_serialize_lower_bound_extras() {
+    let extras = Extras { metrics: None, lower_bound: Some(LowerBound { cost: 80., gap: Some(20.) }), features: None };
+
+    let value = serde_json::to_value(&extras).unwrap();
+
+    assert_eq!(value, serde_json::json!({ "lowerBound": { "cost": 80., "gap": 20. } }));
+}
@@ -0,0 +1,93 @@
+use super::*;
+use crate::helpers::*;
+
+parameterized_test! {can_parse_date_time, (time, expected), {
+    can_parse_date_time_impl(time, expected);
+}}
+
+can_parse_date_time! {
+    case01_utc: ("1970-01-01T00:01:00Z", Some(60.)),
+    case02_offset: ("1970-01-01T02:01:00+02:00", Some(60.)),
+    case03_negative_offset: ("1969-12-31T23:01:00-01:00", Some(60.)),
+    case04_epoch_seconds: ("60", Some(60.)),
+    case05_epoch_seconds_fraction: ("60.5", Some(60.5)),
+    case06_date_only: ("1970-01-01", None),
+    case07_invalid: ("abc", None),
+    case08_two_dots: ("6.0.0", None),
+}
+
+fn can_parse_date_time_impl(time: &str, expected: Option<Float>) {
+    let result = parse_date_time(time).ok();
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_format_time, (format, expected), {
+    can_format_time_impl(format, expected);
+}}
+
+can_format_time! {
+    case01_utc: (TimeFormat::default(), "1970-01-01T00:01:00Z"),
+    case02_offset: (TimeFormat::Rfc3339 { offset: 7200 }, "1970-01-01T02:01:00+02:00"),
+    case03_epoch_seconds: (TimeFormat::EpochSeconds, "60"),
+}
+
+fn can_format_time_impl(format: TimeFormat, expected: &str) {
+    assert_eq!(format.format(60.), expected);
+}
+
+parameterized_test! {can_detect_time_format, (start, timezone, expected), {
+    can_detect_time_format_impl(start, timezone, expected);
+}}
+
+can_detect_time_format! {
+    case01_utc: ("1970-01-01T00:00:00Z", None, TimeFormat::default()),
+    case02_offset: ("1970-01-01T02:00:00+02:00", None, TimeFormat::Rfc3339 { offset: 7200 }),
+    case03_epoch_seconds: ("0", None, TimeFormat::EpochSeconds),
+    case04_date_only: ("1970-01-01", Some("-03:30"), TimeFormat::Rfc3339 { offset: -12600 }),
+}
+
+fn can_detect_time_format_impl(start: &str, timezone: Option<&str>, expected: TimeFormat) {
+    let problem = create_problem_with_start(start, timezone);
+
+    assert_eq!(TimeFormat::detect(&problem), expected);
+}
+
+parameterized_test! {can_normalize_date_only_times, (timezone, expected), {
+    can_normalize_date_only_times_impl(timezone, expected);
+}}
+
+can_normalize_date_only_times! {
+    case01_default: (None, Ok("1970-01-02T00:00:00Z")),
+    case02_utc: (Some("Z"), Ok("1970-01-02T00:00:00Z")),
+    case03_offset: (Some("+02:00"), Ok("1970-01-02T00:00:00+02:00")),
+    case04_invalid: (Some("Europe/Berlin"), Err("E0005")),
+    case05_out_of_range: (Some("+30:00"), Err("E0005")),
+}
+
+fn can_normalize_date_only_times_impl(timezone: Option<&str>, expected: Result<&str, &str>) {
+    let problem = create_problem_with_start("1970-01-02", timezone);
+
+    let result = normalize_times(problem)
+        .map(|problem| problem.fleet.vehicles[0].shifts[0].start.earliest.clone())
+        .map_err(|err| err.code);
+
+    assert_eq!(result, expected.map(str::to_string).map_err(str::to_string));
+}
+
+fn create_problem_with_start(start: &str, timezone: Option<&str>) -> Problem {
+    Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: ShiftStart { earliest: start.to_string(), latest: None, location: (0., 0.).to_loc() },
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        timezone: timezone.map(|timezone| timezone.to_string()),
+        ..create_empty_problem()
+    }
+}
@@ -0,0 +1,102 @@
+use super::*;
+use crate::helpers::*;
+
+fn create_problem(jobs: Vec<Job>, fleet: Fleet) -> Problem {
+    Problem { plan: Plan { jobs, ..create_empty_plan() }, fleet, objectives: None, timezone: None }
+}
+
+fn get_paths(diagnostics: &[Diagnostic], code: &str) -> Vec<String> {
+    diagnostics.iter().filter(|diagnostic| diagnostic.code == code).map(|diagnostic| diagnostic.path.clone()).collect()
+}
+
+#[test]
+fn can_return_no_diagnostics_for_valid_problem() {
+    let problem = create_problem(vec![create_delivery_job("job1", (1., 0.))], create_default_fleet());
+
+    let diagnostics = get_problem_diagnostics(&problem, None);
+
+    assert!(diagnostics.is_empty());
+}
+
+#[test]
+fn can_point_to_duplicated_jobs() {
+    let problem = create_problem(
+        vec![
+            create_delivery_job("job1", (1., 0.)),
+            create_delivery_job("job2", (2., 0.)),
+            create_delivery_job("job1", (3., 0.)),
+        ],
+        create_default_fleet(),
+    );
+
+    let diagnostics = get_problem_diagnostics(&problem, None);
+
+    assert_eq!(get_paths(&diagnostics, "E1100"), vec!["/plan/jobs/0", "/plan/jobs/2"]);
+    assert!(diagnostics.iter().all(|diagnostic| diagnostic.severity == Severity::Error));
+}
+
+#[test]
+fn can_point_to_duplicated_vehicle_ids() {
+    let mut vehicle = create_default_vehicle("vehicle");
+    vehicle.vehicle_ids = vec!["v1".to_string(), "v2".to_string(), "v1".to_string()];
+    let problem = create_problem(
+        vec![create_delivery_job("job1", (1., 0.))],
+        Fleet { vehicles: vec![vehicle], ..create_default_fleet() },
+    );
+
+    let diagnostics = get_problem_diagnostics(&problem, None);
+
+    assert_eq!(
+        get_paths(&diagnostics, "E1301"),
+        vec!["/fleet/vehicles/0/vehicleIds/0", "/fleet/vehicles/0/vehicleIds/2"]
+    );
+}
+
+#[test]
+fn can_point_to_vehicle_with_unknown_profile() {
+    let mut vehicle = create_default_vehicle("vehicle2");
+    vehicle.profile = create_vehicle_profile_with_name("truck");
+    let problem = create_problem(
+        vec![create_delivery_job("job1", (1., 0.))],
+        Fleet { vehicles: vec![create_default_vehicle("vehicle1"), vehicle], ..create_default_fleet() },
+    );
+
+    let diagnostics = get_problem_diagnostics(&problem, None);
+
+    assert_eq!(get_paths(&diagnostics, "E1505"), vec!["/fleet/vehicles/1/profile/matrix"]);
+}
+
+#[test]
+fn can_warn_about_unused_profiles() {
+    let mut fleet = create_default_fleet();
+    fleet.profiles.push(MatrixProfile { name: "truck".to_string(), speed: None, restrictions: None });
+    let problem = create_problem(vec![create_delivery_job("job1", (1., 0.))], fleet);
+
+    let diagnostics = get_problem_diagnostics(&problem, None);
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].code, "W1500");
+    assert_eq!(diagnostics[0].severity, Severity::Warning);
+    assert_eq!(diagnostics[0].path, "/fleet/profiles/1");
+}
+
+#[test]
+fn can_point_to_section_when_element_cannot_be_located() {
+    let problem = create_problem(
+        vec![create_delivery_job("job1", (1., 0.))],
+        Fleet { profiles: vec![], ..create_default_fleet() },
+    );
+
+    let diagnostics = get_problem_diagnostics(&problem, None);
+
+    assert_eq!(get_paths(&diagnostics, "E1501"), vec!["/fleet/profiles"]);
+}
+
+#[test]
+fn can_serialize_diagnostic() {
+    let diagnostic = Diagnostic::from(FormatError::new("E0000".to_string(), "cause".to_string(), "action".to_string()));
+
+    let json = serde_json::to_string(&diagnostic).unwrap();
+
+    assert_eq!(json, r#"{"code":"E0000","severity":"error","path":"","cause":"cause","action":"action"}"#);
+}
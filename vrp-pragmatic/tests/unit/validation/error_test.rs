@@ -0,0 +1,67 @@
+use super::*;
+use crate::format::problem::*;
+use crate::format::{CoordIndex, FormatError};
+use crate::helpers::*;
+use crate::validation::ValidationContext;
+
+fn create_error(code: &str) -> FormatError {
+    FormatError::new(code.to_string(), "some cause".to_string(), "some action".to_string())
+}
+
+parameterized_test! {can_map_format_error_to_validation_error, (code, expected), {
+    can_map_format_error_to_validation_error_impl(code, expected);
+}}
+
+can_map_format_error_to_validation_error! {
+    case01: ("E1100", Some("jobs")),
+    case02: ("E1205", Some("relations")),
+    case03: ("E1306", Some("vehicles")),
+    case04: ("E1500", Some("routing")),
+    case05: ("E1612", Some("objectives")),
+    case06: ("E0000", None),
+    case07: ("W1500", None),
+    case08: ("E1", None),
+}
+
+fn can_map_format_error_to_validation_error_impl(code: &str, expected: Option<&str>) {
+    let result = ValidationError::try_from(create_error(code));
+
+    let actual = result.as_ref().ok().map(|err| match err {
+        ValidationError::Jobs(_) => "jobs",
+        ValidationError::Relations(_) => "relations",
+        ValidationError::Vehicles(_) => "vehicles",
+        ValidationError::Routing(_) => "routing",
+        ValidationError::Objectives(_) => "objectives",
+    });
+    assert_eq!(actual, expected);
+    match result {
+        Ok(err) => assert_eq!(FormatError::from(err).code, code),
+        Err(err) => assert_eq!(err.code, code),
+    }
+}
+
+#[test]
+fn can_preserve_display_text_and_source_of_validation_error() {
+    let format_error = create_error("E1104");
+
+    let error = ValidationError::try_from(format_error.clone()).unwrap();
+
+    assert_eq!(error.code(), "E1104");
+    assert_eq!(error.to_string(), format_error.to_string());
+    assert_eq!(error.source().map(|err| err.to_string()), Some(format_error.to_string()));
+}
+
+#[test]
+fn can_get_validation_errors_from_validation_result() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("departure", (1., 0.))], ..create_empty_plan() },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+
+    let errors = ValidationContext::new(&problem, None, &coord_index).validate().unwrap_err().validation_errors();
+
+    assert!(!errors.is_empty());
+    assert!(errors.iter().any(|err| matches!(err, ValidationError::Jobs(err) if err.code == "E1104")));
+}
@@ -1,5 +1,6 @@
 use super::*;
 use crate::helpers::*;
+use vrp_core::prelude::Float;
 
 fn assert_result(code: &str, action: &str, result: Option<FormatError>) {
     assert_eq!(result.clone().map(|err| err.code), Some(code.to_string()));
@@ -73,3 +74,182 @@ fn can_detect_negative_demand() {
 
     assert_result("E1107", "job1", result);
 }
+
+#[test]
+fn can_detect_zero_priority() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                Job { priority: Some(0), ..create_delivery_job("job1", (1., 0.)) },
+                Job { priority: Some(1), ..create_delivery_job("job2", (1., 0.)) },
+            ],
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1108_positive_priority(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    assert_result("E1108", "job1", result);
+}
+
+parameterized_test! {can_detect_invalid_incompatibilities, (categories, expected), {
+    can_detect_invalid_incompatibilities_impl(categories, expected);
+}}
+
+can_detect_invalid_incompatibilities! {
+    case01: (vec!["food", "chemicals"], None),
+    case02: (vec!["food", "food"], Some("E1109".to_string())),
+    case03: (vec!["food"], Some("E1109".to_string())),
+    case04: (vec![], Some("E1109".to_string())),
+}
+
+fn can_detect_invalid_incompatibilities_impl(categories: Vec<&str>, expected: Option<String>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job { category: Some("food".to_string()), ..create_delivery_job("job1", (1., 0.)) }],
+            incompatibilities: Some(vec![JobIncompatibility {
+                categories: categories.into_iter().map(|category| category.to_string()).collect(),
+            }]),
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1109_correct_incompatibilities(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_negative_place_cost, (cost, expected), {
+    can_detect_negative_place_cost_impl(cost, expected);
+}}
+
+can_detect_negative_place_cost! {
+    case01: (None, None),
+    case02: (Some(0.), None),
+    case03: (Some(10.), None),
+    case04: (Some(-1.), Some("E1110".to_string())),
+}
+
+fn can_detect_negative_place_cost_impl(cost: Option<Float>, expected: Option<String>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                deliveries: Some(vec![JobTask {
+                    places: vec![
+                        create_job_place((1., 0.), None),
+                        JobPlace { cost, ..create_job_place((2., 0.), None) },
+                    ],
+                    demand: Some(vec![1]),
+                    order: None,
+                }]),
+                ..create_job("job1")
+            }],
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1110_negative_place_cost(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_crew, (crew, places, group, with_relation, expected), {
+    can_detect_invalid_crew_impl(crew, places, group, with_relation, expected);
+}}
+
+can_detect_invalid_crew! {
+    case01_no_crew: (None, 1, None, false, None),
+    case02_single_member: (Some(1), 2, Some("group1"), true, None),
+    case03_valid_crew: (Some(2), 1, None, false, None),
+    case04_zero_crew: (Some(0), 1, None, false, Some("E1111".to_string())),
+    case05_multiple_places: (Some(2), 2, None, false, Some("E1111".to_string())),
+    case06_with_group: (Some(2), 1, Some("group1"), false, Some("E1111".to_string())),
+    case07_with_relation: (Some(2), 1, None, true, Some("E1111".to_string())),
+}
+
+fn can_detect_invalid_crew_impl(
+    crew: Option<usize>,
+    places: usize,
+    group: Option<&str>,
+    with_relation: bool,
+    expected: Option<String>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                services: Some(vec![JobTask {
+                    places: (0..places).map(|idx| create_job_place((idx as f64, 0.), None)).collect(),
+                    demand: None,
+                    order: None,
+                }]),
+                group: group.map(|group| group.to_string()),
+                crew,
+                ..create_job("job1")
+            }],
+            relations: if with_relation {
+                Some(vec![Relation {
+                    type_field: RelationType::Any,
+                    jobs: vec!["job1".to_string()],
+                    vehicle_id: "my_vehicle_1".to_string(),
+                    shift_index: None,
+                }])
+            } else {
+                None
+            },
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1111_correct_crew(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_skill_levels, (levels, expected), {
+    can_detect_invalid_skill_levels_impl(levels, expected);
+}}
+
+can_detect_invalid_skill_levels! {
+    case01_valid: (vec![("s1", Some(1), Some(3)), ("s2", None, Some(2))], None),
+    case02_min_only: (vec![("s1", Some(2), None)], None),
+    case03_no_levels: (vec![("s1", None, None)], Some("E1112".to_string())),
+    case04_preferred_below_min: (vec![("s1", Some(3), Some(2))], Some("E1112".to_string())),
+    case05_duplicates: (vec![("s1", Some(1), None), ("s1", Some(2), None)], Some("E1112".to_string())),
+}
+
+fn can_detect_invalid_skill_levels_impl(levels: Vec<(&str, Option<usize>, Option<usize>)>, expected: Option<String>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                skills: Some(JobSkills {
+                    all_of: None,
+                    one_of: None,
+                    none_of: None,
+                    levels: Some(
+                        levels
+                            .into_iter()
+                            .map(|(name, min_level, preferred_level)| JobSkillLevel {
+                                name: name.to_string(),
+                                min_level,
+                                preferred_level,
+                            })
+                            .collect(),
+                    ),
+                }),
+                ..create_delivery_job("job1", (1., 0.))
+            }],
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1112_correct_skill_levels(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
@@ -207,3 +207,157 @@ fn can_detect_missing_value_objective_impl(objectives: Option<Vec<Objective>>, e
 
     assert_eq!(result.err().map(|e| e.code), expected);
 }
+
+parameterized_test! {can_detect_priority_objective_mismatch, (objectives, priority, expected), {
+    can_detect_priority_objective_mismatch_impl(objectives, priority, expected);
+}}
+
+can_detect_priority_objective_mismatch! {
+    case01: (Some(vec![StrictPriority, MinimizeCost]), Some(1), None),
+    case02: (Some(vec![StrictPriority, MinimizeCost]), None, Some("E1608".to_string())),
+    case03: (Some(vec![MinimizeUnassigned { breaks: None }, MinimizeCost]), Some(1), Some("E1609".to_string())),
+    case04: (Some(vec![MinimizeUnassigned { breaks: None }, MinimizeCost]), None, None),
+    case05: (None, Some(1), None),
+}
+
+fn can_detect_priority_objective_mismatch_impl(
+    objectives: Option<Vec<Objective>>,
+    priority: Option<usize>,
+    expected: Option<String>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job { deliveries: Some(vec![create_task((1., 0.), None)]), priority, ..create_job("job1") }],
+            ..create_empty_plan()
+        },
+        objectives,
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+    let objectives = get_objectives(&ctx).unwrap_or_default();
+
+    let result = check_e1608_no_jobs_with_priority_objective(&ctx, objectives.as_slice())
+        .and_then(|_| check_e1609_jobs_with_priority_but_no_objective(&ctx, objectives.as_slice()));
+
+    assert_eq!(result.err().map(|e| e.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_tour_overlap_objective, (threshold, has_coordinates, expected), {
+    can_detect_invalid_tour_overlap_objective_impl(threshold, has_coordinates, expected);
+}}
+
+can_detect_invalid_tour_overlap_objective! {
+    case01: (None, true, None),
+    case02: (Some(0.5), true, None),
+    case03: (None, false, Some("E1610".to_string())),
+    case04: (Some(1.), true, Some("E1611".to_string())),
+    case05: (Some(-0.1), true, Some("E1611".to_string())),
+}
+
+fn can_detect_invalid_tour_overlap_objective_impl(
+    threshold: Option<Float>,
+    has_coordinates: bool,
+    expected: Option<String>,
+) {
+    let job =
+        if has_coordinates { create_delivery_job("job1", (1., 0.)) } else { create_delivery_job_with_index("job1", 0) };
+    let problem = Problem {
+        plan: Plan { jobs: vec![job], ..create_empty_plan() },
+        objectives: Some(vec![MinimizeTourOverlap { threshold }, MinimizeCost]),
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+    let objectives = get_objectives(&ctx).unwrap_or_default();
+
+    let result = check_e1610_tour_overlap_without_coordinates(&ctx, objectives.as_slice())
+        .and_then(|_| check_e1611_tour_overlap_threshold(objectives.as_slice()));
+
+    assert_eq!(result.err().map(|e| e.code), expected);
+}
+
+fn create_weighted_tier(objectives: Vec<(Objective, Float)>) -> Objective {
+    WeightedTier {
+        objectives: objectives.into_iter().map(|(objective, weight)| WeightedObjective { objective, weight }).collect(),
+    }
+}
+
+parameterized_test! {can_detect_invalid_weighted_tier, (objectives, expected), {
+    can_detect_invalid_weighted_tier_impl(objectives, expected);
+}}
+
+can_detect_invalid_weighted_tier! {
+    case01_valid: (vec![create_weighted_tier(vec![(MinimizeCost, 1.), (FastService, 10.)])], None),
+    case02_zero_weight: (vec![create_weighted_tier(vec![(MinimizeCost, 1.), (FastService, 0.)])], None),
+    case03_empty: (vec![create_weighted_tier(vec![])], Some(())),
+    case04_negative_weight: (vec![create_weighted_tier(vec![(MinimizeCost, 1.), (FastService, -1.)])], Some(())),
+    case05_nan_weight: (vec![create_weighted_tier(vec![(MinimizeCost, Float::NAN)])], Some(())),
+    case06_nested: (vec![create_weighted_tier(vec![(create_weighted_tier(vec![(MinimizeCost, 1.)]), 1.)])], Some(())),
+}
+
+fn can_detect_invalid_weighted_tier_impl(objectives: Vec<Objective>, expected: Option<()>) {
+    let problem = Problem { objectives: Some(objectives), ..create_empty_problem() };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+    let objectives = get_objectives(&ctx).unwrap();
+
+    let result = check_e1612_weighted_tier(&objectives);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1612".to_string()));
+}
+
+parameterized_test! {can_detect_nested_strict_priority, (objectives, expected), {
+    can_detect_nested_strict_priority_impl(objectives, expected);
+}}
+
+can_detect_nested_strict_priority! {
+    case01_top_level: (vec![StrictPriority, MinimizeCost], None),
+    case02_weighted: (vec![create_weighted_tier(vec![(StrictPriority, 1.), (MinimizeCost, 1.)])], Some(())),
+    case03_multi: (vec![MultiObjective { objectives: vec![StrictPriority, MinimizeCost], strategy: MultiStrategy::Sum }], Some(())),
+}
+
+fn can_detect_nested_strict_priority_impl(objectives: Vec<Objective>, expected: Option<()>) {
+    let objectives = objectives.iter().collect::<Vec<_>>();
+
+    let result = check_e1613_nested_strict_priority(&objectives);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1613".to_string()));
+}
+
+parameterized_test! {can_detect_redundant_max_stops_objective, (max_stops, expected), {
+    can_detect_redundant_max_stops_objective_impl(max_stops, expected);
+}}
+
+can_detect_redundant_max_stops_objective! {
+    case01_with_limit: (Some(2), None),
+    case02_without_limit: (None, Some("E1614".to_string())),
+}
+
+fn can_detect_redundant_max_stops_objective_impl(max_stops: Option<usize>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    max_duration: None,
+                    tour_size: None,
+                    max_stops,
+                    max_total_distance: None,
+                    max_total_driving_time: None,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        objectives: Some(vec![MaxStops, MinimizeCost]),
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+    let objectives = get_objectives(&ctx).unwrap_or_default();
+
+    let result = check_e1614_no_vehicles_with_max_stops_objective(&ctx, objectives.as_slice());
+
+    assert_eq!(result.err().map(|e| e.code), expected);
+}
@@ -197,3 +197,39 @@ fn can_detect_incomplete_multi_job_in_relation_impl(
 
     assert_eq!(result.map(|err| err.code), expected);
 }
+
+parameterized_test! {can_detect_invalid_negative_relation, (relation_type, jobs, vehicle_id, expected), {
+    can_detect_invalid_negative_relation_impl(relation_type,
+        jobs.iter().map(|job| job.to_string()).collect(),
+        vehicle_id.to_string(),
+        expected.map(|result| result.to_string()));
+}}
+
+can_detect_invalid_negative_relation! {
+    case01: (RelationType::NotSameTour, &["job1", "job2"], "", Option::<String>::None),
+    case02: (RelationType::DifferentVehicle, &["job1", "job2"], "", Option::<String>::None),
+    case03: (RelationType::NotSameTour, &["job1", "job1"], "", Some("E1208")),
+    case04: (RelationType::NotSameTour, &["job1", "break"], "", Some("E1208")),
+    case05: (RelationType::DifferentVehicle, &["job1", "job2"], "my_vehicle_1", Some("E1208")),
+}
+
+fn can_detect_invalid_negative_relation_impl(
+    relation_type: RelationType,
+    jobs: Vec<String>,
+    vehicle_id: String,
+    expected: Option<String>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_pickup_delivery_job("job1", (1., 0.), (2., 0.)), create_delivery_job("job2", (3., 0.))],
+            relations: Some(vec![Relation { type_field: relation_type, jobs, vehicle_id, shift_index: None }]),
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+
+    let result = validate_result(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.map(|err| err.code), expected);
+}
@@ -1,4 +1,5 @@
 use super::*;
+use crate::format_time;
 use crate::helpers::*;
 
 #[test]
@@ -6,8 +7,8 @@ fn can_detect_duplicates() {
     let problem = Problem {
         fleet: Fleet {
             profiles: vec![
-                MatrixProfile { name: "my_vehicle".to_string(), speed: None },
-                MatrixProfile { name: "my_vehicle".to_string(), speed: None },
+                MatrixProfile { name: "my_vehicle".to_string(), speed: None, restrictions: None },
+                MatrixProfile { name: "my_vehicle".to_string(), speed: None, restrictions: None },
             ],
             ..create_default_fleet()
         },
@@ -102,7 +103,7 @@ fn can_detect_missing_profile() {
                 VehicleType { profile: create_vehicle_profile_with_name("car"), ..create_default_vehicle_type() },
                 VehicleType { profile: create_vehicle_profile_with_name("truck"), ..create_default_vehicle_type() },
             ],
-            profiles: vec![MatrixProfile { name: "car".to_string(), speed: None }],
+            profiles: vec![MatrixProfile { name: "car".to_string(), speed: None, restrictions: None }],
             ..create_default_fleet()
         },
         ..create_empty_problem()
@@ -114,3 +115,67 @@ fn can_detect_missing_profile() {
 
     assert_eq!(result.err().map(|err| err.code), Some("E1505".to_string()));
 }
+
+parameterized_test! {can_detect_invalid_access_restrictions, (time, expected), {
+    can_detect_invalid_access_restrictions_impl(time, expected);
+}}
+
+can_detect_invalid_access_restrictions! {
+    case01_valid: (vec![format_time(10.), format_time(20.)], None),
+    case02_reversed: (vec![format_time(20.), format_time(10.)], Some("E1506".to_string())),
+    case03_empty: (vec![format_time(10.), format_time(10.)], Some("E1506".to_string())),
+    case04_not_a_window: (vec![format_time(10.)], Some("E1506".to_string())),
+}
+
+fn can_detect_invalid_access_restrictions_impl(time: Vec<String>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            profiles: vec![MatrixProfile {
+                name: "car".to_string(),
+                speed: None,
+                restrictions: Some(vec![AccessRestriction { locations: vec![(1., 0.).to_loc()], time }]),
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+
+    let result = check_e1506_correct_access_restrictions(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_time_dependent_matrices, (matrices, expected), {
+    can_detect_invalid_time_dependent_matrices_impl(matrices, expected);
+}}
+
+can_detect_invalid_time_dependent_matrices! {
+    case01_no_timestamps: (vec![("car", None), ("truck", None)], None),
+    case02_valid: (vec![("car", Some(format_time(0.))), ("car", Some(format_time(3600.)))], None),
+    case03_single_matrix: (vec![("car", Some(format_time(0.)))], Some("E1507".to_string())),
+    case04_duplicates: (vec![("car", Some(format_time(0.))), ("car", Some(format_time(0.)))], Some("E1507".to_string())),
+    case05_mixed: (vec![("car", Some(format_time(0.))), ("car", None)], Some("E1507".to_string())),
+    case06_invalid: (vec![("car", Some(format_time(0.))), ("car", Some("noon".to_string()))], Some("E1507".to_string())),
+}
+
+fn can_detect_invalid_time_dependent_matrices_impl(matrices: Vec<(&str, Option<String>)>, expected: Option<String>) {
+    let problem = create_empty_problem();
+    let matrices = matrices
+        .into_iter()
+        .map(|(profile, timestamp)| Matrix {
+            profile: Some(profile.to_string()),
+            timestamp,
+            travel_times: vec![1; 4],
+            distances: vec![1; 4],
+            error_codes: None,
+        })
+        .collect::<Vec<_>>();
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, Some(&matrices), &coord_index);
+
+    let result = check_e1507_time_dependent_matrices(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
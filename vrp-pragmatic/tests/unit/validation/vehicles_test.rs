@@ -45,7 +45,7 @@ fn can_detect_zero_costs_impl(costs: (Float, Float), expected: Option<String>) {
     let problem = Problem {
         fleet: Fleet {
             vehicles: vec![VehicleType {
-                costs: VehicleCosts { fixed: None, distance, time },
+                costs: VehicleCosts { fixed: None, distance, time, tiers: None },
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()
@@ -137,3 +137,195 @@ fn can_handle_reload_resources_impl(resources: Option<Vec<&str>>, expected: Opti
 
     assert_eq!(result.err().map(|err| err.code), expected);
 }
+
+parameterized_test! {can_detect_invalid_cost_tiers, (tier, expected), {
+    can_detect_invalid_cost_tiers_impl(tier, expected);
+}}
+
+can_detect_invalid_cost_tiers! {
+    case01_duration: ((10., Some(3600.), None), None),
+    case02_distance: ((10., None, Some(100.)), None),
+    case03_both: ((0., Some(3600.), Some(100.)), None),
+    case04_no_limits: ((10., None, None), Some("E1309".to_string())),
+    case05_negative_fixed: ((-1., Some(3600.), None), Some("E1309".to_string())),
+    case06_negative_limit: ((10., Some(3600.), Some(-1.)), Some("E1309".to_string())),
+}
+
+fn can_detect_invalid_cost_tiers_impl(tier: (Float, Option<Float>, Option<Float>), expected: Option<String>) {
+    let (fixed, max_duration, max_distance) = tier;
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                costs: VehicleCosts {
+                    tiers: Some(vec![VehicleCostTier { fixed, max_duration, max_distance }]),
+                    ..create_default_vehicle_costs()
+                },
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1309_vehicle_cost_tiers(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_depot_hours, (times, expected), {
+    can_detect_invalid_depot_hours_impl(times, expected);
+}}
+
+can_detect_invalid_depot_hours! {
+    case01_valid: (vec![(0., 100.)], None),
+    case02_no_times: (vec![], Some("E1310".to_string())),
+    case03_invalid_window: (vec![(100., 0.)], Some("E1310".to_string())),
+    case04_closed_during_shift: (vec![(2000., 3000.)], Some("E1310".to_string())),
+}
+
+fn can_detect_invalid_depot_hours_impl(times: Vec<(Float, Float)>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            depots: Some(vec![Depot {
+                location: (0., 0.).to_loc(),
+                times: times.into_iter().map(|(start, end)| vec![format_time(start), format_time(end)]).collect(),
+            }]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1310_depot_operating_hours(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_cumulative_limits, (limits, expected), {
+    can_detect_invalid_cumulative_limits_impl(limits, expected);
+}}
+
+can_detect_invalid_cumulative_limits! {
+    case01_valid: ((Some(100.), Some(100.)), None),
+    case02_no_limits: ((None, None), None),
+    case03_negative_distance: ((Some(-1.), None), Some("E1311".to_string())),
+    case04_negative_driving_time: ((None, Some(-1.)), Some("E1311".to_string())),
+    case05_infinite_distance: ((Some(Float::INFINITY), None), Some("E1311".to_string())),
+}
+
+fn can_detect_invalid_cumulative_limits_impl(limits: (Option<Float>, Option<Float>), expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    max_duration: None,
+                    tour_size: None,
+                    max_stops: None,
+                    max_total_distance: limits.0,
+                    max_total_driving_time: limits.1,
+                }),
+                ..create_default_vehicle("my_vehicle")
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1311_vehicle_cumulative_limits(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_vehicle_skill_levels, (skills, expected), {
+    can_detect_invalid_vehicle_skill_levels_impl(skills, expected);
+}}
+
+can_detect_invalid_vehicle_skill_levels! {
+    case01_valid: (vec![("s1", None), ("s2", Some(2))], None),
+    case02_zero_level: (vec![("s1", Some(0))], Some("E1312".to_string())),
+    case03_duplicates: (vec![("s1", None), ("s1", Some(2))], Some("E1312".to_string())),
+}
+
+fn can_detect_invalid_vehicle_skill_levels_impl(skills: Vec<(&str, Option<usize>)>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                skills: Some(
+                    skills
+                        .into_iter()
+                        .map(|(name, level)| match level {
+                            Some(level) => VehicleSkill::Level { name: name.to_string(), level },
+                            None => name.into(),
+                        })
+                        .collect(),
+                ),
+                ..create_default_vehicle("my_vehicle")
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1312_vehicle_skill_levels(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_capacity_change, (capacity, profile, has_reloads, expected), {
+    can_detect_invalid_capacity_change_impl(capacity, profile, has_reloads, expected);
+}}
+
+can_detect_invalid_capacity_change! {
+    case01_valid: (vec![5], None, false, None),
+    case02_valid_with_profile: (vec![5], Some("car"), false, None),
+    case03_exceeds_capacity: (vec![15], None, false, Some("E1313".to_string())),
+    case04_negative: (vec![-1], None, false, Some("E1313".to_string())),
+    case05_wrong_dimensions: (vec![5, 5], None, false, Some("E1313".to_string())),
+    case06_unknown_profile: (vec![5], Some("truck"), false, Some("E1313".to_string())),
+    case07_with_reloads: (vec![5], None, true, Some("E1313".to_string())),
+}
+
+fn can_detect_invalid_capacity_change_impl(
+    capacity: Vec<i32>,
+    profile: Option<&str>,
+    has_reloads: bool,
+    expected: Option<String>,
+) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    reloads: if has_reloads {
+                        Some(vec![VehicleReload {
+                            location: (0., 0.).to_loc(),
+                            duration: 2.0,
+                            times: None,
+                            tag: None,
+                            resource_id: None,
+                        }])
+                    } else {
+                        None
+                    },
+                    capacity_change: Some(VehicleCapacityChange {
+                        location: (1., 0.).to_loc(),
+                        duration: 2.,
+                        times: None,
+                        capacity,
+                        profile: profile.map(|matrix| VehicleProfile { matrix: matrix.to_string(), scale: None }),
+                        tag: None,
+                    }),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle("my_vehicle")
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1313_vehicle_capacity_change(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
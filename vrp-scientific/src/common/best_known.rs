@@ -0,0 +1,127 @@
+#[cfg(test)]
+#[path = "../../tests/unit/common/best_known_test.rs"]
+mod best_known_test;
+
+use super::read_init_solution;
+use std::io::{BufReader, Read};
+use std::sync::Arc;
+use vrp_core::construction::heuristics::InsertionContext;
+use vrp_core::prelude::*;
+
+/// Contains metrics of the solution compared with the best known one.
+#[derive(Clone, Debug)]
+pub struct BestKnownComparison {
+    /// Amount of tours in the solution and in the best known solution.
+    pub tours: (usize, usize),
+    /// Total cost of the solution and of the best known solution.
+    pub cost: (Float, Float),
+}
+
+impl BestKnownComparison {
+    /// Returns relative cost gap in percents: positive value means that the solution is worse than the best known.
+    pub fn cost_gap(&self) -> Float {
+        let (actual, best_known) = self.cost;
+
+        get_cost_gap(actual, best_known)
+    }
+}
+
+/// Represents a best known solution of the benchmark instance.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BestKnownSolution {
+    /// Amount of tours if it is a part of the objective, e.g. for hierarchical objective of Solomon's problems.
+    pub tours: Option<usize>,
+    /// Total cost.
+    pub cost: Float,
+}
+
+impl BestKnownSolution {
+    /// Returns relative cost gap in percents: positive value means that the given cost is worse than the best known.
+    pub fn cost_gap(&self, cost: Float) -> Float {
+        get_cost_gap(cost, self.cost)
+    }
+}
+
+/// Returns the best known solution of the instance from the embedded registry. The registry contains widely used
+/// instances of the supported benchmark sets, instance name is case insensitive, e.g. `C101`, `X-n101-k25`, `tai75a`.
+pub fn get_best_known_solution(name: &str) -> Option<BestKnownSolution> {
+    let name = name.trim();
+
+    BEST_KNOWN_REGISTRY
+        .iter()
+        .find(|(instance, ..)| instance.eq_ignore_ascii_case(name))
+        .map(|&(_, tours, cost)| BestKnownSolution { tours, cost })
+}
+
+/// Compares the solution with the best known one which is read from the buffer in the same text format as the
+/// solution is written, e.g. benchmark best known solution files.
+pub fn compare_with_best_known<R: Read>(
+    reader: BufReader<R>,
+    problem: Arc<Problem>,
+    solution: &Solution,
+    environment: Arc<Environment>,
+) -> GenericResult<BestKnownComparison> {
+    let best_known = read_init_solution(reader, problem.clone(), environment.random.clone())?;
+
+    if !best_known.unassigned.is_empty() {
+        return Err(format!("best known solution has {} unassigned jobs", best_known.unassigned.len()).into());
+    }
+
+    let tours = best_known.routes.len();
+    let cost = InsertionContext::new_from_solution(problem, (best_known, None), environment)
+        .get_total_cost()
+        .unwrap_or_default();
+
+    Ok(BestKnownComparison { tours: (solution.routes.len(), tours), cost: (solution.cost, cost) })
+}
+
+fn get_cost_gap(actual: Float, best_known: Float) -> Float {
+    if best_known == 0. { 0. } else { (actual - best_known) / best_known * 100. }
+}
+
+/// Keeps instance name, amount of tours (if used in hierarchical objective) and cost of best known solutions.
+#[rustfmt::skip]
+const BEST_KNOWN_REGISTRY: &[(&str, Option<usize>, Float)] = &[
+    // Solomon: hierarchical objective (tours, then distance)
+    ("C101", Some(10), 828.94), ("C102", Some(10), 828.94), ("C103", Some(10), 828.06), ("C104", Some(10), 824.78),
+    ("C105", Some(10), 828.94), ("C106", Some(10), 828.94), ("C107", Some(10), 828.94), ("C108", Some(10), 828.94),
+    ("C109", Some(10), 828.94),
+    ("C201", Some(3), 591.56), ("C202", Some(3), 591.56), ("C203", Some(3), 591.17), ("C204", Some(3), 590.60),
+    ("C205", Some(3), 588.88), ("C206", Some(3), 588.49), ("C207", Some(3), 588.29), ("C208", Some(3), 588.32),
+    ("R101", Some(19), 1650.80), ("R102", Some(17), 1486.12), ("R103", Some(13), 1292.68), ("R104", Some(9), 1007.31),
+    ("R105", Some(14), 1377.11), ("R106", Some(12), 1252.03), ("R107", Some(10), 1104.66), ("R108", Some(9), 960.88),
+    ("R109", Some(11), 1194.73), ("R110", Some(10), 1118.84), ("R111", Some(10), 1096.72), ("R112", Some(9), 982.14),
+    ("R201", Some(4), 1252.37), ("R202", Some(3), 1191.70), ("R203", Some(3), 939.50), ("R204", Some(2), 825.52),
+    ("R205", Some(3), 994.42), ("R206", Some(3), 906.14), ("R207", Some(2), 890.61), ("R208", Some(2), 726.82),
+    ("R209", Some(3), 909.16), ("R210", Some(3), 939.37), ("R211", Some(2), 885.71),
+    ("RC101", Some(14), 1696.95), ("RC102", Some(12), 1554.75), ("RC103", Some(11), 1261.67),
+    ("RC104", Some(10), 1135.48), ("RC105", Some(13), 1629.44), ("RC106", Some(11), 1424.73),
+    ("RC107", Some(11), 1230.48), ("RC108", Some(10), 1139.82),
+    ("RC201", Some(4), 1406.94), ("RC202", Some(3), 1365.64), ("RC203", Some(3), 1049.62), ("RC204", Some(3), 798.46),
+    ("RC205", Some(4), 1297.65), ("RC206", Some(3), 1146.32), ("RC207", Some(3), 1061.14), ("RC208", Some(3), 828.14),
+    // Augerat A set: rounded distances
+    ("A-n32-k5", None, 784.), ("A-n33-k5", None, 661.), ("A-n33-k6", None, 742.), ("A-n34-k5", None, 778.),
+    ("A-n36-k5", None, 799.), ("A-n37-k5", None, 669.), ("A-n37-k6", None, 949.), ("A-n38-k5", None, 730.),
+    ("A-n39-k5", None, 822.), ("A-n39-k6", None, 831.), ("A-n44-k6", None, 937.), ("A-n45-k6", None, 944.),
+    ("A-n45-k7", None, 1146.), ("A-n46-k7", None, 914.), ("A-n48-k7", None, 1073.), ("A-n53-k7", None, 1010.),
+    ("A-n54-k7", None, 1167.), ("A-n55-k9", None, 1073.), ("A-n60-k9", None, 1354.), ("A-n61-k9", None, 1034.),
+    ("A-n62-k8", None, 1288.), ("A-n63-k9", None, 1616.), ("A-n63-k10", None, 1314.), ("A-n64-k9", None, 1401.),
+    ("A-n65-k9", None, 1174.), ("A-n69-k9", None, 1159.), ("A-n80-k10", None, 1763.),
+    // Uchoa et al. X set: rounded distances
+    ("X-n101-k25", None, 27591.), ("X-n106-k14", None, 26362.), ("X-n110-k13", None, 14971.),
+    ("X-n115-k10", None, 12747.), ("X-n120-k6", None, 13332.), ("X-n125-k30", None, 55539.),
+    ("X-n129-k18", None, 28940.), ("X-n134-k13", None, 10916.), ("X-n139-k10", None, 13590.),
+    ("X-n143-k7", None, 15700.),
+    // Christofides, Mingozzi and Toth: exact distances
+    ("CMT1", None, 524.61), ("CMT2", None, 835.26), ("CMT3", None, 826.14), ("CMT4", None, 1028.42),
+    ("CMT5", None, 1291.29), ("CMT6", None, 555.43), ("CMT7", None, 909.68), ("CMT8", None, 865.94),
+    ("CMT9", None, 1162.55), ("CMT10", None, 1395.85), ("CMT11", None, 1042.11), ("CMT12", None, 819.56),
+    ("CMT13", None, 1541.14), ("CMT14", None, 866.37),
+    // Taillard: exact distances
+    ("tai75a", None, 1618.36), ("tai75b", None, 1344.62), ("tai75c", None, 1291.01), ("tai75d", None, 1365.42),
+    ("tai100a", None, 2041.34), ("tai100b", None, 1939.90), ("tai100c", None, 1406.20), ("tai100d", None, 1580.46),
+    ("tai150a", None, 3055.23), ("tai150b", None, 2656.47), ("tai150c", None, 2341.84), ("tai150d", None, 2645.39),
+    // Cordeau multi depot: exact distances
+    ("p01", None, 576.87), ("p02", None, 473.53), ("p03", None, 641.19), ("p04", None, 1001.59),
+    ("p05", None, 750.03), ("p06", None, 876.50), ("p07", None, 885.80),
+];
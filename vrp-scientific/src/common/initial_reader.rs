@@ -12,7 +12,8 @@ use vrp_core::models::problem::*;
 use vrp_core::models::solution::{Activity, Registry, Route, Tour};
 use vrp_core::prelude::*;
 
-/// Reads initial solution from a buffer.
+/// Reads initial solution from a buffer. Only lines started with `Route` are considered, so best known solution
+/// files with header lines are supported. Route activities are specified by ids of single jobs, e.g. customer ids.
 /// NOTE: Solution feasibility is not checked.
 pub fn read_init_solution<R: Read>(
     mut reader: BufReader<R>,
@@ -29,27 +30,33 @@ pub fn read_init_solution<R: Read>(
         telemetry: None,
     };
 
-    let mut not_used_jobs = problem.jobs.all().iter().collect::<HashSet<_>>();
+    let mut not_used_jobs = problem.jobs.all().iter().cloned().collect::<HashSet<_>>();
+    let id_map = problem
+        .jobs
+        .all()
+        .iter()
+        .flat_map(|job| match job {
+            Job::Single(single) => vec![single.clone()],
+            Job::Multi(multi) => multi.jobs.clone(),
+        })
+        .fold(HashMap::<String, Arc<Single>>::new(), |mut acc, single| {
+            acc.insert(single.dimens.get_job_id().unwrap().to_string(), single);
+            acc
+        });
 
     loop {
         match read_line(&mut reader, &mut buffer) {
             Ok(read) if read > 0 => {
                 let route: Vec<_> = buffer.split(':').collect();
-                if route.len() != 2 {
+                if route.len() != 2 || !route[0].trim_start().to_lowercase().starts_with("route") {
                     continue;
                 }
 
-                let id_map = problem.jobs.all().iter().fold(HashMap::<String, Arc<Single>>::new(), |mut acc, job| {
-                    let single = job.to_single().clone();
-                    acc.insert(single.dimens.get_job_id().unwrap().to_string(), single);
-                    acc
-                });
-
-                let actor = solution.registry.next().next().unwrap();
+                let actor = solution.registry.next().next().ok_or("not enough vehicles to read initial solution")?;
                 let mut tour = Tour::new(&actor);
 
-                route.last().unwrap().split_whitespace().for_each(|id| {
-                    let single = id_map.get(id).unwrap();
+                route.last().unwrap().split_whitespace().try_for_each(|id| {
+                    let single = id_map.get(id).ok_or_else(|| format!("cannot find job with id '{id}'"))?;
                     let place_idx = 0;
                     let place = &single.places[place_idx];
                     tour.insert_last(Activity {
@@ -64,8 +71,10 @@ pub fn read_init_solution<R: Read>(
                         commute: None,
                     });
 
-                    not_used_jobs.remove(&Job::Single(single.clone()));
-                });
+                    not_used_jobs.remove(&Multi::roots(single).map_or_else(|| Job::Single(single.clone()), Job::Multi));
+
+                    Ok::<_, GenericError>(())
+                })?;
 
                 solution.registry.use_actor(&actor);
                 solution.routes.push(Route { actor, tour });
@@ -81,7 +90,7 @@ pub fn read_init_solution<R: Read>(
         }
     }
 
-    solution.unassigned = not_used_jobs.into_iter().map(|job| (job.clone(), UnassignmentInfo::Unknown)).collect();
+    solution.unassigned = not_used_jobs.into_iter().map(|job| (job, UnassignmentInfo::Unknown)).collect();
 
     Ok(solution)
 }
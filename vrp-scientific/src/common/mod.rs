@@ -11,9 +11,13 @@ pub(crate) use self::text_writer::*;
 mod initial_reader;
 pub use self::initial_reader::read_init_solution;
 
+mod best_known;
+pub use self::best_known::{BestKnownComparison, BestKnownSolution, compare_with_best_known, get_best_known_solution};
+
 mod routing;
 
-pub use self::routing::{CoordIndex, CoordIndexExtraProperty};
+pub(crate) use self::routing::create_matrix_transport;
+pub use self::routing::{CoordIndex, CoordIndexExtraProperty, DistanceRounding};
 
 use vrp_core::models::Extras;
 use vrp_core::solver::{HeuristicFilterExtraProperty, HeuristicFilterFn};
@@ -17,12 +17,13 @@ custom_extra_property!(pub CoordIndex typeof CoordIndex);
 #[derive(Clone, Default)]
 pub struct CoordIndex {
     /// Keeps track of locations.
-    pub locations: Vec<(i32, i32)>,
+    pub locations: Vec<(Float, Float)>,
 }
 
 impl CoordIndex {
     /// Adds location to index.
-    pub fn collect(&mut self, location: (i32, i32)) -> Location {
+    pub fn collect<T: Into<Float>>(&mut self, location: (T, T)) -> Location {
+        let location = (location.0.into(), location.1.into());
         match self.locations.iter().position(|l| l.0 == location.0 && l.1 == location.1) {
             Some(position) => position,
             _ => {
@@ -37,6 +38,15 @@ impl CoordIndex {
         &self,
         is_rounded: bool,
         logger: &InfoLogger,
+    ) -> Result<Arc<dyn TransportCost>, GenericError> {
+        self.create_transport_with_rounding(is_rounded.into(), logger)
+    }
+
+    /// Creates transport (fleet index) using given distance rounding convention.
+    pub fn create_transport_with_rounding(
+        &self,
+        rounding: DistanceRounding,
+        logger: &InfoLogger,
     ) -> Result<Arc<dyn TransportCost>, GenericError> {
         Timer::measure_duration_with_callback(
             || {
@@ -48,11 +58,9 @@ impl CoordIndex {
                     .iter()
                     .flat_map(|&(x1, y1)| {
                         self.locations.iter().map(move |&(x2, y2)| {
-                            let x = x1 as Float - x2 as Float;
-                            let y = y1 as Float - y2 as Float;
-                            let value = (x * x + y * y).sqrt();
-
-                            if is_rounded { value.round() } else { value }
+                            let x = x1 - x2;
+                            let y = y1 - y2;
+                            rounding.apply((x * x + y * y).sqrt())
                         })
                     })
                     .collect::<Vec<Float>>();
@@ -68,6 +76,67 @@ impl CoordIndex {
     }
 }
 
+/// Specifies how distances calculated from coordinates are rounded. Benchmark sets use different conventions
+/// and the same one has to be used to compare a solution cost with the best known one.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DistanceRounding {
+    /// Distances are not rounded, e.g. Christofides, Golden and Taillard sets.
+    Exact,
+    /// Distances are rounded to the nearest integer, e.g. Augerat and Uchoa et al. sets.
+    Round,
+    /// Distances are truncated to integer.
+    Truncate,
+}
+
+impl DistanceRounding {
+    /// Applies rounding to the distance value.
+    pub fn apply(&self, value: Float) -> Float {
+        match self {
+            DistanceRounding::Exact => value,
+            DistanceRounding::Round => value.round(),
+            DistanceRounding::Truncate => value.trunc(),
+        }
+    }
+
+    /// Returns rounding convention used in the literature for the benchmark instance with given name.
+    pub fn from_instance_name(name: &str) -> Option<Self> {
+        const EXACT: &[&str] = &["cmt", "golden", "tai"];
+        const ROUND: &[&str] = &["a-n", "b-n", "e-n", "f-n", "m-n", "p-n", "x-n"];
+
+        let name = name.trim().to_lowercase();
+        let has_prefix = |prefixes: &[&str]| prefixes.iter().any(|prefix| name.starts_with(prefix));
+
+        if has_prefix(EXACT) {
+            Some(DistanceRounding::Exact)
+        } else if has_prefix(ROUND) {
+            Some(DistanceRounding::Round)
+        } else {
+            None
+        }
+    }
+}
+
+impl From<bool> for DistanceRounding {
+    fn from(is_rounded: bool) -> Self {
+        if is_rounded { DistanceRounding::Round } else { DistanceRounding::Exact }
+    }
+}
+
+/// Creates transport from flatten square matrix which values are used as distances and durations.
+pub(crate) fn create_matrix_transport(
+    values: Vec<Float>,
+    logger: &InfoLogger,
+) -> Result<Arc<dyn TransportCost>, GenericError> {
+    Timer::measure_duration_with_callback(
+        || {
+            let transport: Arc<dyn TransportCost> = Arc::new(SingleDataTransportCost::new(values)?);
+
+            Ok(transport)
+        },
+        |duration| (logger)(format!("fleet index created in {}ms", duration.as_millis()).as_str()),
+    )
+}
+
 /// Represents a transport cost which has the same distances as durations and single profile.
 struct SingleDataTransportCost {
     size: usize,
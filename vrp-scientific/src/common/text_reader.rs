@@ -1,13 +1,17 @@
 use std::io::prelude::*;
 use std::io::{BufReader, Read};
 use std::sync::Arc;
+use vrp_core::construction::enablers::create_typed_actor_groups;
 use vrp_core::construction::features::*;
+use vrp_core::custom_dimension;
 use vrp_core::models::common::*;
 use vrp_core::models::problem::*;
 use vrp_core::models::*;
 use vrp_core::prelude::{GenericError, InfoLogger};
 use vrp_core::utils::GenericResult;
 
+custom_dimension!(pub(crate) VehicleDurationLimit typeof Duration);
+
 pub(crate) trait TextReader {
     fn read_problem(&mut self, is_rounded: bool) -> GenericResult<Problem> {
         let (jobs, fleet) = self.read_definitions()?;
@@ -52,6 +56,14 @@ pub(crate) fn create_fleet_with_distance_costs(
     location: Location,
     time: TimeWindow,
 ) -> Fleet {
+    create_fleet_with_vehicles(
+        (0..number)
+            .map(|i| Arc::new(create_vehicle_with_distance_costs(&i.to_string(), capacity, location, time.clone())))
+            .collect(),
+    )
+}
+
+pub(crate) fn create_fleet_with_vehicles(vehicles: Vec<Arc<Vehicle>>) -> Fleet {
     Fleet::new(
         vec![Arc::new(Driver {
             costs: Costs {
@@ -64,39 +76,40 @@ pub(crate) fn create_fleet_with_distance_costs(
             dimens: Default::default(),
             details: Default::default(),
         })],
-        (0..number)
-            .map(|i| {
-                let mut dimens = create_dimens_with_id("v", &i.to_string(), |id, dimens| {
-                    dimens.set_vehicle_id(id.to_string());
-                });
-                dimens.set_vehicle_capacity(SingleDimLoad::new(capacity as i32));
-                Arc::new(Vehicle {
-                    profile: Profile::default(),
-                    costs: Costs {
-                        fixed: 0.0,
-                        per_distance: 1.0,
-                        per_driving_time: 0.0,
-                        per_waiting_time: 0.0,
-                        per_service_time: 0.0,
-                    },
-                    dimens,
-                    details: vec![VehicleDetail {
-                        start: Some(VehiclePlace {
-                            location,
-                            time: TimeInterval { earliest: Some(time.start), latest: None },
-                        }),
-                        end: Some(VehiclePlace {
-                            location,
-                            time: TimeInterval { earliest: None, latest: Some(time.end) },
-                        }),
-                    }],
-                })
-            })
-            .collect(),
-        |_| |_| 0,
+        vehicles,
+        // NOTE vehicles with the same start/end places and times are interchangeable
+        |actors| create_typed_actor_groups(actors, |_| String::default()),
     )
 }
 
+pub(crate) fn create_vehicle_with_distance_costs(
+    id: &str,
+    capacity: usize,
+    location: Location,
+    time: TimeWindow,
+) -> Vehicle {
+    let mut dimens = create_dimens_with_id("v", id, |id, dimens| {
+        dimens.set_vehicle_id(id.to_string());
+    });
+    dimens.set_vehicle_capacity(SingleDimLoad::new(capacity as i32));
+
+    Vehicle {
+        profile: Profile::default(),
+        costs: Costs {
+            fixed: 0.0,
+            per_distance: 1.0,
+            per_driving_time: 0.0,
+            per_waiting_time: 0.0,
+            per_service_time: 0.0,
+        },
+        dimens,
+        details: vec![VehicleDetail {
+            start: Some(VehiclePlace { location, time: TimeInterval { earliest: Some(time.start), latest: None } }),
+            end: Some(VehiclePlace { location, time: TimeInterval { earliest: None, latest: Some(time.end) } }),
+        }],
+    }
+}
+
 pub(crate) fn create_dimens_with_id(
     prefix: &str,
     id: &str,
@@ -133,7 +146,7 @@ pub(crate) fn create_goal_context_distance_only(
         .build()
 }
 
-fn get_essential_features(
+pub(crate) fn get_essential_features(
     activity: Arc<SimpleActivityCost>,
     transport: Arc<dyn TransportCost>,
     is_time_constrained: bool,
@@ -150,6 +163,22 @@ fn get_essential_features(
     ])
 }
 
+/// Creates a hard constraint on tour duration which is specified by `VehicleDurationLimit` dimension.
+pub(crate) fn create_duration_limit_feature(
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<SimpleActivityCost>,
+) -> GenericResult<Feature> {
+    create_travel_limit_feature(
+        "tour_limits",
+        transport,
+        activity,
+        ViolationCode::default(),
+        ViolationCode::default(),
+        Arc::new(|_| None),
+        Arc::new(|actor| actor.vehicle.dimens.get_vehicle_duration_limit().copied()),
+    )
+}
+
 pub(crate) fn read_line<R: Read>(reader: &mut BufReader<R>, buffer: &mut String) -> Result<usize, GenericError> {
     buffer.clear();
     reader.read_line(buffer).map_err(|err| err.to_string().into())
@@ -17,9 +17,8 @@ pub(crate) fn write_text_solution<W: Write>(solution: &Solution, writer: &mut Bu
         let customers = r
             .tour
             .all_activities()
-            .filter(|a| a.job.is_some())
-            .map(|a| a.retrieve_job().unwrap())
-            .map(|job| job.dimens().get_job_id().unwrap().clone())
+            .filter_map(|a| a.job.as_ref())
+            .map(|single| single.dimens.get_job_id().unwrap().clone())
             .collect::<Vec<String>>()
             .join(" ");
         writer.write_all(format!("Route {i}: {customers}\n").as_bytes()).unwrap();
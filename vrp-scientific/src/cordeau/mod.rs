@@ -0,0 +1,7 @@
+//! Contains functionality to read Cordeau's multi depot and periodic problems and write their solutions.
+
+mod reader;
+pub use self::reader::CordeauProblem;
+
+mod writer;
+pub use self::writer::CordeauSolution;
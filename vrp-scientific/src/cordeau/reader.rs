@@ -0,0 +1,330 @@
+#[cfg(test)]
+#[path = "../../tests/unit/cordeau/reader_test.rs"]
+mod reader_test;
+
+use crate::common::*;
+use std::io::{BufReader, Read};
+use std::sync::Arc;
+use vrp_core::construction::features::*;
+use vrp_core::custom_dimension;
+use vrp_core::models::common::*;
+use vrp_core::models::problem::*;
+use vrp_core::models::*;
+use vrp_core::prelude::GenericError;
+use vrp_core::utils::Float;
+
+custom_dimension!(pub(crate) VehicleSlot typeof (usize, usize));
+
+/// A trait to read Cordeau's multi depot (MDVRP, MDVRPTW) and periodic (PVRP, PVRPTW) problems.
+pub trait CordeauProblem {
+    /// Reads Cordeau's problem.
+    fn read_cordeau(self, is_rounded: bool) -> Result<Problem, GenericError>;
+}
+
+impl<R: Read> CordeauProblem for BufReader<R> {
+    fn read_cordeau(self, is_rounded: bool) -> Result<Problem, GenericError> {
+        read_cordeau_format(self, is_rounded)
+    }
+}
+
+impl CordeauProblem for String {
+    fn read_cordeau(self, is_rounded: bool) -> Result<Problem, GenericError> {
+        read_cordeau_format(BufReader::new(self.as_bytes()), is_rounded)
+    }
+}
+
+fn read_cordeau_format<R: Read>(reader: BufReader<R>, is_rounded: bool) -> Result<Problem, GenericError> {
+    CordeauReader { buffer: String::new(), reader, coord_index: CoordIndex::default() }.read_problem(is_rounded)
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum ProblemType {
+    /// Depots are mapped to vehicle shift start and end places.
+    MultiDepot,
+    /// Days are mapped to consecutive intervals of the planning horizon.
+    Periodic,
+}
+
+struct HeaderLine {
+    problem_type: ProblemType,
+    vehicles: usize,
+    customers: usize,
+    /// Amount of depots or days depending on problem type.
+    periods: usize,
+}
+
+struct LimitLine {
+    duration: Float,
+    capacity: usize,
+}
+
+struct NodeLine {
+    id: usize,
+    location: (Float, Float),
+    service: Float,
+    demand: usize,
+    frequency: usize,
+    combinations: Vec<usize>,
+    tw: Option<TimeWindow>,
+}
+
+struct CordeauReader<R: Read> {
+    buffer: String,
+    reader: BufReader<R>,
+    coord_index: CoordIndex,
+}
+
+impl<R: Read> TextReader for CordeauReader<R> {
+    fn create_goal_context(
+        &self,
+        activity: Arc<SimpleActivityCost>,
+        transport: Arc<dyn TransportCost>,
+    ) -> Result<GoalContext, GenericError> {
+        let is_time_constrained = true;
+        let mut features = get_essential_features(activity.clone(), transport.clone(), is_time_constrained)?;
+        features.push(create_duration_limit_feature(transport, activity)?);
+
+        GoalContextBuilder::with_features(&features)?
+            .set_main_goal(Goal::subset_of(&features, &["min_unassigned", "min_distance"])?)
+            .add_alternative_goal(Goal::subset_of(&features, &["min_unassigned", "min_tours", "min_distance"])?)
+            .build()
+    }
+
+    fn read_definitions(&mut self) -> Result<(Vec<Job>, Fleet), GenericError> {
+        let header = self.read_header()?;
+        let limits = (0..header.periods).map(|_| self.read_limit()).collect::<Result<Vec<_>, _>>()?;
+        let (depots, customers): (Vec<_>, Vec<_>) =
+            self.read_nodes()?.into_iter().partition(|node| node.frequency == 0);
+
+        let expected_depots = match header.problem_type {
+            ProblemType::MultiDepot => header.periods,
+            ProblemType::Periodic => 1,
+        };
+        if depots.len() != expected_depots {
+            return Err(format!("expected {expected_depots} depot(s), got {}", depots.len()).into());
+        }
+        if customers.len() != header.customers {
+            return Err(format!("expected {} customers, got {}", header.customers, customers.len()).into());
+        }
+
+        let day_length = get_day_length(&depots, &customers);
+
+        let vehicles = limits
+            .iter()
+            .enumerate()
+            .flat_map(|(period_idx, limit)| {
+                let (depot, offset) = match header.problem_type {
+                    ProblemType::MultiDepot => (&depots[period_idx], 0.),
+                    ProblemType::Periodic => (&depots[0], period_idx as Float * day_length),
+                };
+                let location = self.coord_index.collect(depot.location);
+                let time = get_time_window(depot, header.problem_type, offset, day_length);
+
+                (0..header.vehicles)
+                    .map(|vehicle_idx| {
+                        let id = (period_idx * header.vehicles + vehicle_idx).to_string();
+                        let mut vehicle =
+                            create_vehicle_with_distance_costs(&id, limit.capacity, location, time.clone());
+                        vehicle.dimens.set_vehicle_slot((period_idx + 1, vehicle_idx + 1));
+                        if limit.duration > 0. {
+                            vehicle.dimens.set_vehicle_duration_limit(limit.duration);
+                        }
+
+                        Arc::new(vehicle)
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        let jobs = customers.iter().try_fold(Vec::with_capacity(customers.len()), |mut jobs, customer| {
+            match header.problem_type {
+                ProblemType::MultiDepot => {
+                    let times = vec![get_time_window(customer, header.problem_type, 0., day_length)];
+                    jobs.push(self.create_job(customer.id.to_string(), customer, times));
+                }
+                ProblemType::Periodic => {
+                    let schedules = customer
+                        .combinations
+                        .iter()
+                        .map(|&combination| get_days(combination, header.periods))
+                        .collect::<Vec<_>>();
+
+                    if let Some(days) = schedules.iter().find(|days| days.len() != customer.frequency) {
+                        return Err(format!(
+                            "customer {} has {} visit(s), but combination has {} day(s)",
+                            customer.id,
+                            customer.frequency,
+                            days.len()
+                        )
+                        .into());
+                    }
+
+                    let get_times = |days: &[usize]| {
+                        days.iter()
+                            .map(|&day| {
+                                get_time_window(customer, header.problem_type, day as Float * day_length, day_length)
+                            })
+                            .collect::<Vec<_>>()
+                    };
+
+                    // NOTE consistency between visits of different combinations cannot be expressed with time
+                    // windows, so the first combination is used for customers with more than one visit
+                    match schedules.first() {
+                        None => return Err(format!("customer {} has no visit combinations", customer.id).into()),
+                        Some(_) if customer.frequency == 1 => {
+                            let mut days = schedules.iter().flatten().cloned().collect::<Vec<_>>();
+                            days.sort_unstable();
+                            days.dedup();
+                            jobs.push(self.create_job(customer.id.to_string(), customer, get_times(&days)));
+                        }
+                        Some(days) => days.iter().for_each(|&day| {
+                            let id = format!("{}_{}", customer.id, day + 1);
+                            jobs.push(self.create_job(id, customer, get_times(&[day])));
+                        }),
+                    }
+                }
+            }
+
+            Ok::<_, GenericError>(jobs)
+        })?;
+
+        Ok((jobs, create_fleet_with_vehicles(vehicles)))
+    }
+
+    fn create_transport(&self, is_rounded: bool) -> Result<Arc<dyn TransportCost>, GenericError> {
+        self.coord_index.create_transport(is_rounded, &self.get_logger())
+    }
+
+    fn create_extras(&self) -> Extras {
+        get_extras(self.coord_index.clone())
+    }
+}
+
+impl<R: Read> CordeauReader<R> {
+    fn read_header(&mut self) -> Result<HeaderLine, GenericError> {
+        let values = self.read_values()?.ok_or("cannot read header line")?;
+        let (problem_type, vehicles, customers, periods) = values
+            .into_iter()
+            .map(|value| value as usize)
+            .try_collect_tuple()
+            .ok_or_else(|| "cannot parse problem type, vehicles, customers or/and periods".to_string())?;
+
+        let problem_type = match problem_type {
+            2 | 6 => ProblemType::MultiDepot,
+            1 | 7 => ProblemType::Periodic,
+            _ => return Err(format!("unsupported problem type: {problem_type}").into()),
+        };
+
+        Ok(HeaderLine { problem_type, vehicles, customers, periods })
+    }
+
+    fn read_limit(&mut self) -> Result<LimitLine, GenericError> {
+        let values = self.read_values()?.ok_or("cannot read depot or day limit line")?;
+        let (duration, capacity) = values
+            .into_iter()
+            .try_collect_tuple()
+            .ok_or_else(|| "cannot parse duration or/and capacity".to_string())?;
+
+        Ok(LimitLine { duration, capacity: capacity as usize })
+    }
+
+    fn read_nodes(&mut self) -> Result<Vec<NodeLine>, GenericError> {
+        let mut nodes = vec![];
+        while let Some(values) = self.read_values()? {
+            nodes.push(read_node(values).ok_or_else(|| format!("cannot read node line: '{}'", self.buffer))?);
+        }
+
+        Ok(nodes)
+    }
+
+    /// Reads next non empty line as a list of numbers. Returns `None` at the end of the input.
+    fn read_values(&mut self) -> Result<Option<Vec<Float>>, GenericError> {
+        loop {
+            if read_line(&mut self.reader, &mut self.buffer)? == 0 {
+                return Ok(None);
+            }
+
+            if self.buffer.trim().is_empty() {
+                continue;
+            }
+
+            return self
+                .buffer
+                .split_whitespace()
+                .map(|value| value.parse::<Float>().map_err(|err| format!("cannot parse '{value}': {err}").into()))
+                .collect::<Result<Vec<_>, GenericError>>()
+                .map(Some);
+        }
+    }
+
+    fn create_job(&mut self, id: String, customer: &NodeLine, times: Vec<TimeWindow>) -> Job {
+        let mut dimens = Dimensions::default();
+        dimens.set_job_id(id).set_job_demand(Demand::<SingleDimLoad> {
+            pickup: (SingleDimLoad::default(), SingleDimLoad::default()),
+            delivery: (SingleDimLoad::new(customer.demand as i32), SingleDimLoad::default()),
+        });
+
+        Job::Single(Arc::new(Single {
+            places: vec![Place {
+                location: Some(self.coord_index.collect(customer.location)),
+                duration: customer.service,
+                times: times.into_iter().map(TimeSpan::Window).collect(),
+            }],
+            dimens,
+        }))
+    }
+}
+
+/// Reads node line in `id x y service demand frequency combinations_size [combinations] [start end]` format.
+fn read_node(values: Vec<Float>) -> Option<NodeLine> {
+    let (id, x, y, service, demand, frequency, combinations_size) = values.iter().cloned().try_collect_tuple()?;
+    let combinations_size = combinations_size as usize;
+    let combinations = values.get(7..7 + combinations_size)?.iter().map(|&value| value as usize).collect::<Vec<_>>();
+
+    let tw = match &values[7 + combinations_size..] {
+        [] => None,
+        &[start, end] => Some(TimeWindow::new(start, end)),
+        _ => return None,
+    };
+
+    Some(NodeLine {
+        id: id as usize,
+        location: (x, y),
+        service,
+        demand: demand as usize,
+        frequency: frequency as usize,
+        combinations,
+        tw,
+    })
+}
+
+/// Gets zero based days encoded in the combination: the least significant bit is the first day.
+fn get_days(combination: usize, periods: usize) -> Vec<usize> {
+    (0..periods).filter(|day| combination & (1 << day) != 0).collect()
+}
+
+fn get_time_window(node: &NodeLine, problem_type: ProblemType, offset: Float, day_length: Float) -> TimeWindow {
+    match (problem_type, node.tw.as_ref()) {
+        (_, Some(tw)) => TimeWindow::new(offset + tw.start, offset + tw.end),
+        (ProblemType::Periodic, None) => TimeWindow::new(offset, offset + day_length),
+        (ProblemType::MultiDepot, None) => TimeWindow::max(),
+    }
+}
+
+/// Returns a length of one day in the planning horizon. When there are no time windows, it is long enough
+/// to visit all customers within one day.
+fn get_day_length(depots: &[NodeLine], customers: &[NodeLine]) -> Float {
+    if let Some(tw) = depots.first().and_then(|depot| depot.tw.as_ref()) {
+        return tw.end;
+    }
+
+    let nodes = || depots.iter().chain(customers.iter());
+    let (min_x, max_x, min_y, max_y) =
+        nodes().fold((Float::MAX, Float::MIN, Float::MAX, Float::MIN), |(min_x, max_x, min_y, max_y), node| {
+            let (x, y) = node.location;
+            (min_x.min(x), max_x.max(x), min_y.min(y), max_y.max(y))
+        });
+    let diagonal = ((max_x - min_x).powi(2) + (max_y - min_y).powi(2)).sqrt().ceil() + 1.;
+
+    nodes().map(|node| node.service + diagonal).sum::<Float>() + diagonal
+}
@@ -0,0 +1,70 @@
+#[cfg(test)]
+#[path = "../../tests/unit/cordeau/writer_test.rs"]
+mod writer_test;
+
+use super::reader::VehicleSlotDimension;
+use std::borrow::Borrow;
+use std::io::{BufWriter, Write};
+use vrp_core::construction::features::JobDemandDimension;
+use vrp_core::models::common::{Demand, SingleDimLoad};
+use vrp_core::models::problem::JobIdDimension;
+use vrp_core::prelude::*;
+
+/// A trait to write Cordeau's solution.
+pub trait CordeauSolution<W: Write> {
+    /// Writes solution in the format used by the literature: the first line is the total cost, then each route
+    /// is written as `depot_or_day vehicle duration load 0 customers.. 0`.
+    fn write_cordeau(&self, writer: &mut BufWriter<W>) -> Result<(), GenericError>;
+}
+
+impl<W: Write, B: Borrow<Solution>> CordeauSolution<W> for B {
+    fn write_cordeau(&self, writer: &mut BufWriter<W>) -> Result<(), GenericError> {
+        write_cordeau_solution(self.borrow(), writer)
+    }
+}
+
+fn write_cordeau_solution<W: Write>(solution: &Solution, writer: &mut BufWriter<W>) -> Result<(), GenericError> {
+    if !solution.unassigned.is_empty() {
+        return Err("cannot write cordeau solution with unassigned jobs.".into());
+    }
+
+    let mut routes = solution
+        .routes
+        .iter()
+        .map(|route| {
+            let &(period, vehicle) =
+                route.actor.vehicle.dimens.get_vehicle_slot().ok_or("cannot get depot or day of the vehicle")?;
+
+            let singles = route.tour.all_activities().filter_map(|activity| activity.job.as_ref()).collect::<Vec<_>>();
+            let customers = singles
+                .iter()
+                .map(|single| {
+                    // NOTE ids of periodic visits have a day suffix
+                    let id = single.dimens.get_job_id().ok_or("cannot get job id")?;
+                    Ok(id.split('_').next().unwrap_or(id).to_string())
+                })
+                .collect::<Result<Vec<_>, GenericError>>()?;
+            let load = singles
+                .iter()
+                .filter_map(|single| single.dimens.get_job_demand::<SingleDimLoad>())
+                .map(|demand: &Demand<SingleDimLoad>| demand.delivery.0.value)
+                .sum::<i32>();
+
+            let start = route.tour.start().map_or(0., |activity| activity.schedule.departure);
+            let end = route.tour.end().map_or(start, |activity| activity.schedule.arrival);
+
+            Ok((period, vehicle, end - start, load, customers))
+        })
+        .collect::<Result<Vec<_>, GenericError>>()?;
+
+    routes.sort_by(|(a_period, a_vehicle, ..), (b_period, b_vehicle, ..)| {
+        a_period.cmp(b_period).then(a_vehicle.cmp(b_vehicle))
+    });
+
+    writeln!(writer, "{:.2}", solution.cost)?;
+    routes.into_iter().try_for_each(|(period, vehicle, duration, load, customers)| {
+        writeln!(writer, "{period}\t{vehicle}\t{duration:.2}\t{load}\t0 {} 0", customers.join(" "))
+    })?;
+
+    Ok(())
+}
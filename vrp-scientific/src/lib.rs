@@ -6,7 +6,8 @@
 //!
 //! - **solomon**: see [Solomon benchmark](https://www.sintef.no/projectweb/top/vrptw/solomon-benchmark)
 //! - **lilim**: see [Li&Lim benchmark](https://www.sintef.no/projectweb/top/pdptw/li-lim-benchmark)
-//! - **tsplib** subset of TSPLIB95 format
+//! - **tsplib**: CVRP problems in TSPLIB95 format, see [CVRPLIB](http://vrp.atd-lab.inf.puc-rio.br/index.php/en/)
+//! - **cordeau**: multi depot and periodic VRP instances used by Cordeau et al.
 
 #![warn(missing_docs)]
 #![forbid(unsafe_code)]
@@ -23,6 +24,7 @@ mod known_problems_test;
 pub use vrp_core as core;
 
 pub mod common;
+pub mod cordeau;
 pub mod lilim;
 pub mod solomon;
 pub mod tsplib;
@@ -35,7 +35,6 @@ impl LilimProblem for String {
 struct VehicleLine {
     number: usize,
     capacity: usize,
-    _ignored: usize,
 }
 
 struct JobLine {
@@ -87,7 +86,7 @@ impl<R: Read> TextReader for LilimReader<R> {
 impl<R: Read> LilimReader<R> {
     fn read_fleet(&mut self) -> Result<Fleet, GenericError> {
         let vehicle = self.read_vehicle()?;
-        let depot = self.read_customer()?;
+        let depot = self.read_customer()?.ok_or("cannot find depot line")?;
 
         Ok(create_fleet_with_distance_costs(
             vehicle.number,
@@ -100,42 +99,36 @@ impl<R: Read> LilimReader<R> {
     fn read_jobs(&mut self) -> Result<Vec<Job>, GenericError> {
         let mut customers: HashMap<usize, JobLine> = Default::default();
         let mut relations: Vec<Relation> = Default::default();
-        loop {
-            match self.read_customer() {
-                Ok(customer) => {
-                    if customer.demand > 0 {
-                        relations.push(Relation { pickup: customer.id, delivery: customer.relation });
-                    }
-                    customers.insert(customer.id, customer);
-                }
-                Err(error) => {
-                    if self.buffer.is_empty() {
-                        break;
-                    } else {
-                        return Err(error);
-                    }
-                }
+
+        while let Some(customer) = self.read_customer()? {
+            if customer.demand > 0 {
+                relations.push(Relation { pickup: customer.id, delivery: customer.relation });
             }
+            customers.insert(customer.id, customer);
         }
 
-        let mut jobs: Vec<Job> = Default::default();
-        relations.iter().zip(0..).for_each(|(relation, index)| {
-            let pickup = customers.get(&relation.pickup).unwrap();
-            let delivery = customers.get(&relation.delivery).unwrap();
-
-            jobs.push(Job::Multi(Multi::new_shared(
-                vec![self.create_single_job(pickup), self.create_single_job(delivery)],
-                create_dimens_with_id("", &index.to_string(), |id, dimens| {
-                    dimens.set_job_id(id.to_string());
-                }),
-            )));
-        });
-
-        Ok(jobs)
+        relations
+            .iter()
+            .zip(0..)
+            .map(|(relation, index)| {
+                let pickup = customers.get(&relation.pickup).expect("pickup is always present");
+                let delivery = customers
+                    .get(&relation.delivery)
+                    .filter(|delivery| delivery.demand < 0)
+                    .ok_or_else(|| format!("cannot find delivery {} for pickup {}", relation.delivery, pickup.id))?;
+
+                Ok(Job::Multi(Multi::new_shared(
+                    vec![self.create_single_job(pickup), self.create_single_job(delivery)],
+                    create_dimens_with_id("", &index.to_string(), |id, dimens| {
+                        dimens.set_job_id(id.to_string());
+                    }),
+                )))
+            })
+            .collect()
     }
 
     fn create_single_job(&mut self, customer: &JobLine) -> Arc<Single> {
-        let mut dimens = create_dimens_with_id("c", &customer.id.to_string(), |id, dimens| {
+        let mut dimens = create_dimens_with_id("", &customer.id.to_string(), |id, dimens| {
             dimens.set_job_id(id.to_string());
         });
         dimens.set_job_demand(if customer.demand > 0 {
@@ -146,7 +139,7 @@ impl<R: Read> LilimReader<R> {
         } else {
             Demand::<SingleDimLoad> {
                 pickup: (SingleDimLoad::default(), SingleDimLoad::default()),
-                delivery: (SingleDimLoad::default(), SingleDimLoad::new(customer.demand)),
+                delivery: (SingleDimLoad::default(), SingleDimLoad::new(customer.demand.abs())),
             }
         });
 
@@ -156,37 +149,56 @@ impl<R: Read> LilimReader<R> {
                 duration: customer.service as Float,
                 times: vec![TimeSpan::Window(customer.tw.clone())],
             }],
-            dimens: Default::default(),
+            dimens,
         })
     }
 
     fn read_vehicle(&mut self) -> Result<VehicleLine, GenericError> {
-        read_line(&mut self.reader, &mut self.buffer)?;
-        let (number, capacity, _ignored) = self
-            .buffer
-            .split_whitespace()
-            .map(|line| line.parse::<usize>().unwrap())
-            .try_collect_tuple()
-            .ok_or_else(|| "cannot parse vehicle number or/and capacity".to_string())?;
-
-        Ok(VehicleLine { number, capacity, _ignored })
+        let values = self.read_values()?.ok_or("cannot find vehicle line")?;
+
+        // NOTE native layout has a third value (vehicle speed) which is ignored
+        match values.as_slice() {
+            &[number, capacity] | &[number, capacity, _] if number >= 0 && capacity >= 0 => {
+                Ok(VehicleLine { number: number as usize, capacity: capacity as usize })
+            }
+            _ => Err("cannot parse vehicle number or/and capacity".into()),
+        }
     }
 
-    fn read_customer(&mut self) -> Result<JobLine, GenericError> {
-        read_line(&mut self.reader, &mut self.buffer)?;
-        let (id, x, y, demand, start, end, service, _, relation) = self
-            .buffer
-            .split_whitespace()
-            .map(|line| line.parse::<i32>().unwrap())
-            .try_collect_tuple()
-            .ok_or_else(|| "cannot read customer line".to_string())?;
-        Ok(JobLine {
+    fn read_customer(&mut self) -> Result<Option<JobLine>, GenericError> {
+        let Some(values) = self.read_values()? else { return Ok(None) };
+
+        let (id, x, y, demand, start, end, service, _, relation) =
+            values.into_iter().try_collect_tuple().ok_or_else(|| "cannot read customer line".to_string())?;
+
+        Ok(Some(JobLine {
             id: id as usize,
             location: (x, y),
             demand,
             tw: TimeWindow::new(start as Float, end as Float),
             service: service as usize,
             relation: relation as usize,
-        })
+        }))
+    }
+
+    /// Reads next line with numeric values. Empty lines and header lines (e.g. instance name or column names used
+    /// in solomon like layout) are skipped, so both native and solomon like layouts are supported.
+    fn read_values(&mut self) -> Result<Option<Vec<i32>>, GenericError> {
+        loop {
+            if read_line(&mut self.reader, &mut self.buffer)? == 0 {
+                return Ok(None);
+            }
+
+            let line = self.buffer.trim();
+            if line.is_empty() || line.chars().any(|ch| ch.is_alphabetic()) {
+                continue;
+            }
+
+            return line
+                .split_whitespace()
+                .map(|value| value.parse::<i32>().map_err(|err| format!("cannot parse '{line}': {err}").into()))
+                .collect::<Result<Vec<_>, GenericError>>()
+                .map(Some);
+        }
     }
 }
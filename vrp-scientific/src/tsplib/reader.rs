@@ -13,29 +13,82 @@ use vrp_core::models::*;
 use vrp_core::prelude::GenericError;
 use vrp_core::utils::Float;
 
-/// A trait to read tsplib95 problem. Please note that it is very basic implementation of the format specification.
+/// A trait to read tsplib95 problem. Supports CVRP problems with EUC_2D, GEO and EXPLICIT edge weight types, e.g.
+/// instances from CVRPLIB.
 pub trait TsplibProblem {
-    /// Reads tsplib95 problem.
+    /// Reads tsplib95 problem. If distances are not rounded, the rounding convention of the benchmark set is used
+    /// for known instances, see `DistanceRounding::from_instance_name`.
     fn read_tsplib(self, is_rounded: bool) -> Result<Problem, GenericError>;
+
+    /// Reads tsplib95 problem using given rounding of EUC_2D distances.
+    fn read_tsplib_with_rounding(self, rounding: DistanceRounding) -> Result<Problem, GenericError>;
 }
 
 impl<R: Read> TsplibProblem for BufReader<R> {
     fn read_tsplib(self, is_rounded: bool) -> Result<Problem, GenericError> {
-        TsplibReader::new(self).read_problem(is_rounded)
+        TsplibReader::new(self, None).read_problem(is_rounded)
+    }
+
+    fn read_tsplib_with_rounding(self, rounding: DistanceRounding) -> Result<Problem, GenericError> {
+        TsplibReader::new(self, Some(rounding)).read_problem(false)
     }
 }
 
 impl TsplibProblem for String {
     fn read_tsplib(self, is_rounded: bool) -> Result<Problem, GenericError> {
-        TsplibReader::new(BufReader::new(self.as_bytes())).read_problem(is_rounded)
+        BufReader::new(self.as_bytes()).read_tsplib(is_rounded)
+    }
+
+    fn read_tsplib_with_rounding(self, rounding: DistanceRounding) -> Result<Problem, GenericError> {
+        BufReader::new(self.as_bytes()).read_tsplib_with_rounding(rounding)
     }
 }
 
+/// Specifies how edge weights are calculated.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum EdgeWeightType {
+    /// Euclidean distance in 2D.
+    Euc2D,
+    /// Geographical distance on idealized sphere, coordinates are given in `DDD.MM` format.
+    Geo,
+    /// Weights are listed explicitly in EDGE_WEIGHT_SECTION.
+    Explicit,
+}
+
+/// Specifies how explicit edge weights are listed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum EdgeWeightFormat {
+    FullMatrix,
+    UpperRow,
+    LowerRow,
+    UpperDiagRow,
+    LowerDiagRow,
+}
+
+/// Keeps node data read from problem's sections.
+struct ProblemData {
+    coordinates: HashMap<i32, (Float, Float)>,
+    demands: HashMap<i32, i32>,
+    depot_id: i32,
+}
+
 struct TsplibReader<R: Read> {
     buffer: String,
     reader: BufReader<R>,
+    rounding: Option<DistanceRounding>,
+    name: Option<String>,
     dimension: Option<usize>,
     vehicle_capacity: Option<usize>,
+    /// A maximum route length including service time (non-standard DISTANCE key).
+    distance_limit: Option<Float>,
+    /// A service time of each customer (non-standard SERVICE_TIME key).
+    service_time: Float,
+    edge_weight_type: Option<EdgeWeightType>,
+    edge_weight_format: Option<EdgeWeightFormat>,
+    /// A section name which follows the specification part.
+    section: Option<String>,
+    /// A full distance matrix built from explicit edge weights.
+    matrix: Option<Vec<Float>>,
     coord_index: CoordIndex,
 }
 
@@ -45,45 +98,77 @@ impl<R: Read> TextReader for TsplibReader<R> {
         activity: Arc<SimpleActivityCost>,
         transport: Arc<dyn TransportCost>,
     ) -> Result<GoalContext, GenericError> {
-        let is_time_constrained = false;
-        create_goal_context_distance_only(activity, transport, is_time_constrained)
+        if self.distance_limit.is_none() {
+            let is_time_constrained = false;
+            return create_goal_context_distance_only(activity, transport, is_time_constrained);
+        }
+
+        // NOTE route length is limited by tour duration as durations are the same as distances
+        let is_time_constrained = true;
+        let mut features = get_essential_features(activity.clone(), transport.clone(), is_time_constrained)?;
+        features.push(create_duration_limit_feature(transport, activity)?);
+
+        GoalContextBuilder::with_features(&features)?
+            .set_main_goal(Goal::subset_of(&features, &["min_unassigned", "min_distance"])?)
+            .add_alternative_goal(Goal::subset_of(&features, &["min_unassigned", "min_tours", "min_distance"])?)
+            .build()
     }
 
     fn read_definitions(&mut self) -> Result<(Vec<Job>, Fleet), GenericError> {
         self.read_meta()?;
 
-        let (coordinates, demands) = self.read_customer_data()?;
-        let depot_id = self.read_depot_data()?;
-        self.read_expected_line("EOF")?;
+        let ProblemData { coordinates, demands, depot_id } = self.read_sections()?;
 
         let dimension = self.dimension.unwrap();
+        let locations = self.create_locations(&coordinates)?;
 
-        let jobs = coordinates.iter().filter(|(id, _)| **id != depot_id).try_fold::<_, _, Result<_, GenericError>>(
+        let mut ids = locations.keys().filter(|id| **id != depot_id).cloned().collect::<Vec<_>>();
+        ids.sort();
+
+        let jobs = ids.into_iter().try_fold::<_, _, Result<_, GenericError>>(
             Vec::with_capacity(dimension),
-            |mut jobs, (id, (x, y))| {
-                let demand = demands.get(id).cloned().ok_or_else(|| format!("cannot find demand for id: '{id}'"))?;
+            |mut jobs, id| {
+                let demand = demands.get(&id).cloned().ok_or_else(|| format!("cannot find demand for id: '{id}'"))?;
 
-                jobs.push(self.create_job(&(*id - 1).to_string(), (*x, *y), demand));
+                jobs.push(self.create_job(&(id - 1).to_string(), locations[&id], demand));
 
                 Ok(jobs)
             },
         )?;
 
-        let depot_coord =
-            *coordinates.get(&depot_id).ok_or_else(|| format!("cannot find coordinate for depot id: '{depot_id}'"))?;
+        let depot_location =
+            *locations.get(&depot_id).ok_or_else(|| format!("cannot find location for depot id: '{depot_id}'"))?;
+
+        let vehicles = (0..dimension)
+            .map(|idx| {
+                let capacity = self.vehicle_capacity.unwrap();
+                let mut vehicle =
+                    create_vehicle_with_distance_costs(&idx.to_string(), capacity, depot_location, TimeWindow::max());
+                if let Some(distance_limit) = self.distance_limit {
+                    vehicle.dimens.set_vehicle_duration_limit(distance_limit);
+                }
 
-        let fleet = create_fleet_with_distance_costs(
-            dimension,
-            self.vehicle_capacity.unwrap(),
-            self.coord_index.collect(depot_coord),
-            TimeWindow::max(),
-        );
+                Arc::new(vehicle)
+            })
+            .collect();
 
-        Ok((jobs, fleet))
+        Ok((jobs, create_fleet_with_vehicles(vehicles)))
     }
 
     fn create_transport(&self, is_rounded: bool) -> Result<Arc<dyn TransportCost>, GenericError> {
-        self.coord_index.create_transport(is_rounded, &self.get_logger())
+        let logger = self.get_logger();
+
+        match self.edge_weight_type {
+            Some(EdgeWeightType::Euc2D) => {
+                self.coord_index.create_transport_with_rounding(self.get_rounding(is_rounded), &logger)
+            }
+            Some(EdgeWeightType::Geo) => create_matrix_transport(create_geo_matrix(&self.coord_index), &logger),
+            Some(EdgeWeightType::Explicit) => create_matrix_transport(
+                self.matrix.clone().ok_or_else(|| GenericError::from("cannot find EDGE_WEIGHT_SECTION"))?,
+                &logger,
+            ),
+            None => Err("cannot find EDGE_WEIGHT_TYPE".into()),
+        }
     }
 
     fn create_extras(&self) -> Extras {
@@ -91,71 +176,190 @@ impl<R: Read> TextReader for TsplibReader<R> {
     }
 }
 
-type ProblemData = (HashMap<i32, (i32, i32)>, HashMap<i32, i32>);
-
 impl<R: Read> TsplibReader<R> {
-    fn new(reader: BufReader<R>) -> Self {
+    fn new(reader: BufReader<R>, rounding: Option<DistanceRounding>) -> Self {
         Self {
             buffer: String::new(),
             reader,
+            rounding,
+            name: None,
             dimension: None,
             vehicle_capacity: None,
+            distance_limit: None,
+            service_time: 0.,
+            edge_weight_type: None,
+            edge_weight_format: None,
+            section: None,
+            matrix: None,
             coord_index: CoordIndex::default(),
         }
     }
 
+    /// Reads specification part which consists of `KEY : VALUE` lines in any order.
     fn read_meta(&mut self) -> Result<(), GenericError> {
-        self.skip_lines(2)?;
+        while let Some(line) = self.read_next_line()? {
+            let (key, value) = line.split_once(':').map_or((line.as_str(), ""), |(key, value)| (key, value));
+            let (key, value) = (key.trim(), value.trim());
 
-        let problem_type = self.read_key_value("TYPE")?;
-        if problem_type != "CVRP" {
-            return Err(format!("expecting 'CVRP' as TYPE, got '{problem_type}'").into());
+            if key.ends_with("_SECTION") || key == "EOF" {
+                self.section = Some(key.to_string());
+                break;
+            }
+
+            match key {
+                "NAME" => self.name = Some(value.to_string()),
+                "TYPE" if value != "CVRP" => {
+                    return Err(format!("expecting 'CVRP' as TYPE, got '{value}'").into());
+                }
+                "DIMENSION" => {
+                    self.dimension = Some(parse_int(value, "cannot parse DIMENSION").map(|v| v as usize)?);
+                }
+                "CAPACITY" => {
+                    self.vehicle_capacity = Some(parse_int(value, "cannot parse CAPACITY").map(|v| v as usize)?);
+                }
+                "DISTANCE" => self.distance_limit = Some(parse_float(value, "cannot parse DISTANCE")?),
+                "SERVICE_TIME" => self.service_time = parse_float(value, "cannot parse SERVICE_TIME")?,
+                "EDGE_WEIGHT_TYPE" => {
+                    self.edge_weight_type = Some(match value {
+                        "EUC_2D" => EdgeWeightType::Euc2D,
+                        "GEO" => EdgeWeightType::Geo,
+                        "EXPLICIT" => EdgeWeightType::Explicit,
+                        _ => {
+                            return Err(format!(
+                                "expecting one of 'EUC_2D', 'GEO', 'EXPLICIT' as EDGE_WEIGHT_TYPE, got '{value}'"
+                            )
+                            .into());
+                        }
+                    });
+                }
+                "EDGE_WEIGHT_FORMAT" => {
+                    self.edge_weight_format = match value {
+                        "FULL_MATRIX" => Some(EdgeWeightFormat::FullMatrix),
+                        "UPPER_ROW" => Some(EdgeWeightFormat::UpperRow),
+                        "LOWER_ROW" => Some(EdgeWeightFormat::LowerRow),
+                        "UPPER_DIAG_ROW" => Some(EdgeWeightFormat::UpperDiagRow),
+                        "LOWER_DIAG_ROW" => Some(EdgeWeightFormat::LowerDiagRow),
+                        // NOTE used with GEO edge weight type, weights are calculated from coordinates
+                        "FUNCTION" => None,
+                        _ => return Err(format!("unsupported EDGE_WEIGHT_FORMAT: '{value}'").into()),
+                    };
+                }
+                // NOTE other keys (COMMENT, DISPLAY_DATA_TYPE, etc.) are not relevant
+                _ => {}
+            }
         }
 
-        self.dimension = Some(
-            self.read_key_value("DIMENSION")
-                .and_then(|dimen| parse_int(&dimen, "cannot parse DIMENSION").map(|v| v as usize))?,
-        );
+        if self.dimension.is_none() {
+            return Err("cannot find DIMENSION".into());
+        }
 
-        let edge_type = self.read_key_value("EDGE_WEIGHT_TYPE")?;
-        if edge_type != "EUC_2D" {
-            return Err(format!("expecting 'EUC_2D' as EDGE_WEIGHT_TYPE, got '{edge_type}'").into());
+        if self.vehicle_capacity.is_none() {
+            return Err("cannot find CAPACITY".into());
         }
 
-        self.vehicle_capacity = Some(
-            self.read_key_value("CAPACITY")
-                .and_then(|capacity| parse_int(&capacity, "cannot parse CAPACITY").map(|v| v as usize))?,
-        );
+        match (self.edge_weight_type, self.edge_weight_format) {
+            (None, _) => Err("cannot find EDGE_WEIGHT_TYPE".into()),
+            (Some(EdgeWeightType::Explicit), None) => {
+                Err("EXPLICIT edge weight type requires EDGE_WEIGHT_FORMAT".into())
+            }
+            _ => Ok(()),
+        }
+    }
 
-        Ok(())
+    /// Returns rounding of EUC_2D distances: explicitly specified one, rounding to integer when requested or
+    /// convention of the benchmark set.
+    fn get_rounding(&self, is_rounded: bool) -> DistanceRounding {
+        self.rounding
+            .or_else(|| is_rounded.then_some(DistanceRounding::Round))
+            .or_else(|| self.name.as_deref().and_then(DistanceRounding::from_instance_name))
+            .unwrap_or(DistanceRounding::Exact)
     }
 
-    fn read_customer_data(&mut self) -> Result<ProblemData, GenericError> {
-        let dimension = self.dimension.unwrap();
+    /// Reads data part which consists of sections in any order.
+    fn read_sections(&mut self) -> Result<ProblemData, GenericError> {
+        let mut coordinates = None;
+        let mut display_coordinates = None;
+        let mut demands = None;
+        let mut depot_id = None;
+
+        let mut section = self.section.take();
+        while let Some(name) = section {
+            match name.trim_end_matches(':').trim() {
+                "NODE_COORD_SECTION" => coordinates = Some(self.read_coordinates()?),
+                "DISPLAY_DATA_SECTION" => display_coordinates = Some(self.read_coordinates()?),
+                "EDGE_WEIGHT_SECTION" => self.matrix = Some(self.read_edge_weights()?),
+                "DEMAND_SECTION" => demands = Some(self.read_demands()?),
+                "DEPOT_SECTION" => depot_id = Some(self.read_depot()?),
+                "EOF" => break,
+                name => return Err(format!("unexpected section: '{name}'").into()),
+            }
+
+            section = self.read_next_line()?;
+        }
+
+        if self.edge_weight_type == Some(EdgeWeightType::Explicit) && self.matrix.is_none() {
+            return Err("cannot find EDGE_WEIGHT_SECTION".into());
+        }
+
+        Ok(ProblemData {
+            // NOTE display data is used only when node coordinates are not specified
+            coordinates: coordinates.or(display_coordinates).unwrap_or_default(),
+            demands: demands.ok_or("cannot find DEMAND_SECTION")?,
+            depot_id: depot_id.ok_or("cannot find DEPOT_SECTION")?,
+        })
+    }
+
+    /// Creates a map between node ids and locations in coord index.
+    fn create_locations(
+        &mut self,
+        coordinates: &HashMap<i32, (Float, Float)>,
+    ) -> Result<HashMap<i32, Location>, GenericError> {
+        let dimension = self.dimension.unwrap() as i32;
+
+        if self.edge_weight_type == Some(EdgeWeightType::Explicit) {
+            // NOTE location is defined by the node position in the matrix, so coordinates are used only for
+            // analysis and they can be missing.
+            self.coord_index.locations =
+                (1..=dimension).map(|id| coordinates.get(&id).cloned().unwrap_or((0., 0.))).collect();
+
+            return Ok((1..=dimension).map(|id| (id, (id - 1) as Location)).collect());
+        }
+
+        (1..=dimension)
+            .map(|id| {
+                let coordinate =
+                    coordinates.get(&id).cloned().ok_or_else(|| format!("cannot find coordinate for id: '{id}'"))?;
+                Ok((id, self.coord_index.collect(coordinate)))
+            })
+            .collect()
+    }
 
-        // read coordinates
-        self.read_expected_line("NODE_COORD_SECTION")?;
+    fn read_coordinates(&mut self) -> Result<HashMap<i32, (Float, Float)>, GenericError> {
+        let dimension = self.dimension.unwrap();
 
-        let mut coordinates = HashMap::with_capacity(self.dimension.unwrap());
+        let mut coordinates = HashMap::with_capacity(dimension);
         for _ in 0..dimension {
-            let line = self.read_line()?.trim();
+            let line = self.read_next_line()?.ok_or("unexpected end of file in coord data")?;
             let data = line.split_whitespace().collect::<Vec<_>>();
 
             if data.len() != 3 {
                 return Err(format!("unexpected coord data: '{line}'").into());
             }
 
-            let coord = (parse_int(data[1], "cannot parse coord.0")?, parse_int(data[2], "cannot parse coord.1")?);
+            let coord = (parse_float(data[1], "cannot parse coord.0")?, parse_float(data[2], "cannot parse coord.1")?);
 
             coordinates.insert(parse_int(data[0], "cannot parse id")?, coord);
         }
 
-        // read demand
-        self.read_expected_line("DEMAND_SECTION")?;
+        Ok(coordinates)
+    }
+
+    fn read_demands(&mut self) -> Result<HashMap<i32, i32>, GenericError> {
+        let dimension = self.dimension.unwrap();
 
-        let mut demands = HashMap::with_capacity(self.dimension.unwrap());
+        let mut demands = HashMap::with_capacity(dimension);
         for _ in 0..dimension {
-            let line = self.read_line()?.trim();
+            let line = self.read_next_line()?.ok_or("unexpected end of file in demand data")?;
             let data = line.split_whitespace().collect::<Vec<_>>();
 
             if data.len() != 2 {
@@ -165,48 +369,71 @@ impl<R: Read> TsplibReader<R> {
             demands.insert(parse_int(data[0], "cannot parse id")?, parse_int(data[1], "cannot parse demand")?);
         }
 
-        Ok((coordinates, demands))
+        Ok(demands)
     }
 
-    fn read_depot_data(&mut self) -> Result<i32, GenericError> {
-        self.read_expected_line("DEPOT_SECTION")?;
-        let depot_id = parse_int(self.read_line()?.trim(), "cannot parse depot id")?;
-        self.read_expected_line("-1")?;
-
-        Ok(depot_id)
-    }
+    fn read_depot(&mut self) -> Result<i32, GenericError> {
+        let mut depot_ids = Vec::default();
 
-    fn read_key_value(&mut self, expected_key: &str) -> Result<String, GenericError> {
-        let line = self.read_line()?;
-        let key_value = line.split(':').map(|v| v.to_string()).collect::<Vec<_>>();
+        'outer: loop {
+            let line = self.read_next_line()?.ok_or("unexpected end of file in depot data")?;
+            for value in line.split_whitespace() {
+                match parse_int(value, "cannot parse depot id")? {
+                    -1 => break 'outer,
+                    id => depot_ids.push(id),
+                }
+            }
+        }
 
-        if key_value.len() != 2 {
-            return Err(format!("expected colon separated string, got: '{line}'").into());
+        match depot_ids.as_slice() {
+            &[depot_id] => Ok(depot_id),
+            _ => Err(format!("expecting exactly one depot, got: {}", depot_ids.len()).into()),
         }
+    }
 
-        let actual_key = key_value[0].trim();
-        if actual_key.trim() != expected_key {
-            return Err(format!("unexpected key, expecting: '{expected_key}', got: '{actual_key}'").into());
+    /// Reads explicit edge weights and returns them as a full matrix.
+    fn read_edge_weights(&mut self) -> Result<Vec<Float>, GenericError> {
+        let size = self.dimension.unwrap();
+        let format = self.edge_weight_format.ok_or("EDGE_WEIGHT_SECTION requires EDGE_WEIGHT_FORMAT")?;
+
+        let expected = match format {
+            EdgeWeightFormat::FullMatrix => size * size,
+            EdgeWeightFormat::UpperRow | EdgeWeightFormat::LowerRow => size * (size - 1) / 2,
+            EdgeWeightFormat::UpperDiagRow | EdgeWeightFormat::LowerDiagRow => size * (size + 1) / 2,
+        };
+
+        // NOTE weights are not aligned to lines: one row can be split into multiple lines and vice versa
+        let mut weights = Vec::with_capacity(expected);
+        while weights.len() < expected {
+            let line = self.read_next_line()?.ok_or("unexpected end of file in edge weight data")?;
+            line.split_whitespace().try_for_each(|value| {
+                weights.push(parse_float(value, "cannot parse edge weight")?);
+                Ok::<_, GenericError>(())
+            })?;
         }
 
-        Ok(key_value[1].trim().to_string())
-    }
+        if weights.len() != expected {
+            return Err(format!("expecting {expected} edge weights, got: {}", weights.len()).into());
+        }
 
-    fn read_expected_line(&mut self, expected: &str) -> Result<(), GenericError> {
-        let line = self.read_line()?.trim();
-        if line != expected { Err(format!("expecting {expected}, got: '{line}'").into()) } else { Ok(()) }
+        Ok(create_explicit_matrix(size, format, weights))
     }
 
-    fn read_line(&mut self) -> Result<&String, GenericError> {
-        read_line(&mut self.reader, &mut self.buffer)?;
-        Ok(&self.buffer)
-    }
+    /// Reads next non-empty line or returns `None` when end of file is reached.
+    fn read_next_line(&mut self) -> Result<Option<String>, GenericError> {
+        loop {
+            if read_line(&mut self.reader, &mut self.buffer)? == 0 {
+                return Ok(None);
+            }
 
-    fn skip_lines(&mut self, count: usize) -> Result<(), GenericError> {
-        skip_lines(count, &mut self.reader, &mut self.buffer)
+            let line = self.buffer.trim();
+            if !line.is_empty() {
+                return Ok(Some(line.to_string()));
+            }
+        }
     }
 
-    fn create_job(&mut self, id: &str, location: (i32, i32), demand: i32) -> Job {
+    fn create_job(&mut self, id: &str, location: Location, demand: i32) -> Job {
         let mut dimens = Dimensions::default();
         dimens.set_job_id(id.to_string()).set_job_demand(Demand::<SingleDimLoad> {
             pickup: (SingleDimLoad::default(), SingleDimLoad::default()),
@@ -214,8 +441,8 @@ impl<R: Read> TsplibReader<R> {
         });
         Job::Single(Arc::new(Single {
             places: vec![Place {
-                location: Some(self.coord_index.collect(location)),
-                duration: 0.,
+                location: Some(location),
+                duration: self.service_time,
                 times: vec![TimeSpan::Window(TimeWindow::max())],
             }],
             dimens,
@@ -223,9 +450,69 @@ impl<R: Read> TsplibReader<R> {
     }
 }
 
+/// Creates a full symmetric matrix from explicit edge weights listed in the given format.
+fn create_explicit_matrix(size: usize, format: EdgeWeightFormat, weights: Vec<Float>) -> Vec<Float> {
+    if format == EdgeWeightFormat::FullMatrix {
+        return weights;
+    }
+
+    let indices: Box<dyn Iterator<Item = (usize, usize)>> = match format {
+        EdgeWeightFormat::UpperRow => Box::new((0..size).flat_map(move |i| (i + 1..size).map(move |j| (i, j)))),
+        EdgeWeightFormat::LowerRow => Box::new((0..size).flat_map(|i| (0..i).map(move |j| (i, j)))),
+        EdgeWeightFormat::UpperDiagRow => Box::new((0..size).flat_map(move |i| (i..size).map(move |j| (i, j)))),
+        EdgeWeightFormat::LowerDiagRow => Box::new((0..size).flat_map(|i| (0..=i).map(move |j| (i, j)))),
+        EdgeWeightFormat::FullMatrix => unreachable!(),
+    };
+
+    let mut matrix = vec![0.; size * size];
+    indices.zip(weights).for_each(|((i, j), weight)| {
+        matrix[i * size + j] = weight;
+        matrix[j * size + i] = weight;
+    });
+
+    matrix
+}
+
+/// Creates a full matrix using geographical distance as defined by TSPLIB95 specification.
+fn create_geo_matrix(coord_index: &CoordIndex) -> Vec<Float> {
+    // NOTE use constants from specification to get exactly the same distances as in the literature
+    #[allow(clippy::approx_constant)]
+    const PI: Float = 3.141592;
+    const RRR: Float = 6378.388;
+
+    let to_radians = |value: Float| {
+        let degrees = value.trunc();
+        PI * (degrees + 5. * (value - degrees) / 3.) / 180.
+    };
+
+    let locations = coord_index.locations.iter().map(|&(x, y)| (to_radians(x), to_radians(y))).collect::<Vec<_>>();
+
+    locations
+        .iter()
+        .enumerate()
+        .flat_map(|(i, &(lat1, lon1))| {
+            locations.iter().enumerate().map(move |(j, &(lat2, lon2))| {
+                if i == j {
+                    return 0.;
+                }
+
+                let q1 = (lon1 - lon2).cos();
+                let q2 = (lat1 - lat2).cos();
+                let q3 = (lat1 + lat2).cos();
+
+                (RRR * (0.5 * ((1. + q1) * q2 - (1. - q1) * q3)).acos() + 1.).trunc()
+            })
+        })
+        .collect()
+}
+
 fn parse_int(data: &str, err_msg: &str) -> Result<i32, GenericError> {
     data.parse::<Float>()
         // NOTE observed that some input files might have coordinates like 28.00000
         .map(|value| value.round() as i32)
         .map_err(|err| format!("{err_msg}: '{err}'").into())
 }
+
+fn parse_float(data: &str, err_msg: &str) -> Result<Float, GenericError> {
+    data.parse::<Float>().map_err(|err| format!("{err_msg}: '{err}'").into())
+}
@@ -0,0 +1,92 @@
+use super::*;
+use crate::helpers::create_lc101_problem;
+use crate::lilim::LilimSolution;
+use std::io::BufWriter;
+use vrp_core::rosomaxa::evolution::TelemetryMode;
+use vrp_core::solver::search::{Recreate, RecreateWithCheapest};
+use vrp_core::solver::{ElitismPopulation, RefinementContext};
+
+fn solve_with_cheapest_insertion(problem: Arc<Problem>, environment: Arc<Environment>) -> Solution {
+    let refinement_ctx = RefinementContext::new(
+        problem.clone(),
+        Box::new(ElitismPopulation::new(problem.goal.clone(), environment.random.clone(), 1, 1)),
+        TelemetryMode::None,
+        environment.clone(),
+    );
+
+    RecreateWithCheapest::new(environment.random.clone())
+        .run(&refinement_ctx, InsertionContext::new(problem, environment))
+        .into()
+}
+
+#[test]
+fn can_compare_written_lilim_solution_with_itself() {
+    let environment = Arc::new(Environment::default());
+    let problem = Arc::new(create_lc101_problem());
+    let solution = solve_with_cheapest_insertion(problem.clone(), environment.clone());
+    let mut writer = BufWriter::new(Vec::new());
+    solution.write_lilim(&mut writer).unwrap();
+    let content = writer.into_inner().unwrap();
+
+    let comparison =
+        compare_with_best_known(BufReader::new(content.as_slice()), problem, &solution, environment).unwrap();
+
+    assert_eq!(comparison.tours.0, comparison.tours.1);
+    assert_eq!(comparison.cost.0.round(), comparison.cost.1.round());
+    assert!(comparison.cost_gap().abs() < 1E-6);
+}
+
+#[test]
+fn can_read_best_known_solution_with_header() {
+    let environment = Arc::new(Environment::default());
+    let problem = Arc::new(create_lc101_problem());
+    let solution = solve_with_cheapest_insertion(problem.clone(), environment.clone());
+    let mut writer = BufWriter::new(Vec::new());
+    solution.write_lilim(&mut writer).unwrap();
+    let routes = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+    let best_known = format!("Instance name : lc101\nAuthors       : unknown\nSolution\n{routes}");
+
+    let comparison =
+        compare_with_best_known(BufReader::new(best_known.as_bytes()), problem, &solution, environment).unwrap();
+
+    assert_eq!(comparison.tours.1, solution.routes.len());
+}
+
+#[test]
+fn can_detect_unassigned_jobs_in_best_known_solution() {
+    let environment = Arc::new(Environment::default());
+    let problem = Arc::new(create_lc101_problem());
+    let solution = solve_with_cheapest_insertion(problem.clone(), environment.clone());
+
+    let result = compare_with_best_known(BufReader::new("Route 1: 1 75\n".as_bytes()), problem, &solution, environment);
+
+    assert!(result.is_err_and(|err| err.to_string().contains("unassigned jobs")));
+}
+
+parameterized_test! {can_calculate_cost_gap, (cost, expected), {
+    assert_eq!(BestKnownComparison { tours: (1, 1), cost }.cost_gap(), expected);
+}}
+
+can_calculate_cost_gap! {
+    case01_worse: ((110., 100.), 10.),
+    case02_same: ((100., 100.), 0.),
+    case03_zero: ((10., 0.), 0.),
+}
+
+parameterized_test! {can_get_best_known_solution_from_registry, (name, expected), {
+    assert_eq!(get_best_known_solution(name), expected);
+}}
+
+can_get_best_known_solution_from_registry! {
+    case01_solomon: ("C101", Some(BestKnownSolution { tours: Some(10), cost: 828.94 })),
+    case02_case_insensitive: ("rc201", Some(BestKnownSolution { tours: Some(4), cost: 1406.94 })),
+    case03_tsplib: ("A-n32-k5", Some(BestKnownSolution { tours: None, cost: 784. })),
+    case04_unknown: ("C101.25", None),
+}
+
+#[test]
+fn can_calculate_cost_gap_with_best_known_solution() {
+    let best_known = BestKnownSolution { tours: None, cost: 200. };
+
+    assert_eq!(best_known.cost_gap(210.), 5.);
+}
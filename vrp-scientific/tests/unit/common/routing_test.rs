@@ -28,3 +28,26 @@ fn can_create_transport_with_rounding() {
 
     assert_eq!(transport.distance_approx(&Profile::new(0, None), 0, 1), 2.);
 }
+
+parameterized_test! {can_apply_distance_rounding, (rounding, value, expected), {
+    assert_eq!(rounding.apply(value), expected);
+}}
+
+can_apply_distance_rounding! {
+    case01_exact: (DistanceRounding::Exact, 2.6, 2.6),
+    case02_round: (DistanceRounding::Round, 2.6, 3.),
+    case03_truncate: (DistanceRounding::Truncate, 2.6, 2.),
+}
+
+parameterized_test! {can_get_distance_rounding_from_instance_name, (name, expected), {
+    assert_eq!(DistanceRounding::from_instance_name(name), expected);
+}}
+
+can_get_distance_rounding_from_instance_name! {
+    case01_golden: ("Golden_01", Some(DistanceRounding::Exact)),
+    case02_taillard: ("tai75a", Some(DistanceRounding::Exact)),
+    case03_christofides: ("CMT1", Some(DistanceRounding::Exact)),
+    case04_uchoa: ("X-n101-k25", Some(DistanceRounding::Round)),
+    case05_augerat: ("A-n32-k5", Some(DistanceRounding::Round)),
+    case06_unknown: ("toy.vrp", None),
+}
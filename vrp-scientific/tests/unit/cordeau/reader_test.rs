@@ -0,0 +1,115 @@
+use super::*;
+use crate::helpers::{get_job_ids, get_test_resource};
+
+fn read_problem(name: &str) -> Problem {
+    BufReader::new(get_test_resource(&format!("../../examples/data/scientific/cordeau/{name}")).unwrap())
+        .read_cordeau(false)
+        .unwrap()
+}
+
+type VehicleData = ((usize, usize), Location, TimeWindow, Option<Duration>, i32);
+
+fn get_vehicle_data(problem: &Problem) -> Vec<VehicleData> {
+    problem
+        .fleet
+        .vehicles
+        .iter()
+        .map(|vehicle| {
+            let start = vehicle.details[0].start.as_ref().unwrap();
+            let end = vehicle.details[0].end.as_ref().unwrap();
+            (
+                *vehicle.dimens.get_vehicle_slot().unwrap(),
+                start.location,
+                TimeWindow::new(start.time.earliest.unwrap(), end.time.latest.unwrap()),
+                vehicle.dimens.get_vehicle_duration_limit().copied(),
+                vehicle.dimens.get_vehicle_capacity::<SingleDimLoad>().unwrap().value,
+            )
+        })
+        .collect()
+}
+
+fn get_job_times(problem: &Problem, job_id: &str) -> Vec<TimeWindow> {
+    let job = problem.jobs.all().iter().find(|job| job.dimens().get_job_id().unwrap() == job_id).cloned().unwrap();
+
+    job.to_single().places[0].times.iter().map(|time| time.as_time_window().unwrap()).collect()
+}
+
+#[test]
+fn can_read_multi_depot_problem() {
+    let problem = read_problem("mdvrp.txt");
+
+    assert_eq!(get_job_ids(&problem), (1..=6).map(|id| id.to_string()).collect::<Vec<_>>());
+    let vehicles = get_vehicle_data(&problem);
+    assert_eq!(vehicles.len(), 4);
+    assert_eq!(vehicles.iter().map(|(slot, ..)| *slot).collect::<Vec<_>>(), vec![(1, 1), (1, 2), (2, 1), (2, 2)]);
+    assert_eq!(vehicles[0].1, vehicles[1].1);
+    assert_ne!(vehicles[1].1, vehicles[2].1);
+    assert!(vehicles.iter().all(|(_, _, time, duration, capacity)| {
+        *time == TimeWindow::max() && *duration == Some(100.) && *capacity == 50
+    }));
+    assert_eq!(get_job_times(&problem, "1"), vec![TimeWindow::max()]);
+}
+
+#[test]
+fn can_read_periodic_problem() {
+    let problem = read_problem("pvrp.txt");
+
+    assert_eq!(get_job_ids(&problem), vec!["1_1", "1_2", "2", "3", "4"]);
+    let vehicles = get_vehicle_data(&problem);
+    assert_eq!(vehicles.len(), 4);
+    assert!(vehicles.iter().all(|(_, location, _, duration, _)| *location == vehicles[0].1 && duration.is_none()));
+    let (day1, day2) = (vehicles[0].2.clone(), vehicles[2].2.clone());
+    assert_eq!(day1.start, 0.);
+    assert_eq!(day1.end, day2.start);
+    assert_eq!(vehicles[1].2, day1);
+    assert_eq!(vehicles[3].2, day2);
+    assert_eq!(get_job_times(&problem, "1_1"), vec![day1.clone()]);
+    assert_eq!(get_job_times(&problem, "1_2"), vec![day2.clone()]);
+    assert_eq!(get_job_times(&problem, "2"), vec![day1.clone(), day2.clone()]);
+    assert_eq!(get_job_times(&problem, "3"), vec![day1]);
+    assert_eq!(get_job_times(&problem, "4"), vec![day2]);
+}
+
+#[test]
+fn can_read_problem_with_time_windows_and_float_coordinates() {
+    let problem = r"6 1 2 2
+200 100
+0 100
+1 -5.5 3.25 2 10 1 2 1 2 0 100
+2 10.125 7.5 3 20 1 2 1 2 10 50
+3 0.0 0.0 0 0 0 0 0 500
+4 20.5 20.5 0 0 0 0 0 400
+"
+    .to_string()
+    .read_cordeau(false)
+    .unwrap();
+
+    let vehicles = get_vehicle_data(&problem);
+    assert_eq!(vehicles.len(), 2);
+    assert_eq!((vehicles[0].2.clone(), vehicles[0].3), (TimeWindow::new(0., 500.), Some(200.)));
+    assert_eq!((vehicles[1].2.clone(), vehicles[1].3), (TimeWindow::new(0., 400.), None));
+    assert_eq!(get_job_times(&problem, "2"), vec![TimeWindow::new(10., 50.)]);
+    let job = problem.jobs.all().iter().find(|job| job.dimens().get_job_id().unwrap() == "1").cloned().unwrap();
+    assert_eq!(job.to_single().places[0].duration, 2.);
+}
+
+parameterized_test! {can_reject_invalid_problem, (problem, expected), {
+    can_reject_invalid_problem_impl(problem, expected);
+}}
+
+can_reject_invalid_problem! {
+    case01_unsupported_type: ("3 1 1 1\n0 10\n1 1 1 0 1 1 1 1\n2 0 0 0 0 0 0\n", "unsupported problem type: 3"),
+    case02_missing_depot: ("2 1 1 2\n0 10\n0 10\n1 1 1 0 1 1 1 1\n2 0 0 0 0 0 0\n", "expected 2 depot(s), got 1"),
+    case03_wrong_customers: ("2 1 2 1\n0 10\n1 1 1 0 1 1 1 1\n2 0 0 0 0 0 0\n", "expected 2 customers, got 1"),
+    case04_wrong_combination: (
+        "1 1 1 2\n0 10\n0 10\n0 0 0 0 0 0 0\n1 1 1 0 1 2 1 1\n",
+        "customer 1 has 2 visit(s), but combination has 1 day(s)"
+    ),
+    case05_invalid_node: ("2 1 1 1\n0 10\n1 1 1 0 1 1 3 1\n2 0 0 0 0 0 0\n", "cannot read node line"),
+}
+
+fn can_reject_invalid_problem_impl(problem: &str, expected: &str) {
+    let result = problem.to_string().read_cordeau(false);
+
+    assert!(result.err().unwrap().to_string().starts_with(expected));
+}
@@ -0,0 +1,73 @@
+use super::*;
+use crate::cordeau::CordeauProblem;
+use crate::helpers::get_test_resource;
+use std::io::BufReader;
+use std::sync::Arc;
+use vrp_core::construction::heuristics::InsertionContext;
+use vrp_core::rosomaxa::evolution::TelemetryMode;
+use vrp_core::solver::search::{Recreate, RecreateWithCheapest};
+use vrp_core::solver::{ElitismPopulation, RefinementContext};
+use vrp_core::utils::Environment;
+
+fn write_solution(name: &str) -> String {
+    let environment = Arc::new(Environment::default());
+    let problem = Arc::new(
+        BufReader::new(get_test_resource(&format!("../../examples/data/scientific/cordeau/{name}")).unwrap())
+            .read_cordeau(false)
+            .unwrap(),
+    );
+    let refinement_ctx = RefinementContext::new(
+        problem.clone(),
+        Box::new(ElitismPopulation::new(problem.goal.clone(), environment.random.clone(), 1, 1)),
+        TelemetryMode::None,
+        environment.clone(),
+    );
+
+    let mut writer = BufWriter::new(Vec::new());
+    let solution: Solution = RecreateWithCheapest::new(environment.random.clone())
+        .run(&refinement_ctx, InsertionContext::new(problem, environment))
+        .into();
+    solution.write_cordeau(&mut writer).unwrap();
+
+    String::from_utf8(writer.into_inner().unwrap()).unwrap()
+}
+
+fn get_routes(result: &str) -> Vec<(usize, Vec<String>)> {
+    result
+        .lines()
+        .skip(1)
+        .map(|line| {
+            let values = line.split_whitespace().collect::<Vec<_>>();
+            assert_eq!(values[4], "0");
+            assert_eq!(values.last(), Some(&"0"));
+
+            let customers = values[5..values.len() - 1].iter().map(|id| id.to_string()).collect();
+            (values[0].parse().unwrap(), customers)
+        })
+        .collect()
+}
+
+#[test]
+fn can_write_multi_depot_solution() {
+    let result = write_solution("mdvrp.txt");
+
+    assert!(result.lines().next().unwrap().parse::<Float>().is_ok());
+    let routes = get_routes(result.as_str());
+    let get_depot_customers = |depot: usize| {
+        let mut customers = routes.iter().filter(|(d, _)| *d == depot).flat_map(|(_, c)| c.clone()).collect::<Vec<_>>();
+        customers.sort();
+        customers
+    };
+    assert_eq!(get_depot_customers(1), vec!["1", "2", "3"]);
+    assert_eq!(get_depot_customers(2), vec!["4", "5", "6"]);
+}
+
+#[test]
+fn can_write_periodic_solution_with_customer_ids() {
+    let result = write_solution("pvrp.txt");
+
+    let mut customers =
+        get_routes(result.as_str()).into_iter().flat_map(|(_, customers)| customers).collect::<Vec<_>>();
+    customers.sort();
+    assert_eq!(customers, vec!["1", "1", "2", "3", "4"]);
+}
@@ -1,3 +1,4 @@
+use super::*;
 use crate::helpers::{create_lc101_problem, get_job_ids, get_vehicle_capacity};
 
 #[test]
@@ -9,3 +10,73 @@ fn can_read_lilim_format_from_test_file() {
     assert_eq!(problem.fleet.vehicles.len(), 25);
     assert_eq!(get_vehicle_capacity(&problem), 200);
 }
+
+const NATIVE_LAYOUT: &str = r"2	50	1
+0	40	50	0	0	1236	0	0	0
+1	45	68	10	0	967	90	0	2
+2	45	70	-10	0	970	90	1	0
+";
+
+const SOLOMON_LAYOUT: &str = r"lc_small
+
+VEHICLE
+NUMBER     CAPACITY
+  2         50
+
+CUSTOMER
+CUST NO.  XCOORD.   YCOORD.    DEMAND   READY TIME  DUE DATE   SERVICE   TIME   PICKUP   DELIVERY
+
+    0      40         50          0          0       1236          0      0        0
+    1      45         68         10          0        967         90      0        2
+    2      45         70        -10          0        970         90      1        0
+";
+
+parameterized_test! {can_read_pdptw_layout, layout, {
+    can_read_pdptw_layout_impl(layout);
+}}
+
+can_read_pdptw_layout! {
+    case01_native: NATIVE_LAYOUT,
+    case02_solomon: SOLOMON_LAYOUT,
+}
+
+fn can_read_pdptw_layout_impl(layout: &str) {
+    let problem = layout.to_string().read_lilim(false).unwrap();
+
+    assert_eq!(get_job_ids(&problem), vec!["0".to_string()]);
+    assert_eq!(problem.fleet.vehicles.len(), 2);
+    assert_eq!(get_vehicle_capacity(&problem), 50);
+
+    let singles = problem.jobs.all().first().and_then(|job| job.as_multi()).map(|multi| multi.jobs.clone()).unwrap();
+    let get_demand = |idx: usize| singles[idx].dimens.get_job_demand::<SingleDimLoad>().cloned().unwrap();
+    assert_eq!(
+        singles.iter().map(|single| single.dimens.get_job_id().unwrap().clone()).collect::<Vec<_>>(),
+        ["1", "2"]
+    );
+    assert_eq!(get_demand(0).pickup.1, SingleDimLoad::new(10));
+    assert_eq!(get_demand(1).delivery.1, SingleDimLoad::new(10));
+}
+
+#[test]
+fn can_detect_missing_delivery() {
+    let problem = r"2	50	1
+0	40	50	0	0	1236	0	0	0
+1	45	68	10	0	967	90	0	3
+"
+    .to_string()
+    .read_lilim(false);
+
+    assert_eq!(problem.err().map(|err| err.to_string()), Some("cannot find delivery 3 for pickup 1".to_string()));
+}
+
+#[test]
+fn can_detect_invalid_customer_line() {
+    let problem = r"2	50	1
+0	40	50	0	0	1236	0	0	0
+1	45	68	10	0	967	90	0
+"
+    .to_string()
+    .read_lilim(false);
+
+    assert_eq!(problem.err().map(|err| err.to_string()), Some("cannot read customer line".to_string()));
+}
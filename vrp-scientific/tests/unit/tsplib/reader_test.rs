@@ -1,8 +1,11 @@
+use crate::common::{DistanceRounding, VehicleDurationLimitDimension};
 use crate::helpers::get_test_resource;
 use crate::tsplib::TsplibProblem;
 use crate::tsplib::reader::TsplibReader;
 use std::fs::File;
 use std::io::{BufReader, Read};
+use vrp_core::models::common::Profile;
+use vrp_core::utils::Float;
 
 fn get_example_problem_string() -> String {
     let mut buffer = "".to_string();
@@ -24,11 +27,12 @@ fn can_read_meta_errors() {
     for &(from, to, error) in &[
         ("CVRP", "ASD", "expecting 'CVRP' as TYPE, got 'ASD'"),
         ("DIMENSION : 6", "DIMENSION : asd", "cannot parse DIMENSION: 'invalid float literal'"),
-        ("EUC_2D", "ASD", "expecting 'EUC_2D' as EDGE_WEIGHT_TYPE, got 'ASD'"),
+        ("EUC_2D", "ASD", "expecting one of 'EUC_2D', 'GEO', 'EXPLICIT' as EDGE_WEIGHT_TYPE, got 'ASD'"),
+        ("EUC_2D", "EXPLICIT", "EXPLICIT edge weight type requires EDGE_WEIGHT_FORMAT"),
         ("CAPACITY : 30", "CAPACITY : asd", "cannot parse CAPACITY: 'invalid float literal'"),
     ] {
         let content = get_example_problem_string().replace(from, to);
-        let mut reader = TsplibReader::new(BufReader::new(content.as_bytes()));
+        let mut reader = TsplibReader::new(BufReader::new(content.as_bytes()), None);
 
         let result = reader.read_meta();
 
@@ -38,7 +42,7 @@ fn can_read_meta_errors() {
 
 #[test]
 fn can_read_meta_capacity_and_dimension() {
-    let mut reader = TsplibReader::new(get_example_problem_reader());
+    let mut reader = TsplibReader::new(get_example_problem_reader(), None);
 
     reader.read_meta().expect("cannot read meta");
 
@@ -47,23 +51,40 @@ fn can_read_meta_capacity_and_dimension() {
 }
 
 #[test]
-fn can_read_customer_data() {
-    let mut reader = TsplibReader::new(get_example_problem_reader());
+fn can_read_sections() {
+    let mut reader = TsplibReader::new(get_example_problem_reader(), None);
     reader.read_meta().expect("cannot read meta");
 
-    let (coordinates, demands) = reader.read_customer_data().expect("cannot read coordinates");
+    let data = reader.read_sections().expect("cannot read sections");
 
-    assert_eq!(coordinates.len(), 6);
-    assert_eq!(demands.len(), 6);
+    assert_eq!(data.coordinates.len(), 6);
+    assert_eq!(data.demands.len(), 6);
+    assert_eq!(data.depot_id, 1);
 }
 
 #[test]
-fn can_read_depot_data() {
-    let mut reader = TsplibReader::new(get_example_problem_reader());
+fn can_read_meta_in_any_order() {
+    let content = get_example_problem_string()
+        .replace("CAPACITY : 30\n", "")
+        .replace("TYPE : CVRP", "CAPACITY : 30\nCOMMENT : (Optimal value: 784)\nTYPE : CVRP");
+    let mut reader = TsplibReader::new(BufReader::new(content.as_bytes()), None);
+
+    reader.read_meta().expect("cannot read meta");
+
+    assert_eq!(reader.dimension, Some(6));
+    assert_eq!(reader.vehicle_capacity, Some(30));
+    assert_eq!(reader.section, Some("NODE_COORD_SECTION".to_string()));
+}
+
+#[test]
+fn can_read_depot_section_errors() {
+    let content = get_example_problem_string().replace("DEPOT_SECTION\n1\n", "DEPOT_SECTION\n1\n2\n");
+    let mut reader = TsplibReader::new(BufReader::new(content.as_bytes()), None);
     reader.read_meta().expect("cannot read meta");
-    reader.read_customer_data().expect("cannot read customer data");
 
-    assert_eq!(reader.read_depot_data(), Ok(1));
+    let result = reader.read_sections().map(|_| ());
+
+    assert_eq!(result, Err("expecting exactly one depot, got: 2".into()));
 }
 
 #[test]
@@ -75,3 +96,107 @@ fn can_read_problem() {
     assert_eq!(problem.jobs.size(), 5);
     assert_eq!(problem.fleet.actors.len(), 6);
 }
+
+fn create_explicit_problem(format: &str, weights: &str) -> String {
+    format!(
+        "NAME : explicit\nTYPE : CVRP\nDIMENSION : 4\nEDGE_WEIGHT_TYPE : EXPLICIT\nEDGE_WEIGHT_FORMAT : {format}\n\
+         CAPACITY : 10\nEDGE_WEIGHT_SECTION\n{weights}\nDEMAND_SECTION\n1 0\n2 1\n3 2\n4 3\n\
+         DEPOT_SECTION\n 1\n -1\nEOF\n"
+    )
+}
+
+parameterized_test! {can_read_explicit_edge_weights, (format, weights), {
+    can_read_explicit_edge_weights_impl(format, weights);
+}}
+
+can_read_explicit_edge_weights! {
+    case01_full_matrix: ("FULL_MATRIX", "0 1 2 3\n1 0 4 5\n2 4 0 6\n3 5 6 0"),
+    case02_lower_row: ("LOWER_ROW", "1\n2 4\n3 5 6"),
+    case03_lower_row_single_line: ("LOWER_ROW", "1 2 4 3 5 6"),
+    case04_upper_row: ("UPPER_ROW", "1 2 3\n4 5\n6"),
+    case05_lower_diag_row: ("LOWER_DIAG_ROW", "0\n1 0\n2 4 0\n3 5 6 0"),
+    case06_upper_diag_row: ("UPPER_DIAG_ROW", "0 1 2 3\n0 4 5\n0 6\n0"),
+}
+
+fn can_read_explicit_edge_weights_impl(format: &str, weights: &str) {
+    let expected = [[0., 1., 2., 3.], [1., 0., 4., 5.], [2., 4., 0., 6.], [3., 5., 6., 0.]];
+
+    let problem = create_explicit_problem(format, weights).read_tsplib(false).expect("cannot read problem");
+
+    assert_eq!(problem.jobs.size(), 3);
+    let profile = Profile::default();
+    (0..4).for_each(|from| {
+        (0..4).for_each(|to| {
+            assert_eq!(problem.transport.distance_approx(&profile, from, to), expected[from][to]);
+        })
+    });
+}
+
+#[test]
+fn can_read_explicit_edge_weights_errors() {
+    let result = create_explicit_problem("LOWER_ROW", "1\n2 4").read_tsplib(false).map(|_| ());
+
+    assert_eq!(result, Err("cannot parse edge weight: 'invalid float literal'".into()));
+}
+
+#[test]
+fn can_read_geo_problem() {
+    // NOTE first nodes of ulysses16 tsplib problem
+    let content = "NAME : geo\nTYPE : CVRP\nDIMENSION : 3\nEDGE_WEIGHT_TYPE : GEO\nCAPACITY : 10\n\
+                   NODE_COORD_SECTION\n1 38.24 20.42\n2 39.57 26.15\n3 40.56 25.32\n\
+                   DEMAND_SECTION\n1 0\n2 1\n3 1\nDEPOT_SECTION\n1\n-1\nEOF\n"
+        .to_string();
+
+    let problem = content.read_tsplib(false).expect("cannot read problem");
+
+    let profile = Profile::default();
+    assert_eq!(problem.transport.distance_approx(&profile, 0, 0), 0.);
+    assert_eq!(problem.transport.distance_approx(&profile, 0, 1), 509.);
+    assert_eq!(problem.transport.distance_approx(&profile, 1, 2), 126.);
+}
+
+fn create_golden_like_problem(name: &str) -> String {
+    format!(
+        "NAME : {name}\nTYPE : CVRP\nDIMENSION : 3\nCAPACITY : 10\nDISTANCE : 20\nSERVICE_TIME : 2\n\
+         EDGE_WEIGHT_TYPE : EUC_2D\nNODE_COORD_SECTION\n1 0 0\n2 1.5 1.5\n3 3 0\n\
+         DEMAND_SECTION\n1 0\n2 1\n3 1\nDEPOT_SECTION\n1\n-1\nEOF\n"
+    )
+}
+
+parameterized_test! {can_use_distance_rounding_convention, (name, is_rounded, expected), {
+    can_use_distance_rounding_convention_impl(name, is_rounded, expected);
+}}
+
+can_use_distance_rounding_convention! {
+    case01_golden_exact: ("Golden_01", false, 2.12132),
+    case02_taillard_exact: ("tai75a", false, 2.12132),
+    case03_uchoa_rounded: ("X-n101-k25", false, 2.),
+    case04_unknown_exact: ("unknown", false, 2.12132),
+    case05_golden_forced_rounded: ("Golden_01", true, 2.),
+}
+
+fn can_use_distance_rounding_convention_impl(name: &str, is_rounded: bool, expected: Float) {
+    let problem = create_golden_like_problem(name).read_tsplib(is_rounded).expect("cannot read problem");
+
+    assert!((problem.transport.distance_approx(&Profile::default(), 0, 1) - expected).abs() < 1E-5);
+}
+
+#[test]
+fn can_read_problem_with_explicit_rounding() {
+    let problem = create_golden_like_problem("X-n101-k25")
+        .read_tsplib_with_rounding(DistanceRounding::Truncate)
+        .expect("cannot read problem");
+
+    assert_eq!(problem.transport.distance_approx(&Profile::default(), 0, 1), 2.);
+    assert_eq!(problem.transport.distance_approx(&Profile::default(), 1, 2), 2.);
+}
+
+#[test]
+fn can_read_distance_limit_and_service_time() {
+    let problem = create_golden_like_problem("Golden_01").read_tsplib(false).expect("cannot read problem");
+
+    assert!(problem.jobs.all().iter().all(|job| job.as_single().unwrap().places[0].duration == 2.));
+    assert!(
+        problem.fleet.vehicles.iter().all(|vehicle| vehicle.dimens.get_vehicle_duration_limit().copied() == Some(20.))
+    );
+}